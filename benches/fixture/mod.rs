@@ -0,0 +1,222 @@
+//! Synthetic governance projects for the benchmarks.
+//!
+//! [`generate`] runs `govctl init` and then writes RFCs, clauses, ADRs, and
+//! work items straight to disk, so building a project with thousands of
+//! clauses takes seconds instead of one CLI call per artifact. Artifacts
+//! cross-reference each other the way a real project does, and the result
+//! passes `govctl check`.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+const DATE: &str = "2026-01-01";
+
+/// How many artifacts of each kind to generate.
+#[derive(Debug, Clone, Copy)]
+pub struct FixtureSize {
+    pub rfcs: usize,
+    pub clauses_per_rfc: usize,
+    pub adrs: usize,
+    pub work_items: usize,
+}
+
+impl FixtureSize {
+    /// The default bench project, multiplied by `scale`: 1 000 clauses,
+    /// 200 ADRs, and 500 work items at scale 1.
+    pub fn scaled(scale: usize) -> Self {
+        Self {
+            rfcs: 20 * scale,
+            clauses_per_rfc: 50,
+            adrs: 200 * scale,
+            work_items: 500 * scale,
+        }
+    }
+
+    pub fn clauses(&self) -> usize {
+        self.rfcs * self.clauses_per_rfc
+    }
+}
+
+/// Create a project of `size` in `root`, which must exist and be empty.
+pub fn generate(govctl: &Path, root: &Path, size: FixtureSize) -> io::Result<()> {
+    let init = Command::new(govctl)
+        .arg("init")
+        .current_dir(root)
+        .env("NO_COLOR", "1")
+        .env("GOVCTL_DEFAULT_OWNER", "@bench")
+        .output()?;
+    if !init.status.success() {
+        return Err(io::Error::other(format!(
+            "govctl init failed: {}",
+            String::from_utf8_lossy(&init.stderr)
+        )));
+    }
+
+    let gov = root.join("gov");
+    for rfc in 1..=size.rfcs {
+        write_rfc(&gov, rfc, size)?;
+    }
+    for adr in 1..=size.adrs {
+        write_adr(&gov, adr, size)?;
+    }
+    for item in 1..=size.work_items {
+        write_work_item(&gov, item, size)?;
+    }
+    Ok(())
+}
+
+fn rfc_id(n: usize) -> String {
+    format!("RFC-{n:04}")
+}
+
+fn clause_id(n: usize) -> String {
+    format!("C-REQ-{n:03}")
+}
+
+fn write_rfc(gov: &Path, n: usize, size: FixtureSize) -> io::Result<()> {
+    let id = rfc_id(n);
+    let dir = gov.join("rfc").join(&id);
+    fs::create_dir_all(dir.join("clauses"))?;
+
+    let clause_paths: Vec<String> = (1..=size.clauses_per_rfc)
+        .map(|c| format!("    \"clauses/{}.toml\",", clause_id(c)))
+        .collect();
+    let rfc = format!(
+        r#"#:schema ../../schema/rfc.schema.json
+
+[govctl]
+id = "{id}"
+title = "Synthetic specification {n}"
+version = "0.1.0"
+status = "draft"
+phase = "spec"
+owners = ["@bench"]
+created = "{DATE}"
+tags = []
+
+[[sections]]
+title = "Specification"
+clauses = [
+{clauses}
+]
+
+[[changelog]]
+version = "0.1.0"
+date = "{DATE}"
+notes = "Initial draft"
+"#,
+        clauses = clause_paths.join("\n"),
+    );
+    fs::write(dir.join("rfc.toml"), rfc)?;
+
+    for c in 1..=size.clauses_per_rfc {
+        let clause = format!(
+            r#"#:schema ../../../schema/clause.schema.json
+
+[govctl]
+id = "{clause}"
+title = "Requirement {c} of {id}"
+kind = "normative"
+status = "active"
+
+[content]
+text = """
+{text}"""
+"#,
+            clause = clause_id(c),
+            text = clause_text(n, c, size),
+        );
+        fs::write(
+            dir.join("clauses").join(format!("{}.toml", clause_id(c))),
+            clause,
+        )?;
+    }
+    Ok(())
+}
+
+/// A few paragraphs of prose, with a reference to the previous RFC so
+/// validation has links to resolve.
+fn clause_text(rfc: usize, clause: usize, size: FixtureSize) -> String {
+    let mut text = String::new();
+    for paragraph in 0..3 {
+        let _ = writeln!(
+            text,
+            "The system MUST keep behaviour {clause}.{paragraph} of {} stable across \
+             releases, and SHOULD report any deviation with a diagnostic that names \
+             the affected artifact and the clause it breaks.\n",
+            rfc_id(rfc)
+        );
+    }
+    if rfc > 1 {
+        let target = clause.min(size.clauses_per_rfc);
+        let _ = write!(
+            text,
+            "See [[{}:{}]] for the related requirement.",
+            rfc_id(rfc - 1),
+            clause_id(target)
+        );
+    }
+    text
+}
+
+fn write_adr(gov: &Path, n: usize, size: FixtureSize) -> io::Result<()> {
+    let id = format!("ADR-{n:04}");
+    let rfc = rfc_id((n - 1) % size.rfcs.max(1) + 1);
+    let adr = format!(
+        r#"#:schema ../schema/adr.schema.json
+
+[govctl]
+id = "{id}"
+title = "Synthetic decision {n}"
+status = "accepted"
+date = "{DATE}"
+refs = ["{rfc}"]
+
+[content]
+context = """
+Decision {n} needs a recorded rationale so later readers can tell why the
+project chose this path over the alternatives."""
+decision = """
+We follow [[{rfc}]] for this area and keep the existing layout."""
+consequences = """
+Changes in this area go through the usual review and keep {rfc} current."""
+"#
+    );
+    fs::write(
+        gov.join("adr")
+            .join(format!("{id}-synthetic-decision-{n}.toml")),
+        adr,
+    )
+}
+
+fn write_work_item(gov: &Path, n: usize, size: FixtureSize) -> io::Result<()> {
+    let id = format!("WI-{DATE}-{n:03}");
+    let rfc = rfc_id((n - 1) % size.rfcs.max(1) + 1);
+    let item = format!(
+        r#"#:schema ../schema/work.schema.json
+
+[govctl]
+id = "{id}"
+title = "Synthetic task {n}"
+status = "queue"
+created = "{DATE}"
+refs = ["{rfc}"]
+
+[content]
+description = """
+Implement the next slice of {rfc} and cover it with tests."""
+
+[[content.acceptance_criteria]]
+text = "add: behaviour {n} implemented"
+status = "pending"
+"#
+    );
+    fs::write(
+        gov.join("work")
+            .join(format!("{DATE}-synthetic-task-{n}.toml")),
+        item,
+    )
+}
@@ -0,0 +1,90 @@
+//! Load, validate, and render timings over a synthetic large project.
+//!
+//! Criterion benchmarks that call into govctl in-process on a generated
+//! project (see [`fixture`]):
+//!
+//! ```text
+//! cargo bench                                  # all benchmarks
+//! cargo bench -- validate                      # benchmarks whose name contains "validate"
+//! cargo bench -- --save-baseline main          # record results as "main"
+//! cargo bench -- --baseline main               # compare against "main"
+//! ```
+//!
+//! `GOVCTL_BENCH_SCALE` multiplies the project size (default 1: 1 000
+//! clauses, 200 ADRs, 500 work items).
+
+mod fixture;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use fixture::FixtureSize;
+use govctl::bench::{Index, Project};
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::Instant;
+
+type BenchResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+/// The generated project, opened and loaded once for all benchmarks.
+struct Setup {
+    _dir: tempfile::TempDir,
+    project: Project,
+    index: Index,
+}
+
+fn setup() -> BenchResult<Setup> {
+    let scale = match std::env::var("GOVCTL_BENCH_SCALE") {
+        Ok(value) => value.parse::<usize>()?.max(1),
+        Err(_) => 1,
+    };
+    let size = FixtureSize::scaled(scale);
+    let dir = tempfile::TempDir::new()?;
+    let started = Instant::now();
+    fixture::generate(
+        &PathBuf::from(env!("CARGO_BIN_EXE_govctl")),
+        dir.path(),
+        size,
+    )?;
+    eprintln!(
+        "fixture: {} RFCs, {} clauses, {} ADRs, {} work items ({:.1}s)",
+        size.rfcs,
+        size.clauses(),
+        size.adrs,
+        size.work_items,
+        started.elapsed().as_secs_f64()
+    );
+
+    let project = Project::open(dir.path())?;
+    let index = project.load()?;
+    // Rendering a project that fails to render would time the error path.
+    project.render(&index)?;
+    Ok(Setup {
+        _dir: dir,
+        project,
+        index,
+    })
+}
+
+fn large_project(c: &mut Criterion) {
+    let setup = match setup() {
+        Ok(setup) => setup,
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    };
+    let Setup { project, index, .. } = &setup;
+
+    let mut group = c.benchmark_group("large_project");
+    group.sample_size(10);
+    group.bench_function("load", |b| b.iter(|| black_box(project.load())));
+    group.bench_function("validate", |b| {
+        b.iter(|| black_box(project.validate(black_box(index))))
+    });
+    group.bench_function("render", |b| {
+        b.iter(|| black_box(project.render(black_box(index))))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, large_project);
+criterion_main!(benches);
@@ -0,0 +1,94 @@
+//! Artifact aliases: `@name` in place of an ID.
+//!
+//! Aliases are declared in `gov/config.toml`, and `@last` always names the
+//! artifact most recently created in this checkout:
+//!
+//! ```toml
+//! [aliases]
+//! core = "RFC-0000"
+//! current = "WI-2026-02-22-003"
+//! ```
+//!
+//! Planning expands aliases through [`Aliases::expand`], like ID shorthand;
+//! an alias that names nothing is reported when the plan's IDs are resolved.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, not_found_message};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Alias naming the most recently created artifact.
+pub const LAST: &str = "last";
+
+/// The project's aliases, `@last` included, by name.
+#[derive(Debug, Clone, Default)]
+pub struct Aliases(BTreeMap<String, String>);
+
+impl Aliases {
+    /// Aliases declared in `config`, plus `@last` when this checkout has
+    /// created anything.
+    pub fn load(config: &Config) -> Self {
+        let mut aliases = config.aliases.clone();
+        if let Some(last) = read_last(config) {
+            aliases.insert(LAST.to_string(), last);
+        }
+        Self(aliases)
+    }
+
+    /// ID that `input` names when it is an `@alias`, or a clause of one
+    /// such as `@core:C-SCOPE`.
+    pub fn expand(&self, input: &str) -> Option<String> {
+        let name = input.strip_prefix('@')?;
+        let (name, clause) = match name.split_once(':') {
+            Some((name, clause)) => (name, Some(clause)),
+            None => (name, None),
+        };
+        let id = self.0.get(name)?;
+        Some(match clause {
+            Some(clause) => format!("{id}:{clause}"),
+            None => id.clone(),
+        })
+    }
+
+    /// `input` with an `@alias` expanded; other input is unchanged.
+    pub fn expand_or_keep(&self, input: &str) -> String {
+        self.expand(input).unwrap_or_else(|| input.to_string())
+    }
+}
+
+/// Error for `@name` input that no alias names.
+pub fn unknown_alias(config: &Config, input: &str) -> Diagnostic {
+    let message = if input == format!("@{LAST}") {
+        format!("Alias not found: {input} (hint: nothing has been created in this checkout yet)")
+    } else {
+        let known: Vec<String> = config
+            .aliases
+            .keys()
+            .map(|name| format!("@{name}"))
+            .collect();
+        not_found_message("Alias", input, known.iter().map(String::as_str))
+    };
+    Diagnostic::new(DiagnosticCode::E0830UnknownAlias, message, input)
+}
+
+/// Remember `id` as the artifact `@last` names.
+pub fn record_last(config: &Config, id: &str) -> DiagnosticResult<()> {
+    let path = last_path(config);
+    let display = config.display_path(&path).display().to_string();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| Diagnostic::io_error("create state directory", err, &display))?;
+    }
+    std::fs::write(&path, format!("{id}\n"))
+        .map_err(|err| Diagnostic::io_error("write @last", err, &display))
+}
+
+fn read_last(config: &Config) -> Option<String> {
+    let content = std::fs::read_to_string(last_path(config)).ok()?;
+    let id = content.trim();
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+fn last_path(config: &Config) -> PathBuf {
+    config.project_root().join(".govctl").join(LAST)
+}
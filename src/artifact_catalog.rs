@@ -1,5 +1,6 @@
+use crate::cmd::edit::ArtifactType;
 use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, not_found_message};
 use crate::model::{AdrEntry, GuardEntry, WorkItemEntry};
 use crate::parse::{load_adr, load_adrs, load_guard, load_guards, load_work_item, load_work_items};
 use rusqlite::{Connection, OptionalExtension, params};
@@ -41,6 +42,38 @@ impl CatalogKind {
         }
     }
 
+    pub(crate) fn of_artifact(artifact: ArtifactType) -> Self {
+        match artifact {
+            ArtifactType::Rfc => Self::Rfc,
+            ArtifactType::Clause => Self::Clause,
+            ArtifactType::Adr => Self::Adr,
+            ArtifactType::WorkItem => Self::Work,
+            ArtifactType::Guard => Self::Guard,
+        }
+    }
+
+    /// Code reported when an ID of this kind names nothing.
+    pub(crate) fn not_found_code(self) -> DiagnosticCode {
+        match self {
+            Self::Rfc => DiagnosticCode::E0102RfcNotFound,
+            Self::Clause => DiagnosticCode::E0202ClauseNotFound,
+            Self::Adr => DiagnosticCode::E0302AdrNotFound,
+            Self::Work => DiagnosticCode::E0402WorkNotFound,
+            Self::Guard => DiagnosticCode::E1002GuardNotFound,
+        }
+    }
+
+    /// Name of the artifact kind in messages.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Rfc => "RFC",
+            Self::Clause => "Clause",
+            Self::Adr => "ADR",
+            Self::Work => "Work item",
+            Self::Guard => "Guard",
+        }
+    }
+
     fn dir(self, config: &Config) -> PathBuf {
         match self {
             Self::Rfc | Self::Clause => config.rfc_dir(),
@@ -61,12 +94,6 @@ pub(crate) struct CatalogRecord {
     pub(crate) source_hash: String,
 }
 
-#[derive(Debug, Clone, Copy)]
-struct MissingArtifact {
-    code: DiagnosticCode,
-    label: &'static str,
-}
-
 impl CatalogRecord {
     pub(crate) fn absolute_path(&self, config: &Config) -> PathBuf {
         project_root(config).join(&self.path)
@@ -81,10 +108,7 @@ pub(crate) fn load_adr_by_id(config: &Config, id: &str) -> DiagnosticResult<AdrE
         load_adr,
         load_adrs,
         |entry| &entry.spec.govctl.id,
-        MissingArtifact {
-            code: DiagnosticCode::E0302AdrNotFound,
-            label: "ADR",
-        },
+        DiagnosticCode::E0302AdrNotFound,
     )
 }
 
@@ -96,10 +120,7 @@ pub(crate) fn load_work_item_by_id(config: &Config, id: &str) -> DiagnosticResul
         load_work_item,
         load_work_items,
         |entry| &entry.spec.govctl.id,
-        MissingArtifact {
-            code: DiagnosticCode::E0402WorkNotFound,
-            label: "Work item",
-        },
+        DiagnosticCode::E0402WorkNotFound,
     )
 }
 
@@ -111,10 +132,7 @@ pub(crate) fn load_guard_by_id(config: &Config, id: &str) -> DiagnosticResult<Gu
         load_guard,
         load_guards,
         |entry| &entry.spec.govctl.id,
-        MissingArtifact {
-            code: DiagnosticCode::E1002GuardNotFound,
-            label: "Guard",
-        },
+        DiagnosticCode::E1002GuardNotFound,
     )
 }
 
@@ -125,22 +143,26 @@ fn load_cataloged_entry<T>(
     load_one: fn(&Config, &Path) -> DiagnosticResult<T>,
     load_all: fn(&Config) -> DiagnosticResult<Vec<T>>,
     entry_id: impl Fn(&T) -> &String,
-    missing: MissingArtifact,
+    missing_code: DiagnosticCode,
 ) -> DiagnosticResult<T> {
     if let Some(entry) = load_from_catalog(config, kind, id, load_one, &entry_id) {
         return Ok(entry);
     }
 
-    let entries = load_all(config)?;
-    let found = entries.into_iter().find(|entry| entry_id(entry) == id);
-    let Some(entry) = found else {
+    let mut entries = load_all(config)?;
+    let Some(position) = entries.iter().position(|entry| entry_id(entry) == id) else {
         let location = config.display_path(&kind.dir(config)).display().to_string();
         return Err(Diagnostic::new(
-            missing.code,
-            format!("{} not found: {id}", missing.label),
+            missing_code,
+            not_found_message(
+                kind.label(),
+                id,
+                entries.iter().map(|entry| entry_id(entry).as_str()),
+            ),
             location,
         ));
     };
+    let entry = entries.swap_remove(position);
 
     // [[RFC-0002:C-SEARCH-COMMAND]]: TOML artifacts are authoritative; refresh
     // the derived `.govctl/index.db` catalog opportunistically after fallback.
@@ -197,6 +219,75 @@ fn try_cached_path(
     lookup_path(config, &connection, kind, id)
 }
 
+/// `"{label} not found: {id}"`, with a hint naming the closest IDs of `kind`
+/// artifacts on disk.
+pub(crate) fn missing_id_message(config: &Config, kind: CatalogKind, id: &str) -> String {
+    let known = scan_kind(config, kind).unwrap_or_default();
+    not_found_message(
+        kind.label(),
+        id,
+        known.iter().map(|entry| entry.id.as_str()),
+    )
+}
+
+/// IDs of every `kind` artifact on disk.
+pub(crate) fn artifact_ids(config: &Config, kind: CatalogKind) -> DiagnosticResult<Vec<String>> {
+    Ok(scan_kind(config, kind)?
+        .into_iter()
+        .map(|entry| entry.id)
+        .collect())
+}
+
+/// ID of the one `kind` artifact that `query` names: its ID, the end of its
+/// ID after a dash or colon (`001` for `WI-2026-01-17-001`, `C-EXPIRY` for
+/// `RFC-0001:C-EXPIRY`), or part of its title
+/// (`guard-timeout` for "Guard timeout"). `None` when nothing matches; a
+/// query matching several artifacts is an error listing them.
+pub(crate) fn resolve_partial_id(
+    config: &Config,
+    kind: CatalogKind,
+    query: &str,
+) -> DiagnosticResult<Option<String>> {
+    let entries = scan_kind(config, kind)?;
+    if entries.iter().any(|entry| entry.id == query) {
+        return Ok(Some(query.to_string()));
+    }
+    let upper = query.to_ascii_uppercase();
+    let fragment = slug::slugify(query);
+    let matches: Vec<&CatalogEntry> = entries
+        .iter()
+        .filter(|entry| {
+            let id = entry.id.to_ascii_uppercase();
+            id.strip_suffix(&upper)
+                .is_some_and(|rest| rest.ends_with(['-', ':']))
+                || (!fragment.is_empty() && slug::slugify(&entry.title).contains(&fragment))
+        })
+        .collect();
+    match matches.as_slice() {
+        [] => Ok(None),
+        [only] => Ok(Some(only.id.clone())),
+        _ => {
+            let candidates: Vec<String> = matches
+                .iter()
+                .map(|entry| format!("{} ({})", entry.id, entry.title))
+                .collect();
+            Err(Diagnostic::new(
+                match kind {
+                    CatalogKind::Work => DiagnosticCode::E0406WorkAmbiguousMatch,
+                    _ => DiagnosticCode::E0829AmbiguousArtifactMatch,
+                },
+                format!(
+                    "'{query}' matches {} {}s: {} (hint: use the full ID)",
+                    matches.len(),
+                    kind.label(),
+                    candidates.join(", ")
+                ),
+                query,
+            ))
+        }
+    }
+}
+
 pub(crate) fn refresh_kind(config: &Config, kind: CatalogKind) -> DiagnosticResult<()> {
     let connection = open_catalog(config)?;
     refresh_kind_with_connection(config, &connection, kind)
@@ -425,6 +516,8 @@ fn lookup_path(
 #[derive(Debug)]
 struct CatalogEntry {
     id: String,
+    /// Title for partial addressing; not persisted in the catalog.
+    title: String,
     path: String,
     mtime_ns: i64,
     size: i64,
@@ -567,11 +660,18 @@ fn read_catalog_entry(config: &Config, path: &Path) -> DiagnosticResult<Option<C
         return Ok(None);
     };
 
+    let title = raw
+        .get("govctl")
+        .and_then(|govctl| govctl.get("title"))
+        .and_then(toml::Value::as_str)
+        .unwrap_or_default();
+
     let metadata = fs::metadata(path).map_err(|err| {
         Diagnostic::io_error("read artifact metadata", err, path.display().to_string())
     })?;
     Ok(Some(CatalogEntry {
         id: id.to_string(),
+        title: title.to_string(),
         path: config.display_path(path).display().to_string(),
         mtime_ns: mtime_ns(&metadata),
         size: i64::try_from(metadata.len()).unwrap_or(i64::MAX),
@@ -1,6 +1,10 @@
+use crate::config::PrefixesConfig;
 use crate::model::{AdrStatus, ClauseStatus, ProjectIndex, RfcStatus};
 use std::collections::{HashMap, HashSet};
 
+/// `[[<ANCHOR>]]` reference to a clause anchor in source and docs.
+pub(crate) const ANCHOR_REF_PATTERN: &str = r"\[\[([A-Z][A-Z0-9]*(?:-[A-Z0-9]+)*)\]\]";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ArtifactRefState {
     Active,
@@ -52,3 +56,30 @@ pub(crate) fn artifact_ref_states(index: &ProjectIndex) -> HashMap<String, Artif
 
     known
 }
+
+/// Whether `name` can be a clause anchor: uppercase words joined by hyphens,
+/// such as `CACHE-TTL`, that do not look like an artifact ID.
+pub(crate) fn is_valid_anchor(prefixes: &PrefixesConfig, name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_uppercase())
+        && name.split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit())
+        })
+        && !prefixes.is_artifact_shaped(name)
+}
+
+/// Owning clause ID (`RFC-NNNN:C-NAME`) of each declared anchor. When an
+/// anchor is declared twice, the first declaration wins.
+pub(crate) fn anchor_targets(index: &ProjectIndex) -> HashMap<String, String> {
+    let mut targets = HashMap::new();
+    for (rfc, clause) in index.iter_clauses() {
+        for anchor in &clause.spec.anchors {
+            targets
+                .entry(anchor.clone())
+                .or_insert_with(|| format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id));
+        }
+    }
+    targets
+}
@@ -0,0 +1,75 @@
+//! In-process entry points for `benches/large_project.rs`.
+//!
+//! The benchmarks time the load, validate, and render steps that `govctl
+//! status`, `check`, and `render` run, without a process per sample. The
+//! types are opaque so the rest of the crate stays private.
+
+use crate::config::Config;
+use crate::load::load_project;
+use crate::model::ProjectIndex;
+use crate::render::{RenderProjection, render_adr, render_rfc_with_projection, render_work_item};
+use crate::ui;
+use crate::validate::validate_project;
+use std::path::Path;
+
+/// A governance project on disk.
+pub struct Project {
+    config: Config,
+}
+
+/// Every artifact of a [`Project`], loaded.
+pub struct Index(ProjectIndex);
+
+impl Project {
+    /// Open the project whose config is `gov/config.toml` under `root`, with
+    /// progress and status output turned off.
+    pub fn open(root: &Path) -> Result<Self, String> {
+        ui::init_verbosity(ui::Verbosity::Quiet);
+        let config = Config::load(Some(&root.join("gov").join("config.toml")))
+            .map_err(|diag| diag.message)?;
+        Ok(Self { config })
+    }
+
+    /// Load every RFC, clause, ADR, and work item.
+    pub fn load(&self) -> Result<Index, String> {
+        load_project(&self.config)
+            .map(Index)
+            .map_err(|diagnostics| {
+                let messages: Vec<String> =
+                    diagnostics.into_iter().map(|diag| diag.message).collect();
+                messages.join("; ")
+            })
+    }
+
+    /// Run `govctl check` validation over `index`; returns the diagnostic count.
+    pub fn validate(&self, index: &Index) -> usize {
+        validate_project(&index.0, &self.config).diagnostics.len()
+    }
+
+    /// Render every artifact of `index` to markdown in memory; returns the
+    /// total length of the output.
+    pub fn render(&self, index: &Index) -> Result<usize, String> {
+        let mut len = 0;
+        for rfc in &index.0.rfcs {
+            len += render_rfc_with_projection(
+                &self.config.prefixes,
+                rfc,
+                RenderProjection::Archive,
+                None,
+            )
+            .map_err(|diag| diag.message)?
+            .len();
+        }
+        for adr in &index.0.adrs {
+            len += render_adr(&self.config.prefixes, adr)
+                .map_err(|diag| diag.message)?
+                .len();
+        }
+        for item in &index.0.work_items {
+            len += render_work_item(&self.config, item)
+                .map_err(|diag| diag.message)?
+                .len();
+        }
+        Ok(len)
+    }
+}
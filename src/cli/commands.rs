@@ -1,7 +1,9 @@
 use super::help;
 use super::{
-    AdrCommand, ClauseCommand, GuardCommand, ListTarget, LoopCommand, OutputFormat, RenderTarget,
-    RfcCommand, SkillFormat, TagCommand, WorkCommand,
+    AdrCommand, AnchorCommand, AssetGroup, AttachCommand, AuditCommand, ClauseCommand,
+    ConfigCommand, DocsCommand, GuardCommand, InitPreset, ListTarget, LoopCommand, MappingCommand,
+    PublishCommand, RenderFormat, RenderLayoutArgs, RenderTarget, RfcCommand, ScanCommand,
+    SkillFormat, SupersedeCommand, SyncCommand, TagCommand, TemplateCommand, WorkCommand,
 };
 use clap::{Args, Subcommand};
 use std::path::PathBuf;
@@ -30,6 +32,10 @@ pub(crate) struct ReleaseArgs {
     #[arg(long, requires = "version")]
     pub(crate) date: Option<String>,
 
+    /// Also draft a GitHub Release from the release notes (needs [github] repo)
+    #[arg(long, requires = "version")]
+    pub(crate) github: bool,
+
     #[command(subcommand)]
     pub(crate) command: Option<ReleaseCommand>,
 }
@@ -42,6 +48,18 @@ pub(crate) enum Commands {
         /// Overwrite existing config
         #[arg(short = 'f', long)]
         force: bool,
+        /// Seed the project from a template created by `govctl template pack`
+        #[arg(long, value_name = "TEMPLATE")]
+        from: Option<PathBuf>,
+        /// Start from a scaffold: minimal, full, or agents
+        #[arg(long, value_enum, conflicts_with = "from")]
+        preset: Option<InitPreset>,
+        /// Ask about layout, work item IDs, guards, and agent assets
+        #[arg(short = 'i', long, conflicts_with = "from")]
+        interactive: bool,
+        /// Map governed files to their JSON Schemas in .vscode/settings.json
+        #[arg(long)]
+        vscode: bool,
     },
 
     /// Install skills and agents into the project's agent directory
@@ -57,6 +75,15 @@ pub(crate) enum Commands {
         /// Override output directory (default: agent_dir from config, or format-implied)
         #[arg(long)]
         dir: Option<PathBuf>,
+        /// Only install these asset groups (comma-separated: skills, agents)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        only: Vec<AssetGroup>,
+        /// Print what would change in existing assets instead of writing
+        #[arg(long)]
+        diff: bool,
+        /// Fail when installed assets are missing or differ from this version (writes nothing)
+        #[arg(long, conflicts_with = "force")]
+        check: bool,
     },
 
     /// Validate all governed documents
@@ -70,6 +97,14 @@ pub(crate) enum Commands {
         /// Assert that an active work item exists (exits non-zero if none)
         #[arg(long)]
         has_active: bool,
+
+        /// Check every workspace member and the references between them
+        #[arg(long, conflicts_with = "has_active")]
+        workspace: bool,
+
+        /// Treat refs to deprecated or superseded artifacts as errors
+        #[arg(long, conflicts_with_all = ["has_active", "workspace"])]
+        strict_refs: bool,
     },
 
     /// Show summary counts
@@ -91,15 +126,38 @@ pub(crate) enum Commands {
         /// Dry run: show what would be written
         #[arg(long)]
         dry_run: bool,
-        /// Force full regeneration (for changelog: overwrites released sections)
+        /// Fail if any rendered file differs from what would be written (writes nothing)
+        #[arg(long, conflicts_with = "dry_run")]
+        check: bool,
+        /// Force full regeneration: re-render unchanged artifacts (for changelog: overwrite released sections)
         #[arg(long, short)]
         force: bool,
+        /// Output file format; pdf renders RFCs only
+        #[arg(long, value_enum, default_value = "md")]
+        format: RenderFormat,
+        #[command(flatten)]
+        layout: RenderLayoutArgs,
     },
 
     /// Migrate legacy governance storage to current canonical formats
     #[command(after_help = help::MIGRATE)]
     Migrate,
 
+    /// Rewrite artifact files in canonical form
+    #[command(after_help = help::FMT)]
+    Fmt {
+        /// Fail if any artifact file is not formatted (writes nothing)
+        #[arg(long)]
+        check: bool,
+    },
+
+    /// Explain a diagnostic code: what it means and how to fix it
+    #[command(after_help = help::EXPLAIN)]
+    Explain {
+        /// Diagnostic code, e.g. E0207 (omit to list every code)
+        code: Option<String>,
+    },
+
     /// Execute reusable verification guards
     #[command(after_help = help::VERIFY)]
     Verify {
@@ -142,12 +200,6 @@ pub(crate) enum Commands {
         /// applies a finite default limit.
         #[arg(short = 'n', long)]
         limit: Option<usize>,
-        /// Output format.
-        ///
-        /// [[RFC-0002:C-SEARCH-COMMAND]]: supports table, JSON, and plain
-        /// result contracts, with table as the CLI default.
-        #[arg(short = 'o', long, value_enum, default_value = "table")]
-        output: OutputFormat,
         /// Force a full rebuild of the local search index before querying.
         ///
         /// [[RFC-0002:C-SEARCH-COMMAND]]: `--reindex` rebuilds the local
@@ -209,9 +261,9 @@ pub(crate) enum Commands {
         /// Include project state and suggested actions
         #[arg(long)]
         context: bool,
-        /// Output format (currently only json is supported)
-        #[arg(short = 'o', long, default_value = "json")]
-        output: String,
+        /// Print the JSON Schemas of the artifact file formats instead
+        #[arg(long, conflicts_with = "context")]
+        schemas: bool,
     },
 
     /// Generate shell completion scripts
@@ -222,6 +274,13 @@ pub(crate) enum Commands {
         shell: clap_complete::Shell,
     },
 
+    /// Generate the command reference as a man page or Markdown
+    #[command(after_help = help::DOCS)]
+    Docs {
+        #[command(subcommand)]
+        command: DocsCommand,
+    },
+
     /// Update govctl binary to the latest release
     #[command(name = "self-update")]
     #[command(after_help = help::SELF_UPDATE)]
@@ -235,10 +294,132 @@ pub(crate) enum Commands {
     #[cfg(feature = "tui")]
     Tui,
 
+    /// Map external standard controls to clauses
+    #[command(after_help = help::MAPPING)]
+    Mapping {
+        #[command(subcommand)]
+        command: MappingCommand,
+    },
+
+    /// Attach diagrams and other files to artifacts
+    #[command(after_help = help::ATTACH)]
+    Attach {
+        #[command(subcommand)]
+        command: AttachCommand,
+    },
+
     /// Manage controlled-vocabulary tags
     #[command(after_help = help::TAG)]
     Tag {
         #[command(subcommand)]
         command: TagCommand,
     },
+
+    /// Inspect supersession chains across RFCs, clauses, and ADRs
+    #[command(after_help = help::SUPERSEDE)]
+    Supersede {
+        #[command(subcommand)]
+        command: SupersedeCommand,
+    },
+
+    /// Inspect clause anchors, the targets of `[[<ANCHOR>]]` references
+    #[command(after_help = help::ANCHOR)]
+    Anchor {
+        #[command(subcommand)]
+        command: AnchorCommand,
+    },
+
+    /// Report on artifact references in source code
+    #[command(after_help = help::SCAN)]
+    Scan {
+        #[command(subcommand)]
+        command: ScanCommand,
+    },
+
+    /// Inspect and validate project configuration
+    #[command(after_help = help::CONFIG)]
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+
+    /// Export artifact IDs for other repositories to reference
+    #[command(after_help = help::EXPORT)]
+    Export {
+        /// Output bundle path
+        #[arg(long, value_name = "FILE")]
+        out: PathBuf,
+    },
+
+    /// Publish rendered artifacts to external documentation systems
+    #[command(after_help = help::PUBLISH)]
+    Publish {
+        #[command(subcommand)]
+        command: PublishCommand,
+    },
+
+    /// Pull state from external trackers into work items
+    #[command(after_help = help::SYNC)]
+    Sync {
+        #[command(subcommand)]
+        command: SyncCommand,
+    },
+
+    /// Share governance baselines across projects
+    #[command(after_help = help::TEMPLATE)]
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommand,
+    },
+
+    /// Edit a whole artifact in your editor
+    #[command(after_help = help::EDIT)]
+    Edit {
+        /// Artifact ID (RFC, clause, ADR, work item, or guard)
+        id: String,
+        /// Open the artifact in $VISUAL / $EDITOR and validate on save
+        #[arg(long, required = true)]
+        editor: bool,
+    },
+
+    /// Apply a script of commands as one transaction
+    #[command(after_help = help::APPLY)]
+    Apply {
+        /// YAML file listing the commands to run
+        #[arg(required_unless_present = "stdin", conflicts_with = "stdin")]
+        file: Option<PathBuf>,
+        /// Read the script from stdin
+        #[arg(long)]
+        stdin: bool,
+    },
+
+    /// Restore a deleted clause or work item from the trash
+    #[command(after_help = help::RESTORE)]
+    Restore {
+        /// Clause or work item ID
+        id: String,
+    },
+
+    /// Revert the most recent write command
+    #[command(after_help = help::UNDO)]
+    Undo {
+        /// Revert even if files changed after the command ran
+        #[arg(short = 'f', long)]
+        force: bool,
+    },
+
+    /// List recent write commands that can be undone
+    #[command(after_help = help::HISTORY)]
+    History {
+        /// Limit number of results
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+
+    /// Read the audit log of write commands
+    #[command(after_help = help::AUDIT)]
+    Audit {
+        #[command(subcommand)]
+        command: AuditCommand,
+    },
 }
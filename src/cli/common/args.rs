@@ -2,7 +2,6 @@ use crate::model::ChangelogCategory;
 use clap::Args;
 
 use super::actions::{AdrTickStatus, EditActionArgs, WorkTickStatus};
-use super::targets::{OutputFormat, ShowOutputFormat};
 
 #[derive(Args, Clone, Debug)]
 pub(crate) struct CommonListArgs {
@@ -11,12 +10,12 @@ pub(crate) struct CommonListArgs {
     /// Limit number of results
     #[arg(short = 'n', long)]
     pub(crate) limit: Option<usize>,
-    /// Output format
-    #[arg(short = 'o', long, value_enum, default_value = "table")]
-    pub(crate) output: OutputFormat,
     /// Filter by tag (comma-separated, artifact must have ALL specified tags)
     #[arg(long)]
     pub(crate) tag: Option<String>,
+    /// Only list artifacts past the `[staleness]` limits (RFCs and ADRs)
+    #[arg(long)]
+    pub(crate) stale: bool,
 }
 
 #[derive(Args, Clone, Debug)]
@@ -31,9 +30,6 @@ pub(crate) struct CommonGetArgs {
 pub(crate) struct CommonShowArgs {
     /// Artifact ID
     pub(crate) id: String,
-    /// Output format
-    #[arg(short = 'o', long, value_enum, default_value = "table")]
-    pub(crate) output: ShowOutputFormat,
     /// Include complete historical body content in human-readable output
     #[arg(long)]
     pub(crate) history: bool,
@@ -69,11 +65,14 @@ pub(crate) struct CommonAddArgs {
     pub(crate) id: String,
     /// Array field name
     pub(crate) field: String,
-    /// Value to add (optional if --stdin)
-    pub(crate) value: Option<String>,
+    /// Values to add, appended in order (optional if --stdin)
+    pub(crate) values: Vec<String>,
     /// Read value from stdin
     #[arg(long)]
     pub(crate) stdin: bool,
+    /// With --stdin, add each non-empty line as its own item
+    #[arg(long, requires = "stdin")]
+    pub(crate) lines: bool,
 }
 
 #[derive(Args, Clone, Debug)]
@@ -144,6 +143,26 @@ pub(crate) struct CommonRenderArgs {
     pub(crate) dry_run: bool,
 }
 
+/// Layout flags; each turns on the `[render]` option of the same name.
+#[derive(Args, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RenderLayoutArgs {
+    /// Add a table of contents to rendered RFCs
+    #[arg(long)]
+    pub(crate) toc: bool,
+    /// Number clauses within their section (2.3 style)
+    #[arg(long)]
+    pub(crate) clause_numbers: bool,
+    /// Show a status badge on every clause
+    #[arg(long)]
+    pub(crate) status_badges: bool,
+    /// Start each file with YAML front matter for MkDocs or Docusaurus
+    #[arg(long)]
+    pub(crate) front_matter: bool,
+    /// Render with a `[render.profiles]` entry, e.g. a public docs set
+    #[arg(long, value_name = "NAME")]
+    pub(crate) profile: Option<String>,
+}
+
 #[derive(Args, Clone, Debug)]
 pub(crate) struct CommonDeleteArgs {
     /// Artifact ID
@@ -160,6 +179,18 @@ pub(crate) struct CommonDeprecateArgs {
     /// Force without confirmation
     #[arg(short = 'f', long)]
     pub(crate) force: bool,
+    /// Sunset date (YYYY-MM-DD); `check` warns after it while the artifact is still referenced
+    #[arg(long, value_name = "DATE")]
+    pub(crate) sunset: Option<String>,
+    /// Why the artifact is deprecated, shown in the deprecation notice
+    #[arg(long)]
+    pub(crate) reason: Option<String>,
+}
+
+#[derive(Args, Clone, Debug)]
+pub(crate) struct CommonApproveArgs {
+    /// Artifact ID
+    pub(crate) id: String,
 }
 
 #[derive(Args, Clone, Debug)]
@@ -172,6 +203,9 @@ pub(crate) struct CommonSupersedeArgs {
     /// Force without confirmation
     #[arg(short = 'f', long)]
     pub(crate) force: bool,
+    /// Point inbound `refs` at the replacement and report source references
+    #[arg(long)]
+    pub(crate) update_refs: bool,
 }
 
 #[derive(Args, Clone, Debug)]
@@ -222,6 +256,15 @@ pub(crate) struct WorkEditArgs {
     pub(crate) scope: Option<String>,
 }
 
+#[derive(Args, Clone, Debug)]
+pub(crate) struct WorkListArgs {
+    #[command(flatten)]
+    pub(crate) common: CommonListArgs,
+    /// Show linked GitHub issues and their state (GITHUB_TOKEN or GH_TOKEN)
+    #[arg(long)]
+    pub(crate) github: bool,
+}
+
 #[derive(Args, Clone, Debug)]
 pub(crate) struct WorkAddArgs {
     #[command(flatten)]
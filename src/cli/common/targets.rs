@@ -60,6 +60,77 @@ pub enum OutputFormat {
     Plain,
 }
 
+/// Value of the global `-o/--output` flag.
+///
+/// Each command takes the formats that fit its result: lists and reports take
+/// table, json, or plain; `show` also takes yaml and toml; other commands take
+/// table, or json for a result envelope. Variants carry no doc comments so
+/// the global flag keeps the compact help layout.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputArg {
+    Table,
+    Json,
+    Plain,
+    Yaml,
+    Toml,
+}
+
+impl OutputArg {
+    /// The format for a list or report, if this is one of its formats.
+    pub(crate) fn list_format(self) -> Option<OutputFormat> {
+        match self {
+            Self::Table => Some(OutputFormat::Table),
+            Self::Json => Some(OutputFormat::Json),
+            Self::Plain => Some(OutputFormat::Plain),
+            Self::Yaml | Self::Toml => None,
+        }
+    }
+
+    pub(crate) fn show_format(self) -> ShowOutputFormat {
+        match self {
+            Self::Table => ShowOutputFormat::Table,
+            Self::Json => ShowOutputFormat::Json,
+            Self::Plain => ShowOutputFormat::Plain,
+            Self::Yaml => ShowOutputFormat::Yaml,
+            Self::Toml => ShowOutputFormat::Toml,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Table => "table",
+            Self::Json => "json",
+            Self::Plain => "plain",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+        }
+    }
+}
+
+/// When to color terminal output.
+///
+/// `Auto` colors only on a terminal with `NO_COLOR` unset. Variants carry no
+/// doc comments so the global flag keeps the compact help layout.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Who reads the output, for `--for`.
+///
+/// `Agent` prints diagnostics as stable, uncolored lines with the exact
+/// commands that fix them. Variants carry no doc comments so the global flag
+/// keeps the compact help layout.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Persona {
+    #[default]
+    Human,
+    Agent,
+}
+
 /// Output formats available to resource `show` commands.
 #[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ShowOutputFormat {
@@ -92,15 +163,59 @@ pub(crate) enum RenderTarget {
     Work,
     /// Render CHANGELOG.md from completed work items
     Changelog,
+    /// Render the compliance matrix from gov/mapping/
+    Compliance,
     /// Render all artifact types (local use)
     All,
 }
 
+/// File format for `govctl render`.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum RenderFormat {
+    /// Markdown (default)
+    #[default]
+    #[value(name = "md")]
+    Markdown,
+    /// Print-ready PDF with a title page, contents, and numbered clauses (RFCs only)
+    Pdf,
+}
+
 #[derive(ValueEnum, Clone, Copy, Debug)]
 pub(crate) enum FinalizeStatus {
     Normative,
 }
 
+/// Scaffold for `init --preset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum InitPreset {
+    /// Bare config with only project, paths, and schema settings
+    Minimal,
+    /// Commented config, a `govctl check` default guard, and editor schema settings
+    Full,
+    /// Full, plus ULID work item IDs and agent skills installed
+    Agents,
+}
+
+/// Asset group installed by `init-skills --only`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum AssetGroup {
+    /// Skill bundles under skills/ (these replaced the former commands)
+    Skills,
+    /// Reviewer agents under agents/
+    Agents,
+}
+
+impl AssetGroup {
+    /// Group of an asset path relative to the agent directory.
+    pub fn of(rel_path: &str) -> Self {
+        if rel_path.starts_with("agents/") {
+            Self::Agents
+        } else {
+            Self::Skills
+        }
+    }
+}
+
 /// Output format for agent definitions in `init-skills`.
 #[derive(Clone, Debug, Default, ValueEnum)]
 pub enum SkillFormat {
@@ -1,29 +1,50 @@
 pub(super) const INIT: &str = r#"EXAMPLES:
     govctl init
     govctl init --force
+    govctl init --from org-template.tar.zst
+    govctl init --vscode
+    govctl init --preset agents
+    govctl init --interactive
 
 NOTES:
     - Creates `gov/`, `gov/config.toml`, and baseline governance artifacts.
     - Use `--force` to overwrite an existing initialization.
+    - Use `--from` to start from a template created by `govctl template pack`.
+    - `--preset minimal` writes a bare config; `full` adds a `govctl check` default guard and
+      editor schema settings; `agents` adds ULID work item IDs and installs agent skills.
+    - `--interactive` asks for the docs directory, work item ID scheme, default guard commands,
+      and whether to install agent assets, starting from the preset's answers. Needs a terminal.
+    - Use `--vscode` to map governed TOML files to their JSON Schemas in `.vscode/settings.json`
+      (Even Better TOML); `govctl migrate` keeps that mapping current.
 "#;
 
 pub(super) const INIT_SKILLS: &str = r#"EXAMPLES:
     govctl init-skills
     govctl init-skills --force
+    govctl init-skills --diff
+    govctl init-skills --check --only skills
 
 NOTES:
     - Installs or refreshes project-local skills and agents.
     - Use `--force` to overwrite existing generated assets.
+    - `--only skills,agents` limits the run to those asset groups.
+    - `--diff` shows how installed assets differ from this govctl version, writing nothing.
+    - `--check` exits non-zero (E0826) when assets are missing or out of date; use it in CI.
 "#;
 
 pub(super) const CHECK: &str = r#"EXAMPLES:
     govctl check
     govctl check -W
     govctl check --has-active
+    govctl check --workspace
+    govctl check --strict-refs
 
 NOTES:
     - `-W/--deny-warnings` treats warnings as errors.
+    - `--strict-refs` reports refs to deprecated or superseded artifacts (W0123) as errors.
     - `--has-active` asserts that an active work item exists.
+    - `--workspace` checks each member listed in govctl-workspace.toml and
+      resolves `[[member/ID]]` references between them.
 "#;
 
 pub(super) const STATUS: &str = r#"EXAMPLES:
@@ -38,11 +59,32 @@ pub(super) const RENDER: &str = r#"EXAMPLES:
     govctl render adr
     govctl render work --dry-run
     govctl render changelog --force
+    govctl render compliance
+    govctl render rfc --format pdf
+    govctl render --toc --clause-numbers --status-badges
+    govctl render all --profile public
+    govctl render all --check
+    govctl render all --force
 
 NOTES:
     - This is a bulk render entrypoint.
+    - `render all` also writes index.md into docs/rfc/, docs/adr/, and docs/work/.
+    - Artifacts whose sources, config, templates, and fragments are unchanged since
+      they were last rendered are skipped (tracked in .govctl/render-cache.json);
+      `--force` re-renders them.
+    - `--check` writes nothing and fails if any rendered file is out of date.
     - For a single artifact, use resource render:
       `govctl rfc render <ID>`, `govctl adr render <ID>`, `govctl work render <ID>`.
+    - Tera templates in gov/templates/render/ (rfc.md.tera, rfc/<ID>.md.tera, ...)
+      replace the built-in layout.
+    - `--format pdf` writes print-ready RFCs (title page, contents, numbered
+      clauses) to docs/rfc/<ID>.pdf (needs the `pdf` build feature). Templates
+      apply to markdown only.
+    - --toc, --clause-numbers, and --status-badges turn on the [render] options
+      of the same names for RFC markdown; --front-matter turns on
+      [render.front_matter] YAML front matter for every rendered artifact.
+    - --profile <name> renders with [render.profiles.<name>]: its docs_output
+      and the sections in its omit list (owners, approvals, journal, notes, ...).
 "#;
 
 pub(super) const MIGRATE: &str = r#"EXAMPLES:
@@ -55,6 +97,30 @@ NOTES:
     - If legacy JSON storage is present, first run `govctl migrate` with govctl <0.9.
 "#;
 
+pub(super) const FMT: &str = r#"EXAMPLES:
+    govctl fmt
+    govctl --dry-run fmt
+    govctl fmt --check
+
+NOTES:
+    - Rewrites RFCs, clauses, ADRs, work items, guards, and releases.toml with
+      schema key order and the `#:schema` header; hand-written comments are dropped.
+    - Set `format.wrap_width` to re-wrap prose (clause text of draft RFCs, ADR
+      context/decision/consequences, work item descriptions) at that column.
+    - `--check` exits non-zero (E0827) when a file would change; use it in CI.
+"#;
+
+pub(super) const EXPLAIN: &str = r#"EXAMPLES:
+    govctl explain E0207
+    govctl explain W0122 -o json
+    govctl explain
+
+NOTES:
+    - Prints what the diagnostic reports, typical causes, how to fix it, and
+      the clauses of govctl's own specification that define the rule.
+    - Without a code, lists every code with its level and summary.
+"#;
+
 pub(super) const VERIFY: &str = r#"EXAMPLES:
     govctl verify GUARD-CLIPPY
     govctl verify GUARD-CLIPPY GUARD-TESTS
@@ -159,21 +225,25 @@ START HERE:
 pub(super) const RELEASE: &str = r#"EXAMPLES:
     govctl release 0.2.0
     govctl release 0.2.0 --date 2026-04-07
+    govctl release 0.2.0 --github
     govctl release undo 0.2.0
 
 NOTES:
     - A version argument collects unreleased completed work items into a release.
     - `undo` removes only the newest local release when its version matches.
+    - `--github` drafts a GitHub Release from the notes; it needs `[github] repo` and GITHUB_TOKEN.
     - Undo does not modify CHANGELOG.md or external publication systems.
 "#;
 
 pub(super) const DESCRIBE: &str = r#"EXAMPLES:
     govctl describe
     govctl describe --context
+    govctl describe --schemas
     govctl describe -o json
 
 NOTES:
     - `--context` includes current project state and suggested next actions.
+    - `--schemas` prints the JSON Schemas govctl validates artifact files against, keyed by format.
     - Output is intended for agents and tooling.
 "#;
 
@@ -185,6 +255,15 @@ NOTES:
     - Writes completion script text to stdout for the selected shell.
 "#;
 
+pub(super) const DOCS: &str = r#"EXAMPLES:
+    govctl docs man > govctl.1
+    govctl docs markdown --out docs/reference/cli.md
+
+NOTES:
+    - Both formats are generated from the command definitions, so they always match this binary.
+    - Without `--out`, the reference is written to stdout.
+"#;
+
 pub(super) const SELF_UPDATE: &str = r#"EXAMPLES:
     govctl self-update
     govctl self-update --check
@@ -205,3 +284,169 @@ NOTES:
     - Artifacts may only reference tags declared here.
     - Implements [[RFC-0002:C-RESOURCES]] controlled-vocabulary tags.
 "#;
+
+pub(super) const MAPPING: &str = r#"EXAMPLES:
+    govctl mapping add ISO-27001 A.5.15 RFC-0001:C-ACCESS --title "Access control"
+    govctl mapping list
+    govctl mapping remove ISO-27001 A.5.15
+    govctl render compliance
+
+NOTES:
+    - Each external standard is one file: gov/mapping/<standard>.toml.
+    - `govctl check` rejects controls mapped to missing, deprecated, or superseded clauses.
+    - `govctl render compliance` writes the matrix to docs/compliance.md.
+"#;
+
+pub(super) const ATTACH: &str = r#"EXAMPLES:
+    govctl attach add RFC-0003 diagram.svg
+    govctl attach list RFC-0003
+    govctl attach remove RFC-0003 diagram.svg
+
+NOTES:
+    - Attachments live under gov/assets/ and are listed in the artifact's attachments.
+    - `govctl render` copies them to <docs_output>/assets/ and links them from the page.
+    - `govctl check` reports attachments whose files are missing.
+"#;
+
+pub(super) const SUPERSEDE: &str = r#"EXAMPLES:
+    govctl supersede chain RFC-0001
+
+NOTES:
+    - Chains follow RFC `supersedes` and clause/ADR `superseded_by` links, oldest first.
+    - `govctl check` rejects cycles and forks and warns when a chain's newest artifact is not in force.
+    - To supersede an artifact, use `govctl rfc|clause|adr supersede`.
+"#;
+
+pub(super) const ANCHOR: &str = r#"EXAMPLES:
+    govctl anchor list
+    govctl clause edit RFC-0001:C-CACHE anchors --add CACHE-TTL
+
+NOTES:
+    - Anchors are uppercase words joined by hyphens and unique across the project.
+    - Source scanning resolves `[[<ANCHOR>]]` to the clause that declares the anchor.
+"#;
+
+pub(super) const SCAN: &str = r#"EXAMPLES:
+    govctl scan coverage
+    govctl scan coverage -o json -W
+
+NOTES:
+    - Mark implementing code with a comment such as `// Implements [[RFC-NNNN:C-NAME]]`.
+    - Files come from [source_scan] include/exclude in gov/config.toml.
+"#;
+
+pub(super) const CONFIG: &str = r#"EXAMPLES:
+    govctl config check
+
+NOTES:
+    - `config check` validates gov/config.toml even when other commands fail to load it.
+    - Unknown keys are otherwise ignored silently; check reports them with line/column.
+"#;
+
+pub(super) const EXPORT: &str = r#"EXAMPLES:
+    govctl export --out platform-refs.json
+
+NOTES:
+    - Writes every RFC, clause, ADR, and work item ID with its title and status.
+    - Other repositories reference these as `name:ID` through
+      `[remotes.<name>] bundle = "..."` in their gov/config.toml.
+"#;
+
+pub(super) const PUBLISH: &str = r#"EXAMPLES:
+    govctl publish confluence
+    govctl publish confluence RFC-0001
+
+NOTES:
+    - Configure the target under [confluence] in gov/config.toml.
+    - Credentials come from CONFLUENCE_TOKEN (and CONFLUENCE_USER), never from config.
+"#;
+
+pub(super) const SYNC: &str = r#"EXAMPLES:
+    govctl sync jira
+    govctl sync jira WI-2026-04-06-001
+
+NOTES:
+    - Link issues with `govctl work link <WI-ID> --jira PROJ-123`.
+    - Configure the site under [jira] in gov/config.toml.
+    - Credentials come from JIRA_TOKEN (and JIRA_USER), never from config.
+"#;
+
+pub(super) const TEMPLATE: &str = r#"EXAMPLES:
+    govctl template pack --out org-template.tar.zst
+    govctl init --from org-template.tar.zst
+
+NOTES:
+    - Templates carry config, artifact templates, guards, and the seed RFC-0000.
+    - Work items, ADRs, and other RFCs are never included.
+"#;
+
+pub(super) const APPLY: &str = r#"EXAMPLES:
+    govctl apply ops.yaml
+    govctl apply --stdin <<'EOF'
+    - [rfc, new, "Caching layer"]
+    - [clause, new, "RFC-0001:C-TTL", "Cache TTL", -s, Specification, -k, normative]
+    - [work, new, "Implement caching", --active]
+    EOF
+
+NOTES:
+    - The script is a YAML list; each entry is one command's arguments without `govctl`.
+    - Every step is checked before any runs, and all steps run under one gov lock.
+    - If a step fails, every file written by it and earlier steps is restored.
+    - Interactive, editor, render, init, and other apply commands cannot be scripted.
+    - `--dry-run` previews each step; later steps do not see earlier steps' changes.
+"#;
+
+pub(super) const RESTORE: &str = r#"EXAMPLES:
+    govctl restore RFC-0001:C-SCOPE
+    govctl restore WI-2026-04-06-001
+
+NOTES:
+    - `clause delete` and `work delete` move the file to `gov/.trash/<ID>/` with a tombstone.
+    - Restore puts the file back at its original path; a clause rejoins its original section.
+    - A clause can be restored only while its RFC could still delete it (draft, or the open spec candidate).
+    - Deleting the same ID again replaces its trash entry. Remove `gov/.trash/` entries to purge them.
+"#;
+
+pub(super) const UNDO: &str = r#"EXAMPLES:
+    govctl undo
+    govctl undo --dry-run
+    govctl undo --force
+
+NOTES:
+    - Reverts the newest entry in `govctl history` and removes it; run again to step further back.
+    - Write commands record the prior content of every file they change under `.govctl/history/`.
+    - Undo refuses if a recorded file changed since the command ran; --force overwrites it.
+    - Only the last 20 operations are kept. Undo itself is not recorded.
+"#;
+
+pub(super) const HISTORY: &str = r#"EXAMPLES:
+    govctl history
+    govctl history -n 5
+    govctl history -o json
+
+NOTES:
+    - Newest first; the top entry is what `govctl undo` reverts.
+    - History is local state in `.govctl/history/` and is not committed.
+"#;
+
+pub(super) const AUDIT: &str = r#"EXAMPLES:
+    govctl audit list
+    govctl audit show 42
+
+NOTES:
+    - Set `[audit] enabled = true` to log every write command to gov/audit.log.
+    - Failed commands are logged too, with the error that stopped them.
+    - `[audit] retention_days` drops older entries; 0 keeps them all.
+"#;
+
+pub(super) const EDIT: &str = r#"EXAMPLES:
+    govctl edit RFC-0001 --editor
+    govctl edit ADR-0003 --editor
+    EDITOR="code --wait" govctl edit WI-2026-01-17-001 --editor
+
+NOTES:
+    - Opens the artifact's TOML in $VISUAL or $EDITOR (falls back to vi).
+    - The edited file must pass the same validation as `govctl check` loads; the ID cannot change.
+    - Invalid edits are not written; the edited buffer is kept and its path reported.
+    - Lifecycle-owned fields (status, version, dates) are still best changed with lifecycle verbs.
+"#;
@@ -22,9 +22,6 @@ NOTES:
         /// Limit number of results
         #[arg(short = 'n', long)]
         limit: Option<usize>,
-        /// Output format
-        #[arg(short = 'o', long, value_enum, default_value = "table")]
-        output: crate::OutputFormat,
     },
     /// Start a loop for one or more explicit work items
     #[command(after_help = "\
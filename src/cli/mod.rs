@@ -11,22 +11,71 @@ pub(crate) use common::*;
 pub(crate) use loop_cmd::LoopCommand;
 pub(crate) use resources::*;
 
-use clap::Parser;
+use clap::{ArgAction, Parser};
 use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "govctl")]
 #[command(about = "Project governance CLI for RFC, ADR, and Work Item management")]
 #[command(version)]
+// Global flags are listed apart from each subcommand's own options.
+#[command(next_help_heading = "Global options")]
 pub(crate) struct Cli {
     /// Path to govctl config (TOML)
     #[arg(short = 'C', long, global = true)]
     pub(crate) config: Option<PathBuf>,
 
+    /// Workspace member to operate on (see govctl-workspace.toml)
+    #[arg(
+        short = 'p',
+        long,
+        global = true,
+        value_name = "NAME",
+        conflicts_with = "config"
+    )]
+    pub(crate) project: Option<String>,
+
     /// Dry run: preview changes without writing files
     #[arg(long, global = true)]
     pub(crate) dry_run: bool,
 
+    /// Output format; json prints one JSON document on stdout
+    #[arg(short = 'o', long, global = true, value_name = "FORMAT")]
+    pub(crate) output: Option<OutputArg>,
+
+    /// Seconds to wait for another govctl command to release the gov-root lock
+    /// (default: concurrency.lock_timeout_secs)
+    #[arg(long, global = true, value_name = "SECS")]
+    pub(crate) lock_wait: Option<u64>,
+
+    /// When to use colors
+    #[arg(long, global = true, value_name = "WHEN", default_value = "auto")]
+    pub(crate) color: ColorChoice,
+
+    /// Who reads the output: agent diagnostics list exact fix commands (also GOVCTL_FOR)
+    #[arg(long = "for", global = true, value_name = "PERSONA")]
+    pub(crate) persona: Option<Persona>,
+
+    /// Act as this actor, e.g. agent:claude or @alice; recorded as created_by, in history, and by approve (also GOVCTL_ACTOR)
+    #[arg(long = "as", global = true, value_name = "ACTOR")]
+    pub(crate) actor: Option<String>,
+
+    /// Suppress success and progress messages (diagnostics still print)
+    #[arg(short = 'q', long, global = true, conflicts_with = "verbose")]
+    pub(crate) quiet: bool,
+
+    /// Log file writes, lock waits, and check timing to stderr (-vv: file reads too)
+    #[arg(short = 'v', long, global = true, action = ArgAction::Count)]
+    pub(crate) verbose: u8,
+
+    /// Print how long each phase took (loading, validation, rendering) to stderr
+    #[arg(long, global = true)]
+    pub(crate) timings: bool,
+
+    /// Answer yes to confirmation prompts (also GOVCTL_ASSUME_YES=1)
+    #[arg(short = 'y', long, global = true)]
+    pub(crate) yes: bool,
+
     #[command(subcommand)]
     pub(crate) command: Commands,
 }
@@ -1,9 +1,9 @@
 use clap::Subcommand;
 
 use crate::{
-    AdrAddArgs, AdrEditArgs, AdrTickArgs, CommonDeprecateArgs, CommonGetArgs, CommonIdArgs,
-    CommonListArgs, CommonRemoveArgs, CommonRenderArgs, CommonSetArgs, CommonShowArgs,
-    CommonSupersedeArgs,
+    AdrAddArgs, AdrEditArgs, AdrTickArgs, AltCommand, CommonApproveArgs, CommonDeprecateArgs,
+    CommonGetArgs, CommonIdArgs, CommonListArgs, CommonRemoveArgs, CommonRenderArgs, CommonSetArgs,
+    CommonShowArgs, CommonSupersedeArgs,
 };
 
 /// ADR commands (resource-first structure)
@@ -28,6 +28,7 @@ EXAMPLES:
 VALID FIELDS:
     - title, date, status, superseded_by
     - context, decision, consequences, refs, alternatives
+    - drivers, stakeholders
 
 EXAMPLES:
     govctl adr get ADR-0001
@@ -51,14 +52,20 @@ NOTES:
     #[command(after_help = "\
 EXAMPLES:
     govctl adr new \"Adopt PostgreSQL for primary storage\"
+    govctl adr new --interactive
 
 NOTES:
     - New ADRs start in proposed state.
     - Follow the alternatives-first workflow: add alternatives, discuss, then decide.
+    - `--interactive` prompts for title and refs (requires a terminal).
 ")]
     New {
         /// ADR title
-        title: String,
+        #[arg(required_unless_present = "interactive")]
+        title: Option<String>,
+        /// Prompt for the title and initial fields
+        #[arg(short = 'i', long)]
+        interactive: bool,
     },
     /// Canonical path-first edit entrypoint
     #[command(after_help = "\
@@ -81,7 +88,7 @@ VALID FIELDS:
     - date: ADR date
 
   Array fields (use 'add'/'remove' instead):
-    - refs, alternatives
+    - refs, alternatives, drivers, stakeholders
 
 EXAMPLES:
     govctl adr set ADR-0001 context \"New context\"
@@ -98,6 +105,8 @@ Use dedicated lifecycle verbs instead of `set` for:
 VALID ARRAY FIELDS:
     - refs: Cross-references to RFCs/ADRs (e.g., \"RFC-0001\", \"ADR-0002\")
     - alternatives: Options that were considered
+    - drivers: Forces that shaped the decision
+    - stakeholders: People or teams consulted or affected
 
 ALTERNATIVES FORMAT (per ADR-0027):
     Each alternative has:
@@ -109,6 +118,8 @@ ALTERNATIVES FORMAT (per ADR-0027):
 
 EXAMPLES:
     govctl adr add ADR-0001 refs RFC-0001
+    govctl adr add ADR-0001 drivers \"Reads must stay under 10 ms\"
+    govctl adr add ADR-0001 stakeholders \"Platform team\"
     govctl adr add ADR-0001 alternatives \"Option A: Use PostgreSQL\"
     govctl adr add ADR-0001 alternatives \"Option B: Use Redis\" --pro \"Fast caching\" --con \"Additional infrastructure\"
     govctl adr add ADR-0001 alternatives \"Option C: No cache\" --reject-reason \"Performance issues\"
@@ -117,7 +128,7 @@ EXAMPLES:
     /// Remove value from ADR array field
     #[command(after_help = "\
 VALID ARRAY FIELDS:
-    - refs, alternatives
+    - refs, alternatives, drivers, stakeholders
 
 MATCHING OPTIONS:
     - pattern: Substring match (default)
@@ -142,6 +153,7 @@ NOTES:
     - Mark the selected alternative as `accepted` before accepting the ADR.
     - Requires at least 2 alternatives (1 accepted, 1 rejected) per [[ADR-0042]].
     - Use --force for historical backfills where alternatives cannot be reconstructed.
+    - With `[owners] adr_quorum`, the ADR needs that many approvals first.
 ")]
     Accept {
         /// ADR ID
@@ -150,6 +162,16 @@ NOTES:
         #[arg(short = 'f', long)]
         force: bool,
     },
+    /// Record an approval of a proposed ADR
+    #[command(after_help = "\
+EXAMPLES:
+    govctl adr approve ADR-0001 --as @alice
+
+NOTES:
+    - Approvals are stored in the ADR and shown in rendered output.
+    - `accept` enforces `[owners] adr_quorum`.
+")]
+    Approve(CommonApproveArgs),
     /// Reject ADR (proposed → rejected)
     #[command(after_help = "\
 EXAMPLES:
@@ -160,10 +182,15 @@ NOTES:
     - Use `adr tick ... -s rejected` to reject a specific alternative instead.
 ")]
     Reject(CommonIdArgs),
-    /// Explain that ADRs must be superseded, not deprecated
+    /// Schedule an accepted ADR's sunset (ADRs are superseded, not deprecated)
     #[command(after_help = "\
+EXAMPLES:
+    govctl adr deprecate ADR-0001 --sunset 2026-12-31 --reason \"Moving to event sourcing\" -f
+
 NOTES:
     - ADRs cannot be deprecated; use `govctl adr supersede ADR-0001 --by ADR-0002` when a newer ADR replaces it.
+    - With --sunset/--reason, the notice is recorded and the ADR stays accepted until superseded.
+    - After the --sunset date, `check` warns while other artifacts still reference the ADR.
     - Use `govctl adr reject ADR-0001` for a proposal that should not proceed.
 ")]
     Deprecate(CommonDeprecateArgs),
@@ -172,6 +199,10 @@ NOTES:
 EXAMPLES:
     govctl adr supersede ADR-0001 --by ADR-0002
     govctl adr supersede ADR-0001 --by ADR-0002 --force
+    govctl adr supersede ADR-0001 --by ADR-0002 --update-refs
+
+NOTES:
+    - --update-refs rewrites inbound `refs` to the replacement and warns for each source reference.
 ")]
     Supersede(CommonSupersedeArgs),
     /// Update ADR alternative status
@@ -186,6 +217,11 @@ NOTES:
     - Valid ADR statuses are `accepted`, `considered`, and `rejected`.
 ")]
     Tick(AdrTickArgs),
+    /// Manage ADR alternatives (pros, cons, rejection)
+    Alt {
+        #[command(subcommand)]
+        command: AltCommand,
+    },
     /// Render a single ADR to markdown
     #[command(after_help = "\
 EXAMPLES:
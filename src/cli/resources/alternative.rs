@@ -0,0 +1,62 @@
+use clap::Subcommand;
+
+/// ADR alternative subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum AltCommand {
+    /// Add an alternative with its pros, cons, and rejection
+    #[command(after_help = "\
+EXAMPLES:
+    govctl adr alt add ADR-0001 \"Option A: Use PostgreSQL\" --pro \"Mature\" --con \"Ops cost\"
+    govctl adr alt add ADR-0001 \"Option B: Use Redis\" --reject \"Not durable\"
+
+NOTES:
+    - Alternatives start considered; --reject marks them rejected.
+    - Adding an alternative whose text already exists changes nothing.
+")]
+    Add {
+        /// ADR ID (e.g., ADR-0001)
+        adr_id: String,
+        /// Alternative text
+        text: String,
+        /// Pro/advantage (can be specified multiple times)
+        #[arg(long)]
+        pro: Vec<String>,
+        /// Con/disadvantage (can be specified multiple times)
+        #[arg(long)]
+        con: Vec<String>,
+        /// Reject the alternative for this reason
+        #[arg(long, value_name = "REASON")]
+        reject: Option<String>,
+    },
+    /// Replace an alternative's pros or cons, or reject it
+    #[command(after_help = "\
+EXAMPLES:
+    govctl adr alt set ADR-0001 PostgreSQL --pro \"Mature\" --pro \"Cheap\"
+    govctl adr alt set ADR-0001 Redis --reject \"Not durable\"
+    govctl adr alt set ADR-0001 --at 1 --con \"Vendor lock-in\"
+
+NOTES:
+    - The pattern matches alternative text case-insensitively by substring.
+    - --pro and --con replace the existing lists; omitted lists are kept.
+    - Use `govctl adr tick ADR-0001 alternatives <pattern> -s accepted` to accept one.
+")]
+    Set {
+        /// ADR ID (e.g., ADR-0001)
+        adr_id: String,
+        /// Pattern matching the alternative text
+        #[arg(required_unless_present = "at")]
+        alternative: Option<String>,
+        /// Select the alternative by index (0-based, negative = from end)
+        #[arg(long, allow_hyphen_values = true, conflicts_with = "alternative")]
+        at: Option<i32>,
+        /// Pro/advantage (can be specified multiple times)
+        #[arg(long)]
+        pro: Vec<String>,
+        /// Con/disadvantage (can be specified multiple times)
+        #[arg(long)]
+        con: Vec<String>,
+        /// Reject the alternative for this reason
+        #[arg(long, value_name = "REASON")]
+        reject: Option<String>,
+    },
+}
@@ -0,0 +1,16 @@
+use clap::Subcommand;
+
+/// Clause anchor subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum AnchorCommand {
+    /// List clause anchors and the clauses they resolve to
+    #[command(
+        visible_alias = "ls",
+        after_help = "\
+EXAMPLES:
+    govctl anchor list
+    govctl anchor list -o json
+"
+    )]
+    List,
+}
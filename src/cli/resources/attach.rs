@@ -0,0 +1,47 @@
+use clap::Subcommand;
+use std::path::PathBuf;
+
+/// Attachment subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum AttachCommand {
+    /// Copy a file under gov/assets/ and attach it to an artifact
+    #[command(after_help = "\
+EXAMPLES:
+    govctl attach add RFC-0003 diagram.svg
+    govctl attach add ADR-0002 docs/sketches/flow.png
+
+NOTES:
+    - The file is copied to gov/assets/<ID>/ and listed in the artifact's attachments.
+    - Attaching a file of the same name again replaces the stored copy.
+")]
+    Add {
+        /// RFC, ADR, or work item ID
+        id: String,
+        /// File to attach
+        file: PathBuf,
+    },
+    /// Detach a file and delete its copy under gov/assets/<ID>/
+    #[command(after_help = "\
+EXAMPLES:
+    govctl attach remove RFC-0003 diagram.svg
+")]
+    Remove {
+        /// RFC, ADR, or work item ID
+        id: String,
+        /// File name or attachment path
+        name: String,
+    },
+    /// List attachments and whether their files exist
+    #[command(
+        visible_alias = "ls",
+        after_help = "\
+EXAMPLES:
+    govctl attach list
+    govctl attach list RFC-0003 -o json
+"
+    )]
+    List {
+        /// Only attachments of this artifact
+        id: Option<String>,
+    },
+}
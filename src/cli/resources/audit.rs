@@ -0,0 +1,31 @@
+use clap::Subcommand;
+
+/// Audit log subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum AuditCommand {
+    /// List logged write commands, newest first
+    #[command(
+        visible_alias = "ls",
+        after_help = "\
+EXAMPLES:
+    govctl audit list
+    govctl audit list -n 20
+    govctl audit list -o json
+"
+    )]
+    List {
+        /// Limit number of results
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+    /// Show one logged command with the files it changed
+    #[command(after_help = "\
+EXAMPLES:
+    govctl audit show 42
+    govctl audit show 42 -o json
+")]
+    Show {
+        /// Entry number, as shown by `audit list`
+        seq: u64,
+    },
+}
@@ -49,6 +49,24 @@ NOTES:
     - Use `--history` for complete archival content; structured output is always complete.
 ")]
     Show(CommonShowArgs),
+    /// Show how the clause text changed over time
+    #[command(after_help = "\
+EXAMPLES:
+    govctl clause history RFC-0001:C-SCOPE
+    govctl clause history RFC-0001:C-SCOPE -n 3
+
+NOTES:
+    - Reads the git history of the clause file, following renames.
+    - Each change prints as a line diff; uncommitted edits show last.
+    - Commits that leave the text unchanged are skipped.
+")]
+    History {
+        /// Clause ID (e.g., RFC-0001:C-SCOPE)
+        id: String,
+        /// Show only the most recent N changes
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
     /// Create a new clause
     #[command(after_help = "\
 EXAMPLES:
@@ -160,6 +178,10 @@ NOTES:
 EXAMPLES:
     govctl clause deprecate RFC-0001:C-SCOPE
     govctl clause deprecate RFC-0001:C-SCOPE --force
+    govctl clause deprecate RFC-0001:C-SCOPE --sunset 2026-12-31 --reason \"Folded into C-LIMITS\"
+
+NOTES:
+    - After the --sunset date, `check` warns while other artifacts still reference the clause.
 ")]
     Deprecate(CommonDeprecateArgs),
     /// Supersede clause
@@ -167,6 +189,31 @@ EXAMPLES:
 EXAMPLES:
     govctl clause supersede RFC-0001:C-SCOPE --by RFC-0001:C-NEW-SCOPE
     govctl clause supersede RFC-0001:C-SCOPE --by RFC-0001:C-NEW-SCOPE --force
+    govctl clause supersede RFC-0001:C-SCOPE --by RFC-0001:C-NEW-SCOPE --update-refs
+
+NOTES:
+    - --update-refs rewrites inbound `refs` to the replacement and warns for each source reference.
 ")]
     Supersede(CommonSupersedeArgs),
+    /// Reorder clauses within an RFC section
+    #[command(after_help = "\
+EXAMPLES:
+    govctl clause reorder RFC-0001 --section Specification --order C-SCOPE,C-TERMS
+    govctl clause reorder RFC-0001 -s Rationale --order C-WHY
+
+NOTES:
+    - Listed clauses move to the front in the given order.
+    - Unlisted clauses in the section keep their relative order after them.
+    - Rendered output follows the new order.
+")]
+    Reorder {
+        /// RFC ID (e.g., RFC-0001)
+        rfc_id: String,
+        /// Section whose clauses are reordered
+        #[arg(short = 's', long, default_value = "Specification")]
+        section: String,
+        /// Clause order (comma-separated, e.g., C-SCOPE,C-TERMS)
+        #[arg(long, value_delimiter = ',', required = true)]
+        order: Vec<String>,
+    },
 }
@@ -0,0 +1,74 @@
+use clap::Subcommand;
+
+/// Project configuration subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum ConfigCommand {
+    /// Validate gov/config.toml against the config schema
+    #[command(after_help = "\
+EXAMPLES:
+    govctl config check
+    govctl config check -W
+
+NOTES:
+    - Reports parse errors, type errors, unknown keys, deprecated keys, and conflicting settings.
+    - Each diagnostic points at gov/config.toml:LINE:COLUMN.
+")]
+    Check {
+        /// Treat warnings as errors
+        #[arg(short = 'W', long)]
+        deny_warnings: bool,
+    },
+
+    /// Show config settings and where each one comes from
+    #[command(after_help = "\
+EXAMPLES:
+    govctl config show
+    govctl config show --resolved
+    govctl config show --resolved -o json
+
+NOTES:
+    - Layers, later winning: defaults, gov/config.toml, gov/config.local.toml
+      (per-user, gitignored), then GOVCTL_<SECTION>_<KEY> environment variables.
+    - Without --resolved, only settings that override a default are listed.
+")]
+    Show {
+        /// Include settings left at their defaults
+        #[arg(long)]
+        resolved: bool,
+    },
+
+    /// Print the effective value of a config setting
+    #[command(after_help = "\
+EXAMPLES:
+    govctl config get project.default_owner
+    govctl config get concurrency.lock_timeout_secs
+")]
+    Get {
+        /// Setting key, e.g. concurrency.lock_timeout_secs
+        key: String,
+    },
+
+    /// Change a config setting, keeping comments and layout
+    #[command(after_help = "\
+EXAMPLES:
+    govctl config set concurrency.lock_timeout_secs 60
+    govctl config set source_scan.include 'src/**/*.rs,docs/**/*.md'
+    govctl config set project.default_owner @alice --local
+
+NOTES:
+    - Values are parsed as the setting's type; lists are comma-separated.
+    - The edited file must pass `govctl config check` before it is written.
+")]
+    Set {
+        /// Setting key, e.g. concurrency.lock_timeout_secs
+        key: String,
+        /// New value
+        value: String,
+        /// Write to gov/config.local.toml (per-user, gitignored) instead
+        #[arg(long)]
+        local: bool,
+    },
+
+    /// List every config setting with its type and effective value
+    List,
+}
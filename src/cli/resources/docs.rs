@@ -0,0 +1,29 @@
+use clap::Subcommand;
+use std::path::PathBuf;
+
+/// CLI reference generation subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum DocsCommand {
+    /// Generate a man page (roff) covering every command
+    #[command(after_help = "\
+EXAMPLES:
+    govctl docs man > govctl.1
+    govctl docs man --out share/man/man1/govctl.1
+")]
+    Man {
+        /// Write to this file instead of stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Generate a Markdown command reference covering every command
+    #[command(after_help = "\
+EXAMPLES:
+    govctl docs markdown > docs/reference/cli.md
+    govctl docs markdown --out docs/reference/cli.md
+")]
+    Markdown {
+        /// Write to this file instead of stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
@@ -0,0 +1,62 @@
+use clap::Subcommand;
+
+/// Compliance mapping subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum MappingCommand {
+    /// Map a standard control to one or more clauses
+    #[command(after_help = "\
+EXAMPLES:
+    govctl mapping add ISO-27001 A.5.15 RFC-0001:C-ACCESS --title \"Access control\"
+    govctl mapping add SOC2 CC6.1 RFC-0001:C-ACCESS RFC-0003:C-AUDIT-LOG
+    govctl mapping add ISO-27001 A.8.24 RFC-0004:C-KEYS --standard-title \"ISO/IEC 27001:2022\"
+
+NOTES:
+    - The first add for a standard creates gov/mapping/<standard>.toml.
+    - Each clause must exist and be active.
+")]
+    Add {
+        /// Standard ID (e.g. ISO-27001, SOC2)
+        standard: String,
+        /// Control ID within the standard (e.g. A.5.15, CC6.1)
+        control: String,
+        /// Clauses that satisfy the control (RFC-NNNN:C-NAME)
+        #[arg(required = true)]
+        clauses: Vec<String>,
+        /// Control title
+        #[arg(long)]
+        title: Option<String>,
+        /// Standard title shown in the compliance matrix
+        #[arg(long)]
+        standard_title: Option<String>,
+    },
+    /// Unlink clauses from a control, or drop the control
+    #[command(after_help = "\
+EXAMPLES:
+    govctl mapping remove SOC2 CC6.1 RFC-0003:C-AUDIT-LOG
+    govctl mapping remove SOC2 CC6.1
+
+NOTES:
+    - Without clauses the whole control is removed.
+")]
+    Remove {
+        /// Standard ID
+        standard: String,
+        /// Control ID
+        control: String,
+        /// Clauses to unlink (default: all)
+        clauses: Vec<String>,
+    },
+    /// List mapped controls and their coverage
+    #[command(
+        visible_alias = "ls",
+        after_help = "\
+EXAMPLES:
+    govctl mapping list
+    govctl mapping list SOC2 -o json
+"
+    )]
+    List {
+        /// Only controls of this standard
+        standard: Option<String>,
+    },
+}
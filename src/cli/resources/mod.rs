@@ -1,13 +1,39 @@
 mod adr;
+mod alternative;
+mod anchor;
+mod attach;
+mod audit;
 mod clause;
+mod config;
+mod docs;
 mod guard;
+mod mapping;
+mod publish;
 mod rfc;
+mod scan;
+mod section;
+mod supersede;
+mod sync;
 mod tag;
+mod template;
 mod work;
 
 pub(crate) use adr::AdrCommand;
+pub(crate) use alternative::AltCommand;
+pub(crate) use anchor::AnchorCommand;
+pub(crate) use attach::AttachCommand;
+pub(crate) use audit::AuditCommand;
 pub(crate) use clause::ClauseCommand;
+pub(crate) use config::ConfigCommand;
+pub(crate) use docs::DocsCommand;
 pub(crate) use guard::GuardCommand;
+pub(crate) use mapping::MappingCommand;
+pub(crate) use publish::PublishCommand;
 pub(crate) use rfc::RfcCommand;
+pub(crate) use scan::ScanCommand;
+pub(crate) use section::SectionCommand;
+pub(crate) use supersede::SupersedeCommand;
+pub(crate) use sync::SyncCommand;
 pub(crate) use tag::TagCommand;
+pub(crate) use template::TemplateCommand;
 pub(crate) use work::WorkCommand;
@@ -0,0 +1,24 @@
+use clap::Subcommand;
+
+/// Publishing subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum PublishCommand {
+    /// Push rendered RFCs and ADRs to a Confluence space
+    #[command(after_help = "\
+EXAMPLES:
+    govctl publish confluence
+    govctl publish confluence RFC-0001 ADR-0003
+    govctl --dry-run publish confluence
+
+NOTES:
+    - Needs a [confluence] section in gov/config.toml with base_url and space.
+    - The API token is read from CONFLUENCE_TOKEN; with CONFLUENCE_USER set it is
+      sent as basic auth (Atlassian Cloud), otherwise as a bearer token.
+    - Pages are found through [confluence.pages] or by title, updated in place,
+      and labelled with [confluence].label (default: govctl-generated).
+")]
+    Confluence {
+        /// RFC or ADR IDs to publish (default: all RFCs and ADRs)
+        ids: Vec<String>,
+    },
+}
@@ -2,9 +2,9 @@ use clap::Subcommand;
 
 use crate::model::RfcPhase;
 use crate::{
-    CommonAddArgs, CommonDeprecateArgs, CommonEditArgs, CommonGetArgs, CommonListArgs,
-    CommonRemoveArgs, CommonRenderArgs, CommonSetArgs, CommonShowArgs, CommonSupersedeArgs,
-    FinalizeStatus,
+    CommonAddArgs, CommonApproveArgs, CommonDeprecateArgs, CommonEditArgs, CommonGetArgs,
+    CommonIdArgs, CommonListArgs, CommonRemoveArgs, CommonRenderArgs, CommonSetArgs,
+    CommonShowArgs, CommonSupersedeArgs, FinalizeStatus, RenderLayoutArgs, SectionCommand,
 };
 
 /// RFC commands (resource-first structure)
@@ -28,12 +28,14 @@ EXAMPLES:
     /// Get RFC metadata or specific field
     #[command(after_help = "\
 VALID FIELDS:
-    - title, version, status, phase, owners, refs, changelog
+    - title, version, status, phase, owners, approvals, refs, sections, changelog
+    - sections[N], sections[N].title, sections[N].clauses
 
 EXAMPLES:
     govctl rfc get RFC-0001
     govctl rfc get RFC-0001 title
     govctl rfc get RFC-0001 refs
+    govctl rfc get RFC-0001 sections[0].clauses
 ")]
     Get(CommonGetArgs),
     /// Show rendered RFC content
@@ -43,12 +45,14 @@ EXAMPLES:
     govctl rfc show RFC-0001 --history
     govctl rfc show RFC-0001 -o plain
     govctl rfc show RFC-0001 -o yaml
+    govctl rfc show RFC-0001@1.1.0
 
 NOTES:
     - Human-readable `show` hides deprecated RFC bodies and obsolete Clause bodies by default.
     - Use `--history` for complete archival human-readable content.
     - JSON, YAML, and TOML output stays complete and cannot be combined with `--history`.
     - Use `get` for field/path-level inspection.
+    - `RFC-0001@1.1.0` shows the snapshot saved when 1.1.0 was sealed ([snapshots] rfc_versions).
 ")]
     Show(CommonShowArgs),
     /// Create a new RFC
@@ -56,17 +60,23 @@ NOTES:
 EXAMPLES:
     govctl rfc new \"Add incremental index rebuilding\"
     govctl rfc new \"Add incremental index rebuilding\" --id RFC-0010
+    govctl rfc new --interactive
 
 NOTES:
     - Use `--id` only when you need to pin a specific RFC ID.
     - New RFCs start as draft and can later be finalized.
+    - `--interactive` prompts for title, owners, sections, and refs (requires a terminal).
 ")]
     New {
         /// RFC title
-        title: String,
+        #[arg(required_unless_present = "interactive")]
+        title: Option<String>,
         /// RFC ID (e.g., RFC-0010). Auto-generated if omitted.
         #[arg(long)]
         id: Option<String>,
+        /// Prompt for the title and initial fields
+        #[arg(short = 'i', long)]
+        interactive: bool,
     },
     /// Canonical path-first edit entrypoint
     #[command(after_help = "\
@@ -75,6 +85,7 @@ EXAMPLES:
     govctl rfc edit RFC-0001 changelog.fixed --add \"Correct timeout wording\"
     govctl rfc edit RFC-0001 changelog.fixed[0] --remove
     govctl rfc edit RFC-0001 refs --add RFC-0002
+    govctl rfc edit RFC-0001 sections --add \"Rationale\"
 
 NOTES:
     - Changelog edits apply only to the entry matching the RFC's current version.
@@ -88,11 +99,13 @@ VALID FIELDS:
     - title: RFC title
 
   Array fields (use 'add' / 'remove'):
-    - owners, refs, sections
+    - owners, approvals, refs, sections
 
 EXAMPLES:
     govctl rfc set RFC-0001 title \"New Title\"
 
+Use `govctl rfc section rename` / `reorder` to rename or reorder sections.
+
 Use dedicated lifecycle verbs instead of `set` for:
     - version → `govctl rfc bump`
     - status → `govctl rfc finalize` / `govctl rfc deprecate` / `govctl rfc supersede`
@@ -104,16 +117,21 @@ Use dedicated lifecycle verbs instead of `set` for:
 VALID ARRAY FIELDS:
     - refs: Cross-references to other RFCs (e.g., \"RFC-0002\")
     - owners: RFC owners (e.g., \"@alice\")
+    - approvals: Identities that approved the RFC (e.g., \"@alice\")
+    - sections: Section titles; the new section is appended empty
 
 EXAMPLES:
     govctl rfc add RFC-0001 refs RFC-0002
     govctl rfc add RFC-0001 owners @alice
+    govctl rfc add RFC-0001 approvals @alice
+    govctl rfc add RFC-0001 sections \"Rationale\"
 ")]
     Add(CommonAddArgs),
     /// Remove value from RFC array field
     #[command(after_help = "\
 VALID ARRAY FIELDS:
-    - refs, owners
+    - refs, owners, approvals
+    - sections: exact title only; the section must have no Clauses
 
 MATCHING OPTIONS:
     - pattern: Substring match (default)
@@ -125,6 +143,7 @@ MATCHING OPTIONS:
 EXAMPLES:
     govctl rfc remove RFC-0001 refs RFC-0002     # Remove first match
     govctl rfc remove RFC-0001 refs --at 1       # Remove by index
+    govctl rfc remove RFC-0001 sections Rationale
 ")]
     Remove(CommonRemoveArgs),
     /// Bump RFC version
@@ -168,6 +187,7 @@ EXAMPLES:
 NOTES:
     - Use `deprecate` for normative → deprecated.
     - Use `advance` to move phase after finalization.
+    - With `[owners] require_approval`, one of the RFC owners must be in `approvals`.
 ")]
     Finalize {
         /// RFC ID
@@ -176,15 +196,47 @@ NOTES:
         #[arg(value_enum)]
         status: FinalizeStatus,
     },
+    /// Record an approval of an RFC
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc approve RFC-0001 --as @alice
+
+NOTES:
+    - Approvals are stored in the RFC and shown in rendered output.
+    - `finalize normative` enforces `[owners] rfc_quorum` and `require_approval`.
+")]
+    Approve(CommonApproveArgs),
+    /// Freeze an RFC for review, refusing changes to it and its clauses
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc freeze RFC-0001
+    govctl rfc unfreeze RFC-0001
+
+NOTES:
+    - While frozen, edits, lifecycle changes, and clause writes fail with E0122.
+    - Approvals can still be recorded during the review window.
+")]
+    Freeze(CommonIdArgs),
+    /// Lift a review freeze
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc unfreeze RFC-0001
+")]
+    Unfreeze(CommonIdArgs),
     /// Advance RFC phase
     #[command(after_help = "\
 EXAMPLES:
     govctl rfc advance RFC-0001 impl
     govctl rfc advance RFC-0001 test
+    govctl rfc advance RFC-0001 stable --force
+    govctl rfc advance RFC-0001 test --report
 
 NOTES:
     - Typical progression is `spec -> impl -> test -> stable`.
     - Use this after the RFC has been finalized.
+    - Checklists in `[gates]` must pass first; `--force` overrides them with a warning.
+    - Entering `test` also requires work items referencing the RFC's clauses to be done.
+    - `--report` lists the blockers without advancing.
 ")]
     Advance {
         /// RFC ID
@@ -192,12 +244,22 @@ NOTES:
         /// Target phase
         #[arg(value_enum)]
         phase: RfcPhase,
+        /// Advance even if `[gates]` checklist items are unmet
+        #[arg(short = 'f', long, conflicts_with = "report")]
+        force: bool,
+        /// List what blocks the advance instead of advancing
+        #[arg(long)]
+        report: bool,
     },
     /// Deprecate RFC
     #[command(after_help = "\
 EXAMPLES:
     govctl rfc deprecate RFC-0001
     govctl rfc deprecate RFC-0001 --force
+    govctl rfc deprecate RFC-0001 --sunset 2026-12-31 --reason \"Replaced by RFC-0002\"
+
+NOTES:
+    - After the --sunset date, `check` warns while other artifacts still reference the RFC.
 ")]
     Deprecate(CommonDeprecateArgs),
     /// Supersede RFC
@@ -205,13 +267,78 @@ EXAMPLES:
 EXAMPLES:
     govctl rfc supersede RFC-0001 --by RFC-0002
     govctl rfc supersede RFC-0001 --by RFC-0002 --force
+    govctl rfc supersede RFC-0001 --by RFC-0002 --update-refs
+
+NOTES:
+    - --update-refs rewrites inbound `refs` to the replacement and warns for each source reference.
 ")]
     Supersede(CommonSupersedeArgs),
+    /// Split clauses out of an RFC into a new draft RFC
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc split RFC-0003 --clauses C-A,C-B --into RFC-0010 \"New Title\"
+    govctl rfc split RFC-0003 --clauses C-A --into RFC-0010 \"New Title\" --force
+
+NOTES:
+    - The new RFC starts as draft, refs the source RFC, and keeps each clause's section.
+    - Draft sources move clauses outright; moved clauses must not be referenced.
+    - Normative sources keep the originals as superseded by the new clauses.
+    - The source RFC's current changelog entry records the split.
+")]
+    Split {
+        /// Source RFC ID
+        id: String,
+        /// Clauses to move (comma-separated, `C-NAME` or `RFC-NNNN:C-NAME`)
+        #[arg(long, value_delimiter = ',', required = true)]
+        clauses: Vec<String>,
+        /// New RFC ID
+        #[arg(long)]
+        into: String,
+        /// New RFC title
+        title: String,
+        /// Skip confirmation prompt
+        #[arg(short = 'f', long)]
+        force: bool,
+    },
+    /// Merge an RFC's clauses into another RFC
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc merge RFC-0004 --into RFC-0003
+    govctl rfc merge RFC-0004 --into RFC-0003 --force
+
+NOTES:
+    - Active clauses are copied into matching sections of the target RFC.
+    - Source clauses become superseded by their copies; the source RFC is deprecated.
+    - The target RFC records `supersedes` and both changelogs record the merge.
+    - Merge follows the same status and phase rules as `rfc supersede`.
+    - A draft source's clauses move outright and the source RFC is removed.
+")]
+    Merge {
+        /// Source RFC ID
+        id: String,
+        /// Target RFC ID
+        #[arg(long)]
+        into: String,
+        /// Skip confirmation prompt
+        #[arg(short = 'f', long)]
+        force: bool,
+    },
+    /// Manage RFC sections
+    Section {
+        #[command(subcommand)]
+        command: SectionCommand,
+    },
     /// Render a single RFC to markdown
     #[command(after_help = "\
 EXAMPLES:
     govctl rfc render RFC-0001
     govctl rfc render RFC-0001 --dry-run
+    govctl rfc render RFC-0001 --toc --clause-numbers
 ")]
-    Render(CommonRenderArgs),
+    Render {
+        #[command(flatten)]
+        args: CommonRenderArgs,
+        #[command(flatten)]
+        layout: RenderLayoutArgs,
+    },
 }
@@ -0,0 +1,27 @@
+use clap::Subcommand;
+
+/// Source scan subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum ScanCommand {
+    /// Count `Implements [[...]]` annotations per normative clause
+    #[command(after_help = "\
+EXAMPLES:
+    govctl scan coverage
+    govctl scan coverage --rfc RFC-0001
+    govctl scan coverage -o json -W
+
+NOTES:
+    - Scans the files selected by [source_scan] include/exclude, even when scanning is disabled for check.
+    - Only references with \"Implements\" earlier on the same line count.
+    - Covers active normative clauses of RFCs that are not deprecated.
+    - Each uncovered clause is a W0122 warning; -W makes them fail the command.
+")]
+    Coverage {
+        /// Only clauses of this RFC
+        #[arg(long)]
+        rfc: Option<String>,
+        /// Treat warnings as errors
+        #[arg(short = 'W', long)]
+        deny_warnings: bool,
+    },
+}
@@ -0,0 +1,70 @@
+use clap::Subcommand;
+
+/// RFC section subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum SectionCommand {
+    /// Add an empty section
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc section add RFC-0001 Rationale
+    govctl rfc section add RFC-0001 \"Security Considerations\" --after Specification
+")]
+    Add {
+        /// RFC ID (e.g., RFC-0001)
+        rfc_id: String,
+        /// Section title
+        title: String,
+        /// Insert after this section (default: append)
+        #[arg(long, value_name = "SECTION")]
+        after: Option<String>,
+    },
+    /// Rename a section
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc section rename RFC-0001 Specification Requirements
+")]
+    Rename {
+        /// RFC ID (e.g., RFC-0001)
+        rfc_id: String,
+        /// Current section title
+        from: String,
+        /// New section title
+        to: String,
+    },
+    /// Remove a section
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc section remove RFC-0001 Appendix
+    govctl rfc section remove RFC-0001 Appendix --force
+
+NOTES:
+    - Sections that still contain clauses are refused without --force.
+    - The last remaining section cannot be removed.
+    - --force also deletes those clauses, under the same rules as `clause delete`.
+")]
+    Remove {
+        /// RFC ID (e.g., RFC-0001)
+        rfc_id: String,
+        /// Section title
+        title: String,
+        /// Remove the section even if it contains clauses, deleting them
+        #[arg(short = 'f', long)]
+        force: bool,
+    },
+    /// Reorder sections
+    #[command(after_help = "\
+EXAMPLES:
+    govctl rfc section reorder RFC-0001 --order Summary,Rationale,Specification
+
+NOTES:
+    - Listed sections move to the front in the given order.
+    - Unlisted sections keep their relative order after them.
+")]
+    Reorder {
+        /// RFC ID (e.g., RFC-0001)
+        rfc_id: String,
+        /// Section order (comma-separated titles)
+        #[arg(long, value_delimiter = ',', required = true)]
+        order: Vec<String>,
+    },
+}
@@ -0,0 +1,17 @@
+use clap::Subcommand;
+
+/// Supersession subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum SupersedeCommand {
+    /// Show the supersession chain an RFC, clause, or ADR belongs to
+    #[command(after_help = "\
+EXAMPLES:
+    govctl supersede chain RFC-0001
+    govctl supersede chain ADR-0003
+    govctl supersede chain RFC-0001:C-SCOPE
+")]
+    Chain {
+        /// Artifact ID anywhere in the chain
+        id: String,
+    },
+}
@@ -0,0 +1,25 @@
+use clap::Subcommand;
+
+/// Sync subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum SyncCommand {
+    /// Pull linked Jira issue status into work items
+    #[command(after_help = "\
+EXAMPLES:
+    govctl sync jira
+    govctl sync jira WI-2026-04-06-001
+    govctl --dry-run sync jira
+
+NOTES:
+    - Needs a [jira] section in gov/config.toml with base_url.
+    - The API token is read from JIRA_TOKEN; with JIRA_USER set it is sent as
+      basic auth (Atlassian Cloud), otherwise as a bearer token.
+    - A status the work item has not recorded yet is added as a dated note.
+    - Work items whose status disagrees with the issue's status category are
+      reported as W0124 warnings; sync never moves a work item.
+")]
+    Jira {
+        /// Work item IDs to sync (default: every work item with a jira_key)
+        ids: Vec<String>,
+    },
+}
@@ -31,9 +31,5 @@ EXAMPLES:
     govctl tag list -o json
 "
     )]
-    List {
-        /// Output format
-        #[arg(short = 'o', long, value_enum, default_value = "table")]
-        output: crate::OutputFormat,
-    },
+    List,
 }
@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+/// Project template subcommands
+#[derive(Subcommand, Clone, Debug)]
+pub(crate) enum TemplateCommand {
+    /// Pack the project's governance baseline into a reusable template
+    #[command(after_help = "\
+EXAMPLES:
+    govctl template pack --out org-template.tar.zst
+    govctl init --from org-template.tar.zst
+
+NOTES:
+    - Captures gov/config.toml, gov/templates/, gov/guard/, and gov/rfc/RFC-0000/.
+    - The archive is a gzip-compressed tar with a govctl-template.toml manifest.
+")]
+    Pack {
+        /// Output archive path
+        #[arg(long, value_name = "FILE")]
+        out: PathBuf,
+    },
+}
@@ -4,8 +4,8 @@ use clap::Subcommand;
 
 use crate::model::WorkItemStatus;
 use crate::{
-    CommonDeleteArgs, CommonGetArgs, CommonListArgs, CommonRemoveArgs, CommonRenderArgs,
-    CommonSetArgs, CommonShowArgs, WorkAddArgs, WorkEditArgs, WorkTickArgs,
+    CommonDeleteArgs, CommonGetArgs, CommonRemoveArgs, CommonRenderArgs, CommonSetArgs,
+    CommonShowArgs, WorkAddArgs, WorkEditArgs, WorkListArgs, WorkTickArgs,
 };
 
 /// Work item commands (resource-first structure)
@@ -22,15 +22,16 @@ EXAMPLES:
     govctl work list
     govctl work list active
     govctl work list queue -n 10
+    govctl work list active --github
 "
     )]
-    List(CommonListArgs),
+    List(WorkListArgs),
     /// Get work item metadata or specific field
     #[command(after_help = "\
 VALID FIELDS:
-    - title, description, status, completed_at, refs, depends_on
+    - title, description, status, completed_at, refs, depends_on, priority
     - notes, acceptance_criteria
-    - verification.required_guards, verification.waivers
+    - verification.required_guards, verification.waivers, verification.guards
 
 EXAMPLES:
     govctl work get WI-2026-04-06-001
@@ -56,17 +57,23 @@ NOTES:
 EXAMPLES:
     govctl work new \"Implement RFC-0005 parser\"
     govctl work new \"Implement RFC-0005 parser\" --active
+    govctl work new --interactive --active
 
 NOTES:
     - Use `--active` to immediately start the work item.
     - Add acceptance criteria before moving to `done`.
+    - `--interactive` prompts for title, refs, and acceptance criteria (requires a terminal).
 ")]
     New {
         /// Work item title
-        title: String,
+        #[arg(required_unless_present = "interactive")]
+        title: Option<String>,
         /// Immediately activate the work item
         #[arg(long)]
         active: bool,
+        /// Prompt for the title and initial fields
+        #[arg(short = 'i', long)]
+        interactive: bool,
     },
     /// Canonical path-first edit entrypoint
     #[command(after_help = "\
@@ -111,6 +118,7 @@ Use dedicated verbs instead of `set` for:
     #[command(after_help = "\
 VALID ARRAY FIELDS:
     - refs: Cross-references to RFCs/ADRs (e.g., \"RFC-0001\", \"ADR-0002\")
+    - github: Linked GitHub issues or pull requests (e.g., \"acme/api#123\")
     - depends_on: Blocking dependencies on work items (e.g., \"WI-2026-04-06-001\")
     - notes: Durable constraints or retry rules (short strings)
     - acceptance_criteria: Completion criteria with category prefix
@@ -137,7 +145,7 @@ EXAMPLES:
     /// Remove value from work item array field
     #[command(after_help = "\
 VALID ARRAY FIELDS:
-    - refs, depends_on, notes, acceptance_criteria
+    - refs, depends_on, github, notes, acceptance_criteria
 
 MATCHING OPTIONS:
     - pattern: Substring match (default)
@@ -153,16 +161,43 @@ EXAMPLES:
     govctl work remove WI-001 notes --all       # Remove all
 ")]
     Remove(CommonRemoveArgs),
+    /// Link a GitHub issue, pull request, or Jira issue to a work item
+    #[command(after_help = "\
+EXAMPLES:
+    govctl work link WI-2026-04-06-001 --github acme/api#123
+    govctl work link WI-2026-04-06-001 --jira PROJ-123
+    govctl work list --github
+
+NOTES:
+    - GitHub links are stored in the work item's `github` field and shown by `work list --github`.
+    - Unlink with `govctl work remove WI-2026-04-06-001 github acme/api#123`.
+    - A work item has one Jira issue, stored in `jira_key`; linking another replaces it.
+    - `govctl sync jira` pulls the linked issue's status.
+")]
+    #[command(group(clap::ArgGroup::new("target").required(true).args(["github", "jira"])))]
+    Link {
+        /// Work item ID
+        id: String,
+        /// Issue or pull request as owner/repo#number
+        #[arg(long, value_name = "OWNER/REPO#N")]
+        github: Option<String>,
+        /// Jira issue key
+        #[arg(long, value_name = "KEY")]
+        jira: Option<String>,
+    },
     /// Move work item to new status
     #[command(visible_alias = "mv")]
     #[command(after_help = "\
 EXAMPLES:
     govctl work move WI-2026-04-06-001 active
     govctl work move WI-2026-04-06-001 done
+    govctl work move WI-2026-04-06-001 active --reopen --reason \"Fails on Windows paths\"
 
 NOTES:
     - `done` requires acceptance criteria and effective guards to pass.
     - Use `work tick` to update acceptance-criteria status.
+    - done → active and cancelled → queue need `--reopen --reason`; the
+      reason is recorded as a note.
 ")]
     Move {
         /// Work item file path or ID
@@ -171,6 +206,139 @@ NOTES:
         /// Target status
         #[arg(value_enum)]
         status: WorkItemStatus,
+        /// Reopen a done item (to active) or a cancelled one (to queue)
+        #[arg(long, requires = "reason")]
+        reopen: bool,
+        /// Why the item is reopened, recorded as a note
+        #[arg(long, requires = "reopen")]
+        reason: Option<String>,
+    },
+    /// Queue a copy of a work item
+    #[command(after_help = "\
+EXAMPLES:
+    govctl work clone WI-2026-04-06-001
+    govctl work clone WI-2026-04-06-001 --title \"Rotate keys for staging\"
+
+NOTES:
+    - Copies the description, refs, and pending acceptance criteria; done
+      and cancelled criteria, notes, and verification are left behind.
+    - The copy is queued and keeps the source title unless --title is given.
+")]
+    Clone {
+        /// Work item to copy
+        id: String,
+        /// Title of the copy
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Start a queued work item
+    #[command(after_help = "\
+EXAMPLES:
+    govctl work start WI-2026-04-06-001
+    govctl work start guard-timeout
+
+NOTES:
+    - Same as `work move <ID> active`: the item must be queued, and
+      `started` is set to today.
+    - Record progress as notes with `work add <ID> notes`.
+")]
+    Start {
+        /// Work item file path or ID
+        #[arg(value_name = "FILE_OR_ID")]
+        file: PathBuf,
+    },
+    /// Run guards and mark an active work item done
+    #[command(after_help = "\
+EXAMPLES:
+    govctl work finish WI-2026-04-06-001
+    govctl work finish WI-2026-04-06-001 --tick-chores
+
+NOTES:
+    - Same as `work move <ID> done`, but always runs the item's effective
+      guards, even when `[work_item.done] guards` is off.
+    - `--tick-chores` ticks pending acceptance criteria in the `chore`
+      category first; other pending criteria still block.
+    - `completed` is set to today.
+")]
+    Finish {
+        /// Work item file path or ID
+        #[arg(value_name = "FILE_OR_ID")]
+        file: PathBuf,
+        /// Tick pending `chore:` acceptance criteria before finishing
+        #[arg(long)]
+        tick_chores: bool,
+    },
+    /// Suggest a queue ordering from governance signals
+    #[command(after_help = "\
+EXAMPLES:
+    govctl work prioritize --suggest
+    govctl work prioritize --suggest -o json
+    govctl work prioritize --suggest --apply
+
+SIGNALS:
+    - +3 per referenced normative RFC in the `impl` phase
+    - +4 per active and +2 per queued work item waiting on it via `depends_on`
+    - +3 per referenced RFC for which it is the last open work item
+    - +1 per 30 days in the queue (up to +3)
+    - -5 while its own dependencies are unfinished
+
+NOTES:
+    - Only `queue` work items are ranked; ties keep the oldest first.
+    - `--apply` writes each rank to the work item's `priority` field.
+")]
+    Prioritize {
+        /// Score queued work items and propose an ordering
+        #[arg(long, required = true)]
+        suggest: bool,
+        /// Write the suggested ranks to each work item's `priority` field
+        #[arg(long)]
+        apply: bool,
+    },
+    /// Move a queued work item before or after another
+    #[command(after_help = "\
+EXAMPLES:
+    govctl work rank WI-2026-04-06-002 --before WI-2026-04-06-001
+    govctl work rank guard-timeout --after WI-2026-04-06-001
+
+NOTES:
+    - Both items must be queued.
+    - The queue is renumbered from 1 in the `priority` field; unranked
+      items follow ranked ones in ID order.
+")]
+    #[command(group(clap::ArgGroup::new("position").required(true).args(["before", "after"])))]
+    Rank {
+        /// Work item to move
+        id: String,
+        /// Place it just before this queued item
+        #[arg(long, value_name = "ID")]
+        before: Option<String>,
+        /// Place it just after this queued item
+        #[arg(long, value_name = "ID")]
+        after: Option<String>,
+    },
+    /// Show the next queued work item that is ready to start
+    #[command(after_help = "\
+EXAMPLES:
+    govctl work next
+    govctl work next --tag backend -o plain
+    govctl work next --ref RFC-0002 --activate
+
+NOTES:
+    - Picks the first queued item in rank order whose `depends_on` items
+      are all done or cancelled.
+    - `--ref` matches the artifact or any of its clauses.
+    - `--activate` starts the item, as `work start` does.
+")]
+    Next {
+        /// Only items with all of these tags (comma-separated)
+        #[arg(long)]
+        tag: Option<String>,
+        /// Only items referencing this artifact
+        #[arg(long = "ref", value_name = "ID")]
+        reference: Option<String>,
+        /// Start the item
+        #[arg(long)]
+        activate: bool,
     },
     /// Tick acceptance criteria item
     #[command(after_help = "\
@@ -0,0 +1,67 @@
+//! `govctl anchor list`: clause anchors and the clauses they resolve to.
+
+use crate::OutputFormat;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project;
+use comfy_table::Cell;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct AnchorEntry {
+    anchor: String,
+    clause: String,
+    title: String,
+    status: String,
+}
+
+/// Print every declared anchor, sorted by name, with its clause.
+pub fn anchor_list(config: &Config, output: OutputFormat) -> DiagnosticResult<Diagnostics> {
+    let index = load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for anchor list",
+                "anchor list",
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+
+    let mut entries: Vec<AnchorEntry> = index
+        .iter_clauses()
+        .flat_map(|(rfc, clause)| {
+            clause.spec.anchors.iter().map(move |anchor| AnchorEntry {
+                anchor: anchor.clone(),
+                clause: format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id),
+                title: clause.spec.title.clone(),
+                status: clause.spec.status.as_ref().to_string(),
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.anchor.cmp(&b.anchor));
+
+    match output {
+        OutputFormat::Json => print_json_array(&entries),
+        OutputFormat::Plain => {
+            for entry in &entries {
+                println!("{}\t{}", entry.anchor, entry.clause);
+            }
+        }
+        OutputFormat::Table => {
+            let mut table = table_with_bold_headers(&["Anchor", "Clause", "Title", "Status"]);
+            for entry in &entries {
+                table.add_row(vec![
+                    Cell::new(&entry.anchor),
+                    Cell::new(&entry.clause),
+                    Cell::new(&entry.title),
+                    Cell::new(&entry.status),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(vec![])
+}
@@ -0,0 +1,243 @@
+//! Artifact attachments per [[RFC-0002:C-GLOBAL-COMMANDS]]: `attach add`,
+//! `remove`, and `list`.
+//!
+//! Attachments are files under `gov/assets/`, listed in an RFC's, ADR's, or
+//! work item's `attachments` by path relative to that directory. `attach add`
+//! copies a file to `gov/assets/<ID>/`; rendering copies each attachment next
+//! to the rendered docs, so diagrams travel with the spec.
+
+use crate::OutputFormat;
+use crate::aliases::Aliases;
+use crate::cmd::edit::{ArtifactType, EditFieldRequest, MatchOptionsOwned, OwnedEditAction};
+use crate::cmd::mapping::load_index;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::{Config, PrefixesConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::model::ProjectIndex;
+use crate::ui;
+use crate::validate::attachment_path;
+use crate::write::{WriteOp, create_dir_all, delete_file, with_transaction, write_file_bytes};
+use comfy_table::Cell;
+use serde::Serialize;
+use std::path::Path;
+
+/// Copy `file` to `gov/assets/<ID>/` and list it on the artifact. Attaching
+/// a file of the same name again replaces the stored copy.
+pub fn attach_add(
+    config: &Config,
+    id: &str,
+    file: &Path,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let id = &canonical_id(config, id);
+    crate::cmd::lifecycle::ensure_not_frozen(config, id)?;
+    let index = load_index(config)?;
+    let listed = attachments_of(&config.prefixes, &index, id)?;
+    let name = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E1702AttachmentPathInvalid,
+                format!("Cannot attach '{}': not a file name", file.display()),
+                id,
+            )
+        })?;
+    let bytes = std::fs::read(file)
+        .map_err(|err| Diagnostic::io_error("read attachment", err, file.display().to_string()))?;
+    let attachment = format!("{id}/{name}");
+    let target = attachment_path(config, &attachment, id)?;
+    let already_listed = listed.contains(&attachment);
+
+    with_transaction(op, |op| {
+        if let Some(dir) = target.parent() {
+            create_dir_all(dir, op, Some(&config.display_path(dir)))?;
+        }
+        write_file_bytes(&target, &bytes, op, Some(&config.display_path(&target)))?;
+        if already_listed {
+            if !op.is_preview() {
+                ui::updated("attachment", &attachment);
+            }
+            return Ok(vec![]);
+        }
+        crate::cmd::edit::edit_field(EditFieldRequest {
+            config,
+            id,
+            path: "attachments",
+            action: &OwnedEditAction::Add {
+                values: vec![attachment.clone()],
+                stdin: false,
+                lines: false,
+            },
+            category_override: None,
+            pros: None,
+            cons: None,
+            reject_reason: None,
+            op,
+        })
+    })
+}
+
+/// Unlist an attachment and delete its copy under `gov/assets/<ID>/`. `name`
+/// is the file name or the full attachment path.
+pub fn attach_remove(
+    config: &Config,
+    id: &str,
+    name: &str,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let id = &canonical_id(config, id);
+    crate::cmd::lifecycle::ensure_not_frozen(config, id)?;
+    let index = load_index(config)?;
+    let owned = format!("{id}/{name}");
+    let attachment = attachments_of(&config.prefixes, &index, id)?
+        .iter()
+        .find(|listed| *listed == name || **listed == owned)
+        .cloned()
+        .ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E1701AttachmentMissing,
+                format!("{id} has no attachment '{name}' (hint: `govctl attach list {id}`)"),
+                id,
+            )
+        })?;
+    let path = attachment_path(config, &attachment, id)?;
+    // Files other artifacts may share are only unlisted.
+    let owned_copy = attachment.starts_with(&format!("{id}/")) && path.is_file();
+
+    with_transaction(op, |op| {
+        let match_opts = MatchOptionsOwned {
+            pattern: Some(attachment.clone()),
+            at: None,
+            exact: true,
+            regex: false,
+            all: false,
+        };
+        let diagnostics = crate::cmd::edit::remove_from_field(
+            config,
+            id,
+            "attachments",
+            &match_opts.as_match_options(),
+            op,
+        )?;
+        if owned_copy {
+            delete_file(&path, op, Some(&config.display_path(&path)))?;
+        }
+        Ok(diagnostics)
+    })
+}
+
+#[derive(Serialize)]
+struct AttachmentRow {
+    artifact: String,
+    attachment: String,
+    /// Size in bytes, or `None` when the file is missing.
+    size: Option<u64>,
+}
+
+/// Print the attachments of every artifact, or of one.
+pub fn attach_list(
+    config: &Config,
+    id: Option<&str>,
+    output: OutputFormat,
+) -> DiagnosticResult<Diagnostics> {
+    let index = load_index(config)?;
+    let id = id.map(|id| canonical_id(config, id));
+    let artifacts: Vec<(&str, &[String])> = match id.as_deref() {
+        Some(id) => vec![(id, attachments_of(&config.prefixes, &index, id)?)],
+        None => index
+            .rfcs
+            .iter()
+            .map(|rfc| (rfc.rfc.rfc_id.as_str(), rfc.rfc.attachments.as_slice()))
+            .chain(index.adrs.iter().map(|adr| {
+                let meta = adr.meta();
+                (meta.id.as_str(), meta.attachments.as_slice())
+            }))
+            .chain(index.work_items.iter().map(|work| {
+                let meta = work.meta();
+                (meta.id.as_str(), meta.attachments.as_slice())
+            }))
+            .collect(),
+    };
+    let rows: Vec<AttachmentRow> = artifacts
+        .into_iter()
+        .flat_map(|(artifact, attachments)| {
+            attachments.iter().map(move |attachment| AttachmentRow {
+                artifact: artifact.to_string(),
+                attachment: attachment.clone(),
+                size: attachment_path(config, attachment, artifact)
+                    .ok()
+                    .and_then(|path| std::fs::metadata(path).ok())
+                    .filter(|metadata| metadata.is_file())
+                    .map(|metadata| metadata.len()),
+            })
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => print_json_array(&rows),
+        OutputFormat::Plain => {
+            for row in &rows {
+                println!("{}\t{}", row.artifact, row.attachment);
+            }
+        }
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                ui::info("No attachments");
+                return Ok(vec![]);
+            }
+            let mut table = table_with_bold_headers(&["Artifact", "Attachment", "Size"]);
+            for row in &rows {
+                table.add_row(vec![
+                    Cell::new(&row.artifact),
+                    Cell::new(&row.attachment),
+                    Cell::new(
+                        row.size
+                            .map_or_else(|| "missing".to_string(), |size| format!("{size} B")),
+                    ),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(vec![])
+}
+
+/// The ID an `@alias` names; other IDs are unchanged.
+fn canonical_id(config: &Config, id: &str) -> String {
+    Aliases::load(config).expand_or_keep(id)
+}
+
+/// The attachments `id` lists. Only RFCs, ADRs, and work items have any.
+fn attachments_of<'a>(
+    prefixes: &PrefixesConfig,
+    index: &'a ProjectIndex,
+    id: &str,
+) -> DiagnosticResult<&'a [String]> {
+    let not_found = |code, kind: &str| Diagnostic::new(code, format!("{kind} not found: {id}"), id);
+    match ArtifactType::from_id(prefixes, id) {
+        Some(ArtifactType::Rfc) => index
+            .rfcs
+            .iter()
+            .find(|rfc| rfc.rfc.rfc_id == id)
+            .map(|rfc| rfc.rfc.attachments.as_slice())
+            .ok_or_else(|| not_found(DiagnosticCode::E0102RfcNotFound, "RFC")),
+        Some(ArtifactType::Adr) => index
+            .adrs
+            .iter()
+            .find(|adr| adr.meta().id == id)
+            .map(|adr| adr.meta().attachments.as_slice())
+            .ok_or_else(|| not_found(DiagnosticCode::E0302AdrNotFound, "ADR")),
+        Some(ArtifactType::WorkItem) => index
+            .work_items
+            .iter()
+            .find(|work| work.meta().id == id)
+            .map(|work| work.meta().attachments.as_slice())
+            .ok_or_else(|| not_found(DiagnosticCode::E0402WorkNotFound, "Work item")),
+        _ => Err(Diagnostic::new(
+            DiagnosticCode::E0822UnsupportedOperation,
+            format!("Only RFCs, ADRs, and work items take attachments, not '{id}'"),
+            id,
+        )),
+    }
+}
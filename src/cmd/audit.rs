@@ -0,0 +1,311 @@
+//! Command audit log: `govctl audit`.
+//!
+//! With `[audit] enabled`, every write command appends one JSON line to
+//! `gov/audit.log`, whether it succeeds or fails. Unlike operation history
+//! the log is committed with the project; entries are never rewritten, only
+//! dropped once they are older than `[audit] retention_days`.
+
+use crate::OutputFormat;
+use crate::cmd::output::{print_json, print_json_array, table_with_bold_headers};
+use crate::config::Config;
+use crate::diagnostic::{
+    Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult, Diagnostics,
+};
+use crate::identity::Actor;
+use crate::ui;
+use crate::write::{WriteOp, replace_file, with_write_log};
+use chrono::{DateTime, SecondsFormat};
+use comfy_table::Cell;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+#[derive(Serialize, Deserialize)]
+struct AuditEntry {
+    seq: u64,
+    /// RFC 3339 time the command finished.
+    timestamp: String,
+    user: String,
+    /// Actor the command ran as, `human:<name>` or `agent:<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    actor: Option<String>,
+    command: String,
+    /// Paths relative to the project root.
+    #[serde(default)]
+    files: Vec<String>,
+    result: AuditResult,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum AuditResult {
+    Ok,
+    Error,
+}
+
+impl AuditEntry {
+    /// The actor when one was recorded, else the user.
+    fn by(&self) -> &str {
+        self.actor.as_deref().unwrap_or(&self.user)
+    }
+}
+
+impl AuditResult {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Run a write command as `actor` and append its outcome to the audit log.
+/// The command writes with the op it is handed.
+pub fn record(
+    config: &Config,
+    command: &str,
+    actor: Option<&Actor>,
+    op: WriteOp,
+    operation: impl FnOnce(WriteOp) -> DiagnosticResult<Diagnostics>,
+) -> DiagnosticResult<Diagnostics> {
+    let (result, changes) = with_write_log(op, operation);
+    let root = config.project_root();
+    let local_state = root.join(".govctl");
+    let mut files: Vec<String> = Vec::new();
+    for change in changes.files {
+        // Local state is not part of the project, and unchanged files are noise.
+        if change.path.starts_with(&local_state)
+            || std::fs::read(&change.path).ok() == change.before
+        {
+            continue;
+        }
+        let path = relative(root, &change.path);
+        if !files.contains(&path) {
+            files.push(path);
+        }
+    }
+
+    let error = match &result {
+        Ok(diagnostics) => diagnostics
+            .iter()
+            .find(|diag| diag.level == DiagnosticLevel::Error),
+        Err(diag) => Some(diag),
+    };
+    let entry = AuditEntry {
+        seq: 0,
+        timestamp: chrono::Local::now().to_rfc3339_opts(SecondsFormat::Secs, false),
+        user: current_user(),
+        actor: actor.map(|actor| actor.as_str().to_string()),
+        command: command.to_string(),
+        files,
+        result: if error.is_some() {
+            AuditResult::Error
+        } else {
+            AuditResult::Ok
+        },
+        error: error.map(|diag| format!("{}: {}", diag.code.code(), diag.message)),
+    };
+    if let Err(diag) = append(config, entry) {
+        ui::hint(format!(
+            "Command not recorded in audit log: {}",
+            diag.message
+        ));
+    }
+    result
+}
+
+/// List audit entries, newest first.
+pub fn list(
+    config: &Config,
+    limit: Option<usize>,
+    output: OutputFormat,
+) -> DiagnosticResult<Diagnostics> {
+    let mut entries = load_entries(config)?;
+    entries.reverse();
+    entries.truncate(limit.unwrap_or(usize::MAX));
+
+    match output {
+        OutputFormat::Json => print_json_array(&entries),
+        OutputFormat::Plain => {
+            for entry in &entries {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    entry.seq,
+                    entry.timestamp,
+                    entry.by(),
+                    entry.result.as_str(),
+                    entry.command
+                );
+            }
+        }
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                ui::info("No audit entries");
+                return Ok(vec![]);
+            }
+            let mut table =
+                table_with_bold_headers(&["#", "When", "Actor", "Result", "Command", "Files"]);
+            for entry in &entries {
+                table.add_row(vec![
+                    Cell::new(entry.seq.to_string()),
+                    Cell::new(&entry.timestamp),
+                    Cell::new(entry.by()),
+                    Cell::new(entry.result.as_str()),
+                    Cell::new(&entry.command),
+                    Cell::new(entry.files.join("\n")),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(vec![])
+}
+
+/// Show one audit entry in full.
+pub fn show(config: &Config, seq: u64, output: OutputFormat) -> DiagnosticResult<Diagnostics> {
+    let entry = load_entries(config)?
+        .into_iter()
+        .find(|entry| entry.seq == seq)
+        .ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E1502AuditEntryNotFound,
+                format!("Audit entry #{seq} not found (hint: `govctl audit list`)"),
+                display_log(config),
+            )
+        })?;
+
+    if output == OutputFormat::Json {
+        print_json(
+            &entry,
+            DiagnosticCode::E0903UnexpectedError,
+            "Failed to serialize audit entry",
+            display_log(config),
+        )?;
+        return Ok(vec![]);
+    }
+    println!("#{} {}", entry.seq, entry.command);
+    println!("When:   {}", entry.timestamp);
+    println!("User:   {}", entry.user);
+    if let Some(actor) = &entry.actor {
+        println!("Actor:  {actor}");
+    }
+    println!("Result: {}", entry.result.as_str());
+    if let Some(error) = &entry.error {
+        println!("Error:  {error}");
+    }
+    if !entry.files.is_empty() {
+        println!("\nFiles:");
+        for file in &entry.files {
+            println!("  - {file}");
+        }
+    }
+    Ok(vec![])
+}
+
+/// Number the entry, drop expired ones, and write it to the log.
+fn append(config: &Config, mut entry: AuditEntry) -> DiagnosticResult<()> {
+    let path = config.audit_log_path();
+    let mut entries = load_entries(config)?;
+    entry.seq = entries.last().map_or(1, |last| last.seq + 1);
+    let line = to_line(config, &entry)?;
+
+    let kept = entries.len();
+    if config.audit.retention_days > 0 {
+        let cutoff =
+            chrono::Local::now() - chrono::Duration::days(i64::from(config.audit.retention_days));
+        entries.retain(|entry| {
+            DateTime::parse_from_rfc3339(&entry.timestamp).map_or(true, |time| time >= cutoff)
+        });
+    }
+    if entries.len() < kept {
+        let mut body = String::new();
+        for kept in &entries {
+            body.push_str(&to_line(config, kept)?);
+        }
+        body.push_str(&line);
+        // The log is bookkeeping, so the rewrite stays out of the write journal.
+        return replace_file(
+            &path,
+            body.as_bytes(),
+            &config.display_path(&path),
+            config.concurrency.fsync,
+        );
+    }
+
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|err| Diagnostic::io_error("append audit entry", err, display_log(config)))
+}
+
+fn to_line(config: &Config, entry: &AuditEntry) -> DiagnosticResult<String> {
+    serde_json::to_string(entry)
+        .map(|json| format!("{json}\n"))
+        .map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!("Failed to serialize audit entry: {err}"),
+                display_log(config),
+            )
+        })
+}
+
+/// Every entry in the log, oldest first.
+fn load_entries(config: &Config) -> DiagnosticResult<Vec<AuditEntry>> {
+    let body = match std::fs::read_to_string(config.audit_log_path()) {
+        Ok(body) => body,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => {
+            return Err(Diagnostic::io_error(
+                "read audit log",
+                err,
+                display_log(config),
+            ));
+        }
+    };
+    body.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|err| {
+                Diagnostic::new(
+                    DiagnosticCode::E1501AuditLogInvalid,
+                    format!("Invalid audit entry on line {}: {err}", index + 1),
+                    display_log(config),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Who ran the command: the git identity, else the login name.
+fn current_user() -> String {
+    let name = crate::config::git_config_value("user.name");
+    let email = crate::config::git_config_value("user.email");
+    match (name, email) {
+        (Some(name), Some(email)) => format!("{name} <{email}>"),
+        (Some(name), None) => name,
+        (None, Some(email)) => email,
+        (None, None) => std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .unwrap_or_else(|_| "unknown".to_string()),
+    }
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn display_log(config: &Config) -> String {
+    config
+        .display_path(&config.audit_log_path())
+        .display()
+        .to_string()
+}
@@ -6,18 +6,37 @@ use crate::diagnostic::{
 };
 use crate::load::load_project_with_warnings;
 use crate::model::WorkItemStatus;
-use crate::parse::{load_guards_with_warnings, load_releases, load_work_items};
+use crate::parse::{
+    load_guards_with_warnings, load_mappings_with_warnings, load_releases, load_work_items,
+};
 use crate::scan::scan_source_refs;
 use crate::schema::installed_schema_diagnostics;
-use crate::ui;
-use crate::validate::{validate_project, validate_releases};
+use crate::validate::{validate_mappings, validate_project, validate_releases};
 use crate::verification;
+use crate::{Persona, ui};
 
-/// Validate all governed documents
-pub fn check_all(config: &Config) -> DiagnosticResult<Diagnostics> {
-    let (all_diagnostics, summary) = collect_diagnostics(config)?;
+/// Validate all governed documents. `strict_refs` reports refs to
+/// deprecated or superseded artifacts as errors.
+pub fn check_all(
+    config: &Config,
+    strict_refs: bool,
+    persona: Persona,
+) -> DiagnosticResult<Diagnostics> {
+    let (mut all_diagnostics, summary) = {
+        let progress = ui::progress(persona, "Checking", CHECK_PHASES);
+        ui::timed("check", || {
+            collect_diagnostics_with_progress(config, &progress)
+        })?
+    };
+    if strict_refs {
+        for diag in &mut all_diagnostics {
+            if diag.code == DiagnosticCode::W0123RefInactive {
+                diag.level = DiagnosticLevel::Error;
+            }
+        }
+    }
 
-    if summary.project_loaded {
+    if summary.project_loaded && !ui::is_quiet() {
         // Print summary (colorized)
         ui::check_header();
         ui::check_count(summary.rfc_count, "RFCs");
@@ -60,11 +79,21 @@ pub(crate) struct CheckSummary {
     pub refs_found: usize,
 }
 
+/// Phases of `collect_diagnostics`, each one step of check progress.
+const CHECK_PHASES: usize = 6;
+
 /// Collect check diagnostics without printing. Used by read-only views such as
 /// the TUI so diagnostics can be rendered inside the terminal frame. Implements
 /// [[RFC-0007:C-DIAGNOSTICS]] for the read-only cockpit diagnostics model.
 pub(crate) fn collect_diagnostics(
     config: &Config,
+) -> DiagnosticResult<(Diagnostics, CheckSummary)> {
+    collect_diagnostics_with_progress(config, &ui::Progress::hidden())
+}
+
+fn collect_diagnostics_with_progress(
+    config: &Config,
+    progress: &ui::Progress,
 ) -> DiagnosticResult<(Diagnostics, CheckSummary)> {
     let mut all_diagnostics = Vec::new();
     let mut summary = CheckSummary::default();
@@ -88,7 +117,9 @@ pub(crate) fn collect_diagnostics(
     all_diagnostics.extend(crate::cmd::project_support::local_state_gitignore_diagnostics(config));
 
     // Load project (with warnings for parse errors)
-    let load_result = match load_project_with_warnings(config) {
+    let load_result = match timed(progress, "load project", || {
+        load_project_with_warnings(config)
+    }) {
         Ok(result) => result,
         Err(diags) => {
             all_diagnostics.extend(diags);
@@ -101,38 +132,60 @@ pub(crate) fn collect_diagnostics(
     all_diagnostics.extend(load_result.warnings);
 
     // Validate governance artifacts
-    let result = validate_project(&index, config);
+    let result = timed(progress, "validate artifacts", || {
+        validate_project(&index, config)
+    });
     summary.rfc_count = result.rfc_count;
     summary.clause_count = result.clause_count;
     summary.adr_count = result.adr_count;
     summary.work_count = result.work_count;
     all_diagnostics.extend(result.diagnostics);
 
-    match load_guards_with_warnings(config) {
-        Ok(result) => {
-            summary.guard_count = result.items.len();
-            all_diagnostics.extend(result.warnings);
-            let (guards_by_id, guard_diags) = verification::build_guard_index(result.items);
-            all_diagnostics.extend(guard_diags);
-            all_diagnostics.extend(verification::validate_guard_configuration(
-                config,
-                &guards_by_id,
-                &index.work_items,
-            ));
-        }
-        Err(diag) => all_diagnostics.push(diag),
-    }
+    timed(
+        progress,
+        "validate guards",
+        || match load_guards_with_warnings(config) {
+            Ok(result) => {
+                summary.guard_count = result.items.len();
+                all_diagnostics.extend(result.warnings);
+                let (guards_by_id, guard_diags) = verification::build_guard_index(result.items);
+                all_diagnostics.extend(guard_diags);
+                all_diagnostics.extend(verification::validate_guard_configuration(
+                    config,
+                    &guards_by_id,
+                    &index.work_items,
+                ));
+            }
+            Err(diag) => all_diagnostics.push(diag),
+        },
+    );
 
     // Validate releases separately until they are part of the full project index.
-    match load_releases(config) {
-        Ok(releases) => {
-            all_diagnostics.extend(validate_releases(&releases, &index, config));
+    timed(progress, "validate releases", || {
+        match load_releases(config) {
+            Ok(releases) => {
+                all_diagnostics.extend(validate_releases(&releases, &index, config));
+            }
+            Err(diag) => all_diagnostics.push(diag),
         }
-        Err(diag) => all_diagnostics.push(diag),
-    }
+    });
+
+    timed(
+        progress,
+        "validate mappings",
+        || match load_mappings_with_warnings(config) {
+            Ok(result) => {
+                all_diagnostics.extend(result.warnings);
+                all_diagnostics.extend(validate_mappings(&result.items, &index, config));
+            }
+            Err(diag) => all_diagnostics.push(diag),
+        },
+    );
 
     // Scan source code for references (if enabled)
-    let scan_result = scan_source_refs(config, &index);
+    let scan_result = timed(progress, "scan source references", || {
+        scan_source_refs(config, &index)
+    });
     summary.files_scanned = scan_result.files_scanned;
     summary.refs_found = scan_result.refs_found;
     all_diagnostics.extend(scan_result.diagnostics);
@@ -140,6 +193,15 @@ pub(crate) fn collect_diagnostics(
     Ok((all_diagnostics, summary))
 }
 
+/// Run one check phase as a step of `progress`, timing it for `-v` and
+/// `--timings`.
+fn timed<T>(progress: &ui::Progress, phase: &str, run: impl FnOnce() -> T) -> T {
+    progress.step(phase);
+    let result = ui::timed(phase, run);
+    progress.inc();
+    result
+}
+
 /// Fast-path: assert that at least one active work item exists.
 pub fn check_has_active(config: &Config) -> DiagnosticResult<Diagnostics> {
     let items = load_work_items(config)?;
@@ -0,0 +1,176 @@
+//! `govctl clause history`: a clause's text over time.
+//!
+//! Revisions come from the git history of the clause file (following
+//! renames), plus uncommitted changes in the working tree. Commits that left
+//! the text unchanged are skipped; each remaining change prints as a line diff
+//! against the previous text.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::find_clause_toml;
+use crate::ui;
+use crate::write::line_diff;
+use owo_colors::OwoColorize;
+use std::path::Path;
+use std::process::Command;
+
+const RECORD_SEPARATOR: char = '\x1e';
+const FIELD_SEPARATOR: char = '\x1f';
+
+/// One version of the clause text.
+struct Revision {
+    /// `<hash> <date> <author>: <subject>`, or the working-tree marker.
+    label: String,
+    text: String,
+}
+
+/// Print each change to `clause_id`'s text, oldest first; `limit` keeps the
+/// most recent changes.
+pub fn clause_history(
+    config: &Config,
+    clause_id: &str,
+    limit: Option<usize>,
+) -> DiagnosticResult<Diagnostics> {
+    let path = find_clause_toml(config, clause_id).ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0202ClauseNotFound,
+            format!("Clause not found: {clause_id}"),
+            clause_id,
+        )
+    })?;
+    let root = config.project_root();
+    let relative = path.strip_prefix(root).unwrap_or(&path);
+
+    let mut revisions: Vec<Revision> = Vec::new();
+    for (label, committed_path) in commits_touching(root, relative, clause_id)? {
+        let Some(text) = git_show(root, &label, &committed_path).and_then(|c| clause_text(&c))
+        else {
+            continue;
+        };
+        push_if_changed(&mut revisions, label, text);
+    }
+    if let Some(text) = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| clause_text(&content))
+    {
+        push_if_changed(
+            &mut revisions,
+            "working tree (uncommitted)".to_string(),
+            text,
+        );
+    }
+
+    if revisions.is_empty() {
+        ui::info(format!("No recorded history for {clause_id}"));
+        return Ok(vec![]);
+    }
+
+    let skip = limit.map_or(0, |limit| revisions.len().saturating_sub(limit));
+    let colors = ui::stdout_supports_color();
+    for index in skip..revisions.len() {
+        let revision = &revisions[index];
+        let previous = index.checked_sub(1).map(|i| revisions[i].text.as_str());
+        if colors {
+            println!("{}", revision.label.bold());
+        } else {
+            println!("{}", revision.label);
+        }
+        for (tag, line) in line_diff(previous.unwrap_or_default(), &revision.text) {
+            let rendered = format!("{tag} {line}");
+            match tag {
+                '+' if colors => println!("{}", rendered.green()),
+                '-' if colors => println!("{}", rendered.red()),
+                _ => println!("{rendered}"),
+            }
+        }
+        println!();
+    }
+    Ok(vec![])
+}
+
+fn push_if_changed(revisions: &mut Vec<Revision>, label: String, text: String) {
+    if revisions.last().is_none_or(|last| last.text != text) {
+        revisions.push(Revision { label, text });
+    }
+}
+
+/// Commits that touched the clause file, oldest first, as
+/// `(label, path in that commit)`.
+fn commits_touching(
+    root: &Path,
+    relative: &Path,
+    clause_id: &str,
+) -> DiagnosticResult<Vec<(String, String)>> {
+    let format = format!(
+        "--format={RECORD_SEPARATOR}%h{FIELD_SEPARATOR}%ad{FIELD_SEPARATOR}%an{FIELD_SEPARATOR}%s"
+    );
+    let output = Command::new("git")
+        .current_dir(root)
+        .args([
+            "log",
+            "--follow",
+            "--name-only",
+            "--date=short",
+            &format,
+            "--",
+        ])
+        .arg(relative)
+        .output()
+        .map_err(|err| git_unavailable(clause_id, &err.to_string()))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(git_unavailable(clause_id, stderr.trim()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut commits: Vec<(String, String)> = stdout
+        .split(RECORD_SEPARATOR)
+        .filter_map(|record| {
+            let mut lines = record.lines();
+            let header = lines.next()?;
+            let committed_path = lines.find(|line| !line.trim().is_empty())?;
+            let fields: Vec<&str> = header.split(FIELD_SEPARATOR).collect();
+            let [hash, date, author, subject] = fields.as_slice() else {
+                return None;
+            };
+            Some((
+                format!("{hash} {date} {author}: {subject}"),
+                committed_path.to_string(),
+            ))
+        })
+        .collect();
+    commits.reverse();
+    Ok(commits)
+}
+
+fn git_show(root: &Path, label: &str, committed_path: &str) -> Option<String> {
+    let hash = label.split_whitespace().next()?;
+    let output = Command::new("git")
+        .current_dir(root)
+        .args(["show", &format!("{hash}:{committed_path}")])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `content.text` of a clause file, or top-level `text` in the legacy layout.
+fn clause_text(content: &str) -> Option<String> {
+    let value: toml::Value = toml::from_str(content).ok()?;
+    value
+        .get("content")
+        .and_then(|content| content.get("text"))
+        .or_else(|| value.get("text"))
+        .and_then(toml::Value::as_str)
+        .map(str::to_string)
+}
+
+fn git_unavailable(clause_id: &str, reason: &str) -> Diagnostic {
+    Diagnostic::new(
+        DiagnosticCode::E1304HistoryGitUnavailable,
+        format!("Clause history reads git history, which is unavailable: {reason}"),
+        clause_id,
+    )
+}
@@ -0,0 +1,273 @@
+//! `govctl docs man|markdown`: the command reference, generated from the clap
+//! definitions so it cannot drift from the binary.
+
+use crate::Cli;
+use crate::diagnostic::{DiagnosticResult, Diagnostics};
+use crate::ui;
+use crate::write::{WriteOp, create_dir_all, write_file};
+use clap::{Arg, Command, CommandFactory};
+use std::path::Path;
+
+/// One command in the reference, flattened from the command tree.
+struct CommandDoc {
+    /// Full invocation, e.g. `govctl rfc new`.
+    name: String,
+    about: String,
+    usage: String,
+    arguments: Vec<ArgDoc>,
+    options: Vec<ArgDoc>,
+    /// `after_help` text: examples and notes.
+    details: String,
+}
+
+struct ArgDoc {
+    /// `-o, --output <OUTPUT>` or `<ID>`.
+    spec: String,
+    help: String,
+}
+
+/// Print (or write to `out`) a roff man page covering every command.
+pub fn man_page(out: Option<&Path>, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    emit(&render_man(&collect()), out, op)
+}
+
+/// Print (or write to `out`) a Markdown reference covering every command.
+pub fn markdown_reference(out: Option<&Path>, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    emit(&render_markdown(&collect()), out, op)
+}
+
+fn emit(content: &str, out: Option<&Path>, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    match out {
+        Some(path) => {
+            if let Some(parent) = path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            {
+                create_dir_all(parent, op, None)?;
+            }
+            write_file(path, content, op, Some(path))?;
+            if !op.is_preview() {
+                ui::created_path(path);
+            }
+        }
+        None => print!("{content}"),
+    }
+    Ok(vec![])
+}
+
+/// Every visible command, depth first, starting with `govctl` itself.
+fn collect() -> Vec<CommandDoc> {
+    let mut root = Cli::command();
+    root.build();
+    let mut docs = Vec::new();
+    collect_into(&root, "govctl", true, &mut docs);
+    docs
+}
+
+fn collect_into(command: &Command, name: &str, root: bool, docs: &mut Vec<CommandDoc>) {
+    let visible_args = command
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set() && !matches!(arg.get_id().as_str(), "help" | "version"))
+        // Global options are documented once, on the root command.
+        .filter(|arg| root || !arg.is_global_set());
+    let (positionals, options): (Vec<&Arg>, Vec<&Arg>) =
+        visible_args.partition(|arg| arg.is_positional());
+
+    docs.push(CommandDoc {
+        name: name.to_string(),
+        about: command
+            .get_long_about()
+            .or_else(|| command.get_about())
+            .map(ToString::to_string)
+            .unwrap_or_default(),
+        usage: command
+            .clone()
+            .render_usage()
+            .to_string()
+            .trim_start_matches("Usage: ")
+            .to_string(),
+        arguments: positionals.into_iter().map(arg_doc).collect(),
+        options: options.into_iter().map(arg_doc).collect(),
+        details: command
+            .get_after_long_help()
+            .or_else(|| command.get_after_help())
+            .map(|text| text.to_string().trim_end().to_string())
+            .unwrap_or_default(),
+    });
+
+    for sub in command.get_subcommands() {
+        if sub.is_hide_set() || sub.get_name() == "help" {
+            continue;
+        }
+        collect_into(sub, &format!("{name} {}", sub.get_name()), false, docs);
+    }
+}
+
+fn arg_doc(arg: &Arg) -> ArgDoc {
+    let value_name = || {
+        arg.get_value_names()
+            .and_then(|names| names.first())
+            .map(ToString::to_string)
+            .unwrap_or_else(|| arg.get_id().as_str().to_uppercase())
+    };
+    let spec = if arg.is_positional() {
+        format!("<{}>", value_name())
+    } else {
+        let mut flags = Vec::new();
+        if let Some(short) = arg.get_short() {
+            flags.push(format!("-{short}"));
+        }
+        if let Some(long) = arg.get_long() {
+            flags.push(format!("--{long}"));
+        }
+        let mut spec = flags.join(", ");
+        if arg.get_action().takes_values() {
+            spec.push_str(&format!(" <{}>", value_name()));
+        }
+        spec
+    };
+
+    let mut help = arg
+        .get_long_help()
+        .or_else(|| arg.get_help())
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    let possible: Vec<String> = arg
+        .get_possible_values()
+        .iter()
+        .filter(|value| !value.is_hide_set())
+        .map(|value| value.get_name().to_string())
+        .collect();
+    if !possible.is_empty() {
+        help.push_str(&format!(" [possible values: {}]", possible.join(", ")));
+    }
+    let defaults: Vec<String> = arg
+        .get_default_values()
+        .iter()
+        .map(|value| value.to_string_lossy().into_owned())
+        .collect();
+    if !defaults.is_empty() && arg.get_action().takes_values() {
+        help.push_str(&format!(" [default: {}]", defaults.join(", ")));
+    }
+    ArgDoc {
+        spec,
+        help: help.trim().to_string(),
+    }
+}
+
+fn render_markdown(docs: &[CommandDoc]) -> String {
+    let mut out = String::from("# govctl command reference\n\n");
+    out.push_str(&format!(
+        "Generated by `govctl docs markdown` (govctl {}).\n",
+        env!("CARGO_PKG_VERSION")
+    ));
+    for doc in docs {
+        let level = if doc.name == "govctl" { "##" } else { "###" };
+        out.push_str(&format!("\n{level} `{}`\n\n", doc.name));
+        if !doc.about.is_empty() {
+            out.push_str(&format!("{}\n\n", doc.about));
+        }
+        out.push_str(&format!("```text\n{}\n```\n", doc.usage));
+        for (title, args) in [("Arguments", &doc.arguments), ("Options", &doc.options)] {
+            if args.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n**{title}:**\n\n"));
+            for arg in args {
+                if arg.help.is_empty() {
+                    out.push_str(&format!("- `{}`\n", arg.spec));
+                } else {
+                    out.push_str(&format!("- `{}`: {}\n", arg.spec, arg.help));
+                }
+            }
+        }
+        if !doc.details.is_empty() {
+            out.push_str(&format!("\n```text\n{}\n```\n", doc.details));
+        }
+    }
+    out
+}
+
+fn render_man(docs: &[CommandDoc]) -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    let mut out = format!(".TH GOVCTL 1 \"\" \"govctl {version}\" \"User Commands\"\n");
+    let Some((root, commands)) = docs.split_first() else {
+        return out;
+    };
+    out.push_str(&format!(
+        ".SH NAME\ngovctl \\- {}\n.SH SYNOPSIS\n\\fB{}\\fR\n",
+        roff_escape(&root.about),
+        roff_escape(&root.usage)
+    ));
+    if !root.options.is_empty() {
+        out.push_str(".SH OPTIONS\n");
+        man_args(&mut out, &root.options);
+    }
+    if !root.details.is_empty() {
+        out.push_str(&format!(
+            ".SH NOTES\n.nf\n{}\n.fi\n",
+            roff_lines(&root.details)
+        ));
+    }
+    out.push_str(".SH COMMANDS\n");
+    for doc in commands {
+        out.push_str(&format!(".SS \"{}\"\n", roff_escape(&doc.name)));
+        if !doc.about.is_empty() {
+            out.push_str(&format!("{}\n", roff_lines(&doc.about)));
+        }
+        out.push_str(&format!(
+            ".PP\n.nf\n\\fB{}\\fR\n.fi\n",
+            roff_escape(&doc.usage)
+        ));
+        man_args(&mut out, &doc.arguments);
+        man_args(&mut out, &doc.options);
+        if !doc.details.is_empty() {
+            out.push_str(&format!(".PP\n.nf\n{}\n.fi\n", roff_lines(&doc.details)));
+        }
+    }
+    out.push_str(&format!(".SH VERSION\n{version}\n"));
+    out
+}
+
+fn man_args(out: &mut String, args: &[ArgDoc]) {
+    for arg in args {
+        out.push_str(&format!(
+            ".TP\n\\fB{}\\fR\n{}\n",
+            roff_escape(&arg.spec),
+            roff_lines(&arg.help)
+        ));
+    }
+}
+
+/// Escape text for roff: backslashes and hyphens.
+fn roff_escape(text: &str) -> String {
+    text.replace('\\', "\\e").replace('-', "\\-")
+}
+
+/// [`roff_escape`] each line, and guard lines roff would read as requests.
+fn roff_lines(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let escaped = roff_escape(line);
+            if escaped.starts_with('.') || escaped.starts_with('\'') {
+                format!("\\&{escaped}")
+            } else {
+                escaped
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roff_lines_escapes_control_characters() {
+        assert_eq!(
+            roff_lines(".hidden\n--flag C:\\path"),
+            "\\&.hidden\n\\-\\-flag C:\\epath"
+        );
+    }
+}
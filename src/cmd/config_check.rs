@@ -0,0 +1,337 @@
+//! Config validation command.
+//!
+//! `Config::load` silently ignores unknown keys and stops at the first type
+//! error, so config mistakes tend to surface later as confusing runtime
+//! behavior. `config check` validates the raw TOML against the bundled
+//! `config.schema.json`, then checks settings that conflict with each other,
+//! and reports every problem at its line and column.
+
+use crate::config::Config;
+use crate::diagnostic::{
+    Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult, Diagnostics, Span, pointer_span,
+};
+use crate::schema::CONFIG_SCHEMA;
+use crate::ui;
+use globset::Glob;
+use jsonschema::error::ValidationErrorKind;
+use regex::Regex;
+use serde_json::Value;
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+use toml::de::{DeTable, DeValue};
+
+/// Validate the config file at `config_path`.
+pub fn check_config(config_path: &Path) -> DiagnosticResult<Diagnostics> {
+    let project_root = config_path
+        .parent()
+        .and_then(Path::parent)
+        .unwrap_or_else(|| Path::new("."));
+    let display = config_path
+        .strip_prefix(project_root)
+        .unwrap_or(config_path)
+        .display()
+        .to_string();
+
+    if !config_path.exists() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0502PathNotFound,
+            format!("{display} not found; run `govctl init` first"),
+            display,
+        ));
+    }
+    let content = std::fs::read_to_string(config_path)
+        .map_err(|err| Diagnostic::io_error("read config", err, display.clone()))?;
+
+    let diagnostics = check_config_content(&display, &content, project_root)?;
+    if diagnostics.is_empty() {
+        ui::success(format!("{display} is valid"));
+    }
+    Ok(diagnostics)
+}
+
+/// Validate config text shown to the user as `display`, with relative paths
+/// resolved against `project_root`.
+pub(crate) fn check_config_content(
+    display: &str,
+    content: &str,
+    project_root: &Path,
+) -> DiagnosticResult<Diagnostics> {
+    let source = ConfigSource { display, content };
+
+    let document = DeTable::parse(content).map_err(|err| {
+        source.diagnostic(
+            DiagnosticCode::E0501ConfigInvalid,
+            format!("Failed to parse config: {}", err.message().trim_end()),
+            err.span(),
+        )
+    })?;
+    let document = document.get_ref();
+    let schema: Value = serde_json::from_str(CONFIG_SCHEMA.content).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Invalid bundled config schema: {err}"),
+            CONFIG_SCHEMA.filename,
+        )
+    })?;
+
+    let mut diagnostics = Vec::new();
+    check_keys(&source, document, &schema, "", &mut diagnostics);
+    check_schema(&source, document, &schema, &mut diagnostics)?;
+
+    let has_errors = diagnostics
+        .iter()
+        .any(|diag| diag.level == DiagnosticLevel::Error);
+    if !has_errors && let Ok(config) = toml::from_str::<Config>(content) {
+        check_settings(&source, document, &config, project_root, &mut diagnostics);
+    }
+    Ok(diagnostics)
+}
+
+/// Raw config text, used to turn byte spans into line and column.
+struct ConfigSource<'a> {
+    display: &'a str,
+    content: &'a str,
+}
+
+impl ConfigSource<'_> {
+    fn diagnostic(
+        &self,
+        code: DiagnosticCode,
+        message: impl Into<String>,
+        span: Option<Range<usize>>,
+    ) -> Diagnostic {
+        Diagnostic::new(code, message, self.display)
+            .with_span(span.and_then(|span| Span::at_offset(self.content, span.start)))
+    }
+}
+
+/// Report keys the schema does not know, and keys it marks deprecated.
+fn check_keys(
+    source: &ConfigSource<'_>,
+    table: &DeTable<'_>,
+    schema: &Value,
+    prefix: &str,
+    diagnostics: &mut Diagnostics,
+) {
+    let properties = schema.get("properties").and_then(Value::as_object);
+    for (key, value) in table {
+        let name: &str = key.get_ref();
+        let path = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{prefix}.{name}")
+        };
+
+        match properties.and_then(|properties| properties.get(name)) {
+            None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                let known = properties
+                    .map(|properties| properties.keys().cloned().collect::<Vec<_>>().join(", "))
+                    .unwrap_or_default();
+                diagnostics.push(source.diagnostic(
+                    DiagnosticCode::W0114ConfigUnknownKey,
+                    format!("Unknown config key '{path}' is ignored (expected one of: {known})"),
+                    Some(key.span()),
+                ));
+            }
+            None => {}
+            Some(property) if property.get("deprecated") == Some(&Value::Bool(true)) => {
+                let reason = property
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .unwrap_or("it has no effect");
+                diagnostics.push(source.diagnostic(
+                    DiagnosticCode::W0115ConfigDeprecatedKey,
+                    format!("Config key '{path}' is deprecated: {reason}"),
+                    Some(key.span()),
+                ));
+            }
+            Some(property) => {
+                if let DeValue::Table(nested) = value.get_ref() {
+                    check_keys(source, nested, property, &path, diagnostics);
+                }
+            }
+        }
+    }
+}
+
+/// Report type and value violations. Unknown keys are reported by [`check_keys`].
+fn check_schema(
+    source: &ConfigSource<'_>,
+    document: &DeTable<'_>,
+    schema: &Value,
+    diagnostics: &mut Diagnostics,
+) -> DiagnosticResult<()> {
+    let validator = jsonschema::validator_for(schema).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Failed to compile bundled config schema: {err}"),
+            CONFIG_SCHEMA.filename,
+        )
+    })?;
+    let value = toml::from_str::<toml::Value>(source.content)
+        .ok()
+        .and_then(|value| serde_json::to_value(value).ok())
+        .unwrap_or(Value::Null);
+
+    for error in validator.iter_errors(&value) {
+        if matches!(
+            error.kind(),
+            ValidationErrorKind::AdditionalProperties { .. }
+        ) {
+            continue;
+        }
+        let pointer = error.instance_path().as_str();
+        let key = pointer
+            .trim_start_matches('/')
+            .replace('/', ".")
+            .replace("~1", "/")
+            .replace("~0", "~");
+        let message = if key.is_empty() {
+            error.to_string()
+        } else {
+            format!("{key}: {error}")
+        };
+        diagnostics.push(source.diagnostic(
+            DiagnosticCode::E0507ConfigSchemaInvalid,
+            message,
+            pointer_span(document, pointer),
+        ));
+    }
+    Ok(())
+}
+
+/// Report settings that parse fine but conflict or have no effect.
+fn check_settings(
+    source: &ConfigSource<'_>,
+    document: &DeTable<'_>,
+    config: &Config,
+    project_root: &Path,
+    diagnostics: &mut Diagnostics,
+) {
+    let mut conflict = |pointer: &str, message: String| {
+        diagnostics.push(source.diagnostic(
+            DiagnosticCode::E0504PathConflict,
+            message,
+            pointer_span(document, pointer),
+        ));
+    };
+    let gov_root = normalize(&project_root.join("gov"));
+    let docs_output = normalize(&project_root.join(&config.paths.docs_output));
+    let agent_dir = normalize(&project_root.join(&config.paths.agent_dir));
+    for (key, path) in [("docs_output", &docs_output), ("agent_dir", &agent_dir)] {
+        if overlaps(path, &gov_root) {
+            conflict(
+                &format!("/paths/{key}"),
+                format!(
+                    "paths.{key} overlaps the governance root gov/; generated files would mix with governed sources"
+                ),
+            );
+        }
+    }
+    if overlaps(&docs_output, &agent_dir) {
+        conflict(
+            "/paths/agent_dir",
+            "paths.agent_dir overlaps paths.docs_output; rendering and init-skills would write into the same tree".to_string(),
+        );
+    }
+
+    let mut push = |code: DiagnosticCode, pointer: &str, message: String| {
+        diagnostics.push(source.diagnostic(code, message, pointer_span(document, pointer)));
+    };
+
+    let latest_schema = crate::cmd::migrate::CURRENT_SCHEMA_VERSION;
+    if config.schema.version > latest_schema {
+        push(
+            DiagnosticCode::E0501ConfigInvalid,
+            "/schema/version",
+            format!(
+                "schema.version {} is newer than this govctl supports ({latest_schema}); upgrade govctl",
+                config.schema.version
+            ),
+        );
+    } else if config.schema.version < latest_schema {
+        push(
+            DiagnosticCode::W0110SchemaOutdated,
+            "/schema/version",
+            format!(
+                "schema.version {} is outdated (latest: {latest_schema}). Run `govctl migrate` to upgrade.",
+                config.schema.version
+            ),
+        );
+    }
+
+    // Prefixes `Config::load` would reject; the schema only checks their
+    // shape, not reserved or shared prefixes.
+    for (pointer, checked) in [
+        ("/prefixes", config.prefixes.validate()),
+        ("/work_item/id_prefix", config.work_item.validate()),
+    ] {
+        if let Err(err) = checked {
+            push(err.code, pointer, err.message);
+        }
+    }
+
+    match Regex::new(&config.source_scan.pattern) {
+        Ok(pattern) if pattern.captures_len() < 2 => push(
+            DiagnosticCode::E0501ConfigInvalid,
+            "/source_scan/pattern",
+            "source_scan.pattern needs a capture group for the artifact ID".to_string(),
+        ),
+        Ok(_) => {}
+        Err(err) => push(
+            DiagnosticCode::E0501ConfigInvalid,
+            "/source_scan/pattern",
+            format!("Invalid source_scan.pattern regex: {err}"),
+        ),
+    }
+    for (label, patterns) in [
+        ("include", &config.source_scan.include),
+        ("exclude", &config.source_scan.exclude),
+    ] {
+        for (index, pattern) in patterns.iter().enumerate() {
+            if let Err(err) = Glob::new(pattern) {
+                push(
+                    DiagnosticCode::E0501ConfigInvalid,
+                    &format!("/source_scan/{label}/{index}"),
+                    format!("Invalid source_scan.{label} glob '{pattern}': {err}"),
+                );
+            }
+        }
+    }
+    if config.source_scan.enabled && config.source_scan.include.is_empty() {
+        push(
+            DiagnosticCode::W0116ConfigIneffectiveSetting,
+            "/source_scan/include",
+            "source_scan.enabled is true but source_scan.include is empty; nothing will be scanned"
+                .to_string(),
+        );
+    }
+
+    if !config.verification.enabled && !config.verification.default_guards.is_empty() {
+        push(
+            DiagnosticCode::W0116ConfigIneffectiveSetting,
+            "/verification/default_guards",
+            "verification.default_guards has no effect while verification.enabled is false"
+                .to_string(),
+        );
+    }
+}
+
+/// Lexically resolve `.` and `..` so overlap checks work on unresolved paths.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+fn overlaps(a: &Path, b: &Path) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
@@ -0,0 +1,291 @@
+//! Config editing commands: `config get`, `config set`, and `config list`.
+//!
+//! `set` edits the TOML document with `toml_edit`, so comments and layout
+//! survive. The edited text must pass the same checks as `config check`
+//! before it is written.
+
+use crate::OutputFormat;
+use crate::cmd::config_check::check_config_content;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::{Config, ConfigLayers, LOCAL_CONFIG_FILE_NAME, Setting, parse_setting_value};
+use crate::diagnostic::{
+    Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult, Diagnostics,
+};
+use crate::ui;
+use crate::write::{WriteOp, write_file};
+use comfy_table::Cell;
+use serde::Serialize;
+use std::path::PathBuf;
+use toml::Value;
+use toml_edit::{DocumentMut, Item, Table, TomlError};
+
+#[derive(Serialize)]
+struct ListEntry<'a> {
+    key: String,
+    #[serde(rename = "type")]
+    kind: &'a str,
+    value: &'a Value,
+}
+
+/// Print the effective value of `key`: strings as-is, other values as TOML.
+pub fn config_get(config: &Config, key: &str) -> DiagnosticResult<Diagnostics> {
+    let layers = ConfigLayers::load(&config_path(config))?;
+    match known_setting(&layers, key)?.value {
+        Value::String(value) => println!("{value}"),
+        value => println!("{value}"),
+    }
+    Ok(vec![])
+}
+
+/// List every known setting with its type and effective value.
+pub fn config_list(config: &Config, output: OutputFormat) -> DiagnosticResult<Diagnostics> {
+    let layers = ConfigLayers::load(&config_path(config))?;
+    let entries: Vec<ListEntry> = layers
+        .settings()
+        .map(|setting| ListEntry {
+            key: format!("{}.{}", setting.section, setting.key),
+            kind: setting.value.type_str(),
+            value: setting.value,
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => print_json_array(&entries),
+        OutputFormat::Plain => {
+            for entry in &entries {
+                println!("{} = {}", entry.key, entry.value);
+            }
+        }
+        OutputFormat::Table => {
+            let mut table = table_with_bold_headers(&["Setting", "Type", "Value"]);
+            for entry in &entries {
+                table.add_row(vec![
+                    Cell::new(&entry.key),
+                    Cell::new(entry.kind),
+                    Cell::new(entry.value.to_string()),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(vec![])
+}
+
+/// Set `key` in `gov/config.toml`, or in `gov/config.local.toml` with `local`.
+///
+/// The value is parsed as the setting's type; lists are comma-separated.
+pub fn config_set(
+    config: &Config,
+    key: &str,
+    raw: &str,
+    local: bool,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let project_path = config_path(config);
+    let layers = ConfigLayers::load(&project_path)?;
+    let setting = known_setting(&layers, key)?;
+    if local && setting.section == "schema" {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0501ConfigInvalid,
+            format!("{key} describes the governed tree and can only be set in config.toml"),
+            key,
+        ));
+    }
+    let value = parse_setting_value(raw, setting.value).ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0501ConfigInvalid,
+            format!(
+                "Invalid value for {key}: expected {}, got '{raw}'",
+                setting.value.type_str()
+            ),
+            key,
+        )
+    })?;
+
+    let path = if local {
+        project_path.with_file_name(LOCAL_CONFIG_FILE_NAME)
+    } else {
+        project_path
+    };
+    let display = config.display_path(&path);
+    let display_str = display.display().to_string();
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if local && err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(Diagnostic::io_error("read config", err, display_str)),
+    };
+
+    let updated = set_value(&content, &display_str, setting.section, setting.key, &value)?;
+    if updated == content {
+        ui::info(format!("{key} is already {value} in {display_str}"));
+        return Ok(vec![]);
+    }
+    if let Some(error) = check_config_content(&display_str, &updated, config.project_root())?
+        .into_iter()
+        .find(|diag| diag.level == DiagnosticLevel::Error)
+    {
+        return Err(error);
+    }
+    check_path_setting(config, key, setting.section, &value)?;
+
+    write_file(&path, &updated, op, Some(&display))?;
+    if !op.is_preview() {
+        ui::success(format!("Set {key} = {value} in {display_str}"));
+    }
+    Ok(vec![])
+}
+
+fn config_path(config: &Config) -> PathBuf {
+    config.gov_root.join("config.toml")
+}
+
+fn known_setting<'a>(layers: &'a ConfigLayers, key: &str) -> DiagnosticResult<Setting<'a>> {
+    key.split_once('.')
+        .and_then(|(section, name)| layers.setting(section, name))
+        .ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E0508ConfigKeyUnknown,
+                format!(
+                    "Unknown config key '{key}'. Run `govctl config list` to see the available keys."
+                ),
+                key,
+            )
+        })
+}
+
+/// Output paths must not name a file, and their parent directory must exist.
+fn check_path_setting(
+    config: &Config,
+    key: &str,
+    section: &str,
+    value: &Value,
+) -> DiagnosticResult<()> {
+    let (true, Some(raw)) = (section == "paths", value.as_str()) else {
+        return Ok(());
+    };
+    let path = config.project_root().join(raw);
+    if path.is_file() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0504PathConflict,
+            format!("{key} must name a directory, but {raw} is a file"),
+            key,
+        ));
+    }
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() && !parent.is_dir() => Err(Diagnostic::new(
+            DiagnosticCode::E0502PathNotFound,
+            format!(
+                "{key}: parent directory {} does not exist",
+                config.display_path(parent).display()
+            ),
+            key,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Set `section.key` to `value` in TOML `content` with `toml_edit`, keeping
+/// comments and layout. A replaced value keeps its surrounding whitespace and
+/// trailing comment; a missing key goes after the last key of its `[section]`;
+/// a missing section is appended.
+pub(crate) fn set_value(
+    content: &str,
+    display: &str,
+    section: &str,
+    key: &str,
+    value: &Value,
+) -> DiagnosticResult<String> {
+    let invalid =
+        |message: String| Diagnostic::new(DiagnosticCode::E0501ConfigInvalid, message, display);
+    let mut document: DocumentMut = content
+        .parse()
+        .map_err(|err: TomlError| invalid(format!("Failed to parse config: {}", err.message())))?;
+    let mut literal: toml_edit::Value = value
+        .to_string()
+        .parse()
+        .map_err(|err: TomlError| invalid(format!("Invalid value for {section}.{key}: {err}")))?;
+
+    if !document.contains_key(section) {
+        let mut table = Table::new();
+        if !document.is_empty() {
+            table.decor_mut().set_prefix("\n");
+        }
+        document.insert(section, Item::Table(table));
+    }
+    let table = document
+        .get_mut(section)
+        .and_then(Item::as_table_like_mut)
+        .ok_or_else(|| {
+            invalid(format!(
+                "[{section}] is not a table in {display}; edit it by hand"
+            ))
+        })?;
+
+    match table.get_mut(key) {
+        Some(Item::Value(current)) => {
+            *literal.decor_mut() = current.decor().clone();
+            *current = literal;
+        }
+        Some(_) => {
+            return Err(invalid(format!(
+                "{section}.{key} is not a plain value in {display}; edit it by hand"
+            )));
+        }
+        None => {
+            table.insert(key, Item::Value(literal.decorated(" ", "")));
+        }
+    }
+
+    let mut updated = document.to_string();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(content: &str, section: &str, key: &str, value: Value) -> DiagnosticResult<String> {
+        set_value(content, "config.toml", section, key, &value)
+    }
+
+    #[test]
+    fn replaces_existing_value_and_keeps_comments() -> DiagnosticResult<()> {
+        let content = "[paths]\n# where docs go\ndocs_output = \"docs\" # trailing\n";
+        assert_eq!(
+            set(content, "paths", "docs_output", Value::from("site"))?,
+            "[paths]\n# where docs go\ndocs_output = \"site\" # trailing\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn adds_missing_key_after_last_line_of_section() -> DiagnosticResult<()> {
+        let content = "[project]\nname = \"demo\"\n\n# [concurrency]\n# lock_timeout_secs = 30\n\n[schema]\nversion = 3\n";
+        assert_eq!(
+            set(content, "project", "default_owner", Value::from("@me"))?,
+            "[project]\nname = \"demo\"\ndefault_owner = \"@me\"\n\n# [concurrency]\n# lock_timeout_secs = 30\n\n[schema]\nversion = 3\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn appends_missing_section() -> DiagnosticResult<()> {
+        assert_eq!(
+            set(
+                "[project]\nname = \"demo\"",
+                "concurrency",
+                "lock_timeout_secs",
+                Value::Integer(5)
+            )?,
+            "[project]\nname = \"demo\"\n\n[concurrency]\nlock_timeout_secs = 5\n"
+        );
+        assert_eq!(
+            set("", "project", "default_owner", Value::from("@me"))?,
+            "[project]\ndefault_owner = \"@me\"\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+//! Config inspection command.
+//!
+//! `config show` lists the settings that differ from the built-in defaults
+//! and which layer set each one: `gov/config.toml`, the per-user
+//! `gov/config.local.toml`, or a `GOVCTL_*` environment variable.
+//! `--resolved` lists every effective setting, defaults included.
+
+use crate::OutputFormat;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::{ConfigLayers, SettingSource};
+use crate::diagnostic::{DiagnosticResult, Diagnostics};
+use crate::ui;
+use comfy_table::Cell;
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Serialize)]
+struct SettingEntry<'a> {
+    key: String,
+    value: &'a toml::Value,
+    source: String,
+}
+
+/// Show the config layered over `config_path`.
+pub fn show_config(
+    config_path: &Path,
+    resolved: bool,
+    output: OutputFormat,
+) -> DiagnosticResult<Diagnostics> {
+    let layers = ConfigLayers::load(config_path)?;
+    let entries: Vec<SettingEntry> = layers
+        .settings()
+        .filter(|setting| resolved || *setting.source != SettingSource::Default)
+        .map(|setting| SettingEntry {
+            key: format!("{}.{}", setting.section, setting.key),
+            value: setting.value,
+            source: setting.source.to_string(),
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => print_json_array(&entries),
+        OutputFormat::Plain => {
+            for entry in &entries {
+                println!("{} = {}\t# {}", entry.key, entry.value, entry.source);
+            }
+        }
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                ui::info("All settings use their defaults; see them with --resolved");
+                return Ok(vec![]);
+            }
+            let mut table = table_with_bold_headers(&["Setting", "Value", "Source"]);
+            for entry in &entries {
+                table.add_row(vec![
+                    Cell::new(&entry.key),
+                    Cell::new(entry.value.to_string()),
+                    Cell::new(&entry.source),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(vec![])
+}
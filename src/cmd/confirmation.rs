@@ -1,7 +1,25 @@
-use crate::diagnostic::{Diagnostic, DiagnosticResult};
+//! `[y/N]` confirmation for destructive commands.
+//!
+//! `--force` skips one command's prompt; `--yes` or `GOVCTL_ASSUME_YES`
+//! answers every prompt, by planning the command as if `--force` were given.
+//! Without either, a prompt fails instead of waiting when stdin is not a
+//! terminal, so agents and CI never hang on it.
+
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::ui;
 use crate::write::WriteOp;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+
+/// Whether to answer every confirmation prompt with yes: `--yes` is given or
+/// `GOVCTL_ASSUME_YES` is set to a true value.
+pub(crate) fn assume_yes(yes: bool) -> bool {
+    yes || std::env::var("GOVCTL_ASSUME_YES").is_ok_and(|value| {
+        matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes"
+        )
+    })
+}
 
 pub(crate) fn confirm_destructive_action(
     force: bool,
@@ -12,6 +30,15 @@ pub(crate) fn confirm_destructive_action(
     if force || op.is_preview() {
         return Ok(true);
     }
+    if !io::stdin().is_terminal() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0824InteractiveUnavailable,
+            format!(
+                "Cannot ask \"{prompt}\": stdin is not a terminal; pass --force or --yes (or set GOVCTL_ASSUME_YES=1)"
+            ),
+            "stdin",
+        ));
+    }
 
     print!("{prompt} [y/N] ");
     io::stdout()
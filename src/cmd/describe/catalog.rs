@@ -47,6 +47,48 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             "govctl init",
             &[],
         ),
+        command(
+            "init --preset",
+            "Initialize a project from a preset scaffold",
+            "To start with default guards, editor settings, or agent assets already set up.",
+            "govctl init --preset agents",
+            &["Use --interactive to tailor the preset's layout, ID scheme, and guards"],
+        ),
+        command(
+            "init --from",
+            "Initialize a project from a packed template",
+            "To bootstrap a repository with a shared config, artifact templates, guards, and RFC-0000.",
+            "govctl init --from baseline.tar.zst",
+            &["Template must be produced by govctl template pack"],
+        ),
+        command(
+            "template pack",
+            "Pack this project's governance baseline into a template archive",
+            "To share config, artifact templates, guards, and RFC-0000 with other repositories.",
+            "govctl template pack --out baseline.tar.zst",
+            INIT_REQUIRED,
+        ),
+        command(
+            "export",
+            "Export artifact IDs for other repositories to reference",
+            "To publish this project's RFCs, clauses, ADRs, and work items so service repos can validate `name:ID` refs without a checkout.",
+            "govctl export --out platform-refs.json",
+            INIT_REQUIRED,
+        ),
+        command(
+            "publish confluence",
+            "Push rendered RFCs and ADRs to a Confluence space",
+            "When specs must live in Confluence. Updates existing pages in place and labels them as generated; credentials come from CONFLUENCE_TOKEN.",
+            "govctl publish confluence RFC-0001",
+            INIT_REQUIRED,
+        ),
+        command(
+            "sync jira",
+            "Pull linked Jira issue status into work items",
+            "To keep work items and Jira in step. Records new statuses as notes and warns (W0124) where the two disagree; credentials come from JIRA_TOKEN.",
+            "govctl sync jira",
+            INIT_REQUIRED,
+        ),
         command(
             "init-skills",
             "Install agent skills and agents into the project",
@@ -54,6 +96,13 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             "govctl init-skills",
             INIT_REQUIRED,
         ),
+        command(
+            "init-skills --check",
+            "Detect drift between installed agent assets and this govctl version",
+            "In CI, or before overwriting local edits. Add --diff to see the changes and --only to pick skills or agents.",
+            "govctl init-skills --check --diff",
+            INIT_REQUIRED,
+        ),
         command(
             "status",
             "Show summary counts of all artifacts",
@@ -68,6 +117,34 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             "govctl check",
             INIT_REQUIRED,
         ),
+        command(
+            "check --workspace",
+            "Validate every workspace member and cross-project references",
+            "In a monorepo with govctl-workspace.toml, before committing changes that span services.",
+            "govctl check --workspace",
+            INIT_REQUIRED,
+        ),
+        command(
+            "config check",
+            "Validate gov/config.toml with line/column diagnostics",
+            "After editing config, or when govctl behaves unexpectedly. Reports unknown, deprecated, mistyped, and conflicting settings.",
+            "govctl config check",
+            INIT_REQUIRED,
+        ),
+        command(
+            "config show",
+            "Show config settings and the layer each comes from",
+            "To see the effective config after gov/config.local.toml and GOVCTL_* overrides. Use --resolved to include defaults.",
+            "govctl config show --resolved",
+            INIT_REQUIRED,
+        ),
+        command(
+            "config set",
+            "Change a config setting, keeping comments",
+            "Instead of hand-editing gov/config.toml. Values are type-checked and validated before writing; --local writes gov/config.local.toml.",
+            "govctl config set concurrency.lock_timeout_secs 60",
+            INIT_REQUIRED,
+        ),
         command(
             "verify",
             "Run reusable verification guards",
@@ -75,6 +152,62 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             "govctl verify --work WI-2026-01-18-001",
             INIT_REQUIRED,
         ),
+        command(
+            "edit --editor",
+            "Edit a whole artifact in $VISUAL/$EDITOR with validation on save",
+            "For humans making several changes to one artifact at once. Agents should prefer field-level get/set/add/remove.",
+            "govctl edit RFC-0001 --editor",
+            INIT_REQUIRED,
+        ),
+        command(
+            "apply",
+            "Run a YAML list of commands as one transaction under a single lock",
+            "For multi-step edits that must land together. Every step is checked first; if any step fails, every file the script wrote is restored.",
+            "govctl apply ops.yaml",
+            INIT_REQUIRED,
+        ),
+        command(
+            "restore",
+            "Restore a deleted clause or work item from gov/.trash",
+            "When a clause or queued work item was deleted by mistake. Returns it to its original path and section.",
+            "govctl restore RFC-0001:C-SCOPE",
+            INIT_REQUIRED,
+        ),
+        command(
+            "undo",
+            "Revert the most recent write command",
+            "To back out a mistaken edit, creation, or lifecycle change. Refuses if the files changed since; --force overwrites.",
+            "govctl undo",
+            INIT_REQUIRED,
+        ),
+        command(
+            "history",
+            "List recent write commands that can be undone",
+            "To see what `govctl undo` would revert next.",
+            "govctl history -n 5",
+            INIT_REQUIRED,
+        ),
+        command(
+            "supersede chain",
+            "Show the supersession chain an RFC, clause, or ADR belongs to",
+            "To find what currently replaces an artifact before citing or referencing it.",
+            "govctl supersede chain RFC-0001",
+            INIT_REQUIRED,
+        ),
+        command(
+            "anchor list",
+            "List clause anchors and the clauses they resolve to",
+            "To find the anchor to cite for a clause, or the clause behind a `[[<ANCHOR>]]` reference.",
+            "govctl anchor list",
+            INIT_REQUIRED,
+        ),
+        command(
+            "scan coverage",
+            "Count `Implements [[...]]` annotations per normative clause",
+            "To find normative clauses that no source code claims to implement; -o json -W gates CI.",
+            "govctl scan coverage -o json",
+            INIT_REQUIRED,
+        ),
         command(
             "search",
             "Search governed artifacts across the project",
@@ -159,6 +292,16 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             "govctl work list",
             INIT_REQUIRED,
         ),
+        command(
+            "work link",
+            "Link a work item to a GitHub issue, pull request, or Jira issue",
+            "To track which issues a task closes. 'govctl work list --github' shows GitHub state; 'govctl sync jira' pulls Jira status.",
+            "govctl work link WI-2026-01-18-001 --github acme/api#123",
+            &[
+                "Work item must exist",
+                "Link must be owner/repo#number or a Jira key like PROJ-123",
+            ],
+        ),
         command(
             "rfc new",
             "Create a new RFC (specification document)",
@@ -194,12 +337,36 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             "govctl rfc finalize RFC-0001 normative",
             &["RFC must be in draft status"],
         ),
+        command(
+            "rfc approve",
+            "Record an approval of an RFC",
+            "Before finalizing when [owners] requires owner approval or an rfc_quorum.",
+            "govctl rfc approve RFC-0001 --as @alice",
+            &[
+                "RFC must not be deprecated",
+                "Approver must be a member listed in [owners]",
+            ],
+        ),
+        command(
+            "rfc freeze / rfc unfreeze",
+            "Freeze an RFC for review, or lift the freeze",
+            "To hold an RFC and its clauses still during a review window before finalizing.",
+            "govctl rfc freeze RFC-0001",
+            &[
+                "RFC must exist",
+                "Frozen RFCs refuse all writes except approvals",
+            ],
+        ),
         command(
             "rfc advance",
             "Advance RFC phase (spec → impl → test → stable)",
             "After completing work for current phase. Phase discipline ensures proper workflow.",
             "govctl rfc advance RFC-0001 impl",
-            &["RFC should be normative", "Current phase work complete"],
+            &[
+                "RFC should be normative",
+                "Current phase work complete",
+                "[gates] checklist for the target phase passes (or --force)",
+            ],
         ),
         command(
             "work move",
@@ -209,6 +376,38 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             &[
                 "Work item must exist",
                 "For 'done': acceptance criteria required",
+                "To reopen (done → active, cancelled → queue): --reopen --reason",
+            ],
+        ),
+        command(
+            "work prioritize",
+            "Suggest an ordering for queued work items with reasons",
+            "To decide what to pick up next. Add --apply to write ranks to each item's priority field.",
+            "govctl work prioritize --suggest",
+            &["--suggest is required"],
+        ),
+        command(
+            "work rank",
+            "Move a queued work item before or after another",
+            "To set the queue order by hand. The queue is renumbered in each item's priority field.",
+            "govctl work rank WI-2026-01-18-002 --before WI-2026-01-18-001",
+            &["Both work items must be queued"],
+        ),
+        command(
+            "work next",
+            "Show the next queued work item that is ready to start",
+            "To pick the next task deterministically. Add --activate to start it.",
+            "govctl work next --tag backend --activate",
+            &["Skips items whose depends_on items are not done or cancelled"],
+        ),
+        command(
+            "adr approve",
+            "Record an approval of a proposed ADR",
+            "Before accepting when [owners] sets an adr_quorum.",
+            "govctl adr approve ADR-0001 --as @alice",
+            &[
+                "ADR must be in proposed status",
+                "Approver must be a member listed in [owners]",
             ],
         ),
         command(
@@ -274,6 +473,20 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
             "govctl migrate",
             INIT_REQUIRED,
         ),
+        command(
+            "fmt",
+            "Rewrite artifact files in canonical form",
+            "After hand-editing artifact TOML, or in CI with --check to keep formatting churn out of diffs.",
+            "govctl fmt --check",
+            INIT_REQUIRED,
+        ),
+        command(
+            "explain",
+            "Explain a diagnostic code and how to fix it",
+            "When a command or govctl check reports a code you do not recognize.",
+            "govctl explain E0207",
+            &[],
+        ),
         command(
             "rfc bump",
             "Bump RFC version",
@@ -298,10 +511,44 @@ pub(super) fn command_catalog() -> Vec<CommandInfo> {
         command(
             "rfc supersede / adr supersede / clause supersede",
             "Supersede an artifact with a replacement",
-            "When replacing an artifact with a newer version.",
+            "When replacing an artifact with a newer version; add --update-refs to move inbound refs to the replacement.",
             "govctl rfc supersede RFC-0001 --by RFC-0010",
             &["Both artifacts must exist"],
         ),
+        command(
+            "rfc split / rfc merge",
+            "Move clauses between RFCs with supersession links and changelog entries",
+            "When an RFC has grown to cover separate concerns, or two RFCs specify one concern.",
+            "govctl rfc split RFC-0003 --clauses C-A,C-B --into RFC-0010 \"New Title\"",
+            &["Source and target RFCs must satisfy split or supersede rules"],
+        ),
+        command(
+            "clause history",
+            "Show how a clause's text changed, one diff per change",
+            "To review how a requirement evolved before amending or citing it.",
+            "govctl clause history RFC-0001:C-SCOPE",
+            &["Clause must exist", "Project must be a git repository"],
+        ),
+        command(
+            "clause reorder",
+            "Reorder clauses within an RFC section",
+            "To control rendered clause order without editing rfc.toml by hand.",
+            "govctl clause reorder RFC-0001 --section Specification --order C-SCOPE,C-TERMS",
+            &[
+                "RFC and section must exist",
+                "Listed clauses must belong to the section",
+            ],
+        ),
+        command(
+            "rfc section add / rename / remove / reorder",
+            "Manage the sections of an RFC",
+            "To add, rename, remove, or reorder sections without editing rfc.toml by hand.",
+            "govctl rfc section add RFC-0001 Rationale --after Summary",
+            &[
+                "Section titles must be unique",
+                "Sections with clauses require --force to remove",
+            ],
+        ),
         command(
             "rfc show",
             "Show RFC content to stdout (no file written)",
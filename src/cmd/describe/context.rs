@@ -1,12 +1,25 @@
+//! `describe --context`: project state and a ranked next-action plan.
+
+use crate::cmd::lifecycle::unmet_gates_in;
 use crate::config::Config;
+use crate::diagnostic::DiagnosticLevel;
 use crate::load::load_project;
+use crate::model::{ProjectIndex, RfcPhase, RfcStatus, WorkItemStatus};
+use crate::validate::validate_project;
 use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Serialize)]
 pub struct ProjectState {
     pub rfcs: Vec<RfcState>,
     pub adrs: Vec<AdrState>,
     pub work_items: Vec<WorkItemState>,
+    /// Open work items waiting on unfinished dependencies.
+    pub blocked_work_items: Vec<BlockedWorkItem>,
+    /// `govctl check` errors and warnings, one entry per code.
+    pub failing_checks: Vec<CheckSummary>,
+    /// Normative RFCs whose next phase has unmet gates.
+    pub unmet_gates: Vec<GateState>,
 }
 
 #[derive(Serialize)]
@@ -31,8 +44,34 @@ pub struct WorkItemState {
     pub status: String,
 }
 
+#[derive(Serialize)]
+pub struct BlockedWorkItem {
+    pub id: String,
+    pub status: String,
+    pub blocked_by: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct CheckSummary {
+    pub code: String,
+    pub level: String,
+    pub count: usize,
+    /// Message of the first diagnostic with this code.
+    pub example: String,
+}
+
+#[derive(Serialize)]
+pub struct GateState {
+    pub rfc: String,
+    pub phase: String,
+    /// `<gate>: <what is missing>` per unmet gate.
+    pub unmet: Vec<String>,
+}
+
 #[derive(Serialize)]
 pub struct SuggestedAction {
+    /// 1 is the action to take first.
+    pub rank: usize,
     pub command: String,
     pub reason: String,
     pub priority: String,
@@ -49,6 +88,7 @@ fn suggested_action(
     priority: &str,
 ) -> SuggestedAction {
     SuggestedAction {
+        rank: 0,
         command: command.into(),
         reason: reason.into(),
         priority: priority.to_string(),
@@ -89,11 +129,27 @@ pub(super) fn load_context(config: &Config) -> Option<DescribeContext> {
         })
         .collect();
 
-    let suggested_actions = generate_suggestions(&rfcs, &adrs, &work_items);
+    let blocked_work_items = blocked_work_items(&index);
+    let failing_checks = failing_checks(&index, config);
+    let unmet_gates = next_phase_gates(&index, config);
+
+    let suggested_actions = generate_suggestions(
+        &rfcs,
+        &adrs,
+        &work_items,
+        &Blockers {
+            blocked_work_items: &blocked_work_items,
+            failing_checks: &failing_checks,
+            unmet_gates: &unmet_gates,
+        },
+    );
     let project_state = ProjectState {
         rfcs,
         adrs,
         work_items,
+        blocked_work_items,
+        failing_checks,
+        unmet_gates,
     };
 
     Some(DescribeContext {
@@ -102,14 +158,146 @@ pub(super) fn load_context(config: &Config) -> Option<DescribeContext> {
     })
 }
 
-/// Generate suggested actions based on project state
+/// Queued and active work items with dependencies not yet done or cancelled.
+fn blocked_work_items(index: &ProjectIndex) -> Vec<BlockedWorkItem> {
+    let status_by_id: HashMap<&str, WorkItemStatus> = index
+        .work_items
+        .iter()
+        .map(|work| (work.meta().id.as_str(), work.meta().status))
+        .collect();
+    index
+        .work_items
+        .iter()
+        .filter(|work| {
+            matches!(
+                work.meta().status,
+                WorkItemStatus::Queue | WorkItemStatus::Active
+            )
+        })
+        .filter_map(|work| {
+            let blocked_by: Vec<String> = work
+                .meta()
+                .depends_on
+                .iter()
+                .filter(|dependency| {
+                    !matches!(
+                        status_by_id.get(dependency.as_str()),
+                        Some(WorkItemStatus::Done | WorkItemStatus::Cancelled)
+                    )
+                })
+                .cloned()
+                .collect();
+            (!blocked_by.is_empty()).then(|| BlockedWorkItem {
+                id: work.meta().id.clone(),
+                status: work.meta().status.as_ref().to_string(),
+                blocked_by,
+            })
+        })
+        .collect()
+}
+
+/// Validation errors and warnings grouped by code, errors first.
+fn failing_checks(index: &ProjectIndex, config: &Config) -> Vec<CheckSummary> {
+    let mut by_code: BTreeMap<(bool, &str), CheckSummary> = BTreeMap::new();
+    let result = validate_project(index, config);
+    for diagnostic in &result.diagnostics {
+        let level = match diagnostic.code.level() {
+            DiagnosticLevel::Error => "error",
+            DiagnosticLevel::Warning => "warning",
+            DiagnosticLevel::Info => continue,
+        };
+        by_code
+            .entry((level == "warning", diagnostic.code.code()))
+            .or_insert_with(|| CheckSummary {
+                code: diagnostic.code.code().to_string(),
+                level: level.to_string(),
+                count: 0,
+                example: diagnostic.message.clone(),
+            })
+            .count += 1;
+    }
+    by_code.into_values().collect()
+}
+
+/// Unmet gates for each normative RFC's next phase.
+fn next_phase_gates(index: &ProjectIndex, config: &Config) -> Vec<GateState> {
+    index
+        .rfcs
+        .iter()
+        .filter(|rfc| rfc.rfc.status == RfcStatus::Normative)
+        .filter_map(|rfc| {
+            let phase = match rfc.rfc.phase {
+                RfcPhase::Spec => RfcPhase::Impl,
+                RfcPhase::Impl => RfcPhase::Test,
+                RfcPhase::Test => RfcPhase::Stable,
+                RfcPhase::Stable => return None,
+            };
+            let unmet: Vec<String> = unmet_gates_in(index, config, &rfc.rfc.rfc_id, phase)
+                .iter()
+                .map(|item| format!("{}: {}", item.gate.as_ref(), item.detail))
+                .collect();
+            (!unmet.is_empty()).then(|| GateState {
+                rfc: rfc.rfc.rfc_id.clone(),
+                phase: phase.as_ref().to_string(),
+                unmet,
+            })
+        })
+        .collect()
+}
+
+/// What stands in the way, as computed for `project_state`.
+struct Blockers<'a> {
+    blocked_work_items: &'a [BlockedWorkItem],
+    failing_checks: &'a [CheckSummary],
+    unmet_gates: &'a [GateState],
+}
+
+fn priority_order(priority: &str) -> u8 {
+    match priority {
+        "high" => 0,
+        "medium" => 1,
+        _ => 2,
+    }
+}
+
+/// Generate suggested actions based on project state, ranked by priority
 fn generate_suggestions(
     rfcs: &[RfcState],
     adrs: &[AdrState],
     work_items: &[WorkItemState],
+    blockers: &Blockers,
 ) -> Vec<SuggestedAction> {
     let mut suggestions = Vec::new();
 
+    let errors: Vec<&CheckSummary> = blockers
+        .failing_checks
+        .iter()
+        .filter(|check| check.level == "error")
+        .collect();
+    if !errors.is_empty() {
+        let count: usize = errors.iter().map(|check| check.count).sum();
+        let codes: Vec<String> = errors
+            .iter()
+            .map(|check| format!("{} x{}", check.code, check.count))
+            .collect();
+        suggestions.push(suggested_action(
+            "govctl check",
+            format!(
+                "{count} check error(s) ({}); fix them first. First {}: {}",
+                codes.join(", "),
+                errors[0].code,
+                errors[0].example
+            ),
+            "high",
+        ));
+    } else if !blockers.failing_checks.is_empty() {
+        suggestions.push(suggested_action(
+            "govctl check",
+            "Checks pass with warnings. Review them when convenient.",
+            "low",
+        ));
+    }
+
     for rfc in rfcs {
         if rfc.status == "draft" {
             suggestions.push(suggested_action(
@@ -122,6 +310,24 @@ fn generate_suggestions(
             ));
         }
 
+        if let Some(gates) = blockers
+            .unmet_gates
+            .iter()
+            .find(|gates| gates.rfc == rfc.id)
+        {
+            suggestions.push(suggested_action(
+                format!("govctl rfc advance {} {} --report", rfc.id, gates.phase),
+                format!(
+                    "{} cannot enter {} yet: {}.",
+                    rfc.id,
+                    gates.phase,
+                    gates.unmet.join("; ")
+                ),
+                "high",
+            ));
+            continue;
+        }
+
         match (rfc.status.as_str(), rfc.phase.as_str()) {
             ("normative", "spec") => {
                 suggestions.push(suggested_action(
@@ -170,8 +376,17 @@ fn generate_suggestions(
         }
     }
 
+    let is_blocked = |id: &str| {
+        blockers
+            .blocked_work_items
+            .iter()
+            .any(|blocked| blocked.id == id)
+    };
     let active_count = work_items.iter().filter(|w| w.status == "active").count();
-    let queue_count = work_items.iter().filter(|w| w.status == "queue").count();
+    let queue_count = work_items
+        .iter()
+        .filter(|w| w.status == "queue" && !is_blocked(&w.id))
+        .count();
 
     if active_count == 0 && queue_count > 0 {
         suggestions.push(suggested_action(
@@ -184,8 +399,35 @@ fn generate_suggestions(
         ));
     }
 
+    let mut unblockers: Vec<&str> = Vec::new();
+    for blocked in blockers.blocked_work_items {
+        for dependency in &blocked.blocked_by {
+            if unblockers.contains(&dependency.as_str()) {
+                continue;
+            }
+            unblockers.push(dependency);
+            let status = work_items
+                .iter()
+                .find(|w| &w.id == dependency)
+                .map(|w| w.status.as_str());
+            let (command, next) = match status {
+                Some("queue") => (format!("govctl work move {dependency} active"), "Start it"),
+                Some("active") => (format!("govctl work move {dependency} done"), "Finish it"),
+                _ => (
+                    format!("govctl work remove {} depends_on {dependency}", blocked.id),
+                    "It does not exist; drop the dependency",
+                ),
+            };
+            suggestions.push(suggested_action(
+                command,
+                format!("{} is blocked by {dependency}. {next}.", blocked.id),
+                "high",
+            ));
+        }
+    }
+
     for work_item in work_items {
-        if work_item.status == "active" {
+        if work_item.status == "active" && !is_blocked(&work_item.id) {
             suggestions.push(suggested_action(
                 format!("govctl work move {} done", work_item.id),
                 format!(
@@ -197,5 +439,9 @@ fn generate_suggestions(
         }
     }
 
+    suggestions.sort_by_key(|action| priority_order(&action.priority));
+    for (index, action) in suggestions.iter_mut().enumerate() {
+        action.rank = index + 1;
+    }
     suggestions
 }
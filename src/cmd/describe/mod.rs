@@ -5,8 +5,11 @@ mod context;
 
 use crate::cmd::output::print_json;
 use crate::config::Config;
-use crate::diagnostic::{DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::schema::ARTIFACT_SCHEMA_TEMPLATES;
 use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 use catalog::{CommandInfo, WorkflowInfo, command_catalog, workflow_info};
 use context::{ProjectState, SuggestedAction, load_context};
@@ -25,6 +28,50 @@ struct DescribeOutput {
     suggested_actions: Option<Vec<SuggestedAction>>,
 }
 
+/// `describe --schemas`: the bundled JSON Schemas, keyed by file format
+/// (`rfc`, `clause`, `adr`, `work`, ...).
+#[derive(Serialize)]
+struct SchemasOutput {
+    version: String,
+    schemas: BTreeMap<&'static str, Value>,
+}
+
+/// Print the JSON Schema of every governed file format.
+///
+/// These are the schemas artifact files are validated against on load, so
+/// editors and external tools see the same rules as the running binary.
+pub fn describe_schemas() -> DiagnosticResult<Diagnostics> {
+    let mut schemas = BTreeMap::new();
+    for template in ARTIFACT_SCHEMA_TEMPLATES {
+        let name = template
+            .filename
+            .strip_suffix(".schema.json")
+            .unwrap_or(template.filename);
+        let schema = serde_json::from_str(template.content).map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0902JsonParseError,
+                format!(
+                    "Bundled schema {} is not valid JSON: {err}",
+                    template.filename
+                ),
+                "describe",
+            )
+        })?;
+        schemas.insert(name, schema);
+    }
+
+    print_json(
+        &SchemasOutput {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            schemas,
+        },
+        DiagnosticCode::E0903UnexpectedError,
+        "Failed to serialize schemas",
+        "describe",
+    )?;
+    Ok(vec![])
+}
+
 /// Execute describe command
 pub fn describe(config: &Config, include_context: bool) -> DiagnosticResult<Diagnostics> {
     let version = env!("CARGO_PKG_VERSION").to_string();
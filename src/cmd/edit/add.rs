@@ -9,6 +9,7 @@ use super::rules as edit_rules;
 use super::target_doc::add_to_target_doc;
 use super::toml_target::{is_work_dependency_target, validate_work_dependency_edit};
 use super::{ArtifactType, deserialize_edit_doc, plan_mutation_target, serialize_edit_doc};
+use crate::aliases::Aliases;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::model::{AdrEntry, ChangelogCategory, WorkItemEntry};
@@ -34,7 +35,7 @@ pub(super) struct AddFieldRequest<'a> {
     pub(super) pros: Option<Vec<String>>,
     pub(super) cons: Option<Vec<String>>,
     pub(super) reject_reason: Option<String>,
-    pub(super) op: WriteOp,
+    pub(super) op: WriteOp<'a>,
 }
 
 fn adr_add_alternatives(
@@ -141,11 +142,17 @@ pub fn add_to_field(request: AddFieldRequest<'_>) -> DiagnosticResult<Vec<Diagno
         op,
     } = request;
 
-    let plan = plan_mutation_target(id, field, edit_rules::Verb::Add)?;
+    let plan = plan_mutation_target(&config.prefixes, id, field, edit_rules::Verb::Add)?;
     let artifact = plan.artifact;
     let fp = &plan.field_path;
     let target = &plan.target;
 
+    // Fields holding IDs store the ID an `@alias` names.
+    let aliased = (is_refs_target(target) || is_work_dependency_target(target))
+        .then(|| Aliases::load(config).expand(value))
+        .flatten();
+    let value = aliased.as_deref().unwrap_or(value);
+
     // Validate tags against controlled vocabulary at add time — [[RFC-0002:C-RESOURCES]]
     if fp.as_simple() == Some("tags") {
         crate::cmd::tag::validate_registered_tag(config, value, id)?;
@@ -153,6 +160,18 @@ pub fn add_to_field(request: AddFieldRequest<'_>) -> DiagnosticResult<Vec<Diagno
     if is_refs_target(target) {
         validate_ref_edit(config, artifact, id, value)?;
     }
+    if fp.as_simple() == Some("attachments") {
+        crate::validate::validate_attachment(config, value, id, !op.is_preview())?;
+    }
+    if artifact == ArtifactType::WorkItem && fp.as_simple() == Some("github") {
+        crate::github::parse_issue_ref(value, id)?;
+    }
+    // Owners and approvals must resolve in the [owners] registry.
+    if let Some(field @ ("owners" | "approvals")) = fp.as_simple()
+        && matches!(artifact, ArtifactType::Rfc | ArtifactType::Adr)
+    {
+        crate::validate::validate_owner_identity(config, id, field, value, id)?;
+    }
 
     match artifact {
         ArtifactType::Adr => {
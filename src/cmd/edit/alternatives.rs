@@ -0,0 +1,74 @@
+//! Structured updates to ADR alternatives (`adr alt set`).
+
+use super::adapter::{AdrTomlAdapter, TomlAdapter};
+use super::matching::{MatchOptions, MatchUse, resolve_match_indices};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::model::AlternativeStatus;
+use crate::ui;
+use crate::write::WriteOp;
+
+/// Fields `adr alt set` replaces on one alternative.
+#[derive(Debug, Clone, Default)]
+pub struct AlternativeUpdate {
+    /// Replaces the alternative's pros when non-empty
+    pub pros: Vec<String>,
+    /// Replaces the alternative's cons when non-empty
+    pub cons: Vec<String>,
+    /// Marks the alternative rejected for this reason
+    pub reject_reason: Option<String>,
+}
+
+/// Update the alternative of ADR `id` whose text matches `pattern`, or the
+/// one at index `at`.
+pub fn set_alternative(
+    config: &Config,
+    id: &str,
+    pattern: Option<&str>,
+    at: Option<i32>,
+    update: &AlternativeUpdate,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    if update.pros.is_empty() && update.cons.is_empty() && update.reject_reason.is_none() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "Nothing to set; pass --pro, --con, or --reject",
+            id,
+        ));
+    }
+
+    let mut entry = AdrTomlAdapter::load(config, id)?;
+    let alternatives = &mut entry.spec.content.alternatives;
+    let texts: Vec<&str> = alternatives.iter().map(|alt| alt.text.as_str()).collect();
+    let index = resolve_match_indices(
+        id,
+        "alternatives",
+        &texts,
+        &MatchOptions {
+            pattern,
+            at,
+            ..MatchOptions::default()
+        },
+        MatchUse::TickSingle,
+    )?[0];
+
+    let alternative = &mut alternatives[index];
+    if !update.pros.is_empty() {
+        alternative.pros = update.pros.clone();
+    }
+    if !update.cons.is_empty() {
+        alternative.cons = update.cons.clone();
+    }
+    if let Some(reason) = &update.reject_reason {
+        alternative.status = AlternativeStatus::Rejected;
+        alternative.rejection_reason = Some(reason.clone());
+    }
+    let text = alternative.text.clone();
+
+    AdrTomlAdapter::write(config, &entry, op)?;
+    if !op.is_preview() {
+        ui::updated("ADR", id);
+        ui::sub_info(format!("Updated alternative '{text}'"));
+    }
+    Ok(vec![])
+}
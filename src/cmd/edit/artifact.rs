@@ -1,3 +1,5 @@
+use crate::aliases::Aliases;
+use crate::config::PrefixesConfig;
 use crate::diagnostic::{Diagnostic, DiagnosticCode};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,12 +12,13 @@ pub enum ArtifactType {
 }
 
 impl ArtifactType {
-    pub fn from_id(id: &str) -> Option<Self> {
+    /// Classify an ID by the project's `[prefixes]`.
+    pub fn from_id(prefixes: &PrefixesConfig, id: &str) -> Option<Self> {
         if id.contains(':') {
             Some(Self::Clause)
-        } else if id.starts_with("RFC-") {
+        } else if prefixes.is_rfc(id) {
             Some(Self::Rfc)
-        } else if id.starts_with("ADR-") {
+        } else if prefixes.is_adr(id) {
             Some(Self::Adr)
         } else if id.starts_with("GUARD-") {
             Some(Self::Guard)
@@ -26,6 +29,48 @@ impl ArtifactType {
         }
     }
 
+    /// Classify an ID that carries a full artifact prefix. Unlike
+    /// [`Self::from_id`], a dashed word such as a title fragment is not
+    /// taken for a work item ID.
+    pub fn prefixed(prefixes: &PrefixesConfig, id: &str) -> Option<Self> {
+        Self::from_id(prefixes, id)
+            .filter(|artifact| *artifact != Self::WorkItem || id.starts_with("WI-"))
+    }
+
+    /// Classify `id`, or assume `fallback` for partial IDs and title
+    /// fragments that carry no prefix.
+    pub fn from_id_or(prefixes: &PrefixesConfig, id: &str, fallback: Self) -> Self {
+        Self::prefixed(prefixes, id).unwrap_or(fallback)
+    }
+
+    /// Canonical form of `id` given where an artifact of this type is
+    /// expected: an `@alias` becomes the ID it names, a bare or short number
+    /// such as `12` or `rfc-1` is padded to a full ID, and clause names are
+    /// uppercased. Other IDs are unchanged.
+    pub fn expand_shorthand(
+        self,
+        prefixes: &PrefixesConfig,
+        aliases: &Aliases,
+        id: &str,
+    ) -> String {
+        let id = aliases.expand_or_keep(id);
+        let id = id.as_str();
+        let expand = |prefix: &str, id: &str| {
+            shorthand_number(prefix, id).map(|number| format!("{prefix}-{number:04}"))
+        };
+        let expanded = match (self, id.split_once(':')) {
+            (Self::Rfc | Self::Clause, Some((rfc_id, clause_name))) => Some(format!(
+                "{}:{}",
+                expand(&prefixes.rfc, rfc_id).unwrap_or_else(|| rfc_id.to_string()),
+                clause_name.to_ascii_uppercase()
+            )),
+            (Self::Rfc, None) => expand(&prefixes.rfc, id),
+            (Self::Adr, None) => expand(&prefixes.adr, id),
+            _ => None,
+        };
+        expanded.unwrap_or_else(|| id.to_string())
+    }
+
     pub fn unknown_error(id: &str) -> Diagnostic {
         Diagnostic::new(
             DiagnosticCode::E0819UnknownArtifactType,
@@ -45,6 +90,18 @@ impl ArtifactType {
     }
 }
 
+/// Number of `12`, `prefix-12` or `PREFIX-0012`, ignoring the prefix's case.
+fn shorthand_number(prefix: &str, id: &str) -> Option<u32> {
+    let digits = match id.get(..prefix.len()) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => id[prefix.len()..].strip_prefix('-')?,
+        _ => id,
+    };
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
 #[cfg(test)]
 #[path = "artifact_tests.rs"]
 mod tests;
@@ -1,33 +1,47 @@
 use super::ArtifactType;
+use crate::aliases::Aliases;
+use crate::config::PrefixesConfig;
 
 #[test]
 fn test_artifact_type_clause() {
     assert_eq!(
-        ArtifactType::from_id("RFC-0001:C-NAME"),
+        ArtifactType::from_id(&PrefixesConfig::default(), "RFC-0001:C-NAME"),
         Some(ArtifactType::Clause)
     );
     assert_eq!(
-        ArtifactType::from_id("RFC-0000:C-SUMMARY"),
+        ArtifactType::from_id(&PrefixesConfig::default(), "RFC-0000:C-SUMMARY"),
         Some(ArtifactType::Clause)
     );
 }
 
 #[test]
 fn test_artifact_type_rfc() {
-    assert_eq!(ArtifactType::from_id("RFC-0001"), Some(ArtifactType::Rfc));
-    assert_eq!(ArtifactType::from_id("RFC-9999"), Some(ArtifactType::Rfc));
+    assert_eq!(
+        ArtifactType::from_id(&PrefixesConfig::default(), "RFC-0001"),
+        Some(ArtifactType::Rfc)
+    );
+    assert_eq!(
+        ArtifactType::from_id(&PrefixesConfig::default(), "RFC-9999"),
+        Some(ArtifactType::Rfc)
+    );
 }
 
 #[test]
 fn test_artifact_type_adr() {
-    assert_eq!(ArtifactType::from_id("ADR-0001"), Some(ArtifactType::Adr));
-    assert_eq!(ArtifactType::from_id("ADR-0007"), Some(ArtifactType::Adr));
+    assert_eq!(
+        ArtifactType::from_id(&PrefixesConfig::default(), "ADR-0001"),
+        Some(ArtifactType::Adr)
+    );
+    assert_eq!(
+        ArtifactType::from_id(&PrefixesConfig::default(), "ADR-0007"),
+        Some(ArtifactType::Adr)
+    );
 }
 
 #[test]
 fn test_artifact_type_work_item_by_prefix() {
     assert_eq!(
-        ArtifactType::from_id("WI-2026-01-17-001"),
+        ArtifactType::from_id(&PrefixesConfig::default(), "WI-2026-01-17-001"),
         Some(ArtifactType::WorkItem)
     );
 }
@@ -36,13 +50,73 @@ fn test_artifact_type_work_item_by_prefix() {
 fn test_artifact_type_work_item_by_hyphen() {
     // Any ID with hyphen that doesn't match RFC/ADR/Clause is WorkItem.
     assert_eq!(
-        ArtifactType::from_id("2026-01-17-add-tests"),
+        ArtifactType::from_id(&PrefixesConfig::default(), "2026-01-17-add-tests"),
         Some(ArtifactType::WorkItem)
     );
 }
 
 #[test]
 fn test_artifact_type_unknown() {
-    assert_eq!(ArtifactType::from_id("UNKNOWN"), None);
-    assert_eq!(ArtifactType::from_id("foo"), None);
+    assert_eq!(
+        ArtifactType::from_id(&PrefixesConfig::default(), "UNKNOWN"),
+        None
+    );
+    assert_eq!(
+        ArtifactType::from_id(&PrefixesConfig::default(), "foo"),
+        None
+    );
+}
+
+#[test]
+fn test_artifact_type_follows_configured_prefixes() {
+    let prefixes = PrefixesConfig {
+        rfc: "SPEC".to_string(),
+        adr: "DEC".to_string(),
+    };
+    assert_eq!(
+        ArtifactType::from_id(&prefixes, "SPEC-0001"),
+        Some(ArtifactType::Rfc)
+    );
+    assert_eq!(
+        ArtifactType::from_id(&prefixes, "DEC-0001"),
+        Some(ArtifactType::Adr)
+    );
+    assert_eq!(
+        ArtifactType::from_id(&prefixes, "SPEC-0001:C-NAME"),
+        Some(ArtifactType::Clause)
+    );
+}
+
+#[test]
+fn test_expand_shorthand_pads_numbers_and_clause_names() {
+    let prefixes = PrefixesConfig::default();
+    let expand = |artifact: ArtifactType, id: &str| {
+        artifact.expand_shorthand(&prefixes, &Aliases::default(), id)
+    };
+    assert_eq!(expand(ArtifactType::Adr, "12"), "ADR-0012");
+    assert_eq!(expand(ArtifactType::Rfc, "rfc-01"), "RFC-0001");
+    assert_eq!(expand(ArtifactType::Rfc, "RFC-0001"), "RFC-0001");
+    assert_eq!(
+        expand(ArtifactType::Clause, "RFC-1:c-expiry"),
+        "RFC-0001:C-EXPIRY"
+    );
+    assert_eq!(expand(ArtifactType::Rfc, "ADR-0003"), "ADR-0003");
+    assert_eq!(expand(ArtifactType::Clause, "12"), "12");
+    assert_eq!(expand(ArtifactType::WorkItem, "12"), "12");
+}
+
+#[test]
+fn test_expand_shorthand_follows_configured_prefixes() {
+    let prefixes = PrefixesConfig {
+        rfc: "SPEC".to_string(),
+        adr: "DEC".to_string(),
+    };
+    assert_eq!(
+        ArtifactType::Adr.expand_shorthand(&prefixes, &Aliases::default(), "dec-7"),
+        "DEC-0007"
+    );
+    assert_eq!(
+        ArtifactType::Rfc.expand_shorthand(&prefixes, &Aliases::default(), "3"),
+        "SPEC-0003"
+    );
 }
@@ -1,12 +1,13 @@
 use super::adapter::{ClauseTomlAdapter, DocAdapter, RfcTomlAdapter, TomlAdapter, WorkTomlAdapter};
 use super::delete_referrers::{clause_deletion_referrers, work_item_deletion_referrers};
 use crate::cmd::confirmation::confirm_destructive_action;
+use crate::cmd::trash::{Tombstone, move_to_trash};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::load::split_clause_id;
-use crate::model::{RfcPhase, RfcStatus};
+use crate::model::{ClauseSpec, RfcPhase, RfcSpec, RfcStatus};
 use crate::ui;
-use crate::write::{WriteOp, delete_file, with_file_transaction};
+use crate::write::{WriteOp, with_file_transaction};
 use std::path::Path;
 
 pub fn delete_clause(
@@ -25,25 +26,7 @@ pub fn delete_clause(
 
     let mut rfc_loaded = RfcTomlAdapter::load(config, rfc_id)?;
     let clause_loaded = ClauseTomlAdapter::load(config, clause_id)?;
-    // [[RFC-0000:C-CLAUSE-DEF]] limits normative deletion to the open candidate.
-    let is_current_candidate_clause = rfc_loaded.data.status == RfcStatus::Normative
-        && rfc_loaded.data.phase == RfcPhase::Spec
-        && clause_loaded.data.since.as_deref() == Some(rfc_loaded.data.version.as_str());
-    if rfc_loaded.data.status != RfcStatus::Draft && !is_current_candidate_clause {
-        let since = clause_loaded.data.since.as_deref().unwrap_or("pending");
-        return Err(Diagnostic::new(
-            DiagnosticCode::E0104RfcInvalidTransition,
-            format!(
-                "Cannot delete clause from {} while status={}, phase={}, version={}, and clause since={}. Clause deletion is limited to draft RFCs or Clauses introduced in the current normative spec candidate.",
-                rfc_id,
-                rfc_loaded.data.status.as_ref(),
-                rfc_loaded.data.phase.as_ref(),
-                rfc_loaded.data.version,
-                since,
-            ),
-            clause_id,
-        ));
-    }
+    ensure_clause_deletable(&rfc_loaded.data, &clause_loaded.data, clause_id)?;
 
     let clause_path = clause_loaded.path;
 
@@ -71,7 +54,7 @@ pub fn delete_clause(
 
     let clause_rel_path = format!("clauses/{}", clause_file_name);
 
-    if !unlink_clause_from_sections(&mut rfc_loaded.data, &clause_rel_path) {
+    let Some(section) = unlink_clause_from_sections(&mut rfc_loaded.data, &clause_rel_path) else {
         return Err(Diagnostic::new(
             DiagnosticCode::E0202ClauseNotFound,
             format!(
@@ -80,30 +63,35 @@ pub fn delete_clause(
             ),
             clause_id,
         ));
-    }
+    };
+    let tombstone = Tombstone::new(config, clause_id, &clause_path, Some(section));
 
     with_file_transaction(
         &[rfc_loaded.path.as_path(), clause_path.as_path()],
         op,
-        || {
+        |op| {
             crate::write::write_rfc(
                 &rfc_loaded.path,
                 &rfc_loaded.data,
                 op,
                 Some(&config.display_path(&rfc_loaded.path)),
             )?;
-            delete_file(&clause_path, op, Some(&config.display_path(&clause_path)))
+            move_to_trash(config, &tombstone, &clause_path, op)
         },
     )?;
 
     if !op.is_preview() {
         ui::success(format!("Deleted clause {}", clause_id));
+        ui::sub_info(format!(
+            "Moved to trash; restore with: govctl restore {clause_id}"
+        ));
     }
 
     Ok(vec![])
 }
 
-fn unlink_clause_from_sections(rfc: &mut crate::model::RfcSpec, clause_rel_path: &str) -> bool {
+/// Remove the clause from its section, returning the section title.
+fn unlink_clause_from_sections(rfc: &mut RfcSpec, clause_rel_path: &str) -> Option<String> {
     for section in &mut rfc.sections {
         if let Some(pos) = section
             .clauses
@@ -111,14 +99,50 @@ fn unlink_clause_from_sections(rfc: &mut crate::model::RfcSpec, clause_rel_path:
             .position(|clause| clause == clause_rel_path)
         {
             section.clauses.remove(pos);
-            return true;
+            return Some(section.title.clone());
         }
     }
 
-    false
+    None
+}
+
+/// Whether the clause is still part of an open revision: a draft RFC, or the
+/// current normative spec candidate.
+pub(crate) fn clause_in_open_revision(rfc: &RfcSpec, clause: &ClauseSpec) -> bool {
+    let is_current_candidate_clause = rfc.status == RfcStatus::Normative
+        && rfc.phase == RfcPhase::Spec
+        && clause.since.as_deref() == Some(rfc.version.as_str());
+    rfc.status == RfcStatus::Draft || is_current_candidate_clause
+}
+
+/// [[RFC-0000:C-CLAUSE-DEF]] limits normative deletion to the open candidate.
+pub(crate) fn ensure_clause_deletable(
+    rfc: &RfcSpec,
+    clause: &ClauseSpec,
+    clause_id: &str,
+) -> DiagnosticResult<()> {
+    if !clause_in_open_revision(rfc, clause) {
+        let since = clause.since.as_deref().unwrap_or("pending");
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0104RfcInvalidTransition,
+            format!(
+                "Cannot delete clause from {} while status={}, phase={}, version={}, and clause since={}. Clause deletion is limited to draft RFCs or Clauses introduced in the current normative spec candidate.",
+                rfc.rfc_id,
+                rfc.status.as_ref(),
+                rfc.phase.as_ref(),
+                rfc.version,
+                since,
+            ),
+            clause_id,
+        ));
+    }
+    Ok(())
 }
 
-fn ensure_clause_not_referenced(config: &Config, clause_id: &str) -> DiagnosticResult<()> {
+pub(crate) fn ensure_clause_not_referenced(
+    config: &Config,
+    clause_id: &str,
+) -> DiagnosticResult<()> {
     let load_result = crate::load::load_project_with_warnings(config).map_err(|diagnostics| {
         diagnostics.into_iter().next().unwrap_or_else(|| {
             Diagnostic::new(
@@ -144,6 +168,39 @@ fn ensure_clause_not_referenced(config: &Config, clause_id: &str) -> DiagnosticR
     Ok(())
 }
 
+/// Fail when anything outside `rfc_id` and its own clauses references it,
+/// before a draft RFC is removed.
+pub(crate) fn ensure_rfc_not_referenced(config: &Config, rfc_id: &str) -> DiagnosticResult<()> {
+    let load_result = crate::load::load_project_with_warnings(config).map_err(|diagnostics| {
+        diagnostics.into_iter().next().unwrap_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project before RFC removal",
+                rfc_id,
+            )
+        })
+    })?;
+    let referenced_by: Vec<String> = clause_deletion_referrers(config, &load_result.index, rfc_id)?
+        .into_iter()
+        .filter(|referrer| {
+            split_clause_id(referrer).map_or(referrer.as_str(), |(owner, _)| owner) != rfc_id
+        })
+        .collect();
+
+    if !referenced_by.is_empty() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0104RfcInvalidTransition,
+            format!(
+                "Cannot remove draft RFC: {rfc_id} is referenced by: {}. Remove references first.",
+                referenced_by.join(", ")
+            ),
+            rfc_id,
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn delete_work_item(
     config: &Config,
     id: &str,
@@ -210,10 +267,11 @@ fn proceed_with_deletion(
         return Ok(vec![]);
     }
 
-    delete_file(path, op, Some(&config.display_path(path)))?;
+    move_to_trash(config, &Tombstone::new(config, id, path, None), path, op)?;
 
     if !op.is_preview() {
         ui::success(format!("Deleted work item {}", id));
+        ui::sub_info(format!("Moved to trash; restore with: govctl restore {id}"));
     }
 
     Ok(vec![])
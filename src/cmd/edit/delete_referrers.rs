@@ -56,7 +56,13 @@ fn inline_clause_referrers(
     for rfc in &index.rfcs {
         for clause in &rfc.clauses {
             let source_id = format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id);
-            if source_id != target_id && text_references(inline_re, &clause.spec.text, target_id) {
+            if source_id != target_id
+                && clause
+                    .spec
+                    .text
+                    .as_deref()
+                    .is_some_and(|text| text_references(inline_re, text, target_id))
+            {
                 referrers.push(source_id);
             }
         }
@@ -83,7 +89,10 @@ fn inline_clause_referrers(
 
     for adr in &index.adrs {
         let content = &adr.spec.content;
-        let direct_content = [&content.context, &content.decision, &content.consequences];
+        let direct_content = [&content.context, &content.decision, &content.consequences]
+            .into_iter()
+            .chain(content.drivers.iter())
+            .chain(content.stakeholders.iter());
         let alternative_content = content.alternatives.iter().flat_map(|alternative| {
             std::iter::once(&alternative.text)
                 .chain(alternative.pros.iter())
@@ -91,7 +100,6 @@ fn inline_clause_referrers(
                 .chain(alternative.rejection_reason.iter())
         });
         if direct_content
-            .into_iter()
             .chain(alternative_content)
             .any(|text| text_references(inline_re, text, target_id))
         {
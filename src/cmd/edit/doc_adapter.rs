@@ -1,4 +1,5 @@
 use super::adapter::{DocAdapter, LoadedDoc, display_scope_for_dir};
+use crate::artifact_catalog::{CatalogKind, missing_id_message};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::load::{find_clause_toml, find_rfc_toml};
@@ -12,7 +13,7 @@ fn load_doc_with<T, F, R>(
     finder: F,
     scope_path: PathBuf,
     missing_code: DiagnosticCode,
-    missing_label: &str,
+    missing_kind: CatalogKind,
     read: R,
 ) -> DiagnosticResult<LoadedDoc<T>>
 where
@@ -23,7 +24,7 @@ where
     let path = finder(config, id).ok_or_else(|| {
         Diagnostic::new(
             missing_code,
-            format!("{missing_label} not found: {id}"),
+            missing_id_message(config, missing_kind, id),
             &scope,
         )
     })?;
@@ -41,7 +42,7 @@ where
         finder,
         config.rfc_dir(),
         DiagnosticCode::E0102RfcNotFound,
-        "RFC",
+        CatalogKind::Rfc,
         read_rfc,
     )
 }
@@ -76,7 +77,7 @@ where
         finder,
         clause_scope_path(config, id),
         DiagnosticCode::E0202ClauseNotFound,
-        "Clause",
+        CatalogKind::Clause,
         read_clause,
     )
 }
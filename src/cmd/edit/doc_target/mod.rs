@@ -28,7 +28,7 @@ struct SetDocRequest<'a> {
     id: &'a str,
     target: &'a edit_engine::ResolvedTarget,
     value: &'a str,
-    op: WriteOp,
+    op: WriteOp<'a>,
     allow_forced_simple_set: bool,
     kind: DocTargetKind,
 }
@@ -92,7 +92,7 @@ pub(super) fn get_doc_field<A>(
     id: &str,
     target: Option<&edit_engine::ResolvedTarget>,
     artifact: ArtifactType,
-    nested_error: &str,
+    nested: NestedGetMode<'_>,
 ) -> DiagnosticResult<()>
 where
     A: DocAdapter,
@@ -103,13 +103,7 @@ where
         let doc = serialize_edit_doc(&loaded.data, id)?;
         println!(
             "{}",
-            render_target_from_doc(
-                artifact,
-                &doc,
-                target,
-                id,
-                NestedGetMode::Reject(nested_error),
-            )?
+            render_target_from_doc(artifact, &doc, target, id, nested)?
         );
     } else {
         print_json(
@@ -0,0 +1,146 @@
+//! Whole-artifact editing in the user's editor.
+//!
+//! The artifact's TOML is copied into a scratch file, opened in `$VISUAL` or
+//! `$EDITOR`, and validated with the same loader used for the artifact kind
+//! before the original is atomically replaced. A buffer that fails validation
+//! is kept on disk so the edits are not lost.
+
+use super::ArtifactType;
+use super::adapter::{
+    AdrTomlAdapter, ClauseTomlAdapter, DocAdapter, GuardTomlAdapter, RfcTomlAdapter, TomlAdapter,
+    WorkTomlAdapter,
+};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::parse::{load_adr, load_guard, load_work_item};
+use crate::ui;
+use crate::write::{WriteOp, read_clause, read_rfc, write_file};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Editor used when neither `$VISUAL` nor `$EDITOR` is set.
+const FALLBACK_EDITOR: &str = "vi";
+
+/// Open an artifact in the user's editor and write it back once it validates.
+pub fn edit_in_editor(
+    config: &Config,
+    artifact: ArtifactType,
+    id: &str,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let path = artifact_path(config, artifact, id)?;
+    let display_path = config.display_path(&path);
+    let scope = display_path.display().to_string();
+    let original = std::fs::read_to_string(&path)
+        .map_err(|err| Diagnostic::io_error("read artifact", err, scope.clone()))?;
+    let canonical_id = validated_id(config, artifact, &path)?;
+
+    let scratch_dir = tempfile::Builder::new()
+        .prefix("govctl-edit-")
+        .tempdir()
+        .map_err(|err| Diagnostic::io_error("create edit buffer", err, scope.clone()))?;
+    let file_name = path.file_name().unwrap_or_else(|| "artifact.toml".as_ref());
+    let buffer = scratch_dir.path().join(file_name);
+    std::fs::write(&buffer, &original)
+        .map_err(|err| Diagnostic::io_error("create edit buffer", err, scope.clone()))?;
+
+    run_editor(&buffer)?;
+
+    let edited = std::fs::read_to_string(&buffer)
+        .map_err(|err| Diagnostic::io_error("read edit buffer", err, scope.clone()))?;
+    if edited == original {
+        ui::info(format!("No changes to {canonical_id}"));
+        return Ok(vec![]);
+    }
+
+    let validated = validated_id(config, artifact, &buffer).and_then(|edited_id| {
+        if edited_id == canonical_id {
+            Ok(())
+        } else {
+            Err(Diagnostic::new(
+                DiagnosticCode::E0804FieldNotEditable,
+                format!(
+                    "Artifact ID cannot be changed in the editor ({canonical_id} -> {edited_id})"
+                ),
+                scope.clone(),
+            ))
+        }
+    });
+    if let Err(mut diag) = validated {
+        let kept = scratch_dir.keep();
+        diag.message = format!(
+            "{}; edits kept in {}",
+            diag.message,
+            kept.join(file_name).display()
+        );
+        return Err(diag);
+    }
+
+    write_file(&path, &edited, op, Some(&display_path))?;
+    if !op.is_preview() {
+        ui::updated(kind_label(artifact), &canonical_id);
+    }
+    Ok(vec![])
+}
+
+fn artifact_path(config: &Config, artifact: ArtifactType, id: &str) -> DiagnosticResult<PathBuf> {
+    Ok(match artifact {
+        ArtifactType::Rfc => RfcTomlAdapter::load(config, id)?.path,
+        ArtifactType::Clause => ClauseTomlAdapter::load(config, id)?.path,
+        ArtifactType::Adr => AdrTomlAdapter::load(config, id)?.path,
+        ArtifactType::WorkItem => WorkTomlAdapter::load(config, id)?.path,
+        ArtifactType::Guard => GuardTomlAdapter::load(config, id)?.path,
+    })
+}
+
+/// Load `path` as `artifact` and return the ID it declares.
+fn validated_id(config: &Config, artifact: ArtifactType, path: &Path) -> DiagnosticResult<String> {
+    Ok(match artifact {
+        ArtifactType::Rfc => read_rfc(config, path)?.rfc_id,
+        ArtifactType::Clause => read_clause(config, path)?.clause_id,
+        ArtifactType::Adr => load_adr(config, path)?.spec.govctl.id,
+        ArtifactType::WorkItem => load_work_item(config, path)?.spec.govctl.id,
+        ArtifactType::Guard => load_guard(config, path)?.spec.govctl.id,
+    })
+}
+
+fn run_editor(buffer: &Path) -> DiagnosticResult<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| FALLBACK_EDITOR.to_string());
+    // Editors are commonly configured with flags, e.g. `code --wait`.
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or(FALLBACK_EDITOR);
+
+    let status = Command::new(program)
+        .args(words)
+        .arg(buffer)
+        .status()
+        .map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0823EditorFailed,
+                format!("Failed to launch editor '{editor}': {err}"),
+                "editor",
+            )
+        })?;
+    if !status.success() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0823EditorFailed,
+            format!("Editor '{editor}' exited with {status}; nothing was written"),
+            "editor",
+        ));
+    }
+    Ok(())
+}
+
+fn kind_label(artifact: ArtifactType) -> &'static str {
+    match artifact {
+        ArtifactType::Rfc => "RFC",
+        ArtifactType::Clause => "clause",
+        ArtifactType::Adr => "ADR",
+        ArtifactType::WorkItem => "work item",
+        ArtifactType::Guard => "guard",
+    }
+}
@@ -11,6 +11,7 @@ use self::resolve::resolve_target;
 use super::ArtifactType;
 use super::path::{self, FieldPath};
 use super::rules::{self as edit_rules, Verb};
+use crate::config::PrefixesConfig;
 use crate::diagnostic::DiagnosticResult;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -78,20 +79,38 @@ pub fn parse_and_canonicalize_field(
 ///
 /// This function intentionally does not enforce verb/field capability checks;
 /// those remain in the command-specific execution path.
-pub fn plan_request(id: &str, field: Option<&str>) -> DiagnosticResult<TargetPlan> {
-    plan_request_with_verb(id, field, None)
+pub fn plan_request(
+    prefixes: &PrefixesConfig,
+    id: &str,
+    field: Option<&str>,
+) -> DiagnosticResult<TargetPlan> {
+    plan_request_with_verb(resolve_artifact(prefixes, id)?, id, field, None)
 }
 
-pub fn plan_mutation_request(id: &str, field: &str, verb: Verb) -> DiagnosticResult<TargetPlan> {
-    plan_request_with_verb(id, Some(field), Some(verb))
+/// Plan a request against `artifact`, whatever `id` looks like.
+pub fn plan_request_as(
+    artifact: ArtifactType,
+    id: &str,
+    field: Option<&str>,
+) -> DiagnosticResult<TargetPlan> {
+    plan_request_with_verb(artifact, id, field, None)
+}
+
+pub fn plan_mutation_request(
+    prefixes: &PrefixesConfig,
+    id: &str,
+    field: &str,
+    verb: Verb,
+) -> DiagnosticResult<TargetPlan> {
+    plan_request_with_verb(resolve_artifact(prefixes, id)?, id, Some(field), Some(verb))
 }
 
 fn plan_request_with_verb(
+    artifact: ArtifactType,
     id: &str,
     field: Option<&str>,
     verb: Option<Verb>,
 ) -> DiagnosticResult<TargetPlan> {
-    let artifact = resolve_artifact(id)?;
     let field_path = field
         .map(|path| parse_and_canonicalize_field(artifact, path))
         .transpose()?;
@@ -107,8 +126,8 @@ fn plan_request_with_verb(
     })
 }
 
-fn resolve_artifact(id: &str) -> DiagnosticResult<ArtifactType> {
-    ArtifactType::from_id(id).ok_or_else(|| ArtifactType::unknown_error(id))
+fn resolve_artifact(prefixes: &PrefixesConfig, id: &str) -> DiagnosticResult<ArtifactType> {
+    ArtifactType::from_id(prefixes, id).ok_or_else(|| ArtifactType::unknown_error(id))
 }
 
 fn canonicalize_field_path(artifact: ArtifactType, mut fp: FieldPath) -> FieldPath {
@@ -1,11 +1,12 @@
 use super::super::ArtifactType;
 use super::super::path::PathSegment;
 use super::*;
+use crate::config::PrefixesConfig;
 use crate::diagnostic::DiagnosticCode;
 
 #[test]
 fn test_plan_simple_path() -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_request("ADR-0001", Some("title"))?;
+    let plan = plan_request(&PrefixesConfig::default(), "ADR-0001", Some("title"))?;
     assert_eq!(plan.artifact, ArtifactType::Adr);
     assert_eq!(
         plan.field_path.as_ref().and_then(FieldPath::as_simple),
@@ -31,7 +32,11 @@ fn test_plan_simple_path() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_plan_nested_path() -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_request("ADR-0001", Some("alt[0].pro[1]"))?;
+    let plan = plan_request(
+        &PrefixesConfig::default(),
+        "ADR-0001",
+        Some("alt[0].pro[1]"),
+    )?;
     let fp = plan
         .field_path
         .as_ref()
@@ -44,7 +49,7 @@ fn test_plan_nested_path() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_plan_without_field() -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_request("ADR-0001", None)?;
+    let plan = plan_request(&PrefixesConfig::default(), "ADR-0001", None)?;
     assert_eq!(plan.artifact, ArtifactType::Adr);
     assert!(plan.field_path.is_none());
     assert_eq!(plan.verb, None);
@@ -54,7 +59,7 @@ fn test_plan_without_field() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_plan_unknown_artifact_fails() -> Result<(), Box<dyn std::error::Error>> {
-    let diag = match plan_request("UNKNOWN", Some("title")) {
+    let diag = match plan_request(&PrefixesConfig::default(), "UNKNOWN", Some("title")) {
         Ok(plan) => return Err(format!("unknown artifact should fail, got {plan:?}").into()),
         Err(diag) => diag,
     };
@@ -66,7 +71,7 @@ fn test_plan_unknown_artifact_fails() -> Result<(), Box<dyn std::error::Error>>
 #[test]
 fn test_scope_aware_alias_only_applies_when_valid_for_artifact()
 -> Result<(), Box<dyn std::error::Error>> {
-    let diag = match plan_request("ADR-0001", Some("desc")) {
+    let diag = match plan_request(&PrefixesConfig::default(), "ADR-0001", Some("desc")) {
         Ok(plan) => return Err(format!("unknown ADR field should fail, got {plan:?}").into()),
         Err(diag) => diag,
     };
@@ -77,7 +82,11 @@ fn test_scope_aware_alias_only_applies_when_valid_for_artifact()
 
 #[test]
 fn test_scope_aware_alias_keeps_work_short_name() -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_request("WI-2026-01-01-001", Some("desc"))?;
+    let plan = plan_request(
+        &PrefixesConfig::default(),
+        "WI-2026-01-01-001",
+        Some("desc"),
+    )?;
     let fp = plan.field_path.ok_or("field path should exist")?;
     assert_eq!(fp.as_simple(), Some("description"));
     Ok(())
@@ -85,7 +94,11 @@ fn test_scope_aware_alias_keeps_work_short_name() -> Result<(), Box<dyn std::err
 
 #[test]
 fn test_scope_aware_alias_under_legacy_prefix() -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_request("WI-2026-01-01-001", Some("content.desc"))?;
+    let plan = plan_request(
+        &PrefixesConfig::default(),
+        "WI-2026-01-01-001",
+        Some("content.desc"),
+    )?;
     let fp = plan.field_path.ok_or("field path should exist")?;
     assert_eq!(fp.as_simple(), Some("description"));
     Ok(())
@@ -93,7 +106,11 @@ fn test_scope_aware_alias_under_legacy_prefix() -> Result<(), Box<dyn std::error
 
 #[test]
 fn test_unknown_alias_in_scope_is_not_rewritten() -> Result<(), Box<dyn std::error::Error>> {
-    let diag = match plan_request("WI-2026-01-01-001", Some("alt[0].pro[0]")) {
+    let diag = match plan_request(
+        &PrefixesConfig::default(),
+        "WI-2026-01-01-001",
+        Some("alt[0].pro[0]"),
+    ) {
         Ok(plan) => {
             return Err(format!("unknown work item field should fail, got {plan:?}").into());
         }
@@ -106,7 +123,12 @@ fn test_unknown_alias_in_scope_is_not_rewritten() -> Result<(), Box<dyn std::err
 
 #[test]
 fn test_plan_mutation_request_records_verb() -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_mutation_request("ADR-0001", "content.decision", Verb::Set)?;
+    let plan = plan_mutation_request(
+        &PrefixesConfig::default(),
+        "ADR-0001",
+        "content.decision",
+        Verb::Set,
+    )?;
     assert_eq!(plan.verb, Some(Verb::Set));
     assert_eq!(
         plan.field_path
@@ -133,7 +155,12 @@ fn test_plan_mutation_request_records_verb() -> Result<(), Box<dyn std::error::E
 #[test]
 fn test_plan_mutation_request_classifies_nested_root_item_target()
 -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_mutation_request("ADR-0001", "alternatives[0]", Verb::Remove)?;
+    let plan = plan_mutation_request(
+        &PrefixesConfig::default(),
+        "ADR-0001",
+        "alternatives[0]",
+        Verb::Remove,
+    )?;
     assert_eq!(
         plan.target,
         Some(ResolvedTarget::IndexedItem {
@@ -161,7 +188,12 @@ fn test_plan_mutation_request_classifies_nested_root_item_target()
 #[test]
 fn test_plan_mutation_request_classifies_nested_list_item_target()
 -> Result<(), Box<dyn std::error::Error>> {
-    let plan = plan_mutation_request("ADR-0001", "alternatives[0].pros[1]", Verb::Remove)?;
+    let plan = plan_mutation_request(
+        &PrefixesConfig::default(),
+        "ADR-0001",
+        "alternatives[0].pros[1]",
+        Verb::Remove,
+    )?;
     assert_eq!(
         plan.target,
         Some(ResolvedTarget::IndexedItem {
@@ -4,6 +4,7 @@ use super::adapter::{
 };
 use super::doc_target::{get_doc_field, rfc_changelog};
 use super::engine as edit_engine;
+use super::target_doc::NestedGetMode;
 use super::toml_target::get_toml_field;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticResult};
@@ -13,7 +14,7 @@ pub fn get_field(
     id: &str,
     field: Option<&str>,
 ) -> DiagnosticResult<Vec<Diagnostic>> {
-    let plan = edit_engine::plan_request(id, field)?;
+    let plan = edit_engine::plan_request(&config.prefixes, id, field)?;
     match plan.artifact {
         ArtifactType::Adr => {
             get_toml_field::<AdrTomlAdapter>(config, id, plan.target.as_ref(), ArtifactType::Adr)?
@@ -37,7 +38,7 @@ pub fn get_field(
                     id,
                     plan.target.as_ref(),
                     ArtifactType::Rfc,
-                    "RFC fields do not support nested paths",
+                    NestedGetMode::Allow,
                 )?;
             }
         }
@@ -46,7 +47,7 @@ pub fn get_field(
             id,
             plan.target.as_ref(),
             ArtifactType::Clause,
-            "Clause fields do not support nested paths",
+            NestedGetMode::Reject("Clause fields do not support nested paths"),
         )?,
         ArtifactType::Guard => get_toml_field::<GuardTomlAdapter>(
             config,
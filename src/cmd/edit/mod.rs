@@ -4,11 +4,13 @@
 
 pub mod adapter;
 mod add;
+mod alternatives;
 mod artifact;
 mod delete;
 mod delete_referrers;
 mod doc_adapter;
 mod doc_target;
+mod editor;
 pub mod engine;
 mod get;
 mod matching;
@@ -27,7 +29,9 @@ mod toml_target;
 
 use self::adapter::{ClauseTomlAdapter, DocAdapter};
 use self::add::{AddFieldRequest, add_to_field};
+pub use self::alternatives::{AlternativeUpdate, set_alternative};
 pub use self::artifact::ArtifactType;
+pub use self::editor::edit_in_editor;
 pub use self::get::get_field;
 pub use self::remove::remove_from_field;
 pub use self::request::{EditFieldRequest, OwnedEditAction};
@@ -35,15 +39,19 @@ use self::set::apply_set_field;
 pub(crate) use self::set::set_field_direct;
 pub use self::tick::tick_item;
 use self::{engine as edit_engine, path::FieldPath, rules as edit_rules};
-use crate::config::Config;
+use crate::config::{Config, PrefixesConfig};
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::ui;
-use crate::write::WriteOp;
+use crate::write::{WriteOp, with_transaction};
+pub(crate) use delete::{
+    clause_in_open_revision, ensure_clause_deletable, ensure_clause_not_referenced,
+    ensure_rfc_not_referenced,
+};
 pub use delete::{delete_clause, delete_work_item};
 pub use matching::{MatchOptions, MatchOptionsOwned};
 use std::path::Path;
 
-use self::request::{read_stdin, resolve_owned_value};
+use self::request::{read_stdin, resolve_add_values, resolve_owned_value};
 
 // Field normalization is centralized in edit_engine::plan_request.
 
@@ -84,11 +92,12 @@ pub(super) struct PlannedMutation {
 }
 
 pub(super) fn plan_mutation_target(
+    prefixes: &PrefixesConfig,
     id: &str,
     field: &str,
     verb: edit_rules::Verb,
 ) -> DiagnosticResult<PlannedMutation> {
-    let plan = edit_engine::plan_mutation_request(id, field, verb)?;
+    let plan = edit_engine::plan_mutation_request(prefixes, id, field, verb)?;
     let field_path = plan.field_path.ok_or_else(|| {
         Diagnostic::new(
             DiagnosticCode::E0801MissingRequiredArg,
@@ -132,7 +141,7 @@ pub fn edit_clause(
         _ => unreachable!("clap arg group ensures mutual exclusivity"),
     };
 
-    clause_doc.data.text = new_text;
+    clause_doc.data.text = Some(new_text);
     ClauseTomlAdapter::write(config, &clause_doc, op)?;
 
     if !op.is_preview() {
@@ -157,7 +166,7 @@ pub fn edit_field(request: EditFieldRequest<'_>) -> DiagnosticResult<Vec<Diagnos
     match action {
         OwnedEditAction::Set { value, stdin } => {
             let value = resolve_owned_value(value.as_ref(), *stdin)?;
-            let plan = plan_mutation_target(id, path, edit_rules::Verb::Set)?;
+            let plan = plan_mutation_target(&config.prefixes, id, path, edit_rules::Verb::Set)?;
             apply_set_field(
                 config,
                 id,
@@ -172,18 +181,36 @@ pub fn edit_field(request: EditFieldRequest<'_>) -> DiagnosticResult<Vec<Diagnos
             }
             Ok(vec![])
         }
-        OwnedEditAction::Add { value, stdin } => {
-            let value = resolve_owned_value(value.as_ref(), *stdin)?;
-            add_to_field(AddFieldRequest {
-                config,
-                id,
-                field: path,
-                value: value.as_str(),
-                category_override,
-                pros,
-                cons,
-                reject_reason,
-                op,
+        OwnedEditAction::Add {
+            values,
+            stdin,
+            lines,
+        } => {
+            let values = resolve_add_values(values, *stdin, *lines)?;
+            if values.len() > 1 && (pros.is_some() || cons.is_some() || reject_reason.is_some()) {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E0802ConflictingArgs,
+                    "--pro, --con, and --reject-reason describe one alternative; add alternatives one at a time",
+                    id,
+                ));
+            }
+            // Every value lands, or none does.
+            with_transaction(op, |op| {
+                let mut diagnostics = Vec::new();
+                for value in &values {
+                    diagnostics.extend(add_to_field(AddFieldRequest {
+                        config,
+                        id,
+                        field: path,
+                        value: value.as_str(),
+                        category_override,
+                        pros: pros.clone(),
+                        cons: cons.clone(),
+                        reject_reason: reject_reason.clone(),
+                        op,
+                    })?);
+                }
+                Ok(diagnostics)
             })
         }
         OwnedEditAction::Remove { match_opts } => {
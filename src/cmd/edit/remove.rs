@@ -17,7 +17,7 @@ pub fn remove_from_field(
     opts: &MatchOptions,
     op: WriteOp,
 ) -> DiagnosticResult<Vec<Diagnostic>> {
-    let plan = plan_mutation_target(id, field, edit_rules::Verb::Remove)?;
+    let plan = plan_mutation_target(&config.prefixes, id, field, edit_rules::Verb::Remove)?;
     let artifact = plan.artifact;
     let target = &plan.target;
     reject_match_flags_for_indexed_target(id, target, opts)?;
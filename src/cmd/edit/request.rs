@@ -12,8 +12,10 @@ pub enum OwnedEditAction {
         stdin: bool,
     },
     Add {
-        value: Option<Option<String>>,
+        values: Vec<String>,
         stdin: bool,
+        /// Split stdin into one item per non-empty line.
+        lines: bool,
     },
     Remove {
         match_opts: MatchOptionsOwned,
@@ -33,7 +35,7 @@ pub struct EditFieldRequest<'a> {
     pub pros: Option<Vec<String>>,
     pub cons: Option<Vec<String>>,
     pub reject_reason: Option<String>,
-    pub op: WriteOp,
+    pub op: WriteOp<'a>,
 }
 
 pub(super) fn read_stdin() -> DiagnosticResult<String> {
@@ -68,3 +70,40 @@ pub(super) fn resolve_owned_value(
         )),
     }
 }
+
+pub(super) fn resolve_add_values(
+    values: &[String],
+    stdin: bool,
+    lines: bool,
+) -> DiagnosticResult<Vec<String>> {
+    match (values, stdin) {
+        ([], true) if lines => {
+            let items: Vec<String> = read_stdin()?
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect();
+            if items.is_empty() {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E0801MissingRequiredArg,
+                    "No lines to add on stdin",
+                    "input",
+                ));
+            }
+            Ok(items)
+        }
+        ([], true) => Ok(vec![read_stdin()?]),
+        ([], false) => Err(Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "Provide a value or use --stdin",
+            "input",
+        )),
+        (_, true) => Err(Diagnostic::new(
+            DiagnosticCode::E0802ConflictingArgs,
+            "Cannot use both value and --stdin",
+            "input",
+        )),
+        (values, false) => Ok(values.to_vec()),
+    }
+}
@@ -16,7 +16,7 @@ pub(crate) fn set_field_direct(
     value: &str,
     op: WriteOp,
 ) -> DiagnosticResult<()> {
-    let plan = plan_mutation_target(id, field, edit_rules::Verb::Set)?;
+    let plan = plan_mutation_target(&config.prefixes, id, field, edit_rules::Verb::Set)?;
     apply_set_field(config, id, &plan.target, plan.artifact, value, op, false)
 }
 
@@ -55,6 +55,9 @@ pub(super) fn apply_set_field(
                     id,
                 ));
             }
+            if fp.as_simple() == Some("jira_key") {
+                crate::jira::validate_issue_key(value, id)?;
+            }
             set_work_toml_field(config, id, target, value, op, !enforce_verb_ownership)?
         }
         ArtifactType::Rfc => {
@@ -21,7 +21,7 @@ pub fn tick_item(
     status: crate::TickStatus,
     op: WriteOp,
 ) -> DiagnosticResult<Vec<Diagnostic>> {
-    let plan = plan_mutation_target(id, field, edit_rules::Verb::Tick)?;
+    let plan = plan_mutation_target(&config.prefixes, id, field, edit_rules::Verb::Tick)?;
     let artifact = plan.artifact;
     let target = &plan.target;
     reject_match_flags_for_indexed_target(id, target, opts)?;
@@ -1,8 +1,10 @@
 use super::adapter::{TomlAdapter, display_scope_for_dir};
+use crate::artifact_catalog::{CatalogKind, missing_id_message};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::model::{AdrEntry, GuardEntry, WorkItemEntry};
 use crate::parse::{load_work_items, write_adr, write_guard, write_work_item};
+use crate::secrets::{reveal_adr, seal_adr};
 use crate::write::WriteOp;
 use std::path::PathBuf;
 
@@ -13,7 +15,7 @@ fn load_toml_entry<T, Load, LoadError, Matches>(
     load: Load,
     matches: Matches,
     missing_code: DiagnosticCode,
-    missing_kind: &str,
+    missing_kind: CatalogKind,
 ) -> Result<T, Diagnostic>
 where
     Load: FnOnce(&Config) -> Result<Vec<T>, LoadError>,
@@ -28,7 +30,7 @@ where
         .ok_or_else(|| {
             Diagnostic::new(
                 missing_code,
-                format!("{missing_kind} not found: {id}"),
+                missing_id_message(config, missing_kind, id),
                 &scope,
             )
         })
@@ -41,16 +43,21 @@ impl TomlAdapter for AdrTomlAdapter {
     type Entry = AdrEntry;
 
     fn load(config: &Config, id: &str) -> DiagnosticResult<Self::Entry> {
-        crate::artifact_catalog::load_adr_by_id(config, id)
+        let mut entry = crate::artifact_catalog::load_adr_by_id(config, id)?;
+        let display = config.display_path(&entry.path).display().to_string();
+        reveal_adr(config, &mut entry.spec, &display)?;
+        Ok(entry)
     }
 
     fn write(config: &Config, entry: &Self::Entry, op: WriteOp) -> DiagnosticResult<()> {
-        write_adr(
-            &entry.path,
+        let display = config.display_path(&entry.path);
+        let spec = seal_adr(
+            config,
             &entry.spec,
-            op,
-            Some(&config.display_path(&entry.path)),
-        )
+            &entry.path,
+            &display.display().to_string(),
+        )?;
+        write_adr(&entry.path, &spec, op, Some(&display))
     }
 }
 
@@ -76,7 +83,7 @@ impl TomlAdapter for WorkTomlAdapter {
             load_work_items,
             |entry, id| entry.spec.govctl.id == id || entry.path.to_string_lossy().contains(id),
             DiagnosticCode::E0402WorkNotFound,
-            "Work item",
+            CatalogKind::Work,
         )
     }
 
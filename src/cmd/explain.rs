@@ -0,0 +1,121 @@
+//! `govctl explain`: what a diagnostic code means and how to resolve it.
+
+use crate::OutputFormat;
+use crate::cmd::output::{print_json, print_json_array, table_with_bold_headers};
+use crate::diagnostic::{
+    Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult, Diagnostics,
+};
+use comfy_table::Cell;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct ExplainEntry {
+    code: &'static str,
+    level: &'static str,
+    /// Exit code of a command failing with this error; warnings only fail
+    /// a run when denied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<u8>,
+    summary: &'static str,
+    causes: &'static [&'static str],
+    fixes: &'static [&'static str],
+    clauses: &'static [&'static str],
+}
+
+impl ExplainEntry {
+    fn new(code: DiagnosticCode) -> Self {
+        let explanation = code.explanation();
+        Self {
+            code: code.code(),
+            level: match code.level() {
+                DiagnosticLevel::Error => "error",
+                DiagnosticLevel::Warning => "warning",
+                DiagnosticLevel::Info => "info",
+            },
+            exit_code: (code.level() == DiagnosticLevel::Error)
+                .then(|| code.failure_class().exit_code()),
+            summary: explanation.summary,
+            causes: explanation.causes,
+            fixes: explanation.fixes,
+            clauses: explanation.clauses,
+        }
+    }
+}
+
+/// Explain `code`, or list every code with its summary when none is given.
+pub fn explain(code: Option<&str>, output: OutputFormat) -> DiagnosticResult<Diagnostics> {
+    let Some(code) = code else {
+        list_codes(output);
+        return Ok(vec![]);
+    };
+    let known = DiagnosticCode::from_code(code).ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0828UnknownDiagnostic,
+            format!("Unknown diagnostic code: {code} (hint: `govctl explain` lists all codes)"),
+            "explain",
+        )
+    })?;
+    let entry = ExplainEntry::new(known);
+    if output == OutputFormat::Json {
+        print_json(
+            &entry,
+            DiagnosticCode::E0903UnexpectedError,
+            "Failed to serialize explanation",
+            "explain",
+        )?;
+        return Ok(vec![]);
+    }
+
+    let heading = match entry.exit_code {
+        Some(exit_code) => format!("{} ({}, exit code {exit_code})", entry.code, entry.level),
+        None => format!("{} ({})", entry.code, entry.level),
+    };
+    if crate::ui::stdout_supports_color() {
+        println!("{}", heading.bold());
+    } else {
+        println!("{heading}");
+    }
+    println!("{}", entry.summary);
+    print_list("Typical causes", entry.causes);
+    print_list("How to fix", entry.fixes);
+    print_list("Related clauses (govctl specification)", entry.clauses);
+    Ok(vec![])
+}
+
+fn print_list(title: &str, items: &[&str]) {
+    if items.is_empty() {
+        return;
+    }
+    println!("\n{title}:");
+    for item in items {
+        println!("  - {item}");
+    }
+}
+
+fn list_codes(output: OutputFormat) {
+    let entries: Vec<ExplainEntry> = DiagnosticCode::all()
+        .iter()
+        .copied()
+        .map(ExplainEntry::new)
+        .collect();
+    match output {
+        OutputFormat::Json => print_json_array(&entries),
+        OutputFormat::Plain => {
+            for entry in &entries {
+                println!("{}\t{}", entry.code, entry.summary);
+            }
+        }
+        OutputFormat::Table => {
+            let mut table = table_with_bold_headers(&["Code", "Level", "Summary"]);
+            for entry in &entries {
+                table.add_row(vec![
+                    Cell::new(entry.code),
+                    Cell::new(entry.level),
+                    Cell::new(entry.summary),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+}
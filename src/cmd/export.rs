@@ -0,0 +1,41 @@
+//! `govctl export`: publish this project's artifact IDs for other
+//! repositories that reference them as `name:ID` remotes.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project;
+use crate::remotes::RefBundle;
+use crate::ui;
+use crate::write::{WriteOp, write_file};
+use std::path::Path;
+
+/// Write the reference bundle for this project to `out`.
+pub fn export(config: &Config, out: &Path, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    let index = load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for export",
+                config.gov_root.display().to_string(),
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    let bundle = RefBundle::from_index(&config.project.name, &index);
+    let content = serde_json::to_string_pretty(&bundle).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Failed to serialize export bundle: {err}"),
+            out.display().to_string(),
+        )
+    })?;
+
+    let display_path = config.display_path(out);
+    write_file(out, &format!("{content}\n"), op, Some(&display_path))?;
+    if !op.is_preview() {
+        ui::created("export bundle", &display_path);
+        ui::sub_info(format!("{} artifacts", bundle.artifacts.len()));
+    }
+    Ok(vec![])
+}
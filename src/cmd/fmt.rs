@@ -0,0 +1,310 @@
+//! `govctl fmt`: rewrite artifact files in canonical form.
+//!
+//! The canonical form is what govctl writes for a new artifact: the
+//! `#:schema` header, keys in schema order, and one trailing newline. With
+//! `format.wrap_width` set, prose fields are also re-wrapped to that width.
+//! Clause text of normative RFCs is not re-wrapped, since it is part of the
+//! signed amendment baseline.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project_with_warnings;
+use crate::model::{ClauseWire, RfcStatus, RfcWire};
+use crate::parse::{load_guards_with_warnings, load_mappings_with_warnings, load_releases};
+use crate::schema::{ArtifactSchema, with_schema_header};
+use crate::ui;
+use crate::write::{WriteOp, write_file};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Format every artifact file, or with `check` report the ones that differ.
+/// Files that fail to load are reported and left alone.
+pub fn fmt(config: &Config, check: bool, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    let (files, skipped) = canonical_files(config)?;
+    let mut unformatted = Vec::new();
+    for (path, content) in files {
+        let current = std::fs::read_to_string(&path).map_err(|err| {
+            Diagnostic::io_error(
+                "read artifact file",
+                err,
+                config.display_path(&path).display().to_string(),
+            )
+        })?;
+        if current != content {
+            unformatted.push((path, content));
+        }
+    }
+
+    if unformatted.is_empty() {
+        if skipped.is_empty() {
+            ui::success("All artifact files are formatted");
+        }
+        return Ok(skipped);
+    }
+    if check {
+        let paths: Vec<String> = unformatted
+            .iter()
+            .map(|(path, _)| config.display_path(path).display().to_string())
+            .collect();
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0827ArtifactsNotFormatted,
+            format!(
+                "{} artifact file(s) not formatted: {} (run govctl fmt)",
+                paths.len(),
+                paths.join(", ")
+            ),
+            config.display_path(&config.gov_root).display().to_string(),
+        ));
+    }
+    for (path, content) in &unformatted {
+        let display_path = config.display_path(path);
+        write_file(path, content, op, Some(&display_path))?;
+        if !op.is_preview() {
+            ui::formatted(&display_path);
+        }
+    }
+    Ok(skipped)
+}
+
+/// Each artifact file with the content `fmt` gives it, and the load errors
+/// of the files it cannot read.
+fn canonical_files(config: &Config) -> DiagnosticResult<(Vec<(PathBuf, String)>, Diagnostics)> {
+    let loaded = load_project_with_warnings(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for formatting",
+                config.gov_root.display().to_string(),
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    let index = loaded.index;
+    let mut skipped = loaded.warnings;
+    let width = config.format.wrap_width;
+    let mut files = Vec::new();
+
+    for rfc in &index.rfcs {
+        let wire = RfcWire::from(rfc.rfc.clone());
+        files.push(canonical(config, &rfc.path, ArtifactSchema::Rfc, &wire)?);
+        for clause in &rfc.clauses {
+            let mut spec = clause.spec.clone();
+            if rfc.rfc.status == RfcStatus::Draft
+                && let Some(text) = spec.text.as_mut()
+            {
+                wrap_field(text, width);
+            }
+            let wire = ClauseWire::try_from(spec)?;
+            files.push(canonical(
+                config,
+                &clause.path,
+                ArtifactSchema::Clause,
+                &wire,
+            )?);
+        }
+    }
+    for adr in &index.adrs {
+        let mut spec = adr.spec.clone();
+        wrap_field(&mut spec.content.context, width);
+        wrap_field(&mut spec.content.decision, width);
+        wrap_field(&mut spec.content.consequences, width);
+        files.push(canonical(config, &adr.path, ArtifactSchema::Adr, &spec)?);
+    }
+    for item in &index.work_items {
+        let mut spec = item.spec.clone();
+        wrap_field(&mut spec.content.description, width);
+        files.push(canonical(
+            config,
+            &item.path,
+            ArtifactSchema::WorkItem,
+            &spec,
+        )?);
+    }
+    let guards = load_guards_with_warnings(config)?;
+    skipped.extend(guards.warnings);
+    for guard in guards.items {
+        files.push(canonical(
+            config,
+            &guard.path,
+            ArtifactSchema::Guard,
+            &guard.spec,
+        )?);
+    }
+    let mappings = load_mappings_with_warnings(config)?;
+    skipped.extend(mappings.warnings);
+    for mapping in mappings.items {
+        files.push(canonical(
+            config,
+            &mapping.path,
+            ArtifactSchema::Mapping,
+            &mapping.spec,
+        )?);
+    }
+    let releases_path = config.releases_path();
+    if releases_path.exists() {
+        let releases = load_releases(config)?;
+        files.push(canonical(
+            config,
+            &releases_path,
+            ArtifactSchema::Release,
+            &releases,
+        )?);
+    }
+    Ok((files, skipped))
+}
+
+fn canonical<T: Serialize>(
+    config: &Config,
+    path: &Path,
+    schema: ArtifactSchema,
+    spec: &T,
+) -> DiagnosticResult<(PathBuf, String)> {
+    let body = toml::to_string_pretty(spec).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0901IoError,
+            format!("Failed to serialize TOML: {err}"),
+            config.display_path(path).display().to_string(),
+        )
+    })?;
+    Ok((path.to_path_buf(), with_schema_header(schema, &body)))
+}
+
+fn wrap_field(text: &mut String, width: usize) {
+    if width > 0 {
+        *text = wrap_prose(text, width);
+    }
+}
+
+/// Re-wrap the paragraphs and list items of markdown `text` at `width`
+/// columns. Code blocks, headings, tables, quotes, and hard line breaks are
+/// kept as written.
+fn wrap_prose(text: &str, width: usize) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut block: Vec<&str> = Vec::new();
+    let mut in_fence = false;
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+        // Indented code cannot interrupt a paragraph, so indented lines
+        // inside one are continuation lines.
+        let indented_code =
+            block.is_empty() && (line.starts_with("    ") || line.starts_with('\t'));
+        if fence || in_fence || indented_code || is_verbatim(trimmed) {
+            out.extend(fill(&block, width));
+            block.clear();
+            in_fence ^= fence;
+            out.push(line.to_string());
+            continue;
+        }
+        if list_marker(line).is_some() {
+            out.extend(fill(&block, width));
+            block.clear();
+        }
+        block.push(line);
+        if line.ends_with("  ") || line.ends_with('\\') {
+            out.extend(fill(&block, width));
+            block.clear();
+        }
+    }
+    out.extend(fill(&block, width));
+
+    let mut wrapped = out.join("\n");
+    if text.ends_with('\n') {
+        wrapped.push('\n');
+    }
+    wrapped
+}
+
+/// Blank lines, headings, tables, quotes, HTML, and thematic breaks.
+fn is_verbatim(trimmed: &str) -> bool {
+    trimmed.is_empty()
+        || trimmed.starts_with(['#', '|', '>', '<'])
+        || (trimmed.len() >= 3
+            && trimmed
+                .chars()
+                .all(|ch| matches!(ch, '-' | '=' | '*' | '_')))
+}
+
+/// Byte length of a list item's indent, marker, and following spaces.
+fn list_marker(line: &str) -> Option<usize> {
+    let rest = line.trim_start();
+    let indent = line.len() - rest.len();
+    let marker = if rest.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 || !rest[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        digits + 1
+    };
+    let after = &rest[marker..];
+    let spaces = after.len() - after.trim_start_matches(' ').len();
+    (spaces > 0 && !after.trim().is_empty()).then_some(indent + marker + spaces)
+}
+
+/// Greedily fill the words of one paragraph or list item into lines,
+/// indenting continuation lines under the item's text.
+fn fill(lines: &[&str], width: usize) -> Vec<String> {
+    let Some((first, rest)) = lines.split_first() else {
+        return vec![];
+    };
+    let lead = list_marker(first).unwrap_or(first.len() - first.trim_start().len());
+    let hang = " ".repeat(lead);
+    let words = first[lead..]
+        .split_whitespace()
+        .chain(rest.iter().flat_map(|line| line.split_whitespace()));
+
+    let mut filled = Vec::new();
+    let mut current = first[..lead].to_string();
+    let mut current_width = lead;
+    let mut empty = true;
+    for word in words {
+        let word_width = word.chars().count();
+        if !empty && current_width + 1 + word_width > width {
+            filled.push(std::mem::replace(&mut current, hang.clone()));
+            current_width = lead;
+            empty = true;
+        }
+        if !empty {
+            current.push(' ');
+            current_width += 1;
+        }
+        current.push_str(word);
+        current_width += word_width;
+        empty = false;
+    }
+    if lines.last().is_some_and(|line| line.ends_with("  ")) {
+        current.push_str("  ");
+    }
+    filled.push(current);
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wrap_prose;
+
+    #[test]
+    fn wrap_prose_reflows_paragraphs_and_list_items() {
+        let text = "The cache keeps\nresponses for a short time before they expire.\n\n\
+                    - Entries are evicted\n  when full and the oldest goes first.\n\
+                    1. Numbered items wrap too, under their text.";
+        assert_eq!(
+            wrap_prose(text, 30),
+            "The cache keeps responses for\na short time before they\nexpire.\n\n\
+             - Entries are evicted when\n  full and the oldest goes\n  first.\n\
+             1. Numbered items wrap too,\n   under their text."
+        );
+    }
+
+    #[test]
+    fn wrap_prose_keeps_code_headings_and_tables() {
+        let text = "## A heading that is far too long to fit\n\n\
+                    ```rust\nlet value = compute(a, b, c, d, e, f, g);\n```\n\n\
+                    | column | other column |\n\
+                    Hard break here  \nnext line\n";
+        assert_eq!(wrap_prose(text, 20), text);
+    }
+}
@@ -48,11 +48,10 @@ pub fn new_guard(config: &Config, title: &str, op: WriteOp) -> DiagnosticResult<
 
     let spec = GuardSpec {
         govctl: GuardMeta::new(id.clone(), title),
-        check: GuardCheck {
-            command: "echo 'GUARD NOT CONFIGURED: replace this command' && exit 1".to_string(),
-            timeout_secs: 300,
-            pattern: None,
-        },
+        check: GuardCheck::new(
+            "echo 'GUARD NOT CONFIGURED: replace this command' && exit 1",
+            300,
+        ),
     };
 
     write_guard(&path, &spec, op, Some(&config.display_path(&path)))?;
@@ -0,0 +1,389 @@
+//! Operation history: `govctl undo` and `govctl history`.
+//!
+//! Every write command records the prior content of each file it changed in
+//! `.govctl/history/<seq>/`, alongside the other local state, so the most
+//! recent operation can be reverted. Only the last [`HISTORY_LIMIT`] entries
+//! are kept.
+
+use crate::OutputFormat;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::identity::Actor;
+use crate::ui;
+use crate::write::{
+    RecordedChanges, WriteOp, delete_file, with_recorded_transaction, with_transaction,
+    write_file_bytes,
+};
+use comfy_table::Cell;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Number of operations kept for undo.
+const HISTORY_LIMIT: usize = 20;
+
+const RECORD_FILE: &str = "operation.toml";
+
+#[derive(Serialize, Deserialize)]
+struct OperationRecord {
+    seq: u64,
+    timestamp: String,
+    command: String,
+    /// Actor the command ran as, `human:<name>` or `agent:<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    actor: Option<String>,
+    #[serde(default)]
+    files: Vec<FileRecord>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    created_dirs: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileRecord {
+    /// Path relative to the project root.
+    path: String,
+    /// Blob holding the prior content; absent when the command created the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    before: Option<String>,
+    /// Hash of the content the command left; absent when it deleted the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    after_sha256: Option<String>,
+}
+
+#[derive(Serialize)]
+struct HistoryEntry<'a> {
+    seq: u64,
+    timestamp: &'a str,
+    command: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actor: Option<&'a str>,
+    files: Vec<&'a str>,
+}
+
+/// Run a write command as `actor` and record what it changed for
+/// `govctl undo`. The command writes with the op it is handed.
+pub fn record(
+    config: &Config,
+    command: &str,
+    actor: Option<&Actor>,
+    op: WriteOp,
+    operation: impl FnOnce(WriteOp) -> DiagnosticResult<Diagnostics>,
+) -> DiagnosticResult<Diagnostics> {
+    let (diagnostics, changes) = with_recorded_transaction(op, operation)?;
+    if !op.is_preview()
+        && let Err(diag) = save_record(config, command, actor, changes)
+    {
+        ui::hint(format!("Operation not recorded for undo: {}", diag.message));
+    }
+    Ok(diagnostics)
+}
+
+/// Revert the most recent recorded operation.
+pub fn undo(config: &Config, force: bool, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    let Some((dir, record)) = load_records(config)?.into_iter().next() else {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E1301HistoryEmpty,
+            "No recorded operations to undo",
+            config
+                .display_path(&history_root(config))
+                .display()
+                .to_string(),
+        ));
+    };
+
+    let root = config.project_root();
+    let changed: Vec<&str> = record
+        .files
+        .iter()
+        .filter(|file| current_hash(&root.join(&file.path)) != file.after_sha256)
+        .map(|file| file.path.as_str())
+        .collect();
+    if !changed.is_empty() && !force {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E1302HistoryConflict,
+            format!(
+                "Cannot undo `{}`: files changed since it ran (use --force to overwrite)",
+                record.command
+            ),
+            changed.join(", "),
+        ));
+    }
+
+    with_transaction(op, |op| {
+        for file in record.files.iter().rev() {
+            let path = root.join(&file.path);
+            let display = Path::new(&file.path);
+            match &file.before {
+                Some(blob) => {
+                    let content = std::fs::read(dir.join(blob)).map_err(|err| {
+                        Diagnostic::io_error(
+                            "read history blob",
+                            err,
+                            config.display_path(&dir.join(blob)).display().to_string(),
+                        )
+                    })?;
+                    if let Some(parent) = path.parent() {
+                        crate::write::create_dir_all(parent, op, None)?;
+                    }
+                    write_file_bytes(&path, &content, op, Some(display))?;
+                }
+                None if path.exists() => delete_file(&path, op, Some(display))?,
+                None => {}
+            }
+        }
+        Ok(())
+    })?;
+
+    if op.is_preview() {
+        ui::info(format!("Would undo #{}: {}", record.seq, record.command));
+        return Ok(vec![]);
+    }
+
+    let mut created_dirs: Vec<PathBuf> = record
+        .created_dirs
+        .iter()
+        .map(|dir| root.join(dir))
+        .collect();
+    created_dirs.sort_by_key(|dir| std::cmp::Reverse(dir.components().count()));
+    for created in created_dirs {
+        // Only empty directories go; anything else was added after the command.
+        let _ = std::fs::remove_dir(created);
+    }
+    std::fs::remove_dir_all(&dir).map_err(|err| {
+        Diagnostic::io_error(
+            "remove history entry",
+            err,
+            config.display_path(&dir).display().to_string(),
+        )
+    })?;
+
+    ui::success(format!("Undid #{}: {}", record.seq, record.command));
+    for file in &record.files {
+        ui::sub_info(&file.path);
+    }
+    Ok(vec![])
+}
+
+/// List recorded operations, newest first.
+pub fn show_history(
+    config: &Config,
+    limit: Option<usize>,
+    output: OutputFormat,
+) -> DiagnosticResult<Diagnostics> {
+    let records = load_records(config)?;
+    let entries: Vec<HistoryEntry> = records
+        .iter()
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|(_, record)| HistoryEntry {
+            seq: record.seq,
+            timestamp: &record.timestamp,
+            command: &record.command,
+            actor: record.actor.as_deref(),
+            files: record.files.iter().map(|file| file.path.as_str()).collect(),
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => print_json_array(&entries),
+        OutputFormat::Plain => {
+            for entry in &entries {
+                println!("{}\t{}\t{}", entry.seq, entry.timestamp, entry.command);
+            }
+        }
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                ui::info("No recorded operations");
+                return Ok(vec![]);
+            }
+            let mut table = table_with_bold_headers(&["#", "When", "Actor", "Command", "Files"]);
+            for entry in &entries {
+                table.add_row(vec![
+                    Cell::new(entry.seq.to_string()),
+                    Cell::new(entry.timestamp),
+                    Cell::new(entry.actor.unwrap_or("")),
+                    Cell::new(entry.command),
+                    Cell::new(entry.files.join("\n")),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(vec![])
+}
+
+/// Render command-line arguments as a copyable `govctl ...` line.
+pub(crate) fn command_line(args: &[String]) -> String {
+    std::iter::once("govctl".to_string())
+        .chain(args.iter().map(|arg| {
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                format!("\"{arg}\"")
+            } else {
+                arg.clone()
+            }
+        }))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Stands in for a sensitive value in a recorded command line.
+const REDACTED_ARG: &str = "<redacted>";
+
+/// `args` with every occurrence of a value in `secrets` replaced, so the
+/// command line can be recorded without them.
+pub(crate) fn redact_args(args: &[String], secrets: &[&str]) -> Vec<String> {
+    args.iter()
+        .map(|arg| {
+            secrets
+                .iter()
+                .filter(|secret| !secret.is_empty())
+                .fold(arg.clone(), |arg, secret| arg.replace(secret, REDACTED_ARG))
+        })
+        .collect()
+}
+
+fn history_root(config: &Config) -> PathBuf {
+    config.project_root().join(".govctl").join("history")
+}
+
+fn save_record(
+    config: &Config,
+    command: &str,
+    actor: Option<&Actor>,
+    changes: RecordedChanges,
+) -> DiagnosticResult<()> {
+    let root = config.project_root();
+    let relative = |path: &Path| {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    let mut files = Vec::new();
+    let mut blobs = Vec::new();
+    for change in changes.files {
+        let after = std::fs::read(&change.path).ok();
+        if after == change.before {
+            continue;
+        }
+        let before = change.before.map(|content| {
+            let name = format!("files/{}", blobs.len());
+            blobs.push((name.clone(), content));
+            name
+        });
+        files.push(FileRecord {
+            path: relative(&change.path),
+            before,
+            after_sha256: after.as_deref().map(sha256_hex),
+        });
+    }
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let records = load_records(config)?;
+    let seq = records.first().map_or(1, |(_, record)| record.seq + 1);
+    let record = OperationRecord {
+        seq,
+        timestamp: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        command: command.to_string(),
+        actor: actor.map(|actor| actor.as_str().to_string()),
+        files,
+        created_dirs: changes
+            .created_dirs
+            .iter()
+            .filter(|dir| dir.is_dir())
+            .map(|dir| relative(dir))
+            .collect(),
+    };
+
+    let dir = history_root(config).join(format!("{seq:06}"));
+    let display = |path: &Path| config.display_path(path);
+    let op = WriteOp::EXECUTE.with_fsync(config.concurrency.fsync);
+    crate::write::create_dir_all(&dir.join("files"), op, Some(&display(&dir)))?;
+    for (name, content) in &blobs {
+        let path = dir.join(name);
+        write_file_bytes(&path, content, op, Some(&display(&path)))?;
+    }
+    // The record goes last: a directory without one is an incomplete entry.
+    let body = toml::to_string_pretty(&record).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E1303HistoryInvalid,
+            format!("Failed to serialize operation record: {err}"),
+            display(&dir).display().to_string(),
+        )
+    })?;
+    let path = dir.join(RECORD_FILE);
+    crate::write::write_file(&path, &body, op, Some(&display(&path)))?;
+
+    prune(config, &history_root(config))
+}
+
+/// Drop all but the newest entries, including incomplete ones.
+fn prune(config: &Config, history: &Path) -> DiagnosticResult<()> {
+    let mut dirs = entry_dirs(history)?;
+    dirs.sort_by_key(|(seq, _)| std::cmp::Reverse(*seq));
+    for (_, dir) in dirs.into_iter().skip(HISTORY_LIMIT) {
+        std::fs::remove_dir_all(&dir).map_err(|err| {
+            Diagnostic::io_error(
+                "prune history entry",
+                err,
+                config.display_path(&dir).display().to_string(),
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Complete history entries, newest first.
+fn load_records(config: &Config) -> DiagnosticResult<Vec<(PathBuf, OperationRecord)>> {
+    let mut records = Vec::new();
+    for (_, dir) in entry_dirs(&history_root(config))? {
+        let path = dir.join(RECORD_FILE);
+        let Ok(body) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let record: OperationRecord = toml::from_str(&body).map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E1303HistoryInvalid,
+                format!("Invalid operation record: {err}"),
+                config.display_path(&path).display().to_string(),
+            )
+        })?;
+        records.push((dir, record));
+    }
+    records.sort_by_key(|(_, record)| std::cmp::Reverse(record.seq));
+    Ok(records)
+}
+
+fn entry_dirs(history: &Path) -> DiagnosticResult<Vec<(u64, PathBuf)>> {
+    let entries = match std::fs::read_dir(history) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => {
+            return Err(Diagnostic::io_error(
+                "read history directory",
+                err,
+                history.display().to_string(),
+            ));
+        }
+    };
+    Ok(entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let seq = entry.file_name().to_str()?.parse::<u64>().ok()?;
+            entry.path().is_dir().then(|| (seq, entry.path()))
+        })
+        .collect())
+}
+
+fn current_hash(path: &Path) -> Option<String> {
+    std::fs::read(path).ok().as_deref().map(sha256_hex)
+}
+
+fn sha256_hex(content: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content);
+    format!("{:x}", hasher.finalize())
+}
@@ -1,11 +1,14 @@
 use crate::cmd::edit;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
-use crate::model::{AdrEntry, AdrStatus, AlternativeStatus};
+use crate::model::{AdrEntry, AdrStatus, AlternativeStatus, Deprecation};
 use crate::parse::load_adrs;
 use crate::parse::write_adr;
 use crate::ui;
-use crate::validate::{is_valid_adr_transition, validate_adr_projection};
+use crate::validate::{
+    is_valid_adr_transition, require_adr_approvals, validate_adr_projection,
+    validate_owner_identity,
+};
 use crate::write::WriteOp;
 
 fn adr_not_found(adr_id: &str) -> Diagnostic {
@@ -29,7 +32,7 @@ fn adr_matches_lifecycle_lookup(entry: &AdrEntry, adr_id: &str) -> bool {
 }
 
 fn load_lifecycle_adr(config: &Config, adr_id: &str) -> DiagnosticResult<AdrEntry> {
-    if adr_id.starts_with("ADR-") {
+    if config.prefixes.is_adr(adr_id) {
         // [[RFC-0002:C-LIFECYCLE-VERBS]]: ADR lifecycle verbs operate on
         // existing ADR IDs; normalize catalog misses to the ADR not-found path.
         return crate::artifact_catalog::load_adr_by_id(config, adr_id).map_err(|err| {
@@ -121,6 +124,12 @@ pub fn accept_adr(
     if !force {
         validate_adr_completeness(config, adr_id)?;
     }
+    require_adr_approvals(
+        config,
+        &entry.spec.govctl.id,
+        &entry.spec.govctl.approvals,
+        adr_id,
+    )?;
 
     edit::set_field_direct(config, adr_id, "status", "accepted", op)?;
 
@@ -130,6 +139,83 @@ pub fn accept_adr(
     Ok(vec![])
 }
 
+/// Record `approver`'s approval of a proposed ADR.
+pub(super) fn approve_adr(
+    config: &Config,
+    adr_id: &str,
+    approver: &str,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let mut entry = load_lifecycle_adr(config, adr_id)?;
+    if entry.spec.govctl.status != AdrStatus::Proposed {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0303AdrInvalidTransition,
+            format!(
+                "Cannot approve {adr_id}: only proposed ADRs take approvals (status: {})",
+                entry.spec.govctl.status.as_ref()
+            ),
+            adr_id,
+        ));
+    }
+    validate_owner_identity(config, adr_id, "approvals", approver, adr_id)?;
+    let approvals = &mut entry.spec.govctl.approvals;
+    if approvals.iter().any(|existing| existing == approver) {
+        ui::info(format!("{adr_id} is already approved by {approver}"));
+        return Ok(vec![]);
+    }
+
+    approvals.push(approver.to_string());
+    let count = approvals.len();
+    write_adr(
+        &entry.path,
+        &entry.spec,
+        op,
+        Some(&config.display_path(&entry.path)),
+    )?;
+    if !op.is_preview() {
+        ui::approved(adr_id, approver, count, config.owners.adr_quorum);
+    }
+    Ok(vec![])
+}
+
+/// Record a sunset on an accepted ADR. ADRs are never deprecated, so the
+/// status stays accepted; the notice tells readers a replacement is due.
+pub(super) fn schedule_adr_sunset(
+    config: &Config,
+    adr_id: &str,
+    notice: &Deprecation,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let mut entry = load_lifecycle_adr(config, adr_id)?;
+    if entry.spec.govctl.status != AdrStatus::Accepted {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0305AdrCannotDeprecate,
+            format!(
+                "Cannot schedule a sunset for {adr_id}: only accepted ADRs take one (status: {})",
+                entry.spec.govctl.status.as_ref()
+            ),
+            adr_id,
+        ));
+    }
+
+    entry.spec.govctl.deprecation = Some(notice.clone());
+    write_adr(
+        &entry.path,
+        &entry.spec,
+        op,
+        Some(&config.display_path(&entry.path)),
+    )?;
+    if !op.is_preview() {
+        match &notice.sunset {
+            Some(sunset) => ui::success(format!(
+                "Scheduled {adr_id} sunset for {sunset} (supersede it before then)"
+            )),
+            None => ui::success(format!("Recorded deprecation notice on {adr_id}")),
+        }
+    }
+    Ok(vec![])
+}
+
 /// Reject an ADR
 pub fn reject_adr(config: &Config, adr_id: &str, op: WriteOp) -> DiagnosticResult<Diagnostics> {
     let entry = load_lifecycle_adr(config, adr_id)?;
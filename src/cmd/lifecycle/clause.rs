@@ -2,7 +2,7 @@ use super::paths::{require_clause_toml_path, require_replacement_clause_toml_pat
 use crate::cmd::edit;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
-use crate::model::ClauseStatus;
+use crate::model::{ClauseStatus, Deprecation};
 use crate::ui;
 use crate::validate::{ArtifactKind, normalize_clause_supersession_target, validate_field};
 use crate::write::{WriteOp, read_clause, write_clause};
@@ -10,10 +10,11 @@ use crate::write::{WriteOp, read_clause, write_clause};
 pub(super) fn deprecate_clause(
     config: &Config,
     clause_id: &str,
+    notice: Option<&Deprecation>,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     let clause_path = require_clause_toml_path(config, clause_id)?;
-    let clause = read_clause(config, &clause_path)?;
+    let mut clause = read_clause(config, &clause_path)?;
 
     if clause.status == ClauseStatus::Deprecated {
         return Err(Diagnostic::new(
@@ -30,10 +31,24 @@ pub(super) fn deprecate_clause(
         ));
     }
 
-    edit::set_field_direct(config, clause_id, "status", "deprecated", op)?;
+    if let Some(notice) = notice {
+        clause.status = ClauseStatus::Deprecated;
+        clause.deprecation = Some(notice.clone());
+        write_clause(
+            &clause_path,
+            &clause,
+            op,
+            Some(&config.display_path(&clause_path)),
+        )?;
+    } else {
+        edit::set_field_direct(config, clause_id, "status", "deprecated", op)?;
+    }
 
     if !op.is_preview() {
         ui::deprecated("clause", clause_id);
+        if let Some(sunset) = notice.and_then(|notice| notice.sunset.as_deref()) {
+            ui::sub_info(format!("Sunset: {sunset}"));
+        }
     }
     Ok(vec![])
 }
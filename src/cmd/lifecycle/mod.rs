@@ -3,6 +3,7 @@
 use crate::cmd::confirmation::confirm_destructive_action;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::model::Deprecation;
 use crate::write::WriteOp;
 
 mod adr;
@@ -11,11 +12,36 @@ mod paths;
 mod release;
 mod rfc;
 mod rfc_clause_versions;
+mod rfc_gates;
+mod rfc_restructure;
+mod rfc_sections;
+mod rfc_snapshots;
 mod rfc_supersede;
+mod supersede_refs;
 pub use adr::{accept_adr, reject_adr, validate_adr_completeness};
 pub use release::{cut_release, undo_release};
 pub(crate) use rfc::require_changelog_update_ready;
-pub use rfc::{advance, bump, finalize};
+pub use rfc::{advance, bump, ensure_not_frozen, finalize, set_frozen};
+pub use rfc_gates::advance_report;
+pub(crate) use rfc_gates::unmet_gates_in;
+pub use rfc_restructure::{SplitInto, merge, split};
+pub use rfc_sections::{
+    add_section, remove_section, rename_section, reorder_clauses, reorder_sections,
+};
+
+/// Record an approval of an RFC or ADR
+pub fn approve(
+    config: &Config,
+    id: &str,
+    approver: &str,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    if config.prefixes.is_adr(id) {
+        adr::approve_adr(config, id, approver, op)
+    } else {
+        rfc::approve_rfc(config, id, approver, op)
+    }
+}
 
 /// Deprecate an artifact
 ///
@@ -24,6 +50,7 @@ pub fn deprecate(
     config: &Config,
     id: &str,
     force: bool,
+    notice: Option<&Deprecation>,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     if !confirm_destructive_action(
@@ -36,10 +63,13 @@ pub fn deprecate(
     }
 
     if id.contains(':') {
-        clause::deprecate_clause(config, id, op)
-    } else if id.starts_with("RFC-") {
-        rfc::deprecate_rfc(config, id, op)
-    } else if id.starts_with("ADR-") {
+        clause::deprecate_clause(config, id, notice, op)
+    } else if config.prefixes.is_rfc(id) {
+        rfc::deprecate_rfc(config, id, notice, op)
+    } else if config.prefixes.is_adr(id) {
+        if let Some(notice) = notice {
+            return adr::schedule_adr_sunset(config, id, notice, op);
+        }
         Err(Diagnostic::new(
             DiagnosticCode::E0305AdrCannotDeprecate,
             format!(
@@ -56,7 +86,7 @@ pub fn deprecate(
     }
 }
 
-/// Supersede an artifact
+/// Supersede an artifact, optionally pointing inbound `refs` at `by`
 ///
 /// Per [[ADR-0017]], destructive operations require confirmation unless `--force`.
 pub fn supersede(
@@ -64,6 +94,7 @@ pub fn supersede(
     id: &str,
     by: &str,
     force: bool,
+    update_refs: bool,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     if !confirm_destructive_action(
@@ -75,11 +106,11 @@ pub fn supersede(
         return Ok(vec![]);
     }
 
-    if id.contains(':') {
+    let mut diagnostics = if id.contains(':') {
         clause::supersede_clause(config, id, by, op)
-    } else if id.starts_with("RFC-") {
+    } else if config.prefixes.is_rfc(id) {
         rfc_supersede::supersede_rfc(config, id, by, op)
-    } else if id.starts_with("ADR-") {
+    } else if config.prefixes.is_adr(id) {
         adr::supersede_adr(config, id, by, op)
     } else {
         Err(Diagnostic::new(
@@ -87,5 +118,9 @@ pub fn supersede(
             format!("Supersede is not supported for this artifact type: {id}"),
             id,
         ))
+    }?;
+    if update_refs {
+        diagnostics.extend(supersede_refs::update_inbound_refs(config, id, by, op)?);
     }
+    Ok(diagnostics)
 }
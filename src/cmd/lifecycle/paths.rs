@@ -1,3 +1,4 @@
+use crate::artifact_catalog::{CatalogKind, missing_id_message};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::load::{find_clause_toml, find_rfc_toml, split_clause_id};
@@ -30,7 +31,7 @@ pub(super) fn require_rfc_toml_path(config: &Config, rfc_id: &str) -> Diagnostic
         || {
             Diagnostic::new(
                 DiagnosticCode::E0102RfcNotFound,
-                format!("RFC not found: {rfc_id}"),
+                missing_id_message(config, CatalogKind::Rfc, rfc_id),
                 rfc_id,
             )
         },
@@ -73,7 +74,7 @@ pub(super) fn require_clause_toml_path(
         || {
             Diagnostic::new(
                 DiagnosticCode::E0202ClauseNotFound,
-                format!("Clause not found: {clause_id}"),
+                missing_id_message(config, CatalogKind::Clause, clause_id),
                 clause_id,
             )
         },
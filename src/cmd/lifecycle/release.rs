@@ -1,8 +1,10 @@
 use std::collections::HashSet;
 
+use crate::cmd::render::release_notes;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
-use crate::model::{Release, WorkItemStatus};
+use crate::github::Github;
+use crate::model::{Release, WorkItemEntry, WorkItemStatus};
 use crate::parse::{load_releases, load_work_items, validate_version, write_releases};
 use crate::ui;
 use crate::write::{WriteOp, delete_file, today};
@@ -10,10 +12,14 @@ use chrono::NaiveDate;
 
 /// Cut a release - collect unreleased work items into a version
 /// Per [[ADR-0014]], stores release info in gov/releases.toml
+///
+/// With `github`, a draft GitHub Release is created from the release notes
+/// first, so a failed draft leaves no release behind to undo.
 pub fn cut_release(
     config: &Config,
     version: &str,
     date: Option<&str>,
+    github: bool,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     let releases_path = config.releases_path();
@@ -81,6 +87,15 @@ pub fn cut_release(
         date: release_date.clone(),
         refs: refs.clone(),
     };
+    if github {
+        draft_github_release(
+            config,
+            &release,
+            releases_file.releases.first(),
+            &work_items,
+            op,
+        )?;
+    }
 
     // Releases are stored newest-first for changelog rendering.
     releases_file.releases.insert(0, release);
@@ -94,6 +109,34 @@ pub fn cut_release(
     Ok(vec![])
 }
 
+fn draft_github_release(
+    config: &Config,
+    release: &Release,
+    previous: Option<&Release>,
+    work_items: &[WorkItemEntry],
+    op: WriteOp,
+) -> DiagnosticResult<()> {
+    let repo = config.github.repo.as_deref().ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0513PublishNotConfigured,
+            "No [github] repo in config (set repo = \"owner/name\" to draft releases)",
+            config
+                .display_path(&config.gov_root.join("config.toml"))
+                .display()
+                .to_string(),
+        )
+    })?;
+    let tag = format!("{}{}", config.github.tag_prefix, release.version);
+    if op.is_preview() {
+        ui::dry_run_github_release(repo, &tag);
+        return Ok(());
+    }
+    let notes = release_notes(config, release, previous, work_items)?;
+    let url = Github::new(config).draft_release(repo, &tag, &notes)?;
+    ui::github_release_drafted(&tag, &url);
+    Ok(())
+}
+
 /// Undo the newest local release cut.
 ///
 /// The expected version is an intent guard for [[RFC-0000:C-RELEASE-DEF]].
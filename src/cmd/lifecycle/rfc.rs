@@ -2,15 +2,21 @@ use super::paths::require_rfc_toml_path;
 use super::rfc_clause_versions::{
     fill_pending_clause_versions, pending_clause_ids, rfc_update_paths,
 };
+use super::rfc_gates::unmet_gates;
+use super::rfc_snapshots::write_version_snapshot;
 use crate::FinalizeStatus;
 use crate::cmd::edit;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
-use crate::model::{RfcPhase, RfcSpec, RfcStatus};
+use crate::load::find_rfc_toml;
+use crate::model::{Deprecation, RfcIndex, RfcPhase, RfcSpec, RfcStatus};
 use crate::ui;
-use crate::validate::{is_valid_phase_transition, is_valid_status_transition};
+use crate::validate::{
+    is_valid_phase_transition, is_valid_status_transition, validate_owner_identity,
+};
 use crate::write::{
     BumpLevel, WriteOp, add_changelog_change, bump_rfc_version, read_rfc, with_file_transaction,
+    write_rfc,
 };
 use std::path::Path;
 
@@ -67,7 +73,7 @@ pub fn bump(
 
             let paths = rfc_update_paths(config, &rfc_path)?;
             let path_refs: Vec<_> = paths.iter().map(std::path::PathBuf::as_path).collect();
-            let updated_clause_ids = with_file_transaction(&path_refs, op, || {
+            let updated_clause_ids = with_file_transaction(&path_refs, op, |op| {
                 write_lifecycle_rfc(config, &rfc_path, &rfc, op)?;
                 let updated_clause_ids =
                     fill_pending_clause_versions(config, &rfc_path, &new_version, op)?;
@@ -115,7 +121,7 @@ pub fn bump(
         }
     }
 
-    with_file_transaction(&[rfc_path.as_path()], op, || {
+    with_file_transaction(&[rfc_path.as_path()], op, |op| {
         write_lifecycle_rfc(config, &rfc_path, &rfc, op)
     })?;
     if !op.is_preview() {
@@ -135,23 +141,116 @@ pub fn finalize(
 ) -> DiagnosticResult<Diagnostics> {
     match status {
         FinalizeStatus::Normative => {
-            transition_rfc_status(config, rfc_id, RfcStatus::Normative, op)
+            transition_rfc_status(config, rfc_id, RfcStatus::Normative, None, op)
         }
     }
 }
 
+/// Record `approver`'s approval of an RFC.
+pub(super) fn approve_rfc(
+    config: &Config,
+    rfc_id: &str,
+    approver: &str,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    if rfc.status == RfcStatus::Deprecated {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0104RfcInvalidTransition,
+            format!("Cannot approve {rfc_id}: the RFC is deprecated"),
+            rfc_id,
+        ));
+    }
+    validate_owner_identity(config, rfc_id, "approvals", approver, rfc_id)?;
+    if rfc.approvals.iter().any(|existing| existing == approver) {
+        ui::info(format!("{rfc_id} is already approved by {approver}"));
+        return Ok(vec![]);
+    }
+
+    rfc.approvals.push(approver.to_string());
+    write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))?;
+    if !op.is_preview() {
+        ui::approved(
+            rfc_id,
+            approver,
+            rfc.approvals.len(),
+            config.owners.rfc_quorum,
+        );
+    }
+    Ok(vec![])
+}
+
+/// Freeze an RFC for review, or lift the freeze.
+///
+/// While frozen, every write to the RFC or its clauses fails with E0122;
+/// approvals are still recorded.
+pub fn set_frozen(
+    config: &Config,
+    rfc_id: &str,
+    frozen: bool,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    if frozen && rfc.status == RfcStatus::Deprecated {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0104RfcInvalidTransition,
+            format!("Cannot freeze {rfc_id}: the RFC is deprecated"),
+            rfc_id,
+        ));
+    }
+    if rfc.frozen == frozen {
+        let state = if frozen { "frozen" } else { "not frozen" };
+        ui::info(format!("{rfc_id} is already {state}"));
+        return Ok(vec![]);
+    }
+
+    rfc.frozen = frozen;
+    write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))?;
+    if !op.is_preview() {
+        ui::frozen(rfc_id, frozen);
+    }
+    Ok(vec![])
+}
+
+/// Refuse writes to a frozen RFC, or to a clause of one.
+///
+/// `artifact_id` may be an RFC or clause ID; anything else, and RFCs that
+/// do not exist, pass so the command reports its own error.
+pub fn ensure_not_frozen(config: &Config, artifact_id: &str) -> DiagnosticResult<()> {
+    let rfc_id = artifact_id
+        .split_once(':')
+        .map_or(artifact_id, |(rfc_id, _)| rfc_id);
+    let Some(rfc_path) = find_rfc_toml(config, rfc_id) else {
+        return Ok(());
+    };
+    if read_rfc(config, &rfc_path)?.frozen {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0122RfcFrozen,
+            format!(
+                "{rfc_id} is frozen for review; changes to it or its clauses are refused (hint: `govctl rfc unfreeze {rfc_id}`)"
+            ),
+            artifact_id,
+        ));
+    }
+    Ok(())
+}
+
 pub(super) fn deprecate_rfc(
     config: &Config,
     rfc_id: &str,
+    notice: Option<&Deprecation>,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
-    transition_rfc_status(config, rfc_id, RfcStatus::Deprecated, op)
+    transition_rfc_status(config, rfc_id, RfcStatus::Deprecated, notice, op)
 }
 
 fn transition_rfc_status(
     config: &Config,
     rfc_id: &str,
     target_status: RfcStatus,
+    notice: Option<&Deprecation>,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     let rfc_path = require_rfc_toml_path(config, rfc_id)?;
@@ -185,13 +284,23 @@ fn transition_rfc_status(
         ));
     }
 
+    if target_status == RfcStatus::Normative {
+        crate::validate::require_rfc_approvals(config, &rfc, rfc_id)?;
+    }
+
     let updated_clause_ids = if target_status == RfcStatus::Normative {
         let paths = rfc_update_paths(config, &rfc_path)?;
         let path_refs: Vec<_> = paths.iter().map(std::path::PathBuf::as_path).collect();
-        with_file_transaction(&path_refs, op, || {
+        with_file_transaction(&path_refs, op, |op| {
             edit::set_field_direct(config, rfc_id, "status", target_status.as_ref(), op)?;
             fill_pending_clause_versions(config, &rfc_path, &rfc.version, op)
         })?
+    } else if let Some(notice) = notice {
+        let mut updated_rfc = rfc.clone();
+        updated_rfc.status = target_status;
+        updated_rfc.deprecation = Some(notice.clone());
+        write_lifecycle_rfc(config, &rfc_path, &updated_rfc, op)?;
+        Vec::new()
     } else {
         edit::set_field_direct(config, rfc_id, "status", target_status.as_ref(), op)?;
         Vec::new()
@@ -202,6 +311,9 @@ fn transition_rfc_status(
             ui::sub_info(format!("Set {clause_id}.since = {}", rfc.version));
         }
         ui::finalized(rfc_id, target_status.as_ref());
+        if let Some(sunset) = notice.and_then(|notice| notice.sunset.as_deref()) {
+            ui::sub_info(format!("Sunset: {sunset}"));
+        }
     }
     Ok(vec![])
 }
@@ -211,6 +323,7 @@ pub fn advance(
     config: &Config,
     rfc_id: &str,
     phase: RfcPhase,
+    force: bool,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     require_rfc_content_signature_schema(config, rfc_id)?;
@@ -295,17 +408,61 @@ pub fn advance(
         }
     };
 
+    let unmet = unmet_gates(config, rfc_id, phase)?;
+    let mut diagnostics = vec![];
+    if !unmet.is_empty() {
+        let items = unmet
+            .iter()
+            .map(|item| format!("{} ({})", item.gate.as_ref(), item.detail))
+            .collect::<Vec<_>>()
+            .join("; ");
+        if !force {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0123RfcGateUnmet,
+                format!(
+                    "Cannot advance {rfc_id} to {}: unmet gates: {items} (hint: use --force to override)",
+                    phase.as_ref()
+                ),
+                rfc_id,
+            ));
+        }
+        diagnostics.push(Diagnostic::new(
+            DiagnosticCode::W0118RfcGateOverridden,
+            format!(
+                "Advanced {rfc_id} to {} with unmet gates: {items}",
+                phase.as_ref()
+            ),
+            rfc_id,
+        ));
+    }
+
     let mut updated_rfc = rfc;
     if let Some(signature) = next_signature {
         updated_rfc.signature = Some(signature);
     }
     updated_rfc.phase = phase;
     write_lifecycle_rfc(config, &rfc_path, &updated_rfc, op)?;
+    let snapshot = if seals_current_version && config.snapshots.rfc_versions {
+        let sealed = RfcIndex {
+            rfc: updated_rfc.clone(),
+            ..rfc_index
+        };
+        write_version_snapshot(config, &sealed, op)?
+    } else {
+        None
+    };
 
     if !op.is_preview() {
         ui::phase_advanced(rfc_id, phase.as_ref());
+        if let Some(path) = snapshot {
+            ui::sub_info(format!(
+                "Saved v{} snapshot to {}",
+                updated_rfc.version,
+                config.display_path(&path).display()
+            ));
+        }
     }
-    Ok(vec![])
+    Ok(diagnostics)
 }
 
 fn valid_rfc_status_targets(status: RfcStatus) -> &'static str {
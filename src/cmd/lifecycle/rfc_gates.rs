@@ -0,0 +1,158 @@
+//! Phase gates: checklists evaluated by `rfc advance`.
+//!
+//! Gates come from `[gates]` in config, plus `clause-work-done`, which every
+//! RFC must pass to enter `test`.
+
+use super::paths::require_rfc_toml_path;
+use crate::config::{Config, PhaseGate};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project;
+use crate::model::{ClauseStatus, ProjectIndex, RfcPhase, WorkItemEntry, WorkItemStatus};
+
+/// One unmet checklist item.
+pub(crate) struct UnmetGate {
+    pub(crate) gate: PhaseGate,
+    pub(crate) detail: String,
+}
+
+/// Gates `phase` always has, whatever `[gates]` says.
+fn builtin_gates(phase: RfcPhase) -> &'static [PhaseGate] {
+    match phase {
+        RfcPhase::Test => &[PhaseGate::ClauseWorkDone],
+        RfcPhase::Spec | RfcPhase::Impl | RfcPhase::Stable => &[],
+    }
+}
+
+/// Evaluate the gate checklist for `rfc_id` entering `phase`.
+pub(super) fn unmet_gates(
+    config: &Config,
+    rfc_id: &str,
+    phase: RfcPhase,
+) -> DiagnosticResult<Vec<UnmetGate>> {
+    if gate_checklist(config, phase).is_empty() {
+        return Ok(vec![]);
+    }
+    let index = load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for phase gates",
+                rfc_id,
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    Ok(unmet_gates_in(&index, config, rfc_id, phase))
+}
+
+/// [`unmet_gates`] against an already loaded project.
+pub(crate) fn unmet_gates_in(
+    index: &ProjectIndex,
+    config: &Config,
+    rfc_id: &str,
+    phase: RfcPhase,
+) -> Vec<UnmetGate> {
+    gate_checklist(config, phase)
+        .into_iter()
+        .filter_map(|gate| check_gate(index, rfc_id, gate).map(|detail| UnmetGate { gate, detail }))
+        .collect()
+}
+
+fn gate_checklist(config: &Config, phase: RfcPhase) -> Vec<PhaseGate> {
+    let mut gates = builtin_gates(phase).to_vec();
+    for gate in config.gates.for_phase(phase) {
+        if !gates.contains(gate) {
+            gates.push(*gate);
+        }
+    }
+    gates
+}
+
+/// `None` when the gate holds, else what is missing.
+fn check_gate(index: &ProjectIndex, rfc_id: &str, gate: PhaseGate) -> Option<String> {
+    match gate {
+        PhaseGate::ClausesHaveSince => {
+            let missing: Vec<String> = index
+                .rfcs
+                .iter()
+                .filter(|rfc| rfc.rfc.rfc_id == rfc_id)
+                .flat_map(|rfc| &rfc.clauses)
+                .filter(|clause| {
+                    clause.spec.status == ClauseStatus::Active && clause.spec.since.is_none()
+                })
+                .map(|clause| format!("{rfc_id}:{}", clause.spec.clause_id))
+                .collect();
+            (!missing.is_empty()).then(|| format!("no `since` on {}", missing.join(", ")))
+        }
+        PhaseGate::AdrReferencesRfc => {
+            let referenced = index
+                .adrs
+                .iter()
+                .any(|adr| adr.meta().refs.iter().any(|r| targets_rfc(r, rfc_id)));
+            (!referenced).then(|| format!("no ADR references {rfc_id}"))
+        }
+        PhaseGate::WorkItemsDone => {
+            let open: Vec<&str> = open_work_items(index)
+                .filter(|work| work.meta().refs.iter().any(|r| targets_rfc(r, rfc_id)))
+                .map(|work| work.meta().id.as_str())
+                .collect();
+            (!open.is_empty()).then(|| format!("open work items: {}", open.join(", ")))
+        }
+        PhaseGate::ClauseWorkDone => {
+            let open: Vec<String> = open_work_items(index)
+                .filter_map(|work| {
+                    let clauses: Vec<&str> = work
+                        .meta()
+                        .refs
+                        .iter()
+                        .filter_map(|r| {
+                            r.strip_prefix(rfc_id)
+                                .and_then(|rest| rest.strip_prefix(':'))
+                        })
+                        .collect();
+                    (!clauses.is_empty())
+                        .then(|| format!("{} ({})", work.meta().id, clauses.join(", ")))
+                })
+                .collect();
+            (!open.is_empty())
+                .then(|| format!("open work items on its clauses: {}", open.join(", ")))
+        }
+    }
+}
+
+fn open_work_items(index: &ProjectIndex) -> impl Iterator<Item = &WorkItemEntry> {
+    index.work_items.iter().filter(|work| {
+        !matches!(
+            work.meta().status,
+            WorkItemStatus::Done | WorkItemStatus::Cancelled
+        )
+    })
+}
+
+/// `rfc advance --report`: list what blocks `rfc_id` from entering `phase`
+/// without changing anything.
+pub fn advance_report(
+    config: &Config,
+    rfc_id: &str,
+    phase: RfcPhase,
+) -> DiagnosticResult<Diagnostics> {
+    require_rfc_toml_path(config, rfc_id)?;
+    let unmet = unmet_gates(config, rfc_id, phase)?;
+    if unmet.is_empty() {
+        println!("No blockers for {rfc_id} to enter {}", phase.as_ref());
+        return Ok(vec![]);
+    }
+    println!("Blockers for {rfc_id} to enter {}:", phase.as_ref());
+    for item in &unmet {
+        println!("  - {}: {}", item.gate.as_ref(), item.detail);
+    }
+    Ok(vec![])
+}
+
+/// Whether `ref_id` names `rfc_id` or one of its clauses.
+fn targets_rfc(ref_id: &str, rfc_id: &str) -> bool {
+    ref_id
+        .strip_prefix(rfc_id)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(':'))
+}
@@ -0,0 +1,536 @@
+//! RFC split and merge operations.
+//!
+//! Both operations move Clauses between RFCs while keeping history auditable:
+//! Clauses leaving a draft RFC are moved outright, while Clauses leaving a
+//! normative RFC stay in place as superseded history per
+//! [[RFC-0001:C-CLAUSE-STATUS]].
+
+use super::paths::{require_clause_toml_path, require_rfc_toml_path};
+use super::rfc::require_changelog_update_ready;
+use super::rfc_supersede::validate_supersede_transition;
+use crate::cmd::confirmation::confirm_destructive_action;
+use crate::cmd::edit::{ensure_clause_not_referenced, ensure_rfc_not_referenced};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::model::{
+    ChangelogEntry, ClauseSpec, ClauseStatus, RfcPhase, RfcSpec, RfcStatus, SectionSpec,
+};
+use crate::ui;
+use crate::write::{
+    WriteOp, add_changelog_change, create_dir_all, delete_file, read_clause, read_rfc, today,
+    with_file_transaction, write_clause, write_rfc,
+};
+use std::path::{Path, PathBuf};
+
+/// A Clause selected for relocation, with its source section.
+struct ClauseMove {
+    name: String,
+    section: String,
+    source_path: PathBuf,
+    target_path: PathBuf,
+    clause: ClauseSpec,
+}
+
+/// The new draft RFC `rfc split` creates.
+pub struct SplitInto<'a> {
+    pub id: &'a str,
+    pub title: &'a str,
+    /// Actor recorded as `created_by`.
+    pub created_by: Option<String>,
+}
+
+/// Split Clauses out of an RFC into a new draft RFC.
+pub fn split(
+    config: &Config,
+    rfc_id: &str,
+    clause_ids: &[String],
+    new_rfc: &SplitInto,
+    force: bool,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let (into, title) = (new_rfc.id, new_rfc.title);
+    if clause_ids.is_empty() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "rfc split requires at least one clause via --clauses",
+            rfc_id,
+        ));
+    }
+    if !config.prefixes.is_rfc(into) {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0110RfcInvalidId,
+            format!(
+                "RFC ID must start with '{}-' (got: {into})",
+                config.prefixes.rfc
+            ),
+            into,
+        ));
+    }
+    if config.rfc_artifact_dir(into).exists() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0109RfcAlreadyExists,
+            format!("RFC already exists: {into}"),
+            into,
+        ));
+    }
+
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut source = read_rfc(config, &rfc_path)?;
+    if source.status == RfcStatus::Deprecated {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0104RfcInvalidTransition,
+            format!("Cannot split deprecated RFC: {rfc_id}"),
+            rfc_id,
+        ));
+    }
+    require_changelog_update_ready(config, &rfc_path, rfc_id)?;
+
+    let names = normalize_clause_names(rfc_id, clause_ids)?;
+    let moves = collect_clause_moves(config, &source, &names, into)?;
+    let moves_outright = source.status == RfcStatus::Draft;
+    if moves_outright {
+        for clause_move in &moves {
+            ensure_clause_not_referenced(config, &format!("{rfc_id}:{}", clause_move.name))?;
+        }
+    }
+
+    if !confirm_destructive_action(
+        force,
+        op,
+        &format!("Split {} from {rfc_id} into {into}?", names.join(", ")),
+        "Split cancelled",
+    )? {
+        return Ok(vec![]);
+    }
+
+    let today = today();
+    let mut target = RfcSpec {
+        rfc_id: into.to_string(),
+        title: title.to_string(),
+        version: "0.1.0".to_string(),
+        status: RfcStatus::Draft,
+        phase: RfcPhase::Spec,
+        owners: source.owners.clone(),
+        approvals: vec![],
+        frozen: false,
+        created: today.clone(),
+        created_by: new_rfc.created_by.clone(),
+        attachments: vec![],
+        updated: None,
+        supersedes: None,
+        deprecation: None,
+        refs: vec![rfc_id.to_string()],
+        tags: source.tags.clone(),
+        sections: vec![],
+        changelog: vec![ChangelogEntry {
+            version: "0.1.0".to_string(),
+            date: today.clone(),
+            notes: Some(format!("Split from [[{rfc_id}]]")),
+            added: vec![],
+            changed: vec![],
+            deprecated: vec![],
+            removed: vec![],
+            fixed: vec![],
+            security: vec![],
+        }],
+        signature: None,
+    };
+    for section in &source.sections {
+        for clause_move in moves.iter().filter(|m| m.section == section.title) {
+            append_to_section(&mut target, &clause_move.section, &clause_move.name);
+        }
+    }
+
+    let moved_list = names.join(", ");
+    if moves_outright {
+        for clause_move in &moves {
+            unlink_from_sections(&mut source, &clause_move.name);
+        }
+        add_changelog_change(
+            &mut source,
+            &format!("remove: Move {moved_list} to [[{into}]]"),
+        )?;
+    } else {
+        add_changelog_change(
+            &mut source,
+            &format!("change: Supersede {moved_list} with Clauses in [[{into}]]"),
+        )?;
+    }
+    source.updated = Some(today);
+
+    let target_rfc_path = config.rfc_source_path(into, "toml");
+    let clauses_dir = config.clause_dir(into);
+    let mut paths: Vec<&Path> = vec![rfc_path.as_path(), target_rfc_path.as_path()];
+    for clause_move in &moves {
+        paths.push(clause_move.source_path.as_path());
+        paths.push(clause_move.target_path.as_path());
+    }
+
+    create_dir_all(&clauses_dir, op, Some(&config.display_path(&clauses_dir)))?;
+    with_file_transaction(&paths, op, |op| {
+        for clause_move in &moves {
+            write_clause(
+                &clause_move.target_path,
+                &relocated_clause(&clause_move.clause, None),
+                op,
+                Some(&config.display_path(&clause_move.target_path)),
+            )?;
+            if moves_outright {
+                delete_file(
+                    &clause_move.source_path,
+                    op,
+                    Some(&config.display_path(&clause_move.source_path)),
+                )?;
+            } else {
+                write_clause(
+                    &clause_move.source_path,
+                    &superseded_clause(&clause_move.clause, into),
+                    op,
+                    Some(&config.display_path(&clause_move.source_path)),
+                )?;
+            }
+        }
+        write_rfc(
+            &target_rfc_path,
+            &target,
+            op,
+            Some(&config.display_path(&target_rfc_path)),
+        )?;
+        write_rfc(
+            &rfc_path,
+            &source,
+            op,
+            Some(&config.display_path(&rfc_path)),
+        )
+    })?;
+
+    if !op.is_preview() {
+        ui::created("RFC", &config.display_path(&target_rfc_path));
+        for clause_move in &moves {
+            if moves_outright {
+                ui::sub_info(format!(
+                    "Moved {rfc_id}:{} -> {into}:{}",
+                    clause_move.name, clause_move.name
+                ));
+            } else {
+                ui::sub_info(format!(
+                    "Superseded {rfc_id}:{} by {into}:{}",
+                    clause_move.name, clause_move.name
+                ));
+            }
+        }
+    }
+    Ok(vec![])
+}
+
+/// Merge every active Clause of an RFC into another RFC and retire the source.
+/// A draft source has no history to keep: its Clauses move outright and the
+/// RFC is removed.
+pub fn merge(
+    config: &Config,
+    rfc_id: &str,
+    into: &str,
+    force: bool,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    if rfc_id == into {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0802ConflictingArgs,
+            "RFC cannot be merged into itself",
+            rfc_id,
+        ));
+    }
+
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let target_rfc_path = require_rfc_toml_path(config, into)?;
+    let mut source = read_rfc(config, &rfc_path)?;
+    let mut target = read_rfc(config, &target_rfc_path)?;
+
+    let moves_outright = source.status == RfcStatus::Draft;
+    if moves_outright {
+        if target.status == RfcStatus::Deprecated {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0104RfcInvalidTransition,
+                format!("Cannot merge into deprecated RFC: {into}"),
+                into,
+            ));
+        }
+    } else {
+        // Merging retires the source exactly like `rfc supersede`, so it
+        // shares the same transition rules.
+        validate_supersede_transition(&source, &target, rfc_id, into)?;
+        require_changelog_update_ready(config, &rfc_path, rfc_id)?;
+    }
+    require_changelog_update_ready(config, &target_rfc_path, into)?;
+
+    // A draft source keeps nothing behind, so every Clause must move.
+    let mut names = Vec::new();
+    for section in &source.sections {
+        for rel_path in &section.clauses {
+            let name = clause_name_from_rel_path(rel_path);
+            let path = config.clause_source_path(rfc_id, &name, "toml");
+            if moves_outright || read_clause(config, &path)?.status == ClauseStatus::Active {
+                names.push(name);
+            }
+        }
+    }
+    let moves = collect_clause_moves(config, &source, &names, into)?;
+    if moves_outright {
+        ensure_rfc_not_referenced(config, rfc_id)?;
+        for clause_move in &moves {
+            ensure_clause_not_referenced(config, &format!("{rfc_id}:{}", clause_move.name))?;
+        }
+    }
+
+    if !confirm_destructive_action(
+        force,
+        op,
+        &format!("Merge {rfc_id} into {into}?"),
+        "Merge cancelled",
+    )? {
+        return Ok(vec![]);
+    }
+
+    // New Clauses join the target like `clause new` would per
+    // [[RFC-0000:C-CLAUSE-DEF]].
+    let since = match (target.status, target.phase) {
+        (RfcStatus::Normative, RfcPhase::Spec) => Some(target.version.clone()),
+        _ => None,
+    };
+    for clause_move in &moves {
+        append_to_section(&mut target, &clause_move.section, &clause_move.name);
+    }
+
+    let today = today();
+    target.updated = Some(today.clone());
+    if moves_outright {
+        // The source is removed, so the changelog names it without a link.
+        if !moves.is_empty() {
+            add_changelog_change(
+                &mut target,
+                &format!("add: Merge {} from draft {rfc_id}", names.join(", ")),
+            )?;
+        }
+    } else {
+        target.supersedes = Some(rfc_id.to_string());
+        if !moves.is_empty() {
+            add_changelog_change(
+                &mut target,
+                &format!("add: Merge {} from [[{rfc_id}]]", names.join(", ")),
+            )?;
+        }
+        source.status = RfcStatus::Deprecated;
+        source.updated = Some(today);
+        add_changelog_change(&mut source, &format!("deprecate: Merge into [[{into}]]"))?;
+    }
+
+    let mut paths: Vec<&Path> = vec![rfc_path.as_path(), target_rfc_path.as_path()];
+    for clause_move in &moves {
+        paths.push(clause_move.source_path.as_path());
+        paths.push(clause_move.target_path.as_path());
+    }
+
+    with_file_transaction(&paths, op, |op| {
+        for clause_move in &moves {
+            write_clause(
+                &clause_move.target_path,
+                &relocated_clause(&clause_move.clause, since.clone()),
+                op,
+                Some(&config.display_path(&clause_move.target_path)),
+            )?;
+            if moves_outright {
+                delete_file(
+                    &clause_move.source_path,
+                    op,
+                    Some(&config.display_path(&clause_move.source_path)),
+                )?;
+            } else {
+                write_clause(
+                    &clause_move.source_path,
+                    &superseded_clause(&clause_move.clause, into),
+                    op,
+                    Some(&config.display_path(&clause_move.source_path)),
+                )?;
+            }
+        }
+        write_rfc(
+            &target_rfc_path,
+            &target,
+            op,
+            Some(&config.display_path(&target_rfc_path)),
+        )?;
+        if moves_outright {
+            delete_file(&rfc_path, op, Some(&config.display_path(&rfc_path)))
+        } else {
+            write_rfc(
+                &rfc_path,
+                &source,
+                op,
+                Some(&config.display_path(&rfc_path)),
+            )
+        }
+    })?;
+
+    if op.is_preview() {
+        return Ok(vec![]);
+    }
+    if moves_outright {
+        // The emptied directories go last, once nothing can roll back into them.
+        let rfc_dir = config.rfc_artifact_dir(rfc_id);
+        let _ = std::fs::remove_dir(config.clause_dir(rfc_id));
+        let _ = std::fs::remove_dir(&rfc_dir);
+        ui::success(format!("Merged draft {rfc_id} into {into}"));
+        for clause_move in &moves {
+            ui::sub_info(format!(
+                "Moved {rfc_id}:{} -> {into}:{}",
+                clause_move.name, clause_move.name
+            ));
+        }
+    } else {
+        ui::superseded("RFC", rfc_id, into);
+        for clause_move in &moves {
+            ui::sub_info(format!(
+                "Superseded {rfc_id}:{} by {into}:{}",
+                clause_move.name, clause_move.name
+            ));
+        }
+    }
+    Ok(vec![])
+}
+
+/// Accept `C-NAME` or `RFC-NNNN:C-NAME` and return bare names in input order.
+pub(super) fn normalize_clause_names(
+    rfc_id: &str,
+    clause_ids: &[String],
+) -> DiagnosticResult<Vec<String>> {
+    let mut names: Vec<String> = Vec::new();
+    for raw in clause_ids {
+        let name = match raw.split_once(':') {
+            Some((owner, name)) if owner == rfc_id => name,
+            Some(_) => {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E0802ConflictingArgs,
+                    format!("Clause {raw} does not belong to {rfc_id}"),
+                    raw,
+                ));
+            }
+            None => raw.as_str(),
+        };
+        if name.is_empty() {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0210ClauseInvalidIdFormat,
+                format!("Invalid clause ID format: {raw}"),
+                raw,
+            ));
+        }
+        if !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+        }
+    }
+    Ok(names)
+}
+
+fn collect_clause_moves(
+    config: &Config,
+    source: &RfcSpec,
+    names: &[String],
+    into: &str,
+) -> DiagnosticResult<Vec<ClauseMove>> {
+    let mut moves = Vec::new();
+    for name in names {
+        let clause_id = format!("{}:{name}", source.rfc_id);
+        let section = source
+            .sections
+            .iter()
+            .find(|section| {
+                section
+                    .clauses
+                    .iter()
+                    .any(|rel_path| clause_name_from_rel_path(rel_path) == *name)
+            })
+            .ok_or_else(|| {
+                Diagnostic::new(
+                    DiagnosticCode::E0202ClauseNotFound,
+                    format!(
+                        "Clause {name} not found in any section of {}",
+                        source.rfc_id
+                    ),
+                    &clause_id,
+                )
+            })?;
+        let source_path = require_clause_toml_path(config, &clause_id)?;
+        let clause = read_clause(config, &source_path)?;
+        if clause.status != ClauseStatus::Active {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0218ClauseNotRelocatable,
+                format!(
+                    "Cannot relocate {} clause: {clause_id}",
+                    clause.status.as_ref()
+                ),
+                &clause_id,
+            ));
+        }
+        let target_path = config.clause_source_path(into, name, "toml");
+        if target_path.exists() {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0802ConflictingArgs,
+                format!("Clause {name} already exists in {into}"),
+                format!("{into}:{name}"),
+            ));
+        }
+        moves.push(ClauseMove {
+            name: name.clone(),
+            section: section.title.clone(),
+            source_path,
+            target_path,
+            clause,
+        });
+    }
+    Ok(moves)
+}
+
+pub(super) fn clause_name_from_rel_path(rel_path: &str) -> String {
+    let file_name = rel_path.rsplit('/').next().unwrap_or(rel_path);
+    file_name
+        .strip_suffix(".toml")
+        .unwrap_or(file_name)
+        .to_string()
+}
+
+fn append_to_section(rfc: &mut RfcSpec, section: &str, clause_name: &str) {
+    let rel_path = format!("clauses/{clause_name}.toml");
+    if let Some(existing) = rfc.sections.iter_mut().find(|s| s.title == section) {
+        existing.clauses.push(rel_path);
+    } else {
+        rfc.sections.push(SectionSpec {
+            title: section.to_string(),
+            clauses: vec![rel_path],
+        });
+    }
+}
+
+fn unlink_from_sections(rfc: &mut RfcSpec, clause_name: &str) {
+    for section in &mut rfc.sections {
+        section
+            .clauses
+            .retain(|rel_path| clause_name_from_rel_path(rel_path) != clause_name);
+    }
+}
+
+fn relocated_clause(clause: &ClauseSpec, since: Option<String>) -> ClauseSpec {
+    ClauseSpec {
+        status: ClauseStatus::Active,
+        superseded_by: None,
+        deprecation: None,
+        since,
+        ..clause.clone()
+    }
+}
+
+fn superseded_clause(clause: &ClauseSpec, into: &str) -> ClauseSpec {
+    ClauseSpec {
+        status: ClauseStatus::Superseded,
+        superseded_by: Some(format!("{into}:{}", clause.clause_id)),
+        ..clause.clone()
+    }
+}
@@ -0,0 +1,294 @@
+//! RFC section structure operations.
+//!
+//! Section order and the order of Clauses within a section determine rendered
+//! output order, so these commands edit the `[[sections]]` arrays in place
+//! instead of requiring hand edits to `rfc.toml`. Section titles stay
+//! non-empty and unique within an RFC.
+
+use super::paths::require_rfc_toml_path;
+use super::rfc_restructure::{clause_name_from_rel_path, normalize_clause_names};
+use crate::cmd::edit::{ensure_clause_deletable, ensure_clause_not_referenced};
+use crate::cmd::trash::{Tombstone, move_to_trash};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::model::{RfcSpec, SectionSpec};
+use crate::ui;
+use crate::write::{WriteOp, read_clause, read_rfc, with_file_transaction, write_rfc};
+use std::path::{Path, PathBuf};
+
+/// Add an empty section, appended or placed after an existing one.
+pub fn add_section(
+    config: &Config,
+    rfc_id: &str,
+    title: &str,
+    after: Option<&str>,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    let title = validate_new_title(&rfc, title)?;
+
+    let position = match after {
+        Some(anchor) => section_index(&rfc, anchor)? + 1,
+        None => rfc.sections.len(),
+    };
+    rfc.sections.insert(
+        position,
+        SectionSpec {
+            title: title.clone(),
+            clauses: vec![],
+        },
+    );
+
+    write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))?;
+    if !op.is_preview() {
+        ui::updated("RFC", rfc_id);
+        ui::sub_info(format!("Added section '{title}'"));
+    }
+    Ok(vec![])
+}
+
+/// Rename a section, keeping its position and Clauses.
+pub fn rename_section(
+    config: &Config,
+    rfc_id: &str,
+    from: &str,
+    to: &str,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    let index = section_index(&rfc, from)?;
+    let to = validate_new_title(&rfc, to)?;
+    rfc.sections[index].title = to.clone();
+
+    write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))?;
+    if !op.is_preview() {
+        ui::updated("RFC", rfc_id);
+        ui::sub_info(format!("Renamed section '{from}' to '{to}'"));
+    }
+    Ok(vec![])
+}
+
+/// Remove a section.
+///
+/// A section that still lists Clauses is only removed with `force`, which
+/// also deletes those Clauses under the same rules as `clause delete`.
+pub fn remove_section(
+    config: &Config,
+    rfc_id: &str,
+    title: &str,
+    force: bool,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    let index = section_index(&rfc, title)?;
+    if rfc.sections.len() == 1 {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0119RfcLastSection,
+            format!("Cannot remove '{title}': {rfc_id} must keep at least one section"),
+            rfc_id,
+        ));
+    }
+    let clause_names: Vec<String> = rfc.sections[index]
+        .clauses
+        .iter()
+        .map(|rel_path| clause_name_from_rel_path(rel_path))
+        .collect();
+
+    if !clause_names.is_empty() && !force {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0118RfcSectionNotEmpty,
+            format!(
+                "Section '{title}' still contains {} clause(s): {}. Delete them first, or use `govctl rfc section remove --force` to delete them with the section.",
+                clause_names.len(),
+                clause_names.join(", ")
+            ),
+            rfc_id,
+        ));
+    }
+
+    let mut clause_paths: Vec<PathBuf> = Vec::with_capacity(clause_names.len());
+    let mut tombstones = Vec::with_capacity(clause_names.len());
+    for rel_path in &rfc.sections[index].clauses {
+        let clause_id = format!("{rfc_id}:{}", clause_name_from_rel_path(rel_path));
+        let clause_path = config.rfc_artifact_dir(rfc_id).join(rel_path);
+        let clause = read_clause(config, &clause_path)?;
+        ensure_clause_deletable(&rfc, &clause, &clause_id)?;
+        ensure_clause_not_referenced(config, &clause_id)?;
+        tombstones.push(Tombstone::new(
+            config,
+            &clause_id,
+            &clause_path,
+            Some(title.to_string()),
+        ));
+        clause_paths.push(clause_path);
+    }
+    rfc.sections.remove(index);
+
+    let mut paths: Vec<&Path> = vec![rfc_path.as_path()];
+    paths.extend(clause_paths.iter().map(PathBuf::as_path));
+    with_file_transaction(&paths, op, |op| {
+        write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))?;
+        for (tombstone, clause_path) in tombstones.iter().zip(&clause_paths) {
+            move_to_trash(config, tombstone, clause_path, op)?;
+        }
+        Ok(())
+    })?;
+
+    if !op.is_preview() {
+        ui::updated("RFC", rfc_id);
+        ui::sub_info(format!("Removed section '{title}'"));
+        if !clause_names.is_empty() {
+            ui::sub_info(format!(
+                "Deleted clauses: {} (moved to trash)",
+                clause_names.join(", ")
+            ));
+        }
+    }
+    Ok(vec![])
+}
+
+/// Reorder sections. Listed sections move to the front in the given order;
+/// the rest keep their relative order after them.
+pub fn reorder_sections(
+    config: &Config,
+    rfc_id: &str,
+    order: &[String],
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    if order.is_empty() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "section reorder requires at least one section via --order",
+            rfc_id,
+        ));
+    }
+
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    let mut reordered = Vec::with_capacity(rfc.sections.len());
+    for title in order {
+        let index = section_index(&rfc, title.trim())?;
+        reordered.push(rfc.sections.remove(index));
+    }
+    reordered.append(&mut rfc.sections);
+    rfc.sections = reordered;
+
+    write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))?;
+    if !op.is_preview() {
+        ui::updated("RFC", rfc_id);
+        ui::sub_info(format!(
+            "Sections: {}",
+            rfc.sections
+                .iter()
+                .map(|section| section.title.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    Ok(vec![])
+}
+
+/// Reorder Clauses within one section of an RFC.
+///
+/// Listed Clauses move to the front in the given order; unlisted Clauses keep
+/// their relative order after them.
+pub fn reorder_clauses(
+    config: &Config,
+    rfc_id: &str,
+    section: &str,
+    order: &[String],
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    if order.is_empty() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "clause reorder requires at least one clause via --order",
+            rfc_id,
+        ));
+    }
+
+    let rfc_path = require_rfc_toml_path(config, rfc_id)?;
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    let names = normalize_clause_names(rfc_id, order)?;
+    let current = section_clauses_mut(&mut rfc, section)?;
+
+    let mut reordered = Vec::with_capacity(current.len());
+    for name in &names {
+        let position = current
+            .iter()
+            .position(|rel_path| clause_name_from_rel_path(rel_path) == *name)
+            .ok_or_else(|| {
+                Diagnostic::new(
+                    DiagnosticCode::E0202ClauseNotFound,
+                    format!("Clause {rfc_id}:{name} is not in section '{section}'"),
+                    format!("{rfc_id}:{name}"),
+                )
+            })?;
+        reordered.push(current.remove(position));
+    }
+    reordered.append(current);
+    *current = reordered;
+
+    write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))?;
+
+    if !op.is_preview() {
+        ui::updated("RFC", rfc_id);
+        ui::sub_info(format!(
+            "Section '{section}': {}",
+            section_clause_names(&rfc, section).join(", ")
+        ));
+    }
+    Ok(vec![])
+}
+
+fn section_clauses_mut<'a>(
+    rfc: &'a mut RfcSpec,
+    section: &str,
+) -> DiagnosticResult<&'a mut Vec<String>> {
+    let index = section_index(rfc, section)?;
+    Ok(&mut rfc.sections[index].clauses)
+}
+
+fn section_clause_names(rfc: &RfcSpec, section: &str) -> Vec<String> {
+    rfc.sections
+        .iter()
+        .filter(|candidate| candidate.title == section)
+        .flat_map(|candidate| candidate.clauses.iter())
+        .map(|rel_path| clause_name_from_rel_path(rel_path))
+        .collect()
+}
+
+fn section_index(rfc: &RfcSpec, title: &str) -> DiagnosticResult<usize> {
+    rfc.sections
+        .iter()
+        .position(|section| section.title == title)
+        .ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E0116RfcSectionNotFound,
+                format!("Section '{title}' not found in {}", rfc.rfc_id),
+                rfc.rfc_id.as_str(),
+            )
+        })
+}
+
+fn validate_new_title(rfc: &RfcSpec, title: &str) -> DiagnosticResult<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "Section title must not be empty",
+            rfc.rfc_id.as_str(),
+        ));
+    }
+    if rfc.sections.iter().any(|section| section.title == title) {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0117RfcSectionExists,
+            format!("Section '{title}' already exists in {}", rfc.rfc_id),
+            rfc.rfc_id.as_str(),
+        ));
+    }
+    Ok(title.to_string())
+}
@@ -0,0 +1,64 @@
+//! Frozen copies of released RFC versions, written when `rfc advance` seals
+//! a version (spec -> impl) and `[snapshots] rfc_versions` is on.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::{ClauseSpec, RfcIndex, RfcSpec};
+use crate::render::{RenderProjection, expand_inline_refs, render_rfc_with_projection};
+use crate::write::{WriteOp, create_dir_all, write_file};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Machine-readable snapshot: the RFC and its clause set at one version.
+#[derive(Serialize)]
+struct VersionSnapshot<'a> {
+    rfc: &'a RfcSpec,
+    clauses: Vec<&'a ClauseSpec>,
+}
+
+/// Snapshot `rfc` at its current version. Existing snapshots are never
+/// rewritten; returns the markdown path when one was written.
+pub(super) fn write_version_snapshot(
+    config: &Config,
+    rfc: &RfcIndex,
+    op: WriteOp,
+) -> DiagnosticResult<Option<PathBuf>> {
+    let [md_path, json_path] = ["md", "json"]
+        .map(|extension| config.rfc_snapshot_path(&rfc.rfc.rfc_id, &rfc.rfc.version, extension));
+    if md_path.exists() {
+        return Ok(None);
+    }
+
+    let expanded = crate::fragments::expand_rfc(config, rfc)?;
+    let raw =
+        render_rfc_with_projection(&config.prefixes, &expanded, RenderProjection::Archive, None)?;
+    let markdown = expand_inline_refs(&config.prefixes, &raw, &config.source_scan.pattern);
+    let snapshot = VersionSnapshot {
+        rfc: &rfc.rfc,
+        clauses: rfc.clauses.iter().map(|clause| &clause.spec).collect(),
+    };
+    let json = serde_json::to_string_pretty(&snapshot).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0101RfcSchemaInvalid,
+            format!("Failed to serialize version snapshot: {err}"),
+            &rfc.rfc.rfc_id,
+        )
+    })?;
+
+    if let Some(dir) = md_path.parent() {
+        create_dir_all(dir, op, Some(&config.display_path(dir)))?;
+    }
+    write_file(
+        &md_path,
+        &markdown,
+        op,
+        Some(&config.display_path(&md_path)),
+    )?;
+    write_file(
+        &json_path,
+        &format!("{json}\n"),
+        op,
+        Some(&config.display_path(&json_path)),
+    )?;
+    Ok(Some(md_path))
+}
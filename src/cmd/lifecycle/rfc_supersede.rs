@@ -36,7 +36,7 @@ pub(super) fn supersede_rfc(
     with_file_transaction(
         &[rfc_path.as_path(), replacement_path.as_path()],
         op,
-        || {
+        |op| {
             write_rfc(
                 &rfc_path,
                 &source,
@@ -58,7 +58,7 @@ pub(super) fn supersede_rfc(
     Ok(vec![])
 }
 
-fn validate_supersede_transition(
+pub(super) fn validate_supersede_transition(
     source: &crate::model::RfcSpec,
     replacement: &crate::model::RfcSpec,
     rfc_id: &str,
@@ -0,0 +1,156 @@
+//! `supersede --update-refs`: point inbound `refs` at the replacement.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project;
+use crate::model::ProjectIndex;
+use crate::parse::{load_guards, write_adr, write_guard, write_work_item};
+use crate::scan::{RefMatcher, scan_files};
+use crate::ui;
+use crate::validate::normalize_clause_supersession_target;
+use crate::write::{WriteOp, write_rfc};
+
+/// Rewrite `refs` entries naming `id` to `by` across RFCs, ADRs, work items,
+/// and guards. Frozen RFCs keep their refs; they and every source reference
+/// to `id` are reported as warnings to update by hand.
+pub(super) fn update_inbound_refs(
+    config: &Config,
+    id: &str,
+    by: &str,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let by = if id.contains(':') {
+        normalize_clause_supersession_target(id, by)?
+    } else {
+        by.to_string()
+    };
+    let index = load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project to update references",
+                id,
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+
+    let mut updated = Vec::new();
+    let mut diagnostics = Vec::new();
+    for entry in &index.rfcs {
+        let mut rfc = entry.rfc.clone();
+        if !replace_ref(&mut rfc.refs, &rfc.rfc_id, id, &by) {
+            continue;
+        }
+        if rfc.frozen {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticCode::W0107SourceRefOutdated,
+                format!(
+                    "{} is frozen; its reference to {id} was not updated to {by}",
+                    rfc.rfc_id
+                ),
+                config.display_path(&entry.path).display().to_string(),
+            ));
+            continue;
+        }
+        write_rfc(
+            &entry.path,
+            &rfc,
+            op,
+            Some(&config.display_path(&entry.path)),
+        )?;
+        updated.push(rfc.rfc_id);
+    }
+    for entry in &index.adrs {
+        let mut spec = entry.spec.clone();
+        if replace_ref(&mut spec.govctl.refs, &spec.govctl.id, id, &by) {
+            write_adr(
+                &entry.path,
+                &spec,
+                op,
+                Some(&config.display_path(&entry.path)),
+            )?;
+            updated.push(spec.govctl.id);
+        }
+    }
+    for entry in &index.work_items {
+        let mut spec = entry.spec.clone();
+        if replace_ref(&mut spec.govctl.refs, &spec.govctl.id, id, &by) {
+            write_work_item(
+                &entry.path,
+                &spec,
+                op,
+                Some(&config.display_path(&entry.path)),
+            )?;
+            updated.push(spec.govctl.id);
+        }
+    }
+    for entry in load_guards(config)? {
+        let mut spec = entry.spec;
+        if replace_ref(&mut spec.govctl.refs, &spec.govctl.id, id, &by) {
+            write_guard(
+                &entry.path,
+                &spec,
+                op,
+                Some(&config.display_path(&entry.path)),
+            )?;
+            updated.push(spec.govctl.id);
+        }
+    }
+
+    if !op.is_preview() {
+        for owner in &updated {
+            ui::refs_updated(owner, id, &by);
+        }
+    }
+    diagnostics.extend(source_refs(config, &index, id, &by)?);
+    Ok(diagnostics)
+}
+
+/// Replace `id` with `by` in `refs`, keeping a single entry when `by` is
+/// already listed. The replacement's own refs are left alone, since they
+/// usually record what it replaces.
+fn replace_ref(refs: &mut Vec<String>, owner: &str, id: &str, by: &str) -> bool {
+    if owner == by || !refs.iter().any(|ref_id| ref_id == id) {
+        return false;
+    }
+    if refs.iter().any(|ref_id| ref_id == by) {
+        refs.retain(|ref_id| ref_id != id);
+    } else {
+        for ref_id in refs.iter_mut().filter(|ref_id| *ref_id == id) {
+            *ref_id = by.to_string();
+        }
+    }
+    true
+}
+
+/// A warning for each reference to `id` in the files `[source_scan]` selects.
+/// Source is never rewritten.
+fn source_refs(
+    config: &Config,
+    index: &ProjectIndex,
+    id: &str,
+    by: &str,
+) -> DiagnosticResult<Diagnostics> {
+    if !config.source_scan.enabled {
+        return Ok(vec![]);
+    }
+    let matcher = RefMatcher::new(config, index)?;
+    let mut diagnostics = Vec::new();
+    for file in scan_files(config)? {
+        for found in matcher.refs(&file) {
+            if found.target.as_deref() == Some(id) {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::W0107SourceRefOutdated,
+                    format!(
+                        "Reference to superseded {}: update it to {by}",
+                        found.written
+                    ),
+                    format!("{}:{}", file.path, file.line_of(found.offset)),
+                ));
+            }
+        }
+    }
+    Ok(diagnostics)
+}
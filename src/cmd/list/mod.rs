@@ -2,15 +2,21 @@
 
 mod output;
 mod resources;
+mod rows;
 mod summaries;
 
 use crate::ListTarget;
 use crate::OutputFormat;
 use crate::config::Config;
-use crate::diagnostic::{DiagnosticResult, Diagnostics};
-use crate::load::load_project;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::github::Github;
+use crate::load::load_project_metadata;
 use crate::parse::load_guards_with_warnings;
-use resources::{list_adrs, list_clauses, list_guards, list_rfcs, list_work_items};
+use resources::{
+    list_adrs, list_clauses, list_guards, list_rfcs, list_work_items, list_work_items_github,
+};
+
+pub use rows::ListRows;
 
 /// List artifacts
 pub fn list(
@@ -20,25 +26,53 @@ pub fn list(
     limit: Option<usize>,
     output: OutputFormat,
     tags: &[String],
+    stale: bool,
 ) -> DiagnosticResult<Diagnostics> {
+    if stale && !matches!(target, ListTarget::Rfc | ListTarget::Adr) {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0822UnsupportedOperation,
+            "--stale applies to `rfc list` and `adr list` only",
+            "list",
+        ));
+    }
     if target == ListTarget::Guard {
         let result = load_guards_with_warnings(config)?;
         list_guards(&result.items, filter, limit, output, tags);
         return Ok(result.warnings);
     }
 
-    let index = match load_project(config) {
+    let index = match load_project_metadata(config) {
         Ok(idx) => idx,
         Err(diags) => return Ok(diags),
     };
 
     match target {
-        ListTarget::Rfc => list_rfcs(&index, filter, limit, output, tags),
+        ListTarget::Rfc => list_rfcs(config, &index, filter, limit, output, tags, stale),
         ListTarget::Clause => list_clauses(&index, filter, limit, output, tags),
-        ListTarget::Adr => list_adrs(&index, filter, limit, output, tags),
+        ListTarget::Adr => {
+            let stale = stale.then_some(config);
+            list_adrs(&index, filter, limit, output, tags, stale)
+        }
         ListTarget::Work => list_work_items(&index, filter, limit, output, tags),
         ListTarget::Guard => unreachable!("handled above"),
     }
 
     Ok(vec![])
 }
+
+/// `work list --github`: work items with the state of their linked GitHub
+/// issues and pull requests.
+pub fn list_work_github(
+    config: &Config,
+    filter: Option<&str>,
+    limit: Option<usize>,
+    output: OutputFormat,
+    tags: &[String],
+) -> DiagnosticResult<Diagnostics> {
+    let index = match load_project_metadata(config) {
+        Ok(idx) => idx,
+        Err(diags) => return Ok(diags),
+    };
+    list_work_items_github(&Github::new(config), &index, filter, limit, output, tags)?;
+    Ok(vec![])
+}
@@ -2,7 +2,7 @@ use crate::OutputFormat;
 use crate::cmd::output::{command_table, print_json_array};
 use crate::theme::{SemanticColor, status_semantic};
 use crate::ui::stdout_supports_color;
-use comfy_table::{Attribute, Cell};
+use comfy_table::{Attribute, Cell, ColumnConstraint, Row};
 use serde::Serialize;
 
 fn cell(text: &str) -> Cell {
@@ -70,18 +70,20 @@ pub(super) fn output_list<T: Serialize>(
                     .collect::<Vec<_>>(),
             );
 
+            let is_status = |i: usize| {
+                headers
+                    .get(i)
+                    .is_some_and(|h| *h == "Status" || *h == "Phase")
+            };
             for item in items {
                 let row = to_row(item);
-                table.add_row(
+                let mut row = Row::from(
                     row.iter()
                         .enumerate()
                         .map(|(i, v)| {
                             if i == 0 {
                                 id_cell(v, use_colors)
-                            } else if headers
-                                .get(i)
-                                .is_some_and(|h| *h == "Status" || *h == "Phase")
-                            {
+                            } else if is_status(i) {
                                 status_cell(v, use_colors)
                             } else {
                                 cell(v)
@@ -89,6 +91,17 @@ pub(super) fn output_list<T: Serialize>(
                         })
                         .collect::<Vec<_>>(),
                 );
+                // Narrow terminals truncate long cells with an ellipsis
+                // instead of wrapping each row over several lines.
+                row.max_height(1);
+                table.add_row(row);
+            }
+            for i in 0..headers.len() {
+                if (i == 0 || is_status(i))
+                    && let Some(column) = table.column_mut(i)
+                {
+                    column.set_constraint(ColumnConstraint::ContentWidth);
+                }
             }
 
             println!("{table}");
@@ -1,18 +1,30 @@
 use super::output::output_list;
-use super::summaries::{AdrSummary, ClauseSummary, GuardSummary, RfcSummary, WorkItemSummary};
+use super::summaries::{
+    AdrSummary, ClauseSummary, GuardSummary, RfcSummary, WorkItemGithubSummary, WorkItemSummary,
+};
 use crate::OutputFormat;
-use crate::model::{GuardEntry, ProjectIndex, WorkItemStatus};
+use crate::config::Config;
+use crate::diagnostic::DiagnosticResult;
+use crate::github::Github;
+use crate::model::{GuardEntry, ProjectIndex, WorkItemEntry, WorkItemStatus};
+use crate::validate::{adr_staleness, rfc_staleness};
 use serde::Serialize;
 
 pub(super) fn list_rfcs(
+    config: &Config,
     index: &ProjectIndex,
     filter: Option<&str>,
     limit: Option<usize>,
     output: OutputFormat,
     tags: &[String],
+    stale: bool,
 ) {
     let mut rfcs: Vec<_> = index.rfcs.iter().collect();
 
+    if stale {
+        rfcs.retain(|r| rfc_staleness(config, r).is_some());
+    }
+
     if let Some(f) = filter {
         rfcs.retain(|r| {
             r.rfc.status.as_ref() == f || r.rfc.phase.as_ref() == f || r.rfc.rfc_id.contains(f)
@@ -26,7 +38,7 @@ pub(super) fn list_rfcs(
     output_resource_list(
         &mut rfcs,
         limit,
-        &["RFC", "Version", "Status", "Phase", "Title"],
+        RfcSummary::HEADERS,
         output,
         |rfc| RfcSummary::from_entry(rfc),
         RfcSummary::row,
@@ -61,7 +73,7 @@ pub(super) fn list_clauses(
     output_resource_list(
         &mut clauses,
         limit,
-        &["Clause", "RFC", "Kind", "Status", "Title"],
+        ClauseSummary::HEADERS,
         output,
         |(rfc_id, clause)| ClauseSummary::from_entry(rfc_id, clause),
         ClauseSummary::row,
@@ -74,9 +86,14 @@ pub(super) fn list_adrs(
     limit: Option<usize>,
     output: OutputFormat,
     tags: &[String],
+    stale: Option<&Config>,
 ) {
     let mut adrs: Vec<_> = index.adrs.iter().collect();
 
+    if let Some(config) = stale {
+        adrs.retain(|a| adr_staleness(config, a).is_some());
+    }
+
     if let Some(f) = filter {
         adrs.retain(|a| a.meta().status.as_ref() == f || a.meta().id.contains(f));
     }
@@ -88,7 +105,7 @@ pub(super) fn list_adrs(
     output_resource_list(
         &mut adrs,
         limit,
-        &["ADR", "Status", "Date", "Title"],
+        AdrSummary::HEADERS,
         output,
         |adr| AdrSummary::from_entry(adr),
         AdrSummary::row,
@@ -115,7 +132,7 @@ pub(super) fn list_guards(
     output_resource_list(
         &mut items,
         limit,
-        &["Guard", "Title", "Command"],
+        GuardSummary::HEADERS,
         output,
         |guard| GuardSummary::from_entry(guard),
         GuardSummary::row,
@@ -129,6 +146,46 @@ pub(super) fn list_work_items(
     output: OutputFormat,
     tags: &[String],
 ) {
+    let mut items = matching_work_items(index, filter, tags);
+    output_resource_list(
+        &mut items,
+        limit,
+        WorkItemSummary::HEADERS,
+        output,
+        |item| WorkItemSummary::from_entry(item),
+        WorkItemSummary::row,
+    );
+}
+
+/// Like [`list_work_items`], with the state of each linked GitHub issue.
+pub(super) fn list_work_items_github(
+    github: &Github,
+    index: &ProjectIndex,
+    filter: Option<&str>,
+    limit: Option<usize>,
+    output: OutputFormat,
+    tags: &[String],
+) -> DiagnosticResult<()> {
+    let mut items = matching_work_items(index, filter, tags);
+    apply_limit(&mut items, limit);
+    let summaries = items
+        .iter()
+        .map(|item| WorkItemGithubSummary::from_entry(item, github))
+        .collect::<DiagnosticResult<Vec<_>>>()?;
+    output_list(
+        &summaries,
+        WorkItemGithubSummary::HEADERS,
+        output,
+        WorkItemGithubSummary::row,
+    );
+    Ok(())
+}
+
+fn matching_work_items<'a>(
+    index: &'a ProjectIndex,
+    filter: Option<&str>,
+    tags: &[String],
+) -> Vec<&'a WorkItemEntry> {
     let mut items: Vec<_> = index.work_items.iter().collect();
 
     if let Some(f) = filter {
@@ -153,15 +210,11 @@ pub(super) fn list_work_items(
     retain_by_tags(&mut items, tags, |i| i.meta().tags.as_slice());
 
     items.sort_by(|a, b| a.meta().id.cmp(&b.meta().id));
-
-    output_resource_list(
-        &mut items,
-        limit,
-        &["ID", "Status", "Title"],
-        output,
-        |item| WorkItemSummary::from_entry(item),
-        WorkItemSummary::row,
-    );
+    if filter == Some("queue") {
+        // The queue lists in pick-up order.
+        items.sort_by_key(|i| crate::cmd::queue::rank_key(i));
+    }
+    items
 }
 
 fn output_resource_list<T, S>(
@@ -0,0 +1,134 @@
+//! Lists in `govctl list` form, for callers that write them somewhere other
+//! than stdout.
+
+use super::summaries::{AdrSummary, ClauseSummary, GuardSummary, RfcSummary, WorkItemSummary};
+use crate::model::{AdrEntry, ClauseEntry, GuardEntry, RfcIndex, WorkItemEntry};
+use serde::Serialize;
+
+/// Table rows and JSON items for one list, in the given order.
+pub struct ListRows {
+    pub headers: &'static [&'static str],
+    pub rows: Vec<Vec<String>>,
+    /// The items as `govctl list -o json` prints them.
+    json: Vec<serde_json::Value>,
+}
+
+impl ListRows {
+    pub fn rfcs<'a>(rfcs: impl IntoIterator<Item = &'a RfcIndex>) -> Self {
+        Self::collect(
+            RfcSummary::HEADERS,
+            rfcs.into_iter().map(RfcSummary::from_entry),
+            RfcSummary::row,
+        )
+    }
+
+    /// Clauses paired with the ID of their RFC.
+    pub fn clauses<'a>(clauses: impl IntoIterator<Item = (&'a str, &'a ClauseEntry)>) -> Self {
+        Self::collect(
+            ClauseSummary::HEADERS,
+            clauses
+                .into_iter()
+                .map(|(rfc_id, clause)| ClauseSummary::from_entry(rfc_id, clause)),
+            ClauseSummary::row,
+        )
+    }
+
+    pub fn adrs<'a>(adrs: impl IntoIterator<Item = &'a AdrEntry>) -> Self {
+        Self::collect(
+            AdrSummary::HEADERS,
+            adrs.into_iter().map(AdrSummary::from_entry),
+            AdrSummary::row,
+        )
+    }
+
+    pub fn work_items<'a>(items: impl IntoIterator<Item = &'a WorkItemEntry>) -> Self {
+        Self::collect(
+            WorkItemSummary::HEADERS,
+            items.into_iter().map(WorkItemSummary::from_entry),
+            WorkItemSummary::row,
+        )
+    }
+
+    pub fn guards<'a>(guards: impl IntoIterator<Item = &'a GuardEntry>) -> Self {
+        Self::collect(
+            GuardSummary::HEADERS,
+            guards.into_iter().map(GuardSummary::from_entry),
+            GuardSummary::row,
+        )
+    }
+
+    fn collect<S: Serialize>(
+        headers: &'static [&'static str],
+        summaries: impl Iterator<Item = S>,
+        to_row: impl Fn(&S) -> Vec<String>,
+    ) -> Self {
+        let mut rows = Vec::new();
+        let mut json = Vec::new();
+        for summary in summaries {
+            rows.push(to_row(&summary));
+            json.push(serde_json::to_value(&summary).unwrap_or(serde_json::Value::Null));
+        }
+        Self {
+            headers,
+            rows,
+            json,
+        }
+    }
+
+    /// RFC 4180 CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+        let header = self.headers.iter().map(|h| h.to_string()).collect();
+        for row in std::iter::once(&header).chain(&self.rows) {
+            let cells: Vec<String> = row.iter().map(|cell| csv_cell(cell)).collect();
+            out.push_str(&cells.join(","));
+            out.push_str("\r\n");
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut out = serde_json::to_string_pretty(&self.json).unwrap_or_else(|_| "[]".into());
+        out.push('\n');
+        out
+    }
+}
+
+fn csv_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        WorkItemContent, WorkItemMeta, WorkItemSpec, WorkItemStatus, WorkItemVerification,
+    };
+
+    #[test]
+    fn csv_quotes_cells_and_json_matches_list_output() {
+        let item = WorkItemEntry {
+            spec: WorkItemSpec {
+                govctl: WorkItemMeta::new(
+                    "WI-2026-01-01-001",
+                    "Parse \"quoted\", commas",
+                    WorkItemStatus::Queue,
+                ),
+                content: WorkItemContent::default(),
+                verification: WorkItemVerification::default(),
+            },
+            path: "gov/work/WI-2026-01-01-001.toml".into(),
+        };
+        let rows = ListRows::work_items([&item]);
+
+        assert_eq!(
+            rows.to_csv(),
+            "ID,Status,Title\r\nWI-2026-01-01-001,queue,\"Parse \"\"quoted\"\", commas\"\r\n"
+        );
+        assert!(rows.to_json().contains("\"status\": \"queue\""));
+    }
+}
@@ -1,4 +1,6 @@
 use super::output::truncate_chars;
+use crate::diagnostic::DiagnosticResult;
+use crate::github::{Github, IssueRef};
 use crate::model::{AdrEntry, ClauseEntry, GuardEntry, RfcIndex, WorkItemEntry};
 use serde::Serialize;
 
@@ -14,6 +16,8 @@ pub(super) struct RfcSummary {
 }
 
 impl RfcSummary {
+    pub(super) const HEADERS: &[&str] = &["RFC", "Version", "Status", "Phase", "Title"];
+
     pub(super) fn from_entry(rfc: &RfcIndex) -> Self {
         let amended = crate::signature::is_rfc_amended(rfc);
         Self {
@@ -51,6 +55,8 @@ pub(super) struct ClauseSummary {
 }
 
 impl ClauseSummary {
+    pub(super) const HEADERS: &[&str] = &["Clause", "RFC", "Kind", "Status", "Title"];
+
     pub(super) fn from_entry(rfc_id: &str, clause: &ClauseEntry) -> Self {
         Self {
             id: clause.spec.clause_id.clone(),
@@ -81,6 +87,8 @@ pub(super) struct AdrSummary {
 }
 
 impl AdrSummary {
+    pub(super) const HEADERS: &[&str] = &["ADR", "Status", "Date", "Title"];
+
     pub(super) fn from_entry(adr: &AdrEntry) -> Self {
         Self {
             id: adr.meta().id.clone(),
@@ -108,6 +116,8 @@ pub(super) struct GuardSummary {
 }
 
 impl GuardSummary {
+    pub(super) const HEADERS: &[&str] = &["Guard", "Title", "Command"];
+
     pub(super) fn from_entry(guard: &GuardEntry) -> Self {
         Self {
             id: guard.meta().id.clone(),
@@ -133,6 +143,8 @@ pub(super) struct WorkItemSummary {
 }
 
 impl WorkItemSummary {
+    pub(super) const HEADERS: &[&str] = &["ID", "Status", "Title"];
+
     pub(super) fn from_entry(item: &WorkItemEntry) -> Self {
         Self {
             id: item.meta().id.clone(),
@@ -145,3 +157,60 @@ impl WorkItemSummary {
         vec![self.id.clone(), self.status.clone(), self.title.clone()]
     }
 }
+
+#[derive(Serialize)]
+pub(super) struct WorkItemGithubSummary {
+    id: String,
+    status: String,
+    title: String,
+    github: Vec<LinkedIssue>,
+}
+
+#[derive(Serialize)]
+struct LinkedIssue {
+    link: String,
+    state: String,
+}
+
+impl WorkItemGithubSummary {
+    pub(super) const HEADERS: &[&str] = &["ID", "Status", "Title", "GitHub"];
+
+    pub(super) fn from_entry(item: &WorkItemEntry, github: &Github) -> DiagnosticResult<Self> {
+        let github = item
+            .meta()
+            .github
+            .iter()
+            .map(|link| {
+                let state = match IssueRef::parse(link) {
+                    Some(issue) => github.issue_state(&issue)?,
+                    None => "invalid".to_string(),
+                };
+                Ok(LinkedIssue {
+                    link: link.clone(),
+                    state,
+                })
+            })
+            .collect::<DiagnosticResult<_>>()?;
+        Ok(Self {
+            id: item.meta().id.clone(),
+            status: item.meta().status.as_ref().to_string(),
+            title: item.meta().title.clone(),
+            github,
+        })
+    }
+
+    pub(super) fn row(&self) -> Vec<String> {
+        let github = self
+            .github
+            .iter()
+            .map(|issue| format!("{} ({})", issue.link, issue.state))
+            .collect::<Vec<_>>()
+            .join(", ");
+        vec![
+            self.id.clone(),
+            self.status.clone(),
+            self.title.clone(),
+            github,
+        ]
+    }
+}
@@ -0,0 +1,260 @@
+//! Compliance mapping commands per [[RFC-0002:C-RESOURCES]]: `mapping add`,
+//! `remove`, and `list`.
+//!
+//! Each external standard is one file, `gov/mapping/<standard>.toml`, listing
+//! its controls and the clauses that satisfy them.
+
+use crate::OutputFormat;
+use crate::artifact_index::artifact_ref_states;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project;
+use crate::model::{MappingControl, MappingEntry, MappingMeta, MappingSpec, ProjectIndex};
+use crate::parse::{load_mappings, write_mapping};
+use crate::ui;
+use crate::validate::{control_coverage, require_active_clause};
+use crate::write::{WriteOp, create_dir_all};
+use comfy_table::Cell;
+use serde::Serialize;
+use slug::slugify;
+
+/// Options for `mapping add` beyond the control and its clauses.
+#[derive(Debug, Clone, Default)]
+pub struct MappingAddOptions {
+    /// Title of the control
+    pub title: Option<String>,
+    /// Title of the standard, used when the mapping file is created or renamed
+    pub standard_title: Option<String>,
+}
+
+/// Map `control` of `standard` to `clauses`, creating the mapping file on
+/// first use. Clauses already linked are kept once.
+pub fn mapping_add(
+    config: &Config,
+    standard: &str,
+    control: &str,
+    clauses: &[String],
+    options: &MappingAddOptions,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    validate_standard_id(standard)?;
+    let index = load_index(config)?;
+    let states = artifact_ref_states(&index);
+    for clause in clauses {
+        require_active_clause(&states, standard, control, clause, standard)?;
+    }
+
+    let mut mapping = match find_mapping(config, standard)? {
+        Some(mapping) => mapping,
+        None => {
+            let mapping_dir = config.mapping_dir();
+            create_dir_all(&mapping_dir, op, Some(&config.display_path(&mapping_dir)))?;
+            MappingEntry {
+                spec: MappingSpec {
+                    govctl: MappingMeta::new(standard, standard),
+                    controls: vec![],
+                },
+                path: mapping_dir.join(format!("{}.toml", slugify(standard))),
+            }
+        }
+    };
+    if let Some(title) = &options.standard_title {
+        mapping.spec.govctl.title = title.clone();
+    }
+
+    let controls = &mut mapping.spec.controls;
+    let position = match controls.iter().position(|entry| entry.id == control) {
+        Some(position) => position,
+        None => {
+            controls.push(MappingControl {
+                id: control.to_string(),
+                title: None,
+                clauses: vec![],
+            });
+            controls.len() - 1
+        }
+    };
+    let entry = &mut controls[position];
+    if let Some(title) = &options.title {
+        entry.title = Some(title.clone());
+    }
+    for clause in clauses {
+        if !entry.clauses.contains(clause) {
+            entry.clauses.push(clause.clone());
+        }
+    }
+
+    write_mapping(
+        &mapping.path,
+        &mapping.spec,
+        op,
+        Some(&config.display_path(&mapping.path)),
+    )?;
+    if !op.is_preview() {
+        ui::updated("mapping", standard);
+        ui::sub_info(format!("{control} -> {}", clauses.join(", ")));
+    }
+    Ok(vec![])
+}
+
+/// Unlink `clauses` from `control`, or drop the whole control when no
+/// clauses are given. A control left without clauses is dropped too.
+pub fn mapping_remove(
+    config: &Config,
+    standard: &str,
+    control: &str,
+    clauses: &[String],
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let mut mapping = find_mapping(config, standard)?.ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E1405MappingNotFound,
+            format!("Standard '{standard}' is not mapped (hint: `govctl mapping list`)"),
+            standard,
+        )
+    })?;
+    let display = config.display_path(&mapping.path);
+    let controls = &mut mapping.spec.controls;
+    let position = controls
+        .iter()
+        .position(|entry| entry.id == control)
+        .ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E1405MappingNotFound,
+                format!("Standard '{standard}' has no control '{control}'"),
+                display.display().to_string(),
+            )
+        })?;
+
+    for clause in clauses {
+        let linked = &mut controls[position].clauses;
+        let Some(at) = linked.iter().position(|linked| linked == clause) else {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E1405MappingNotFound,
+                format!("Control '{standard} {control}' is not mapped to {clause}"),
+                display.display().to_string(),
+            ));
+        };
+        linked.remove(at);
+    }
+    if clauses.is_empty() || controls[position].clauses.is_empty() {
+        controls.remove(position);
+    }
+
+    write_mapping(&mapping.path, &mapping.spec, op, Some(&display))?;
+    if !op.is_preview() {
+        ui::updated("mapping", standard);
+        if clauses.is_empty() {
+            ui::sub_info(format!("Removed control {control}"));
+        } else {
+            ui::sub_info(format!("{control} no longer -> {}", clauses.join(", ")));
+        }
+    }
+    Ok(vec![])
+}
+
+#[derive(Serialize)]
+struct MappingRow {
+    standard: String,
+    control: String,
+    title: Option<String>,
+    clauses: Vec<String>,
+    coverage: &'static str,
+}
+
+/// Print every mapped control, optionally of one standard only.
+pub fn mapping_list(
+    config: &Config,
+    standard: Option<&str>,
+    output: OutputFormat,
+) -> DiagnosticResult<Diagnostics> {
+    let index = load_index(config)?;
+    let states = artifact_ref_states(&index);
+    let rows: Vec<MappingRow> = load_mappings(config)?
+        .into_iter()
+        .filter(|mapping| standard.is_none_or(|id| mapping.meta().id == id))
+        .flat_map(|mapping| {
+            let standard = mapping.spec.govctl.id;
+            let states = &states;
+            mapping
+                .spec
+                .controls
+                .into_iter()
+                .map(move |control| MappingRow {
+                    standard: standard.clone(),
+                    coverage: control_coverage(states, &control.clauses),
+                    control: control.id,
+                    title: control.title,
+                    clauses: control.clauses,
+                })
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => print_json_array(&rows),
+        OutputFormat::Plain => {
+            for row in &rows {
+                println!(
+                    "{}\t{}\t{}",
+                    row.standard,
+                    row.control,
+                    row.clauses.join(",")
+                );
+            }
+        }
+        OutputFormat::Table => {
+            let mut table =
+                table_with_bold_headers(&["Standard", "Control", "Title", "Clauses", "Coverage"]);
+            for row in &rows {
+                table.add_row(vec![
+                    Cell::new(&row.standard),
+                    Cell::new(&row.control),
+                    Cell::new(row.title.as_deref().unwrap_or_default()),
+                    Cell::new(row.clauses.join("\n")),
+                    Cell::new(row.coverage),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(vec![])
+}
+
+fn find_mapping(config: &Config, standard: &str) -> DiagnosticResult<Option<MappingEntry>> {
+    Ok(load_mappings(config)?
+        .into_iter()
+        .find(|mapping| mapping.meta().id == standard))
+}
+
+/// Standard IDs become file names, so they stay to a path-safe alphabet.
+fn validate_standard_id(standard: &str) -> DiagnosticResult<()> {
+    let mut chars = standard.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+    if valid {
+        Ok(())
+    } else {
+        Err(Diagnostic::new(
+            DiagnosticCode::E0820InvalidFieldValue,
+            format!(
+                "Invalid standard ID '{standard}' (expected letters, digits, '.', '_' or '-', e.g. ISO-27001)"
+            ),
+            standard,
+        ))
+    }
+}
+
+pub(crate) fn load_index(config: &Config) -> DiagnosticResult<ProjectIndex> {
+    load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for compliance mappings",
+                "mapping",
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })
+}
@@ -61,10 +61,12 @@ pub fn migrate(config: &Config, op: WriteOp) -> DiagnosticResult<Diagnostics> {
     let schemas_synced = sync_schemas(config, op)?;
     let gitignore_entries_synced =
         crate::cmd::project_support::ensure_local_state_gitignore_entries(config, op)?;
+    let editor_mappings_synced =
+        crate::cmd::project_support::ensure_editor_schema_settings(config, op, false)?;
 
     let current = config.schema.version;
     if current >= CURRENT_SCHEMA_VERSION {
-        if schemas_synced > 0 || gitignore_entries_synced > 0 {
+        if schemas_synced > 0 || gitignore_entries_synced > 0 || editor_mappings_synced > 0 {
             let mut parts = Vec::new();
             if schemas_synced > 0 {
                 parts.push(format!("{schemas_synced} schema file(s)"));
@@ -77,6 +79,9 @@ pub fn migrate(config: &Config, op: WriteOp) -> DiagnosticResult<Diagnostics> {
                 };
                 parts.push(format!("{gitignore_entries_synced} {label}"));
             }
+            if editor_mappings_synced > 0 {
+                parts.push(format!("{editor_mappings_synced} editor schema mapping(s)"));
+            }
             let message = if op.is_preview() {
                 format!(
                     "Would sync {}; already at schema version {CURRENT_SCHEMA_VERSION}",
@@ -117,7 +122,7 @@ pub fn migrate(config: &Config, op: WriteOp) -> DiagnosticResult<Diagnostics> {
     all_ops.push(plan_config_version_bump(config, CURRENT_SCHEMA_VERSION)?);
 
     if op.is_preview() {
-        preview_ops(config, &all_ops);
+        preview_ops(config, &all_ops, op)?;
     } else {
         execute_ops(config, &all_ops)?;
         for name in &step_names {
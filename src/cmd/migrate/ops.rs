@@ -1,6 +1,6 @@
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
-use crate::ui;
+use crate::write::{WriteOp, delete_file, replace_file, write_file};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -18,85 +18,58 @@ pub(super) enum FileOp {
     },
 }
 
-pub(super) fn preview_ops(config: &Config, ops: &[FileOp]) {
+pub(super) fn preview_ops(
+    config: &Config,
+    ops: &[FileOp],
+    write_op: WriteOp,
+) -> DiagnosticResult<()> {
     for op in ops {
         match op {
             FileOp::Write { path, content } => {
-                ui::dry_run_file_preview(&config.display_path(path), content);
+                write_file(path, content, write_op, Some(&config.display_path(path)))?;
             }
             FileOp::Delete { path } => {
-                ui::info(format!(
-                    "[DRY RUN] Would delete: {}",
-                    config.display_path(path).display()
-                ));
+                delete_file(path, write_op, Some(&config.display_path(path)))?;
             }
         }
     }
+    Ok(())
 }
 
 pub(super) fn execute_ops(config: &Config, ops: &[FileOp]) -> DiagnosticResult<()> {
     let gov_root = &config.gov_root;
-    let stage_root = gov_root.join(".migrate-stage");
     let backup_root = gov_root.join(".migrate-backup");
 
-    if stage_root.exists() || backup_root.exists() {
-        let mut conflicts = Vec::new();
-        if stage_root.exists() {
-            conflicts.push(config.display_path(&stage_root).display().to_string());
-        }
-        if backup_root.exists() {
-            conflicts.push(config.display_path(&backup_root).display().to_string());
-        }
+    if backup_root.exists() {
         return Err(Diagnostic::new(
             DiagnosticCode::E0504PathConflict,
             format!(
-                "Migration staging directories already exist: {}",
-                conflicts.join(", ")
+                "Migration backup directory already exists: {}",
+                config.display_path(&backup_root).display()
             ),
             config.display_path(gov_root).display().to_string(),
         ));
     }
 
-    fs::create_dir_all(&stage_root)
-        .map_err(|err| io_error(&stage_root, "create migration stage directory", err))?;
     fs::create_dir_all(&backup_root)
         .map_err(|err| io_error(&backup_root, "create migration backup directory", err))?;
 
-    // Stage: write all new content to staging area
-    if let Err(err) = materialize_stage(&stage_root, ops) {
-        let _ = fs::remove_dir_all(&stage_root);
-        let _ = fs::remove_dir_all(&backup_root);
-        return Err(err);
-    }
-
-    // Commit: backup originals then apply staged content
-    let result = commit_ops(&stage_root, &backup_root, ops);
-    let _ = fs::remove_dir_all(&stage_root);
+    // Commit: backup originals then replace each file atomically
+    let result = commit_ops(&backup_root, ops, config.concurrency.fsync);
     if result.is_ok() {
         let _ = fs::remove_dir_all(&backup_root);
     }
     result
 }
 
-fn materialize_stage(stage_root: &Path, ops: &[FileOp]) -> DiagnosticResult<()> {
-    for (i, op) in ops.iter().enumerate() {
-        if let FileOp::Write { content, .. } = op {
-            let staged = stage_root.join(format!("{i}"));
-            fs::write(&staged, content)
-                .map_err(|err| io_error(&staged, "write migration staged file", err))?;
-        }
-    }
-    Ok(())
-}
-
-fn commit_ops(stage_root: &Path, backup_root: &Path, ops: &[FileOp]) -> DiagnosticResult<()> {
+fn commit_ops(backup_root: &Path, ops: &[FileOp], fsync: bool) -> DiagnosticResult<()> {
     let mut applied: Vec<AppliedOp> = Vec::new();
 
     let result = (|| -> DiagnosticResult<()> {
         for (i, op) in ops.iter().enumerate() {
             let backup_path = backup_root.join(format!("{i}"));
             match op {
-                FileOp::Write { path, .. } => {
+                FileOp::Write { path, content } => {
                     let existed =
                         backup_existing_file(path, &backup_path, "backup file before migration")?;
                     if let Some(parent) = path.parent() {
@@ -104,9 +77,7 @@ fn commit_ops(stage_root: &Path, backup_root: &Path, ops: &[FileOp]) -> Diagnost
                             io_error(parent, "create migration target directory", err)
                         })?;
                     }
-                    let staged = stage_root.join(format!("{i}"));
-                    fs::copy(&staged, path)
-                        .map_err(|err| io_error(path, "apply migrated file", err))?;
+                    replace_file(path, content.as_bytes(), path, fsync)?;
                     if existed {
                         applied.push(AppliedOp::Restore {
                             path: path.clone(),
@@ -50,7 +50,7 @@ pub(super) fn plan_release_upgrade(config: &Config) -> DiagnosticResult<Option<V
         }
     }
 
-    validate_toml_value(ArtifactSchema::Release, config, &path, &raw)?;
+    validate_toml_value(ArtifactSchema::Release, config, &path, &content, &raw)?;
     let releases: ReleasesFile = raw.try_into().map_err(|e| {
         Diagnostic::new(
             DiagnosticCode::E0704ReleaseSchemaInvalid,
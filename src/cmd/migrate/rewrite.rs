@@ -96,6 +96,10 @@ pub(super) fn plan_toml_rewrites(
         ArtifactSchema::WorkItem,
     ));
     ops.extend(collect_rewrites(&config.guard_dir(), ArtifactSchema::Guard));
+    ops.extend(collect_rewrites(
+        &config.mapping_dir(),
+        ArtifactSchema::Mapping,
+    ));
 
     let rfc_root = config.rfc_dir();
     if rfc_root.exists() {
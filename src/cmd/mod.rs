@@ -1,24 +1,47 @@
 //! Command implementations.
 
+pub mod anchor;
+pub mod attach;
+pub mod audit;
 pub mod check;
+pub mod clause_history;
+pub mod cli_reference;
+pub mod config_check;
+pub mod config_edit;
+pub mod config_show;
 pub(crate) mod confirmation;
 pub mod describe;
 pub mod edit;
+pub mod explain;
+pub mod export;
+pub mod fmt;
 pub mod guard;
 pub(crate) mod guard_refs;
+pub mod history;
 pub mod lifecycle;
 pub mod list;
 pub mod loop_cmd;
+pub mod mapping;
 pub mod migrate;
 #[path = "move_.rs"]
 pub mod move_;
 pub mod new;
 pub(crate) mod output;
+pub mod prioritize;
 pub(crate) mod project_support;
+pub mod publish;
+pub mod queue;
 pub mod render;
+pub(crate) mod result_envelope;
+pub mod scan_coverage;
 pub mod search;
 pub mod self_update;
 pub mod status;
+pub mod supersede_chain;
+pub mod sync;
 pub mod tag;
+pub mod template;
+pub mod trash;
 pub mod verify;
 pub(crate) mod work_lookup;
+pub mod workspace_check;
@@ -3,34 +3,48 @@
 use crate::cmd::verify;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
-use crate::model::{ChecklistStatus, WorkItemStatus};
+use crate::model::{ChangelogCategory, ChecklistStatus, WorkItemEntry, WorkItemStatus};
 use crate::parse::{load_releases, load_work_item, write_work_item};
 use crate::ui;
-use crate::validate::is_valid_work_transition;
+use crate::validate::{is_valid_work_transition, is_work_reopen};
 use crate::write::{WriteOp, today};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// The work item file `move` acts on: a path, a file name in the work
+/// directory, or an ID or partial name.
+pub(crate) fn resolve_work_path(config: &Config, file: &Path) -> DiagnosticResult<PathBuf> {
+    if file.is_absolute() || file.exists() {
+        return Ok(file.to_path_buf());
+    }
+    let in_work_dir = config.work_dir().join(file);
+    if in_work_dir.exists() {
+        Ok(in_work_dir)
+    } else {
+        find_work_item_by_name(config, &file.to_string_lossy())
+    }
+}
+
+/// How `work finish` closes an item, on top of moving it to done.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FinishOptions {
+    /// Tick acceptance criteria still pending in the `chore` category.
+    pub tick_chores: bool,
+}
 
 /// Move work item to new status
+///
+/// Reopening (done → active, cancelled → queue) requires `reopen_reason`,
+/// which is recorded as a dated note. `finish` runs the item's guards even
+/// when `[work_item.done] guards` is off.
 pub fn move_item(
     config: &Config,
     file: &Path,
     status: WorkItemStatus,
+    reopen_reason: Option<&str>,
+    finish: Option<FinishOptions>,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
-    // Find the work item file
-    let work_path = if file.is_absolute() || file.exists() {
-        file.to_path_buf()
-    } else {
-        // Try in work directory
-        let in_work_dir = config.work_dir().join(file);
-        if in_work_dir.exists() {
-            in_work_dir
-        } else {
-            // Try to find by partial name
-            find_work_item_by_name(config, &file.to_string_lossy())?
-        }
-    };
-
+    let work_path = resolve_work_path(config, file)?;
     let mut entry = load_work_item(config, &work_path)?;
 
     let work_id = &entry.spec.govctl.id;
@@ -49,66 +63,90 @@ pub fn move_item(
         ));
     }
 
-    if previous_status == WorkItemStatus::Done && status == WorkItemStatus::Active {
-        let releases = load_releases(config)?;
-        if releases
-            .releases
-            .iter()
-            .any(|release| release.refs.iter().any(|id| id == work_id))
-        {
+    let reopening = is_work_reopen(previous_status, status);
+    match reopen_reason.map(str::trim) {
+        None if reopening => {
             return Err(Diagnostic::new(
                 DiagnosticCode::E0403WorkInvalidTransition,
-                "Cannot reopen a Work Item referenced by a release. Released Work Items have no valid transition away from done.",
+                format!(
+                    "Reopening {} ({} -> {}) requires --reopen --reason \"<why>\"",
+                    work_id,
+                    previous_status.as_ref(),
+                    status.as_ref()
+                ),
                 work_id,
             ));
         }
-    }
-
-    // Validate acceptance criteria before marking done
-    if status == WorkItemStatus::Done {
-        // Must have at least one acceptance criterion
-        if entry.spec.content.acceptance_criteria.is_empty() {
+        Some(_) if !reopening => {
             return Err(Diagnostic::new(
-                DiagnosticCode::E0407WorkMissingCriteria,
+                DiagnosticCode::E0403WorkInvalidTransition,
                 format!(
-                    "Cannot mark as done: no acceptance criteria defined.\n\
-                     Add criteria with: govctl add {} acceptance_criteria \"<criterion>\"",
-                    work_id
+                    "--reopen only applies to done -> active and cancelled -> queue, not {} -> {}",
+                    previous_status.as_ref(),
+                    status.as_ref()
                 ),
                 work_id,
             ));
         }
+        Some("") => {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0812FieldEmpty,
+                "Reopen reason cannot be empty",
+                work_id,
+            ));
+        }
+        _ => {}
+    }
 
-        // All criteria must be done or cancelled (no pending)
-        let pending: Vec<_> = entry
-            .spec
-            .content
-            .acceptance_criteria
+    if previous_status == WorkItemStatus::Done && status == WorkItemStatus::Active {
+        let releases = load_releases(config)?;
+        if releases
+            .releases
             .iter()
-            .filter(|c| c.status == ChecklistStatus::Pending)
-            .map(|c| c.text.as_str())
-            .collect();
-
-        if !pending.is_empty() {
-            let list = pending
-                .iter()
-                .map(|t| format!("  - {t}"))
-                .collect::<Vec<_>>()
-                .join("\n");
+            .any(|release| release.refs.iter().any(|id| id == work_id))
+        {
             return Err(Diagnostic::new(
-                DiagnosticCode::E0407WorkMissingCriteria,
-                format!(
-                    "Cannot mark as done: {} pending acceptance criteria:\n{}",
-                    pending.len(),
-                    list
-                ),
+                DiagnosticCode::E0403WorkInvalidTransition,
+                "Cannot reopen a Work Item referenced by a release. Released Work Items have no valid transition away from done.",
                 work_id,
             ));
         }
+    }
 
-        verify::enforce_work_item_guards(config, &entry)?;
+    if status == WorkItemStatus::Done {
+        if finish.is_some_and(|finish| finish.tick_chores) {
+            tick_pending_chores(&mut entry);
+        }
+        let unmet = unmet_done_requirements(config, &entry);
+        if !unmet.is_empty() {
+            return Ok(unmet);
+        }
+        // Guards are slow; run them only once everything else is met. Guards
+        // the item declares itself always run.
+        if config.work_item.done.guards
+            || finish.is_some()
+            || !entry.spec.verification.guards.is_empty()
+        {
+            let mut results = Vec::new();
+            let enforced = verify::enforce_work_item_guards(config, &entry, &mut results);
+            if let Err(err) = enforced {
+                // The item stays put, but the failed runs are still evidence;
+                // reporting rather than returning the error keeps that write.
+                verify::record_guard_results(config, &work_path, results, op)?;
+                return Ok(vec![err]);
+            }
+            entry.spec.verification.results.extend(results);
+        }
     }
 
+    if let Some(reason) = reopen_reason {
+        entry.spec.content.notes.push(format!(
+            "Reopened from {} on {}: {}",
+            previous_status.as_ref(),
+            today(),
+            reason.trim()
+        ));
+    }
     entry.spec.govctl.status = status;
 
     // Update dates
@@ -118,7 +156,8 @@ pub fn move_item(
                 entry.spec.govctl.started = Some(today());
             }
         }
-        (WorkItemStatus::Done, WorkItemStatus::Active) => {
+        (WorkItemStatus::Done, WorkItemStatus::Active)
+        | (WorkItemStatus::Cancelled, WorkItemStatus::Queue) => {
             entry.spec.govctl.completed = None;
         }
         (_, WorkItemStatus::Done | WorkItemStatus::Cancelled) => {
@@ -145,12 +184,86 @@ pub fn move_item(
     Ok(vec![])
 }
 
+fn tick_pending_chores(entry: &mut WorkItemEntry) {
+    for criterion in &mut entry.spec.content.acceptance_criteria {
+        if criterion.status == ChecklistStatus::Pending
+            && criterion.category == ChangelogCategory::Chore
+        {
+            criterion.status = ChecklistStatus::Done;
+        }
+    }
+}
+
+/// The `[work_item.done]` requirements `entry` fails, other than guards,
+/// one error per requirement.
+fn unmet_done_requirements(config: &Config, entry: &WorkItemEntry) -> Diagnostics {
+    let requirements = &config.work_item.done;
+    let work_id = entry.spec.govctl.id.as_str();
+    let content = &entry.spec.content;
+    let mut unmet = Vec::new();
+
+    if requirements.acceptance_criteria {
+        // Must have at least one acceptance criterion, none of them pending
+        let pending: Vec<_> = content
+            .acceptance_criteria
+            .iter()
+            .filter(|c| c.status == ChecklistStatus::Pending)
+            .map(|c| format!("  - {}", c.text))
+            .collect();
+        if content.acceptance_criteria.is_empty() {
+            unmet.push(Diagnostic::new(
+                DiagnosticCode::E0407WorkMissingCriteria,
+                format!(
+                    "Cannot mark as done: no acceptance criteria defined.\n\
+                     Add criteria with: govctl add {} acceptance_criteria \"<criterion>\"",
+                    work_id
+                ),
+                work_id,
+            ));
+        } else if !pending.is_empty() {
+            unmet.push(Diagnostic::new(
+                DiagnosticCode::E0407WorkMissingCriteria,
+                format!(
+                    "Cannot mark as done: {} pending acceptance criteria:\n{}",
+                    pending.len(),
+                    pending.join("\n")
+                ),
+                work_id,
+            ));
+        }
+    }
+
+    if requirements.notes && content.notes.is_empty() && content.journal.is_empty() {
+        unmet.push(Diagnostic::new(
+            DiagnosticCode::E0412WorkDoneRequirementUnmet,
+            format!(
+                "Cannot mark as done: no notes recorded (required by work_item.done.notes).\n\
+                 Add one with: govctl work add {work_id} notes \"<note>\""
+            ),
+            work_id,
+        ));
+    }
+
+    if requirements.refs && entry.spec.govctl.refs.is_empty() {
+        unmet.push(Diagnostic::new(
+            DiagnosticCode::E0412WorkDoneRequirementUnmet,
+            format!(
+                "Cannot mark as done: no refs (required by work_item.done.refs).\n\
+                 Add one with: govctl work add {work_id} refs <RFC-or-ADR-ID>"
+            ),
+            work_id,
+        ));
+    }
+
+    unmet
+}
+
 fn valid_work_targets(status: WorkItemStatus) -> &'static str {
     match status {
         WorkItemStatus::Queue => "active, cancelled",
         WorkItemStatus::Active => "done, cancelled",
-        WorkItemStatus::Done => "active when no release references the Work Item",
-        WorkItemStatus::Cancelled => "none (cancelled is terminal)",
+        WorkItemStatus::Done => "active with --reopen when no release references the Work Item",
+        WorkItemStatus::Cancelled => "queue with --reopen",
     }
 }
 
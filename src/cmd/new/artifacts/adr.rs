@@ -1,4 +1,4 @@
-use super::write_new_artifact_toml;
+use super::{NewDetails, validate_new_refs, write_new_artifact_toml};
 use crate::config::Config;
 use crate::diagnostic::{DiagnosticCode, DiagnosticResult, Diagnostics};
 use crate::model::{AdrContent, AdrMeta, AdrSpec, AdrStatus};
@@ -7,7 +7,12 @@ use crate::ui;
 use crate::write::{WriteOp, create_dir_all, today};
 use slug::slugify;
 
-pub(super) fn create(config: &Config, title: &str, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+pub(super) fn create(
+    config: &Config,
+    title: &str,
+    details: &NewDetails,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
     let adr_dir = config.adr_dir();
     let display_adr_dir = config.display_path(&adr_dir);
     create_dir_all(&adr_dir, op, Some(&display_adr_dir))?;
@@ -15,13 +20,9 @@ pub(super) fn create(config: &Config, title: &str, op: WriteOp) -> DiagnosticRes
     let mut max_num = 0u32;
     if let Ok(entries) = std::fs::read_dir(&adr_dir) {
         for entry in entries.flatten() {
-            let name = entry.file_name();
-            let name_str = name.to_string_lossy();
-            if name_str.starts_with("ADR-")
-                && let Some(num_str) = name_str
-                    .strip_prefix("ADR-")
-                    .and_then(|s| s.split('-').next())
-                && let Ok(num) = num_str.parse::<u32>()
+            if let Some(num) = config
+                .prefixes
+                .adr_number(&entry.file_name().to_string_lossy())
             {
                 max_num = max_num.max(num);
             }
@@ -29,17 +30,23 @@ pub(super) fn create(config: &Config, title: &str, op: WriteOp) -> DiagnosticRes
     }
 
     let next_num = max_num + 1;
-    let adr_id = format!("ADR-{next_num:04}");
+    let adr_id = config.prefixes.adr_id(next_num);
     let slug = slugify(title);
     let filename = format!("{adr_id}-{slug}.toml");
     let adr_path = adr_dir.join(&filename);
+    validate_new_refs(config, &adr_id, &details.refs)?;
 
+    let mut govctl = AdrMeta::new(adr_id.clone(), title, AdrStatus::Proposed, today());
+    govctl.refs = details.refs.clone();
+    govctl.created_by = details.created_by.clone();
     let spec = AdrSpec {
-        govctl: AdrMeta::new(adr_id.clone(), title, AdrStatus::Proposed, today()),
+        govctl,
         content: AdrContent {
             context: "Describe the context and problem statement.\nWhat is the issue that we're seeing that is motivating this decision?".to_string(),
             decision: "Describe the decision that was made.\nWhat is the change that we're proposing and/or doing?".to_string(),
             consequences: "Describe the resulting context after applying the decision.\nWhat becomes easier or more difficult to do because of this change?".to_string(),
+            drivers: vec![],
+            stakeholders: vec![],
             alternatives: vec![],
         },
     };
@@ -54,16 +54,17 @@ pub(super) fn create(
         title: title.to_string(),
         kind,
         status: ClauseStatus::Active,
-        text: "TODO: Add clause text here.".to_string(),
+        text: Some("TODO: Add clause text here.".to_string()),
         anchors: vec![],
         superseded_by: None,
+        deprecation: None,
         since,
         tags: vec![],
     };
 
     let clause_path = config.clause_source_path(rfc_id, clause_name, "toml");
 
-    let clause_wire: ClauseWire = clause.into();
+    let clause_wire = ClauseWire::try_from(clause)?;
 
     let clause_rel_path = format!("clauses/{clause_name}.toml");
     if let Some(sec) = rfc.sections.iter_mut().find(|s| s.title == section) {
@@ -78,7 +79,7 @@ pub(super) fn create(
     }
 
     let rfc_wire: RfcWire = rfc.into();
-    with_file_transaction(&[clause_path.as_path(), rfc_path.as_path()], op, || {
+    with_file_transaction(&[clause_path.as_path(), rfc_path.as_path()], op, |op| {
         write_new_artifact_toml(
             config,
             &clause_path,
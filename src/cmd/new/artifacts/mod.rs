@@ -5,14 +5,41 @@ mod clause;
 mod rfc;
 mod work;
 
+pub(crate) use rfc::DEFAULT_SECTIONS;
+
 use crate::NewTarget;
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::identity::{Actor, created_by};
+use crate::model::{ChecklistItem, ChecklistStatus};
 use crate::schema::{ArtifactSchema, with_schema_header};
 use crate::write::{WriteOp, write_file};
 use serde::Serialize;
 use std::path::Path;
 
+/// Initial content beyond the title, collected by the interactive wizard.
+///
+/// Empty fields keep the defaults a plain `new` would write.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NewDetails {
+    pub(crate) owners: Vec<String>,
+    pub(crate) sections: Vec<String>,
+    pub(crate) refs: Vec<String>,
+    pub(crate) acceptance_criteria: Vec<ChecklistItem>,
+    /// Work item description, in place of the placeholder.
+    pub(crate) description: Option<String>,
+    /// Actor recorded as `created_by`.
+    pub(crate) created_by: Option<String>,
+}
+
+/// Check refs against the project before the new artifact is written.
+fn validate_new_refs(config: &Config, owner_id: &str, refs: &[String]) -> DiagnosticResult<()> {
+    for ref_id in refs {
+        crate::validate::validate_artifact_ref_edit(config, owner_id, ref_id, owner_id)?;
+    }
+    Ok(())
+}
+
 pub(super) fn write_new_artifact_toml<T: Serialize>(
     config: &Config,
     path: &Path,
@@ -34,17 +61,62 @@ pub(super) fn write_new_artifact_toml<T: Serialize>(
     write_file(path, &content, op, Some(&display_path))
 }
 
-/// Create a new artifact.
-pub fn create(config: &Config, target: &NewTarget, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+/// Create a new artifact on behalf of `actor`.
+pub fn create(
+    config: &Config,
+    target: &NewTarget,
+    actor: Option<&Actor>,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let details = NewDetails {
+        created_by: created_by(actor),
+        ..NewDetails::default()
+    };
+    create_with_details(config, target, &details, op)
+}
+
+/// Create a new artifact seeded with `details`.
+pub(crate) fn create_with_details(
+    config: &Config,
+    target: &NewTarget,
+    details: &NewDetails,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
     match target {
-        NewTarget::Rfc { title, id } => rfc::create(config, title, id.as_deref(), op),
+        NewTarget::Rfc { title, id } => rfc::create(config, title, id.as_deref(), details, op),
         NewTarget::Clause {
             clause_id,
             title,
             section,
             kind,
         } => clause::create(config, clause_id, title, section, *kind, op),
-        NewTarget::Adr { title } => adr::create(config, title, op),
-        NewTarget::Work { title, active } => work::create(config, title, *active, op),
+        NewTarget::Adr { title } => adr::create(config, title, details, op),
+        NewTarget::Work { title, active } => work::create(config, title, *active, details, op),
     }
 }
+
+/// Queue a new work item with the description, refs, and pending acceptance
+/// criteria of `source_id`, titled `title` or after the source.
+pub fn clone_work(
+    config: &Config,
+    source_id: &str,
+    title: Option<&str>,
+    actor: Option<&Actor>,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let source = crate::artifact_catalog::load_work_item_by_id(config, source_id)?;
+    let content = source.spec.content;
+    let details = NewDetails {
+        refs: source.spec.govctl.refs,
+        acceptance_criteria: content
+            .acceptance_criteria
+            .into_iter()
+            .filter(|criterion| criterion.status == ChecklistStatus::Pending)
+            .collect(),
+        description: Some(content.description),
+        created_by: created_by(actor),
+        ..NewDetails::default()
+    };
+    let title = title.unwrap_or(&source.spec.govctl.title);
+    work::create(config, title, false, &details, op)
+}
@@ -1,4 +1,4 @@
-use super::write_new_artifact_toml;
+use super::{NewDetails, validate_new_refs, write_new_artifact_toml};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
 use crate::model::{ChangelogEntry, RfcPhase, RfcSpec, RfcStatus, RfcWire, SectionSpec};
@@ -6,20 +6,27 @@ use crate::schema::ArtifactSchema;
 use crate::ui;
 use crate::write::{WriteOp, create_dir_all, today};
 
+/// Sections every new RFC starts with unless others are given.
+pub(crate) const DEFAULT_SECTIONS: [&str; 2] = ["Summary", "Specification"];
+
 pub(super) fn create(
     config: &Config,
     title: &str,
     manual_id: Option<&str>,
+    details: &NewDetails,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     let rfcs_dir = config.rfc_dir();
 
     let rfc_id = match manual_id {
         Some(id) => {
-            if !id.starts_with("RFC-") {
+            if !config.prefixes.is_rfc(id) {
                 return Err(Diagnostic::new(
                     DiagnosticCode::E0110RfcInvalidId,
-                    format!("RFC ID must start with 'RFC-' (got: {id})"),
+                    format!(
+                        "RFC ID must start with '{}-' (got: {id})",
+                        config.prefixes.rfc
+                    ),
                     id,
                 ));
             }
@@ -38,16 +45,14 @@ pub(super) fn create(
                 .flatten()
                 .flatten()
                 .filter_map(|entry| {
-                    let name = entry.file_name();
-                    let name_str = name.to_string_lossy();
-                    name_str
-                        .strip_prefix("RFC-")
-                        .and_then(|s| s.parse::<u32>().ok())
+                    config
+                        .prefixes
+                        .rfc_number(&entry.file_name().to_string_lossy())
                 })
                 .max()
                 .unwrap_or(0);
 
-            format!("RFC-{:04}", max_num + 1)
+            config.prefixes.rfc_id(max_num + 1)
         }
     };
 
@@ -62,6 +67,8 @@ pub(super) fn create(
         ));
     }
 
+    validate_new_refs(config, &rfc_id, &details.refs)?;
+
     let display_clauses_dir = config.display_path(&clauses_dir);
     create_dir_all(&clauses_dir, op, Some(&display_clauses_dir))?;
 
@@ -71,22 +78,35 @@ pub(super) fn create(
         version: "0.1.0".to_string(),
         status: RfcStatus::Draft,
         phase: RfcPhase::Spec,
-        owners: vec![config.project.default_owner.clone()],
+        owners: if details.owners.is_empty() {
+            vec![config.project.default_owner.clone()]
+        } else {
+            details.owners.clone()
+        },
+        approvals: vec![],
+        frozen: false,
         created: today(),
+        created_by: details.created_by.clone(),
+        attachments: vec![],
         updated: None,
         supersedes: None,
-        refs: vec![],
+        deprecation: None,
+        refs: details.refs.clone(),
         tags: vec![],
-        sections: vec![
-            SectionSpec {
-                title: "Summary".to_string(),
-                clauses: vec![],
-            },
-            SectionSpec {
-                title: "Specification".to_string(),
-                clauses: vec![],
-            },
-        ],
+        sections: if details.sections.is_empty() {
+            DEFAULT_SECTIONS
+                .iter()
+                .map(|title| title.to_string())
+                .collect()
+        } else {
+            details.sections.clone()
+        }
+        .into_iter()
+        .map(|title| SectionSpec {
+            title,
+            clauses: vec![],
+        })
+        .collect(),
         changelog: vec![ChangelogEntry {
             version: "0.1.0".to_string(),
             date: today(),
@@ -1,5 +1,5 @@
-use super::write_new_artifact_toml;
-use crate::config::{Config, IdStrategy};
+use super::{NewDetails, validate_new_refs, write_new_artifact_toml};
+use crate::config::{Config, IdStrategy, WORK_PREFIX};
 use crate::diagnostic::{DiagnosticCode, DiagnosticResult, Diagnostics};
 use crate::model::{
     WorkItemContent, WorkItemMeta, WorkItemSpec, WorkItemStatus, WorkItemVerification,
@@ -14,6 +14,7 @@ pub(super) fn create(
     config: &Config,
     title: &str,
     active: bool,
+    details: &NewDetails,
     op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     let work_dir = config.work_dir();
@@ -23,25 +24,32 @@ pub(super) fn create(
     let date = today();
     let slug = slugify(title);
 
+    let namespace = match &config.work_item.id_prefix {
+        Some(prefix) => format!("{WORK_PREFIX}-{prefix}-"),
+        None => format!("{WORK_PREFIX}-"),
+    };
     let work_id = match config.work_item.id_strategy {
         IdStrategy::Sequential => {
-            let id_prefix = format!("WI-{date}-");
+            let id_prefix = format!("{namespace}{date}-");
             let max_seq = find_max_sequence(&work_dir, &id_prefix);
-            format!("WI-{date}-{:03}", max_seq + 1)
+            format!("{id_prefix}{:03}", max_seq + 1)
         }
         IdStrategy::AuthorHash => {
             let author_hash =
                 IdStrategy::get_author_hash().unwrap_or_else(IdStrategy::generate_random_suffix);
-            let id_prefix = format!("WI-{date}-{author_hash}-");
+            let id_prefix = format!("{namespace}{date}-{author_hash}-");
             let max_seq = find_max_sequence(&work_dir, &id_prefix);
-            format!("WI-{date}-{author_hash}-{:03}", max_seq + 1)
+            format!("{id_prefix}{:03}", max_seq + 1)
         }
         IdStrategy::Random => {
             let random_suffix = IdStrategy::generate_random_suffix();
-            format!("WI-{date}-{random_suffix}")
+            format!("{namespace}{date}-{random_suffix}")
         }
+        IdStrategy::Ulid => format!("{namespace}{}", IdStrategy::generate_ulid()),
     };
 
+    validate_new_refs(config, &work_id, &details.refs)?;
+
     let mut filename = format!("{date}-{slug}.toml");
     let mut work_path = work_dir.join(&filename);
     let mut suffix = 1u32;
@@ -60,14 +68,18 @@ pub(super) fn create(
 
     let mut meta = WorkItemMeta::new(work_id.clone(), title, status);
     meta.created = Some(date.clone());
+    meta.created_by = details.created_by.clone();
     meta.started = started;
+    meta.refs = details.refs.clone();
 
     let spec = WorkItemSpec {
         govctl: meta,
         content: WorkItemContent {
-            description:
+            description: details.description.clone().unwrap_or_else(|| {
                 "Describe the work to be done.\nWhat is the goal? What are the acceptance criteria?"
-                    .to_string(),
+                    .to_string()
+            }),
+            acceptance_criteria: details.acceptance_criteria.clone(),
             ..WorkItemContent::default()
         },
         verification: WorkItemVerification::default(),
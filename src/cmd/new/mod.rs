@@ -5,11 +5,16 @@ use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostic
 use crate::schema::ARTIFACT_SCHEMA_TEMPLATES;
 use crate::ui;
 use crate::write::{WriteOp, create_dir_all, write_file};
+use std::path::Path;
 
 mod artifacts;
+mod scaffold;
 mod skills;
-pub use artifacts::create;
-pub use skills::sync_skills;
+mod wizard;
+pub use artifacts::{clone_work, create};
+pub use scaffold::InitScaffold;
+pub use skills::{SkillSync, sync_skills};
+pub use wizard::{WizardTarget, run_wizard};
 
 fn schema_version_for_init() -> u32 {
     std::env::var("GOVCTL_SCHEMA_VERSION")
@@ -18,7 +23,13 @@ fn schema_version_for_init() -> u32 {
         .unwrap_or(crate::cmd::migrate::CURRENT_SCHEMA_VERSION)
 }
 /// Initialize govctl project
-pub fn init_project(config: &Config, force: bool, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+pub fn init_project(
+    config: &Config,
+    force: bool,
+    from: Option<&Path>,
+    mut scaffold: InitScaffold,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
     let config_path = config.gov_root.join("config.toml");
 
     if config_path.exists() && !force && !op.is_preview() {
@@ -32,6 +43,11 @@ pub fn init_project(config: &Config, force: bool, op: WriteOp) -> DiagnosticResu
         ));
     }
 
+    // Validate the whole template before touching the project.
+    let template = from.map(crate::cmd::template::read_template).transpose()?;
+    scaffold.prompt()?;
+    let config = &scaffold.apply_layout(config);
+
     let dirs: Vec<_> = vec![
         config.gov_root.clone(),
         config.rfc_dir(),
@@ -51,9 +67,14 @@ pub fn init_project(config: &Config, force: bool, op: WriteOp) -> DiagnosticResu
     }
 
     // Write config after gov_root exists
+    let default_config = scaffold.config_toml(schema_version_for_init())?;
+    let config_content = template
+        .as_ref()
+        .and_then(|template| template.config())
+        .unwrap_or(&default_config);
     write_file(
         &config_path,
-        &Config::default_toml(schema_version_for_init()),
+        config_content,
         op,
         Some(&config.display_path(&config_path)),
     )?;
@@ -72,15 +93,36 @@ pub fn init_project(config: &Config, force: bool, op: WriteOp) -> DiagnosticResu
         }
     }
 
+    if let Some(template) = &template {
+        template.install(config, op)?;
+    }
+    scaffold.write_guards(config, op)?;
+
     // Ensure .gitignore contains local govctl state entries.
     crate::cmd::project_support::ensure_local_state_gitignore_entries(config, op)?;
+    if scaffold.vscode() {
+        crate::cmd::project_support::ensure_editor_schema_settings(config, op, true)?;
+    }
+    let diagnostics = if scaffold.install_skills() {
+        sync_skills(
+            config,
+            &SkillSync::default(),
+            &crate::SkillFormat::Claude,
+            None,
+            op,
+        )?
+    } else {
+        vec![]
+    };
 
     if !op.is_preview() {
         ui::success("Project initialized");
-        ui::hint(
-            "To install agent skills locally: govctl init-skills\n  \
-             Or install the govctl plugin:    /plugin install govctl@govctl",
-        );
+        if !scaffold.install_skills() {
+            ui::hint(
+                "To install agent skills locally: govctl init-skills\n  \
+                 Or install the govctl plugin:    /plugin install govctl@govctl",
+            );
+        }
     }
-    Ok(vec![])
+    Ok(diagnostics)
 }
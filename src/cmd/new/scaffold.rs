@@ -0,0 +1,272 @@
+//! Project scaffolds for `init --preset` and `init --interactive`.
+//!
+//! A scaffold starts from a preset and may be tailored by prompts; it then
+//! decides the generated config, the default guards, and whether agent
+//! assets are installed alongside the project.
+
+use crate::InitPreset;
+use crate::cmd::config_edit::set_value;
+use crate::config::{Config, IdStrategy};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::{GuardCheck, GuardMeta, GuardSpec};
+use crate::parse::write_guard;
+use crate::ui;
+use crate::write::WriteOp;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Confirm, Input, Select};
+use slug::slugify;
+use std::io::IsTerminal;
+use toml::Value;
+
+/// Work item ID schemes offered by the prompt: strategy, config value, label.
+const ID_STRATEGIES: [(IdStrategy, &str, &str); 4] = [
+    (
+        IdStrategy::Sequential,
+        "sequential",
+        "sequential   WI-YYYY-MM-DD-NNN (solo projects)",
+    ),
+    (
+        IdStrategy::AuthorHash,
+        "author-hash",
+        "author-hash  WI-YYYY-MM-DD-{hash}-NNN (teams)",
+    ),
+    (
+        IdStrategy::Random,
+        "random",
+        "random       WI-YYYY-MM-DD-{rand}",
+    ),
+    (
+        IdStrategy::Ulid,
+        "ulid",
+        "ulid         WI-{ulid} (parallel branches and agents)",
+    ),
+];
+
+/// What `init` writes besides the directory structure and schemas.
+#[derive(Debug, Clone, Default)]
+pub struct InitScaffold {
+    interactive: bool,
+    /// Omit the commented guidance from the generated config.
+    bare_config: bool,
+    docs_output: Option<String>,
+    agent_dir: Option<String>,
+    id_strategy: Option<IdStrategy>,
+    /// Commands run as default verification guards.
+    guards: Vec<String>,
+    install_skills: bool,
+    vscode: bool,
+}
+
+impl InitScaffold {
+    pub fn new(preset: Option<InitPreset>, interactive: bool, vscode: bool) -> Self {
+        let mut scaffold = Self {
+            interactive,
+            vscode,
+            ..Self::default()
+        };
+        match preset {
+            None => {}
+            Some(InitPreset::Minimal) => scaffold.bare_config = true,
+            Some(InitPreset::Full) => {
+                scaffold.guards = vec!["govctl check".to_string()];
+                scaffold.vscode = true;
+            }
+            Some(InitPreset::Agents) => {
+                scaffold.guards = vec!["govctl check".to_string()];
+                scaffold.vscode = true;
+                scaffold.id_strategy = Some(IdStrategy::Ulid);
+                scaffold.install_skills = true;
+            }
+        }
+        scaffold
+    }
+
+    pub fn vscode(&self) -> bool {
+        self.vscode
+    }
+
+    pub fn install_skills(&self) -> bool {
+        self.install_skills
+    }
+
+    /// Ask for the layout, ID scheme, guards, and agent assets when
+    /// `--interactive` was given, starting from the preset's answers.
+    pub fn prompt(&mut self) -> DiagnosticResult<()> {
+        if !self.interactive {
+            return Ok(());
+        }
+        if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0824InteractiveUnavailable,
+                "init --interactive needs a terminal; use --preset minimal|full|agents instead",
+                "init",
+            ));
+        }
+        let theme = ColorfulTheme::default();
+
+        let docs_output: String = Input::with_theme(&theme)
+            .with_prompt("Rendered docs directory")
+            .default(
+                self.docs_output
+                    .clone()
+                    .unwrap_or_else(|| "docs".to_string()),
+            )
+            .validate_with(|input: &String| -> Result<(), String> {
+                if input.trim().is_empty() {
+                    Err("Directory must not be empty".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .interact_text()
+            .map_err(prompt_error)?;
+        self.docs_output = Some(docs_output.trim().to_string());
+
+        let current = self.id_strategy.unwrap_or_default();
+        let labels: Vec<&str> = ID_STRATEGIES.iter().map(|(_, _, label)| *label).collect();
+        let selected = Select::with_theme(&theme)
+            .with_prompt("Work item ID scheme")
+            .items(&labels)
+            .default(
+                ID_STRATEGIES
+                    .iter()
+                    .position(|(strategy, _, _)| *strategy == current)
+                    .unwrap_or(0),
+            )
+            .interact()
+            .map_err(prompt_error)?;
+        self.id_strategy = Some(ID_STRATEGIES[selected].0);
+
+        for command in &self.guards {
+            ui::info(format!("Default guard: {command}"));
+        }
+        loop {
+            let command: String = Input::with_theme(&theme)
+                .with_prompt("Default guard command (empty to finish)")
+                .allow_empty(true)
+                .validate_with(|input: &String| -> Result<(), String> {
+                    if input.trim().is_empty() || guard_slug(input).is_some() {
+                        Ok(())
+                    } else {
+                        Err("Command must start with a letter".to_string())
+                    }
+                })
+                .interact_text()
+                .map_err(prompt_error)?;
+            let command = command.trim();
+            if command.is_empty() {
+                break;
+            }
+            if !self.guards.iter().any(|existing| existing == command) {
+                self.guards.push(command.to_string());
+            }
+        }
+
+        self.install_skills = Confirm::with_theme(&theme)
+            .with_prompt("Install agent skills and agents?")
+            .default(self.install_skills)
+            .interact()
+            .map_err(prompt_error)?;
+        if self.install_skills {
+            let agent_dir: String =
+                Input::with_theme(&theme)
+                    .with_prompt("Agent directory")
+                    .default(self.agent_dir.clone().unwrap_or_else(|| {
+                        crate::config::default_agent_dir().display().to_string()
+                    }))
+                    .interact_text()
+                    .map_err(prompt_error)?;
+            self.agent_dir = Some(agent_dir.trim().to_string());
+        }
+        Ok(())
+    }
+
+    /// `config` with the scaffold's directory layout, resolved like
+    /// `Config::load` resolves it.
+    pub fn apply_layout(&self, config: &Config) -> Config {
+        let mut config = config.clone();
+        let project_root = config.project_root().to_path_buf();
+        if let Some(docs_output) = &self.docs_output {
+            config.paths.docs_output = project_root.join(docs_output);
+        }
+        if let Some(agent_dir) = &self.agent_dir {
+            config.paths.agent_dir = project_root.join(agent_dir);
+        }
+        config
+    }
+
+    /// The generated `gov/config.toml`.
+    pub fn config_toml(&self, schema_version: u32) -> DiagnosticResult<String> {
+        let mut content = if self.bare_config {
+            Config::minimal_toml(schema_version)
+        } else {
+            Config::default_toml(schema_version)
+        };
+        let mut settings: Vec<(&str, &str, Value)> = Vec::new();
+        if let Some(docs_output) = &self.docs_output {
+            settings.push(("paths", "docs_output", Value::from(docs_output.as_str())));
+        }
+        if let Some(agent_dir) = &self.agent_dir {
+            settings.push(("paths", "agent_dir", Value::from(agent_dir.as_str())));
+        }
+        if let Some((_, name, _)) = ID_STRATEGIES
+            .iter()
+            .find(|(strategy, _, _)| Some(*strategy) == self.id_strategy)
+            && self.id_strategy != Some(IdStrategy::default())
+        {
+            settings.push(("work_item", "id_strategy", Value::from(*name)));
+        }
+        if !self.guards.is_empty() {
+            let ids = self.guard_specs().map(|spec| Value::from(spec.govctl.id));
+            settings.push(("verification", "enabled", Value::from(true)));
+            settings.push((
+                "verification",
+                "default_guards",
+                Value::Array(ids.collect()),
+            ));
+        }
+        for (section, key, value) in settings {
+            content = set_value(&content, "gov/config.toml", section, key, &value)?;
+        }
+        Ok(content)
+    }
+
+    /// Write one guard per default guard command.
+    pub fn write_guards(&self, config: &Config, op: WriteOp) -> DiagnosticResult<()> {
+        for spec in self.guard_specs() {
+            let Some(slug) = guard_slug(&spec.check.command) else {
+                continue;
+            };
+            let path = config
+                .guard_dir()
+                .join(format!("{}.toml", slug.to_lowercase()));
+            let display_path = config.display_path(&path);
+            write_guard(&path, &spec, op, Some(&display_path))?;
+            if !op.is_preview() {
+                ui::created_path(&display_path);
+            }
+        }
+        Ok(())
+    }
+
+    fn guard_specs(&self) -> impl Iterator<Item = GuardSpec> + '_ {
+        self.guards.iter().filter_map(|command| {
+            let slug = guard_slug(command)?;
+            Some(GuardSpec {
+                govctl: GuardMeta::new(format!("GUARD-{slug}"), format!("{command} passes")),
+                check: GuardCheck::new(command.clone(), 300),
+            })
+        })
+    }
+}
+
+/// Guard ID suffix for a command: `cargo test` becomes `CARGO-TEST`.
+fn guard_slug(command: &str) -> Option<String> {
+    let slug = slugify(command).to_uppercase();
+    slug.starts_with(|ch: char| ch.is_ascii_uppercase())
+        .then_some(slug)
+}
+
+fn prompt_error(err: dialoguer::Error) -> Diagnostic {
+    Diagnostic::io_error("read answer", err, "init")
+}
@@ -1,9 +1,10 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::config::Config;
-use crate::diagnostic::{DiagnosticResult, Diagnostics};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
 use crate::ui;
-use crate::write::{WriteOp, create_dir_all, write_file};
+use crate::write::{WriteOp, create_dir_all, line_diff, write_file};
+use owo_colors::OwoColorize;
 
 // Skill bundle assets are generated recursively from .claude/skills/** by build.rs.
 // Implements [[RFC-0002:C-GLOBAL-COMMANDS]] and [[ADR-0028]].
@@ -33,10 +34,32 @@ const AGENT_TEMPLATES: &[(&str, &str)] = &[
 // Codex agent templates generated by build.rs from .claude/agents/*.md
 include!(concat!(env!("OUT_DIR"), "/agent_codex_templates.rs"));
 
+/// Unchanged lines shown around each change in `init-skills --diff`.
+const DIFF_CONTEXT: usize = 2;
+
+/// How `init-skills` treats the assets it would install.
+#[derive(Debug, Clone, Default)]
+pub struct SkillSync {
+    /// Overwrite assets that already exist
+    pub force: bool,
+    /// Asset groups to install; empty installs every group
+    pub only: Vec<crate::AssetGroup>,
+    /// Print a line diff of each asset that would change, writing nothing
+    pub diff: bool,
+    /// Fail with E0826 when assets are missing or differ, writing nothing
+    pub check: bool,
+}
+
+impl SkillSync {
+    fn includes(&self, rel_path: &str) -> bool {
+        self.only.is_empty() || self.only.contains(&crate::AssetGroup::of(rel_path))
+    }
+}
+
 /// Install agent skills and agents into the project's agent directory. [[ADR-0035]]
 pub fn sync_skills(
     config: &Config,
-    force: bool,
+    sync: &SkillSync,
     format: &crate::SkillFormat,
     dir_override: Option<&Path>,
     op: WriteOp,
@@ -77,10 +100,19 @@ pub fn sync_skills(
         crate::SkillFormat::Codex => AGENT_TEMPLATES_CODEX,
     };
 
+    let assets = SKILL_ASSETS
+        .iter()
+        .chain(agent_templates.iter())
+        .filter(|(rel_path, _)| sync.includes(rel_path));
+    if sync.diff || sync.check {
+        return compare_assets(config, agent_dir, assets, sync);
+    }
+
+    let force = sync.force;
     let mut synced = 0;
     let mut skipped = 0;
 
-    for (rel_path, template) in SKILL_ASSETS.iter().chain(agent_templates.iter()) {
+    for (rel_path, template) in assets {
         let path = agent_dir.join(rel_path);
         let display_path = config.display_path(&path);
 
@@ -132,3 +164,102 @@ pub fn sync_skills(
 
     Ok(vec![])
 }
+
+/// Report installed assets that are missing or differ from the bundled ones,
+/// printing their diffs with `--diff` and failing with `--check`.
+fn compare_assets<'a>(
+    config: &Config,
+    agent_dir: &Path,
+    assets: impl Iterator<Item = &'a (&'a str, &'a str)>,
+    sync: &SkillSync,
+) -> DiagnosticResult<Diagnostics> {
+    let mut drifted: Vec<PathBuf> = Vec::new();
+    for (rel_path, template) in assets {
+        let path = agent_dir.join(rel_path);
+        let display_path = config.display_path(&path);
+        let installed = match std::fs::read_to_string(&path) {
+            Ok(content) => Some(content),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => {
+                return Err(Diagnostic::io_error(
+                    "read agent asset",
+                    err,
+                    display_path.display().to_string(),
+                ));
+            }
+        };
+        if installed.as_deref() == Some(*template) {
+            continue;
+        }
+        if sync.diff {
+            print_asset_diff(&display_path, installed.as_deref(), template);
+        }
+        drifted.push(display_path);
+    }
+
+    if drifted.is_empty() {
+        ui::success("Agent assets are up to date");
+        return Ok(vec![]);
+    }
+    if sync.check {
+        let paths: Vec<String> = drifted
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0826AgentAssetsOutOfDate,
+            format!(
+                "{} agent asset(s) missing or out of date: {} (run govctl init-skills --force)",
+                drifted.len(),
+                paths.join(", ")
+            ),
+            config.display_path(agent_dir).display().to_string(),
+        ));
+    }
+    ui::info(format!(
+        "{} asset(s) would change (use -f to overwrite)",
+        drifted.len()
+    ));
+    Ok(vec![])
+}
+
+/// Print the changes from `installed` to `bundled`, with `DIFF_CONTEXT`
+/// unchanged lines around each change.
+fn print_asset_diff(display_path: &Path, installed: Option<&str>, bundled: &str) {
+    let colors = ui::stdout_supports_color();
+    let header = match installed {
+        Some(_) => format!("--- {}", display_path.display()),
+        None => format!("--- {} (new)", display_path.display()),
+    };
+    if colors {
+        println!("{}", header.bold());
+    } else {
+        println!("{header}");
+    }
+
+    let diff = line_diff(installed.unwrap_or_default(), bundled);
+    let near_change = |index: usize| {
+        let end = (index + DIFF_CONTEXT + 1).min(diff.len());
+        diff[index.saturating_sub(DIFF_CONTEXT)..end]
+            .iter()
+            .any(|(tag, _)| *tag != ' ')
+    };
+    let mut elided = false;
+    for (index, (tag, line)) in diff.iter().enumerate() {
+        if !near_change(index) {
+            if !elided {
+                println!("  ...");
+                elided = true;
+            }
+            continue;
+        }
+        elided = false;
+        let rendered = format!("{tag} {line}");
+        match tag {
+            '+' if colors => println!("{}", rendered.green()),
+            '-' if colors => println!("{}", rendered.red()),
+            _ => println!("{rendered}"),
+        }
+    }
+    println!();
+}
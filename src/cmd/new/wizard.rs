@@ -0,0 +1,254 @@
+//! Interactive creation wizards for `rfc new`, `adr new`, and `work new`.
+//!
+//! Humans get a guided flow that validates each answer as it is typed;
+//! agents and scripts keep using flags. Answers end up in the same creation
+//! path as the flag-driven commands, so the written artifact is identical.
+
+use super::artifacts::{DEFAULT_SECTIONS, NewDetails, create_with_details};
+use crate::NewTarget;
+use crate::artifact_index::artifact_ref_ids;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::identity::{Actor, created_by};
+use crate::model::ChecklistItem;
+use crate::ui;
+use crate::write::{WriteOp, parse_changelog_change};
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Confirm, Input};
+use std::collections::HashSet;
+use std::io::IsTerminal;
+
+/// Artifact kind the wizard creates, with the flags given alongside `--interactive`.
+#[derive(Debug, Clone)]
+pub enum WizardTarget {
+    Rfc { id: Option<String> },
+    Adr,
+    Work { active: bool },
+}
+
+impl WizardTarget {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Rfc { .. } => "RFC",
+            Self::Adr => "ADR",
+            Self::Work { .. } => "work item",
+        }
+    }
+}
+
+/// Prompt for a new artifact and create it.
+pub fn run_wizard(
+    config: &Config,
+    target: &WizardTarget,
+    title: Option<&str>,
+    actor: Option<&Actor>,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0824InteractiveUnavailable,
+            "--interactive needs a terminal; pass the title as an argument and use add/set for other fields",
+            target.label(),
+        ));
+    }
+
+    let known_ids = known_ref_ids(config)?;
+    let theme = ColorfulTheme::default();
+    let title = prompt_title(&theme, title)?;
+    let mut details = NewDetails {
+        created_by: created_by(actor),
+        ..NewDetails::default()
+    };
+
+    if let WizardTarget::Rfc { .. } = target {
+        details.owners = prompt_list(
+            &theme,
+            "Owners (comma-separated)",
+            Some(config.project.default_owner.as_str()),
+            validate_owners,
+        )?;
+        details.sections = prompt_list(
+            &theme,
+            "Sections (comma-separated)",
+            Some(DEFAULT_SECTIONS.join(", ").as_str()),
+            validate_sections,
+        )?;
+    }
+    details.refs = prompt_list(
+        &theme,
+        "References (comma-separated, optional)",
+        None,
+        |refs| validate_refs(&known_ids, refs),
+    )?;
+    if let WizardTarget::Work { .. } = target {
+        details.acceptance_criteria = prompt_acceptance_criteria(&theme)?;
+    }
+
+    let label = target.label();
+    let confirmed = Confirm::with_theme(&theme)
+        .with_prompt(format!("Create {label} '{title}'?"))
+        .default(true)
+        .interact()
+        .map_err(prompt_error)?;
+    if !confirmed {
+        ui::info(format!("Cancelled; no {label} created"));
+        return Ok(vec![]);
+    }
+
+    let new_target = match target {
+        WizardTarget::Rfc { id } => NewTarget::Rfc {
+            title,
+            id: id.clone(),
+        },
+        WizardTarget::Adr => NewTarget::Adr { title },
+        WizardTarget::Work { active } => NewTarget::Work {
+            title,
+            active: *active,
+        },
+    };
+    create_with_details(config, &new_target, &details, op)
+}
+
+fn known_ref_ids(config: &Config) -> DiagnosticResult<HashSet<String>> {
+    let index = crate::load::load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for refs validation",
+                "wizard",
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    Ok(artifact_ref_ids(&index))
+}
+
+fn prompt_title(theme: &ColorfulTheme, initial: Option<&str>) -> DiagnosticResult<String> {
+    let title: String = Input::with_theme(theme)
+        .with_prompt("Title")
+        .with_initial_text(initial.unwrap_or_default())
+        .validate_with(|input: &String| -> Result<(), String> {
+            if input.trim().is_empty() {
+                Err("Title must not be empty".to_string())
+            } else {
+                Ok(())
+            }
+        })
+        .interact_text()
+        .map_err(prompt_error)?;
+    Ok(title.trim().to_string())
+}
+
+/// Prompt for a comma-separated list, re-asking until `validate` accepts it.
+fn prompt_list(
+    theme: &ColorfulTheme,
+    prompt: &str,
+    default: Option<&str>,
+    validate: impl Fn(&[String]) -> Result<(), String>,
+) -> DiagnosticResult<Vec<String>> {
+    let mut input = Input::<String>::with_theme(theme)
+        .with_prompt(prompt)
+        .allow_empty(true)
+        .validate_with(|input: &String| validate(&split_list(input)));
+    if let Some(default) = default {
+        input = input.default(default.to_string());
+    }
+    let answer = input.interact_text().map_err(prompt_error)?;
+    Ok(split_list(&answer))
+}
+
+fn prompt_acceptance_criteria(theme: &ColorfulTheme) -> DiagnosticResult<Vec<ChecklistItem>> {
+    let mut criteria: Vec<ChecklistItem> = Vec::new();
+    loop {
+        let answer: String = Input::with_theme(theme)
+            .with_prompt("Acceptance criterion (e.g. 'add: ...'; empty to finish)")
+            .allow_empty(true)
+            .validate_with(|input: &String| -> Result<(), String> {
+                if input.trim().is_empty() {
+                    Ok(())
+                } else {
+                    parse_criterion(input).map(|_| ())
+                }
+            })
+            .interact_text()
+            .map_err(prompt_error)?;
+        if answer.trim().is_empty() {
+            return Ok(criteria);
+        }
+        let item = parse_criterion(&answer).map_err(|message| {
+            Diagnostic::new(
+                DiagnosticCode::E0408WorkCriteriaMissingCategory,
+                message,
+                "wizard",
+            )
+        })?;
+        if !criteria.iter().any(|existing| existing.text == item.text) {
+            criteria.push(item);
+        }
+    }
+}
+
+fn parse_criterion(input: &str) -> Result<ChecklistItem, String> {
+    let parsed = parse_changelog_change(input.trim()).map_err(|diag| diag.message)?;
+    if !parsed.explicit {
+        return Err(format!(
+            "Acceptance criteria requires category. Use prefix (e.g., 'fix: {}')",
+            parsed.message
+        ));
+    }
+    Ok(ChecklistItem::with_category(
+        &parsed.message,
+        parsed.category,
+    ))
+}
+
+fn split_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn validate_owners(owners: &[String]) -> Result<(), String> {
+    if owners.is_empty() {
+        return Err("At least one owner is required".to_string());
+    }
+    match owners.iter().find(|owner| !is_owner_handle(owner)) {
+        Some(owner) => Err(format!("Owner must be an @handle (got: {owner})")),
+        None => Ok(()),
+    }
+}
+
+fn is_owner_handle(owner: &str) -> bool {
+    owner
+        .strip_prefix('@')
+        .is_some_and(|handle| !handle.is_empty() && !handle.contains(char::is_whitespace))
+}
+
+fn validate_sections(sections: &[String]) -> Result<(), String> {
+    if sections.is_empty() {
+        return Err("At least one section is required".to_string());
+    }
+    let mut seen = HashSet::new();
+    match sections.iter().find(|title| !seen.insert(title.as_str())) {
+        Some(title) => Err(format!("Duplicate section: {title}")),
+        None => Ok(()),
+    }
+}
+
+fn validate_refs(known_ids: &HashSet<String>, refs: &[String]) -> Result<(), String> {
+    match refs
+        .iter()
+        .find(|ref_id| !known_ids.contains(ref_id.as_str()))
+    {
+        Some(ref_id) => Err(format!("Unknown artifact: {ref_id}")),
+        None => Ok(()),
+    }
+}
+
+fn prompt_error(err: dialoguer::Error) -> Diagnostic {
+    Diagnostic::io_error("read answer", err, "wizard")
+}
@@ -1,4 +1,5 @@
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::ui;
 use comfy_table::{Attribute, Cell, ContentArrangement, Table, presets::UTF8_FULL};
 use serde::Serialize;
 use std::fmt::Display;
@@ -7,6 +8,16 @@ pub(crate) fn command_table() -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_truncation_indicator("…");
+    // Styling and width follow `--color` and `COLUMNS`, not comfy-table's own
+    // TTY probe.
+    table.force_no_tty();
+    if ui::stdout_supports_color() {
+        table.enforce_styling();
+    }
+    if let Some(width) = ui::terminal_width() {
+        table.set_width(width);
+    }
     table
 }
 
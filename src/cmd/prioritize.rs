@@ -0,0 +1,334 @@
+//! Work queue prioritization suggestions.
+//!
+//! Scores queued work items from governance signals and proposes an ordering.
+//! The ordering is advisory until `--apply` writes it to each item's
+//! `priority` field.
+
+use crate::OutputFormat;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::{Config, PrefixesConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticResult, Diagnostics};
+use crate::load::load_rfcs;
+use crate::model::{RfcPhase, RfcStatus, WorkItemEntry, WorkItemStatus};
+use crate::parse::{load_work_items, write_work_item};
+use crate::ui;
+use crate::write::{WriteOp, with_file_transaction};
+use chrono::{Local, NaiveDate};
+use comfy_table::Cell;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+/// Per distinct normative RFC in `impl` referenced by the item.
+const IMPL_RFC_WEIGHT: i64 = 3;
+/// Per queued item transitively waiting on this one.
+const BLOCKS_QUEUED_WEIGHT: i64 = 2;
+/// Per active item transitively waiting on this one.
+const BLOCKS_ACTIVE_WEIGHT: i64 = 4;
+/// Per referenced RFC for which this is the last open work item.
+const MILESTONE_WEIGHT: i64 = 3;
+/// Per full 30 days spent in the queue.
+const AGE_WEIGHT: i64 = 1;
+const AGE_CAP_MONTHS: i64 = 3;
+/// Applied once when the item has unfinished dependencies of its own.
+const WAITING_PENALTY: i64 = 5;
+
+#[derive(Debug, Serialize)]
+struct Suggestion {
+    rank: u32,
+    id: String,
+    title: String,
+    score: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_priority: Option<u32>,
+    reasons: Vec<String>,
+    #[serde(skip)]
+    created: String,
+}
+
+/// Suggest (and optionally apply) an ordering for queued work items.
+pub fn prioritize(
+    config: &Config,
+    apply: bool,
+    output: OutputFormat,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let items = load_work_items(config)?;
+    let rfcs = load_rfcs(config).map_err(Diagnostic::from)?;
+    let impl_rfcs: HashSet<&str> = rfcs
+        .iter()
+        .filter(|index| {
+            index.rfc.status == RfcStatus::Normative && index.rfc.phase == RfcPhase::Impl
+        })
+        .map(|index| index.rfc.rfc_id.as_str())
+        .collect();
+
+    let signals = QueueSignals::new(&config.prefixes, &items);
+    let today = Local::now().date_naive();
+
+    let mut suggestions: Vec<Suggestion> = items
+        .iter()
+        .filter(|item| item.meta().status == WorkItemStatus::Queue)
+        .map(|item| score_item(item, &signals, &impl_rfcs, today))
+        .collect();
+    suggestions.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.created.cmp(&b.created))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    for (rank, suggestion) in (1..).zip(suggestions.iter_mut()) {
+        suggestion.rank = rank;
+    }
+
+    print_suggestions(&suggestions, output);
+
+    if apply {
+        apply_priorities(config, &items, &suggestions, op)?;
+    }
+    Ok(vec![])
+}
+
+/// Dependency and reference lookups shared by every scored item.
+struct QueueSignals<'a> {
+    prefixes: &'a PrefixesConfig,
+    status_by_id: HashMap<&'a str, WorkItemStatus>,
+    /// Open items that directly depend on the key.
+    dependents: HashMap<&'a str, Vec<&'a str>>,
+    /// Open (queue or active) item count per referenced RFC ID.
+    open_by_rfc: HashMap<&'a str, usize>,
+    /// Done item count per referenced RFC ID.
+    done_by_rfc: HashMap<&'a str, usize>,
+}
+
+impl<'a> QueueSignals<'a> {
+    fn new(prefixes: &'a PrefixesConfig, items: &'a [WorkItemEntry]) -> Self {
+        let mut status_by_id = HashMap::new();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut open_by_rfc: HashMap<&str, usize> = HashMap::new();
+        let mut done_by_rfc: HashMap<&str, usize> = HashMap::new();
+
+        for item in items {
+            let meta = item.meta();
+            status_by_id.insert(meta.id.as_str(), meta.status);
+            if is_open(meta.status) {
+                for dependency in &meta.depends_on {
+                    dependents
+                        .entry(dependency.as_str())
+                        .or_default()
+                        .push(meta.id.as_str());
+                }
+            }
+            let counts = match meta.status {
+                WorkItemStatus::Queue | WorkItemStatus::Active => &mut open_by_rfc,
+                WorkItemStatus::Done => &mut done_by_rfc,
+                WorkItemStatus::Cancelled => continue,
+            };
+            for rfc_id in referenced_rfcs(prefixes, &meta.refs) {
+                *counts.entry(rfc_id).or_default() += 1;
+            }
+        }
+
+        Self {
+            prefixes,
+            status_by_id,
+            dependents,
+            open_by_rfc,
+            done_by_rfc,
+        }
+    }
+
+    /// Open items transitively waiting on `id`, in breadth-first order.
+    fn blocked_by(&self, id: &str) -> Vec<&'a str> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([id]);
+        let mut blocked = Vec::new();
+        while let Some(current) = queue.pop_front() {
+            for &dependent in self.dependents.get(current).into_iter().flatten() {
+                if dependent != id && seen.insert(dependent) {
+                    blocked.push(dependent);
+                    queue.push_back(dependent);
+                }
+            }
+        }
+        blocked
+    }
+}
+
+fn is_open(status: WorkItemStatus) -> bool {
+    matches!(status, WorkItemStatus::Queue | WorkItemStatus::Active)
+}
+
+/// Distinct RFC IDs named by refs, including clause refs (`RFC-0001:C-X`).
+fn referenced_rfcs<'a>(prefixes: &PrefixesConfig, refs: &'a [String]) -> BTreeSet<&'a str> {
+    refs.iter()
+        .filter(|reference| prefixes.is_rfc(reference))
+        .map(|reference| {
+            reference
+                .split_once(':')
+                .map_or(reference.as_str(), |(rfc_id, _)| rfc_id)
+        })
+        .collect()
+}
+
+fn score_item(
+    item: &WorkItemEntry,
+    signals: &QueueSignals<'_>,
+    impl_rfcs: &HashSet<&str>,
+    today: NaiveDate,
+) -> Suggestion {
+    let meta = item.meta();
+    let mut score = 0;
+    let mut reasons = Vec::new();
+
+    let rfc_ids = referenced_rfcs(signals.prefixes, &meta.refs);
+    for rfc_id in &rfc_ids {
+        if impl_rfcs.contains(rfc_id) {
+            score += IMPL_RFC_WEIGHT;
+            reasons.push(format!("implements {rfc_id} (normative, impl)"));
+        }
+    }
+
+    let blocked = signals.blocked_by(&meta.id);
+    let (active, queued): (Vec<&str>, Vec<&str>) = blocked
+        .iter()
+        .partition(|id| signals.status_by_id.get(*id).copied() == Some(WorkItemStatus::Active));
+    if !active.is_empty() {
+        score += BLOCKS_ACTIVE_WEIGHT * active.len() as i64;
+        reasons.push(format!("unblocks active {}", active.join(", ")));
+    }
+    if !queued.is_empty() {
+        score += BLOCKS_QUEUED_WEIGHT * queued.len() as i64;
+        reasons.push(format!("blocks queued {}", queued.join(", ")));
+    }
+
+    for rfc_id in &rfc_ids {
+        let open = signals.open_by_rfc.get(rfc_id).copied().unwrap_or(0);
+        let done = signals.done_by_rfc.get(rfc_id).copied().unwrap_or(0);
+        if open == 1 && done > 0 {
+            score += MILESTONE_WEIGHT;
+            reasons.push(format!("last open work item for {rfc_id} ({done} done)"));
+        }
+    }
+
+    let created = meta.created.clone().unwrap_or_default();
+    if let Ok(date) = NaiveDate::parse_from_str(&created, "%Y-%m-%d") {
+        let days = (today - date).num_days();
+        let months = (days / 30).min(AGE_CAP_MONTHS);
+        if months > 0 {
+            score += AGE_WEIGHT * months;
+            reasons.push(format!("queued {days} days"));
+        }
+    }
+
+    let waiting: Vec<&str> = meta
+        .depends_on
+        .iter()
+        .map(String::as_str)
+        .filter(|dependency| {
+            !matches!(
+                signals.status_by_id.get(dependency),
+                Some(WorkItemStatus::Done | WorkItemStatus::Cancelled)
+            )
+        })
+        .collect();
+    if !waiting.is_empty() {
+        score -= WAITING_PENALTY;
+        reasons.push(format!("waiting on {}", waiting.join(", ")));
+    }
+
+    Suggestion {
+        rank: 0,
+        id: meta.id.clone(),
+        title: meta.title.clone(),
+        score,
+        current_priority: meta.priority,
+        reasons,
+        created,
+    }
+}
+
+fn print_suggestions(suggestions: &[Suggestion], output: OutputFormat) {
+    match output {
+        OutputFormat::Json => print_json_array(suggestions),
+        OutputFormat::Plain => {
+            for suggestion in suggestions {
+                println!(
+                    "{}\t{}\t{}\t{}",
+                    suggestion.rank,
+                    suggestion.id,
+                    suggestion.score,
+                    suggestion.reasons.join("; ")
+                );
+            }
+        }
+        OutputFormat::Table => {
+            if suggestions.is_empty() {
+                ui::info("No queued work items to prioritize");
+                return;
+            }
+            let mut table =
+                table_with_bold_headers(&["Rank", "ID", "Title", "Score", "Current", "Reasons"]);
+            for suggestion in suggestions {
+                table.add_row(vec![
+                    Cell::new(suggestion.rank),
+                    Cell::new(&suggestion.id),
+                    Cell::new(&suggestion.title),
+                    Cell::new(suggestion.score),
+                    Cell::new(
+                        suggestion
+                            .current_priority
+                            .map_or_else(|| "-".to_string(), |priority| priority.to_string()),
+                    ),
+                    Cell::new(suggestion.reasons.join("\n")),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+}
+
+fn apply_priorities(
+    config: &Config,
+    items: &[WorkItemEntry],
+    suggestions: &[Suggestion],
+    op: WriteOp,
+) -> DiagnosticResult<()> {
+    let rank_by_id: HashMap<&str, u32> = suggestions
+        .iter()
+        .map(|suggestion| (suggestion.id.as_str(), suggestion.rank))
+        .collect();
+    let changed: Vec<(&WorkItemEntry, u32)> = items
+        .iter()
+        .filter_map(|item| {
+            let rank = *rank_by_id.get(item.meta().id.as_str())?;
+            (item.meta().priority != Some(rank)).then_some((item, rank))
+        })
+        .collect();
+
+    let paths: Vec<&Path> = changed
+        .iter()
+        .map(|(item, _)| item.path.as_path())
+        .collect();
+    with_file_transaction(&paths, op, |op| {
+        for (item, rank) in &changed {
+            let mut spec = item.spec.clone();
+            spec.govctl.priority = Some(*rank);
+            write_work_item(
+                &item.path,
+                &spec,
+                op,
+                Some(&config.display_path(&item.path)),
+            )?;
+        }
+        Ok(())
+    })?;
+
+    if !op.is_preview() {
+        ui::success(format!(
+            "Applied priority to {} queued work item(s)",
+            changed.len()
+        ));
+    }
+    Ok(())
+}
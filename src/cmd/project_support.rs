@@ -4,11 +4,13 @@ use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
 use crate::ui;
 use crate::write::{WriteOp, write_file};
+use serde_json::{Map, Value};
 use std::io::ErrorKind;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // Implements [[RFC-0002:C-GLOBAL-COMMANDS]]: init/migrate maintain local-state ignore entries.
-const LOCAL_STATE_GITIGNORE_ENTRIES: &[&str] = &[".govctl.lock", ".govctl/"];
+const LOCAL_STATE_GITIGNORE_ENTRIES: &[&str] =
+    &[".govctl.lock", ".govctl/", "gov/config.local.toml"];
 
 // Implements [[RFC-0002:C-GLOBAL-COMMANDS]]: migrate refreshes local-state
 // .gitignore entries regardless of schema version.
@@ -91,6 +93,141 @@ pub(crate) fn local_state_gitignore_diagnostics(config: &Config) -> Diagnostics
     )]
 }
 
+/// VS Code setting read by the Even Better TOML (taplo) extension: file
+/// regex -> schema path.
+const EDITOR_SCHEMA_SETTING: &str = "evenBetterToml.schema.associations";
+
+/// Map governed TOML files to their JSON Schemas in `.vscode/settings.json`,
+/// so files without a `#:schema` header are validated too.
+///
+/// `create` writes the file (or the setting) when absent, as `init --vscode`
+/// does; otherwise only an existing mapping is refreshed, as `migrate` does.
+/// Returns the number of associations added.
+pub(crate) fn ensure_editor_schema_settings(
+    config: &Config,
+    op: WriteOp,
+    create: bool,
+) -> DiagnosticResult<usize> {
+    let settings_path = config.project_root().join(".vscode").join("settings.json");
+    let display_path = config.display_path(&settings_path);
+
+    let mut settings = match std::fs::read_to_string(&settings_path) {
+        Ok(content) => match serde_json::from_str::<Value>(&content) {
+            Ok(Value::Object(settings)) => settings,
+            _ => {
+                if create {
+                    ui::info(format!(
+                        "Skipped {}: not plain JSON; add `{EDITOR_SCHEMA_SETTING}` by hand",
+                        display_path.display()
+                    ));
+                }
+                return Ok(0);
+            }
+        },
+        Err(err) if err.kind() == ErrorKind::NotFound => Map::new(),
+        Err(err) => {
+            return Err(Diagnostic::io_error(
+                "read .vscode/settings.json",
+                err,
+                display_path.display().to_string(),
+            ));
+        }
+    };
+    if !create && !settings.contains_key(EDITOR_SCHEMA_SETTING) {
+        return Ok(0);
+    }
+
+    let associations = settings
+        .entry(EDITOR_SCHEMA_SETTING)
+        .or_insert_with(|| Value::Object(Map::new()));
+    let Value::Object(associations) = associations else {
+        return Ok(0);
+    };
+    let mut added = 0;
+    for (pattern, schema) in editor_schema_associations(config) {
+        if !associations.contains_key(&pattern) {
+            associations.insert(pattern, Value::String(schema));
+            added += 1;
+        }
+    }
+    if added == 0 {
+        return Ok(0);
+    }
+
+    let content = serde_json::to_string_pretty(&settings).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Failed to serialize editor settings: {err}"),
+            display_path.display().to_string(),
+        )
+    })?;
+    if let Some(dir) = settings_path.parent() {
+        crate::write::create_dir_all(dir, op, Some(&config.display_path(dir)))?;
+    }
+    write_file(
+        &settings_path,
+        &format!("{content}\n"),
+        op,
+        Some(&display_path),
+    )?;
+    if !op.is_preview() {
+        ui::info(format!(
+            "Mapped {added} governed file pattern(s) to their schemas in {}",
+            display_path.display()
+        ));
+    }
+    Ok(added)
+}
+
+/// `(file regex, schema path)` for each governed TOML format, relative to
+/// the project root.
+fn editor_schema_associations(config: &Config) -> Vec<(String, String)> {
+    let relative = |path: &Path| {
+        let path = config.display_path(path);
+        path.components()
+            .map(|part| part.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+    let pattern = |dir: &Path, rest: &str| format!(".*/{}/{rest}", regex::escape(&relative(dir)));
+    let schema = |name: &str| format!("./{}/{name}", relative(&config.schema_dir()));
+
+    vec![
+        (
+            pattern(&config.rfc_dir(), r"[^/]+/rfc\.toml$"),
+            schema("rfc.schema.json"),
+        ),
+        (
+            pattern(&config.rfc_dir(), r"[^/]+/clauses/[^/]+\.toml$"),
+            schema("clause.schema.json"),
+        ),
+        (
+            pattern(&config.adr_dir(), r"[^/]+\.toml$"),
+            schema("adr.schema.json"),
+        ),
+        (
+            pattern(&config.work_dir(), r"[^/]+\.toml$"),
+            schema("work.schema.json"),
+        ),
+        (
+            pattern(&config.guard_dir(), r"[^/]+\.toml$"),
+            schema("guard.schema.json"),
+        ),
+        (
+            pattern(&config.mapping_dir(), r"[^/]+\.toml$"),
+            schema("mapping.schema.json"),
+        ),
+        (
+            pattern(&config.gov_root, r"releases\.toml$"),
+            schema("release.schema.json"),
+        ),
+        (
+            pattern(&config.gov_root, r"config(\.local)?\.toml$"),
+            schema("config.schema.json"),
+        ),
+    ]
+}
+
 fn gitignore_path(config: &Config) -> PathBuf {
     config.project_root().join(".gitignore")
 }
@@ -0,0 +1,301 @@
+//! `govctl publish confluence`: push rendered RFCs and ADRs to a Confluence
+//! space through its REST API.
+//!
+//! Each artifact is one page titled `<ID>: <title>`. A page is found through
+//! `[confluence.pages]` or by title in the space, updated in place when it
+//! exists and created under the configured parent otherwise, then labelled
+//! so readers know the content is generated.
+
+use crate::config::{Config, ConfluenceConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project;
+use crate::model::ProjectIndex;
+use crate::render::{adr_html, rfc_html};
+use crate::ui;
+use crate::write::WriteOp;
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::{Method, Url};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+const TOKEN_ENV: &str = "CONFLUENCE_TOKEN";
+const USER_ENV: &str = "CONFLUENCE_USER";
+
+/// One artifact's page content.
+struct Page {
+    artifact: String,
+    title: String,
+    parent: Option<String>,
+    body: String,
+}
+
+#[derive(Deserialize)]
+struct Content {
+    id: String,
+    #[serde(default)]
+    version: Option<Version>,
+}
+
+#[derive(Deserialize)]
+struct Version {
+    number: u64,
+}
+
+#[derive(Deserialize)]
+struct SearchResults {
+    results: Vec<Content>,
+}
+
+/// Publish `ids`, or every RFC and ADR when empty.
+pub fn publish_confluence(
+    config: &Config,
+    ids: &[String],
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let settings = config.confluence.as_ref().ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0513PublishNotConfigured,
+            "No [confluence] section in config (set base_url and space to publish)",
+            config
+                .display_path(&config.gov_root.join("config.toml"))
+                .display()
+                .to_string(),
+        )
+    })?;
+    let index = load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for publishing",
+                "publish confluence",
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    let pages = pages(config, settings, &index, ids)?;
+
+    if op.is_preview() {
+        for page in &pages {
+            ui::dry_run_publish(&page.artifact, &page.title);
+        }
+        return Ok(vec![]);
+    }
+
+    let confluence = Confluence::from_env(settings)?;
+    for page in &pages {
+        let page_id = confluence.publish(page)?;
+        ui::published(&page.artifact, &confluence.page_url(&page_id));
+    }
+    Ok(vec![])
+}
+
+fn pages(
+    config: &Config,
+    settings: &ConfluenceConfig,
+    index: &ProjectIndex,
+    ids: &[String],
+) -> DiagnosticResult<Vec<Page>> {
+    let selected = |id: &str| ids.is_empty() || ids.iter().any(|wanted| wanted == id);
+    for id in ids {
+        let known = index.rfcs.iter().any(|rfc| &rfc.rfc.rfc_id == id)
+            || index.adrs.iter().any(|adr| &adr.meta().id == id);
+        if !known {
+            return Err(unknown_artifact(config, id));
+        }
+    }
+
+    let mut pages = Vec::new();
+    for rfc in index.rfcs.iter().filter(|rfc| selected(&rfc.rfc.rfc_id)) {
+        pages.push(Page {
+            artifact: rfc.rfc.rfc_id.clone(),
+            title: format!("{}: {}", rfc.rfc.rfc_id, rfc.rfc.title),
+            parent: settings.rfc_parent.clone(),
+            body: rfc_html(config, rfc)?,
+        });
+    }
+    for adr in index.adrs.iter().filter(|adr| selected(&adr.meta().id)) {
+        let meta = adr.meta();
+        pages.push(Page {
+            artifact: meta.id.clone(),
+            title: format!("{}: {}", meta.id, meta.title),
+            parent: settings.adr_parent.clone(),
+            body: adr_html(config, adr)?,
+        });
+    }
+    Ok(pages)
+}
+
+fn unknown_artifact(config: &Config, id: &str) -> Diagnostic {
+    if config.prefixes.is_adr(id) {
+        Diagnostic::new(
+            DiagnosticCode::E0302AdrNotFound,
+            format!("ADR not found: {id}"),
+            id,
+        )
+    } else if config.prefixes.is_rfc(id) {
+        Diagnostic::new(
+            DiagnosticCode::E0102RfcNotFound,
+            format!("RFC not found: {id}"),
+            id,
+        )
+    } else {
+        Diagnostic::new(
+            DiagnosticCode::E0822UnsupportedOperation,
+            format!("Only RFCs and ADRs can be published to Confluence: {id}"),
+            id,
+        )
+    }
+}
+
+/// How requests authenticate; the token itself stays in the environment.
+enum Auth {
+    Basic { user: String, token: String },
+    Bearer { token: String },
+}
+
+struct Confluence<'a> {
+    settings: &'a ConfluenceConfig,
+    client: Client,
+    auth: Auth,
+}
+
+impl<'a> Confluence<'a> {
+    fn from_env(settings: &'a ConfluenceConfig) -> DiagnosticResult<Self> {
+        let token = std::env::var(TOKEN_ENV).map_err(|_| {
+            Diagnostic::new(
+                DiagnosticCode::E0513PublishNotConfigured,
+                format!("{TOKEN_ENV} is not set (hint: export a Confluence API token)"),
+                settings.base_url.as_str(),
+            )
+        })?;
+        let auth = match std::env::var(USER_ENV) {
+            Ok(user) if !user.is_empty() => Auth::Basic { user, token },
+            _ => Auth::Bearer { token },
+        };
+        Ok(Self {
+            settings,
+            client: crate::http::client(),
+            auth,
+        })
+    }
+
+    fn base_url(&self) -> &str {
+        self.settings.base_url.trim_end_matches('/')
+    }
+
+    fn page_url(&self, page_id: &str) -> String {
+        format!("{}/pages/viewpage.action?pageId={page_id}", self.base_url())
+    }
+
+    /// Create or update `page`, label it, and return its page ID.
+    fn publish(&self, page: &Page) -> DiagnosticResult<String> {
+        let existing = match self.settings.pages.get(&page.artifact) {
+            Some(page_id) => Some(self.send::<Content>(
+                self.request(
+                    Method::GET,
+                    &format!("/content/{page_id}"),
+                    &[("expand", "version")],
+                )?,
+                &page.artifact,
+            )?),
+            None => self.find_by_title(page)?,
+        };
+
+        let mut body = json!({
+            "type": "page",
+            "title": page.title,
+            "space": { "key": self.settings.space },
+            "body": {
+                "storage": {
+                    "value": storage_body(page),
+                    "representation": "storage",
+                },
+            },
+        });
+        let page_id = match existing {
+            Some(content) => {
+                body["version"] = json!({
+                    "number": content.version.map_or(1, |version| version.number + 1),
+                });
+                self.send::<Content>(
+                    self.request(Method::PUT, &format!("/content/{}", content.id), &[])?
+                        .json(&body),
+                    &page.artifact,
+                )?;
+                content.id
+            }
+            None => {
+                if let Some(parent) = &page.parent {
+                    body["ancestors"] = json!([{ "id": parent }]);
+                }
+                self.send::<Content>(
+                    self.request(Method::POST, "/content", &[])?.json(&body),
+                    &page.artifact,
+                )?
+                .id
+            }
+        };
+
+        self.send::<serde::de::IgnoredAny>(
+            self.request(Method::POST, &format!("/content/{page_id}/label"), &[])?
+                .json(&json!([{ "prefix": "global", "name": self.settings.label }])),
+            &page.artifact,
+        )?;
+        Ok(page_id)
+    }
+
+    fn find_by_title(&self, page: &Page) -> DiagnosticResult<Option<Content>> {
+        let found = self.send::<SearchResults>(
+            self.request(
+                Method::GET,
+                "/content",
+                &[
+                    ("spaceKey", self.settings.space.as_str()),
+                    ("title", page.title.as_str()),
+                    ("expand", "version"),
+                ],
+            )?,
+            &page.artifact,
+        )?;
+        Ok(found.results.into_iter().next())
+    }
+
+    fn request(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> DiagnosticResult<RequestBuilder> {
+        let mut url =
+            Url::parse(&format!("{}/rest/api{path}", self.base_url())).map_err(|err| {
+                Diagnostic::new(
+                    DiagnosticCode::E0501ConfigInvalid,
+                    format!("Invalid confluence.base_url: {err}"),
+                    self.settings.base_url.as_str(),
+                )
+            })?;
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        let request = self.client.request(method, url);
+        Ok(match &self.auth {
+            Auth::Basic { user, token } => request.basic_auth(user, Some(token)),
+            Auth::Bearer { token } => request.bearer_auth(token),
+        })
+    }
+
+    fn send<T: DeserializeOwned>(&self, request: RequestBuilder, id: &str) -> DiagnosticResult<T> {
+        let action = format!("publish {id} to Confluence");
+        crate::http::send_json(request, &action, self.base_url())
+    }
+}
+
+/// The page body: a notice that the page is generated, then the rendering.
+fn storage_body(page: &Page) -> String {
+    format!(
+        "<p><em>Generated by govctl from {}. Edit the source in the repository; changes made here are overwritten.</em></p>\n{}",
+        page.artifact, page.body
+    )
+}
@@ -0,0 +1,246 @@
+//! Explicit work queue order: `work rank` and `work next`.
+//!
+//! Queued work items are picked up in order of their `priority` rank, lowest
+//! first. Unranked items follow the ranked ones, and ties keep ID order.
+//! `work rank` moves an item before or after another and renumbers the queue
+//! from 1; `work next` names the first queued item that is ready to start.
+
+use crate::OutputFormat;
+use crate::aliases::Aliases;
+use crate::cmd::move_::{move_item, resolve_work_path};
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::model::{WorkItemEntry, WorkItemStatus};
+use crate::parse::{load_work_item, load_work_items, write_work_item};
+use crate::ui;
+use crate::write::{WriteOp, with_file_transaction};
+use comfy_table::Cell;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Where `work rank` places an item, relative to another queued item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RankPosition {
+    Before(String),
+    After(String),
+}
+
+/// Sort key for queue order: ranked items first, by rank.
+pub(crate) fn rank_key(item: &WorkItemEntry) -> (bool, Option<u32>) {
+    let priority = item.meta().priority;
+    (priority.is_none(), priority)
+}
+
+/// Queued work items in pick-up order.
+pub(crate) fn queue_order(items: &[WorkItemEntry]) -> Vec<&WorkItemEntry> {
+    let mut queue: Vec<&WorkItemEntry> = items
+        .iter()
+        .filter(|item| item.meta().status == WorkItemStatus::Queue)
+        .collect();
+    queue.sort_by(|a, b| {
+        rank_key(a)
+            .cmp(&rank_key(b))
+            .then_with(|| a.meta().id.cmp(&b.meta().id))
+    });
+    queue
+}
+
+/// Move a queued item before or after another and renumber the queue.
+pub fn rank(
+    config: &Config,
+    id: &str,
+    position: &RankPosition,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let items = load_work_items(config)?;
+    let item = find_queued(config, &items, id)?;
+    let (other, after) = match position {
+        RankPosition::Before(other) => (other, false),
+        RankPosition::After(other) => (other, true),
+    };
+    let other = find_queued(config, &items, other)?;
+    let item_id = &item.meta().id;
+    if *item_id == other.meta().id {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0802ConflictingArgs,
+            format!("Cannot rank {item_id} relative to itself"),
+            item_id,
+        ));
+    }
+
+    let mut order: Vec<&WorkItemEntry> = queue_order(&items)
+        .into_iter()
+        .filter(|queued| queued.meta().id != *item_id)
+        .collect();
+    let at = order
+        .iter()
+        .position(|queued| queued.meta().id == other.meta().id)
+        .map_or(0, |index| index + usize::from(after));
+    order.insert(at, item);
+
+    let changed: Vec<(&WorkItemEntry, u32)> = order
+        .iter()
+        .zip(1..)
+        .filter_map(|(queued, rank)| {
+            (queued.meta().priority != Some(rank)).then_some((*queued, rank))
+        })
+        .collect();
+    let paths: Vec<&Path> = changed
+        .iter()
+        .map(|(queued, _)| queued.path.as_path())
+        .collect();
+    with_file_transaction(&paths, op, |op| {
+        for (queued, rank) in &changed {
+            let mut spec = queued.spec.clone();
+            spec.govctl.priority = Some(*rank);
+            write_work_item(
+                &queued.path,
+                &spec,
+                op,
+                Some(&config.display_path(&queued.path)),
+            )?;
+        }
+        Ok(())
+    })?;
+
+    if !op.is_preview() {
+        ui::success(format!(
+            "Ranked {item_id} {} of {} in the queue",
+            at + 1,
+            order.len()
+        ));
+    }
+    Ok(vec![])
+}
+
+/// Filters `work next` applies to the queue.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NextFilter {
+    /// The item must have all of these tags.
+    pub tags: Vec<String>,
+    /// The item must reference this artifact, or a clause of it.
+    pub reference: Option<String>,
+}
+
+#[derive(Serialize)]
+struct NextItem<'a> {
+    id: &'a str,
+    title: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rank: Option<u32>,
+}
+
+/// Print the first queued item matching `filter` whose dependencies are all
+/// finished, and optionally start it.
+pub fn next(
+    config: &Config,
+    filter: &NextFilter,
+    activate: bool,
+    output: OutputFormat,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let items = load_work_items(config)?;
+    let status_by_id: HashMap<&str, WorkItemStatus> = items
+        .iter()
+        .map(|item| (item.meta().id.as_str(), item.meta().status))
+        .collect();
+    let next = queue_order(&items).into_iter().find(|item| {
+        let meta = item.meta();
+        let references = |reference: &String| {
+            meta.refs
+                .iter()
+                .any(|target| target == reference || target.starts_with(&format!("{reference}:")))
+        };
+        filter.tags.iter().all(|tag| meta.tags.contains(tag))
+            && filter.reference.as_ref().is_none_or(references)
+            && meta.depends_on.iter().all(|dependency| {
+                matches!(
+                    status_by_id.get(dependency.as_str()),
+                    Some(WorkItemStatus::Done | WorkItemStatus::Cancelled)
+                )
+            })
+    });
+    let rows: Vec<NextItem> = next
+        .iter()
+        .map(|item| NextItem {
+            id: &item.meta().id,
+            title: &item.meta().title,
+            rank: item.meta().priority,
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => print_json_array(&rows),
+        OutputFormat::Plain => {
+            for row in &rows {
+                println!("{}", row.id);
+            }
+        }
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                ui::info("No queued work item is ready");
+            } else {
+                let mut table = table_with_bold_headers(&["ID", "Title", "Rank"]);
+                for row in &rows {
+                    table.add_row(vec![
+                        Cell::new(row.id),
+                        Cell::new(row.title),
+                        Cell::new(
+                            row.rank
+                                .map_or_else(|| "-".to_string(), |rank| rank.to_string()),
+                        ),
+                    ]);
+                }
+                println!("{table}");
+            }
+        }
+    }
+
+    match next {
+        Some(item) if activate => {
+            move_item(config, &item.path, WorkItemStatus::Active, None, None, op)
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// The queued work item `id` names: an ID, `@alias`, or partial name.
+fn find_queued<'a>(
+    config: &Config,
+    items: &'a [WorkItemEntry],
+    id: &str,
+) -> DiagnosticResult<&'a WorkItemEntry> {
+    let id = Aliases::load(config).expand_or_keep(id);
+    let item = match items.iter().find(|item| item.meta().id == id) {
+        Some(item) => item,
+        None => {
+            let path = resolve_work_path(config, Path::new(&id))?;
+            let entry = load_work_item(config, &path)?;
+            items
+                .iter()
+                .find(|item| item.meta().id == entry.meta().id)
+                .ok_or_else(|| {
+                    Diagnostic::new(
+                        DiagnosticCode::E0402WorkNotFound,
+                        format!("Work item not found: {id}"),
+                        &id,
+                    )
+                })?
+        }
+    };
+    let meta = item.meta();
+    if meta.status != WorkItemStatus::Queue {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0413WorkNotQueued,
+            format!(
+                "{} is {}; only queued work items have a queue rank",
+                meta.id,
+                meta.status.as_ref()
+            ),
+            &meta.id,
+        ));
+    }
+    Ok(item)
+}
@@ -1,26 +1,26 @@
 use std::collections::HashSet;
 
 use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticResult, Diagnostics};
-use crate::model::{ReleasesFile, WorkItemEntry, WorkItemStatus};
+use crate::diagnostic::{Diagnostic, DiagnosticResult};
+use crate::model::{Release, ReleasesFile, WorkItemEntry, WorkItemStatus};
 use crate::parse::{load_releases, load_work_items};
+use crate::render::{Drift, RenderMode, check_rendered, expand_inline_refs_plain};
 use crate::ui;
+use crate::write::{WriteOp, replace_file, write_file};
 
 mod preserve;
 mod sections;
 
 /// Render CHANGELOG.md from completed work items
 /// Per [[ADR-0014]], groups by release version and changelog category.
+/// `[render.changelog]` adds RFC versions and accepted ADRs.
 ///
 /// Default behavior: only updates the Unreleased section, preserving manually
 /// edited released sections. Use `force=true` to regenerate the entire file.
-pub fn render_changelog(
-    config: &Config,
-    dry_run: bool,
-    force: bool,
-) -> DiagnosticResult<Diagnostics> {
+pub fn render_changelog(config: &Config, mode: RenderMode, force: bool) -> DiagnosticResult<Drift> {
     let releases_file = load_releases(config)?;
     let work_items = load_work_items(config)?;
+    let sources = sections::Sources::load(config, &work_items)?;
 
     // Get all released work item IDs
     let released_ids: HashSet<_> = releases_file
@@ -40,58 +40,103 @@ pub fn render_changelog(
 
     let output = if force {
         // Force mode: regenerate entire file
-        render_changelog_full(config, &releases_file, &work_items, &unreleased)?
+        render_changelog_full(config, &releases_file, &sources, &unreleased)?
     } else {
         // Default mode: update Unreleased section + add missing releases, preserve existing
         render_changelog_incremental(
             config,
             &changelog_path,
             &releases_file,
-            &work_items,
+            &sources,
             &unreleased,
         )?
     };
 
     let unreleased_count = unreleased.len();
 
-    if dry_run {
-        ui::dry_run_file_preview(&changelog_path, &output);
-    } else {
-        std::fs::write(&changelog_path, &output).map_err(|err| {
-            Diagnostic::io_error("write changelog", err, changelog_path.display().to_string())
-        })?;
-        ui::changelog_rendered(
+    let display_path = config.display_path(&changelog_path);
+    match mode {
+        RenderMode::Check => {
+            return Ok(check_rendered(
+                &changelog_path,
+                output.as_bytes(),
+                &display_path,
+            ));
+        }
+        RenderMode::DryRun => write_file(
             &changelog_path,
-            releases_file.releases.len(),
-            unreleased_count,
-        );
+            &output,
+            WriteOp::PREVIEW,
+            Some(&display_path),
+        )?,
+        RenderMode::Write => {
+            replace_file(
+                &changelog_path,
+                output.as_bytes(),
+                &display_path,
+                config.concurrency.fsync,
+            )?;
+            ui::changelog_rendered(
+                &changelog_path,
+                releases_file.releases.len(),
+                unreleased_count,
+            );
+        }
     }
 
-    Ok(vec![])
+    Ok(Drift::new())
+}
+
+/// Notes for `release` published outside the repository: its changelog
+/// entries without the version heading, with references as plain IDs.
+/// `previous` is the release before it, which bounds its window.
+pub fn release_notes(
+    config: &Config,
+    release: &Release,
+    previous: Option<&Release>,
+    work_items: &[WorkItemEntry],
+) -> DiagnosticResult<String> {
+    let sources = sections::Sources::load(config, work_items)?;
+    let window = sections::Window::release(release, previous);
+    let changes = sections::release_changes(release, &sources, window);
+    Ok(
+        expand_inline_refs_plain(&changes, &config.source_scan.pattern)
+            .trim_end()
+            .to_string(),
+    )
 }
 
 /// Generate the complete changelog from scratch (force mode)
 fn render_changelog_full(
     config: &Config,
     releases_file: &ReleasesFile,
-    work_items: &[WorkItemEntry],
+    sources: &sections::Sources<'_>,
     unreleased: &[&WorkItemEntry],
 ) -> DiagnosticResult<String> {
-    let work_item_map = sections::work_item_map(work_items);
-
+    let releases = &releases_file.releases;
     let mut output = String::new();
     output.push_str(sections::CHANGELOG_HEADER);
 
     // Unreleased section
-    let unreleased_expanded =
-        sections::render_unreleased_section(unreleased, &config.source_scan.pattern);
+    let unreleased_expanded = sections::render_unreleased_section(
+        &config.prefixes,
+        unreleased,
+        sources,
+        sections::Window::unreleased(releases),
+        &config.source_scan.pattern,
+    );
     output.push_str(unreleased_expanded.trim_end());
     output.push('\n');
 
     // Released sections (newest first per releases.toml order)
-    for release in &releases_file.releases {
-        let release_expanded =
-            sections::render_release_section(release, &work_item_map, &config.source_scan.pattern);
+    for (index, release) in releases.iter().enumerate() {
+        let release_expanded = sections::render_release_section(
+            &config.prefixes,
+            release,
+            sources,
+            sections::Window::release(release, releases.get(index + 1)),
+            &config.source_scan.pattern,
+        );
         output.push('\n');
         output.push_str(release_expanded.trim_end());
         output.push('\n');
@@ -105,7 +150,7 @@ fn render_changelog_incremental(
     config: &Config,
     changelog_path: &std::path::Path,
     releases_file: &ReleasesFile,
-    work_items: &[WorkItemEntry],
+    sources: &sections::Sources<'_>,
     unreleased: &[&WorkItemEntry],
 ) -> DiagnosticResult<String> {
     let existing = if changelog_path.exists() {
@@ -116,12 +161,17 @@ fn render_changelog_incremental(
         String::new()
     };
 
-    let work_item_map = sections::work_item_map(work_items);
+    let releases = &releases_file.releases;
     let mut existing_changelog = preserve::split_existing_changelog(&existing);
 
     // Generate new Unreleased section and expand inline refs
-    let unreleased_expanded =
-        sections::render_unreleased_section(unreleased, &config.source_scan.pattern);
+    let unreleased_expanded = sections::render_unreleased_section(
+        &config.prefixes,
+        unreleased,
+        sources,
+        sections::Window::unreleased(releases),
+        &config.source_scan.pattern,
+    );
 
     // Build output: header + unreleased + (new releases + existing releases merged)
     let mut output = existing_changelog.header;
@@ -129,11 +179,13 @@ fn render_changelog_incremental(
     output.push('\n');
 
     // Add releases from releases.toml that don't exist yet
-    for release in &releases_file.releases {
+    for (index, release) in releases.iter().enumerate() {
         if !preserve::contains_version_variant(&existing_changelog.releases, &release.version) {
             let release_expanded = sections::render_release_section(
+                &config.prefixes,
                 release,
-                &work_item_map,
+                sources,
+                sections::Window::release(release, releases.get(index + 1)),
                 &config.source_scan.pattern,
             );
             existing_changelog.releases.insert(
@@ -1,6 +1,12 @@
 use std::collections::HashMap;
 
-use crate::model::{ChangelogCategory, ChecklistStatus, Release, WorkItemEntry};
+use crate::config::{Config, PrefixesConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticResult};
+use crate::load::load_rfcs;
+use crate::model::{
+    AdrEntry, AdrStatus, ChangelogCategory, ChecklistStatus, Release, RfcIndex, WorkItemEntry,
+};
+use crate::parse::load_adrs;
 use crate::render::expand_inline_refs_from_root;
 
 pub(super) const CHANGELOG_HEADER: &str = "# Changelog\n\n\
@@ -8,50 +14,120 @@ All notable changes to this project will be documented in this file.\n\n\
 The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\n\
 and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).\n\n";
 
-pub(super) fn work_item_map(work_items: &[WorkItemEntry]) -> HashMap<String, &WorkItemEntry> {
-    work_items
-        .iter()
-        .map(|work_item| (work_item.spec.govctl.id.clone(), work_item))
-        .collect()
+/// Everything changelog sections are built from.
+pub(super) struct Sources<'a> {
+    work_items: HashMap<String, &'a WorkItemEntry>,
+    /// Empty unless `[render.changelog] rfc_versions` is set.
+    rfcs: Vec<RfcIndex>,
+    /// Empty unless `[render.changelog] adr_acceptances` is set.
+    adrs: Vec<AdrEntry>,
+}
+
+impl<'a> Sources<'a> {
+    pub(super) fn load(config: &Config, work_items: &'a [WorkItemEntry]) -> DiagnosticResult<Self> {
+        let options = &config.render.changelog;
+        let rfcs = if options.rfc_versions {
+            load_rfcs(config).map_err(Diagnostic::from)?
+        } else {
+            Vec::new()
+        };
+        let adrs = if options.adr_acceptances {
+            load_adrs(config)?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            work_items: work_items
+                .iter()
+                .map(|work_item| (work_item.spec.govctl.id.clone(), work_item))
+                .collect(),
+            rfcs,
+            adrs,
+        })
+    }
+}
+
+/// The dates a changelog section covers: after the previous release, up to
+/// and including this one. RFC versions and ADRs are placed by their date.
+#[derive(Clone, Copy)]
+pub(super) struct Window<'a> {
+    since: Option<&'a str>,
+    until: Option<&'a str>,
+}
+
+impl<'a> Window<'a> {
+    /// Everything after the newest release.
+    pub(super) fn unreleased(releases: &'a [Release]) -> Self {
+        Self {
+            since: releases.first().map(|release| release.date.as_str()),
+            until: None,
+        }
+    }
+
+    /// The window of `release`, cut after `previous`.
+    pub(super) fn release(release: &'a Release, previous: Option<&'a Release>) -> Self {
+        Self {
+            since: previous.map(|previous| previous.date.as_str()),
+            until: Some(&release.date),
+        }
+    }
+
+    fn contains(self, date: &str) -> bool {
+        self.since.is_none_or(|since| date > since) && self.until.is_none_or(|until| date <= until)
+    }
 }
 
 pub(super) fn render_unreleased_section(
+    prefixes: &PrefixesConfig,
     items: &[&WorkItemEntry],
+    sources: &Sources<'_>,
+    window: Window<'_>,
     source_scan_pattern: &str,
 ) -> String {
     let mut content = String::new();
     content.push_str("## [Unreleased]\n\n");
-    if !items.is_empty() {
-        render_changelog_section(&mut content, items);
-    }
-    expand_inline_refs_from_root(&content, source_scan_pattern, "docs")
+    render_changelog_section(&mut content, items);
+    render_governance_sections(&mut content, sources, window);
+    expand_inline_refs_from_root(prefixes, &content, source_scan_pattern, "docs")
         .trim_end()
         .to_string()
 }
 
 pub(super) fn render_release_section(
+    prefixes: &PrefixesConfig,
     release: &Release,
-    work_item_map: &HashMap<String, &WorkItemEntry>,
+    sources: &Sources<'_>,
+    window: Window<'_>,
     source_scan_pattern: &str,
 ) -> String {
     let mut content = String::new();
     content.push_str(&format!("## [{}] - {}\n\n", release.version, release.date));
+    content.push_str(&release_changes(release, sources, window));
 
+    expand_inline_refs_from_root(prefixes, &content, source_scan_pattern, "docs")
+        .trim_end()
+        .to_string()
+}
+
+/// The changes in `release` by category, before references are expanded.
+pub(super) fn release_changes(
+    release: &Release,
+    sources: &Sources<'_>,
+    window: Window<'_>,
+) -> String {
     let items: Vec<_> = release
         .refs
         .iter()
-        .filter_map(|id| work_item_map.get(id).copied())
+        .filter_map(|id| sources.work_items.get(id).copied())
         .collect();
 
-    if items.is_empty() {
+    let mut content = String::new();
+    render_changelog_section(&mut content, &items);
+    render_governance_sections(&mut content, sources, window);
+    if content.is_empty() {
         content.push_str("*No changes recorded.*\n");
-    } else {
-        render_changelog_section(&mut content, &items);
     }
-
-    expand_inline_refs_from_root(&content, source_scan_pattern, "docs")
-        .trim_end()
-        .to_string()
+    content
 }
 
 fn render_changelog_section(output: &mut String, items: &[&WorkItemEntry]) {
@@ -78,3 +154,54 @@ fn render_changelog_section(output: &mut String, items: &[&WorkItemEntry]) {
         }
     }
 }
+
+/// RFC versions and accepted ADRs dated within `window`, each linked.
+fn render_governance_sections(output: &mut String, sources: &Sources<'_>, window: Window<'_>) {
+    let versions: Vec<String> = sources
+        .rfcs
+        .iter()
+        .flat_map(|rfc| {
+            // An RFC's changelog is newest first; list its versions in order.
+            rfc.rfc
+                .changelog
+                .iter()
+                .rev()
+                .filter(move |entry| window.contains(&entry.date))
+                .map(move |entry| {
+                    let mut line = format!(
+                        "- [[{}]] {} {}",
+                        rfc.rfc.rfc_id, rfc.rfc.title, entry.version
+                    );
+                    if let Some(notes) = entry.notes.as_deref().filter(|notes| !notes.is_empty()) {
+                        line.push_str(&format!(": {notes}"));
+                    }
+                    line
+                })
+        })
+        .collect();
+    push_list(output, "Specifications", &versions);
+
+    let decisions: Vec<String> = sources
+        .adrs
+        .iter()
+        .map(AdrEntry::meta)
+        .filter(|meta| {
+            matches!(meta.status, AdrStatus::Accepted | AdrStatus::Superseded)
+                && window.contains(&meta.date)
+        })
+        .map(|meta| format!("- [[{}]] {}", meta.id, meta.title))
+        .collect();
+    push_list(output, "Decisions", &decisions);
+}
+
+fn push_list(output: &mut String, label: &str, lines: &[String]) {
+    if lines.is_empty() {
+        return;
+    }
+    output.push_str(&format!("### {}\n\n", label));
+    for line in lines {
+        output.push_str(line);
+        output.push('\n');
+    }
+    output.push('\n');
+}
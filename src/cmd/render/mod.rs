@@ -1,16 +1,20 @@
 //! Render command implementation.
 
+use crate::artifact_catalog::{CatalogKind, missing_id_message};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
 use crate::load::load_rfcs;
-use crate::parse::{load_adrs, load_work_items};
-use crate::render::{write_adr_md, write_rfc, write_work_item_md};
-use crate::ui;
-use std::path::Path;
+use crate::parse::{load_adrs, load_mappings, load_work_items};
+use crate::render::{
+    Drift, RenderCache, RenderMode, write_adr_index, write_adr_md, write_compliance_matrix,
+    write_rfc, write_rfc_index, write_rfc_pdf, write_work_index, write_work_item_md,
+};
+use crate::{Persona, ui};
+use std::path::{Path, PathBuf};
 
 mod changelog;
 mod show;
-pub use changelog::render_changelog;
+pub use changelog::{release_notes, render_changelog};
 pub use show::{show_adr, show_clause, show_rfc, show_work};
 
 fn display_path_string(config: &Config, path: impl AsRef<Path>) -> String {
@@ -20,40 +24,50 @@ fn display_path_string(config: &Config, path: impl AsRef<Path>) -> String {
 fn artifact_not_found(
     config: &Config,
     code: DiagnosticCode,
-    artifact: &str,
+    kind: CatalogKind,
     id: &str,
     scope_path: impl AsRef<Path>,
 ) -> Diagnostic {
     Diagnostic::new(
         code,
-        format!("{artifact} not found: {id}"),
+        missing_id_message(config, kind, id),
         display_path_string(config, scope_path),
     )
 }
 
 struct RenderSelection<'a> {
+    config: &'a Config,
     id: Option<&'a str>,
-    dry_run: bool,
+    mode: RenderMode,
+    /// Render every selected item, even those the render cache says are current.
+    force: bool,
     summary_label: &'a str,
+    persona: Persona,
 }
 
-fn render_selected<T, Empty, NotFound, Id, Write>(
+/// Where an item renders to and the fingerprint of what it renders from.
+type CachedOutput = (PathBuf, String);
+
+fn render_selected<T, Empty, NotFound, Id, Output, Write>(
     items: Vec<T>,
     selection: RenderSelection<'_>,
     empty: Empty,
     not_found: NotFound,
     item_id: Id,
+    output: Output,
     mut write: Write,
-) -> DiagnosticResult<Diagnostics>
+) -> DiagnosticResult<Drift>
 where
     Empty: FnOnce(),
     NotFound: FnOnce(&str) -> Diagnostic,
     Id: for<'a> Fn(&'a T) -> &'a str,
-    Write: FnMut(&T) -> DiagnosticResult<()>,
+    Output: Fn(&RenderCache, &T) -> DiagnosticResult<CachedOutput>,
+    Write: FnMut(&T) -> DiagnosticResult<Drift>,
 {
+    let config = selection.config;
     if items.is_empty() {
         empty();
-        return Ok(vec![]);
+        return Ok(Drift::new());
     }
 
     let items_to_render: Vec<_> = if let Some(id) = selection.id {
@@ -71,76 +85,230 @@ where
         return Err(not_found(id));
     }
 
-    for item in &items_to_render {
-        write(item)?;
-    }
+    // Dry runs and `--check` compare every item, so they never use the cache.
+    let mut cache = if selection.mode == RenderMode::Write {
+        Some(RenderCache::load(config)?)
+    } else {
+        None
+    };
+    // Naming one item asks for it to be rendered.
+    let skip_current = !selection.force && selection.id.is_none();
+    let mut unchanged = 0;
+    let mut drift = Drift::new();
+    ui::timed(&format!("render {}s", selection.summary_label), || {
+        let progress = ui::progress(
+            selection.persona,
+            format!("Rendering {}s", selection.summary_label),
+            items_to_render.len(),
+        );
+        for item in &items_to_render {
+            progress.step(item_id(item));
+            match cache.as_mut() {
+                Some(cache) => {
+                    let (path, inputs) = output(cache, item)?;
+                    if skip_current && cache.is_current(config, &path, &inputs) {
+                        unchanged += 1;
+                    } else {
+                        write(item)?;
+                        cache.record(config, &path, inputs);
+                    }
+                }
+                None => drift.extend(write(item)?),
+            }
+            progress.inc();
+        }
+        Ok::<_, Diagnostic>(())
+    })?;
 
-    if !selection.dry_run {
-        ui::render_summary(items_to_render.len(), selection.summary_label);
+    if let Some(cache) = cache {
+        cache.save(config)?;
+        ui::render_summary(
+            items_to_render.len() - unchanged,
+            unchanged,
+            selection.summary_label,
+        );
     }
 
-    Ok(vec![])
+    Ok(drift)
+}
+
+/// Fail if `render --check` found rendered files that differ from the ones
+/// on disk.
+pub fn check_up_to_date(config: &Config, drifted: &[PathBuf]) -> DiagnosticResult<Diagnostics> {
+    if drifted.is_empty() {
+        ui::success("Rendered files are up to date");
+        return Ok(vec![]);
+    }
+    let paths: Vec<String> = drifted
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect();
+    Err(Diagnostic::new(
+        DiagnosticCode::E0833RenderedDocsOutOfDate,
+        format!(
+            "{} rendered file(s) out of date: {} (run govctl render without --check)",
+            paths.len(),
+            paths.join(", ")
+        ),
+        display_path_string(config, &config.paths.docs_output),
+    ))
 }
 
 /// Render RFC markdown from JSON source
 pub fn render(
     config: &Config,
     rfc_id: Option<&str>,
-    dry_run: bool,
-) -> DiagnosticResult<Diagnostics> {
+    mode: RenderMode,
+    force: bool,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
     let rfcs = load_rfcs(config).map_err(Diagnostic::from)?;
+    let adrs = load_adrs(config)?;
 
     render_selected(
         rfcs,
         RenderSelection {
+            config,
             id: rfc_id,
-            dry_run,
+            mode,
+            force,
             summary_label: "RFC",
+            persona,
+        },
+        || ui::not_found("RFC", &config.rfc_dir()),
+        |id| {
+            artifact_not_found(
+                config,
+                DiagnosticCode::E0102RfcNotFound,
+                CatalogKind::Rfc,
+                id,
+                config.rfc_dir(),
+            )
+        },
+        |rfc| rfc.rfc.rfc_id.as_str(),
+        |cache, rfc| {
+            Ok((
+                config.rfc_output().join(format!("{}.md", rfc.rfc.rfc_id)),
+                cache.rfc_inputs(config, rfc, &adrs)?,
+            ))
+        },
+        |rfc| write_rfc(config, rfc, mode),
+    )
+}
+
+/// Render RFCs to print-ready PDF next to their markdown
+pub fn render_pdf(
+    config: &Config,
+    rfc_id: Option<&str>,
+    mode: RenderMode,
+    force: bool,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
+    let rfcs = load_rfcs(config).map_err(Diagnostic::from)?;
+    let adrs = load_adrs(config)?;
+
+    render_selected(
+        rfcs,
+        RenderSelection {
+            config,
+            id: rfc_id,
+            mode,
+            force,
+            summary_label: "RFC PDF",
+            persona,
         },
         || ui::not_found("RFC", &config.rfc_dir()),
         |id| {
             artifact_not_found(
                 config,
                 DiagnosticCode::E0102RfcNotFound,
-                "RFC",
+                CatalogKind::Rfc,
                 id,
                 config.rfc_dir(),
             )
         },
         |rfc| rfc.rfc.rfc_id.as_str(),
-        |rfc| write_rfc(config, rfc, dry_run),
+        |cache, rfc| {
+            Ok((
+                config.rfc_output().join(format!("{}.pdf", rfc.rfc.rfc_id)),
+                cache.rfc_inputs(config, rfc, &adrs)?,
+            ))
+        },
+        |rfc| write_rfc_pdf(config, rfc, mode),
     )
 }
 
+/// Write an `index.md` into each output directory that has artifacts
+pub fn render_indexes(config: &Config, mode: RenderMode) -> DiagnosticResult<Drift> {
+    let mut drift = Drift::new();
+    let rfcs = load_rfcs(config).map_err(Diagnostic::from)?;
+    if !rfcs.is_empty() {
+        drift.extend(write_rfc_index(config, &rfcs, mode)?);
+    }
+    let adrs = load_adrs(config)?;
+    if !adrs.is_empty() {
+        drift.extend(write_adr_index(config, &adrs, mode)?);
+    }
+    let items = load_work_items(config)?;
+    if !items.is_empty() {
+        drift.extend(write_work_index(config, &items, mode)?);
+    }
+    Ok(drift)
+}
+
+/// Render the compliance matrix of mapped standard controls
+pub fn render_compliance(config: &Config, mode: RenderMode) -> DiagnosticResult<Drift> {
+    let mappings = load_mappings(config)?;
+    if mappings.is_empty() {
+        ui::not_found("compliance mapping", &config.mapping_dir());
+        return Ok(Drift::new());
+    }
+    let index = crate::cmd::mapping::load_index(config)?;
+    write_compliance_matrix(config, &mappings, &index, mode)
+}
+
 /// Render ADRs to markdown
 ///
 /// If `adr_id` is provided, renders only that ADR. Otherwise renders all.
 pub fn render_adrs(
     config: &Config,
     adr_id: Option<&str>,
-    dry_run: bool,
-) -> DiagnosticResult<Diagnostics> {
+    mode: RenderMode,
+    force: bool,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
     let adrs = load_adrs(config)?;
 
     render_selected(
         adrs,
         RenderSelection {
+            config,
             id: adr_id,
-            dry_run,
+            mode,
+            force,
             summary_label: "ADR",
+            persona,
         },
         || ui::info("No ADRs found"),
         |id| {
             artifact_not_found(
                 config,
                 DiagnosticCode::E0302AdrNotFound,
-                "ADR",
+                CatalogKind::Adr,
                 id,
                 config.adr_dir(),
             )
         },
         |adr| adr.spec.govctl.id.as_str(),
-        |adr| write_adr_md(config, adr, dry_run),
+        |cache, adr| {
+            Ok((
+                config
+                    .adr_output()
+                    .join(format!("{}.md", adr.spec.govctl.id)),
+                cache.adr_inputs(config, adr)?,
+            ))
+        },
+        |adr| write_adr_md(config, adr, mode),
     )
 }
 
@@ -150,28 +318,41 @@ pub fn render_adrs(
 pub fn render_work_items(
     config: &Config,
     work_id: Option<&str>,
-    dry_run: bool,
-) -> DiagnosticResult<Diagnostics> {
+    mode: RenderMode,
+    force: bool,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
     let items = load_work_items(config)?;
 
     render_selected(
         items,
         RenderSelection {
+            config,
             id: work_id,
-            dry_run,
+            mode,
+            force,
             summary_label: "work item",
+            persona,
         },
         || ui::info("No work items found"),
         |id| {
             artifact_not_found(
                 config,
                 DiagnosticCode::E0402WorkNotFound,
-                "Work item",
+                CatalogKind::Work,
                 id,
                 config.work_dir(),
             )
         },
         |item| item.spec.govctl.id.as_str(),
-        |item| write_work_item_md(config, item, dry_run),
+        |cache, item| {
+            Ok((
+                config
+                    .work_output()
+                    .join(format!("{}.md", item.spec.govctl.id)),
+                cache.work_item_inputs(config, item)?,
+            ))
+        },
+        |item| write_work_item_md(config, item, mode),
     )
 }
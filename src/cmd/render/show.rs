@@ -1,5 +1,6 @@
 use super::artifact_not_found;
 use crate::ShowOutputFormat;
+use crate::artifact_catalog::CatalogKind;
 use crate::cmd::output::{print_json, print_toml, print_yaml};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
@@ -10,7 +11,7 @@ use crate::render::{
     RenderProjection, expand_inline_refs, render_adr_with_projection,
     render_clause_with_projection, render_rfc_with_projection, render_work_item_with_projection,
 };
-use crate::terminal_md::render_terminal_md;
+use crate::terminal_md::{render_plain_md, render_terminal_md};
 use serde::Serialize;
 
 struct ShowOutputRequest<'a, T> {
@@ -70,8 +71,12 @@ where
                 RenderProjection::Current
             };
             let raw = render_human(projection)?;
-            let expanded = expand_inline_refs(&raw, &config.source_scan.pattern);
-            print!("{}", render_terminal_md(&expanded));
+            let expanded = expand_inline_refs(&config.prefixes, &raw, &config.source_scan.pattern);
+            if request.output == ShowOutputFormat::Plain {
+                print!("{}", render_plain_md(&expanded));
+            } else {
+                print!("{}", render_terminal_md(&expanded));
+            }
         }
     }
     Ok(())
@@ -87,13 +92,16 @@ pub fn show_rfc(
     output: ShowOutputFormat,
     history: bool,
 ) -> DiagnosticResult<Diagnostics> {
+    if let Some((rfc_id, version)) = id.split_once('@') {
+        return show_rfc_version(config, rfc_id, version, output, history);
+    }
     // [[RFC-0002:C-CRUD-VERBS]]: read-by-ID must error when no RFC exists for
     // the requested stable resource ID from [[RFC-0002:C-RESOURCES]].
     let path = find_rfc_toml(config, id).ok_or_else(|| {
         artifact_not_found(
             config,
             DiagnosticCode::E0102RfcNotFound,
-            "RFC",
+            CatalogKind::Rfc,
             id,
             config.rfc_dir(),
         )
@@ -105,7 +113,7 @@ pub fn show_rfc(
         return Err(artifact_not_found(
             config,
             DiagnosticCode::E0102RfcNotFound,
-            "RFC",
+            CatalogKind::Rfc,
             id,
             config.rfc_dir(),
         ));
@@ -133,13 +141,73 @@ pub fn show_rfc(
             } else {
                 None
             };
-            render_rfc_with_projection(&rfc, projection, superseded_by.as_deref())
+            let rfc = crate::fragments::expand_rfc(config, &rfc)?;
+            render_rfc_with_projection(&config.prefixes, &rfc, projection, superseded_by.as_deref())
         },
     )?;
 
     Ok(vec![])
 }
 
+/// Show a snapshot of an RFC version (`RFC-0001@1.1.0`).
+///
+/// Human output is the markdown frozen at that version; structured output is
+/// the RFC and clause set stored beside it.
+fn show_rfc_version(
+    config: &Config,
+    rfc_id: &str,
+    version: &str,
+    output: ShowOutputFormat,
+    history: bool,
+) -> DiagnosticResult<Diagnostics> {
+    let id = format!("{rfc_id}@{version}");
+    if find_rfc_toml(config, rfc_id).is_none() {
+        return Err(artifact_not_found(
+            config,
+            DiagnosticCode::E0102RfcNotFound,
+            CatalogKind::Rfc,
+            rfc_id,
+            config.rfc_dir(),
+        ));
+    }
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let read_snapshot = |extension: &str| {
+        let path = config.rfc_snapshot_path(rfc_id, version, extension);
+        std::fs::read_to_string(&path).map_err(|_| {
+            Diagnostic::new(
+                DiagnosticCode::E0124RfcSnapshotNotFound,
+                format!(
+                    "No snapshot of {rfc_id} at version {version} (hint: versions sealed with [snapshots] rfc_versions = true are snapshotted)"
+                ),
+                config.display_path(&path).display().to_string(),
+            )
+        })
+    };
+    let markdown = read_snapshot("md")?;
+    let structured: serde_json::Value =
+        serde_json::from_str(&read_snapshot("json")?).map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0101RfcSchemaInvalid,
+                format!("Invalid snapshot of {rfc_id} at version {version}: {err}"),
+                &id,
+            )
+        })?;
+
+    print_show_output(
+        config,
+        ShowOutputRequest {
+            output,
+            history,
+            structured_value: &structured,
+            structured_error_code: DiagnosticCode::E0101RfcSchemaInvalid,
+            structured_error_message: "Failed to serialize RFC snapshot",
+            id: &id,
+        },
+        |_| Ok(markdown),
+    )?;
+    Ok(vec![])
+}
+
 /// Show ADR content to stdout (no file written).
 ///
 /// Per [[RFC-0002:C-SHOW-PROJECTION]], human output defaults to the current
@@ -162,7 +230,10 @@ pub fn show_adr(
             structured_error_message: "Failed to serialize ADR structured output",
             id,
         },
-        |projection| render_adr_with_projection(&adr, projection),
+        |projection| {
+            let adr = crate::fragments::expand_adr(config, &adr)?;
+            render_adr_with_projection(&config.prefixes, &adr, projection)
+        },
     )?;
 
     Ok(vec![])
@@ -190,7 +261,7 @@ pub fn show_work(
             structured_error_message: "Failed to serialize work item structured output",
             id,
         },
-        |projection| render_work_item_with_projection(&item, projection),
+        |projection| render_work_item_with_projection(config, &item, projection),
     )?;
 
     Ok(vec![])
@@ -218,7 +289,7 @@ pub fn show_clause(
         artifact_not_found(
             config,
             DiagnosticCode::E0202ClauseNotFound,
-            "Clause",
+            CatalogKind::Clause,
             id,
             config.clause_dir(rfc_id),
         )
@@ -230,7 +301,7 @@ pub fn show_clause(
         return Err(artifact_not_found(
             config,
             DiagnosticCode::E0202ClauseNotFound,
-            "Clause",
+            CatalogKind::Clause,
             id,
             config.clause_dir(rfc_id),
         ));
@@ -248,6 +319,7 @@ pub fn show_clause(
         },
         |projection| {
             let mut raw = String::new();
+            let clause = crate::fragments::expand_clause(config, rfc_id, &clause)?;
             render_clause_with_projection(&mut raw, rfc_id, &clause, projection);
             Ok(raw)
         },
@@ -0,0 +1,212 @@
+//! Machine-readable command results for `--output json`.
+//!
+//! Commands that change files report through one envelope on stdout: overall
+//! status, the files and artifacts they touched, and every diagnostic. Human
+//! progress messages keep going to stderr.
+
+use crate::Persona;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticLevel};
+use crate::write::RecordedChanges;
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Serialize)]
+pub struct ResultEnvelope {
+    status: &'static str,
+    command: String,
+    dry_run: bool,
+    changes: Vec<ChangeEntry>,
+    diagnostics: Vec<DiagnosticEntry>,
+}
+
+#[derive(Serialize)]
+struct ChangeEntry {
+    /// Path relative to the project root.
+    path: String,
+    change: &'static str,
+    /// Artifact ID when the file is a governed artifact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    artifact: Option<String>,
+    /// Unified diff of the change a dry run would make, for text files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff: Option<String>,
+}
+
+#[derive(Serialize)]
+struct DiagnosticEntry {
+    code: &'static str,
+    level: &'static str,
+    message: String,
+    file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    /// Commands that fix the diagnostic, under `--for agent`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<String>,
+}
+
+impl ResultEnvelope {
+    pub fn new(
+        config: Option<&Config>,
+        command: String,
+        dry_run: bool,
+        changes: RecordedChanges,
+        diagnostics: &[Diagnostic],
+        persona: Persona,
+    ) -> Self {
+        let failed = diagnostics
+            .iter()
+            .any(|diag| diag.level == DiagnosticLevel::Error);
+        Self {
+            status: if failed { "error" } else { "ok" },
+            command,
+            dry_run,
+            changes: config
+                .map(|config| {
+                    if dry_run {
+                        preview_entries(config, changes)
+                    } else {
+                        change_entries(config, changes)
+                    }
+                })
+                .unwrap_or_default(),
+            diagnostics: diagnostics
+                .iter()
+                .map(|diag| DiagnosticEntry::new(diag, persona))
+                .collect(),
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+        );
+    }
+}
+
+impl DiagnosticEntry {
+    fn new(diag: &Diagnostic, persona: Persona) -> Self {
+        Self {
+            code: diag.code.code(),
+            level: match diag.level {
+                DiagnosticLevel::Error => "error",
+                DiagnosticLevel::Warning => "warning",
+                DiagnosticLevel::Info => "info",
+            },
+            message: diag.message.clone(),
+            file: diag.file.clone(),
+            line: diag.span.map(|span| span.line),
+            column: diag.span.map(|span| span.column),
+            fixes: if persona.is_agent() {
+                diag.corrective_commands()
+            } else {
+                vec![]
+            },
+        }
+    }
+}
+
+fn change_entries(config: &Config, changes: RecordedChanges) -> Vec<ChangeEntry> {
+    let root = config.project_root();
+    changes
+        .files
+        .into_iter()
+        // Undo bookkeeping is not part of the command's result.
+        .filter(|file| !file.path.starts_with(root.join(".govctl").join("history")))
+        .filter_map(|file| {
+            let after = std::fs::read(&file.path).ok();
+            let change = match (&file.before, &after) {
+                (None, None) => return None,
+                (None, Some(_)) => "created",
+                (Some(_), None) => "deleted",
+                (Some(before), Some(after)) if before == after => return None,
+                (Some(_), Some(_)) => "modified",
+            };
+            let content = after.as_deref().or(file.before.as_deref());
+            Some(ChangeEntry {
+                path: relative_path(root, &file.path),
+                change,
+                artifact: content.and_then(|content| artifact_id(config, &file.path, content)),
+                diff: None,
+            })
+        })
+        .collect()
+}
+
+/// What a dry run would have changed, with the diff of each file.
+fn preview_entries(config: &Config, changes: RecordedChanges) -> Vec<ChangeEntry> {
+    let root = config.project_root();
+    changes
+        .previews
+        .into_iter()
+        .filter_map(|file| {
+            let change = match (&file.before, &file.after) {
+                (None, None) => return None,
+                (None, Some(_)) => "created",
+                (Some(_), None) => "deleted",
+                (Some(before), Some(after)) if before == after => return None,
+                (Some(_), Some(_)) => "modified",
+            };
+            let content = file.after.as_deref().or(file.before.as_deref());
+            Some(ChangeEntry {
+                path: relative_path(root, &file.path),
+                change,
+                artifact: content.and_then(|content| artifact_id(config, &file.path, content)),
+                diff: file.diff,
+            })
+        })
+        .collect()
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// The `[govctl] id` of a governed TOML artifact, qualified for clauses.
+fn artifact_id(config: &Config, path: &Path, content: &[u8]) -> Option<String> {
+    let relative = path.strip_prefix(&config.gov_root).ok()?;
+    if path.extension()? != "toml" || relative.starts_with(".trash") {
+        return None;
+    }
+    let value: toml::Value = toml::from_str(std::str::from_utf8(content).ok()?).ok()?;
+    let id = value.get("govctl")?.get("id")?.as_str()?;
+    let mut components = relative.components().map(|c| c.as_os_str());
+    match (
+        components.next()?.to_str()?,
+        components.next(),
+        components.next(),
+    ) {
+        ("rfc", Some(rfc_id), Some(dir)) if dir == "clauses" => {
+            Some(format!("{}:{id}", rfc_id.to_str()?))
+        }
+        _ => Some(id.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::DiagnosticCode;
+
+    #[test]
+    fn test_fixes_are_listed_for_the_agent_persona_only() {
+        let diag = Diagnostic::new(
+            DiagnosticCode::E1405MappingNotFound,
+            "Standard 'SOC2' has no control 'CC1.1'",
+            "gov/mapping/soc2.toml",
+        );
+
+        let agent = DiagnosticEntry::new(&diag, Persona::Agent);
+        let human = DiagnosticEntry::new(&diag, Persona::Human);
+
+        assert_eq!(agent.fixes, vec!["govctl mapping list".to_string()]);
+        assert!(human.fixes.is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+//! `govctl scan coverage`: which normative clauses source code implements.
+//!
+//! Counts `Implements [[RFC-NNNN:C-NAME]]` annotations in the files selected
+//! by `[source_scan]`, per [[ADR-0009]].
+
+use crate::OutputFormat;
+use crate::cmd::output::{print_json_array, table_with_bold_headers};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project;
+use crate::model::{ClauseKind, ClauseStatus, ProjectIndex, RfcStatus};
+use crate::scan::{RefMatcher, ScannedFile, scan_files};
+use crate::theme::SemanticColor;
+use crate::ui::stdout_supports_color;
+use comfy_table::Cell;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+/// "Implements" earlier on the line marks a reference as an implementation.
+static IMPLEMENTS: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r"(?i)\bimplements\b"));
+
+#[derive(Serialize)]
+struct ClauseCoverage {
+    clause: String,
+    title: String,
+    count: usize,
+    /// `path:line` of each annotation
+    locations: Vec<String>,
+}
+
+/// Print per-clause annotation counts and warn for each uncovered clause.
+pub fn scan_coverage(
+    config: &Config,
+    rfc: Option<&str>,
+    output: OutputFormat,
+) -> DiagnosticResult<Diagnostics> {
+    let index = load_project(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for coverage",
+                "scan coverage",
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    if let Some(rfc_id) = rfc
+        && !index.rfcs.iter().any(|entry| entry.rfc.rfc_id == rfc_id)
+    {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0102RfcNotFound,
+            format!("RFC not found: {rfc_id}"),
+            rfc_id,
+        ));
+    }
+
+    let matcher = RefMatcher::new(config, &index)?;
+    let files = scan_files(config)?;
+    let mut locations = implementation_locations(&files, &matcher);
+
+    let mut entries = Vec::new();
+    let mut diagnostics = Vec::new();
+    for (clause_id, title, path) in normative_clauses(config, &index, rfc) {
+        let found = locations.remove(&clause_id).unwrap_or_default();
+        if found.is_empty() {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticCode::W0122ClauseUncovered,
+                format!(
+                    "Normative clause {clause_id} has no `Implements` annotation in scanned files"
+                ),
+                path,
+            ));
+        }
+        entries.push(ClauseCoverage {
+            clause: clause_id,
+            title,
+            count: found.len(),
+            locations: found,
+        });
+    }
+
+    print_coverage(&entries, output);
+    Ok(diagnostics)
+}
+
+/// Active normative clauses of RFCs that are not deprecated, with their
+/// titles and display paths.
+fn normative_clauses(
+    config: &Config,
+    index: &ProjectIndex,
+    rfc: Option<&str>,
+) -> Vec<(String, String, String)> {
+    index
+        .rfcs
+        .iter()
+        .filter(|entry| entry.rfc.status != RfcStatus::Deprecated)
+        .filter(|entry| rfc.is_none_or(|rfc_id| entry.rfc.rfc_id == rfc_id))
+        .flat_map(|entry| {
+            entry
+                .clauses
+                .iter()
+                .filter(|clause| {
+                    clause.spec.kind == ClauseKind::Normative
+                        && clause.spec.status == ClauseStatus::Active
+                })
+                .map(|clause| {
+                    (
+                        format!("{}:{}", entry.rfc.rfc_id, clause.spec.clause_id),
+                        clause.spec.title.clone(),
+                        config.display_path(&clause.path).display().to_string(),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// `path:line` of every `Implements [[...]]` reference, by artifact ID.
+/// Anchors count for their clause.
+fn implementation_locations(
+    files: &[ScannedFile],
+    matcher: &RefMatcher,
+) -> BTreeMap<String, Vec<String>> {
+    let mut locations: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let Ok(implements) = IMPLEMENTS.as_ref() else {
+        return locations;
+    };
+    for file in files {
+        for found in matcher.refs(file) {
+            let (offset, Some(id)) = (found.offset, found.target) else {
+                continue;
+            };
+            let line_start = file.content[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+            if !implements.is_match(&file.content[line_start..offset]) {
+                continue;
+            }
+            locations.entry(id).or_default().push(format!(
+                "{}:{}",
+                file.path,
+                file.line_of(offset)
+            ));
+        }
+    }
+    locations
+}
+
+fn print_coverage(entries: &[ClauseCoverage], output: OutputFormat) {
+    match output {
+        OutputFormat::Json => print_json_array(entries),
+        OutputFormat::Plain => {
+            for entry in entries {
+                println!(
+                    "{}\t{}\t{}",
+                    entry.clause,
+                    entry.count,
+                    entry.locations.join(",")
+                );
+            }
+        }
+        OutputFormat::Table => {
+            let colors = stdout_supports_color();
+            let mut table = table_with_bold_headers(&["Clause", "Title", "Count", "Locations"]);
+            for entry in entries {
+                let count = Cell::new(entry.count.to_string());
+                table.add_row(vec![
+                    Cell::new(&entry.clause),
+                    Cell::new(&entry.title),
+                    if colors && entry.count == 0 {
+                        count.fg(SemanticColor::Warning.to_comfy())
+                    } else {
+                        count
+                    },
+                    Cell::new(entry.locations.join("\n")),
+                ]);
+            }
+            println!("{table}");
+            let covered = entries.iter().filter(|entry| entry.count > 0).count();
+            println!(
+                "{covered}/{} normative clauses have implementations",
+                entries.len()
+            );
+        }
+    }
+}
@@ -626,7 +626,7 @@ fn clause_document(config: &Config, record: &CatalogRecord, entry: &ClauseEntry)
         clause.title.clone(),
         clause.kind.as_ref().to_string(),
         clause.status.as_ref().to_string(),
-        clause.text.clone(),
+        clause.text.clone().unwrap_or_default(),
     ];
     parts.extend(clause.anchors.iter().cloned());
     parts.extend(clause.tags.iter().cloned());
@@ -659,6 +659,8 @@ fn adr_document(config: &Config, entry: &AdrEntry) -> SearchDocument {
         content.decision.clone(),
         content.consequences.clone(),
     ];
+    parts.extend(content.drivers.iter().cloned());
+    parts.extend(content.stakeholders.iter().cloned());
     parts.extend(meta.refs.iter().cloned());
     parts.extend(meta.tags.iter().cloned());
     if let Some(superseded_by) = &meta.superseded_by {
@@ -2,7 +2,7 @@
 
 use crate::config::Config;
 use crate::diagnostic::{DiagnosticResult, Diagnostics};
-use crate::load::load_project;
+use crate::load::load_project_metadata;
 use crate::model::{AdrStatus, ClauseStatus, RfcPhase, RfcStatus, WorkItemEntry, WorkItemStatus};
 use crate::status_counts::{StatusCounts, count_by, count_for, total_count};
 use crate::theme::status_semantic;
@@ -156,7 +156,7 @@ impl StatusPrinter {
 
 /// Show summary status
 pub fn show_status(config: &Config) -> DiagnosticResult<Diagnostics> {
-    let index = match load_project(config) {
+    let index = match load_project_metadata(config) {
         Ok(idx) => idx,
         Err(diags) => return Ok(diags),
     };
@@ -0,0 +1,80 @@
+//! `govctl supersede chain`: the supersession chain an artifact belongs to.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::load_project_metadata;
+use crate::validate::SupersessionGraph;
+use owo_colors::OwoColorize;
+
+/// Print the chain through `id`, oldest first, with each artifact's status.
+/// Forks are listed under the artifact that has more than one link.
+pub fn supersede_chain(config: &Config, id: &str) -> DiagnosticResult<Diagnostics> {
+    let index = load_project_metadata(config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                "Failed to load project for supersession chain",
+                id,
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    let graph = SupersessionGraph::build(&index, config);
+    if !graph.nodes.contains_key(id) {
+        return Err(not_found(config, id));
+    }
+
+    let (chain, cycle) = graph.chain(id);
+    let mut line = chain.join(" → ");
+    if cycle {
+        line.push_str(" → … (cycle)");
+    }
+    println!("{line}");
+
+    let colors = crate::ui::stdout_supports_color();
+    let width = chain.iter().map(String::len).max().unwrap_or_default();
+    for (position, member) in chain.iter().enumerate() {
+        let Some(node) = graph.nodes.get(member) else {
+            continue;
+        };
+        let role = match position {
+            _ if cycle || chain.len() == 1 => "",
+            0 => " (oldest)",
+            _ if position + 1 == chain.len() => " (head)",
+            _ => "",
+        };
+        let status = if !colors {
+            node.status.clone()
+        } else if node.active {
+            node.status.green().to_string()
+        } else {
+            node.status.yellow().to_string()
+        };
+        println!("  {member:<width$}  {status}{role}");
+        for other in graph.successors(member).iter().skip(1) {
+            println!("    also superseded by {other}");
+        }
+        for other in graph.predecessors(member).iter().skip(1) {
+            println!("    also supersedes {other}");
+        }
+    }
+    Ok(vec![])
+}
+
+fn not_found(config: &Config, id: &str) -> Diagnostic {
+    let (code, kind) = if id.contains(':') {
+        (DiagnosticCode::E0202ClauseNotFound, "Clause")
+    } else if config.prefixes.is_adr(id) {
+        (DiagnosticCode::E0302AdrNotFound, "ADR")
+    } else if config.prefixes.is_rfc(id) {
+        (DiagnosticCode::E0102RfcNotFound, "RFC")
+    } else {
+        return Diagnostic::new(
+            DiagnosticCode::E0813SupersedeNotSupported,
+            format!("Supersession chains cover RFCs, clauses, and ADRs: {id}"),
+            id,
+        );
+    };
+    Diagnostic::new(code, format!("{kind} not found: {id}"), id)
+}
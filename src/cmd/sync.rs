@@ -0,0 +1,134 @@
+//! `govctl sync jira`: pull the status of each work item's linked Jira issue.
+//!
+//! A status the item has not seen before is recorded as a dated note, so the
+//! work item keeps a history of the issue's workflow. Status categories that
+//! disagree with the work item's own status are reported as `W0124` warnings;
+//! sync never moves a work item.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::jira::{Jira, StatusCategory};
+use crate::model::{WorkItemEntry, WorkItemStatus};
+use crate::parse::{load_work_items, write_work_item};
+use crate::ui;
+use crate::write::{WriteOp, today, with_file_transaction};
+use std::path::Path;
+
+/// Sync `ids`, or every work item with a `jira_key` when empty.
+pub fn sync_jira(config: &Config, ids: &[String], op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    let settings = config.jira.as_ref().ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0513PublishNotConfigured,
+            "No [jira] section in config (set base_url to sync)",
+            config
+                .display_path(&config.gov_root.join("config.toml"))
+                .display()
+                .to_string(),
+        )
+    })?;
+    let items = load_work_items(config)?;
+    let linked = linked_items(&items, ids)?;
+    let jira = Jira::from_env(settings)?;
+
+    let mut warnings = Vec::new();
+    let mut updated = Vec::new();
+    for (item, key) in linked {
+        let status = jira.issue_status(key)?;
+        let meta = item.meta();
+        if !category_matches(status.category, meta.status) {
+            warnings.push(Diagnostic::new(
+                DiagnosticCode::W0124JiraStatusDiverged,
+                format!(
+                    "{} is {} but Jira {key} is '{}'",
+                    meta.id,
+                    meta.status.as_ref(),
+                    status.name
+                ),
+                meta.id.as_str(),
+            ));
+        }
+        if last_recorded_status(&item.spec.content.notes, key) != Some(status.name.as_str()) {
+            let mut spec = item.spec.clone();
+            spec.content.notes.push(format!(
+                "Jira {key} status: {} (as of {})",
+                status.name,
+                today()
+            ));
+            updated.push((item, spec));
+        }
+    }
+
+    let paths: Vec<&Path> = updated
+        .iter()
+        .map(|(item, _)| item.path.as_path())
+        .collect();
+    with_file_transaction(&paths, op, |op| {
+        for (item, spec) in &updated {
+            write_work_item(&item.path, spec, op, Some(&config.display_path(&item.path)))?;
+        }
+        Ok(())
+    })?;
+
+    if !op.is_preview() {
+        ui::success(format!(
+            "Recorded Jira status changes on {} work item(s)",
+            updated.len()
+        ));
+    }
+    Ok(warnings)
+}
+
+/// Work items to sync, each with its issue key.
+fn linked_items<'a>(
+    items: &'a [WorkItemEntry],
+    ids: &[String],
+) -> DiagnosticResult<Vec<(&'a WorkItemEntry, &'a str)>> {
+    if ids.is_empty() {
+        return Ok(items
+            .iter()
+            .filter_map(|item| Some((item, item.meta().jira_key.as_deref()?)))
+            .collect());
+    }
+    ids.iter()
+        .map(|id| {
+            let item = items
+                .iter()
+                .find(|item| &item.meta().id == id)
+                .ok_or_else(|| {
+                    Diagnostic::new(
+                        DiagnosticCode::E0402WorkNotFound,
+                        format!("Work item not found: {id}"),
+                        id,
+                    )
+                })?;
+            let key = item.meta().jira_key.as_deref().ok_or_else(|| {
+                Diagnostic::new(
+                    DiagnosticCode::E0812FieldEmpty,
+                    format!("{id} has no jira_key (hint: govctl work link {id} --jira PROJ-123)"),
+                    id,
+                )
+            })?;
+            Ok((item, key))
+        })
+        .collect()
+}
+
+fn category_matches(category: StatusCategory, status: WorkItemStatus) -> bool {
+    match category {
+        StatusCategory::ToDo => status == WorkItemStatus::Queue,
+        StatusCategory::InProgress => status == WorkItemStatus::Active,
+        StatusCategory::Done => {
+            matches!(status, WorkItemStatus::Done | WorkItemStatus::Cancelled)
+        }
+        StatusCategory::Other => true,
+    }
+}
+
+/// The status named by the newest sync note for `key`.
+fn last_recorded_status<'a>(notes: &'a [String], key: &str) -> Option<&'a str> {
+    let prefix = format!("Jira {key} status: ");
+    notes.iter().rev().find_map(|note| {
+        let (status, _) = note.strip_prefix(&prefix)?.rsplit_once(" (as of ")?;
+        Some(status)
+    })
+}
@@ -11,6 +11,7 @@ use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
 use crate::load::load_rfcs;
 use crate::parse::{load_adrs, load_guards_with_warnings, load_work_items};
+use crate::ui;
 use comfy_table::Cell;
 use registry::{
     get_allowed_tags, has_allowed_tag, read_config_table, set_allowed_tags, validate_tag_format,
@@ -49,10 +50,10 @@ pub fn tag_new(
     set_allowed_tags(&mut table, allowed)?;
 
     if !op.is_preview() {
-        write_config_table(config, &table)?;
-        println!("Added tag: {tag}");
+        write_config_table(config, &table, op)?;
+        ui::info(format!("Added tag: {tag}"));
     } else {
-        println!("Would add tag: {tag}");
+        ui::info(format!("Would add tag: {tag}"));
     }
     Ok(vec![])
 }
@@ -92,10 +93,10 @@ pub fn tag_delete(
     set_allowed_tags(&mut table, new_allowed)?;
 
     if !op.is_preview() {
-        write_config_table(config, &table)?;
-        println!("Deleted tag: {tag}");
+        write_config_table(config, &table, op)?;
+        ui::info(format!("Deleted tag: {tag}"));
     } else {
-        println!("Would delete tag: {tag}");
+        ui::info(format!("Would delete tag: {tag}"));
     }
     Ok(vec![])
 }
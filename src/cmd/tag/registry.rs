@@ -1,5 +1,6 @@
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::write::{WriteOp, write_file};
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -109,7 +110,11 @@ pub(super) fn read_config_table(config: &Config) -> DiagnosticResult<toml::Table
 }
 
 /// Write a modified TOML table back to config.toml.
-pub(super) fn write_config_table(config: &Config, table: &toml::Table) -> DiagnosticResult<()> {
+pub(super) fn write_config_table(
+    config: &Config,
+    table: &toml::Table,
+    op: WriteOp,
+) -> DiagnosticResult<()> {
     let config_path = config.gov_root.join("config.toml");
     let content = toml::to_string_pretty(table).map_err(|err| {
         Diagnostic::new(
@@ -118,10 +123,12 @@ pub(super) fn write_config_table(config: &Config, table: &toml::Table) -> Diagno
             config_path.display().to_string(),
         )
     })?;
-    std::fs::write(&config_path, content).map_err(|err| {
-        Diagnostic::io_error("write config", err, config_path.display().to_string())
-    })?;
-    Ok(())
+    write_file(
+        &config_path,
+        &content,
+        op,
+        Some(&config.display_path(&config_path)),
+    )
 }
 
 /// Get the current allowed tags array from a TOML table.
@@ -0,0 +1,352 @@
+//! Project template packing and unpacking.
+//!
+//! A project template is a compressed tar archive of the governance files
+//! that define a project's conventions: `config.toml`, artifact templates,
+//! verification guards, and the seed RFC (number 0 under the project's RFC
+//! prefix, `RFC-0000` by default). `govctl init --from` replays
+//! it into a new project so many repositories can share one baseline.
+//!
+//! Archives are zstd-compressed (`.tar.zst`) or, for tools without zstd,
+//! gzip-compressed (`.tar.gz`, `.tgz`); `pack` picks the codec from the
+//! output name and reading recognizes either from the archive's magic bytes.
+
+use crate::config::{Config, PrefixesConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::ui;
+use crate::write::{WriteOp, create_dir_all, write_file, write_file_bytes};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+const MANIFEST_NAME: &str = "govctl-template.toml";
+const TEMPLATE_FORMAT: u32 = 1;
+const CONFIG_FILE: &str = "config.toml";
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Compression of a template archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Codec {
+    Zstd,
+    Gzip,
+}
+
+impl Codec {
+    /// The codec named by an archive's file name.
+    fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".tar.zst") {
+            Some(Self::Zstd)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::Gzip)
+        } else {
+            None
+        }
+    }
+
+    /// The codec an archive was written with, from its first bytes.
+    fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&ZSTD_MAGIC) {
+            Some(Self::Zstd)
+        } else if bytes.starts_with(&GZIP_MAGIC) {
+            Some(Self::Gzip)
+        } else {
+            None
+        }
+    }
+
+    fn compress(self, tar: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Zstd => zstd::encode_all(tar, zstd::DEFAULT_COMPRESSION_LEVEL),
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(tar)?;
+                encoder.finish()
+            }
+        }
+    }
+
+    fn decompress(self, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Zstd => zstd::decode_all(bytes),
+            Self::Gzip => {
+                let mut tar = Vec::new();
+                GzDecoder::new(bytes).read_to_end(&mut tar)?;
+                Ok(tar)
+            }
+        }
+    }
+}
+
+/// Archive manifest, stored as the first entry.
+#[derive(Debug, Serialize, Deserialize)]
+struct TemplateManifest {
+    format: u32,
+    govctl_version: String,
+    files: Vec<String>,
+}
+
+/// A validated template: gov-root-relative paths and their text content.
+#[derive(Debug)]
+pub(crate) struct ProjectTemplate {
+    files: Vec<(String, String)>,
+}
+
+impl ProjectTemplate {
+    pub(crate) fn config(&self) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(path, _)| path == CONFIG_FILE)
+            .map(|(_, content)| content.as_str())
+    }
+
+    /// Write every file except `config.toml` under the gov root.
+    pub(crate) fn install(&self, config: &Config, op: WriteOp) -> DiagnosticResult<()> {
+        for (rel_path, content) in &self.files {
+            if rel_path == CONFIG_FILE {
+                continue;
+            }
+            let path = config.gov_root.join(rel_path);
+            if let Some(parent) = path.parent() {
+                create_dir_all(parent, op, Some(&config.display_path(parent)))?;
+            }
+            let display_path = config.display_path(&path);
+            write_file(&path, content, op, Some(&display_path))?;
+            if !op.is_preview() {
+                ui::created_path(&display_path);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pack the current project's governance baseline into a template archive.
+pub fn pack(config: &Config, out: &Path, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    let config_path = config.gov_root.join(CONFIG_FILE);
+    if !config_path.exists() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0501ConfigInvalid,
+            format!(
+                "{} not found; run `govctl init` first",
+                config.display_path(&config_path).display()
+            ),
+            config.display_path(&config_path).display().to_string(),
+        ));
+    }
+    let codec = Codec::from_path(out).ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0506TemplateInvalid,
+            format!(
+                "Unsupported template archive name: {} (use .tar.zst, .tar.gz, or .tgz)",
+                out.display()
+            ),
+            out.display().to_string(),
+        )
+    })?;
+
+    let mut files = vec![CONFIG_FILE.to_string()];
+    for dir in [
+        config.templates_dir(),
+        config.guard_dir(),
+        config.rfc_artifact_dir(&seed_rfc_id(&config.prefixes)),
+    ] {
+        files.extend(collect_files(config, &dir)?);
+    }
+
+    let manifest = TemplateManifest {
+        format: TEMPLATE_FORMAT,
+        govctl_version: env!("CARGO_PKG_VERSION").to_string(),
+        files,
+    };
+    let archive = build_archive(config, &manifest, codec).map_err(|err| {
+        Diagnostic::io_error("build template archive", err, out.display().to_string())
+    })?;
+
+    let display_path = config.display_path(out);
+    write_file_bytes(out, &archive, op, Some(&display_path))?;
+    if !op.is_preview() {
+        ui::created("template", &display_path);
+        ui::sub_info(format!("{} files", manifest.files.len()));
+    }
+    Ok(vec![])
+}
+
+/// Read and validate a template archive produced by [`pack`].
+pub(crate) fn read_template(archive: &Path) -> DiagnosticResult<ProjectTemplate> {
+    let scope = archive.display().to_string();
+    let bytes = std::fs::read(archive)
+        .map_err(|err| Diagnostic::io_error("read template", err, scope.clone()))?;
+    let invalid = |message: String| {
+        Diagnostic::new(DiagnosticCode::E0506TemplateInvalid, message, scope.clone())
+    };
+
+    let codec = Codec::sniff(&bytes).ok_or_else(|| {
+        invalid("Not a govctl template archive: expected zstd or gzip compression".to_string())
+    })?;
+    let tar_bytes = codec
+        .decompress(&bytes)
+        .map_err(|err| invalid(format!("Not a govctl template archive: {err}")))?;
+    let mut tar = tar::Archive::new(tar_bytes.as_slice());
+    let entries = tar
+        .entries()
+        .map_err(|err| invalid(format!("Not a govctl template archive: {err}")))?;
+
+    let mut manifest: Option<TemplateManifest> = None;
+    let mut files = Vec::new();
+    for entry in entries {
+        let mut entry =
+            entry.map_err(|err| invalid(format!("Not a govctl template archive: {err}")))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let rel_path = entry
+            .path()
+            .map_err(|err| invalid(format!("Invalid entry path: {err}")))?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|err| invalid(format!("Entry {rel_path} is not UTF-8 text: {err}")))?;
+
+        if rel_path == MANIFEST_NAME {
+            let parsed: TemplateManifest = toml::from_str(&content)
+                .map_err(|err| invalid(format!("Invalid {MANIFEST_NAME}: {err}")))?;
+            if parsed.format > TEMPLATE_FORMAT {
+                return Err(invalid(format!(
+                    "Template format {} requires a newer govctl (supported: {TEMPLATE_FORMAT})",
+                    parsed.format
+                )));
+            }
+            manifest = Some(parsed);
+            continue;
+        }
+        files.push((rel_path, content));
+    }
+
+    let manifest = manifest.ok_or_else(|| invalid(format!("Missing {MANIFEST_NAME}")))?;
+    let prefixes = template_prefixes(&files).map_err(|err| {
+        invalid(format!(
+            "Invalid [prefixes] in template {CONFIG_FILE}: {}",
+            err.message
+        ))
+    })?;
+    for (rel_path, _) in &files {
+        if !is_allowed_entry(rel_path, &prefixes) {
+            return Err(invalid(format!(
+                "Template entry is outside the governance baseline: {rel_path}"
+            )));
+        }
+        if !manifest.files.contains(rel_path) {
+            return Err(invalid(format!(
+                "Template entry not listed in manifest: {rel_path}"
+            )));
+        }
+    }
+    Ok(ProjectTemplate { files })
+}
+
+fn collect_files(config: &Config, dir: &Path) -> DiagnosticResult<Vec<String>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut files = Vec::new();
+    for entry in WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry.map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0901IoError,
+                format!("Failed to read template source: {err}"),
+                config.display_path(dir).display().to_string(),
+            )
+        })?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if let Ok(rel_path) = entry.path().strip_prefix(&config.gov_root) {
+            files.push(rel_path_string(rel_path));
+        }
+    }
+    Ok(files)
+}
+
+fn rel_path_string(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn build_archive(
+    config: &Config,
+    manifest: &TemplateManifest,
+    codec: Codec,
+) -> std::io::Result<Vec<u8>> {
+    let manifest_toml = toml::to_string_pretty(manifest).map_err(std::io::Error::other)?;
+    let mut builder = tar::Builder::new(Vec::new());
+    append_entry(&mut builder, MANIFEST_NAME, manifest_toml.as_bytes())?;
+    for rel_path in &manifest.files {
+        let content = std::fs::read(config.gov_root.join(rel_path))?;
+        append_entry(&mut builder, rel_path, &content)?;
+    }
+    codec.compress(&builder.into_inner()?)
+}
+
+fn append_entry<W: std::io::Write>(
+    builder: &mut tar::Builder<W>,
+    path: &str,
+    content: &[u8],
+) -> std::io::Result<()> {
+    // Fixed metadata keeps archives byte-identical for identical inputs.
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(0);
+    header.set_cksum();
+    builder.append_data(&mut header, path, content)
+}
+
+/// The seed RFC packed with the baseline: number 0 under the RFC prefix.
+fn seed_rfc_id(prefixes: &PrefixesConfig) -> String {
+    prefixes.rfc_id(0)
+}
+
+/// The `[prefixes]` of the template's own `config.toml`, which decide where
+/// its seed RFC lives.
+fn template_prefixes(files: &[(String, String)]) -> DiagnosticResult<PrefixesConfig> {
+    #[derive(Deserialize)]
+    struct TemplateConfig {
+        #[serde(default)]
+        prefixes: PrefixesConfig,
+    }
+
+    let Some((_, content)) = files.iter().find(|(path, _)| path == CONFIG_FILE) else {
+        return Ok(PrefixesConfig::default());
+    };
+    let parsed: TemplateConfig = toml::from_str(content).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0506TemplateInvalid,
+            err.message().to_string(),
+            CONFIG_FILE,
+        )
+    })?;
+    parsed.prefixes.validate()?;
+    Ok(parsed.prefixes)
+}
+
+fn is_allowed_entry(rel_path: &str, prefixes: &PrefixesConfig) -> bool {
+    let path = PathBuf::from(rel_path);
+    if !path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return false;
+    }
+    rel_path == CONFIG_FILE
+        || rel_path.starts_with("templates/")
+        || rel_path.starts_with("guard/")
+        || rel_path.starts_with(&format!("rfc/{}/", seed_rfc_id(prefixes)))
+}
@@ -0,0 +1,270 @@
+//! Trash area for deleted clauses and work items, and `govctl restore`.
+//!
+//! `delete` moves the artifact file into `gov/.trash/<ID>/` next to a
+//! `tombstone.toml` recording where it came from, so the deletion is reviewed
+//! and committed like any other change and can be reversed later.
+
+use crate::cmd::edit::{ArtifactType, clause_in_open_revision};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::load::split_clause_id;
+use crate::model::{RfcStatus, SectionSpec};
+use crate::parse::load_work_items;
+use crate::ui;
+use crate::write::{
+    WriteOp, create_dir_all, delete_file, read_clause, read_rfc, today, with_transaction,
+    write_file, write_file_bytes, write_rfc,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const TOMBSTONE_FILE: &str = "tombstone.toml";
+
+/// Where a trashed artifact came from.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Tombstone {
+    pub id: String,
+    /// Date the artifact was deleted.
+    pub deleted: String,
+    /// Original path, relative to the gov root.
+    pub path: String,
+    /// RFC section the clause was listed under.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+}
+
+impl Tombstone {
+    pub(crate) fn new(config: &Config, id: &str, source: &Path, section: Option<String>) -> Self {
+        let path = source
+            .strip_prefix(&config.gov_root)
+            .unwrap_or(source)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        Self {
+            id: id.to_string(),
+            deleted: today(),
+            path,
+            section,
+        }
+    }
+}
+
+/// Move `source` into the trash with its tombstone, replacing any older
+/// trash entry for the same ID.
+pub(crate) fn move_to_trash(
+    config: &Config,
+    tombstone: &Tombstone,
+    source: &Path,
+    op: WriteOp,
+) -> DiagnosticResult<()> {
+    let dir = entry_dir(config, &tombstone.id);
+    let file_name = source.file_name().ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0814InvalidPath,
+            format!("Invalid artifact path: {}", source.display()),
+            tombstone.id.as_str(),
+        )
+    })?;
+    let content = std::fs::read(source).map_err(|err| {
+        Diagnostic::io_error(
+            "read artifact",
+            err,
+            config.display_path(source).display().to_string(),
+        )
+    })?;
+    let body = toml::to_string_pretty(tombstone).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Failed to serialize tombstone: {err}"),
+            tombstone.id.as_str(),
+        )
+    })?;
+
+    clear_entry(config, &dir, op)?;
+    create_dir_all(&dir, op, Some(&config.display_path(&dir)))?;
+    let trashed = dir.join(file_name);
+    write_file_bytes(&trashed, &content, op, Some(&config.display_path(&trashed)))?;
+    let tombstone_path = dir.join(TOMBSTONE_FILE);
+    write_file(
+        &tombstone_path,
+        &body,
+        op,
+        Some(&config.display_path(&tombstone_path)),
+    )?;
+    delete_file(source, op, Some(&config.display_path(source)))
+}
+
+/// Bring a trashed clause or work item back to where it was deleted from.
+pub fn restore(config: &Config, id: &str, op: WriteOp) -> DiagnosticResult<Diagnostics> {
+    let not_found = match ArtifactType::from_id(&config.prefixes, id) {
+        Some(ArtifactType::Clause) => DiagnosticCode::E0202ClauseNotFound,
+        Some(ArtifactType::WorkItem) => DiagnosticCode::E0402WorkNotFound,
+        _ => {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0822UnsupportedOperation,
+                "restore supports clauses and work items",
+                id,
+            ));
+        }
+    };
+
+    let dir = entry_dir(config, id);
+    let tombstone_path = dir.join(TOMBSTONE_FILE);
+    let body = std::fs::read_to_string(&tombstone_path).map_err(|_| {
+        Diagnostic::new(
+            not_found,
+            format!("{id} is not in the trash"),
+            config.display_path(&dir).display().to_string(),
+        )
+    })?;
+    let tombstone: Tombstone = toml::from_str(&body).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Invalid tombstone: {err}"),
+            config.display_path(&tombstone_path).display().to_string(),
+        )
+    })?;
+
+    let target = config.gov_root.join(&tombstone.path);
+    let trashed = target
+        .file_name()
+        .map(|name| dir.join(name))
+        .ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E0814InvalidPath,
+                format!("Invalid tombstone path: {}", tombstone.path),
+                id,
+            )
+        })?;
+    if target.exists() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0822UnsupportedOperation,
+            format!(
+                "Cannot restore {id}: {} already exists",
+                config.display_path(&target).display()
+            ),
+            id,
+        ));
+    }
+
+    with_transaction(op, |op| {
+        match ArtifactType::from_id(&config.prefixes, id) {
+            Some(ArtifactType::Clause) => {
+                relink_clause(config, id, &tombstone, &trashed, op)?;
+            }
+            _ => ensure_work_id_free(config, id)?,
+        }
+        let content = std::fs::read(&trashed).map_err(|err| {
+            Diagnostic::io_error(
+                "read trashed artifact",
+                err,
+                config.display_path(&trashed).display().to_string(),
+            )
+        })?;
+        write_file_bytes(&target, &content, op, Some(&config.display_path(&target)))?;
+        clear_entry(config, &dir, op)
+    })?;
+
+    if !op.is_preview() {
+        // The empty entry directory goes last, once nothing can roll back into it.
+        let _ = std::fs::remove_dir(&dir);
+        ui::success(format!("Restored {id}"));
+        ui::sub_info(config.display_path(&target).display());
+    }
+    Ok(vec![])
+}
+
+/// List the clause under its original section again.
+///
+/// The RFC must still accept the clause the way `delete` required it to.
+fn relink_clause(
+    config: &Config,
+    id: &str,
+    tombstone: &Tombstone,
+    trashed: &Path,
+    op: WriteOp,
+) -> DiagnosticResult<()> {
+    let (rfc_id, _) = split_clause_id(id).ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0210ClauseInvalidIdFormat,
+            "Invalid clause ID format. Expected RFC-NNNN:C-NAME",
+            id,
+        )
+    })?;
+    let rfc_path = config.rfc_source_path(rfc_id, "toml");
+    if !rfc_path.exists() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0102RfcNotFound,
+            format!("RFC not found: {rfc_id}"),
+            id,
+        ));
+    }
+    let mut rfc = read_rfc(config, &rfc_path)?;
+    let clause = read_clause(config, trashed)?;
+    if rfc.status == RfcStatus::Deprecated || !clause_in_open_revision(&rfc, &clause) {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0104RfcInvalidTransition,
+            format!(
+                "Cannot restore clause into {rfc_id} while status={}, phase={}, version={}. Restoring is limited to draft RFCs or Clauses introduced in the current normative spec candidate.",
+                rfc.status.as_ref(),
+                rfc.phase.as_ref(),
+                rfc.version,
+            ),
+            id,
+        ));
+    }
+
+    let clause_rel_path = tombstone
+        .path
+        .rsplit_once("/clauses/")
+        .map(|(_, name)| format!("clauses/{name}"))
+        .unwrap_or_else(|| tombstone.path.clone());
+    let section = tombstone.section.as_deref().unwrap_or("Specification");
+    match rfc.sections.iter_mut().find(|s| s.title == section) {
+        Some(sec) => {
+            if !sec.clauses.contains(&clause_rel_path) {
+                sec.clauses.push(clause_rel_path);
+            }
+        }
+        None => rfc.sections.push(SectionSpec {
+            title: section.to_string(),
+            clauses: vec![clause_rel_path],
+        }),
+    }
+    write_rfc(&rfc_path, &rfc, op, Some(&config.display_path(&rfc_path)))
+}
+
+fn ensure_work_id_free(config: &Config, id: &str) -> DiagnosticResult<()> {
+    if load_work_items(config)?
+        .iter()
+        .any(|item| item.spec.govctl.id == id)
+    {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0822UnsupportedOperation,
+            format!("Cannot restore {id}: a work item with that ID already exists"),
+            id,
+        ));
+    }
+    Ok(())
+}
+
+/// Delete every file of a trash entry, leaving the directory.
+fn clear_entry(config: &Config, dir: &Path, op: WriteOp) -> DiagnosticResult<()> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Ok(());
+    };
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if path.is_file() {
+            delete_file(&path, op, Some(&config.display_path(&path)))?;
+        }
+    }
+    Ok(())
+}
+
+fn entry_dir(config: &Config, id: &str) -> PathBuf {
+    // `:` is not portable in file names; clause IDs become RFC-0001_C-NAME.
+    config.gov_root.join(".trash").join(id.replace(':', "_"))
+}
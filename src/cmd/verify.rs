@@ -1,13 +1,19 @@
 //! Verification guard command and work-item enforcement.
+//!
+//! Guards run for a work item leave their results on it, under
+//! `[[verification.results]]`.
 
 use crate::config::Config;
 use crate::diagnostic::{
     Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult, Diagnostics,
 };
-use crate::parse::load_guards_with_warnings;
+use crate::model::GuardResult;
+use crate::parse::{load_guards_with_warnings, load_work_item, write_work_item};
 use crate::ui;
 use crate::verification;
+use crate::write::WriteOp;
 use std::collections::HashMap;
+use std::path::Path;
 
 use super::work_lookup::load_work_item_by_id;
 
@@ -15,6 +21,7 @@ pub fn verify(
     config: &Config,
     guard_ids: &[String],
     work_id: Option<&str>,
+    op: WriteOp,
 ) -> DiagnosticResult<Diagnostics> {
     let (guards_by_id, mut diagnostics) = load_guard_context(config)?;
     let work_item = if let Some(work_id) = work_id {
@@ -23,6 +30,10 @@ pub fn verify(
         None
     };
 
+    let guards_by_id = match work_item.as_ref() {
+        Some(work_item) => verification::work_item_guard_index(&guards_by_id, work_item),
+        None => guards_by_id,
+    };
     if let Some(work_item) = work_item.as_ref() {
         diagnostics.extend(verification::validate_work_item_verification(
             config,
@@ -55,20 +66,28 @@ pub fn verify(
         return Ok(diagnostics);
     }
 
-    diagnostics.extend(run_selected_guards(
+    let (run_diags, results) = run_selected_guards(
         config,
         &guards_by_id,
         &selected_guard_ids,
         work_id.unwrap_or("verify"),
-    )?);
+    )?;
+    diagnostics.extend(run_diags);
+    if let Some(work_item) = work_item.as_ref() {
+        record_guard_results(config, &work_item.path, results, op)?;
+    }
     Ok(diagnostics)
 }
 
+/// Run the guards `work_item` requires, failing unless all of them pass.
+/// The result of each guard run is appended to `results`, pass or fail.
 pub fn enforce_work_item_guards(
     config: &Config,
     work_item: &crate::model::WorkItemEntry,
+    results: &mut Vec<GuardResult>,
 ) -> DiagnosticResult<()> {
     let (guards_by_id, mut diagnostics) = load_guard_context(config)?;
+    let guards_by_id = verification::work_item_guard_index(&guards_by_id, work_item);
     diagnostics.extend(verification::validate_work_item_verification(
         config,
         &guards_by_id,
@@ -85,18 +104,22 @@ pub fn enforce_work_item_guards(
         return Ok(());
     }
 
-    let run_diags = run_selected_guards(
-        config,
-        &guards_by_id,
-        &selected_guard_ids,
-        &work_item.spec.govctl.id,
-    )?;
-    errors.extend(
-        run_diags
-            .into_iter()
-            .filter(|diag| diag.level == DiagnosticLevel::Error)
-            .map(|diag| diag.message),
-    );
+    // A misconfigured guard fails the transition before any guard runs.
+    if errors.is_empty() {
+        let (run_diags, run_results) = run_selected_guards(
+            config,
+            &guards_by_id,
+            &selected_guard_ids,
+            &work_item.spec.govctl.id,
+        )?;
+        results.extend(run_results);
+        errors.extend(
+            run_diags
+                .into_iter()
+                .filter(|diag| diag.level == DiagnosticLevel::Error)
+                .map(|diag| diag.message),
+        );
+    }
 
     if errors.is_empty() {
         Ok(())
@@ -116,6 +139,22 @@ pub fn enforce_work_item_guards(
     }
 }
 
+/// Append `results` to the work item at `path`, leaving the rest of it as
+/// stored.
+pub(crate) fn record_guard_results(
+    config: &Config,
+    path: &Path,
+    results: Vec<GuardResult>,
+    op: WriteOp,
+) -> DiagnosticResult<()> {
+    if results.is_empty() {
+        return Ok(());
+    }
+    let mut entry = load_work_item(config, path)?;
+    entry.spec.verification.results.extend(results);
+    write_work_item(path, &entry.spec, op, Some(&config.display_path(path)))
+}
+
 fn load_guard_context(
     config: &Config,
 ) -> DiagnosticResult<(HashMap<String, crate::model::GuardEntry>, Diagnostics)> {
@@ -136,8 +175,9 @@ fn run_selected_guards(
     guards_by_id: &std::collections::HashMap<String, crate::model::GuardEntry>,
     selected_guard_ids: &[String],
     location: &str,
-) -> DiagnosticResult<Diagnostics> {
+) -> DiagnosticResult<(Diagnostics, Vec<GuardResult>)> {
     let mut diagnostics = Vec::new();
+    let mut results = Vec::new();
 
     for guard_id in selected_guard_ids {
         let Some(guard) = guards_by_id.get(guard_id) else {
@@ -145,7 +185,9 @@ fn run_selected_guards(
             continue;
         };
 
+        let ran_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
         let result = verification::run_guard(config, guard)?;
+        results.push(result.record(guard, ran_at, config.verification.output_lines));
         if result.passed {
             ui::info(format!("PASS {}", result.id));
             continue;
@@ -166,7 +208,9 @@ fn run_selected_guards(
         } else {
             format!("Verification guard '{}' failed", result.id)
         };
-        let code = if result.timed_out {
+        let code = if guard.spec.check.allow_failure {
+            DiagnosticCode::W0128GuardFailureAllowed
+        } else if result.timed_out {
             DiagnosticCode::E1005GuardTimeout
         } else {
             DiagnosticCode::E1004GuardCheckFailed
@@ -181,8 +225,12 @@ fn run_selected_guards(
             },
             guard.path.display().to_string(),
         ));
-        ui::info(format!("FAIL {}", result.id));
+        if guard.spec.check.allow_failure {
+            ui::info(format!("FAIL {} (failure allowed)", result.id));
+        } else {
+            ui::info(format!("FAIL {}", result.id));
+        }
     }
 
-    Ok(diagnostics)
+    Ok((diagnostics, results))
 }
@@ -0,0 +1,136 @@
+//! `check --workspace`: validate every workspace member and the
+//! `[[member/ID]]` references between them.
+
+use crate::artifact_index::artifact_ref_ids;
+use crate::cmd::check::collect_diagnostics;
+use crate::config::Config;
+use crate::diagnostic::{
+    Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult, Diagnostics,
+};
+use crate::load::load_project;
+use crate::ui;
+use crate::workspace::{Workspace, cross_project_ref_pattern};
+use regex::Regex;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Governed directories scanned for cross-project references.
+const GOVERNED_DIRS: &[&str] = &["rfc", "adr", "work"];
+
+/// Validate all members of the workspace found above the current directory.
+pub fn check_workspace() -> DiagnosticResult<Diagnostics> {
+    let workspace = Workspace::require()?;
+    let mut all_diagnostics = Vec::new();
+    let mut configs = BTreeMap::new();
+    let mut known_ids = BTreeMap::new();
+
+    for (name, config) in workspace.member_configs() {
+        let prefix = workspace.display_member_root(name);
+        let config = match config {
+            Ok(config) => config,
+            Err(diag) => {
+                all_diagnostics.push(in_member(diag, &prefix));
+                continue;
+            }
+        };
+        let (diagnostics, summary) = collect_diagnostics(&config)?;
+        all_diagnostics.extend(diagnostics.into_iter().map(|diag| in_member(diag, &prefix)));
+        if summary.project_loaded {
+            ui::info(format!(
+                "{name} ({prefix}): {} RFCs, {} clauses, {} ADRs, {} work items",
+                summary.rfc_count, summary.clause_count, summary.adr_count, summary.work_count
+            ));
+        }
+        if let Ok(index) = load_project(&config) {
+            known_ids.insert(name.to_string(), artifact_ref_ids(&index));
+        }
+        configs.insert(name.to_string(), config);
+    }
+
+    let ref_re = Regex::new(&cross_project_ref_pattern()).map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Invalid built-in cross-project reference pattern: {err}"),
+            "internal",
+        )
+    })?;
+    for (name, config) in &configs {
+        let prefix = workspace.display_member_root(name);
+        all_diagnostics.extend(
+            check_cross_project_refs(config, &ref_re, &known_ids)
+                .into_iter()
+                .map(|diag| in_member(diag, &prefix)),
+        );
+    }
+
+    let has_blocking_diagnostics = all_diagnostics.iter().any(|diag| {
+        matches!(
+            diag.level,
+            DiagnosticLevel::Error | DiagnosticLevel::Warning
+        )
+    });
+    if !has_blocking_diagnostics {
+        ui::success(format!(
+            "All checks passed for {} workspace members",
+            workspace.members.len()
+        ));
+    }
+    Ok(all_diagnostics)
+}
+
+/// Resolve every `[[member/ID]]` in one member's governed sources.
+fn check_cross_project_refs(
+    config: &Config,
+    ref_re: &Regex,
+    known_ids: &BTreeMap<String, HashSet<String>>,
+) -> Diagnostics {
+    let mut diagnostics = Vec::new();
+    for dir in GOVERNED_DIRS {
+        let files = WalkDir::new(config.gov_root.join(dir))
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file());
+        for entry in files {
+            let Ok(content) = std::fs::read_to_string(entry.path()) else {
+                continue;
+            };
+            for caps in ref_re.captures_iter(&content) {
+                let (Some(whole), Some(member), Some(id)) = (caps.get(0), caps.get(1), caps.get(2))
+                else {
+                    continue;
+                };
+                let message = match known_ids.get(member.as_str()) {
+                    None => format!(
+                        "Reference {} names unknown workspace member '{}'",
+                        whole.as_str(),
+                        member.as_str()
+                    ),
+                    Some(ids) if !ids.contains(id.as_str()) => format!(
+                        "Reference {} not found: {} has no {}",
+                        whole.as_str(),
+                        member.as_str(),
+                        id.as_str()
+                    ),
+                    Some(_) => continue,
+                };
+                let line = content[..whole.start()].matches('\n').count() + 1;
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::E0511WorkspaceRefNotFound,
+                    message,
+                    format!("{}:{line}", config.display_path(entry.path()).display()),
+                ));
+            }
+        }
+    }
+    diagnostics
+}
+
+/// Locate a member's diagnostic relative to the workspace root.
+fn in_member(mut diag: Diagnostic, member_root: &str) -> Diagnostic {
+    if !diag.file.is_empty() && Path::new(&diag.file).is_relative() {
+        diag.file = format!("{member_root}/{}", diag.file);
+    }
+    diag
+}
@@ -69,8 +69,9 @@ pub(crate) fn owned_edit_action(args: &EditActionArgs) -> DiagnosticResult<Owned
     if let Some(value) = &args.add {
         reject_selector_flags_for_value_action("add", args)?;
         return Ok(OwnedEditAction::Add {
-            value: Some(value.clone()),
+            values: value.iter().cloned().collect(),
             stdin: args.stdin,
+            lines: false,
         });
     }
     if let Some(status) = args.tick {
@@ -120,9 +121,14 @@ pub(crate) fn set_action(value: Option<String>, stdin: bool) -> OwnedEditAction
 }
 
 pub(crate) fn add_action(value: Option<String>, stdin: bool) -> OwnedEditAction {
+    add_values_action(value.into_iter().collect(), stdin, false)
+}
+
+pub(crate) fn add_values_action(values: Vec<String>, stdin: bool, lines: bool) -> OwnedEditAction {
     OwnedEditAction::Add {
-        value: Some(value),
+        values,
         stdin,
+        lines,
     }
 }
 
@@ -0,0 +1,217 @@
+//! Batch execution for `govctl apply`.
+//!
+//! A script is a YAML list of command lines. Every step is parsed and planned
+//! before anything runs, then the steps execute in order under the caller's
+//! gov lock inside one write transaction, so a failing step restores every
+//! file the batch wrote.
+
+use super::super::{BuiltinOp, CommandPlan, CreateOp, EditOp, Op};
+use super::CommandResult;
+use crate::aliases::Aliases;
+use crate::cmd::history::command_line;
+use crate::config::{Config, PrefixesConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult};
+use crate::identity::Actor;
+use crate::ui;
+use crate::write::{WriteOp, with_transaction};
+use crate::{Cli, Persona};
+use clap::Parser;
+use std::io::Read;
+use std::path::Path;
+
+/// One planned script step.
+struct Step {
+    line: String,
+    plan: CommandPlan,
+}
+
+/// Run the script at `file` (stdin when `None`) as one transaction.
+pub(super) fn execute_apply(
+    config: &Config,
+    file: Option<&Path>,
+    assume_yes: bool,
+    actor: Option<&Actor>,
+    op: WriteOp,
+    persona: Persona,
+) -> CommandResult {
+    let (source, script) = read_script(file)?;
+    let steps = plan_steps(
+        &config.prefixes,
+        &Aliases::load(config),
+        &source,
+        &script,
+        assume_yes,
+    )?;
+    if steps.is_empty() {
+        ui::info(format!("No steps in {source}"));
+        return Ok(vec![]);
+    }
+
+    let mut diagnostics = Vec::new();
+    with_transaction(op, |op| {
+        let mut config = config.clone();
+        for (index, step) in steps.iter().enumerate() {
+            let mut plan = step.plan.clone();
+            plan.persona = persona;
+            let step_diagnostics = plan
+                .resolve_ids(&config)
+                .and_then(|()| plan.execute(&config, actor, op))
+                .and_then(|step_diagnostics| {
+                    match step_diagnostics
+                        .iter()
+                        .find(|diag| diag.level == DiagnosticLevel::Error)
+                    {
+                        Some(error) => Err(error.clone()),
+                        None => Ok(step_diagnostics),
+                    }
+                });
+            match step_diagnostics {
+                Ok(step_diagnostics) => diagnostics.extend(step_diagnostics),
+                Err(mut diag) => {
+                    diag.message = format!(
+                        "Step {} (`{}`) failed: {}; no changes were applied",
+                        index + 1,
+                        step.line,
+                        diag.message
+                    );
+                    return Err(diag);
+                }
+            }
+            // Tag steps edit config.toml; later steps must see the new vocabulary.
+            if matches!(
+                step.plan.op,
+                Op::Builtin(BuiltinOp::TagNew { .. } | BuiltinOp::TagDelete { .. })
+            ) && !op.is_preview()
+            {
+                config = Config::load(Some(&config.gov_root.join("config.toml")))?;
+            }
+        }
+        Ok(())
+    })?;
+
+    if !op.is_preview() {
+        ui::success(format!("Applied {} step(s) from {source}", steps.len()));
+    }
+    Ok(diagnostics)
+}
+
+fn read_script(file: Option<&Path>) -> DiagnosticResult<(String, String)> {
+    match file {
+        Some(path) => {
+            let source = path.display().to_string();
+            let script = std::fs::read_to_string(path)
+                .map_err(|err| Diagnostic::io_error("read apply script", err, source.clone()))?;
+            Ok((source, script))
+        }
+        None => {
+            let mut script = String::new();
+            std::io::stdin()
+                .read_to_string(&mut script)
+                .map_err(|err| Diagnostic::io_error("read apply script", err, "stdin"))?;
+            Ok(("stdin".to_string(), script))
+        }
+    }
+}
+
+/// Parse and plan every step, so a malformed step fails before anything is written.
+fn plan_steps(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    source: &str,
+    script: &str,
+    assume_yes: bool,
+) -> DiagnosticResult<Vec<Step>> {
+    let invalid =
+        |message: String| Diagnostic::new(DiagnosticCode::E0825ApplyScriptInvalid, message, source);
+
+    let commands: Option<Vec<Vec<String>>> = serde_yaml::from_str(script)
+        .map_err(|err| invalid(format!("Invalid apply script: {err}")))?;
+    let mut steps: Vec<Step> = Vec::new();
+    for (index, args) in commands.unwrap_or_default().into_iter().enumerate() {
+        let line = command_line(&args);
+        let step_error =
+            |message: String| invalid(format!("Step {} (`{line}`): {message}", index + 1));
+
+        if args.iter().any(|arg| arg == "--stdin") {
+            return Err(step_error(
+                "--stdin is not available in scripts; pass the value inline".to_string(),
+            ));
+        }
+        // Steps are planned before any runs, so `@last` would still name the
+        // artifact created before the script.
+        if args.iter().any(|arg| arg == "@last")
+            && steps
+                .iter()
+                .any(|step| matches!(step.plan.op, Op::Create(_)))
+        {
+            return Err(step_error(
+                "@last cannot name an artifact created earlier in the script; use its ID"
+                    .to_string(),
+            ));
+        }
+        let cli = Cli::try_parse_from(std::iter::once("govctl".to_string()).chain(args))
+            .map_err(|err| step_error(clap_error_summary(&err)))?;
+        if cli.config.is_some()
+            || cli.project.is_some()
+            || cli.dry_run
+            || cli.output.is_some()
+            || cli.lock_wait.is_some()
+            || cli.actor.is_some()
+        {
+            return Err(step_error(
+                "--config, --project, --dry-run, --output, --lock-wait, and --as apply to the whole script, not single steps"
+                    .to_string(),
+            ));
+        }
+        let mut plan = CommandPlan::from_parsed(&cli.command, false, prefixes, aliases).map_err(
+            |mut diag| {
+                diag.message = format!("Step {} (`{line}`): {}", index + 1, diag.message);
+                diag
+            },
+        )?;
+        if let Some(reason) = unsupported_reason(&plan.op) {
+            return Err(step_error(format!("{reason} cannot run inside apply")));
+        }
+        if assume_yes || cli.yes {
+            plan.assume_yes();
+        }
+        steps.push(Step { line, plan });
+    }
+    Ok(steps)
+}
+
+/// Steps that prompt, write outside `gov/`, or nest transactions are refused.
+fn unsupported_reason(op: &Op) -> Option<&'static str> {
+    match op {
+        Op::Create(CreateOp::Wizard { .. }) => Some("interactive creation"),
+        Op::Edit(EditOp::Editor) => Some("editor editing"),
+        Op::RenderArtifact { .. } => Some("rendering"),
+        Op::Builtin(builtin) => match builtin {
+            BuiltinOp::Check { .. }
+            | BuiltinOp::Status
+            | BuiltinOp::TagNew { .. }
+            | BuiltinOp::TagDelete { .. }
+            | BuiltinOp::TagList { .. }
+            | BuiltinOp::MappingAdd { .. }
+            | BuiltinOp::MappingRemove { .. }
+            | BuiltinOp::MappingList { .. }
+            | BuiltinOp::AttachAdd { .. }
+            | BuiltinOp::AttachRemove { .. }
+            | BuiltinOp::AttachList { .. }
+            | BuiltinOp::ReleaseCut { github: false, .. }
+            | BuiltinOp::ReleaseUndo { .. }
+            | BuiltinOp::WorkPrioritize { .. }
+            | BuiltinOp::WorkRank { .. } => None,
+            BuiltinOp::Apply { .. } => Some("apply"),
+            _ => Some("this command"),
+        },
+        _ => None,
+    }
+}
+
+/// First line of a clap error, without the `error: ` prefix.
+fn clap_error_summary(err: &clap::Error) -> String {
+    let rendered = err.render().to_string();
+    let first = rendered.lines().next().unwrap_or_default();
+    first.strip_prefix("error: ").unwrap_or(first).to_string()
+}
@@ -1,27 +1,78 @@
 use super::super::BuiltinOp;
+use crate::Persona;
 use crate::cmd;
+use crate::cmd::new::InitScaffold;
 use crate::config::Config;
+use crate::identity::Actor;
+use crate::render::RenderMode;
 use crate::write::WriteOp;
 
-use super::{CommandResult, render::execute_global_render};
+use super::{CommandResult, apply::execute_apply, render::execute_global_render};
 
-pub(super) fn execute_builtin(config: &Config, builtin: &BuiltinOp, op: WriteOp) -> CommandResult {
+pub(super) fn execute_builtin(
+    config: &Config,
+    builtin: &BuiltinOp,
+    actor: Option<&Actor>,
+    op: WriteOp,
+    persona: Persona,
+) -> CommandResult {
     match builtin {
-        BuiltinOp::Init { force } => cmd::new::init_project(config, *force, op),
-        BuiltinOp::InitSkills { force, format, dir } => {
-            cmd::new::sync_skills(config, *force, format, dir.as_deref(), op)
+        BuiltinOp::Init {
+            force,
+            from,
+            preset,
+            interactive,
+            vscode,
+        } => cmd::new::init_project(
+            config,
+            *force,
+            from.as_deref(),
+            InitScaffold::new(*preset, *interactive, *vscode),
+            op,
+        ),
+        BuiltinOp::InitSkills { sync, format, dir } => {
+            cmd::new::sync_skills(config, sync, format, dir.as_deref(), op)
         }
-        BuiltinOp::Check { has_active: true } => cmd::check::check_has_active(config),
-        BuiltinOp::Check { has_active: false } => cmd::check::check_all(config),
+        BuiltinOp::Check {
+            has_active: true, ..
+        } => cmd::check::check_has_active(config),
+        BuiltinOp::Check {
+            has_active: false,
+            strict_refs,
+        } => cmd::check::check_all(config, *strict_refs, persona),
+        BuiltinOp::CheckWorkspace => cmd::workspace_check::check_workspace(),
         BuiltinOp::Status => cmd::status::show_status(config),
+        BuiltinOp::ConfigCheck => {
+            cmd::config_check::check_config(&config.gov_root.join("config.toml"))
+        }
+        BuiltinOp::ConfigGet { key } => cmd::config_edit::config_get(config, key),
+        BuiltinOp::ConfigSet { key, value, local } => {
+            cmd::config_edit::config_set(config, key, value, *local, op)
+        }
+        BuiltinOp::ConfigList { output } => cmd::config_edit::config_list(config, *output),
+        BuiltinOp::ConfigShow { resolved, output } => {
+            cmd::config_show::show_config(&config.gov_root.join("config.toml"), *resolved, *output)
+        }
         BuiltinOp::RenderGlobal {
             target,
             dry_run,
+            check,
             force,
-        } => execute_global_render(config, *target, *dry_run, *force),
+            format,
+            layout,
+        } => execute_global_render(
+            config,
+            *target,
+            RenderMode::from_flags(*dry_run, *check),
+            *force,
+            *format,
+            layout.clone(),
+            persona,
+        ),
         BuiltinOp::Migrate => cmd::migrate::migrate(config, op),
+        BuiltinOp::Fmt { check } => cmd::fmt::fmt(config, *check, op),
         BuiltinOp::Verify { guard_ids, work } => {
-            cmd::verify::verify(config, guard_ids, work.as_deref())
+            cmd::verify::verify(config, guard_ids, work.as_deref(), op)
         }
         BuiltinOp::Search {
             query,
@@ -31,7 +82,9 @@ pub(super) fn execute_builtin(config: &Config, builtin: &BuiltinOp, op: WriteOp)
             output,
             reindex,
         } => cmd::search::search(config, query, types, tags, *limit, *output, *reindex),
-        BuiltinOp::Describe { context } => cmd::describe::describe(config, *context),
+        BuiltinOp::Explain { code, output } => cmd::explain::explain(code.as_deref(), *output),
+        BuiltinOp::Describe { schemas: true, .. } => cmd::describe::describe_schemas(),
+        BuiltinOp::Describe { context, .. } => cmd::describe::describe(config, *context),
         BuiltinOp::SelfUpdate { check } => cmd::self_update::self_update(*check),
         BuiltinOp::Completions { shell } => {
             use crate::Cli;
@@ -40,17 +93,66 @@ pub(super) fn execute_builtin(config: &Config, builtin: &BuiltinOp, op: WriteOp)
             clap_complete::generate(*shell, &mut cmd, "govctl", &mut std::io::stdout());
             Ok(vec![])
         }
-        #[cfg(feature = "tui")]
-        BuiltinOp::Tui => crate::tui::run(config).map(|()| vec![]),
-        BuiltinOp::ReleaseCut { version, date } => {
-            cmd::lifecycle::cut_release(config, version, date.as_deref(), op)
+        BuiltinOp::DocsMan { out } => cmd::cli_reference::man_page(out.as_deref(), op),
+        BuiltinOp::DocsMarkdown { out } => {
+            cmd::cli_reference::markdown_reference(out.as_deref(), op)
         }
+        #[cfg(feature = "tui")]
+        BuiltinOp::Tui => crate::tui::run(config, actor).map(|()| vec![]),
+        BuiltinOp::ReleaseCut {
+            version,
+            date,
+            github,
+        } => cmd::lifecycle::cut_release(config, version, date.as_deref(), *github, op),
         BuiltinOp::ReleaseUndo { expected_version } => {
             cmd::lifecycle::undo_release(config, expected_version, op)
         }
         BuiltinOp::TagNew { tag } => cmd::tag::tag_new(config, tag, op),
         BuiltinOp::TagDelete { tag } => cmd::tag::tag_delete(config, tag, op),
         BuiltinOp::TagList { output } => cmd::tag::tag_list(config, *output),
+        BuiltinOp::MappingAdd {
+            standard,
+            control,
+            clauses,
+            options,
+        } => cmd::mapping::mapping_add(config, standard, control, clauses, options, op),
+        BuiltinOp::MappingRemove {
+            standard,
+            control,
+            clauses,
+        } => cmd::mapping::mapping_remove(config, standard, control, clauses, op),
+        BuiltinOp::MappingList { standard, output } => {
+            cmd::mapping::mapping_list(config, standard.as_deref(), *output)
+        }
+        BuiltinOp::AttachAdd { id, file } => cmd::attach::attach_add(config, id, file, op),
+        BuiltinOp::AttachRemove { id, name } => cmd::attach::attach_remove(config, id, name, op),
+        BuiltinOp::AttachList { id, output } => {
+            cmd::attach::attach_list(config, id.as_deref(), *output)
+        }
+        BuiltinOp::SupersedeChain { id } => cmd::supersede_chain::supersede_chain(config, id),
+        BuiltinOp::AnchorList { output } => cmd::anchor::anchor_list(config, *output),
+        BuiltinOp::ScanCoverage { rfc, output } => {
+            cmd::scan_coverage::scan_coverage(config, rfc.as_deref(), *output)
+        }
+        BuiltinOp::TemplatePack { out } => cmd::template::pack(config, out, op),
+        BuiltinOp::Export { out } => cmd::export::export(config, out, op),
+        BuiltinOp::PublishConfluence { ids } => cmd::publish::publish_confluence(config, ids, op),
+        BuiltinOp::SyncJira { ids } => cmd::sync::sync_jira(config, ids, op),
+        BuiltinOp::WorkListGithub {
+            filter,
+            limit,
+            output,
+            tags,
+        } => cmd::list::list_work_github(config, filter.as_deref(), *limit, *output, tags),
+        BuiltinOp::WorkPrioritize { apply, output } => {
+            cmd::prioritize::prioritize(config, *apply, *output, op)
+        }
+        BuiltinOp::WorkRank { id, position } => cmd::queue::rank(config, id, position, op),
+        BuiltinOp::WorkNext {
+            filter,
+            activate,
+            output,
+        } => cmd::queue::next(config, filter, *activate, *output, op),
         BuiltinOp::LoopStart { loop_id, work_ids } => {
             cmd::loop_cmd::start(config, loop_id.as_deref(), work_ids, op)
         }
@@ -76,5 +178,13 @@ pub(super) fn execute_builtin(config: &Config, builtin: &BuiltinOp, op: WriteOp)
             loop_id,
             target_work_ids,
         } => cmd::loop_cmd::run(config, loop_id, target_work_ids, op),
+        BuiltinOp::Apply { file, assume_yes } => {
+            execute_apply(config, file.as_deref(), *assume_yes, actor, op, persona)
+        }
+        BuiltinOp::Restore { id } => cmd::trash::restore(config, id, op),
+        BuiltinOp::Undo { force } => cmd::history::undo(config, *force, op),
+        BuiltinOp::History { limit, output } => cmd::history::show_history(config, *limit, *output),
+        BuiltinOp::AuditList { limit, output } => cmd::audit::list(config, *limit, *output),
+        BuiltinOp::AuditShow { seq, output } => cmd::audit::show(config, *seq, *output),
     }
 }
@@ -1,20 +1,29 @@
+mod apply;
 mod builtin;
 mod render;
 mod scope;
 
 use super::{CommandPlan, CreateOp, EditOp, LifecycleOp, Op, Scope};
-use crate::cmd;
+use crate::artifact_catalog::{self, CatalogKind};
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
-use crate::write::WriteOp;
+use crate::identity::{Actor, created_by};
+use crate::write::{WriteOp, with_transaction};
 use crate::{NewTarget, OutputFormat, ShowOutputFormat};
+use crate::{aliases, cmd, ui};
 use builtin::execute_builtin;
 use render::execute_artifact_render;
 use scope::{ShowKind, extract_artifact_scope, extract_collection_scope, extract_target_scope};
 
 type CommandResult = DiagnosticResult<Diagnostics>;
 
-fn execute_create(config: &Config, create: &CreateOp, op: WriteOp) -> CommandResult {
+fn execute_create(
+    config: &Config,
+    scope: &Scope,
+    create: &CreateOp,
+    actor: Option<&Actor>,
+    op: WriteOp,
+) -> CommandResult {
     match create {
         CreateOp::Rfc { title, id } => cmd::new::create(
             config,
@@ -22,6 +31,7 @@ fn execute_create(config: &Config, create: &CreateOp, op: WriteOp) -> CommandRes
                 title: title.clone(),
                 id: id.clone(),
             },
+            actor,
             op,
         ),
         CreateOp::Clause {
@@ -37,6 +47,7 @@ fn execute_create(config: &Config, create: &CreateOp, op: WriteOp) -> CommandRes
                 section: section.clone(),
                 kind: *kind,
             },
+            actor,
             op,
         ),
         CreateOp::Adr { title } => cmd::new::create(
@@ -44,6 +55,7 @@ fn execute_create(config: &Config, create: &CreateOp, op: WriteOp) -> CommandRes
             &NewTarget::Adr {
                 title: title.clone(),
             },
+            actor,
             op,
         ),
         CreateOp::Work { title, active } => cmd::new::create(
@@ -52,9 +64,17 @@ fn execute_create(config: &Config, create: &CreateOp, op: WriteOp) -> CommandRes
                 title: title.clone(),
                 active: *active,
             },
+            actor,
             op,
         ),
         CreateOp::Guard { title } => cmd::guard::new_guard(config, title, op),
+        CreateOp::WorkClone { title } => {
+            let (_, source) = extract_artifact_scope(scope)?;
+            cmd::new::clone_work(config, source, title.as_deref(), actor, op)
+        }
+        CreateOp::Wizard { target, title } => {
+            cmd::new::run_wizard(config, target, title.as_deref(), actor, op)
+        }
     }
 }
 
@@ -65,6 +85,7 @@ fn execute_list(
     limit: Option<usize>,
     output: OutputFormat,
     tags: &[String],
+    stale: bool,
 ) -> CommandResult {
     cmd::list::list(
         config,
@@ -73,6 +94,7 @@ fn execute_list(
         limit,
         output,
         tags,
+        stale,
     )
 }
 
@@ -141,6 +163,18 @@ fn execute_edit(plan: &CommandPlan, config: &Config, edit: &EditOp, op: WriteOp)
                 op,
             )
         }
+        EditOp::Alternative {
+            pattern,
+            at,
+            update,
+        } => {
+            let (_, id) = extract_artifact_scope(&plan.scope)?;
+            cmd::edit::set_alternative(config, id, pattern.as_deref(), *at, update, op)
+        }
+        EditOp::Editor => {
+            let (artifact, id) = extract_artifact_scope(&plan.scope)?;
+            cmd::edit::edit_in_editor(config, artifact, id, op)
+        }
     }
 }
 
@@ -148,6 +182,7 @@ fn execute_lifecycle(
     plan: &CommandPlan,
     config: &Config,
     lifecycle: &LifecycleOp,
+    actor: Option<&Actor>,
     op: WriteOp,
 ) -> CommandResult {
     let (artifact, id) = extract_artifact_scope(&plan.scope)?;
@@ -158,10 +193,64 @@ fn execute_lifecycle(
             changes,
         } => cmd::lifecycle::bump(config, id, *level, summary.as_deref(), changes, op),
         LifecycleOp::Finalize { status } => cmd::lifecycle::finalize(config, id, *status, op),
-        LifecycleOp::Advance { phase } => cmd::lifecycle::advance(config, id, *phase, op),
-        LifecycleOp::Deprecate { force } => cmd::lifecycle::deprecate(config, id, *force, op),
-        LifecycleOp::Supersede { by, force } => {
-            cmd::lifecycle::supersede(config, id, by, *force, op)
+        LifecycleOp::Approve => {
+            let approver = actor.and_then(Actor::approver).ok_or_else(|| {
+                Diagnostic::new(
+                    DiagnosticCode::E0801MissingRequiredArg,
+                    "Approving needs an identity (hint: pass `--as <identity>`, e.g. `--as @alice`)",
+                    id,
+                )
+            })?;
+            cmd::lifecycle::approve(config, id, approver, op)
+        }
+        LifecycleOp::Freeze => cmd::lifecycle::set_frozen(config, id, true, op),
+        LifecycleOp::Unfreeze => cmd::lifecycle::set_frozen(config, id, false, op),
+        LifecycleOp::Advance { phase, force } => {
+            cmd::lifecycle::advance(config, id, *phase, *force, op)
+        }
+        LifecycleOp::AdvanceReport { phase } => cmd::lifecycle::advance_report(config, id, *phase),
+        LifecycleOp::Deprecate { force, notice } => {
+            cmd::lifecycle::deprecate(config, id, *force, notice.as_ref(), op)
+        }
+        LifecycleOp::Supersede {
+            by,
+            force,
+            update_refs,
+        } => cmd::lifecycle::supersede(config, id, by, *force, *update_refs, op),
+        LifecycleOp::SplitRfc {
+            clauses,
+            into,
+            title,
+            force,
+        } => cmd::lifecycle::split(
+            config,
+            id,
+            clauses,
+            &cmd::lifecycle::SplitInto {
+                id: into,
+                title,
+                created_by: created_by(actor),
+            },
+            *force,
+            op,
+        ),
+        LifecycleOp::MergeRfc { into, force } => {
+            cmd::lifecycle::merge(config, id, into, *force, op)
+        }
+        LifecycleOp::ReorderClauses { section, order } => {
+            cmd::lifecycle::reorder_clauses(config, id, section, order, op)
+        }
+        LifecycleOp::AddSection { title, after } => {
+            cmd::lifecycle::add_section(config, id, title, after.as_deref(), op)
+        }
+        LifecycleOp::RenameSection { from, to } => {
+            cmd::lifecycle::rename_section(config, id, from, to, op)
+        }
+        LifecycleOp::RemoveSection { title, force } => {
+            cmd::lifecycle::remove_section(config, id, title, *force, op)
+        }
+        LifecycleOp::ReorderSections { order } => {
+            cmd::lifecycle::reorder_sections(config, id, order, op)
         }
         LifecycleOp::AcceptAdr { force } => {
             debug_assert!(matches!(artifact, cmd::edit::ArtifactType::Adr));
@@ -171,9 +260,19 @@ fn execute_lifecycle(
             debug_assert!(matches!(artifact, cmd::edit::ArtifactType::Adr));
             cmd::lifecycle::reject_adr(config, id, op)
         }
-        LifecycleOp::MoveWork { file_or_id, status } => {
-            cmd::move_::move_item(config, file_or_id, *status, op)
-        }
+        LifecycleOp::MoveWork {
+            file_or_id,
+            status,
+            reopen_reason,
+            finish,
+        } => cmd::move_::move_item(
+            config,
+            file_or_id,
+            *status,
+            reopen_reason.as_deref(),
+            *finish,
+            op,
+        ),
     }
 }
 
@@ -191,21 +290,126 @@ fn execute_delete(plan: &CommandPlan, config: &Config, force: bool, op: WriteOp)
     }
 }
 
-pub(super) fn execute_plan(plan: &CommandPlan, config: &Config, op: WriteOp) -> CommandResult {
+fn created_kind(create: &CreateOp) -> CatalogKind {
+    match create {
+        CreateOp::Rfc { .. }
+        | CreateOp::Wizard {
+            target: cmd::new::WizardTarget::Rfc { .. },
+            ..
+        } => CatalogKind::Rfc,
+        CreateOp::Clause { .. } => CatalogKind::Clause,
+        CreateOp::Adr { .. }
+        | CreateOp::Wizard {
+            target: cmd::new::WizardTarget::Adr,
+            ..
+        } => CatalogKind::Adr,
+        CreateOp::Work { .. }
+        | CreateOp::WorkClone { .. }
+        | CreateOp::Wizard {
+            target: cmd::new::WizardTarget::Work { .. },
+            ..
+        } => CatalogKind::Work,
+        CreateOp::Guard { .. } => CatalogKind::Guard,
+    }
+}
+
+/// Point `@last` at the `kind` artifact that is not among the `before` IDs.
+fn record_last_created(config: &Config, kind: CatalogKind, before: &[String]) {
+    let recorded = artifact_catalog::artifact_ids(config, kind).and_then(|ids| {
+        match ids.into_iter().find(|id| !before.contains(id)) {
+            Some(id) => aliases::record_last(config, &id),
+            None => Ok(()),
+        }
+    });
+    if let Err(diag) = recorded {
+        ui::hint(format!("@last not updated: {}", diag.message));
+    }
+}
+
+/// Artifacts a command would write that a review freeze protects.
+///
+/// Approvals and the freeze toggles themselves are exempt; so are reads.
+fn frozen_targets(plan: &CommandPlan) -> Vec<&str> {
+    let scope_id = match &plan.scope {
+        Scope::Artifact { artifact, id } | Scope::Target { artifact, id, .. }
+            if matches!(
+                artifact,
+                cmd::edit::ArtifactType::Rfc | cmd::edit::ArtifactType::Clause
+            ) =>
+        {
+            Some(id.as_str())
+        }
+        _ => None,
+    };
+    match &plan.op {
+        Op::Create(CreateOp::Clause { clause_id, .. }) => vec![clause_id],
+        Op::Edit(_) | Op::Delete { .. } => scope_id.into_iter().collect(),
+        Op::Lifecycle(
+            LifecycleOp::Approve
+            | LifecycleOp::AdvanceReport { .. }
+            | LifecycleOp::Freeze
+            | LifecycleOp::Unfreeze,
+        ) => vec![],
+        Op::Lifecycle(
+            LifecycleOp::Supersede { by: other, .. } | LifecycleOp::MergeRfc { into: other, .. },
+        ) => scope_id.into_iter().chain([other.as_str()]).collect(),
+        Op::Lifecycle(_) => scope_id.into_iter().collect(),
+        _ => vec![],
+    }
+}
+
+pub(super) fn execute_plan(
+    plan: &CommandPlan,
+    config: &Config,
+    actor: Option<&Actor>,
+    op: WriteOp,
+) -> CommandResult {
+    for id in frozen_targets(plan) {
+        cmd::lifecycle::ensure_not_frozen(config, id)?;
+    }
     match &plan.op {
-        Op::Builtin(builtin) => execute_builtin(config, builtin, op),
-        Op::Create(create) => execute_create(config, create, op),
+        Op::Builtin(builtin) => execute_builtin(config, builtin, actor, op, plan.persona),
+        // Artifact mutations run in a write transaction so a mid-operation
+        // failure leaves no partial writes behind.
+        Op::Create(create) => {
+            let kind = created_kind(create);
+            let before = artifact_catalog::artifact_ids(config, kind)?;
+            let diagnostics = with_transaction(op, |op| {
+                execute_create(config, &plan.scope, create, actor, op)
+            })?;
+            if !op.is_preview() {
+                record_last_created(config, kind, &before);
+            }
+            Ok(diagnostics)
+        }
         Op::List {
             filter,
             limit,
             output,
             tags,
-        } => execute_list(plan, config, filter.as_deref(), *limit, *output, tags),
+            stale,
+        } => execute_list(
+            plan,
+            config,
+            filter.as_deref(),
+            *limit,
+            *output,
+            tags,
+            *stale,
+        ),
         Op::Get => execute_get(plan, config),
         Op::Show { output, history } => execute_show(plan, config, *output, *history),
-        Op::Edit(edit) => execute_edit(plan, config, edit, op),
-        Op::Lifecycle(lifecycle) => execute_lifecycle(plan, config, lifecycle, op),
-        Op::Delete { force } => execute_delete(plan, config, *force, op),
-        Op::RenderArtifact { dry_run } => execute_artifact_render(plan, config, *dry_run),
+        Op::Edit(edit) => with_transaction(op, |op| execute_edit(plan, config, edit, op)),
+        Op::Lifecycle(lifecycle) => with_transaction(op, |op| {
+            execute_lifecycle(plan, config, lifecycle, actor, op)
+        }),
+        Op::Delete { force } => with_transaction(op, |op| execute_delete(plan, config, *force, op)),
+        Op::RenderArtifact { dry_run, layout } => {
+            execute_artifact_render(plan, config, *dry_run, layout.clone())
+        }
+        Op::TextHistory { limit } => {
+            let (_, id) = extract_artifact_scope(&plan.scope)?;
+            cmd::clause_history::clause_history(config, id, *limit)
+        }
     }
 }
@@ -1,61 +1,142 @@
 use super::{CommandResult, scope::extract_artifact_scope};
-use crate::RenderTarget;
 use crate::cmd;
 use crate::command_router::CommandPlan;
 use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::render::{Drift, RenderMode};
+use crate::{Persona, RenderFormat, RenderLayoutArgs, RenderTarget};
 
-fn render_rfc(config: &Config, id: Option<&str>, dry_run: bool) -> CommandResult {
-    cmd::render::render(config, id, dry_run)
+/// `config` with the layout flags turned on over `[render]`, and the
+/// selected profile's output directory and omitted sections applied.
+fn with_layout(config: &Config, layout: RenderLayoutArgs) -> Result<Config, Diagnostic> {
+    let mut config = config.clone();
+    config.render.toc |= layout.toc;
+    config.render.clause_numbers |= layout.clause_numbers;
+    config.render.status_badges |= layout.status_badges;
+    config.render.front_matter.enabled |= layout.front_matter;
+    if let Some(name) = layout.profile {
+        let profile = config.render.profiles.get(&name).cloned().ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E0832UnknownRenderProfile,
+                format!("Unknown render profile '{name}' (hint: declare [render.profiles.{name}])"),
+                "render",
+            )
+        })?;
+        if let Some(docs_output) = profile.docs_output {
+            config.paths.docs_output = config.project_root().join(docs_output);
+        }
+        for section in profile.omit {
+            if !config.render.omit.contains(&section) {
+                config.render.omit.push(section);
+            }
+        }
+    }
+    Ok(config)
 }
 
-fn render_adr(config: &Config, id: Option<&str>, dry_run: bool) -> CommandResult {
-    cmd::render::render_adrs(config, id, dry_run)
+fn render_rfc(
+    config: &Config,
+    id: Option<&str>,
+    mode: RenderMode,
+    force: bool,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
+    cmd::render::render(config, id, mode, force, persona)
 }
 
-fn render_work(config: &Config, id: Option<&str>, dry_run: bool) -> CommandResult {
-    cmd::render::render_work_items(config, id, dry_run)
+fn render_adr(
+    config: &Config,
+    id: Option<&str>,
+    mode: RenderMode,
+    force: bool,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
+    cmd::render::render_adrs(config, id, mode, force, persona)
 }
 
-fn render_changelog(config: &Config, dry_run: bool, force: bool) -> CommandResult {
-    cmd::render::render_changelog(config, dry_run, force)
+fn render_work(
+    config: &Config,
+    id: Option<&str>,
+    mode: RenderMode,
+    force: bool,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
+    cmd::render::render_work_items(config, id, mode, force, persona)
 }
 
 pub(super) fn execute_global_render(
     config: &Config,
     target: RenderTarget,
-    dry_run: bool,
+    mode: RenderMode,
     force: bool,
+    format: RenderFormat,
+    layout: RenderLayoutArgs,
+    persona: Persona,
 ) -> CommandResult {
-    let mut all_diags = vec![];
+    let config = &with_layout(config, layout)?;
+    let drift = render_target(config, target, mode, force, format, persona)?;
+    if mode == RenderMode::Check {
+        cmd::render::check_up_to_date(config, &drift)
+    } else {
+        Ok(vec![])
+    }
+}
+
+fn render_target(
+    config: &Config,
+    target: RenderTarget,
+    mode: RenderMode,
+    force: bool,
+    format: RenderFormat,
+    persona: Persona,
+) -> DiagnosticResult<Drift> {
+    if format == RenderFormat::Pdf {
+        return match target {
+            RenderTarget::Rfc => cmd::render::render_pdf(config, None, mode, force, persona),
+            _ => Err(Diagnostic::new(
+                DiagnosticCode::E0822UnsupportedOperation,
+                "PDF rendering supports RFCs only (hint: `govctl render rfc --format pdf`)",
+                "render",
+            )),
+        };
+    }
     match target {
-        RenderTarget::Rfc => all_diags.extend(render_rfc(config, None, dry_run)?),
-        RenderTarget::Adr => all_diags.extend(render_adr(config, None, dry_run)?),
-        RenderTarget::Work => all_diags.extend(render_work(config, None, dry_run)?),
-        RenderTarget::Changelog => all_diags.extend(render_changelog(config, dry_run, force)?),
+        RenderTarget::Rfc => render_rfc(config, None, mode, force, persona),
+        RenderTarget::Adr => render_adr(config, None, mode, force, persona),
+        RenderTarget::Work => render_work(config, None, mode, force, persona),
+        RenderTarget::Changelog => cmd::render::render_changelog(config, mode, force),
+        RenderTarget::Compliance => cmd::render::render_compliance(config, mode),
         RenderTarget::All => {
-            all_diags.extend(render_rfc(config, None, dry_run)?);
-            all_diags.extend(render_adr(config, None, dry_run)?);
-            all_diags.extend(render_work(config, None, dry_run)?);
+            let mut drift = render_rfc(config, None, mode, force, persona)?;
+            drift.extend(render_adr(config, None, mode, force, persona)?);
+            drift.extend(render_work(config, None, mode, force, persona)?);
+            drift.extend(cmd::render::render_indexes(config, mode)?);
+            Ok(drift)
         }
     }
-    Ok(all_diags)
 }
 
 pub(super) fn execute_artifact_render(
     plan: &CommandPlan,
     config: &Config,
     dry_run: bool,
+    layout: RenderLayoutArgs,
 ) -> CommandResult {
+    let config = &with_layout(config, layout)?;
+    let persona = plan.persona;
     let (artifact, id) = extract_artifact_scope(&plan.scope)?;
+    let mode = RenderMode::from_flags(dry_run, false);
     match artifact {
-        cmd::edit::ArtifactType::Rfc => render_rfc(config, Some(id), dry_run),
-        cmd::edit::ArtifactType::Adr => render_adr(config, Some(id), dry_run),
-        cmd::edit::ArtifactType::WorkItem => render_work(config, Some(id), dry_run),
-        cmd::edit::ArtifactType::Clause | cmd::edit::ArtifactType::Guard => Err(Diagnostic::new(
-            DiagnosticCode::E0822UnsupportedOperation,
-            "render is not supported for this artifact",
-            id,
-        )),
-    }
+        cmd::edit::ArtifactType::Rfc => render_rfc(config, Some(id), mode, true, persona)?,
+        cmd::edit::ArtifactType::Adr => render_adr(config, Some(id), mode, true, persona)?,
+        cmd::edit::ArtifactType::WorkItem => render_work(config, Some(id), mode, true, persona)?,
+        cmd::edit::ArtifactType::Clause | cmd::edit::ArtifactType::Guard => {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0822UnsupportedOperation,
+                "render is not supported for this artifact",
+                id,
+            ));
+        }
+    };
+    Ok(vec![])
 }
@@ -0,0 +1,217 @@
+//! Command hooks: `[hooks]` scripts around a plan, and webhooks that follow
+//! a committed top-level command.
+//!
+//! Scripts run inside the plan's transaction, so they also run for each
+//! `apply` step. Webhooks run once per CLI or TUI command, never per step,
+//! since a later step can still roll an earlier one back.
+
+use super::{BuiltinOp, CommandPlan, LifecycleOp, Op, Scope, execute};
+use crate::config::{Config, NotifyEvent};
+use crate::diagnostic::{
+    Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult, Diagnostics,
+};
+use crate::hooks::{self, HookContext, Phase, Trigger};
+use crate::identity::Actor;
+use crate::load::{find_rfc_toml, load_rfc};
+use crate::model::WorkItemStatus;
+use crate::notify::{self, Event};
+use crate::parse::{load_releases, load_work_item};
+use crate::write::{WriteOp, with_transaction};
+
+/// Execute `plan` between its `pre_*` and `post_*` hooks. A blocking hook
+/// failure rolls back everything the plan wrote.
+pub(super) fn execute_with_hooks(
+    plan: &CommandPlan,
+    config: &Config,
+    actor: Option<&Actor>,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let Some((trigger, context)) = hook_context(plan, config) else {
+        return execute::execute_plan(plan, config, actor, op);
+    };
+    with_transaction(op, |op| {
+        let mut diagnostics = hooks::run(config, trigger, Phase::Pre, &context, op)?;
+        diagnostics.extend(execute::execute_plan(plan, config, actor, op)?);
+        if !diagnostics
+            .iter()
+            .any(|diag| diag.level == DiagnosticLevel::Error)
+        {
+            diagnostics.extend(hooks::run(config, trigger, Phase::Post, &context, op)?);
+        }
+        Ok(diagnostics)
+    })
+}
+
+/// The hook trigger `plan` fires, when hooks are configured for it.
+///
+/// A work item that cannot be resolved fires nothing; the plan itself
+/// reports the error.
+fn hook_context(plan: &CommandPlan, config: &Config) -> Option<(Trigger, HookContext)> {
+    let trigger = match &plan.op {
+        Op::Lifecycle(LifecycleOp::MoveWork { .. }) => Trigger::Move,
+        Op::Lifecycle(LifecycleOp::Finalize { .. }) => Trigger::Finalize,
+        Op::Lifecycle(LifecycleOp::AcceptAdr { .. }) => Trigger::Accept,
+        Op::Builtin(BuiltinOp::ReleaseCut { .. }) => Trigger::Release,
+        _ => return None,
+    };
+    if config.hooks.commands(trigger, Phase::Pre).is_empty()
+        && config.hooks.commands(trigger, Phase::Post).is_empty()
+    {
+        return None;
+    }
+    let scope_id = match &plan.scope {
+        Scope::Artifact { id, .. } | Scope::Target { id, .. } => Some(id.clone()),
+        Scope::Global | Scope::Collection { .. } => None,
+    };
+    let (id, status) = match &plan.op {
+        Op::Lifecycle(LifecycleOp::MoveWork {
+            file_or_id, status, ..
+        }) => {
+            let path = crate::cmd::move_::resolve_work_path(config, file_or_id).ok()?;
+            let meta = load_work_item(config, &path).ok()?.spec.govctl;
+            (meta.id, Some(status.as_ref().to_string()))
+        }
+        Op::Lifecycle(LifecycleOp::Finalize { .. }) => (scope_id?, Some("normative".to_string())),
+        Op::Lifecycle(LifecycleOp::AcceptAdr { .. }) => (scope_id?, Some("accepted".to_string())),
+        Op::Builtin(BuiltinOp::ReleaseCut { version, .. }) => (version.clone(), None),
+        _ => return None,
+    };
+    Some((
+        trigger,
+        HookContext {
+            project: config.project.name.clone(),
+            id,
+            status,
+        },
+    ))
+}
+
+impl CommandPlan {
+    /// Run post-command hooks after the plan executed with `diagnostics`,
+    /// appending any warnings they raise. Nothing runs when the command
+    /// reported an error.
+    pub fn after_command(
+        &self,
+        config: &Config,
+        op: WriteOp,
+        mut diagnostics: Diagnostics,
+    ) -> Diagnostics {
+        if config.notify.webhooks.is_empty()
+            || diagnostics
+                .iter()
+                .any(|diag| diag.level == DiagnosticLevel::Error)
+        {
+            return diagnostics;
+        }
+        match lifecycle_event(self, config, op) {
+            Ok(Some(event)) => diagnostics.extend(notify::deliver(config, &event, op)),
+            Ok(None) => {}
+            // In a dry run the artifact may not have reached its new state.
+            Err(_) if op.is_preview() => {}
+            Err(diag) => diagnostics.push(Diagnostic::new(
+                DiagnosticCode::W0125WebhookFailed,
+                format!("Webhooks not sent: {}", diag.message),
+                diag.file,
+            )),
+        }
+        diagnostics
+    }
+}
+
+/// The notifiable event `plan` raised, read back from the committed state.
+fn lifecycle_event(
+    plan: &CommandPlan,
+    config: &Config,
+    op: WriteOp,
+) -> DiagnosticResult<Option<Event>> {
+    let project = config.project.name.clone();
+    let scope_id = match &plan.scope {
+        Scope::Artifact { id, .. } | Scope::Target { id, .. } => Some(id.as_str()),
+        Scope::Global | Scope::Collection { .. } => None,
+    };
+    let event = match (&plan.op, scope_id) {
+        (Op::Lifecycle(LifecycleOp::Finalize { .. }), Some(id)) => {
+            let path = find_rfc_toml(config, id).ok_or_else(|| {
+                Diagnostic::new(
+                    DiagnosticCode::E0102RfcNotFound,
+                    format!("RFC not found: {id}"),
+                    id,
+                )
+            })?;
+            let rfc = load_rfc(config, &path).map_err(Diagnostic::from)?.rfc;
+            let status = rfc.status.as_ref().to_string();
+            Event {
+                event: NotifyEvent::RfcFinalize,
+                summary: format!(
+                    "{} {} finalized as {status} (v{})",
+                    rfc.rfc_id, rfc.title, rfc.version
+                ),
+                project,
+                id: rfc.rfc_id,
+                title: rfc.title,
+                status: Some(status),
+                version: Some(rfc.version),
+                work_items: vec![],
+            }
+        }
+        (Op::Lifecycle(LifecycleOp::AcceptAdr { .. }), Some(id)) => {
+            let adr = crate::artifact_catalog::load_adr_by_id(config, id)?;
+            let meta = adr.spec.govctl;
+            Event {
+                event: NotifyEvent::AdrAccept,
+                summary: format!("{} {} accepted", meta.id, meta.title),
+                project,
+                id: meta.id,
+                title: meta.title,
+                status: Some(meta.status.as_ref().to_string()),
+                version: None,
+                work_items: vec![],
+            }
+        }
+        (
+            Op::Lifecycle(LifecycleOp::MoveWork {
+                file_or_id,
+                status: WorkItemStatus::Done,
+                ..
+            }),
+            _,
+        ) => {
+            let path = crate::cmd::move_::resolve_work_path(config, file_or_id)?;
+            let meta = load_work_item(config, &path)?.spec.govctl;
+            Event {
+                event: NotifyEvent::WorkDone,
+                summary: format!("{} {} done", meta.id, meta.title),
+                project,
+                id: meta.id,
+                title: meta.title,
+                status: Some(meta.status.as_ref().to_string()),
+                version: None,
+                work_items: vec![],
+            }
+        }
+        (Op::Builtin(BuiltinOp::ReleaseCut { version, .. }), _) => {
+            let work_items = if op.is_preview() {
+                vec![]
+            } else {
+                load_releases(config)?
+                    .releases
+                    .into_iter()
+                    .find(|release| &release.version == version)
+                    .map(|release| release.refs)
+                    .unwrap_or_default()
+            };
+            Event {
+                event: NotifyEvent::Release,
+                summary: format!("Released {version} with {} work item(s)", work_items.len()),
+                project,
+                id: version.clone(),
+                title: format!("Release {version}"),
+                status: None,
+                version: Some(version.clone()),
+                work_items,
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
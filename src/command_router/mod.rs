@@ -6,39 +6,55 @@
 
 mod edit_action;
 mod execute;
+mod hooks;
 mod parsed;
 mod plan;
 
+use crate::aliases::Aliases;
 use crate::cmd;
+use crate::config::PrefixesConfig;
 use crate::diagnostic::DiagnosticResult;
-use crate::{ListTarget, OutputFormat, ShowOutputFormat};
+use crate::{ListTarget, OutputFormat, RenderLayoutArgs, ShowOutputFormat};
 
 pub(crate) type OwnedMatchOptions = cmd::edit::MatchOptionsOwned;
 pub(crate) type OwnedEditAction = cmd::edit::OwnedEditAction;
 
 pub(crate) use edit_action::{
-    add_action, owned_edit_action, remove_action, set_action, tick_action,
+    add_action, add_values_action, owned_edit_action, remove_action, set_action, tick_action,
 };
 pub use plan::{
     BuiltinOp, CommandPlan, CreateOp, EditExtras, EditOp, LifecycleOp, LockDisposition, Op, Scope,
 };
 
-fn artifact_scope(artifact: cmd::edit::ArtifactType, id: &str) -> Scope {
+fn artifact_scope(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    id: &str,
+) -> Scope {
     Scope::Artifact {
         artifact,
-        id: id.to_string(),
+        id: artifact.expand_shorthand(prefixes, aliases, id),
     }
 }
 
-fn resolve_scope(id: &str, field: Option<&str>) -> DiagnosticResult<Scope> {
-    let plan = cmd::edit::engine::plan_request(id, field)?;
+fn resolve_scope(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    id: &str,
+    field: Option<&str>,
+) -> DiagnosticResult<Scope> {
+    let id = artifact.expand_shorthand(prefixes, aliases, id);
+    let artifact = cmd::edit::ArtifactType::from_id_or(prefixes, &id, artifact);
+    let plan = cmd::edit::engine::plan_request_as(artifact, &id, field)?;
     Ok(match plan.target {
         Some(target) => Scope::Target {
             artifact: plan.artifact,
-            id: id.to_string(),
+            id,
             target,
         },
-        None => artifact_scope(plan.artifact, id),
+        None => artifact_scope(prefixes, aliases, plan.artifact, &id),
     })
 }
 
@@ -50,12 +66,28 @@ fn collection(target: ListTarget, op: Op) -> CommandPlan {
     CommandPlan::new(Scope::Collection { target }, op)
 }
 
-pub(crate) fn artifact(artifact: cmd::edit::ArtifactType, id: &str, op: Op) -> CommandPlan {
-    CommandPlan::new(artifact_scope(artifact, id), op)
+pub(crate) fn artifact(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    id: &str,
+    op: Op,
+) -> CommandPlan {
+    CommandPlan::new(artifact_scope(prefixes, aliases, artifact, id), op)
 }
 
-fn target(id: &str, field: Option<&str>, op: Op) -> DiagnosticResult<CommandPlan> {
-    Ok(CommandPlan::new(resolve_scope(id, field)?, op))
+fn target(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    id: &str,
+    field: Option<&str>,
+    op: Op,
+) -> DiagnosticResult<CommandPlan> {
+    Ok(CommandPlan::new(
+        resolve_scope(prefixes, aliases, artifact, id, field)?,
+        op,
+    ))
 }
 
 fn edit_op_with_extras(action: OwnedEditAction, extras: EditExtras) -> Op {
@@ -70,64 +102,117 @@ pub(crate) fn plan_list(
     target_kind: ListTarget,
     filter: Option<String>,
     limit: Option<usize>,
-    output: OutputFormat,
     tags: Vec<String>,
+    stale: bool,
 ) -> CommandPlan {
     collection(
         target_kind,
         Op::List {
             filter,
             limit,
-            output,
+            output: OutputFormat::default(),
             tags,
+            stale,
         },
     )
 }
 
-pub(crate) fn plan_get(id: &str, field: Option<&str>) -> DiagnosticResult<CommandPlan> {
-    target(id, field, Op::Get)
+pub(crate) fn plan_collection_builtin(
+    collection_target: ListTarget,
+    builtin: BuiltinOp,
+) -> CommandPlan {
+    collection(collection_target, Op::Builtin(builtin))
+}
+
+pub(crate) fn plan_get(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    id: &str,
+    field: Option<&str>,
+) -> DiagnosticResult<CommandPlan> {
+    target(prefixes, aliases, artifact, id, field, Op::Get)
 }
 
 pub(crate) fn plan_show(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact_type: cmd::edit::ArtifactType,
     id: &str,
-    output: ShowOutputFormat,
     history: bool,
 ) -> CommandPlan {
-    artifact(artifact_type, id, Op::Show { output, history })
+    artifact(
+        prefixes,
+        aliases,
+        artifact_type,
+        id,
+        Op::Show {
+            output: ShowOutputFormat::default(),
+            history,
+        },
+    )
 }
 
 pub(crate) fn plan_edit(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
     id: &str,
     field: &str,
     action: OwnedEditAction,
     extras: EditExtras,
 ) -> DiagnosticResult<CommandPlan> {
-    target(id, Some(field), edit_op_with_extras(action, extras))
+    target(
+        prefixes,
+        aliases,
+        artifact,
+        id,
+        Some(field),
+        edit_op_with_extras(action, extras),
+    )
 }
 
 pub(crate) fn plan_lifecycle(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact_type: cmd::edit::ArtifactType,
     id: &str,
     lifecycle: LifecycleOp,
 ) -> CommandPlan {
-    artifact(artifact_type, id, Op::Lifecycle(lifecycle))
+    artifact(
+        prefixes,
+        aliases,
+        artifact_type,
+        id,
+        Op::Lifecycle(lifecycle),
+    )
 }
 
 pub(crate) fn plan_artifact_render(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact_type: cmd::edit::ArtifactType,
     id: &str,
     dry_run: bool,
+    layout: RenderLayoutArgs,
 ) -> CommandPlan {
-    artifact(artifact_type, id, Op::RenderArtifact { dry_run })
+    artifact(
+        prefixes,
+        aliases,
+        artifact_type,
+        id,
+        Op::RenderArtifact { dry_run, layout },
+    )
 }
 
 pub(crate) fn plan_delete(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact_type: cmd::edit::ArtifactType,
     id: &str,
     force: bool,
 ) -> CommandPlan {
-    artifact(artifact_type, id, Op::Delete { force })
+    artifact(prefixes, aliases, artifact_type, id, Op::Delete { force })
 }
 
 #[cfg(test)]
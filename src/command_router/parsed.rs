@@ -1,34 +1,88 @@
-use super::{BuiltinOp, CommandPlan, Op, global};
+use super::{BuiltinOp, CommandPlan, EditOp, Op, artifact as artifact_plan, global};
+use crate::aliases::Aliases;
+use crate::cmd;
+use crate::config::PrefixesConfig;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
-use crate::{Commands, LoopCommand, ReleaseArgs, ReleaseCommand, TagCommand};
+use crate::{
+    AnchorCommand, AttachCommand, AuditCommand, Commands, ConfigCommand, DocsCommand, LoopCommand,
+    MappingCommand, OutputFormat, PublishCommand, ReleaseArgs, ReleaseCommand, ScanCommand,
+    SupersedeCommand, SyncCommand, TagCommand, TemplateCommand,
+};
 
 impl CommandPlan {
-    pub fn from_parsed(cmd: &Commands, global_dry_run: bool) -> DiagnosticResult<Self> {
+    /// Plan `cmd`, classifying and expanding IDs by the project's `prefixes`.
+    pub fn from_parsed(
+        cmd: &Commands,
+        global_dry_run: bool,
+        prefixes: &PrefixesConfig,
+        aliases: &Aliases,
+    ) -> DiagnosticResult<Self> {
         use crate::resource_plan::ToPlan;
 
         match cmd {
-            Commands::Init { force } => Ok(global(Op::Builtin(BuiltinOp::Init { force: *force }))),
-            Commands::InitSkills { force, format, dir } => {
-                Ok(global(Op::Builtin(BuiltinOp::InitSkills {
+            Commands::Init {
+                force,
+                from,
+                preset,
+                interactive,
+                vscode,
+            } => Ok(global(Op::Builtin(BuiltinOp::Init {
+                force: *force,
+                from: from.clone(),
+                preset: *preset,
+                interactive: *interactive,
+                vscode: *vscode,
+            }))),
+            Commands::InitSkills {
+                force,
+                format,
+                dir,
+                only,
+                diff,
+                check,
+            } => Ok(global(Op::Builtin(BuiltinOp::InitSkills {
+                sync: cmd::new::SkillSync {
                     force: *force,
-                    format: format.clone(),
-                    dir: dir.clone(),
-                })))
-            }
-            Commands::Check { has_active, .. } => Ok(global(Op::Builtin(BuiltinOp::Check {
+                    only: only.clone(),
+                    diff: *diff,
+                    check: *check,
+                },
+                format: format.clone(),
+                dir: dir.clone(),
+            }))),
+            Commands::Check {
+                workspace: true, ..
+            } => Ok(global(Op::Builtin(BuiltinOp::CheckWorkspace))),
+            Commands::Check {
+                has_active,
+                strict_refs,
+                ..
+            } => Ok(global(Op::Builtin(BuiltinOp::Check {
                 has_active: *has_active,
+                strict_refs: *strict_refs,
             }))),
             Commands::Status => Ok(global(Op::Builtin(BuiltinOp::Status))),
             Commands::Render {
                 target,
                 dry_run,
+                check,
                 force,
+                format,
+                layout,
             } => Ok(global(Op::Builtin(BuiltinOp::RenderGlobal {
                 target: *target,
                 dry_run: global_dry_run || *dry_run,
+                check: *check,
                 force: *force,
+                format: *format,
+                layout: layout.clone(),
             }))),
             Commands::Migrate => Ok(global(Op::Builtin(BuiltinOp::Migrate))),
+            Commands::Fmt { check } => Ok(global(Op::Builtin(BuiltinOp::Fmt { check: *check }))),
+            Commands::Explain { code } => Ok(global(Op::Builtin(BuiltinOp::Explain {
+                code: code.clone(),
+                output: OutputFormat::default(),
+            }))),
             Commands::Verify { guard_ids, work } => Ok(global(Op::Builtin(BuiltinOp::Verify {
                 guard_ids: guard_ids.clone(),
                 work: work.clone(),
@@ -38,35 +92,107 @@ impl CommandPlan {
                 types,
                 tag,
                 limit,
-                output,
                 reindex,
             } => Ok(global(Op::Builtin(BuiltinOp::Search {
                 query: query.clone(),
                 types: types.clone(),
                 tags: tag.clone(),
                 limit: *limit,
-                output: *output,
+                output: OutputFormat::default(),
                 reindex: *reindex,
             }))),
-            Commands::Describe { context, .. } => Ok(global(Op::Builtin(BuiltinOp::Describe {
+            Commands::Describe {
+                context, schemas, ..
+            } => Ok(global(Op::Builtin(BuiltinOp::Describe {
                 context: *context,
+                schemas: *schemas,
             }))),
             Commands::Completions { shell } => Ok(global(Op::Builtin(BuiltinOp::Completions {
                 shell: *shell,
             }))),
+            Commands::Docs { command } => Ok(global(Op::Builtin(match command {
+                DocsCommand::Man { out } => BuiltinOp::DocsMan { out: out.clone() },
+                DocsCommand::Markdown { out } => BuiltinOp::DocsMarkdown { out: out.clone() },
+            }))),
             Commands::SelfUpdate { check } => {
                 Ok(global(Op::Builtin(BuiltinOp::SelfUpdate { check: *check })))
             }
             #[cfg(feature = "tui")]
             Commands::Tui => Ok(global(Op::Builtin(BuiltinOp::Tui))),
-            Commands::Rfc { command } => command.to_plan(),
-            Commands::Clause { command } => command.to_plan(),
-            Commands::Adr { command } => command.to_plan(),
-            Commands::Work { command } => command.to_plan(),
-            Commands::Guard { command } => command.to_plan(),
+            Commands::Rfc { command } => command.to_plan(prefixes, aliases),
+            Commands::Clause { command } => command.to_plan(prefixes, aliases),
+            Commands::Adr { command } => command.to_plan(prefixes, aliases),
+            Commands::Work { command } => command.to_plan(prefixes, aliases),
+            Commands::Guard { command } => command.to_plan(prefixes, aliases),
             Commands::Loop { command } => Ok(plan_loop_command(command)),
             Commands::Release(args) => plan_release_command(args),
             Commands::Tag { command } => Ok(plan_tag_command(command)),
+            Commands::Mapping { command } => Ok(plan_mapping_command(command)),
+            Commands::Attach { command } => Ok(plan_attach_command(command)),
+            Commands::Supersede {
+                command: SupersedeCommand::Chain { id },
+            } => Ok(global(Op::Builtin(BuiltinOp::SupersedeChain {
+                id: id.clone(),
+            }))),
+            Commands::Anchor {
+                command: AnchorCommand::List,
+            } => Ok(global(Op::Builtin(BuiltinOp::AnchorList {
+                output: OutputFormat::default(),
+            }))),
+            Commands::Scan {
+                command: ScanCommand::Coverage { rfc, .. },
+            } => Ok(global(Op::Builtin(BuiltinOp::ScanCoverage {
+                rfc: rfc.clone(),
+                output: OutputFormat::default(),
+            }))),
+            Commands::Config { command } => Ok(plan_config_command(command)),
+            Commands::Export { out } => {
+                Ok(global(Op::Builtin(BuiltinOp::Export { out: out.clone() })))
+            }
+            Commands::Publish {
+                command: PublishCommand::Confluence { ids },
+            } => Ok(global(Op::Builtin(BuiltinOp::PublishConfluence {
+                ids: ids.clone(),
+            }))),
+            Commands::Sync {
+                command: SyncCommand::Jira { ids },
+            } => Ok(global(Op::Builtin(BuiltinOp::SyncJira {
+                ids: ids.clone(),
+            }))),
+            Commands::Template { command } => Ok(plan_template_command(command)),
+            Commands::Apply { file, .. } => Ok(global(Op::Builtin(BuiltinOp::Apply {
+                file: file.clone(),
+                assume_yes: false,
+            }))),
+            Commands::Restore { id } => {
+                Ok(global(Op::Builtin(BuiltinOp::Restore { id: id.clone() })))
+            }
+            Commands::Undo { force } => Ok(global(Op::Builtin(BuiltinOp::Undo { force: *force }))),
+            Commands::History { limit } => Ok(global(Op::Builtin(BuiltinOp::History {
+                limit: *limit,
+                output: OutputFormat::default(),
+            }))),
+            Commands::Audit { command } => Ok(global(Op::Builtin(match command {
+                AuditCommand::List { limit } => BuiltinOp::AuditList {
+                    limit: *limit,
+                    output: OutputFormat::default(),
+                },
+                AuditCommand::Show { seq } => BuiltinOp::AuditShow {
+                    seq: *seq,
+                    output: OutputFormat::default(),
+                },
+            }))),
+            Commands::Edit { id, .. } => {
+                let artifact = cmd::edit::ArtifactType::from_id(prefixes, id)
+                    .ok_or_else(|| cmd::edit::ArtifactType::unknown_error(id))?;
+                Ok(artifact_plan(
+                    prefixes,
+                    aliases,
+                    artifact,
+                    id,
+                    Op::Edit(EditOp::Editor),
+                ))
+            }
         }
     }
 }
@@ -85,6 +211,7 @@ fn plan_release_command(args: &ReleaseArgs) -> DiagnosticResult<CommandPlan> {
                 )
             })?,
             date: args.date.clone(),
+            github: args.github,
         },
     };
     Ok(global(Op::Builtin(op)))
@@ -92,14 +219,10 @@ fn plan_release_command(args: &ReleaseArgs) -> DiagnosticResult<CommandPlan> {
 
 fn plan_loop_command(command: &LoopCommand) -> CommandPlan {
     let op = match command {
-        LoopCommand::List {
-            filter,
-            limit,
-            output,
-        } => BuiltinOp::LoopList {
+        LoopCommand::List { filter, limit } => BuiltinOp::LoopList {
             filter: filter.clone(),
             limit: *limit,
-            output: *output,
+            output: OutputFormat::default(),
         },
         LoopCommand::Start { id, work_ids } => BuiltinOp::LoopStart {
             loop_id: id.clone(),
@@ -139,7 +262,88 @@ fn plan_tag_command(command: &TagCommand) -> CommandPlan {
     let op = match command {
         TagCommand::New { tag } => BuiltinOp::TagNew { tag: tag.clone() },
         TagCommand::Delete { tag } => BuiltinOp::TagDelete { tag: tag.clone() },
-        TagCommand::List { output } => BuiltinOp::TagList { output: *output },
+        TagCommand::List => BuiltinOp::TagList {
+            output: OutputFormat::default(),
+        },
+    };
+    global(Op::Builtin(op))
+}
+
+fn plan_mapping_command(command: &MappingCommand) -> CommandPlan {
+    let op = match command {
+        MappingCommand::Add {
+            standard,
+            control,
+            clauses,
+            title,
+            standard_title,
+        } => BuiltinOp::MappingAdd {
+            standard: standard.clone(),
+            control: control.clone(),
+            clauses: clauses.clone(),
+            options: cmd::mapping::MappingAddOptions {
+                title: title.clone(),
+                standard_title: standard_title.clone(),
+            },
+        },
+        MappingCommand::Remove {
+            standard,
+            control,
+            clauses,
+        } => BuiltinOp::MappingRemove {
+            standard: standard.clone(),
+            control: control.clone(),
+            clauses: clauses.clone(),
+        },
+        MappingCommand::List { standard } => BuiltinOp::MappingList {
+            standard: standard.clone(),
+            output: OutputFormat::default(),
+        },
+    };
+    global(Op::Builtin(op))
+}
+
+fn plan_attach_command(command: &AttachCommand) -> CommandPlan {
+    let op = match command {
+        AttachCommand::Add { id, file } => BuiltinOp::AttachAdd {
+            id: id.clone(),
+            file: file.clone(),
+        },
+        AttachCommand::Remove { id, name } => BuiltinOp::AttachRemove {
+            id: id.clone(),
+            name: name.clone(),
+        },
+        AttachCommand::List { id } => BuiltinOp::AttachList {
+            id: id.clone(),
+            output: OutputFormat::default(),
+        },
+    };
+    global(Op::Builtin(op))
+}
+
+fn plan_config_command(command: &ConfigCommand) -> CommandPlan {
+    let op = match command {
+        ConfigCommand::Check { .. } => BuiltinOp::ConfigCheck,
+        ConfigCommand::Show { resolved } => BuiltinOp::ConfigShow {
+            resolved: *resolved,
+            output: OutputFormat::default(),
+        },
+        ConfigCommand::Get { key } => BuiltinOp::ConfigGet { key: key.clone() },
+        ConfigCommand::Set { key, value, local } => BuiltinOp::ConfigSet {
+            key: key.clone(),
+            value: value.clone(),
+            local: *local,
+        },
+        ConfigCommand::List => BuiltinOp::ConfigList {
+            output: OutputFormat::default(),
+        },
+    };
+    global(Op::Builtin(op))
+}
+
+fn plan_template_command(command: &TemplateCommand) -> CommandPlan {
+    let op = match command {
+        TemplateCommand::Pack { out } => BuiltinOp::TemplatePack { out: out.clone() },
     };
     global(Op::Builtin(op))
 }
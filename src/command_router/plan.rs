@@ -1,10 +1,15 @@
-use super::{OwnedEditAction, execute};
+use super::{OwnedEditAction, hooks};
+use crate::artifact_catalog::{CatalogKind, missing_id_message, resolve_partial_id};
 use crate::cmd;
 use crate::config::Config;
-use crate::diagnostic::{DiagnosticResult, Diagnostics};
-use crate::model::{ChangelogCategory, ClauseKind, RfcPhase, WorkItemStatus};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::identity::Actor;
+use crate::model::{ChangelogCategory, ClauseKind, Deprecation, RfcPhase, WorkItemStatus};
 use crate::write::{BumpLevel, WriteOp};
-use crate::{FinalizeStatus, ListTarget, OutputFormat, RenderTarget, ShowOutputFormat};
+use crate::{
+    FinalizeStatus, ListTarget, OutputArg, OutputFormat, Persona, RenderFormat, RenderLayoutArgs,
+    RenderTarget, ShowOutputFormat,
+};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,22 +42,57 @@ pub struct EditExtras {
 pub enum BuiltinOp {
     Init {
         force: bool,
+        from: Option<PathBuf>,
+        preset: Option<crate::InitPreset>,
+        /// Prompt for the scaffold instead of writing it as-is.
+        interactive: bool,
+        /// Also write `.vscode/settings.json` schema associations.
+        vscode: bool,
     },
     InitSkills {
-        force: bool,
+        sync: crate::cmd::new::SkillSync,
         format: crate::SkillFormat,
         dir: Option<std::path::PathBuf>,
     },
     Check {
         has_active: bool,
+        /// Report W0123 inactive refs as errors
+        strict_refs: bool,
     },
+    CheckWorkspace,
     Status,
+    ConfigCheck,
+    ConfigShow {
+        resolved: bool,
+        output: OutputFormat,
+    },
+    ConfigGet {
+        key: String,
+    },
+    ConfigSet {
+        key: String,
+        value: String,
+        local: bool,
+    },
+    ConfigList {
+        output: OutputFormat,
+    },
     RenderGlobal {
         target: RenderTarget,
         dry_run: bool,
+        check: bool,
         force: bool,
+        format: RenderFormat,
+        layout: RenderLayoutArgs,
     },
     Migrate,
+    Fmt {
+        check: bool,
+    },
+    Explain {
+        code: Option<String>,
+        output: OutputFormat,
+    },
     Verify {
         guard_ids: Vec<String>,
         work: Option<String>,
@@ -67,10 +107,17 @@ pub enum BuiltinOp {
     },
     Describe {
         context: bool,
+        schemas: bool,
     },
     Completions {
         shell: clap_complete::Shell,
     },
+    DocsMan {
+        out: Option<PathBuf>,
+    },
+    DocsMarkdown {
+        out: Option<PathBuf>,
+    },
     SelfUpdate {
         check: bool,
     },
@@ -79,6 +126,7 @@ pub enum BuiltinOp {
     ReleaseCut {
         version: String,
         date: Option<String>,
+        github: bool,
     },
     ReleaseUndo {
         expected_version: String,
@@ -92,6 +140,74 @@ pub enum BuiltinOp {
     TagList {
         output: crate::OutputFormat,
     },
+    MappingAdd {
+        standard: String,
+        control: String,
+        clauses: Vec<String>,
+        options: crate::cmd::mapping::MappingAddOptions,
+    },
+    MappingRemove {
+        standard: String,
+        control: String,
+        clauses: Vec<String>,
+    },
+    MappingList {
+        standard: Option<String>,
+        output: crate::OutputFormat,
+    },
+    AttachAdd {
+        id: String,
+        file: std::path::PathBuf,
+    },
+    AttachRemove {
+        id: String,
+        name: String,
+    },
+    AttachList {
+        id: Option<String>,
+        output: crate::OutputFormat,
+    },
+    SupersedeChain {
+        id: String,
+    },
+    ScanCoverage {
+        rfc: Option<String>,
+        output: crate::OutputFormat,
+    },
+    AnchorList {
+        output: crate::OutputFormat,
+    },
+    PublishConfluence {
+        ids: Vec<String>,
+    },
+    SyncJira {
+        ids: Vec<String>,
+    },
+    WorkListGithub {
+        filter: Option<String>,
+        limit: Option<usize>,
+        output: OutputFormat,
+        tags: Vec<String>,
+    },
+    TemplatePack {
+        out: PathBuf,
+    },
+    Export {
+        out: PathBuf,
+    },
+    WorkPrioritize {
+        apply: bool,
+        output: crate::OutputFormat,
+    },
+    WorkRank {
+        id: String,
+        position: cmd::queue::RankPosition,
+    },
+    WorkNext {
+        filter: cmd::queue::NextFilter,
+        activate: bool,
+        output: crate::OutputFormat,
+    },
     LoopStart {
         loop_id: Option<String>,
         work_ids: Vec<String>,
@@ -124,21 +240,71 @@ pub enum BuiltinOp {
         loop_id: String,
         target_work_ids: Vec<String>,
     },
+    Apply {
+        /// Script path; `None` reads the script from stdin.
+        file: Option<PathBuf>,
+        /// `--yes` for every step.
+        assume_yes: bool,
+    },
+    Restore {
+        id: String,
+    },
+    Undo {
+        force: bool,
+    },
+    History {
+        limit: Option<usize>,
+        output: crate::OutputFormat,
+    },
+    AuditList {
+        limit: Option<usize>,
+        output: crate::OutputFormat,
+    },
+    AuditShow {
+        seq: u64,
+        output: crate::OutputFormat,
+    },
 }
 
 impl BuiltinOp {
     fn is_lock_free(&self) -> bool {
         match self {
             Self::Check { .. }
+            | Self::CheckWorkspace
             | Self::Status
-            | Self::Verify { .. }
+            | Self::ConfigCheck
+            | Self::ConfigShow { .. }
+            | Self::ConfigGet { .. }
+            | Self::ConfigList { .. }
+            | Self::Verify { work: None, .. }
             | Self::Describe { .. }
+            | Self::Explain { .. }
             | Self::Completions { .. }
+            | Self::DocsMan { .. }
+            | Self::DocsMarkdown { .. }
             | Self::SelfUpdate { .. }
             | Self::TagList { .. }
+            | Self::MappingList { .. }
+            | Self::AttachList { .. }
+            | Self::SupersedeChain { .. }
+            | Self::ScanCoverage { .. }
+            | Self::AnchorList { .. }
+            | Self::TemplatePack { .. }
+            | Self::Export { .. }
+            | Self::PublishConfluence { .. }
+            | Self::WorkListGithub { .. }
+            | Self::WorkPrioritize { apply: false, .. }
+            | Self::WorkNext {
+                activate: false, ..
+            }
+            | Self::Fmt { check: true }
+            | Self::RenderGlobal { check: true, .. }
             | Self::LoopList { .. }
             | Self::LoopShow { .. }
-            | Self::LoopResume { .. } => true,
+            | Self::LoopResume { .. }
+            | Self::History { .. }
+            | Self::AuditList { .. }
+            | Self::AuditShow { .. } => true,
             // [[RFC-0002:C-SEARCH-COMMAND]]: search may sync `.govctl/`
             // derived local state but must not mutate governed artifacts or
             // rendered docs; [[RFC-0004:C-DEFINITIONS]] keeps that outside the
@@ -173,6 +339,14 @@ pub enum CreateOp {
     Guard {
         title: String,
     },
+    /// `work clone`: the source work item is the plan's scope.
+    WorkClone {
+        title: Option<String>,
+    },
+    Wizard {
+        target: cmd::new::WizardTarget,
+        title: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -186,6 +360,13 @@ pub enum EditOp {
         text_file: Option<PathBuf>,
         stdin: bool,
     },
+    /// `adr alt set`: update one alternative, matched by text or index
+    Alternative {
+        pattern: Option<String>,
+        at: Option<i32>,
+        update: cmd::edit::AlternativeUpdate,
+    },
+    Editor,
 }
 
 #[derive(Debug, Clone)]
@@ -198,15 +379,56 @@ pub enum LifecycleOp {
     Finalize {
         status: FinalizeStatus,
     },
+    /// Approve as the identity given by the global `--as`.
+    Approve,
+    Freeze,
+    Unfreeze,
     Advance {
         phase: RfcPhase,
+        force: bool,
+    },
+    /// `rfc advance --report`: list blockers, change nothing.
+    AdvanceReport {
+        phase: RfcPhase,
     },
     Deprecate {
         force: bool,
+        notice: Option<Deprecation>,
     },
     Supersede {
         by: String,
         force: bool,
+        /// Rewrite inbound `refs` to `by` and report source references
+        update_refs: bool,
+    },
+    SplitRfc {
+        clauses: Vec<String>,
+        into: String,
+        title: String,
+        force: bool,
+    },
+    MergeRfc {
+        into: String,
+        force: bool,
+    },
+    ReorderClauses {
+        section: String,
+        order: Vec<String>,
+    },
+    AddSection {
+        title: String,
+        after: Option<String>,
+    },
+    RenameSection {
+        from: String,
+        to: String,
+    },
+    RemoveSection {
+        title: String,
+        force: bool,
+    },
+    ReorderSections {
+        order: Vec<String>,
     },
     AcceptAdr {
         force: bool,
@@ -215,6 +437,10 @@ pub enum LifecycleOp {
     MoveWork {
         file_or_id: PathBuf,
         status: WorkItemStatus,
+        /// `--reopen --reason`, required for done → active and cancelled → queue
+        reopen_reason: Option<String>,
+        /// Set by `work finish`
+        finish: Option<cmd::move_::FinishOptions>,
     },
 }
 
@@ -228,6 +454,8 @@ pub enum Op {
         output: OutputFormat,
         /// Tags to filter by (artifact must have ALL specified tags) — [[RFC-0002:C-CRUD-VERBS]]
         tags: Vec<String>,
+        /// Only artifacts past the `[staleness]` limits.
+        stale: bool,
     },
     Get,
     Show {
@@ -241,6 +469,11 @@ pub enum Op {
     },
     RenderArtifact {
         dry_run: bool,
+        layout: RenderLayoutArgs,
+    },
+    /// `clause history`: text changes from git, newest `limit` kept.
+    TextHistory {
+        limit: Option<usize>,
     },
 }
 
@@ -248,7 +481,11 @@ impl Op {
     fn is_lock_free(&self) -> bool {
         match self {
             Self::Builtin(builtin) => builtin.is_lock_free(),
-            Self::Get | Self::List { .. } | Self::Show { .. } => true,
+            Self::Get
+            | Self::List { .. }
+            | Self::Show { .. }
+            | Self::TextHistory { .. }
+            | Self::Lifecycle(LifecycleOp::AdvanceReport { .. }) => true,
             _ => false,
         }
     }
@@ -264,11 +501,60 @@ pub enum LockDisposition {
 pub struct CommandPlan {
     pub scope: Scope,
     pub op: Op,
+    /// Who progress and other output is addressed to (`--for`).
+    pub persona: Persona,
 }
 
 impl CommandPlan {
     pub(super) fn new(scope: Scope, op: Op) -> Self {
-        Self { scope, op }
+        Self {
+            scope,
+            op,
+            persona: Persona::Human,
+        }
+    }
+
+    /// Replace a partial ID or title fragment in the scope with the full ID
+    /// of the one artifact it names. Full IDs are left to the command.
+    pub fn resolve_ids(&mut self, config: &Config) -> DiagnosticResult<()> {
+        let (Scope::Artifact { artifact, id } | Scope::Target { artifact, id, .. }) =
+            &mut self.scope
+        else {
+            return Ok(());
+        };
+        // Planning expanded every alias it knows.
+        if id.starts_with('@') {
+            return Err(crate::aliases::unknown_alias(config, id));
+        }
+        if cmd::edit::ArtifactType::prefixed(&config.prefixes, id).is_some() {
+            return Ok(());
+        }
+        // `work move` also takes a file path or part of a file name.
+        let moved = match &mut self.op {
+            Op::Lifecycle(LifecycleOp::MoveWork { file_or_id, .. }) => Some(file_or_id),
+            _ => None,
+        };
+        if moved.as_ref().is_some_and(|file| file.exists()) {
+            return Ok(());
+        }
+        let kind = CatalogKind::of_artifact(*artifact);
+        match resolve_partial_id(config, kind, id)? {
+            Some(resolved) => {
+                if let Some(file_or_id) = moved {
+                    *file_or_id = PathBuf::from(&resolved);
+                }
+                *id = resolved;
+            }
+            None if moved.is_some() => {}
+            None => {
+                return Err(Diagnostic::new(
+                    kind.not_found_code(),
+                    missing_id_message(config, kind, id),
+                    id.as_str(),
+                ));
+            }
+        }
+        Ok(())
     }
 
     pub fn lock_disposition(&self) -> LockDisposition {
@@ -279,7 +565,182 @@ impl CommandPlan {
         }
     }
 
-    pub fn execute(&self, config: &Config, op: WriteOp) -> DiagnosticResult<Diagnostics> {
-        execute::execute_plan(self, config, op)
+    /// Whether the command prints its own result on stdout.
+    ///
+    /// Under `--output json` these commands emit their data instead of a result
+    /// envelope; the rest only report progress on stderr.
+    pub fn prints_own_result(&self) -> bool {
+        match &self.op {
+            Op::List { .. }
+            | Op::Show { .. }
+            | Op::Get
+            | Op::TextHistory { .. }
+            | Op::Lifecycle(LifecycleOp::AdvanceReport { .. }) => true,
+            Op::Builtin(builtin) => matches!(
+                builtin,
+                BuiltinOp::Status
+                    | BuiltinOp::ConfigShow { .. }
+                    | BuiltinOp::ConfigGet { .. }
+                    | BuiltinOp::ConfigList { .. }
+                    | BuiltinOp::Search { .. }
+                    | BuiltinOp::Describe { .. }
+                    | BuiltinOp::Explain { .. }
+                    | BuiltinOp::Completions { .. }
+                    | BuiltinOp::DocsMan { out: None }
+                    | BuiltinOp::DocsMarkdown { out: None }
+                    | BuiltinOp::TagList { .. }
+                    | BuiltinOp::MappingList { .. }
+                    | BuiltinOp::AttachList { .. }
+                    | BuiltinOp::SupersedeChain { .. }
+                    | BuiltinOp::ScanCoverage { .. }
+                    | BuiltinOp::AnchorList { .. }
+                    | BuiltinOp::WorkListGithub { .. }
+                    | BuiltinOp::WorkPrioritize { .. }
+                    | BuiltinOp::WorkNext { .. }
+                    | BuiltinOp::LoopStart { .. }
+                    | BuiltinOp::LoopList { .. }
+                    | BuiltinOp::LoopShow { .. }
+                    | BuiltinOp::LoopResume { .. }
+                    | BuiltinOp::LoopReplan { .. }
+                    | BuiltinOp::LoopRun { .. }
+                    | BuiltinOp::History { .. }
+                    | BuiltinOp::AuditList { .. }
+                    | BuiltinOp::AuditShow { .. }
+            ),
+            _ => false,
+        }
+    }
+
+    /// Apply the global `-o/--output` format.
+    ///
+    /// Lists and reports take table, json, or plain, and `show` also takes
+    /// yaml and toml. Other commands take table, or json for a result
+    /// envelope; any other format is an error rather than silently ignored.
+    /// `--yes`: answer the command's confirmation prompt as `--force` does.
+    /// `apply` passes it on to each step.
+    pub fn assume_yes(&mut self) {
+        match &mut self.op {
+            Op::Delete { force }
+            | Op::Lifecycle(
+                LifecycleOp::Deprecate { force, .. }
+                | LifecycleOp::Supersede { force, .. }
+                | LifecycleOp::SplitRfc { force, .. }
+                | LifecycleOp::MergeRfc { force, .. },
+            ) => *force = true,
+            Op::Builtin(BuiltinOp::Apply { assume_yes, .. }) => *assume_yes = true,
+            _ => {}
+        }
+    }
+
+    pub fn select_output(&mut self, format: OutputArg) -> DiagnosticResult<()> {
+        let unsupported = || {
+            Diagnostic::new(
+                DiagnosticCode::E0822UnsupportedOperation,
+                format!("This command does not support --output {}", format.name()),
+                "--output",
+            )
+        };
+        match &mut self.op {
+            Op::List { output, .. }
+            | Op::Builtin(
+                BuiltinOp::Search { output, .. }
+                | BuiltinOp::ConfigShow { output, .. }
+                | BuiltinOp::ConfigList { output }
+                | BuiltinOp::Explain { output, .. }
+                | BuiltinOp::TagList { output }
+                | BuiltinOp::MappingList { output, .. }
+                | BuiltinOp::AttachList { output, .. }
+                | BuiltinOp::ScanCoverage { output, .. }
+                | BuiltinOp::AnchorList { output }
+                | BuiltinOp::WorkListGithub { output, .. }
+                | BuiltinOp::WorkPrioritize { output, .. }
+                | BuiltinOp::WorkNext { output, .. }
+                | BuiltinOp::LoopList { output, .. }
+                | BuiltinOp::History { output, .. }
+                | BuiltinOp::AuditList { output, .. }
+                | BuiltinOp::AuditShow { output, .. },
+            ) => *output = format.list_format().ok_or_else(unsupported)?,
+            Op::Show { output, .. } => *output = format.show_format(),
+            _ if matches!(format, OutputArg::Table | OutputArg::Json) => {}
+            _ => return Err(unsupported()),
+        }
+        Ok(())
+    }
+
+    /// Whether the command's writes are recorded for `govctl undo`.
+    ///
+    /// Covers artifact mutations and the builtins that edit governed files;
+    /// setup, migration, rendering, and loop state are not undoable.
+    pub fn records_history(&self) -> bool {
+        match &self.op {
+            Op::Lifecycle(LifecycleOp::AdvanceReport { .. }) => false,
+            Op::Create(_) | Op::Edit(_) | Op::Lifecycle(_) | Op::Delete { .. } => true,
+            Op::Builtin(builtin) => matches!(
+                builtin,
+                BuiltinOp::ReleaseCut { .. }
+                    | BuiltinOp::ReleaseUndo { .. }
+                    | BuiltinOp::ConfigSet { .. }
+                    | BuiltinOp::TagNew { .. }
+                    | BuiltinOp::TagDelete { .. }
+                    | BuiltinOp::MappingAdd { .. }
+                    | BuiltinOp::MappingRemove { .. }
+                    | BuiltinOp::AttachAdd { .. }
+                    | BuiltinOp::AttachRemove { .. }
+                    | BuiltinOp::WorkPrioritize { apply: true, .. }
+                    | BuiltinOp::WorkRank { .. }
+                    | BuiltinOp::WorkNext { activate: true, .. }
+                    | BuiltinOp::Verify { work: Some(_), .. }
+                    | BuiltinOp::SyncJira { .. }
+                    | BuiltinOp::Apply { .. }
+                    | BuiltinOp::Restore { .. }
+            ),
+            _ => false,
+        }
+    }
+
+    /// Values the command writes into ADR fields marked `sensitive`.
+    ///
+    /// They are sealed in the ADR, so the history and audit log must not
+    /// record them in plaintext as part of the command line.
+    pub fn sensitive_values(&self, config: &Config) -> Vec<&str> {
+        let Scope::Target {
+            artifact: cmd::edit::ArtifactType::Adr,
+            id,
+            target,
+        } = &self.scope
+        else {
+            return vec![];
+        };
+        let values: Vec<&str> = match &self.op {
+            Op::Edit(EditOp::Field {
+                action: OwnedEditAction::Set { value, .. },
+                ..
+            }) => value.iter().flatten().map(String::as_str).collect(),
+            Op::Edit(EditOp::Field {
+                action: OwnedEditAction::Add { values, .. },
+                ..
+            }) => values.iter().map(String::as_str).collect(),
+            _ => vec![],
+        };
+        let Some(field) = target.path().segments.last() else {
+            return vec![];
+        };
+        if values.is_empty() || !crate::secrets::SENSITIVE_FIELDS.contains(&field.name.as_str()) {
+            return vec![];
+        }
+        let sensitive = crate::artifact_catalog::load_adr_by_id(config, id)
+            .is_ok_and(|entry| entry.spec.govctl.sensitive.contains(&field.name));
+        if sensitive { values } else { vec![] }
+    }
+
+    /// Run the plan as `actor`, who is recorded on what it creates and
+    /// approves.
+    pub fn execute(
+        &self,
+        config: &Config,
+        actor: Option<&Actor>,
+        op: WriteOp,
+    ) -> DiagnosticResult<Diagnostics> {
+        hooks::execute_with_hooks(self, config, actor, op)
     }
 }
@@ -19,7 +19,7 @@ fn test_from_clause_command_uses_canonical_edit_when_path_is_present()
         text_file: None,
     };
 
-    let plan = cmd.to_plan()?;
+    let plan = cmd.to_plan(&PrefixesConfig::default(), &Aliases::default())?;
     assert!(matches!(
         plan.scope,
         Scope::Target {
@@ -59,7 +59,7 @@ fn test_from_clause_command_requires_path_for_canonical_flags()
         text_file: None,
     };
 
-    let result = cmd.to_plan();
+    let result = cmd.to_plan(&PrefixesConfig::default(), &Aliases::default());
     assert!(result.is_err(), "missing path should fail");
     let diag = result.err().ok_or("expected Err")?;
     assert_eq!(diag.code, DiagnosticCode::E0801MissingRequiredArg);
@@ -85,7 +85,7 @@ fn test_from_clause_command_uses_legacy_edit_without_canonical_flags()
         text_file: None,
     };
 
-    let plan = cmd.to_plan()?;
+    let plan = cmd.to_plan(&PrefixesConfig::default(), &Aliases::default())?;
     assert!(matches!(
         plan.scope,
         Scope::Artifact {
@@ -127,7 +127,7 @@ fn test_from_clause_command_rejects_mixed_canonical_and_legacy_edit_flags()
         text_file: None,
     };
 
-    let result = cmd.to_plan();
+    let result = cmd.to_plan(&PrefixesConfig::default(), &Aliases::default());
     assert!(result.is_err(), "mixed modes should fail");
     let diag = result.err().ok_or("expected Err")?;
     assert_eq!(diag.code, DiagnosticCode::E0802ConflictingArgs);
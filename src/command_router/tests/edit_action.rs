@@ -116,8 +116,8 @@ fn test_owned_edit_action_preserves_explicit_empty_strings()
         all: false,
     })?;
     match add {
-        OwnedEditAction::Add { value, stdin } => {
-            assert_eq!(value.as_ref(), Some(&Some(String::new())));
+        OwnedEditAction::Add { values, stdin, .. } => {
+            assert_eq!(values, vec![String::new()]);
             assert!(!stdin);
         }
         other => return Err(format!("expected add action, got {other:?}").into()),
@@ -17,7 +17,7 @@ fn test_edit_plans_are_mutating() -> Result<(), Box<dyn std::error::Error>> {
             all: false,
         },
     })
-    .to_plan()?;
+    .to_plan(&PrefixesConfig::default(), &Aliases::default())?;
     assert!(matches!(plan.scope, Scope::Target { .. }));
     assert!(matches!(plan.op, Op::Edit(EditOp::Field { .. })));
     assert_eq!(plan.lock_disposition(), LockDisposition::GovRootExclusive);
@@ -37,7 +37,7 @@ fn test_edit_plans_are_mutating() -> Result<(), Box<dyn std::error::Error>> {
         text: None,
         text_file: None,
     }
-    .to_plan()?;
+    .to_plan(&PrefixesConfig::default(), &Aliases::default())?;
     assert!(matches!(plan.op, Op::Edit(EditOp::ClauseLegacy { .. })));
     assert_eq!(plan.lock_disposition(), LockDisposition::GovRootExclusive);
     Ok(())
@@ -48,7 +48,13 @@ fn test_read_plans_are_lock_free() -> Result<(), Box<dyn std::error::Error>> {
     let status = global(Op::Builtin(BuiltinOp::Status));
     assert_eq!(status.lock_disposition(), LockDisposition::None);
 
-    let plan = plan_get("RFC-0001", Some("title"))?;
+    let plan = plan_get(
+        &PrefixesConfig::default(),
+        &Aliases::default(),
+        cmd::edit::ArtifactType::Rfc,
+        "RFC-0001",
+        Some("title"),
+    )?;
     assert!(matches!(plan.scope, Scope::Target { .. }));
     assert!(matches!(plan.op, Op::Get));
     assert_eq!(plan.lock_disposition(), LockDisposition::None);
@@ -62,6 +68,18 @@ fn test_lock_disposition_is_lock_free_for_inspect_commands()
         global(Op::Builtin(BuiltinOp::Status)).lock_disposition(),
         LockDisposition::None
     );
+    assert_eq!(
+        global(Op::Builtin(BuiltinOp::ConfigCheck)).lock_disposition(),
+        LockDisposition::None
+    );
+    assert_eq!(
+        global(Op::Builtin(BuiltinOp::ConfigShow {
+            resolved: true,
+            output: OutputFormat::Table,
+        }))
+        .lock_disposition(),
+        LockDisposition::None
+    );
     assert_eq!(
         global(Op::Builtin(BuiltinOp::LoopList {
             filter: None,
@@ -92,6 +110,22 @@ fn test_lock_disposition_is_lock_free_for_inspect_commands()
         .lock_disposition(),
         LockDisposition::None
     );
+    assert_eq!(
+        global(Op::Builtin(BuiltinOp::ScanCoverage {
+            rfc: None,
+            output: OutputFormat::Table,
+        }))
+        .lock_disposition(),
+        LockDisposition::None
+    );
+    assert_eq!(
+        global(Op::Builtin(BuiltinOp::WorkPrioritize {
+            apply: false,
+            output: OutputFormat::Table,
+        }))
+        .lock_disposition(),
+        LockDisposition::None
+    );
     assert_eq!(
         global(Op::Builtin(BuiltinOp::Search {
             query: vec!["cache".to_string()],
@@ -105,14 +139,22 @@ fn test_lock_disposition_is_lock_free_for_inspect_commands()
         LockDisposition::None
     );
     assert_eq!(
-        plan_get("RFC-0001", Some("title"))?.lock_disposition(),
+        plan_get(
+            &PrefixesConfig::default(),
+            &Aliases::default(),
+            cmd::edit::ArtifactType::Rfc,
+            "RFC-0001",
+            Some("title")
+        )?
+        .lock_disposition(),
         LockDisposition::None
     );
     assert_eq!(
         plan_show(
+            &PrefixesConfig::default(),
+            &Aliases::default(),
             cmd::edit::ArtifactType::Adr,
             "ADR-0038",
-            crate::ShowOutputFormat::Table,
             false,
         )
         .lock_disposition(),
@@ -124,6 +166,14 @@ fn test_lock_disposition_is_lock_free_for_inspect_commands()
 #[test]
 fn test_lock_disposition_requires_lock_for_mutating_commands()
 -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        global(Op::Builtin(BuiltinOp::WorkPrioritize {
+            apply: true,
+            output: OutputFormat::Table,
+        }))
+        .lock_disposition(),
+        LockDisposition::GovRootExclusive
+    );
     assert_eq!(
         global(Op::Builtin(BuiltinOp::ReleaseUndo {
             expected_version: "0.2.0".to_string(),
@@ -132,7 +182,14 @@ fn test_lock_disposition_requires_lock_for_mutating_commands()
         LockDisposition::GovRootExclusive
     );
     assert_eq!(
-        global(Op::Builtin(BuiltinOp::Init { force: false })).lock_disposition(),
+        global(Op::Builtin(BuiltinOp::Init {
+            force: false,
+            from: None,
+            preset: None,
+            interactive: false,
+            vscode: false,
+        }))
+        .lock_disposition(),
         LockDisposition::GovRootExclusive
     );
     assert_eq!(
@@ -152,6 +209,9 @@ fn test_lock_disposition_requires_lock_for_mutating_commands()
     );
     assert_eq!(
         plan_edit(
+            &PrefixesConfig::default(),
+            &Aliases::default(),
+            cmd::edit::ArtifactType::WorkItem,
             "WI-2026-04-07-004",
             "acceptance_criteria[0]",
             tick_action(OwnedMatchOptions::default(), TickStatus::Done),
@@ -162,11 +222,15 @@ fn test_lock_disposition_requires_lock_for_mutating_commands()
     );
     assert_eq!(
         plan_lifecycle(
+            &PrefixesConfig::default(),
+            &Aliases::default(),
             cmd::edit::ArtifactType::WorkItem,
             "WI-2026-04-07-004",
             LifecycleOp::MoveWork {
                 file_or_id: std::path::PathBuf::from("WI-2026-04-07-004"),
                 status: WorkItemStatus::Done,
+                reopen_reason: None,
+                finish: None,
             },
         )
         .lock_disposition(),
@@ -186,3 +250,22 @@ fn test_self_update_is_lock_free() {
         LockDisposition::None
     );
 }
+
+#[test]
+fn test_undo_locks_and_history_reads() {
+    let undo = global(Op::Builtin(BuiltinOp::Undo { force: false }));
+    assert_eq!(undo.lock_disposition(), LockDisposition::GovRootExclusive);
+    assert!(!undo.records_history());
+
+    let history = global(Op::Builtin(BuiltinOp::History {
+        limit: None,
+        output: OutputFormat::Table,
+    }));
+    assert_eq!(history.lock_disposition(), LockDisposition::None);
+    assert!(!history.records_history());
+
+    let tag = global(Op::Builtin(BuiltinOp::TagNew {
+        tag: "caching".to_string(),
+    }));
+    assert!(tag.records_history());
+}
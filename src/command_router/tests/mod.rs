@@ -1,4 +1,6 @@
 use super::*;
+use crate::aliases::Aliases;
+use crate::config::PrefixesConfig;
 use crate::diagnostic::DiagnosticCode;
 use crate::model::WorkItemStatus;
 use crate::resource_plan::ToPlan;
@@ -2,14 +2,24 @@ use super::*;
 
 #[test]
 fn test_self_update_routes_to_builtin_op() -> Result<(), Box<dyn std::error::Error>> {
-    let check_plan = CommandPlan::from_parsed(&Commands::SelfUpdate { check: true }, false)?;
+    let check_plan = CommandPlan::from_parsed(
+        &Commands::SelfUpdate { check: true },
+        false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
+    )?;
     assert!(matches!(check_plan.scope, Scope::Global));
     assert!(matches!(
         check_plan.op,
         Op::Builtin(BuiltinOp::SelfUpdate { check: true })
     ));
 
-    let update_plan = CommandPlan::from_parsed(&Commands::SelfUpdate { check: false }, false)?;
+    let update_plan = CommandPlan::from_parsed(
+        &Commands::SelfUpdate { check: false },
+        false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
+    )?;
     assert!(matches!(update_plan.scope, Scope::Global));
     assert!(matches!(
         update_plan.op,
@@ -18,21 +28,76 @@ fn test_self_update_routes_to_builtin_op() -> Result<(), Box<dyn std::error::Err
     Ok(())
 }
 
+#[test]
+fn test_editor_edit_routes_to_artifact_scope() -> Result<(), Box<dyn std::error::Error>> {
+    let plan = CommandPlan::from_parsed(
+        &Commands::Edit {
+            id: "ADR-0003".to_string(),
+            editor: true,
+        },
+        false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
+    )?;
+    assert!(matches!(
+        plan.scope,
+        Scope::Artifact {
+            artifact: cmd::edit::ArtifactType::Adr,
+            ref id,
+        } if id == "ADR-0003"
+    ));
+    assert!(matches!(plan.op, Op::Edit(EditOp::Editor)));
+    assert_eq!(plan.lock_disposition(), LockDisposition::GovRootExclusive);
+    Ok(())
+}
+
+#[test]
+fn test_interactive_new_routes_to_wizard() -> Result<(), Box<dyn std::error::Error>> {
+    let plan = crate::WorkCommand::New {
+        title: None,
+        active: true,
+        interactive: true,
+    }
+    .to_plan(&PrefixesConfig::default(), &Aliases::default())?;
+    assert!(matches!(
+        plan.op,
+        Op::Create(CreateOp::Wizard {
+            target: cmd::new::WizardTarget::Work { active: true },
+            title: None,
+        })
+    ));
+
+    let flags = crate::AdrCommand::New {
+        title: Some("Adopt caching".to_string()),
+        interactive: false,
+    }
+    .to_plan(&PrefixesConfig::default(), &Aliases::default())?;
+    assert!(matches!(
+        flags.op,
+        Op::Create(CreateOp::Adr { ref title }) if title == "Adopt caching"
+    ));
+    Ok(())
+}
+
 #[test]
 fn test_release_commands_route_to_builtin_ops() -> Result<(), Box<dyn std::error::Error>> {
     let cut = CommandPlan::from_parsed(
         &Commands::Release(ReleaseArgs {
             version: Some("0.2.0".to_string()),
             date: Some("2026-07-15".to_string()),
+            github: true,
             command: None,
         }),
         false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
     )?;
     assert!(matches!(
         cut.op,
         Op::Builtin(BuiltinOp::ReleaseCut {
             ref version,
             date: Some(ref date),
+            github: true,
         }) if version == "0.2.0" && date == "2026-07-15"
     ));
 
@@ -40,11 +105,14 @@ fn test_release_commands_route_to_builtin_ops() -> Result<(), Box<dyn std::error
         &Commands::Release(ReleaseArgs {
             version: None,
             date: None,
+            github: false,
             command: Some(ReleaseCommand::Undo {
                 expected_version: "0.2.0".to_string(),
             }),
         }),
         false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
     )?;
     assert!(matches!(
         undo.op,
@@ -69,7 +137,7 @@ fn test_target_resolves_get_and_edit_to_same_field_target() -> Result<(), Box<dy
         id: "ADR-0038".to_string(),
         field: Some("alternatives[1].status".to_string()),
     })
-    .to_plan()?;
+    .to_plan(&PrefixesConfig::default(), &Aliases::default())?;
     let edit = crate::AdrCommand::Edit(crate::AdrEditArgs {
         common: crate::CommonEditArgs {
             id: "ADR-0038".to_string(),
@@ -90,7 +158,7 @@ fn test_target_resolves_get_and_edit_to_same_field_target() -> Result<(), Box<dy
         con: vec![],
         reject_reason: None,
     })
-    .to_plan()?;
+    .to_plan(&PrefixesConfig::default(), &Aliases::default())?;
 
     match ((&get.op, &get.scope), (&edit.op, &edit.scope)) {
         (
@@ -142,16 +210,18 @@ fn test_work_tick_defaults_status_to_done() -> Result<(), Box<dyn std::error::Er
 
 #[test]
 fn test_loop_commands_route_to_builtin_ops() -> Result<(), Box<dyn std::error::Error>> {
-    let list_plan = CommandPlan::from_parsed(
+    let mut list_plan = CommandPlan::from_parsed(
         &Commands::Loop {
             command: crate::LoopCommand::List {
                 filter: Some("open".to_string()),
                 limit: Some(3),
-                output: crate::OutputFormat::Json,
             },
         },
         false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
     )?;
+    list_plan.select_output(crate::OutputArg::Json)?;
     assert!(matches!(list_plan.scope, Scope::Global));
     assert!(matches!(
         list_plan.op,
@@ -170,6 +240,8 @@ fn test_loop_commands_route_to_builtin_ops() -> Result<(), Box<dyn std::error::E
             },
         },
         false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
     )?;
     assert!(matches!(
         run_plan.op,
@@ -184,28 +256,34 @@ fn test_loop_commands_route_to_builtin_ops() -> Result<(), Box<dyn std::error::E
 
 #[test]
 fn test_tag_commands_route_to_builtin_ops() -> Result<(), Box<dyn std::error::Error>> {
-    let new_plan = CommandPlan::from_parsed(
+    let mut new_plan = CommandPlan::from_parsed(
         &Commands::Tag {
             command: crate::TagCommand::New {
                 tag: "cleanup".to_string(),
             },
         },
         false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
     )?;
+    new_plan.select_output(crate::OutputArg::Json)?;
+    assert!(new_plan.select_output(crate::OutputArg::Plain).is_err());
     assert!(matches!(new_plan.scope, Scope::Global));
     assert!(matches!(
         new_plan.op,
         Op::Builtin(BuiltinOp::TagNew { ref tag }) if tag == "cleanup"
     ));
 
-    let list_plan = CommandPlan::from_parsed(
+    let mut list_plan = CommandPlan::from_parsed(
         &Commands::Tag {
-            command: crate::TagCommand::List {
-                output: crate::OutputFormat::Plain,
-            },
+            command: crate::TagCommand::List,
         },
         false,
+        &PrefixesConfig::default(),
+        &Aliases::default(),
     )?;
+    assert!(list_plan.select_output(crate::OutputArg::Yaml).is_err());
+    list_plan.select_output(crate::OutputArg::Plain)?;
     assert!(matches!(
         list_plan.op,
         Op::Builtin(BuiltinOp::TagList {
@@ -222,10 +300,18 @@ fn test_artifact_render_rejects_unsupported_artifacts() -> Result<(), Box<dyn st
         (cmd::edit::ArtifactType::Clause, "RFC-0001:C-SCOPE"),
         (cmd::edit::ArtifactType::Guard, "GUARD-CHECK"),
     ] {
-        let plan = plan_artifact_render(artifact, id, false);
+        let plan = plan_artifact_render(
+            &PrefixesConfig::default(),
+            &Aliases::default(),
+            artifact,
+            id,
+            false,
+            Default::default(),
+        );
         let err = match plan.execute(
             &crate::config::Config::default(),
-            crate::write::WriteOp::Execute,
+            None,
+            crate::write::WriteOp::EXECUTE,
         ) {
             Ok(_) => return Err("unsupported artifact render should fail".into()),
             Err(err) => err,
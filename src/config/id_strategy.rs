@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Work item ID generation strategy
 ///
 /// - `Sequential`: `WI-YYYY-MM-DD-NNN` (default, for solo projects)
 /// - `AuthorHash`: `WI-YYYY-MM-DD-{hash4}-NNN` (for multi-person teams)
 /// - `Random`: `WI-YYYY-MM-DD-{rand4}` (simple uniqueness)
+/// - `Ulid`: `WI-{ulid}` (no coordination needed across branches or agents)
+///
+/// `work_item.id_prefix` inserts a namespace after `WI-` in every strategy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum IdStrategy {
@@ -15,6 +19,8 @@ pub enum IdStrategy {
     AuthorHash,
     /// Random 4-char hex suffix (no sequence number)
     Random,
+    /// 26-char ULID, sortable by creation time
+    Ulid,
 }
 
 impl IdStrategy {
@@ -36,8 +42,47 @@ impl IdStrategy {
         let bytes: [u8; 2] = rng.random();
         format_hex4(bytes[0], bytes[1])
     }
+
+    /// Generate a ULID: a 48-bit millisecond timestamp followed by 80 random
+    /// bits, in Crockford base32
+    pub fn generate_ulid() -> String {
+        use rand::RngExt;
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis());
+        let random: u128 = rand::rng().random();
+        encode_ulid(millis, random)
+    }
+}
+
+fn encode_ulid(millis: u128, random: u128) -> String {
+    const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    let value = (millis & ((1 << 48) - 1)) << 80 | (random & ((1 << 80) - 1));
+    (0..26)
+        .rev()
+        .map(|digit| ALPHABET[((value >> (digit * 5)) & 31) as usize] as char)
+        .collect()
 }
 
 fn format_hex4(first: u8, second: u8) -> String {
     format!("{first:02x}{second:02x}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_ulid_timestamp_first() {
+        assert_eq!(encode_ulid(0, 0), "00000000000000000000000000");
+        assert_eq!(
+            encode_ulid(1_469_918_176_385, 0),
+            "01ARYZ6S410000000000000000"
+        );
+        assert_eq!(
+            encode_ulid((1 << 48) - 1, u128::MAX),
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+        assert!(encode_ulid(2, 0) > encode_ulid(1, u128::MAX));
+    }
+}
@@ -0,0 +1,314 @@
+//! Layered configuration sources.
+//!
+//! Settings resolve per key, later layers winning: built-in defaults, the
+//! project's `gov/config.toml`, the per-user `gov/config.local.toml`
+//! (gitignored), then `GOVCTL_<SECTION>_<KEY>` environment variables such as
+//! `GOVCTL_CONCURRENCY_LOCK_TIMEOUT_SECS`.
+
+use super::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Per-user override file, next to `config.toml`.
+pub const LOCAL_CONFIG_FILE_NAME: &str = "config.local.toml";
+
+/// Sections describing the governed tree itself; only the project file sets them.
+const PROJECT_ONLY_SECTIONS: &[&str] = &["schema", "prefixes"];
+
+/// Where a resolved setting came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingSource {
+    Default,
+    /// Config file, as a path relative to the project root.
+    File(PathBuf),
+    /// Environment variable name.
+    Env(String),
+}
+
+impl fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str("default"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Env(name) => write!(f, "env {name}"),
+        }
+    }
+}
+
+/// One resolved `section.key` setting.
+#[derive(Debug, Clone)]
+pub struct Setting<'a> {
+    pub section: &'a str,
+    pub key: &'a str,
+    pub value: &'a Value,
+    pub source: &'a SettingSource,
+}
+
+/// Defaults merged with every config layer, remembering each key's source.
+#[derive(Debug, Clone)]
+pub struct ConfigLayers {
+    merged: Table,
+    sources: BTreeMap<(String, String), SettingSource>,
+}
+
+impl ConfigLayers {
+    /// Read all layers for the project config at `config_path`.
+    ///
+    /// Missing files are skipped; a file that fails to parse as config is an
+    /// error pointing at that file.
+    pub fn load(config_path: &Path) -> DiagnosticResult<Self> {
+        let defaults = Table::try_from(Config::default()).map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!("Failed to serialize default config: {err}"),
+                config_path.display().to_string(),
+            )
+        })?;
+        let mut layers = Self {
+            merged: Table::new(),
+            sources: BTreeMap::new(),
+        };
+        layers.merge(defaults, &SettingSource::Default);
+
+        let project_root = config_path
+            .parent()
+            .and_then(Path::parent)
+            .unwrap_or_else(|| Path::new("."));
+        let display = |path: &Path| {
+            path.strip_prefix(project_root)
+                .unwrap_or(path)
+                .to_path_buf()
+        };
+
+        if let Some(table) = read_layer(config_path)? {
+            layers.merge(table, &SettingSource::File(display(config_path)));
+        }
+
+        let local_path = config_path.with_file_name(LOCAL_CONFIG_FILE_NAME);
+        if let Some(table) = read_layer(&local_path)? {
+            if let Some(section) = PROJECT_ONLY_SECTIONS
+                .iter()
+                .find(|section| table.contains_key(**section))
+            {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E0501ConfigInvalid,
+                    format!(
+                        "[{section}] describes the governed tree and can only be set in config.toml"
+                    ),
+                    local_path.display().to_string(),
+                ));
+            }
+            layers.merge(table, &SettingSource::File(display(&local_path)));
+        }
+
+        layers.apply_env(|name| std::env::var(name).ok())?;
+        Ok(layers)
+    }
+
+    /// The effective config. Paths are left as written.
+    pub fn config(&self) -> DiagnosticResult<Config> {
+        self.merged.clone().try_into().map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0501ConfigInvalid,
+                format!("Failed to resolve config: {err}"),
+                "config",
+            )
+        })
+    }
+
+    /// Every `section.key` setting with its value and source, sorted by key.
+    pub fn settings(&self) -> impl Iterator<Item = Setting<'_>> {
+        self.sources.iter().filter_map(|((section, key), source)| {
+            let value = self.merged.get(section)?.as_table()?.get(key)?;
+            Some(Setting {
+                section,
+                key,
+                value,
+                source,
+            })
+        })
+    }
+
+    /// The setting for `section.key`, if it is a known setting.
+    pub fn setting(&self, section: &str, key: &str) -> Option<Setting<'_>> {
+        self.settings()
+            .find(|setting| setting.section == section && setting.key == key)
+    }
+
+    fn merge(&mut self, table: Table, source: &SettingSource) {
+        for (section, value) in table {
+            let Value::Table(entries) = value else {
+                self.merged.insert(section, value);
+                continue;
+            };
+            let target = self
+                .merged
+                .entry(section.clone())
+                .or_insert_with(|| Value::Table(Table::new()));
+            let Value::Table(target) = target else {
+                continue;
+            };
+            for (key, value) in entries {
+                self.sources
+                    .insert((section.clone(), key.clone()), source.clone());
+                target.insert(key, value);
+            }
+        }
+    }
+
+    /// Override known settings from `GOVCTL_<SECTION>_<KEY>` variables, parsed
+    /// according to the type of the default value.
+    fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> DiagnosticResult<()> {
+        let mut overrides = Vec::new();
+        for Setting {
+            section,
+            key,
+            value,
+            ..
+        } in self.settings()
+        {
+            if PROJECT_ONLY_SECTIONS.contains(&section) {
+                continue;
+            }
+            let name = env_var_name(section, key);
+            if let Some(raw) = var(&name) {
+                let parsed = parse_setting_value(&raw, value).ok_or_else(|| {
+                    Diagnostic::new(
+                        DiagnosticCode::E0501ConfigInvalid,
+                        format!(
+                            "Invalid value for {name}: expected {}, got '{raw}'",
+                            value.type_str()
+                        ),
+                        name.clone(),
+                    )
+                })?;
+                overrides.push((section.to_string(), key.to_string(), parsed, name));
+            }
+        }
+        for (section, key, value, name) in overrides {
+            let mut entries = Table::new();
+            entries.insert(key, value);
+            let mut table = Table::new();
+            table.insert(section, Value::Table(entries));
+            self.merge(table, &SettingSource::Env(name));
+        }
+        Ok(())
+    }
+}
+
+/// Environment variable overriding `section.key`.
+fn env_var_name(section: &str, key: &str) -> String {
+    format!("GOVCTL_{section}_{key}").to_uppercase()
+}
+
+fn read_layer(path: &Path) -> DiagnosticResult<Option<Table>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| Diagnostic::io_error("read config", err, path.display().to_string()))?;
+    let parse_error = |err: toml::de::Error| {
+        Diagnostic::new(
+            DiagnosticCode::E0501ConfigInvalid,
+            format!("Failed to parse config: {err}"),
+            path.display().to_string(),
+        )
+    };
+    // Deserializing the layer on its own reports type errors at their line.
+    toml::from_str::<Config>(&content).map_err(parse_error)?;
+    toml::from_str::<Table>(&content)
+        .map(Some)
+        .map_err(parse_error)
+}
+
+/// Parse `raw` as a value of the same type as `default`.
+///
+/// Used for environment overrides and `config set`.
+pub fn parse_setting_value(raw: &str, default: &Value) -> Option<Value> {
+    match default {
+        Value::String(_) => Some(Value::String(raw.to_string())),
+        Value::Integer(_) => raw.trim().parse().ok().map(Value::Integer),
+        Value::Boolean(_) => match raw.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Some(Value::Boolean(true)),
+            "false" | "0" | "no" | "off" => Some(Value::Boolean(false)),
+            _ => None,
+        },
+        // Lists are comma-separated strings.
+        Value::Array(_) => Some(Value::Array(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layers_with_env(vars: &[(&str, &str)]) -> DiagnosticResult<ConfigLayers> {
+        let mut layers = ConfigLayers {
+            merged: Table::new(),
+            sources: BTreeMap::new(),
+        };
+        let defaults = Table::try_from(Config::default()).map_err(|err| {
+            Diagnostic::new(DiagnosticCode::E0903UnexpectedError, err.to_string(), "")
+        })?;
+        layers.merge(defaults, &SettingSource::Default);
+        layers.apply_env(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        })?;
+        Ok(layers)
+    }
+
+    #[test]
+    fn env_overrides_are_typed_by_default_value() -> DiagnosticResult<()> {
+        let layers = layers_with_env(&[
+            ("GOVCTL_CONCURRENCY_LOCK_TIMEOUT_SECS", "5"),
+            ("GOVCTL_SOURCE_SCAN_ENABLED", "yes"),
+            ("GOVCTL_SOURCE_SCAN_INCLUDE", "src/**/*.rs, lib/**/*.rs"),
+        ])?;
+        let config = layers.config()?;
+        assert_eq!(config.concurrency.lock_timeout_secs, 5);
+        assert!(config.source_scan.enabled);
+        assert_eq!(config.source_scan.include, ["src/**/*.rs", "lib/**/*.rs"]);
+
+        let source = layers
+            .setting("concurrency", "lock_timeout_secs")
+            .map(|setting| setting.source.clone());
+        assert_eq!(
+            source,
+            Some(SettingSource::Env(
+                "GOVCTL_CONCURRENCY_LOCK_TIMEOUT_SECS".to_string()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_env_value_names_the_variable() {
+        let err = layers_with_env(&[("GOVCTL_CONCURRENCY_LOCK_TIMEOUT_SECS", "soon")]).err();
+        assert_eq!(
+            err.map(|diag| diag.file),
+            Some("GOVCTL_CONCURRENCY_LOCK_TIMEOUT_SECS".to_string())
+        );
+    }
+
+    #[test]
+    fn schema_version_is_not_overridable_from_env() -> DiagnosticResult<()> {
+        let layers = layers_with_env(&[("GOVCTL_SCHEMA_VERSION", "1")])?;
+        assert_eq!(
+            layers.config()?.schema.version,
+            crate::cmd::migrate::CURRENT_SCHEMA_VERSION
+        );
+        Ok(())
+    }
+}
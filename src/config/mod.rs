@@ -2,14 +2,24 @@
 //!
 //! Implements [[ADR-0009]] configurable source code reference scanning.
 
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::RfcPhase;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use strum::AsRefStr;
 
 mod id_strategy;
+mod layers;
+mod prefixes;
 mod runtime;
 mod template;
 
 pub use id_strategy::IdStrategy;
+pub use layers::{
+    ConfigLayers, LOCAL_CONFIG_FILE_NAME, Setting, SettingSource, parse_setting_value,
+};
+pub use prefixes::{PrefixesConfig, WORK_PREFIX, any_id_pattern, work_id_pattern};
 
 /// Project configuration (gov/config.toml)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +36,8 @@ pub struct Config {
     #[serde(default)]
     pub source_scan: SourceScanConfig,
     #[serde(default)]
+    pub prefixes: PrefixesConfig,
+    #[serde(default)]
     pub work_item: WorkItemConfig,
     #[serde(default)]
     pub verification: VerificationConfig,
@@ -33,6 +45,44 @@ pub struct Config {
     pub concurrency: ConcurrencyConfig,
     #[serde(default)]
     pub tags: TagsConfig,
+    #[serde(default)]
+    pub owners: OwnersConfig,
+    #[serde(default)]
+    pub gates: GatesConfig,
+    #[serde(default)]
+    pub staleness: StalenessConfig,
+    #[serde(default)]
+    pub snapshots: SnapshotsConfig,
+    #[serde(default)]
+    pub audit: AuditConfig,
+    #[serde(default)]
+    pub identity: IdentityConfig,
+    #[serde(default)]
+    pub secrets: SecretsConfig,
+    #[serde(default)]
+    pub render: RenderConfig,
+    #[serde(default)]
+    pub format: FormatConfig,
+    #[serde(default)]
+    pub tui: TuiConfig,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub remotes: BTreeMap<String, RemoteConfig>,
+    /// Names for frequently used artifact IDs, used as `@name` (`[aliases]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+    /// Values for `{{var.<name>}}` in clause and ADR text (`[variables]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confluence: Option<ConfluenceConfig>,
+    #[serde(default)]
+    pub github: GithubConfig,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira: Option<JiraConfig>,
+    #[serde(default)]
+    pub notify: NotifyConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
 }
 
 impl Default for Config {
@@ -43,14 +93,613 @@ impl Default for Config {
             paths: PathsConfig::default(),
             schema: SchemaConfig::default(),
             source_scan: SourceScanConfig::default(),
+            prefixes: PrefixesConfig::default(),
             work_item: WorkItemConfig::default(),
             verification: VerificationConfig::default(),
             concurrency: ConcurrencyConfig::default(),
             tags: TagsConfig::default(),
+            owners: OwnersConfig::default(),
+            gates: GatesConfig::default(),
+            staleness: StalenessConfig::default(),
+            snapshots: SnapshotsConfig::default(),
+            audit: AuditConfig::default(),
+            identity: IdentityConfig::default(),
+            secrets: SecretsConfig::default(),
+            render: RenderConfig::default(),
+            format: FormatConfig::default(),
+            tui: TuiConfig::default(),
+            remotes: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+            variables: BTreeMap::new(),
+            confluence: None,
+            github: GithubConfig::default(),
+            jira: None,
+            notify: NotifyConfig::default(),
+            hooks: HooksConfig::default(),
+        }
+    }
+}
+
+/// Another repository whose artifacts can be referenced as `name:ID`.
+///
+/// Exactly one source is set: `path` to a checkout of the other project, or
+/// `bundle` to a file written there by `govctl export`. Relative paths are
+/// resolved against the project root.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<PathBuf>,
+}
+
+/// Confluence space `govctl publish confluence` writes RFCs and ADRs to.
+///
+/// Credentials are never read from config: `CONFLUENCE_TOKEN` holds the API
+/// token, sent with `CONFLUENCE_USER` as basic auth when that is set
+/// (Atlassian Cloud) and as a bearer token otherwise (Data Center).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfluenceConfig {
+    /// Site URL the REST API lives under, e.g. `https://example.atlassian.net/wiki`.
+    pub base_url: String,
+    /// Key of the space pages are created in.
+    pub space: String,
+    /// Page new RFC pages are created under; the space root when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rfc_parent: Option<String>,
+    /// Page new ADR pages are created under; the space root when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adr_parent: Option<String>,
+    /// Existing page ID per artifact ID. Unmapped artifacts are matched by
+    /// page title.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pages: BTreeMap<String, String>,
+    /// Label added to every published page.
+    #[serde(default = "default_confluence_label")]
+    pub label: String,
+}
+
+fn default_confluence_label() -> String {
+    "govctl-generated".to_string()
+}
+
+/// GitHub for linked issues (`work list --github`) and release drafts
+/// (`release --github`). The API token is read from `GITHUB_TOKEN` or
+/// `GH_TOKEN`, never from config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GithubConfig {
+    /// `owner/repo` releases are drafted on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// REST API root; GitHub Enterprise Server uses `https://<host>/api/v3`.
+    #[serde(default = "default_github_api_url")]
+    pub api_url: String,
+    /// Prefix of release tags, so version `1.2.0` is tagged `v1.2.0`.
+    #[serde(default = "default_github_tag_prefix")]
+    pub tag_prefix: String,
+}
+
+fn default_github_api_url() -> String {
+    "https://api.github.com".to_string()
+}
+
+fn default_github_tag_prefix() -> String {
+    "v".to_string()
+}
+
+impl Default for GithubConfig {
+    fn default() -> Self {
+        Self {
+            repo: None,
+            api_url: default_github_api_url(),
+            tag_prefix: default_github_tag_prefix(),
+        }
+    }
+}
+
+/// Jira site work items link to through `jira_key`, read by `sync jira` and
+/// used for issue links in rendered work items.
+///
+/// Credentials follow `[confluence]`: `JIRA_TOKEN` holds the API token, sent
+/// with `JIRA_USER` as basic auth when that is set and as a bearer token
+/// otherwise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JiraConfig {
+    /// Site URL, e.g. `https://example.atlassian.net`.
+    pub base_url: String,
+}
+
+/// Webhooks told about lifecycle events once the command that caused them
+/// has committed.
+///
+/// ```toml
+/// [[notify.webhooks]]
+/// url = "$SLACK_WEBHOOK_URL"
+/// events = ["rfc.finalize", "release"]
+/// format = "slack"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct NotifyConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookConfig {
+    /// Endpoint to POST to; `$NAME` reads the URL from that environment
+    /// variable so secret URLs stay out of the repository.
+    pub url: String,
+    /// Events sent to this webhook; all of them when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<NotifyEvent>,
+    #[serde(default)]
+    pub format: WebhookFormat,
+}
+
+/// Lifecycle events webhooks can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotifyEvent {
+    #[serde(rename = "rfc.finalize")]
+    RfcFinalize,
+    #[serde(rename = "adr.accept")]
+    AdrAccept,
+    #[serde(rename = "work.done")]
+    WorkDone,
+    #[serde(rename = "release")]
+    Release,
+}
+
+/// Request body sent to a webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// The event as a JSON object.
+    #[default]
+    Json,
+    /// `{"text": ...}`, as Slack incoming webhooks expect.
+    Slack,
+}
+
+/// User commands run around lifecycle transitions.
+///
+/// `pre_*` hooks run before the command changes anything and `post_*` hooks
+/// after its writes, inside the same transaction, so a blocking failure at
+/// either point rolls the command back.
+///
+/// ```toml
+/// [hooks]
+/// pre_move = [{ command = "scripts/check-branch.sh" }]
+/// post_release = [{ command = "make changelog", on_failure = "warn" }]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct HooksConfig {
+    /// `work move`, to any status.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_move: Vec<HookConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_move: Vec<HookConfig>,
+    /// `rfc finalize`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_finalize: Vec<HookConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_finalize: Vec<HookConfig>,
+    /// `adr accept`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_accept: Vec<HookConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_accept: Vec<HookConfig>,
+    /// `release`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_release: Vec<HookConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_release: Vec<HookConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HookConfig {
+    /// Shell command, run with bash from the project root.
+    pub command: String,
+    #[serde(default)]
+    pub on_failure: HookFailure,
+    /// Seconds before the hook's process group is killed and it fails.
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    crate::verification::DEFAULT_GUARD_TIMEOUT_SECS
+}
+
+/// What a failing hook (non-zero exit) does to its command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum HookFailure {
+    /// Fail the command and roll back its changes.
+    #[default]
+    Block,
+    /// Report a `W0126` warning and carry on.
+    Warn,
+}
+
+/// Checklists `rfc advance` evaluates before an RFC enters a phase.
+///
+/// ```toml
+/// [gates]
+/// impl = ["clauses-have-since", "adr-references-rfc"]
+/// stable = ["work-items-done"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct GatesConfig {
+    #[serde(default, rename = "impl")]
+    pub impl_phase: Vec<PhaseGate>,
+    #[serde(default)]
+    pub test: Vec<PhaseGate>,
+    #[serde(default)]
+    pub stable: Vec<PhaseGate>,
+}
+
+impl GatesConfig {
+    /// Gates for entering `phase`; `spec` has none.
+    pub fn for_phase(&self, phase: RfcPhase) -> &[PhaseGate] {
+        match phase {
+            RfcPhase::Spec => &[],
+            RfcPhase::Impl => &self.impl_phase,
+            RfcPhase::Test => &self.test,
+            RfcPhase::Stable => &self.stable,
         }
     }
 }
 
+/// One checklist item of a phase gate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AsRefStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum PhaseGate {
+    /// Every active clause of the RFC records the version it appeared in.
+    ClausesHaveSince,
+    /// At least one ADR references the RFC or one of its clauses.
+    AdrReferencesRfc,
+    /// Every work item referencing the RFC or its clauses is done or cancelled.
+    WorkItemsDone,
+    /// Every work item referencing one of the RFC's clauses is done or
+    /// cancelled. Always checked on entering `test`.
+    ClauseWorkDone,
+}
+
+/// Age limits after which open governance is reported as stale.
+///
+/// `0` disables a limit. Stale artifacts are `check` warnings and show up in
+/// `list --stale`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct StalenessConfig {
+    /// Days a draft RFC may go without an update.
+    #[serde(default)]
+    pub draft_rfc_days: u32,
+    /// Days an ADR may stay proposed.
+    #[serde(default)]
+    pub proposed_adr_days: u32,
+}
+
+/// Frozen copies of released RFC versions.
+///
+/// With `rfc_versions`, sealing a version (`rfc advance` from spec to impl)
+/// writes it to `docs/rfc/<RFC-ID>/v<version>.md` and `.json` (the clause
+/// set), which `rfc show <RFC-ID>@<version>` reads. Every bumped version is
+/// snapshotted once it is released this way.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SnapshotsConfig {
+    /// Snapshot each RFC version when it is sealed.
+    #[serde(default)]
+    pub rfc_versions: bool,
+}
+
+/// Append-only record of write commands in `gov/audit.log`.
+///
+/// With `enabled`, every write command appends one JSON line: when it ran,
+/// who ran it, the command line, the files it changed, and its result.
+/// Entries older than `retention_days` are dropped on the next append.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AuditConfig {
+    /// Log write commands.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Days an entry is kept; `0` keeps every entry.
+    #[serde(default)]
+    pub retention_days: u32,
+}
+
+/// Who commands act as when neither `--as` nor `GOVCTL_ACTOR` is set.
+///
+/// Personal, so it usually lives in `gov/config.local.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct IdentityConfig {
+    /// `human:<name>` or `agent:<name>`; the git `user.name` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+}
+
+/// Key for ADR fields marked `sensitive`.
+///
+/// `GOVCTL_SECRET_KEY` takes precedence over `key_file`. Either holds a
+/// base64-encoded 32-byte key; keep the file out of version control.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SecretsConfig {
+    /// Key file, relative to the project root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_file: Option<PathBuf>,
+}
+
+/// Layout of rendered markdown. The `render` flags of the same names turn
+/// an option on for one run.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RenderConfig {
+    /// Table of contents linking sections and clauses.
+    #[serde(default)]
+    pub toc: bool,
+    /// Number clauses within their section, `2.3` style.
+    #[serde(default)]
+    pub clause_numbers: bool,
+    /// A status badge on every clause heading, active ones included.
+    #[serde(default)]
+    pub status_badges: bool,
+    /// YAML front matter on every rendered artifact.
+    #[serde(default)]
+    pub front_matter: FrontMatterConfig,
+    /// Sections left out of every rendered artifact.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub omit: Vec<RenderSection>,
+    /// Named variants selected with `render --profile <name>`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, RenderProfile>,
+    /// Pre-rendering of diagram blocks in HTML output.
+    #[serde(default)]
+    pub diagrams: DiagramsConfig,
+    /// Governance entries listed in CHANGELOG.md besides work item criteria.
+    #[serde(default)]
+    pub changelog: ChangelogRenderConfig,
+}
+
+impl RenderConfig {
+    /// Whether `section` is rendered, i.e. not omitted.
+    pub fn shows(&self, section: RenderSection) -> bool {
+        !self.omit.contains(&section)
+    }
+}
+
+/// Commands that turn fenced `mermaid` and `plantuml` blocks into SVG for
+/// HTML output. Each reads the diagram source on stdin and writes SVG to
+/// stdout; a kind without a command stays a code block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DiagramsConfig {
+    /// e.g. `mmdc -i - -o - -e svg`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mermaid: Option<String>,
+    /// e.g. `plantuml -tsvg -pipe`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plantuml: Option<String>,
+    /// Seconds before a diagram command's process group is killed and the
+    /// block stays code (default: 60).
+    #[serde(default = "default_diagram_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_diagram_timeout_secs() -> u64 {
+    60
+}
+
+impl Default for DiagramsConfig {
+    fn default() -> Self {
+        Self {
+            mermaid: None,
+            plantuml: None,
+            timeout_secs: default_diagram_timeout_secs(),
+        }
+    }
+}
+
+/// What `render changelog` lists for each release besides the acceptance
+/// criteria of its work items. Both are off by default.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ChangelogRenderConfig {
+    /// RFC versions dated within the release window.
+    #[serde(default)]
+    pub rfc_versions: bool,
+    /// Accepted ADRs dated within the release window.
+    #[serde(default)]
+    pub adr_acceptances: bool,
+}
+
+/// A render variant, such as a public documentation set rendered from the
+/// same artifacts as the internal one.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RenderProfile {
+    /// Output directory, relative to the project root; `paths.docs_output`
+    /// when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs_output: Option<PathBuf>,
+    /// Sections left out, on top of `[render] omit`.
+    #[serde(default)]
+    pub omit: Vec<RenderSection>,
+}
+
+/// A rendered section a profile can leave out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RenderSection {
+    /// RFC owners
+    Owners,
+    /// RFC and ADR approvals
+    Approvals,
+    /// RFC changelog
+    Changelog,
+    /// ADR stakeholders
+    Stakeholders,
+    /// ADR alternatives
+    Alternatives,
+    /// Work item journal
+    Journal,
+    /// Work item notes
+    Notes,
+    /// Work item guard results
+    Verification,
+}
+
+/// Layout `govctl fmt` gives artifact files.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FormatConfig {
+    /// Column to wrap prose fields at; `0` keeps line breaks as written.
+    #[serde(default)]
+    pub wrap_width: usize,
+}
+
+/// YAML front matter for static site generators such as MkDocs and
+/// Docusaurus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrontMatterConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Keys to write, in order.
+    #[serde(default = "default_front_matter_fields")]
+    pub fields: Vec<FrontMatterField>,
+}
+
+fn default_front_matter_fields() -> Vec<FrontMatterField> {
+    vec![
+        FrontMatterField::Title,
+        FrontMatterField::Slug,
+        FrontMatterField::SidebarPosition,
+        FrontMatterField::Tags,
+    ]
+}
+
+impl Default for FrontMatterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fields: default_front_matter_fields(),
+        }
+    }
+}
+
+/// One front matter key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrontMatterField {
+    /// `<ID>: <title>`
+    Title,
+    /// The lowercased ID
+    Slug,
+    /// The ID's digits as a number, so the sidebar follows ID order
+    SidebarPosition,
+    /// The artifact's tags
+    Tags,
+}
+
+/// Terminal UI preferences (`govctl tui`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TuiConfig {
+    #[serde(default)]
+    pub keys: TuiKeysConfig,
+    #[serde(default)]
+    pub theme: TuiThemeConfig,
+}
+
+/// Replacement keys for TUI navigation, one character each.
+///
+/// ```toml
+/// [tui.keys]
+/// down = "n"
+/// up = "e"
+/// ```
+///
+/// A remapped action no longer answers to its default key. `quit` and
+/// `help` apply everywhere; the rest apply outside the dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TuiKeysConfig {
+    /// Default `q`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quit: Option<char>,
+    /// Default `?`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<char>,
+    /// Default `j`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub down: Option<char>,
+    /// Default `k`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub up: Option<char>,
+    /// Default `h`; previous board column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub left: Option<char>,
+    /// Default `l`; next board column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub right: Option<char>,
+    /// Default `g`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top: Option<char>,
+    /// Default `G`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<char>,
+    /// Default `/`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<char>,
+    /// Default `r`; refs and backlinks in detail views.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<char>,
+    /// Default `m`; move a work item on the board.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "move")]
+    pub move_item: Option<char>,
+}
+
+/// TUI colors and glyphs.
+///
+/// Colors are names (`green`, `light-blue`), `#rrggbb`, or 256-color
+/// indexes, and replace the status and phase colors they are named after.
+/// `ascii` swaps emoji and Unicode status icons for plain ASCII.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TuiThemeConfig {
+    /// Normative, accepted, active, done; stable phase. Default green.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<String>,
+    /// Draft, proposed, queue; spec phase. Default yellow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    /// Deprecated, superseded, cancelled. Default dark gray.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<String>,
+    /// Test phase. Default cyan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    /// Impl phase. Default blue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+    #[serde(default)]
+    pub ascii: bool,
+}
+
 /// Controlled-vocabulary tag configuration.
 ///
 /// Defines the allowed tag set for the project. Artifacts may only use tags
@@ -64,13 +713,77 @@ pub struct TagsConfig {
     pub allowed: Vec<String>,
 }
 
-/// Project-level verification guard policy.
+/// Owner registry for CODEOWNERS-style RFC ownership.
+///
+/// When `members` or `teams` is non-empty, every RFC owner must name a known
+/// identity and approvals must come from listed members. An empty registry
+/// leaves owners unchecked.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OwnersConfig {
+    /// Individual identities: `@handle` or an email address.
+    #[serde(default)]
+    pub members: Vec<String>,
+    /// Team identity to its members, e.g. `"@acme/platform" = ["@alice"]`.
+    #[serde(default)]
+    pub teams: BTreeMap<String, Vec<String>>,
+    /// Require an approval from an RFC owner before `finalize normative`.
+    #[serde(default)]
+    pub require_approval: bool,
+    /// Distinct approvals required before `rfc finalize normative`.
+    #[serde(default)]
+    pub rfc_quorum: u32,
+    /// Distinct approvals required before `adr accept`.
+    #[serde(default)]
+    pub adr_quorum: u32,
+}
+
+impl OwnersConfig {
+    /// Whether any identities are registered.
+    pub fn is_enabled(&self) -> bool {
+        !self.members.is_empty() || !self.teams.is_empty()
+    }
+
+    /// Whether `identity` is a registered member or team.
+    pub fn is_known(&self, identity: &str) -> bool {
+        self.members.iter().any(|member| member == identity) || self.teams.contains_key(identity)
+    }
+
+    /// Whether `approver` is one of `owners`, directly or through a team.
+    pub fn is_owner_approval(&self, approver: &str, owners: &[String]) -> bool {
+        owners.iter().any(|owner| {
+            owner == approver
+                || self
+                    .teams
+                    .get(owner)
+                    .is_some_and(|members| members.iter().any(|member| member == approver))
+        })
+    }
+}
+
+/// Project-level verification guard policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationConfig {
     #[serde(default)]
     pub enabled: bool,
     #[serde(default)]
     pub default_guards: Vec<String>,
+    /// Lines of guard output kept in a work item's recorded results (default: 20).
+    #[serde(default = "default_output_lines")]
+    pub output_lines: usize,
+}
+
+fn default_output_lines() -> usize {
+    20
+}
+
+impl Default for VerificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            default_guards: Vec::new(),
+            output_lines: default_output_lines(),
+        }
+    }
 }
 
 /// Concurrency and write-safety configuration.
@@ -81,21 +794,29 @@ pub struct ConcurrencyConfig {
     /// Maximum seconds to wait for exclusive access before failing (default: 30).
     #[serde(default = "default_lock_timeout_secs")]
     pub lock_timeout_secs: u64,
+    /// Flush each written file to disk before it replaces the original (default: true).
+    #[serde(default = "default_fsync")]
+    pub fsync: bool,
 }
 
 fn default_lock_timeout_secs() -> u64 {
     30
 }
 
+fn default_fsync() -> bool {
+    true
+}
+
 impl Default for ConcurrencyConfig {
     fn default() -> Self {
         Self {
             lock_timeout_secs: default_lock_timeout_secs(),
+            fsync: default_fsync(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectConfig {
     #[serde(default = "default_project_name")]
     pub name: String,
@@ -104,6 +825,15 @@ pub struct ProjectConfig {
     pub default_owner: String,
 }
 
+impl Default for ProjectConfig {
+    fn default() -> Self {
+        Self {
+            name: default_project_name(),
+            default_owner: default_owner(),
+        }
+    }
+}
+
 fn default_project_name() -> String {
     "govctl-project".to_string()
 }
@@ -122,7 +852,7 @@ fn default_owner() -> String {
         .unwrap_or_else(|| "@your-handle".to_string())
 }
 
-fn git_config_value(key: &str) -> Option<String> {
+pub(crate) fn git_config_value(key: &str) -> Option<String> {
     let output = std::process::Command::new("git")
         .args(["config", key])
         .output()
@@ -202,6 +932,37 @@ pub struct SourceScanConfig {
     /// Regex pattern with capture group 1 for artifact ID
     #[serde(default = "default_scan_pattern")]
     pub pattern: String,
+    /// Only count references inside comments, so string literals and test
+    /// fixtures are skipped (default: false)
+    #[serde(default)]
+    pub comments_only: bool,
+    /// Comment syntax by file extension, overriding the built-in table
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub comment_syntax: BTreeMap<String, CommentSyntax>,
+    /// Skip paths ignored by `.gitignore` files (default: true);
+    /// `.govctlignore` files always apply
+    #[serde(default = "default_respect_gitignore")]
+    pub respect_gitignore: bool,
+}
+
+fn default_respect_gitignore() -> bool {
+    true
+}
+
+/// How a file type writes comments, for `source_scan.comments_only`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentSyntax {
+    /// `//` and `/* */`, as in Rust, C, Go, Java, and JavaScript
+    C,
+    /// `#`, as in Python, shell, YAML, and TOML
+    Hash,
+    /// `--`, as in SQL, Lua, and Haskell
+    Dash,
+    /// `<!-- -->`, as in HTML and XML
+    Html,
+    /// Prose, where the whole file counts as comment
+    Text,
 }
 
 fn default_scan_include() -> Vec<String> {
@@ -219,7 +980,14 @@ fn default_scan_pattern() -> String {
     // - [[WI-YYYY-MM-DD-NNN]] (sequential)
     // - [[WI-YYYY-MM-DD-HHHH-NNN]] (author-hash)
     // - [[WI-YYYY-MM-DD-HHHH]] (random)
-    r"\[\[(RFC-\d{4}(?::C-[A-Z][A-Z0-9-]*)?|ADR-\d{4}|WI-\d{4}-\d{2}-\d{2}-(?:[a-f0-9]{4}(?:-\d{3})?|\d{3}))\]\]".to_string()
+    // - [[WI-ULID]] (ulid)
+    // Config::load swaps in `[prefixes]` when this default is left in place.
+    bracket_ref_pattern(&PrefixesConfig::default())
+}
+
+/// Double-bracket reference pattern for the given artifact prefixes.
+pub fn bracket_ref_pattern(prefixes: &PrefixesConfig) -> String {
+    format!(r"\[\[({})\]\]", prefixes.id_pattern())
 }
 
 impl Default for SourceScanConfig {
@@ -229,6 +997,9 @@ impl Default for SourceScanConfig {
             include: default_scan_include(),
             exclude: vec![],
             pattern: default_scan_pattern(),
+            comments_only: false,
+            comment_syntax: BTreeMap::new(),
+            respect_gitignore: true,
         }
     }
 }
@@ -241,12 +1012,78 @@ pub struct WorkItemConfig {
     /// ID generation strategy (default: sequential)
     #[serde(default)]
     pub id_strategy: IdStrategy,
+    /// Namespace after `WI-` in new IDs, e.g. `PAY` for `WI-PAY-2026-01-17-001`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_prefix: Option<String>,
+    /// What `work move <id> done` checks first
+    #[serde(default)]
+    pub done: DoneRequirements,
 }
 
 impl Default for WorkItemConfig {
     fn default() -> Self {
         Self {
             id_strategy: IdStrategy::Sequential,
+            id_prefix: None,
+            done: DoneRequirements::default(),
+        }
+    }
+}
+
+impl WorkItemConfig {
+    /// `id_prefix` must keep IDs matching the work item ID pattern.
+    pub fn validate(&self) -> DiagnosticResult<()> {
+        match &self.id_prefix {
+            Some(prefix) if !prefixes::is_valid_prefix(prefix) => Err(Diagnostic::new(
+                DiagnosticCode::E0501ConfigInvalid,
+                format!(
+                    "Invalid work_item.id_prefix '{prefix}' (expected 1-10 uppercase letters or digits, starting with a letter)"
+                ),
+                "work_item",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Requirements a work item must meet before it can move to `done`.
+///
+/// The defaults match govctl's original behavior: criteria and guards are
+/// checked, notes and refs are not.
+///
+/// ```toml
+/// [work_item.done]
+/// notes = true
+/// refs = true
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DoneRequirements {
+    /// At least one acceptance criterion, none of them pending
+    #[serde(default = "default_done_check")]
+    pub acceptance_criteria: bool,
+    /// At least one note (legacy journal entries also count)
+    #[serde(default)]
+    pub notes: bool,
+    /// At least one ref
+    #[serde(default)]
+    pub refs: bool,
+    /// Required verification guards pass
+    #[serde(default = "default_done_check")]
+    pub guards: bool,
+}
+
+fn default_done_check() -> bool {
+    true
+}
+
+impl Default for DoneRequirements {
+    fn default() -> Self {
+        Self {
+            acceptance_criteria: true,
+            notes: false,
+            refs: false,
+            guards: true,
         }
     }
 }
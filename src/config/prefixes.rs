@@ -0,0 +1,221 @@
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use serde::{Deserialize, Serialize};
+
+/// Prefix of work item IDs, which `[prefixes]` cannot change.
+pub const WORK_PREFIX: &str = "WI";
+
+/// Prefix of clause IDs (`C-NAME`).
+pub const CLAUSE_PREFIX: &str = "C";
+
+/// An artifact prefix or work item `id_prefix`: 1-10 uppercase letters or
+/// digits, starting with a letter.
+const PREFIX_PATTERN: &str = "[A-Z][A-Z0-9]{0,9}";
+
+/// Prefixes reserved for other artifacts and clauses.
+const RESERVED_PREFIXES: &[&str] = &[WORK_PREFIX, CLAUSE_PREFIX, "GUARD", "LOOP"];
+
+/// Work item IDs for every `IdStrategy`, with the optional `id_prefix`
+/// namespace.
+pub fn work_id_pattern() -> String {
+    format!(
+        r"{WORK_PREFIX}-(?:{PREFIX_PATTERN}-)?(?:\d{{4}}-\d{{2}}-\d{{2}}-(?:[a-f0-9]{{4}}(?:-\d{{3}})?|\d{{3}})|[0-9A-HJKMNP-TV-Z]{{26}})"
+    )
+}
+
+/// Regex alternation matching artifact IDs under any `[prefixes]`, for
+/// references into projects whose prefixes are not known.
+pub fn any_id_pattern() -> String {
+    format!(
+        r"{PREFIX_PATTERN}-\d{{4}}(?::{CLAUSE_PREFIX}-[A-Z][A-Z0-9-]*)?|{}",
+        work_id_pattern()
+    )
+}
+
+/// Whether `prefix` is a well-formed artifact prefix or `id_prefix`.
+pub fn is_valid_prefix(prefix: &str) -> bool {
+    prefix.len() <= 10
+        && prefix.starts_with(|ch: char| ch.is_ascii_uppercase())
+        && prefix
+            .chars()
+            .all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit())
+}
+
+/// Artifact ID prefixes (`[prefixes]`)
+///
+/// `rfc = "SPEC"` makes RFC IDs `SPEC-0001`; `adr = "DEC"` makes ADR IDs
+/// `DEC-0001`. Work items, clauses, and guards keep their fixed prefixes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrefixesConfig {
+    /// Prefix of RFC IDs (default: "RFC")
+    #[serde(default = "default_rfc_prefix")]
+    pub rfc: String,
+    /// Prefix of ADR IDs (default: "ADR")
+    #[serde(default = "default_adr_prefix")]
+    pub adr: String,
+}
+
+fn default_rfc_prefix() -> String {
+    "RFC".to_string()
+}
+
+fn default_adr_prefix() -> String {
+    "ADR".to_string()
+}
+
+impl Default for PrefixesConfig {
+    fn default() -> Self {
+        Self {
+            rfc: default_rfc_prefix(),
+            adr: default_adr_prefix(),
+        }
+    }
+}
+
+impl PrefixesConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether `id` is an RFC or clause ID (`RFC-0001`, `RFC-0001:C-NAME`).
+    pub fn is_rfc(&self, id: &str) -> bool {
+        has_prefix(id, &self.rfc)
+    }
+
+    pub fn is_adr(&self, id: &str) -> bool {
+        has_prefix(id, &self.adr)
+    }
+
+    pub fn is_work(&self, id: &str) -> bool {
+        has_prefix(id, WORK_PREFIX)
+    }
+
+    /// Artifact IDs, clause names, and placeholders such as `RFC-NNNN`.
+    pub fn is_artifact_shaped(&self, name: &str) -> bool {
+        self.is_rfc(name)
+            || self.is_adr(name)
+            || self.is_work(name)
+            || has_prefix(name, CLAUSE_PREFIX)
+    }
+
+    pub fn rfc_id(&self, number: u32) -> String {
+        format!("{}-{number:04}", self.rfc)
+    }
+
+    pub fn adr_id(&self, number: u32) -> String {
+        format!("{}-{number:04}", self.adr)
+    }
+
+    /// Number of an RFC ID, e.g. `7` for `RFC-0007`.
+    pub fn rfc_number(&self, id: &str) -> Option<u32> {
+        id_number(id, &self.rfc)
+    }
+
+    /// Number of an ADR file name or ID, e.g. `7` for `ADR-0007-cache.toml`.
+    pub fn adr_number(&self, id: &str) -> Option<u32> {
+        id_number(id, &self.adr)
+    }
+
+    /// Regex alternation matching any RFC, clause, ADR, or work item ID.
+    pub fn id_pattern(&self) -> String {
+        format!(
+            r"{}-\d{{4}}(?::{CLAUSE_PREFIX}-[A-Z][A-Z0-9-]*)?|{}-\d{{4}}|{}",
+            self.rfc,
+            self.adr,
+            work_id_pattern()
+        )
+    }
+
+    /// Rewrite the default prefixes in a bundled JSON schema regex.
+    pub fn schema_pattern(&self, pattern: &str) -> String {
+        pattern
+            .replace(r"RFC-\d", &format!(r"{}-\d", self.rfc))
+            .replace(r"ADR-\d", &format!(r"{}-\d", self.adr))
+    }
+
+    pub fn validate(&self) -> DiagnosticResult<()> {
+        for prefix in [&self.rfc, &self.adr] {
+            if !is_valid_prefix(prefix) || RESERVED_PREFIXES.contains(&prefix.as_str()) {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E0501ConfigInvalid,
+                    format!(
+                        "Invalid artifact prefix '{prefix}' (expected 1-10 uppercase letters or digits, starting with a letter, other than {})",
+                        RESERVED_PREFIXES.join(", ")
+                    ),
+                    "prefixes",
+                ));
+            }
+        }
+        if self.rfc == self.adr {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0501ConfigInvalid,
+                format!("RFCs and ADRs cannot share the prefix '{}'", self.rfc),
+                "prefixes",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn has_prefix(id: &str, prefix: &str) -> bool {
+    id.strip_prefix(prefix)
+        .is_some_and(|rest| rest.starts_with('-'))
+}
+
+fn id_number(id: &str, prefix: &str) -> Option<u32> {
+    let rest = id.strip_prefix(prefix)?.strip_prefix('-')?;
+    let end = rest
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom() -> PrefixesConfig {
+        PrefixesConfig {
+            rfc: "SPEC".to_string(),
+            adr: "DEC".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_classifies_configured_prefixes() {
+        let prefixes = custom();
+        assert!(prefixes.is_rfc("SPEC-0001"));
+        assert!(prefixes.is_rfc("SPEC-0001:C-NAME"));
+        assert!(!prefixes.is_rfc("RFC-0001"));
+        assert!(!prefixes.is_rfc("SPECS-0001"));
+        assert!(prefixes.is_adr("DEC-0002"));
+        assert_eq!(prefixes.rfc_id(7), "SPEC-0007");
+        assert_eq!(prefixes.adr_number("DEC-0012-cache.toml"), Some(12));
+        assert_eq!(prefixes.rfc_number("SPEC-x001"), None);
+        assert!(prefixes.is_artifact_shaped("SPEC-NNNN"));
+        assert!(prefixes.is_artifact_shaped("WI-PAY-2026-01-17-001"));
+        assert!(prefixes.is_artifact_shaped("C-CACHE"));
+        assert!(!prefixes.is_artifact_shaped("RFC-0001"));
+        assert!(!prefixes.is_artifact_shaped("CACHE-TTL"));
+    }
+
+    #[test]
+    fn test_rewrites_schema_patterns() {
+        assert_eq!(
+            custom().schema_pattern(r"^(RFC-\d{4}(?::C-[A-Z]+)?|ADR-\d{4})$"),
+            r"^(SPEC-\d{4}(?::C-[A-Z]+)?|DEC-\d{4})$"
+        );
+    }
+
+    #[test]
+    fn test_rejects_reserved_or_shared_prefixes() {
+        let mut prefixes = custom();
+        prefixes.adr = "WI".to_string();
+        assert!(prefixes.validate().is_err());
+        prefixes.adr = "SPEC".to_string();
+        assert!(prefixes.validate().is_err());
+        prefixes.adr = "dec".to_string();
+        assert!(prefixes.validate().is_err());
+        assert!(custom().validate().is_ok());
+    }
+}
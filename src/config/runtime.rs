@@ -1,45 +1,46 @@
-use super::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use super::{Config, ConfigLayers, PrefixesConfig, bracket_ref_pattern};
+use crate::diagnostic::DiagnosticResult;
 use std::path::{Path, PathBuf};
 
 impl Config {
-    /// Load config from file or use defaults
+    /// Load config from its layers: defaults, the config file, the per-user
+    /// `config.local.toml`, and `GOVCTL_*` environment overrides.
     ///
     /// All relative paths in the config are resolved relative to the project root
     /// (the parent of gov/config.toml), not the current working directory.
     pub fn load(path: Option<&Path>) -> DiagnosticResult<Self> {
-        let config_path = path
-            .map(PathBuf::from)
-            .or_else(Self::find_config)
-            .unwrap_or_else(|| PathBuf::from("gov/config.toml"));
-
-        if config_path.exists() {
-            let content = std::fs::read_to_string(&config_path).map_err(|err| {
-                Diagnostic::io_error("read config", err, config_path.display().to_string())
-            })?;
-            let mut config: Config = toml::from_str(&content).map_err(|err| {
-                Diagnostic::new(
-                    DiagnosticCode::E0501ConfigInvalid,
-                    format!("Failed to parse config: {err}"),
-                    config_path.display().to_string(),
-                )
-            })?;
-
-            // Resolve paths to absolute. gov_root is always <project_root>/gov.
-            if let Some(project_root) = config_path.parent().and_then(|p| p.parent()) {
-                config.gov_root = project_root.join("gov");
-                if config.paths.docs_output.is_relative() {
-                    config.paths.docs_output = project_root.join(&config.paths.docs_output);
-                }
-                if config.paths.agent_dir.is_relative() {
-                    config.paths.agent_dir = project_root.join(&config.paths.agent_dir);
-                }
-            }
+        let config_path = Self::resolve_path(path);
+        let mut config = ConfigLayers::load(&config_path)?.config()?;
+        config.prefixes.validate()?;
+        config.work_item.validate()?;
+        if !config.prefixes.is_default()
+            && config.source_scan.pattern == bracket_ref_pattern(&PrefixesConfig::default())
+        {
+            config.source_scan.pattern = bracket_ref_pattern(&config.prefixes);
+        }
 
-            Ok(config)
-        } else {
-            Ok(Config::default())
+        // Resolve paths against the project root. gov_root is always <project_root>/gov.
+        let project_root = config_path
+            .parent()
+            .and_then(Path::parent)
+            .unwrap_or_else(|| Path::new(""));
+        config.gov_root = project_root.join("gov");
+        if config.paths.docs_output.is_relative() {
+            config.paths.docs_output = project_root.join(&config.paths.docs_output);
+        }
+        if config.paths.agent_dir.is_relative() {
+            config.paths.agent_dir = project_root.join(&config.paths.agent_dir);
         }
+
+        Ok(config)
+    }
+
+    /// Config file location: the explicit path, else the nearest
+    /// `gov/config.toml` above the current directory.
+    pub fn resolve_path(path: Option<&Path>) -> PathBuf {
+        path.map(PathBuf::from)
+            .or_else(Self::find_config)
+            .unwrap_or_else(|| PathBuf::from("gov/config.toml"))
     }
 
     /// Find config file by walking up directory tree.
@@ -102,6 +103,22 @@ impl Config {
         self.gov_root.join("guard")
     }
 
+    pub fn mapping_dir(&self) -> PathBuf {
+        self.gov_root.join("mapping")
+    }
+
+    pub fn audit_log_path(&self) -> PathBuf {
+        self.gov_root.join("audit.log")
+    }
+
+    pub fn assets_dir(&self) -> PathBuf {
+        self.gov_root.join("assets")
+    }
+
+    pub fn fragments_dir(&self) -> PathBuf {
+        self.gov_root.join("fragments")
+    }
+
     pub fn templates_dir(&self) -> PathBuf {
         self.gov_root.join("templates")
     }
@@ -110,6 +127,13 @@ impl Config {
         self.paths.docs_output.join("rfc")
     }
 
+    /// Snapshot of one RFC version: `docs/rfc/<RFC-ID>/v<version>.<extension>`.
+    pub fn rfc_snapshot_path(&self, rfc_id: &str, version: &str, extension: &str) -> PathBuf {
+        self.rfc_output()
+            .join(rfc_id)
+            .join(format!("v{version}.{extension}"))
+    }
+
     pub fn adr_output(&self) -> PathBuf {
         self.paths.docs_output.join("adr")
     }
@@ -118,6 +142,11 @@ impl Config {
         self.paths.docs_output.join("work")
     }
 
+    /// Rendered copies of attachments: `docs/assets/<path under gov/assets/>`.
+    pub fn assets_output(&self) -> PathBuf {
+        self.paths.docs_output.join("assets")
+    }
+
     pub fn releases_path(&self) -> PathBuf {
         self.gov_root.join("releases.toml")
     }
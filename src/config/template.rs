@@ -4,7 +4,9 @@ impl Config {
     /// Generate default config TOML at the given schema version.
     pub fn default_toml(schema_version: u32) -> String {
         format!(
-            r#"[project]
+            r#"#:schema schema/config.schema.json
+
+[project]
 name = "my-project"
 # Default owner for new RFCs (uses git user.name if not set)
 # default_owner = "@your-handle"
@@ -21,12 +23,20 @@ docs_output = "docs"
 [schema]
 version = {schema_version}
 
+# [prefixes]
+# Rename artifact ID prefixes, e.g. SPEC-0001 and DEC-0001
+# rfc = "SPEC"
+# adr = "DEC"
+
 # [work_item]
 # ID strategy for work items (default: sequential)
 # - sequential: WI-YYYY-MM-DD-NNN (solo projects)
 # - author-hash: WI-YYYY-MM-DD-{{hash}}-NNN (multi-person teams, uses git email)
 # - random: WI-YYYY-MM-DD-{{rand}} (simple uniqueness)
+# - ulid: WI-{{ulid}} (no coordination across branches or agents)
 # id_strategy = "author-hash"
+# Namespace after WI- for every strategy, e.g. WI-PAY-YYYY-MM-DD-NNN
+# id_prefix = "PAY"
 
 # [verification]
 # Enable project-level default verification guards.
@@ -38,16 +48,40 @@ version = {schema_version}
 # enabled = false
 # include = ["src/**/*.rs", "crates/**/*.rs", "**/*.md"]
 # exclude = []
+# Skip references in string literals; docs and other prose count as comment
+# comments_only = false
+# comment_syntax = {{ vue = "html" }}
+# Paths in .gitignore and .govctlignore files are skipped
+# respect_gitignore = true
 
 # [concurrency]
 # Maximum seconds to wait for exclusive lock before failing (default: 30)
 # Implements [[RFC-0004]] concurrent write safety
 # lock_timeout_secs = 30
+# Flush each written file to disk before it replaces the original (default: true)
+# fsync = true
 
 # [tags]
 # Controlled-vocabulary tags for artifact classification — [[RFC-0002:C-RESOURCES]]
 # Artifacts may only use tags listed here.
 # allowed = ["security", "breaking-change", "performance"]
+"#
+        )
+    }
+
+    /// Generate a config TOML with only the required sections and no guidance.
+    pub fn minimal_toml(schema_version: u32) -> String {
+        format!(
+            r#"#:schema schema/config.schema.json
+
+[project]
+name = "my-project"
+
+[paths]
+docs_output = "docs"
+
+[schema]
+version = {schema_version}
 "#
         )
     }
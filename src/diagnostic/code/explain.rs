@@ -0,0 +1,1415 @@
+//! Long-form explanations shown by `govctl explain <CODE>`.
+//!
+//! Every code has an entry, so adding a code without documenting it fails to
+//! compile. Related clauses name govctl's own specification RFCs under
+//! `gov/rfc/`, not RFCs of the project being checked.
+
+use super::DiagnosticCode;
+
+/// What a diagnostic means and how to resolve it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation {
+    /// One or two sentences on what the diagnostic reports.
+    pub summary: &'static str,
+    /// Situations that typically produce it.
+    pub causes: &'static [&'static str],
+    /// Steps that resolve it.
+    pub fixes: &'static [&'static str],
+    /// Specification clauses that define the rule, as `RFC-NNNN:C-NAME`.
+    pub clauses: &'static [&'static str],
+}
+
+const fn explained(
+    summary: &'static str,
+    causes: &'static [&'static str],
+    fixes: &'static [&'static str],
+    clauses: &'static [&'static str],
+) -> Explanation {
+    Explanation {
+        summary,
+        causes,
+        fixes,
+        clauses,
+    }
+}
+
+pub(super) fn explanation(code: &DiagnosticCode) -> Explanation {
+    match code {
+        // E01xx - RFC
+        DiagnosticCode::E0101RfcSchemaInvalid => explained(
+            "An RFC's rfc.toml does not match the RFC schema.",
+            &[
+                "A hand edit left invalid TOML, an unknown field, or a value of the wrong type",
+                "The version is not valid semver",
+            ],
+            &[
+                "Fix the field named in the message, or edit through `govctl rfc set`",
+                "Run `govctl check` to confirm the file loads",
+            ],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0102RfcNotFound => explained(
+            "The command names an RFC that does not exist.",
+            &[
+                "A typo in the RFC ID",
+                "The RFC was deleted, moved, or lives in another workspace member",
+            ],
+            &["List existing RFCs with `govctl rfc list` and retry with one of them"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E0103RfcIdMismatch => explained(
+            "The `id` inside rfc.toml differs from the name of its directory.",
+            &["The directory was renamed or copied by hand"],
+            &["Rename the directory or the `id` so they agree"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0104RfcInvalidTransition => explained(
+            "The requested RFC status or phase change is not allowed by the lifecycle.",
+            &[
+                "Advancing a draft RFC past spec before it is finalized",
+                "A status and phase combination the lifecycle forbids, such as draft with stable",
+                "Deleting a clause from an RFC that is no longer a draft",
+            ],
+            &[
+                "Check the RFC with `govctl rfc show` and follow the allowed order",
+                "Finalize with `govctl rfc finalize` before advancing the phase",
+            ],
+            &[
+                "RFC-0001:C-RFC-STATUS",
+                "RFC-0001:C-RFC-PHASE",
+                "RFC-0000:C-PHASE-LIFECYCLE",
+            ],
+        ),
+        DiagnosticCode::E0105RfcRefNotFound => explained(
+            "An RFC's `refs` names an artifact that does not exist.",
+            &["The referenced artifact was renamed, deleted, or mistyped"],
+            &["Fix the ID, or drop it with `govctl rfc remove <RFC> refs <ID>`"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0106RfcSupersedesNotFound => explained(
+            "An RFC's `supersedes` names an RFC that does not exist.",
+            &["The superseded RFC was deleted or the ID is mistyped"],
+            &["Point `supersedes` at an existing RFC or clear it"],
+            &["RFC-0001:C-RFC-STATUS"],
+        ),
+        DiagnosticCode::E0107SourceRefUnknown => explained(
+            "A `[[...]]` reference in scanned source or docs names no known artifact or anchor.",
+            &[
+                "The artifact was deleted or renamed",
+                "A typo in the ID or anchor name",
+                "An anchor that no clause declares",
+            ],
+            &[
+                "Correct the reference or remove the brackets",
+                "List valid anchors with `govctl anchor list`",
+            ],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0108RfcBumpRequiresSummary => explained(
+            "`rfc bump` needs both a bump level and a summary.",
+            &["`--summary` given without `--patch`, `--minor`, or `--major`, or the reverse"],
+            &["Pass a level and a summary, e.g. `govctl rfc bump RFC-0001 --minor -m \"...\"`"],
+            &["RFC-0002:C-LIFECYCLE-VERBS"],
+        ),
+        DiagnosticCode::E0109RfcAlreadyExists => explained(
+            "An RFC with this ID or directory already exists.",
+            &["`rfc new --id`, `rfc split`, or `rfc merge` chose an ID that is taken"],
+            &["Pick another ID, or let `govctl rfc new` allocate the next one"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0110RfcInvalidId => explained(
+            "An RFC ID does not use the configured prefix.",
+            &[
+                "A typo in the ID",
+                "The project changed `[prefixes]` and the ID uses the old prefix",
+            ],
+            &["Use an ID of the form `<prefix>-NNNN`, matching `[prefixes] rfc` in config.toml"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0111RfcNoChangelog => explained(
+            "The RFC has no changelog entry for its current version.",
+            &["The version was raised by hand without adding a changelog entry"],
+            &["Bump through `govctl rfc bump`, which records the entry, or add it by hand"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0112RfcReferenceHierarchy => explained(
+            "An RFC references an ADR or work item; RFCs may only reference other RFCs and clauses.",
+            &["`refs` or a `[[...]]` link in RFC prose points down the hierarchy"],
+            &["Move the link to the ADR or work item, which may reference the RFC"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0113RfcBumpNoAmendment => explained(
+            "`rfc bump` found no RFC or clause changes since the last version.",
+            &["The bump was run twice, or before editing any content"],
+            &["Make the amendment first, then bump"],
+            &["RFC-0002:C-LIFECYCLE-VERBS"],
+        ),
+        DiagnosticCode::E0114RfcPendingAmendment => explained(
+            "A normative RFC has content changes that are not yet released as a version.",
+            &["Clauses or RFC fields were edited and the phase was advanced before bumping"],
+            &["Run `govctl rfc bump <RFC> --patch|--minor|--major -m \"...\"` first"],
+            &["RFC-0001:C-RFC-PHASE", "RFC-0001:C-GATE-CONDITIONS"],
+        ),
+        DiagnosticCode::E0115RfcCurrentChangelogInvalid => explained(
+            "The RFC has more than one changelog entry for its current version.",
+            &["A hand edit or merge duplicated the current changelog entry"],
+            &["Merge the entries into one in rfc.toml"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0116RfcSectionNotFound => explained(
+            "The RFC has no section with this title.",
+            &["A typo in the section title, which must match exactly"],
+            &["Check the section titles with `govctl rfc show <RFC>`"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0117RfcSectionExists => explained(
+            "The RFC already has a section with this title.",
+            &["`rfc section add` or `rename` reused an existing title"],
+            &["Choose a different title"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0118RfcSectionNotEmpty => explained(
+            "The section still contains clauses and cannot be removed.",
+            &["`rfc section remove` on a section with clauses"],
+            &[
+                "Move or delete the clauses first",
+                "Pass `--force` to delete the section together with its clauses",
+            ],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0119RfcLastSection => explained(
+            "An RFC must keep at least one section.",
+            &["`rfc section remove` on the only section"],
+            &["Add the replacement section before removing this one"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0120RfcOwnerUnknown => explained(
+            "An owner or approver is not a member or team listed in `[owners]`.",
+            &[
+                "A typo in the identity",
+                "The person was never added to `[owners]` in config.toml",
+            ],
+            &["Add the identity to `[owners]`, or use one that is listed"],
+            &["RFC-0001:C-GATE-CONDITIONS"],
+        ),
+        DiagnosticCode::E0121RfcApprovalMissing => explained(
+            "Finalizing the RFC as normative needs approvals it does not have.",
+            &["`[approvals]` requires an owner's approval or a quorum that is not met"],
+            &["Record approvals with `govctl rfc approve <RFC> --as <identity>`, then retry"],
+            &["RFC-0001:C-RFC-STATUS", "RFC-0001:C-GATE-CONDITIONS"],
+        ),
+        DiagnosticCode::E0122RfcFrozen => explained(
+            "The RFC is frozen for review, so changes to it and its clauses are refused.",
+            &["`govctl rfc freeze` was run and the review is still open"],
+            &["Finish the review, then run `govctl rfc unfreeze <RFC>`"],
+            &["RFC-0001:C-RFC-STATUS"],
+        ),
+        DiagnosticCode::E0123RfcGateUnmet => explained(
+            "`rfc advance` refused because a `[gates]` checklist item for the next phase is unmet.",
+            &["The project requires checks such as linked work items before the phase change"],
+            &[
+                "Satisfy the listed gates and advance again",
+                "Pass `--force` to override, which records a W0118 warning",
+            ],
+            &["RFC-0001:C-GATE-CONDITIONS"],
+        ),
+        DiagnosticCode::E0124RfcSnapshotNotFound => explained(
+            "There is no stored snapshot of the RFC at the requested version.",
+            &[
+                "The version was never released",
+                "Snapshots were not enabled when that version was sealed",
+            ],
+            &[
+                "List released versions in the RFC changelog",
+                "Enable `[snapshots] rfc_versions = true` to keep future versions",
+            ],
+            &["RFC-0002:C-SHOW-PROJECTION"],
+        ),
+        DiagnosticCode::E0125RfcSupersessionCycle => explained(
+            "RFC `supersedes` links loop back on themselves.",
+            &["Two RFCs each claim to supersede the other, directly or through a chain"],
+            &["Inspect the chain with `govctl supersede chain <RFC>` and break the loop"],
+            &["RFC-0001:C-RFC-STATUS"],
+        ),
+        DiagnosticCode::E0126RfcSupersessionFork => explained(
+            "More than one artifact claims to supersede the same RFC.",
+            &["Two replacements were written in parallel"],
+            &["Keep one replacement and point the others at it or elsewhere"],
+            &["RFC-0001:C-RFC-STATUS"],
+        ),
+        // E02xx - Clause
+        DiagnosticCode::E0201ClauseSchemaInvalid => explained(
+            "A clause file does not match the clause schema.",
+            &["A hand edit left invalid TOML, an unknown field, or a value of the wrong type"],
+            &["Fix the field named in the message, or edit through `govctl clause set`"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0202ClauseNotFound => explained(
+            "The command names a clause that does not exist.",
+            &[
+                "A typo in the `RFC-NNNN:C-NAME` ID",
+                "The clause was moved to another RFC",
+            ],
+            &["List clauses with `govctl clause list <RFC>` and retry with one of them"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0203ClauseIdMismatch => explained(
+            "The clause `id` differs from its file name.",
+            &["The file was renamed or copied by hand"],
+            &["Rename the file or the `id` so they agree"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0204ClausePathInvalid => explained(
+            "A clause path in rfc.toml or on the command line is malformed.",
+            &["A section lists a clause file outside the RFC's `clauses/` directory"],
+            &["Use paths of the form `clauses/C-NAME.toml`"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0206ClauseSupersededByUnknown => explained(
+            "A clause's `superseded_by` is inconsistent with its status or names an unknown clause.",
+            &[
+                "`superseded_by` set on a clause that is not superseded",
+                "The replacement clause was deleted or mistyped",
+            ],
+            &["Supersede through `govctl clause supersede`, which keeps both fields consistent"],
+            &["RFC-0001:C-CLAUSE-STATUS"],
+        ),
+        DiagnosticCode::E0207ClauseSupersededByNotActive => explained(
+            "A clause can only be superseded by an active clause.",
+            &[
+                "The replacement is itself deprecated or superseded",
+                "Relocating a clause that is no longer active",
+            ],
+            &[
+                "Supersede by the current head of the chain instead",
+                "Find it with `govctl supersede chain <clause>`",
+            ],
+            &["RFC-0001:C-CLAUSE-STATUS"],
+        ),
+        DiagnosticCode::E0208ClauseAlreadyDeprecated => explained(
+            "The clause is already deprecated.",
+            &["`clause deprecate` run a second time"],
+            &["To replace a deprecated clause, use `govctl clause supersede`"],
+            &["RFC-0001:C-CLAUSE-STATUS"],
+        ),
+        DiagnosticCode::E0209ClauseAlreadySuperseded => explained(
+            "The clause is superseded, which is terminal.",
+            &["Deprecating or superseding a clause that is already superseded"],
+            &["Act on its replacement instead"],
+            &["RFC-0001:C-CLAUSE-STATUS"],
+        ),
+        DiagnosticCode::E0210ClauseInvalidIdFormat => explained(
+            "A clause ID is not of the form `RFC-NNNN:C-NAME`.",
+            &[
+                "The `C-` prefix or the RFC part is missing",
+                "The name uses lowercase or other characters",
+            ],
+            &["Use uppercase words joined by hyphens after `C-`, e.g. `RFC-0001:C-CACHE-TTL`"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0211ClauseStillReferenced => explained(
+            "The clause cannot be deleted while other artifacts reference it.",
+            &["ADRs, work items, or other clauses list it in `refs` or prose"],
+            &[
+                "Remove or retarget the listed references first",
+                "For clauses already in force, deprecate or supersede instead",
+            ],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0212ClauseSupersessionCycle => explained(
+            "Clause `superseded_by` links loop back on themselves.",
+            &[
+                "Superseding a clause with itself",
+                "Two clauses that supersede each other, directly or through a chain",
+            ],
+            &["Inspect the chain with `govctl supersede chain <clause>` and break the loop"],
+            &["RFC-0001:C-CLAUSE-STATUS"],
+        ),
+        DiagnosticCode::E0213ClauseSupersededByMissing => explained(
+            "A superseded clause does not say which clause replaced it.",
+            &["The status was set to superseded by hand"],
+            &["Set `superseded_by`, or use `govctl clause supersede` to do both"],
+            &["RFC-0001:C-CLAUSE-STATUS"],
+        ),
+        DiagnosticCode::E0214ClauseAnchorInvalid => explained(
+            "A clause anchor is not uppercase words joined by hyphens, or looks like an artifact ID.",
+            &["An anchor such as `cache-ttl` or `RFC-0001`"],
+            &["Rename the anchor, e.g. `CACHE-TTL`"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0215ClauseAnchorDuplicate => explained(
+            "Two clauses declare the same anchor.",
+            &["A clause was copied along with its anchors"],
+            &["Keep the anchor on one clause; check owners with `govctl anchor list`"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0216FragmentNotFound => explained(
+            "Clause text includes a fragment that has no file under `gov/fragments/`.",
+            &[
+                "A typo in the fragment ID of `{{include:<ID>}}`",
+                "The fragment file was renamed or deleted",
+            ],
+            &["Create `gov/fragments/<ID>.md`, or fix the ID in the include"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0217FragmentParameterMissing => explained(
+            "An include does not give a value for a `{{name}}` placeholder of its fragment.",
+            &["The fragment gained a placeholder after the clause was written"],
+            &["Pass the value in the include, e.g. `{{include:FRAG-SEC-001 system=\"Payments\"}}`"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::E0218ClauseNotRelocatable => explained(
+            "Only active clauses can move to another RFC.",
+            &["`rfc split` or `rfc merge` named a deprecated or superseded clause"],
+            &[
+                "Leave the clause in its RFC as history",
+                "Split or merge only the active clauses, and cite the old one from the new RFC",
+            ],
+            &["RFC-0001:C-CLAUSE-STATUS"],
+        ),
+        // E03xx - ADR
+        DiagnosticCode::E0301AdrSchemaInvalid => explained(
+            "An ADR file does not match the ADR schema.",
+            &["A hand edit left invalid TOML, an unknown field, or a value of the wrong type"],
+            &["Fix the field named in the message, or edit through `govctl adr set`"],
+            &["RFC-0000:C-ADR-DEF"],
+        ),
+        DiagnosticCode::E0302AdrNotFound => explained(
+            "The command names an ADR that does not exist.",
+            &["A typo in the ADR ID", "The ADR was deleted"],
+            &["List ADRs with `govctl adr list` and retry with one of them"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E0303AdrInvalidTransition => explained(
+            "The requested ADR status change is not allowed.",
+            &[
+                "Accepting an ADR that is not proposed",
+                "Accepting an ADR whose alternatives lack pros, cons, or a rejection reason",
+            ],
+            &[
+                "Follow proposed -> accepted | rejected, then accepted -> superseded",
+                "Complete the alternatives, or pass `--force` for historical backfills",
+            ],
+            &["RFC-0001:C-ADR-STATUS"],
+        ),
+        DiagnosticCode::E0304AdrRefNotFound => explained(
+            "An ADR's `refs` names an artifact that does not exist.",
+            &["The referenced artifact was renamed, deleted, or mistyped"],
+            &["Fix the ID, or drop it with `govctl adr remove <ADR> refs <ID>`"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0305AdrCannotDeprecate => explained(
+            "ADRs are not deprecated; they are superseded by a newer decision.",
+            &[
+                "A generic deprecate on an ADR",
+                "`adr deprecate` (a sunset date) on an ADR that is not accepted",
+            ],
+            &["Record the new decision and run `govctl adr supersede <ADR> --by <ADR>`"],
+            &["RFC-0001:C-ADR-STATUS"],
+        ),
+        DiagnosticCode::E0306AdrReferenceHierarchy => explained(
+            "An ADR references a work item; ADRs may only reference RFCs, clauses, and ADRs.",
+            &["`refs` or a `[[...]]` link in ADR prose points at a work item"],
+            &["Reference the ADR from the work item instead"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0307AdrProjectionConflict => explained(
+            "ADR prose uses a heading that the renderer owns.",
+            &["Context, decision, or consequences contain a heading like `## Decision`"],
+            &["Use a lower heading level or different wording"],
+            &["RFC-0000:C-ADR-PROJECTION-OWNERSHIP"],
+        ),
+        DiagnosticCode::E0308AdrApprovalMissing => explained(
+            "Accepting the ADR needs approvals it does not have.",
+            &["`[approvals] adr_quorum` is not met"],
+            &["Record approvals with `govctl adr approve <ADR> --as <identity>`, then retry"],
+            &["RFC-0001:C-ADR-STATUS"],
+        ),
+        DiagnosticCode::E0309AdrSupersessionCycle => explained(
+            "ADR `superseded_by` links loop back on themselves.",
+            &["Two ADRs each claim to supersede the other, directly or through a chain"],
+            &["Inspect the chain with `govctl supersede chain <ADR>` and break the loop"],
+            &["RFC-0001:C-ADR-STATUS"],
+        ),
+        // E04xx - Work Item
+        DiagnosticCode::E0401WorkSchemaInvalid => explained(
+            "A work item file does not match the work item schema.",
+            &[
+                "A hand edit left invalid TOML, an unknown field, or a value of the wrong type",
+                "Verification waivers that are duplicated, empty, or name unrequired guards",
+            ],
+            &["Fix the field named in the message, or edit through `govctl work set`"],
+            &["RFC-0000:C-WORK-DEF"],
+        ),
+        DiagnosticCode::E0402WorkNotFound => explained(
+            "The command names a work item that does not exist.",
+            &["A typo in the work item ID", "The work item was deleted"],
+            &["List work items with `govctl work list` and retry with one of them"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E0403WorkInvalidTransition => explained(
+            "The requested work item status change is not allowed.",
+            &[
+                "Skipping a state, such as queue -> done",
+                "Reopening a done or cancelled item without `--reopen --reason`",
+            ],
+            &[
+                "Follow queue -> active -> done, or cancel",
+                "Reopen with `govctl work move <WI> active --reopen --reason \"...\"`",
+            ],
+            &["RFC-0001:C-WORK-STATUS"],
+        ),
+        DiagnosticCode::E0404WorkRefNotFound => explained(
+            "A work item's `refs` names an unknown artifact, or the item is still referenced.",
+            &[
+                "The referenced artifact was renamed, deleted, or mistyped",
+                "Deleting a work item that other items depend on or reference",
+            ],
+            &["Fix or remove the listed references"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0405WorkDirNotFound => explained(
+            "The work item directory does not exist.",
+            &[
+                "`paths` in config.toml points somewhere else",
+                "The project was never initialized",
+            ],
+            &["Run `govctl init`, or fix the configured work directory"],
+            &["RFC-0000:C-WORK-DEF"],
+        ),
+        DiagnosticCode::E0406WorkAmbiguousMatch => explained(
+            "A work item title fragment matches more than one item.",
+            &["The fragment is shared by several titles"],
+            &["Use one of the full work item IDs listed in the message, or a longer fragment"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E0407WorkMissingCriteria => explained(
+            "A work item cannot be done without acceptance criteria, all of them ticked.",
+            &["No criteria were added", "Some criteria are still pending"],
+            &[
+                "Add criteria with `govctl work add <WI> acceptance_criteria \"...\"`",
+                "Tick them with `govctl work tick <WI> acceptance_criteria <pattern> -s done`",
+            ],
+            &["RFC-0001:C-WORK-STATUS", "RFC-0000:C-WORK-DEF"],
+        ),
+        DiagnosticCode::E0408WorkCriteriaMissingCategory => explained(
+            "Acceptance criteria need a changelog category.",
+            &["A criterion was added without a `fix:`/`add:`/... prefix or `--category`"],
+            &["Prefix the text, e.g. `\"fix: handle empty input\"`, or pass `--category`"],
+            &["RFC-0000:C-WORK-DEF"],
+        ),
+        DiagnosticCode::E0409WorkDependencyInvalid => explained(
+            "A work item dependency is not a work item ID.",
+            &["`depends_on` lists an RFC, ADR, or free text"],
+            &["List only work item IDs; put other artifacts in `refs`"],
+            &["RFC-0006:C-DEPENDENCY-SEMANTICS"],
+        ),
+        DiagnosticCode::E0410WorkDependencyNotFound => explained(
+            "A work item depends on a work item that does not exist.",
+            &["The dependency was deleted or mistyped"],
+            &["Fix or remove the dependency"],
+            &["RFC-0006:C-DEPENDENCY-SEMANTICS"],
+        ),
+        DiagnosticCode::E0411WorkDependencyCycle => explained(
+            "Work item dependencies form a cycle.",
+            &["Two items depend on each other, directly or through a chain"],
+            &["Remove one dependency in the reported cycle"],
+            &["RFC-0006:C-DEPENDENCY-SEMANTICS"],
+        ),
+        DiagnosticCode::E0412WorkDoneRequirementUnmet => explained(
+            "A `[work_item.done]` requirement other than acceptance criteria is unmet.",
+            &["The project requires notes, refs, or passing guards before done"],
+            &["Add what the message names, then move the item to done again"],
+            &["RFC-0001:C-WORK-STATUS"],
+        ),
+        DiagnosticCode::E0413WorkNotQueued => explained(
+            "`work rank` names a work item that is not queued; only queued items have a queue rank.",
+            &["The item was already started, finished, or cancelled"],
+            &["Rank relative to a queued item (`govctl work list queue`)"],
+            &["RFC-0002:C-LIFECYCLE-VERBS"],
+        ),
+        // E05xx - Config
+        DiagnosticCode::E0501ConfigInvalid => explained(
+            "config.toml is invalid or inconsistent.",
+            &[
+                "Invalid TOML or a value of the wrong type",
+                "Settings that reference unknown guards or tags",
+                "Tree-wide sections set in config.local.toml",
+            ],
+            &["Run `govctl config check` for details and fix the named key"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0502PathNotFound => explained(
+            "A required file or directory does not exist.",
+            &[
+                "Running outside a govctl project",
+                "The gov root, a config file, or a workspace manifest is missing",
+            ],
+            &["Run `govctl init`, or point at the project with `-C`/`-p`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0503LockTimeout => explained(
+            "Another govctl write command holds the gov-root lock.",
+            &["A parallel govctl run, or one stuck waiting for input"],
+            &[
+                "Wait for it to finish",
+                "Raise `--lock-wait` or `concurrency.lock_timeout_secs`",
+            ],
+            &[
+                "RFC-0004:C-CONCURRENT-WRITE",
+                "RFC-0004:C-FAILURE-BEHAVIOUR",
+            ],
+        ),
+        DiagnosticCode::E0504PathConflict => explained(
+            "Two configured paths collide, or a path has the wrong kind.",
+            &[
+                "Output directories that overlap `gov/` or each other",
+                "A file where a directory is expected",
+                "A leftover migration backup directory",
+            ],
+            &["Move or remove the conflicting path, or change the setting"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0505MigrationRequired => explained(
+            "The project uses a storage format this govctl no longer reads.",
+            &["Legacy JSON artifacts or amendment signatures from an old govctl"],
+            &["Run `govctl migrate`, with an older govctl if the message says so"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0506TemplateInvalid => explained(
+            "A render template failed to parse or render.",
+            &["A syntax error or unknown variable in a custom template"],
+            &["Fix the template, or remove it to use the built-in one"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0507ConfigSchemaInvalid => explained(
+            "config.toml does not match the config schema.",
+            &["A value of the wrong type, or a value outside its allowed set"],
+            &["Run `govctl config check` and fix the reported key"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0508ConfigKeyUnknown => explained(
+            "`config get` or `config set` names a key that does not exist.",
+            &["A typo or a key from another govctl version"],
+            &["List keys with `govctl config list`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0509WorkspaceInvalid => explained(
+            "The workspace manifest is invalid.",
+            &["Invalid TOML, or a member name with characters other than a-z, 0-9, '-', '_'"],
+            &["Fix the manifest entry named in the message"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0510WorkspaceMemberNotFound => explained(
+            "The command names a workspace member that does not exist.",
+            &["A typo in the member name"],
+            &["Use one of the members the message lists"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0511WorkspaceRefNotFound => explained(
+            "A cross-member reference names an artifact the other member does not have.",
+            &["The artifact was renamed or deleted in the other member"],
+            &["Fix the reference; run `govctl check --workspace` to recheck"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0512RemoteNotConfigured => explained(
+            "A remote reference names a remote with no `[remotes.<name>]` section.",
+            &[
+                "A typo in the remote name",
+                "The remote was never configured",
+            ],
+            &["Add `[remotes.<name>]` to config.toml or fix the reference"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::E0513PublishNotConfigured => explained(
+            "An integration is missing its config section or API token.",
+            &[
+                "No `[confluence]`, `[jira]`, or GitHub settings",
+                "The token environment variable is not set",
+            ],
+            &["Add the config section and export the token the message names"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0514VariableUndefined => explained(
+            "Clause or ADR text references `{{var.<name>}}`, but `[variables]` has no such name.",
+            &[
+                "A typo in the variable name",
+                "The variable is set only in another checkout's `config.local.toml`",
+            ],
+            &["Add the name under `[variables]` in `gov/config.toml`, or fix the reference"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        // E06xx - Signature
+        DiagnosticCode::E0601SignatureMismatch => explained(
+            "Rendered markdown does not match the source it claims to come from.",
+            &[
+                "The rendered file was edited directly",
+                "The source changed without re-rendering",
+            ],
+            &["Edit the source artifact, then run `govctl render`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0602SignatureMissing => explained(
+            "Rendered markdown lacks the signature header govctl writes.",
+            &["The file was written by hand or the header was deleted"],
+            &["Run `govctl render` to regenerate it"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        // E07xx - Release
+        DiagnosticCode::E0701ReleaseInvalidSemver => explained(
+            "A release version is not valid semver.",
+            &["A `v` prefix or a missing component, e.g. `v1.2` or `1.2`"],
+            &["Use `MAJOR.MINOR.PATCH`, e.g. `1.2.0`"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0702ReleaseDuplicate => explained(
+            "A release with this version already exists.",
+            &["`release` run twice with the same version"],
+            &["Choose the next version, or `govctl release undo` the existing one"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0703ReleaseNoUnreleasedItems => explained(
+            "There are no done work items that are not already in a release.",
+            &["Nothing was completed since the last release"],
+            &["Finish work items first"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0704ReleaseSchemaInvalid => explained(
+            "releases.toml does not match the release schema.",
+            &["A hand edit left invalid TOML or a value of the wrong type"],
+            &["Fix the reported entry in releases.toml"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0705ReleaseRefNotFound => explained(
+            "A release lists a work item that does not exist.",
+            &["The work item was deleted or renamed"],
+            &["Fix or remove the entry in releases.toml"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0706ReleaseWorkNotDone => explained(
+            "A release lists a work item that is not done.",
+            &["The item was reopened after the release was cut"],
+            &["Finish the item again, or remove it from the release"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0707ReleaseWorkDuplicate => explained(
+            "A work item appears in more than one release.",
+            &["A hand edit or merge added it twice"],
+            &["Keep it only in the release that shipped it"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0708ReleaseHistoryEmpty => explained(
+            "There is no release to undo.",
+            &["`release undo` before any release was cut"],
+            &["Nothing to do"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        DiagnosticCode::E0709ReleaseLatestMismatch => explained(
+            "`release undo` only removes the newest release, and the given version is not it.",
+            &["The version argument names an older release"],
+            &["Pass the newest version, shown first in releases.toml"],
+            &["RFC-0000:C-RELEASE-DEF"],
+        ),
+        // E10xx - Verification Guard
+        DiagnosticCode::E1001GuardSchemaInvalid => explained(
+            "A guard file does not match the guard schema.",
+            &["Invalid TOML, a wrong type, or an invalid regex `pattern`"],
+            &["Fix the field named in the message, or edit through `govctl guard set`"],
+            &["RFC-0000:C-GUARD-DEF"],
+        ),
+        DiagnosticCode::E1002GuardNotFound => explained(
+            "A guard named by a command, work item, or waiver does not exist.",
+            &["A typo in the guard ID", "The guard was deleted"],
+            &["List guards with `govctl guard list` and fix the reference"],
+            &["RFC-0000:C-GUARD-DEF", "RFC-0002:C-VERIFY-CONFIG"],
+        ),
+        DiagnosticCode::E1003GuardDuplicate => explained(
+            "Two guard files declare the same ID, or the new guard's ID is taken.",
+            &["A guard file was copied without changing its ID"],
+            &["Give each guard a unique ID"],
+            &["RFC-0000:C-GUARD-DEF"],
+        ),
+        DiagnosticCode::E1004GuardCheckFailed => explained(
+            "A verification guard failed.",
+            &[
+                "The guard command exited non-zero or its output missed `pattern`",
+                "Its output could not be captured",
+            ],
+            &[
+                "Run `govctl verify <GUARD>` to see the output and fix the failure",
+                "Waive it for a work item only with a recorded reason",
+            ],
+            &["RFC-0000:C-GUARD-DEF", "RFC-0002:C-VERIFY-CONFIG"],
+        ),
+        DiagnosticCode::E1005GuardTimeout => explained(
+            "A verification guard ran longer than its timeout.",
+            &["A slow or hanging command"],
+            &["Speed the command up or raise the guard's `timeout_secs`"],
+            &["RFC-0000:C-GUARD-DEF"],
+        ),
+        DiagnosticCode::E1006GuardInvalidTitle => explained(
+            "A guard title must produce an ID that starts with a letter.",
+            &["A title made only of digits or punctuation"],
+            &["Start the title with a word"],
+            &["RFC-0000:C-GUARD-DEF"],
+        ),
+        DiagnosticCode::E1007GuardStillReferenced => explained(
+            "The guard cannot be deleted while work items or config use it.",
+            &["Work items require or waive it, or it is in `verification.default_guards`"],
+            &["Remove the listed references first"],
+            &["RFC-0002:C-VERIFY-CONFIG"],
+        ),
+        DiagnosticCode::E1008HookFailed => explained(
+            "A blocking `[hooks]` command failed, so the command was stopped.",
+            &["The hook exited non-zero or could not be run"],
+            &[
+                "Fix the hook, or set `on_failure = \"warn\"` to make it advisory",
+                "Run with `-v` to see its output",
+            ],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        // E11xx - Tags
+        DiagnosticCode::E1101TagInvalidFormat => explained(
+            "Tags must be lowercase letters, digits, and hyphens, starting with a letter.",
+            &["Uppercase letters, spaces, or underscores in a tag"],
+            &["Rename the tag, e.g. `api-design`"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1102TagAlreadyExists => explained(
+            "The tag is already in `[tags] allowed`.",
+            &["`tag new` run twice"],
+            &["Nothing to do"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1103TagNotFound => explained(
+            "The tag is not in `[tags] allowed`.",
+            &["A typo in the tag"],
+            &["List tags with `govctl tag list`"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1104TagStillReferenced => explained(
+            "The tag cannot be deleted while artifacts use it.",
+            &["Artifacts still carry the tag"],
+            &["Remove the tag from those artifacts first"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1105TagUnknown => explained(
+            "An artifact uses a tag that is not in `[tags] allowed`.",
+            &["A typo, or a tag added by hand without registering it"],
+            &["Register it with `govctl tag new <tag>` or fix the artifact"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        // E12xx - Loop state
+        DiagnosticCode::E1201LoopStateInvalid => explained(
+            "A loop state or round record is invalid.",
+            &["A hand edit under `.govctl/loops`, or a record copied between loops"],
+            &["Restore the file, or start a new loop"],
+            &["RFC-0006:C-LOOP-STATE-STORAGE"],
+        ),
+        DiagnosticCode::E1202LoopStateNotFound => explained(
+            "The loop or one of its round records does not exist.",
+            &["A typo in the loop ID", "Local loop state was cleaned"],
+            &["List loops with `govctl loop list`"],
+            &["RFC-0006:C-LOOP-STATE-STORAGE"],
+        ),
+        DiagnosticCode::E1203LoopInvalidTransition => explained(
+            "The requested loop state change is not allowed.",
+            &["Acting on a loop that is already finished or not yet started"],
+            &["Check its state with `govctl loop show <LOOP>`"],
+            &["RFC-0006:C-LOOP-LIFECYCLE"],
+        ),
+        DiagnosticCode::E1204LoopInvalidId => explained(
+            "A loop ID is not in the canonical format, or no ID is left for the day.",
+            &["A typo in the loop ID"],
+            &["Use the ID printed by `govctl loop list`"],
+            &["RFC-0006:C-LOOP-DEFINITION"],
+        ),
+        DiagnosticCode::E1205LoopDependencyNotFound => explained(
+            "A work item in the loop, or one it depends on, does not exist.",
+            &["The work item was deleted while the loop was planned"],
+            &["Fix the dependency, then `govctl loop replan`"],
+            &["RFC-0006:C-DEPENDENCY-SEMANTICS"],
+        ),
+        DiagnosticCode::E1206LoopDependencyCycle => explained(
+            "The loop's work items depend on each other in a cycle.",
+            &["Work item `depends_on` links form a loop"],
+            &["Break one dependency in the reported cycle"],
+            &["RFC-0006:C-DEPENDENCY-SEMANTICS"],
+        ),
+        DiagnosticCode::E1208LoopResumeAmbiguous => explained(
+            "More than one unfinished loop matches, so it is unclear which to resume.",
+            &["Several loops were started for the same work"],
+            &["Pass the loop ID explicitly"],
+            &["RFC-0006:C-LOOP-RESUMPTION"],
+        ),
+        DiagnosticCode::E1209LoopWorkMismatch => explained(
+            "The requested work items differ from those of the existing loop.",
+            &["`loop start` with a different item list than the loop being resumed"],
+            &["Use `govctl loop replan` to change its scope"],
+            &["RFC-0006:C-LOOP-SCOPE-MUTATION"],
+        ),
+        DiagnosticCode::E1210LoopExecutionFailed => explained(
+            "A loop round could not run.",
+            &[
+                "The loop is finished",
+                "The previous round summary is incomplete",
+            ],
+            &["Complete the round record the message names, or start a new loop"],
+            &["RFC-0006:C-ROUND-EXECUTION"],
+        ),
+        // E13xx - Operation history
+        DiagnosticCode::E1301HistoryEmpty => explained(
+            "There is no recorded operation to undo.",
+            &["No undoable command has run, or the history was already undone"],
+            &["Nothing to do"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E1302HistoryConflict => explained(
+            "The files an operation wrote have changed since, so undoing it would lose edits.",
+            &["Later commands or hand edits touched the same files"],
+            &["Review the changes, then pass `--force` to overwrite them"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E1303HistoryInvalid => explained(
+            "An operation history record is unreadable.",
+            &["A hand edit or partial write under `.govctl/history`"],
+            &["Remove the damaged record"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E1304HistoryGitUnavailable => explained(
+            "`clause history` reads git history, which is unavailable.",
+            &["git is not installed, or the project is not a git repository"],
+            &["Install git, or run from inside the repository"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        // E14xx - Compliance mapping
+        DiagnosticCode::E1401MappingSchemaInvalid => explained(
+            "A compliance mapping file under `gov/mapping/` is not valid TOML or does not match the mapping schema.",
+            &[
+                "A hand edit with a typo or an unknown key",
+                "A control with no clauses, or a clause ref that is not `RFC-NNNN:C-NAME`",
+            ],
+            &["Fix the file against `gov/schema/mapping.schema.json`"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1402MappingClauseNotFound => explained(
+            "A mapped control names a clause that does not exist.",
+            &[
+                "The clause was renamed or deleted",
+                "A typo in the clause ref",
+            ],
+            &[
+                "Point the control at the current clause with `govctl mapping add`, or drop the link with `govctl mapping remove`",
+            ],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1403MappingClauseInactive => explained(
+            "A mapped control names a clause that is deprecated or superseded, so it no longer satisfies the control.",
+            &["The clause was deprecated or superseded after the mapping was written"],
+            &["Map the control to the replacement clause and remove the old link"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1404MappingDuplicate => explained(
+            "A standard or control is declared more than once.",
+            &[
+                "Two files under `gov/mapping/` use the same standard `id`",
+                "One mapping file lists the same control twice",
+            ],
+            &["Merge the duplicate entries into one"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E1405MappingNotFound => explained(
+            "The standard, control, or clause link is not in any mapping.",
+            &[
+                "A typo in the standard or control ID",
+                "The link was already removed",
+            ],
+            &["Run `govctl mapping list` to see what is mapped"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        // E15xx - Audit log
+        DiagnosticCode::E1501AuditLogInvalid => explained(
+            "A line of `gov/audit.log` is not a valid audit entry.",
+            &[
+                "A hand edit of the log",
+                "A merge that interleaved or cut lines",
+            ],
+            &["Restore the log from version control"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E1502AuditEntryNotFound => explained(
+            "No audit entry has that number.",
+            &[
+                "A typo in the entry number",
+                "The entry is older than `[audit] retention_days` and was dropped",
+            ],
+            &["Run `govctl audit list` to see the recorded entries"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        // E16xx - Secrets
+        DiagnosticCode::E1601SecretKeyMissing => explained(
+            "A sensitive ADR field must be encrypted or decrypted, but no usable key is configured.",
+            &[
+                "Neither `GOVCTL_SECRET_KEY` nor `[secrets] key_file` is set",
+                "The key is not 32 bytes of base64",
+            ],
+            &["Set `GOVCTL_SECRET_KEY` or point `[secrets] key_file` at the project key"],
+            &["RFC-0000:C-ADR-DEF"],
+        ),
+        DiagnosticCode::E1602SecretDecryptFailed => explained(
+            "A sensitive ADR field does not decrypt with the configured key.",
+            &[
+                "A different project's key, or a rotated key",
+                "A hand edit of the encrypted value",
+            ],
+            &["Use the key the field was encrypted with, or restore the file from version control"],
+            &["RFC-0000:C-ADR-DEF"],
+        ),
+        DiagnosticCode::E1603SensitiveFieldPlaintext => explained(
+            "A field the ADR marks sensitive is stored as plaintext.",
+            &["The field was edited by hand instead of with `govctl adr set`"],
+            &["Set the field again with `govctl adr set` so it is encrypted"],
+            &["RFC-0000:C-ADR-DEF"],
+        ),
+        // E17xx - Attachments
+        DiagnosticCode::E1701AttachmentMissing => explained(
+            "An attachment an artifact lists does not exist under `gov/assets/`.",
+            &[
+                "The file was deleted or renamed by hand",
+                "The path was added with `add <ID> attachments` before the file was copied",
+            ],
+            &[
+                "Restore the file, or attach it again with `govctl attach add`",
+                "Drop the entry with `govctl attach remove`",
+            ],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E1702AttachmentPathInvalid => explained(
+            "An attachment path is not a relative path inside `gov/assets/`.",
+            &["An absolute path, or a path containing `..`"],
+            &["Use `govctl attach add`, which copies the file under `gov/assets/<ID>/`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        // E08xx - CLI/Command
+        DiagnosticCode::E0801MissingRequiredArg => explained(
+            "A required argument or field was not given.",
+            &["An empty title, or a prompt answered with nothing"],
+            &["Pass the value named in the message; see `--help`"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0802ConflictingArgs => explained(
+            "Two arguments that cannot be combined were given together.",
+            &["Several edit actions or mutually exclusive flags at once"],
+            &["Run the actions as separate commands"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0803UnknownField => explained(
+            "The artifact has no field with this name.",
+            &["A typo, or a field of another artifact type"],
+            &["List fields with `govctl describe` or the artifact's `--help`"],
+            &["RFC-0002:C-RESOURCE-MODEL"],
+        ),
+        DiagnosticCode::E0804FieldNotEditable => explained(
+            "The field exists but cannot be changed this way.",
+            &[
+                "Setting a list field that only supports add and remove",
+                "Changing an artifact ID or a lifecycle-managed field",
+            ],
+            &["Use the verb the message suggests, such as `add`, `remove`, or a lifecycle verb"],
+            &["RFC-0002:C-CRUD-VERBS", "RFC-0002:C-LIFECYCLE-VERBS"],
+        ),
+        DiagnosticCode::E0805EmptyValue => explained(
+            "The value is empty where text is required.",
+            &["An empty string, or a changelog prefix with no message after it"],
+            &["Provide non-empty text"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0806InvalidPattern => explained(
+            "A match pattern, regex, or search query is invalid.",
+            &["Unbalanced regex syntax or an invalid search expression"],
+            &["Fix the pattern, or use `--exact` for a literal match"],
+            &["RFC-0002:C-CRUD-VERBS", "RFC-0002:C-SEARCH-COMMAND"],
+        ),
+        DiagnosticCode::E0807AmbiguousMatch => explained(
+            "The pattern matches more than one list item.",
+            &["A short pattern shared by several items"],
+            &["Use a longer pattern, `--at <index>`, or `--all`"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0808InvalidPrefix => explained(
+            "The changelog prefix is not one of the known categories.",
+            &["A typo such as `feat:` instead of `add:`"],
+            &["Use one of the prefixes the message lists"],
+            &["RFC-0000:C-WORK-DEF"],
+        ),
+        DiagnosticCode::E0809ChoreNotAllowed => explained(
+            "The `chore:` category is for work items only, not RFC changelogs.",
+            &["An RFC bump change tagged `chore:`"],
+            &["Pick another category for RFC changes"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::E0810CannotAddToField => explained(
+            "`add` only works on list fields.",
+            &["`add` on a scalar field"],
+            &["Use `set` for scalar fields"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0811CannotRemoveFromField => explained(
+            "`remove` only works on list fields.",
+            &["`remove` on a scalar field"],
+            &["Use `set` to change a scalar field"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0812FieldEmpty => explained(
+            "The field has no value to act on.",
+            &["Removing from an empty list, or a required link that is not set"],
+            &["Set the field first, as the message suggests"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0813SupersedeNotSupported => explained(
+            "This artifact type does not support the requested operation.",
+            &["Superseding a work item or guard, or ticking a non-work artifact"],
+            &["Supersession covers RFCs, clauses, and ADRs; use the type's own lifecycle verbs"],
+            &["RFC-0002:C-LIFECYCLE-VERBS"],
+        ),
+        DiagnosticCode::E0814InvalidPath => explained(
+            "A field path is empty or malformed.",
+            &["Unbalanced brackets or a trailing dot, e.g. `alt[0`"],
+            &["Write paths like `alternatives[0].pros`"],
+            &["RFC-0002:C-RESOURCE-MODEL"],
+        ),
+        DiagnosticCode::E0815PathFieldNotFound => explained(
+            "A segment of the field path does not exist.",
+            &["A typo in a nested field name"],
+            &["Check the artifact's fields with `govctl <type> show <ID> -o json`"],
+            &["RFC-0002:C-RESOURCE-MODEL"],
+        ),
+        DiagnosticCode::E0816PathIndexOutOfBounds => explained(
+            "A field path index is past the end of the list.",
+            &[
+                "Indexes start at 0",
+                "The list changed since the index was read",
+            ],
+            &["Use an index below the list length"],
+            &["RFC-0002:C-RESOURCE-MODEL"],
+        ),
+        DiagnosticCode::E0817PathTypeMismatch => explained(
+            "The field path points at a value of the wrong kind for this verb.",
+            &["`add` on an indexed element, or a plain string where a structured item is needed"],
+            &["Use `set` or `remove` for a specific element, or provide the structured value"],
+            &["RFC-0002:C-RESOURCE-MODEL"],
+        ),
+        DiagnosticCode::E0818PathIndexConflict => explained(
+            "An indexed path cannot be combined with match flags.",
+            &["`alt[0]` together with `--at`, `--exact`, `--regex`, `--all`, or a pattern"],
+            &["Use either the index or the match flags"],
+            &["RFC-0002:C-RESOURCE-MODEL"],
+        ),
+        DiagnosticCode::E0819UnknownArtifactType => explained(
+            "The ID does not match any artifact type's prefix.",
+            &["A typo, or a prefix changed in `[prefixes]`"],
+            &["Use an RFC, clause, ADR, work item, or guard ID"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E0820InvalidFieldValue => explained(
+            "The value is not valid for this field.",
+            &["A malformed date, link, issue key, or enum value"],
+            &["Use the format the message shows"],
+            &["RFC-0002:C-RESOURCE-MODEL"],
+        ),
+        DiagnosticCode::E0821InvalidCommandScope => explained(
+            "The command was routed to the wrong artifact scope.",
+            &["A verb used without the artifact it needs"],
+            &["Name the artifact, e.g. `govctl work get <WI> <field>`"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0822UnsupportedOperation => explained(
+            "The operation is not supported for this artifact, view, or format.",
+            &[
+                "Deleting an artifact type that cannot be deleted",
+                "Rendering something other than RFCs to PDF",
+            ],
+            &["Use a supported alternative, as the message suggests"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0823EditorFailed => explained(
+            "The external editor could not be launched or exited with an error.",
+            &["`$VISUAL`/`$EDITOR` names a missing program, or the editor was aborted"],
+            &["Set `$EDITOR` to an installed editor; nothing was written"],
+            &["RFC-0002:C-CRUD-VERBS"],
+        ),
+        DiagnosticCode::E0824InteractiveUnavailable => explained(
+            "The command needs a terminal to prompt, and there is none.",
+            &["Running in CI, a pipe, or an agent without a TTY"],
+            &[
+                "Pass the values as arguments",
+                "Answer confirmations with `--yes` or `GOVCTL_ASSUME_YES=1`",
+            ],
+            &["RFC-0002:C-OUTPUT-FORMAT"],
+        ),
+        DiagnosticCode::E0825ApplyScriptInvalid => explained(
+            "An apply script could not be parsed or names unknown operations.",
+            &["Invalid syntax, or a line that is not a govctl command"],
+            &["Fix the reported line, then rerun `govctl apply`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0826AgentAssetsOutOfDate => explained(
+            "Installed agent skills or agents differ from those bundled with this govctl.",
+            &["govctl was upgraded, or the assets were edited locally"],
+            &[
+                "Review the changes with `govctl init-skills --check --diff`",
+                "Reinstall with `govctl init-skills --force`",
+            ],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0827ArtifactsNotFormatted => explained(
+            "Artifact files are not in the canonical form `govctl fmt` writes.",
+            &["Hand edits changed key order, quoting, or wrapping"],
+            &["Run `govctl fmt` and commit the result"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0828UnknownDiagnostic => explained(
+            "`govctl explain` was given a code that govctl does not define.",
+            &["A typo, or a code from another govctl version"],
+            &["Copy the code from the `error[...]` or `warning[...]` prefix of a diagnostic"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0829AmbiguousArtifactMatch => explained(
+            "A partial ID or title fragment matches more than one artifact.",
+            &["The fragment is shared by several titles or ID endings"],
+            &["Use one of the full IDs listed in the message, or a longer fragment"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E0830UnknownAlias => explained(
+            "An `@name` argument names no alias.",
+            &[
+                "The name is misspelled or missing from `[aliases]` in gov/config.toml",
+                "`@last` was used before anything was created in this checkout",
+            ],
+            &["Declare the alias under `[aliases]`, or use the artifact ID"],
+            &["RFC-0002:C-RESOURCES"],
+        ),
+        DiagnosticCode::E0831InvalidActor => explained(
+            "The actor a command runs as is not `human:<name>`, `agent:<name>`, or a bare name.",
+            &[
+                "A kind other than `human` or `agent`, such as `bot:ci`",
+                "An empty name after the kind",
+            ],
+            &[
+                "Pass `--as agent:<name>` or `--as human:<name>`, or fix `GOVCTL_ACTOR` / `[identity] actor`",
+            ],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0832UnknownRenderProfile => explained(
+            "`--profile` names a render profile the project does not define.",
+            &["The name is misspelled or missing from `[render.profiles]` in gov/config.toml"],
+            &["Declare the profile as `[render.profiles.<name>]`, or drop `--profile`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::E0833RenderedDocsOutOfDate => explained(
+            "Rendered files differ from what `govctl render` would write now.",
+            &[
+                "An artifact changed and its docs were not re-rendered",
+                "A rendered file was edited by hand, or is missing",
+            ],
+            &["Run `govctl render` for the same target and commit the result"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        // E09xx - General
+        DiagnosticCode::E0901IoError => explained(
+            "A file could not be read or written.",
+            &["Missing permissions, a full disk, or a path that does not exist"],
+            &["Check the path and permissions named in the message"],
+            &["RFC-0004:C-FAILURE-BEHAVIOUR"],
+        ),
+        DiagnosticCode::E0902JsonParseError => explained(
+            "JSON input or a bundled JSON schema could not be parsed.",
+            &["Malformed JSON passed to a command"],
+            &["Validate the JSON and retry"],
+            &["RFC-0002:C-OUTPUT-FORMAT"],
+        ),
+        DiagnosticCode::E0903UnexpectedError => explained(
+            "govctl hit an internal error it did not expect.",
+            &["A bug, or project state govctl cannot interpret"],
+            &["Rerun with `-v` and report the message with the command that triggered it"],
+            &["RFC-0004:C-FAILURE-BEHAVIOUR"],
+        ),
+        // W01xx - Warnings
+        DiagnosticCode::W0101RfcNoChangelog => explained(
+            "The RFC has no changelog. Current govctl reports E0111 instead.",
+            &["An RFC written by an older govctl"],
+            &["Bump the RFC with `govctl rfc bump`"],
+            &["RFC-0000:C-RFC-DEF"],
+        ),
+        DiagnosticCode::W0102ClauseNoSince => explained(
+            "The clause has no `since` version.",
+            &["A clause added to a draft RFC that has not been bumped or finalized"],
+            &["Nothing to do; `govctl rfc bump` or `rfc finalize` sets it"],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::W0103AdrNoRefs => explained(
+            "The ADR does not reference any RFC or clause.",
+            &["The decision was recorded without linking what it affects"],
+            &["Add refs with `govctl adr add <ADR> refs <RFC>`"],
+            &["RFC-0000:C-ADR-DEF"],
+        ),
+        DiagnosticCode::W0106RenderedReadError => explained(
+            "Rendered markdown could not be read, so its signature was not checked.",
+            &["The RFC was never rendered, or the file is unreadable"],
+            &["Run `govctl rfc render`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0107SourceRefOutdated => explained(
+            "A reference points at a deprecated or superseded artifact or anchor.",
+            &["The artifact was replaced and the reference was not updated"],
+            &["Update the reference to the replacement the message names"],
+            &["RFC-0001:C-CLAUSE-STATUS", "RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::W0108WorkPlaceholderDescription => explained(
+            "The work item still has its generated placeholder description.",
+            &["The item was created without a description"],
+            &["Set one with `govctl work set <WI> description \"...\"`"],
+            &["RFC-0000:C-WORK-DEF"],
+        ),
+        DiagnosticCode::W0109WorkNoActive => explained(
+            "No work item is active.",
+            &["All items are queued, done, or cancelled"],
+            &["Start one with `govctl work move <WI> active`"],
+            &["RFC-0001:C-WORK-STATUS"],
+        ),
+        DiagnosticCode::W0110SchemaOutdated => explained(
+            "The project's schema version is older than this govctl writes.",
+            &["govctl was upgraded"],
+            &["Run `govctl migrate`"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0111ProjectSupportOutdated => explained(
+            "Project support files, such as .gitignore entries, are missing or stale.",
+            &["govctl was upgraded, or the entries were removed"],
+            &["Run `govctl migrate` to refresh them"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0112BareArtifactReference => explained(
+            "Governed prose mentions a known artifact ID without `[[...]]` syntax.",
+            &["A plain `RFC-0001` in clause text, ADR prose, or a description"],
+            &["Wrap it as `[[RFC-0001]]` so it is validated and linked"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::W0113AdrPlaceholderContext => explained(
+            "The ADR still has its generated placeholder context.",
+            &["The ADR was created without a context"],
+            &["Set one with `govctl adr set <ADR> context \"...\"`"],
+            &["RFC-0000:C-ADR-DEF"],
+        ),
+        DiagnosticCode::W0114ConfigUnknownKey => explained(
+            "config.toml contains a key govctl does not recognize; it is ignored.",
+            &["A typo, or a key from another govctl version"],
+            &["Fix or remove the key; `govctl config list` shows valid keys"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0115ConfigDeprecatedKey => explained(
+            "config.toml uses a deprecated key that no longer has any effect.",
+            &["The key was renamed or removed in a newer govctl"],
+            &["Use the replacement the message names"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0116ConfigIneffectiveSetting => explained(
+            "A config setting has no effect because of another setting.",
+            &["E.g. source scanning enabled with an empty `include` list"],
+            &["Adjust either setting so the combination does something"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0117RemoteUnavailable => explained(
+            "A remote repository could not be read, so references to it were not verified.",
+            &["The remote path is missing or the network is unavailable"],
+            &["Check `[remotes.<name>]` in config.toml, then rerun `govctl check`"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::W0118RfcGateOverridden => explained(
+            "`rfc advance --force` advanced past unmet `[gates]` items.",
+            &["The override was deliberate"],
+            &["Satisfy the listed gates after the fact"],
+            &["RFC-0001:C-GATE-CONDITIONS"],
+        ),
+        DiagnosticCode::W0119ArtifactStale => explained(
+            "A draft RFC or proposed ADR is older than its `[staleness]` limit.",
+            &["The artifact has not been updated or decided in a long time"],
+            &["Finalize, accept, or reject it, or raise the limit"],
+            &["RFC-0001:C-RFC-STATUS", "RFC-0001:C-ADR-STATUS"],
+        ),
+        DiagnosticCode::W0120SunsetPassed => explained(
+            "An artifact passed its sunset date and is still referenced.",
+            &["References were not moved to the replacement before the sunset"],
+            &["Point the listed references at the replacement"],
+            &["RFC-0001:C-CLAUSE-STATUS", "RFC-0001:C-RFC-STATUS"],
+        ),
+        DiagnosticCode::W0121SupersessionHeadInactive => explained(
+            "The newest artifact in a supersession chain is not in force.",
+            &["The replacement is a draft, deprecated, proposed, or rejected"],
+            &["Bring the replacement into force, or supersede it with one that is"],
+            &[
+                "RFC-0001:C-RFC-STATUS",
+                "RFC-0001:C-ADR-STATUS",
+                "RFC-0001:C-CLAUSE-STATUS",
+            ],
+        ),
+        DiagnosticCode::W0122ClauseUncovered => explained(
+            "A normative clause has no `Implements` annotation in scanned source.",
+            &["The clause is not implemented yet, or the annotation is outside `[source_scan]`"],
+            &["Annotate the implementing code, or widen the scan include globs"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0123RefInactive => explained(
+            "A live artifact lists a deprecated or superseded artifact in `refs`.",
+            &["The referenced artifact was retired after the ref was added"],
+            &["Point the ref at the replacement or remove it"],
+            &["RFC-0000:C-REFERENCE-HIERARCHY"],
+        ),
+        DiagnosticCode::W0124JiraStatusDiverged => explained(
+            "A work item's status disagrees with its linked Jira issue.",
+            &["One side was updated without the other"],
+            &["Run `govctl sync jira` or update the item by hand"],
+            &["RFC-0001:C-WORK-STATUS"],
+        ),
+        DiagnosticCode::W0125WebhookFailed => explained(
+            "A lifecycle webhook could not be delivered; the command itself succeeded.",
+            &["The endpoint is down, unreachable, or rejected the request"],
+            &["Check the `[webhooks]` URL and the receiving service"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0126HookFailed => explained(
+            "A `[hooks]` command with `on_failure = \"warn\"` failed; the command carried on.",
+            &["The hook exited non-zero or could not be run"],
+            &["Fix the hook; run with `-v` to see its output"],
+            &["RFC-0002:C-GLOBAL-COMMANDS"],
+        ),
+        DiagnosticCode::W0127DiagramInvalid => explained(
+            "A fenced `mermaid` or `plantuml` block in clause or ADR text does not parse.",
+            &[
+                "A misspelled diagram type, such as `grpah TD`",
+                "A `subgraph`, `loop`, `if`, or `note` block that is never closed",
+                "An unclosed bracket, or a missing `@enduml`",
+            ],
+            &[
+                "Fix the diagram at the line the message names; preview it in a Mermaid or PlantUML editor",
+            ],
+            &["RFC-0000:C-CLAUSE-DEF"],
+        ),
+        DiagnosticCode::W0128GuardFailureAllowed => explained(
+            "A verification guard with `allow_failure = true` failed; it did not block the work item.",
+            &["The guard's check is advisory, such as a benchmark or a flaky integration suite"],
+            &[
+                "Review the failure in the work item's recorded guard results",
+                "Remove `allow_failure` once the check should block again",
+            ],
+            &["RFC-0000:C-GUARD-DEF"],
+        ),
+        // I04xx - Work Item info
+        DiagnosticCode::I0401WorkLegacyInlineHistory => explained(
+            "The work item has execution history inline, which now belongs in loop state.",
+            &["The item was written by an older govctl"],
+            &["Move durable takeaways to notes; keep new execution trace in loops"],
+            &["RFC-0006:C-EXECUTION-STATE-SEPARATION"],
+        ),
+    }
+}
@@ -1,4 +1,199 @@
-use super::{DiagnosticCode, DiagnosticLevel};
+use super::{DiagnosticCode, DiagnosticLevel, FailureClass};
+
+/// Every code, in catalog order.
+pub(super) const ALL: &[DiagnosticCode] = &[
+    DiagnosticCode::E0101RfcSchemaInvalid,
+    DiagnosticCode::E0102RfcNotFound,
+    DiagnosticCode::E0103RfcIdMismatch,
+    DiagnosticCode::E0104RfcInvalidTransition,
+    DiagnosticCode::E0105RfcRefNotFound,
+    DiagnosticCode::E0106RfcSupersedesNotFound,
+    DiagnosticCode::E0107SourceRefUnknown,
+    DiagnosticCode::E0108RfcBumpRequiresSummary,
+    DiagnosticCode::E0109RfcAlreadyExists,
+    DiagnosticCode::E0110RfcInvalidId,
+    DiagnosticCode::E0111RfcNoChangelog,
+    DiagnosticCode::E0112RfcReferenceHierarchy,
+    DiagnosticCode::E0113RfcBumpNoAmendment,
+    DiagnosticCode::E0114RfcPendingAmendment,
+    DiagnosticCode::E0115RfcCurrentChangelogInvalid,
+    DiagnosticCode::E0116RfcSectionNotFound,
+    DiagnosticCode::E0117RfcSectionExists,
+    DiagnosticCode::E0118RfcSectionNotEmpty,
+    DiagnosticCode::E0119RfcLastSection,
+    DiagnosticCode::E0120RfcOwnerUnknown,
+    DiagnosticCode::E0121RfcApprovalMissing,
+    DiagnosticCode::E0122RfcFrozen,
+    DiagnosticCode::E0123RfcGateUnmet,
+    DiagnosticCode::E0124RfcSnapshotNotFound,
+    DiagnosticCode::E0125RfcSupersessionCycle,
+    DiagnosticCode::E0126RfcSupersessionFork,
+    DiagnosticCode::E0201ClauseSchemaInvalid,
+    DiagnosticCode::E0202ClauseNotFound,
+    DiagnosticCode::E0203ClauseIdMismatch,
+    DiagnosticCode::E0204ClausePathInvalid,
+    DiagnosticCode::E0206ClauseSupersededByUnknown,
+    DiagnosticCode::E0207ClauseSupersededByNotActive,
+    DiagnosticCode::E0208ClauseAlreadyDeprecated,
+    DiagnosticCode::E0209ClauseAlreadySuperseded,
+    DiagnosticCode::E0210ClauseInvalidIdFormat,
+    DiagnosticCode::E0211ClauseStillReferenced,
+    DiagnosticCode::E0212ClauseSupersessionCycle,
+    DiagnosticCode::E0213ClauseSupersededByMissing,
+    DiagnosticCode::E0214ClauseAnchorInvalid,
+    DiagnosticCode::E0215ClauseAnchorDuplicate,
+    DiagnosticCode::E0216FragmentNotFound,
+    DiagnosticCode::E0217FragmentParameterMissing,
+    DiagnosticCode::E0218ClauseNotRelocatable,
+    DiagnosticCode::E0301AdrSchemaInvalid,
+    DiagnosticCode::E0302AdrNotFound,
+    DiagnosticCode::E0303AdrInvalidTransition,
+    DiagnosticCode::E0304AdrRefNotFound,
+    DiagnosticCode::E0305AdrCannotDeprecate,
+    DiagnosticCode::E0306AdrReferenceHierarchy,
+    DiagnosticCode::E0307AdrProjectionConflict,
+    DiagnosticCode::E0308AdrApprovalMissing,
+    DiagnosticCode::E0309AdrSupersessionCycle,
+    DiagnosticCode::E0401WorkSchemaInvalid,
+    DiagnosticCode::E0402WorkNotFound,
+    DiagnosticCode::E0403WorkInvalidTransition,
+    DiagnosticCode::E0404WorkRefNotFound,
+    DiagnosticCode::E0405WorkDirNotFound,
+    DiagnosticCode::E0406WorkAmbiguousMatch,
+    DiagnosticCode::E0407WorkMissingCriteria,
+    DiagnosticCode::E0408WorkCriteriaMissingCategory,
+    DiagnosticCode::E0409WorkDependencyInvalid,
+    DiagnosticCode::E0410WorkDependencyNotFound,
+    DiagnosticCode::E0411WorkDependencyCycle,
+    DiagnosticCode::E0412WorkDoneRequirementUnmet,
+    DiagnosticCode::E0413WorkNotQueued,
+    DiagnosticCode::E0501ConfigInvalid,
+    DiagnosticCode::E0502PathNotFound,
+    DiagnosticCode::E0503LockTimeout,
+    DiagnosticCode::E0504PathConflict,
+    DiagnosticCode::E0505MigrationRequired,
+    DiagnosticCode::E0506TemplateInvalid,
+    DiagnosticCode::E0507ConfigSchemaInvalid,
+    DiagnosticCode::E0508ConfigKeyUnknown,
+    DiagnosticCode::E0509WorkspaceInvalid,
+    DiagnosticCode::E0510WorkspaceMemberNotFound,
+    DiagnosticCode::E0511WorkspaceRefNotFound,
+    DiagnosticCode::E0512RemoteNotConfigured,
+    DiagnosticCode::E0513PublishNotConfigured,
+    DiagnosticCode::E0514VariableUndefined,
+    DiagnosticCode::E0601SignatureMismatch,
+    DiagnosticCode::E0602SignatureMissing,
+    DiagnosticCode::E0701ReleaseInvalidSemver,
+    DiagnosticCode::E0702ReleaseDuplicate,
+    DiagnosticCode::E0703ReleaseNoUnreleasedItems,
+    DiagnosticCode::E0704ReleaseSchemaInvalid,
+    DiagnosticCode::E0705ReleaseRefNotFound,
+    DiagnosticCode::E0706ReleaseWorkNotDone,
+    DiagnosticCode::E0707ReleaseWorkDuplicate,
+    DiagnosticCode::E0708ReleaseHistoryEmpty,
+    DiagnosticCode::E0709ReleaseLatestMismatch,
+    DiagnosticCode::E1001GuardSchemaInvalid,
+    DiagnosticCode::E1002GuardNotFound,
+    DiagnosticCode::E1003GuardDuplicate,
+    DiagnosticCode::E1004GuardCheckFailed,
+    DiagnosticCode::E1005GuardTimeout,
+    DiagnosticCode::E1006GuardInvalidTitle,
+    DiagnosticCode::E1007GuardStillReferenced,
+    DiagnosticCode::E1008HookFailed,
+    DiagnosticCode::E1101TagInvalidFormat,
+    DiagnosticCode::E1102TagAlreadyExists,
+    DiagnosticCode::E1103TagNotFound,
+    DiagnosticCode::E1104TagStillReferenced,
+    DiagnosticCode::E1105TagUnknown,
+    DiagnosticCode::E1201LoopStateInvalid,
+    DiagnosticCode::E1202LoopStateNotFound,
+    DiagnosticCode::E1203LoopInvalidTransition,
+    DiagnosticCode::E1204LoopInvalidId,
+    DiagnosticCode::E1205LoopDependencyNotFound,
+    DiagnosticCode::E1206LoopDependencyCycle,
+    DiagnosticCode::E1208LoopResumeAmbiguous,
+    DiagnosticCode::E1209LoopWorkMismatch,
+    DiagnosticCode::E1210LoopExecutionFailed,
+    DiagnosticCode::E1301HistoryEmpty,
+    DiagnosticCode::E1302HistoryConflict,
+    DiagnosticCode::E1303HistoryInvalid,
+    DiagnosticCode::E1304HistoryGitUnavailable,
+    DiagnosticCode::E1401MappingSchemaInvalid,
+    DiagnosticCode::E1402MappingClauseNotFound,
+    DiagnosticCode::E1403MappingClauseInactive,
+    DiagnosticCode::E1404MappingDuplicate,
+    DiagnosticCode::E1405MappingNotFound,
+    DiagnosticCode::E1501AuditLogInvalid,
+    DiagnosticCode::E1502AuditEntryNotFound,
+    DiagnosticCode::E1601SecretKeyMissing,
+    DiagnosticCode::E1602SecretDecryptFailed,
+    DiagnosticCode::E1603SensitiveFieldPlaintext,
+    DiagnosticCode::E1701AttachmentMissing,
+    DiagnosticCode::E1702AttachmentPathInvalid,
+    DiagnosticCode::E0801MissingRequiredArg,
+    DiagnosticCode::E0802ConflictingArgs,
+    DiagnosticCode::E0803UnknownField,
+    DiagnosticCode::E0804FieldNotEditable,
+    DiagnosticCode::E0805EmptyValue,
+    DiagnosticCode::E0806InvalidPattern,
+    DiagnosticCode::E0807AmbiguousMatch,
+    DiagnosticCode::E0808InvalidPrefix,
+    DiagnosticCode::E0809ChoreNotAllowed,
+    DiagnosticCode::E0810CannotAddToField,
+    DiagnosticCode::E0811CannotRemoveFromField,
+    DiagnosticCode::E0812FieldEmpty,
+    DiagnosticCode::E0813SupersedeNotSupported,
+    DiagnosticCode::E0814InvalidPath,
+    DiagnosticCode::E0815PathFieldNotFound,
+    DiagnosticCode::E0816PathIndexOutOfBounds,
+    DiagnosticCode::E0817PathTypeMismatch,
+    DiagnosticCode::E0818PathIndexConflict,
+    DiagnosticCode::E0819UnknownArtifactType,
+    DiagnosticCode::E0820InvalidFieldValue,
+    DiagnosticCode::E0821InvalidCommandScope,
+    DiagnosticCode::E0822UnsupportedOperation,
+    DiagnosticCode::E0823EditorFailed,
+    DiagnosticCode::E0824InteractiveUnavailable,
+    DiagnosticCode::E0825ApplyScriptInvalid,
+    DiagnosticCode::E0826AgentAssetsOutOfDate,
+    DiagnosticCode::E0827ArtifactsNotFormatted,
+    DiagnosticCode::E0828UnknownDiagnostic,
+    DiagnosticCode::E0829AmbiguousArtifactMatch,
+    DiagnosticCode::E0830UnknownAlias,
+    DiagnosticCode::E0831InvalidActor,
+    DiagnosticCode::E0832UnknownRenderProfile,
+    DiagnosticCode::E0833RenderedDocsOutOfDate,
+    DiagnosticCode::E0901IoError,
+    DiagnosticCode::E0902JsonParseError,
+    DiagnosticCode::E0903UnexpectedError,
+    DiagnosticCode::W0101RfcNoChangelog,
+    DiagnosticCode::W0102ClauseNoSince,
+    DiagnosticCode::W0103AdrNoRefs,
+    DiagnosticCode::W0106RenderedReadError,
+    DiagnosticCode::W0107SourceRefOutdated,
+    DiagnosticCode::W0108WorkPlaceholderDescription,
+    DiagnosticCode::W0109WorkNoActive,
+    DiagnosticCode::W0110SchemaOutdated,
+    DiagnosticCode::W0111ProjectSupportOutdated,
+    DiagnosticCode::W0112BareArtifactReference,
+    DiagnosticCode::W0113AdrPlaceholderContext,
+    DiagnosticCode::W0114ConfigUnknownKey,
+    DiagnosticCode::W0115ConfigDeprecatedKey,
+    DiagnosticCode::W0116ConfigIneffectiveSetting,
+    DiagnosticCode::W0117RemoteUnavailable,
+    DiagnosticCode::W0118RfcGateOverridden,
+    DiagnosticCode::W0119ArtifactStale,
+    DiagnosticCode::W0120SunsetPassed,
+    DiagnosticCode::W0121SupersessionHeadInactive,
+    DiagnosticCode::W0122ClauseUncovered,
+    DiagnosticCode::W0123RefInactive,
+    DiagnosticCode::W0124JiraStatusDiverged,
+    DiagnosticCode::W0125WebhookFailed,
+    DiagnosticCode::W0126HookFailed,
+    DiagnosticCode::W0127DiagramInvalid,
+    DiagnosticCode::W0128GuardFailureAllowed,
+    DiagnosticCode::I0401WorkLegacyInlineHistory,
+];
 
 pub(super) fn level(code: &DiagnosticCode) -> DiagnosticLevel {
     match code {
@@ -12,12 +207,124 @@ pub(super) fn level(code: &DiagnosticCode) -> DiagnosticLevel {
         | DiagnosticCode::W0110SchemaOutdated
         | DiagnosticCode::W0111ProjectSupportOutdated
         | DiagnosticCode::W0112BareArtifactReference
-        | DiagnosticCode::W0113AdrPlaceholderContext => DiagnosticLevel::Warning,
+        | DiagnosticCode::W0113AdrPlaceholderContext
+        | DiagnosticCode::W0114ConfigUnknownKey
+        | DiagnosticCode::W0115ConfigDeprecatedKey
+        | DiagnosticCode::W0116ConfigIneffectiveSetting
+        | DiagnosticCode::W0117RemoteUnavailable
+        | DiagnosticCode::W0118RfcGateOverridden
+        | DiagnosticCode::W0119ArtifactStale
+        | DiagnosticCode::W0120SunsetPassed
+        | DiagnosticCode::W0121SupersessionHeadInactive
+        | DiagnosticCode::W0122ClauseUncovered
+        | DiagnosticCode::W0123RefInactive
+        | DiagnosticCode::W0124JiraStatusDiverged
+        | DiagnosticCode::W0125WebhookFailed
+        | DiagnosticCode::W0126HookFailed
+        | DiagnosticCode::W0127DiagramInvalid
+        | DiagnosticCode::W0128GuardFailureAllowed => DiagnosticLevel::Warning,
         DiagnosticCode::I0401WorkLegacyInlineHistory => DiagnosticLevel::Info,
         _ => DiagnosticLevel::Error,
     }
 }
 
+pub(super) fn failure_class(code: &DiagnosticCode) -> FailureClass {
+    match code {
+        DiagnosticCode::E0102RfcNotFound
+        | DiagnosticCode::E0116RfcSectionNotFound
+        | DiagnosticCode::E0124RfcSnapshotNotFound
+        | DiagnosticCode::E0202ClauseNotFound
+        | DiagnosticCode::E0302AdrNotFound
+        | DiagnosticCode::E0402WorkNotFound
+        | DiagnosticCode::E0502PathNotFound
+        | DiagnosticCode::E0508ConfigKeyUnknown
+        | DiagnosticCode::E0510WorkspaceMemberNotFound
+        | DiagnosticCode::E1002GuardNotFound
+        | DiagnosticCode::E1103TagNotFound
+        | DiagnosticCode::E1405MappingNotFound
+        | DiagnosticCode::E1502AuditEntryNotFound
+        | DiagnosticCode::E1202LoopStateNotFound
+        | DiagnosticCode::E0828UnknownDiagnostic
+        | DiagnosticCode::E0830UnknownAlias
+        | DiagnosticCode::E0832UnknownRenderProfile => FailureClass::NotFound,
+        DiagnosticCode::E0104RfcInvalidTransition
+        | DiagnosticCode::E0207ClauseSupersededByNotActive
+        | DiagnosticCode::E0208ClauseAlreadyDeprecated
+        | DiagnosticCode::E0209ClauseAlreadySuperseded
+        | DiagnosticCode::E0218ClauseNotRelocatable
+        | DiagnosticCode::E0122RfcFrozen
+        | DiagnosticCode::E0123RfcGateUnmet
+        | DiagnosticCode::E0303AdrInvalidTransition
+        | DiagnosticCode::E0305AdrCannotDeprecate
+        | DiagnosticCode::E0403WorkInvalidTransition
+        | DiagnosticCode::E0413WorkNotQueued
+        | DiagnosticCode::E1203LoopInvalidTransition => FailureClass::InvalidTransition,
+        DiagnosticCode::E0503LockTimeout => FailureClass::LockContention,
+        DiagnosticCode::E0101RfcSchemaInvalid
+        | DiagnosticCode::E0103RfcIdMismatch
+        | DiagnosticCode::E0105RfcRefNotFound
+        | DiagnosticCode::E0106RfcSupersedesNotFound
+        | DiagnosticCode::E0107SourceRefUnknown
+        | DiagnosticCode::E0111RfcNoChangelog
+        | DiagnosticCode::E0112RfcReferenceHierarchy
+        | DiagnosticCode::E0115RfcCurrentChangelogInvalid
+        | DiagnosticCode::E0120RfcOwnerUnknown
+        | DiagnosticCode::E0121RfcApprovalMissing
+        | DiagnosticCode::E0125RfcSupersessionCycle
+        | DiagnosticCode::E0126RfcSupersessionFork
+        | DiagnosticCode::E0201ClauseSchemaInvalid
+        | DiagnosticCode::E0203ClauseIdMismatch
+        | DiagnosticCode::E0204ClausePathInvalid
+        | DiagnosticCode::E0206ClauseSupersededByUnknown
+        | DiagnosticCode::E0212ClauseSupersessionCycle
+        | DiagnosticCode::E0213ClauseSupersededByMissing
+        | DiagnosticCode::E0214ClauseAnchorInvalid
+        | DiagnosticCode::E0215ClauseAnchorDuplicate
+        | DiagnosticCode::E0216FragmentNotFound
+        | DiagnosticCode::E0217FragmentParameterMissing
+        | DiagnosticCode::E0301AdrSchemaInvalid
+        | DiagnosticCode::E0304AdrRefNotFound
+        | DiagnosticCode::E0306AdrReferenceHierarchy
+        | DiagnosticCode::E0307AdrProjectionConflict
+        | DiagnosticCode::E0308AdrApprovalMissing
+        | DiagnosticCode::E0309AdrSupersessionCycle
+        | DiagnosticCode::E0401WorkSchemaInvalid
+        | DiagnosticCode::E0404WorkRefNotFound
+        | DiagnosticCode::E0407WorkMissingCriteria
+        | DiagnosticCode::E0408WorkCriteriaMissingCategory
+        | DiagnosticCode::E0409WorkDependencyInvalid
+        | DiagnosticCode::E0410WorkDependencyNotFound
+        | DiagnosticCode::E0411WorkDependencyCycle
+        | DiagnosticCode::E0412WorkDoneRequirementUnmet
+        | DiagnosticCode::E0507ConfigSchemaInvalid
+        | DiagnosticCode::E0511WorkspaceRefNotFound
+        | DiagnosticCode::E0512RemoteNotConfigured
+        | DiagnosticCode::E0514VariableUndefined
+        | DiagnosticCode::E0601SignatureMismatch
+        | DiagnosticCode::E0602SignatureMissing
+        | DiagnosticCode::E0704ReleaseSchemaInvalid
+        | DiagnosticCode::E0705ReleaseRefNotFound
+        | DiagnosticCode::E0707ReleaseWorkDuplicate
+        | DiagnosticCode::E1001GuardSchemaInvalid
+        | DiagnosticCode::E1004GuardCheckFailed
+        | DiagnosticCode::E1005GuardTimeout
+        | DiagnosticCode::E1105TagUnknown
+        | DiagnosticCode::E1201LoopStateInvalid
+        | DiagnosticCode::E1205LoopDependencyNotFound
+        | DiagnosticCode::E1206LoopDependencyCycle
+        | DiagnosticCode::E1401MappingSchemaInvalid
+        | DiagnosticCode::E1402MappingClauseNotFound
+        | DiagnosticCode::E1403MappingClauseInactive
+        | DiagnosticCode::E1404MappingDuplicate
+        | DiagnosticCode::E1603SensitiveFieldPlaintext
+        | DiagnosticCode::E1701AttachmentMissing
+        | DiagnosticCode::E1702AttachmentPathInvalid => FailureClass::Validation,
+        // Warnings only fail a run when denied, which is a validation failure.
+        _ if level(code) != DiagnosticLevel::Error => FailureClass::Validation,
+        _ => FailureClass::General,
+    }
+}
+
 pub(super) fn code(code: &DiagnosticCode) -> &'static str {
     match code {
         // E01xx - RFC
@@ -36,6 +343,17 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::E0113RfcBumpNoAmendment => "E0113",
         DiagnosticCode::E0114RfcPendingAmendment => "E0114",
         DiagnosticCode::E0115RfcCurrentChangelogInvalid => "E0115",
+        DiagnosticCode::E0116RfcSectionNotFound => "E0116",
+        DiagnosticCode::E0117RfcSectionExists => "E0117",
+        DiagnosticCode::E0118RfcSectionNotEmpty => "E0118",
+        DiagnosticCode::E0119RfcLastSection => "E0119",
+        DiagnosticCode::E0120RfcOwnerUnknown => "E0120",
+        DiagnosticCode::E0121RfcApprovalMissing => "E0121",
+        DiagnosticCode::E0122RfcFrozen => "E0122",
+        DiagnosticCode::E0123RfcGateUnmet => "E0123",
+        DiagnosticCode::E0124RfcSnapshotNotFound => "E0124",
+        DiagnosticCode::E0125RfcSupersessionCycle => "E0125",
+        DiagnosticCode::E0126RfcSupersessionFork => "E0126",
         // E02xx - Clause
         DiagnosticCode::E0201ClauseSchemaInvalid => "E0201",
         DiagnosticCode::E0202ClauseNotFound => "E0202",
@@ -49,6 +367,11 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::E0211ClauseStillReferenced => "E0211",
         DiagnosticCode::E0212ClauseSupersessionCycle => "E0212",
         DiagnosticCode::E0213ClauseSupersededByMissing => "E0213",
+        DiagnosticCode::E0214ClauseAnchorInvalid => "E0214",
+        DiagnosticCode::E0215ClauseAnchorDuplicate => "E0215",
+        DiagnosticCode::E0216FragmentNotFound => "E0216",
+        DiagnosticCode::E0217FragmentParameterMissing => "E0217",
+        DiagnosticCode::E0218ClauseNotRelocatable => "E0218",
         // E03xx - ADR
         DiagnosticCode::E0301AdrSchemaInvalid => "E0301",
         DiagnosticCode::E0302AdrNotFound => "E0302",
@@ -57,6 +380,8 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::E0305AdrCannotDeprecate => "E0305",
         DiagnosticCode::E0306AdrReferenceHierarchy => "E0306",
         DiagnosticCode::E0307AdrProjectionConflict => "E0307",
+        DiagnosticCode::E0308AdrApprovalMissing => "E0308",
+        DiagnosticCode::E0309AdrSupersessionCycle => "E0309",
         // E04xx - Work Item
         DiagnosticCode::E0401WorkSchemaInvalid => "E0401",
         DiagnosticCode::E0402WorkNotFound => "E0402",
@@ -69,12 +394,23 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::E0409WorkDependencyInvalid => "E0409",
         DiagnosticCode::E0410WorkDependencyNotFound => "E0410",
         DiagnosticCode::E0411WorkDependencyCycle => "E0411",
+        DiagnosticCode::E0412WorkDoneRequirementUnmet => "E0412",
+        DiagnosticCode::E0413WorkNotQueued => "E0413",
         // E05xx - Config
         DiagnosticCode::E0501ConfigInvalid => "E0501",
         DiagnosticCode::E0502PathNotFound => "E0502",
         DiagnosticCode::E0503LockTimeout => "E0503",
         DiagnosticCode::E0504PathConflict => "E0504",
         DiagnosticCode::E0505MigrationRequired => "E0505",
+        DiagnosticCode::E0506TemplateInvalid => "E0506",
+        DiagnosticCode::E0507ConfigSchemaInvalid => "E0507",
+        DiagnosticCode::E0508ConfigKeyUnknown => "E0508",
+        DiagnosticCode::E0509WorkspaceInvalid => "E0509",
+        DiagnosticCode::E0510WorkspaceMemberNotFound => "E0510",
+        DiagnosticCode::E0511WorkspaceRefNotFound => "E0511",
+        DiagnosticCode::E0512RemoteNotConfigured => "E0512",
+        DiagnosticCode::E0513PublishNotConfigured => "E0513",
+        DiagnosticCode::E0514VariableUndefined => "E0514",
         // E06xx - Signature
         DiagnosticCode::E0601SignatureMismatch => "E0601",
         DiagnosticCode::E0602SignatureMissing => "E0602",
@@ -96,6 +432,7 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::E1005GuardTimeout => "E1005",
         DiagnosticCode::E1006GuardInvalidTitle => "E1006",
         DiagnosticCode::E1007GuardStillReferenced => "E1007",
+        DiagnosticCode::E1008HookFailed => "E1008",
         // E11xx - Tags
         DiagnosticCode::E1101TagInvalidFormat => "E1101",
         DiagnosticCode::E1102TagAlreadyExists => "E1102",
@@ -112,6 +449,27 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::E1208LoopResumeAmbiguous => "E1208",
         DiagnosticCode::E1209LoopWorkMismatch => "E1209",
         DiagnosticCode::E1210LoopExecutionFailed => "E1210",
+        // E13xx - Operation history
+        DiagnosticCode::E1301HistoryEmpty => "E1301",
+        DiagnosticCode::E1302HistoryConflict => "E1302",
+        DiagnosticCode::E1303HistoryInvalid => "E1303",
+        DiagnosticCode::E1304HistoryGitUnavailable => "E1304",
+        // E14xx - Compliance mapping
+        DiagnosticCode::E1401MappingSchemaInvalid => "E1401",
+        DiagnosticCode::E1402MappingClauseNotFound => "E1402",
+        DiagnosticCode::E1403MappingClauseInactive => "E1403",
+        DiagnosticCode::E1404MappingDuplicate => "E1404",
+        DiagnosticCode::E1405MappingNotFound => "E1405",
+        // E15xx - Audit log
+        DiagnosticCode::E1501AuditLogInvalid => "E1501",
+        DiagnosticCode::E1502AuditEntryNotFound => "E1502",
+        // E16xx - Secrets
+        DiagnosticCode::E1601SecretKeyMissing => "E1601",
+        DiagnosticCode::E1602SecretDecryptFailed => "E1602",
+        DiagnosticCode::E1603SensitiveFieldPlaintext => "E1603",
+        // E17xx - Attachments
+        DiagnosticCode::E1701AttachmentMissing => "E1701",
+        DiagnosticCode::E1702AttachmentPathInvalid => "E1702",
         // E08xx - CLI/Command
         DiagnosticCode::E0801MissingRequiredArg => "E0801",
         DiagnosticCode::E0802ConflictingArgs => "E0802",
@@ -135,6 +493,17 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::E0820InvalidFieldValue => "E0820",
         DiagnosticCode::E0821InvalidCommandScope => "E0821",
         DiagnosticCode::E0822UnsupportedOperation => "E0822",
+        DiagnosticCode::E0823EditorFailed => "E0823",
+        DiagnosticCode::E0824InteractiveUnavailable => "E0824",
+        DiagnosticCode::E0825ApplyScriptInvalid => "E0825",
+        DiagnosticCode::E0826AgentAssetsOutOfDate => "E0826",
+        DiagnosticCode::E0827ArtifactsNotFormatted => "E0827",
+        DiagnosticCode::E0828UnknownDiagnostic => "E0828",
+        DiagnosticCode::E0829AmbiguousArtifactMatch => "E0829",
+        DiagnosticCode::E0830UnknownAlias => "E0830",
+        DiagnosticCode::E0831InvalidActor => "E0831",
+        DiagnosticCode::E0832UnknownRenderProfile => "E0832",
+        DiagnosticCode::E0833RenderedDocsOutOfDate => "E0833",
         // E09xx - General
         DiagnosticCode::E0901IoError => "E0901",
         DiagnosticCode::E0902JsonParseError => "E0902",
@@ -151,6 +520,21 @@ pub(super) fn code(code: &DiagnosticCode) -> &'static str {
         DiagnosticCode::W0111ProjectSupportOutdated => "W0111",
         DiagnosticCode::W0112BareArtifactReference => "W0112",
         DiagnosticCode::W0113AdrPlaceholderContext => "W0113",
+        DiagnosticCode::W0114ConfigUnknownKey => "W0114",
+        DiagnosticCode::W0115ConfigDeprecatedKey => "W0115",
+        DiagnosticCode::W0116ConfigIneffectiveSetting => "W0116",
+        DiagnosticCode::W0117RemoteUnavailable => "W0117",
+        DiagnosticCode::W0118RfcGateOverridden => "W0118",
+        DiagnosticCode::W0119ArtifactStale => "W0119",
+        DiagnosticCode::W0120SunsetPassed => "W0120",
+        DiagnosticCode::W0121SupersessionHeadInactive => "W0121",
+        DiagnosticCode::W0122ClauseUncovered => "W0122",
+        DiagnosticCode::W0123RefInactive => "W0123",
+        DiagnosticCode::W0124JiraStatusDiverged => "W0124",
+        DiagnosticCode::W0125WebhookFailed => "W0125",
+        DiagnosticCode::W0126HookFailed => "W0126",
+        DiagnosticCode::W0127DiagramInvalid => "W0127",
+        DiagnosticCode::W0128GuardFailureAllowed => "W0128",
         // I04xx - Work Item info
         DiagnosticCode::I0401WorkLegacyInlineHistory => "I0401",
     }
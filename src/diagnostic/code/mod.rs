@@ -1,7 +1,10 @@
 //! Diagnostic code catalog.
 
+mod explain;
 mod metadata;
 
+pub use self::explain::Explanation;
+
 /// Diagnostic severity level
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DiagnosticLevel {
@@ -10,6 +13,36 @@ pub enum DiagnosticLevel {
     Info,
 }
 
+/// Failure class of an error, surfaced to scripts as the process exit code.
+///
+/// Exit code 2 is left to argument parsing errors, which clap reports before
+/// any diagnostic exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureClass {
+    /// Anything without a more specific class (I/O, arguments, config, ...)
+    General,
+    /// Artifacts or project state fail validation
+    Validation,
+    /// The requested artifact or resource does not exist
+    NotFound,
+    /// The lifecycle does not allow the requested change
+    InvalidTransition,
+    /// Another govctl command holds the gov-root lock
+    LockContention,
+}
+
+impl FailureClass {
+    pub fn exit_code(self) -> u8 {
+        match self {
+            FailureClass::General => 1,
+            FailureClass::Validation => 3,
+            FailureClass::NotFound => 4,
+            FailureClass::InvalidTransition => 5,
+            FailureClass::LockContention => 6,
+        }
+    }
+}
+
 /// Diagnostic error codes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DiagnosticCode {
@@ -30,6 +63,22 @@ pub enum DiagnosticCode {
     E0113RfcBumpNoAmendment,
     E0114RfcPendingAmendment,
     E0115RfcCurrentChangelogInvalid,
+    E0116RfcSectionNotFound,
+    E0117RfcSectionExists,
+    E0118RfcSectionNotEmpty,
+    E0119RfcLastSection,
+    E0120RfcOwnerUnknown,
+    E0121RfcApprovalMissing,
+    /// Write refused: the RFC is frozen for review
+    E0122RfcFrozen,
+    /// `rfc advance` refused: a `[gates]` checklist item is unmet
+    E0123RfcGateUnmet,
+    /// `rfc show ID@version` found no snapshot of that version
+    E0124RfcSnapshotNotFound,
+    /// RFC `supersedes` links form a cycle
+    E0125RfcSupersessionCycle,
+    /// More than one RFC claims to supersede the same RFC
+    E0126RfcSupersessionFork,
 
     // Clause errors (E02xx)
     E0201ClauseSchemaInvalid,
@@ -44,6 +93,14 @@ pub enum DiagnosticCode {
     E0211ClauseStillReferenced,
     E0212ClauseSupersessionCycle,
     E0213ClauseSupersededByMissing,
+    E0214ClauseAnchorInvalid,
+    E0215ClauseAnchorDuplicate,
+    /// Clause text includes a fragment with no file under `gov/fragments/`.
+    E0216FragmentNotFound,
+    /// An include leaves a placeholder of its fragment unfilled.
+    E0217FragmentParameterMissing,
+    /// `rfc split` or `rfc merge` named a Clause that is not active.
+    E0218ClauseNotRelocatable,
 
     // ADR errors (E03xx)
     E0301AdrSchemaInvalid,
@@ -54,6 +111,9 @@ pub enum DiagnosticCode {
     /// ADR refs or [[...]] targets WI-* — violates [[RFC-0000:C-REFERENCE-HIERARCHY]]
     E0306AdrReferenceHierarchy,
     E0307AdrProjectionConflict,
+    E0308AdrApprovalMissing,
+    /// ADR `superseded_by` links form a cycle
+    E0309AdrSupersessionCycle,
 
     // Work Item errors (E04xx)
     E0401WorkSchemaInvalid,
@@ -67,6 +127,10 @@ pub enum DiagnosticCode {
     E0409WorkDependencyInvalid,
     E0410WorkDependencyNotFound,
     E0411WorkDependencyCycle,
+    /// A `[work_item.done]` requirement other than acceptance criteria is unmet.
+    E0412WorkDoneRequirementUnmet,
+    /// `work rank` names a work item that is not in the queue.
+    E0413WorkNotQueued,
 
     // Config errors (E05xx)
     E0501ConfigInvalid,
@@ -74,6 +138,16 @@ pub enum DiagnosticCode {
     E0503LockTimeout,
     E0504PathConflict,
     E0505MigrationRequired,
+    E0506TemplateInvalid,
+    E0507ConfigSchemaInvalid,
+    E0508ConfigKeyUnknown,
+    E0509WorkspaceInvalid,
+    E0510WorkspaceMemberNotFound,
+    E0511WorkspaceRefNotFound,
+    E0512RemoteNotConfigured,
+    E0513PublishNotConfigured,
+    /// Clause or ADR text references a variable `[variables]` does not define.
+    E0514VariableUndefined,
 
     // Signature errors (E06xx)
     E0601SignatureMismatch,
@@ -98,6 +172,8 @@ pub enum DiagnosticCode {
     E1005GuardTimeout,
     E1006GuardInvalidTitle,
     E1007GuardStillReferenced,
+    /// A blocking `[hooks]` command failed.
+    E1008HookFailed,
 
     // Tag errors (E11xx)
     /// Tag format is invalid (must match ^[a-z][a-z0-9-]*$)
@@ -122,6 +198,44 @@ pub enum DiagnosticCode {
     E1209LoopWorkMismatch,
     E1210LoopExecutionFailed,
 
+    // Operation history errors (E13xx)
+    E1301HistoryEmpty,
+    E1302HistoryConflict,
+    E1303HistoryInvalid,
+    /// `clause history` cannot read git history (no git, or not a repository).
+    E1304HistoryGitUnavailable,
+
+    // Compliance mapping errors (E14xx)
+    E1401MappingSchemaInvalid,
+    /// A mapped control names a clause that does not exist.
+    E1402MappingClauseNotFound,
+    /// A mapped control names a deprecated or superseded clause.
+    E1403MappingClauseInactive,
+    /// Two mapping files declare the same standard, or one declares a control twice.
+    E1404MappingDuplicate,
+    /// `mapping remove` names a standard or control that is not mapped.
+    E1405MappingNotFound,
+
+    // Audit log errors (E15xx)
+    /// A line of `gov/audit.log` is not a valid audit entry.
+    E1501AuditLogInvalid,
+    /// `audit show` names an entry that is not in the log.
+    E1502AuditEntryNotFound,
+
+    // Secret errors (E16xx)
+    /// A sensitive field must be encrypted or decrypted, but no usable key is configured.
+    E1601SecretKeyMissing,
+    /// A sensitive field does not decrypt with the configured key.
+    E1602SecretDecryptFailed,
+    /// A field marked sensitive holds plaintext.
+    E1603SensitiveFieldPlaintext,
+
+    // Attachment errors (E17xx)
+    /// An attachment an artifact lists is not under `gov/assets/`.
+    E1701AttachmentMissing,
+    /// An attachment path is absolute or leaves `gov/assets/`.
+    E1702AttachmentPathInvalid,
+
     // CLI/Command errors (E08xx)
     E0801MissingRequiredArg,
     E0802ConflictingArgs,
@@ -145,6 +259,25 @@ pub enum DiagnosticCode {
     E0820InvalidFieldValue,
     E0821InvalidCommandScope,
     E0822UnsupportedOperation,
+    E0823EditorFailed,
+    E0824InteractiveUnavailable,
+    E0825ApplyScriptInvalid,
+    /// `init-skills --check` found missing or changed agent assets.
+    E0826AgentAssetsOutOfDate,
+    /// `fmt --check` found artifact files that are not in canonical form.
+    E0827ArtifactsNotFormatted,
+    /// `explain` was given a code that is not in the catalog.
+    E0828UnknownDiagnostic,
+    /// A partial ID or title fragment names more than one artifact.
+    E0829AmbiguousArtifactMatch,
+    /// An `@alias` is neither declared in `[aliases]` nor `@last`.
+    E0830UnknownAlias,
+    /// `--as`, `GOVCTL_ACTOR`, or `[identity] actor` is not `human:` or `agent:` a name.
+    E0831InvalidActor,
+    /// `--profile` names no `[render.profiles]` entry.
+    E0832UnknownRenderProfile,
+    /// `render --check` found rendered files that differ from their sources.
+    E0833RenderedDocsOutOfDate,
 
     // General errors (E09xx)
     E0901IoError,
@@ -167,6 +300,36 @@ pub enum DiagnosticCode {
     W0112BareArtifactReference,
     /// ADR context still contains the generated placeholder text.
     W0113AdrPlaceholderContext,
+    /// Config key is not recognized and will be ignored.
+    W0114ConfigUnknownKey,
+    /// Config key is deprecated and no longer has any effect.
+    W0115ConfigDeprecatedKey,
+    /// Config setting has no effect because of another setting.
+    W0116ConfigIneffectiveSetting,
+    /// Remote repository could not be read, so its refs were not verified.
+    W0117RemoteUnavailable,
+    /// `rfc advance --force` skipped unmet `[gates]` checklist items.
+    W0118RfcGateOverridden,
+    /// Draft RFC or proposed ADR is older than its `[staleness]` limit.
+    W0119ArtifactStale,
+    /// A deprecation sunset date has passed and the artifact is still referenced.
+    W0120SunsetPassed,
+    /// The newest artifact in a supersession chain is not in force.
+    W0121SupersessionHeadInactive,
+    /// A normative clause has no `Implements` annotation in scanned source.
+    W0122ClauseUncovered,
+    /// A live artifact lists a deprecated or superseded artifact in `refs`.
+    W0123RefInactive,
+    /// A work item's status disagrees with its linked Jira issue.
+    W0124JiraStatusDiverged,
+    /// A lifecycle webhook could not be delivered; the command itself succeeded.
+    W0125WebhookFailed,
+    /// A `[hooks]` command with `on_failure = "warn"` failed.
+    W0126HookFailed,
+    /// A fenced `mermaid` or `plantuml` block does not parse.
+    W0127DiagramInvalid,
+    /// A verification guard with `allow_failure = true` failed.
+    W0128GuardFailureAllowed,
 
     // Informational diagnostics (I04xx)
     I0401WorkLegacyInlineHistory,
@@ -180,11 +343,32 @@ impl DiagnosticCode {
     pub fn code(&self) -> &'static str {
         metadata::code(self)
     }
+
+    pub fn failure_class(&self) -> FailureClass {
+        metadata::failure_class(self)
+    }
+
+    pub fn explanation(&self) -> Explanation {
+        explain::explanation(self)
+    }
+
+    /// Look up a code by its string form, e.g. `E0207`, ignoring case.
+    pub fn from_code(code: &str) -> Option<Self> {
+        metadata::ALL
+            .iter()
+            .copied()
+            .find(|known| known.code().eq_ignore_ascii_case(code.trim()))
+    }
+
+    /// Every code, in catalog order.
+    pub fn all() -> &'static [DiagnosticCode] {
+        metadata::ALL
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{DiagnosticCode, DiagnosticLevel};
+    use super::{DiagnosticCode, DiagnosticLevel, FailureClass};
 
     #[test]
     fn code_strings_match_representative_catalog_ids() {
@@ -234,4 +418,74 @@ mod tests {
             DiagnosticLevel::Info
         );
     }
+
+    #[test]
+    fn failure_classes_map_to_distinct_exit_codes() {
+        assert_eq!(
+            DiagnosticCode::E0101RfcSchemaInvalid.failure_class(),
+            FailureClass::Validation
+        );
+        assert_eq!(
+            DiagnosticCode::E0402WorkNotFound.failure_class(),
+            FailureClass::NotFound
+        );
+        assert_eq!(
+            DiagnosticCode::E0403WorkInvalidTransition.failure_class(),
+            FailureClass::InvalidTransition
+        );
+        assert_eq!(
+            DiagnosticCode::E0503LockTimeout.failure_class(),
+            FailureClass::LockContention
+        );
+        assert_eq!(
+            DiagnosticCode::E0901IoError.failure_class(),
+            FailureClass::General
+        );
+        let codes = [
+            FailureClass::General,
+            FailureClass::Validation,
+            FailureClass::NotFound,
+            FailureClass::InvalidTransition,
+            FailureClass::LockContention,
+        ]
+        .map(FailureClass::exit_code);
+        assert_eq!(codes, [1, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn every_code_is_found_by_its_string_form() {
+        let mut seen = std::collections::HashSet::new();
+        for code in DiagnosticCode::all() {
+            assert!(seen.insert(code.code()), "duplicate {}", code.code());
+            assert_eq!(DiagnosticCode::from_code(code.code()), Some(*code));
+        }
+        assert_eq!(
+            DiagnosticCode::from_code(" e0207 "),
+            Some(DiagnosticCode::E0207ClauseSupersededByNotActive)
+        );
+        assert_eq!(DiagnosticCode::from_code("E9999"), None);
+    }
+
+    #[test]
+    fn explanations_are_complete_and_cite_existing_clauses() {
+        let rfc_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("gov/rfc");
+        for code in DiagnosticCode::all() {
+            let explanation = code.explanation();
+            assert!(!explanation.summary.is_empty(), "{}", code.code());
+            assert!(!explanation.causes.is_empty(), "{}", code.code());
+            assert!(!explanation.fixes.is_empty(), "{}", code.code());
+            for clause in explanation.clauses {
+                let (rfc, clause_id) = clause.split_once(':').unwrap_or_default();
+                let path = rfc_root
+                    .join(rfc)
+                    .join("clauses")
+                    .join(format!("{clause_id}.toml"));
+                assert!(
+                    path.is_file(),
+                    "{} cites missing clause {clause}",
+                    code.code()
+                );
+            }
+        }
+    }
 }
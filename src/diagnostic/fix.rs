@@ -0,0 +1,88 @@
+//! Corrective commands extracted from a diagnostic, for the agent persona.
+
+use super::Diagnostic;
+
+const HINT_OPEN: &str = "(hint: ";
+
+impl Diagnostic {
+    /// The message without its trailing `(hint: ...)`.
+    pub fn summary(&self) -> &str {
+        self.split_hint().0
+    }
+
+    /// The text of the trailing `(hint: ...)`, if the message has one.
+    pub fn hint(&self) -> Option<&str> {
+        self.split_hint().1
+    }
+
+    /// Exact `govctl` commands that address this diagnostic: those quoted in
+    /// its hint, or else those quoted in the code's `explain` fixes.
+    pub fn corrective_commands(&self) -> Vec<String> {
+        let from_hint = self.hint().map(quoted_commands).unwrap_or_default();
+        if !from_hint.is_empty() {
+            return from_hint;
+        }
+        self.code
+            .explanation()
+            .fixes
+            .iter()
+            .flat_map(|fix| quoted_commands(fix))
+            .collect()
+    }
+
+    fn split_hint(&self) -> (&str, Option<&str>) {
+        let message = self.message.as_str();
+        match message.rfind(HINT_OPEN) {
+            Some(start) if message.ends_with(')') => (
+                message[..start].trim_end(),
+                Some(&message[start + HINT_OPEN.len()..message.len() - 1]),
+            ),
+            _ => (message, None),
+        }
+    }
+}
+
+/// Backtick-quoted spans of `text` that are `govctl` invocations.
+fn quoted_commands(text: &str) -> Vec<String> {
+    text.split('`')
+        .skip(1)
+        .step_by(2)
+        .filter(|span| span.starts_with("govctl "))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::DiagnosticCode;
+
+    #[test]
+    fn test_hint_commands_are_split_from_the_summary() {
+        let diag = Diagnostic::new(
+            DiagnosticCode::W0109WorkNoActive,
+            "No active work item (hint: `govctl work new --active \"<title>\"`)",
+            "",
+        );
+        assert_eq!(diag.summary(), "No active work item");
+        assert_eq!(
+            diag.corrective_commands(),
+            vec!["govctl work new --active \"<title>\"".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_explanation_fixes_stand_in_for_a_missing_hint() {
+        let diag = Diagnostic::new(
+            DiagnosticCode::E1405MappingNotFound,
+            "Standard 'SOC2' has no control 'CC1.1'",
+            "gov/mapping/soc2.toml",
+        );
+        assert_eq!(diag.summary(), diag.message);
+        assert_eq!(diag.hint(), None);
+        assert_eq!(
+            diag.corrective_commands(),
+            vec!["govctl mapping list".to_string()]
+        );
+    }
+}
@@ -1,8 +1,14 @@
 //! Diagnostic message type and error formatting.
 
 mod code;
+mod fix;
+mod span;
+mod suggest;
 
-pub use self::code::{DiagnosticCode, DiagnosticLevel};
+pub use self::code::{DiagnosticCode, DiagnosticLevel, FailureClass};
+pub use self::span::Span;
+pub(crate) use self::span::pointer_span;
+pub(crate) use self::suggest::not_found_message;
 
 use std::fmt;
 
@@ -13,6 +19,8 @@ pub struct Diagnostic {
     pub message: String,
     pub file: String,
     pub level: DiagnosticLevel,
+    /// Line and column within `file`, when the finding can be located.
+    pub span: Option<Span>,
 }
 
 pub type Diagnostics = Vec<Diagnostic>;
@@ -25,6 +33,20 @@ impl Diagnostic {
             code,
             message: message.into(),
             file: file.into(),
+            span: None,
+        }
+    }
+
+    pub fn with_span(mut self, span: Option<Span>) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// `file`, followed by `:line:column` when the span is known.
+    pub fn location(&self) -> String {
+        match self.span {
+            Some(span) => format!("{}:{}:{}", self.file, span.line, span.column),
+            None => self.file.clone(),
         }
     }
 
@@ -54,7 +76,7 @@ impl fmt::Display for Diagnostic {
             level_str,
             self.code.code(),
             self.message,
-            self.file
+            self.location()
         )
     }
 }
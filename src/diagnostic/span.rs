@@ -0,0 +1,106 @@
+//! Line and column of a diagnostic within its file.
+
+use serde::de::DeserializeOwned;
+use std::ops::Range;
+use toml::Spanned;
+use toml::de::{DeTable, DeValue};
+
+/// 1-based line and column, in characters, of a position in a text file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    /// Position of byte `offset` in `source`.
+    pub fn at_offset(source: &str, offset: usize) -> Option<Self> {
+        let before = source.get(..offset)?;
+        Some(Self {
+            line: before.matches('\n').count() + 1,
+            column: before
+                .rsplit('\n')
+                .next()
+                .map_or(0, |text| text.chars().count())
+                + 1,
+        })
+    }
+
+    /// Where a TOML parse or deserialize error points in `source`.
+    pub fn of_toml_error(source: &str, err: &toml::de::Error) -> Option<Self> {
+        Self::at_offset(source, err.span()?.start)
+    }
+
+    /// Where `err`, raised while deserializing a `toml::Value` parsed from
+    /// `source` into `T`, points in `source`.
+    ///
+    /// Values carry no positions, so the error is reproduced on the text. When
+    /// normalization makes the text fail differently, no span is reported.
+    pub fn of_deserialize_error<T: DeserializeOwned>(
+        source: &str,
+        err: &toml::de::Error,
+    ) -> Option<Self> {
+        let reproduced = toml::from_str::<T>(source).err()?;
+        (reproduced.message() == err.message())
+            .then(|| Self::of_toml_error(source, &reproduced))
+            .flatten()
+    }
+
+    /// Position of the value at JSON pointer `pointer`, such as
+    /// `/govctl/refs/1`, in TOML `source`.
+    pub fn of_toml_pointer(source: &str, pointer: &str) -> Option<Self> {
+        let document = DeTable::parse(source).ok()?;
+        Self::at_offset(source, pointer_span(document.get_ref(), pointer)?.start)
+    }
+}
+
+/// Byte span of the value at JSON pointer `pointer` in a parsed TOML table.
+pub(crate) fn pointer_span(document: &DeTable<'_>, pointer: &str) -> Option<Range<usize>> {
+    let mut segments = pointer.split('/').skip(1);
+    let mut current = table_entry(document, segments.next()?)?;
+    for segment in segments {
+        current = match current.get_ref() {
+            DeValue::Table(table) => table_entry(table, segment)?,
+            DeValue::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current.span())
+}
+
+fn table_entry<'a, 'i>(table: &'a DeTable<'i>, key: &str) -> Option<&'a Spanned<DeValue<'i>>> {
+    table
+        .iter()
+        .find(|(candidate, _)| {
+            let name: &str = candidate.get_ref();
+            name == key
+        })
+        .map(|(_, value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Span;
+
+    const SOURCE: &str = "[govctl]\nid = \"ADR-0001\"\nrefs = [\"RFC-0001\", \"RFC-0009\"]\n";
+
+    #[test]
+    fn pointer_resolves_nested_keys_and_array_items() {
+        assert_eq!(
+            Span::of_toml_pointer(SOURCE, "/govctl/refs/1"),
+            Some(Span {
+                line: 3,
+                column: 21
+            })
+        );
+        assert_eq!(Span::of_toml_pointer(SOURCE, "/govctl/missing"), None);
+    }
+
+    #[test]
+    fn toml_errors_point_at_the_offending_text() {
+        let source = "[govctl]\nid = \"ADR-0001\"\ntitle = \n";
+        let err = toml::from_str::<toml::Value>(source).err();
+        let span = err.and_then(|err| Span::of_toml_error(source, &err));
+        assert_eq!(span.map(|span| span.line), Some(3));
+    }
+}
@@ -0,0 +1,91 @@
+//! "Did you mean" hints for mistyped IDs.
+
+/// Most suggestions offered for one mistyped ID.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Hint naming the `known` IDs closest to `input`, such as
+/// `did you mean RFC-0010 or RFC-0011?`, or `None` when nothing is close.
+///
+/// IDs are compared case-insensitively. A candidate is close when it is a
+/// few edits away, relative to its length, or contains `input` outright.
+pub(crate) fn did_you_mean<'a>(
+    input: &str,
+    known: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let input = input.trim().to_ascii_uppercase();
+    if input.is_empty() {
+        return None;
+    }
+    let mut close: Vec<(usize, &str)> = known
+        .into_iter()
+        .filter_map(|candidate| {
+            let upper = candidate.to_ascii_uppercase();
+            let distance = strsim::damerau_levenshtein(&input, &upper);
+            let budget = (upper.chars().count() / 4).max(1);
+            if distance <= budget {
+                Some((distance, candidate))
+            } else if input.chars().count() >= 3 && upper.contains(&input) {
+                Some((budget + 1, candidate))
+            } else {
+                None
+            }
+        })
+        .collect();
+    close.sort();
+    close.dedup_by(|a, b| a.1 == b.1);
+    let names: Vec<&str> = close
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect();
+    match names.as_slice() {
+        [] => None,
+        [only] => Some(format!("did you mean {only}?")),
+        [rest @ .., last] => Some(format!("did you mean {} or {last}?", rest.join(", "))),
+    }
+}
+
+/// `"{label} not found: {id}"`, with a hint naming the closest `known` IDs.
+pub(crate) fn not_found_message<'a>(
+    label: &str,
+    id: &str,
+    known: impl IntoIterator<Item = &'a str>,
+) -> String {
+    match did_you_mean(id, known) {
+        Some(hint) => format!("{label} not found: {id} (hint: {hint})"),
+        None => format!("{label} not found: {id}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::did_you_mean;
+
+    const KNOWN: &[&str] = &[
+        "RFC-0001",
+        "RFC-0010",
+        "RFC-0001:C-EXPIRY",
+        "RFC-0001:C-EVICTION",
+    ];
+
+    #[test]
+    fn suggests_ids_a_few_edits_away() {
+        assert_eq!(
+            did_you_mean("RFC-0100", KNOWN.iter().copied()),
+            Some("did you mean RFC-0010 or RFC-0001?".to_string())
+        );
+        assert_eq!(
+            did_you_mean("rfc-0001:c-expire", KNOWN.iter().copied()),
+            Some("did you mean RFC-0001:C-EXPIRY?".to_string())
+        );
+    }
+
+    #[test]
+    fn suggests_ids_containing_the_input() {
+        assert_eq!(
+            did_you_mean("eviction", KNOWN.iter().copied()),
+            Some("did you mean RFC-0001:C-EVICTION?".to_string())
+        );
+        assert_eq!(did_you_mean("ADR-0042", KNOWN.iter().copied()), None);
+    }
+}
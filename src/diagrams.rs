@@ -0,0 +1,484 @@
+//! Diagram blocks in governed prose.
+//!
+//! Fenced `mermaid` and `plantuml` blocks in clause and ADR text are checked
+//! by `govctl check` with a parse-only pass: the diagram type, block
+//! keywords, and brackets must line up. Nothing is drawn, so no diagram tool
+//! is needed for validation. HTML output can pre-render the blocks to SVG
+//! with the commands in `[render.diagrams]`.
+
+use crate::config::Config;
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Mermaid diagram types, as the first statement of a block.
+const MERMAID_TYPES: &[&str] = &[
+    "graph",
+    "flowchart",
+    "sequenceDiagram",
+    "classDiagram",
+    "classDiagram-v2",
+    "stateDiagram",
+    "stateDiagram-v2",
+    "erDiagram",
+    "journey",
+    "gantt",
+    "pie",
+    "quadrantChart",
+    "requirementDiagram",
+    "gitGraph",
+    "C4Context",
+    "C4Container",
+    "C4Component",
+    "C4Dynamic",
+    "C4Deployment",
+    "mindmap",
+    "timeline",
+    "zenuml",
+    "sankey-beta",
+    "xychart-beta",
+    "block-beta",
+    "packet-beta",
+    "kanban",
+    "architecture-beta",
+    "radar-beta",
+];
+
+/// Flowchart directions.
+const MERMAID_DIRECTIONS: &[&str] = &["TB", "TD", "BT", "RL", "LR"];
+
+/// Sequence diagram statements closed by `end`.
+const MERMAID_SEQUENCE_BLOCKS: &[&str] = &[
+    "loop", "alt", "opt", "par", "critical", "break", "rect", "box",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagramKind {
+    Mermaid,
+    PlantUml,
+}
+
+impl DiagramKind {
+    /// The kind a code fence's info string names, e.g. ```` ```mermaid ````.
+    pub fn from_info(info: &str) -> Option<Self> {
+        match info.split_whitespace().next()? {
+            "mermaid" => Some(Self::Mermaid),
+            "plantuml" | "puml" => Some(Self::PlantUml),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Mermaid => "mermaid",
+            Self::PlantUml => "plantuml",
+        }
+    }
+}
+
+/// A fenced diagram block.
+#[derive(Debug, Clone)]
+pub struct DiagramBlock {
+    pub kind: DiagramKind,
+    pub source: String,
+    /// 1-based line of the opening fence.
+    pub line: usize,
+}
+
+/// Every fenced diagram block in `markdown`.
+pub fn diagram_blocks(markdown: &str) -> Vec<DiagramBlock> {
+    let mut blocks = Vec::new();
+    let mut open: Option<DiagramBlock> = None;
+    for (event, range) in Parser::new(markdown).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                open = DiagramKind::from_info(&info).map(|kind| DiagramBlock {
+                    kind,
+                    source: String::new(),
+                    line: markdown[..range.start].lines().count() + 1,
+                });
+            }
+            Event::Text(text) => {
+                if let Some(block) = &mut open {
+                    block.source.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => blocks.extend(open.take()),
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// Check a diagram without rendering it. The error names the problem and,
+/// where there is one, its line within the block.
+pub fn check(kind: DiagramKind, source: &str) -> Result<(), String> {
+    match kind {
+        DiagramKind::Mermaid => check_mermaid(source),
+        DiagramKind::PlantUml => check_plantuml(source),
+    }
+}
+
+fn check_mermaid(source: &str) -> Result<(), String> {
+    let mut lines = source
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()));
+
+    // Front matter (`---` ... `---`), directives, and comments come first.
+    let mut in_front_matter = false;
+    let (header_line, header) = loop {
+        let Some((number, line)) = lines.next() else {
+            return Err("empty diagram".to_string());
+        };
+        if line == "---" {
+            in_front_matter = !in_front_matter;
+        } else if !(in_front_matter || line.is_empty() || line.starts_with("%%")) {
+            break (number, line);
+        }
+    };
+    if in_front_matter {
+        return Err("unterminated front matter".to_string());
+    }
+
+    let mut words = header.split_whitespace();
+    let diagram = words.next().unwrap_or_default();
+    if !MERMAID_TYPES.contains(&diagram) {
+        return Err(format!(
+            "line {header_line}: unknown diagram type '{diagram}'"
+        ));
+    }
+    if matches!(diagram, "graph" | "flowchart")
+        && let Some(direction) = words.next()
+        && !MERMAID_DIRECTIONS.contains(&direction.trim_end_matches(';'))
+    {
+        return Err(format!(
+            "line {header_line}: unknown direction '{direction}' (expected one of: {})",
+            MERMAID_DIRECTIONS.join(", ")
+        ));
+    }
+
+    let body: Vec<(usize, &str)> = lines
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("%%"))
+        .collect();
+    match diagram {
+        "graph" | "flowchart" => {
+            check_end_blocks(&body, |line| first_word(line) == "subgraph")?;
+            check_brackets(&body, "([{")
+        }
+        "sequenceDiagram" => check_end_blocks(&body, |line| {
+            MERMAID_SEQUENCE_BLOCKS.contains(&first_word(line))
+        }),
+        "classDiagram" | "classDiagram-v2" | "stateDiagram" | "stateDiagram-v2" => {
+            check_brackets(&body, "{")
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_plantuml(source: &str) -> Result<(), String> {
+    let body: Vec<(usize, &str)> = source
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('\''))
+        .collect();
+    let (Some(&(first_line, first)), Some(&(last_line, last))) = (body.first(), body.last()) else {
+        return Err("empty diagram".to_string());
+    };
+
+    let start = first.strip_prefix("@start").map(first_word);
+    let end = last.strip_prefix("@end").map(first_word);
+    match (start, end) {
+        (Some(start), Some(end)) if start != end => {
+            return Err(format!(
+                "line {last_line}: @end{end} does not close @start{start}"
+            ));
+        }
+        (Some(start), None) => {
+            return Err(format!("line {first_line}: @start{start} is never closed"));
+        }
+        (None, Some(end)) => {
+            return Err(format!("line {last_line}: @end{end} without @start{end}"));
+        }
+        _ => {}
+    }
+    let inner = &body[usize::from(start.is_some())..body.len() - usize::from(end.is_some())];
+    if let Some((number, line)) = inner.iter().find(|(_, line)| line.starts_with('@')) {
+        return Err(format!("line {number}: unexpected '{}'", first_word(line)));
+    }
+
+    // Activity diagram blocks and their closing keywords.
+    let mut open: Vec<(usize, &str)> = Vec::new();
+    for &(number, line) in inner {
+        let word = first_word(line).to_ascii_lowercase();
+        let closes = match word.as_str() {
+            "endif" => Some("if"),
+            "endwhile" => Some("while"),
+            "end" => match line.split_whitespace().nth(1).map(str::to_ascii_lowercase) {
+                Some(what) if what == "note" => Some("note"),
+                Some(what) if what == "fork" || what == "merge" => Some("fork"),
+                Some(what) if what == "if" => Some("if"),
+                Some(what) if what == "while" => Some("while"),
+                _ => None,
+            },
+            "endnote" => Some("note"),
+            _ => None,
+        };
+        if let Some(closes) = closes {
+            match open.pop() {
+                Some((_, opened)) if opened == closes => continue,
+                Some((opened_at, opened)) => {
+                    return Err(format!(
+                        "line {number}: '{line}' closes the '{opened}' opened on line {opened_at}"
+                    ));
+                }
+                None => return Err(format!("line {number}: '{line}' closes nothing")),
+            }
+        }
+        let opens = match word.as_str() {
+            "if" | "while" => Some(if word == "if" { "if" } else { "while" }),
+            "fork" => Some("fork"),
+            // `note left: text` is one line; a note without `:` runs to `end note`.
+            "note" | "hnote" | "rnote" if !line.contains(':') => Some("note"),
+            _ => None,
+        };
+        if let Some(opens) = opens {
+            open.push((number, opens));
+        }
+    }
+    if let Some((number, opened)) = open.pop() {
+        return Err(format!("line {number}: '{opened}' is never closed"));
+    }
+    check_brackets(inner, "{")
+}
+
+/// Statements `opens` matches must each be closed by an `end` line.
+fn check_end_blocks(body: &[(usize, &str)], opens: impl Fn(&str) -> bool) -> Result<(), String> {
+    let mut open = Vec::new();
+    for &(number, line) in body {
+        if opens(line) {
+            open.push((number, first_word(line)));
+        } else if first_word(line) == "end" && open.pop().is_none() {
+            return Err(format!("line {number}: 'end' closes nothing"));
+        }
+    }
+    match open.pop() {
+        Some((number, keyword)) => Err(format!(
+            "line {number}: '{keyword}' is never closed by 'end'"
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Brackets of the kinds in `kinds` must pair up; text in double quotes is
+/// skipped.
+fn check_brackets(body: &[(usize, &str)], kinds: &str) -> Result<(), String> {
+    let closing = |open: char| match open {
+        '(' => ')',
+        '[' => ']',
+        _ => '}',
+    };
+    let mut stack: Vec<(usize, char)> = Vec::new();
+    for &(number, line) in body {
+        let mut quoted = false;
+        for c in line.chars() {
+            if c == '"' {
+                quoted = !quoted;
+            } else if quoted {
+                continue;
+            } else if kinds.contains(c) {
+                stack.push((number, c));
+            } else if kinds.chars().any(|open| closing(open) == c) {
+                match stack.pop() {
+                    Some((_, open)) if closing(open) == c => {}
+                    Some((opened_at, open)) => {
+                        return Err(format!(
+                            "line {number}: '{c}' does not close the '{open}' on line {opened_at}"
+                        ));
+                    }
+                    None => return Err(format!("line {number}: unmatched '{c}'")),
+                }
+            }
+        }
+    }
+    match stack.pop() {
+        Some((number, open)) => Err(format!("line {number}: unclosed '{open}'")),
+        None => Ok(()),
+    }
+}
+
+fn first_word(line: &str) -> &str {
+    line.split(|c: char| c.is_whitespace() || c == '(' || c == ':')
+        .next()
+        .unwrap_or_default()
+}
+
+/// The diagram as inline SVG, from the `[render.diagrams]` command for its
+/// kind. `Ok(None)` when no command is configured.
+pub fn render_svg(
+    config: &Config,
+    kind: DiagramKind,
+    source: &str,
+) -> Result<Option<String>, String> {
+    let command = match kind {
+        DiagramKind::Mermaid => &config.render.diagrams.mermaid,
+        DiagramKind::PlantUml => &config.render.diagrams.plantuml,
+    };
+    let Some(command) = command else {
+        return Ok(None);
+    };
+    let mut shell = Command::new("/bin/bash");
+    shell
+        .args(["-lc", command])
+        .current_dir(config.project_root())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    // Feed stdin and drain both pipes on their own threads, so a renderer
+    // that writes while it reads cannot block on a full pipe.
+    let mut pipes = None;
+    let timeout_secs = config.render.diagrams.timeout_secs;
+    let status =
+        crate::verification::run_with_timeout(shell, Duration::from_secs(timeout_secs), |child| {
+            let stdin = child.stdin.take();
+            let source = source.to_string();
+            let writer = thread::spawn(move || {
+                if let Some(mut stdin) = stdin {
+                    // A renderer may exit before reading all of its input.
+                    let _ = stdin.write_all(source.as_bytes());
+                }
+            });
+            pipes = Some((
+                writer,
+                drain(child.stdout.take()),
+                drain(child.stderr.take()),
+            ));
+        })
+        .map_err(|err| format!("{command}: {err}"))?;
+    let (writer, stdout, stderr) = pipes.ok_or_else(|| format!("{command}: not started"))?;
+    let _ = writer.join();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = String::from_utf8_lossy(&stderr.join().unwrap_or_default())
+        .trim()
+        .to_string();
+    let Some(status) = status else {
+        return Err(format!("{command}: timed out after {timeout_secs}s"));
+    };
+    if !status.success() {
+        return Err(if stderr.is_empty() {
+            format!("{command}: {status}")
+        } else {
+            format!("{command}: {status}: {stderr}")
+        });
+    }
+    let svg = String::from_utf8_lossy(&stdout);
+    // Drop any XML prolog so the SVG can sit inside HTML.
+    match svg.find("<svg") {
+        Some(start) => Ok(Some(svg[start..].trim_end().to_string())),
+        None => Err(format!("{command}: output is not SVG")),
+    }
+}
+
+/// Read `pipe` to its end on a new thread.
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        bytes
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mermaid_checks() {
+        assert!(check(DiagramKind::Mermaid, "graph TD\n  A[Start] --> B{Ok?}\n").is_ok());
+        assert!(
+            check(
+                DiagramKind::Mermaid,
+                "%% note\nsequenceDiagram\n  loop Every minute\n    A->>B: ping (x\n  end\n"
+            )
+            .is_ok()
+        );
+        assert!(check(DiagramKind::Mermaid, "grpah TD\n  A --> B\n").is_err());
+        assert!(check(DiagramKind::Mermaid, "flowchart XY\n").is_err());
+        assert!(check(DiagramKind::Mermaid, "graph LR\n  A[Start --> B\n").is_err());
+        assert!(
+            check(
+                DiagramKind::Mermaid,
+                "flowchart LR\n  subgraph one\n    A --> B\n"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_plantuml_checks() {
+        assert!(
+            check(
+                DiagramKind::PlantUml,
+                "@startuml\nA -> B\nnote left\n  text\nend note\n@enduml\n"
+            )
+            .is_ok()
+        );
+        assert!(check(DiagramKind::PlantUml, "@startuml\nA -> B\n").is_err());
+        assert!(
+            check(
+                DiagramKind::PlantUml,
+                "@startuml\nif (x) then\n:a;\n@enduml\n"
+            )
+            .is_err()
+        );
+        assert!(check(DiagramKind::PlantUml, "@startmindmap\n* root\n@enduml\n").is_err());
+    }
+
+    #[test]
+    fn test_diagram_blocks_reports_fence_lines() {
+        let blocks =
+            diagram_blocks("Intro\n\n```mermaid\ngraph TD\n```\n\n```rust\nfn f() {}\n```\n");
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].line, 3);
+        assert_eq!(blocks[0].source, "graph TD\n");
+    }
+
+    #[test]
+    fn test_render_svg_reports_stderr_and_timeouts() {
+        let mut config = Config::default();
+        config.render.diagrams.mermaid =
+            Some("cat >/dev/null; echo 'bad arrow' >&2; exit 3".to_string());
+        let result = render_svg(&config, DiagramKind::Mermaid, "graph TD\n");
+        assert!(
+            matches!(&result, Err(err) if err.contains("exit status: 3: ") && err.ends_with("bad arrow")),
+            "{result:?}"
+        );
+
+        config.render.diagrams.mermaid = Some("sleep 30".to_string());
+        config.render.diagrams.timeout_secs = 1;
+        let started = std::time::Instant::now();
+        let result = render_svg(&config, DiagramKind::Mermaid, "graph TD\n");
+        assert!(
+            matches!(&result, Err(err) if err.ends_with("timed out after 1s")),
+            "{result:?}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_render_svg_streams_large_diagrams() {
+        let mut config = Config::default();
+        config.render.diagrams.mermaid = Some("printf '<svg>'; cat; printf '</svg>'".to_string());
+        let source = "A --> B\n".repeat(100_000);
+        let svg = render_svg(&config, DiagramKind::Mermaid, &source);
+        assert_eq!(
+            svg.map(|svg| svg.map(|svg| svg.len())),
+            Ok(Some(source.len() + 11))
+        );
+    }
+}
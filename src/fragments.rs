@@ -0,0 +1,198 @@
+//! Reusable text fragments and config variables for clause and ADR text.
+//!
+//! A fragment is a markdown file, `gov/fragments/<ID>.md`, holding shared
+//! language such as standard security boilerplate. Clause text includes one
+//! with `{{include:FRAG-SEC-001}}`, optionally passing parameters that fill
+//! the fragment's `{{name}}` placeholders:
+//! `{{include:FRAG-SEC-001 system="Payments API"}}`. Fragments do not include
+//! other fragments.
+//!
+//! Clause and ADR text, fragments included, may also reference a value from
+//! `[variables]` in the project config as `{{var.product}}`, so a spec can be
+//! white-labeled without editing it. Both are expanded when an artifact is
+//! rendered and checked by `govctl check`.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::{AdrEntry, ClauseEntry, RfcIndex};
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+static INCLUDE: LazyLock<Result<Regex, regex::Error>> = LazyLock::new(|| {
+    Regex::new(
+        r#"\{\{include:([A-Z][A-Z0-9-]*)((?:\s+[a-z_][a-z0-9_]*=(?:"[^"]*"|[^\s"}]+))*)\s*\}\}"#,
+    )
+});
+
+static PARAM: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r#"([a-z_][a-z0-9_]*)=(?:"([^"]*)"|([^\s"}]+))"#));
+
+static PLACEHOLDER: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r"\{\{([a-z_][a-z0-9_]*)\}\}"));
+
+static VARIABLE: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r"\{\{var\.([a-z_][a-z0-9_]*)\}\}"));
+
+fn pattern(
+    regex: &'static LazyLock<Result<Regex, regex::Error>>,
+) -> DiagnosticResult<&'static Regex> {
+    regex.as_ref().map_err(|err| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Invalid fragment pattern: {err}"),
+            "fragments",
+        )
+    })
+}
+
+/// `text` with every include replaced by its fragment and every variable by
+/// its value. A missing fragment is `E0216`; a placeholder the include leaves
+/// unfilled is `E0217`; a variable `[variables]` does not define is `E0514`.
+pub fn expand(config: &Config, text: &str, source: &str) -> DiagnosticResult<String> {
+    if !text.contains("{{") {
+        return Ok(text.to_string());
+    }
+    let included = include_fragments(config, text, source)?;
+    substitute_variables(config, &included, source)
+}
+
+/// `rfc` with includes and variables in its clause text expanded.
+pub fn expand_rfc(config: &Config, rfc: &RfcIndex) -> DiagnosticResult<RfcIndex> {
+    let mut expanded = rfc.clone();
+    for clause in &mut expanded.clauses {
+        *clause = expand_clause(config, &rfc.rfc.rfc_id, clause)?;
+    }
+    Ok(expanded)
+}
+
+/// `clause` with includes and variables in its text expanded.
+pub fn expand_clause(
+    config: &Config,
+    rfc_id: &str,
+    clause: &ClauseEntry,
+) -> DiagnosticResult<ClauseEntry> {
+    let mut expanded = clause.clone();
+    let source = format!("{rfc_id}:{}", clause.spec.clause_id);
+    if let Some(text) = &clause.spec.text {
+        expanded.spec.text = Some(expand(config, text, &source)?);
+    }
+    Ok(expanded)
+}
+
+/// `adr` with includes and variables in its context, decision, and
+/// consequences expanded.
+pub fn expand_adr(config: &Config, adr: &AdrEntry) -> DiagnosticResult<AdrEntry> {
+    let mut expanded = adr.clone();
+    let source = &adr.meta().id;
+    let content = &mut expanded.spec.content;
+    for text in [
+        &mut content.context,
+        &mut content.decision,
+        &mut content.consequences,
+    ] {
+        *text = expand(config, text, source)?;
+    }
+    Ok(expanded)
+}
+
+fn include_fragments(config: &Config, text: &str, source: &str) -> DiagnosticResult<String> {
+    if !text.contains("{{include:") {
+        return Ok(text.to_string());
+    }
+    let mut loaded: HashMap<String, String> = HashMap::new();
+    let mut error = None;
+    let expanded = pattern(&INCLUDE)?.replace_all(text, |caps: &Captures| {
+        let id = &caps[1];
+        match fill(config, &mut loaded, id, &caps[2], source) {
+            Ok(body) => body,
+            Err(diag) => {
+                error.get_or_insert(diag);
+                caps[0].to_string()
+            }
+        }
+    });
+    match error {
+        Some(diag) => Err(diag),
+        None => Ok(expanded.into_owned()),
+    }
+}
+
+fn fill(
+    config: &Config,
+    loaded: &mut HashMap<String, String>,
+    id: &str,
+    params: &str,
+    source: &str,
+) -> DiagnosticResult<String> {
+    let body = match loaded.get(id) {
+        Some(body) => body.clone(),
+        None => {
+            let path = config.fragments_dir().join(format!("{id}.md"));
+            let body = std::fs::read_to_string(&path).map_err(|_| {
+                Diagnostic::new(
+                    DiagnosticCode::E0216FragmentNotFound,
+                    format!(
+                        "Included fragment {id} not found at {}",
+                        config.display_path(&path).display()
+                    ),
+                    source,
+                )
+            })?;
+            let body = body.trim_end().to_string();
+            loaded.insert(id.to_string(), body.clone());
+            body
+        }
+    };
+
+    let values: HashMap<&str, &str> = pattern(&PARAM)?
+        .captures_iter(params)
+        .filter_map(|caps| {
+            let name = caps.get(1)?.as_str();
+            let value = caps.get(2).or_else(|| caps.get(3))?.as_str();
+            Some((name, value))
+        })
+        .collect();
+    let mut missing = None;
+    let filled =
+        pattern(&PLACEHOLDER)?.replace_all(&body, |caps: &Captures| match values.get(&caps[1]) {
+            Some(value) => (*value).to_string(),
+            None => {
+                missing.get_or_insert_with(|| caps[1].to_string());
+                caps[0].to_string()
+            }
+        });
+    if let Some(name) = missing {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0217FragmentParameterMissing,
+            format!(
+                "Fragment {id} needs parameter '{name}' (e.g. {{{{include:{id} {name}=\"...\"}}}})"
+            ),
+            source,
+        ));
+    }
+    Ok(filled.into_owned())
+}
+
+fn substitute_variables(config: &Config, text: &str, source: &str) -> DiagnosticResult<String> {
+    let mut undefined = None;
+    let substituted = pattern(&VARIABLE)?.replace_all(text, |caps: &Captures| {
+        match config.variables.get(&caps[1]) {
+            Some(value) => value.clone(),
+            None => {
+                undefined.get_or_insert_with(|| caps[1].to_string());
+                caps[0].to_string()
+            }
+        }
+    });
+    if let Some(name) = undefined {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0514VariableUndefined,
+            format!(
+                "Variable '{name}' is not defined (hint: add it under [variables] in gov/config.toml)"
+            ),
+            source,
+        ));
+    }
+    Ok(substituted.into_owned())
+}
@@ -0,0 +1,178 @@
+//! GitHub REST API access for linked work item issues and release drafts.
+//!
+//! The token comes from `GITHUB_TOKEN` or `GH_TOKEN`, the variables the
+//! `gh` CLI reads. Issue lookups work without one for public repositories;
+//! release drafts require it.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use reqwest::Method;
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, RequestBuilder};
+use serde::Deserialize;
+use serde_json::json;
+use std::fmt;
+
+const TOKEN_ENVS: [&str; 2] = ["GITHUB_TOKEN", "GH_TOKEN"];
+
+/// An issue or pull request, written `owner/repo#123`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueRef {
+    pub repo: String,
+    pub number: u64,
+}
+
+impl IssueRef {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (repo, number) = value.split_once('#')?;
+        let (owner, name) = repo.split_once('/')?;
+        let valid = |part: &str| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        };
+        if !valid(owner) || !valid(name) {
+            return None;
+        }
+        Some(Self {
+            repo: repo.to_string(),
+            number: number.parse().ok().filter(|number| *number > 0)?,
+        })
+    }
+}
+
+impl fmt::Display for IssueRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.repo, self.number)
+    }
+}
+
+/// `value` as an [`IssueRef`], or the error for a malformed link on `id`.
+pub fn parse_issue_ref(value: &str, id: &str) -> DiagnosticResult<IssueRef> {
+    IssueRef::parse(value).ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0820InvalidFieldValue,
+            format!("Invalid GitHub link '{value}' (expected owner/repo#123)"),
+            id,
+        )
+    })
+}
+
+#[derive(Deserialize)]
+struct Issue {
+    state: String,
+    #[serde(default)]
+    pull_request: Option<PullRequest>,
+}
+
+#[derive(Deserialize)]
+struct PullRequest {
+    #[serde(default)]
+    merged_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CreatedRelease {
+    html_url: String,
+}
+
+pub struct Github<'a> {
+    config: &'a Config,
+    client: Client,
+    token: Option<String>,
+}
+
+impl<'a> Github<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        let token = TOKEN_ENVS
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|token| !token.is_empty());
+        Self {
+            config,
+            client: crate::http::client(),
+            token,
+        }
+    }
+
+    fn api_url(&self) -> &str {
+        self.config.github.api_url.trim_end_matches('/')
+    }
+
+    /// `open`, `closed`, or `merged` for pull requests; `not found` when
+    /// the issue does not exist or is not visible with the token.
+    pub fn issue_state(&self, issue: &IssueRef) -> DiagnosticResult<String> {
+        let action = format!("read {issue} from GitHub");
+        let response = self
+            .request(
+                Method::GET,
+                &format!("/repos/{}/issues/{}", issue.repo, issue.number),
+            )
+            .send()
+            .map_err(|err| Diagnostic::io_error(&action, err, self.api_url()))?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok("not found".to_string());
+        }
+        let found: Issue = crate::http::parse_json(response, &action, self.api_url())?;
+        Ok(match found.pull_request {
+            Some(PullRequest { merged_at: Some(_) }) => "merged".to_string(),
+            _ => found.state,
+        })
+    }
+
+    /// Create a draft release of `tag` on `[github].repo` and return its URL.
+    pub fn draft_release(&self, repo: &str, tag: &str, notes: &str) -> DiagnosticResult<String> {
+        if self.token.is_none() {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0513PublishNotConfigured,
+                "GITHUB_TOKEN is not set (hint: export a token that can create releases, or GH_TOKEN)",
+                repo,
+            ));
+        }
+        let action = format!("draft GitHub release {tag}");
+        let request = self
+            .request(Method::POST, &format!("/repos/{repo}/releases"))
+            .json(&json!({
+                "tag_name": tag,
+                "name": tag,
+                "body": notes,
+                "draft": true,
+            }));
+        let created: CreatedRelease = crate::http::send_json(request, &action, self.api_url())?;
+        Ok(created.html_url)
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}{path}", self.api_url()))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", concat!("govctl/", env!("CARGO_PKG_VERSION")));
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_owner_repo_number_links() {
+        assert_eq!(
+            IssueRef::parse("acme/api.rs#42"),
+            Some(IssueRef {
+                repo: "acme/api.rs".to_string(),
+                number: 42
+            })
+        );
+        assert_eq!(IssueRef::parse("acme/api#0"), None);
+        assert_eq!(IssueRef::parse("acme#42"), None);
+        assert_eq!(IssueRef::parse("acme/api/x#42"), None);
+        assert_eq!(IssueRef::parse("acme/api#42x"), None);
+        assert_eq!(IssueRef::parse("/api#42"), None);
+    }
+}
@@ -0,0 +1,157 @@
+//! User commands configured under `[hooks]`.
+//!
+//! Each hook runs with bash from the project root. The event is passed as a
+//! JSON object on stdin and as `GOVCTL_*` environment variables; the hook's
+//! stdout is sent to stderr so it never mixes with `--output json` results.
+
+use crate::config::{Config, HookConfig, HookFailure, HooksConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Diagnostics};
+use crate::ui;
+use crate::write::WriteOp;
+use serde::Serialize;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Commands hooks can run around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    Move,
+    Finalize,
+    Accept,
+    Release,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Pre,
+    Post,
+}
+
+/// What the triggering command acts on.
+#[derive(Debug, Serialize)]
+pub struct HookContext {
+    pub project: String,
+    /// Artifact ID; the version for releases.
+    pub id: String,
+    /// Status the command moves the artifact to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Payload<'a> {
+    hook: &'a str,
+    #[serde(flatten)]
+    context: &'a HookContext,
+}
+
+impl HooksConfig {
+    pub fn commands(&self, trigger: Trigger, phase: Phase) -> &[HookConfig] {
+        match (phase, trigger) {
+            (Phase::Pre, Trigger::Move) => &self.pre_move,
+            (Phase::Post, Trigger::Move) => &self.post_move,
+            (Phase::Pre, Trigger::Finalize) => &self.pre_finalize,
+            (Phase::Post, Trigger::Finalize) => &self.post_finalize,
+            (Phase::Pre, Trigger::Accept) => &self.pre_accept,
+            (Phase::Post, Trigger::Accept) => &self.post_accept,
+            (Phase::Pre, Trigger::Release) => &self.pre_release,
+            (Phase::Post, Trigger::Release) => &self.post_release,
+        }
+    }
+}
+
+fn hook_name(trigger: Trigger, phase: Phase) -> String {
+    let phase = match phase {
+        Phase::Pre => "pre",
+        Phase::Post => "post",
+    };
+    let trigger = match trigger {
+        Trigger::Move => "move",
+        Trigger::Finalize => "finalize",
+        Trigger::Accept => "accept",
+        Trigger::Release => "release",
+    };
+    format!("{phase}_{trigger}")
+}
+
+/// Run the hooks configured for `trigger` at `phase`, in order.
+///
+/// A failing `block` hook stops at once with `E1008`; failing `warn` hooks
+/// are returned as `W0126` warnings.
+pub fn run(
+    config: &Config,
+    trigger: Trigger,
+    phase: Phase,
+    context: &HookContext,
+    op: WriteOp,
+) -> DiagnosticResult<Diagnostics> {
+    let name = hook_name(trigger, phase);
+    let mut warnings = Vec::new();
+    for hook in config.hooks.commands(trigger, phase) {
+        if op.is_preview() {
+            ui::dry_run_hook(&name, &hook.command);
+            continue;
+        }
+        let Err(err) = spawn(config, hook, &name, context) else {
+            continue;
+        };
+        let message = format!("{name} hook `{}` failed: {err}", hook.command);
+        match hook.on_failure {
+            HookFailure::Block => {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E1008HookFailed,
+                    message,
+                    context.id.as_str(),
+                ));
+            }
+            HookFailure::Warn => warnings.push(Diagnostic::new(
+                DiagnosticCode::W0126HookFailed,
+                message,
+                context.id.as_str(),
+            )),
+        }
+    }
+    Ok(warnings)
+}
+
+fn spawn(
+    config: &Config,
+    hook: &HookConfig,
+    name: &str,
+    context: &HookContext,
+) -> Result<(), String> {
+    let payload = serde_json::to_string(&Payload {
+        hook: name,
+        context,
+    })
+    .map_err(|err| err.to_string())?;
+    let mut command = Command::new("/bin/bash");
+    command
+        .args(["-lc", &hook.command])
+        .current_dir(config.project_root())
+        .env("GOVCTL_HOOK", name)
+        .env("GOVCTL_PROJECT", &context.project)
+        .env("GOVCTL_ID", &context.id)
+        .stdin(Stdio::piped())
+        .stdout(std::io::stderr());
+    if let Some(status) = &context.status {
+        command.env("GOVCTL_STATUS", status);
+    }
+    // The gov lock is held while hooks run, so a hung hook must not hold it
+    // forever.
+    let timeout = Duration::from_secs(hook.timeout_secs);
+    let status = crate::verification::run_with_timeout(command, timeout, |child| {
+        if let Some(mut stdin) = child.stdin.take() {
+            // Hooks that ignore stdin may exit before reading it.
+            let _ = stdin.write_all(payload.as_bytes());
+        }
+    })
+    .map_err(|err| err.to_string())?
+    .ok_or_else(|| format!("timed out after {}s", hook.timeout_secs))?;
+    match status.code() {
+        Some(0) => Ok(()),
+        Some(code) => Err(format!("exit code {code}")),
+        None => Err("terminated by signal".to_string()),
+    }
+}
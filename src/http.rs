@@ -0,0 +1,61 @@
+//! HTTP plumbing shared by the GitHub, Jira, and Confluence clients.
+
+use crate::diagnostic::{Diagnostic, DiagnosticResult};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+/// How long one request may take, so an unreachable server cannot hang a
+/// command that holds the gov lock.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Characters of an error response body kept in the diagnostic.
+const ERROR_DETAIL_CHARS: usize = 300;
+
+/// A client whose requests time out after [`TIMEOUT`].
+pub fn client() -> Client {
+    // `build` fails only where `Client::new` would panic: no TLS backend.
+    Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .unwrap_or_default()
+}
+
+/// Send `request` and parse its JSON response; failures are reported as
+/// `action` against `target`.
+pub fn send_json<T: DeserializeOwned>(
+    request: RequestBuilder,
+    action: &str,
+    target: &str,
+) -> DiagnosticResult<T> {
+    let response = request
+        .send()
+        .map_err(|err| Diagnostic::io_error(action, err, target))?;
+    parse_json(response, action, target)
+}
+
+/// Parse a successful JSON response. An error status is reported with the
+/// start of the response body, which usually says what was wrong.
+pub fn parse_json<T: DeserializeOwned>(
+    response: Response,
+    action: &str,
+    target: &str,
+) -> DiagnosticResult<T> {
+    let status = response.status();
+    if !status.is_success() {
+        let detail = response.text().unwrap_or_default();
+        let detail = detail.trim();
+        let err = if detail.is_empty() {
+            status.to_string()
+        } else {
+            format!(
+                "{status}: {}",
+                detail.chars().take(ERROR_DETAIL_CHARS).collect::<String>()
+            )
+        };
+        return Err(Diagnostic::io_error(action, err, target));
+    }
+    response
+        .json()
+        .map_err(|err| Diagnostic::io_error(action, err, target))
+}
@@ -0,0 +1,109 @@
+//! Actor identity: who a command acts as.
+//!
+//! An actor is `human:<name>` or `agent:<name>`; a bare name is a human.
+//! It comes from `--as`, then `GOVCTL_ACTOR`, then `[identity] actor`, then
+//! the git `user.name`, and is recorded as `created_by` on new artifacts and
+//! on each operation history and audit log entry, so edits made by agents can
+//! be told apart from edits made by people. The name of an explicitly given
+//! actor is also the identity `rfc approve` and `adr approve` record.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+
+/// Environment variable naming the actor when `--as` is not given.
+pub const ACTOR_ENV: &str = "GOVCTL_ACTOR";
+
+const KINDS: &[&str] = &["human", "agent"];
+
+/// The actor a command runs as, resolved once per command.
+#[derive(Debug, Clone)]
+pub struct Actor {
+    /// `kind:name`.
+    value: String,
+    /// Given by `--as`, `GOVCTL_ACTOR`, or config rather than taken from git.
+    explicit: bool,
+}
+
+impl Actor {
+    /// Resolve the actor from `--as` (`flag`), the environment, `config`, or
+    /// git; `None` when none of them names one.
+    pub fn resolve(flag: Option<&str>, config: &Config) -> DiagnosticResult<Option<Self>> {
+        let explicit = if let Some(flag) = flag {
+            Some(parse(flag, "--as")?)
+        } else if let Some(env) = std::env::var(ACTOR_ENV)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+        {
+            Some(parse(&env, ACTOR_ENV)?)
+        } else if let Some(configured) = &config.identity.actor {
+            Some(parse(configured, "identity.actor")?)
+        } else {
+            None
+        };
+        Ok(match explicit {
+            Some(value) => Some(Self {
+                value,
+                explicit: true,
+            }),
+            None => crate::config::git_config_value("user.name").map(|name| Self {
+                value: format!("human:{name}"),
+                explicit: false,
+            }),
+        })
+    }
+
+    /// `kind:name`, as recorded in `created_by` and log entries.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Name of an explicitly given actor, without its kind: `@alice` for
+    /// `--as @alice`. A git fallback does not count as approving anything.
+    pub fn approver(&self) -> Option<&str> {
+        self.explicit
+            .then(|| self.value.split_once(':'))
+            .flatten()
+            .map(|(_, name)| name)
+    }
+}
+
+/// `created_by` for an artifact `actor` creates.
+pub fn created_by(actor: Option<&Actor>) -> Option<String> {
+    actor.map(|actor| actor.as_str().to_string())
+}
+
+/// Normalize `raw` to `kind:name`.
+fn parse(raw: &str, source: &str) -> DiagnosticResult<String> {
+    let raw = raw.trim();
+    let (kind, name) = match raw.split_once(':') {
+        Some((kind, name)) => (kind.trim(), name.trim()),
+        None => ("human", raw),
+    };
+    if !KINDS.contains(&kind) || name.is_empty() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0831InvalidActor,
+            format!("Invalid actor '{raw}': expected human:<name>, agent:<name>, or a bare name"),
+            source,
+        ));
+    }
+    Ok(format!("{kind}:{name}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn test_parse_actor_forms() {
+        assert_eq!(
+            parse("agent:claude", "--as").ok().as_deref(),
+            Some("agent:claude")
+        );
+        assert_eq!(
+            parse(" Jane Doe ", "--as").ok().as_deref(),
+            Some("human:Jane Doe")
+        );
+        assert!(parse("bot:ci", "--as").is_err());
+        assert!(parse("agent:", "--as").is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! Jira REST API access for work items linked through `jira_key`.
+//!
+//! The token comes from `JIRA_TOKEN`; with `JIRA_USER` set it is sent as
+//! basic auth (Atlassian Cloud), otherwise as a bearer token (Data Center).
+
+use crate::config::JiraConfig;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use reqwest::Url;
+use reqwest::blocking::{Client, RequestBuilder};
+use serde::Deserialize;
+
+const TOKEN_ENV: &str = "JIRA_TOKEN";
+const USER_ENV: &str = "JIRA_USER";
+
+/// Whether `value` is an issue key such as `PROJ-123`.
+pub fn is_issue_key(value: &str) -> bool {
+    let Some((project, number)) = value.rsplit_once('-') else {
+        return false;
+    };
+    let mut chars = project.chars();
+    chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && !chars.as_str().is_empty()
+        && chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && !number.starts_with('0')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Reject `value` unless it is an issue key.
+pub fn validate_issue_key(value: &str, id: &str) -> DiagnosticResult<()> {
+    if is_issue_key(value) {
+        Ok(())
+    } else {
+        Err(Diagnostic::new(
+            DiagnosticCode::E0820InvalidFieldValue,
+            format!("Invalid Jira issue key '{value}' (expected PROJ-123)"),
+            id,
+        ))
+    }
+}
+
+/// Browser URL of `key` on the configured site.
+pub fn issue_url(settings: &JiraConfig, key: &str) -> String {
+    format!("{}/browse/{key}", settings.base_url.trim_end_matches('/'))
+}
+
+/// Jira's workflow-independent grouping of statuses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusCategory {
+    ToDo,
+    InProgress,
+    Done,
+    /// A category key this version does not know; never reported as divergent.
+    Other,
+}
+
+/// An issue's current status.
+pub struct IssueStatus {
+    /// Workflow status name, e.g. `In Review`.
+    pub name: String,
+    pub category: StatusCategory,
+}
+
+#[derive(Deserialize)]
+struct Issue {
+    fields: Fields,
+}
+
+#[derive(Deserialize)]
+struct Fields {
+    status: Status,
+}
+
+#[derive(Deserialize)]
+struct Status {
+    name: String,
+    #[serde(rename = "statusCategory")]
+    category: Category,
+}
+
+#[derive(Deserialize)]
+struct Category {
+    key: String,
+}
+
+/// How requests authenticate; the token itself stays in the environment.
+enum Auth {
+    Basic { user: String, token: String },
+    Bearer { token: String },
+}
+
+pub struct Jira<'a> {
+    settings: &'a JiraConfig,
+    client: Client,
+    auth: Auth,
+}
+
+impl<'a> Jira<'a> {
+    pub fn from_env(settings: &'a JiraConfig) -> DiagnosticResult<Self> {
+        let token = std::env::var(TOKEN_ENV)
+            .ok()
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| {
+                Diagnostic::new(
+                    DiagnosticCode::E0513PublishNotConfigured,
+                    format!("{TOKEN_ENV} is not set (hint: export a Jira API token)"),
+                    settings.base_url.as_str(),
+                )
+            })?;
+        let auth = match std::env::var(USER_ENV) {
+            Ok(user) if !user.is_empty() => Auth::Basic { user, token },
+            _ => Auth::Bearer { token },
+        };
+        Ok(Self {
+            settings,
+            client: crate::http::client(),
+            auth,
+        })
+    }
+
+    fn base_url(&self) -> &str {
+        self.settings.base_url.trim_end_matches('/')
+    }
+
+    pub fn issue_status(&self, key: &str) -> DiagnosticResult<IssueStatus> {
+        let action = format!("read {key} from Jira");
+        let issue: Issue = crate::http::send_json(
+            self.request(&format!("/rest/api/2/issue/{key}"))?,
+            &action,
+            self.base_url(),
+        )?;
+        let status = issue.fields.status;
+        Ok(IssueStatus {
+            category: match status.category.key.as_str() {
+                "new" => StatusCategory::ToDo,
+                "indeterminate" => StatusCategory::InProgress,
+                "done" => StatusCategory::Done,
+                _ => StatusCategory::Other,
+            },
+            name: status.name,
+        })
+    }
+
+    fn request(&self, path: &str) -> DiagnosticResult<RequestBuilder> {
+        let mut url = Url::parse(&format!("{}{path}", self.base_url())).map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0501ConfigInvalid,
+                format!("Invalid jira.base_url: {err}"),
+                self.settings.base_url.as_str(),
+            )
+        })?;
+        url.query_pairs_mut().append_pair("fields", "status");
+        let request = self.client.get(url).header("Accept", "application/json");
+        Ok(match &self.auth {
+            Auth::Basic { user, token } => request.basic_auth(user, Some(token)),
+            Auth::Bearer { token } => request.bearer_auth(token),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_issue_keys() {
+        assert!(is_issue_key("PROJ-123"));
+        assert!(is_issue_key("AB2_X-7"));
+        assert!(!is_issue_key("P-1"));
+        assert!(!is_issue_key("proj-1"));
+        assert!(!is_issue_key("PROJ-0"));
+        assert!(!is_issue_key("PROJ-"));
+        assert!(!is_issue_key("PROJ123"));
+        assert!(!is_issue_key("PROJ-12a"));
+    }
+}
@@ -0,0 +1,300 @@
+//! govctl: Project governance CLI for RFC, ADR, and Work Item management.
+//!
+//! The crate is a library so benchmarks can call into it; `src/main.rs` only
+//! runs [`main`]. Nothing here is a stable API.
+
+use clap::Parser;
+use std::process::ExitCode;
+
+mod aliases;
+mod artifact_catalog;
+mod artifact_index;
+#[doc(hidden)]
+pub mod bench;
+mod cli;
+mod cmd;
+mod command_router;
+mod config;
+mod diagnostic;
+mod diagrams;
+mod fragments;
+mod github;
+mod hooks;
+mod http;
+mod identity;
+mod jira;
+mod load;
+mod lock;
+mod loop_planner;
+mod loop_state;
+mod model;
+mod notify;
+mod parse;
+mod remotes;
+mod render;
+mod resource_plan;
+mod scan;
+mod schema;
+mod secrets;
+mod signature;
+mod status_counts;
+mod terminal_md;
+mod theme;
+mod ui;
+mod validate;
+mod verification;
+mod workspace;
+mod write;
+
+#[cfg(feature = "tui")]
+mod tui;
+
+// Re-export CLI types so modules can use `crate::TickStatus`, etc.
+pub(crate) use cli::*;
+
+use aliases::Aliases;
+use config::Config;
+use diagnostic::{Diagnostic, DiagnosticLevel, DiagnosticResult, Diagnostics, FailureClass};
+
+/// Parse the command line, run the command, and report its diagnostics.
+pub fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let persona = Persona::resolve(cli.persona);
+    ui::init_color(cli.color, persona);
+    ui::init_verbosity(ui::Verbosity::from_flags(cli.quiet, cli.verbose));
+    ui::init_timings(cli.timings);
+    let started = std::time::Instant::now();
+    let (result, reported) = if cli.output == Some(OutputArg::Json) {
+        run_json(&cli, persona)
+    } else {
+        (
+            run(&cli, persona, write::WriteOp::from_dry_run(cli.dry_run)),
+            false,
+        )
+    };
+
+    let code = match result {
+        Ok(diags) => {
+            let has_warnings = diags.iter().any(|d| d.level == DiagnosticLevel::Warning);
+
+            if !reported {
+                for diag in &diags {
+                    ui::diagnostic(diag, persona);
+                }
+            }
+
+            if let Some(class) = report_failure_class(&diags) {
+                ExitCode::from(class.exit_code())
+            } else if has_warnings
+                && matches!(
+                    cli.command,
+                    Commands::Check {
+                        deny_warnings: true,
+                        ..
+                    } | Commands::Check {
+                        has_active: true,
+                        ..
+                    } | Commands::Config {
+                        command: ConfigCommand::Check {
+                            deny_warnings: true
+                        }
+                    } | Commands::Scan {
+                        command: ScanCommand::Coverage {
+                            deny_warnings: true,
+                            ..
+                        }
+                    }
+                )
+            {
+                ExitCode::from(FailureClass::Validation.exit_code())
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Err(diag) => {
+            if !reported {
+                ui::diagnostic(&diag, persona);
+            }
+            ExitCode::from(diag.code.failure_class().exit_code())
+        }
+    };
+    ui::report_timings(started.elapsed());
+    code
+}
+
+/// Exit class for a command that completed with error diagnostics.
+///
+/// Returned diagnostics are findings such as `check` reports, so they count
+/// as validation failures even when they reuse a not-found or transition
+/// code; only reports made entirely of general errors exit 1.
+fn report_failure_class(diags: &[Diagnostic]) -> Option<FailureClass> {
+    let mut errors = diags
+        .iter()
+        .filter(|diag| diag.level == DiagnosticLevel::Error)
+        .peekable();
+    errors.peek()?;
+    if errors.all(|diag| diag.code.failure_class() == FailureClass::General) {
+        Some(FailureClass::General)
+    } else {
+        Some(FailureClass::Validation)
+    }
+}
+
+/// `--output json`: stdout carries one JSON document. Commands that print their own
+/// result emit it as JSON; everything else, and any failure, is reported in a
+/// result envelope. Returns whether diagnostics went into an envelope.
+fn run_json(cli: &Cli, persona: Persona) -> (DiagnosticResult<Diagnostics>, bool) {
+    let (result, changes) =
+        write::with_write_log(write::WriteOp::from_dry_run(cli.dry_run), |op| {
+            run(cli, persona, op)
+        });
+    let config = config_path(cli)
+        .ok()
+        .and_then(|path| Config::load(path.as_deref()).ok());
+    let prefixes = config
+        .as_ref()
+        .map(|config| config.prefixes.clone())
+        .unwrap_or_default();
+    let aliases = config.as_ref().map(Aliases::load).unwrap_or_default();
+    let prints_own_result =
+        command_router::CommandPlan::from_parsed(&cli.command, cli.dry_run, &prefixes, &aliases)
+            .is_ok_and(|plan| plan.prints_own_result());
+    if prints_own_result && result.is_ok() {
+        return (result, false);
+    }
+
+    let diagnostics = match &result {
+        Ok(diags) => diags.clone(),
+        Err(diag) => vec![diag.clone()],
+    };
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    cmd::result_envelope::ResultEnvelope::new(
+        config.as_ref(),
+        cmd::history::command_line(&args),
+        cli.dry_run,
+        changes,
+        &diagnostics,
+        persona,
+    )
+    .print();
+    (result, true)
+}
+
+/// Config file chosen by `-C/--config` or `-p/--project`, if any.
+fn config_path(cli: &Cli) -> DiagnosticResult<Option<std::path::PathBuf>> {
+    workspace::resolve_config_arg(cli.config.as_deref(), cli.project.as_deref())
+}
+
+fn run(cli: &Cli, persona: Persona, op: write::WriteOp) -> DiagnosticResult<Diagnostics> {
+    // Planning classifies IDs by the project's `[prefixes]` and expands its
+    // `[aliases]`; `config check` and `config show` still run when the config
+    // fails to load.
+    let loaded = Config::load(config_path(cli)?.as_deref());
+    let prefixes = loaded
+        .as_ref()
+        .map(|config| config.prefixes.clone())
+        .unwrap_or_default();
+    let aliases = loaded.as_ref().map(Aliases::load).unwrap_or_default();
+
+    // Convert parsed CLI command to canonical form
+    let mut plan =
+        command_router::CommandPlan::from_parsed(&cli.command, cli.dry_run, &prefixes, &aliases)?;
+    if let Some(format) = cli.output {
+        plan.select_output(format)?;
+    }
+    if cmd::confirmation::assume_yes(cli.yes) {
+        plan.assume_yes();
+    }
+    plan.persona = persona;
+
+    // `config check` must diagnose configs that fail to load, so it reads the
+    // file itself instead of going through `Config::load`; `config show`
+    // reads the layers to report where each setting comes from.
+    match &plan.op {
+        command_router::Op::Builtin(command_router::BuiltinOp::ConfigCheck) => {
+            return cmd::config_check::check_config(&Config::resolve_path(
+                config_path(cli)?.as_deref(),
+            ));
+        }
+        command_router::Op::Builtin(command_router::BuiltinOp::ConfigShow { resolved, output }) => {
+            return cmd::config_show::show_config(
+                &Config::resolve_path(config_path(cli)?.as_deref()),
+                *resolved,
+                *output,
+            );
+        }
+        _ => {}
+    }
+
+    let mut config = loaded?;
+    let actor = identity::Actor::resolve(cli.actor.as_deref(), &config)?;
+    let actor = actor.as_ref();
+    if let Some(secs) = cli.lock_wait {
+        config.concurrency.lock_timeout_secs = secs;
+    }
+    tracing::debug!("gov root {}", config.gov_root.display());
+    let op = op.with_fsync(config.concurrency.fsync);
+
+    let lock_disposition = plan.lock_disposition();
+
+    // Acquire gov-root exclusive lock for mutating operations (RFC-0004)
+    let _guard = if matches!(
+        lock_disposition,
+        command_router::LockDisposition::GovRootExclusive
+    ) {
+        if matches!(
+            plan.op,
+            command_router::Op::Builtin(command_router::BuiltinOp::Init { .. })
+        ) {
+            let gov_root = config.gov_root.as_path();
+            if !op.is_preview() && !gov_root.exists() {
+                std::fs::create_dir_all(gov_root).map_err(|e| {
+                    Diagnostic::io_error("create gov root", e, gov_root.display().to_string())
+                })?;
+            }
+        }
+        if op.is_preview()
+            && matches!(
+                plan.op,
+                command_router::Op::Builtin(command_router::BuiltinOp::Init { .. })
+            )
+        {
+            None
+        } else {
+            Some(lock::acquire_gov_lock(&config)?)
+        }
+    } else {
+        None
+    };
+    plan.resolve_ids(&config)?;
+
+    // Execute via canonical command pattern (single execution path). Values
+    // bound for sealed ADR fields never reach the history or audit log.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = cmd::history::command_line(&cmd::history::redact_args(
+        &args,
+        &plan.sensitive_values(&config),
+    ));
+    let execute = |op: write::WriteOp| {
+        if plan.records_history() && !op.is_preview() {
+            cmd::history::record(&config, &command, actor, op, |op| {
+                plan.execute(&config, actor, op)
+            })
+        } else {
+            plan.execute(&config, actor, op)
+        }
+    };
+    // `[audit]` logs every write command, failed ones included.
+    let audited = config.audit.enabled
+        && !op.is_preview()
+        && matches!(
+            lock_disposition,
+            command_router::LockDisposition::GovRootExclusive
+        );
+    let diagnostics = if audited {
+        cmd::audit::record(&config, &command, actor, op, execute)?
+    } else {
+        execute(op)?
+    };
+    Ok(plan.after_command(&config, op, diagnostics))
+}
@@ -5,9 +5,12 @@ use crate::diagnostic::{Diagnostic, DiagnosticCode};
 mod project;
 mod rfc;
 
-pub use project::{load_project, load_project_with_warnings};
+pub use project::{load_project, load_project_metadata, load_project_with_warnings};
 pub(crate) use rfc::split_clause_id;
-pub use rfc::{find_clause_toml, find_rfc_toml, load_rfc, load_rfcs, reject_legacy_json_storage};
+pub use rfc::{
+    find_clause_toml, find_rfc_toml, load_rfc, load_rfcs, reject_legacy_json_storage,
+    with_project_clause_text,
+};
 
 /// Result of loading a project: index plus any warnings encountered
 pub struct ProjectLoadResult {
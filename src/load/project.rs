@@ -1,5 +1,5 @@
 use super::ProjectLoadResult;
-use super::rfc::load_rfcs;
+use super::rfc::{ClauseText, load_rfcs_with};
 use crate::config::Config;
 use crate::diagnostic::Diagnostic;
 use crate::model::ProjectIndex;
@@ -9,13 +9,31 @@ pub fn load_project(config: &Config) -> Result<ProjectIndex, Vec<Diagnostic>> {
     load_project_with_warnings(config).map(|r| r.index)
 }
 
+/// Load the project index without clause bodies.
+///
+/// Every artifact is still read and validated, but each RFC's clause text is
+/// dropped once its amendment flag is computed, so commands that only need
+/// metadata (`list`, `status`) stay flat on projects with thousands of
+/// clauses. Clause text is `None` in this index; bodies are read back on
+/// demand with [`super::with_project_clause_text`].
+pub fn load_project_metadata(config: &Config) -> Result<ProjectIndex, Vec<Diagnostic>> {
+    load_project_inner(config, ClauseText::Drop).map(|r| r.index)
+}
+
 /// Load full project index, returning both the index and any parse warnings
 pub fn load_project_with_warnings(config: &Config) -> Result<ProjectLoadResult, Vec<Diagnostic>> {
+    load_project_inner(config, ClauseText::Keep)
+}
+
+fn load_project_inner(
+    config: &Config,
+    text: ClauseText,
+) -> Result<ProjectLoadResult, Vec<Diagnostic>> {
     let mut index = ProjectIndex::default();
     let mut errors = Vec::new();
     let mut warnings = Vec::new();
 
-    match load_rfcs(config) {
+    match load_rfcs_with(config, text) {
         Ok(rfcs) => index.rfcs = rfcs,
         Err(e) => errors.push(e.into()),
     }
@@ -42,3 +60,61 @@ pub fn load_project_with_warnings(config: &Config) -> Result<ProjectLoadResult,
         Err(errors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::rfc::with_clause_text;
+
+    #[test]
+    fn metadata_load_reads_clause_text_back_on_demand() -> Result<(), Box<dyn std::error::Error>> {
+        let config = Config {
+            gov_root: std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("gov"),
+            ..Default::default()
+        };
+        let full = load_project(&config).map_err(|diags| format!("{diags:?}"))?;
+        let metadata = load_project_metadata(&config).map_err(|diags| format!("{diags:?}"))?;
+
+        assert_eq!(full.rfcs.len(), metadata.rfcs.len());
+        assert!(
+            metadata
+                .iter_clauses()
+                .all(|(_, clause)| clause.spec.text.is_none())
+        );
+
+        for (full_rfc, bare_rfc) in full.rfcs.iter().zip(&metadata.rfcs) {
+            let restored = with_clause_text(&config, bare_rfc).map_err(Diagnostic::from)?;
+            let texts = |rfc: &crate::model::RfcIndex| {
+                rfc.clauses
+                    .iter()
+                    .map(|clause| clause.spec.text.clone())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(texts(full_rfc), texts(&restored));
+            assert_eq!(
+                bare_rfc.amended,
+                Some(crate::signature::is_rfc_amended(full_rfc))
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn metadata_index_validates_like_the_full_index() -> Result<(), String> {
+        let config = Config {
+            gov_root: std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("gov"),
+            ..Default::default()
+        };
+        let full = load_project(&config).map_err(|diags| format!("{diags:?}"))?;
+        let metadata = load_project_metadata(&config).map_err(|diags| format!("{diags:?}"))?;
+        let codes = |index: &ProjectIndex| {
+            crate::validate::validate_project(index, &config)
+                .diagnostics
+                .iter()
+                .map(|diagnostic| format!("{} {}", diagnostic.code.code(), diagnostic.message))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(codes(&full), codes(&metadata));
+        Ok(())
+    }
+}
@@ -1,13 +1,32 @@
 use super::LoadError;
 use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
-use crate::model::{ClauseEntry, ClauseWire, RfcIndex, RfcSpec, RfcWire};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Span};
+use crate::model::{ClauseEntry, ClauseWire, ProjectIndex, RfcIndex, RfcSpec, RfcWire};
 use crate::schema::{ArtifactSchema, validate_toml_value};
 use serde::de::DeserializeOwned;
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 
+/// Whether loaded clauses keep their text or only their metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ClauseText {
+    Keep,
+    Drop,
+}
+
 /// Load all RFCs from the gov/rfc directory
 pub fn load_rfcs(config: &Config) -> Result<Vec<RfcIndex>, LoadError> {
+    load_rfcs_with(config, ClauseText::Keep)
+}
+
+pub(super) fn load_rfcs_with(
+    config: &Config,
+    text: ClauseText,
+) -> Result<Vec<RfcIndex>, LoadError> {
+    crate::ui::timed("load RFCs", || read_rfcs(config, text))
+}
+
+fn read_rfcs(config: &Config, text: ClauseText) -> Result<Vec<RfcIndex>, LoadError> {
     let rfcs_dir = config.rfc_dir();
     if !rfcs_dir.exists() {
         return Ok(vec![]);
@@ -34,7 +53,7 @@ pub fn load_rfcs(config: &Config) -> Result<Vec<RfcIndex>, LoadError> {
         if path.is_dir()
             && let Some(rfc_path) = find_rfc_in_dir(&path)
         {
-            let rfc_index = load_rfc(config, &rfc_path)?;
+            let rfc_index = load_rfc_with(config, &rfc_path, text)?;
             rfcs.push(rfc_index);
         }
     }
@@ -46,6 +65,14 @@ pub fn load_rfcs(config: &Config) -> Result<Vec<RfcIndex>, LoadError> {
 
 /// Load a single RFC and its clauses
 pub fn load_rfc(config: &Config, rfc_path: &Path) -> Result<RfcIndex, LoadError> {
+    load_rfc_with(config, rfc_path, ClauseText::Keep)
+}
+
+fn load_rfc_with(
+    config: &Config,
+    rfc_path: &Path,
+    text: ClauseText,
+) -> Result<RfcIndex, LoadError> {
     if rfc_path.extension().and_then(|ext| ext.to_str()) == Some("json") {
         return Err(LoadError::Diagnostic(legacy_json_diagnostic(
             config, rfc_path,
@@ -82,17 +109,24 @@ pub fn load_rfc(config: &Config, rfc_path: &Path) -> Result<RfcIndex, LoadError>
 
             let full_path = rfc_dir.join(clause_path);
             if full_path.exists() {
-                let clause = super::load_clause(config, &full_path)?;
-                clauses.push(clause);
+                clauses.push(super::load_clause(config, &full_path)?);
             }
         }
     }
 
-    Ok(RfcIndex {
+    let mut index = RfcIndex {
         rfc,
         clauses,
         path: rfc_path.to_path_buf(),
-    })
+        amended: None,
+    };
+    if text == ClauseText::Drop {
+        index.amended = Some(crate::signature::is_rfc_amended(&index));
+        for clause in &mut index.clauses {
+            clause.spec.text = None;
+        }
+    }
+    Ok(index)
 }
 
 /// Load a single clause
@@ -119,6 +153,43 @@ pub(super) fn load_clause_file(config: &Config, path: &Path) -> Result<ClauseEnt
     })
 }
 
+/// `rfc` with every clause body present. Borrows when nothing is missing;
+/// otherwise the bodies are read for this RFC alone.
+pub fn with_clause_text<'a>(
+    config: &Config,
+    rfc: &'a RfcIndex,
+) -> Result<Cow<'a, RfcIndex>, LoadError> {
+    if rfc.clauses.iter().all(|clause| clause.spec.text.is_some()) {
+        return Ok(Cow::Borrowed(rfc));
+    }
+    let mut full = rfc.clone();
+    for clause in full.clauses.iter_mut() {
+        if clause.spec.text.is_none() {
+            clause.spec.text = load_clause_file(config, &clause.path)?.spec.text;
+        }
+    }
+    Ok(Cow::Owned(full))
+}
+
+/// `index` with every clause body present, for checks that read clause text.
+/// Borrows when the index was fully loaded.
+pub fn with_project_clause_text<'a>(
+    config: &Config,
+    index: &'a ProjectIndex,
+) -> Result<Cow<'a, ProjectIndex>, LoadError> {
+    if index
+        .iter_clauses()
+        .all(|(_, clause)| clause.spec.text.is_some())
+    {
+        return Ok(Cow::Borrowed(index));
+    }
+    let mut full = index.clone();
+    for rfc in &mut full.rfcs {
+        *rfc = with_clause_text(config, rfc)?.into_owned();
+    }
+    Ok(Cow::Owned(full))
+}
+
 pub fn find_rfc_toml(config: &Config, rfc_id: &str) -> Option<PathBuf> {
     let path = config.rfc_source_path(rfc_id, "toml");
     path.exists().then_some(path)
@@ -196,6 +267,7 @@ fn legacy_json_diagnostic(config: &Config, path: &Path) -> Diagnostic {
 }
 
 fn read_source_file(path: &Path, action: &'static str) -> Result<String, LoadError> {
+    tracing::trace!("read {}", path.display());
     std::fs::read_to_string(path).map_err(|e| LoadError::Io {
         file: path.display().to_string(),
         action,
@@ -238,16 +310,31 @@ fn load_toml_wire<Wire>(
 where
     Wire: DeserializeOwned,
 {
-    let mut raw: toml::Value = toml::from_str(content).map_err(|e| LoadError::Json {
-        file: path.display().to_string(),
-        message: e.to_string(),
+    let located = |err: LoadError, span: Option<Span>| {
+        LoadError::Diagnostic(Diagnostic::from(err).with_span(span))
+    };
+    let mut raw: toml::Value = toml::from_str(content).map_err(|e| {
+        let span = Span::of_toml_error(content, &e);
+        let err = LoadError::Json {
+            file: path.display().to_string(),
+            message: e.to_string(),
+        };
+        located(err, span)
     })?;
     (spec.normalize_toml)(&mut raw);
-    validate_toml_value(spec.schema, config, path, &raw)
-        .map_err(|e| (spec.schema_error)(path.display().to_string(), e.message))?;
-    raw.try_into().map_err(|e| LoadError::Json {
-        file: path.display().to_string(),
-        message: e.to_string(),
+    validate_toml_value(spec.schema, config, path, content, &raw).map_err(|e| {
+        located(
+            (spec.schema_error)(path.display().to_string(), e.message),
+            e.span,
+        )
+    })?;
+    raw.try_into().map_err(|e| {
+        let span = Span::of_deserialize_error::<Wire>(content, &e);
+        let err = LoadError::Json {
+            file: path.display().to_string(),
+            message: e.to_string(),
+        };
+        located(err, span)
     })
 }
 
@@ -3,12 +3,26 @@
 //! Implements [[RFC-0004]] concurrent write safety: at most one write command
 //! holds exclusive access at any time. Lock is released when the guard is dropped
 //! (e.g. on process exit or when the command finishes).
+//!
+//! The holder records its PID, host, and command line in the lock file so a
+//! waiting command can say who it is waiting for.
+//!
+//! A held lock is never removed: the OS releases it when its holder exits, so
+//! a crashed command leaves at most a stale record, which the next holder
+//! overwrites. A record naming a process that no longer exists on this host
+//! while the lock is still held (another PID namespace, or an older govctl
+//! that does not write records) is only reported, as a hint in the timeout
+//! error.
 
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::ui;
 use fs2::FileExt;
-use std::fs::OpenOptions;
-use std::io;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
 use std::thread;
 use std::time::{Duration, Instant};
 
@@ -20,13 +34,63 @@ const POLL_INTERVAL_MS: u64 = 100;
 
 /// Guard that holds the exclusive lock; releasing on drop.
 pub struct GovLockGuard {
-    _file: std::fs::File,
+    file: File,
+}
+
+impl Drop for GovLockGuard {
+    fn drop(&mut self) {
+        // Clear the holder record so it cannot outlive the lock.
+        let _ = self.file.set_len(0);
+    }
+}
+
+/// Process recorded as holding the lock.
+#[derive(Debug, Serialize, Deserialize)]
+struct LockHolder {
+    pid: u32,
+    host: String,
+    command: String,
+    since: String,
 }
 
-/// Acquires an exclusive lock on the gov root, waiting up to `timeout_secs`.
+impl LockHolder {
+    fn current() -> Self {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        Self {
+            pid: std::process::id(),
+            host: host_name(),
+            command: crate::cmd::history::command_line(&args),
+            since: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+
+    fn read(lock_path: &Path) -> Option<Self> {
+        toml::from_str(&std::fs::read_to_string(lock_path).ok()?).ok()
+    }
+
+    /// The recorded holder ran on this host and has exited. The record may be
+    /// stale even though the lock is held by some other process.
+    fn is_stale(&self) -> bool {
+        self.host == host_name() && !process_exists(self.pid)
+    }
+}
+
+impl fmt::Display for LockHolder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pid {} on {} (`{}`, since {})",
+            self.pid, self.host, self.command, self.since
+        )
+    }
+}
+
+/// Acquires an exclusive lock on the gov root, waiting up to
+/// `concurrency.lock_timeout_secs` (or `--lock-wait`).
 /// Returns a guard that releases the lock when dropped.
 ///
-/// Fails with an actionable error if the lock cannot be acquired within the timeout.
+/// Fails with an actionable error naming the holder if the lock cannot be
+/// acquired within the timeout.
 pub fn acquire_gov_lock(config: &Config) -> DiagnosticResult<GovLockGuard> {
     let gov_root = config.gov_root.as_path();
     let lock_path = gov_root.join(LOCK_FILE_NAME);
@@ -44,31 +108,36 @@ pub fn acquire_gov_lock(config: &Config) -> DiagnosticResult<GovLockGuard> {
         ));
     }
 
-    let file = OpenOptions::new()
-        .create(true)
-        .truncate(false)
-        .read(true)
-        .write(true)
-        .open(&lock_path)
-        .map_err(|e| Diagnostic::io_error("open lock file", e, lock_path.display().to_string()))?;
+    let mut file = open_lock_file(&lock_path)?;
 
-    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+    let started = Instant::now();
+    let deadline = started + Duration::from_secs(timeout_secs);
     let poll = Duration::from_millis(POLL_INTERVAL_MS);
+    let mut waiting = false;
 
     loop {
         match file.try_lock_exclusive() {
             Ok(()) => {
-                return Ok(GovLockGuard { _file: file });
+                tracing::debug!(
+                    "acquired lock {} after {:?}",
+                    lock_path.display(),
+                    started.elapsed()
+                );
+                record_holder(&mut file, &lock_path)?;
+                return Ok(GovLockGuard { file });
             }
             Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                let holder = LockHolder::read(&lock_path);
                 if Instant::now() >= deadline {
-                    return Err(Diagnostic::new(
-                        DiagnosticCode::E0503LockTimeout,
-                        format!(
-                            "Another govctl write command is in progress. Wait for it to finish or retry later. (Timed out after {} seconds waiting for exclusive access.)",
-                            timeout_secs
-                        ),
-                        lock_path.display().to_string(),
+                    return Err(timeout_error(&lock_path, holder.as_ref(), timeout_secs));
+                }
+                if !waiting && timeout_secs > 0 {
+                    waiting = true;
+                    let held_by = holder
+                        .map(|holder| holder.to_string())
+                        .unwrap_or_else(|| "another process".to_string());
+                    ui::info(format!(
+                        "Waiting up to {timeout_secs}s for the gov-root lock held by {held_by}"
                     ));
                 }
                 thread::sleep(poll);
@@ -84,6 +153,94 @@ pub fn acquire_gov_lock(config: &Config) -> DiagnosticResult<GovLockGuard> {
     }
 }
 
+fn timeout_error(lock_path: &Path, holder: Option<&LockHolder>, timeout_secs: u64) -> Diagnostic {
+    let held_by = holder
+        .map(|holder| format!(" (recorded holder: {holder})"))
+        .unwrap_or_default();
+    let hint = match holder {
+        Some(holder) if holder.is_stale() => format!(
+            " Hint: pid {} is not running on this host, so the record may be stale. The lock is still held, possibly by a process in another PID namespace or an older govctl that does not record itself; it is released when that process exits.",
+            holder.pid
+        ),
+        _ => String::new(),
+    };
+    Diagnostic::new(
+        DiagnosticCode::E0503LockTimeout,
+        format!(
+            "Another govctl write command is in progress{held_by}. Wait for it to finish or retry with a longer --lock-wait. (Timed out after {timeout_secs} seconds waiting for exclusive access.){hint}"
+        ),
+        lock_path.display().to_string(),
+    )
+}
+
+fn open_lock_file(lock_path: &Path) -> DiagnosticResult<File> {
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .read(true)
+        .write(true)
+        .open(lock_path)
+        .map_err(|e| Diagnostic::io_error("open lock file", e, lock_path.display().to_string()))
+}
+
+fn record_holder(file: &mut File, lock_path: &Path) -> DiagnosticResult<()> {
+    let record = toml::to_string(&LockHolder::current()).map_err(|e| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Failed to serialize lock holder: {e}"),
+            lock_path.display().to_string(),
+        )
+    })?;
+    file.set_len(0)
+        .and_then(|()| file.write_all(record.as_bytes()))
+        .and_then(|()| file.flush())
+        .map_err(|e| Diagnostic::io_error("write lock file", e, lock_path.display().to_string()))
+}
+
+#[cfg(unix)]
+fn process_exists(pid: u32) -> bool {
+    let Ok(pid) = i32::try_from(pid) else {
+        return true;
+    };
+    if pid <= 0 {
+        return true;
+    }
+    // Signal 0 only checks whether the process exists. EPERM means it does.
+    if unsafe { kill(pid, 0) } == 0 {
+        return true;
+    }
+    io::Error::last_os_error().raw_os_error() != Some(ESRCH)
+}
+
+#[cfg(not(unix))]
+fn process_exists(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(unix)]
+fn host_name() -> String {
+    let mut buf = [0u8; 256];
+    if unsafe { gethostname(buf.as_mut_ptr().cast(), buf.len()) } != 0 {
+        return String::new();
+    }
+    let len = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+    String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
+#[cfg(not(unix))]
+fn host_name() -> String {
+    std::env::var("COMPUTERNAME").unwrap_or_default()
+}
+
+#[cfg(unix)]
+const ESRCH: i32 = 3;
+
+#[cfg(unix)]
+unsafe extern "C" {
+    fn kill(pid: i32, sig: i32) -> i32;
+    fn gethostname(name: *mut std::ffi::c_char, len: usize) -> i32;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +249,29 @@ mod tests {
     fn lock_file_name_is_under_gov_root() {
         assert_eq!(LOCK_FILE_NAME, ".govctl.lock");
     }
+
+    #[test]
+    fn current_process_holder_is_not_stale() {
+        assert!(!LockHolder::current().is_stale());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exited_process_on_this_host_is_stale() -> Result<(), Box<dyn std::error::Error>> {
+        let mut child = std::process::Command::new("true").spawn()?;
+        let pid = child.id();
+        child.wait()?;
+
+        let holder = LockHolder {
+            pid,
+            ..LockHolder::current()
+        };
+        assert!(holder.is_stale());
+        let remote = LockHolder {
+            host: format!("{}-elsewhere", holder.host),
+            ..holder
+        };
+        assert!(!remote.is_stale());
+        Ok(())
+    }
 }
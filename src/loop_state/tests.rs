@@ -57,7 +57,7 @@ fn test_loop_state_round_trips_state_toml() -> TestResult {
         deps(&[(root, &[dependency]), (dependency, &[])]),
     )?;
 
-    write_loop_state_with_op(&config, &state, WriteOp::Execute)?;
+    write_loop_state_with_op(&config, &state, WriteOp::EXECUTE)?;
 
     let state_path = temp_dir
         .path()
@@ -155,7 +155,7 @@ note_candidates = []
     assert_eq!(record.round_meta.round_number, 1);
     assert_eq!(record.round_meta.work, vec![work_id.to_string()]);
 
-    write_loop_round_record(&config, &record, WriteOp::Execute)?;
+    write_loop_round_record(&config, &record, WriteOp::EXECUTE)?;
     let rewritten = std::fs::read_to_string(round_dir.join("round-001.toml"))?;
     assert!(!rewritten.contains("max_rounds"), "{rewritten}");
     Ok(())
@@ -176,7 +176,7 @@ fn test_loop_state_updates_lifecycle_item_status_and_round_count() -> TestResult
     state.transition_to(LoopLifecycleState::Active)?;
     state.set_item_status(work_id, LoopWorkItemStatus::Active)?;
     assert_eq!(state.increment_round_count(work_id)?, 1);
-    write_loop_state_with_op(&config, &state, WriteOp::Execute)?;
+    write_loop_state_with_op(&config, &state, WriteOp::EXECUTE)?;
 
     let loaded = load_loop_state(&config, "LOOP-2026-05-31-002")?;
     assert_eq!(loaded.loop_meta.state, LoopLifecycleState::Active);
@@ -1,3 +1,4 @@
+use super::deprecation::Deprecation;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
@@ -11,12 +12,28 @@ pub struct AdrMeta {
     pub title: String,
     pub status: AdrStatus,
     pub date: String,
+    /// Actor that created the ADR, `human:<name>` or `agent:<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub superseded_by: Option<String>,
+    /// Scheduled sunset, recorded by `adr deprecate --sunset`; the status
+    /// stays accepted until the ADR is superseded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub refs: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Identities that approved the decision, recorded by `adr approve`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approvals: Vec<String>,
+    /// Content fields stored encrypted and redacted in rendered output.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sensitive: Vec<String>,
+    /// Files under `gov/assets/`, as paths relative to it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
 }
 
 impl AdrMeta {
@@ -32,9 +49,14 @@ impl AdrMeta {
             title: title.into(),
             status,
             date: date.into(),
+            created_by: None,
             superseded_by: None,
+            deprecation: None,
             refs: vec![],
             tags: vec![],
+            approvals: vec![],
+            sensitive: vec![],
+            attachments: vec![],
         }
     }
 }
@@ -90,6 +112,12 @@ pub struct AdrContent {
     pub decision: String,
     #[serde(default)]
     pub consequences: String,
+    /// Forces that shaped the decision (MADR "Decision Drivers")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub drivers: Vec<String>,
+    /// People or teams consulted on or affected by the decision
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stakeholders: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub alternatives: Vec<Alternative>,
 }
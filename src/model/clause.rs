@@ -1,3 +1,5 @@
+use super::deprecation::Deprecation;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
@@ -10,12 +12,17 @@ pub struct ClauseSpec {
     pub kind: ClauseKind,
     #[serde(default)]
     pub status: ClauseStatus,
-    pub text: String,
+    /// `None` when the clause was loaded metadata-only
+    /// ([`crate::load::load_project_metadata`]); see
+    /// [`crate::load::with_project_clause_text`].
+    pub text: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub anchors: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub superseded_by: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub since: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
@@ -43,6 +50,8 @@ pub struct ClauseMeta {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub superseded_by: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub since: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
@@ -54,9 +63,19 @@ pub struct ClauseContent {
     pub text: String,
 }
 
-impl From<ClauseSpec> for ClauseWire {
-    fn from(s: ClauseSpec) -> Self {
-        Self {
+/// Fails for a clause loaded metadata-only: writing it would drop its text.
+impl TryFrom<ClauseSpec> for ClauseWire {
+    type Error = Diagnostic;
+
+    fn try_from(s: ClauseSpec) -> Result<Self, Diagnostic> {
+        let Some(text) = s.text else {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!("Clause {} was loaded without its text", s.clause_id),
+                s.clause_id,
+            ));
+        };
+        Ok(Self {
             govctl: ClauseMeta {
                 _schema: 1,
                 id: s.clause_id,
@@ -65,11 +84,12 @@ impl From<ClauseSpec> for ClauseWire {
                 status: s.status,
                 anchors: s.anchors,
                 superseded_by: s.superseded_by,
+                deprecation: s.deprecation,
                 since: s.since,
                 tags: s.tags,
             },
-            content: ClauseContent { text: s.text },
-        }
+            content: ClauseContent { text },
+        })
     }
 }
 
@@ -80,9 +100,10 @@ impl From<ClauseWire> for ClauseSpec {
             title: w.govctl.title,
             kind: w.govctl.kind,
             status: w.govctl.status,
-            text: w.content.text,
+            text: Some(w.content.text),
             anchors: w.govctl.anchors,
             superseded_by: w.govctl.superseded_by,
+            deprecation: w.govctl.deprecation,
             since: w.govctl.since,
             tags: w.govctl.tags,
         }
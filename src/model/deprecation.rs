@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Deprecation notice recorded by `deprecate --sunset/--reason`.
+///
+/// After `sunset` (YYYY-MM-DD), `check` warns while the artifact is still
+/// referenced.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deprecation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sunset: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
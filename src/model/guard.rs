@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Verification Guard metadata section `[govctl]`
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +34,32 @@ pub struct GuardCheck {
     pub timeout_secs: u64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
+    /// Environment variables set for the command, on top of govctl's own.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Directory the command runs in, relative to the project root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+    /// Shell that runs the command as `<shell> -c <command>`; `bash -l` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// Report a failure as a warning instead of blocking.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_failure: bool,
+}
+
+impl GuardCheck {
+    pub fn new(command: impl Into<String>, timeout_secs: u64) -> Self {
+        Self {
+            command: command.into(),
+            timeout_secs,
+            pattern: None,
+            env: BTreeMap::new(),
+            working_dir: None,
+            shell: None,
+            allow_failure: false,
+        }
+    }
 }
 
 fn default_guard_timeout_secs() -> u64 {
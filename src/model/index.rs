@@ -10,6 +10,10 @@ pub struct RfcIndex {
     pub rfc: RfcSpec,
     pub clauses: Vec<ClauseEntry>,
     pub path: PathBuf,
+    /// Whether the RFC changed since its signature was recorded, computed
+    /// while clause text was still loaded when the index is metadata-only.
+    /// `None` otherwise; see [`crate::signature::is_rfc_amended`].
+    pub amended: Option<bool>,
 }
 
 /// Clause with its path
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Compliance mapping metadata section `[govctl]`, naming the external standard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingMeta {
+    #[serde(default, rename = "schema", skip_serializing)]
+    _schema: u32,
+    /// Standard identifier, e.g. `ISO-27001` or `SOC2`
+    pub id: String,
+    pub title: String,
+}
+
+impl MappingMeta {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            _schema: 1,
+            id: id.into(),
+            title: title.into(),
+        }
+    }
+}
+
+/// One control of the external standard and the clauses that satisfy it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingControl {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Clause refs as `RFC-NNNN:C-NAME`
+    pub clauses: Vec<String>,
+}
+
+/// Complete compliance mapping file structure (`gov/mapping/<standard>.toml`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingSpec {
+    pub govctl: MappingMeta,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub controls: Vec<MappingControl>,
+}
+
+/// Loaded compliance mapping with its source path.
+#[derive(Debug, Clone)]
+pub struct MappingEntry {
+    pub spec: MappingSpec,
+    pub path: PathBuf,
+}
+
+impl MappingEntry {
+    pub fn meta(&self) -> &MappingMeta {
+        &self.spec.govctl
+    }
+}
@@ -4,14 +4,17 @@
 //! - RFCs with clauses ([[RFC-0000:C-RFC-DEF]])
 //! - ADRs ([[RFC-0000:C-ADR-DEF]])
 //! - Work Items ([[RFC-0000:C-WORK-DEF]])
+//! - Compliance mappings to external standards ([[RFC-0002:C-RESOURCES]])
 //!
 //! Lifecycle state machines per [[RFC-0001]].
 
 mod adr;
 mod changelog;
 mod clause;
+mod deprecation;
 mod guard;
 mod index;
+mod mapping;
 mod release;
 mod rfc;
 #[cfg(test)]
@@ -21,13 +24,15 @@ mod work;
 pub use adr::{AdrContent, AdrMeta, AdrSpec, AdrStatus, Alternative, AlternativeStatus};
 pub use changelog::{ChangelogCategory, ChangelogEntry};
 pub use clause::{ClauseKind, ClauseSpec, ClauseStatus, ClauseWire};
+pub use deprecation::Deprecation;
 pub use guard::{GuardCheck, GuardMeta, GuardSpec};
 pub use index::{AdrEntry, ClauseEntry, GuardEntry, ProjectIndex, RfcIndex, WorkItemEntry};
+pub use mapping::{MappingControl, MappingEntry, MappingMeta, MappingSpec};
 pub use release::{Release, ReleasesFile};
 pub use rfc::{RfcPhase, RfcSpec, RfcStatus, RfcWire, SectionSpec};
 #[cfg(test)]
 pub use work::JournalEntry;
 pub use work::{
-    ChecklistItem, ChecklistStatus, WorkItemContent, WorkItemMeta, WorkItemSpec, WorkItemStatus,
-    WorkItemVerification,
+    ChecklistItem, ChecklistStatus, GuardResult, WorkItemContent, WorkItemMeta, WorkItemSpec,
+    WorkItemStatus, WorkItemVerification,
 };
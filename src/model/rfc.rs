@@ -1,4 +1,5 @@
 use super::changelog::ChangelogEntry;
+use super::deprecation::Deprecation;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
@@ -12,15 +13,31 @@ pub struct RfcSpec {
     pub status: RfcStatus,
     pub phase: RfcPhase,
     pub owners: Vec<String>,
+    /// Identities that approved the RFC; one must be an owner before
+    /// `finalize normative` when `[owners] require_approval` is set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approvals: Vec<String>,
+    /// Frozen for review: writes to the RFC and its clauses are refused
+    /// until `rfc unfreeze`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub frozen: bool,
     pub created: String,
+    /// Actor that created the RFC, `human:<name>` or `agent:<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub updated: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub supersedes: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub refs: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Files under `gov/assets/`, as paths relative to it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
     pub sections: Vec<SectionSpec>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub changelog: Vec<ChangelogEntry>,
@@ -60,15 +77,26 @@ pub struct RfcMeta {
     pub status: RfcStatus,
     pub phase: RfcPhase,
     pub owners: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approvals: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub frozen: bool,
     pub created: String,
+    /// Actor that created the RFC, `human:<name>` or `agent:<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub updated: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub supersedes: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub refs: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signature: Option<String>,
 }
@@ -84,11 +112,16 @@ impl From<RfcSpec> for RfcWire {
                 status: s.status,
                 phase: s.phase,
                 owners: s.owners,
+                approvals: s.approvals,
+                frozen: s.frozen,
                 created: s.created,
+                created_by: s.created_by,
                 updated: s.updated,
                 supersedes: s.supersedes,
+                deprecation: s.deprecation,
                 refs: s.refs,
                 tags: s.tags,
+                attachments: s.attachments,
                 signature: s.signature,
             },
             sections: s.sections,
@@ -106,11 +139,16 @@ impl From<RfcWire> for RfcSpec {
             status: w.govctl.status,
             phase: w.govctl.phase,
             owners: w.govctl.owners,
+            approvals: w.govctl.approvals,
+            frozen: w.govctl.frozen,
             created: w.govctl.created,
+            created_by: w.govctl.created_by,
             updated: w.govctl.updated,
             supersedes: w.govctl.supersedes,
+            deprecation: w.govctl.deprecation,
             refs: w.govctl.refs,
             tags: w.govctl.tags,
+            attachments: w.govctl.attachments,
             sections: w.sections,
             changelog: w.changelog,
             signature: w.govctl.signature,
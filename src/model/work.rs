@@ -1,4 +1,5 @@
 use super::changelog::ChangelogCategory;
+use super::guard::GuardCheck;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
@@ -13,6 +14,9 @@ pub struct WorkItemMeta {
     pub status: WorkItemStatus,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created: Option<String>,
+    /// Actor that created the work item, `human:<name>` or `agent:<name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub started: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -23,6 +27,18 @@ pub struct WorkItemMeta {
     pub depends_on: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Linked GitHub issues and pull requests, `owner/repo#123`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub github: Vec<String>,
+    /// Linked Jira issue key, `PROJ-123`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira_key: Option<String>,
+    /// Queue rank; lower values are picked up first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+    /// Files under `gov/assets/`, as paths relative to it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
 }
 
 impl WorkItemMeta {
@@ -33,11 +49,16 @@ impl WorkItemMeta {
             title: title.into(),
             status,
             created: None,
+            created_by: None,
             started: None,
             completed: None,
             refs: vec![],
             depends_on: vec![],
             tags: vec![],
+            github: vec![],
+            jira_key: None,
+            priority: None,
+            attachments: vec![],
         }
     }
 }
@@ -49,14 +70,55 @@ pub struct WorkItemVerification {
     pub required_guards: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub waivers: Vec<GuardWaiver>,
+    /// Guards declared on this item, required along with `required_guards`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub guards: Vec<WorkItemGuard>,
+    /// Outcomes of guard runs on this item, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub results: Vec<GuardResult>,
 }
 
 impl WorkItemVerification {
     pub fn is_empty(&self) -> bool {
-        self.required_guards.is_empty() && self.waivers.is_empty()
+        self.required_guards.is_empty()
+            && self.waivers.is_empty()
+            && self.guards.is_empty()
+            && self.results.is_empty()
     }
 }
 
+/// A verification guard declared on one work item. An ID of a project guard
+/// overrides that guard's check for this item; any other ID adds a guard only
+/// this item runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemGuard {
+    pub id: String,
+    #[serde(flatten)]
+    pub check: GuardCheck,
+}
+
+/// The recorded outcome of one guard run, kept as verification evidence.
+///
+/// Guard runs are recorded here rather than in the legacy `journal`, which
+/// [[ADR-0047]] keeps read-only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardResult {
+    pub guard: String,
+    pub command: String,
+    /// Local time the run started, "YYYY-MM-DD HH:MM:SS".
+    pub ran_at: String,
+    pub duration_ms: u64,
+    /// Exit code of the command; absent when it was killed or timed out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    pub passed: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+    /// The last `[verification] output_lines` lines of output.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub output: String,
+}
+
 /// Explicit waiver for one required verification guard.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GuardWaiver {
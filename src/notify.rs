@@ -0,0 +1,116 @@
+//! Lifecycle webhooks configured under `[[notify.webhooks]]`.
+//!
+//! Delivery is best effort: the command that raised the event has already
+//! committed, so a webhook that cannot be reached is reported as a `W0125`
+//! warning instead of failing the command.
+
+use crate::config::{Config, NotifyEvent, WebhookConfig, WebhookFormat};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, Diagnostics};
+use crate::ui;
+use crate::write::WriteOp;
+use reqwest::Url;
+use reqwest::blocking::Client;
+use serde::Serialize;
+use serde_json::json;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One lifecycle event, serialized as the JSON webhook body.
+#[derive(Debug, Serialize)]
+pub struct Event {
+    pub event: NotifyEvent,
+    pub project: String,
+    /// Artifact ID; the version for releases.
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Work items included in a release.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work_items: Vec<String>,
+    /// One-line description, also used as the Slack message.
+    pub summary: String,
+}
+
+impl NotifyEvent {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::RfcFinalize => "rfc.finalize",
+            Self::AdrAccept => "adr.accept",
+            Self::WorkDone => "work.done",
+            Self::Release => "release",
+        }
+    }
+}
+
+/// Send `event` to every webhook subscribed to it.
+pub fn deliver(config: &Config, event: &Event, op: WriteOp) -> Diagnostics {
+    let webhooks = config
+        .notify
+        .webhooks
+        .iter()
+        .filter(|webhook| webhook.events.is_empty() || webhook.events.contains(&event.event));
+    let mut warnings = Vec::new();
+    for webhook in webhooks {
+        let url = match resolve_url(webhook) {
+            Ok(url) => url,
+            Err(err) => {
+                warnings.push(failure(event, &webhook.url, err));
+                continue;
+            }
+        };
+        let host = url.host_str().unwrap_or_default().to_string();
+        if op.is_preview() {
+            ui::dry_run_notify(&host, event.event.name(), &event.id);
+            continue;
+        }
+        if let Err(err) = post(&url, webhook.format, event) {
+            warnings.push(failure(event, &host, err));
+        }
+    }
+    warnings
+}
+
+fn resolve_url(webhook: &WebhookConfig) -> Result<Url, String> {
+    let raw = match webhook.url.strip_prefix('$') {
+        Some(name) => std::env::var(name).map_err(|_| format!("{name} is not set"))?,
+        None => webhook.url.clone(),
+    };
+    // The URL itself may be a secret; errors name the variable, not the value.
+    Url::parse(&raw).map_err(|err| format!("invalid URL: {err}"))
+}
+
+fn post(url: &Url, format: WebhookFormat, event: &Event) -> Result<(), String> {
+    let body = match format {
+        WebhookFormat::Json => json!(event),
+        WebhookFormat::Slack => {
+            json!({ "text": format!("*{}*: {}", event.project, event.summary) })
+        }
+    };
+    let response = Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .and_then(|client| client.post(url.clone()).json(&body).send())
+        .map_err(|err| err.without_url().to_string())?;
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(status.to_string())
+    }
+}
+
+fn failure(event: &Event, target: &str, err: String) -> Diagnostic {
+    Diagnostic::new(
+        DiagnosticCode::W0125WebhookFailed,
+        format!(
+            "Webhook for {} {} failed: {err}",
+            event.event.name(),
+            event.id
+        ),
+        target,
+    )
+}
@@ -1,11 +1,12 @@
-//! TOML parsing for ADR, Work Item, Guard, and Release files.
+//! TOML parsing for ADR, Work Item, Guard, Release, and compliance mapping files.
 
 mod toml_io;
 
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode};
 use crate::model::{
-    AdrEntry, AdrSpec, GuardEntry, GuardSpec, ReleasesFile, WorkItemEntry, WorkItemSpec,
+    AdrEntry, AdrSpec, GuardEntry, GuardSpec, MappingEntry, MappingSpec, ReleasesFile,
+    WorkItemEntry, WorkItemSpec,
 };
 use crate::schema::ArtifactSchema;
 use crate::write::WriteOp;
@@ -25,11 +26,13 @@ pub fn load_adrs(config: &Config) -> Result<Vec<AdrEntry>, Diagnostic> {
 /// Load all ADRs, returning both items and parse warnings
 pub fn load_adrs_with_warnings(config: &Config) -> Result<LoadResult<AdrEntry>, Diagnostic> {
     let adr_dir = config.adr_dir();
-    toml_io::load_toml_dir(
-        &adr_dir,
-        |path| load_adr(config, path),
-        |adrs| adrs.sort_by(|a, b| a.spec.govctl.id.cmp(&b.spec.govctl.id)),
-    )
+    crate::ui::timed("load ADRs", || {
+        toml_io::load_toml_dir(
+            &adr_dir,
+            |path| load_adr(config, path),
+            |adrs| adrs.sort_by(|a, b| a.spec.govctl.id.cmp(&b.spec.govctl.id)),
+        )
+    })
 }
 
 /// Load a single ADR from TOML file
@@ -77,11 +80,13 @@ pub fn load_work_items_with_warnings(
     config: &Config,
 ) -> Result<LoadResult<WorkItemEntry>, Diagnostic> {
     let work_dir = config.work_dir();
-    toml_io::load_toml_dir(
-        &work_dir,
-        |path| load_work_item(config, path),
-        |items| items.sort_by(|a, b| a.spec.govctl.id.cmp(&b.spec.govctl.id)),
-    )
+    crate::ui::timed("load work items", || {
+        toml_io::load_toml_dir(
+            &work_dir,
+            |path| load_work_item(config, path),
+            |items| items.sort_by(|a, b| a.spec.govctl.id.cmp(&b.spec.govctl.id)),
+        )
+    })
 }
 
 /// Load all verification guards from the guard directory.
@@ -180,6 +185,58 @@ pub fn write_guard(
     )
 }
 
+/// Load all compliance mappings from the mapping directory.
+pub fn load_mappings(config: &Config) -> Result<Vec<MappingEntry>, Diagnostic> {
+    load_mappings_with_warnings(config).map(|r| r.items)
+}
+
+/// Load all compliance mappings, returning both items and parse warnings.
+pub fn load_mappings_with_warnings(
+    config: &Config,
+) -> Result<LoadResult<MappingEntry>, Diagnostic> {
+    let mapping_dir = config.mapping_dir();
+    toml_io::load_toml_dir(
+        &mapping_dir,
+        |path| load_mapping(config, path),
+        |items| items.sort_by(|a, b| a.spec.govctl.id.cmp(&b.spec.govctl.id)),
+    )
+}
+
+/// Load a single compliance mapping from TOML file.
+pub fn load_mapping(config: &Config, path: &Path) -> Result<MappingEntry, Diagnostic> {
+    let spec = toml_io::load_toml_spec(
+        config,
+        path,
+        ArtifactSchema::Mapping,
+        DiagnosticCode::E1401MappingSchemaInvalid,
+        "Invalid TOML",
+        "Invalid compliance mapping structure",
+        |_| {},
+    )?;
+
+    Ok(MappingEntry {
+        spec,
+        path: path.to_path_buf(),
+    })
+}
+
+/// Write a compliance mapping to TOML file.
+pub fn write_mapping(
+    path: &Path,
+    spec: &MappingSpec,
+    op: WriteOp,
+    display_path: Option<&Path>,
+) -> Result<(), Diagnostic> {
+    toml_io::write_toml_spec(
+        path,
+        ArtifactSchema::Mapping,
+        spec,
+        op,
+        display_path,
+        "Failed to serialize TOML",
+    )
+}
+
 /// Load releases from gov/releases.toml
 /// Returns empty ReleasesFile if file doesn't exist.
 /// Validates that all versions are valid semver.
@@ -235,3 +292,6 @@ pub fn write_releases(
         "Failed to serialize releases",
     )
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,732 @@
+//! Property tests of the artifact read/write cycle.
+//!
+//! Generated projects stay inside each field's schema pattern, but free text
+//! leans on what tends to break a TOML writer: quotes, backslashes, runs of
+//! `"""` and `'''`, tabs, CRLF line endings, and characters outside ASCII.
+//! The properties:
+//!
+//! - parse → write → parse is stable: what is read back equals what was
+//!   written, and writing it again reproduces the same bytes;
+//! - validation is idempotent: validating the project twice, or validating
+//!   the re-read project, reports the same diagnostics;
+//! - malformed input fails cleanly: mutated copies of a file are accepted or
+//!   rejected with a diagnostic, never with a panic.
+
+use super::*;
+use crate::load::{load_clause, load_project, load_rfc};
+use crate::model::{
+    AdrContent, AdrMeta, AdrSpec, AdrStatus, Alternative, AlternativeStatus, ChangelogCategory,
+    ChangelogEntry, ChecklistItem, ChecklistStatus, ClauseKind, ClauseSpec, ClauseStatus,
+    ProjectIndex, RfcPhase, RfcSpec, RfcStatus, SectionSpec, WorkItemContent, WorkItemMeta,
+    WorkItemSpec, WorkItemStatus,
+};
+use crate::validate::validate_project;
+use crate::write::{write_clause, write_rfc};
+use proptest::prelude::*;
+use proptest::sample::{Index, select};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Fragments mixed into generated prose.
+const PIECES: &[&str] = &[
+    "the",
+    "system",
+    "MUST",
+    "SHOULD",
+    "reject",
+    "input",
+    "with",
+    "a",
+    "diagnostic",
+    "\"quoted\"",
+    "'single'",
+    "\\",
+    "\\n",
+    "\"\"\"",
+    "'''",
+    "\t",
+    "#",
+    "[[RFC-0001]]",
+    "{{include:X}}",
+    "=",
+    "[",
+    "]",
+    "{",
+    "}",
+    "é",
+    "日本語",
+    "🦀",
+    "—",
+    "\u{200b}",
+    "$",
+    "`code`",
+    "*",
+    "-",
+    "1.",
+    ">",
+];
+
+/// Snippets spliced into a file to break its structure.
+const SPLICES: &[&str] = &[
+    "\"",
+    "'",
+    "\"\"\"",
+    "'''",
+    "[",
+    "]",
+    "[[",
+    "]]",
+    "{",
+    "}",
+    "=",
+    ",",
+    "\n",
+    "\\",
+    "\\u",
+    "\\U",
+    "#",
+    ".",
+    "\u{0}",
+    "\u{1b}",
+    "\u{feff}",
+    "é",
+    "🦀",
+    "[govctl]",
+    "[content]",
+    "id = ",
+    "1979-05-27",
+    "nan",
+    "inf",
+    "0x",
+    "true",
+    "[]",
+    "{}",
+];
+
+/// Non-empty single-line text.
+fn line() -> impl Strategy<Value = String> {
+    prop::collection::vec(select(PIECES), 1..=8).prop_map(|words| {
+        let line = words.join(" ").replace(['\n', '\r'], " ");
+        if line.trim().is_empty() {
+            "blank".to_string()
+        } else {
+            line
+        }
+    })
+}
+
+fn lines(max: usize) -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(line(), 0..=max)
+}
+
+/// Non-empty prose of one or more paragraphs, sometimes with CRLF line
+/// endings or a trailing empty line.
+fn text() -> impl Strategy<Value = String> {
+    (
+        prop::collection::vec(line(), 1..=3),
+        prop::bool::weighted(0.1),
+        prop::bool::weighted(0.2),
+    )
+        .prop_map(|(mut paragraphs, crlf, trailing)| {
+            if trailing {
+                paragraphs.push(String::new());
+            }
+            paragraphs.join(if crlf { "\r\n" } else { "\n" })
+        })
+}
+
+fn version() -> impl Strategy<Value = String> {
+    (0..10u32, 0..20u32, 0..20u32)
+        .prop_map(|(major, minor, patch)| format!("{major}.{minor}.{patch}"))
+}
+
+fn date() -> impl Strategy<Value = String> {
+    (2000..=2099u32, 1..=12u32, 1..=28u32)
+        .prop_map(|(year, month, day)| format!("{year}-{month:02}-{day:02}"))
+}
+
+fn tags() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec("[a-z]{1,8}", 0..=2)
+}
+
+fn clause() -> impl Strategy<Value = ClauseSpec> {
+    (
+        "[A-Z]{1,8}",
+        line(),
+        select(&[ClauseKind::Normative, ClauseKind::Informative][..]),
+        select(&[ClauseStatus::Active, ClauseStatus::Deprecated][..]),
+        text(),
+        prop::option::weighted(0.3, version()),
+        tags(),
+    )
+        .prop_map(
+            |(word, title, kind, status, text, since, tags)| ClauseSpec {
+                clause_id: format!("C-{word}"),
+                title,
+                kind,
+                status,
+                text: Some(text),
+                anchors: vec![],
+                superseded_by: None,
+                deprecation: None,
+                since,
+                tags,
+            },
+        )
+}
+
+fn changelog_entry(version: String) -> impl Strategy<Value = ChangelogEntry> {
+    (
+        date(),
+        prop::option::of(line()),
+        lines(2),
+        lines(2),
+        lines(1),
+    )
+        .prop_map(move |(date, notes, added, changed, fixed)| ChangelogEntry {
+            version: version.clone(),
+            date,
+            notes,
+            added,
+            changed,
+            deprecated: vec![],
+            removed: vec![],
+            fixed,
+            security: vec![],
+        })
+}
+
+/// An RFC numbered `n` with one to four clauses, numbered to keep their ids
+/// unique.
+fn rfc(n: usize) -> impl Strategy<Value = (RfcSpec, Vec<ClauseSpec>)> {
+    let clauses = prop::collection::vec(clause(), 1..=4).prop_map(|clauses| {
+        clauses
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut clause)| {
+                clause.clause_id = format!("{}-{}", clause.clause_id, index + 1);
+                clause
+            })
+            .collect::<Vec<_>>()
+    });
+    let header = (
+        line(),
+        select(
+            &[
+                RfcStatus::Draft,
+                RfcStatus::Normative,
+                RfcStatus::Deprecated,
+            ][..],
+        ),
+        select(
+            &[
+                RfcPhase::Spec,
+                RfcPhase::Impl,
+                RfcPhase::Test,
+                RfcPhase::Stable,
+            ][..],
+        ),
+        prop::bool::weighted(0.1),
+        date(),
+        prop::option::of(Just("agent:proptest".to_string())),
+        prop::option::of(date()),
+        tags(),
+        line(),
+    );
+    (version(), clauses, header).prop_flat_map(move |(version, clauses, header)| {
+        changelog_entry(version.clone()).prop_map(move |entry| {
+            let (title, status, phase, frozen, created, created_by, updated, tags, section) =
+                header.clone();
+            let rfc = RfcSpec {
+                rfc_id: format!("RFC-{n:04}"),
+                title,
+                version: version.clone(),
+                status,
+                phase,
+                owners: vec!["@proptest".to_string()],
+                approvals: vec![],
+                frozen,
+                created,
+                created_by,
+                updated,
+                supersedes: None,
+                deprecation: None,
+                refs: vec![],
+                tags,
+                attachments: vec![],
+                sections: vec![SectionSpec {
+                    title: section,
+                    clauses: clauses
+                        .iter()
+                        .map(|clause| format!("clauses/{}.toml", clause.clause_id))
+                        .collect(),
+                }],
+                changelog: vec![entry],
+                signature: None,
+            };
+            (rfc, clauses.clone())
+        })
+    })
+}
+
+fn alternative() -> impl Strategy<Value = Alternative> {
+    (
+        line(),
+        select(
+            &[
+                AlternativeStatus::Considered,
+                AlternativeStatus::Rejected,
+                AlternativeStatus::Accepted,
+            ][..],
+        ),
+        lines(2),
+        lines(2),
+        prop::option::weighted(0.3, line()),
+    )
+        .prop_map(|(text, status, pros, cons, rejection_reason)| Alternative {
+            text,
+            status,
+            pros,
+            cons,
+            rejection_reason,
+        })
+}
+
+/// An ADR numbered `n`; `refs` picks which of the project's RFCs it cites.
+fn adr(n: usize) -> impl Strategy<Value = (AdrSpec, Vec<bool>)> {
+    (
+        (
+            line(),
+            select(
+                &[
+                    AdrStatus::Proposed,
+                    AdrStatus::Accepted,
+                    AdrStatus::Rejected,
+                ][..],
+            ),
+            date(),
+            tags(),
+            prop::collection::vec(any::<bool>(), 3),
+        ),
+        (
+            text(),
+            text(),
+            text(),
+            lines(2),
+            lines(1),
+            prop::collection::vec(alternative(), 0..=2),
+        ),
+    )
+        .prop_map(
+            move |(
+                (title, status, date, tags, refs),
+                (context, decision, consequences, drivers, stakeholders, alternatives),
+            )| {
+                let mut meta = AdrMeta::new(format!("ADR-{n:04}"), title, status, date);
+                meta.tags = tags;
+                let adr = AdrSpec {
+                    govctl: meta,
+                    content: AdrContent {
+                        context,
+                        decision,
+                        consequences,
+                        drivers,
+                        stakeholders,
+                        alternatives,
+                    },
+                };
+                (adr, refs)
+            },
+        )
+}
+
+fn checklist_item() -> impl Strategy<Value = ChecklistItem> {
+    (
+        line(),
+        select(
+            &[
+                ChecklistStatus::Pending,
+                ChecklistStatus::Done,
+                ChecklistStatus::Cancelled,
+            ][..],
+        ),
+        select(
+            &[
+                ChangelogCategory::Added,
+                ChangelogCategory::Changed,
+                ChangelogCategory::Fixed,
+                ChangelogCategory::Chore,
+            ][..],
+        ),
+    )
+        .prop_map(|(text, status, category)| ChecklistItem {
+            text,
+            status,
+            category,
+        })
+}
+
+/// A work item numbered `n`; `refs` picks which of the project's RFCs it
+/// cites.
+fn work_item(n: usize) -> impl Strategy<Value = (WorkItemSpec, Vec<bool>)> {
+    (
+        (
+            line(),
+            select(
+                &[
+                    WorkItemStatus::Queue,
+                    WorkItemStatus::Active,
+                    WorkItemStatus::Done,
+                    WorkItemStatus::Cancelled,
+                ][..],
+            ),
+            date(),
+            tags(),
+            prop::option::weighted(0.3, 1..=5u32),
+            prop::collection::vec(any::<bool>(), 3),
+        ),
+        (
+            text(),
+            prop::collection::vec(checklist_item(), 0..=3),
+            lines(2),
+        ),
+    )
+        .prop_map(
+            move |(
+                (title, status, date, tags, priority, refs),
+                (description, acceptance_criteria, notes),
+            )| {
+                let mut meta = WorkItemMeta::new(format!("WI-{date}-{n:03}"), title, status);
+                meta.created = Some(date);
+                meta.tags = tags;
+                meta.priority = priority;
+                let item = WorkItemSpec {
+                    govctl: meta,
+                    content: WorkItemContent {
+                        description,
+                        acceptance_criteria,
+                        notes,
+                        ..Default::default()
+                    },
+                    verification: Default::default(),
+                };
+                (item, refs)
+            },
+        )
+}
+
+/// The artifacts of one generated project, in the order the loader returns
+/// them.
+#[derive(Debug, Clone)]
+struct Project {
+    rfcs: Vec<(RfcSpec, Vec<ClauseSpec>)>,
+    adrs: Vec<AdrSpec>,
+    work_items: Vec<WorkItemSpec>,
+}
+
+/// One to three RFCs, one or two ADRs and work items, and refs from the ADRs
+/// and work items to the RFCs.
+fn project() -> impl Strategy<Value = Project> {
+    (1..=3usize, 1..=2usize, 1..=2usize)
+        .prop_flat_map(|(rfcs, adrs, items)| {
+            (
+                (1..=rfcs).map(rfc).collect::<Vec<_>>(),
+                (1..=adrs).map(adr).collect::<Vec<_>>(),
+                (1..=items).map(work_item).collect::<Vec<_>>(),
+            )
+        })
+        .prop_map(|(rfcs, adrs, work_items)| {
+            let rfc_ids: Vec<String> = rfcs.iter().map(|(rfc, _)| rfc.rfc_id.clone()).collect();
+            let pick = |mask: &[bool]| -> Vec<String> {
+                rfc_ids
+                    .iter()
+                    .zip(mask)
+                    .filter(|(_, picked)| **picked)
+                    .map(|(id, _)| id.clone())
+                    .collect()
+            };
+            let adrs = adrs
+                .into_iter()
+                .map(|(mut adr, mask)| {
+                    adr.govctl.refs = pick(&mask);
+                    adr
+                })
+                .collect();
+            let mut work_items: Vec<WorkItemSpec> = work_items
+                .into_iter()
+                .map(|(mut item, mask)| {
+                    item.govctl.refs = pick(&mask);
+                    item
+                })
+                .collect();
+            work_items.sort_by(|a, b| a.govctl.id.cmp(&b.govctl.id));
+            Project {
+                rfcs,
+                adrs,
+                work_items,
+            }
+        })
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    Rfc,
+    Clause,
+    Adr,
+    WorkItem,
+}
+
+struct ArtifactFile {
+    kind: Kind,
+    path: PathBuf,
+}
+
+/// A scratch project directory holding `project`.
+struct Scratch {
+    _dir: tempfile::TempDir,
+    config: Config,
+    files: Vec<ArtifactFile>,
+}
+
+impl Scratch {
+    fn write(project: &Project) -> Result<Self, TestCaseError> {
+        let dir = tempfile::TempDir::new().map_err(|err| TestCaseError::fail(err.to_string()))?;
+        let config = Config {
+            gov_root: dir.path().join("gov"),
+            ..Default::default()
+        };
+        let mut files = Vec::new();
+        let mut add = |kind, path: PathBuf| -> Result<PathBuf, TestCaseError> {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|err| TestCaseError::fail(err.to_string()))?;
+            }
+            files.push(ArtifactFile {
+                kind,
+                path: path.clone(),
+            });
+            Ok(path)
+        };
+        for (rfc, clauses) in &project.rfcs {
+            let path = add(Kind::Rfc, config.rfc_source_path(&rfc.rfc_id, "toml"))?;
+            write_rfc(&path, rfc, WriteOp::EXECUTE, None)?;
+            for clause in clauses {
+                let path = config.clause_source_path(&rfc.rfc_id, &clause.clause_id, "toml");
+                let path = add(Kind::Clause, path)?;
+                write_clause(&path, clause, WriteOp::EXECUTE, None)?;
+            }
+        }
+        for adr in &project.adrs {
+            let path = config.adr_dir().join(format!("{}.toml", adr.govctl.id));
+            let path = add(Kind::Adr, path)?;
+            write_adr(&path, adr, WriteOp::EXECUTE, None)?;
+        }
+        for item in &project.work_items {
+            let path = config.work_dir().join(format!("{}.toml", item.govctl.id));
+            let path = add(Kind::WorkItem, path)?;
+            write_work_item(&path, item, WriteOp::EXECUTE, None)?;
+        }
+        Ok(Self {
+            _dir: dir,
+            config,
+            files,
+        })
+    }
+
+    fn load(&self) -> Result<ProjectIndex, TestCaseError> {
+        load_project(&self.config).map_err(|diagnostics| {
+            let messages: Vec<String> = diagnostics
+                .into_iter()
+                .map(|diag| format!("{} ({})", diag.message, diag.file))
+                .collect();
+            TestCaseError::fail(format!(
+                "generated project does not load: {}",
+                messages.join("; ")
+            ))
+        })
+    }
+
+    fn contents(&self) -> Result<Vec<String>, TestCaseError> {
+        self.files
+            .iter()
+            .map(|file| {
+                std::fs::read_to_string(&file.path)
+                    .map_err(|err| TestCaseError::fail(format!("{}: {err}", file.path.display())))
+            })
+            .collect()
+    }
+
+    fn validation_report(&self, index: &ProjectIndex) -> Vec<String> {
+        validate_project(index, &self.config)
+            .diagnostics
+            .iter()
+            .map(|diag| format!("{:?} {} {}", diag.code, diag.file, diag.message))
+            .collect()
+    }
+}
+
+/// Write every artifact of `index` back to the file it was read from.
+fn write_index(index: &ProjectIndex) -> Result<(), Diagnostic> {
+    for rfc in &index.rfcs {
+        write_rfc(&rfc.path, &rfc.rfc, WriteOp::EXECUTE, None)?;
+        for clause in &rfc.clauses {
+            write_clause(&clause.path, &clause.spec, WriteOp::EXECUTE, None)?;
+        }
+    }
+    for adr in &index.adrs {
+        write_adr(&adr.path, &adr.spec, WriteOp::EXECUTE, None)?;
+    }
+    for item in &index.work_items {
+        write_work_item(&item.path, &item.spec, WriteOp::EXECUTE, None)?;
+    }
+    Ok(())
+}
+
+/// Every spec in a project, as JSON, for comparing two loads.
+#[derive(Debug, PartialEq)]
+struct Snapshot {
+    rfcs: Vec<serde_json::Value>,
+    clauses: Vec<serde_json::Value>,
+    adrs: Vec<serde_json::Value>,
+    work_items: Vec<serde_json::Value>,
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<serde_json::Value, TestCaseError> {
+    serde_json::to_value(value).map_err(|err| TestCaseError::fail(err.to_string()))
+}
+
+fn to_json_all<'a, T: Serialize + 'a>(
+    values: impl Iterator<Item = &'a T>,
+) -> Result<Vec<serde_json::Value>, TestCaseError> {
+    values.map(to_json).collect()
+}
+
+impl Snapshot {
+    fn of_project(project: &Project) -> Result<Self, TestCaseError> {
+        Ok(Self {
+            rfcs: to_json_all(project.rfcs.iter().map(|(rfc, _)| rfc))?,
+            clauses: to_json_all(project.rfcs.iter().flat_map(|(_, clauses)| clauses))?,
+            adrs: to_json_all(project.adrs.iter())?,
+            work_items: to_json_all(project.work_items.iter())?,
+        })
+    }
+
+    fn of_index(index: &ProjectIndex) -> Result<Self, TestCaseError> {
+        Ok(Self {
+            rfcs: to_json_all(index.rfcs.iter().map(|rfc| &rfc.rfc))?,
+            clauses: to_json_all(index.iter_clauses().map(|(_, clause)| &clause.spec))?,
+            adrs: to_json_all(index.adrs.iter().map(|adr| &adr.spec))?,
+            work_items: to_json_all(index.work_items.iter().map(|item| &item.spec))?,
+        })
+    }
+}
+
+/// One edit to a file's bytes: delete, duplicate, splice, overwrite, or
+/// truncate at a position. Edits work on bytes, so the result need not be
+/// valid UTF-8.
+#[derive(Clone, Debug)]
+enum Edit {
+    Delete(Index, usize),
+    Duplicate(Index, usize),
+    Splice(Index, &'static str),
+    Overwrite(Index, u8),
+    Truncate(Index),
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        (any::<Index>(), 0..=16usize).prop_map(|(at, len)| Edit::Delete(at, len)),
+        (any::<Index>(), 0..=16usize).prop_map(|(at, len)| Edit::Duplicate(at, len)),
+        (any::<Index>(), select(SPLICES)).prop_map(|(at, text)| Edit::Splice(at, text)),
+        (any::<Index>(), any::<u8>()).prop_map(|(at, byte)| Edit::Overwrite(at, byte)),
+        any::<Index>().prop_map(Edit::Truncate),
+    ]
+}
+
+fn apply(source: &str, edits: &[Edit]) -> Vec<u8> {
+    let mut bytes = source.as_bytes().to_vec();
+    for edit in edits {
+        // Positions run from 0 to the length inclusive.
+        let at = |index: &Index| index.index(bytes.len() + 1);
+        match edit {
+            Edit::Delete(index, len) => {
+                let at = at(index);
+                bytes.drain(at..(at + len).min(bytes.len()));
+            }
+            Edit::Duplicate(index, len) => {
+                let at = at(index);
+                let copy = bytes[at..(at + len).min(bytes.len())].to_vec();
+                bytes.splice(at..at, copy);
+            }
+            Edit::Splice(index, text) => {
+                let at = at(index);
+                bytes.splice(at..at, text.bytes());
+            }
+            Edit::Overwrite(index, byte) => {
+                let at = at(index);
+                if let Some(slot) = bytes.get_mut(at) {
+                    *slot = *byte;
+                }
+            }
+            Edit::Truncate(index) => {
+                let at = at(index);
+                bytes.truncate(at);
+            }
+        }
+    }
+    bytes
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn written_artifacts_read_back_and_rewrite_identically(project in project()) {
+        let scratch = Scratch::write(&project)?;
+        let first = scratch.load()?;
+        prop_assert_eq!(Snapshot::of_project(&project)?, Snapshot::of_index(&first)?);
+
+        let written = scratch.contents()?;
+        write_index(&first)?;
+        for ((file, before), after) in scratch.files.iter().zip(&written).zip(scratch.contents()?) {
+            prop_assert_eq!(before, &after, "rewriting {} changed it", file.path.display());
+        }
+        let second = scratch.load()?;
+        prop_assert_eq!(Snapshot::of_index(&first)?, Snapshot::of_index(&second)?);
+    }
+
+    #[test]
+    fn validation_is_idempotent(project in project()) {
+        let scratch = Scratch::write(&project)?;
+        let first = scratch.load()?;
+        let report = scratch.validation_report(&first);
+        prop_assert_eq!(&report, &scratch.validation_report(&first));
+
+        write_index(&first)?;
+        let second = scratch.load()?;
+        prop_assert_eq!(&report, &scratch.validation_report(&second));
+    }
+
+    #[test]
+    fn malformed_artifacts_fail_without_panicking(
+        project in project(),
+        file in any::<Index>(),
+        edits in prop::collection::vec(edit(), 1..=4),
+    ) {
+        let scratch = Scratch::write(&project)?;
+        let file = &scratch.files[file.index(scratch.files.len())];
+        let original = std::fs::read_to_string(&file.path)
+            .map_err(|err| TestCaseError::fail(err.to_string()))?;
+        std::fs::write(&file.path, apply(&original, &edits))
+            .map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+        // Either outcome is fine; a panic fails the case.
+        let config = &scratch.config;
+        let _ = match file.kind {
+            Kind::Rfc => load_rfc(config, &file.path).map(drop).map_err(Diagnostic::from),
+            Kind::Clause => load_clause(config, &file.path).map(drop).map_err(Diagnostic::from),
+            Kind::Adr => load_adr(config, &file.path).map(drop),
+            Kind::WorkItem => load_work_item(config, &file.path).map(drop),
+        };
+    }
+}
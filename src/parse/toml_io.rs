@@ -1,8 +1,8 @@
 use super::LoadResult;
 use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, Span};
 use crate::schema::{ArtifactSchema, validate_toml_value, with_schema_header};
-use crate::write::{WriteOp, write_file};
+use crate::write::{WriteOp, preserve_layout, write_file};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::path::Path;
@@ -52,6 +52,7 @@ where
 {
     let content = std::fs::read_to_string(path)
         .map_err(|e| Diagnostic::io_error("read TOML file", e, path.display().to_string()))?;
+    tracing::trace!("read {}", config.display_path(path).display());
 
     let raw: toml::Value = toml::from_str(&content).map_err(|e| {
         Diagnostic::new(
@@ -59,16 +60,18 @@ where
             format!("{invalid_toml_context}: {e}"),
             path.display().to_string(),
         )
+        .with_span(Span::of_toml_error(&content, &e))
     })?;
     let mut schema_raw = raw.clone();
     prepare_schema_value(&mut schema_raw);
-    validate_toml_value(schema, config, path, &schema_raw)?;
+    validate_toml_value(schema, config, path, &content, &schema_raw)?;
     raw.try_into().map_err(|e| {
         Diagnostic::new(
             diagnostic_code,
             format!("{invalid_structure_context}: {e}"),
             path.display().to_string(),
         )
+        .with_span(Span::of_deserialize_error::<T>(&content, &e))
     })
 }
 
@@ -91,7 +94,7 @@ where
             diagnostic_path.display().to_string(),
         )
     })?;
-    let content = with_schema_header(schema, &body);
+    let content = preserve_layout(path, with_schema_header(schema, &body));
 
     write_file(path, &content, op, Some(diagnostic_path))
 }
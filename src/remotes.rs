@@ -0,0 +1,270 @@
+//! Cross-repository references: `refs` entries such as `platform:RFC-0004`.
+//!
+//! Each remote is declared in `gov/config.toml` and resolved either from a
+//! checkout of the other project or from a bundle it published with
+//! `govctl export`:
+//!
+//! ```toml
+//! [remotes.platform]
+//! path = "../platform"
+//!
+//! [remotes.billing]
+//! bundle = "vendor/billing-refs.json"
+//! ```
+//!
+//! A remote that cannot be read (say, a sibling checkout missing in CI) is a
+//! warning: its references are left unverified rather than failing `check`.
+
+use crate::artifact_index::artifact_ref_ids;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::load::load_project;
+use crate::model::ProjectIndex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Version of the bundle format written by `govctl export`.
+pub const BUNDLE_FORMAT: u32 = 1;
+
+/// Artifact IDs published by a project for other repositories to reference.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefBundle {
+    pub format: u32,
+    pub project: String,
+    pub govctl_version: String,
+    pub artifacts: Vec<BundleArtifact>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleArtifact {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+}
+
+impl RefBundle {
+    /// Every referenceable artifact in `index`, clauses included, sorted by ID.
+    pub fn from_index(project: &str, index: &ProjectIndex) -> Self {
+        let mut artifacts = Vec::new();
+        for rfc in &index.rfcs {
+            artifacts.push(BundleArtifact {
+                id: rfc.rfc.rfc_id.clone(),
+                title: rfc.rfc.title.clone(),
+                status: rfc.rfc.status.as_ref().to_string(),
+            });
+            for clause in &rfc.clauses {
+                artifacts.push(BundleArtifact {
+                    id: format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id),
+                    title: clause.spec.title.clone(),
+                    status: clause.spec.status.as_ref().to_string(),
+                });
+            }
+        }
+        for adr in &index.adrs {
+            artifacts.push(BundleArtifact {
+                id: adr.meta().id.clone(),
+                title: adr.meta().title.clone(),
+                status: adr.meta().status.as_ref().to_string(),
+            });
+        }
+        for work in &index.work_items {
+            artifacts.push(BundleArtifact {
+                id: work.meta().id.clone(),
+                title: work.meta().title.clone(),
+                status: work.meta().status.as_ref().to_string(),
+            });
+        }
+        artifacts.sort_by(|a, b| a.id.cmp(&b.id));
+        Self {
+            format: BUNDLE_FORMAT,
+            project: project.to_string(),
+            govctl_version: env!("CARGO_PKG_VERSION").to_string(),
+            artifacts,
+        }
+    }
+}
+
+/// Split `platform:RFC-0004` into `("platform", "RFC-0004")`.
+///
+/// Local clause refs such as `RFC-0004:C-SCOPE` have an uppercase prefix and
+/// are not remote refs.
+pub fn split_remote_ref(ref_id: &str) -> Option<(&str, &str)> {
+    let (remote, id) = ref_id.split_once(':')?;
+    let mut chars = remote.chars();
+    let starts_well = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+    let rest_ok =
+        chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    (starts_well && rest_ok).then_some((remote, id))
+}
+
+/// Outcome of resolving one remote ref.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RemoteLookup<'a> {
+    Found,
+    Missing,
+    /// No `[remotes.<name>]` in config.
+    NotConfigured,
+    /// The remote could not be read; carries the reason.
+    Unavailable(&'a str),
+}
+
+/// Artifact IDs of the configured remotes, loaded on first use.
+#[derive(Debug, Default)]
+pub struct RemoteIndex {
+    remotes: BTreeMap<String, Result<HashSet<String>, String>>,
+}
+
+impl RemoteIndex {
+    /// Load every configured remote named by one of `refs`.
+    pub fn for_refs<'a>(config: &Config, refs: impl IntoIterator<Item = &'a String>) -> Self {
+        let mut index = Self::default();
+        for ref_id in refs {
+            let Some((name, _)) = split_remote_ref(ref_id) else {
+                continue;
+            };
+            if index.remotes.contains_key(name) || !config.remotes.contains_key(name) {
+                continue;
+            }
+            index
+                .remotes
+                .insert(name.to_string(), load_remote_ids(config, name));
+        }
+        index
+    }
+
+    pub fn lookup(&self, remote: &str, id: &str) -> RemoteLookup<'_> {
+        match self.remotes.get(remote) {
+            None => RemoteLookup::NotConfigured,
+            Some(Ok(ids)) if ids.contains(id) => RemoteLookup::Found,
+            Some(Ok(_)) => RemoteLookup::Missing,
+            Some(Err(reason)) => RemoteLookup::Unavailable(reason),
+        }
+    }
+
+    /// One W0117 warning per remote that could not be read.
+    pub fn unavailable_diagnostics(&self, config: &Config) -> Vec<Diagnostic> {
+        self.remotes
+            .iter()
+            .filter_map(|(name, ids)| ids.as_ref().err().map(|reason| (name, reason)))
+            .map(|(name, reason)| {
+                Diagnostic::new(
+                    DiagnosticCode::W0117RemoteUnavailable,
+                    format!("References to remote '{name}' were not verified: {reason}"),
+                    config
+                        .display_path(&config.gov_root.join("config.toml"))
+                        .display()
+                        .to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Diagnostic for a ref naming a remote missing from `[remotes]`.
+pub fn remote_not_configured(
+    config: &Config,
+    owner_id: &str,
+    ref_id: &str,
+    path_display: &str,
+) -> Diagnostic {
+    let remote = split_remote_ref(ref_id).map_or(ref_id, |(remote, _)| remote);
+    let mut message =
+        format!("'{owner_id}' references {ref_id}, but no [remotes.{remote}] is configured");
+    if !config.remotes.is_empty() {
+        let known: Vec<&str> = config.remotes.keys().map(String::as_str).collect();
+        message.push_str(&format!(" (remotes: {})", known.join(", ")));
+    }
+    Diagnostic::new(
+        DiagnosticCode::E0512RemoteNotConfigured,
+        message,
+        path_display,
+    )
+}
+
+/// IDs published by remote `name`, or why they could not be read.
+fn load_remote_ids(config: &Config, name: &str) -> Result<HashSet<String>, String> {
+    let Some(remote) = config.remotes.get(name) else {
+        return Err(format!("no [remotes.{name}] in config"));
+    };
+    match (&remote.path, &remote.bundle) {
+        (Some(path), None) => {
+            let root = config.project_root().join(path);
+            load_checkout_ids(&root).map_err(|diag| diag.message)
+        }
+        (None, Some(bundle)) => {
+            let path = config.project_root().join(bundle);
+            read_bundle(&path).map(|bundle| {
+                bundle
+                    .artifacts
+                    .into_iter()
+                    .map(|artifact| artifact.id)
+                    .collect()
+            })
+        }
+        _ => Err(format!(
+            "[remotes.{name}] must set exactly one of `path` or `bundle`"
+        )),
+    }
+}
+
+fn load_checkout_ids(root: &Path) -> DiagnosticResult<HashSet<String>> {
+    let config_path = root.join("gov").join("config.toml");
+    if !config_path.is_file() {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0502PathNotFound,
+            format!("{} not found", config_path.display()),
+            config_path.display().to_string(),
+        ));
+    }
+    let config = Config::load(Some(&config_path))?;
+    let index = load_project(&config).map_err(|mut diagnostics| {
+        if diagnostics.is_empty() {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!("Failed to load project at {}", root.display()),
+                root.display().to_string(),
+            )
+        } else {
+            diagnostics.remove(0)
+        }
+    })?;
+    Ok(artifact_ref_ids(&index))
+}
+
+fn read_bundle(path: &Path) -> Result<RefBundle, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    let bundle: RefBundle = serde_json::from_str(&content)
+        .map_err(|err| format!("{} is not a govctl export bundle: {err}", path.display()))?;
+    if bundle.format != BUNDLE_FORMAT {
+        return Err(format!(
+            "{} has bundle format {}, expected {BUNDLE_FORMAT}",
+            path.display(),
+            bundle.format
+        ));
+    }
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_only_lowercase_remote_prefixes() {
+        assert_eq!(
+            split_remote_ref("platform:RFC-0004"),
+            Some(("platform", "RFC-0004"))
+        );
+        assert_eq!(
+            split_remote_ref("core-v2:RFC-0004:C-SCOPE"),
+            Some(("core-v2", "RFC-0004:C-SCOPE"))
+        );
+        assert_eq!(split_remote_ref("RFC-0004:C-SCOPE"), None);
+        assert_eq!(split_remote_ref("ADR-0001"), None);
+        assert_eq!(split_remote_ref(":RFC-0004"), None);
+    }
+}
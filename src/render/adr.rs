@@ -1,7 +1,11 @@
-use super::{RenderProjection, render_refs, write_expanded_rendered_md};
-use crate::config::Config;
+use super::{
+    Drift, RenderMode, RenderProjection, apply_template, copy_attachments, front_matter,
+    render_attachments, render_deprecation, render_refs, write_expanded_rendered_md,
+};
+use crate::config::{Config, PrefixesConfig, RenderConfig, RenderSection};
 use crate::diagnostic::DiagnosticResult;
 use crate::model::{AdrEntry, AdrStatus, AlternativeStatus};
+use crate::secrets::REDACTED;
 use crate::signature::{compute_adr_signature, format_signature_header};
 use std::fmt::Write as FmtWrite;
 
@@ -9,8 +13,8 @@ use std::fmt::Write as FmtWrite;
 ///
 /// # Errors
 /// Returns an error if signature computation fails.
-pub fn render_adr(adr: &AdrEntry) -> DiagnosticResult<String> {
-    render_adr_with_projection(adr, RenderProjection::Archive)
+pub fn render_adr(prefixes: &PrefixesConfig, adr: &AdrEntry) -> DiagnosticResult<String> {
+    render_adr_with_projection(prefixes, adr, RenderProjection::Archive)
 }
 
 /// Render an ADR using the selected lifecycle projection.
@@ -18,11 +22,29 @@ pub fn render_adr(adr: &AdrEntry) -> DiagnosticResult<String> {
 /// # Errors
 /// Returns an error if signature computation fails.
 pub fn render_adr_with_projection(
+    prefixes: &PrefixesConfig,
     adr: &AdrEntry,
     projection: RenderProjection,
+) -> DiagnosticResult<String> {
+    render_adr_with_layout(prefixes, adr, projection, &RenderConfig::default())
+}
+
+/// Render an ADR, leaving out the sections `layout` omits.
+fn render_adr_with_layout<'a>(
+    prefixes: &PrefixesConfig,
+    adr: &'a AdrEntry,
+    projection: RenderProjection,
+    layout: &RenderConfig,
 ) -> DiagnosticResult<String> {
     let meta = adr.meta();
     let content = &adr.spec.content;
+    let shown = |field: &str, value: &'a str| -> &'a str {
+        if meta.sensitive.iter().any(|name| name == field) {
+            REDACTED
+        } else {
+            value
+        }
+    };
     let mut out = String::new();
 
     // Compute signature (per ADR-0003)
@@ -46,6 +68,12 @@ pub fn render_adr_with_projection(
     if let Some(ref by) = meta.superseded_by {
         let _ = writeln!(out, "> **Superseded by:** {by}");
     }
+    if let Some(ref notice) = meta.deprecation {
+        render_deprecation(&mut out, notice);
+    }
+    if !meta.approvals.is_empty() && layout.shows(RenderSection::Approvals) {
+        let _ = writeln!(out, "> **Approved by:** {}", meta.approvals.join(", "));
+    }
     let _ = writeln!(out);
 
     // Tags
@@ -56,7 +84,7 @@ pub fn render_adr_with_projection(
 
     // References (expanded to markdown links)
     if !meta.refs.is_empty() {
-        let _ = writeln!(out, "**References:** {}", render_refs(&meta.refs));
+        let _ = writeln!(out, "**References:** {}", render_refs(prefixes, &meta.refs));
         let _ = writeln!(out);
     }
 
@@ -69,23 +97,42 @@ pub fn render_adr_with_projection(
     // Context
     let _ = writeln!(out, "## Context");
     let _ = writeln!(out);
-    let _ = writeln!(out, "{}", content.context);
+    let _ = writeln!(out, "{}", shown("context", &content.context));
     let _ = writeln!(out);
 
+    // Decision drivers and stakeholders (MADR)
+    for (heading, items, shown) in [
+        ("Decision Drivers", &content.drivers, true),
+        (
+            "Stakeholders",
+            &content.stakeholders,
+            layout.shows(RenderSection::Stakeholders),
+        ),
+    ] {
+        if !items.is_empty() && shown {
+            let _ = writeln!(out, "## {heading}");
+            let _ = writeln!(out);
+            for item in items {
+                let _ = writeln!(out, "- {item}");
+            }
+            let _ = writeln!(out);
+        }
+    }
+
     // Decision
     let _ = writeln!(out, "## Decision");
     let _ = writeln!(out);
-    let _ = writeln!(out, "{}", content.decision);
+    let _ = writeln!(out, "{}", shown("decision", &content.decision));
     let _ = writeln!(out);
 
     // Consequences
     let _ = writeln!(out, "## Consequences");
     let _ = writeln!(out);
-    let _ = writeln!(out, "{}", content.consequences);
+    let _ = writeln!(out, "{}", shown("consequences", &content.consequences));
     let _ = writeln!(out);
 
     // Alternatives Considered (extended per ADR-0027)
-    if !content.alternatives.is_empty() {
+    if !content.alternatives.is_empty() && layout.shows(RenderSection::Alternatives) {
         let _ = writeln!(out, "## Alternatives Considered");
         let _ = writeln!(out);
         for alt in &content.alternatives {
@@ -117,14 +164,42 @@ pub fn render_adr_with_projection(
         }
     }
 
+    render_attachments(&mut out, &meta.attachments);
+
     Ok(out)
 }
 
 /// Write rendered ADR to file
-pub fn write_adr_md(config: &Config, adr: &AdrEntry, dry_run: bool) -> DiagnosticResult<()> {
+pub fn write_adr_md(config: &Config, adr: &AdrEntry, mode: RenderMode) -> DiagnosticResult<Drift> {
     let meta = adr.meta();
     let output_path = config.adr_output().join(format!("{}.md", meta.id));
+    let raw = adr_markdown(config, adr)?;
+    let front_matter = front_matter(config, &meta.id, &meta.title, &meta.tags);
+    let mut drift = write_expanded_rendered_md(config, &output_path, &raw, front_matter, mode)?;
+    drift.extend(copy_attachments(config, &meta.attachments, mode)?);
+    Ok(drift)
+}
 
-    let raw = render_adr(adr)?;
-    write_expanded_rendered_md(config, &output_path, &raw, dry_run, 15)
+/// The ADR as written to the docs tree, before inline references are expanded.
+pub(super) fn adr_markdown(config: &Config, adr: &AdrEntry) -> DiagnosticResult<String> {
+    let adr = &crate::fragments::expand_adr(config, adr)?;
+    let mut context = tera::Context::new();
+    let mut spec = adr.spec.clone();
+    if !config.render.shows(RenderSection::Approvals) {
+        spec.govctl.approvals.clear();
+    }
+    if !config.render.shows(RenderSection::Stakeholders) {
+        spec.content.stakeholders.clear();
+    }
+    if !config.render.shows(RenderSection::Alternatives) {
+        spec.content.alternatives.clear();
+    }
+    context.insert("adr", &spec);
+    let builtin = render_adr_with_layout(
+        &config.prefixes,
+        adr,
+        RenderProjection::Archive,
+        &config.render,
+    )?;
+    apply_template(config, "adr", &adr.meta().id, builtin, context)
 }
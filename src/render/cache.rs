@@ -0,0 +1,228 @@
+//! Render cache: skip artifacts whose rendered file is already current.
+//!
+//! `.govctl/render-cache.json` maps each rendered file to a fingerprint of
+//! everything it was rendered from and the hash of the bytes written. A file
+//! is current when both still match: the inputs are unchanged and nobody
+//! edited or deleted the output since.
+//!
+//! An artifact's inputs are its source signature ([[ADR-0003]]), the
+//! attachments it copies, and, for RFCs, the ADRs that cite its clauses.
+//! Inputs every artifact shares — the govctl version, the project config,
+//! templates, and fragments — are hashed once per run into each fingerprint.
+//!
+//! The cache is derived local state: deleting it only costs a full render.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticResult};
+use crate::model::{AdrEntry, AdrStatus, RfcIndex, WorkItemEntry};
+use crate::signature::{compute_adr_signature, compute_rfc_signature, compute_work_item_signature};
+use crate::write::replace_file;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const CACHE_FILE: &str = "render-cache.json";
+
+#[derive(Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Rendered files by display path.
+    outputs: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    inputs: String,
+    output: String,
+}
+
+/// What each rendered file was last rendered from.
+pub struct RenderCache {
+    path: PathBuf,
+    shared: String,
+    file: CacheFile,
+    changed: bool,
+}
+
+impl RenderCache {
+    /// Read the project's cache; a missing or unreadable cache is empty.
+    pub fn load(config: &Config) -> DiagnosticResult<Self> {
+        let path = config.project_root().join(".govctl").join(CACHE_FILE);
+        let file = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Ok(Self {
+            path,
+            shared: shared_inputs(config)?,
+            file,
+            changed: false,
+        })
+    }
+
+    /// Fingerprint of an RFC's inputs, given every ADR in the project.
+    pub fn rfc_inputs(
+        &self,
+        config: &Config,
+        rfc: &RfcIndex,
+        adrs: &[AdrEntry],
+    ) -> DiagnosticResult<String> {
+        let mut hasher = self.hasher(&compute_rfc_signature(rfc)?);
+        let prefix = format!("{}:", rfc.rfc.rfc_id);
+        for adr in adrs {
+            let meta = adr.meta();
+            if matches!(meta.status, AdrStatus::Proposed | AdrStatus::Accepted)
+                && meta.refs.iter().any(|ref_id| ref_id.starts_with(&prefix))
+            {
+                hash_field(&mut hasher, &meta.id);
+                hash_field(&mut hasher, &meta.title);
+                hash_field(&mut hasher, meta.status.as_ref());
+                for ref_id in &meta.refs {
+                    hash_field(&mut hasher, ref_id);
+                }
+            }
+        }
+        hash_attachments(&mut hasher, config, &rfc.rfc.attachments);
+        Ok(hex(hasher))
+    }
+
+    /// Fingerprint of an ADR's inputs.
+    pub fn adr_inputs(&self, config: &Config, adr: &AdrEntry) -> DiagnosticResult<String> {
+        let mut hasher = self.hasher(&compute_adr_signature(adr)?);
+        hash_attachments(&mut hasher, config, &adr.meta().attachments);
+        Ok(hex(hasher))
+    }
+
+    /// Fingerprint of a work item's inputs.
+    pub fn work_item_inputs(
+        &self,
+        config: &Config,
+        item: &WorkItemEntry,
+    ) -> DiagnosticResult<String> {
+        let mut hasher = self.hasher(&compute_work_item_signature(item)?);
+        hash_attachments(&mut hasher, config, &item.meta().attachments);
+        Ok(hex(hasher))
+    }
+
+    /// Whether `output` holds what rendering from `inputs` last wrote.
+    pub fn is_current(&self, config: &Config, output: &Path, inputs: &str) -> bool {
+        let Some(entry) = self.file.outputs.get(&key(config, output)) else {
+            return false;
+        };
+        entry.inputs == inputs
+            && std::fs::read(output).is_ok_and(|bytes| sha256_hex(&bytes) == entry.output)
+    }
+
+    /// Note that `output` was just rendered from `inputs`.
+    pub fn record(&mut self, config: &Config, output: &Path, inputs: String) {
+        let key = key(config, output);
+        match std::fs::read(output) {
+            Ok(bytes) => {
+                self.file.outputs.insert(
+                    key,
+                    CacheEntry {
+                        inputs,
+                        output: sha256_hex(&bytes),
+                    },
+                );
+            }
+            Err(_) => {
+                self.file.outputs.remove(&key);
+            }
+        }
+        self.changed = true;
+    }
+
+    /// Write the cache back if anything was recorded.
+    pub fn save(&self, config: &Config) -> DiagnosticResult<()> {
+        if !self.changed {
+            return Ok(());
+        }
+        let display_path = config.display_path(&self.path);
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| {
+                Diagnostic::io_error(
+                    "create render cache directory",
+                    err,
+                    display_path.display().to_string(),
+                )
+            })?;
+        }
+        let content = serde_json::to_string_pretty(&self.file).map_err(|err| {
+            Diagnostic::io_error(
+                "serialize render cache",
+                err,
+                display_path.display().to_string(),
+            )
+        })?;
+        replace_file(
+            &self.path,
+            content.as_bytes(),
+            &display_path,
+            config.concurrency.fsync,
+        )
+    }
+
+    fn hasher(&self, signature: &str) -> Sha256 {
+        let mut hasher = Sha256::new();
+        hash_field(&mut hasher, &self.shared);
+        hash_field(&mut hasher, signature);
+        hasher
+    }
+}
+
+/// Hash of the inputs every rendered artifact depends on.
+fn shared_inputs(config: &Config) -> DiagnosticResult<String> {
+    let mut hasher = Sha256::new();
+    hash_field(&mut hasher, env!("CARGO_PKG_VERSION"));
+    let config_json = serde_json::to_string(config)
+        .map_err(|err| Diagnostic::io_error("serialize config for render cache", err, "config"))?;
+    hash_field(&mut hasher, &config_json);
+    hash_field(&mut hasher, &config.paths.docs_output.display().to_string());
+    for dir in [
+        config.templates_dir().join("render"),
+        config.fragments_dir(),
+    ] {
+        let mut files: Vec<PathBuf> = walkdir::WalkDir::new(&dir)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .map(walkdir::DirEntry::into_path)
+            .collect();
+        files.sort();
+        for file in files {
+            hash_field(&mut hasher, &file.display().to_string());
+            hasher.update(std::fs::read(&file).unwrap_or_default());
+        }
+    }
+    Ok(hex(hasher))
+}
+
+fn hash_attachments(hasher: &mut Sha256, config: &Config, attachments: &[String]) {
+    for attachment in attachments {
+        hash_field(hasher, attachment);
+        if let Ok(source) = crate::validate::attachment_path(config, attachment, attachment) {
+            hasher.update(std::fs::read(source).unwrap_or_default());
+        }
+    }
+}
+
+/// Hash `value` followed by a separator, so adjacent fields cannot run together.
+fn hash_field(hasher: &mut Sha256, value: &str) {
+    hasher.update(value.as_bytes());
+    hasher.update([0]);
+}
+
+fn key(config: &Config, output: &Path) -> String {
+    config.display_path(output).display().to_string()
+}
+
+fn hex(hasher: Sha256) -> String {
+    format!("{:x}", hasher.finalize())
+}
+
+fn sha256_hex(content: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content);
+    hex(hasher)
+}
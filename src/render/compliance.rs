@@ -0,0 +1,62 @@
+//! `compliance.md`: the matrix of external standard controls and the clauses
+//! that satisfy them, per [[RFC-0002:C-RESOURCES]].
+
+use super::index::table;
+use super::links::ref_link_with_base;
+use super::output::write_rendered_md;
+use super::{Drift, RenderMode};
+use crate::artifact_index::{ArtifactRefState, artifact_ref_states};
+use crate::config::Config;
+use crate::diagnostic::DiagnosticResult;
+use crate::model::{MappingEntry, ProjectIndex};
+use crate::validate::control_coverage;
+use std::fmt::Write as FmtWrite;
+use std::path::PathBuf;
+
+/// `<docs_output>/compliance.md`.
+pub fn compliance_path(config: &Config) -> PathBuf {
+    config.paths.docs_output.join("compliance.md")
+}
+
+/// Write the compliance matrix with one table per mapped standard.
+pub fn write_compliance_matrix(
+    config: &Config,
+    mappings: &[MappingEntry],
+    index: &ProjectIndex,
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let states = artifact_ref_states(index);
+    let mut out = String::from(
+        "<!-- GENERATED: do not edit. Rebuilt by `govctl render compliance`. -->\n\n# Compliance Matrix\n",
+    );
+    for mapping in mappings {
+        let meta = mapping.meta();
+        let _ = write!(out, "\n## {} ({})\n\n", meta.title, meta.id);
+        let rows = mapping.spec.controls.iter().map(|control| {
+            let clauses: Vec<String> = control
+                .clauses
+                .iter()
+                .map(|clause| match states.get(clause) {
+                    Some(ArtifactRefState::Active) => {
+                        ref_link_with_base(&config.prefixes, clause, ".")
+                    }
+                    Some(ArtifactRefState::Outdated(reason)) => {
+                        format!(
+                            "{} ({reason})",
+                            ref_link_with_base(&config.prefixes, clause, ".")
+                        )
+                    }
+                    None => format!("{clause} (missing)"),
+                })
+                .collect();
+            vec![
+                control.id.clone(),
+                control.title.clone().unwrap_or_default(),
+                clauses.join(", "),
+                control_coverage(&states, &control.clauses).to_string(),
+            ]
+        });
+        out.push_str(&table(&["Control", "Title", "Clauses", "Coverage"], rows));
+    }
+    write_rendered_md(config, &compliance_path(config), &out, mode)
+}
@@ -0,0 +1,37 @@
+//! `render --check`: compare rendered output with the files on disk.
+//!
+//! A check renders everything a dry run would, but where a dry run would
+//! preview a rendered file, it instead returns the file if its content on
+//! disk differs (or it is missing), and prints nothing.
+
+use std::path::{Path, PathBuf};
+
+/// Display paths of rendered files that differ from disk.
+pub type Drift = Vec<PathBuf>;
+
+/// Whether `govctl render` writes, previews, or compares with disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    Write,
+    DryRun,
+    Check,
+}
+
+impl RenderMode {
+    pub fn from_flags(dry_run: bool, check: bool) -> Self {
+        match (dry_run, check) {
+            (_, true) => Self::Check,
+            (true, false) => Self::DryRun,
+            (false, false) => Self::Write,
+        }
+    }
+}
+
+/// `display_path` when `path` does not hold exactly `content`.
+pub fn check_rendered(path: &Path, content: &[u8], display_path: &Path) -> Drift {
+    if std::fs::read(path).map_or(true, |current| current != content) {
+        vec![display_path.to_path_buf()]
+    } else {
+        Drift::new()
+    }
+}
@@ -0,0 +1,47 @@
+//! YAML front matter from `[render.front_matter]`, so rendered markdown drops
+//! into MkDocs and Docusaurus sites as is.
+
+use crate::config::{Config, FrontMatterField};
+use std::fmt::Write as FmtWrite;
+
+/// The front matter block for an artifact, or `None` when it is off.
+pub(super) fn front_matter(
+    config: &Config,
+    id: &str,
+    title: &str,
+    tags: &[String],
+) -> Option<String> {
+    let settings = &config.render.front_matter;
+    if !settings.enabled {
+        return None;
+    }
+    let mut out = String::from("---\n");
+    for field in &settings.fields {
+        match field {
+            FrontMatterField::Title => {
+                let _ = writeln!(out, "title: {}", quote(&format!("{id}: {title}")));
+            }
+            FrontMatterField::Slug => {
+                let _ = writeln!(out, "slug: {}", id.to_lowercase());
+            }
+            FrontMatterField::SidebarPosition => {
+                let digits: String = id.chars().filter(char::is_ascii_digit).collect();
+                if let Ok(position) = digits.parse::<u64>() {
+                    let _ = writeln!(out, "sidebar_position: {position}");
+                }
+            }
+            FrontMatterField::Tags if !tags.is_empty() => {
+                let tags: Vec<_> = tags.iter().map(|tag| quote(tag)).collect();
+                let _ = writeln!(out, "tags: [{}]", tags.join(", "));
+            }
+            FrontMatterField::Tags => {}
+        }
+    }
+    out.push_str("---\n");
+    Some(out)
+}
+
+/// `text` as a double-quoted YAML scalar.
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
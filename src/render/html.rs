@@ -0,0 +1,109 @@
+//! Rendered RFCs and ADRs as XHTML, for publishing outside the docs tree.
+
+use super::adr::adr_markdown;
+use super::expand_inline_refs_plain;
+use super::rfc::rfc_markdown;
+use crate::config::Config;
+use crate::diagnostic::DiagnosticResult;
+use crate::diagrams::{DiagramKind, render_svg};
+use crate::model::{AdrEntry, RfcIndex};
+use crate::ui;
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd, html};
+
+/// The rendered RFC body as XHTML.
+pub fn rfc_html(config: &Config, rfc: &RfcIndex) -> DiagnosticResult<String> {
+    Ok(to_xhtml(config, &rfc_markdown(config, rfc)?))
+}
+
+/// The rendered ADR body as XHTML.
+pub fn adr_html(config: &Config, adr: &AdrEntry) -> DiagnosticResult<String> {
+    Ok(to_xhtml(config, &adr_markdown(config, adr)?))
+}
+
+/// Inline references become plain IDs, since the docs-tree links do not
+/// resolve elsewhere. Comments (the signature header) and section anchors
+/// are dropped, and diagram blocks become SVG where `[render.diagrams]` has
+/// a command for them.
+fn to_xhtml(config: &Config, markdown: &str) -> String {
+    let markdown = expand_inline_refs_plain(markdown, &config.source_scan.pattern);
+    let events = Parser::new_ext(
+        &markdown,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    )
+    .filter(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => {
+            let raw = raw.trim_start();
+            !(raw.starts_with("<!--") || raw.starts_with("<a ") || raw.starts_with("</a>"))
+        }
+        _ => true,
+    });
+    let mut out = String::new();
+    html::push_html(&mut out, with_rendered_diagrams(config, events).into_iter());
+    out
+}
+
+/// Replace each diagram code block with the SVG its command produces. A
+/// block whose command fails is kept as code.
+fn with_rendered_diagrams<'a>(
+    config: &Config,
+    events: impl Iterator<Item = Event<'a>>,
+) -> Vec<Event<'a>> {
+    let mut out = Vec::new();
+    let mut block: Option<(DiagramKind, Vec<Event<'a>>)> = None;
+    for event in events {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(ref info))) if block.is_none() => {
+                match DiagramKind::from_info(info) {
+                    Some(kind) => block = Some((kind, vec![event])),
+                    None => out.push(event),
+                }
+            }
+            Event::End(TagEnd::CodeBlock) if block.is_some() => {
+                let Some((kind, mut events)) = block.take() else {
+                    continue;
+                };
+                events.push(event);
+                let source: String = events
+                    .iter()
+                    .filter_map(|event| match event {
+                        Event::Text(text) => Some(text.as_ref()),
+                        _ => None,
+                    })
+                    .collect();
+                match render_svg(config, kind, &source) {
+                    Ok(Some(svg)) => out.push(Event::Html(CowStr::from(format!("{svg}\n")))),
+                    Ok(None) => out.extend(events),
+                    Err(err) => {
+                        ui::hint(format!("{} diagram left as code: {err}", kind.as_str()));
+                        out.extend(events);
+                    }
+                }
+            }
+            event => match &mut block {
+                Some((_, events)) => events.push(event),
+                None => out.push(event),
+            },
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagram_blocks_become_svg_when_a_command_is_set() {
+        let mut config = Config::default();
+        config.render.diagrams.mermaid =
+            Some("cat >/dev/null; printf '<?xml version=\"1.0\"?>\\n<svg id=\"d\"/>'".to_string());
+        let html = to_xhtml(
+            &config,
+            "```mermaid\ngraph TD\n  A --> B\n```\n\n```plantuml\n@startuml\nA -> B\n@enduml\n```\n",
+        );
+        assert!(html.contains("<svg id=\"d\"/>"), "{html}");
+        assert!(!html.contains("<?xml"), "{html}");
+        assert!(!html.contains("graph TD"), "{html}");
+        assert!(html.contains("@startuml"), "{html}");
+    }
+}
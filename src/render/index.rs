@@ -0,0 +1,114 @@
+//! `index.md` for each rendered output directory, so the docs tree can be
+//! browsed without opening every file.
+
+use super::output::write_rendered_md_if_changed;
+use super::{Drift, RenderMode};
+use crate::config::Config;
+use crate::diagnostic::DiagnosticResult;
+use crate::model::{AdrEntry, RfcIndex, WorkItemEntry};
+use std::fmt::Write as FmtWrite;
+use std::path::Path;
+
+const INDEX_FILE: &str = "index.md";
+
+/// Write `<rfc_output>/index.md` listing every RFC.
+pub fn write_rfc_index(
+    config: &Config,
+    rfcs: &[RfcIndex],
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let rows = rfcs.iter().map(|rfc| {
+        let spec = &rfc.rfc;
+        vec![
+            link(&spec.rfc_id),
+            spec.title.clone(),
+            spec.status.as_ref().to_string(),
+            spec.phase.as_ref().to_string(),
+            spec.version.clone(),
+            spec.updated.clone().unwrap_or_else(|| spec.created.clone()),
+        ]
+    });
+    let table = table(
+        &["ID", "Title", "Status", "Phase", "Version", "Updated"],
+        rows,
+    );
+    write_index(config, &config.rfc_output(), "RFCs", &table, mode)
+}
+
+/// Write `<adr_output>/index.md` listing every ADR.
+pub fn write_adr_index(
+    config: &Config,
+    adrs: &[AdrEntry],
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let rows = adrs.iter().map(|adr| {
+        let meta = &adr.spec.govctl;
+        vec![
+            link(&meta.id),
+            meta.title.clone(),
+            meta.status.as_ref().to_string(),
+            meta.date.clone(),
+        ]
+    });
+    let table = table(&["ID", "Title", "Status", "Date"], rows);
+    write_index(
+        config,
+        &config.adr_output(),
+        "Architecture Decision Records",
+        &table,
+        mode,
+    )
+}
+
+/// Write `<work_output>/index.md` listing every work item.
+pub fn write_work_index(
+    config: &Config,
+    items: &[WorkItemEntry],
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let rows = items.iter().map(|item| {
+        let meta = &item.spec.govctl;
+        let updated = meta
+            .completed
+            .as_ref()
+            .or(meta.started.as_ref())
+            .or(meta.created.as_ref());
+        vec![
+            link(&meta.id),
+            meta.title.clone(),
+            meta.status.as_ref().to_string(),
+            updated.cloned().unwrap_or_default(),
+        ]
+    });
+    let table = table(&["ID", "Title", "Status", "Updated"], rows);
+    write_index(config, &config.work_output(), "Work Items", &table, mode)
+}
+
+fn write_index(
+    config: &Config,
+    dir: &Path,
+    heading: &str,
+    table: &str,
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let content = format!(
+        "<!-- GENERATED: do not edit. Rebuilt by `govctl render all`. -->\n\n# {heading}\n\n{table}"
+    );
+    write_rendered_md_if_changed(config, &dir.join(INDEX_FILE), &content, mode)
+}
+
+/// Link to the artifact's rendered file, next to the index.
+fn link(id: &str) -> String {
+    format!("[{id}]({id}.md)")
+}
+
+pub(super) fn table(headers: &[&str], rows: impl Iterator<Item = Vec<String>>) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "| {} |", headers.join(" | "));
+    let _ = writeln!(out, "|{}", " --- |".repeat(headers.len()));
+    for row in rows {
+        let cells: Vec<_> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        let _ = writeln!(out, "| {} |", cells.join(" | "));
+    }
+    out
+}
@@ -1,3 +1,4 @@
+use crate::config::PrefixesConfig;
 use regex::Regex;
 
 /// Generate a markdown link for an artifact reference.
@@ -7,23 +8,26 @@ use regex::Regex;
 /// - Clause refs: `RFC-0000:C-NAME` -> `[RFC-0000:C-NAME](../rfc/RFC-0000.md#rfc-0000c-name)`
 /// - ADR refs: `ADR-0042` -> `[ADR-0042](../adr/ADR-0042.md)`
 /// - Work Item refs: `WI-2026-01-17-001` -> `[WI-2026-01-17-001](../work/WI-2026-01-17-001.md)`
-fn ref_link(ref_id: &str) -> String {
-    ref_link_with_base(ref_id, "..")
+///
+/// Remote refs such as `platform:RFC-0004` stay plain text.
+fn ref_link(prefixes: &PrefixesConfig, ref_id: &str) -> String {
+    ref_link_with_base(prefixes, ref_id, "..")
 }
 
 /// Generate a markdown link for an artifact reference from the repository root.
 ///
 /// Used for files like CHANGELOG.md that live at the root level.
 /// The `docs_output` path comes from config, for example "docs".
-pub fn ref_link_from_root(ref_id: &str, docs_output: &str) -> String {
-    ref_link_with_base(ref_id, docs_output)
+pub fn ref_link_from_root(prefixes: &PrefixesConfig, ref_id: &str, docs_output: &str) -> String {
+    ref_link_with_base(prefixes, ref_id, docs_output)
 }
 
 /// Generate a markdown link with a configurable base path.
 ///
 /// `base` is the path prefix before `/rfc/`, `/adr/`, `/work/`, for example ".." or "docs".
-fn ref_link_with_base(ref_id: &str, base: &str) -> String {
-    if ref_id.starts_with("RFC-") {
+/// RFC and ADR IDs are recognized by the project's `[prefixes]`.
+pub(super) fn ref_link_with_base(prefixes: &PrefixesConfig, ref_id: &str, base: &str) -> String {
+    if prefixes.is_rfc(ref_id) {
         if ref_id.contains(':') {
             let rfc_id = ref_id.split(':').next().unwrap_or(ref_id);
             let anchor = ref_id.to_lowercase().replace(':', "");
@@ -31,9 +35,9 @@ fn ref_link_with_base(ref_id: &str, base: &str) -> String {
         } else {
             format!("[{}]({}/rfc/{}.md)", ref_id, base, ref_id)
         }
-    } else if ref_id.starts_with("ADR-") {
+    } else if prefixes.is_adr(ref_id) {
         format!("[{}]({}/adr/{}.md)", ref_id, base, ref_id)
-    } else if ref_id.starts_with("WI-") {
+    } else if prefixes.is_work(ref_id) {
         format!("[{}]({}/work/{}.md)", ref_id, base, ref_id)
     } else {
         ref_id.to_string()
@@ -41,9 +45,9 @@ fn ref_link_with_base(ref_id: &str, base: &str) -> String {
 }
 
 /// Render a list of refs as markdown links.
-pub(super) fn render_refs(refs: &[String]) -> String {
+pub(super) fn render_refs(prefixes: &PrefixesConfig, refs: &[String]) -> String {
     refs.iter()
-        .map(|r| ref_link(r))
+        .map(|r| ref_link(prefixes, r))
         .collect::<Vec<_>>()
         .join(", ")
 }
@@ -52,8 +56,8 @@ pub(super) fn render_refs(refs: &[String]) -> String {
 ///
 /// Uses the pattern from source_scan config. The pattern must have a capture group for the
 /// artifact ID.
-pub fn expand_inline_refs(text: &str, pattern: &str) -> String {
-    expand_inline_refs_with_linker(text, pattern, ref_link)
+pub fn expand_inline_refs(prefixes: &PrefixesConfig, text: &str, pattern: &str) -> String {
+    expand_inline_refs_with_linker(text, pattern, |ref_id| ref_link(prefixes, ref_id))
 }
 
 pub(super) fn expand_inline_refs_with_linker<F>(text: &str, pattern: &str, linker: F) -> String
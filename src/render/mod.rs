@@ -8,20 +8,41 @@
 //! - Inline `[[artifact-id]]` references expanded to markdown links
 
 mod adr;
+mod cache;
+mod compliance;
+mod drift;
+mod front_matter;
+mod html;
+mod index;
 mod links;
 mod output;
+#[cfg(feature = "pdf")]
+mod pdf;
 mod rfc;
+mod template;
 #[cfg(test)]
 mod tests;
 mod work;
 
+use crate::config::PrefixesConfig;
+use crate::model::Deprecation;
 pub use adr::{render_adr, render_adr_with_projection, write_adr_md};
+pub use cache::RenderCache;
+pub use compliance::write_compliance_matrix;
+pub use drift::{Drift, RenderMode, check_rendered};
+use front_matter::front_matter;
+pub use html::{adr_html, rfc_html};
+pub use index::{write_adr_index, write_rfc_index, write_work_index};
 pub use links::expand_inline_refs;
 use links::render_refs;
-use output::write_expanded_rendered_md;
+use output::{copy_attachments, write_expanded_rendered_md};
+#[cfg(feature = "pdf")]
+pub use pdf::write_rfc_pdf;
 pub use rfc::{
     render_clause, render_clause_with_projection, render_rfc_with_projection, write_rfc,
 };
+use std::fmt::Write as FmtWrite;
+use template::apply_template;
 pub use work::{render_work_item, render_work_item_with_projection, write_work_item_md};
 
 /// Selects whether lifecycle-obsolete body content is included in human-readable output.
@@ -31,12 +52,78 @@ pub enum RenderProjection {
     Archive,
 }
 
-pub fn ref_link_from_root(ref_id: &str, docs_output: &str) -> String {
-    links::ref_link_from_root(ref_id, docs_output)
+/// Blockquote lines for a deprecation notice (sunset date and reason).
+fn render_deprecation(out: &mut String, notice: &Deprecation) {
+    if let Some(ref sunset) = notice.sunset {
+        let _ = writeln!(out, "> **Sunset:** {sunset}");
+    }
+    if let Some(ref reason) = notice.reason {
+        let _ = writeln!(out, "> **Deprecation reason:** {reason}");
+    }
 }
 
-pub fn expand_inline_refs_from_root(text: &str, pattern: &str, docs_output: &str) -> String {
+/// Attachments with these extensions are shown inline rather than linked.
+const IMAGE_EXTENSIONS: &[&str] = &["svg", "png", "jpg", "jpeg", "gif", "webp"];
+
+/// `## Attachments` section pointing at the copies `govctl render` places
+/// under `<docs_output>/assets/`.
+fn render_attachments(out: &mut String, attachments: &[String]) {
+    if attachments.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "## Attachments");
+    let _ = writeln!(out);
+    for attachment in attachments {
+        let path = std::path::Path::new(attachment);
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(attachment);
+        let link = format!("../assets/{}", attachment.replace(' ', "%20"));
+        let image = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+        if image {
+            let _ = writeln!(out, "![{name}]({link})");
+        } else {
+            let _ = writeln!(out, "[{name}]({link})");
+        }
+        let _ = writeln!(out);
+    }
+}
+
+/// Builds without the `pdf` feature have no typst backend to render with.
+#[cfg(not(feature = "pdf"))]
+pub fn write_rfc_pdf(
+    _config: &crate::config::Config,
+    rfc: &crate::model::RfcIndex,
+    _mode: RenderMode,
+) -> crate::diagnostic::DiagnosticResult<Drift> {
+    Err(crate::diagnostic::Diagnostic::new(
+        crate::diagnostic::DiagnosticCode::E0822UnsupportedOperation,
+        "This govctl was built without PDF support (enable the `pdf` feature)",
+        rfc.rfc.rfc_id.as_str(),
+    ))
+}
+
+pub fn ref_link_from_root(prefixes: &PrefixesConfig, ref_id: &str, docs_output: &str) -> String {
+    links::ref_link_from_root(prefixes, ref_id, docs_output)
+}
+
+/// Inline references replaced by the bare IDs, for text read outside the
+/// docs tree.
+pub fn expand_inline_refs_plain(text: &str, pattern: &str) -> String {
+    links::expand_inline_refs_with_linker(text, pattern, |ref_id| ref_id.to_string())
+}
+
+pub fn expand_inline_refs_from_root(
+    prefixes: &PrefixesConfig,
+    text: &str,
+    pattern: &str,
+    docs_output: &str,
+) -> String {
     links::expand_inline_refs_with_linker(text, pattern, |ref_id| {
-        ref_link_from_root(ref_id, docs_output)
+        ref_link_from_root(prefixes, ref_id, docs_output)
     })
 }
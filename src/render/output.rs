@@ -1,33 +1,111 @@
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticResult};
 use crate::ui;
-use std::io::Write;
+use crate::write::{WriteOp, replace_file, write_file};
 use std::path::Path;
 
+use super::drift::{Drift, RenderMode, check_rendered};
 use super::expand_inline_refs;
 
 /// Write rendered markdown to file with common formatting.
 ///
-/// Handles dry-run preview, directory creation, and consistent formatting.
-/// `preview_lines` controls how many lines to show in dry-run mode.
+/// Handles dry-run preview, `render --check` comparison, directory creation,
+/// and consistent formatting. Returns the file under `--check` if it is out
+/// of date.
 pub(super) fn write_rendered_md(
     config: &Config,
     output_path: &std::path::Path,
     content: &str,
-    dry_run: bool,
-    preview_lines: usize,
-) -> DiagnosticResult<()> {
-    let content = format!("{}\n", content.trim_end());
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let content = rendered_text(content);
     let display_path = config.display_path(output_path);
 
-    if dry_run {
-        ui::dry_run_preview(&display_path);
-        for line in content.lines().take(preview_lines) {
-            ui::preview_line(line);
+    match mode {
+        RenderMode::Check => {
+            return Ok(check_rendered(
+                output_path,
+                content.as_bytes(),
+                &display_path,
+            ));
         }
-        ui::preview_truncated();
-    } else {
-        if let Some(parent) = output_path.parent() {
+        RenderMode::DryRun => {
+            write_file(output_path, &content, WriteOp::PREVIEW, Some(&display_path))?;
+        }
+        RenderMode::Write => {
+            if let Some(parent) = output_path.parent() {
+                std::fs::create_dir_all(parent).map_err(|err| {
+                    Diagnostic::io_error(
+                        "create render output directory",
+                        err,
+                        config.display_path(parent).display().to_string(),
+                    )
+                })?;
+            }
+            replace_file(
+                output_path,
+                content.as_bytes(),
+                &display_path,
+                config.concurrency.fsync,
+            )?;
+            ui::rendered(&display_path);
+        }
+    }
+
+    Ok(Drift::new())
+}
+
+/// Like [`write_rendered_md`], but in write mode leaves the file alone when
+/// it already holds this output, so an unchanged page keeps its mtime.
+pub(super) fn write_rendered_md_if_changed(
+    config: &Config,
+    output_path: &Path,
+    content: &str,
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    if mode == RenderMode::Write
+        && std::fs::read(output_path)
+            .is_ok_and(|existing| existing == rendered_text(content).as_bytes())
+    {
+        return Ok(Drift::new());
+    }
+    write_rendered_md(config, output_path, content, mode)
+}
+
+/// Rendered markdown as written: trailing whitespace trimmed to one newline.
+fn rendered_text(content: &str) -> String {
+    format!("{}\n", content.trim_end())
+}
+
+/// Copy attachments from `gov/assets/` to `<docs_output>/assets/`, where the
+/// rendered pages link them. Missing files are left to `govctl check`.
+pub(super) fn copy_attachments(
+    config: &Config,
+    attachments: &[String],
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let mut drift = Drift::new();
+    for attachment in attachments {
+        let Ok(source) = crate::validate::attachment_path(config, attachment, attachment) else {
+            continue;
+        };
+        let Ok(bytes) = std::fs::read(&source) else {
+            continue;
+        };
+        let target = config.assets_output().join(attachment);
+        let display_path = config.display_path(&target);
+        match mode {
+            RenderMode::Check => {
+                drift.extend(check_rendered(&target, &bytes, &display_path));
+                continue;
+            }
+            RenderMode::DryRun => {
+                ui::dry_run_preview(&display_path);
+                continue;
+            }
+            RenderMode::Write => {}
+        }
+        if let Some(parent) = target.parent() {
             std::fs::create_dir_all(parent).map_err(|err| {
                 Diagnostic::io_error(
                     "create render output directory",
@@ -36,33 +114,25 @@ pub(super) fn write_rendered_md(
                 )
             })?;
         }
-        let mut file = std::fs::File::create(output_path).map_err(|err| {
-            Diagnostic::io_error(
-                "create render output file",
-                err,
-                display_path.display().to_string(),
-            )
-        })?;
-        file.write_all(content.as_bytes()).map_err(|err| {
-            Diagnostic::io_error(
-                "write render output file",
-                err,
-                display_path.display().to_string(),
-            )
-        })?;
+        replace_file(&target, &bytes, &display_path, config.concurrency.fsync)?;
         ui::rendered(&display_path);
     }
-
-    Ok(())
+    Ok(drift)
 }
 
+/// Like [`write_rendered_md`], with inline references expanded to links and
+/// `front_matter` placed first.
 pub(super) fn write_expanded_rendered_md(
     config: &Config,
     output_path: &Path,
     raw_markdown: &str,
-    dry_run: bool,
-    preview_lines: usize,
-) -> DiagnosticResult<()> {
-    let expanded = expand_inline_refs(raw_markdown, &config.source_scan.pattern);
-    write_rendered_md(config, output_path, &expanded, dry_run, preview_lines)
+    front_matter: Option<String>,
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
+    let mut expanded =
+        expand_inline_refs(&config.prefixes, raw_markdown, &config.source_scan.pattern);
+    if let Some(front_matter) = front_matter {
+        expanded.insert_str(0, &front_matter);
+    }
+    write_rendered_md(config, output_path, &expanded, mode)
 }
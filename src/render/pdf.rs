@@ -0,0 +1,404 @@
+//! RFC rendering to print-ready PDF through an embedded typst compiler.
+//!
+//! The RFC is laid out as typst markup (title page, table of contents,
+//! numbered sections and clauses, changelog) and compiled with the bundled
+//! fonts, so no external tools are needed. The title page carries the
+//! [[ADR-0003]] source signature, tying a delivered PDF to its SSOT.
+
+use super::drift::{Drift, RenderMode, check_rendered};
+use super::expand_inline_refs_plain;
+use super::rfc::section_clauses;
+use crate::config::{Config, RenderSection};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::{ChangelogEntry, ClauseEntry, ClauseKind, ClauseStatus, RfcIndex};
+use crate::signature::compute_rfc_signature;
+use crate::ui;
+use crate::write::replace_file;
+use comemo::Prehashed;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use std::fmt::Write as FmtWrite;
+use typst::diag::{FileError, FileResult};
+use typst::eval::Tracer;
+use typst::foundations::{Bytes, Datetime, Smart};
+use typst::model::Document;
+use typst::syntax::{FileId, Source};
+use typst::text::{Font, FontBook};
+use typst::{Library, World};
+
+/// Write the RFC to `<rfc_output>/<RFC-ID>.pdf`.
+///
+/// # Errors
+/// Returns an error if the signature cannot be computed, the layout fails
+/// to compile, or the file cannot be written.
+pub fn write_rfc_pdf(config: &Config, rfc: &RfcIndex, mode: RenderMode) -> DiagnosticResult<Drift> {
+    let output_path = config.rfc_output().join(format!("{}.pdf", rfc.rfc.rfc_id));
+    let display_path = config.display_path(&output_path);
+
+    let markup = rfc_markup(config, rfc)?;
+    if mode == RenderMode::DryRun {
+        ui::dry_run_preview(&display_path);
+        return Ok(Drift::new());
+    }
+
+    let document = compile(markup).map_err(|message| {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("PDF layout failed: {message}"),
+            display_path.display().to_string(),
+        )
+    })?;
+    let bytes = typst_pdf::pdf(&document, Smart::Custom(&rfc.rfc.rfc_id), None);
+    if mode == RenderMode::Check {
+        return Ok(check_rendered(&output_path, &bytes, &display_path));
+    }
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| {
+            Diagnostic::io_error(
+                "create render output directory",
+                err,
+                config.display_path(parent).display().to_string(),
+            )
+        })?;
+    }
+    replace_file(
+        &output_path,
+        &bytes,
+        &display_path,
+        config.concurrency.fsync,
+    )?;
+    ui::rendered(&display_path);
+    Ok(Drift::new())
+}
+
+/// The RFC as a typst document.
+fn rfc_markup(config: &Config, rfc: &RfcIndex) -> DiagnosticResult<String> {
+    let rfc = &crate::fragments::expand_rfc(config, rfc)?;
+    let spec = &rfc.rfc;
+    let signature = compute_rfc_signature(rfc)?;
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "#set document(title: {}, author: ({}))",
+        string(&format!("{}: {}", spec.rfc_id, spec.title)),
+        spec.owners
+            .iter()
+            .filter(|_| config.render.shows(RenderSection::Owners))
+            .map(|owner| format!("{},", string(owner)))
+            .collect::<String>()
+    );
+    out.push_str(
+        "#set page(paper: \"a4\", margin: 2.5cm, numbering: \"1\")\n\
+         #set text(size: 11pt)\n\
+         #set par(justify: true)\n\
+         #set heading(numbering: \"1.1\")\n\n",
+    );
+
+    // Title page
+    let mut details = vec![
+        ("Version", spec.version.clone()),
+        ("Status", spec.status.as_ref().to_string()),
+        ("Phase", spec.phase.as_ref().to_string()),
+    ];
+    if !spec.owners.is_empty() && config.render.shows(RenderSection::Owners) {
+        details.push(("Owners", spec.owners.join(", ")));
+    }
+    if !spec.approvals.is_empty() && config.render.shows(RenderSection::Approvals) {
+        details.push(("Approved by", spec.approvals.join(", ")));
+    }
+    details.push(("Created", spec.created.clone()));
+    if let Some(ref updated) = spec.updated {
+        details.push(("Updated", updated.clone()));
+    }
+    if !spec.tags.is_empty() {
+        details.push(("Tags", spec.tags.join(", ")));
+    }
+    if let Some(ref supersedes) = spec.supersedes {
+        details.push(("Supersedes", supersedes.clone()));
+    }
+    if let Some(ref notice) = spec.deprecation {
+        if let Some(ref sunset) = notice.sunset {
+            details.push(("Sunset", sunset.clone()));
+        }
+        if let Some(ref reason) = notice.reason {
+            details.push(("Deprecation reason", reason.clone()));
+        }
+    }
+    let _ = writeln!(
+        out,
+        "#page(numbering: none)[\n#v(25%)\n#align(center)[\n#text(size: 14pt)[{}]\n\n#text(size: 24pt, weight: \"bold\")[{}]\n]\n#v(2em)",
+        escape(&spec.rfc_id),
+        escape(&spec.title)
+    );
+    out.push_str("#align(center, table(columns: 2, stroke: none, align: (right, left),\n");
+    for (label, value) in details {
+        let _ = writeln!(out, "[*{}*], [{}],", escape(label), escape(&value));
+    }
+    out.push_str("))\n#v(1fr)\n");
+    let _ = writeln!(
+        out,
+        "#align(center, text(size: 8pt)[Source signature: #raw({})])\n]\n",
+        string(&signature)
+    );
+
+    out.push_str("#outline(indent: auto)\n#pagebreak()\n\n");
+
+    let inline_refs = |text: &str| expand_inline_refs_plain(text, &config.source_scan.pattern);
+    if !spec.refs.is_empty() {
+        let _ = writeln!(out, "*References:* {}\n", escape(&spec.refs.join(", ")));
+    }
+    for section in &spec.sections {
+        let _ = writeln!(out, "= {}\n", escape(&section.title));
+        for clause in section_clauses(rfc, section) {
+            clause_markup(&mut out, clause, &inline_refs);
+        }
+    }
+
+    if !spec.changelog.is_empty() && config.render.shows(RenderSection::Changelog) {
+        out.push_str("#pagebreak()\n#heading(numbering: none)[Changelog]\n\n");
+        for entry in &spec.changelog {
+            changelog_markup(&mut out, entry);
+        }
+    }
+    Ok(out)
+}
+
+fn clause_markup(out: &mut String, clause: &ClauseEntry, inline_refs: &impl Fn(&str) -> String) {
+    let spec = &clause.spec;
+    let _ = writeln!(
+        out,
+        "== {}: {}\n",
+        escape(&spec.clause_id),
+        escape(&spec.title)
+    );
+
+    let mut status = vec![match spec.kind {
+        ClauseKind::Normative => "Normative".to_string(),
+        ClauseKind::Informative => "Informative".to_string(),
+    }];
+    match spec.status {
+        ClauseStatus::Active => {}
+        ClauseStatus::Deprecated => status.push("deprecated".to_string()),
+        ClauseStatus::Superseded => status.push("superseded".to_string()),
+    }
+    if let Some(ref by) = spec.superseded_by {
+        status.push(format!("superseded by {by}"));
+    }
+    if let Some(ref sunset) = spec.deprecation.as_ref().and_then(|n| n.sunset.clone()) {
+        status.push(format!("sunset {sunset}"));
+    }
+    let _ = writeln!(
+        out,
+        "#text(size: 9pt, fill: gray.darken(40%))[{}]\n",
+        escape(&status.join(" · "))
+    );
+
+    if let Some(text) = &spec.text {
+        markdown_markup(out, &inline_refs(text));
+    }
+
+    if let Some(ref since) = spec.since {
+        let _ = writeln!(out, "#emph[Since: v{}]\n", escape(since));
+    }
+}
+
+fn changelog_markup(out: &mut String, entry: &ChangelogEntry) {
+    let _ = writeln!(
+        out,
+        "#heading(level: 2, numbering: none)[v{} ({})]\n",
+        escape(&entry.version),
+        escape(&entry.date)
+    );
+    if let Some(ref notes) = entry.notes {
+        markdown_markup(out, notes);
+    }
+    for (heading, items) in [
+        ("Added", &entry.added),
+        ("Changed", &entry.changed),
+        ("Deprecated", &entry.deprecated),
+        ("Removed", &entry.removed),
+        ("Fixed", &entry.fixed),
+        ("Security", &entry.security),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "*{heading}*\n");
+        out.push_str("#list(\n");
+        for item in items {
+            let _ = writeln!(out, "[{}],", escape(item));
+        }
+        out.push_str(")\n\n");
+    }
+}
+
+/// Clause markdown as typst markup. Everything is emitted in function form
+/// with escaped text, so nothing in the source is read as typst syntax.
+fn markdown_markup(out: &mut String, markdown: &str) {
+    let mut code: Option<(String, String)> = None;
+    for event in Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Heading { .. } => out.push_str("#strong["),
+                Tag::BlockQuote(_) => out.push_str("#quote(block: true)["),
+                Tag::CodeBlock(kind) => {
+                    let lang = match kind {
+                        CodeBlockKind::Fenced(lang) => lang.to_string(),
+                        CodeBlockKind::Indented => String::new(),
+                    };
+                    code = Some((lang, String::new()));
+                }
+                Tag::List(Some(start)) => {
+                    let _ = writeln!(out, "#enum(start: {start},");
+                }
+                Tag::List(None) => out.push_str("#list(\n"),
+                Tag::Item => out.push('['),
+                Tag::Emphasis => out.push_str("#emph["),
+                Tag::Strong => out.push_str("#strong["),
+                Tag::Strikethrough => out.push_str("#strike["),
+                Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                    let _ = write!(out, "#link({})[", string(&dest_url));
+                }
+                _ => {}
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Paragraph => out.push_str("\n\n"),
+                TagEnd::Heading(_) | TagEnd::BlockQuote(_) => out.push_str("]\n\n"),
+                TagEnd::CodeBlock => {
+                    if let Some((lang, text)) = code.take() {
+                        let lang = if lang.is_empty() {
+                            String::new()
+                        } else {
+                            format!("lang: {}, ", string(&lang))
+                        };
+                        let _ = writeln!(out, "#raw(block: true, {lang}{})\n", string(&text));
+                    }
+                }
+                TagEnd::List(_) => out.push_str(")\n\n"),
+                TagEnd::Item => out.push_str("],\n"),
+                TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Link
+                | TagEnd::Image => out.push(']'),
+                _ => {}
+            },
+            Event::Text(text) => match code {
+                Some((_, ref mut buffer)) => buffer.push_str(&text),
+                None => out.push_str(&escape(&text)),
+            },
+            Event::Code(text) => {
+                let _ = write!(out, "#raw({})", string(&text));
+            }
+            Event::Html(text) | Event::InlineHtml(text) => out.push_str(&escape(&text)),
+            Event::SoftBreak => out.push(' '),
+            Event::HardBreak => out.push_str("#linebreak()"),
+            Event::Rule => out.push_str("#line(length: 100%)\n\n"),
+            _ => {}
+        }
+    }
+}
+
+/// `text` as literal typst markup: every ASCII punctuation character is
+/// backslash-escaped and line breaks become spaces.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch.is_ascii_punctuation() {
+            out.push('\\');
+            out.push(ch);
+        } else if ch == '\n' || ch == '\r' {
+            out.push(' ');
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// `text` as a typst string literal.
+fn string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn compile(markup: String) -> Result<Document, String> {
+    let world = RfcWorld::new(markup);
+    typst::compile(&world, &mut Tracer::new()).map_err(|errors| {
+        errors
+            .iter()
+            .map(|error| error.message.to_string())
+            .collect::<Vec<_>>()
+            .join("; ")
+    })
+}
+
+/// A single generated source file and the bundled fonts; nothing is read
+/// from disk.
+struct RfcWorld {
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+    main: Source,
+}
+
+impl RfcWorld {
+    fn new(markup: String) -> Self {
+        let fonts: Vec<Font> = typst_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .collect();
+        Self {
+            library: Prehashed::new(Library::builder().build()),
+            book: Prehashed::new(FontBook::from_fonts(&fonts)),
+            fonts,
+            main: Source::detached(markup),
+        }
+    }
+}
+
+impl World for RfcWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.main.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.main.id() {
+            Ok(self.main.clone())
+        } else {
+            Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+        }
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.fonts.get(index).cloned()
+    }
+
+    fn today(&self, _offset: Option<i64>) -> Option<Datetime> {
+        None
+    }
+}
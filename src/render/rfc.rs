@@ -1,16 +1,34 @@
-use super::{RenderProjection, render_refs, write_expanded_rendered_md};
-use crate::config::Config;
+use super::{
+    Drift, RenderMode, RenderProjection, apply_template, copy_attachments, front_matter,
+    render_attachments, render_deprecation, render_refs, write_expanded_rendered_md,
+};
+use crate::config::{Config, PrefixesConfig, RenderConfig, RenderSection};
 use crate::diagnostic::DiagnosticResult;
-use crate::model::{ClauseEntry, ClauseKind, ClauseStatus, RfcIndex, RfcStatus};
+use crate::model::{
+    AdrEntry, AdrStatus, ClauseEntry, ClauseKind, ClauseStatus, ClauseWire, RfcIndex, RfcStatus,
+    RfcWire, SectionSpec,
+};
 use crate::signature::{compute_rfc_signature, format_signature_header};
+use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
 
-/// Render an RFC to Markdown
-///
-/// # Errors
-/// Returns an error if signature computation fails.
-pub fn render_rfc(rfc: &RfcIndex) -> DiagnosticResult<String> {
-    render_rfc_with_projection(rfc, RenderProjection::Archive, None)
+/// Proposed and accepted ADRs citing each Clause of an RFC, by clause ID.
+type ClauseDecisions<'a> = HashMap<&'a str, Vec<&'a AdrEntry>>;
+
+fn clause_decisions<'a>(rfc_id: &str, adrs: &'a [AdrEntry]) -> ClauseDecisions<'a> {
+    let mut decisions = ClauseDecisions::new();
+    for adr in adrs {
+        if !matches!(adr.meta().status, AdrStatus::Proposed | AdrStatus::Accepted) {
+            continue;
+        }
+        for clause_id in adr.meta().refs.iter().filter_map(|ref_id| {
+            let (rfc, clause) = ref_id.split_once(':')?;
+            (rfc == rfc_id).then_some(clause)
+        }) {
+            decisions.entry(clause_id).or_default().push(adr);
+        }
+    }
+    decisions
 }
 
 /// Render an RFC using the selected lifecycle projection.
@@ -18,9 +36,31 @@ pub fn render_rfc(rfc: &RfcIndex) -> DiagnosticResult<String> {
 /// # Errors
 /// Returns an error if signature computation fails.
 pub fn render_rfc_with_projection(
+    prefixes: &PrefixesConfig,
     rfc: &RfcIndex,
     projection: RenderProjection,
     superseded_by: Option<&str>,
+) -> DiagnosticResult<String> {
+    render_rfc_with_layout(
+        prefixes,
+        rfc,
+        projection,
+        superseded_by,
+        &RenderConfig::default(),
+        &ClauseDecisions::new(),
+    )
+}
+
+/// Render an RFC with the optional `[render]` layout: table of contents,
+/// clause numbers, and status badges. Each Clause lists the `decisions`
+/// that cite it.
+fn render_rfc_with_layout(
+    prefixes: &PrefixesConfig,
+    rfc: &RfcIndex,
+    projection: RenderProjection,
+    superseded_by: Option<&str>,
+    layout: &RenderConfig,
+    decisions: &ClauseDecisions,
 ) -> DiagnosticResult<String> {
     let mut out = String::new();
 
@@ -47,9 +87,15 @@ pub fn render_rfc_with_projection(
     let suppress_body =
         projection == RenderProjection::Current && rfc.rfc.status == RfcStatus::Deprecated;
 
-    if !rfc.rfc.owners.is_empty() {
+    if !rfc.rfc.owners.is_empty() && layout.shows(RenderSection::Owners) {
         let _ = writeln!(out, "> **Owners:** {}", rfc.rfc.owners.join(", "));
     }
+    if !rfc.rfc.approvals.is_empty() && layout.shows(RenderSection::Approvals) {
+        let _ = writeln!(out, "> **Approved by:** {}", rfc.rfc.approvals.join(", "));
+    }
+    if rfc.rfc.frozen {
+        let _ = writeln!(out, "> **Frozen for review**");
+    }
     if !rfc.rfc.tags.is_empty() {
         let _ = writeln!(out, "> **Tags:** `{}`", rfc.rfc.tags.join("`, `"));
     }
@@ -59,11 +105,18 @@ pub fn render_rfc_with_projection(
     if suppress_body && let Some(superseded_by) = superseded_by {
         let _ = writeln!(out, "> **Superseded by:** {superseded_by}");
     }
+    if let Some(ref notice) = rfc.rfc.deprecation {
+        render_deprecation(&mut out, notice);
+    }
     let _ = writeln!(out);
 
     // References (expanded to markdown links)
     if !rfc.rfc.refs.is_empty() {
-        let _ = writeln!(out, "**References:** {}", render_refs(&rfc.rfc.refs));
+        let _ = writeln!(
+            out,
+            "**References:** {}",
+            render_refs(prefixes, &rfc.rfc.refs)
+        );
         let _ = writeln!(out);
     }
 
@@ -73,28 +126,57 @@ pub fn render_rfc_with_projection(
         return Ok(out);
     }
 
+    if layout.toc {
+        render_toc(&mut out, rfc, layout);
+    }
+
     // Render sections with clauses
     for (i, section) in rfc.rfc.sections.iter().enumerate() {
         let _ = writeln!(out, "---");
         let _ = writeln!(out);
-        let _ = writeln!(out, "## {}. {}", i + 1, section.title);
+        if layout.toc {
+            let _ = writeln!(
+                out,
+                "## {}. {} <a id=\"{}\"></a>",
+                i + 1,
+                section.title,
+                section_anchor(i)
+            );
+        } else {
+            let _ = writeln!(out, "## {}. {}", i + 1, section.title);
+        }
         let _ = writeln!(out);
 
-        // Find and render clauses for this section
-        for clause_path in &section.clauses {
-            if let Some(clause) = rfc.clauses.iter().find(|c| {
-                c.path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .is_some_and(|n| clause_path.ends_with(n))
-            }) {
-                render_clause_with_projection(&mut out, &rfc.rfc.rfc_id, clause, projection);
-            }
+        for (j, clause) in section_clauses(rfc, section).enumerate() {
+            let number = layout
+                .clause_numbers
+                .then(|| format!("{}.{}", i + 1, j + 1));
+            render_clause_with_layout(
+                &mut out,
+                &rfc.rfc.rfc_id,
+                clause,
+                projection,
+                number.as_deref(),
+                layout.status_badges,
+            );
+            render_clause_decisions(
+                prefixes,
+                &mut out,
+                decisions
+                    .get(clause.spec.clause_id.as_str())
+                    .map_or(&[], Vec::as_slice),
+            );
         }
     }
 
+    if !rfc.rfc.attachments.is_empty() {
+        let _ = writeln!(out, "---");
+        let _ = writeln!(out);
+        render_attachments(&mut out, &rfc.rfc.attachments);
+    }
+
     // Changelog (Keep a Changelog format)
-    if !rfc.rfc.changelog.is_empty() {
+    if !rfc.rfc.changelog.is_empty() && layout.shows(RenderSection::Changelog) {
         let _ = writeln!(out, "---");
         let _ = writeln!(out);
         let _ = writeln!(out, "## Changelog");
@@ -121,6 +203,59 @@ pub fn render_rfc_with_projection(
     Ok(out)
 }
 
+/// Contents list linking each section and clause, and the changelog.
+fn render_toc(out: &mut String, rfc: &RfcIndex, layout: &RenderConfig) {
+    let _ = writeln!(out, "## Contents");
+    let _ = writeln!(out);
+    for (i, section) in rfc.rfc.sections.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "- [{}. {}](#{})",
+            i + 1,
+            section.title,
+            section_anchor(i)
+        );
+        for (j, clause) in section_clauses(rfc, section).enumerate() {
+            let number = if layout.clause_numbers {
+                format!("{}.{} ", i + 1, j + 1)
+            } else {
+                String::new()
+            };
+            let _ = writeln!(
+                out,
+                "  - [{number}{}: {}](#{})",
+                clause.spec.clause_id,
+                clause.spec.title,
+                clause_anchor(&rfc.rfc.rfc_id, &clause.spec.clause_id)
+            );
+        }
+    }
+    if !rfc.rfc.changelog.is_empty() && layout.shows(RenderSection::Changelog) {
+        let _ = writeln!(out, "- [Changelog](#changelog)");
+    }
+    let _ = writeln!(out);
+}
+
+/// Anchor ID of the `index`-th section, for the contents list.
+fn section_anchor(index: usize) -> String {
+    format!("section-{}", index + 1)
+}
+
+/// The clauses a section lists, in its order; missing files are skipped.
+pub(super) fn section_clauses<'a>(
+    rfc: &'a RfcIndex,
+    section: &'a SectionSpec,
+) -> impl Iterator<Item = &'a ClauseEntry> {
+    section.clauses.iter().filter_map(|clause_path| {
+        rfc.clauses.iter().find(|c| {
+            c.path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| clause_path.ends_with(n))
+        })
+    })
+}
+
 /// Render a changelog section (Keep a Changelog format)
 fn render_changelog_section(out: &mut String, heading: &str, items: &[String]) {
     if items.is_empty() {
@@ -152,6 +287,19 @@ pub fn render_clause_with_projection(
     rfc_id: &str,
     clause: &ClauseEntry,
     projection: RenderProjection,
+) {
+    render_clause_with_layout(out, rfc_id, clause, projection, None, false);
+}
+
+/// Render a Clause, prefixed with its `number` and with a status badge when
+/// `status_badge` is set.
+fn render_clause_with_layout(
+    out: &mut String,
+    rfc_id: &str,
+    clause: &ClauseEntry,
+    projection: RenderProjection,
+    number: Option<&str>,
+    status_badge: bool,
 ) {
     let spec = &clause.spec;
 
@@ -172,9 +320,17 @@ pub fn render_clause_with_projection(
         ClauseStatus::Deprecated | ClauseStatus::Superseded => format!("<del>{}</del>", title_part),
     };
 
+    let number = number
+        .map(|number| format!("{number} "))
+        .unwrap_or_default();
+    let badge = if status_badge {
+        format!(" `{}`", spec.status.as_ref())
+    } else {
+        String::new()
+    };
     let _ = writeln!(
         out,
-        "### {} {kind_marker} <a id=\"{anchor}\"></a>",
+        "### {number}{} {kind_marker}{badge} <a id=\"{anchor}\"></a>",
         formatted_title
     );
     let _ = writeln!(out);
@@ -193,8 +349,14 @@ pub fn render_clause_with_projection(
     if let Some(ref by) = spec.superseded_by {
         let _ = writeln!(out, "> **Superseded by:** {by}");
     }
+    if let Some(ref notice) = spec.deprecation {
+        render_deprecation(out, notice);
+    }
 
-    if spec.status != ClauseStatus::Active || spec.superseded_by.is_some() {
+    if spec.status != ClauseStatus::Active
+        || spec.superseded_by.is_some()
+        || spec.deprecation.is_some()
+    {
         let _ = writeln!(out);
     }
 
@@ -203,8 +365,8 @@ pub fn render_clause_with_projection(
             spec.status,
             ClauseStatus::Deprecated | ClauseStatus::Superseded
         );
-    if !suppress_body {
-        let _ = writeln!(out, "{}", spec.text);
+    if !suppress_body && let Some(text) = &spec.text {
+        let _ = writeln!(out, "{text}");
         let _ = writeln!(out);
     }
 
@@ -221,10 +383,69 @@ pub fn render_clause_with_projection(
     }
 }
 
+/// List the ADRs in `decisions` below the Clause they cite.
+fn render_clause_decisions(prefixes: &PrefixesConfig, out: &mut String, decisions: &[&AdrEntry]) {
+    if !decisions.is_empty() {
+        let _ = writeln!(out, "<details>");
+        let _ = writeln!(out, "<summary>Decisions affecting this clause</summary>");
+        let _ = writeln!(out);
+        for adr in decisions {
+            let meta = adr.meta();
+            let _ = writeln!(
+                out,
+                "- {}: {} ({})",
+                render_refs(prefixes, std::slice::from_ref(&meta.id)),
+                meta.title,
+                meta.status.as_ref()
+            );
+        }
+        let _ = writeln!(out);
+        let _ = writeln!(out, "</details>");
+        let _ = writeln!(out);
+    }
+}
+
 /// Write rendered RFC to file
-pub fn write_rfc(config: &Config, rfc: &RfcIndex, dry_run: bool) -> DiagnosticResult<()> {
+pub fn write_rfc(config: &Config, rfc: &RfcIndex, mode: RenderMode) -> DiagnosticResult<Drift> {
     let output_path = config.rfc_output().join(format!("{}.md", rfc.rfc.rfc_id));
+    let raw = rfc_markdown(config, rfc)?;
+    let front_matter = front_matter(config, &rfc.rfc.rfc_id, &rfc.rfc.title, &rfc.rfc.tags);
+    let mut drift = write_expanded_rendered_md(config, &output_path, &raw, front_matter, mode)?;
+    drift.extend(copy_attachments(config, &rfc.rfc.attachments, mode)?);
+    Ok(drift)
+}
 
-    let raw = render_rfc(rfc)?;
-    write_expanded_rendered_md(config, &output_path, &raw, dry_run, 20)
+/// The RFC as written to the docs tree, built-in layout or user template,
+/// before inline references are expanded.
+pub(super) fn rfc_markdown(config: &Config, rfc: &RfcIndex) -> DiagnosticResult<String> {
+    let rfc = &crate::fragments::expand_rfc(config, rfc)?;
+    let mut context = tera::Context::new();
+    let mut spec = rfc.rfc.clone();
+    if !config.render.shows(RenderSection::Owners) {
+        spec.owners.clear();
+    }
+    if !config.render.shows(RenderSection::Approvals) {
+        spec.approvals.clear();
+    }
+    if !config.render.shows(RenderSection::Changelog) {
+        spec.changelog.clear();
+    }
+    context.insert("rfc", &RfcWire::from(spec));
+    context.insert(
+        "clauses",
+        &rfc.clauses
+            .iter()
+            .map(|clause| ClauseWire::try_from(clause.spec.clone()))
+            .collect::<DiagnosticResult<Vec<_>>>()?,
+    );
+    let adrs = crate::parse::load_adrs(config)?;
+    let builtin = render_rfc_with_layout(
+        &config.prefixes,
+        rfc,
+        RenderProjection::Archive,
+        None,
+        &config.render,
+        &clause_decisions(&rfc.rfc.rfc_id, &adrs),
+    )?;
+    apply_template(config, "rfc", &rfc.rfc.rfc_id, builtin, context)
 }
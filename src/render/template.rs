@@ -0,0 +1,74 @@
+//! User render templates from `gov/templates/render/`.
+//!
+//! `render/<kind>/<ID>.md.tera` applies to one artifact and
+//! `render/<kind>.md.tera` to every artifact of a kind; without either the
+//! built-in layout is written. Templates see the artifact under its kind
+//! name, `id`, and `builtin`, the built-in markdown. The signature header is
+//! always kept so `govctl check` can verify the rendered file.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use std::error::Error;
+use std::path::PathBuf;
+use tera::{Context, Tera};
+
+const TEMPLATE_EXTENSION: &str = "md.tera";
+
+/// The user template for artifact `id` of `kind`, most specific first.
+fn find_template(config: &Config, kind: &str, id: &str) -> Option<PathBuf> {
+    let dir = config.templates_dir().join("render");
+    [
+        dir.join(kind).join(format!("{id}.{TEMPLATE_EXTENSION}")),
+        dir.join(format!("{kind}.{TEMPLATE_EXTENSION}")),
+    ]
+    .into_iter()
+    .find(|path| path.is_file())
+}
+
+/// `raw`, the built-in rendering, or the user template's output in its place.
+/// `context` holds the artifact; `id` and `builtin` are added here.
+pub(super) fn apply_template(
+    config: &Config,
+    kind: &str,
+    id: &str,
+    raw: String,
+    mut context: Context,
+) -> DiagnosticResult<String> {
+    let Some(path) = find_template(config, kind, id) else {
+        return Ok(raw);
+    };
+    let display = config.display_path(&path).display().to_string();
+    let source = std::fs::read_to_string(&path)
+        .map_err(|err| Diagnostic::io_error("read render template", err, display.as_str()))?;
+
+    // The signature header is the first two lines.
+    let header_end = raw.match_indices('\n').nth(1).map_or(0, |(pos, _)| pos + 1);
+    let (header, builtin) = raw.split_at(header_end);
+    context.insert("id", id);
+    context.insert("builtin", builtin.trim_start());
+
+    let mut tera = Tera::default();
+    let rendered = tera
+        .add_raw_template(&display, &source)
+        .and_then(|()| tera.render(&display, &context))
+        .map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0506TemplateInvalid,
+                format!("Render template failed: {}", error_chain(&err)),
+                display.as_str(),
+            )
+        })?;
+    Ok(format!("{header}\n{rendered}"))
+}
+
+/// Tera puts the useful detail in the error's sources.
+fn error_chain(err: &tera::Error) -> String {
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
@@ -9,6 +9,8 @@ fn test_render_adr_alternatives_with_pros_cons() -> Result<(), Box<dyn std::erro
                 context: "Test context".to_string(),
                 decision: "Test decision".to_string(),
                 consequences: "Test consequences".to_string(),
+                drivers: vec![],
+                stakeholders: vec![],
                 alternatives: vec![Alternative {
                     text: "Option A".to_string(),
                     status: AlternativeStatus::Considered,
@@ -21,7 +23,7 @@ fn test_render_adr_alternatives_with_pros_cons() -> Result<(), Box<dyn std::erro
         path: std::path::PathBuf::new(),
     };
 
-    let result = render_adr(&adr)?;
+    let result = render_adr(&PrefixesConfig::default(), &adr)?;
     assert!(result.contains("### Option A"));
     assert!(result.contains("- **Pros:** Fast, Cheap"));
     assert!(result.contains("- **Cons:** Less reliable"));
@@ -42,6 +44,8 @@ fn test_render_adr_alternatives_rejected_with_reason() -> Result<(), Box<dyn std
                 context: "Test context".to_string(),
                 decision: "Test decision".to_string(),
                 consequences: "Test consequences".to_string(),
+                drivers: vec![],
+                stakeholders: vec![],
                 alternatives: vec![Alternative {
                     text: "Option B".to_string(),
                     status: AlternativeStatus::Rejected,
@@ -54,7 +58,7 @@ fn test_render_adr_alternatives_rejected_with_reason() -> Result<(), Box<dyn std
         path: std::path::PathBuf::new(),
     };
 
-    let result = render_adr(&adr)?;
+    let result = render_adr(&PrefixesConfig::default(), &adr)?;
     assert!(result.contains("### Option B (rejected)"));
     assert!(result.contains("- **Rejected because:** Budget constraints"));
     Ok(())
@@ -79,14 +83,18 @@ fn test_superseded_adr_current_projection_is_metadata_only_but_archive_is_comple
                 context: "Historical context".to_string(),
                 decision: "Historical decision body".to_string(),
                 consequences: "Historical consequences".to_string(),
+                drivers: vec![],
+                stakeholders: vec![],
                 alternatives: vec![],
             },
         },
         path: std::path::PathBuf::new(),
     };
 
-    let current = render_adr_with_projection(&adr, RenderProjection::Current)?;
-    let archive = render_adr_with_projection(&adr, RenderProjection::Archive)?;
+    let current =
+        render_adr_with_projection(&PrefixesConfig::default(), &adr, RenderProjection::Current)?;
+    let archive =
+        render_adr_with_projection(&PrefixesConfig::default(), &adr, RenderProjection::Archive)?;
 
     assert!(current.contains("# ADR-9997: Historical decision"));
     assert!(current.contains("**Status:** superseded"));
@@ -97,3 +105,31 @@ fn test_superseded_adr_current_projection_is_metadata_only_but_archive_is_comple
     assert!(archive.contains("Historical decision body"));
     Ok(())
 }
+
+#[test]
+fn test_render_adr_drivers_and_stakeholders_sections() -> Result<(), Box<dyn std::error::Error>> {
+    let adr = AdrEntry {
+        spec: AdrSpec {
+            govctl: AdrMeta::new("ADR-9996", "Test ADR", AdrStatus::Proposed, "2026-02-22"),
+            content: AdrContent {
+                context: "Test context".to_string(),
+                decision: "Test decision".to_string(),
+                consequences: "Test consequences".to_string(),
+                drivers: vec!["Low latency".to_string(), "Small team".to_string()],
+                stakeholders: vec![],
+                alternatives: vec![],
+            },
+        },
+        path: std::path::PathBuf::new(),
+    };
+
+    let result = render_adr(&PrefixesConfig::default(), &adr)?;
+    let context = result.find("## Context").ok_or("context section")?;
+    let drivers = result
+        .find("## Decision Drivers\n\n- Low latency\n- Small team\n")
+        .ok_or("drivers section")?;
+    let decision = result.find("## Decision\n").ok_or("decision section")?;
+    assert!(context < drivers && drivers < decision);
+    assert!(!result.contains("## Stakeholders"));
+    Ok(())
+}
@@ -3,14 +3,14 @@ use super::*;
 #[test]
 fn test_expand_inline_refs_rfc() {
     let text = "See [[RFC-0000]] for details.";
-    let result = expand_inline_refs(text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), text, &default_pattern());
     assert_eq!(result, "See [RFC-0000](../rfc/RFC-0000.md) for details.");
 }
 
 #[test]
 fn test_expand_inline_refs_clause() {
     let text = "Per [[RFC-0000:C-WORK-DEF]], work items must...";
-    let result = expand_inline_refs(text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), text, &default_pattern());
     assert_eq!(
         result,
         "Per [RFC-0000:C-WORK-DEF](../rfc/RFC-0000.md#rfc-0000c-work-def), work items must..."
@@ -20,7 +20,7 @@ fn test_expand_inline_refs_clause() {
 #[test]
 fn test_expand_inline_refs_adr() {
     let text = "This follows [[ADR-0005]] guidelines.";
-    let result = expand_inline_refs(text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), text, &default_pattern());
     assert_eq!(
         result,
         "This follows [ADR-0005](../adr/ADR-0005.md) guidelines."
@@ -30,7 +30,7 @@ fn test_expand_inline_refs_adr() {
 #[test]
 fn test_expand_inline_refs_multiple() {
     let text = "See [[RFC-0000]] and [[ADR-0042]] for context.";
-    let result = expand_inline_refs(text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), text, &default_pattern());
     assert_eq!(
         result,
         "See [RFC-0000](../rfc/RFC-0000.md) and [ADR-0042](../adr/ADR-0042.md) for context."
@@ -40,26 +40,26 @@ fn test_expand_inline_refs_multiple() {
 #[test]
 fn test_expand_inline_refs_no_match() {
     let text = "No references here.";
-    let result = expand_inline_refs(text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), text, &default_pattern());
     assert_eq!(result, "No references here.");
 }
 
 #[test]
 fn test_expand_inline_refs_invalid_pattern() {
     let text = "[[RFC-0000]] test";
-    let result = expand_inline_refs(text, "[invalid(regex");
+    let result = expand_inline_refs(&PrefixesConfig::default(), text, "[invalid(regex");
     assert_eq!(result, "[[RFC-0000]] test");
 }
 
 #[test]
 fn test_ref_link_from_root_rfc() {
-    let result = ref_link_from_root("RFC-0000", "docs");
+    let result = ref_link_from_root(&PrefixesConfig::default(), "RFC-0000", "docs");
     assert_eq!(result, "[RFC-0000](docs/rfc/RFC-0000.md)");
 }
 
 #[test]
 fn test_ref_link_from_root_clause() {
-    let result = ref_link_from_root("RFC-0000:C-WORK-DEF", "docs");
+    let result = ref_link_from_root(&PrefixesConfig::default(), "RFC-0000:C-WORK-DEF", "docs");
     assert_eq!(
         result,
         "[RFC-0000:C-WORK-DEF](docs/rfc/RFC-0000.md#rfc-0000c-work-def)"
@@ -68,20 +68,21 @@ fn test_ref_link_from_root_clause() {
 
 #[test]
 fn test_ref_link_from_root_adr() {
-    let result = ref_link_from_root("ADR-0005", "docs");
+    let result = ref_link_from_root(&PrefixesConfig::default(), "ADR-0005", "docs");
     assert_eq!(result, "[ADR-0005](docs/adr/ADR-0005.md)");
 }
 
 #[test]
 fn test_ref_link_from_root_custom_path() {
-    let result = ref_link_from_root("RFC-0001", "documentation");
+    let result = ref_link_from_root(&PrefixesConfig::default(), "RFC-0001", "documentation");
     assert_eq!(result, "[RFC-0001](documentation/rfc/RFC-0001.md)");
 }
 
 #[test]
 fn test_expand_inline_refs_from_root() {
     let text = "Per [[RFC-0002:C-RESOURCE-MODEL]], resources use verb pattern.";
-    let result = expand_inline_refs_from_root(text, DEFAULT_PATTERN, "docs");
+    let result =
+        expand_inline_refs_from_root(&PrefixesConfig::default(), text, &default_pattern(), "docs");
     assert_eq!(
         result,
         "Per [RFC-0002:C-RESOURCE-MODEL](docs/rfc/RFC-0002.md#rfc-0002c-resource-model), resources use verb pattern."
@@ -91,7 +92,8 @@ fn test_expand_inline_refs_from_root() {
 #[test]
 fn test_expand_inline_refs_from_root_multiple() {
     let text = "See [[RFC-0000]] and [[ADR-0018]] for details.";
-    let result = expand_inline_refs_from_root(text, DEFAULT_PATTERN, "docs");
+    let result =
+        expand_inline_refs_from_root(&PrefixesConfig::default(), text, &default_pattern(), "docs");
     assert_eq!(
         result,
         "See [RFC-0000](docs/rfc/RFC-0000.md) and [ADR-0018](docs/adr/ADR-0018.md) for details."
@@ -102,7 +104,7 @@ fn test_expand_inline_refs_from_root_multiple() {
 fn test_expand_inline_refs_work_item_sequential() {
     let id = "WI-9999-01-26-001";
     let text = format!("See {} for task details.", wi_ref(id));
-    let result = expand_inline_refs(&text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), &text, &default_pattern());
     assert_eq!(
         result,
         format!("See [{}](../work/{}.md) for task details.", id, id)
@@ -113,7 +115,7 @@ fn test_expand_inline_refs_work_item_sequential() {
 fn test_expand_inline_refs_work_item_author_hash() {
     let id = "WI-9999-01-26-a7f3-001";
     let text = format!("See {} for task details.", wi_ref(id));
-    let result = expand_inline_refs(&text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), &text, &default_pattern());
     assert_eq!(
         result,
         format!("See [{}](../work/{}.md) for task details.", id, id)
@@ -124,7 +126,7 @@ fn test_expand_inline_refs_work_item_author_hash() {
 fn test_expand_inline_refs_work_item_random() {
     let id = "WI-9999-01-26-b2c9";
     let text = format!("See {} for task details.", wi_ref(id));
-    let result = expand_inline_refs(&text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), &text, &default_pattern());
     assert_eq!(
         result,
         format!("See [{}](../work/{}.md) for task details.", id, id)
@@ -135,7 +137,7 @@ fn test_expand_inline_refs_work_item_random() {
 fn test_expand_inline_refs_work_item_mixed() {
     let wi_id = "WI-9999-01-26-001";
     let text = format!("Per [[RFC-0000]], see {} and [[ADR-0020]].", wi_ref(wi_id));
-    let result = expand_inline_refs(&text, DEFAULT_PATTERN);
+    let result = expand_inline_refs(&PrefixesConfig::default(), &text, &default_pattern());
     assert_eq!(
         result,
         format!(
@@ -144,3 +146,25 @@ fn test_expand_inline_refs_work_item_mixed() {
         )
     );
 }
+
+#[test]
+fn test_ref_links_follow_configured_prefixes() {
+    let prefixes = crate::config::PrefixesConfig {
+        rfc: "SPEC".to_string(),
+        adr: "DEC".to_string(),
+    };
+    let text = format!(
+        "Per {} and {}.",
+        wi_ref("SPEC-0002:C-SCOPE"),
+        wi_ref("DEC-0003")
+    );
+    let result = expand_inline_refs(
+        &prefixes,
+        &text,
+        &crate::config::bracket_ref_pattern(&prefixes),
+    );
+    assert_eq!(
+        result,
+        "Per [SPEC-0002:C-SCOPE](../rfc/SPEC-0002.md#spec-0002c-scope) and [DEC-0003](../adr/DEC-0003.md)."
+    );
+}
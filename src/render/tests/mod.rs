@@ -5,7 +5,9 @@ use crate::model::{
     WorkItemEntry, WorkItemMeta, WorkItemSpec, WorkItemStatus,
 };
 
-const DEFAULT_PATTERN: &str = r"\[\[(RFC-\d{4}(?::C-[A-Z][A-Z0-9-]*)?|ADR-\d{4}|WI-\d{4}-\d{2}-\d{2}-(?:[a-f0-9]{4}(?:-\d{3})?|\d{3}))\]\]";
+fn default_pattern() -> String {
+    crate::config::bracket_ref_pattern(&PrefixesConfig::default())
+}
 
 // Work item inline reference tests (per ADR-0020 ID formats)
 // Constructs strings at runtime to avoid source_scan matching test fixtures
@@ -12,9 +12,10 @@ fn clause(status: ClauseStatus, superseded_by: Option<&str>) -> ClauseEntry {
             title: "Historical requirement".to_string(),
             kind: ClauseKind::Normative,
             status,
-            text: "The implementation MUST preserve this behavior.".to_string(),
+            text: Some("The implementation MUST preserve this behavior.".to_string()),
             anchors: vec![],
             superseded_by: superseded_by.map(str::to_string),
+            deprecation: None,
             since: Some("0.1.0".to_string()),
             tags: vec![],
         },
@@ -112,9 +113,14 @@ fn rfc(status: RfcStatus, clause_status: ClauseStatus) -> RfcIndex {
             status,
             phase: RfcPhase::Stable,
             owners: vec!["@owner".to_string()],
+            approvals: vec![],
+            frozen: false,
             created: "2026-07-21".to_string(),
+            created_by: None,
+            attachments: vec![],
             updated: None,
             supersedes: None,
+            deprecation: None,
             refs: vec!["RFC-0002".to_string()],
             tags: vec!["cli".to_string()],
             sections: vec![SectionSpec {
@@ -126,6 +132,7 @@ fn rfc(status: RfcStatus, clause_status: ClauseStatus) -> RfcIndex {
         },
         clauses: vec![clause],
         path: PathBuf::new(),
+        amended: None,
     }
 }
 
@@ -133,6 +140,7 @@ fn rfc(status: RfcStatus, clause_status: ClauseStatus) -> RfcIndex {
 fn test_current_rfc_projection_suppresses_only_obsolete_nested_clause_bodies()
 -> Result<(), Box<dyn std::error::Error>> {
     let current = render_rfc_with_projection(
+        &PrefixesConfig::default(),
         &rfc(RfcStatus::Normative, ClauseStatus::Superseded),
         RenderProjection::Current,
         None,
@@ -148,8 +156,18 @@ fn test_current_rfc_projection_suppresses_only_obsolete_nested_clause_bodies()
 fn test_deprecated_rfc_current_projection_is_metadata_only_but_archive_is_complete()
 -> Result<(), Box<dyn std::error::Error>> {
     let rfc = rfc(RfcStatus::Deprecated, ClauseStatus::Active);
-    let current = render_rfc_with_projection(&rfc, RenderProjection::Current, Some("RFC-0002"))?;
-    let archive = render_rfc_with_projection(&rfc, RenderProjection::Archive, None)?;
+    let current = render_rfc_with_projection(
+        &PrefixesConfig::default(),
+        &rfc,
+        RenderProjection::Current,
+        Some("RFC-0002"),
+    )?;
+    let archive = render_rfc_with_projection(
+        &PrefixesConfig::default(),
+        &rfc,
+        RenderProjection::Archive,
+        None,
+    )?;
 
     assert!(current.contains("# RFC-0001: Projection test"));
     assert!(current.contains("**Status:** deprecated"));
@@ -172,7 +190,12 @@ fn test_rfc_archive_renders_its_direct_supersedes_relation()
     let mut rfc = rfc(RfcStatus::Normative, ClauseStatus::Active);
     rfc.rfc.supersedes = Some("RFC-0000".to_string());
 
-    let archive = render_rfc_with_projection(&rfc, RenderProjection::Archive, None)?;
+    let archive = render_rfc_with_projection(
+        &PrefixesConfig::default(),
+        &rfc,
+        RenderProjection::Archive,
+        None,
+    )?;
 
     assert!(archive.contains("> **Supersedes:** RFC-0000"));
     Ok(())
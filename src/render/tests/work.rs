@@ -1,4 +1,5 @@
 use super::*;
+use crate::config::{Config, JiraConfig};
 
 #[test]
 fn test_render_work_item_journal() -> Result<(), Box<dyn std::error::Error>> {
@@ -28,7 +29,7 @@ fn test_render_work_item_journal() -> Result<(), Box<dyn std::error::Error>> {
         path: std::path::PathBuf::new(),
     };
 
-    let result = render_work_item(&item)?;
+    let result = render_work_item(&Config::default(), &item)?;
     assert!(result.contains("## Journal"));
     assert!(result.contains("Legacy execution history"));
     assert!(result.contains("loop state"));
@@ -72,7 +73,7 @@ fn test_render_work_item_journal_with_scope() -> Result<(), Box<dyn std::error::
         path: std::path::PathBuf::new(),
     };
 
-    let result = render_work_item(&item)?;
+    let result = render_work_item(&Config::default(), &item)?;
     assert!(result.contains("### 2026-02-22 · API"));
     assert!(result.contains("Created endpoint"));
     assert!(result.contains("### 2026-02-23 · Testing"));
@@ -115,9 +116,36 @@ fn test_render_work_item_acceptance_criteria_show_categories()
         path: std::path::PathBuf::new(),
     };
 
-    let result = render_work_item(&item)?;
+    let result = render_work_item(&Config::default(), &item)?;
     assert!(result.contains("- [ ] added: Add reviewer context"));
     assert!(result.contains("- [x] fixed: Fix rendered category"));
     assert!(result.contains("- ~~chore: Obsolete validation path~~"));
     Ok(())
 }
+
+#[test]
+fn test_render_work_item_links_jira_issue() -> Result<(), Box<dyn std::error::Error>> {
+    let mut meta = WorkItemMeta::new("WI-2026-02-22-004", "Linked", WorkItemStatus::Queue);
+    meta.jira_key = Some("PROJ-12".to_string());
+    let item = WorkItemEntry {
+        spec: WorkItemSpec {
+            govctl: meta,
+            content: WorkItemContent::default(),
+            verification: crate::model::WorkItemVerification::default(),
+        },
+        path: std::path::PathBuf::new(),
+    };
+
+    let result = render_work_item(&Config::default(), &item)?;
+    assert!(result.contains("**Jira:** PROJ-12\n"));
+
+    let config = Config {
+        jira: Some(JiraConfig {
+            base_url: "https://example.atlassian.net/".to_string(),
+        }),
+        ..Config::default()
+    };
+    let result = render_work_item(&config, &item)?;
+    assert!(result.contains("**Jira:** [PROJ-12](https://example.atlassian.net/browse/PROJ-12)"));
+    Ok(())
+}
@@ -1,6 +1,10 @@
-use super::{RenderProjection, render_refs, write_expanded_rendered_md};
-use crate::config::Config;
+use super::{
+    Drift, RenderMode, RenderProjection, apply_template, copy_attachments, front_matter,
+    render_attachments, render_refs, write_expanded_rendered_md,
+};
+use crate::config::{Config, RenderSection};
 use crate::diagnostic::DiagnosticResult;
+use crate::jira::issue_url;
 use crate::model::{ChecklistStatus, WorkItemEntry};
 use crate::signature::{compute_work_item_signature, format_signature_header};
 use std::fmt::Write as FmtWrite;
@@ -25,8 +29,8 @@ fn indent_continuation(text: &str) -> String {
 ///
 /// # Errors
 /// Returns an error if signature computation fails.
-pub fn render_work_item(item: &WorkItemEntry) -> DiagnosticResult<String> {
-    render_work_item_with_projection(item, RenderProjection::Archive)
+pub fn render_work_item(config: &Config, item: &WorkItemEntry) -> DiagnosticResult<String> {
+    render_work_item_with_projection(config, item, RenderProjection::Archive)
 }
 
 /// Render a Work Item using an explicit projection.
@@ -37,6 +41,7 @@ pub fn render_work_item(item: &WorkItemEntry) -> DiagnosticResult<String> {
 /// # Errors
 /// Returns an error if signature computation fails.
 pub fn render_work_item_with_projection(
+    config: &Config,
     item: &WorkItemEntry,
     _projection: RenderProjection,
 ) -> DiagnosticResult<String> {
@@ -61,6 +66,9 @@ pub fn render_work_item_with_projection(
         meta.id,
         meta.status.as_ref()
     );
+    if let Some(priority) = meta.priority {
+        status_line.push_str(&format!(" | **Priority:** {priority}"));
+    }
     if let Some(ref start) = meta.started {
         status_line.push_str(&format!(" | **Started:** {start}"));
     }
@@ -78,13 +86,38 @@ pub fn render_work_item_with_projection(
 
     // References (expanded to markdown links)
     if !meta.refs.is_empty() {
-        let _ = writeln!(out, "**References:** {}", render_refs(&meta.refs));
+        let _ = writeln!(
+            out,
+            "**References:** {}",
+            render_refs(&config.prefixes, &meta.refs)
+        );
+        let _ = writeln!(out);
+    }
+
+    if !meta.github.is_empty() {
+        let _ = writeln!(out, "**GitHub:** {}", meta.github.join(", "));
+        let _ = writeln!(out);
+    }
+
+    if let Some(key) = &meta.jira_key {
+        match &config.jira {
+            Some(jira) => {
+                let _ = writeln!(out, "**Jira:** [{key}]({})", issue_url(jira, key));
+            }
+            None => {
+                let _ = writeln!(out, "**Jira:** {key}");
+            }
+        }
         let _ = writeln!(out);
     }
 
     // Work item dependencies (expanded to markdown links)
     if !meta.depends_on.is_empty() {
-        let _ = writeln!(out, "**Depends On:** {}", render_refs(&meta.depends_on));
+        let _ = writeln!(
+            out,
+            "**Depends On:** {}",
+            render_refs(&config.prefixes, &meta.depends_on)
+        );
         let _ = writeln!(out);
     }
 
@@ -95,7 +128,7 @@ pub fn render_work_item_with_projection(
     let _ = writeln!(out);
 
     // Legacy inline history remains renderable for existing work items per [[ADR-0047]].
-    if !content.journal.is_empty() {
+    if !content.journal.is_empty() && config.render.shows(RenderSection::Journal) {
         let _ = writeln!(out, "## Journal");
         let _ = writeln!(out);
         let _ = writeln!(
@@ -136,7 +169,7 @@ pub fn render_work_item_with_projection(
     }
 
     // Notes
-    if !content.notes.is_empty() {
+    if !content.notes.is_empty() && config.render.shows(RenderSection::Notes) {
         let _ = writeln!(out, "## Notes");
         let _ = writeln!(out);
         for note in &content.notes {
@@ -145,6 +178,40 @@ pub fn render_work_item_with_projection(
         let _ = writeln!(out);
     }
 
+    let results = &item.spec.verification.results;
+    if !results.is_empty() && config.render.shows(RenderSection::Verification) {
+        let _ = writeln!(out, "## Verification");
+        let _ = writeln!(out);
+        for result in results {
+            let outcome = if result.passed { "PASS" } else { "FAIL" };
+            let status = match result.exit_code {
+                Some(code) => format!("exit {code}"),
+                None if result.timed_out => "timed out".to_string(),
+                None => "killed".to_string(),
+            };
+            let _ = writeln!(
+                out,
+                "- **{outcome}** {} · {} · {}.{}s · {status} · `{}`",
+                result.guard,
+                result.ran_at,
+                result.duration_ms / 1000,
+                result.duration_ms % 1000 / 100,
+                result.command
+            );
+            if !result.output.is_empty() {
+                let _ = writeln!(out);
+                let _ = writeln!(out, "  ```text");
+                for line in result.output.lines() {
+                    let _ = writeln!(out, "  {line}");
+                }
+                let _ = writeln!(out, "  ```");
+            }
+        }
+        let _ = writeln!(out);
+    }
+
+    render_attachments(&mut out, &meta.attachments);
+
     Ok(out)
 }
 
@@ -152,11 +219,27 @@ pub fn render_work_item_with_projection(
 pub fn write_work_item_md(
     config: &Config,
     item: &WorkItemEntry,
-    dry_run: bool,
-) -> DiagnosticResult<()> {
+    mode: RenderMode,
+) -> DiagnosticResult<Drift> {
     let meta = item.meta();
     let output_path = config.work_output().join(format!("{}.md", meta.id));
 
-    let raw = render_work_item_with_projection(item, RenderProjection::Archive)?;
-    write_expanded_rendered_md(config, &output_path, &raw, dry_run, 15)
+    let mut context = tera::Context::new();
+    let mut spec = item.spec.clone();
+    if !config.render.shows(RenderSection::Journal) {
+        spec.content.journal.clear();
+    }
+    if !config.render.shows(RenderSection::Notes) {
+        spec.content.notes.clear();
+    }
+    if !config.render.shows(RenderSection::Verification) {
+        spec.verification.results.clear();
+    }
+    context.insert("work", &spec);
+    let raw = render_work_item_with_projection(config, item, RenderProjection::Archive)?;
+    let raw = apply_template(config, "work", &meta.id, raw, context)?;
+    let front_matter = front_matter(config, &meta.id, &meta.title, &meta.tags);
+    let mut drift = write_expanded_rendered_md(config, &output_path, &raw, front_matter, mode)?;
+    drift.extend(copy_attachments(config, &meta.attachments, mode)?);
+    Ok(drift)
 }
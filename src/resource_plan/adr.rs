@@ -1,33 +1,54 @@
 use super::{
-    ToPlan, compile_common_add, compile_common_deprecate, compile_common_edit, compile_common_get,
-    compile_common_list, compile_common_remove, compile_common_render, compile_common_set,
-    compile_common_show, compile_common_supersede, compile_common_tick,
+    ToPlan, compile_common_add, compile_common_approve, compile_common_deprecate,
+    compile_common_edit, compile_common_get, compile_common_list, compile_common_new,
+    compile_common_remove, compile_common_render, compile_common_set, compile_common_show,
+    compile_common_supersede, compile_common_tick,
 };
+use crate::aliases::Aliases;
 use crate::cmd;
 use crate::command_router::{
-    CommandPlan, CreateOp, EditExtras, LifecycleOp, plan_create, plan_lifecycle,
+    CommandPlan, CreateOp, EditExtras, EditOp, LifecycleOp, Op, add_action, artifact, plan_edit,
+    plan_lifecycle,
 };
+use crate::config::PrefixesConfig;
 use crate::diagnostic::DiagnosticResult;
-use crate::{AdrAddArgs, AdrCommand, AdrEditArgs, AdrTickArgs, CommonIdArgs, ListTarget};
+use crate::{
+    AdrAddArgs, AdrCommand, AdrEditArgs, AdrTickArgs, AltCommand, CommonIdArgs, ListTarget,
+};
 
 impl ToPlan for AdrCommand {
-    fn to_plan(&self) -> DiagnosticResult<CommandPlan> {
+    fn to_plan(
+        &self,
+        prefixes: &PrefixesConfig,
+        aliases: &Aliases,
+    ) -> DiagnosticResult<CommandPlan> {
         match self {
             AdrCommand::List(args) => Ok(compile_common_list(ListTarget::Adr, args)),
-            AdrCommand::Get(args) => compile_common_get(args),
-            AdrCommand::Show(args) => Ok(compile_common_show(cmd::edit::ArtifactType::Adr, args)),
-            AdrCommand::New { title } => Ok(plan_create(
-                ListTarget::Adr,
-                CreateOp::Adr {
-                    title: title.clone(),
-                },
+            AdrCommand::Get(args) => {
+                compile_common_get(prefixes, aliases, cmd::edit::ArtifactType::Adr, args)
+            }
+            AdrCommand::Show(args) => Ok(compile_common_show(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Adr,
+                args,
             )),
+            AdrCommand::New { title, interactive } => compile_common_new(
+                ListTarget::Adr,
+                title.as_ref(),
+                *interactive,
+                cmd::new::WizardTarget::Adr,
+                |title| CreateOp::Adr { title },
+            ),
             AdrCommand::Edit(AdrEditArgs {
                 common,
                 pro,
                 con,
                 reject_reason,
             }) => compile_common_edit(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Adr,
                 common,
                 EditExtras {
                     pros: pro.clone(),
@@ -36,13 +57,18 @@ impl ToPlan for AdrCommand {
                     ..EditExtras::default()
                 },
             ),
-            AdrCommand::Set(args) => compile_common_set(args),
+            AdrCommand::Set(args) => {
+                compile_common_set(prefixes, aliases, cmd::edit::ArtifactType::Adr, args)
+            }
             AdrCommand::Add(AdrAddArgs {
                 common,
                 pro,
                 con,
                 reject_reason,
             }) => compile_common_add(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Adr,
                 common,
                 EditExtras {
                     pros: pro.clone(),
@@ -51,27 +77,98 @@ impl ToPlan for AdrCommand {
                     ..EditExtras::default()
                 },
             ),
-            AdrCommand::Remove(args) => compile_common_remove(args),
+            AdrCommand::Remove(args) => {
+                compile_common_remove(prefixes, aliases, cmd::edit::ArtifactType::Adr, args)
+            }
             AdrCommand::Accept { id, force } => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
                 cmd::edit::ArtifactType::Adr,
                 id,
                 LifecycleOp::AcceptAdr { force: *force },
             )),
             AdrCommand::Reject(CommonIdArgs { id }) => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
                 cmd::edit::ArtifactType::Adr,
                 id,
                 LifecycleOp::RejectAdr,
             )),
+            AdrCommand::Approve(args) => {
+                compile_common_approve(prefixes, aliases, cmd::edit::ArtifactType::Adr, args)
+            }
             AdrCommand::Deprecate(args) => {
-                compile_common_deprecate(cmd::edit::ArtifactType::Adr, args)
+                compile_common_deprecate(prefixes, aliases, cmd::edit::ArtifactType::Adr, args)
             }
             AdrCommand::Supersede(args) => {
-                compile_common_supersede(cmd::edit::ArtifactType::Adr, args)
+                compile_common_supersede(prefixes, aliases, cmd::edit::ArtifactType::Adr, args)
             }
-            AdrCommand::Tick(AdrTickArgs { common, status }) => {
-                compile_common_tick(common, (*status).into())
-            }
-            AdrCommand::Render(args) => compile_common_render(cmd::edit::ArtifactType::Adr, args),
+            AdrCommand::Tick(AdrTickArgs { common, status }) => compile_common_tick(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Adr,
+                common,
+                (*status).into(),
+            ),
+            AdrCommand::Alt { command } => plan_alt_command(prefixes, aliases, command),
+            AdrCommand::Render(args) => compile_common_render(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Adr,
+                args,
+                Default::default(),
+            ),
         }
     }
 }
+
+fn plan_alt_command(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    command: &AltCommand,
+) -> DiagnosticResult<CommandPlan> {
+    match command {
+        AltCommand::Add {
+            adr_id,
+            text,
+            pro,
+            con,
+            reject,
+        } => plan_edit(
+            prefixes,
+            aliases,
+            cmd::edit::ArtifactType::Adr,
+            adr_id,
+            "alternatives",
+            add_action(Some(text.clone()), false),
+            EditExtras {
+                pros: pro.clone(),
+                cons: con.clone(),
+                reject_reason: reject.clone(),
+                ..EditExtras::default()
+            },
+        ),
+        AltCommand::Set {
+            adr_id,
+            alternative,
+            at,
+            pro,
+            con,
+            reject,
+        } => Ok(artifact(
+            prefixes,
+            aliases,
+            cmd::edit::ArtifactType::Adr,
+            adr_id,
+            Op::Edit(EditOp::Alternative {
+                pattern: alternative.clone(),
+                at: *at,
+                update: cmd::edit::AlternativeUpdate {
+                    pros: pro.clone(),
+                    cons: con.clone(),
+                    reject_reason: reject.clone(),
+                },
+            }),
+        )),
+    }
+}
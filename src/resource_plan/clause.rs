@@ -2,21 +2,40 @@ use super::{
     ToPlan, compile_common_delete, compile_common_deprecate, compile_common_get,
     compile_common_list, compile_common_set, compile_common_show, compile_common_supersede,
 };
+use crate::aliases::Aliases;
 use crate::cmd;
 use crate::command_router::{
-    EditExtras, EditOp, Op, artifact, owned_edit_action, plan_create, plan_edit,
+    EditExtras, EditOp, LifecycleOp, Op, artifact, owned_edit_action, plan_create, plan_edit,
+    plan_lifecycle,
 };
+use crate::config::PrefixesConfig;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::{ClauseCommand, EditActionArgs, ListTarget};
 
 impl ToPlan for ClauseCommand {
-    fn to_plan(&self) -> DiagnosticResult<crate::command_router::CommandPlan> {
+    fn to_plan(
+        &self,
+        prefixes: &PrefixesConfig,
+        aliases: &Aliases,
+    ) -> DiagnosticResult<crate::command_router::CommandPlan> {
         match self {
             ClauseCommand::List(args) => Ok(compile_common_list(ListTarget::Clause, args)),
-            ClauseCommand::Get(args) => compile_common_get(args),
-            ClauseCommand::Show(args) => {
-                Ok(compile_common_show(cmd::edit::ArtifactType::Clause, args))
+            ClauseCommand::Get(args) => {
+                compile_common_get(prefixes, aliases, cmd::edit::ArtifactType::Clause, args)
             }
+            ClauseCommand::Show(args) => Ok(compile_common_show(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Clause,
+                args,
+            )),
+            ClauseCommand::History { id, limit } => Ok(artifact(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Clause,
+                id,
+                Op::TextHistory { limit: *limit },
+            )),
             ClauseCommand::New {
                 clause_id,
                 title,
@@ -75,7 +94,10 @@ impl ToPlan for ClauseCommand {
                         )
                     })?;
                     plan_edit(
-                        id,
+                        prefixes,
+                        aliases,
+                        cmd::edit::ArtifactType::Clause,
+                        &cmd::edit::ArtifactType::Clause.expand_shorthand(prefixes, aliases, id),
                         &path,
                         owned_edit_action(&EditActionArgs {
                             set: set.clone(),
@@ -92,6 +114,8 @@ impl ToPlan for ClauseCommand {
                     )
                 } else {
                     Ok(artifact(
+                        prefixes,
+                        aliases,
                         cmd::edit::ArtifactType::Clause,
                         id,
                         Op::Edit(EditOp::ClauseLegacy {
@@ -102,16 +126,32 @@ impl ToPlan for ClauseCommand {
                     ))
                 }
             }
-            ClauseCommand::Set(args) => compile_common_set(args),
+            ClauseCommand::Set(args) => {
+                compile_common_set(prefixes, aliases, cmd::edit::ArtifactType::Clause, args)
+            }
             ClauseCommand::Delete(args) => {
-                compile_common_delete(cmd::edit::ArtifactType::Clause, args)
+                compile_common_delete(prefixes, aliases, cmd::edit::ArtifactType::Clause, args)
             }
             ClauseCommand::Deprecate(args) => {
-                compile_common_deprecate(cmd::edit::ArtifactType::Clause, args)
+                compile_common_deprecate(prefixes, aliases, cmd::edit::ArtifactType::Clause, args)
             }
             ClauseCommand::Supersede(args) => {
-                compile_common_supersede(cmd::edit::ArtifactType::Clause, args)
+                compile_common_supersede(prefixes, aliases, cmd::edit::ArtifactType::Clause, args)
             }
+            ClauseCommand::Reorder {
+                rfc_id,
+                section,
+                order,
+            } => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                rfc_id,
+                LifecycleOp::ReorderClauses {
+                    section: section.clone(),
+                    order: order.clone(),
+                },
+            )),
         }
     }
 }
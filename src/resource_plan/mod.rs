@@ -1,14 +1,18 @@
+use crate::aliases::Aliases;
 use crate::cmd;
 use crate::command_router::{
-    CommandPlan, EditExtras, LifecycleOp, OwnedMatchOptions, add_action, owned_edit_action,
-    plan_artifact_render, plan_delete, plan_edit, plan_get, plan_lifecycle, plan_list, plan_show,
-    remove_action, set_action, tick_action,
+    CommandPlan, CreateOp, EditExtras, LifecycleOp, OwnedMatchOptions, add_values_action,
+    owned_edit_action, plan_artifact_render, plan_create, plan_delete, plan_edit, plan_get,
+    plan_lifecycle, plan_list, plan_show, remove_action, set_action, tick_action,
 };
-use crate::diagnostic::DiagnosticResult;
+use crate::config::PrefixesConfig;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::Deprecation;
 use crate::{
-    CommonAddArgs, CommonDeleteArgs, CommonDeprecateArgs, CommonEditArgs, CommonGetArgs,
-    CommonListArgs, CommonRemoveArgs, CommonRenderArgs, CommonSetArgs, CommonShowArgs,
-    CommonSupersedeArgs, CommonTickSelectorArgs, ListTarget, TickStatus,
+    CommonAddArgs, CommonApproveArgs, CommonDeleteArgs, CommonDeprecateArgs, CommonEditArgs,
+    CommonGetArgs, CommonListArgs, CommonRemoveArgs, CommonRenderArgs, CommonSetArgs,
+    CommonShowArgs, CommonSupersedeArgs, CommonTickSelectorArgs, ListTarget, RenderLayoutArgs,
+    TickStatus,
 };
 
 mod adr;
@@ -18,13 +22,26 @@ mod rfc;
 mod work;
 
 pub(crate) trait ToPlan {
-    fn to_plan(&self) -> DiagnosticResult<CommandPlan>;
+    fn to_plan(
+        &self,
+        prefixes: &PrefixesConfig,
+        aliases: &Aliases,
+    ) -> DiagnosticResult<CommandPlan>;
 }
 
 fn compile_common_list(target: ListTarget, args: &CommonListArgs) -> CommandPlan {
-    // Parse comma-separated tags from --tag option
-    let tags: Vec<String> = args
-        .tag
+    plan_list(
+        target,
+        args.filter.clone(),
+        args.limit,
+        list_tags(args),
+        args.stale,
+    )
+}
+
+/// Comma-separated tags from the `--tag` option.
+fn list_tags(args: &CommonListArgs) -> Vec<String> {
+    args.tag
         .as_deref()
         .map(|t| {
             t.split(',')
@@ -32,48 +49,125 @@ fn compile_common_list(target: ListTarget, args: &CommonListArgs) -> CommandPlan
                 .filter(|s| !s.is_empty())
                 .collect()
         })
-        .unwrap_or_default();
-    plan_list(target, args.filter.clone(), args.limit, args.output, tags)
+        .unwrap_or_default()
+}
+
+/// Plan `<kind> new`: the prompt wizard with `--interactive`, otherwise a
+/// flag-driven create that requires the title.
+fn compile_common_new(
+    target: ListTarget,
+    title: Option<&String>,
+    interactive: bool,
+    wizard: cmd::new::WizardTarget,
+    create: impl FnOnce(String) -> CreateOp,
+) -> DiagnosticResult<CommandPlan> {
+    if interactive {
+        return Ok(plan_create(
+            target,
+            CreateOp::Wizard {
+                target: wizard,
+                title: title.cloned(),
+            },
+        ));
+    }
+    let title = title.cloned().ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "A title is required unless --interactive is given",
+            "new",
+        )
+    })?;
+    Ok(plan_create(target, create(title)))
 }
 
-fn compile_common_get(args: &CommonGetArgs) -> DiagnosticResult<CommandPlan> {
-    plan_get(&args.id, args.field.as_deref())
+fn compile_common_get(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    args: &CommonGetArgs,
+) -> DiagnosticResult<CommandPlan> {
+    plan_get(
+        prefixes,
+        aliases,
+        artifact,
+        &artifact.expand_shorthand(prefixes, aliases, &args.id),
+        args.field.as_deref(),
+    )
 }
 
-fn compile_common_show(artifact: cmd::edit::ArtifactType, args: &CommonShowArgs) -> CommandPlan {
-    plan_show(artifact, &args.id, args.output, args.history)
+fn compile_common_show(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    args: &CommonShowArgs,
+) -> CommandPlan {
+    plan_show(prefixes, aliases, artifact, &args.id, args.history)
 }
 
-fn compile_common_edit(args: &CommonEditArgs, extras: EditExtras) -> DiagnosticResult<CommandPlan> {
+fn compile_common_edit(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    args: &CommonEditArgs,
+    extras: EditExtras,
+) -> DiagnosticResult<CommandPlan> {
     plan_edit(
-        &args.id,
+        prefixes,
+        aliases,
+        artifact,
+        &artifact.expand_shorthand(prefixes, aliases, &args.id),
         &args.path,
         owned_edit_action(&args.action)?,
         extras,
     )
 }
 
-fn compile_common_set(args: &CommonSetArgs) -> DiagnosticResult<CommandPlan> {
+fn compile_common_set(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    args: &CommonSetArgs,
+) -> DiagnosticResult<CommandPlan> {
     plan_edit(
-        &args.id,
+        prefixes,
+        aliases,
+        artifact,
+        &artifact.expand_shorthand(prefixes, aliases, &args.id),
         &args.field,
         set_action(args.value.clone(), args.stdin),
         EditExtras::default(),
     )
 }
 
-fn compile_common_add(args: &CommonAddArgs, extras: EditExtras) -> DiagnosticResult<CommandPlan> {
+fn compile_common_add(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    args: &CommonAddArgs,
+    extras: EditExtras,
+) -> DiagnosticResult<CommandPlan> {
     plan_edit(
-        &args.id,
+        prefixes,
+        aliases,
+        artifact,
+        &artifact.expand_shorthand(prefixes, aliases, &args.id),
         &args.field,
-        add_action(args.value.clone(), args.stdin),
+        add_values_action(args.values.clone(), args.stdin, args.lines),
         extras,
     )
 }
 
-fn compile_common_remove(args: &CommonRemoveArgs) -> DiagnosticResult<CommandPlan> {
+fn compile_common_remove(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    args: &CommonRemoveArgs,
+) -> DiagnosticResult<CommandPlan> {
     plan_edit(
-        &args.id,
+        prefixes,
+        aliases,
+        artifact,
+        &artifact.expand_shorthand(prefixes, aliases, &args.id),
         &args.field,
         remove_action(OwnedMatchOptions {
             pattern: args.pattern.clone(),
@@ -87,11 +181,17 @@ fn compile_common_remove(args: &CommonRemoveArgs) -> DiagnosticResult<CommandPla
 }
 
 fn compile_common_tick(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
     args: &CommonTickSelectorArgs,
     status: TickStatus,
 ) -> DiagnosticResult<CommandPlan> {
     plan_edit(
-        &args.id,
+        prefixes,
+        aliases,
+        artifact,
+        &artifact.expand_shorthand(prefixes, aliases, &args.id),
         &args.field,
         tick_action(
             OwnedMatchOptions {
@@ -108,40 +208,94 @@ fn compile_common_tick(
 }
 
 fn compile_common_render(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact: cmd::edit::ArtifactType,
     args: &CommonRenderArgs,
+    layout: RenderLayoutArgs,
 ) -> DiagnosticResult<CommandPlan> {
-    Ok(plan_artifact_render(artifact, &args.id, args.dry_run))
+    Ok(plan_artifact_render(
+        prefixes,
+        aliases,
+        artifact,
+        &args.id,
+        args.dry_run,
+        layout,
+    ))
 }
 
 fn compile_common_delete(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact: cmd::edit::ArtifactType,
     args: &CommonDeleteArgs,
 ) -> DiagnosticResult<CommandPlan> {
-    Ok(plan_delete(artifact, &args.id, args.force))
+    Ok(plan_delete(
+        prefixes, aliases, artifact, &args.id, args.force,
+    ))
 }
 
 fn compile_common_deprecate(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact: cmd::edit::ArtifactType,
     args: &CommonDeprecateArgs,
+) -> DiagnosticResult<CommandPlan> {
+    if let Some(sunset) = &args.sunset
+        && chrono::NaiveDate::parse_from_str(sunset, "%Y-%m-%d").is_err()
+    {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0820InvalidFieldValue,
+            format!("Invalid --sunset date '{sunset}' (expected YYYY-MM-DD)"),
+            &args.id,
+        ));
+    }
+    let notice = (args.sunset.is_some() || args.reason.is_some()).then(|| Deprecation {
+        sunset: args.sunset.clone(),
+        reason: args.reason.clone(),
+    });
+    Ok(plan_lifecycle(
+        prefixes,
+        aliases,
+        artifact,
+        &args.id,
+        LifecycleOp::Deprecate {
+            force: args.force,
+            notice,
+        },
+    ))
+}
+
+fn compile_common_approve(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    artifact: cmd::edit::ArtifactType,
+    args: &CommonApproveArgs,
 ) -> DiagnosticResult<CommandPlan> {
     Ok(plan_lifecycle(
+        prefixes,
+        aliases,
         artifact,
         &args.id,
-        LifecycleOp::Deprecate { force: args.force },
+        LifecycleOp::Approve,
     ))
 }
 
 fn compile_common_supersede(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
     artifact: cmd::edit::ArtifactType,
     args: &CommonSupersedeArgs,
 ) -> DiagnosticResult<CommandPlan> {
     Ok(plan_lifecycle(
+        prefixes,
+        aliases,
         artifact,
         &args.id,
         LifecycleOp::Supersede {
-            by: args.by.clone(),
+            by: artifact.expand_shorthand(prefixes, aliases, &args.by),
             force: args.force,
+            update_refs: args.update_refs,
         },
     ))
 }
@@ -1,33 +1,94 @@
 use super::{
-    ToPlan, compile_common_add, compile_common_deprecate, compile_common_edit, compile_common_get,
-    compile_common_list, compile_common_remove, compile_common_render, compile_common_set,
-    compile_common_show, compile_common_supersede,
+    ToPlan, compile_common_add, compile_common_approve, compile_common_deprecate,
+    compile_common_edit, compile_common_get, compile_common_list, compile_common_new,
+    compile_common_remove, compile_common_render, compile_common_set, compile_common_show,
+    compile_common_supersede,
 };
+use crate::aliases::Aliases;
 use crate::cmd;
-use crate::command_router::{
-    CommandPlan, CreateOp, EditExtras, LifecycleOp, plan_create, plan_lifecycle,
-};
-use crate::diagnostic::DiagnosticResult;
+use crate::command_router::{CommandPlan, CreateOp, EditExtras, LifecycleOp, plan_lifecycle};
+use crate::config::PrefixesConfig;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
 use crate::write::BumpLevel;
-use crate::{ListTarget, RfcCommand};
+use crate::{CommonEditArgs, CommonIdArgs, ListTarget, RfcCommand, SectionCommand};
+
+/// Field name routed to the section operations so `add`/`remove` keep
+/// section titles unique and refuse to drop Clauses.
+const SECTIONS_FIELD: &str = "sections";
 
 impl ToPlan for RfcCommand {
-    fn to_plan(&self) -> DiagnosticResult<CommandPlan> {
+    fn to_plan(
+        &self,
+        prefixes: &PrefixesConfig,
+        aliases: &Aliases,
+    ) -> DiagnosticResult<CommandPlan> {
         match self {
             RfcCommand::List(args) => Ok(compile_common_list(ListTarget::Rfc, args)),
-            RfcCommand::Get(args) => compile_common_get(args),
-            RfcCommand::Show(args) => Ok(compile_common_show(cmd::edit::ArtifactType::Rfc, args)),
-            RfcCommand::New { title, id } => Ok(plan_create(
+            RfcCommand::Get(args) => {
+                compile_common_get(prefixes, aliases, cmd::edit::ArtifactType::Rfc, args)
+            }
+            RfcCommand::Show(args) => Ok(compile_common_show(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                args,
+            )),
+            RfcCommand::New {
+                title,
+                id,
+                interactive,
+            } => compile_common_new(
                 ListTarget::Rfc,
-                CreateOp::Rfc {
-                    title: title.clone(),
+                title.as_ref(),
+                *interactive,
+                cmd::new::WizardTarget::Rfc { id: id.clone() },
+                |title| CreateOp::Rfc {
+                    title,
                     id: id.clone(),
                 },
-            )),
-            RfcCommand::Edit(args) => compile_common_edit(args, EditExtras::default()),
-            RfcCommand::Set(args) => compile_common_set(args),
-            RfcCommand::Add(args) => compile_common_add(args, EditExtras::default()),
-            RfcCommand::Remove(args) => compile_common_remove(args),
+            ),
+            RfcCommand::Edit(args) if args.path == SECTIONS_FIELD => {
+                plan_edit_sections(prefixes, aliases, args)
+            }
+            RfcCommand::Edit(args) => compile_common_edit(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                args,
+                EditExtras::default(),
+            ),
+            RfcCommand::Set(args) => {
+                compile_common_set(prefixes, aliases, cmd::edit::ArtifactType::Rfc, args)
+            }
+            RfcCommand::Add(args) if args.field == SECTIONS_FIELD => match args.values.as_slice() {
+                [] | [_] => {
+                    plan_add_section(prefixes, aliases, &args.id, args.values.first(), args.stdin)
+                }
+                _ => Err(Diagnostic::new(
+                    DiagnosticCode::E0802ConflictingArgs,
+                    "Adding to sections takes one section title at a time",
+                    &args.id,
+                )),
+            },
+            RfcCommand::Add(args) => compile_common_add(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                args,
+                EditExtras::default(),
+            ),
+            RfcCommand::Remove(args) if args.field == SECTIONS_FIELD => plan_remove_section(
+                prefixes,
+                aliases,
+                &args.id,
+                args.pattern.as_ref(),
+                args.at,
+                args.regex,
+                args.all,
+            ),
+            RfcCommand::Remove(args) => {
+                compile_common_remove(prefixes, aliases, cmd::edit::ArtifactType::Rfc, args)
+            }
             RfcCommand::Bump {
                 id,
                 patch,
@@ -44,6 +105,8 @@ impl ToPlan for RfcCommand {
                     _ => unreachable!("clap arg group ensures mutual exclusivity"),
                 };
                 Ok(plan_lifecycle(
+                    prefixes,
+                    aliases,
                     cmd::edit::ArtifactType::Rfc,
                     id,
                     LifecycleOp::Bump {
@@ -54,22 +117,225 @@ impl ToPlan for RfcCommand {
                 ))
             }
             RfcCommand::Finalize { id, status } => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
                 cmd::edit::ArtifactType::Rfc,
                 id,
                 LifecycleOp::Finalize { status: *status },
             )),
-            RfcCommand::Advance { id, phase } => Ok(plan_lifecycle(
+            RfcCommand::Advance {
+                id,
+                phase,
+                force,
+                report,
+            } => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
                 cmd::edit::ArtifactType::Rfc,
                 id,
-                LifecycleOp::Advance { phase: *phase },
+                if *report {
+                    LifecycleOp::AdvanceReport { phase: *phase }
+                } else {
+                    LifecycleOp::Advance {
+                        phase: *phase,
+                        force: *force,
+                    }
+                },
+            )),
+            RfcCommand::Approve(args) => {
+                compile_common_approve(prefixes, aliases, cmd::edit::ArtifactType::Rfc, args)
+            }
+            RfcCommand::Freeze(CommonIdArgs { id }) => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                id,
+                LifecycleOp::Freeze,
+            )),
+            RfcCommand::Unfreeze(CommonIdArgs { id }) => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                id,
+                LifecycleOp::Unfreeze,
             )),
             RfcCommand::Deprecate(args) => {
-                compile_common_deprecate(cmd::edit::ArtifactType::Rfc, args)
+                compile_common_deprecate(prefixes, aliases, cmd::edit::ArtifactType::Rfc, args)
             }
             RfcCommand::Supersede(args) => {
-                compile_common_supersede(cmd::edit::ArtifactType::Rfc, args)
+                compile_common_supersede(prefixes, aliases, cmd::edit::ArtifactType::Rfc, args)
             }
-            RfcCommand::Render(args) => compile_common_render(cmd::edit::ArtifactType::Rfc, args),
+            RfcCommand::Split {
+                id,
+                clauses,
+                into,
+                title,
+                force,
+            } => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                id,
+                LifecycleOp::SplitRfc {
+                    clauses: clauses.clone(),
+                    into: into.clone(),
+                    title: title.clone(),
+                    force: *force,
+                },
+            )),
+            RfcCommand::Merge { id, into, force } => Ok(plan_lifecycle(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                id,
+                LifecycleOp::MergeRfc {
+                    into: cmd::edit::ArtifactType::Rfc.expand_shorthand(prefixes, aliases, into),
+                    force: *force,
+                },
+            )),
+            RfcCommand::Section { command } => Ok(plan_section_command(prefixes, aliases, command)),
+            RfcCommand::Render { args, layout } => compile_common_render(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::Rfc,
+                args,
+                layout.clone(),
+            ),
         }
     }
 }
+
+fn plan_section_command(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    command: &SectionCommand,
+) -> CommandPlan {
+    let (rfc_id, op) = match command {
+        SectionCommand::Add {
+            rfc_id,
+            title,
+            after,
+        } => (
+            rfc_id,
+            LifecycleOp::AddSection {
+                title: title.clone(),
+                after: after.clone(),
+            },
+        ),
+        SectionCommand::Rename { rfc_id, from, to } => (
+            rfc_id,
+            LifecycleOp::RenameSection {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        ),
+        SectionCommand::Remove {
+            rfc_id,
+            title,
+            force,
+        } => (
+            rfc_id,
+            LifecycleOp::RemoveSection {
+                title: title.clone(),
+                force: *force,
+            },
+        ),
+        SectionCommand::Reorder { rfc_id, order } => (
+            rfc_id,
+            LifecycleOp::ReorderSections {
+                order: order.clone(),
+            },
+        ),
+    };
+    plan_lifecycle(prefixes, aliases, cmd::edit::ArtifactType::Rfc, rfc_id, op)
+}
+
+fn plan_edit_sections(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    args: &CommonEditArgs,
+) -> DiagnosticResult<CommandPlan> {
+    let action = &args.action;
+    match (&action.add, &action.remove) {
+        (Some(title), _) => {
+            plan_add_section(prefixes, aliases, &args.id, title.as_ref(), action.stdin)
+        }
+        (_, Some(title)) => plan_remove_section(
+            prefixes,
+            aliases,
+            &args.id,
+            title.as_ref(),
+            action.at,
+            action.regex,
+            action.all,
+        ),
+        _ => compile_common_edit(
+            prefixes,
+            aliases,
+            cmd::edit::ArtifactType::Rfc,
+            args,
+            EditExtras::default(),
+        ),
+    }
+}
+
+fn plan_add_section(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    id: &str,
+    title: Option<&String>,
+    stdin: bool,
+) -> DiagnosticResult<CommandPlan> {
+    let title = match (title, stdin) {
+        (Some(title), false) => title.clone(),
+        _ => {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0801MissingRequiredArg,
+                "Adding to sections requires the section title as a value (--stdin is not supported)",
+                id,
+            ));
+        }
+    };
+    Ok(plan_lifecycle(
+        prefixes,
+        aliases,
+        cmd::edit::ArtifactType::Rfc,
+        id,
+        LifecycleOp::AddSection { title, after: None },
+    ))
+}
+
+fn plan_remove_section(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    id: &str,
+    title: Option<&String>,
+    at: Option<i32>,
+    regex: bool,
+    all: bool,
+) -> DiagnosticResult<CommandPlan> {
+    if at.is_some() || regex || all {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0802ConflictingArgs,
+            "Removing from sections matches the exact section title; --at, --regex, and --all are not supported",
+            id,
+        ));
+    }
+    let title = title.cloned().ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E0801MissingRequiredArg,
+            "Removing from sections requires the section title",
+            id,
+        )
+    })?;
+    Ok(plan_lifecycle(
+        prefixes,
+        aliases,
+        cmd::edit::ArtifactType::Rfc,
+        id,
+        LifecycleOp::RemoveSection {
+            title,
+            force: false,
+        },
+    ))
+}
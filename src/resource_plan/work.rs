@@ -1,43 +1,113 @@
 use super::{
     ToPlan, compile_common_add, compile_common_delete, compile_common_edit, compile_common_get,
-    compile_common_list, compile_common_remove, compile_common_render, compile_common_set,
-    compile_common_show, compile_common_tick,
+    compile_common_list, compile_common_new, compile_common_remove, compile_common_render,
+    compile_common_set, compile_common_show, compile_common_tick, list_tags,
 };
+use crate::aliases::Aliases;
 use crate::cmd;
 use crate::command_router::{
-    CommandPlan, CreateOp, EditExtras, LifecycleOp, plan_create, plan_lifecycle,
+    BuiltinOp, CommandPlan, CreateOp, EditExtras, LifecycleOp, Op, add_action, artifact,
+    plan_collection_builtin, plan_edit, plan_lifecycle, set_action,
 };
-use crate::diagnostic::DiagnosticResult;
-use crate::{ListTarget, WorkAddArgs, WorkCommand, WorkEditArgs, WorkTickArgs};
+use crate::config::PrefixesConfig;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::WorkItemStatus;
+use crate::{
+    ListTarget, OutputFormat, WorkAddArgs, WorkCommand, WorkEditArgs, WorkListArgs, WorkTickArgs,
+};
+use std::path::{Path, PathBuf};
 
 impl ToPlan for WorkCommand {
-    fn to_plan(&self) -> DiagnosticResult<CommandPlan> {
+    fn to_plan(
+        &self,
+        prefixes: &PrefixesConfig,
+        aliases: &Aliases,
+    ) -> DiagnosticResult<CommandPlan> {
         match self {
-            WorkCommand::List(args) => Ok(compile_common_list(ListTarget::Work, args)),
-            WorkCommand::Get(args) => compile_common_get(args),
-            WorkCommand::Show(args) => {
-                Ok(compile_common_show(cmd::edit::ArtifactType::WorkItem, args))
+            WorkCommand::List(WorkListArgs { common, github }) => Ok(if *github {
+                plan_collection_builtin(
+                    ListTarget::Work,
+                    BuiltinOp::WorkListGithub {
+                        filter: common.filter.clone(),
+                        limit: common.limit,
+                        output: OutputFormat::default(),
+                        tags: list_tags(common),
+                    },
+                )
+            } else {
+                compile_common_list(ListTarget::Work, common)
+            }),
+            WorkCommand::Get(args) => {
+                compile_common_get(prefixes, aliases, cmd::edit::ArtifactType::WorkItem, args)
             }
-            WorkCommand::Move { file, status } => Ok(plan_lifecycle(
+            WorkCommand::Show(args) => Ok(compile_common_show(
+                prefixes,
+                aliases,
                 cmd::edit::ArtifactType::WorkItem,
-                &file.display().to_string(),
-                LifecycleOp::MoveWork {
-                    file_or_id: file.clone(),
-                    status: *status,
-                },
+                args,
             )),
-            WorkCommand::New { title, active } => Ok(plan_create(
-                ListTarget::Work,
-                CreateOp::Work {
+            WorkCommand::Move {
+                file,
+                status,
+                reopen: _,
+                reason,
+            } => Ok(plan_move(
+                prefixes,
+                aliases,
+                file,
+                *status,
+                reason.clone(),
+                None,
+            )),
+            WorkCommand::Clone { id, title } => Ok(artifact(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::WorkItem,
+                id,
+                Op::Create(CreateOp::WorkClone {
                     title: title.clone(),
+                }),
+            )),
+            WorkCommand::Start { file } => Ok(plan_move(
+                prefixes,
+                aliases,
+                file,
+                WorkItemStatus::Active,
+                None,
+                None,
+            )),
+            WorkCommand::Finish { file, tick_chores } => Ok(plan_move(
+                prefixes,
+                aliases,
+                file,
+                WorkItemStatus::Done,
+                None,
+                Some(cmd::move_::FinishOptions {
+                    tick_chores: *tick_chores,
+                }),
+            )),
+            WorkCommand::New {
+                title,
+                active,
+                interactive,
+            } => compile_common_new(
+                ListTarget::Work,
+                title.as_ref(),
+                *interactive,
+                cmd::new::WizardTarget::Work { active: *active },
+                |title| CreateOp::Work {
+                    title,
                     active: *active,
                 },
-            )),
+            ),
             WorkCommand::Edit(WorkEditArgs {
                 common,
                 category,
                 scope,
             }) => compile_common_edit(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::WorkItem,
                 common,
                 EditExtras {
                     category: *category,
@@ -45,12 +115,17 @@ impl ToPlan for WorkCommand {
                     ..EditExtras::default()
                 },
             ),
-            WorkCommand::Set(args) => compile_common_set(args),
+            WorkCommand::Set(args) => {
+                compile_common_set(prefixes, aliases, cmd::edit::ArtifactType::WorkItem, args)
+            }
             WorkCommand::Add(WorkAddArgs {
                 common,
                 category,
                 scope,
             }) => compile_common_add(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::WorkItem,
                 common,
                 EditExtras {
                     category: *category,
@@ -58,16 +133,116 @@ impl ToPlan for WorkCommand {
                     ..EditExtras::default()
                 },
             ),
-            WorkCommand::Remove(args) => compile_common_remove(args),
-            WorkCommand::Tick(WorkTickArgs { common, status }) => {
-                compile_common_tick(common, (*status).into())
+            WorkCommand::Remove(args) => {
+                compile_common_remove(prefixes, aliases, cmd::edit::ArtifactType::WorkItem, args)
             }
+            WorkCommand::Link { id, github, jira } => match (github, jira) {
+                (Some(github), _) => plan_edit(
+                    prefixes,
+                    aliases,
+                    cmd::edit::ArtifactType::WorkItem,
+                    id,
+                    "github",
+                    add_action(Some(github.clone()), false),
+                    EditExtras::default(),
+                ),
+                (None, jira) => plan_edit(
+                    prefixes,
+                    aliases,
+                    cmd::edit::ArtifactType::WorkItem,
+                    id,
+                    "jira_key",
+                    set_action(jira.clone(), false),
+                    EditExtras::default(),
+                ),
+            },
+            WorkCommand::Prioritize { suggest: _, apply } => Ok(plan_collection_builtin(
+                ListTarget::Work,
+                BuiltinOp::WorkPrioritize {
+                    apply: *apply,
+                    output: OutputFormat::default(),
+                },
+            )),
+            WorkCommand::Rank { id, before, after } => Ok(plan_collection_builtin(
+                ListTarget::Work,
+                BuiltinOp::WorkRank {
+                    id: id.clone(),
+                    position: match (before, after) {
+                        (Some(other), _) => cmd::queue::RankPosition::Before(other.clone()),
+                        (None, Some(other)) => cmd::queue::RankPosition::After(other.clone()),
+                        (None, None) => {
+                            return Err(Diagnostic::new(
+                                DiagnosticCode::E0801MissingRequiredArg,
+                                "work rank needs --before or --after",
+                                id,
+                            ));
+                        }
+                    },
+                },
+            )),
+            WorkCommand::Next {
+                tag,
+                reference,
+                activate,
+            } => Ok(plan_collection_builtin(
+                ListTarget::Work,
+                BuiltinOp::WorkNext {
+                    filter: cmd::queue::NextFilter {
+                        tags: tag
+                            .iter()
+                            .flat_map(|tags| tags.split(','))
+                            .map(str::trim)
+                            .filter(|tag| !tag.is_empty())
+                            .map(str::to_string)
+                            .collect(),
+                        reference: reference.as_deref().map(|id| aliases.expand_or_keep(id)),
+                    },
+                    activate: *activate,
+                    output: OutputFormat::default(),
+                },
+            )),
+            WorkCommand::Tick(WorkTickArgs { common, status }) => compile_common_tick(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::WorkItem,
+                common,
+                (*status).into(),
+            ),
             WorkCommand::Delete(args) => {
-                compile_common_delete(cmd::edit::ArtifactType::WorkItem, args)
-            }
-            WorkCommand::Render(args) => {
-                compile_common_render(cmd::edit::ArtifactType::WorkItem, args)
+                compile_common_delete(prefixes, aliases, cmd::edit::ArtifactType::WorkItem, args)
             }
+            WorkCommand::Render(args) => compile_common_render(
+                prefixes,
+                aliases,
+                cmd::edit::ArtifactType::WorkItem,
+                args,
+                Default::default(),
+            ),
         }
     }
 }
+
+/// `work move`, `start`, and `finish`: a status change of the item at `file`.
+fn plan_move(
+    prefixes: &PrefixesConfig,
+    aliases: &Aliases,
+    file: &Path,
+    status: WorkItemStatus,
+    reopen_reason: Option<String>,
+    finish: Option<cmd::move_::FinishOptions>,
+) -> CommandPlan {
+    plan_lifecycle(
+        prefixes,
+        aliases,
+        cmd::edit::ArtifactType::WorkItem,
+        &file.display().to_string(),
+        LifecycleOp::MoveWork {
+            file_or_id: aliases
+                .expand(&file.to_string_lossy())
+                .map_or_else(|| file.to_path_buf(), PathBuf::from),
+            status,
+            reopen_reason,
+            finish,
+        },
+    )
+}
@@ -0,0 +1,156 @@
+//! `.gitignore` and `.govctlignore` rules for the source scan walk.
+//!
+//! Supports the common gitignore syntax: `#` comments, `!` negation,
+//! trailing `/` for directories, and patterns anchored by a `/`. Each
+//! directory's ignore files apply below that directory, and later rules win,
+//! so deeper files and `.govctlignore` override `.gitignore`. A directory
+//! is not walked once ignored, so nothing below it can be re-included.
+
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use globset::{GlobBuilder, GlobMatcher};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const GOVCTL_IGNORE_FILE: &str = ".govctlignore";
+const GIT_IGNORE_FILE: &str = ".gitignore";
+
+struct Rule {
+    /// Directory of the ignore file, relative to the project root
+    base: PathBuf,
+    matcher: GlobMatcher,
+    negate: bool,
+    dir_only: bool,
+}
+
+pub struct IgnoreRules {
+    rules: Vec<Rule>,
+    gitignore: bool,
+}
+
+impl IgnoreRules {
+    /// Rules from `.govctlignore` files, and `.gitignore` files when
+    /// `gitignore` is set.
+    pub fn new(gitignore: bool) -> Self {
+        Self {
+            rules: Vec::new(),
+            gitignore,
+        }
+    }
+
+    /// Add the ignore files of `dir`, given relative to `root`.
+    pub fn load_dir(&mut self, root: &Path, dir: &Path) -> Result<(), Diagnostic> {
+        let names: &[&str] = if self.gitignore {
+            &[GIT_IGNORE_FILE, GOVCTL_IGNORE_FILE]
+        } else {
+            &[GOVCTL_IGNORE_FILE]
+        };
+        for name in names {
+            let path = root.join(dir).join(name);
+            let Ok(content) = fs::read_to_string(&path) else {
+                continue;
+            };
+            for line in content.lines() {
+                match parse_rule(dir, line, &path) {
+                    Ok(Some(rule)) => self.rules.push(rule),
+                    Ok(None) => {}
+                    // A pattern git accepts but globset does not is skipped;
+                    // `.govctlignore` is ours to get right.
+                    Err(diagnostic) if *name == GOVCTL_IGNORE_FILE => return Err(diagnostic),
+                    Err(_) => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether `path`, relative to the project root, is ignored.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if path.file_name().is_some_and(|name| name == ".git") {
+            return true;
+        }
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                (is_dir || !rule.dir_only)
+                    && path
+                        .strip_prefix(&rule.base)
+                        .is_ok_and(|rel| rule.matcher.is_match(rel))
+            })
+            .is_some_and(|rule| !rule.negate)
+    }
+}
+
+fn parse_rule(base: &Path, line: &str, file: &Path) -> Result<Option<Rule>, Diagnostic> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    let (negate, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (dir_only, pattern) = match pattern.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+    let glob = match pattern.strip_prefix('/') {
+        Some(anchored) => anchored.to_string(),
+        None if pattern.contains('/') => pattern.to_string(),
+        None => format!("**/{pattern}"),
+    };
+    let matcher = GlobBuilder::new(&glob)
+        .literal_separator(true)
+        .build()
+        .map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0501ConfigInvalid,
+                format!("Invalid ignore pattern '{line}': {err}"),
+                file.display().to_string(),
+            )
+        })?
+        .compile_matcher();
+    Ok(Some(Rule {
+        base: base.to_path_buf(),
+        matcher,
+        negate,
+        dir_only,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_and_deeper_rules_win() -> Result<(), Box<dyn std::error::Error>> {
+        let root = tempfile::TempDir::new()?;
+        fs::write(
+            root.path().join(".gitignore"),
+            "# build output\ntarget/\n*.gen.rs\n/vendor\n",
+        )?;
+        fs::write(root.path().join(".govctlignore"), "!api.gen.rs\n")?;
+        fs::create_dir_all(root.path().join("src"))?;
+        fs::write(root.path().join("src/.gitignore"), "fixtures/\n")?;
+
+        let mut rules = IgnoreRules::new(true);
+        rules.load_dir(root.path(), Path::new(""))?;
+        rules.load_dir(root.path(), Path::new("src"))?;
+
+        assert!(rules.is_ignored(Path::new("target"), true));
+        assert!(rules.is_ignored(Path::new("crates/a/target"), true));
+        assert!(!rules.is_ignored(Path::new("target"), false));
+        assert!(rules.is_ignored(Path::new("src/model.gen.rs"), false));
+        assert!(!rules.is_ignored(Path::new("src/api.gen.rs"), false));
+        assert!(rules.is_ignored(Path::new("vendor"), true));
+        assert!(!rules.is_ignored(Path::new("src/vendor"), true));
+        assert!(rules.is_ignored(Path::new("src/fixtures"), true));
+        assert!(!rules.is_ignored(Path::new("fixtures"), true));
+        assert!(rules.is_ignored(Path::new(".git"), true));
+
+        let mut govctl_only = IgnoreRules::new(false);
+        govctl_only.load_dir(root.path(), Path::new(""))?;
+        assert!(!govctl_only.is_ignored(Path::new("target"), true));
+        Ok(())
+    }
+}
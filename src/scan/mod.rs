@@ -0,0 +1,478 @@
+//! Source code reference scanning.
+//!
+//! Implements [[ADR-0009]] configurable source code reference scanning.
+//!
+//! Scans files matching include/exclude glob patterns for references to
+//! governance artifacts and validates they exist in the project index.
+//! With `comments_only`, references in code files only count inside
+//! comments; prose files such as Markdown are scanned whole. Paths ignored
+//! by `.gitignore` or `.govctlignore` are not walked.
+
+mod ignore;
+
+use crate::artifact_index::{
+    ANCHOR_REF_PATTERN, ArtifactRefState, anchor_targets, artifact_ref_states,
+};
+use crate::config::{CommentSyntax, Config, PrefixesConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, Span};
+use crate::model::ProjectIndex;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::IgnoreRules;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Result of source scanning
+#[derive(Debug, Default)]
+pub struct ScanResult {
+    pub diagnostics: Vec<Diagnostic>,
+    pub files_scanned: usize,
+    pub refs_found: usize,
+}
+
+/// A file selected by the `[source_scan]` include/exclude globs.
+pub struct ScannedFile {
+    /// Path relative to the project root
+    pub path: String,
+    pub content: String,
+    /// Comment spans, when `comments_only` applies to this file type
+    comments: Option<Vec<Range<usize>>>,
+}
+
+impl ScannedFile {
+    /// Capture group 1 of each match with the byte offset of the match,
+    /// skipping matches outside comments under `comments_only`.
+    fn matches<'a>(&'a self, pattern: &'a Regex) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+        pattern.captures_iter(&self.content).filter_map(|caps| {
+            let whole = caps.get(0)?;
+            let id = caps.get(1)?;
+            if let Some(spans) = &self.comments
+                && !spans.iter().any(|span| span.contains(&whole.start()))
+            {
+                return None;
+            }
+            Some((whole.start(), id.as_str()))
+        })
+    }
+
+    /// 1-based line number of a byte offset.
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.content[..offset.min(self.content.len())]
+            .matches('\n')
+            .count()
+            + 1
+    }
+}
+
+/// A reference found in a scanned file.
+pub struct SourceRef<'a> {
+    pub offset: usize,
+    /// The artifact ID or anchor name as written
+    pub written: &'a str,
+    /// The referenced artifact; for an anchor, its clause, or `None` when
+    /// no clause declares the anchor
+    pub target: Option<String>,
+}
+
+/// Finds artifact IDs and `[[<ANCHOR>]]` anchors in scanned files.
+pub struct RefMatcher {
+    prefixes: PrefixesConfig,
+    pattern: Regex,
+    anchor: Regex,
+    anchors: HashMap<String, String>,
+}
+
+impl RefMatcher {
+    /// Compile `source_scan.pattern` and collect the anchors of `index`.
+    pub fn new(config: &Config, index: &ProjectIndex) -> Result<Self, Diagnostic> {
+        let pattern = Regex::new(&config.source_scan.pattern).map_err(|e| {
+            Diagnostic::new(
+                DiagnosticCode::E0501ConfigInvalid,
+                format!("Invalid source_scan.pattern regex: {}", e),
+                "gov/config.toml".to_string(),
+            )
+        })?;
+        let anchor = Regex::new(ANCHOR_REF_PATTERN).map_err(|e| {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!("Invalid built-in anchor reference pattern: {e}"),
+                "internal",
+            )
+        })?;
+        Ok(Self {
+            prefixes: config.prefixes.clone(),
+            pattern,
+            anchor,
+            anchors: anchor_targets(index),
+        })
+    }
+
+    /// References in `file`, in order. Anchor-shaped text that `pattern`
+    /// already matched, or that looks like an artifact ID, is not an anchor.
+    pub fn refs<'a>(&'a self, file: &'a ScannedFile) -> Vec<SourceRef<'a>> {
+        let mut refs: Vec<SourceRef<'a>> = file
+            .matches(&self.pattern)
+            .map(|(offset, id)| SourceRef {
+                offset,
+                written: id,
+                target: Some(id.to_string()),
+            })
+            .collect();
+        let taken: HashSet<usize> = refs.iter().map(|found| found.offset).collect();
+        refs.extend(
+            file.matches(&self.anchor)
+                .filter(|(offset, name)| {
+                    !taken.contains(offset) && !self.prefixes.is_artifact_shaped(name)
+                })
+                .map(|(offset, name)| SourceRef {
+                    offset,
+                    written: name,
+                    target: self.anchors.get(name).cloned(),
+                }),
+        );
+        refs.sort_by_key(|found| found.offset);
+        refs
+    }
+}
+
+/// Read every file matching the include/exclude globs and not ignored,
+/// whether or not scanning is enabled for `govctl check`.
+pub fn scan_files(config: &Config) -> Result<Vec<ScannedFile>, Diagnostic> {
+    let include_set = build_glob_set(&config.source_scan.include, "include")?;
+    let exclude_set = build_glob_set(&config.source_scan.exclude, "exclude")?;
+    let project_root = config.project_root();
+    let mut ignore = IgnoreRules::new(config.source_scan.respect_gitignore);
+
+    // Walk from project root, skipping ignored directories, and filter files
+    // by project-relative include/exclude globs.
+    let mut walk = WalkDir::new(project_root)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter();
+    let mut scanned = Vec::new();
+    while let Some(entry) = walk.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let path = entry.path();
+        let match_path = path.strip_prefix(project_root).unwrap_or(path);
+        let is_dir = entry.file_type().is_dir();
+        if entry.depth() > 0 && ignore.is_ignored(match_path, is_dir) {
+            if is_dir {
+                walk.skip_current_dir();
+            }
+            continue;
+        }
+        if is_dir {
+            ignore.load_dir(project_root, match_path)?;
+            continue;
+        }
+
+        // Check include/exclude
+        if !entry.file_type().is_file()
+            || !include_set.is_match(match_path)
+            || exclude_set.is_match(match_path)
+        {
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+
+        let comments = config
+            .source_scan
+            .comments_only
+            .then(|| comment_syntax(config, path))
+            .filter(|syntax| *syntax != CommentSyntax::Text)
+            .map(|syntax| comment_spans(&content, syntax));
+        scanned.push(ScannedFile {
+            path: match_path.to_string_lossy().to_string(),
+            content,
+            comments,
+        });
+    }
+    Ok(scanned)
+}
+
+/// Scan source files for artifact references
+pub fn scan_source_refs(config: &Config, index: &ProjectIndex) -> ScanResult {
+    if !config.source_scan.enabled {
+        return ScanResult::default();
+    }
+
+    let mut result = ScanResult::default();
+
+    // Build known artifact IDs
+    let known_ids = artifact_ref_states(index);
+
+    // Compile the artifact pattern
+    let matcher = match RefMatcher::new(config, index) {
+        Ok(matcher) => matcher,
+        Err(diagnostic) => {
+            result.diagnostics.push(diagnostic);
+            return result;
+        }
+    };
+
+    let files = match scan_files(config) {
+        Ok(files) => files,
+        Err(diagnostic) => {
+            result.diagnostics.push(diagnostic);
+            return result;
+        }
+    };
+
+    for file in &files {
+        result.files_scanned += 1;
+
+        // Find all matches
+        for found in matcher.refs(file) {
+            result.refs_found += 1;
+            let span = Span::at_offset(&file.content, found.offset);
+
+            // Anchors name their clause in messages
+            let Some(artifact_id) = found.target.as_deref() else {
+                result.diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticCode::E0107SourceRefUnknown,
+                        format!("Unknown anchor reference: {}", found.written),
+                        file.path.clone(),
+                    )
+                    .with_span(span),
+                );
+                continue;
+            };
+            let shown = if found.written == artifact_id {
+                artifact_id.to_string()
+            } else {
+                format!("{} ({})", found.written, artifact_id)
+            };
+
+            // Check if artifact exists
+            match known_ids.get(artifact_id).copied() {
+                None => {
+                    result.diagnostics.push(
+                        Diagnostic::new(
+                            DiagnosticCode::E0107SourceRefUnknown,
+                            format!("Unknown artifact reference: {}", shown),
+                            file.path.clone(),
+                        )
+                        .with_span(span),
+                    );
+                }
+                Some(ArtifactRefState::Outdated(reason)) => {
+                    result.diagnostics.push(Diagnostic::new(
+                        DiagnosticCode::W0107SourceRefOutdated,
+                        format!(
+                            "Outdated reference: {} ({}) (hint: update comment or remove [[...]])",
+                            shown, reason
+                        ),
+                        file.path.clone(),
+                    )
+                    .with_span(span));
+                }
+                Some(ArtifactRefState::Active) => {
+                    // OK - reference is valid
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Comment syntax of `path`: configured for its extension, else built in.
+/// Unknown extensions are treated as text.
+fn comment_syntax(config: &Config, path: &Path) -> CommentSyntax {
+    let ext = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    if let Some(syntax) = config.source_scan.comment_syntax.get(&ext) {
+        return *syntax;
+    }
+    match ext.as_str() {
+        "rs" | "c" | "h" | "cc" | "cpp" | "hpp" | "cs" | "go" | "java" | "kt" | "scala"
+        | "swift" | "js" | "jsx" | "mjs" | "ts" | "tsx" | "css" | "scss" | "proto" | "zig"
+        | "dart" => CommentSyntax::C,
+        "py" | "rb" | "sh" | "bash" | "zsh" | "pl" | "r" | "yaml" | "yml" | "toml" | "ini"
+        | "cfg" | "nix" | "tf" | "ex" | "exs" => CommentSyntax::Hash,
+        "sql" | "lua" | "hs" | "elm" => CommentSyntax::Dash,
+        "html" | "htm" | "xml" | "svg" => CommentSyntax::Html,
+        _ => CommentSyntax::Text,
+    }
+}
+
+/// Byte ranges of the comments in `content`. String literals are skipped,
+/// so a comment marker inside a string does not start a comment.
+fn comment_spans(content: &str, syntax: CommentSyntax) -> Vec<Range<usize>> {
+    let bytes = content.as_bytes();
+    let mut spans = Vec::new();
+    if syntax == CommentSyntax::Text {
+        spans.push(0..bytes.len());
+        return spans;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let comment_end = match syntax {
+            CommentSyntax::C if rest.starts_with(b"//") => Some(line_end(bytes, i)),
+            CommentSyntax::C if rest.starts_with(b"/*") => Some(block_end(bytes, i + 2, b"*/")),
+            CommentSyntax::Hash if rest.starts_with(b"#") => Some(line_end(bytes, i)),
+            CommentSyntax::Dash if rest.starts_with(b"--") => Some(line_end(bytes, i)),
+            CommentSyntax::Html if rest.starts_with(b"<!--") => {
+                Some(block_end(bytes, i + 4, b"-->"))
+            }
+            _ => None,
+        };
+        if let Some(end) = comment_end {
+            spans.push(i..end);
+            i = end;
+            continue;
+        }
+        i = match syntax {
+            CommentSyntax::Html => i + 1,
+            _ => string_end(bytes, i, syntax).unwrap_or(i + 1),
+        };
+    }
+    spans
+}
+
+fn line_end(bytes: &[u8], from: usize) -> usize {
+    bytes[from..]
+        .iter()
+        .position(|b| *b == b'\n')
+        .map_or(bytes.len(), |pos| from + pos)
+}
+
+/// End of a block comment whose body starts at `from`, past `close`.
+fn block_end(bytes: &[u8], from: usize, close: &[u8]) -> usize {
+    bytes[from.min(bytes.len())..]
+        .windows(close.len())
+        .position(|window| window == close)
+        .map_or(bytes.len(), |pos| from + pos + close.len())
+}
+
+/// End of the string literal starting at `start`, if one starts there.
+fn string_end(bytes: &[u8], start: usize, syntax: CommentSyntax) -> Option<usize> {
+    let rest = &bytes[start..];
+    let after_ident =
+        start > 0 && (bytes[start - 1].is_ascii_alphanumeric() || bytes[start - 1] == b'_');
+    match (syntax, rest.first()?) {
+        // Rust raw strings: r"...", r#"..."#
+        (CommentSyntax::C, b'r') if !after_ident => {
+            let hashes = rest[1..].iter().take_while(|b| **b == b'#').count();
+            if rest.get(1 + hashes) != Some(&b'"') {
+                return None;
+            }
+            let mut close = vec![b'"'];
+            close.extend(std::iter::repeat_n(b'#', hashes));
+            Some(block_end(bytes, start + hashes + 2, &close))
+        }
+        (CommentSyntax::C, quote @ (b'"' | b'`')) => {
+            Some(quoted_end(bytes, start + 1, *quote, true))
+        }
+        // Character literals; a quote not closed after one character is a
+        // Rust lifetime or label.
+        (CommentSyntax::C, b'\'') => {
+            let len = match *rest.get(1)? {
+                b'\\' => rest.get(3..)?.iter().take(8).position(|b| *b == b'\'')? + 2,
+                b'\'' | b'\n' => return None,
+                b if b < 0x80 => 1,
+                b if b < 0xE0 => 2,
+                b if b < 0xF0 => 3,
+                _ => 4,
+            };
+            (rest.get(1 + len) == Some(&b'\'')).then_some(start + len + 2)
+        }
+        (CommentSyntax::Hash, quote @ (b'"' | b'\'')) => {
+            let triple = [*quote; 3];
+            if rest.starts_with(&triple) {
+                Some(block_end(bytes, start + 3, &triple))
+            } else {
+                Some(quoted_end(bytes, start + 1, *quote, false))
+            }
+        }
+        (CommentSyntax::Dash, quote @ (b'"' | b'\'')) => {
+            Some(quoted_end(bytes, start + 1, *quote, false))
+        }
+        _ => None,
+    }
+}
+
+/// End of a string with backslash escapes whose body starts at `from`.
+/// Single-line strings end unclosed at the line break, so a stray quote in
+/// prose-like files does not hide the rest of the file.
+fn quoted_end(bytes: &[u8], from: usize, quote: u8, multiline: bool) -> usize {
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b if b == quote => return i + 1,
+            b'\n' if !multiline => return i,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn build_glob_set(patterns: &[String], label: &str) -> Result<GlobSet, Diagnostic> {
+    let mut builder = GlobSetBuilder::new();
+    for pat in patterns {
+        let glob = Glob::new(pat).map_err(|e| {
+            Diagnostic::new(
+                DiagnosticCode::E0501ConfigInvalid,
+                format!("Invalid source_scan.{label} glob '{}': {}", pat, e),
+                "gov/config.toml".to_string(),
+            )
+        })?;
+        builder.add(glob);
+    }
+
+    builder.build().map_err(|e| {
+        Diagnostic::new(
+            DiagnosticCode::E0501ConfigInvalid,
+            format!("Failed to build {label} glob set: {}", e),
+            "gov/config.toml".to_string(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comments(content: &str, syntax: CommentSyntax) -> Vec<&str> {
+        comment_spans(content, syntax)
+            .into_iter()
+            .map(|span| &content[span])
+            .collect()
+    }
+
+    #[test]
+    fn c_comments_skip_strings_and_char_literals() {
+        let content = r####"let a = "// not"; // one
+let b = r#"/* "not" */"#; /* two */
+let c = '"'; fn f<'a>(x: &'a str) {} // three
+"####;
+
+        assert_eq!(
+            comments(content, CommentSyntax::C),
+            ["// one", "/* two */", "// three"]
+        );
+    }
+
+    #[test]
+    fn hash_and_html_comments_skip_strings() {
+        let python = "x = '# not'\n\"\"\"# not\n\"\"\"\ny = 1  # one\n";
+        let html = "<p title=\"x\">text</p><!-- one -->\n";
+
+        assert_eq!(comments(python, CommentSyntax::Hash), ["# one"]);
+        assert_eq!(comments(html, CommentSyntax::Html), ["<!-- one -->"]);
+        assert_eq!(comments("prose", CommentSyntax::Text), ["prose"]);
+    }
+}
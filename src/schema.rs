@@ -1,7 +1,7 @@
 //! Runtime JSON Schema validation for governance artifacts.
 
-use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode, Diagnostics};
+use crate::config::{Config, PrefixesConfig};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, Diagnostics, Span};
 use serde_json::Value;
 use std::borrow::Cow;
 use std::io::ErrorKind;
@@ -20,6 +20,7 @@ pub enum ArtifactSchema {
     WorkItem,
     Release,
     Guard,
+    Mapping,
 }
 
 impl ArtifactSchema {
@@ -31,6 +32,7 @@ impl ArtifactSchema {
             Self::WorkItem => "work.schema.json",
             Self::Release => "release.schema.json",
             Self::Guard => "guard.schema.json",
+            Self::Mapping => "mapping.schema.json",
         }
     }
 
@@ -42,6 +44,7 @@ impl ArtifactSchema {
             Self::WorkItem => include_str!("../gov/schema/work.schema.json"),
             Self::Release => include_str!("../gov/schema/release.schema.json"),
             Self::Guard => include_str!("../gov/schema/guard.schema.json"),
+            Self::Mapping => include_str!("../gov/schema/mapping.schema.json"),
         }
     }
 
@@ -53,6 +56,7 @@ impl ArtifactSchema {
             Self::WorkItem => DiagnosticCode::E0401WorkSchemaInvalid,
             Self::Release => DiagnosticCode::E0704ReleaseSchemaInvalid,
             Self::Guard => DiagnosticCode::E1001GuardSchemaInvalid,
+            Self::Mapping => DiagnosticCode::E1401MappingSchemaInvalid,
         }
     }
 
@@ -64,6 +68,7 @@ impl ArtifactSchema {
             Self::WorkItem => "work item",
             Self::Release => "release",
             Self::Guard => "verification guard",
+            Self::Mapping => "compliance mapping",
         }
     }
 
@@ -77,6 +82,7 @@ impl ArtifactSchema {
             Self::WorkItem => "../schema/work.schema.json",
             Self::Release => "schema/release.schema.json",
             Self::Guard => "../schema/guard.schema.json",
+            Self::Mapping => "../schema/mapping.schema.json",
         }
     }
 }
@@ -86,6 +92,13 @@ pub fn with_schema_header(kind: ArtifactSchema, body: &str) -> String {
     format!("#:schema {}\n\n{}", kind.relative_schema_path(), body)
 }
 
+/// Schema for `gov/config.toml`. `config check` always validates against the
+/// bundled copy so the rules match the running binary.
+pub const CONFIG_SCHEMA: SchemaTemplate = SchemaTemplate {
+    filename: "config.schema.json",
+    content: include_str!("../gov/schema/config.schema.json"),
+};
+
 pub const ARTIFACT_SCHEMA_TEMPLATES: &[SchemaTemplate] = &[
     SchemaTemplate {
         filename: "rfc.schema.json",
@@ -111,6 +124,10 @@ pub const ARTIFACT_SCHEMA_TEMPLATES: &[SchemaTemplate] = &[
         filename: "guard.schema.json",
         content: include_str!("../gov/schema/guard.schema.json"),
     },
+    SchemaTemplate {
+        filename: "mapping.schema.json",
+        content: include_str!("../gov/schema/mapping.schema.json"),
+    },
     SchemaTemplate {
         filename: "loop-state.schema.json",
         content: include_str!("../gov/schema/loop-state.schema.json"),
@@ -119,6 +136,7 @@ pub const ARTIFACT_SCHEMA_TEMPLATES: &[SchemaTemplate] = &[
         filename: "loop-round.schema.json",
         content: include_str!("../gov/schema/loop-round.schema.json"),
     },
+    CONFIG_SCHEMA,
 ];
 
 // Implements [[RFC-0002:C-GLOBAL-COMMANDS]]: check reports stale bundled schemas
@@ -154,6 +172,7 @@ pub fn validate_toml_value(
     kind: ArtifactSchema,
     config: &Config,
     artifact_path: &Path,
+    source: &str,
     value: &toml::Value,
 ) -> Result<(), Diagnostic> {
     let json_value = serde_json::to_value(value).map_err(|err| {
@@ -167,13 +186,14 @@ pub fn validate_toml_value(
             config.display_path(artifact_path).display().to_string(),
         )
     })?;
-    validate_value(kind, config, artifact_path, &json_value)
+    validate_value(kind, config, artifact_path, source, &json_value)
 }
 
 fn validate_value(
     kind: ArtifactSchema,
     config: &Config,
     artifact_path: &Path,
+    source: &str,
     value: &Value,
 ) -> Result<(), Diagnostic> {
     let artifact_display = config.display_path(artifact_path).display().to_string();
@@ -192,13 +212,16 @@ fn validate_value(
         }
     };
 
-    let schema_value: Value = serde_json::from_str(&schema_text).map_err(|err| {
+    let mut schema_value: Value = serde_json::from_str(&schema_text).map_err(|err| {
         Diagnostic::new(
             kind.diagnostic_code(),
             format!("Invalid schema file '{}': {}", schema_display, err),
             schema_display.clone(),
         )
     })?;
+    if !config.prefixes.is_default() {
+        apply_prefixes(&config.prefixes, &mut schema_value);
+    }
 
     let compiled = jsonschema::validator_for(&schema_value).map_err(|err| {
         Diagnostic::new(
@@ -208,20 +231,22 @@ fn validate_value(
         )
     })?;
 
-    let mut violations: Vec<String> = compiled
+    let mut violations: Vec<(String, String)> = compiled
         .iter_errors(value)
-        .map(|err| err.to_string())
+        .map(|err| (err.to_string(), err.instance_path().as_str().to_string()))
         .collect();
     violations.sort();
-    violations.dedup();
+    violations.dedup_by(|a, b| a.0 == b.0);
 
-    if violations.is_empty() {
+    // Point at the first listed violation; the rest stay in the message.
+    let Some((_, pointer)) = violations.first() else {
         return Ok(());
-    }
+    };
+    let span = Span::of_toml_pointer(source, pointer);
 
     let body = violations
         .into_iter()
-        .map(|item| format!("  - {item}"))
+        .map(|(item, _)| format!("  - {item}"))
         .collect::<Vec<_>>()
         .join("\n");
 
@@ -234,5 +259,28 @@ fn validate_value(
             body
         ),
         artifact_display,
-    ))
+    )
+    .with_span(span))
+}
+
+/// Point the bundled schemas' ID patterns at the configured `[prefixes]`.
+fn apply_prefixes(prefixes: &PrefixesConfig, value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                match child {
+                    Value::String(pattern) if key == "pattern" => {
+                        *pattern = prefixes.schema_pattern(pattern);
+                    }
+                    _ => apply_prefixes(prefixes, child),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                apply_prefixes(prefixes, item);
+            }
+        }
+        _ => {}
+    }
 }
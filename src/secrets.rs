@@ -0,0 +1,252 @@
+//! Sensitive ADR fields.
+//!
+//! Content fields an ADR lists in `sensitive` are stored as
+//! `ENC[chacha20-poly1305,<base64>]` and rendered as a redaction notice.
+//! The key comes from `GOVCTL_SECRET_KEY`, else `[secrets] key_file`; with
+//! it, `adr get` and `adr set` see plaintext. Without it the ciphertext is
+//! passed through untouched, so the rest of the ADR stays editable.
+
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::{AdrContent, AdrSpec};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+use std::path::Path;
+
+/// Environment variable holding the base64-encoded key.
+pub const KEY_ENV: &str = "GOVCTL_SECRET_KEY";
+
+/// ADR content fields that can be marked sensitive.
+pub const SENSITIVE_FIELDS: &[&str] = &["context", "decision", "consequences"];
+
+/// Shown in rendered output in place of a sensitive field.
+pub const REDACTED: &str = "*Redacted.*";
+
+const PREFIX: &str = "ENC[chacha20-poly1305,";
+
+/// Whether `value` is a sealed field value.
+pub fn is_sealed(value: &str) -> bool {
+    value.starts_with(PREFIX) && value.ends_with(']')
+}
+
+/// Decrypt the sensitive fields of `spec` in place. Without a key the
+/// sealed values are left as they are.
+pub fn reveal_adr(config: &Config, spec: &mut AdrSpec, source: &str) -> DiagnosticResult<()> {
+    if !spec
+        .govctl
+        .sensitive
+        .iter()
+        .any(|field| content_field(&spec.content, field).is_some_and(|value| is_sealed(value)))
+    {
+        return Ok(());
+    }
+    let Some(key) = load_key(config)? else {
+        return Ok(());
+    };
+    for field in spec.govctl.sensitive.clone() {
+        if let Some(value) = content_field_mut(&mut spec.content, &field)
+            && is_sealed(value)
+        {
+            *value = open(&key, &field, value, source)?;
+        }
+    }
+    Ok(())
+}
+
+/// The form of `spec` to write to `path`: sensitive fields holding plaintext
+/// are encrypted and fields no longer sensitive are decrypted. A field whose
+/// plaintext is unchanged keeps its ciphertext from the file on disk, so
+/// unrelated edits do not rewrite it.
+pub fn seal_adr(
+    config: &Config,
+    spec: &AdrSpec,
+    path: &Path,
+    source: &str,
+) -> DiagnosticResult<AdrSpec> {
+    for field in &spec.govctl.sensitive {
+        if !SENSITIVE_FIELDS.contains(&field.as_str()) {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0820InvalidFieldValue,
+                format!(
+                    "'{field}' cannot be sensitive; expected one of: {}",
+                    SENSITIVE_FIELDS.join(", ")
+                ),
+                source,
+            ));
+        }
+    }
+
+    let mut sealed = spec.clone();
+    let mut key = None;
+    let mut previous = None;
+    for &field in SENSITIVE_FIELDS {
+        let sensitive = spec.govctl.sensitive.iter().any(|name| name == field);
+        let Some(value) = content_field_mut(&mut sealed.content, field) else {
+            continue;
+        };
+        if sensitive == is_sealed(value) {
+            continue;
+        }
+        let key = match &key {
+            Some(key) => key,
+            None => key.insert(require_key(config, source)?),
+        };
+        if !sensitive {
+            *value = open(key, field, value, source)?;
+            continue;
+        }
+        let previous = previous.get_or_insert_with(|| {
+            crate::parse::load_adr(config, path)
+                .ok()
+                .map(|entry| entry.spec.content)
+        });
+        let unchanged = previous
+            .as_ref()
+            .and_then(|content| content_field(content, field))
+            .filter(|old| is_sealed(old))
+            .filter(|old| open(key, field, old, source).ok().as_deref() == Some(value.as_str()));
+        *value = match unchanged {
+            Some(old) => old.to_string(),
+            None => seal(key, field, value, source)?,
+        };
+    }
+    Ok(sealed)
+}
+
+/// Sensitive fields of `spec` that hold plaintext, such as after a hand edit.
+pub fn unsealed_sensitive_fields(spec: &AdrSpec) -> Vec<&str> {
+    spec.govctl
+        .sensitive
+        .iter()
+        .map(String::as_str)
+        .filter(|field| content_field(&spec.content, field).is_some_and(|value| !is_sealed(value)))
+        .collect()
+}
+
+fn content_field<'a>(content: &'a AdrContent, field: &str) -> Option<&'a String> {
+    match field {
+        "context" => Some(&content.context),
+        "decision" => Some(&content.decision),
+        "consequences" => Some(&content.consequences),
+        _ => None,
+    }
+}
+
+fn content_field_mut<'a>(content: &'a mut AdrContent, field: &str) -> Option<&'a mut String> {
+    match field {
+        "context" => Some(&mut content.context),
+        "decision" => Some(&mut content.decision),
+        "consequences" => Some(&mut content.consequences),
+        _ => None,
+    }
+}
+
+fn require_key(config: &Config, source: &str) -> DiagnosticResult<LessSafeKey> {
+    load_key(config)?.ok_or_else(|| {
+        Diagnostic::new(
+            DiagnosticCode::E1601SecretKeyMissing,
+            format!("Sensitive fields need a key: set {KEY_ENV} or [secrets] key_file"),
+            source,
+        )
+    })
+}
+
+/// The configured key, or `None` when neither source is set.
+fn load_key(config: &Config) -> DiagnosticResult<Option<LessSafeKey>> {
+    let (encoded, source) = if let Some(env) = std::env::var(KEY_ENV)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+    {
+        (env, KEY_ENV.to_string())
+    } else if let Some(file) = &config.secrets.key_file {
+        let path = config.project_root().join(file);
+        let display = config.display_path(&path).display().to_string();
+        let body = std::fs::read_to_string(&path)
+            .map_err(|err| Diagnostic::io_error("read secret key", err, &display))?;
+        (body, display)
+    } else {
+        return Ok(None);
+    };
+
+    let invalid = || {
+        Diagnostic::new(
+            DiagnosticCode::E1601SecretKeyMissing,
+            "Secret key must be 32 bytes, base64-encoded",
+            &source,
+        )
+    };
+    let bytes = STANDARD.decode(encoded.trim()).map_err(|_| invalid())?;
+    UnboundKey::new(&CHACHA20_POLY1305, &bytes)
+        .map(|key| Some(LessSafeKey::new(key)))
+        .map_err(|_| invalid())
+}
+
+/// Encrypt `plaintext`, binding it to `field` so values cannot be swapped
+/// between fields.
+fn seal(key: &LessSafeKey, field: &str, plaintext: &str, source: &str) -> DiagnosticResult<String> {
+    let failed = || {
+        Diagnostic::new(
+            DiagnosticCode::E0903UnexpectedError,
+            format!("Failed to encrypt '{field}'"),
+            source,
+        )
+    };
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new().fill(&mut nonce).map_err(|_| failed())?;
+    let mut buffer = plaintext.as_bytes().to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(field.as_bytes()),
+        &mut buffer,
+    )
+    .map_err(|_| failed())?;
+    let mut payload = nonce.to_vec();
+    payload.extend_from_slice(&buffer);
+    Ok(format!("{PREFIX}{}]", STANDARD.encode(payload)))
+}
+
+fn open(key: &LessSafeKey, field: &str, sealed: &str, source: &str) -> DiagnosticResult<String> {
+    let failed = || {
+        Diagnostic::new(
+            DiagnosticCode::E1602SecretDecryptFailed,
+            format!("Cannot decrypt '{field}': wrong key or corrupted value"),
+            source,
+        )
+    };
+    let encoded = sealed
+        .strip_prefix(PREFIX)
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(failed)?;
+    let payload = STANDARD.decode(encoded).map_err(|_| failed())?;
+    if payload.len() < NONCE_LEN {
+        return Err(failed());
+    }
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| failed())?;
+    let mut buffer = ciphertext.to_vec();
+    let plaintext = key
+        .open_in_place(nonce, Aad::from(field.as_bytes()), &mut buffer)
+        .map_err(|_| failed())?;
+    String::from_utf8(plaintext.to_vec()).map_err(|_| failed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_roundtrips_and_binds_field() -> Result<(), ring::error::Unspecified> {
+        let key = LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, &[7u8; 32])?);
+        let sealed = seal(&key, "context", "Vendor X pricing", "test").ok();
+        let sealed = sealed.as_deref().unwrap_or_default();
+        assert!(is_sealed(sealed));
+        assert_eq!(
+            open(&key, "context", sealed, "test").ok().as_deref(),
+            Some("Vendor X pricing")
+        );
+        assert!(open(&key, "decision", sealed, "test").is_err());
+        Ok(())
+    }
+}
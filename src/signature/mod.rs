@@ -37,7 +37,8 @@ pub fn compute_rfc_signature(rfc: &RfcIndex) -> Result<String, Diagnostic> {
 
 /// Compute SHA-256 signature for RFC amendment detection.
 ///
-/// This signature intentionally ignores bump bookkeeping fields. It answers:
+/// This signature intentionally ignores bump bookkeeping fields, approvals,
+/// and the review freeze. It answers:
 /// "Has RFC or clause content changed since the last recorded amendment
 /// baseline?" rather than "Would the rendered projection change?"
 ///
@@ -49,6 +50,8 @@ pub fn compute_rfc_content_signature(rfc: &RfcIndex) -> Result<String, Diagnosti
         map.remove("version");
         map.remove("phase");
         map.remove("changelog");
+        map.remove("approvals");
+        map.remove("frozen");
     })
 }
 
@@ -73,6 +76,16 @@ fn compute_rfc_signature_with_filter(
     clauses.sort_by(|a, b| a.spec.clause_id.cmp(&b.spec.clause_id));
 
     for clause in clauses {
+        if clause.spec.text.is_none() {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!(
+                    "Cannot sign {}:{}: clause text was not loaded",
+                    rfc.rfc.rfc_id, clause.spec.clause_id
+                ),
+                format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id),
+            ));
+        }
         let clause_json = signature_value(
             &clause.spec,
             DiagnosticCode::E0201ClauseSchemaInvalid,
@@ -204,6 +217,9 @@ fn hex_encode(bytes: &[u8]) -> String {
 /// Stored signatures created before content-only signatures are accepted as a
 /// legacy clean baseline when the full rendered-projection signature still matches.
 pub fn is_rfc_amended(rfc: &RfcIndex) -> bool {
+    if let Some(amended) = rfc.amended {
+        return amended;
+    }
     if rfc.rfc.phase == crate::model::RfcPhase::Spec {
         return false;
     }
@@ -73,7 +73,7 @@ fn test_rfc_content_signature_includes_clause_content() -> Result<(), Diagnostic
     let mut rfc = test_rfc_index();
     let baseline = compute_rfc_content_signature(&rfc)?;
 
-    rfc.clauses[0].spec.text = "Updated normative behavior.".to_string();
+    rfc.clauses[0].spec.text = Some("Updated normative behavior.".to_string());
 
     assert_ne!(compute_rfc_content_signature(&rfc)?, baseline);
     Ok(())
@@ -121,9 +121,14 @@ fn test_rfc_index() -> RfcIndex {
             status: RfcStatus::Normative,
             phase: RfcPhase::Impl,
             owners: vec!["@test-user".to_string()],
+            approvals: vec![],
+            frozen: false,
             created: "2026-06-15".to_string(),
+            created_by: None,
+            attachments: vec![],
             updated: None,
             supersedes: None,
+            deprecation: None,
             refs: vec![],
             tags: vec![],
             sections: vec![SectionSpec {
@@ -139,14 +144,16 @@ fn test_rfc_index() -> RfcIndex {
                 title: "Test Clause".to_string(),
                 kind: ClauseKind::Normative,
                 status: ClauseStatus::Active,
-                text: "Original normative behavior.".to_string(),
+                text: Some("Original normative behavior.".to_string()),
                 anchors: vec![],
                 superseded_by: None,
+                deprecation: None,
                 since: Some("0.1.0".to_string()),
                 tags: vec![],
             },
             path: PathBuf::from("gov/rfc/RFC-0001/clauses/C-TEST.toml"),
         }],
         path: PathBuf::from("gov/rfc/RFC-0001/rfc.toml"),
+        amended: None,
     }
 }
@@ -70,15 +70,9 @@ pub fn strip_for_terminal(md: &str) -> String {
 /// When stdout is a TTY and NO_COLOR is not set, renders styled output
 /// via markdown-to-ansi. Otherwise returns cleaned plain markdown.
 pub fn render_terminal_md(md: &str) -> String {
-    let stripped = strip_for_terminal(md);
-    let clean = stripped.trim();
-
-    if clean.is_empty() {
-        return String::new();
-    }
-
-    if !stdout_supports_color() {
-        return clean.to_string();
+    let clean = render_plain_md(md);
+    if clean.is_empty() || !stdout_supports_color() {
+        return clean;
     }
 
     let width = terminal_size::terminal_size()
@@ -91,7 +85,12 @@ pub fn render_terminal_md(md: &str) -> String {
         code_bg: true,
     };
 
-    markdown_to_ansi::render(clean, &opts)
+    markdown_to_ansi::render(&clean, &opts)
+}
+
+/// Render markdown as cleaned plain text, never styled.
+pub fn render_plain_md(md: &str) -> String {
+    strip_for_terminal(md).trim().to_string()
 }
 
 /// Render markdown to a ratatui `Text` widget via the shared pipeline.
@@ -0,0 +1,244 @@
+//! Work board: work items in status columns, moved with `work move`.
+
+use super::{App, View, run_command};
+use crate::aliases::Aliases;
+use crate::cmd;
+use crate::command_router::{LifecycleOp, plan_lifecycle};
+use crate::model::WorkItemStatus;
+use std::path::PathBuf;
+
+/// Board columns, left to right.
+pub const BOARD_COLUMNS: [WorkItemStatus; 4] = [
+    WorkItemStatus::Queue,
+    WorkItemStatus::Active,
+    WorkItemStatus::Done,
+    WorkItemStatus::Cancelled,
+];
+
+impl App {
+    /// Indices into `index.work_items` for one board column, in ID order.
+    pub fn board_column_items(&self, column: usize) -> Vec<usize> {
+        let Some(status) = BOARD_COLUMNS.get(column) else {
+            return Vec::new();
+        };
+        self.index
+            .work_items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.meta().status == *status)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// The focused work item, if its column is not empty.
+    pub fn board_selected_item(&self) -> Option<usize> {
+        self.board_column_items(self.board_column)
+            .get(self.board_row)
+            .copied()
+    }
+
+    pub fn board_left(&mut self) {
+        match &mut self.board_move_target {
+            Some(target) => *target = target.saturating_sub(1),
+            None => {
+                self.board_column = self.board_column.saturating_sub(1);
+                self.clamp_board_row();
+            }
+        }
+    }
+
+    pub fn board_right(&mut self) {
+        let last = BOARD_COLUMNS.len() - 1;
+        match &mut self.board_move_target {
+            Some(target) => *target = (*target + 1).min(last),
+            None => {
+                self.board_column = (self.board_column + 1).min(last);
+                self.clamp_board_row();
+            }
+        }
+    }
+
+    pub fn board_down(&mut self) {
+        if self.board_move_target.is_none() {
+            self.board_row = self.board_row.saturating_add(1);
+            self.clamp_board_row();
+        }
+    }
+
+    pub fn board_up(&mut self) {
+        if self.board_move_target.is_none() {
+            self.board_row = self.board_row.saturating_sub(1);
+        }
+    }
+
+    fn clamp_board_row(&mut self) {
+        let len = self.board_column_items(self.board_column).len();
+        self.board_row = self.board_row.min(len.saturating_sub(1));
+    }
+
+    /// Pick up the focused item; h/l then choose its destination column.
+    pub fn start_board_move(&mut self) {
+        if self.board_selected_item().is_some() {
+            self.board_move_target = Some(self.board_column);
+        }
+    }
+
+    /// Open the focused item's detail; Esc returns to the board.
+    pub fn enter_board_detail(&mut self) {
+        if let Some(idx) = self.board_selected_item() {
+            self.view = View::WorkDetail(idx);
+            self.board_detail = true;
+            self.scroll = 0;
+        }
+    }
+
+    pub fn cancel_board_move(&mut self) {
+        self.board_move_target = None;
+    }
+
+    /// Move the picked-up item to the destination column through the same
+    /// path as `govctl work move`, then reload and keep it focused. Invalid
+    /// transitions are reported in the footer.
+    pub fn confirm_board_move(&mut self) {
+        let Some(target) = self.board_move_target.take() else {
+            return;
+        };
+        let Some(id) = self
+            .board_selected_item()
+            .and_then(|idx| self.index.work_items.get(idx))
+            .map(|item| item.meta().id.clone())
+        else {
+            return;
+        };
+        let Some(status) = BOARD_COLUMNS.get(target).copied() else {
+            return;
+        };
+        if target == self.board_column {
+            return;
+        }
+
+        let plan = plan_lifecycle(
+            &self.config.prefixes,
+            &Aliases::load(&self.config),
+            cmd::edit::ArtifactType::WorkItem,
+            &id,
+            LifecycleOp::MoveWork {
+                file_or_id: PathBuf::from(&id),
+                status,
+                reopen_reason: None,
+                finish: None,
+            },
+        );
+        let args = [
+            "work".to_string(),
+            "move".to_string(),
+            id.clone(),
+            status.as_ref().to_string(),
+        ];
+        if let Err(diagnostic) = run_command(&self.config, self.actor.as_ref(), &plan, &args) {
+            self.notice = Some(format!(
+                "{}: {}",
+                diagnostic.code.code(),
+                diagnostic.message
+            ));
+            return;
+        }
+        if let Err(diagnostic) = self.reload() {
+            self.notice = Some(format!("Moved, but reload failed: {}", diagnostic.message));
+            return;
+        }
+        self.board_column = target;
+        self.board_row = self
+            .board_column_items(target)
+            .iter()
+            .position(|idx| self.index.work_items[*idx].meta().id == id)
+            .unwrap_or_default();
+        self.notice = Some(format!("Moved {id} to {}", status.as_ref()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::write::WriteOp;
+
+    #[test]
+    fn board_navigation_stays_within_columns() -> Result<(), Box<dyn std::error::Error>> {
+        let (_temp_dir, mut app) = board_app()?;
+
+        assert_eq!(app.board_column_items(0).len(), 2);
+        app.board_down();
+        app.board_down();
+        assert_eq!(app.board_row, 1);
+        app.board_right();
+        assert_eq!((app.board_column, app.board_row), (1, 0));
+        app.board_left();
+        app.board_left();
+        assert_eq!(app.board_column, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn board_move_runs_work_move_and_follows_item() -> Result<(), Box<dyn std::error::Error>> {
+        let (_temp_dir, mut app) = board_app()?;
+        let id = app.index.work_items[0].meta().id.clone();
+
+        app.start_board_move();
+        app.board_right();
+        app.confirm_board_move();
+
+        assert_eq!(app.board_move_target, None);
+        assert_eq!((app.board_column, app.board_row), (1, 0));
+        assert_eq!(
+            app.index.work_items[0].meta().status,
+            WorkItemStatus::Active
+        );
+        assert_eq!(app.notice, Some(format!("Moved {id} to active")));
+
+        // queue -> done skips active and is rejected like `work move`.
+        app.board_column = 0;
+        app.board_row = 0;
+        app.start_board_move();
+        app.board_right();
+        app.board_right();
+        app.confirm_board_move();
+
+        assert_eq!(app.board_column, 0);
+        assert!(
+            app.notice
+                .as_deref()
+                .is_some_and(|notice| notice.starts_with("E0403"))
+        );
+        Ok(())
+    }
+
+    fn board_app() -> Result<(tempfile::TempDir, App), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let config = Config {
+            gov_root: temp_dir.path().join("gov"),
+            ..Default::default()
+        };
+        cmd::new::init_project(
+            &config,
+            false,
+            None,
+            cmd::new::InitScaffold::default(),
+            WriteOp::EXECUTE,
+        )?;
+        for title in ["First", "Second"] {
+            cmd::new::create(
+                &config,
+                &crate::NewTarget::Work {
+                    title: title.to_string(),
+                    active: false,
+                },
+                None,
+                WriteOp::EXECUTE,
+            )?;
+        }
+        let mut app = App::with_project(config, Default::default());
+        app.reload()?;
+        Ok((temp_dir, app))
+    }
+}
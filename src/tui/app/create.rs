@@ -0,0 +1,437 @@
+//! Creation form for quick capture from list views.
+
+use super::{App, View, run_command};
+use crate::ListTarget;
+use crate::command_router::{CreateOp, plan_create};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::ClauseKind;
+use std::collections::BTreeSet;
+
+/// Artifact kind a creation form produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreateKind {
+    Rfc,
+    Clause,
+    Adr,
+    Work,
+    Guard,
+}
+
+impl CreateKind {
+    /// The kind created from a list view, if that list supports creation.
+    pub fn for_view(view: View) -> Option<Self> {
+        match view {
+            View::RfcList => Some(Self::Rfc),
+            View::ClauseList => Some(Self::Clause),
+            View::AdrList => Some(Self::Adr),
+            View::WorkList => Some(Self::Work),
+            View::GuardList => Some(Self::Guard),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Rfc => "RFC",
+            Self::Clause => "Clause",
+            Self::Adr => "ADR",
+            Self::Work => "Work Item",
+            Self::Guard => "Guard",
+        }
+    }
+
+    fn list_target(self) -> ListTarget {
+        match self {
+            Self::Rfc => ListTarget::Rfc,
+            Self::Clause => ListTarget::Clause,
+            Self::Adr => ListTarget::Adr,
+            Self::Work => ListTarget::Work,
+            Self::Guard => ListTarget::Guard,
+        }
+    }
+}
+
+/// Editable value of one form field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    Text(String),
+    /// One of a fixed set of options, cycled with Space.
+    Choice {
+        options: &'static [&'static str],
+        selected: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label: &'static str,
+    pub value: FieldValue,
+    /// Required text fields must not be blank on submit.
+    pub required: bool,
+}
+
+impl FormField {
+    fn text(label: &'static str, value: impl Into<String>, required: bool) -> Self {
+        Self {
+            label,
+            value: FieldValue::Text(value.into()),
+            required,
+        }
+    }
+
+    fn choice(label: &'static str, options: &'static [&'static str]) -> Self {
+        Self {
+            label,
+            value: FieldValue::Choice {
+                options,
+                selected: 0,
+            },
+            required: false,
+        }
+    }
+
+    /// The current value as entered or selected, trimmed.
+    pub fn display_value(&self) -> &str {
+        match &self.value {
+            FieldValue::Text(text) => text.trim(),
+            FieldValue::Choice { options, selected } => options.get(*selected).unwrap_or(&""),
+        }
+    }
+}
+
+/// State of an open creation form.
+#[derive(Debug, Clone)]
+pub struct CreateForm {
+    pub kind: CreateKind,
+    pub fields: Vec<FormField>,
+    /// Index of the focused field.
+    pub focus: usize,
+    /// Why the last submit failed, shown under the fields.
+    pub error: Option<Diagnostic>,
+}
+
+impl CreateForm {
+    /// A blank form; `rfc_id` prefills the clause ID.
+    pub fn new(kind: CreateKind, rfc_id: Option<&str>) -> Self {
+        let fields = match kind {
+            CreateKind::Rfc => vec![
+                FormField::text("Title", "", true),
+                FormField::text("ID (blank: next free)", "", false),
+            ],
+            CreateKind::Clause => vec![
+                FormField::text(
+                    "Clause ID",
+                    rfc_id.map(|id| format!("{id}:C-")).unwrap_or_default(),
+                    true,
+                ),
+                FormField::text("Title", "", true),
+                FormField::text("Section", "Specification", true),
+                FormField::choice("Kind", &["normative", "informative"]),
+            ],
+            CreateKind::Adr | CreateKind::Guard => vec![FormField::text("Title", "", true)],
+            CreateKind::Work => vec![
+                FormField::text("Title", "", true),
+                FormField::choice("Status", &["queue", "active"]),
+            ],
+        };
+        Self {
+            kind,
+            fields,
+            focus: 0,
+            error: None,
+        }
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % self.fields.len().max(1);
+    }
+
+    pub fn focus_prev(&mut self) {
+        let len = self.fields.len().max(1);
+        self.focus = (self.focus + len - 1) % len;
+    }
+
+    /// Type into the focused text field; Space cycles a choice field.
+    pub fn push_char(&mut self, ch: char) {
+        let Some(field) = self.fields.get_mut(self.focus) else {
+            return;
+        };
+        match &mut field.value {
+            FieldValue::Text(text) => text.push(ch),
+            FieldValue::Choice { options, selected } => {
+                if ch == ' ' {
+                    *selected = (*selected + 1) % options.len().max(1);
+                }
+            }
+        }
+    }
+
+    pub fn pop_char(&mut self) {
+        if let Some(FormField {
+            value: FieldValue::Text(text),
+            ..
+        }) = self.fields.get_mut(self.focus)
+        {
+            text.pop();
+        }
+    }
+
+    fn value(&self, label: &str) -> &str {
+        self.fields
+            .iter()
+            .find(|field| field.label == label)
+            .map(FormField::display_value)
+            .unwrap_or_default()
+    }
+
+    /// The create operation and its equivalent CLI arguments, or the first
+    /// missing required field.
+    fn request(&self) -> DiagnosticResult<(CreateOp, Vec<String>)> {
+        if let Some(field) = self
+            .fields
+            .iter()
+            .find(|field| field.required && field.display_value().is_empty())
+        {
+            return Err(Diagnostic::new(
+                DiagnosticCode::E0801MissingRequiredArg,
+                format!("{} is required", field.label),
+                "tui",
+            ));
+        }
+        let title = self.value("Title").to_string();
+        let mut args = Vec::new();
+        let create = match self.kind {
+            CreateKind::Rfc => {
+                let id = Some(self.value("ID (blank: next free)").to_string())
+                    .filter(|id| !id.is_empty());
+                args.extend(["rfc".to_string(), "new".to_string(), title.clone()]);
+                if let Some(id) = &id {
+                    args.extend(["--id".to_string(), id.clone()]);
+                }
+                CreateOp::Rfc { title, id }
+            }
+            CreateKind::Clause => {
+                let clause_id = self.value("Clause ID").to_string();
+                let section = self.value("Section").to_string();
+                let informative = self.value("Kind") == "informative";
+                args.extend([
+                    "clause".to_string(),
+                    "new".to_string(),
+                    clause_id.clone(),
+                    title.clone(),
+                    "--section".to_string(),
+                    section.clone(),
+                    "--kind".to_string(),
+                    self.value("Kind").to_string(),
+                ]);
+                CreateOp::Clause {
+                    clause_id,
+                    title,
+                    section,
+                    kind: if informative {
+                        ClauseKind::Informative
+                    } else {
+                        ClauseKind::Normative
+                    },
+                }
+            }
+            CreateKind::Adr => {
+                args.extend(["adr".to_string(), "new".to_string(), title.clone()]);
+                CreateOp::Adr { title }
+            }
+            CreateKind::Work => {
+                let active = self.value("Status") == "active";
+                args.extend(["work".to_string(), "new".to_string(), title.clone()]);
+                if active {
+                    args.push("--active".to_string());
+                }
+                CreateOp::Work { title, active }
+            }
+            CreateKind::Guard => {
+                args.extend(["guard".to_string(), "new".to_string(), title.clone()]);
+                CreateOp::Guard { title }
+            }
+        };
+        Ok((create, args))
+    }
+}
+
+impl App {
+    /// Open the creation form for the current list view.
+    pub fn open_create_form(&mut self) {
+        let Some(kind) = CreateKind::for_view(self.view) else {
+            return;
+        };
+        let rfc_id = match kind {
+            CreateKind::Clause => self
+                .list_indices()
+                .get(self.selected)
+                .and_then(|idx| self.supplement.clauses.get(*idx))
+                .map(|entry| entry.rfc_id.clone()),
+            _ => None,
+        };
+        self.create_form = Some(CreateForm::new(kind, rfc_id.as_deref()));
+    }
+
+    pub fn close_create_form(&mut self) {
+        self.create_form = None;
+    }
+
+    /// Create the artifact described by the open form, then reload the
+    /// project and select it. On failure the form stays open with the error.
+    pub fn submit_create_form(&mut self) {
+        let Some(form) = self.create_form.as_mut() else {
+            return;
+        };
+        let kind = form.kind;
+        let result = form.request().and_then(|(create, args)| {
+            run_command(
+                &self.config,
+                self.actor.as_ref(),
+                &plan_create(kind.list_target(), create),
+                &args,
+            )
+        });
+        if let Err(diagnostic) = result {
+            form.error = Some(diagnostic);
+            return;
+        }
+        self.create_form = None;
+
+        let before: BTreeSet<String> = self.list_item_ids().into_iter().collect();
+        if let Err(diagnostic) = self.reload() {
+            self.notice = Some(format!(
+                "Created, but reload failed: {}",
+                diagnostic.message
+            ));
+            return;
+        }
+        self.clear_filter();
+        let created = self
+            .list_item_ids()
+            .into_iter()
+            .enumerate()
+            .find(|(_, id)| !before.contains(id));
+        match created {
+            Some((idx, id)) => {
+                self.selected = idx;
+                self.table_state.select(Some(idx));
+                self.notice = Some(format!("Created {id}"));
+            }
+            None => self.notice = Some(format!("Created {}", kind.label())),
+        }
+    }
+
+    /// IDs of the current list's items, in display order before filtering.
+    fn list_item_ids(&self) -> Vec<String> {
+        match self.view {
+            View::RfcList => self
+                .index
+                .rfcs
+                .iter()
+                .map(|rfc| rfc.rfc.rfc_id.clone())
+                .collect(),
+            View::ClauseList => self
+                .supplement
+                .clauses
+                .iter()
+                .map(|entry| format!("{}:{}", entry.rfc_id, entry.clause.spec.clause_id))
+                .collect(),
+            View::AdrList => self
+                .index
+                .adrs
+                .iter()
+                .map(|adr| adr.meta().id.clone())
+                .collect(),
+            View::WorkList => self
+                .index
+                .work_items
+                .iter()
+                .map(|item| item.meta().id.clone())
+                .collect(),
+            View::GuardList => self
+                .supplement
+                .guards
+                .iter()
+                .map(|guard| guard.meta().id.clone())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd;
+    use crate::config::Config;
+    use crate::write::WriteOp;
+
+    #[test]
+    fn work_form_builds_create_op_and_cli_args() -> DiagnosticResult<()> {
+        let mut form = CreateForm::new(CreateKind::Work, None);
+        for ch in "Fix login".chars() {
+            form.push_char(ch);
+        }
+        form.focus_next();
+        form.push_char(' ');
+
+        let (create, args) = form.request()?;
+
+        assert!(matches!(
+            create,
+            CreateOp::Work { ref title, active: true } if title == "Fix login"
+        ));
+        assert_eq!(args, ["work", "new", "Fix login", "--active"]);
+        Ok(())
+    }
+
+    #[test]
+    fn submit_creates_work_item_and_selects_it() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let config = Config {
+            gov_root: temp_dir.path().join("gov"),
+            ..Default::default()
+        };
+        cmd::new::init_project(
+            &config,
+            false,
+            None,
+            cmd::new::InitScaffold::default(),
+            WriteOp::EXECUTE,
+        )?;
+        let mut app = App::with_project(config, crate::model::ProjectIndex::default());
+        app.go_to(View::WorkList);
+
+        app.open_create_form();
+        if let Some(form) = app.create_form.as_mut() {
+            for ch in "Quick capture".chars() {
+                form.push_char(ch);
+            }
+        }
+        app.submit_create_form();
+
+        assert!(app.create_form.is_none());
+        assert_eq!(app.index.work_items.len(), 1);
+        assert_eq!(app.index.work_items[0].meta().title, "Quick capture");
+        assert_eq!(app.selected, 0);
+        assert!(
+            app.notice
+                .as_deref()
+                .is_some_and(|notice| notice.starts_with("Created WI-"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn blank_required_field_is_rejected() {
+        let mut form = CreateForm::new(CreateKind::Clause, Some("RFC-0001"));
+        assert_eq!(form.value("Clause ID"), "RFC-0001:C-");
+
+        let result = form.request();
+
+        assert!(matches!(result, Err(ref diag) if diag.message == "Title is required"));
+        form.focus_prev();
+        assert_eq!(form.focus, 3);
+    }
+}
@@ -0,0 +1,326 @@
+//! Export of the current list or detail view to a file or the clipboard.
+
+use super::{App, View};
+use crate::cmd::list::ListRows;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::render::{
+    RenderProjection, expand_inline_refs, render_adr_with_projection,
+    render_clause_with_projection, render_rfc_with_projection, render_work_item_with_projection,
+};
+use crate::tui::data::guard_markdown;
+use crate::write::{WriteOp, write_file};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+            Self::Markdown => "Markdown",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportTarget {
+    File,
+    Clipboard,
+}
+
+/// One entry of the export menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportOption {
+    pub format: ExportFormat,
+    pub target: ExportTarget,
+    /// File name, for file targets, in the project root.
+    pub file_name: String,
+}
+
+/// What the current view exports: its base file name and formats.
+fn export_source(app: &App) -> Option<(String, &'static [ExportFormat])> {
+    const LIST: &[ExportFormat] = &[ExportFormat::Csv, ExportFormat::Json];
+    const DETAIL: &[ExportFormat] = &[ExportFormat::Markdown];
+    let list = |name: &str| Some((name.to_string(), LIST));
+    match app.view {
+        View::RfcList => list("rfcs"),
+        View::ClauseList => list("clauses"),
+        View::AdrList => list("adrs"),
+        View::WorkList => list("work-items"),
+        View::GuardList => list("guards"),
+        View::RfcDetail(_)
+        | View::ClauseDetail(_, _)
+        | View::AdrDetail(_)
+        | View::WorkDetail(_)
+        | View::GuardDetail(_) => app
+            .detail_artifact_id()
+            .map(|id| (id.replace(':', "-"), DETAIL)),
+        _ => None,
+    }
+}
+
+impl App {
+    /// Export choices for the current view; empty where nothing exports.
+    pub fn export_options(&self) -> Vec<ExportOption> {
+        let Some((name, formats)) = export_source(self) else {
+            return Vec::new();
+        };
+        [ExportTarget::File, ExportTarget::Clipboard]
+            .into_iter()
+            .flat_map(|target| formats.iter().map(move |format| (target, *format)))
+            .map(|(target, format)| ExportOption {
+                format,
+                target,
+                file_name: format!("{name}.{}", format.extension()),
+            })
+            .collect()
+    }
+
+    pub fn open_export_menu(&mut self) {
+        if self.export_options().is_empty() {
+            self.notice = Some("Nothing to export in this view".to_string());
+        } else {
+            self.export_menu = true;
+        }
+    }
+
+    pub fn close_export_menu(&mut self) {
+        self.export_menu = false;
+    }
+
+    /// Run the `choice`-th export option and report the outcome in the footer.
+    pub fn export(&mut self, choice: usize) {
+        let Some(option) = self.export_options().into_iter().nth(choice) else {
+            return;
+        };
+        self.export_menu = false;
+        let result = self
+            .export_content(option.format)
+            .and_then(|(content, what)| match option.target {
+                ExportTarget::File => {
+                    let path = self.free_export_path(&option.file_name);
+                    let op = WriteOp::EXECUTE.with_fsync(self.config.concurrency.fsync);
+                    write_file(&path, &content, op, None)?;
+                    let shown = path
+                        .strip_prefix(self.config.project_root())
+                        .unwrap_or(&path);
+                    Ok(format!("Exported {what} to {}", shown.display()))
+                }
+                ExportTarget::Clipboard => {
+                    copy_to_clipboard(&content)?;
+                    Ok(format!("Copied {what} to the clipboard"))
+                }
+            });
+        self.notice = Some(match result {
+            Ok(message) => message,
+            Err(diagnostic) => format!("{}: {}", diagnostic.code.code(), diagnostic.message),
+        });
+    }
+
+    /// What is on screen in `format`, and a short description of it. Lists
+    /// export their filtered rows in display order.
+    fn export_content(&mut self, format: ExportFormat) -> DiagnosticResult<(String, String)> {
+        if format == ExportFormat::Markdown {
+            let id = self.detail_artifact_id().unwrap_or_default();
+            return Ok((self.detail_markdown()?, id));
+        }
+        let indices = self.list_indices();
+        let rows = match self.view {
+            View::RfcList => ListRows::rfcs(indices.iter().filter_map(|i| self.index.rfcs.get(*i))),
+            View::ClauseList => ListRows::clauses(
+                indices
+                    .iter()
+                    .filter_map(|i| self.supplement.clauses.get(*i))
+                    .map(|entry| (entry.rfc_id.as_str(), &entry.clause)),
+            ),
+            View::AdrList => ListRows::adrs(indices.iter().filter_map(|i| self.index.adrs.get(*i))),
+            View::WorkList => {
+                ListRows::work_items(indices.iter().filter_map(|i| self.index.work_items.get(*i)))
+            }
+            View::GuardList => ListRows::guards(
+                indices
+                    .iter()
+                    .filter_map(|i| self.supplement.guards.get(*i)),
+            ),
+            _ => return Err(nothing_to_export()),
+        };
+        let what = match rows.rows.len() {
+            1 => "1 row".to_string(),
+            n => format!("{n} rows"),
+        };
+        let content = match format {
+            ExportFormat::Csv => rows.to_csv(),
+            _ => rows.to_json(),
+        };
+        Ok((content, what))
+    }
+
+    /// The detail view's artifact as `govctl <kind> show` renders it.
+    fn detail_markdown(&self) -> DiagnosticResult<String> {
+        let current = RenderProjection::Current;
+        let raw = match self.view {
+            View::RfcDetail(idx) => {
+                let rfc = self.index.rfcs.get(idx).ok_or_else(nothing_to_export)?;
+                render_rfc_with_projection(&self.config.prefixes, rfc, current, None)?
+            }
+            View::ClauseDetail(rfc_idx, clause_idx) => {
+                let rfc = self.index.rfcs.get(rfc_idx).ok_or_else(nothing_to_export)?;
+                let clause = rfc.clauses.get(clause_idx).ok_or_else(nothing_to_export)?;
+                let mut raw = String::new();
+                render_clause_with_projection(&mut raw, &rfc.rfc.rfc_id, clause, current);
+                raw
+            }
+            View::AdrDetail(idx) => {
+                let adr = self.index.adrs.get(idx).ok_or_else(nothing_to_export)?;
+                render_adr_with_projection(&self.config.prefixes, adr, current)?
+            }
+            View::WorkDetail(idx) => {
+                let item = self
+                    .index
+                    .work_items
+                    .get(idx)
+                    .ok_or_else(nothing_to_export)?;
+                render_work_item_with_projection(&self.config, item, current)?
+            }
+            View::GuardDetail(idx) => self
+                .supplement
+                .guards
+                .get(idx)
+                .map(guard_markdown)
+                .ok_or_else(nothing_to_export)?,
+            _ => return Err(nothing_to_export()),
+        };
+        Ok(expand_inline_refs(
+            &self.config.prefixes,
+            &raw,
+            &self.config.source_scan.pattern,
+        ))
+    }
+
+    /// `file_name` in the project root, numbered so an existing file is
+    /// never overwritten.
+    fn free_export_path(&self, file_name: &str) -> PathBuf {
+        let root = self.config.project_root();
+        let path = root.join(file_name);
+        if !path.exists() {
+            return path;
+        }
+        let (stem, extension) = file_name.rsplit_once('.').unwrap_or((file_name, ""));
+        (2..)
+            .map(|n| root.join(format!("{stem}-{n}.{extension}")))
+            .find(|path| !path.exists())
+            .unwrap_or(path)
+    }
+}
+
+fn nothing_to_export() -> Diagnostic {
+    Diagnostic::new(
+        DiagnosticCode::E0822UnsupportedOperation,
+        "Nothing to export in this view",
+        "tui",
+    )
+}
+
+/// Copy through the terminal (OSC 52), which also works over SSH.
+fn copy_to_clipboard(content: &str) -> DiagnosticResult<()> {
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::clipboard::CopyToClipboard::to_clipboard_from(content)
+    )
+    .map_err(|err| super::super::terminal_error("copy to clipboard", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd;
+    use crate::config::Config;
+
+    #[test]
+    fn export_writes_filtered_list_and_detail_without_overwriting()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let config = Config {
+            gov_root: temp_dir.path().join("gov"),
+            ..Default::default()
+        };
+        cmd::new::init_project(
+            &config,
+            false,
+            None,
+            cmd::new::InitScaffold::default(),
+            WriteOp::EXECUTE,
+        )?;
+        for title in ["Parser", "Renderer"] {
+            cmd::new::create(
+                &config,
+                &crate::NewTarget::Work {
+                    title: title.to_string(),
+                    active: false,
+                },
+                None,
+                WriteOp::EXECUTE,
+            )?;
+        }
+        let mut app = App::with_project(config, Default::default());
+        app.reload()?;
+        app.go_to(View::WorkList);
+        for ch in "render".chars() {
+            app.push_filter_char(ch);
+        }
+
+        assert_eq!(
+            app.export_options()
+                .iter()
+                .map(|option| (option.format, option.target, option.file_name.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (ExportFormat::Csv, ExportTarget::File, "work-items.csv"),
+                (ExportFormat::Json, ExportTarget::File, "work-items.json"),
+                (ExportFormat::Csv, ExportTarget::Clipboard, "work-items.csv"),
+                (
+                    ExportFormat::Json,
+                    ExportTarget::Clipboard,
+                    "work-items.json"
+                ),
+            ]
+        );
+        app.open_export_menu();
+        app.export(0);
+
+        let csv = std::fs::read_to_string(temp_dir.path().join("work-items.csv"))?;
+        assert!(!app.export_menu);
+        assert_eq!(csv.lines().count(), 2);
+        assert!(csv.contains(",queue,Renderer"));
+        assert!(
+            app.notice
+                .as_deref()
+                .is_some_and(|notice| notice == "Exported 1 row to work-items.csv")
+        );
+
+        app.export(0);
+        assert!(temp_dir.path().join("work-items-2.csv").exists());
+
+        app.clear_filter();
+        app.enter_detail();
+        let id = app.detail_artifact_id().unwrap_or_default();
+        app.export(0);
+        let markdown = std::fs::read_to_string(temp_dir.path().join(format!("{id}.md")))?;
+        assert!(markdown.contains("Parser"));
+        Ok(())
+    }
+}
@@ -0,0 +1,249 @@
+//! Refs and backlinks of the artifact in a detail view.
+
+use super::{App, View};
+use ratatui::widgets::ListState;
+
+/// One row of the links panel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkRow {
+    /// The artifact at the other end of the link.
+    pub id: String,
+    /// Title of `id`, when it is a known artifact.
+    pub title: Option<String>,
+    /// Relation as read from the current artifact, e.g. "refs" or "needed by".
+    pub relation: &'static str,
+    /// True for the current artifact's own refs, false for backlinks.
+    pub outgoing: bool,
+    /// Detail view of `id`, when it is a known artifact.
+    pub target: Option<View>,
+}
+
+impl App {
+    /// ID of the artifact shown in the current detail view.
+    pub fn detail_artifact_id(&self) -> Option<String> {
+        match self.view {
+            View::RfcDetail(idx) => self.index.rfcs.get(idx).map(|rfc| rfc.rfc.rfc_id.clone()),
+            View::ClauseDetail(rfc_idx, clause_idx) => {
+                self.index.rfcs.get(rfc_idx).and_then(|rfc| {
+                    rfc.clauses
+                        .get(clause_idx)
+                        .map(|clause| format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id))
+                })
+            }
+            View::AdrDetail(idx) => self.index.adrs.get(idx).map(|adr| adr.meta().id.clone()),
+            View::WorkDetail(idx) => self
+                .index
+                .work_items
+                .get(idx)
+                .map(|item| item.meta().id.clone()),
+            View::GuardDetail(idx) => self
+                .supplement
+                .guards
+                .get(idx)
+                .map(|guard| guard.meta().id.clone()),
+            _ => None,
+        }
+    }
+
+    /// Detail view for an artifact ID, if it names a loaded artifact.
+    pub fn detail_view_for(&self, id: &str) -> Option<View> {
+        if let Some((rfc_id, clause_id)) = id.split_once(':') {
+            let rfc_idx = self
+                .index
+                .rfcs
+                .iter()
+                .position(|rfc| rfc.rfc.rfc_id == rfc_id)?;
+            let clause_idx = self.index.rfcs[rfc_idx]
+                .clauses
+                .iter()
+                .position(|clause| clause.spec.clause_id == clause_id)?;
+            return Some(View::ClauseDetail(rfc_idx, clause_idx));
+        }
+        self.index
+            .rfcs
+            .iter()
+            .position(|rfc| rfc.rfc.rfc_id == id)
+            .map(View::RfcDetail)
+            .or_else(|| {
+                self.index
+                    .adrs
+                    .iter()
+                    .position(|adr| adr.meta().id == id)
+                    .map(View::AdrDetail)
+            })
+            .or_else(|| {
+                self.index
+                    .work_items
+                    .iter()
+                    .position(|item| item.meta().id == id)
+                    .map(View::WorkDetail)
+            })
+            .or_else(|| {
+                self.supplement
+                    .guards
+                    .iter()
+                    .position(|guard| guard.meta().id == id)
+                    .map(View::GuardDetail)
+            })
+    }
+
+    /// Refs of the current artifact, then its backlinks.
+    pub fn link_rows(&self) -> Vec<LinkRow> {
+        let Some(id) = self.detail_artifact_id() else {
+            return Vec::new();
+        };
+        let outgoing = self
+            .supplement
+            .links
+            .iter()
+            .filter(|link| link.source == id)
+            .map(|link| (link.target.clone(), link.kind.outgoing_label(), true));
+        let incoming = self
+            .supplement
+            .links
+            .iter()
+            .filter(|link| link.target == id)
+            .map(|link| (link.source.clone(), link.kind.incoming_label(), false));
+        outgoing
+            .chain(incoming)
+            .map(|(other, relation, outgoing)| {
+                let target = self.detail_view_for(&other);
+                LinkRow {
+                    title: target.and_then(|view| self.detail_title(view)),
+                    target,
+                    id: other,
+                    relation,
+                    outgoing,
+                }
+            })
+            .collect()
+    }
+
+    fn detail_title(&self, view: View) -> Option<String> {
+        match view {
+            View::RfcDetail(idx) => self.index.rfcs.get(idx).map(|rfc| rfc.rfc.title.clone()),
+            View::ClauseDetail(rfc_idx, clause_idx) => self
+                .index
+                .rfcs
+                .get(rfc_idx)
+                .and_then(|rfc| rfc.clauses.get(clause_idx))
+                .map(|clause| clause.spec.title.clone()),
+            View::AdrDetail(idx) => self.index.adrs.get(idx).map(|adr| adr.meta().title.clone()),
+            View::WorkDetail(idx) => self
+                .index
+                .work_items
+                .get(idx)
+                .map(|item| item.meta().title.clone()),
+            View::GuardDetail(idx) => self
+                .supplement
+                .guards
+                .get(idx)
+                .map(|guard| guard.meta().title.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn open_links(&mut self) {
+        if self.detail_artifact_id().is_some() {
+            self.links_open = true;
+            self.link_selected = 0;
+        }
+    }
+
+    pub fn close_links(&mut self) {
+        self.links_open = false;
+    }
+
+    pub fn link_next(&mut self) {
+        let len = self.link_rows().len();
+        if self.link_selected + 1 < len {
+            self.link_selected += 1;
+        }
+    }
+
+    pub fn link_prev(&mut self) {
+        self.link_selected = self.link_selected.saturating_sub(1);
+    }
+
+    /// Open the selected link's detail view. Esc from there returns here.
+    pub fn follow_link(&mut self) {
+        let Some(target) = self
+            .link_rows()
+            .get(self.link_selected)
+            .and_then(|row| row.target)
+        else {
+            return;
+        };
+        self.link_history.push(self.view);
+        self.view = target;
+        self.links_open = false;
+        self.scroll = 0;
+        if matches!(target, View::RfcDetail(_)) {
+            self.clause_list_state = ListState::default().with_selected(Some(0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        ProjectIndex, WorkItemContent, WorkItemEntry, WorkItemMeta, WorkItemSpec, WorkItemStatus,
+        WorkItemVerification,
+    };
+    use crate::tui::data::artifact_links;
+    use std::path::PathBuf;
+
+    #[test]
+    fn links_list_refs_then_backlinks_and_follow_them() {
+        let mut first = work_item("WI-2026-01-01-001");
+        first.spec.govctl.depends_on = vec!["WI-2026-01-01-002".to_string()];
+        first.spec.govctl.refs = vec!["RFC-9999".to_string()];
+        let mut app = App::new(ProjectIndex {
+            rfcs: vec![],
+            adrs: vec![],
+            work_items: vec![first, work_item("WI-2026-01-01-002")],
+        });
+        app.supplement.links = artifact_links(&app.index, &[]);
+        app.view = View::WorkDetail(0);
+
+        let rows = app.link_rows();
+        assert_eq!(
+            rows.iter()
+                .map(|row| (row.id.as_str(), row.relation, row.target))
+                .collect::<Vec<_>>(),
+            vec![
+                ("RFC-9999", "refs", None),
+                ("WI-2026-01-01-002", "depends on", Some(View::WorkDetail(1))),
+            ]
+        );
+
+        app.open_links();
+        app.link_next();
+        app.follow_link();
+        assert_eq!(app.view, View::WorkDetail(1));
+        assert_eq!(
+            app.link_rows()
+                .iter()
+                .map(|row| (row.id.as_str(), row.relation, row.outgoing))
+                .collect::<Vec<_>>(),
+            vec![("WI-2026-01-01-001", "needed by", false)]
+        );
+
+        app.go_back();
+        assert_eq!(app.view, View::WorkDetail(0));
+        app.go_back();
+        assert_eq!(app.view, View::WorkList);
+    }
+
+    fn work_item(id: &str) -> WorkItemEntry {
+        WorkItemEntry {
+            spec: WorkItemSpec {
+                govctl: WorkItemMeta::new(id, id, WorkItemStatus::Queue),
+                content: WorkItemContent::default(),
+                verification: WorkItemVerification::default(),
+            },
+            path: PathBuf::from(format!("gov/work/{id}.toml")),
+        }
+    }
+}
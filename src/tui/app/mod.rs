@@ -1,15 +1,30 @@
 //! Application state for TUI.
 
 use super::data::{TuiLoopEntry, TuiSupplement, load_supplement};
+use super::keys::KeyMap;
+use super::theme::TuiTheme;
 use crate::cmd::search::SearchResult;
+use crate::command_router::CommandPlan;
 use crate::config::Config;
-use crate::diagnostic::Diagnostic;
+use crate::diagnostic::{Diagnostic, DiagnosticResult};
+use crate::identity::Actor;
+use crate::load::load_project;
 use crate::model::ProjectIndex;
+use crate::write::WriteOp;
+use crate::{cmd, lock, ui};
 use ratatui::widgets::{ListState, TableState};
 
+mod board;
+mod create;
+mod export;
 mod filter;
+mod links;
 mod navigation;
 
+pub use board::BOARD_COLUMNS;
+pub use create::{CreateForm, FieldValue};
+pub use export::ExportTarget;
+
 /// Current view in the TUI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum View {
@@ -19,6 +34,8 @@ pub enum View {
     ClauseList,
     AdrList,
     WorkList,
+    /// Work items in status columns
+    WorkBoard,
     GuardList,
     ReleaseList,
     TagList,
@@ -73,23 +90,42 @@ pub struct App {
     pub filter_mode: bool,
     /// Show help overlay
     pub show_help: bool,
+    /// Open creation form, if any
+    pub create_form: Option<CreateForm>,
+    /// One-line result of the last action, shown in the footer
+    pub notice: Option<String>,
+    /// Focused column on the work board
+    pub board_column: usize,
+    /// Focused row within the board column
+    pub board_row: usize,
+    /// Destination column while moving a work item on the board
+    pub board_move_target: Option<usize>,
+    /// Whether the open work detail was entered from the board
+    pub board_detail: bool,
+    /// Show the refs and backlinks panel in detail views
+    pub links_open: bool,
+    /// Selected row in the links panel
+    pub link_selected: usize,
+    /// Detail views left by following links, most recent last
+    pub link_history: Vec<View>,
+    /// Key remaps from `[tui.keys]`
+    pub keys: KeyMap,
+    /// Colors and glyphs from `[tui.theme]`
+    pub theme: TuiTheme,
+    /// Actor the commands run from the TUI act as
+    pub actor: Option<Actor>,
+    /// Show the export menu for the current view
+    pub export_menu: bool,
     /// Should quit
     pub should_quit: bool,
 }
 
 impl App {
     /// Create new app with loaded project index
-    pub fn new(mut index: ProjectIndex) -> Self {
-        // Sort all items by ID for consistent display
-        index.rfcs.sort_by(|a, b| a.rfc.rfc_id.cmp(&b.rfc.rfc_id));
-        index.adrs.sort_by(|a, b| a.meta().id.cmp(&b.meta().id));
-        index
-            .work_items
-            .sort_by(|a, b| a.meta().id.cmp(&b.meta().id));
-
+    pub fn new(index: ProjectIndex) -> Self {
         Self {
             config: Config::default(),
-            index,
+            index: sorted(index),
             supplement: TuiSupplement::default(),
             view: View::Dashboard,
             selected: 0,
@@ -107,6 +143,19 @@ impl App {
             search_error: None,
             filter_mode: false,
             show_help: false,
+            create_form: None,
+            notice: None,
+            board_column: 0,
+            board_row: 0,
+            board_move_target: None,
+            board_detail: false,
+            links_open: false,
+            link_selected: 0,
+            link_history: Vec::new(),
+            keys: KeyMap::default(),
+            theme: TuiTheme::default(),
+            actor: None,
+            export_menu: false,
             should_quit: false,
         }
     }
@@ -119,6 +168,17 @@ impl App {
         app
     }
 
+    /// Reload the project from disk, keeping the current view.
+    pub fn reload(&mut self) -> Result<(), Diagnostic> {
+        let index = load_project(&self.config)
+            .map_err(|diags| super::project_load_error(diags, &self.config.gov_root))?;
+        self.index = sorted(index);
+        self.supplement = load_supplement(&self.config, &self.index);
+        self.invalidate_indices();
+        self.ensure_selection_in_bounds();
+        Ok(())
+    }
+
     pub fn loop_entries(&self) -> &[TuiLoopEntry] {
         &self.supplement.loops
     }
@@ -308,6 +368,38 @@ impl App {
     }
 }
 
+/// Run a mutating command the way the CLI does: under the gov-root lock, in a
+/// write transaction, and recorded for `govctl undo` as `args`. Success
+/// messages are suppressed because the TUI owns the terminal.
+fn run_command(
+    config: &Config,
+    actor: Option<&Actor>,
+    plan: &CommandPlan,
+    args: &[String],
+) -> DiagnosticResult<()> {
+    let _guard = lock::acquire_gov_lock(config)?;
+    let op = WriteOp::EXECUTE.with_fsync(config.concurrency.fsync);
+    ui::quietly(|| {
+        let diagnostics =
+            cmd::history::record(config, &cmd::history::command_line(args), actor, op, |op| {
+                plan.execute(config, actor, op)
+            })?;
+        plan.after_command(config, op, diagnostics);
+        Ok::<_, crate::diagnostic::Diagnostic>(())
+    })?;
+    Ok(())
+}
+
+/// Sort all items by ID for consistent display.
+fn sorted(mut index: ProjectIndex) -> ProjectIndex {
+    index.rfcs.sort_by(|a, b| a.rfc.rfc_id.cmp(&b.rfc.rfc_id));
+    index.adrs.sort_by(|a, b| a.meta().id.cmp(&b.meta().id));
+    index
+        .work_items
+        .sort_by(|a, b| a.meta().id.cmp(&b.meta().id));
+    index
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -557,9 +649,14 @@ mod tests {
                     status: RfcStatus::Normative,
                     phase: RfcPhase::Impl,
                     owners: vec![],
+                    approvals: vec![],
+                    frozen: false,
                     created: "2026-06-06".to_string(),
+                    created_by: None,
+                    attachments: vec![],
                     updated: None,
                     supersedes: None,
+                    deprecation: None,
                     refs: vec![],
                     tags: vec![],
                     sections: vec![],
@@ -572,15 +669,17 @@ mod tests {
                         title: "Clause test".to_string(),
                         kind: ClauseKind::Normative,
                         status: ClauseStatus::Active,
-                        text: "Clause body".to_string(),
+                        text: Some("Clause body".to_string()),
                         anchors: vec![],
                         superseded_by: None,
+                        deprecation: None,
                         since: None,
                         tags: vec![],
                     },
                     path: PathBuf::from("gov/rfc/RFC-0001/clauses/C-TEST.toml"),
                 }],
                 path: PathBuf::from("gov/rfc/RFC-0001/rfc.toml"),
+                amended: None,
             }],
             adrs: vec![],
             work_items: vec![WorkItemEntry {
@@ -607,9 +706,14 @@ mod tests {
                 status: RfcStatus::Normative,
                 phase: RfcPhase::Impl,
                 owners: vec![],
+                approvals: vec![],
+                frozen: false,
                 created: "2026-06-06".to_string(),
+                created_by: None,
+                attachments: vec![],
                 updated: None,
                 supersedes: None,
+                deprecation: None,
                 refs: vec![],
                 tags: vec![],
                 sections: vec![],
@@ -622,15 +726,17 @@ mod tests {
                     title: clause_id.to_string(),
                     kind: ClauseKind::Normative,
                     status: ClauseStatus::Active,
-                    text: "Clause body".to_string(),
+                    text: Some("Clause body".to_string()),
                     anchors: vec![],
                     superseded_by: None,
+                    deprecation: None,
                     since: None,
                     tags: vec![],
                 },
                 path: PathBuf::from(format!("gov/rfc/{rfc_id}/clauses/{clause_id}.toml")),
             }],
             path: PathBuf::from(format!("gov/rfc/{rfc_id}/rfc.toml")),
+            amended: None,
         }
     }
 
@@ -638,11 +744,7 @@ mod tests {
         GuardEntry {
             spec: GuardSpec {
                 govctl: GuardMeta::new("GUARD-TEST", "Guard test"),
-                check: GuardCheck {
-                    command: "true".to_string(),
-                    timeout_secs: 1,
-                    pattern: None,
-                },
+                check: GuardCheck::new("true", 1),
             },
             path: PathBuf::from("gov/guard/GUARD-TEST.toml"),
         }
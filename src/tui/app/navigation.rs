@@ -98,10 +98,17 @@ impl App {
 
     /// Go back to previous view
     pub fn go_back(&mut self) {
+        self.links_open = false;
+        if let Some(previous) = self.link_history.pop() {
+            self.view = previous;
+            self.scroll = 0;
+            return;
+        }
         self.view = match self.view {
             View::ClauseDetail(rfc_idx, _) => View::RfcDetail(rfc_idx),
             View::RfcDetail(_) => View::RfcList,
             View::AdrDetail(_) => View::AdrList,
+            View::WorkDetail(_) if self.board_detail => View::WorkBoard,
             View::WorkDetail(_) => View::WorkList,
             View::GuardDetail(_) => View::GuardList,
             View::LoopDetail(_) => View::LoopList,
@@ -114,7 +121,8 @@ impl App {
             | View::TagList
             | View::Search
             | View::LoopList
-            | View::DiagnosticList => View::Dashboard,
+            | View::DiagnosticList
+            | View::WorkBoard => View::Dashboard,
             View::Dashboard => {
                 self.should_quit = true;
                 View::Dashboard
@@ -133,6 +141,12 @@ impl App {
         self.selected = 0;
         self.table_state = TableState::default().with_selected(Some(0));
         self.scroll = 0;
+        self.board_column = 0;
+        self.board_row = 0;
+        self.board_move_target = None;
+        self.board_detail = false;
+        self.links_open = false;
+        self.link_history.clear();
         self.invalidate_indices();
         if matches!(
             self.view,
@@ -1,3 +1,4 @@
+use super::theme::TuiTheme;
 use crate::diagnostic::DiagnosticResult;
 use crate::loop_planner::topological_order_for_state;
 use crate::loop_state::LoopState;
@@ -13,6 +14,7 @@ pub struct DagLine {
 }
 
 pub fn dag_lines(
+    theme: TuiTheme,
     state: &LoopState,
     selected_work_id: Option<&str>,
     max_lines: usize,
@@ -49,7 +51,7 @@ pub fn dag_lines(
             .filter(|deps| !deps.is_empty())
             .map(|deps| format!(" <- {}", deps.join(", ")))
             .unwrap_or_default();
-        let branch = if depth == 0 { "●" } else { "└─" };
+        let branch = theme.glyph(if depth == 0 { "●" } else { "└─" });
         let marker = if selected { ">" } else { " " };
         let indent = "  ".repeat(depth);
         lines.push(DagLine {
@@ -164,7 +166,7 @@ mod tests {
     #[test]
     fn dag_lines_are_deterministic_and_status_aware() -> Result<(), Box<dyn std::error::Error>> {
         let state = sample_loop_state()?;
-        let lines = dag_lines(&state, Some("WI-2026-01-01-002"), 10)?;
+        let lines = dag_lines(TuiTheme::default(), &state, Some("WI-2026-01-01-002"), 10)?;
 
         assert_eq!(lines[0].work_id, "WI-2026-01-01-001");
         assert!(lines.iter().any(|line| line.selected));
@@ -180,7 +182,7 @@ mod tests {
     #[test]
     fn dag_lines_show_neighborhood_fallback() -> Result<(), Box<dyn std::error::Error>> {
         let state = sample_loop_state()?;
-        let lines = dag_lines(&state, Some("WI-2026-01-01-002"), 2)?;
+        let lines = dag_lines(TuiTheme::default(), &state, Some("WI-2026-01-01-002"), 2)?;
 
         assert!(lines.iter().any(|line| line.hidden));
         assert!(lines.len() <= 2);
@@ -191,7 +193,7 @@ mod tests {
     #[test]
     fn dag_lines_fallback_keeps_late_selected_item() -> Result<(), Box<dyn std::error::Error>> {
         let state = sample_loop_state()?;
-        let lines = dag_lines(&state, Some("WI-2026-01-01-003"), 2)?;
+        let lines = dag_lines(TuiTheme::default(), &state, Some("WI-2026-01-01-003"), 2)?;
 
         assert!(
             lines
@@ -206,7 +208,7 @@ mod tests {
     #[test]
     fn dag_lines_one_line_fallback_shows_hidden_count() -> Result<(), Box<dyn std::error::Error>> {
         let state = sample_loop_state()?;
-        let lines = dag_lines(&state, Some("WI-2026-01-01-003"), 1)?;
+        let lines = dag_lines(TuiTheme::default(), &state, Some("WI-2026-01-01-003"), 1)?;
 
         assert_eq!(lines.len(), 1);
         assert!(lines[0].hidden);
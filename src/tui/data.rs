@@ -25,6 +25,47 @@ pub struct TuiTagSummary {
     pub count: usize,
 }
 
+/// How one artifact points at another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuiLinkKind {
+    Refs,
+    Supersedes,
+    SupersededBy,
+    DependsOn,
+    RequiresGuard,
+}
+
+impl TuiLinkKind {
+    /// Label read from the source artifact's side.
+    pub fn outgoing_label(self) -> &'static str {
+        match self {
+            Self::Refs => "refs",
+            Self::Supersedes => "supersedes",
+            Self::SupersededBy => "superseded by",
+            Self::DependsOn => "depends on",
+            Self::RequiresGuard => "requires guard",
+        }
+    }
+
+    /// Label read from the target artifact's side.
+    pub fn incoming_label(self) -> &'static str {
+        match self {
+            Self::Refs => "referenced by",
+            Self::Supersedes => "superseded by",
+            Self::SupersededBy => "supersedes",
+            Self::DependsOn => "needed by",
+            Self::RequiresGuard => "required by",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TuiLink {
+    pub source: String,
+    pub target: String,
+    pub kind: TuiLinkKind,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TuiSupplement {
     pub guards: Vec<GuardEntry>,
@@ -34,6 +75,8 @@ pub struct TuiSupplement {
     pub tags: Vec<TuiTagSummary>,
     pub diagnostics: Diagnostics,
     pub check_summary: CheckSummary,
+    /// Every cross-reference between artifacts, for refs and backlinks.
+    pub links: Vec<TuiLink>,
 }
 
 pub fn load_supplement(config: &Config, index: &ProjectIndex) -> TuiSupplement {
@@ -66,9 +109,90 @@ pub fn load_supplement(config: &Config, index: &ProjectIndex) -> TuiSupplement {
     }
 
     supplement.loops = load_loop_entries(config);
+    supplement.links = artifact_links(index, &supplement.guards);
     supplement
 }
 
+/// Markdown shown for a guard, which has no rendered document of its own.
+pub fn guard_markdown(guard: &GuardEntry) -> String {
+    let meta = guard.meta();
+    let mut markdown = format!(
+        "# {}\n\n**ID:** {}\n\n**Command:** `{}`\n\n**Timeout:** {} seconds\n",
+        meta.title, meta.id, guard.spec.check.command, guard.spec.check.timeout_secs
+    );
+    if let Some(pattern) = &guard.spec.check.pattern {
+        markdown.push_str(&format!("\n**Pattern:** `{pattern}`\n"));
+    }
+    if !meta.refs.is_empty() {
+        markdown.push_str(&format!("\n**References:** {}\n", meta.refs.join(", ")));
+    }
+    if !meta.tags.is_empty() {
+        markdown.push_str(&format!("\n**Tags:** `{}`\n", meta.tags.join("`, `")));
+    }
+    markdown
+}
+
+/// Cross-references declared in artifact fields: `refs`, supersession,
+/// work item dependencies, and required guards.
+pub fn artifact_links(index: &ProjectIndex, guards: &[GuardEntry]) -> Vec<TuiLink> {
+    let mut links = Vec::new();
+    let mut push = |source: &str, targets: &[String], kind: TuiLinkKind| {
+        for target in targets {
+            links.push(TuiLink {
+                source: source.to_string(),
+                target: target.clone(),
+                kind,
+            });
+        }
+    };
+    for rfc in &index.rfcs {
+        let rfc_id = &rfc.rfc.rfc_id;
+        push(rfc_id, &rfc.rfc.refs, TuiLinkKind::Refs);
+        if let Some(old) = &rfc.rfc.supersedes {
+            push(rfc_id, std::slice::from_ref(old), TuiLinkKind::Supersedes);
+        }
+        for clause in &rfc.clauses {
+            if let Some(new) = &clause.spec.superseded_by {
+                let target = if new.contains(':') {
+                    new.clone()
+                } else {
+                    format!("{rfc_id}:{new}")
+                };
+                push(
+                    &format!("{rfc_id}:{}", clause.spec.clause_id),
+                    &[target],
+                    TuiLinkKind::SupersededBy,
+                );
+            }
+        }
+    }
+    for adr in &index.adrs {
+        let meta = adr.meta();
+        push(&meta.id, &meta.refs, TuiLinkKind::Refs);
+        if let Some(new) = &meta.superseded_by {
+            push(
+                &meta.id,
+                std::slice::from_ref(new),
+                TuiLinkKind::SupersededBy,
+            );
+        }
+    }
+    for item in &index.work_items {
+        let meta = item.meta();
+        push(&meta.id, &meta.refs, TuiLinkKind::Refs);
+        push(&meta.id, &meta.depends_on, TuiLinkKind::DependsOn);
+        push(
+            &meta.id,
+            &item.spec.verification.required_guards,
+            TuiLinkKind::RequiresGuard,
+        );
+    }
+    for guard in guards {
+        push(&guard.meta().id, &guard.meta().refs, TuiLinkKind::Refs);
+    }
+    links
+}
+
 fn tag_summaries(config: &Config, index: &ProjectIndex) -> Vec<TuiTagSummary> {
     let mut counts = BTreeMap::<String, usize>::new();
     for tag in &config.tags.allowed {
@@ -190,12 +314,12 @@ mod tests {
         write_loop_state_with_op(
             &config,
             &loop_state("LOOP-2026-06-06-002", "WI-2026-06-06-002")?,
-            WriteOp::Execute,
+            WriteOp::EXECUTE,
         )?;
         write_loop_state_with_op(
             &config,
             &loop_state("LOOP-2026-06-06-001", "WI-2026-06-06-001")?,
-            WriteOp::Execute,
+            WriteOp::EXECUTE,
         )?;
 
         let loops = load_loop_entries(&config);
@@ -38,6 +38,22 @@ pub(super) fn handle_key(app: &mut App, key: KeyEvent) {
     if key.kind != KeyEventKind::Press {
         return;
     }
+    app.notice = None;
+
+    if app.create_form.is_some() {
+        handle_create_form_input(app, key);
+        return;
+    }
+
+    // Text input takes keys literally; everywhere else apply `[tui.keys]`.
+    let key = if app.filter_mode || app.search_mode {
+        key
+    } else {
+        match app.keys.translate(key, app.view == View::Dashboard) {
+            Some(key) => key,
+            None => return,
+        }
+    };
 
     if matches!(key.code, KeyCode::Char('?')) {
         app.show_help = !app.show_help;
@@ -51,6 +67,16 @@ pub(super) fn handle_key(app: &mut App, key: KeyEvent) {
         return;
     }
 
+    if app.links_open {
+        handle_links_keys(app, key);
+        return;
+    }
+
+    if app.export_menu {
+        handle_export_keys(app, key);
+        return;
+    }
+
     match app.view {
         View::Dashboard => handle_dashboard_keys(app, key),
         View::RfcList
@@ -77,6 +103,7 @@ pub(super) fn handle_key(app: &mut App, key: KeyEvent) {
                 handle_search_keys(app, key);
             }
         }
+        View::WorkBoard => handle_board_keys(app, key),
         View::LoopDetail(_) => handle_loop_detail_keys(app, key),
         View::RfcDetail(_) => handle_rfc_detail_keys(app, key),
         View::AdrDetail(_)
@@ -104,6 +131,8 @@ fn handle_dashboard_keys(app: &mut App, key: KeyEvent) {
         // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: dashboard entry point.
         KeyCode::Char('4') | KeyCode::Char('w') => app.go_to(View::WorkList),
         // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: dashboard entry point.
+        KeyCode::Char('b') => app.go_to(View::WorkBoard),
+        // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: dashboard entry point.
         KeyCode::Char('5') | KeyCode::Char('g') => app.go_to(View::GuardList),
         // Implements [[RFC-0007:C-SEARCH]]: enter search with single-focus input.
         KeyCode::Char('6') | KeyCode::Char('s') => {
@@ -136,6 +165,9 @@ fn handle_list_keys(app: &mut App, key: KeyEvent) {
         KeyCode::PageUp => app.select_half_page_up(),
         KeyCode::Char('n') if app.filter_active() => app.select_next(),
         KeyCode::Char('p') if app.filter_active() => app.select_prev(),
+        // Implements [[RFC-0007:C-READ-ONLY]]: creation goes through the CLI create path.
+        KeyCode::Char('n') => app.open_create_form(),
+        KeyCode::Char('e') => app.open_export_menu(),
         KeyCode::Enter => app.enter_detail(),
         KeyCode::Esc => app.go_back(),
         // Implements [[RFC-0003:C-FILTER]]
@@ -147,6 +179,32 @@ fn handle_list_keys(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_board_keys(app: &mut App, key: KeyEvent) {
+    if app.board_move_target.is_some() {
+        match key.code {
+            KeyCode::Char('h') | KeyCode::Left => app.board_left(),
+            KeyCode::Char('l') | KeyCode::Right => app.board_right(),
+            // Implements [[RFC-0007:C-READ-ONLY]]: moves go through the `work move` path.
+            KeyCode::Char('m') | KeyCode::Enter => app.confirm_board_move(),
+            KeyCode::Esc => app.cancel_board_move(),
+            _ => {}
+        }
+        return;
+    }
+    match key.code {
+        KeyCode::Char('q') => app.should_quit = true,
+        KeyCode::Char('h') | KeyCode::Left => app.board_left(),
+        KeyCode::Char('l') | KeyCode::Right => app.board_right(),
+        KeyCode::Char('j') | KeyCode::Down => app.board_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.board_up(),
+        KeyCode::Char('m') => app.start_board_move(),
+        KeyCode::Enter => app.enter_board_detail(),
+        // Implements [[RFC-0007:C-HUMAN-UX]]: keyboard-only return navigation.
+        KeyCode::Esc => app.go_back(),
+        _ => {}
+    }
+}
+
 fn handle_search_keys(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Char('q') => app.should_quit = true,
@@ -186,6 +244,8 @@ fn handle_rfc_detail_keys(app: &mut App, key: KeyEvent) {
         KeyCode::Char('k') | KeyCode::Up => app.clause_prev(),
         // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: browse from RFC to clause detail.
         KeyCode::Enter => app.enter_clause_detail(),
+        KeyCode::Char('r') => app.open_links(),
+        KeyCode::Char('e') => app.open_export_menu(),
         // Implements [[RFC-0007:C-HUMAN-UX]]: keyboard-only return navigation.
         KeyCode::Esc => app.go_back(),
         _ => {}
@@ -201,12 +261,33 @@ fn handle_detail_keys(app: &mut App, key: KeyEvent) {
         KeyCode::Char('u') if is_ctrl(&key) => app.scroll_half_page_up(),
         KeyCode::PageDown => app.scroll_page_down(),
         KeyCode::PageUp => app.scroll_page_up(),
+        KeyCode::Char('r') => app.open_links(),
+        KeyCode::Char('e') => app.open_export_menu(),
         // Implements [[RFC-0007:C-HUMAN-UX]]: keyboard-only return navigation.
         KeyCode::Esc => app.go_back(),
         _ => {}
     }
 }
 
+fn handle_export_keys(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char(ch @ '1'..='9') => app.export(ch as usize - '1' as usize),
+        KeyCode::Esc | KeyCode::Char('e') => app.close_export_menu(),
+        _ => {}
+    }
+}
+
+fn handle_links_keys(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('q') => app.should_quit = true,
+        KeyCode::Char('j') | KeyCode::Down => app.link_next(),
+        KeyCode::Char('k') | KeyCode::Up => app.link_prev(),
+        KeyCode::Enter => app.follow_link(),
+        KeyCode::Esc | KeyCode::Char('r') => app.close_links(),
+        _ => {}
+    }
+}
+
 fn handle_loop_detail_keys(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Char('q') => app.should_quit = true,
@@ -220,6 +301,25 @@ fn handle_loop_detail_keys(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_create_form_input(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.close_create_form(),
+        KeyCode::Enter => app.submit_create_form(),
+        _ => {
+            let Some(form) = app.create_form.as_mut() else {
+                return;
+            };
+            match key.code {
+                KeyCode::Tab | KeyCode::Down => form.focus_next(),
+                KeyCode::BackTab | KeyCode::Up => form.focus_prev(),
+                KeyCode::Backspace => form.pop_char(),
+                KeyCode::Char(ch) if !is_ctrl(&key) => form.push_char(ch),
+                _ => {}
+            }
+        }
+    }
+}
+
 fn handle_filter_input(app: &mut App, key: KeyEvent) {
     match key.code {
         // Implements [[RFC-0003:C-FILTER]]
@@ -242,6 +342,7 @@ mod tests {
         RfcSpec, RfcStatus, WorkItemContent, WorkItemEntry, WorkItemMeta, WorkItemSpec,
         WorkItemStatus, WorkItemVerification,
     };
+    use crate::tui::keys::KeyMap;
     use std::path::PathBuf;
 
     #[test]
@@ -260,6 +361,26 @@ mod tests {
         assert!(!app.show_help);
     }
 
+    #[test]
+    fn handle_key_applies_key_remaps_outside_text_input() -> DiagnosticResult<()> {
+        let mut app = App::new(project_index());
+        app.keys = KeyMap::from_config(&crate::config::TuiKeysConfig {
+            down: Some('n'),
+            ..Default::default()
+        })?;
+        app.go_to(View::WorkList);
+
+        handle_key(&mut app, key(KeyCode::Char('n')));
+        assert_eq!(app.selected, 1);
+        handle_key(&mut app, key(KeyCode::Char('j')));
+        assert_eq!(app.selected, 1);
+
+        handle_key(&mut app, key(KeyCode::Char('/')));
+        handle_key(&mut app, key(KeyCode::Char('n')));
+        assert_eq!(app.filter_query, "n");
+        Ok(())
+    }
+
     #[test]
     fn handle_key_routes_list_filter_and_selection() {
         let mut app = App::new(project_index());
@@ -283,6 +404,35 @@ mod tests {
         assert_eq!(app.view, View::WorkDetail(1));
     }
 
+    #[test]
+    fn handle_key_routes_create_form_input() {
+        let mut app = App::new(project_index());
+        app.go_to(View::WorkList);
+
+        handle_key(&mut app, key(KeyCode::Char('n')));
+        assert!(app.create_form.is_some());
+        handle_key(&mut app, key(KeyCode::Char('q')));
+        handle_key(&mut app, key(KeyCode::Char('?')));
+        assert!(!app.should_quit);
+        assert!(!app.show_help);
+        handle_key(&mut app, key(KeyCode::Tab));
+        handle_key(&mut app, key(KeyCode::Char(' ')));
+        let form = app.create_form.as_ref().map(|form| {
+            (
+                form.fields[0].display_value().to_string(),
+                form.fields[1].display_value().to_string(),
+            )
+        });
+        assert_eq!(form, Some(("q?".to_string(), "active".to_string())));
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(app.create_form.is_none());
+        assert_eq!(app.view, View::WorkList);
+
+        app.go_to(View::TagList);
+        handle_key(&mut app, key(KeyCode::Char('n')));
+        assert!(app.create_form.is_none());
+    }
+
     #[test]
     fn handle_key_routes_search_modes() {
         let mut app = App::new(ProjectIndex::default());
@@ -317,6 +467,14 @@ mod tests {
         app.view = View::RfcDetail(0);
         handle_key(&mut app, key(KeyCode::Enter));
         assert_eq!(app.view, View::ClauseDetail(0, 0));
+
+        handle_key(&mut app, key(KeyCode::Char('r')));
+        assert!(app.links_open);
+        handle_key(&mut app, key(KeyCode::Char('j')));
+        assert_eq!(app.view, View::ClauseDetail(0, 0));
+        handle_key(&mut app, key(KeyCode::Esc));
+        assert!(!app.links_open);
+        assert_eq!(app.view, View::ClauseDetail(0, 0));
     }
 
     fn key(code: KeyCode) -> KeyEvent {
@@ -337,9 +495,14 @@ mod tests {
                     status: RfcStatus::Normative,
                     phase: RfcPhase::Impl,
                     owners: vec![],
+                    approvals: vec![],
+                    frozen: false,
                     created: "2026-06-07".to_string(),
+                    created_by: None,
+                    attachments: vec![],
                     updated: None,
                     supersedes: None,
+                    deprecation: None,
                     refs: vec![],
                     tags: vec![],
                     sections: vec![],
@@ -352,15 +515,17 @@ mod tests {
                         title: "Clause".to_string(),
                         kind: ClauseKind::Normative,
                         status: ClauseStatus::Active,
-                        text: "Clause text".to_string(),
+                        text: Some("Clause text".to_string()),
                         anchors: vec![],
                         superseded_by: None,
+                        deprecation: None,
                         since: None,
                         tags: vec![],
                     },
                     path: PathBuf::from("gov/rfc/RFC-0001/clauses/C-TEST.toml"),
                 }],
                 path: PathBuf::from("gov/rfc/RFC-0001/rfc.toml"),
+                amended: None,
             }],
             adrs: vec![],
             work_items: vec![
@@ -0,0 +1,160 @@
+//! Remappable TUI keys from `[tui.keys]`.
+//!
+//! Handlers match the default keys; a configured key is translated to the
+//! default of its action before dispatch, and the default of a remapped
+//! action is swallowed.
+
+use crate::config::TuiKeysConfig;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// Remappable actions: config name, default key, and whether the action
+/// also applies on the dashboard, whose letter keys open views.
+const ACTIONS: [(&str, char, bool); 11] = [
+    ("quit", 'q', true),
+    ("help", '?', true),
+    ("down", 'j', false),
+    ("up", 'k', false),
+    ("left", 'h', false),
+    ("right", 'l', false),
+    ("top", 'g', false),
+    ("bottom", 'G', false),
+    ("filter", '/', false),
+    ("links", 'r', false),
+    ("move", 'm', false),
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyMap {
+    /// (configured key, default key, applies on dashboard), remapped only.
+    remaps: Vec<(char, char, bool)>,
+}
+
+impl KeyMap {
+    /// Build the keymap, rejecting two actions bound to the same key.
+    pub fn from_config(keys: &TuiKeysConfig) -> DiagnosticResult<Self> {
+        let configured = [
+            keys.quit,
+            keys.help,
+            keys.down,
+            keys.up,
+            keys.left,
+            keys.right,
+            keys.top,
+            keys.bottom,
+            keys.filter,
+            keys.links,
+            keys.move_item,
+        ];
+        let bound: Vec<char> = ACTIONS
+            .iter()
+            .zip(configured)
+            .map(|((_, default, _), key)| key.unwrap_or(*default))
+            .collect();
+        let mut remaps = Vec::new();
+        for (idx, (name, default, dashboard)) in ACTIONS.iter().enumerate() {
+            let key = bound[idx];
+            if let Some(other) = bound[..idx].iter().position(|other| *other == key) {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E0501ConfigInvalid,
+                    format!(
+                        "tui.keys: '{key}' is bound to both {} and {name}",
+                        ACTIONS[other].0
+                    ),
+                    "gov/config.toml",
+                ));
+            }
+            if key != *default {
+                remaps.push((key, *default, *dashboard));
+            }
+        }
+        Ok(Self { remaps })
+    }
+
+    /// The key the handlers expect for `key`, or `None` when `key` is the
+    /// default of a remapped action.
+    pub fn translate(&self, key: KeyEvent, on_dashboard: bool) -> Option<KeyEvent> {
+        let KeyCode::Char(ch) = key.code else {
+            return Some(key);
+        };
+        let mut active = self.active(on_dashboard);
+        if let Some((_, default, _)) = active.clone().find(|(configured, ..)| *configured == ch) {
+            return Some(KeyEvent {
+                code: KeyCode::Char(*default),
+                ..key
+            });
+        }
+        if active.any(|(_, default, _)| *default == ch) {
+            return None;
+        }
+        Some(key)
+    }
+
+    /// Key hint with default keys replaced by their configured ones, for
+    /// hints like `j/k` or `q`.
+    pub fn label(&self, hint: &str, on_dashboard: bool) -> String {
+        hint.split('/')
+            .map(|part| {
+                let mut chars = part.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => self
+                        .active(on_dashboard)
+                        .find(|(_, default, _)| *default == ch)
+                        .map(|(configured, ..)| configured.to_string())
+                        .unwrap_or_else(|| part.to_string()),
+                    _ => part.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn active(&self, on_dashboard: bool) -> impl Iterator<Item = &(char, char, bool)> + Clone {
+        self.remaps
+            .iter()
+            .filter(move |(_, _, dashboard)| *dashboard || !on_dashboard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn remapped_keys_translate_and_free_their_defaults() -> DiagnosticResult<()> {
+        let keys = KeyMap::from_config(&TuiKeysConfig {
+            down: Some('n'),
+            quit: Some('x'),
+            ..Default::default()
+        })?;
+
+        assert_eq!(keys.translate(key('n'), false), Some(key('j')));
+        assert_eq!(keys.translate(key('j'), false), None);
+        assert_eq!(keys.translate(key('k'), false), Some(key('k')));
+        assert_eq!(keys.translate(key('x'), true), Some(key('q')));
+        // Dashboard letters keep their meaning unless quit/help move there.
+        assert_eq!(keys.translate(key('n'), true), Some(key('n')));
+        assert_eq!(keys.label("j/k", false), "n/k");
+        assert_eq!(keys.label("^d/^u", false), "^d/^u");
+        assert_eq!(keys.label("j", true), "j");
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_bindings_are_rejected() {
+        let result = KeyMap::from_config(&TuiKeysConfig {
+            down: Some('k'),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            result,
+            Err(ref diag) if diag.message == "tui.keys: 'k' is bound to both down and up"
+        ));
+    }
+
+    fn key(ch: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE)
+    }
+}
@@ -7,10 +7,13 @@ mod app;
 mod dag;
 mod data;
 mod event;
+mod keys;
+mod theme;
 mod ui;
 
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticLevel, DiagnosticResult};
+use crate::identity::Actor;
 use crate::load::load_project;
 use crossterm::{
     execute,
@@ -39,11 +42,13 @@ fn project_load_error(diags: Vec<Diagnostic>, gov_root: &std::path::Path) -> Dia
         })
 }
 
-/// Run the TUI application
-pub fn run(config: &Config) -> DiagnosticResult<()> {
+/// Run the TUI application; commands it runs act as `actor`.
+pub fn run(config: &Config, actor: Option<&Actor>) -> DiagnosticResult<()> {
     // Load project data
     let index =
         load_project(config).map_err(|diags| project_load_error(diags, &config.gov_root))?;
+    let keys = keys::KeyMap::from_config(&config.tui.keys)?;
+    let theme = theme::TuiTheme::from_config(&config.tui.theme)?;
 
     // Setup terminal
     enable_raw_mode().map_err(|err| terminal_error("enable raw mode", err))?;
@@ -56,6 +61,9 @@ pub fn run(config: &Config) -> DiagnosticResult<()> {
 
     // Create app state
     let mut app = App::with_project(config.clone(), index);
+    app.keys = keys;
+    app.theme = theme;
+    app.actor = actor.cloned();
 
     // Run event loop
     let result = event::run_event_loop(&mut terminal, &mut app);
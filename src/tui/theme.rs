@@ -0,0 +1,142 @@
+//! Colors and glyphs from `[tui.theme]`.
+//!
+//! The theme lives on [`App`](super::app::App) and is passed down to the
+//! rendering code, so tests that never configure one see the defaults.
+
+use crate::config::TuiThemeConfig;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::theme::SemanticColor;
+use ratatui::style::Color;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TuiTheme {
+    success: Color,
+    warning: Color,
+    muted: Color,
+    info: Color,
+    accent: Color,
+    /// Plain ASCII in place of emoji and Unicode status icons.
+    ascii: bool,
+}
+
+impl Default for TuiTheme {
+    fn default() -> Self {
+        Self {
+            success: SemanticColor::Success.to_ratatui(),
+            warning: SemanticColor::Warning.to_ratatui(),
+            muted: SemanticColor::Muted.to_ratatui(),
+            info: SemanticColor::Info.to_ratatui(),
+            accent: SemanticColor::Accent.to_ratatui(),
+            ascii: false,
+        }
+    }
+}
+
+impl TuiTheme {
+    pub fn from_config(config: &TuiThemeConfig) -> DiagnosticResult<Self> {
+        let defaults = Self::default();
+        Ok(Self {
+            success: parse_color("success", &config.success, defaults.success)?,
+            warning: parse_color("warning", &config.warning, defaults.warning)?,
+            muted: parse_color("muted", &config.muted, defaults.muted)?,
+            info: parse_color("info", &config.info, defaults.info)?,
+            accent: parse_color("accent", &config.accent, defaults.accent)?,
+            ascii: config.ascii,
+        })
+    }
+
+    /// Themed color for a semantic intent.
+    pub fn color(self, semantic: SemanticColor) -> Color {
+        match semantic {
+            SemanticColor::Success => self.success,
+            SemanticColor::Warning => self.warning,
+            SemanticColor::Muted => self.muted,
+            SemanticColor::Info => self.info,
+            SemanticColor::Accent => self.accent,
+            SemanticColor::Neutral => Color::Reset,
+        }
+    }
+
+    /// `glyph` as drawn: its ASCII stand-in in ASCII mode, empty for emoji.
+    pub fn glyph(self, glyph: &'static str) -> &'static str {
+        if !self.ascii {
+            return glyph;
+        }
+        match glyph {
+            "●" => "*",
+            "◉" => "@",
+            "○" => "o",
+            "✗" => "x",
+            "•" => "-",
+            "→" => "->",
+            "←" => "<-",
+            "▶" => ">",
+            "└─" => "`-",
+            "—" => "-",
+            emoji if is_emoji(emoji) => "",
+            other => other,
+        }
+    }
+
+    /// Block title with a leading emoji and space dropped in ASCII mode.
+    pub fn title(self, title: &str) -> String {
+        match title.split_once(' ') {
+            Some((first, rest)) if self.ascii && is_emoji(first) => rest.to_string(),
+            _ => title.to_string(),
+        }
+    }
+}
+
+fn parse_color(name: &str, value: &Option<String>, default: Color) -> DiagnosticResult<Color> {
+    let Some(value) = value else {
+        return Ok(default);
+    };
+    Color::from_str(value).map_err(|_| {
+        Diagnostic::new(
+            DiagnosticCode::E0501ConfigInvalid,
+            format!(
+                "tui.theme.{name}: unknown color '{value}' (use a name like \"light-blue\", \"#rrggbb\", or a 256-color index)"
+            ),
+            "gov/config.toml",
+        )
+    })
+}
+
+fn is_emoji(text: &str) -> bool {
+    matches!(text, "📋" | "📝" | "📌" | "📜")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_colors_and_ascii_mode_apply() -> DiagnosticResult<()> {
+        let theme = TuiTheme::from_config(&TuiThemeConfig {
+            success: Some("light-blue".to_string()),
+            ascii: true,
+            ..Default::default()
+        })?;
+
+        assert_eq!(theme.color(SemanticColor::Success), Color::LightBlue);
+        assert_eq!(theme.color(SemanticColor::Warning), Color::Yellow);
+        assert_eq!(theme.glyph("●"), "*");
+        assert_eq!(theme.title("📋 RFCs"), "RFCs");
+        assert_eq!(TuiTheme::default().title("📋 RFCs"), "📋 RFCs");
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_color_is_rejected() {
+        let result = TuiTheme::from_config(&TuiThemeConfig {
+            muted: Some("greenish".to_string()),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            result,
+            Err(ref diag) if diag.message.starts_with("tui.theme.muted: unknown color 'greenish'")
+        ));
+    }
+}
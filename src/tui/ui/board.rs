@@ -0,0 +1,113 @@
+use super::super::app::{App, BOARD_COLUMNS};
+use super::{rounded_block, status_style};
+use ratatui::{
+    prelude::*,
+    widgets::{List, ListItem, ListState},
+};
+
+/// Draw the work board: one column per work item status.
+pub(super) fn draw(frame: &mut Frame, app: &App, area: Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, BOARD_COLUMNS.len() as u32); BOARD_COLUMNS.len()])
+        .split(area);
+
+    for (column, status) in BOARD_COLUMNS.iter().enumerate() {
+        let items = app.board_column_items(column);
+        let focused = column == app.board_column;
+        let target = app.board_move_target == Some(column);
+
+        let mut title = format!("{} ({})", column_label(status.as_ref()), items.len());
+        if target {
+            title = format!("{} {title}", app.theme.glyph("→"));
+        }
+        let border = if target {
+            Style::default().fg(Color::Yellow)
+        } else if focused && app.board_move_target.is_none() {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let block = rounded_block(app.theme, &title)
+            .border_style(border)
+            .title_style(status_style(app.theme, status.as_ref()));
+
+        let rows: Vec<ListItem> = items
+            .iter()
+            .filter_map(|idx| app.index.work_items.get(*idx))
+            .map(|item| {
+                let meta = item.meta();
+                ListItem::new(vec![
+                    Line::from(Span::styled(
+                        meta.id.clone(),
+                        Style::default().fg(Color::DarkGray),
+                    )),
+                    Line::from(meta.title.clone()),
+                ])
+            })
+            .collect();
+
+        let highlight = if app.board_move_target.is_some() {
+            Style::default().fg(Color::Yellow).bold()
+        } else {
+            Style::default().add_modifier(Modifier::REVERSED)
+        };
+        let list = List::new(rows).block(block).highlight_style(highlight);
+        let mut state = ListState::default()
+            .with_selected((focused && !items.is_empty()).then_some(app.board_row));
+        frame.render_stateful_widget(list, columns[column], &mut state);
+    }
+}
+
+fn column_label(status: &str) -> String {
+    let mut chars = status.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::app::View;
+    use super::super::test_support::{project_index, render_app, work_item};
+    use super::*;
+    use crate::model::WorkItemStatus;
+
+    #[test]
+    fn board_renders_status_columns_and_move_target() -> Result<(), Box<dyn std::error::Error>> {
+        let mut app = App::new(project_index(
+            vec![],
+            vec![],
+            vec![
+                work_item(
+                    "WI-2026-01-01-001",
+                    "Queued item",
+                    WorkItemStatus::Queue,
+                    &[],
+                ),
+                work_item(
+                    "WI-2026-01-01-002",
+                    "Active item",
+                    WorkItemStatus::Active,
+                    &[],
+                ),
+            ],
+        ));
+        app.view = View::WorkBoard;
+        app.start_board_move();
+        app.board_right();
+
+        let (_, rendered) = render_app(120, 20, app, |frame, app| {
+            draw(frame, app, frame.area());
+        })?;
+
+        let header = &rendered[0];
+        assert!(header.contains("Queue (1)"));
+        assert!(header.contains("→ Active (1)"));
+        assert!(header.contains("Done (0)"));
+        assert!(header.contains("Cancelled (0)"));
+        assert!(rendered.iter().any(|line| line.contains("Queued item")));
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use super::super::app::{App, View};
+use super::super::keys::KeyMap;
 use super::components::ChromeBar;
 use ratatui::prelude::*;
 
@@ -9,6 +10,7 @@ fn breadcrumb(app: &App) -> String {
         View::ClauseList => "Dashboard > Clauses".to_string(),
         View::AdrList => "Dashboard > ADRs".to_string(),
         View::WorkList => "Dashboard > Work".to_string(),
+        View::WorkBoard => "Dashboard > Board".to_string(),
         View::GuardList => "Dashboard > Guards".to_string(),
         View::ReleaseList => "Dashboard > Releases".to_string(),
         View::TagList => "Dashboard > Tags".to_string(),
@@ -149,6 +151,8 @@ fn bindings_for_view(view: View) -> &'static [&'static str] {
             "ADRs",
             "w",
             "Work",
+            "b",
+            "Board",
             "s",
             "Search",
             "l",
@@ -186,12 +190,18 @@ fn bindings_for_view(view: View) -> &'static [&'static str] {
             "q",
             "Quit",
         ],
+        View::WorkBoard => &[
+            "h/l", "Column", "j/k", "Navigate", "m", "Move", "Enter", "View", "Esc", "Back", "?",
+            "Help", "q", "Quit",
+        ],
         View::LoopDetail(_) => &["j/k", "Select", "Esc", "Back", "?", "Help", "q", "Quit"],
         View::RfcDetail(_) => &[
             "j/k",
             "Navigate",
             "Enter",
             "View Clause",
+            "r",
+            "Links",
             "Esc",
             "Back",
             "?",
@@ -203,18 +213,18 @@ fn bindings_for_view(view: View) -> &'static [&'static str] {
         | View::WorkDetail(_)
         | View::GuardDetail(_)
         | View::ClauseDetail(_, _) => &[
-            "j/k", "Scroll", "^d/^u", "Page", "Esc", "Back", "?", "Help", "q", "Quit",
+            "j/k", "Scroll", "^d/^u", "Page", "r", "Links", "Esc", "Back", "?", "Help", "q", "Quit",
         ],
     }
 }
 
-fn keybind_line(bindings: &[&str]) -> Line<'static> {
+fn keybind_line(bindings: &[&str], keys: &KeyMap, on_dashboard: bool) -> Line<'static> {
     let mut spans: Vec<Span<'static>> = vec![Span::raw(" ")];
     for chunk in bindings.chunks(2) {
         if chunk.len() == 2 {
             spans.push(Span::styled("[", Style::default().fg(Color::DarkGray)));
             spans.push(Span::styled(
-                chunk[0].to_string(),
+                keys.label(chunk[0], on_dashboard),
                 Style::default().fg(Color::Cyan).bold(),
             ));
             spans.push(Span::styled("] ", Style::default().fg(Color::DarkGray)));
@@ -229,19 +239,24 @@ fn keybind_line(bindings: &[&str]) -> Line<'static> {
 
 pub(super) struct Footer<'a> {
     view: View,
+    keys: &'a KeyMap,
     status: Option<&'a str>,
 }
 
 impl<'a> Footer<'a> {
-    pub(super) fn new(view: View, status: Option<&'a str>) -> Self {
-        Self { view, status }
+    pub(super) fn new(view: View, keys: &'a KeyMap, status: Option<&'a str>) -> Self {
+        Self { view, keys, status }
     }
 
     // Implements [[RFC-0003:C-NAV]]
     pub(super) fn render(self, frame: &mut Frame, area: Rect) {
         ChromeBar::new(
             Color::DarkGray,
-            keybind_line(bindings_for_view(self.view)),
+            keybind_line(
+                bindings_for_view(self.view),
+                self.keys,
+                self.view == View::Dashboard,
+            ),
             self.status.unwrap_or(""),
         )
         .left_alignment(Alignment::Center)
@@ -318,7 +333,7 @@ mod tests {
     }
 
     #[test]
-    fn footer_bindings_cover_all_view_groups() {
+    fn footer_bindings_cover_all_view_groups() -> Result<(), Box<dyn std::error::Error>> {
         for view in [
             View::Dashboard,
             View::RfcList,
@@ -327,9 +342,17 @@ mod tests {
             View::RfcDetail(0),
             View::WorkDetail(0),
         ] {
-            let line = keybind_line(bindings_for_view(view));
+            let line = keybind_line(bindings_for_view(view), &KeyMap::default(), false);
             assert!(line.width() > 0);
         }
+
+        let keys = KeyMap::from_config(&crate::config::TuiKeysConfig {
+            down: Some('n'),
+            ..Default::default()
+        })?;
+        let line = keybind_line(bindings_for_view(View::WorkDetail(0)), &keys, false);
+        assert!(line.to_string().contains("[n/k] Scroll"));
+        Ok(())
     }
 
     #[test]
@@ -343,7 +366,7 @@ mod tests {
                 .constraints([Constraint::Length(3), Constraint::Length(3)])
                 .split(frame.area());
             Header::new(app).render(frame, chunks[0]);
-            Footer::new(app.view, Some("status")).render(frame, chunks[1]);
+            Footer::new(app.view, &app.keys, Some("status")).render(frame, chunks[1]);
         })?;
 
         assert!(rendered.iter().any(|line| line.contains("govctl")));
@@ -1,5 +1,6 @@
 use super::super::{phase_style, rounded_block, status_style};
 use crate::theme::status_icon;
+use crate::tui::theme::TuiTheme;
 use ratatui::{
     prelude::*,
     widgets::{List, ListItem, ListState, Row, Table, TableState},
@@ -16,6 +17,7 @@ pub(in crate::tui::ui) struct ResourceTableSpec {
     pub(in crate::tui::ui) header_color: Color,
     pub(in crate::tui::ui) title: &'static str,
     pub(in crate::tui::ui) border_color: Color,
+    pub(in crate::tui::ui) theme: TuiTheme,
 }
 
 impl ResourceTable {
@@ -47,7 +49,7 @@ impl ResourceTable {
             )
             .row_highlight_style(Style::default().bg(Color::DarkGray))
             .block(
-                rounded_block(self.spec.title)
+                rounded_block(self.spec.theme, self.spec.title)
                     .border_style(Style::default().fg(self.spec.border_color)),
             );
         frame.render_stateful_widget(table, area, state);
@@ -59,6 +61,7 @@ pub(in crate::tui::ui) struct ResourceListRow<'a> {
     pub(in crate::tui::ui) title: &'a str,
     pub(in crate::tui::ui) status: &'a str,
     pub(in crate::tui::ui) tags: &'a [String],
+    pub(in crate::tui::ui) theme: TuiTheme,
 }
 
 impl ResourceListRow<'_> {
@@ -66,7 +69,7 @@ impl ResourceListRow<'_> {
         Row::new(vec![
             Line::from(self.id.to_string()),
             Line::from(self.title.to_string()),
-            StatusText::new(self.status).render(),
+            StatusText::new(self.theme, self.status).render(),
             TagsCell::new(self.tags).render(),
         ])
     }
@@ -76,26 +79,28 @@ pub(in crate::tui::ui) struct ClauseListRow<'a> {
     pub(in crate::tui::ui) id: &'a str,
     pub(in crate::tui::ui) title: &'a str,
     pub(in crate::tui::ui) status: &'a str,
+    pub(in crate::tui::ui) theme: TuiTheme,
 }
 
 impl ClauseListRow<'_> {
     pub(in crate::tui::ui) fn render(&self) -> ListItem<'static> {
         ListItem::new(Line::from(vec![
-            StatusText::new(self.status).icon_span(),
+            StatusText::new(self.theme, self.status).icon_span(),
             Span::styled(self.id.to_string(), Style::default().fg(Color::Blue).bold()),
-            Span::raw(" — "),
+            Span::raw(format!(" {} ", self.theme.glyph("—"))),
             Span::raw(self.title.to_string()),
         ]))
     }
 }
 
 pub(in crate::tui::ui) struct StatusText<'a> {
+    theme: TuiTheme,
     status: &'a str,
 }
 
 impl<'a> StatusText<'a> {
-    pub(in crate::tui::ui) fn new(status: &'a str) -> Self {
-        Self { status }
+    pub(in crate::tui::ui) fn new(theme: TuiTheme, status: &'a str) -> Self {
+        Self { theme, status }
     }
 
     pub(in crate::tui::ui) fn render(&self) -> Line<'static> {
@@ -105,31 +110,35 @@ impl<'a> StatusText<'a> {
     pub(in crate::tui::ui) fn spans<'b>(&self) -> Vec<Span<'b>> {
         vec![
             self.icon_span(),
-            Span::styled(self.status.to_string(), status_style(self.status)),
+            Span::styled(
+                self.status.to_string(),
+                status_style(self.theme, self.status),
+            ),
         ]
     }
 
     pub(in crate::tui::ui) fn icon_span<'b>(&self) -> Span<'b> {
         Span::styled(
-            format!("{} ", status_icon(self.status)),
-            status_style(self.status),
+            format!("{} ", self.theme.glyph(status_icon(self.status))),
+            status_style(self.theme, self.status),
         )
     }
 }
 
 pub(in crate::tui::ui) struct PhaseCell<'a> {
+    theme: TuiTheme,
     phase: &'a str,
 }
 
 impl<'a> PhaseCell<'a> {
-    pub(in crate::tui::ui) fn new(phase: &'a str) -> Self {
-        Self { phase }
+    pub(in crate::tui::ui) fn new(theme: TuiTheme, phase: &'a str) -> Self {
+        Self { theme, phase }
     }
 
     pub(in crate::tui::ui) fn render(&self) -> Line<'static> {
         Line::from(Span::styled(
             self.phase.to_string(),
-            phase_style(self.phase),
+            phase_style(self.theme, self.phase),
         ))
     }
 }
@@ -152,6 +161,7 @@ impl<'a> TagsCell<'a> {
 }
 
 pub(in crate::tui::ui) struct SelectableList {
+    theme: TuiTheme,
     title: String,
     border_color: Color,
     items: Vec<ListItem<'static>>,
@@ -159,11 +169,13 @@ pub(in crate::tui::ui) struct SelectableList {
 
 impl SelectableList {
     pub(in crate::tui::ui) fn new(
+        theme: TuiTheme,
         title: impl Into<String>,
         border_color: Color,
         items: Vec<ListItem<'static>>,
     ) -> Self {
         Self {
+            theme,
             title: title.into(),
             border_color,
             items,
@@ -172,9 +184,12 @@ impl SelectableList {
 
     pub(in crate::tui::ui) fn render(self, frame: &mut Frame, area: Rect, state: &mut ListState) {
         let list = List::new(self.items)
-            .block(rounded_block(&self.title).border_style(Style::default().fg(self.border_color)))
+            .block(
+                rounded_block(self.theme, &self.title)
+                    .border_style(Style::default().fg(self.border_color)),
+            )
             .highlight_style(Style::default().bg(Color::DarkGray))
-            .highlight_symbol("▶ ");
+            .highlight_symbol(format!("{} ", self.theme.glyph("▶")));
 
         frame.render_stateful_widget(list, area, state);
     }
@@ -1,7 +1,9 @@
 use super::super::rounded_block;
+use crate::tui::theme::TuiTheme;
 use ratatui::{prelude::*, widgets::Paragraph};
 
 pub(in crate::tui::ui) struct SummaryCard {
+    theme: TuiTheme,
     title: &'static str,
     border_color: Color,
     metrics: Vec<SummaryMetric>,
@@ -10,12 +12,14 @@ pub(in crate::tui::ui) struct SummaryCard {
 
 impl SummaryCard {
     pub(in crate::tui::ui) fn new(
+        theme: TuiTheme,
         title: &'static str,
         border_color: Color,
         metrics: Vec<SummaryMetric>,
         total: usize,
     ) -> Self {
         Self {
+            theme,
             title,
             border_color,
             metrics,
@@ -24,10 +28,11 @@ impl SummaryCard {
     }
 
     pub(in crate::tui::ui) fn into_paragraph(self) -> Paragraph<'static> {
+        let theme = self.theme;
         let title = self.title;
         let border_color = self.border_color;
         Paragraph::new(self.lines())
-            .block(rounded_block(title).border_style(Style::default().fg(border_color)))
+            .block(rounded_block(theme, title).border_style(Style::default().fg(border_color)))
     }
 
     fn lines(self) -> Vec<Line<'static>> {
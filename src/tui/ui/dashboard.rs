@@ -1,6 +1,7 @@
 use super::super::app::App;
 use super::components::{SummaryCard, SummaryMetric};
 use crate::status_counts::{count_by, counts_for_keys};
+use crate::theme::SemanticColor;
 use ratatui::{prelude::*, widgets::Paragraph};
 
 pub(super) fn draw(frame: &mut Frame, app: &App, area: Rect) {
@@ -27,12 +28,13 @@ pub(super) fn draw(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn summary_block(
+    app: &App,
     title: &'static str,
     border_color: Color,
     metrics: Vec<SummaryMetric>,
     total: usize,
 ) -> Paragraph<'static> {
-    SummaryCard::new(title, border_color, metrics, total).into_paragraph()
+    SummaryCard::new(app.theme, title, border_color, metrics, total).into_paragraph()
 }
 
 fn rfc_stats(app: &App) -> Paragraph<'static> {
@@ -40,12 +42,23 @@ fn rfc_stats(app: &App) -> Paragraph<'static> {
     let counts = counts_for_keys(&counts, ["draft", "normative", "deprecated"]);
 
     summary_block(
+        app,
         "📋 RFCs",
         Color::Blue,
         vec![
-            SummaryMetric::new("○", Color::Yellow, "Draft:      ", counts[0]),
-            SummaryMetric::new("●", Color::Green, "Normative:  ", counts[1]),
-            SummaryMetric::new("✗", Color::Red, "Deprecated: ", counts[2]),
+            SummaryMetric::new(
+                app.theme.glyph("○"),
+                app.theme.color(SemanticColor::Warning),
+                "Draft:      ",
+                counts[0],
+            ),
+            SummaryMetric::new(
+                app.theme.glyph("●"),
+                app.theme.color(SemanticColor::Success),
+                "Normative:  ",
+                counts[1],
+            ),
+            SummaryMetric::new(app.theme.glyph("✗"), Color::Red, "Deprecated: ", counts[2]),
         ],
         app.index.rfcs.len(),
     )
@@ -56,12 +69,23 @@ fn adr_stats(app: &App) -> Paragraph<'static> {
     let counts = counts_for_keys(&counts, ["proposed", "accepted", "superseded"]);
 
     summary_block(
+        app,
         "📝 ADRs",
         Color::Green,
         vec![
-            SummaryMetric::new("○", Color::Yellow, "Proposed:   ", counts[0]),
-            SummaryMetric::new("●", Color::Green, "Accepted:   ", counts[1]),
-            SummaryMetric::new("✗", Color::Red, "Superseded: ", counts[2]),
+            SummaryMetric::new(
+                app.theme.glyph("○"),
+                app.theme.color(SemanticColor::Warning),
+                "Proposed:   ",
+                counts[0],
+            ),
+            SummaryMetric::new(
+                app.theme.glyph("●"),
+                app.theme.color(SemanticColor::Success),
+                "Accepted:   ",
+                counts[1],
+            ),
+            SummaryMetric::new(app.theme.glyph("✗"), Color::Red, "Superseded: ", counts[2]),
         ],
         app.index.adrs.len(),
     )
@@ -72,12 +96,28 @@ fn work_stats(app: &App) -> Paragraph<'static> {
     let counts = counts_for_keys(&counts, ["queue", "active", "done"]);
 
     summary_block(
+        app,
         "📌 Work Items",
         Color::Yellow,
         vec![
-            SummaryMetric::new("○", Color::Yellow, "Queue:  ", counts[0]),
-            SummaryMetric::new("◉", Color::Green, "Active: ", counts[1]),
-            SummaryMetric::new("●", Color::Green, "Done:   ", counts[2]),
+            SummaryMetric::new(
+                app.theme.glyph("○"),
+                app.theme.color(SemanticColor::Warning),
+                "Queue:  ",
+                counts[0],
+            ),
+            SummaryMetric::new(
+                app.theme.glyph("◉"),
+                app.theme.color(SemanticColor::Success),
+                "Active: ",
+                counts[1],
+            ),
+            SummaryMetric::new(
+                app.theme.glyph("●"),
+                app.theme.color(SemanticColor::Success),
+                "Done:   ",
+                counts[2],
+            ),
         ],
         app.index.work_items.len(),
     )
@@ -100,6 +140,7 @@ fn ops_stats(app: &App) -> Paragraph<'static> {
     let total = app.supplement.guards.len() + app.supplement.loops.len();
 
     summary_block(
+        app,
         "Ops",
         Color::Cyan,
         vec![
@@ -166,8 +207,10 @@ fn cockpit_menu(app: &App) -> Paragraph<'static> {
             "check findings",
         ),
     ];
-    Paragraph::new(lines)
-        .block(super::rounded_block("Cockpit").border_style(Style::default().fg(Color::DarkGray)))
+    Paragraph::new(lines).block(
+        super::rounded_block(app.theme, "Cockpit")
+            .border_style(Style::default().fg(Color::DarkGray)),
+    )
 }
 
 fn menu_line(
@@ -4,6 +4,8 @@ use super::{
     phase_style, rounded_block, wrapped_line_count,
 };
 use crate::tui::dag::dag_lines;
+use crate::tui::data::guard_markdown;
+use crate::tui::theme::TuiTheme;
 use ratatui::{
     prelude::*,
     widgets::{List, ListItem, Paragraph, Wrap},
@@ -11,14 +13,21 @@ use ratatui::{
 use std::borrow::Cow;
 
 struct MetadataPanel<'a> {
+    theme: TuiTheme,
     title: String,
     border_color: Color,
     lines: Vec<Line<'a>>,
 }
 
 impl<'a> MetadataPanel<'a> {
-    fn new(title: impl Into<String>, border_color: Color, lines: Vec<Line<'a>>) -> Self {
+    fn new(
+        theme: TuiTheme,
+        title: impl Into<String>,
+        border_color: Color,
+        lines: Vec<Line<'a>>,
+    ) -> Self {
         Self {
+            theme,
             title: title.into(),
             border_color,
             lines,
@@ -30,13 +39,15 @@ impl<'a> MetadataPanel<'a> {
     }
 
     fn render(self, frame: &mut Frame, area: Rect) {
-        let block = rounded_block(&self.title).border_style(Style::default().fg(self.border_color));
+        let block = rounded_block(self.theme, &self.title)
+            .border_style(Style::default().fg(self.border_color));
         let panel = Paragraph::new(self.lines).block(block);
         frame.render_widget(panel, area);
     }
 }
 
 struct MarkdownPanel<'a> {
+    theme: TuiTheme,
     title: &'a str,
     border_color: Color,
     scroll: u16,
@@ -44,8 +55,15 @@ struct MarkdownPanel<'a> {
 }
 
 impl<'a> MarkdownPanel<'a> {
-    fn new(title: &'a str, border_color: Color, scroll: u16, text: Text<'a>) -> Self {
+    fn new(
+        theme: TuiTheme,
+        title: &'a str,
+        border_color: Color,
+        scroll: u16,
+        text: Text<'a>,
+    ) -> Self {
         Self {
+            theme,
             title,
             border_color,
             scroll,
@@ -54,7 +72,8 @@ impl<'a> MarkdownPanel<'a> {
     }
 
     fn render(self, frame: &mut Frame, area: Rect) -> DetailViewport {
-        let block = rounded_block(self.title).border_style(Style::default().fg(self.border_color));
+        let block = rounded_block(self.theme, self.title)
+            .border_style(Style::default().fg(self.border_color));
         let inner_width = block.inner(area).width;
         let total_lines = wrapped_line_count(&self.text.lines, inner_width);
         let content = Paragraph::new(self.text)
@@ -68,6 +87,7 @@ impl<'a> MarkdownPanel<'a> {
 }
 
 struct MarkdownDetailPanel<'a> {
+    theme: TuiTheme,
     title: &'a str,
     border_color: Color,
     scroll: u16,
@@ -75,8 +95,15 @@ struct MarkdownDetailPanel<'a> {
 }
 
 impl<'a> MarkdownDetailPanel<'a> {
-    fn new(title: &'a str, border_color: Color, scroll: u16, markdown: &'a str) -> Self {
+    fn new(
+        theme: TuiTheme,
+        title: &'a str,
+        border_color: Color,
+        scroll: u16,
+        markdown: &'a str,
+    ) -> Self {
         Self {
+            theme,
             title,
             border_color,
             scroll,
@@ -86,7 +113,8 @@ impl<'a> MarkdownDetailPanel<'a> {
 
     fn render(self, frame: &mut Frame, area: Rect) -> DetailViewport {
         let text = crate::terminal_md::render_to_tui_text(self.markdown);
-        MarkdownPanel::new(self.title, self.border_color, self.scroll, text).render(frame, area)
+        MarkdownPanel::new(self.theme, self.title, self.border_color, self.scroll, text)
+            .render(frame, area)
     }
 }
 
@@ -133,17 +161,17 @@ impl<'a> MetadataLine<'a> {
         }
     }
 
-    fn status(label: &'static str, status: &str) -> Self {
+    fn status(theme: TuiTheme, label: &'static str, status: &str) -> Self {
         Self {
             label,
-            value: StatusText::new(status).spans(),
+            value: StatusText::new(theme, status).spans(),
         }
     }
 
-    fn phase(label: &'static str, phase: &str) -> Self {
+    fn phase(theme: TuiTheme, label: &'static str, phase: &str) -> Self {
         Self {
             label,
-            value: vec![Span::styled(phase.to_string(), phase_style(phase))],
+            value: vec![Span::styled(phase.to_string(), phase_style(theme, phase))],
         }
     }
 
@@ -176,6 +204,7 @@ pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect, idx: usize)
         return;
     };
 
+    let theme = app.theme;
     let status = rfc.rfc.status.as_ref();
     let phase = rfc.rfc.phase.as_ref();
 
@@ -188,8 +217,8 @@ pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect, idx: usize)
             Style::default().fg(Color::Cyan),
         )
         .render(),
-        MetadataLine::status("Status:  ", status).render(),
-        MetadataLine::phase("Phase:   ", phase).render(),
+        MetadataLine::status(theme, "Status:  ", status).render(),
+        MetadataLine::phase(theme, "Phase:   ", phase).render(),
         MetadataLine::joined("Owners:  ", &rfc.rfc.owners, ", ").render(),
     ];
 
@@ -201,8 +230,12 @@ pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect, idx: usize)
         header_lines.push(MetadataLine::tags("Tags:    ", &rfc.rfc.tags).render());
     }
 
-    let header_panel =
-        MetadataPanel::new(format!("📋 {}", rfc.rfc.rfc_id), Color::Blue, header_lines);
+    let header_panel = MetadataPanel::new(
+        theme,
+        format!("📋 {}", rfc.rfc.rfc_id),
+        Color::Blue,
+        header_lines,
+    );
     let header_height = header_panel.outer_height();
 
     let chunks = Layout::default()
@@ -221,12 +254,13 @@ pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect, idx: usize)
                 id: &clause.spec.clause_id,
                 title: &clause.spec.title,
                 status: clause_status,
+                theme,
             }
             .render()
         })
         .collect();
 
-    SelectableList::new("Clauses", Color::Cyan, clause_items).render(
+    SelectableList::new(theme, "Clauses", Color::Cyan, clause_items).render(
         frame,
         chunks[1],
         &mut app.clause_list_state,
@@ -238,9 +272,10 @@ pub(super) fn draw_adr(frame: &mut Frame, app: &mut App, area: Rect, idx: usize)
         return DetailViewport::new(0);
     };
 
-    let markdown = crate::render::render_adr(adr).unwrap_or_default();
+    let markdown = crate::render::render_adr(&app.config.prefixes, adr).unwrap_or_default();
     let title = format!("📝 {}", adr.meta().id);
-    MarkdownDetailPanel::new(&title, Color::Green, app.scroll, &markdown).render(frame, area)
+    MarkdownDetailPanel::new(app.theme, &title, Color::Green, app.scroll, &markdown)
+        .render(frame, area)
 }
 
 pub(super) fn draw_work(
@@ -253,9 +288,10 @@ pub(super) fn draw_work(
         return DetailViewport::new(0);
     };
 
-    let markdown = crate::render::render_work_item(item).unwrap_or_default();
+    let markdown = crate::render::render_work_item(&app.config, item).unwrap_or_default();
     let title = format!("📌 {}", item.meta().id);
-    MarkdownDetailPanel::new(&title, Color::Yellow, app.scroll, &markdown).render(frame, area)
+    MarkdownDetailPanel::new(app.theme, &title, Color::Yellow, app.scroll, &markdown)
+        .render(frame, area)
 }
 
 // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: guard artifacts are browsable read-only.
@@ -268,22 +304,10 @@ pub(super) fn draw_guard(
     let Some(guard) = app.supplement.guards.get(idx) else {
         return DetailViewport::new(0);
     };
-    let meta = guard.meta();
-    let mut markdown = format!(
-        "# {}\n\n**ID:** {}\n\n**Command:** `{}`\n\n**Timeout:** {} seconds\n",
-        meta.title, meta.id, guard.spec.check.command, guard.spec.check.timeout_secs
-    );
-    if let Some(pattern) = &guard.spec.check.pattern {
-        markdown.push_str(&format!("\n**Pattern:** `{pattern}`\n"));
-    }
-    if !meta.refs.is_empty() {
-        markdown.push_str(&format!("\n**References:** {}\n", meta.refs.join(", ")));
-    }
-    if !meta.tags.is_empty() {
-        markdown.push_str(&format!("\n**Tags:** `{}`\n", meta.tags.join("`, `")));
-    }
-    let title = format!("Guard {}", meta.id);
-    MarkdownDetailPanel::new(&title, Color::LightBlue, app.scroll, &markdown).render(frame, area)
+    let markdown = guard_markdown(guard);
+    let title = format!("Guard {}", guard.meta().id);
+    MarkdownDetailPanel::new(app.theme, &title, Color::LightBlue, app.scroll, &markdown)
+        .render(frame, area)
 }
 
 // Implements [[RFC-0007:C-LOOP-VIEWS]] and [[RFC-0007:C-LOOP-DAG]].
@@ -298,9 +322,9 @@ pub(super) fn draw_loop(frame: &mut Frame, app: &mut App, area: Rect, idx: usize
             .map(|diag| diag.to_string())
             .unwrap_or_else(|| "Invalid loop state".to_string());
         frame.render_widget(
-            Paragraph::new(message)
-                .wrap(Wrap { trim: false })
-                .block(rounded_block("Loop").border_style(Style::default().fg(Color::Red))),
+            Paragraph::new(message).wrap(Wrap { trim: false }).block(
+                rounded_block(app.theme, "Loop").border_style(Style::default().fg(Color::Red)),
+            ),
             area,
         );
         return;
@@ -320,7 +344,7 @@ pub(super) fn draw_loop(frame: &mut Frame, app: &mut App, area: Rect, idx: usize
     };
 
     let max_lines = chunks[0].height.saturating_sub(2) as usize;
-    let dag_items = match dag_lines(state, selected.as_deref(), max_lines) {
+    let dag_items = match dag_lines(app.theme, state, selected.as_deref(), max_lines) {
         Ok(lines) => lines
             .into_iter()
             .map(|line| {
@@ -345,15 +369,17 @@ pub(super) fn draw_loop(frame: &mut Frame, app: &mut App, area: Rect, idx: usize
             Style::default().fg(Color::Red),
         )))],
     };
-    let dag = List::new(dag_items)
-        .block(rounded_block("Dependency DAG").border_style(Style::default().fg(Color::Yellow)));
+    let dag = List::new(dag_items).block(
+        rounded_block(app.theme, "Dependency DAG").border_style(Style::default().fg(Color::Yellow)),
+    );
     frame.render_widget(dag, chunks[0]);
 
     let inspector = loop_inspector_lines(state, selected.as_deref());
     frame.render_widget(
-        Paragraph::new(inspector)
-            .wrap(Wrap { trim: false })
-            .block(rounded_block("Selected Work").border_style(Style::default().fg(Color::Cyan))),
+        Paragraph::new(inspector).wrap(Wrap { trim: false }).block(
+            rounded_block(app.theme, "Selected Work")
+                .border_style(Style::default().fg(Color::Cyan)),
+        ),
         chunks[1],
     );
 }
@@ -450,7 +476,8 @@ pub(super) fn draw_clause(
     crate::render::render_clause(&mut raw, &rfc.rfc.rfc_id, clause);
 
     let title = format!("📜 {}", clause.spec.clause_id);
-    MarkdownDetailPanel::new(&title, Color::Magenta, app.scroll, &raw).render(frame, area)
+    MarkdownDetailPanel::new(app.theme, &title, Color::Magenta, app.scroll, &raw)
+        .render(frame, area)
 }
 
 #[cfg(test)]
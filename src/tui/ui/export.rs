@@ -0,0 +1,80 @@
+use super::super::app::{App, ExportTarget};
+use super::help::centered_rect;
+use super::rounded_block;
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, Paragraph},
+};
+
+/// Draw the export choices for the current view as a popup.
+pub(super) fn draw_menu(frame: &mut Frame, app: &App) {
+    let popup = centered_rect(50, 40, frame.area());
+    frame.render_widget(Clear, popup);
+    let block = rounded_block(app.theme, "Export").border_style(Style::default().fg(Color::Cyan));
+
+    let mut lines: Vec<Line> = app
+        .export_options()
+        .iter()
+        .enumerate()
+        .map(|(idx, option)| {
+            let destination = match option.target {
+                ExportTarget::File => option.file_name.clone(),
+                ExportTarget::Clipboard => "clipboard".to_string(),
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("  {}  ", idx + 1),
+                    Style::default().fg(Color::Cyan).bold(),
+                ),
+                Span::raw(format!("{} to {destination}", option.format.label())),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "1-9 export  Esc cancel",
+        Style::default().fg(Color::DarkGray),
+    ));
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::app::View;
+    use super::super::test_support::{project_index, render_app, work_item};
+    use super::*;
+    use crate::model::WorkItemStatus;
+
+    #[test]
+    fn export_menu_lists_numbered_formats_and_targets() -> Result<(), Box<dyn std::error::Error>> {
+        let mut app = App::new(project_index(
+            vec![],
+            vec![],
+            vec![work_item(
+                "WI-2026-01-01-001",
+                "Item",
+                WorkItemStatus::Queue,
+                &[],
+            )],
+        ));
+        app.view = View::WorkDetail(0);
+        app.open_export_menu();
+
+        let (_, rendered) = render_app(100, 30, app, |frame, app| {
+            draw_menu(frame, app);
+        })?;
+
+        assert!(
+            rendered
+                .iter()
+                .any(|line| line.contains("1  Markdown to WI-2026-01-01-001.md"))
+        );
+        assert!(
+            rendered
+                .iter()
+                .any(|line| line.contains("2  Markdown to clipboard"))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use super::super::app::{CreateForm, FieldValue};
+use super::help::centered_rect;
+use super::rounded_block;
+use crate::tui::theme::TuiTheme;
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, Paragraph, Wrap},
+};
+
+/// Draw the creation form as a popup over the current view.
+pub(super) fn draw_create_form(frame: &mut Frame, theme: TuiTheme, form: &CreateForm) {
+    let popup = centered_rect(60, 50, frame.area());
+    frame.render_widget(Clear, popup);
+
+    let title = format!("New {}", form.kind.label());
+    let block = rounded_block(theme, &title).border_style(Style::default().fg(Color::Cyan));
+
+    let mut lines = Vec::new();
+    for (idx, field) in form.fields.iter().enumerate() {
+        let focused = idx == form.focus;
+        let label_style = if focused {
+            Style::default().fg(Color::Cyan).bold()
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let marker = if focused { "> " } else { "  " };
+        let value = match &field.value {
+            FieldValue::Text(text) if focused => format!("{text}_"),
+            FieldValue::Text(text) => text.clone(),
+            FieldValue::Choice { .. } => format!("< {} >", field.display_value()),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{marker}{}: ", field.label), label_style),
+            Span::raw(value),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    if let Some(error) = &form.error {
+        lines.push(Line::styled(
+            format!("{}: {}", error.code.code(), error.message),
+            Style::default().fg(Color::Red),
+        ));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::styled(
+        "Enter create  Tab next field  Space change choice  Esc cancel",
+        Style::default().fg(Color::DarkGray),
+    ));
+
+    let content = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(content, popup);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::app::{App, View};
+    use super::super::test_support::{project_index, render_app};
+    use super::*;
+    use crate::diagnostic::{Diagnostic, DiagnosticCode};
+
+    #[test]
+    fn create_form_renders_fields_and_error() -> Result<(), Box<dyn std::error::Error>> {
+        let mut app = App::new(project_index(vec![], vec![], vec![]));
+        app.view = View::WorkList;
+        app.open_create_form();
+        if let Some(form) = app.create_form.as_mut() {
+            form.error = Some(Diagnostic::new(
+                DiagnosticCode::E0801MissingRequiredArg,
+                "Title is required",
+                "tui",
+            ));
+        }
+
+        let (_, rendered) = render_app(100, 30, app, |frame, app| {
+            if let Some(form) = &app.create_form {
+                draw_create_form(frame, app.theme, form);
+            }
+        })?;
+
+        assert!(rendered.iter().any(|line| line.contains("New Work Item")));
+        assert!(rendered.iter().any(|line| line.contains("> Title: _")));
+        assert!(
+            rendered
+                .iter()
+                .any(|line| line.contains("Status: < queue >"))
+        );
+        assert!(
+            rendered
+                .iter()
+                .any(|line| line.contains("E0801: Title is required"))
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use super::super::app::{App, View};
+use super::super::keys::KeyMap;
 use super::rounded_block;
 use ratatui::{
     prelude::*,
@@ -11,7 +12,7 @@ pub(super) fn draw_overlay(frame: &mut Frame, app: &App) {
     frame.render_widget(Clear, popup);
 
     let title = "Help";
-    let block = rounded_block(title).border_style(Style::default().fg(Color::Cyan));
+    let block = rounded_block(app.theme, title).border_style(Style::default().fg(Color::Cyan));
 
     let mut lines = vec![
         Line::from("Global"),
@@ -27,6 +28,7 @@ pub(super) fn draw_overlay(frame: &mut Frame, app: &App) {
             lines.push(Line::from("  c      Clause list"));
             lines.push(Line::from("  a      ADR list"));
             lines.push(Line::from("  w      Work list"));
+            lines.push(Line::from("  b      Work board"));
             lines.push(Line::from("  g      Guard list"));
             lines.push(Line::from("  s      Search"));
             lines.push(Line::from("  l      Loop list"));
@@ -49,6 +51,10 @@ pub(super) fn draw_overlay(frame: &mut Frame, app: &App) {
             lines.push(Line::from("  g/G    Top/Bottom"));
             lines.push(Line::from("  /      Filter"));
             lines.push(Line::from("  n/p    Next/Prev match (when filtered)"));
+            lines.push(Line::from(
+                "  n      New artifact (RFC, clause, ADR, work, guard lists)",
+            ));
+            lines.push(Line::from("  e      Export rows as CSV or JSON"));
             lines.push(Line::from("  Esc    Back (or clear filter in filter mode)"));
         }
         View::Search => {
@@ -58,6 +64,16 @@ pub(super) fn draw_overlay(frame: &mut Frame, app: &App) {
             lines.push(Line::from("  j/k    Move selection"));
             lines.push(Line::from("  Esc    Back"));
         }
+        View::WorkBoard => {
+            lines.push(Line::from("Work Board"));
+            lines.push(Line::from("  h/l    Move between columns"));
+            lines.push(Line::from("  j/k    Move selection"));
+            lines.push(Line::from("  Enter  View work item"));
+            lines.push(Line::from(
+                "  m      Move item: h/l pick column, m/Enter confirm",
+            ));
+            lines.push(Line::from("  Esc    Cancel move, or back"));
+        }
         View::LoopDetail(_) => {
             lines.push(Line::from("Loop DAG"));
             lines.push(Line::from("  j/k    Select work item"));
@@ -67,6 +83,8 @@ pub(super) fn draw_overlay(frame: &mut Frame, app: &App) {
             lines.push(Line::from("RFC Detail"));
             lines.push(Line::from("  j/k    Move clause selection"));
             lines.push(Line::from("  Enter  View clause"));
+            lines.push(Line::from("  r      Refs and backlinks"));
+            lines.push(Line::from("  e      Export as Markdown"));
             lines.push(Line::from("  Esc    Back"));
         }
         View::AdrDetail(_)
@@ -77,15 +95,35 @@ pub(super) fn draw_overlay(frame: &mut Frame, app: &App) {
             lines.push(Line::from("  j/k      Scroll line"));
             lines.push(Line::from("  Ctrl+d/u Half-page"));
             lines.push(Line::from("  PgDn/Up  Full page"));
+            lines.push(Line::from("  r        Refs and backlinks"));
+            lines.push(Line::from("  e        Export as Markdown"));
             lines.push(Line::from("  Esc      Back"));
         }
     }
 
+    let on_dashboard = app.view == View::Dashboard;
+    let lines: Vec<Line> = lines
+        .into_iter()
+        .map(|line| relabel(line, &app.keys, on_dashboard))
+        .collect();
     let content = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
     frame.render_widget(content, popup);
 }
 
-fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+/// Show `[tui.keys]` remaps in a "  key  description" line. Remaps are one
+/// character for one, so the columns stay aligned.
+fn relabel(line: Line<'static>, keys: &KeyMap, on_dashboard: bool) -> Line<'static> {
+    let text = line.to_string();
+    let Some((key, description)) = text
+        .strip_prefix("  ")
+        .and_then(|rest| rest.split_once(' '))
+    else {
+        return line;
+    };
+    Line::from(format!("  {} {description}", keys.label(key, on_dashboard)))
+}
+
+pub(super) fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
     let vertical = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
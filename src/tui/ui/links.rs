@@ -0,0 +1,127 @@
+use super::super::app::App;
+use super::help::centered_rect;
+use super::rounded_block;
+use ratatui::{
+    prelude::*,
+    widgets::{Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Draw the refs and backlinks of the current detail view as a popup.
+pub(super) fn draw_overlay(frame: &mut Frame, app: &App) {
+    let popup = centered_rect(70, 60, frame.area());
+    frame.render_widget(Clear, popup);
+
+    let title = match app.detail_artifact_id() {
+        Some(id) => format!("Links: {id}"),
+        None => "Links".to_string(),
+    };
+    let block = rounded_block(app.theme, &title).border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+
+    let rows = app.link_rows();
+    if rows.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No references or backlinks")
+                .style(Style::default().fg(Color::DarkGray)),
+            chunks[0],
+        );
+    } else {
+        let relation_width = rows
+            .iter()
+            .map(|row| row.relation.chars().count())
+            .max()
+            .unwrap_or_default();
+        let items: Vec<ListItem> = rows
+            .iter()
+            .map(|row| {
+                let arrow = app.theme.glyph(if row.outgoing { "→" } else { "←" });
+                let id_style = if row.target.is_some() {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{arrow} {:relation_width$}  ", row.relation)),
+                    Span::styled(row.id.clone(), id_style),
+                    Span::styled(
+                        row.title
+                            .as_deref()
+                            .map(|title| format!("  {title}"))
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]))
+            })
+            .collect();
+        let list =
+            List::new(items).highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(app.link_selected));
+        frame.render_stateful_widget(list, chunks[0], &mut state);
+    }
+
+    frame.render_widget(
+        Paragraph::new("j/k select  Enter open  Esc close")
+            .style(Style::default().fg(Color::DarkGray)),
+        chunks[1],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::app::View;
+    use super::super::super::data::artifact_links;
+    use super::super::test_support::{project_index, render_app, work_item};
+    use super::*;
+    use crate::model::WorkItemStatus;
+
+    #[test]
+    fn links_overlay_lists_refs_and_backlinks() -> Result<(), Box<dyn std::error::Error>> {
+        let mut blocked = work_item(
+            "WI-2026-01-01-002",
+            "Blocked item",
+            WorkItemStatus::Queue,
+            &[],
+        );
+        blocked.spec.govctl.depends_on = vec!["WI-2026-01-01-001".to_string()];
+        blocked.spec.govctl.refs = vec!["ADR-0042".to_string()];
+        let mut app = App::new(project_index(
+            vec![],
+            vec![],
+            vec![
+                work_item(
+                    "WI-2026-01-01-001",
+                    "Base item",
+                    WorkItemStatus::Active,
+                    &[],
+                ),
+                blocked,
+            ],
+        ));
+        app.supplement.links = artifact_links(&app.index, &[]);
+        app.view = View::WorkDetail(1);
+        app.open_links();
+
+        let (_, rendered) = render_app(100, 30, app, |frame, app| {
+            draw_overlay(frame, app);
+        })?;
+
+        assert!(
+            rendered
+                .iter()
+                .any(|line| line.contains("Links: WI-2026-01-01-002"))
+        );
+        assert!(rendered.iter().any(|line| line.contains("ADR-0042")));
+        assert!(
+            rendered
+                .iter()
+                .any(|line| line.contains("depends on  WI-2026-01-01-001  Base item"))
+        );
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@ use ratatui::{
 
 pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.index.rfcs,
         &indices,
@@ -26,6 +27,7 @@ pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Cyan,
             title: "📋 RFCs",
             border_color: Color::Blue,
+            theme,
         },
         |rfc| {
             let status = rfc.rfc.status.as_ref();
@@ -34,8 +36,8 @@ pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect) {
             Row::new(vec![
                 Line::from(rfc.rfc.rfc_id.clone()),
                 Line::from(rfc.rfc.title.clone()),
-                StatusText::new(status).render(),
-                PhaseCell::new(phase).render(),
+                StatusText::new(theme, status).render(),
+                PhaseCell::new(theme, phase).render(),
                 TagsCell::new(&rfc.rfc.tags).render(),
             ])
         },
@@ -45,6 +47,7 @@ pub(super) fn draw_rfc(frame: &mut Frame, app: &mut App, area: Rect) {
 
 pub(super) fn draw_adr(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.index.adrs,
         &indices,
@@ -59,6 +62,7 @@ pub(super) fn draw_adr(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Green,
             title: "📝 ADRs",
             border_color: Color::Green,
+            theme,
         },
         |adr| {
             let meta = adr.meta();
@@ -67,6 +71,7 @@ pub(super) fn draw_adr(frame: &mut Frame, app: &mut App, area: Rect) {
                 title: &meta.title,
                 status: meta.status.as_ref(),
                 tags: &meta.tags,
+                theme,
             }
             .render()
         },
@@ -76,6 +81,7 @@ pub(super) fn draw_adr(frame: &mut Frame, app: &mut App, area: Rect) {
 
 pub(super) fn draw_work(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.index.work_items,
         &indices,
@@ -90,6 +96,7 @@ pub(super) fn draw_work(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Yellow,
             title: "📌 Work Items",
             border_color: Color::Yellow,
+            theme,
         },
         |item| {
             let meta = item.meta();
@@ -98,6 +105,7 @@ pub(super) fn draw_work(frame: &mut Frame, app: &mut App, area: Rect) {
                 title: &meta.title,
                 status: meta.status.as_ref(),
                 tags: &meta.tags,
+                theme,
             }
             .render()
         },
@@ -108,6 +116,7 @@ pub(super) fn draw_work(frame: &mut Frame, app: &mut App, area: Rect) {
 // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: clause browsing view.
 pub(super) fn draw_clause(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.supplement.clauses,
         &indices,
@@ -123,6 +132,7 @@ pub(super) fn draw_clause(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Magenta,
             title: "Clauses",
             border_color: Color::Magenta,
+            theme,
         },
         |entry| {
             let clause = &entry.clause.spec;
@@ -130,7 +140,7 @@ pub(super) fn draw_clause(frame: &mut Frame, app: &mut App, area: Rect) {
                 Line::from(entry.rfc_id.clone()),
                 Line::from(clause.clause_id.clone()),
                 Line::from(clause.title.clone()),
-                StatusText::new(clause.status.as_ref()).render(),
+                StatusText::new(theme, clause.status.as_ref()).render(),
                 TagsCell::new(&clause.tags).render(),
             ])
         },
@@ -141,6 +151,7 @@ pub(super) fn draw_clause(frame: &mut Frame, app: &mut App, area: Rect) {
 // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: guard browsing view.
 pub(super) fn draw_guard(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.supplement.guards,
         &indices,
@@ -155,6 +166,7 @@ pub(super) fn draw_guard(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::LightBlue,
             title: "Guards",
             border_color: Color::LightBlue,
+            theme,
         },
         |guard| {
             Row::new(vec![
@@ -171,6 +183,7 @@ pub(super) fn draw_guard(frame: &mut Frame, app: &mut App, area: Rect) {
 // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: release browsing view.
 pub(super) fn draw_release(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.supplement.releases,
         &indices,
@@ -185,6 +198,7 @@ pub(super) fn draw_release(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Cyan,
             title: "Releases",
             border_color: Color::Cyan,
+            theme,
         },
         |release| {
             Row::new(vec![
@@ -201,6 +215,7 @@ pub(super) fn draw_release(frame: &mut Frame, app: &mut App, area: Rect) {
 // Implements [[RFC-0007:C-COCKPIT-VIEWS]]: tag browsing view.
 pub(super) fn draw_tag(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.supplement.tags,
         &indices,
@@ -210,6 +225,7 @@ pub(super) fn draw_tag(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Magenta,
             title: "Tags",
             border_color: Color::Magenta,
+            theme,
         },
         |tag| {
             Row::new(vec![
@@ -224,6 +240,7 @@ pub(super) fn draw_tag(frame: &mut Frame, app: &mut App, area: Rect) {
 // Implements [[RFC-0007:C-LOOP-VIEWS]]: loop list view.
 pub(super) fn draw_loop(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         app.loop_entries(),
         &indices,
@@ -240,6 +257,7 @@ pub(super) fn draw_loop(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Yellow,
             title: "Loops",
             border_color: Color::Yellow,
+            theme,
         },
         |entry| {
             if let Some(state) = &entry.state {
@@ -298,15 +316,16 @@ pub(super) fn draw_search(frame: &mut Frame, app: &mut App, area: Rect) {
             ]),
         ]);
         frame.render_widget(
-            Paragraph::new(message)
-                .wrap(Wrap { trim: false })
-                .block(rounded_block("Search").border_style(Style::default().fg(Color::Red))),
+            Paragraph::new(message).wrap(Wrap { trim: false }).block(
+                rounded_block(app.theme, "Search").border_style(Style::default().fg(Color::Red)),
+            ),
             area,
         );
         return;
     }
 
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.search_results,
         &indices,
@@ -321,6 +340,7 @@ pub(super) fn draw_search(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Green,
             title: "Search",
             border_color: Color::Green,
+            theme,
         },
         |result| {
             Row::new(vec![
@@ -337,6 +357,7 @@ pub(super) fn draw_search(frame: &mut Frame, app: &mut App, area: Rect) {
 // Implements [[RFC-0007:C-DIAGNOSTICS]]: check diagnostics view.
 pub(super) fn draw_diagnostics(frame: &mut Frame, app: &mut App, area: Rect) {
     let indices = app.list_indices();
+    let theme = app.theme;
     ResourceTable::from_indexed_items(
         &app.supplement.diagnostics,
         &indices,
@@ -351,13 +372,14 @@ pub(super) fn draw_diagnostics(frame: &mut Frame, app: &mut App, area: Rect) {
             header_color: Color::Red,
             title: "Diagnostics",
             border_color: Color::Red,
+            theme,
         },
         |diagnostic| {
             Row::new(vec![
                 Line::from(level_label(diagnostic.level)),
                 Line::from(diagnostic.code.code()),
                 Line::from(diagnostic.message.clone()),
-                Line::from(diagnostic.file.clone()),
+                Line::from(diagnostic.location()),
             ])
         },
     )
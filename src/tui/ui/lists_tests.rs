@@ -223,11 +223,7 @@ fn guard_entry() -> GuardEntry {
     GuardEntry {
         spec: GuardSpec {
             govctl: GuardMeta::new("GUARD-LIST", "Guard row"),
-            check: GuardCheck {
-                command: "cargo test".to_string(),
-                timeout_secs: 30,
-                pattern: None,
-            },
+            check: GuardCheck::new("cargo test", 30),
         },
         path: "gov/guard/GUARD-LIST.toml".into(),
     }
@@ -1,15 +1,20 @@
 //! UI rendering for TUI.
 
+mod board;
 mod chrome;
 mod components;
 mod dashboard;
 mod detail;
+mod export;
+mod form;
 mod help;
+mod links;
 mod lists;
 #[cfg(test)]
 mod test_support;
 
 use super::app::{App, View};
+use super::theme::TuiTheme;
 use crate::theme::{phase_semantic, status_semantic};
 use detail::DetailViewport;
 use ratatui::{
@@ -18,12 +23,12 @@ use ratatui::{
     widgets::{Block, Borders},
 };
 
-fn status_style(status: &str) -> Style {
-    Style::default().fg(status_semantic(status).to_ratatui())
+fn status_style(theme: TuiTheme, status: &str) -> Style {
+    Style::default().fg(theme.color(status_semantic(status)))
 }
 
-fn phase_style(phase: &str) -> Style {
-    Style::default().fg(phase_semantic(phase).to_ratatui())
+fn phase_style(theme: TuiTheme, phase: &str) -> Style {
+    Style::default().fg(theme.color(phase_semantic(phase)))
 }
 
 /// Main draw function
@@ -47,10 +52,17 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         footer_status = Some(viewport.footer_status(&mut app.scroll));
     }
 
-    chrome::Footer::new(app.view, footer_status.as_deref()).render(frame, chunks[2]);
+    let footer_status = footer_status.as_deref().or(app.notice.as_deref());
+    chrome::Footer::new(app.view, &app.keys, footer_status).render(frame, chunks[2]);
 
-    if app.show_help {
+    if let Some(form) = &app.create_form {
+        form::draw_create_form(frame, app.theme, form);
+    } else if app.show_help {
         help::draw_overlay(frame, app);
+    } else if app.links_open {
+        links::draw_overlay(frame, app);
+    } else if app.export_menu {
+        export::draw_menu(frame, app);
     }
 }
 
@@ -80,6 +92,10 @@ fn draw_content(frame: &mut Frame, app: &mut App, area: Rect) -> Option<DetailVi
             lists::draw_guard(frame, app, area);
             None
         }
+        View::WorkBoard => {
+            board::draw(frame, app, area);
+            None
+        }
         View::ReleaseList => {
             lists::draw_release(frame, app, area);
             None
@@ -145,9 +161,9 @@ fn wrapped_line_count(lines: &[Line], render_width: u16) -> usize {
         .sum()
 }
 
-fn rounded_block(title: &str) -> Block<'_> {
+fn rounded_block(theme: TuiTheme, title: &str) -> Block<'_> {
     Block::default()
-        .title(format!(" {} ", title))
+        .title(format!(" {} ", theme.title(title)))
         .borders(Borders::ALL)
         .border_set(border::ROUNDED)
 }
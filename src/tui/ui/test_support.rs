@@ -65,9 +65,14 @@ pub(super) fn rfc(
             status,
             phase,
             owners: vec![],
+            approvals: vec![],
+            frozen: false,
             created: "2026-01-01".to_string(),
+            created_by: None,
+            attachments: vec![],
             updated: None,
             supersedes: None,
+            deprecation: None,
             refs: vec![],
             tags: tags.iter().map(|tag| tag.to_string()).collect(),
             sections: vec![],
@@ -76,6 +81,7 @@ pub(super) fn rfc(
         },
         clauses: vec![],
         path: PathBuf::from(format!("gov/rfc/{id}.toml")),
+        amended: None,
     }
 }
 
@@ -86,9 +92,10 @@ pub(super) fn clause(id: &str, title: &str, text: &str) -> ClauseEntry {
             title: title.to_string(),
             kind: ClauseKind::Normative,
             status: ClauseStatus::Active,
-            text: text.to_string(),
+            text: Some(text.to_string()),
             anchors: vec![],
             superseded_by: None,
+            deprecation: None,
             since: None,
             tags: vec![],
         },
@@ -1,28 +1,63 @@
+use crate::{ColorChoice, Persona};
 use owo_colors::OwoColorize;
 use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
 
-/// Check if stderr supports colors (TTY detection + NO_COLOR)
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+/// Apply the global `--color` choice; `--for agent` turns auto-detected
+/// color off.
+pub fn init_color(choice: ColorChoice, persona: Persona) {
+    let choice = match choice {
+        ColorChoice::Auto if persona.is_agent() => ColorChoice::Never,
+        choice => choice,
+    };
+    COLOR_CHOICE.store(choice as u8, Ordering::Relaxed);
+}
+
+/// Resolve `--color` for one output stream.
 ///
 /// Implements [[ADR-0017]] terminal capability detection:
-/// - Auto-detect TTY
-/// - Respect `NO_COLOR` environment variable
-pub(super) fn use_colors() -> bool {
-    // Respect NO_COLOR environment variable per https://no-color.org/
-    if std::env::var("NO_COLOR").is_ok() {
-        return false;
+/// - `--color=always` / `--color=never` win outright
+/// - Otherwise auto-detect TTY and respect `NO_COLOR`
+fn stream_supports_color(stream: supports_color::Stream) -> bool {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        c if c == ColorChoice::Always as u8 => true,
+        c if c == ColorChoice::Never as u8 => false,
+        _ => {
+            // Respect NO_COLOR environment variable per https://no-color.org/
+            if std::env::var("NO_COLOR").is_ok() {
+                return false;
+            }
+            supports_color::on(stream).is_some()
+        }
     }
-    supports_color::on(supports_color::Stream::Stderr).is_some()
 }
 
-/// Check if stdout supports colors (TTY detection + NO_COLOR)
+/// Check if stderr supports colors (`--color`, TTY detection, NO_COLOR)
+pub(super) fn use_colors() -> bool {
+    stream_supports_color(supports_color::Stream::Stderr)
+}
+
+/// Check if stdout supports colors (`--color`, TTY detection, NO_COLOR)
 ///
 /// Use this for commands that output to stdout (e.g., `list`, `status`).
 pub fn stdout_supports_color() -> bool {
-    // Respect NO_COLOR environment variable per https://no-color.org/
-    if std::env::var("NO_COLOR").is_ok() {
-        return false;
+    stream_supports_color(supports_color::Stream::Stdout)
+}
+
+/// Width available for stdout tables, in columns.
+///
+/// `COLUMNS` overrides detection; piped output without it has no width limit.
+pub fn terminal_width() -> Option<u16> {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|value| value.parse::<u16>().ok())
+        .filter(|columns| *columns > 0)
+    {
+        return Some(columns);
     }
-    supports_color::on(supports_color::Stream::Stdout).is_some()
+    terminal_size::terminal_size().map(|(width, _)| width.0)
 }
 
 /// Format a file path (cyan)
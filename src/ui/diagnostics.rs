@@ -1,10 +1,13 @@
 use super::color::use_colors;
+use crate::Persona;
 use crate::diagnostic::{Diagnostic, DiagnosticLevel};
 use owo_colors::OwoColorize;
 
-/// Format a diagnostic message
-pub fn diagnostic(diag: &Diagnostic) {
-    if use_colors() {
+/// Format a diagnostic message for `persona`
+pub fn diagnostic(diag: &Diagnostic, persona: Persona) {
+    if persona.is_agent() {
+        agent_diagnostic(diag);
+    } else if use_colors() {
         let level_str = match diag.level {
             DiagnosticLevel::Error => "error".red().bold().to_string(),
             DiagnosticLevel::Warning => "warning".yellow().bold().to_string(),
@@ -15,20 +18,38 @@ pub fn diagnostic(diag: &Diagnostic) {
             level_str,
             diag.code.code().bright_black(),
             diag.message,
-            diag.file.cyan()
+            diag.location().cyan()
         );
     } else {
-        let level_str = match diag.level {
-            DiagnosticLevel::Error => "error",
-            DiagnosticLevel::Warning => "warning",
-            DiagnosticLevel::Info => "info",
-        };
-        eprintln!(
-            "{}[{}]: {} ({})",
-            level_str,
-            diag.code.code(),
-            diag.message,
-            diag.file
-        );
+        eprintln!("{diag}");
+    }
+}
+
+/// One `level[CODE]: summary (location)` line, then one indented
+/// `hint:`, `fix:`, and `explain:` line each, in that order.
+fn agent_diagnostic(diag: &Diagnostic) {
+    let level_str = match diag.level {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        DiagnosticLevel::Info => "info",
+    };
+    let code = diag.code.code();
+    eprintln!(
+        "{level_str}[{code}]: {} ({})",
+        diag.summary(),
+        diag.location()
+    );
+    let commands = diag.corrective_commands();
+    // A hint that is only a quoted command is repeated by its `fix:` line.
+    if let Some(hint) = diag.hint().filter(|hint| {
+        !commands
+            .iter()
+            .any(|command| hint.trim_matches('`') == command)
+    }) {
+        eprintln!("  hint: {hint}");
+    }
+    for command in commands {
+        eprintln!("  fix: {command}");
     }
+    eprintln!("  explain: govctl explain {code}");
 }
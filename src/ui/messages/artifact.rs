@@ -1,8 +1,12 @@
 use super::super::color::use_colors;
+use super::super::verbosity::is_quiet;
 use owo_colors::OwoColorize;
 use std::path::Path;
 
 pub fn created(kind: &str, path: &Path) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("{} {}: {}", "Created".green(), kind, path.display().cyan());
     } else {
@@ -11,6 +15,9 @@ pub fn created(kind: &str, path: &Path) {
 }
 
 pub fn field_set(id: &str, field: &str, value: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Set {}.{} = {}",
@@ -24,6 +31,9 @@ pub fn field_set(id: &str, field: &str, value: &str) {
 }
 
 pub fn field_added(id: &str, field: &str, value: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Added '{}' to {}.{}",
@@ -37,6 +47,9 @@ pub fn field_added(id: &str, field: &str, value: &str) {
 }
 
 pub fn field_removed(id: &str, field: &str, value: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Removed '{}' from {}.{}",
@@ -50,6 +63,9 @@ pub fn field_removed(id: &str, field: &str, value: &str) {
 }
 
 pub fn moved(filename: &str, status: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Moved {} to {}", filename.cyan(), status.green().bold());
     } else {
@@ -58,6 +74,9 @@ pub fn moved(filename: &str, status: &str) {
 }
 
 pub fn phase_advanced(id: &str, phase: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Advanced {} to phase: {}", id.cyan().bold(), phase.green());
     } else {
@@ -66,6 +85,9 @@ pub fn phase_advanced(id: &str, phase: &str) {
 }
 
 pub fn version_bumped(id: &str, version: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Bumped {} to {}", id.cyan().bold(), version.green().bold());
     } else {
@@ -74,6 +96,9 @@ pub fn version_bumped(id: &str, version: &str) {
 }
 
 pub fn changelog_change_added(id: &str, version: &str, change: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Added change to {} v{}: {}",
@@ -87,6 +112,9 @@ pub fn changelog_change_added(id: &str, version: &str, change: &str) {
 }
 
 pub fn ticked(item: &str, status: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Marked '{}' as {}", item.white(), status.green());
     } else {
@@ -95,6 +123,9 @@ pub fn ticked(item: &str, status: &str) {
 }
 
 pub fn accepted(kind: &str, id: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Accepted {}: {}", kind, id.cyan().bold());
     } else {
@@ -103,6 +134,9 @@ pub fn accepted(kind: &str, id: &str) {
 }
 
 pub fn rejected(kind: &str, id: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Rejected {}: {}", kind, id.cyan().bold());
     } else {
@@ -110,7 +144,37 @@ pub fn rejected(kind: &str, id: &str) {
     }
 }
 
+pub fn refs_updated(owner: &str, from: &str, to: &str) {
+    if is_quiet() {
+        return;
+    }
+    if use_colors() {
+        eprintln!(
+            "Updated refs in {}: {} -> {}",
+            owner.cyan().bold(),
+            from,
+            to.cyan().bold()
+        );
+    } else {
+        eprintln!("Updated refs in {}: {} -> {}", owner, from, to);
+    }
+}
+
+pub fn published(id: &str, url: &str) {
+    if is_quiet() {
+        return;
+    }
+    if use_colors() {
+        eprintln!("Published {}: {}", id.cyan().bold(), url);
+    } else {
+        eprintln!("Published {}: {}", id, url);
+    }
+}
+
 pub fn deprecated(kind: &str, id: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Deprecated {}: {}", kind, id.yellow().bold());
     } else {
@@ -119,6 +183,9 @@ pub fn deprecated(kind: &str, id: &str) {
 }
 
 pub fn superseded(kind: &str, id: &str, by: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Superseded {}: {}", kind, id.yellow().bold());
         eprintln!("  Replaced by: {}", by.cyan().bold());
@@ -129,6 +196,9 @@ pub fn superseded(kind: &str, id: &str, by: &str) {
 }
 
 pub fn updated(kind: &str, id: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("Updated {}: {}", kind, id.cyan().bold());
     } else {
@@ -136,7 +206,42 @@ pub fn updated(kind: &str, id: &str) {
     }
 }
 
+pub fn approved(id: &str, approver: &str, count: usize, quorum: u32) {
+    if is_quiet() {
+        return;
+    }
+    let tally = if quorum > 0 {
+        format!("{count} of {quorum} required approvals")
+    } else {
+        format!("{count} approvals")
+    };
+    if use_colors() {
+        eprintln!(
+            "Approved {} as {} ({tally})",
+            id.cyan().bold(),
+            approver.white()
+        );
+    } else {
+        eprintln!("Approved {id} as {approver} ({tally})");
+    }
+}
+
+pub fn frozen(id: &str, frozen: bool) {
+    if is_quiet() {
+        return;
+    }
+    let action = if frozen { "Froze" } else { "Unfroze" };
+    if use_colors() {
+        eprintln!("{action} {}", id.cyan().bold());
+    } else {
+        eprintln!("{action} {id}");
+    }
+}
+
 pub fn finalized(id: &str, status: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Finalized {} to status: {}",
@@ -149,6 +254,9 @@ pub fn finalized(id: &str, status: &str) {
 }
 
 pub fn release_created(version: &str, date: &str, work_item_count: usize) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Created release {} ({}) with {} work items",
@@ -164,7 +272,21 @@ pub fn release_created(version: &str, date: &str, work_item_count: usize) {
     }
 }
 
+pub fn github_release_drafted(tag: &str, url: &str) {
+    if is_quiet() {
+        return;
+    }
+    if use_colors() {
+        eprintln!("Drafted GitHub release {}: {}", tag.cyan().bold(), url);
+    } else {
+        eprintln!("Drafted GitHub release {}: {}", tag, url);
+    }
+}
+
 pub fn release_undone(version: &str, work_item_count: usize) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Undid release {} ({} work items are now unreleased)",
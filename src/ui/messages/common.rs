@@ -1,10 +1,14 @@
 use super::super::color::use_colors;
 use super::super::path_str;
+use super::super::verbosity::is_quiet;
 use owo_colors::OwoColorize;
 use std::fmt::Display;
 use std::path::Path;
 
 pub fn success(msg: impl Display) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("{} {}", "✓".green(), msg);
     } else {
@@ -13,10 +17,16 @@ pub fn success(msg: impl Display) {
 }
 
 pub fn info(msg: impl Display) {
+    if is_quiet() {
+        return;
+    }
     eprintln!("{}", msg);
 }
 
 pub fn hint(msg: impl Display) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("{} {}", "hint:".dimmed(), msg.to_string().dimmed());
     } else {
@@ -25,6 +35,9 @@ pub fn hint(msg: impl Display) {
 }
 
 pub fn rendered(path: &Path) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("{}: {}", "Rendered".green(), path.display().cyan());
     } else {
@@ -32,7 +45,21 @@ pub fn rendered(path: &Path) {
     }
 }
 
+pub fn formatted(path: &Path) {
+    if is_quiet() {
+        return;
+    }
+    if use_colors() {
+        eprintln!("{}: {}", "Formatted".green(), path.display().cyan());
+    } else {
+        eprintln!("Formatted: {}", path.display());
+    }
+}
+
 pub fn not_found(kind: &str, location: &Path) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("No {}s found in {}", kind, location.display().cyan());
     } else {
@@ -41,6 +68,9 @@ pub fn not_found(kind: &str, location: &Path) {
 }
 
 pub fn check_header() {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("{}:", "Checked".bold());
     } else {
@@ -49,6 +79,9 @@ pub fn check_header() {
 }
 
 pub fn check_count(count: usize, kind: &str) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("  {} {}", count.to_string().cyan().bold(), kind);
     } else {
@@ -56,20 +89,32 @@ pub fn check_count(count: usize, kind: &str) {
     }
 }
 
-pub fn render_summary(count: usize, kind: &str) {
+/// `unchanged` counts artifacts skipped because their rendered file was current.
+pub fn render_summary(count: usize, unchanged: usize, kind: &str) {
+    if is_quiet() {
+        return;
+    }
+    let unchanged = if unchanged > 0 {
+        format!(", {unchanged} unchanged")
+    } else {
+        String::new()
+    };
     if use_colors() {
         eprintln!(
-            "{} Rendered {} {}(s)",
+            "{} Rendered {} {}(s){unchanged}",
             "✓".green(),
             count.to_string().cyan().bold(),
             kind
         );
     } else {
-        eprintln!("✓ Rendered {} {}(s)", count, kind);
+        eprintln!("✓ Rendered {} {}(s){unchanged}", count, kind);
     }
 }
 
 pub fn created_path(path: &Path) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!("{}: {}", "Created".green(), path.display().cyan());
     } else {
@@ -78,6 +123,9 @@ pub fn created_path(path: &Path) {
 }
 
 pub fn changelog_rendered(path: &Path, release_count: usize, unreleased_count: usize) {
+    if is_quiet() {
+        return;
+    }
     if use_colors() {
         eprintln!(
             "Rendered CHANGELOG to {} ({} releases, {} unreleased)",
@@ -96,6 +144,9 @@ pub fn changelog_rendered(path: &Path, release_count: usize, unreleased_count: u
 }
 
 pub fn sub_info(msg: impl Display) {
+    if is_quiet() {
+        return;
+    }
     eprintln!("  {}", msg);
 }
 
@@ -105,35 +156,90 @@ pub fn dry_run_preview(path: &Path) {
     } else {
         eprintln!("Would write: {}", path.display());
     }
-    eprintln!("--- Content preview ---");
 }
 
-pub fn preview_line(line: &str) {
-    eprintln!("{}", line);
+/// `Would write` followed by the unified diff of the change.
+pub fn dry_run_file_diff(path: &Path, diff: &str) {
+    if diff.is_empty() {
+        if use_colors() {
+            eprintln!(
+                "{}: {} (unchanged)",
+                "Would write".yellow(),
+                path.display().cyan()
+            );
+        } else {
+            eprintln!("Would write: {} (unchanged)", path.display());
+        }
+        return;
+    }
+    dry_run_preview(path);
+    for line in diff.lines() {
+        match line.as_bytes().first() {
+            _ if !use_colors() => eprintln!("{line}"),
+            Some(b'@') => eprintln!("{}", line.cyan()),
+            Some(b'+') if !line.starts_with("+++") => eprintln!("{}", line.green()),
+            Some(b'-') if !line.starts_with("---") => eprintln!("{}", line.red()),
+            _ => eprintln!("{}", line.bold()),
+        }
+    }
 }
 
-pub fn preview_truncated() {
-    eprintln!("...");
+pub fn dry_run_mkdir(path: &Path) {
+    if use_colors() {
+        eprintln!("{}: {}", "Would create dir".yellow(), path.display().cyan());
+    } else {
+        eprintln!("Would create dir: {}", path.display());
+    }
 }
 
-pub fn dry_run_file_preview(path: &Path, content: &str) {
+pub fn dry_run_publish(id: &str, title: &str) {
     if use_colors() {
-        eprintln!("{}: {}", "Would write".yellow(), path.display().cyan());
+        eprintln!("{}: {} ({})", "Would publish".yellow(), id.cyan(), title);
     } else {
-        eprintln!("Would write: {}", path.display());
+        eprintln!("Would publish: {} ({})", id, title);
     }
-    for line in content.lines().take(20) {
-        eprintln!("  {}", line);
+}
+
+pub fn dry_run_github_release(repo: &str, tag: &str) {
+    if use_colors() {
+        eprintln!(
+            "{}: {} on {}",
+            "Would draft GitHub release".yellow(),
+            tag.cyan(),
+            repo
+        );
+    } else {
+        eprintln!("Would draft GitHub release: {} on {}", tag, repo);
     }
-    if content.lines().count() > 20 {
-        eprintln!("  ...");
+}
+
+pub fn dry_run_notify(host: &str, event: &str, id: &str) {
+    if use_colors() {
+        eprintln!(
+            "{}: {} ({} {})",
+            "Would notify".yellow(),
+            host.cyan(),
+            event,
+            id
+        );
+    } else {
+        eprintln!("Would notify: {} ({} {})", host, event, id);
     }
 }
 
-pub fn dry_run_mkdir(path: &Path) {
+pub fn dry_run_hook(hook: &str, command: &str) {
     if use_colors() {
-        eprintln!("{}: {}", "Would create dir".yellow(), path.display().cyan());
+        eprintln!(
+            "{}: {} ({})",
+            "Would run hook".yellow(),
+            command.cyan(),
+            hook
+        );
     } else {
-        eprintln!("Would create dir: {}", path.display());
+        eprintln!("Would run hook: {} ({})", command, hook);
     }
 }
+
+pub fn dry_run_delete(path: &Path) {
+    eprintln!("[DRY RUN] Would delete: {}", path.display());
+}
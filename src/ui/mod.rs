@@ -3,12 +3,28 @@
 //! Implements [[ADR-0005]] CLI output color scheme and formatting.
 //!
 //! Provides consistent, colorized output for all CLI commands.
-//! Colors auto-disable when output is not a TTY (agent-friendly).
+//! Colors auto-disable when output is not a TTY (agent-friendly), and
+//! `--for agent` switches diagnostics to a machine-stable layout.
+
+/// `std::eprintln!` that lifts an active progress bar off the screen first,
+/// so messages printed mid-command do not tear through it.
+macro_rules! eprintln {
+    ($($arg:tt)*) => {
+        $crate::ui::progress::suspend(|| std::eprintln!($($arg)*))
+    };
+}
 
 mod color;
 mod diagnostics;
 mod messages;
+mod persona;
+mod progress;
+mod timings;
+mod verbosity;
 
-pub use color::{path_str, stdout_supports_color};
+pub use color::{init_color, path_str, stdout_supports_color, terminal_width};
 pub use diagnostics::diagnostic;
 pub use messages::*;
+pub use progress::{Progress, progress};
+pub use timings::{init_timings, report_timings, timed};
+pub use verbosity::{Verbosity, init_verbosity, is_quiet, quietly};
@@ -0,0 +1,29 @@
+//! Output persona for `--for`.
+//!
+//! The human persona (default) gets colored prose. The agent persona gets
+//! uncolored, line-oriented diagnostics that spell out the exact commands
+//! that fix them, so callers can act on output without parsing prose.
+
+use crate::Persona;
+
+impl Persona {
+    /// Resolve `--for`, falling back to `GOVCTL_FOR` and then the human persona.
+    pub fn resolve(choice: Option<Persona>) -> Self {
+        choice.unwrap_or_else(|| {
+            match std::env::var("GOVCTL_FOR")
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase()
+                .as_str()
+            {
+                "agent" => Persona::Agent,
+                _ => Persona::Human,
+            }
+        })
+    }
+
+    /// Whether output is addressed to an agent rather than a person.
+    pub fn is_agent(self) -> bool {
+        self == Persona::Agent
+    }
+}
@@ -0,0 +1,129 @@
+//! Progress for long-running commands such as `check` and `render all`.
+//!
+//! On a terminal, progress is a spinner and bar on stderr, cleared when the
+//! work is done. Otherwise a plain `<label>: <done>/<total>` line is written
+//! at most every few seconds, so logs of a slow run show it is still moving
+//! while fast runs print nothing. Quiet mode, `-v` logging, and `--for agent`
+//! turn progress off.
+
+use super::verbosity::is_normal;
+use crate::Persona;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::cell::Cell;
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How often plain progress lines are written.
+const PLAIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The bar on screen, which other stderr output has to draw around.
+static ACTIVE: Mutex<Option<ProgressBar>> = Mutex::new(None);
+
+/// Run `print` with the active bar, if any, lifted off the screen.
+pub(super) fn suspend<R>(print: impl FnOnce() -> R) -> R {
+    let active = ACTIVE.lock().ok().and_then(|active| active.clone());
+    match active {
+        Some(bar) => bar.suspend(print),
+        None => print(),
+    }
+}
+
+/// Progress through `total` steps of one piece of work.
+pub struct Progress {
+    display: Display,
+    label: String,
+    total: usize,
+    done: Cell<usize>,
+}
+
+enum Display {
+    Hidden,
+    Bar(ProgressBar),
+    Plain { last_report: Cell<Instant> },
+}
+
+/// Start reporting progress to `persona` through `total` steps, described by
+/// `label`.
+pub fn progress(persona: Persona, label: impl Into<String>, total: usize) -> Progress {
+    let label = label.into();
+    let display = if !is_normal() || persona.is_agent() || total == 0 {
+        Display::Hidden
+    } else if std::io::stderr().is_terminal() {
+        Display::Bar(progress_bar(&label, total))
+    } else {
+        Display::Plain {
+            last_report: Cell::new(Instant::now()),
+        }
+    };
+    Progress {
+        display,
+        label,
+        total,
+        done: Cell::new(0),
+    }
+}
+
+fn progress_bar(label: &str, total: usize) -> ProgressBar {
+    let bar = ProgressBar::with_draw_target(
+        Some(u64::try_from(total).unwrap_or(u64::MAX)),
+        ProgressDrawTarget::stderr(),
+    );
+    if let Ok(style) =
+        ProgressStyle::with_template("{spinner} {prefix} [{bar:30}] {pos}/{len} {wide_msg}")
+    {
+        bar.set_style(style.progress_chars("=> "));
+    }
+    bar.set_prefix(label.to_string());
+    bar.enable_steady_tick(Duration::from_millis(100));
+    if let Ok(mut active) = ACTIVE.lock() {
+        *active = Some(bar.clone());
+    }
+    bar
+}
+
+impl Progress {
+    /// Progress that is never shown, for callers that own the terminal.
+    pub fn hidden() -> Self {
+        Progress {
+            display: Display::Hidden,
+            label: String::new(),
+            total: 0,
+            done: Cell::new(0),
+        }
+    }
+
+    /// Name the step now under way, such as the artifact being rendered.
+    pub fn step(&self, message: impl Into<String>) {
+        if let Display::Bar(bar) = &self.display {
+            bar.set_message(message.into());
+        }
+    }
+
+    /// Mark one step done.
+    pub fn inc(&self) {
+        let done = self.done.get() + 1;
+        self.done.set(done);
+        match &self.display {
+            Display::Hidden => {}
+            Display::Bar(bar) => bar.inc(1),
+            Display::Plain { last_report } => {
+                if last_report.get().elapsed() >= PLAIN_INTERVAL && done < self.total {
+                    std::eprintln!("{}: {done}/{}", self.label, self.total);
+                    last_report.set(Instant::now());
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if let Display::Bar(bar) = &self.display {
+            bar.finish_and_clear();
+            if let Ok(mut active) = ACTIVE.lock() {
+                *active = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! `--timings`: how long each phase of a command took.
+//!
+//! Phases are recorded with [`timed`] while the command runs and printed to
+//! stderr by [`report_timings`] once it finishes. Nested phases (loading the
+//! project inside `check`) are indented under the phase that ran them, and a
+//! phase that runs repeatedly under the same parent is summed into one row
+//! with its call count. Without `--timings`, [`timed`] only logs the
+//! duration at `-v`.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+struct Phase {
+    depth: usize,
+    name: String,
+    calls: usize,
+    elapsed: Duration,
+}
+
+struct Recorder {
+    phases: Vec<Phase>,
+    depth: usize,
+}
+
+impl Recorder {
+    /// Earlier row for `name` under the currently running parent phase.
+    fn sibling(&self, name: &str) -> Option<usize> {
+        for (slot, phase) in self.phases.iter().enumerate().rev() {
+            if phase.depth < self.depth {
+                return None;
+            }
+            if phase.depth == self.depth && phase.name == name {
+                return Some(slot);
+            }
+        }
+        None
+    }
+}
+
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// Start recording phases for `--timings`.
+pub fn init_timings(enabled: bool) {
+    if !enabled {
+        return;
+    }
+    if let Ok(mut recorder) = RECORDER.lock() {
+        *recorder = Some(Recorder {
+            phases: Vec::new(),
+            depth: 0,
+        });
+    }
+}
+
+/// Run `run` as the named phase, recording its duration.
+pub fn timed<T>(phase: &str, run: impl FnOnce() -> T) -> T {
+    let slot = with_recorder(|recorder| {
+        let slot = recorder.sibling(phase).unwrap_or_else(|| {
+            recorder.phases.push(Phase {
+                depth: recorder.depth,
+                name: phase.to_string(),
+                calls: 0,
+                elapsed: Duration::ZERO,
+            });
+            recorder.phases.len() - 1
+        });
+        recorder.depth += 1;
+        slot
+    });
+    let started = Instant::now();
+    let result = run();
+    let elapsed = started.elapsed();
+    tracing::debug!("{phase} took {elapsed:?}");
+    if let Some(slot) = slot {
+        with_recorder(|recorder| {
+            recorder.depth = recorder.depth.saturating_sub(1);
+            if let Some(entry) = recorder.phases.get_mut(slot) {
+                entry.calls += 1;
+                entry.elapsed += elapsed;
+            }
+        });
+    }
+    result
+}
+
+/// Print the recorded phases to stderr, with the whole run as the last line.
+pub fn report_timings(total: Duration) {
+    let Some(phases) = with_recorder(|recorder| std::mem::take(&mut recorder.phases)) else {
+        return;
+    };
+    let rows: Vec<(String, Duration)> = phases
+        .into_iter()
+        .filter(|phase| phase.calls > 0)
+        .map(|phase| {
+            let indent = "  ".repeat(phase.depth + 1);
+            let label = match phase.calls {
+                1 => format!("{indent}{}", phase.name),
+                calls => format!("{indent}{} (x{calls})", phase.name),
+            };
+            (label, phase.elapsed)
+        })
+        .chain(std::iter::once(("total".to_string(), total)))
+        .collect();
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+
+    eprintln!("Timings:");
+    for (label, elapsed) in rows {
+        eprintln!("{label:<width$}  {}", format_duration(elapsed));
+    }
+}
+
+fn with_recorder<T>(f: impl FnOnce(&mut Recorder) -> T) -> Option<T> {
+    RECORDER.lock().ok()?.as_mut().map(f)
+}
+
+fn format_duration(elapsed: Duration) -> String {
+    let millis = elapsed.as_secs_f64() * 1000.0;
+    if millis >= 1000.0 {
+        format!("{:>9.2}s", millis / 1000.0)
+    } else {
+        format!("{millis:>8.1}ms")
+    }
+}
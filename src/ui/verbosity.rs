@@ -0,0 +1,75 @@
+//! Output verbosity for `-q` and `-v`.
+//!
+//! Quiet mode drops success and progress messages so scripts only see
+//! results, previews, and diagnostics. Verbose levels turn on `tracing`
+//! events written to stderr: `-v` shows file writes, lock acquisition, and
+//! validation timing; `-vv` also shows every file read.
+
+use super::color::use_colors;
+use std::sync::atomic::{AtomicU8, Ordering};
+use tracing::level_filters::LevelFilter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+    Trace,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+impl Verbosity {
+    /// Level selected by `-q` and the number of `-v` flags.
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::Trace,
+        }
+    }
+
+    fn level_filter(self) -> LevelFilter {
+        match self {
+            Verbosity::Quiet | Verbosity::Normal => LevelFilter::OFF,
+            Verbosity::Verbose => LevelFilter::DEBUG,
+            Verbosity::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// Set the process-wide verbosity and install the stderr log subscriber.
+pub fn init_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+    if verbosity > Verbosity::Normal {
+        // Only fails if a subscriber is already installed, which keeps it.
+        let _ = tracing_subscriber::fmt()
+            .with_writer(std::io::stderr)
+            .with_max_level(verbosity.level_filter())
+            .with_ansi(use_colors())
+            .with_target(false)
+            .without_time()
+            .try_init();
+    }
+}
+
+/// Run `f` with success and progress messages suppressed, for callers such
+/// as the TUI that own the terminal while running a command.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let previous = VERBOSITY.swap(Verbosity::Quiet as u8, Ordering::Relaxed);
+    let result = f();
+    VERBOSITY.store(previous, Ordering::Relaxed);
+    result
+}
+
+/// Whether neither `-q` nor `-v` is in effect.
+pub(super) fn is_normal() -> bool {
+    VERBOSITY.load(Ordering::Relaxed) == Verbosity::Normal as u8
+}
+
+/// Whether success and progress messages are suppressed.
+pub fn is_quiet() -> bool {
+    VERBOSITY.load(Ordering::Relaxed) == Verbosity::Quiet as u8
+}
@@ -47,6 +47,12 @@ fn reserved_headings(adr: &AdrEntry) -> Vec<String> {
         .iter()
         .map(|heading| (*heading).to_string())
         .collect::<Vec<_>>();
+    if !adr.spec.content.drivers.is_empty() {
+        headings.push("Decision Drivers".to_string());
+    }
+    if !adr.spec.content.stakeholders.is_empty() {
+        headings.push("Stakeholders".to_string());
+    }
     headings.extend(heading_texts(&format!(
         "# {}: {}",
         adr.meta().id,
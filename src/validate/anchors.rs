@@ -0,0 +1,41 @@
+use super::ValidationResult;
+use crate::artifact_index::is_valid_anchor;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::model::ProjectIndex;
+use std::collections::HashMap;
+
+/// Validate clause anchors: each is well-formed and declared by one clause,
+/// so `[[<ANCHOR>]]` resolves to exactly one clause.
+pub(super) fn validate_anchors(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    let mut owners: HashMap<&str, String> = HashMap::new();
+    for (rfc, clause) in index.iter_clauses() {
+        let clause_id = format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id);
+        let path = config.display_path(&clause.path).display().to_string();
+        for anchor in &clause.spec.anchors {
+            if !is_valid_anchor(&config.prefixes, anchor) {
+                result.diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::E0214ClauseAnchorInvalid,
+                    format!(
+                        "Anchor '{anchor}' on {clause_id} must be uppercase words joined by hyphens, e.g. CACHE-TTL, and not an artifact ID"
+                    ),
+                    path.clone(),
+                ));
+                continue;
+            }
+            if let Some(owner) = owners.get(anchor.as_str()) {
+                result.diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::E0215ClauseAnchorDuplicate,
+                    format!("Anchor '{anchor}' on {clause_id} is already declared by {owner}"),
+                    path.clone(),
+                ));
+            } else {
+                owners.insert(anchor, clause_id.clone());
+            }
+        }
+    }
+}
@@ -2,9 +2,11 @@ use super::ValidationResult;
 use super::reference_hierarchy::{ReferenceSurface, check_ref_hierarchy};
 use crate::artifact_index::artifact_ref_ids;
 use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Span};
 use crate::model::ProjectIndex;
+use crate::remotes::{RemoteIndex, RemoteLookup, remote_not_configured, split_remote_ref};
 use std::collections::HashSet;
+use std::path::Path;
 
 /// Validate refs fields in RFCs, ADRs and Work Items
 pub(super) fn validate_artifact_refs(
@@ -13,14 +15,30 @@ pub(super) fn validate_artifact_refs(
     result: &mut ValidationResult,
 ) {
     let known_ids = artifact_ref_ids(index);
+    let remotes = RemoteIndex::for_refs(
+        config,
+        index
+            .rfcs
+            .iter()
+            .flat_map(|rfc| &rfc.rfc.refs)
+            .chain(index.adrs.iter().flat_map(|adr| &adr.meta().refs))
+            .chain(index.work_items.iter().flat_map(|work| &work.meta().refs)),
+    );
+    result
+        .diagnostics
+        .extend(remotes.unavailable_diagnostics(config));
 
     // Validate RFC refs and supersedes
     for rfc in &index.rfcs {
         let rfc_path_display = config.display_path(&rfc.path).display().to_string();
         let rfc_ref_check = RefCheck {
             known_ids: &known_ids,
+            remotes: &remotes,
+            config,
             owner_id: &rfc.rfc.rfc_id,
+            path: &rfc.path,
             path_display: &rfc_path_display,
+            field: "/govctl/refs",
             unknown_code: DiagnosticCode::E0105RfcRefNotFound,
             check_hierarchy: true,
         };
@@ -35,8 +53,12 @@ pub(super) fn validate_artifact_refs(
         if let Some(ref supersedes) = rfc.rfc.supersedes {
             let supersedes_check = RefCheck {
                 known_ids: &known_ids,
+                remotes: &remotes,
+                config,
                 owner_id: &rfc.rfc.rfc_id,
+                path: &rfc.path,
                 path_display: &rfc_path_display,
+                field: "/govctl/supersedes",
                 unknown_code: DiagnosticCode::E0106RfcSupersedesNotFound,
                 check_hierarchy: true,
             };
@@ -59,17 +81,28 @@ pub(super) fn validate_artifact_refs(
         let adr_path_display = config.display_path(&adr.path).display().to_string();
         let adr_ref_check = RefCheck {
             known_ids: &known_ids,
+            remotes: &remotes,
+            config,
             owner_id: &adr.meta().id,
+            path: &adr.path,
             path_display: &adr_path_display,
+            field: "/govctl/refs",
             unknown_code: DiagnosticCode::E0304AdrRefNotFound,
             check_hierarchy: true,
         };
         validate_refs(result, adr_ref_check, &adr.meta().refs, |ref_id| {
-            format!(
-                "ADR '{}' references unknown artifact: {}",
-                adr.meta().id,
-                ref_id
-            )
+            match ref_id.split_once(':') {
+                // The RFC is there, so the clause the decision affects is gone.
+                Some((rfc_id, _)) if known_ids.contains(rfc_id) => format!(
+                    "ADR '{}' references clause {ref_id}, which {rfc_id} no longer has (hint: point the ref at the clause that replaced it, or remove it)",
+                    adr.meta().id
+                ),
+                _ => format!(
+                    "ADR '{}' references unknown artifact: {}",
+                    adr.meta().id,
+                    ref_id
+                ),
+            }
         });
     }
 
@@ -78,8 +111,12 @@ pub(super) fn validate_artifact_refs(
         let work_path_display = config.display_path(&work.path).display().to_string();
         let work_ref_check = RefCheck {
             known_ids: &known_ids,
+            remotes: &remotes,
+            config,
             owner_id: &work.meta().id,
+            path: &work.path,
             path_display: &work_path_display,
+            field: "/govctl/refs",
             unknown_code: DiagnosticCode::E0404WorkRefNotFound,
             check_hierarchy: false,
         };
@@ -110,15 +147,40 @@ pub fn validate_artifact_ref_edit(
             diagnostics.remove(0)
         }
     })?;
+    if let Some((remote, id)) = split_remote_ref(ref_id) {
+        let remotes = RemoteIndex::for_refs(config, [&ref_id.to_string()]);
+        return match remotes.lookup(remote, id) {
+            RemoteLookup::NotConfigured => Err(remote_not_configured(
+                config,
+                owner_id,
+                ref_id,
+                diagnostic_path,
+            )),
+            RemoteLookup::Missing => Err(Diagnostic::new(
+                unknown_ref_code(config, owner_id),
+                unknown_ref_message(config, owner_id, ref_id),
+                diagnostic_path,
+            )),
+            // Unreadable remotes are reported by `check`; don't block the edit.
+            RemoteLookup::Found | RemoteLookup::Unavailable(_) => check_ref_hierarchy(
+                &config.prefixes,
+                owner_id,
+                ref_id,
+                diagnostic_path,
+                ReferenceSurface::StructuredRef,
+            ),
+        };
+    }
     let known_ids = artifact_ref_ids(&index);
     if !known_ids.contains(ref_id) {
         return Err(Diagnostic::new(
-            unknown_ref_code(owner_id),
-            unknown_ref_message(owner_id, ref_id),
+            unknown_ref_code(config, owner_id),
+            unknown_ref_message(config, owner_id, ref_id),
             diagnostic_path,
         ));
     }
     check_ref_hierarchy(
+        &config.prefixes,
         owner_id,
         ref_id,
         diagnostic_path,
@@ -129,8 +191,13 @@ pub fn validate_artifact_ref_edit(
 #[derive(Clone, Copy)]
 struct RefCheck<'a> {
     known_ids: &'a HashSet<String>,
+    remotes: &'a RemoteIndex,
+    config: &'a Config,
     owner_id: &'a str,
+    path: &'a Path,
     path_display: &'a str,
+    /// JSON pointer of the checked field in the artifact file.
+    field: &'a str,
     unknown_code: DiagnosticCode,
     check_hierarchy: bool,
 }
@@ -144,15 +211,35 @@ fn validate_refs<'a, I, F>(
     I: IntoIterator<Item = &'a String>,
     F: Fn(&str) -> String,
 {
-    for ref_id in refs {
-        if !check.known_ids.contains(ref_id) {
-            result.diagnostics.push(Diagnostic::new(
-                check.unknown_code,
-                unknown_message(ref_id),
-                check.path_display.to_string(),
-            ));
+    for (position, ref_id) in refs.into_iter().enumerate() {
+        let known = match split_remote_ref(ref_id) {
+            Some((remote, id)) => match check.remotes.lookup(remote, id) {
+                RemoteLookup::NotConfigured => {
+                    result.diagnostics.push(remote_not_configured(
+                        check.config,
+                        check.owner_id,
+                        ref_id,
+                        check.path_display,
+                    ));
+                    continue;
+                }
+                RemoteLookup::Missing => false,
+                RemoteLookup::Found | RemoteLookup::Unavailable(_) => true,
+            },
+            None => check.known_ids.contains(ref_id),
+        };
+        if !known {
+            result.diagnostics.push(
+                Diagnostic::new(
+                    check.unknown_code,
+                    unknown_message(ref_id),
+                    check.path_display.to_string(),
+                )
+                .with_span(ref_span(&check, position)),
+            );
         } else if check.check_hierarchy
             && let Err(diagnostic) = check_ref_hierarchy(
+                &check.config.prefixes,
                 check.owner_id,
                 ref_id,
                 check.path_display,
@@ -164,20 +251,28 @@ fn validate_refs<'a, I, F>(
     }
 }
 
-fn unknown_ref_code(owner_id: &str) -> DiagnosticCode {
-    if owner_id.starts_with("RFC-") {
+/// Where the `position`th entry of the checked field sits in the artifact
+/// file; a single-valued field points at the value itself.
+fn ref_span(check: &RefCheck<'_>, position: usize) -> Option<Span> {
+    let source = std::fs::read_to_string(check.path).ok()?;
+    Span::of_toml_pointer(&source, &format!("{}/{position}", check.field))
+        .or_else(|| Span::of_toml_pointer(&source, check.field))
+}
+
+fn unknown_ref_code(config: &Config, owner_id: &str) -> DiagnosticCode {
+    if config.prefixes.is_rfc(owner_id) {
         DiagnosticCode::E0105RfcRefNotFound
-    } else if owner_id.starts_with("ADR-") {
+    } else if config.prefixes.is_adr(owner_id) {
         DiagnosticCode::E0304AdrRefNotFound
     } else {
         DiagnosticCode::E0404WorkRefNotFound
     }
 }
 
-fn unknown_ref_message(owner_id: &str, ref_id: &str) -> String {
-    if owner_id.starts_with("RFC-") {
+fn unknown_ref_message(config: &Config, owner_id: &str, ref_id: &str) -> String {
+    if config.prefixes.is_rfc(owner_id) {
         format!("RFC '{owner_id}' references unknown artifact: {ref_id}")
-    } else if owner_id.starts_with("ADR-") {
+    } else if config.prefixes.is_adr(owner_id) {
         format!("ADR '{owner_id}' references unknown artifact: {ref_id}")
     } else {
         format!("Work item '{owner_id}' references unknown artifact: {ref_id}")
@@ -0,0 +1,84 @@
+use super::ValidationResult;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::ProjectIndex;
+use std::path::{Component, Path, PathBuf};
+
+/// Resolve an attachment to its file under `gov/assets/`. The path must be
+/// relative and stay inside the assets directory.
+pub(crate) fn attachment_path(
+    config: &Config,
+    attachment: &str,
+    source: &str,
+) -> DiagnosticResult<PathBuf> {
+    let relative = Path::new(attachment);
+    if attachment.trim().is_empty()
+        || !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E1702AttachmentPathInvalid,
+            format!("Invalid attachment '{attachment}': expected a path relative to gov/assets/"),
+            source,
+        ));
+    }
+    Ok(config.assets_dir().join(relative))
+}
+
+/// Check an attachment before it is added: the path must be valid and, unless
+/// the write is only previewed, the file must exist.
+pub(crate) fn validate_attachment(
+    config: &Config,
+    attachment: &str,
+    source: &str,
+    require_file: bool,
+) -> DiagnosticResult<()> {
+    let path = attachment_path(config, attachment, source)?;
+    if require_file && !path.is_file() {
+        return Err(missing(config, attachment, &path, source));
+    }
+    Ok(())
+}
+
+/// Validate that every attachment an RFC, ADR, or work item lists exists.
+pub(super) fn validate_attachments(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    let mut check = |attachments: &[String], source: &str| {
+        for attachment in attachments {
+            let diagnostic = match attachment_path(config, attachment, source) {
+                Ok(path) if path.is_file() => continue,
+                Ok(path) => missing(config, attachment, &path, source),
+                Err(diagnostic) => diagnostic,
+            };
+            result.diagnostics.push(diagnostic);
+        }
+    };
+
+    for rfc in &index.rfcs {
+        let path = config.display_path(&rfc.path).display().to_string();
+        check(&rfc.rfc.attachments, &path);
+    }
+    for adr in &index.adrs {
+        let path = config.display_path(&adr.path).display().to_string();
+        check(&adr.meta().attachments, &path);
+    }
+    for work in &index.work_items {
+        let path = config.display_path(&work.path).display().to_string();
+        check(&work.meta().attachments, &path);
+    }
+}
+
+fn missing(config: &Config, attachment: &str, path: &Path, source: &str) -> Diagnostic {
+    Diagnostic::new(
+        DiagnosticCode::E1701AttachmentMissing,
+        format!(
+            "Attachment '{attachment}' not found at {} (hint: `govctl attach add`)",
+            config.display_path(path).display()
+        ),
+        source,
+    )
+}
@@ -1,15 +1,14 @@
 use super::ValidationResult;
 use super::reference_hierarchy::{ReferenceSurface, check_ref_hierarchy};
 use crate::artifact_index::artifact_ref_ids;
-use crate::config::Config;
+use crate::config::{Config, PrefixesConfig};
 use crate::diagnostic::{Diagnostic, DiagnosticCode};
 use crate::model::{AdrStatus, ProjectIndex, RfcStatus, WorkItemStatus};
 use regex::Regex;
 use std::collections::HashSet;
 
-const BARE_ARTIFACT_ID_PATTERN: &str = r"\b(RFC-\d{4}(?::C-[A-Z][A-Z0-9-]*)?|ADR-\d{4}|WI-\d{4}-\d{2}-\d{2}-(?:[a-f0-9]{4}(?:-\d{3})?|\d{3}))\b";
-
 struct ReferenceScanner {
+    prefixes: PrefixesConfig,
     bracket_re: Regex,
     bare_re: Regex,
     known_ids: HashSet<String>,
@@ -38,7 +37,7 @@ pub(super) fn validate_bracket_reference_hierarchy(
             return;
         }
     };
-    let bare_re = match Regex::new(BARE_ARTIFACT_ID_PATTERN) {
+    let bare_re = match Regex::new(&bare_id_pattern(&config.prefixes)) {
         Ok(r) => r,
         Err(e) => {
             result.diagnostics.push(Diagnostic::new(
@@ -50,6 +49,7 @@ pub(super) fn validate_bracket_reference_hierarchy(
         }
     };
     let scanner = ReferenceScanner {
+        prefixes: config.prefixes.clone(),
         bracket_re,
         bare_re,
         known_ids: artifact_ref_ids(index),
@@ -60,11 +60,14 @@ pub(super) fn validate_bracket_reference_hierarchy(
         let rid = rfc.rfc.rfc_id.as_str();
         let warn_on_bare_text = rfc.rfc.status == RfcStatus::Draft;
         for clause in &rfc.clauses {
+            let Some(text) = &clause.spec.text else {
+                continue;
+            };
             let clause_path = config.display_path(&clause.path).display().to_string();
             let field = format!("{} content.text", clause.spec.clause_id);
             scan_rfc_reference_hierarchy(
                 &scanner,
-                &clause.spec.text,
+                text,
                 rid,
                 TextSource {
                     path: &clause_path,
@@ -157,6 +160,22 @@ pub(super) fn validate_bracket_reference_hierarchy(
             warn_on_bare_text,
             result,
         );
+        for (name, items) in [("drivers", &c.drivers), ("stakeholders", &c.stakeholders)] {
+            for (index, item) in items.iter().enumerate() {
+                let field = format!("content.{name}[{index}]");
+                scan_adr_reference_hierarchy(
+                    &scanner,
+                    item,
+                    aid,
+                    TextSource {
+                        path: &adr_path,
+                        field: &field,
+                    },
+                    warn_on_bare_text,
+                    result,
+                );
+            }
+        }
         for (alt_index, alt) in c.alternatives.iter().enumerate() {
             let alt_text_field = format!("content.alternatives[{alt_index}].text");
             scan_adr_reference_hierarchy(
@@ -338,9 +357,13 @@ fn scan_reference_hierarchy(
             continue;
         };
         let target = m.as_str();
-        if let Err(diagnostic) =
-            check_ref_hierarchy(owner_id, target, source.path, ReferenceSurface::BracketLink)
-        {
+        if let Err(diagnostic) = check_ref_hierarchy(
+            &scanner.prefixes,
+            owner_id,
+            target,
+            source.path,
+            ReferenceSurface::BracketLink,
+        ) {
             result.diagnostics.push(diagnostic);
         }
     }
@@ -359,11 +382,21 @@ fn scan_reference_hierarchy(
         {
             continue;
         }
+        // `[[member/ID]]` names another workspace member's artifact.
+        if text[..m.start()].ends_with('/') {
+            continue;
+        }
         let target = m.as_str();
         if !scanner.known_ids.contains(target) {
             continue;
         }
-        match check_ref_hierarchy(owner_id, target, source.path, ReferenceSurface::BareText) {
+        match check_ref_hierarchy(
+            &scanner.prefixes,
+            owner_id,
+            target,
+            source.path,
+            ReferenceSurface::BareText,
+        ) {
             Ok(()) if warn_on_bare_text => result.diagnostics.push(
                 bare_artifact_reference_warning(owner_id, target, source, text, m.start()),
             ),
@@ -418,16 +451,19 @@ fn truncate_context(context: &str) -> String {
     out.replace('"', "\\\"")
 }
 
+/// Artifact IDs written without brackets.
+fn bare_id_pattern(prefixes: &PrefixesConfig) -> String {
+    format!(r"\b({})\b", prefixes.id_pattern())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::bracket_ref_pattern;
     use crate::diagnostic::DiagnosticResult;
 
     fn bracket_re() -> DiagnosticResult<Regex> {
-        Regex::new(
-            r"\[\[(RFC-\d{4}(?::C-[A-Z][A-Z0-9-]*)?|ADR-\d{4}|WI-\d{4}-\d{2}-\d{2}-(?:[a-f0-9]{4}(?:-\d{3})?|\d{3}))\]\]",
-        )
-        .map_err(|err| {
+        Regex::new(&bracket_ref_pattern(&PrefixesConfig::default())).map_err(|err| {
             Diagnostic::new(
                 DiagnosticCode::E0903UnexpectedError,
                 format!("test bracket regex must compile: {err}"),
@@ -437,7 +473,7 @@ mod tests {
     }
 
     fn bare_re() -> DiagnosticResult<Regex> {
-        Regex::new(BARE_ARTIFACT_ID_PATTERN).map_err(|err| {
+        Regex::new(&bare_id_pattern(&PrefixesConfig::default())).map_err(|err| {
             Diagnostic::new(
                 DiagnosticCode::E0903UnexpectedError,
                 format!("test bare regex must compile: {err}"),
@@ -448,6 +484,7 @@ mod tests {
 
     fn scanner(known_ids: HashSet<String>) -> DiagnosticResult<ReferenceScanner> {
         Ok(ReferenceScanner {
+            prefixes: PrefixesConfig::default(),
             bracket_re: bracket_re()?,
             bare_re: bare_re()?,
             known_ids,
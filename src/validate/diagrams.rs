@@ -0,0 +1,51 @@
+use super::ValidationResult;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::diagrams::{check, diagram_blocks};
+use crate::model::ProjectIndex;
+
+/// Check fenced `mermaid` and `plantuml` blocks in clause text and ADR
+/// prose, parse-only, per [[RFC-0000:C-CLAUSE-DEF]].
+pub(super) fn validate_diagrams(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    let mut check_text = |text: &str, field: &str, path: &str| {
+        for block in diagram_blocks(text) {
+            if let Err(problem) = check(block.kind, &block.source) {
+                result.diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::W0127DiagramInvalid,
+                    format!(
+                        "Invalid {} diagram in {field} (block at line {}): {problem}",
+                        block.kind.as_str(),
+                        block.line
+                    ),
+                    path,
+                ));
+            }
+        }
+    };
+
+    for (_, clause) in index.iter_clauses() {
+        let path = config.display_path(&clause.path).display().to_string();
+        if let Some(text) = &clause.spec.text {
+            check_text(text, "text", &path);
+        }
+    }
+
+    for adr in &index.adrs {
+        let path = config.display_path(&adr.path).display().to_string();
+        let content = &adr.spec.content;
+        for (field, text) in [
+            ("context", &content.context),
+            ("decision", &content.decision),
+            ("consequences", &content.consequences),
+        ] {
+            // Sealed sensitive fields cannot be read without the key.
+            if !crate::secrets::is_sealed(text) {
+                check_text(text, field, &path);
+            }
+        }
+    }
+}
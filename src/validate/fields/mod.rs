@@ -178,7 +178,7 @@ fn validate_artifact_ref(ctx: &ValidationContext, ref_id: &str) -> DiagnosticRes
     use crate::load::find_rfc_toml;
     use crate::parse::{load_adrs, load_work_items};
 
-    if ref_id.starts_with("RFC-") {
+    if ctx.config.prefixes.is_rfc(ref_id) {
         if find_rfc_toml(ctx.config, ref_id).is_none() {
             return Err(Diagnostic::new(
                 DiagnosticCode::E0102RfcNotFound,
@@ -186,7 +186,7 @@ fn validate_artifact_ref(ctx: &ValidationContext, ref_id: &str) -> DiagnosticRes
                 ref_id,
             ));
         }
-    } else if ref_id.starts_with("ADR-") {
+    } else if ctx.config.prefixes.is_adr(ref_id) {
         let adrs = load_adrs(ctx.config)?;
         if !adrs.iter().any(|a| a.spec.govctl.id == ref_id) {
             return Err(Diagnostic::new(
@@ -213,6 +213,7 @@ fn validate_artifact_ref(ctx: &ValidationContext, ref_id: &str) -> DiagnosticRes
     }
 
     check_ref_hierarchy(
+        &ctx.config.prefixes,
         ctx.artifact_id,
         ref_id,
         ctx.artifact_id,
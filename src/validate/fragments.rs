@@ -0,0 +1,31 @@
+use super::ValidationResult;
+use crate::config::Config;
+use crate::diagnostic::Diagnostic;
+use crate::model::ProjectIndex;
+
+/// Validate that every `{{include:<ID>}}` in clause text names a fragment
+/// under `gov/fragments/` and fills its placeholders, and that every
+/// `{{var.<name>}}` in clause and ADR text is defined under `[variables]`.
+pub(super) fn validate_substitutions(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    let mut report = |diag: Diagnostic, path: &std::path::Path| {
+        let path = config.display_path(path).display().to_string();
+        result
+            .diagnostics
+            .push(Diagnostic::new(diag.code, diag.message, path));
+    };
+
+    for (rfc, clause) in index.iter_clauses() {
+        if let Err(diag) = crate::fragments::expand_clause(config, &rfc.rfc.rfc_id, clause) {
+            report(diag, &clause.path);
+        }
+    }
+    for adr in &index.adrs {
+        if let Err(diag) = crate::fragments::expand_adr(config, adr) {
+            report(diag, &adr.path);
+        }
+    }
+}
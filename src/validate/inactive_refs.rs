@@ -0,0 +1,106 @@
+//! `refs` entries that point at deprecated or superseded artifacts.
+
+use super::ValidationResult;
+use super::sunset::{live_referrers, passed_sunset, targets};
+use super::supersession::SupersessionGraph;
+use crate::artifact_index::{ArtifactRefState, artifact_ref_states};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::model::ProjectIndex;
+use chrono::Local;
+use std::collections::{BTreeSet, HashMap};
+
+/// W0123 for every ref from a live artifact to an artifact that is no
+/// longer in force. Refs to artifacts past their sunset are left to W0120;
+/// an RFC may cite its own clauses, a replacement anything earlier in its
+/// supersession chain, and an ADR the decision it amended.
+pub(super) fn validate_inactive_refs(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    let states = artifact_ref_states(index);
+    let graph = SupersessionGraph::build(index, config);
+    let today = Local::now().date_naive();
+    let adr_dates: HashMap<&str, &str> = index
+        .adrs
+        .iter()
+        .map(|adr| (adr.meta().id.as_str(), adr.meta().date.as_str()))
+        .collect();
+    let sunset_passed: Vec<String> = index
+        .rfcs
+        .iter()
+        .flat_map(|rfc| {
+            let clauses = rfc.clauses.iter().filter_map(|clause| {
+                passed_sunset(clause.spec.deprecation.as_ref(), today)
+                    .map(|_| format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id))
+            });
+            passed_sunset(rfc.rfc.deprecation.as_ref(), today)
+                .map(|_| rfc.rfc.rfc_id.clone())
+                .into_iter()
+                .chain(clauses)
+        })
+        .chain(index.adrs.iter().filter_map(|adr| {
+            passed_sunset(adr.meta().deprecation.as_ref(), today).map(|_| adr.meta().id.clone())
+        }))
+        .collect();
+
+    for (owner, refs, path) in live_referrers(index) {
+        for ref_id in refs {
+            let Some(ArtifactRefState::Outdated(reason)) = states.get(ref_id) else {
+                continue;
+            };
+            if targets(ref_id, owner)
+                || replaces(&graph, owner, ref_id)
+                || amends(&graph, &adr_dates, owner, ref_id)
+                || sunset_passed.iter().any(|id| targets(ref_id, id))
+            {
+                continue;
+            }
+            result.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::W0123RefInactive,
+                format!(
+                    "{owner} references {ref_id}, which is {reason} (hint: point the ref at its replacement or remove it)"
+                ),
+                config.display_path(path).display().to_string(),
+            ));
+        }
+    }
+}
+
+/// Whether `owner` follows `ref_id` in its supersession chain, directly or
+/// through intermediate replacements.
+fn replaces(graph: &SupersessionGraph, owner: &str, ref_id: &str) -> bool {
+    let mut seen = BTreeSet::new();
+    let mut pending = vec![ref_id];
+    while let Some(id) = pending.pop() {
+        for next in graph.successors(id) {
+            if targets(next, owner) {
+                return true;
+            }
+            if seen.insert(next.as_str()) {
+                pending.push(next);
+            }
+        }
+    }
+    false
+}
+
+/// Whether the ADR `owner` amended the ADR `ref_id` while it was in force:
+/// it is dated no later than every ADR that replaced `ref_id`.
+fn amends(
+    graph: &SupersessionGraph,
+    adr_dates: &HashMap<&str, &str>,
+    owner: &str,
+    ref_id: &str,
+) -> bool {
+    let (Some(owner_date), replacements) = (adr_dates.get(owner), graph.successors(ref_id)) else {
+        return false;
+    };
+    !replacements.is_empty()
+        && replacements.iter().all(|next| {
+            adr_dates
+                .get(next.as_str())
+                .is_some_and(|date| owner_date <= date)
+        })
+}
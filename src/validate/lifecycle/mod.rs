@@ -41,6 +41,17 @@ pub fn is_valid_work_transition(from: WorkItemStatus, to: WorkItemStatus) -> boo
             | (WorkItemStatus::Done, WorkItemStatus::Active)
             | (WorkItemStatus::Queue, WorkItemStatus::Cancelled)
             | (WorkItemStatus::Active, WorkItemStatus::Cancelled)
+            | (WorkItemStatus::Cancelled, WorkItemStatus::Queue)
+    )
+}
+
+/// Check if a Work Item transition reopens a terminal status, which needs an
+/// explicit `--reopen --reason`.
+pub fn is_work_reopen(from: WorkItemStatus, to: WorkItemStatus) -> bool {
+    matches!(
+        (from, to),
+        (WorkItemStatus::Done, WorkItemStatus::Active)
+            | (WorkItemStatus::Cancelled, WorkItemStatus::Queue)
     )
 }
 
@@ -212,15 +212,36 @@ fn test_work_status_invalid_done_transitions() {
     ));
 }
 
+#[test]
+fn test_work_status_cancelled_to_queue() {
+    assert!(is_valid_work_transition(
+        WorkItemStatus::Cancelled,
+        WorkItemStatus::Queue
+    ));
+}
+
 #[test]
 fn test_work_status_invalid_cancelled_transitions() {
-    // Cancelled is terminal.
+    // A cancelled item can only be requeued.
     assert!(!is_valid_work_transition(
         WorkItemStatus::Cancelled,
         WorkItemStatus::Active
     ));
     assert!(!is_valid_work_transition(
+        WorkItemStatus::Cancelled,
+        WorkItemStatus::Done
+    ));
+}
+
+#[test]
+fn test_work_reopen_transitions() {
+    assert!(is_work_reopen(WorkItemStatus::Done, WorkItemStatus::Active));
+    assert!(is_work_reopen(
         WorkItemStatus::Cancelled,
         WorkItemStatus::Queue
     ));
+    assert!(!is_work_reopen(
+        WorkItemStatus::Queue,
+        WorkItemStatus::Active
+    ));
 }
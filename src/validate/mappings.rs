@@ -0,0 +1,98 @@
+//! Compliance mappings from external standard controls to clauses, per
+//! [[RFC-0002:C-RESOURCES]].
+
+use crate::artifact_index::{ArtifactRefState, artifact_ref_states};
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::model::{MappingEntry, ProjectIndex};
+use std::collections::{HashMap, HashSet};
+
+/// E1402/E1403 for every mapped clause that is missing or no longer active,
+/// and E1404 for standards or controls declared twice.
+pub fn validate_mappings(
+    mappings: &[MappingEntry],
+    index: &ProjectIndex,
+    config: &Config,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let states = artifact_ref_states(index);
+    let mut standards: HashMap<&str, &MappingEntry> = HashMap::new();
+
+    for mapping in mappings {
+        let standard = mapping.meta().id.as_str();
+        let display = config.display_path(&mapping.path).display().to_string();
+        if let Some(first) = standards.insert(standard, mapping) {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticCode::E1404MappingDuplicate,
+                format!(
+                    "Standard '{standard}' is also mapped in {}",
+                    config.display_path(&first.path).display()
+                ),
+                display.clone(),
+            ));
+        }
+
+        let mut controls = HashSet::new();
+        for control in &mapping.spec.controls {
+            if !controls.insert(control.id.as_str()) {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticCode::E1404MappingDuplicate,
+                    format!(
+                        "Control '{standard} {}' is listed more than once",
+                        control.id
+                    ),
+                    display.clone(),
+                ));
+            }
+            for clause in &control.clauses {
+                if let Err(diag) =
+                    require_active_clause(&states, standard, &control.id, clause, &display)
+                {
+                    diagnostics.push(diag);
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// `covered` when every clause mapped to a control is active, `gap` otherwise.
+pub(crate) fn control_coverage(
+    states: &HashMap<String, ArtifactRefState>,
+    clauses: &[String],
+) -> &'static str {
+    if clauses
+        .iter()
+        .all(|clause| states.get(clause) == Some(&ArtifactRefState::Active))
+    {
+        "covered"
+    } else {
+        "gap"
+    }
+}
+
+/// Fail unless `clause` names an active clause of a live RFC.
+pub(crate) fn require_active_clause(
+    states: &HashMap<String, ArtifactRefState>,
+    standard: &str,
+    control: &str,
+    clause: &str,
+    location: &str,
+) -> Result<(), Diagnostic> {
+    match states.get(clause) {
+        Some(ArtifactRefState::Active) => Ok(()),
+        Some(ArtifactRefState::Outdated(reason)) => Err(Diagnostic::new(
+            DiagnosticCode::E1403MappingClauseInactive,
+            format!(
+                "Control '{standard} {control}' is mapped to {clause}, which is {reason} (hint: map it to the replacement clause)"
+            ),
+            location,
+        )),
+        None => Err(Diagnostic::new(
+            DiagnosticCode::E1402MappingClauseNotFound,
+            format!("Control '{standard} {control}' is mapped to unknown clause {clause}"),
+            location,
+        )),
+    }
+}
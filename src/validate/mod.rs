@@ -10,35 +10,60 @@ use crate::diagnostic::{Diagnostic, DiagnosticCode};
 use crate::model::{AdrStatus, ProjectIndex};
 
 mod adr_projection;
+mod anchors;
 mod artifact_refs;
+mod attachments;
 mod bracket_refs;
+mod diagrams;
 mod fields;
+mod fragments;
+mod inactive_refs;
 mod lifecycle;
+mod mappings;
+mod owners;
 mod reference_hierarchy;
 mod releases;
 mod rfc;
 mod signatures;
+mod staleness;
+mod sunset;
+mod supersession;
 mod tags;
 mod work_dependencies;
 mod work_items;
 
 use adr_projection::validate_adr_projection_ownership;
+use anchors::validate_anchors;
 use artifact_refs::validate_artifact_refs;
+use attachments::validate_attachments;
 use bracket_refs::validate_bracket_reference_hierarchy;
+use diagrams::validate_diagrams;
+use fragments::validate_substitutions;
+use inactive_refs::validate_inactive_refs;
+use owners::validate_rfc_owners;
 use rfc::{validate_clause_references, validate_rfc};
 use signatures::validate_rfc_signatures;
+use staleness::validate_staleness;
+use sunset::validate_sunsets;
+use supersession::validate_supersession;
 use tags::validate_artifact_tags;
 use work_items::{validate_work_item_descriptions, validate_work_item_legacy_inline_history};
 
 pub(crate) use adr_projection::validate_adr_projection_ownership as validate_adr_projection;
 pub use artifact_refs::validate_artifact_ref_edit;
+pub(crate) use attachments::{attachment_path, validate_attachment};
 pub(crate) use fields::normalize_clause_supersession_target;
 pub use fields::{ArtifactKind, validate_field};
 pub use lifecycle::{
     is_valid_adr_transition, is_valid_phase_transition, is_valid_status_transition,
-    is_valid_work_transition,
+    is_valid_work_transition, is_work_reopen,
 };
+pub use mappings::validate_mappings;
+pub(crate) use mappings::{control_coverage, require_active_clause};
+pub(crate) use owners::{require_adr_approvals, require_rfc_approvals, validate_owner_identity};
 pub use releases::validate_releases;
+pub(crate) use staleness::{adr_staleness, rfc_staleness};
+pub(crate) use supersession::SupersessionGraph;
 pub use work_dependencies::{is_work_item_id, validate_work_dependencies};
 
 /// Validation result with diagnostics
@@ -53,6 +78,17 @@ pub struct ValidationResult {
 
 /// Validate the entire project
 pub fn validate_project(index: &ProjectIndex, config: &Config) -> ValidationResult {
+    // Text checks need clause bodies; read them back for a metadata-only index.
+    let index = match crate::load::with_project_clause_text(config, index) {
+        Ok(index) => index,
+        Err(err) => {
+            return ValidationResult {
+                diagnostics: vec![err.into()],
+                ..Default::default()
+            };
+        }
+    };
+    let index = index.as_ref();
     let mut result = ValidationResult {
         rfc_count: index.rfcs.len(),
         clause_count: index.iter_clauses().count(),
@@ -72,6 +108,9 @@ pub fn validate_project(index: &ProjectIndex, config: &Config) -> ValidationResu
     // Validate cross-references
     validate_clause_references(index, config, &mut result);
 
+    // Clause anchors resolve `[[<ANCHOR>]]` references to one clause
+    validate_anchors(index, config, &mut result);
+
     // Validate ADRs
     for adr in &index.adrs {
         let adr_path_display = config.display_path(&adr.path).display().to_string();
@@ -98,6 +137,17 @@ pub fn validate_project(index: &ProjectIndex, config: &Config) -> ValidationResu
             ));
         }
 
+        for field in crate::secrets::unsealed_sensitive_fields(&adr.spec) {
+            result.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::E1603SensitiveFieldPlaintext,
+                format!(
+                    "ADR {field} is marked sensitive but stored as plaintext (hint: `govctl adr set {} {field} ...`)",
+                    adr.meta().id
+                ),
+                adr_path_display.clone(),
+            ));
+        }
+
         if adr.meta().status == AdrStatus::Proposed {
             result
                 .diagnostics
@@ -116,6 +166,13 @@ pub fn validate_project(index: &ProjectIndex, config: &Config) -> ValidationResu
     // Inline reference syntax in governed prose — [[RFC-0000:C-REFERENCE-HIERARCHY]]
     validate_bracket_reference_hierarchy(index, config, &mut result);
 
+    // Diagram blocks in clause and ADR prose parse — [[RFC-0000:C-CLAUSE-DEF]]
+    validate_diagrams(index, config, &mut result);
+
+    // Fragment includes and variables in clause and ADR text resolve —
+    // [[RFC-0000:C-CLAUSE-DEF]]
+    validate_substitutions(index, config, &mut result);
+
     // Validate work item descriptions
     validate_work_item_descriptions(index, config, &mut result);
 
@@ -125,5 +182,23 @@ pub fn validate_project(index: &ProjectIndex, config: &Config) -> ValidationResu
     // Validate tags against allowed set — [[RFC-0002:C-RESOURCES]]
     validate_artifact_tags(index, config, &mut result);
 
+    // Attachments must exist under gov/assets/
+    validate_attachments(index, config, &mut result);
+
+    // Validate owners and approvals against the [owners] registry
+    validate_rfc_owners(index, config, &mut result);
+
+    // Flag drafts and proposals left open past the [staleness] limits
+    validate_staleness(index, config, &mut result);
+
+    // Flag artifacts past their deprecation sunset that are still referenced
+    validate_sunsets(index, config, &mut result);
+
+    // Flag live refs to deprecated or superseded artifacts
+    validate_inactive_refs(index, config, &mut result);
+
+    // Supersession chains: no cycles or forks, and a head still in force
+    validate_supersession(index, config, &mut result);
+
     result
 }
@@ -0,0 +1,164 @@
+use super::ValidationResult;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::model::{AdrStatus, ProjectIndex, RfcSpec, RfcStatus};
+use std::collections::HashSet;
+
+/// Validate owners and approvals against the `[owners]` registry.
+///
+/// Owners must name a registered member or team and approvals a registered
+/// member. Normative RFCs and accepted ADRs must meet the configured
+/// approval rules, as `finalize` and `accept` require.
+pub(super) fn validate_rfc_owners(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    for rfc in &index.rfcs {
+        let path = config.display_path(&rfc.path).display().to_string();
+        let rfc_id = &rfc.rfc.rfc_id;
+        let identities = rfc.rfc.owners.iter().map(|owner| ("owners", owner)).chain(
+            rfc.rfc
+                .approvals
+                .iter()
+                .map(|approver| ("approvals", approver)),
+        );
+        for (field, identity) in identities {
+            if let Err(diagnostic) = validate_owner_identity(config, rfc_id, field, identity, &path)
+            {
+                result.diagnostics.push(diagnostic);
+            }
+        }
+        if rfc.rfc.status == RfcStatus::Normative
+            && let Err(diagnostic) = require_rfc_approvals(config, &rfc.rfc, &path)
+        {
+            result.diagnostics.push(diagnostic);
+        }
+    }
+
+    for adr in &index.adrs {
+        let path = config.display_path(&adr.path).display().to_string();
+        let meta = adr.meta();
+        for approver in &meta.approvals {
+            if let Err(diagnostic) =
+                validate_owner_identity(config, &meta.id, "approvals", approver, &path)
+            {
+                result.diagnostics.push(diagnostic);
+            }
+        }
+        if meta.status == AdrStatus::Accepted
+            && let Err(diagnostic) = require_adr_approvals(config, &meta.id, &meta.approvals, &path)
+        {
+            result.diagnostics.push(diagnostic);
+        }
+    }
+}
+
+/// Check one `owners` or `approvals` entry against the registry.
+pub(crate) fn validate_owner_identity(
+    config: &Config,
+    artifact_id: &str,
+    field: &str,
+    identity: &str,
+    diagnostic_path: &str,
+) -> DiagnosticResult<()> {
+    let registry = &config.owners;
+    if !registry.is_enabled() {
+        return Ok(());
+    }
+    let kind = artifact_kind(config, artifact_id);
+    let message = if field == "approvals" {
+        if registry.members.iter().any(|member| member == identity) {
+            return Ok(());
+        }
+        format!(
+            "{kind} '{artifact_id}' approval by '{identity}' is not from a member listed in [owners]"
+        )
+    } else {
+        if registry.is_known(identity) {
+            return Ok(());
+        }
+        format!(
+            "{kind} '{artifact_id}' owner '{identity}' is not a member or team listed in [owners]"
+        )
+    };
+    Err(Diagnostic::new(
+        DiagnosticCode::E0120RfcOwnerUnknown,
+        message,
+        diagnostic_path,
+    ))
+}
+
+/// Approval rules for `rfc finalize normative`: an owner's approval with
+/// `require_approval`, and at least `rfc_quorum` distinct approvals.
+pub(crate) fn require_rfc_approvals(
+    config: &Config,
+    rfc: &RfcSpec,
+    diagnostic_path: &str,
+) -> DiagnosticResult<()> {
+    let registry = &config.owners;
+    let approve_hint = format!("hint: `govctl rfc approve {} --as <identity>`", rfc.rfc_id);
+    if registry.require_approval
+        && !rfc
+            .approvals
+            .iter()
+            .any(|approver| registry.is_owner_approval(approver, &rfc.owners))
+    {
+        return Err(Diagnostic::new(
+            DiagnosticCode::E0121RfcApprovalMissing,
+            format!(
+                "RFC '{}' needs an approval from one of its owners ({}) ({approve_hint})",
+                rfc.rfc_id,
+                rfc.owners.join(", ")
+            ),
+            diagnostic_path,
+        ));
+    }
+    check_quorum(&rfc.approvals, registry.rfc_quorum).map_err(|count| {
+        Diagnostic::new(
+            DiagnosticCode::E0121RfcApprovalMissing,
+            format!(
+                "RFC '{}' has {count} of {} required approvals ({approve_hint})",
+                rfc.rfc_id, registry.rfc_quorum
+            ),
+            diagnostic_path,
+        )
+    })
+}
+
+/// Approval rule for `adr accept`: at least `adr_quorum` distinct approvals.
+pub(crate) fn require_adr_approvals(
+    config: &Config,
+    adr_id: &str,
+    approvals: &[String],
+    diagnostic_path: &str,
+) -> DiagnosticResult<()> {
+    let quorum = config.owners.adr_quorum;
+    check_quorum(approvals, quorum).map_err(|count| {
+        Diagnostic::new(
+            DiagnosticCode::E0308AdrApprovalMissing,
+            format!(
+                "ADR '{adr_id}' has {count} of {quorum} required approvals (hint: `govctl adr approve {adr_id} --as <identity>`)"
+            ),
+            diagnostic_path,
+        )
+    })
+}
+
+/// `Err(count)` when fewer than `quorum` distinct identities approved.
+fn check_quorum(approvals: &[String], quorum: u32) -> Result<(), usize> {
+    let count = approvals.iter().collect::<HashSet<_>>().len();
+    if count >= quorum as usize {
+        Ok(())
+    } else {
+        Err(count)
+    }
+}
+
+fn artifact_kind(config: &Config, artifact_id: &str) -> &'static str {
+    if config.prefixes.is_adr(artifact_id) {
+        "ADR"
+    } else {
+        "RFC"
+    }
+}
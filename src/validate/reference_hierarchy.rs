@@ -1,4 +1,6 @@
+use crate::config::PrefixesConfig;
 use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::remotes::split_remote_ref;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum ReferenceSurface {
@@ -9,26 +11,29 @@ pub(super) enum ReferenceSurface {
 
 /// Enforce [[RFC-0000:C-REFERENCE-HIERARCHY]] across refs and inline links.
 pub(super) fn check_ref_hierarchy(
+    prefixes: &PrefixesConfig,
     owner_id: &str,
     target_id: &str,
     diagnostic_path: &str,
     surface: ReferenceSurface,
 ) -> Result<(), Diagnostic> {
-    let owner_is_rfc = owner_id.starts_with("RFC-");
-    let owner_is_adr = owner_id.starts_with("ADR-");
+    let owner_is_rfc = prefixes.is_rfc(owner_id);
+    let owner_is_adr = prefixes.is_adr(owner_id);
     let owner_is_wi = owner_id.starts_with("WI-");
+    // Remote refs (`platform:ADR-0001`) follow the same hierarchy.
+    let target_kind = split_remote_ref(target_id).map_or(target_id, |(_, id)| id);
 
     if owner_is_wi {
         return Ok(());
     }
-    if owner_is_rfc && (target_id.starts_with("ADR-") || target_id.starts_with("WI-")) {
+    if owner_is_rfc && (prefixes.is_adr(target_kind) || target_kind.starts_with("WI-")) {
         return Err(Diagnostic::new(
             DiagnosticCode::E0112RfcReferenceHierarchy,
             hierarchy_message("RFC", owner_id, target_id, surface),
             diagnostic_path,
         ));
     }
-    if owner_is_adr && target_id.starts_with("WI-") {
+    if owner_is_adr && target_kind.starts_with("WI-") {
         return Err(Diagnostic::new(
             DiagnosticCode::E0306AdrReferenceHierarchy,
             hierarchy_message("ADR", owner_id, target_id, surface),
@@ -74,11 +79,18 @@ mod tests {
     #[test]
     fn rfc_rejects_adr_and_wi() {
         assert!(
-            check_ref_hierarchy("RFC-0001", "ADR-0001", "f", ReferenceSurface::StructuredRef,)
-                .is_err()
+            check_ref_hierarchy(
+                &PrefixesConfig::default(),
+                "RFC-0001",
+                "ADR-0001",
+                "f",
+                ReferenceSurface::StructuredRef,
+            )
+            .is_err()
         );
         assert!(
             check_ref_hierarchy(
+                &PrefixesConfig::default(),
                 "RFC-0001",
                 "WI-2026-01-17-001",
                 "f",
@@ -91,11 +103,18 @@ mod tests {
     #[test]
     fn rfc_allows_rfc_and_clause() {
         assert!(
-            check_ref_hierarchy("RFC-0001", "RFC-0002", "f", ReferenceSurface::StructuredRef,)
-                .is_ok()
+            check_ref_hierarchy(
+                &PrefixesConfig::default(),
+                "RFC-0001",
+                "RFC-0002",
+                "f",
+                ReferenceSurface::StructuredRef,
+            )
+            .is_ok()
         );
         assert!(
             check_ref_hierarchy(
+                &PrefixesConfig::default(),
                 "RFC-0001",
                 "RFC-0002:C-FOO",
                 "f",
@@ -109,6 +128,7 @@ mod tests {
     fn adr_rejects_wi() {
         assert!(
             check_ref_hierarchy(
+                &PrefixesConfig::default(),
                 "ADR-0001",
                 "WI-2026-01-17-001",
                 "f",
@@ -122,6 +142,7 @@ mod tests {
     fn adr_allows_rfc_adr() {
         assert!(
             check_ref_hierarchy(
+                &PrefixesConfig::default(),
                 "ADR-0001",
                 "RFC-0000:C-RFC-DEF",
                 "f",
@@ -130,8 +151,14 @@ mod tests {
             .is_ok()
         );
         assert!(
-            check_ref_hierarchy("ADR-0001", "ADR-0002", "f", ReferenceSurface::StructuredRef,)
-                .is_ok()
+            check_ref_hierarchy(
+                &PrefixesConfig::default(),
+                "ADR-0001",
+                "ADR-0002",
+                "f",
+                ReferenceSurface::StructuredRef,
+            )
+            .is_ok()
         );
     }
 
@@ -139,6 +166,7 @@ mod tests {
     fn work_allows_any() {
         assert!(
             check_ref_hierarchy(
+                &PrefixesConfig::default(),
                 "WI-2026-01-17-001",
                 "WI-2026-01-17-002",
                 "f",
@@ -148,6 +176,7 @@ mod tests {
         );
         assert!(
             check_ref_hierarchy(
+                &PrefixesConfig::default(),
                 "WI-2026-01-17-001",
                 "ADR-0001",
                 "f",
@@ -159,8 +188,13 @@ mod tests {
 
     #[test]
     fn preserves_structured_ref_diagnostic_wording() {
-        let result =
-            check_ref_hierarchy("RFC-0001", "ADR-0001", "f", ReferenceSurface::StructuredRef);
+        let result = check_ref_hierarchy(
+            &PrefixesConfig::default(),
+            "RFC-0001",
+            "ADR-0001",
+            "f",
+            ReferenceSurface::StructuredRef,
+        );
 
         assert!(result.is_err(), "RFC to ADR structured ref should fail");
         if let Err(err) = result {
@@ -174,7 +208,13 @@ mod tests {
     #[test]
     fn preserves_bracket_link_diagnostic_wording() {
         let target_id = "WI-2026-01-17-001";
-        let result = check_ref_hierarchy("ADR-0001", target_id, "f", ReferenceSurface::BracketLink);
+        let result = check_ref_hierarchy(
+            &PrefixesConfig::default(),
+            "ADR-0001",
+            target_id,
+            "f",
+            ReferenceSurface::BracketLink,
+        );
         assert!(result.is_err(), "ADR to WI bracket link should fail");
         if let Err(err) = result {
             assert_eq!(
@@ -188,7 +228,13 @@ mod tests {
 
     #[test]
     fn bare_text_diagnostic_names_the_plain_identifier() {
-        let result = check_ref_hierarchy("RFC-0001", "ADR-0001", "f", ReferenceSurface::BareText);
+        let result = check_ref_hierarchy(
+            &PrefixesConfig::default(),
+            "RFC-0001",
+            "ADR-0001",
+            "f",
+            ReferenceSurface::BareText,
+        );
 
         assert!(result.is_err(), "RFC to ADR bare mention should fail");
         if let Err(err) = result {
@@ -51,8 +51,10 @@ pub(super) fn validate_rfc_signatures(
             continue;
         };
 
-        // Compute expected signature from source
-        let expected_sig = match compute_rfc_signature(rfc) {
+        // Compute expected signature from source, with fragments expanded as
+        // rendered; an include that fails to expand is reported separately.
+        let expanded = crate::fragments::expand_rfc(config, rfc).ok();
+        let expected_sig = match compute_rfc_signature(expanded.as_ref().unwrap_or(rfc)) {
             Ok(sig) => sig,
             Err(e) => {
                 result.diagnostics.push(Diagnostic::new(
@@ -0,0 +1,82 @@
+//! Stale governance: draft RFCs and proposed ADRs past the `[staleness]` limits.
+
+use super::ValidationResult;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::model::{AdrEntry, AdrStatus, ProjectIndex, RfcIndex, RfcStatus};
+use chrono::{Local, NaiveDate};
+
+/// How long an artifact has been idle, and the limit it exceeds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Staleness {
+    pub(crate) days: i64,
+    pub(crate) limit: u32,
+}
+
+/// Idle age of a draft RFC past `draft_rfc_days`, counted from its latest
+/// `created`, `updated`, or changelog date.
+pub(crate) fn rfc_staleness(config: &Config, rfc: &RfcIndex) -> Option<Staleness> {
+    let limit = config.staleness.draft_rfc_days;
+    if limit == 0 || rfc.rfc.status != RfcStatus::Draft {
+        return None;
+    }
+    let last_touched = [Some(&rfc.rfc.created), rfc.rfc.updated.as_ref()]
+        .into_iter()
+        .flatten()
+        .chain(rfc.rfc.changelog.iter().map(|entry| &entry.date))
+        .filter_map(|date| parse_date(date))
+        .max()?;
+    exceeded(last_touched, limit)
+}
+
+/// Age of a proposed ADR past `proposed_adr_days`, counted from its date.
+pub(crate) fn adr_staleness(config: &Config, adr: &AdrEntry) -> Option<Staleness> {
+    let limit = config.staleness.proposed_adr_days;
+    if limit == 0 || adr.meta().status != AdrStatus::Proposed {
+        return None;
+    }
+    exceeded(parse_date(&adr.meta().date)?, limit)
+}
+
+/// W0119 for every stale draft RFC and proposed ADR.
+pub(super) fn validate_staleness(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    for rfc in &index.rfcs {
+        if let Some(stale) = rfc_staleness(config, rfc) {
+            let id = &rfc.rfc.rfc_id;
+            result.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::W0119ArtifactStale,
+                format!(
+                    "Draft {id} has not been updated in {} days (limit {}) (hint: finalize it or record why it is still open)",
+                    stale.days, stale.limit
+                ),
+                config.display_path(&rfc.path).display().to_string(),
+            ));
+        }
+    }
+    for adr in &index.adrs {
+        if let Some(stale) = adr_staleness(config, adr) {
+            let id = &adr.meta().id;
+            result.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::W0119ArtifactStale,
+                format!(
+                    "{id} has been proposed for {} days (limit {}) (hint: `govctl adr accept {id}` or `govctl adr reject {id}`)",
+                    stale.days, stale.limit
+                ),
+                config.display_path(&adr.path).display().to_string(),
+            ));
+        }
+    }
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+fn exceeded(since: NaiveDate, limit: u32) -> Option<Staleness> {
+    let days = (Local::now().date_naive() - since).num_days();
+    (days > i64::from(limit)).then_some(Staleness { days, limit })
+}
@@ -0,0 +1,115 @@
+//! Deprecation sunsets: artifacts still referenced after their sunset date.
+
+use super::ValidationResult;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::model::{AdrStatus, Deprecation, ProjectIndex, RfcStatus, WorkItemStatus};
+use chrono::{Local, NaiveDate};
+use std::path::Path;
+
+/// W0120 for every RFC, clause, or ADR past its sunset that live artifacts
+/// still list in `refs`.
+pub(super) fn validate_sunsets(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    let today = Local::now().date_naive();
+    let referrers = live_referrers(index);
+    let mut check = |id: &str, notice: Option<&Deprecation>, path: &Path| {
+        let Some(sunset) = passed_sunset(notice, today) else {
+            return;
+        };
+        let users: Vec<&str> = referrers
+            .iter()
+            .filter(|(owner, refs, _)| !targets(id, owner) && refs.iter().any(|r| targets(r, id)))
+            .map(|(owner, ..)| *owner)
+            .collect();
+        if users.is_empty() {
+            return;
+        }
+        result.diagnostics.push(Diagnostic::new(
+            DiagnosticCode::W0120SunsetPassed,
+            format!(
+                "{id} passed its sunset date {sunset} but is still referenced by {} (hint: point these refs at its replacement)",
+                users.join(", ")
+            ),
+            config.display_path(path).display().to_string(),
+        ));
+    };
+
+    for rfc in &index.rfcs {
+        check(&rfc.rfc.rfc_id, rfc.rfc.deprecation.as_ref(), &rfc.path);
+        for clause in &rfc.clauses {
+            let id = format!("{}:{}", rfc.rfc.rfc_id, clause.spec.clause_id);
+            check(&id, clause.spec.deprecation.as_ref(), &clause.path);
+        }
+    }
+    for adr in &index.adrs {
+        check(&adr.meta().id, adr.meta().deprecation.as_ref(), &adr.path);
+    }
+}
+
+/// The sunset date of `notice`, when it is before `today`.
+pub(super) fn passed_sunset(notice: Option<&Deprecation>, today: NaiveDate) -> Option<&str> {
+    let sunset = notice?.sunset.as_deref()?;
+    NaiveDate::parse_from_str(sunset, "%Y-%m-%d")
+        .is_ok_and(|date| date < today)
+        .then_some(sunset)
+}
+
+/// `refs` and paths of artifacts that are still in force: RFCs not
+/// deprecated, ADRs not rejected or superseded, and open work items.
+pub(super) fn live_referrers(index: &ProjectIndex) -> Vec<(&str, &[String], &Path)> {
+    let rfcs = index
+        .rfcs
+        .iter()
+        .filter(|rfc| rfc.rfc.status != RfcStatus::Deprecated)
+        .map(|rfc| {
+            (
+                rfc.rfc.rfc_id.as_str(),
+                rfc.rfc.refs.as_slice(),
+                rfc.path.as_path(),
+            )
+        });
+    let adrs = index
+        .adrs
+        .iter()
+        .filter(|adr| {
+            !matches!(
+                adr.meta().status,
+                AdrStatus::Rejected | AdrStatus::Superseded
+            )
+        })
+        .map(|adr| {
+            (
+                adr.meta().id.as_str(),
+                adr.meta().refs.as_slice(),
+                adr.path.as_path(),
+            )
+        });
+    let work_items = index
+        .work_items
+        .iter()
+        .filter(|work| {
+            !matches!(
+                work.meta().status,
+                WorkItemStatus::Done | WorkItemStatus::Cancelled
+            )
+        })
+        .map(|work| {
+            (
+                work.meta().id.as_str(),
+                work.meta().refs.as_slice(),
+                work.path.as_path(),
+            )
+        });
+    rfcs.chain(adrs).chain(work_items).collect()
+}
+
+/// Whether `ref_id` names `id` or, for an RFC, one of its clauses.
+pub(super) fn targets(ref_id: &str, id: &str) -> bool {
+    ref_id
+        .strip_prefix(id)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(':'))
+}
@@ -0,0 +1,260 @@
+//! Supersession chains across RFCs, clauses, and ADRs.
+//!
+//! Edges run from the replaced artifact to its replacement: an RFC's
+//! `supersedes` field and the `superseded_by` field of clauses and ADRs.
+//! Validation rejects cycles and forks and warns when a chain's newest
+//! artifact is no longer in force.
+
+use super::ValidationResult;
+use super::normalize_clause_supersession_target;
+use crate::config::Config;
+use crate::diagnostic::{Diagnostic, DiagnosticCode};
+use crate::model::{AdrStatus, ClauseStatus, ProjectIndex, RfcStatus};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// One artifact that can take part in a supersession chain.
+pub(crate) struct ChainNode {
+    pub status: String,
+    /// Whether the artifact is in force: a normative RFC, an active clause,
+    /// or an accepted ADR.
+    pub active: bool,
+    pub path: String,
+}
+
+/// Supersession links between known artifacts.
+#[derive(Default)]
+pub(crate) struct SupersessionGraph {
+    pub nodes: BTreeMap<String, ChainNode>,
+    /// Replaced artifact -> its replacements (more than one is a fork).
+    successors: BTreeMap<String, Vec<String>>,
+    /// Replacement -> the artifacts it replaces.
+    predecessors: BTreeMap<String, Vec<String>>,
+}
+
+impl SupersessionGraph {
+    /// Links whose ends are not both known artifacts are left out; dangling
+    /// targets are reported by the reference checks.
+    pub(crate) fn build(index: &ProjectIndex, config: &Config) -> Self {
+        let mut graph = Self::default();
+        let mut edges = Vec::new();
+        for rfc in &index.rfcs {
+            let id = rfc.rfc.rfc_id.clone();
+            if let Some(old) = &rfc.rfc.supersedes {
+                edges.push((old.clone(), id.clone()));
+            }
+            graph.add_node(
+                &id,
+                rfc.rfc.status.as_ref(),
+                rfc.rfc.status == RfcStatus::Normative,
+                config.display_path(&rfc.path).display().to_string(),
+            );
+            for clause in &rfc.clauses {
+                let clause_id = format!("{id}:{}", clause.spec.clause_id);
+                if let Some(target) = clause.spec.superseded_by.as_deref()
+                    && let Ok(target) = normalize_clause_supersession_target(&clause_id, target)
+                {
+                    edges.push((clause_id.clone(), target));
+                }
+                graph.add_node(
+                    &clause_id,
+                    clause.spec.status.as_ref(),
+                    clause.spec.status == ClauseStatus::Active,
+                    config.display_path(&clause.path).display().to_string(),
+                );
+            }
+        }
+        for adr in &index.adrs {
+            let meta = adr.meta();
+            if let Some(new) = &meta.superseded_by {
+                edges.push((meta.id.clone(), new.clone()));
+            }
+            graph.add_node(
+                &meta.id,
+                meta.status.as_ref(),
+                meta.status == AdrStatus::Accepted,
+                config.display_path(&adr.path).display().to_string(),
+            );
+        }
+
+        for (old, new) in edges {
+            if old == new || !graph.nodes.contains_key(&old) || !graph.nodes.contains_key(&new) {
+                continue;
+            }
+            graph
+                .successors
+                .entry(old.clone())
+                .or_default()
+                .push(new.clone());
+            graph.predecessors.entry(new).or_default().push(old);
+        }
+        for ids in graph
+            .successors
+            .values_mut()
+            .chain(graph.predecessors.values_mut())
+        {
+            ids.sort();
+            ids.dedup();
+        }
+        graph
+    }
+
+    fn add_node(&mut self, id: &str, status: &str, active: bool, path: String) {
+        self.nodes.insert(
+            id.to_string(),
+            ChainNode {
+                status: status.to_string(),
+                active,
+                path,
+            },
+        );
+    }
+
+    pub(crate) fn successors(&self, id: &str) -> &[String] {
+        self.successors.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    pub(crate) fn predecessors(&self, id: &str) -> &[String] {
+        self.predecessors.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    /// The chain through `id`, oldest first, following the first
+    /// predecessor back and the first successor forward. The second value
+    /// is true when `id` sits on a cycle; the chain then starts at `id` and
+    /// goes once around.
+    pub(crate) fn chain(&self, id: &str) -> (Vec<String>, bool) {
+        let mut seen = BTreeSet::from([id.to_string()]);
+        let mut chain = Vec::new();
+        let mut current = id;
+        while let Some(previous) = self.predecessors(current).first() {
+            if !seen.insert(previous.clone()) {
+                chain.clear();
+                seen = BTreeSet::from([id.to_string()]);
+                break;
+            }
+            chain.push(previous.clone());
+            current = previous;
+        }
+        chain.reverse();
+        chain.push(id.to_string());
+        let mut current = id;
+        let mut cycle = false;
+        while let Some(next) = self.successors(current).first() {
+            if !seen.insert(next.clone()) {
+                cycle = true;
+                break;
+            }
+            chain.push(next.clone());
+            current = next;
+        }
+        (chain, cycle)
+    }
+}
+
+/// E0125/E0309 for RFC and ADR supersession cycles, E0126 for RFCs
+/// superseded twice, and W0121 for chains whose newest artifact is not in
+/// force. Clause cycles are reported by the clause checks (E0212).
+pub(super) fn validate_supersession(
+    index: &ProjectIndex,
+    config: &Config,
+    result: &mut ValidationResult,
+) {
+    let graph = SupersessionGraph::build(index, config);
+
+    for (old, news) in &graph.successors {
+        if news.len() > 1 && !old.contains(':') {
+            result.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::E0126RfcSupersessionFork,
+                format!(
+                    "{old} is superseded by more than one artifact: {} (hint: keep one replacement and point the others elsewhere)",
+                    news.join(", ")
+                ),
+                graph.nodes[old].path.clone(),
+            ));
+        }
+    }
+
+    let cycles = find_cycles(&graph);
+    for cycle in &cycles {
+        let Some(start) = cycle.first() else {
+            continue;
+        };
+        if start.contains(':') {
+            continue;
+        }
+        let code = if config.prefixes.is_adr(start) {
+            DiagnosticCode::E0309AdrSupersessionCycle
+        } else {
+            DiagnosticCode::E0125RfcSupersessionCycle
+        };
+        result.diagnostics.push(Diagnostic::new(
+            code,
+            format!("Supersession cycle detected: {}", cycle.join(" -> ")),
+            graph.nodes[start].path.clone(),
+        ));
+    }
+
+    let in_cycle: BTreeSet<&String> = cycles.iter().flatten().collect();
+    for (id, node) in &graph.nodes {
+        let is_head = !graph.predecessors(id).is_empty() && graph.successors(id).is_empty();
+        if !is_head || node.active || in_cycle.contains(id) {
+            continue;
+        }
+        let (chain, _) = graph.chain(id);
+        result.diagnostics.push(Diagnostic::new(
+            DiagnosticCode::W0121SupersessionHeadInactive,
+            format!(
+                "Supersession chain {} ends at {id}, which is {} (hint: bring it into force or supersede it with one that is)",
+                chain.join(" -> "),
+                node.status
+            ),
+            node.path.clone(),
+        ));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    Visiting,
+    Visited,
+}
+
+/// Each cycle once, as the path around it ending back at its first node.
+fn find_cycles(graph: &SupersessionGraph) -> Vec<Vec<String>> {
+    let mut state: HashMap<&str, Visit> = HashMap::new();
+    let mut cycles = Vec::new();
+    for start in graph.successors.keys() {
+        if state.contains_key(start.as_str()) {
+            continue;
+        }
+        // Iterative DFS: (node, index of the next successor to visit).
+        let mut stack: Vec<(&str, usize)> = vec![(start, 0)];
+        state.insert(start, Visit::Visiting);
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            let Some(child) = graph.successors(node).get(*next) else {
+                state.insert(node, Visit::Visited);
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            match state.get(child.as_str()) {
+                Some(Visit::Visiting) => {
+                    let from = stack
+                        .iter()
+                        .position(|(id, _)| *id == child)
+                        .unwrap_or_default();
+                    let mut cycle: Vec<String> =
+                        stack[from..].iter().map(|(id, _)| id.to_string()).collect();
+                    cycle.push(child.clone());
+                    cycles.push(cycle);
+                }
+                Some(Visit::Visited) => {}
+                None => {
+                    state.insert(child, Visit::Visiting);
+                    stack.push((child, 0));
+                }
+            }
+        }
+    }
+    cycles
+}
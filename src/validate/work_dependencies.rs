@@ -1,4 +1,4 @@
-use crate::config::Config;
+use crate::config::{Config, work_id_pattern};
 use crate::diagnostic::{Diagnostic, DiagnosticCode};
 use crate::model::ProjectIndex;
 use regex::Regex;
@@ -11,8 +11,7 @@ enum WorkDependencyVisit {
 }
 
 pub fn is_work_item_id(value: &str) -> bool {
-    Regex::new(r"^WI-\d{4}-\d{2}-\d{2}-(?:[a-f0-9]{4}(?:-\d{3})?|\d{3})$")
-        .is_ok_and(|re| re.is_match(value))
+    Regex::new(&format!("^{}$", work_id_pattern())).is_ok_and(|re| re.is_match(value))
 }
 
 /// Validate work item `depends_on` declarations per [[RFC-0006:C-DEPENDENCY-SEMANTICS]].
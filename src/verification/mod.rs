@@ -4,11 +4,12 @@ mod runner;
 
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode};
-use crate::model::{GuardEntry, WorkItemEntry};
+use crate::model::{GuardCheck, GuardEntry, GuardMeta, GuardSpec, WorkItemEntry};
 use regex::RegexBuilder;
 use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path};
 
-pub use runner::run_guard;
+pub use runner::{DEFAULT_GUARD_TIMEOUT_SECS, run_guard, run_with_timeout};
 
 pub fn build_guard_index(
     guards: Vec<GuardEntry>,
@@ -47,13 +48,47 @@ pub fn required_guard_ids_before_waivers(
     config: &Config,
     work_item: &WorkItemEntry,
 ) -> Vec<String> {
+    let verification = &work_item.spec.verification;
     dedup_guard_ids(
         configured_default_guard_ids(config)
             .into_iter()
-            .chain(work_item.spec.verification.required_guards.iter().cloned()),
+            .chain(verification.required_guards.iter().cloned())
+            .chain(verification.guards.iter().map(|guard| guard.id.clone())),
     )
 }
 
+/// The guards `work_item` runs: project guards, with the item's own guard
+/// declarations replacing the check of a project guard of the same ID or
+/// added alongside them.
+pub fn work_item_guard_index(
+    guards: &HashMap<String, GuardEntry>,
+    work_item: &WorkItemEntry,
+) -> HashMap<String, GuardEntry> {
+    let mut index = guards.clone();
+    for declared in &work_item.spec.verification.guards {
+        let govctl = guards.get(&declared.id).map_or_else(
+            || {
+                GuardMeta::new(
+                    &declared.id,
+                    format!("{} ({})", declared.id, work_item.meta().id),
+                )
+            },
+            |project| project.spec.govctl.clone(),
+        );
+        index.insert(
+            declared.id.clone(),
+            GuardEntry {
+                spec: GuardSpec {
+                    govctl,
+                    check: declared.check.clone(),
+                },
+                path: work_item.path.clone(),
+            },
+        );
+    }
+    index
+}
+
 pub fn effective_required_guard_ids(config: &Config, work_item: &WorkItemEntry) -> Vec<String> {
     let waived: HashSet<&str> = work_item
         .spec
@@ -78,7 +113,8 @@ pub fn validate_guard_configuration(
 
     diagnostics.extend(validate_verification_config(config, guards));
     for work_item in work_items {
-        diagnostics.extend(validate_work_item_verification(config, guards, work_item));
+        let guards = work_item_guard_index(guards, work_item);
+        diagnostics.extend(validate_work_item_verification(config, &guards, work_item));
     }
     diagnostics
 }
@@ -100,23 +136,79 @@ pub fn validate_verification_config(
     }
 
     for guard in guards.values() {
-        if let Some(pattern) = &guard.spec.check.pattern
-            && RegexBuilder::new(pattern)
-                .case_insensitive(true)
-                .build()
-                .is_err()
+        diagnostics.extend(validate_guard_check(
+            config,
+            &guard.meta().id,
+            &guard.spec.check,
+            &guard.path.display().to_string(),
+        ));
+    }
+
+    diagnostics
+}
+
+/// Problems with a guard's `[check]` that would only surface once it ran:
+/// a bad pattern, working directory, or shell. The schema covers `env` names.
+fn validate_guard_check(
+    config: &Config,
+    guard_id: &str,
+    check: &GuardCheck,
+    location: &str,
+) -> Vec<Diagnostic> {
+    let mut problems = Vec::new();
+
+    if let Some(pattern) = &check.pattern
+        && RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+            .is_err()
+    {
+        problems.push(format!("Invalid guard regex pattern: {guard_id}"));
+    }
+
+    if let Some(dir) = &check.working_dir {
+        let path = Path::new(dir);
+        if path.is_absolute()
+            || path
+                .components()
+                .any(|component| component == Component::ParentDir)
         {
-            diagnostics.push(Diagnostic::new(
-                DiagnosticCode::E1001GuardSchemaInvalid,
-                format!("Invalid guard regex pattern: {}", guard.meta().id),
-                guard.path.display().to_string(),
+            problems.push(format!(
+                "Guard working_dir must be a relative path inside the project: {guard_id}"
+            ));
+        } else if !config.project_root().join(path).is_dir() {
+            problems.push(format!(
+                "Guard working_dir '{dir}' is not a directory: {guard_id}"
             ));
         }
     }
 
-    diagnostics
+    if let Some(shell) = &check.shell
+        && !shell_exists(shell)
+    {
+        problems.push(format!("Guard shell '{shell}' not found: {guard_id}"));
+    }
+
+    problems
+        .into_iter()
+        .map(|message| Diagnostic::new(DiagnosticCode::E1001GuardSchemaInvalid, message, location))
+        .collect()
+}
+
+/// Whether `shell` names an executable file, as a path or on `PATH`.
+fn shell_exists(shell: &str) -> bool {
+    if shell.trim().is_empty() {
+        return false;
+    }
+    if shell.contains('/') {
+        return Path::new(shell).is_file();
+    }
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(shell).is_file()))
 }
 
+/// Validate a work item's verification policy against `guards`, the index
+/// [`work_item_guard_index`] builds for it.
 pub fn validate_work_item_verification(
     config: &Config,
     guards: &HashMap<String, GuardEntry>,
@@ -124,6 +216,34 @@ pub fn validate_work_item_verification(
 ) -> Vec<Diagnostic> {
     let mut diagnostics = Vec::new();
 
+    let mut seen_guards = HashSet::new();
+    for declared in &work_item.spec.verification.guards {
+        let work_path = config.display_path(&work_item.path).display().to_string();
+        if !seen_guards.insert(declared.id.as_str()) {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticCode::E0401WorkSchemaInvalid,
+                format!("Duplicate verification guard declaration: {}", declared.id),
+                work_path.clone(),
+            ));
+        }
+        if declared.check.command.trim().is_empty() {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticCode::E1001GuardSchemaInvalid,
+                format!(
+                    "Verification guard command must not be empty: {}",
+                    declared.id
+                ),
+                work_path.clone(),
+            ));
+        }
+        diagnostics.extend(validate_guard_check(
+            config,
+            &declared.id,
+            &declared.check,
+            &work_path,
+        ));
+    }
+
     for guard_id in &work_item.spec.verification.required_guards {
         if !guards.contains_key(guard_id) {
             diagnostics.push(Diagnostic::new(
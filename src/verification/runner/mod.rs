@@ -1,8 +1,8 @@
 use crate::config::Config;
 use crate::diagnostic::{Diagnostic, DiagnosticCode};
-use crate::model::GuardEntry;
+use crate::model::{GuardEntry, GuardResult};
 use regex::RegexBuilder;
-use std::process::{Command, ExitStatus};
+use std::process::{Child, Command, ExitStatus};
 use std::time::{Duration, Instant};
 
 mod capture;
@@ -16,15 +16,63 @@ use process_group::{
 
 pub const DEFAULT_GUARD_TIMEOUT_SECS: u64 = 300;
 
+/// Run `command` in its own process group, killing the group if it is still
+/// running after `timeout`. `start` gets the child before the wait, e.g. to
+/// feed its stdin. Returns `None` when the command timed out.
+pub fn run_with_timeout(
+    mut command: Command,
+    timeout: Duration,
+    start: impl FnOnce(&mut Child),
+) -> std::io::Result<Option<ExitStatus>> {
+    configure_guard_process_group(&mut command);
+    let mut child = command.spawn()?;
+    let process_group = guard_process_group(child.id());
+    start(&mut child);
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            terminate_guard_process_group(process_group);
+            return Ok(Some(status));
+        }
+        if started.elapsed() >= timeout {
+            terminate_guard_process(&mut child, process_group);
+            child.wait()?;
+            return Ok(None);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GuardRunResult {
     pub id: String,
     pub passed: bool,
     pub timed_out: bool,
     pub primary_shell_running_at_timeout: Option<bool>,
+    pub exit_code: Option<i32>,
+    pub duration: Duration,
     pub output: String,
 }
 
+impl GuardRunResult {
+    /// This run as a work item result, keeping the last `output_lines`
+    /// lines of output.
+    pub fn record(&self, guard: &GuardEntry, ran_at: String, output_lines: usize) -> GuardResult {
+        let lines: Vec<&str> = self.output.trim_end().lines().collect();
+        let tail = &lines[lines.len().saturating_sub(output_lines)..];
+        GuardResult {
+            guard: self.id.clone(),
+            command: guard.spec.check.command.clone(),
+            ran_at,
+            duration_ms: u64::try_from(self.duration.as_millis()).unwrap_or(u64::MAX),
+            exit_code: self.exit_code,
+            passed: self.passed,
+            timed_out: self.timed_out,
+            output: tail.join("\n"),
+        }
+    }
+}
+
 pub fn run_guard(config: &Config, guard: &GuardEntry) -> Result<GuardRunResult, Diagnostic> {
     let project_root = config
         .gov_root
@@ -40,10 +88,21 @@ pub fn run_guard(config: &Config, guard: &GuardEntry) -> Result<GuardRunResult,
     let mut stdout_capture = GuardOutputCapture::new(guard, "stdout")?;
     let mut stderr_capture = GuardOutputCapture::new(guard, "stderr")?;
 
-    let mut command = Command::new("/bin/bash");
+    let check = &guard.spec.check;
+    let working_dir = match &check.working_dir {
+        Some(dir) => project_root.join(dir),
+        None => project_root,
+    };
+    let (shell, flag) = match &check.shell {
+        Some(shell) => (shell.as_str(), "-c"),
+        None => ("/bin/bash", "-lc"),
+    };
+
+    let mut command = Command::new(shell);
     command
-        .args(["-lc", &guard.spec.check.command])
-        .current_dir(project_root)
+        .args([flag, &check.command])
+        .envs(&check.env)
+        .current_dir(working_dir)
         .stdout(stdout_capture.stdio(guard, "stdout")?)
         .stderr(stderr_capture.stdio(guard, "stderr")?);
     configure_guard_process_group(&mut command);
@@ -109,6 +168,7 @@ pub fn run_guard(config: &Config, guard: &GuardEntry) -> Result<GuardRunResult,
         }
     }
 
+    let duration = started.elapsed();
     let stdout = stdout_capture.read(guard, "stdout")?;
     let stderr = stderr_capture.read(guard, "stderr")?;
     let combined_output = format!(
@@ -141,6 +201,8 @@ pub fn run_guard(config: &Config, guard: &GuardEntry) -> Result<GuardRunResult,
         passed: !timed_out && status.success() && pattern_matched,
         timed_out,
         primary_shell_running_at_timeout,
+        exit_code: if timed_out { None } else { status.code() },
+        duration,
         output: combined_output,
     })
 }
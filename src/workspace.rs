@@ -0,0 +1,170 @@
+//! Workspaces: several governed projects in one repository.
+//!
+//! A `govctl-workspace.toml` at the repository root names each member and
+//! the project root holding its `gov/` tree:
+//!
+//! ```toml
+//! [members]
+//! api = "services/api"
+//! billing = "services/billing"
+//! ```
+//!
+//! `-p/--project <name>` runs a command against one member from anywhere in
+//! the repository. Governed prose can reference another member's artifacts as
+//! `[[billing/RFC-0003]]`; `check --workspace` validates every member and
+//! resolves those cross-project references.
+
+use crate::config::{Config, any_id_pattern};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Manifest file name, looked up from the current directory upwards.
+pub const WORKSPACE_FILE_NAME: &str = "govctl-workspace.toml";
+
+/// Cross-project reference in governed prose: `[[member/ARTIFACT-ID]]`.
+///
+/// RFC and ADR prefixes are matched loosely since each member may configure
+/// its own `[prefixes]`.
+pub fn cross_project_ref_pattern() -> String {
+    format!(r"\[\[([a-z0-9][a-z0-9_-]*)/({})\]\]", any_id_pattern())
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(default)]
+    members: BTreeMap<String, PathBuf>,
+}
+
+/// A loaded workspace manifest.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// Directory holding the manifest.
+    pub root: PathBuf,
+    /// Member name to absolute project root, sorted by name.
+    pub members: BTreeMap<String, PathBuf>,
+}
+
+impl Workspace {
+    /// Find the nearest manifest above the current directory.
+    pub fn find() -> DiagnosticResult<Option<Self>> {
+        let Ok(mut current) = std::env::current_dir() else {
+            return Ok(None);
+        };
+        loop {
+            let manifest = current.join(WORKSPACE_FILE_NAME);
+            if manifest.is_file() {
+                return Self::load(&manifest).map(Some);
+            }
+            if !current.pop() {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Find the nearest manifest, failing if there is none.
+    pub fn require() -> DiagnosticResult<Self> {
+        Self::find()?.ok_or_else(|| {
+            Diagnostic::new(
+                DiagnosticCode::E0502PathNotFound,
+                format!("No {WORKSPACE_FILE_NAME} found in this directory or its parents"),
+                WORKSPACE_FILE_NAME,
+            )
+        })
+    }
+
+    fn load(path: &Path) -> DiagnosticResult<Self> {
+        let display = path.display().to_string();
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| Diagnostic::io_error("read workspace manifest", err, &display))?;
+        let manifest: Manifest = toml::from_str(&content).map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0509WorkspaceInvalid,
+                format!("Failed to parse workspace manifest: {err}"),
+                &display,
+            )
+        })?;
+        let root = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let name_re = Regex::new(r"^[a-z0-9][a-z0-9_-]*$").map_err(|err| {
+            Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!("Invalid built-in member name pattern: {err}"),
+                "internal",
+            )
+        })?;
+        let mut members = BTreeMap::new();
+        for (name, member_path) in manifest.members {
+            if !name_re.is_match(&name) {
+                return Err(Diagnostic::new(
+                    DiagnosticCode::E0509WorkspaceInvalid,
+                    format!(
+                        "Workspace member name '{name}' must be lowercase letters, digits, '-' or '_'"
+                    ),
+                    &display,
+                ));
+            }
+            members.insert(name, root.join(member_path));
+        }
+        Ok(Self { root, members })
+    }
+
+    /// Config path of member `name`.
+    pub fn member_config_path(&self, name: &str) -> DiagnosticResult<PathBuf> {
+        let root = self.members.get(name).ok_or_else(|| {
+            let known: Vec<&str> = self.members.keys().map(String::as_str).collect();
+            Diagnostic::new(
+                DiagnosticCode::E0510WorkspaceMemberNotFound,
+                format!(
+                    "Unknown workspace member '{name}' (members: {})",
+                    known.join(", ")
+                ),
+                self.root.join(WORKSPACE_FILE_NAME).display().to_string(),
+            )
+        })?;
+        Ok(root.join("gov").join("config.toml"))
+    }
+
+    /// Member project root as shown to the user, relative to the workspace root.
+    pub fn display_member_root(&self, name: &str) -> String {
+        self.members
+            .get(name)
+            .map(|root| {
+                root.strip_prefix(&self.root)
+                    .unwrap_or(root)
+                    .display()
+                    .to_string()
+            })
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Load the config of every member, in name order.
+    pub fn member_configs(&self) -> Vec<(&str, DiagnosticResult<Config>)> {
+        self.members
+            .keys()
+            .map(|name| {
+                let config = self
+                    .member_config_path(name)
+                    .and_then(|path| Config::load(Some(&path)));
+                (name.as_str(), config)
+            })
+            .collect()
+    }
+}
+
+/// Config path selected by `-C/--config` or `-p/--project`.
+pub fn resolve_config_arg(
+    config: Option<&Path>,
+    project: Option<&str>,
+) -> DiagnosticResult<Option<PathBuf>> {
+    match project {
+        Some(name) => Workspace::require()?.member_config_path(name).map(Some),
+        None => Ok(config.map(Path::to_path_buf)),
+    }
+}
@@ -65,7 +65,7 @@ pub fn write_clause(
     op: WriteOp,
     display_path: Option<&Path>,
 ) -> DiagnosticResult<()> {
-    let wire: ClauseWire = clause.clone().into();
+    let wire = ClauseWire::try_from(clause.clone())?;
     write_toml_artifact(
         path,
         &wire,
@@ -1,6 +1,6 @@
-use super::{WriteOp, write_file};
+use super::{WriteOp, preserve_layout, write_file};
 use crate::config::Config;
-use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult, Span};
 use crate::schema::{ArtifactSchema, validate_toml_value, with_schema_header};
 use serde::{Serialize, de::DeserializeOwned};
 use std::path::Path;
@@ -49,15 +49,17 @@ where
             format!("Failed to parse {} TOML: {err}", io.message_label),
             path.display().to_string(),
         )
+        .with_span(Span::of_toml_error(&content, &err))
     })?;
     (io.normalize_toml)(&mut raw);
-    validate_toml_value(io.schema, config, path, &raw)?;
+    validate_toml_value(io.schema, config, path, &content, &raw)?;
     let wire: Wire = raw.try_into().map_err(|err| {
         Diagnostic::new(
             io.schema_error,
             format!("Failed to deserialize {} TOML: {err}", io.message_label),
             path.display().to_string(),
         )
+        .with_span(Span::of_deserialize_error::<Wire>(&content, &err))
     })?;
     Ok(wire.into())
 }
@@ -79,6 +81,6 @@ pub(super) fn write_toml_artifact<Wire: Serialize>(
             diagnostic_path.display().to_string(),
         )
     })?;
-    let content = with_schema_header(schema, &body);
+    let content = preserve_layout(path, with_schema_header(schema, &body));
     write_file(path, &content, op, display_path)
 }
@@ -24,9 +24,14 @@ fn test_rfc() -> RfcSpec {
         status: RfcStatus::Normative,
         phase: RfcPhase::Spec,
         owners: vec![],
+        approvals: vec![],
+        frozen: false,
         created: "2026-07-16".to_string(),
+        created_by: None,
+        attachments: vec![],
         updated: None,
         supersedes: None,
+        deprecation: None,
         refs: vec![],
         tags: vec![],
         sections: vec![],
@@ -176,12 +181,15 @@ fn add_change_resolves_current_entry_by_version() -> Result<(), Box<dyn std::err
 }
 
 #[test]
-fn current_entry_resolution_rejects_duplicate_matches() {
+fn current_entry_resolution_rejects_duplicate_matches() -> Result<(), Box<dyn std::error::Error>> {
     let mut rfc = test_rfc();
     rfc.changelog.push(changelog_entry("0.2.0"));
 
-    let error = add_changelog_change(&mut rfc, "fix: ambiguous").unwrap_err();
+    let Err(error) = add_changelog_change(&mut rfc, "fix: ambiguous") else {
+        return Err("duplicate current entries must be rejected".into());
+    };
 
     assert_eq!(error.code, DiagnosticCode::E0115RfcCurrentChangelogInvalid);
     assert!(error.message.contains("found 2"));
+    Ok(())
 }
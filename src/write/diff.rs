@@ -0,0 +1,166 @@
+//! Line diffs for dry-run previews, `clause history`, and `init-skills --diff`.
+
+/// Unchanged lines shown around each change in a unified diff hunk.
+const CONTEXT: usize = 3;
+
+/// Line diff of `old` to `new` as `(' ' | '-' | '+', line)`, by longest
+/// common subsequence.
+pub fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<(char, &'a str)> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    // Only the differing middle needs the quadratic table.
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let (old_mid, new_mid) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+
+    let mut common = vec![vec![0usize; new_mid.len() + 1]; old_mid.len() + 1];
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            common[i][j] = if old_mid[i] == new_mid[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    diff.extend(old[..prefix].iter().map(|line| (' ', *line)));
+    let (mut i, mut j) = (0, 0);
+    while i < old_mid.len() && j < new_mid.len() {
+        if old_mid[i] == new_mid[j] {
+            diff.push((' ', old_mid[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            diff.push(('-', old_mid[i]));
+            i += 1;
+        } else {
+            diff.push(('+', new_mid[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old_mid[i..].iter().map(|line| ('-', *line)));
+    diff.extend(new_mid[j..].iter().map(|line| ('+', *line)));
+    diff.extend(old[old.len() - suffix..].iter().map(|line| (' ', *line)));
+    diff
+}
+
+/// Unified diff of `old` to `new` with `---`/`+++` headers, or an empty
+/// string when the texts have the same lines.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let diff = line_diff(old, new);
+    let changes: Vec<usize> = diff
+        .iter()
+        .enumerate()
+        .filter(|(_, (tag, _))| *tag != ' ')
+        .map(|(index, _)| index)
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    // Old and new line numbers (1-based) at each diff entry.
+    let mut positions = Vec::with_capacity(diff.len());
+    let (mut old_line, mut new_line) = (1, 1);
+    for (tag, _) in &diff {
+        positions.push((old_line, new_line));
+        match tag {
+            '-' => old_line += 1,
+            '+' => new_line += 1,
+            _ => {
+                old_line += 1;
+                new_line += 1;
+            }
+        }
+    }
+
+    let mut out = format!("--- {old_label}\n+++ {new_label}\n");
+    let mut next = 0;
+    while next < changes.len() {
+        let start = changes[next].saturating_sub(CONTEXT);
+        let mut last = changes[next];
+        next += 1;
+        while next < changes.len() && changes[next] <= last + 2 * CONTEXT + 1 {
+            last = changes[next];
+            next += 1;
+        }
+        let end = (last + CONTEXT + 1).min(diff.len());
+        let hunk = &diff[start..end];
+        let old_count = hunk.iter().filter(|(tag, _)| *tag != '+').count();
+        let new_count = hunk.iter().filter(|(tag, _)| *tag != '-').count();
+        // An empty side starts at the line before the hunk, as in diff -u.
+        let (old_start, new_start) = positions[start];
+        let old_start = if old_count == 0 {
+            old_start - 1
+        } else {
+            old_start
+        };
+        let new_start = if new_count == 0 {
+            new_start - 1
+        } else {
+            new_start
+        };
+        out.push_str(&format!(
+            "@@ -{old_start},{old_count} +{new_start},{new_count} @@\n"
+        ));
+        for (tag, line) in hunk {
+            out.push(*tag);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_diff_marks_changed_lines() {
+        assert_eq!(
+            line_diff("a\nb\nc", "a\nB\nc\nd"),
+            vec![(' ', "a"), ('-', "b"), ('+', "B"), (' ', "c"), ('+', "d")]
+        );
+        assert_eq!(line_diff("", "x"), vec![('+', "x")]);
+    }
+
+    #[test]
+    fn unified_diff_groups_changes_into_hunks() {
+        let old: String = (1..=20).map(|n| format!("{n}\n")).collect();
+        let new: String = (1..=20)
+            .filter(|n| *n != 18)
+            .map(|n| {
+                if n == 2 {
+                    "two\n".to_string()
+                } else {
+                    format!("{n}\n")
+                }
+            })
+            .collect();
+        assert_eq!(
+            unified_diff(&old, &new, "a/f", "b/f"),
+            "--- a/f\n+++ b/f\n\
+             @@ -1,5 +1,5 @@\n 1\n-2\n+two\n 3\n 4\n 5\n\
+             @@ -15,6 +15,5 @@\n 15\n 16\n 17\n-18\n 19\n 20\n"
+        );
+        assert_eq!(
+            unified_diff("", "x\n", "/dev/null", "b/f"),
+            "--- /dev/null\n+++ b/f\n@@ -0,0 +1,1 @@\n+x\n"
+        );
+        assert_eq!(unified_diff("same\n", "same\n", "a/f", "b/f"), "");
+    }
+}
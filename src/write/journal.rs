@@ -0,0 +1,314 @@
+//! Write transactions.
+//!
+//! While a transaction is open, every file written or deleted and every
+//! directory created with the [`WriteOp`] it hands to the operation records
+//! its before-image in the transaction's journal. If the operation returns an error the journal is replayed in
+//! reverse, so a multi-file command that fails halfway (e.g. a clause delete
+//! that rewrites `rfc.toml` but cannot remove the clause file) leaves the
+//! project as it found it, per [[RFC-0002:C-LIFECYCLE-VERBS]].
+
+use super::{WriteOp, atomic_write_file, inspect_write_target, resolve_write_target};
+use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+struct FileSnapshot {
+    path: PathBuf,
+    content: Option<Vec<u8>>,
+}
+
+/// A file written by a committed transaction, with its prior content.
+pub struct RecordedFile {
+    pub path: PathBuf,
+    /// `None` when the transaction created the file.
+    pub before: Option<Vec<u8>>,
+}
+
+/// A file a dry run would have written or deleted.
+#[derive(Clone)]
+pub struct PreviewedFile {
+    pub path: PathBuf,
+    /// `None` when the file does not exist yet.
+    pub before: Option<Vec<u8>>,
+    /// `None` when the file would be deleted.
+    pub after: Option<Vec<u8>>,
+    /// Unified diff of the change, when both sides are text.
+    pub diff: Option<String>,
+}
+
+/// Everything a committed transaction changed.
+pub struct RecordedChanges {
+    pub files: Vec<RecordedFile>,
+    pub created_dirs: Vec<PathBuf>,
+    /// What a dry run would have changed; empty outside `--dry-run`.
+    pub previews: Vec<PreviewedFile>,
+}
+
+/// Before-images recorded by one transaction scope.
+#[derive(Default)]
+struct JournalFrame {
+    files: Vec<FileSnapshot>,
+    targets: HashSet<PathBuf>,
+    created_dirs: Vec<PathBuf>,
+    previews: Vec<PreviewedFile>,
+}
+
+impl JournalFrame {
+    fn recorded_changes(&self) -> RecordedChanges {
+        RecordedChanges {
+            files: self
+                .files
+                .iter()
+                .map(|snapshot| RecordedFile {
+                    path: snapshot.path.clone(),
+                    before: snapshot.content.clone(),
+                })
+                .collect(),
+            created_dirs: self.created_dirs.clone(),
+            previews: self.previews.clone(),
+        }
+    }
+
+    /// Hand a committed inner scope's journal to its enclosing scope.
+    fn absorb(&mut self, inner: JournalFrame) {
+        for snapshot in inner.files {
+            if self.targets.insert(snapshot.path.clone()) {
+                self.files.push(snapshot);
+            }
+        }
+        self.created_dirs.extend(inner.created_dirs);
+        self.previews.extend(inner.previews);
+    }
+}
+
+/// Before-images of the innermost open transaction, which [`WriteOp`]
+/// carries to every write made inside it.
+pub(super) struct Journal {
+    frame: RefCell<JournalFrame>,
+}
+
+impl std::fmt::Debug for Journal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Journal").finish_non_exhaustive()
+    }
+}
+
+impl Journal {
+    fn new(frame: JournalFrame) -> Self {
+        Self {
+            frame: RefCell::new(frame),
+        }
+    }
+
+    /// Journal `path` before it is replaced or deleted.
+    pub(super) fn record_file(&self, path: &Path, output_path: &Path) -> DiagnosticResult<()> {
+        let target = resolve_write_target(path, output_path)?;
+        if self.frame.borrow().targets.contains(&target) {
+            return Ok(());
+        }
+        let content = read_before_image(&target, output_path)?;
+        let mut frame = self.frame.borrow_mut();
+        frame.targets.insert(target.clone());
+        frame.files.push(FileSnapshot {
+            path: target,
+            content,
+        });
+        Ok(())
+    }
+
+    /// Record what a dry run would write to or delete from `path`.
+    pub(super) fn record_preview(&self, preview: PreviewedFile) {
+        self.frame.borrow_mut().previews.push(preview);
+    }
+
+    /// Journal the directories `create_dir_all(path)` is about to create.
+    pub(super) fn record_dirs(&self, path: &Path) {
+        let missing = path
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .map(Path::to_path_buf);
+        self.frame.borrow_mut().created_dirs.extend(missing);
+    }
+}
+
+/// Run an operation as one transaction, rolling back every write on error.
+///
+/// The operation receives `op` journaled into the new transaction; writes
+/// made with any other op are not rolled back. Nested transactions roll
+/// back only their own writes on error; on success their journal moves to
+/// the enclosing transaction.
+pub fn with_transaction<T>(
+    op: WriteOp,
+    operation: impl FnOnce(WriteOp) -> DiagnosticResult<T>,
+) -> DiagnosticResult<T> {
+    with_file_transaction(&[], op, operation)
+}
+
+/// Run a group of artifact writes with preflight checks and rollback on error.
+///
+/// `paths` are checked for writability and journaled before the operation
+/// runs; anything else the operation writes is journaled when first touched.
+pub fn with_file_transaction<T>(
+    paths: &[&Path],
+    op: WriteOp,
+    operation: impl FnOnce(WriteOp) -> DiagnosticResult<T>,
+) -> DiagnosticResult<T> {
+    if op.is_preview() {
+        return operation(op);
+    }
+    let (value, frame) = run_frame(paths, op, operation)?;
+    commit_to_outer(op, frame);
+    Ok(value)
+}
+
+/// Run an operation as one transaction and return the before-images it committed.
+///
+/// Used by `govctl undo` bookkeeping; previews record nothing.
+pub fn with_recorded_transaction<T>(
+    op: WriteOp,
+    operation: impl FnOnce(WriteOp) -> DiagnosticResult<T>,
+) -> DiagnosticResult<(T, RecordedChanges)> {
+    if op.is_preview() {
+        let changes = RecordedChanges {
+            files: vec![],
+            created_dirs: vec![],
+            previews: vec![],
+        };
+        return Ok((operation(op)?, changes));
+    }
+    let (value, frame) = run_frame(&[], op, operation)?;
+    let changes = frame.recorded_changes();
+    commit_to_outer(op, frame);
+    Ok((value, changes))
+}
+
+/// Run an operation and return the before-images of everything it wrote.
+///
+/// Unlike a transaction, an error leaves the writes in place; this only
+/// observes what the command changed, for the `--output json` result envelope.
+pub fn with_write_log<T>(
+    op: WriteOp,
+    operation: impl FnOnce(WriteOp) -> DiagnosticResult<T>,
+) -> (DiagnosticResult<T>, RecordedChanges) {
+    let journal = Journal::new(JournalFrame::default());
+    let result = operation(op.in_journal(&journal));
+    let frame = journal.frame.into_inner();
+    let changes = frame.recorded_changes();
+    commit_to_outer(op, frame);
+    (result, changes)
+}
+
+/// Run the operation journaled into a new frame, and roll the frame back on error.
+fn run_frame<T>(
+    paths: &[&Path],
+    op: WriteOp,
+    operation: impl FnOnce(WriteOp) -> DiagnosticResult<T>,
+) -> DiagnosticResult<(T, JournalFrame)> {
+    let mut frame = JournalFrame::default();
+    for path in paths {
+        let (target, _) = inspect_write_target(path, path)?;
+        if frame.targets.insert(target.clone()) {
+            let content = read_before_image(&target, path)?;
+            frame.files.push(FileSnapshot {
+                path: target,
+                content,
+            });
+        }
+    }
+
+    let journal = Journal::new(frame);
+    let result = operation(op.in_journal(&journal));
+    let frame = journal.frame.into_inner();
+
+    match result {
+        Ok(value) => Ok((value, frame)),
+        Err(operation_error) => match rollback(frame) {
+            Ok(()) => Err(operation_error),
+            Err(rollback_error) => Err(Diagnostic::new(
+                DiagnosticCode::E0903UnexpectedError,
+                format!(
+                    "{}; transaction rollback failed; governed-artifact restoration may be incomplete: {}",
+                    operation_error.message, rollback_error.message
+                ),
+                operation_error.file,
+            )),
+        },
+    }
+}
+
+/// Hand a committed frame to the transaction `op` was journaled into, if any.
+fn commit_to_outer(op: WriteOp, frame: JournalFrame) {
+    if let Some(outer) = op.journal {
+        outer.frame.borrow_mut().absorb(frame);
+    }
+}
+
+fn read_before_image(target: &Path, output_path: &Path) -> DiagnosticResult<Option<Vec<u8>>> {
+    match std::fs::read(target) {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(Diagnostic::io_error(
+            "read file before transaction",
+            err,
+            output_path.display().to_string(),
+        )),
+    }
+}
+
+fn rollback(frame: JournalFrame) -> DiagnosticResult<()> {
+    for snapshot in frame.files.into_iter().rev() {
+        match snapshot.content {
+            Some(content) => {
+                let current = std::fs::read(&snapshot.path).ok();
+                if current.as_deref() == Some(content.as_slice()) {
+                    continue;
+                }
+                if let Some(parent) = snapshot.path.parent()
+                    && !parent.as_os_str().is_empty()
+                    && !parent.exists()
+                {
+                    std::fs::create_dir_all(parent).map_err(|err| {
+                        Diagnostic::io_error(
+                            "recreate directory during transaction rollback",
+                            err,
+                            parent.display().to_string(),
+                        )
+                    })?;
+                }
+                // Restores always sync, whatever `concurrency.fsync` says.
+                atomic_write_file(&snapshot.path, &content, &snapshot.path, true)?;
+            }
+            None => match std::fs::remove_file(&snapshot.path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    return Err(Diagnostic::io_error(
+                        "remove file during transaction rollback",
+                        err,
+                        snapshot.path.display().to_string(),
+                    ));
+                }
+            },
+        }
+    }
+
+    let mut created_dirs = frame.created_dirs;
+    // Deepest first, so parents are empty by the time they are removed.
+    created_dirs.sort_by_key(|dir| std::cmp::Reverse(dir.components().count()));
+    created_dirs.dedup();
+    for dir in created_dirs {
+        match std::fs::remove_dir(&dir) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(Diagnostic::io_error(
+                    "remove directory during transaction rollback",
+                    err,
+                    dir.display().to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
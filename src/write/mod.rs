@@ -3,7 +3,7 @@
 //! Implements [[ADR-0006]] global dry-run support for content-modifying commands.
 //! Implements [[ADR-0012]] prefix-based changelog category parsing.
 
-use crate::diagnostic::{Diagnostic, DiagnosticCode, DiagnosticResult};
+use crate::diagnostic::{Diagnostic, DiagnosticResult};
 use crate::ui;
 use std::collections::HashSet;
 use std::fs::OpenOptions;
@@ -14,6 +14,9 @@ mod artifact;
 mod artifact_io;
 mod artifact_normalize;
 mod changelog;
+mod diff;
+mod journal;
+mod toml_layout;
 
 pub use artifact::{read_clause, read_rfc, write_clause, write_rfc};
 pub use artifact_normalize::{normalize_clause_value, normalize_rfc_value};
@@ -21,6 +24,13 @@ pub use changelog::{
     BumpLevel, ParsedChange, add_changelog_change, bump_rfc_version, current_changelog_entry,
     current_changelog_entry_mut, today,
 };
+pub use diff::{line_diff, unified_diff};
+use journal::Journal;
+pub use journal::{
+    PreviewedFile, RecordedChanges, with_file_transaction, with_recorded_transaction,
+    with_transaction, with_write_log,
+};
+pub use toml_layout::preserve_layout;
 
 pub fn parse_changelog_change(change: &str) -> DiagnosticResult<ParsedChange> {
     changelog::parse_changelog_change(change)
@@ -28,29 +38,80 @@ pub fn parse_changelog_change(change: &str) -> DiagnosticResult<ParsedChange> {
 
 /// Write operation mode.
 ///
-/// Controls whether write operations execute or just preview.
+/// Controls whether write operations execute or just preview, and carries
+/// the journal of the innermost open transaction so its writes can be
+/// rolled back.
 #[derive(Debug, Clone, Copy, Default)]
-pub enum WriteOp {
+pub struct WriteOp<'j> {
+    mode: WriteMode,
+    /// `None` outside a transaction: writes are not journaled.
+    journal: Option<&'j Journal>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum WriteMode {
     /// Actually write to disk
     #[default]
     Execute,
+    /// Write to disk without flushing before replacing the original
+    /// (`concurrency.fsync = false`). A killed process still never leaves a
+    /// truncated file behind, but a power loss may lose the write.
+    ExecuteUnsynced,
     /// Preview only: show what would be written
     Preview,
 }
 
-impl WriteOp {
+impl WriteOp<'static> {
+    /// Actually write to disk, outside any transaction
+    pub const EXECUTE: Self = Self {
+        mode: WriteMode::Execute,
+        journal: None,
+    };
+    /// Preview only: show what would be written
+    pub const PREVIEW: Self = Self {
+        mode: WriteMode::Preview,
+        journal: None,
+    };
+
     /// Create WriteOp from dry_run boolean flag
     pub fn from_dry_run(dry_run: bool) -> Self {
         if dry_run {
-            WriteOp::Preview
+            Self::PREVIEW
         } else {
-            WriteOp::Execute
+            Self::EXECUTE
         }
     }
+}
 
+impl<'j> WriteOp<'j> {
     /// Returns true if this is a preview/dry-run operation
     pub fn is_preview(&self) -> bool {
-        matches!(self, WriteOp::Preview)
+        self.mode == WriteMode::Preview
+    }
+
+    /// Apply `concurrency.fsync` to an executing operation.
+    pub fn with_fsync(self, fsync: bool) -> Self {
+        let mode = match self.mode {
+            WriteMode::Execute | WriteMode::ExecuteUnsynced if fsync => WriteMode::Execute,
+            WriteMode::Execute | WriteMode::ExecuteUnsynced => WriteMode::ExecuteUnsynced,
+            WriteMode::Preview => WriteMode::Preview,
+        };
+        Self { mode, ..self }
+    }
+
+    /// The same operation, journaled into `journal`.
+    fn in_journal<'a>(self, journal: &'a Journal) -> WriteOp<'a>
+    where
+        'j: 'a,
+    {
+        WriteOp {
+            mode: self.mode,
+            journal: Some(journal),
+        }
+    }
+
+    fn fsync(self) -> bool {
+        self.mode != WriteMode::ExecuteUnsynced
     }
 }
 
@@ -65,21 +126,99 @@ pub fn write_file(
     display_path: Option<&Path>,
 ) -> DiagnosticResult<()> {
     let output_path = display_path.unwrap_or(path);
-    match op {
-        WriteOp::Execute => {
-            atomic_write_file(path, content, output_path)?;
+    match op.mode {
+        WriteMode::Execute | WriteMode::ExecuteUnsynced => {
+            if let Some(journal) = op.journal {
+                journal.record_file(path, output_path)?;
+            }
+            atomic_write_file(path, content.as_bytes(), output_path, op.fsync())?;
+            tracing::debug!("wrote {}", output_path.display());
         }
-        WriteOp::Preview => {
-            ui::dry_run_file_preview(output_path, content);
+        WriteMode::Preview => preview_change(op, path, output_path, Some(content.as_bytes())),
+    }
+    Ok(())
+}
+
+/// Write binary content to a file, respecting WriteOp mode.
+///
+/// Preview mode reports the target path only, since the content is not text.
+pub fn write_file_bytes(
+    path: &Path,
+    content: &[u8],
+    op: WriteOp,
+    display_path: Option<&Path>,
+) -> DiagnosticResult<()> {
+    let output_path = display_path.unwrap_or(path);
+    match op.mode {
+        WriteMode::Execute | WriteMode::ExecuteUnsynced => {
+            if let Some(journal) = op.journal {
+                journal.record_file(path, output_path)?;
+            }
+            atomic_write_file(path, content, output_path, op.fsync())?;
+            tracing::debug!("wrote {}", output_path.display());
         }
+        WriteMode::Preview => preview_change(op, path, output_path, Some(content)),
     }
     Ok(())
 }
 
+/// Atomically replace `path` with `content` without recording it in the
+/// write journal, for derived output such as rendered docs. `fsync` is the
+/// project's `concurrency.fsync`.
+pub fn replace_file(
+    path: &Path,
+    content: &[u8],
+    display_path: &Path,
+    fsync: bool,
+) -> DiagnosticResult<()> {
+    atomic_write_file(path, content, display_path, fsync)?;
+    tracing::debug!("wrote {}", display_path.display());
+    Ok(())
+}
+
+/// Content as diff text: missing files are empty, binary files have none.
+fn preview_text(content: Option<&[u8]>) -> Option<&str> {
+    content.map_or(Some(""), |bytes| std::str::from_utf8(bytes).ok())
+}
+
+/// Show and record the unified diff of a write (`after`) or delete (`None`)
+/// that a dry run skips. Binary content is reported by path only.
+fn preview_change(op: WriteOp, path: &Path, output_path: &Path, after: Option<&[u8]>) {
+    let before = std::fs::read(path).ok();
+    let label = output_path.display();
+    let old_label = before
+        .as_ref()
+        .map_or_else(|| "/dev/null".to_string(), |_| format!("a/{label}"));
+    let new_label = after.map_or_else(|| "/dev/null".to_string(), |_| format!("b/{label}"));
+    let diff = preview_text(before.as_deref())
+        .zip(preview_text(after))
+        .map(|(old, new)| unified_diff(old, new, &old_label, &new_label));
+    match (after, &diff) {
+        (None, _) => ui::dry_run_delete(output_path),
+        (Some(_), Some(diff)) => ui::dry_run_file_diff(output_path, diff),
+        (Some(_), None) => ui::dry_run_preview(output_path),
+    }
+    if let Some(journal) = op.journal {
+        journal.record_preview(PreviewedFile {
+            path: path.to_path_buf(),
+            before,
+            after: after.map(<[u8]>::to_vec),
+            diff,
+        });
+    }
+}
+
 // Write and sync in the target directory before replacing the destination so
 // returned lifecycle errors can restore prior content per
-// [[RFC-0002:C-LIFECYCLE-VERBS]].
-fn atomic_write_file(path: &Path, content: &str, output_path: &Path) -> DiagnosticResult<()> {
+// [[RFC-0002:C-LIFECYCLE-VERBS]]. The temporary file shares the target's
+// filesystem, so the rename is atomic and a killed process leaves either the
+// old or the new content, never a truncated file ([[RFC-0004:C-CONCURRENT-WRITE]]).
+fn atomic_write_file(
+    path: &Path,
+    content: &[u8],
+    output_path: &Path,
+    fsync: bool,
+) -> DiagnosticResult<()> {
     let (target_path, existing_permissions) = inspect_write_target(path, output_path)?;
     let parent = target_path
         .parent()
@@ -105,7 +244,7 @@ fn atomic_write_file(path: &Path, content: &str, output_path: &Path) -> Diagnost
             output_path.display().to_string(),
         )
     })?;
-    temporary.write_all(content.as_bytes()).map_err(|err| {
+    temporary.write_all(content).map_err(|err| {
         Diagnostic::io_error(
             "write temporary file",
             err,
@@ -124,16 +263,37 @@ fn atomic_write_file(path: &Path, content: &str, output_path: &Path) -> Diagnost
                 )
             })?;
     }
-    temporary.as_file().sync_all().map_err(|err| {
-        Diagnostic::io_error(
-            "sync temporary file",
-            err,
-            output_path.display().to_string(),
-        )
-    })?;
+    if fsync {
+        temporary.as_file().sync_all().map_err(|err| {
+            Diagnostic::io_error(
+                "sync temporary file",
+                err,
+                output_path.display().to_string(),
+            )
+        })?;
+    }
     temporary.persist(&target_path).map_err(|err| {
         Diagnostic::io_error("replace file", err.error, output_path.display().to_string())
     })?;
+    if fsync {
+        sync_dir(parent, output_path)?;
+    }
+    Ok(())
+}
+
+/// Flush a directory so a rename into it survives a crash.
+#[cfg(unix)]
+fn sync_dir(dir: &Path, output_path: &Path) -> DiagnosticResult<()> {
+    std::fs::File::open(dir)
+        .and_then(|dir| dir.sync_all())
+        .map_err(|err| {
+            Diagnostic::io_error("sync directory", err, output_path.display().to_string())
+        })
+}
+
+/// Windows cannot open directories as files; `MoveFileEx` is durable enough.
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path, _output_path: &Path) -> DiagnosticResult<()> {
     Ok(())
 }
 
@@ -213,94 +373,6 @@ fn resolve_write_target(path: &Path, output_path: &Path) -> DiagnosticResult<Pat
     ))
 }
 
-struct FileSnapshot {
-    path: PathBuf,
-    content: Option<Vec<u8>>,
-}
-
-/// Run a group of artifact writes with preflight checks and rollback on error.
-pub fn with_file_transaction<T>(
-    paths: &[&Path],
-    op: WriteOp,
-    operation: impl FnOnce() -> DiagnosticResult<T>,
-) -> DiagnosticResult<T> {
-    if op.is_preview() {
-        return operation();
-    }
-
-    let mut targets = HashSet::new();
-    let mut snapshots = Vec::new();
-    for path in paths {
-        let (target, _) = inspect_write_target(path, path)?;
-        if !targets.insert(target.clone()) {
-            continue;
-        }
-        let content = match std::fs::read(&target) {
-            Ok(content) => Some(content),
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
-            Err(err) => {
-                return Err(Diagnostic::io_error(
-                    "read file before transaction",
-                    err,
-                    path.display().to_string(),
-                ));
-            }
-        };
-        snapshots.push(FileSnapshot {
-            path: target,
-            content,
-        });
-    }
-
-    match operation() {
-        Ok(value) => Ok(value),
-        Err(operation_error) => match rollback_files(snapshots) {
-            Ok(()) => Err(operation_error),
-            Err(rollback_error) => Err(Diagnostic::new(
-                DiagnosticCode::E0903UnexpectedError,
-                format!(
-                    "{}; transaction rollback failed; governed-artifact restoration may be incomplete: {}",
-                    operation_error.message, rollback_error.message
-                ),
-                operation_error.file,
-            )),
-        },
-    }
-}
-
-fn rollback_files(snapshots: Vec<FileSnapshot>) -> DiagnosticResult<()> {
-    for snapshot in snapshots.into_iter().rev() {
-        match snapshot.content {
-            Some(content) => {
-                let current = std::fs::read(&snapshot.path).ok();
-                if current.as_deref() == Some(content.as_slice()) {
-                    continue;
-                }
-                let content = std::str::from_utf8(&content).map_err(|err| {
-                    Diagnostic::new(
-                        DiagnosticCode::E0903UnexpectedError,
-                        format!("Failed to restore non-UTF-8 artifact: {err}"),
-                        snapshot.path.display().to_string(),
-                    )
-                })?;
-                atomic_write_file(&snapshot.path, content, &snapshot.path)?;
-            }
-            None => match std::fs::remove_file(&snapshot.path) {
-                Ok(()) => {}
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-                Err(err) => {
-                    return Err(Diagnostic::io_error(
-                        "remove file during transaction rollback",
-                        err,
-                        snapshot.path.display().to_string(),
-                    ));
-                }
-            },
-        }
-    }
-    Ok(())
-}
-
 /// Create a directory, respecting WriteOp mode.
 ///
 /// In Preview mode, shows what directory would be created.
@@ -311,13 +383,17 @@ pub fn create_dir_all(
     display_path: Option<&Path>,
 ) -> DiagnosticResult<()> {
     let output_path = display_path.unwrap_or(path);
-    match op {
-        WriteOp::Execute => {
+    match op.mode {
+        WriteMode::Execute | WriteMode::ExecuteUnsynced => {
+            if let Some(journal) = op.journal {
+                journal.record_dirs(path);
+            }
             std::fs::create_dir_all(path).map_err(|err| {
                 Diagnostic::io_error("create directory", err, output_path.display().to_string())
             })?;
+            tracing::debug!("created directory {}", output_path.display());
         }
-        WriteOp::Preview => {
+        WriteMode::Preview => {
             ui::dry_run_mkdir(output_path);
         }
     }
@@ -330,15 +406,17 @@ pub fn create_dir_all(
 /// If `display_path` is provided, it's used for error messages and preview output.
 pub fn delete_file(path: &Path, op: WriteOp, display_path: Option<&Path>) -> DiagnosticResult<()> {
     let output_path = display_path.unwrap_or(path);
-    match op {
-        WriteOp::Execute => {
+    match op.mode {
+        WriteMode::Execute | WriteMode::ExecuteUnsynced => {
+            if let Some(journal) = op.journal {
+                journal.record_file(path, output_path)?;
+            }
             std::fs::remove_file(path).map_err(|err| {
                 Diagnostic::io_error("delete file", err, output_path.display().to_string())
             })?;
+            tracing::debug!("deleted {}", output_path.display());
         }
-        WriteOp::Preview => {
-            ui::info(format!("[DRY RUN] Would delete: {}", output_path.display()));
-        }
+        WriteMode::Preview => preview_change(op, path, output_path, None),
     }
     Ok(())
 }
@@ -361,7 +439,7 @@ mod tests {
         unwritable_dir_permissions.set_mode(original_dir_permissions.mode() & !0o222);
         std::fs::set_permissions(dir.path(), unwritable_dir_permissions)?;
 
-        let result = write_file(&path, "replacement", WriteOp::Execute, None);
+        let result = write_file(&path, "replacement", WriteOp::EXECUTE, None);
         std::fs::set_permissions(dir.path(), original_dir_permissions)?;
 
         assert!(result.is_err());
@@ -377,7 +455,7 @@ mod tests {
         std::fs::write(&path, "original")?;
         std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640))?;
 
-        write_file(&path, "replacement", WriteOp::Execute, None)?;
+        write_file(&path, "replacement", WriteOp::EXECUTE, None)?;
 
         assert_eq!(std::fs::read_to_string(&path)?, "replacement");
         assert_eq!(std::fs::metadata(path)?.permissions().mode() & 0o777, 0o640);
@@ -392,7 +470,7 @@ mod tests {
         std::fs::write(&path, "original")?;
         std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o444))?;
 
-        let result = write_file(&path, "replacement", WriteOp::Execute, None);
+        let result = write_file(&path, "replacement", WriteOp::EXECUTE, None);
 
         assert!(result.is_err());
         assert_eq!(std::fs::read_to_string(path)?, "original");
@@ -408,7 +486,7 @@ mod tests {
         std::fs::write(&target, "original")?;
         std::os::unix::fs::symlink(&target, &link)?;
 
-        write_file(&link, "replacement", WriteOp::Execute, None)?;
+        write_file(&link, "replacement", WriteOp::EXECUTE, None)?;
 
         assert!(std::fs::symlink_metadata(&link)?.file_type().is_symlink());
         assert_eq!(std::fs::read_to_string(target)?, "replacement");
@@ -423,7 +501,7 @@ mod tests {
         let link = dir.path().join("artifact.toml");
         std::os::unix::fs::symlink("target.toml", &link)?;
 
-        write_file(&link, "replacement", WriteOp::Execute, None)?;
+        write_file(&link, "replacement", WriteOp::EXECUTE, None)?;
 
         assert!(std::fs::symlink_metadata(&link)?.file_type().is_symlink());
         assert_eq!(std::fs::read_to_string(target)?, "replacement");
@@ -448,7 +526,7 @@ mod tests {
         write_file(
             &dir.path().join("link-0.toml"),
             "replacement",
-            WriteOp::Execute,
+            WriteOp::EXECUTE,
             None,
         )?;
 
@@ -474,7 +552,7 @@ mod tests {
         let result = write_file(
             &dir.path().join("link-0.toml"),
             "replacement",
-            WriteOp::Execute,
+            WriteOp::EXECUTE,
             None,
         );
 
@@ -494,9 +572,9 @@ mod tests {
 
         let result = with_file_transaction(
             &[first.as_path(), second.as_path()],
-            WriteOp::Execute,
-            || {
-                write_file(&first, "first-replacement", WriteOp::Execute, None)?;
+            WriteOp::EXECUTE,
+            |op| {
+                write_file(&first, "first-replacement", op, None)?;
                 Err::<(), _>(Diagnostic::new(
                     crate::diagnostic::DiagnosticCode::E0903UnexpectedError,
                     "injected write failure",
@@ -511,6 +589,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn transaction_restores_journaled_writes_after_failure()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let modified = dir.path().join("modified.toml");
+        let deleted = dir.path().join("deleted.toml");
+        std::fs::write(&modified, "modified-original")?;
+        std::fs::write(&deleted, "deleted-original")?;
+
+        let result = with_transaction(WriteOp::EXECUTE, |op| {
+            write_file(&modified, "replacement", op, None)?;
+            write_file(&modified, "second replacement", op, None)?;
+            delete_file(&deleted, op, None)?;
+            create_dir_all(&dir.path().join("created/deeper"), op, None)?;
+            write_file(&dir.path().join("created/deeper/new.toml"), "new", op, None)?;
+            Err::<(), _>(Diagnostic::new(
+                crate::diagnostic::DiagnosticCode::E0903UnexpectedError,
+                "injected failure after journaled writes",
+                dir.path().display().to_string(),
+            ))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(modified)?, "modified-original");
+        assert_eq!(std::fs::read_to_string(deleted)?, "deleted-original");
+        assert!(!dir.path().join("created").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn nested_transaction_commits_into_enclosing_transaction()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let inner = dir.path().join("inner.toml");
+        let failed = dir.path().join("failed.toml");
+
+        let result = with_transaction(WriteOp::EXECUTE, |op| {
+            with_transaction(op, |op| write_file(&inner, "inner", op, None))?;
+            let nested_failure = with_transaction(op, |op| {
+                write_file(&failed, "failed", op, None)?;
+                Err::<(), _>(Diagnostic::new(
+                    crate::diagnostic::DiagnosticCode::E0903UnexpectedError,
+                    "injected nested failure",
+                    failed.display().to_string(),
+                ))
+            });
+            assert!(nested_failure.is_err());
+            assert!(!failed.exists());
+            assert!(inner.exists());
+            Err::<(), _>(Diagnostic::new(
+                crate::diagnostic::DiagnosticCode::E0903UnexpectedError,
+                "injected outer failure",
+                inner.display().to_string(),
+            ))
+        });
+
+        assert!(result.is_err());
+        assert!(!inner.exists());
+        Ok(())
+    }
+
     #[test]
     fn file_transaction_restores_deleted_file_after_failure()
     -> Result<(), Box<dyn std::error::Error>> {
@@ -518,7 +657,7 @@ mod tests {
         let path = dir.path().join("artifact.toml");
         std::fs::write(&path, "original")?;
 
-        let result = with_file_transaction(&[path.as_path()], WriteOp::Execute, || {
+        let result = with_file_transaction(&[path.as_path()], WriteOp::EXECUTE, |_| {
             std::fs::remove_file(&path).map_err(|err| {
                 Diagnostic::io_error("delete file", err, path.display().to_string())
             })?;
@@ -541,8 +680,8 @@ mod tests {
         let path = dir.path().join("artifact.toml");
         std::fs::write(&path, "original")?;
 
-        let result = with_file_transaction(&[path.as_path()], WriteOp::Execute, || {
-            write_file(&path, "replacement", WriteOp::Execute, None)?;
+        let result = with_file_transaction(&[path.as_path()], WriteOp::EXECUTE, |op| {
+            write_file(&path, "replacement", op, None)?;
             std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o444)).map_err(
                 |err| Diagnostic::io_error("make file read-only", err, path.display().to_string()),
             )?;
@@ -572,9 +711,40 @@ mod tests {
         let path = dir.path().join("artifact.toml");
         std::fs::write(&path, "original")?;
 
-        write_file(&path, "replacement", WriteOp::Execute, None)?;
+        write_file(&path, "replacement", WriteOp::EXECUTE, None)?;
 
         assert_eq!(std::fs::read_to_string(path)?, "replacement");
         Ok(())
     }
+
+    #[test]
+    fn replace_file_leaves_no_temporary_files() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("RFC-0001.md");
+        std::fs::write(&path, "original")?;
+
+        replace_file(&path, b"rendered", &path, true)?;
+
+        assert_eq!(std::fs::read_to_string(&path)?, "rendered");
+        let names: Vec<_> = std::fs::read_dir(dir.path())?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(names, vec![std::ffi::OsString::from("RFC-0001.md")]);
+        Ok(())
+    }
+
+    #[test]
+    fn unsynced_write_still_replaces_content() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("artifact.toml");
+        std::fs::write(&path, "original")?;
+        let op = WriteOp::from_dry_run(false).with_fsync(false);
+
+        write_file(&path, "replacement", op, None)?;
+
+        assert_eq!(op.mode, WriteMode::ExecuteUnsynced);
+        assert!(WriteOp::PREVIEW.with_fsync(false).is_preview());
+        assert_eq!(std::fs::read_to_string(&path)?, "replacement");
+        Ok(())
+    }
 }
@@ -0,0 +1,236 @@
+//! Layout-preserving rewrites of TOML artifacts.
+//!
+//! Artifact writes serialize the whole spec. When the file already exists,
+//! the new document is merged into the old one with `toml_edit`: unchanged
+//! values keep their formatting and comments, keys keep their order, and only
+//! the values that changed are replaced, so edits produce minimal diffs.
+
+use std::path::Path;
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table};
+
+/// `updated` laid out like the file at `path`, or `updated` as-is when the
+/// file is missing, starts with another schema header, or cannot be merged
+/// to exactly the same values.
+pub fn preserve_layout(path: &Path, updated: String) -> String {
+    match std::fs::read_to_string(path) {
+        Ok(existing) => merge_layout(&existing, &updated).unwrap_or(updated),
+        Err(_) => updated,
+    }
+}
+
+fn merge_layout(existing: &str, updated: &str) -> Option<String> {
+    if existing.lines().next() != updated.lines().next() {
+        return None;
+    }
+    let old_values: toml::Table = toml::from_str(existing).ok()?;
+    let new_values: toml::Table = toml::from_str(updated).ok()?;
+    let mut document: DocumentMut = existing.parse().ok()?;
+    let new_document: DocumentMut = updated.parse().ok()?;
+    merge_table(
+        document.as_table_mut(),
+        &old_values,
+        new_document.as_table(),
+        &new_values,
+    );
+    let merged = document.to_string();
+    // The layout is only worth keeping if the values come out identical.
+    (toml::from_str::<toml::Table>(&merged).ok()? == new_values).then_some(merged)
+}
+
+fn merge_table(old: &mut Table, old_values: &toml::Table, new: &Table, new_values: &toml::Table) {
+    old.retain(|key, _| new.contains_key(key));
+    let mut order: Vec<String> = old.iter().map(|(key, _)| key.to_string()).collect();
+    let mut inserted = false;
+    let mut previous: Option<&str> = None;
+    for (key, new_item) in new.iter() {
+        let values = old_values.get(key).zip(new_values.get(key));
+        match (old.get_mut(key), values) {
+            (Some(old_item), Some((old_value, new_value))) => {
+                if old_value != new_value {
+                    merge_item(old_item, old_value, new_item, new_value);
+                }
+            }
+            (Some(old_item), None) => *old_item = detach(new_item),
+            (None, _) => {
+                // New keys follow the key they follow in the new document.
+                let at = previous
+                    .and_then(|previous| order.iter().position(|key| key == previous))
+                    .map_or(0, |index| index + 1);
+                order.insert(at, key.to_string());
+                old.insert(key, detach(new_item));
+                inserted = true;
+            }
+        }
+        previous = Some(key);
+    }
+    if inserted {
+        let rank = |key: &str| order.iter().position(|ordered| ordered == key);
+        old.sort_values_by(|left, _, right, _| rank(left.get()).cmp(&rank(right.get())));
+    }
+}
+
+fn merge_item(old: &mut Item, old_value: &toml::Value, new: &Item, new_value: &toml::Value) {
+    match (old, new, old_value, new_value) {
+        (
+            Item::Table(old),
+            Item::Table(new),
+            toml::Value::Table(old_values),
+            toml::Value::Table(new_values),
+        ) => merge_table(old, old_values, new, new_values),
+        (
+            Item::ArrayOfTables(old),
+            Item::ArrayOfTables(new),
+            toml::Value::Array(old_values),
+            toml::Value::Array(new_values),
+        ) => merge_array_of_tables(old, old_values, new, new_values),
+        (Item::Value(old), Item::Value(new), _, _) => {
+            // Keep the spacing and trailing comment around the value.
+            let decor = old.decor().clone();
+            *old = new.clone();
+            *old.decor_mut() = decor;
+        }
+        (old, new, _, _) => *old = detach(new),
+    }
+}
+
+fn merge_array_of_tables(
+    old: &mut ArrayOfTables,
+    old_values: &[toml::Value],
+    new: &ArrayOfTables,
+    new_values: &[toml::Value],
+) {
+    while old.len() > new.len() {
+        old.remove(old.len() - 1);
+    }
+    for (index, new_table) in new.iter().enumerate() {
+        let values = old_values
+            .get(index)
+            .and_then(toml::Value::as_table)
+            .zip(new_values.get(index).and_then(toml::Value::as_table));
+        match (old.get_mut(index), values) {
+            (Some(old_table), Some((old_values, new_values))) => {
+                if old_values != new_values {
+                    merge_table(old_table, old_values, new_table, new_values);
+                }
+            }
+            (Some(old_table), None) => *old_table = detach_table(new_table),
+            (None, _) => old.push(detach_table(new_table)),
+        }
+    }
+}
+
+/// `item` without its position in the new document, so it is laid out
+/// after its siblings in the old one.
+fn detach(item: &Item) -> Item {
+    match item {
+        Item::Table(table) => Item::Table(detach_table(table)),
+        Item::ArrayOfTables(array) => {
+            let mut detached = ArrayOfTables::new();
+            for table in array.iter() {
+                detached.push(detach_table(table));
+            }
+            Item::ArrayOfTables(detached)
+        }
+        other => other.clone(),
+    }
+}
+
+fn detach_table(table: &Table) -> Table {
+    let mut detached = Table::new();
+    detached.set_implicit(table.is_implicit());
+    for (key, item) in table.iter() {
+        detached.insert(key, detach(item));
+    }
+    detached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "#:schema ../schema/work.schema.json\n\n";
+
+    fn merged(existing: &str, updated: &str) -> Option<String> {
+        merge_layout(
+            &format!("{HEADER}{existing}"),
+            &format!("{HEADER}{updated}"),
+        )
+        .map(|merged| merged.trim_start_matches(HEADER).to_string())
+    }
+
+    #[test]
+    fn changed_values_keep_comments_and_key_order() {
+        let existing = "[govctl]\n\
+                        # Shown in lists\n\
+                        title = 'Cache'  # short\n\
+                        id = \"WI-1\"\n\
+                        created = \"2026-01-01\"\n\
+                        refs = [\"RFC-0001\"]\n";
+        let updated = "[govctl]\n\
+                       id = \"WI-1\"\n\
+                       title = \"Cache API\"\n\
+                       created = \"2026-01-01\"\n\
+                       started = \"2026-01-02\"\n\
+                       refs = [\"RFC-0001\"]\n";
+        assert_eq!(
+            merged(existing, updated).as_deref(),
+            Some(
+                "[govctl]\n\
+                 # Shown in lists\n\
+                 title = \"Cache API\"  # short\n\
+                 id = \"WI-1\"\n\
+                 created = \"2026-01-01\"\n\
+                 started = \"2026-01-02\"\n\
+                 refs = [\"RFC-0001\"]\n"
+            )
+        );
+    }
+
+    #[test]
+    fn arrays_of_tables_grow_and_shrink_in_place() {
+        let existing = "[content]\n\
+                        description = \"d\"\n\n\
+                        # first\n\
+                        [[content.criteria]]\n\
+                        text = \"a\"\n\n\
+                        [[content.criteria]]\n\
+                        text = \"b\"\n\n\
+                        [verification]\n\
+                        required = []\n";
+        let grown = "[content]\n\
+                     description = \"d\"\n\n\
+                     [[content.criteria]]\n\
+                     text = \"a\"\n\n\
+                     [[content.criteria]]\n\
+                     text = \"b\"\n\n\
+                     [[content.criteria]]\n\
+                     text = \"c\"\n\n\
+                     [verification]\n\
+                     required = []\n";
+        let merged_grown = merged(existing, grown).unwrap_or_default();
+        assert!(merged_grown.contains("# first\n[[content.criteria]]\ntext = \"a\""));
+        assert!(merged_grown.contains("text = \"b\"\n\n[[content.criteria]]\ntext = \"c\""));
+        assert!(merged_grown.ends_with("[verification]\nrequired = []\n"));
+
+        let shrunk = "[content]\n\
+                      description = \"d\"\n\n\
+                      [[content.criteria]]\n\
+                      text = \"a\"\n\n\
+                      [verification]\n\
+                      required = []\n";
+        let merged_shrunk = merged(existing, shrunk).unwrap_or_default();
+        assert!(merged_shrunk.contains("# first\n[[content.criteria]]\ntext = \"a\""));
+        assert!(!merged_shrunk.contains("text = \"b\""));
+    }
+
+    #[test]
+    fn other_schema_header_is_rewritten() {
+        assert_eq!(
+            merge_layout(
+                "[govctl]\nid = \"WI-1\"\n",
+                &format!("{HEADER}[govctl]\nid = \"WI-1\"\n")
+            ),
+            None
+        );
+    }
+}
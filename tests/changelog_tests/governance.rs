@@ -0,0 +1,88 @@
+use super::*;
+
+fn done_work_item(work_id: &str, title: &str, criterion: &str) -> Vec<Vec<String>> {
+    vec![
+        work_new_active(title),
+        work_add_acceptance(work_id, criterion),
+        work_tick_acceptance_done(work_id, ""),
+        work_move_done(work_id),
+    ]
+}
+
+/// The section of `changelog` that starts at `heading`.
+fn section<'a>(changelog: &'a str, heading: &str) -> &'a str {
+    let start = changelog.find(heading).unwrap_or(changelog.len());
+    let rest = &changelog[start..];
+    let end = rest[heading.len()..]
+        .find("\n## ")
+        .map_or(rest.len(), |end| end + heading.len());
+    &rest[..end]
+}
+
+#[test]
+fn test_changelog_lists_rfc_versions_and_accepted_adrs_per_release() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+    let dir = temp_dir.path();
+    let date = today();
+    let wi1 = format!("WI-{}-001", date);
+    let wi2 = format!("WI-{}-002", date);
+
+    let mut commands = vec![command(&["init"])];
+    commands.extend(done_work_item(
+        &wi1,
+        "Scaffolding",
+        "add: Project scaffolding",
+    ));
+    commands.extend([
+        command(&["release", "0.1.0", "--date", "2026-01-15"]),
+        command(&["rfc", "new", "Payments"]),
+        command(&["adr", "new", "Use a ledger"]),
+        command(&["adr", "accept", "ADR-0001", "--force"]),
+        command(&["adr", "new", "Still under discussion"]),
+        command(&["render", "changelog"]),
+    ]);
+    run_dynamic_commands(dir, &commands)?;
+    let changelog = std::fs::read_to_string(dir.join("CHANGELOG.md"))?;
+    assert!(!changelog.contains("### Specifications"), "{changelog}");
+    assert!(!changelog.contains("ADR-0001"), "{changelog}");
+
+    let config = dir.join("gov/config.toml");
+    let mut content = std::fs::read_to_string(&config)?;
+    content.push_str("\n[render.changelog]\nrfc_versions = true\nadr_acceptances = true\n");
+    std::fs::write(&config, content)?;
+    run_commands(dir, &[&["render", "changelog"]])?;
+    let changelog = std::fs::read_to_string(dir.join("CHANGELOG.md"))?;
+    let unreleased = section(&changelog, "## [Unreleased]");
+    assert!(
+        unreleased.contains(
+            "### Specifications\n\n- [RFC-0001](docs/rfc/RFC-0001.md) Payments 0.1.0: Initial draft\n"
+        ),
+        "{changelog}"
+    );
+    assert!(
+        unreleased.contains("### Decisions\n\n- [ADR-0001](docs/adr/ADR-0001.md) Use a ledger\n"),
+        "{changelog}"
+    );
+    assert!(!unreleased.contains("ADR-0002"), "{changelog}");
+    assert!(
+        !section(&changelog, "## [0.1.0]").contains("RFC-0001"),
+        "{changelog}"
+    );
+
+    let mut commands = done_work_item(&wi2, "Ledger", "add: Ledger entries");
+    commands.extend([
+        command(&["release", "0.2.0"]),
+        command(&["render", "changelog"]),
+    ]);
+    run_dynamic_commands(dir, &commands)?;
+    let changelog = std::fs::read_to_string(dir.join("CHANGELOG.md"))?;
+    assert!(
+        !section(&changelog, "## [Unreleased]").contains("###"),
+        "{changelog}"
+    );
+    let released = section(&changelog, "## [0.2.0]");
+    assert!(released.contains("- Ledger entries ("), "{changelog}");
+    assert!(released.contains("Payments 0.1.0"), "{changelog}");
+    assert!(released.contains("Use a ledger"), "{changelog}");
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use super::*;
 
+mod governance;
 mod preservation;
 mod release_workflow;
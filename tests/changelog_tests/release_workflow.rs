@@ -163,7 +163,15 @@ fn test_release_undo_restores_unreleased_projection_and_reopen_boundary() -> com
     let reopen = run_commands(
         dir,
         &[
-            &["work", "move", &wi2, "active"],
+            &[
+                "work",
+                "move",
+                &wi2,
+                "active",
+                "--reopen",
+                "--reason",
+                "Correction incomplete",
+            ],
             &["work", "get", &wi2, "status"],
         ],
     )?;
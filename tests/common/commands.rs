@@ -124,7 +124,9 @@ fn append_command_output(
         .args(args)
         .current_dir(dir)
         .env("NO_COLOR", "1")
+        .env_remove("COLUMNS")
         .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
         .output()?;
 
     output.push_str(&format_command_output(args, &result));
@@ -18,7 +18,8 @@ pub fn init_project_at(schema_version: Option<u32>) -> Result<TempDir, Box<dyn s
     cmd.args(["init"])
         .current_dir(temp_dir.path())
         .env("NO_COLOR", "1")
-        .env("GOVCTL_DEFAULT_OWNER", "@test-user");
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user");
 
     if let Some(v) = schema_version {
         cmd.env("GOVCTL_SCHEMA_VERSION", v.to_string());
@@ -0,0 +1,80 @@
+//! A local stand-in for the REST APIs govctl talks to: canned responses on a
+//! loopback port, with every request recorded.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+/// A request the mock server received.
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: String,
+    /// Path including any query string.
+    pub path: String,
+    /// The `authorization` header line as sent; empty when there was none.
+    pub authorization: String,
+    pub body: String,
+}
+
+/// Requests recorded by [`mock_server`], in the order they arrived.
+pub type RecordedRequests = Arc<Mutex<Vec<MockRequest>>>;
+
+/// Serve `respond(method, path)`, a status such as `200 OK` and a JSON body,
+/// on a local port.
+pub fn mock_server(
+    respond: impl Fn(&str, &str) -> (&'static str, &'static str) + Send + 'static,
+) -> std::io::Result<(u16, RecordedRequests)> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let Some(request) = read_request(&mut BufReader::new(&mut stream)) else {
+                continue;
+            };
+            let (status, response) = respond(&request.method, &request.path);
+            if let Ok(mut log) = recorded.lock() {
+                log.push(request);
+            }
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            );
+        }
+    });
+    Ok((port, requests))
+}
+
+fn read_request(reader: &mut impl BufRead) -> Option<MockRequest> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+    let mut authorization = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() || line.trim().is_empty() {
+            break;
+        }
+        let lower = line.to_ascii_lowercase();
+        if let Some(value) = lower.strip_prefix("content-length:") {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+        if lower.starts_with("authorization:") {
+            authorization = line.trim().to_string();
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+    Some(MockRequest {
+        method,
+        path,
+        authorization,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
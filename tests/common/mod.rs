@@ -5,6 +5,7 @@
 mod commands;
 mod fixtures;
 pub mod loop_helpers;
+mod mock_http;
 mod snapshots;
 
 // Each integration test binary imports a different subset of this facade.
@@ -23,6 +24,8 @@ pub use fixtures::{
     write_guard, write_guard_with_timeout, write_guarded_work_item, write_minimal_rfc,
 };
 #[allow(unused_imports)]
+pub use mock_http::{MockRequest, RecordedRequests, mock_server};
+#[allow(unused_imports)]
 pub use snapshots::{
     current_test_snapshot_name, named_snapshot_name, normalize_output, snapshot_path,
 };
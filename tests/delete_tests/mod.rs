@@ -1,4 +1,5 @@
 use super::*;
 
 mod clause;
+mod restore;
 mod work;
@@ -0,0 +1,106 @@
+use super::*;
+
+/// Test: Deleted clause is kept in the trash and restored into its section
+#[test]
+fn test_restore_deleted_clause() -> TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-TTL",
+                "Cache TTL",
+                "-s",
+                "Behavior",
+                "-k",
+                "normative",
+            ],
+            &["clause", "delete", "RFC-0001:C-TTL", "-f"],
+        ],
+    )?;
+    assert!(
+        output.contains("restore with: govctl restore RFC-0001:C-TTL"),
+        "output: {output}"
+    );
+    let clause_path = temp_dir.path().join("gov/rfc/RFC-0001/clauses/C-TTL.toml");
+    let trash_dir = temp_dir.path().join("gov/.trash/RFC-0001_C-TTL");
+    assert!(!clause_path.exists());
+    assert!(trash_dir.join("C-TTL.toml").exists());
+    let tombstone = fs::read_to_string(trash_dir.join("tombstone.toml"))?;
+    assert!(
+        tombstone.contains("path = \"rfc/RFC-0001/clauses/C-TTL.toml\""),
+        "tombstone: {tombstone}"
+    );
+    assert!(
+        tombstone.contains("section = \"Behavior\""),
+        "tombstone: {tombstone}"
+    );
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["restore", "RFC-0001:C-TTL"],
+            &["clause", "list", "RFC-0001"],
+            &["check"],
+        ],
+    )?;
+    assert!(
+        output.contains("Restored RFC-0001:C-TTL"),
+        "output: {output}"
+    );
+    assert!(output.contains("C-TTL"), "output: {output}");
+    assert!(!output.contains("exit: 1"), "output: {output}");
+    assert!(clause_path.exists());
+    assert!(!trash_dir.exists());
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("clauses/C-TTL.toml"), "rfc: {rfc}");
+    Ok(())
+}
+
+/// Test: Deleted work item is restored to its original file
+#[test]
+fn test_restore_deleted_work_item() -> TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let wi1 = first_work_id(&date);
+    let commands: Vec<Vec<String>> = vec![
+        work_new("Delete this work item"),
+        work_delete_force(&wi1),
+        command(&["restore", &wi1]),
+        command(&["work", "list"]),
+        command(&["restore", &wi1]),
+    ];
+
+    let output = run_dynamic_commands(temp_dir.path(), &commands)?;
+    assert!(
+        output.contains(&format!("Restored {wi1}")),
+        "output: {output}"
+    );
+    assert!(output.contains("Delete this work item"), "output: {output}");
+    assert!(output.contains("error[E0402]"), "output: {output}");
+    assert!(output.contains("is not in the trash"), "output: {output}");
+    Ok(())
+}
+
+/// Test: Restore refuses to overwrite a work item that reused the ID
+#[test]
+fn test_restore_refuses_taken_work_id() -> TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let wi1 = first_work_id(&date);
+    let commands: Vec<Vec<String>> = vec![
+        work_new("Delete this work item"),
+        work_delete_force(&wi1),
+        work_new("Replacement work item"),
+        command(&["restore", &wi1]),
+    ];
+
+    let output = run_dynamic_commands(temp_dir.path(), &commands)?;
+    assert!(output.contains("error[E0822]"), "output: {output}");
+    assert!(
+        output.contains("a work item with that ID already exists"),
+        "output: {output}"
+    );
+    Ok(())
+}
@@ -104,7 +104,7 @@ fn test_delete_work_safeguard_depended_on() -> TestResult {
 
     let output = run_dynamic_commands(temp_dir.path(), &[work_delete_force(&wi1)])?;
 
-    assert!(output.contains("exit: 1"), "output: {}", output);
+    assert!(output.contains("exit: 3"), "output: {}", output);
     assert!(
         output.contains("Cannot delete work item"),
         "output: {}",
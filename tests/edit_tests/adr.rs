@@ -268,3 +268,83 @@ fn test_adr_get_nonexistent() -> common::TestResult {
     assert_edit_snapshot!(normalize_output(&output, temp_dir.path(), &date)?);
     Ok(())
 }
+
+#[test]
+fn test_adr_alt_add_and_set_structured_alternatives() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "new", "Test Decision"],
+            &[
+                "adr",
+                "alt",
+                "add",
+                "ADR-0001",
+                "Use PostgreSQL",
+                "--pro",
+                "Mature",
+                "--con",
+                "Ops cost",
+            ],
+            &[
+                "adr",
+                "alt",
+                "add",
+                "ADR-0001",
+                "Use Redis",
+                "--reject",
+                "Not durable",
+            ],
+            &[
+                "adr", "alt", "set", "ADR-0001", "postgres", "--pro", "Cheap", "--pro", "Known",
+            ],
+            &[
+                "adr",
+                "alt",
+                "set",
+                "ADR-0001",
+                "--at",
+                "-1",
+                "--con",
+                "Memory bound",
+            ],
+            &["adr", "alt", "set", "ADR-0001", "use", "--pro", "Ambiguous"],
+            &["adr", "get", "ADR-0001", "alternatives"],
+            &["adr", "get", "ADR-0001", "alternatives[0].pros"],
+            &["adr", "get", "ADR-0001", "alternatives[0].cons"],
+            &["adr", "get", "ADR-0001", "alternatives[1].cons"],
+            &["adr", "get", "ADR-0001", "alternatives[1].rejection_reason"],
+        ],
+    )?;
+    assert_edit_snapshot!(normalize_output(&output, temp_dir.path(), &date)?);
+    Ok(())
+}
+
+#[test]
+fn test_adr_add_drivers_and_stakeholders() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "new", "Test Decision"],
+            &[
+                "adr",
+                "add",
+                "ADR-0001",
+                "drivers",
+                "Low latency",
+                "Small team",
+            ],
+            &["adr", "add", "ADR-0001", "stakeholders", "Platform team"],
+            &["adr", "remove", "ADR-0001", "drivers", "small"],
+            &["adr", "get", "ADR-0001", "drivers"],
+            &["adr", "get", "ADR-0001", "stakeholders"],
+            &["check"],
+        ],
+    )?;
+    assert_edit_snapshot!(normalize_output(&output, temp_dir.path(), &date)?);
+    Ok(())
+}
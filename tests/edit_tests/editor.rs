@@ -0,0 +1,80 @@
+use super::*;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::Command;
+
+// ============================================================================
+// Whole-Artifact Editor Tests
+// ============================================================================
+
+/// Write an editor script that applies `sed_expr` to the buffer it is given.
+fn sed_editor(dir: &Path, sed_expr: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let script = dir.join("editor.sh");
+    std::fs::write(&script, format!("#!/bin/sh\nsed -i '{sed_expr}' \"$1\"\n"))?;
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+    Ok(script.display().to_string())
+}
+
+fn run_with_editor(
+    dir: &Path,
+    editor: &str,
+    args: &[&str],
+) -> Result<String, Box<dyn std::error::Error>> {
+    let result = Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
+        .env_remove("VISUAL")
+        .env("EDITOR", editor)
+        .env("TMPDIR", dir)
+        .output()?;
+    Ok(common::format_command_output(args, &result))
+}
+
+#[test]
+fn test_edit_in_editor_writes_valid_changes() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(temp_dir.path(), &[&["rfc", "new", "Original Title"]])?;
+    let editor = sed_editor(temp_dir.path(), "s/^title = .*/title = \"Edited Title\"/")?;
+
+    let output = run_with_editor(temp_dir.path(), &editor, &["edit", "RFC-0001", "--editor"])?;
+    assert!(output.contains("Updated RFC: RFC-0001"), "output: {output}");
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["rfc", "get", "RFC-0001", "title"], &["check"]],
+    )?;
+    assert!(output.contains("Edited Title"), "output: {output}");
+    assert!(output.ends_with("exit: 0\n\n"), "output: {output}");
+
+    let output = run_with_editor(temp_dir.path(), "true", &["edit", "RFC-0001", "--editor"])?;
+    assert!(
+        output.contains("No changes to RFC-0001"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_edit_in_editor_rejects_invalid_changes() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(temp_dir.path(), &[&["adr", "new", "Use TOML"]])?;
+    let adr_path = temp_dir.path().join("gov/adr/ADR-0001-use-toml.toml");
+    let before = std::fs::read_to_string(&adr_path)?;
+
+    let editor = sed_editor(temp_dir.path(), "s/^status = .*/status = 7/")?;
+    let output = run_with_editor(temp_dir.path(), &editor, &["edit", "ADR-0001", "--editor"])?;
+    assert!(output.contains("E0301"), "output: {output}");
+    assert!(output.contains("edits kept in"), "output: {output}");
+    assert!(output.ends_with("exit: 3\n\n"), "output: {output}");
+
+    let editor = sed_editor(temp_dir.path(), "s/ADR-0001/ADR-0009/")?;
+    let output = run_with_editor(temp_dir.path(), &editor, &["edit", "ADR-0001", "--editor"])?;
+    assert!(output.contains("E0804"), "output: {output}");
+
+    let output = run_with_editor(temp_dir.path(), "false", &["edit", "ADR-0001", "--editor"])?;
+    assert!(output.contains("E0823"), "output: {output}");
+    assert_eq!(std::fs::read_to_string(&adr_path)?, before);
+    Ok(())
+}
@@ -2,6 +2,8 @@ use super::*;
 
 mod adr;
 mod clause;
+#[cfg(unix)]
+mod editor;
 mod path_cases;
 mod rfc;
 mod work_tests;
@@ -202,3 +202,67 @@ fn test_work_remove_acceptance_criteria() -> common::TestResult {
     assert_edit_snapshot!(normalize_output(&output, temp_dir.path(), &date)?);
     Ok(())
 }
+
+#[test]
+fn test_work_add_many_acceptance_criteria_in_one_command() -> common::TestResult {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "new", "Test Task"],
+            &[
+                "work",
+                "add",
+                &id,
+                ACCEPTANCE_CRITERIA,
+                "add: One",
+                "fix: Two",
+            ],
+            &[
+                "work",
+                "add",
+                &id,
+                ACCEPTANCE_CRITERIA,
+                "add: Three",
+                "Four",
+            ],
+        ],
+    )?;
+    assert!(output.contains("error["), "{output}");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args([
+            "work",
+            "add",
+            &id,
+            ACCEPTANCE_CRITERIA,
+            "--stdin",
+            "--lines",
+        ])
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(b"chore: Five\n\n  add: Six  \n")?;
+    }
+    let result = child.wait_with_output()?;
+    assert!(result.status.success(), "{result:?}");
+
+    let output = run_commands(temp_dir.path(), &[&["work", "show", &id]])?;
+    let positions: Vec<usize> = ["One", "Two", "Five", "Six"]
+        .iter()
+        .map(|text| output.find(text).ok_or(format!("missing {text}: {output}")))
+        .collect::<Result<_, _>>()?;
+    assert!(positions.is_sorted(), "{output}");
+    // The rejected second command added nothing.
+    assert!(!output.contains("Three"), "{output}");
+    Ok(())
+}
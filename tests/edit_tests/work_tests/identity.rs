@@ -107,3 +107,54 @@ fn test_work_new_random_id_strategy_uses_random_suffix() -> common::TestResult {
     );
     Ok(())
 }
+
+#[test]
+fn test_work_new_ulid_id_strategy_with_prefix_is_accepted_everywhere() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+
+    set_work_item_id_strategy(temp_dir.path(), "ulid")?;
+    let config_path = temp_dir.path().join("gov").join("config.toml");
+    let content = std::fs::read_to_string(&config_path)?;
+    std::fs::write(&config_path, format!("{content}id_prefix = \"PAY\"\n"))?;
+    run_commands(
+        temp_dir.path(),
+        &[&["work", "new", "First"], &["work", "new", "Second"]],
+    )?;
+
+    let ids = read_work_ids(temp_dir.path())?;
+    let id_pattern = regex::Regex::new(r"^WI-PAY-[0-9A-HJKMNP-TV-Z]{26}$")?;
+    assert!(
+        ids.iter().all(|id| id_pattern.is_match(id)),
+        "unexpected ulid work item ids: {ids:?}"
+    );
+    assert_ne!(ids[0], ids[1]);
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "add", &ids[1], "depends_on", &ids[0]],
+            &["work", "move", &ids[0], "active"],
+            &["check"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_work_new_rejects_invalid_id_prefix() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+
+    let config_path = temp_dir.path().join("gov").join("config.toml");
+    let content = std::fs::read_to_string(&config_path)?;
+    std::fs::write(
+        &config_path,
+        format!("{content}\n[work_item]\nid_prefix = \"pay-\"\n"),
+    )?;
+    let output = run_commands(temp_dir.path(), &[&["work", "new", "First"]])?;
+    assert!(
+        output.contains("error[E0501]: Invalid work_item.id_prefix 'pay-'"),
+        "output: {output}"
+    );
+    Ok(())
+}
@@ -220,7 +220,7 @@ text = "This clause is superseded."
         output.contains("error[E0213]: Superseded clause 'C-OLD' has no superseded_by target"),
         "output: {output}"
     );
-    assert!(output.ends_with("exit: 1\n\n"), "output: {output}");
+    assert!(output.ends_with("exit: 3\n\n"), "output: {output}");
     Ok(())
 }
 
@@ -291,7 +291,7 @@ text = "Clause two."
         output.contains("Clause supersession cycle detected"),
         "output: {output}"
     );
-    assert!(output.ends_with("exit: 1\n\n"), "output: {output}");
+    assert!(output.ends_with("exit: 3\n\n"), "output: {output}");
     Ok(())
 }
 
@@ -388,7 +388,7 @@ text = "Clause two."
         ),
         "output: {output}"
     );
-    assert!(output.ends_with("exit: 1\n\n"), "output: {output}");
+    assert!(output.ends_with("exit: 3\n\n"), "output: {output}");
     Ok(())
 }
 
@@ -640,7 +640,7 @@ consequences = "Consequences"
         "output: {}",
         output
     );
-    assert!(output.contains("exit: 1"), "output: {}", output);
+    assert!(output.contains("exit: 3"), "output: {}", output);
     Ok(())
 }
 
@@ -22,9 +22,13 @@ fn test_clause_commands_reject_invalid_clause_id_format() -> common::TestResult
         ],
     )?;
 
+    // Format errors exit 1; lookups of a malformed ID exit 4 (not found).
     assert_eq!(
-        output.matches("exit: 1").count(),
-        4,
+        (
+            output.matches("exit: 1").count(),
+            output.matches("exit: 4").count()
+        ),
+        (2, 2),
         "all malformed clause commands should fail: {output}"
     );
     assert!(
@@ -293,3 +293,33 @@ description = "Work description"
     );
     Ok(())
 }
+
+/// Test: `new --interactive` without a terminal fails cleanly and creates nothing
+#[test]
+fn test_interactive_new_without_terminal_is_rejected() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "new", "--interactive"],
+            &["rfc", "new", "Wizard RFC", "--interactive"],
+            &["adr", "new"],
+            &["work", "list", "all"],
+        ],
+    )?;
+    assert!(output.contains("error[E0824]"), "output: {}", output);
+    assert!(
+        output.contains("--interactive needs a terminal"),
+        "output: {}",
+        output
+    );
+    assert!(
+        output.contains("required arguments were not provided"),
+        "output: {}",
+        output
+    );
+    assert!(!output.contains("Created"), "output: {}", output);
+    assert!(!temp_dir.path().join("gov/rfc/RFC-0001").exists());
+    Ok(())
+}
@@ -96,7 +96,7 @@ fn test_guard_delete_missing_returns_coded_error() -> common::TestResult {
     let temp_dir = init_project()?;
 
     let output = run_commands(temp_dir.path(), &[&["guard", "delete", "GUARD-MISSING"]])?;
-    assert!(output.contains("exit: 1"), "output: {}", output);
+    assert!(output.contains("exit: 4"), "output: {}", output);
     assert!(output.contains("error[E1002]"), "output: {}", output);
     Ok(())
 }
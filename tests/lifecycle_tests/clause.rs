@@ -562,7 +562,7 @@ fn test_supersede_clause_rejects_unqualified_cross_rfc_replacement() -> common::
         output.contains("Replacement clause not found: RFC-0001:C-TARGET"),
         "output: {output}"
     );
-    assert!(output.ends_with("exit: 1\n\n"), "output: {output}");
+    assert!(output.ends_with("exit: 4\n\n"), "output: {output}");
     Ok(())
 }
 
@@ -600,7 +600,7 @@ fn test_supersede_clause_rejects_self_replacement() -> common::TestResult {
         "output: {output}"
     );
     assert!(output.contains("error[E0212]"), "output: {output}");
-    assert!(output.ends_with("exit: 1\n\n"), "output: {output}");
+    assert!(output.ends_with("exit: 3\n\n"), "output: {output}");
     Ok(())
 }
 
@@ -648,7 +648,7 @@ fn test_supersede_clause_rejects_deprecated_replacement() -> common::TestResult
         output.contains("Cannot supersede by a deprecated clause: RFC-0001:C-REPLACEMENT"),
         "output: {output}"
     );
-    assert!(output.ends_with("exit: 1\n\n"), "output: {output}");
+    assert!(output.ends_with("exit: 5\n\n"), "output: {output}");
     Ok(())
 }
 
@@ -713,7 +713,7 @@ fn test_supersede_clause_rejects_superseded_replacement() -> common::TestResult
         output.contains("Cannot supersede by a superseded clause: RFC-0001:C-REPLACEMENT"),
         "output: {output}"
     );
-    assert!(output.ends_with("exit: 1\n\n"), "output: {output}");
+    assert!(output.ends_with("exit: 5\n\n"), "output: {output}");
     Ok(())
 }
 
@@ -782,7 +782,7 @@ fn test_supersede_clause_rejects_repeated_transition() -> common::TestResult {
         "output: {output}"
     );
     assert!(
-        output.contains("error[E0209]: Clause is already superseded. Superseded is terminal; there are no valid transitions (RFC-0001:C-OLD)\nexit: 1"),
+        output.contains("error[E0209]: Clause is already superseded. Superseded is terminal; there are no valid transitions (RFC-0001:C-OLD)\nexit: 5"),
         "output: {output}"
     );
     assert!(
@@ -1032,3 +1032,108 @@ fn test_deprecate_clause_superseded_fails() -> common::TestResult {
     assert_lifecycle_snapshot!(normalize_output(&output, temp_dir.path(), &date)?);
     Ok(())
 }
+
+// ============================================================================
+// Clause Reorder Tests
+// ============================================================================
+
+fn setup_rfc_with_three_clauses(dir: &std::path::Path) -> common::TestResult {
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Reorder RFC"],
+            &["clause", "new", "RFC-0001:C-ONE", "One"],
+            &["clause", "new", "RFC-0001:C-TWO", "Two"],
+            &["clause", "new", "RFC-0001:C-THREE", "Three"],
+        ],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_clause_reorder_moves_listed_clauses_first() -> common::TestResult {
+    let temp_dir = init_project()?;
+    setup_rfc_with_three_clauses(temp_dir.path())?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&[
+            "clause",
+            "reorder",
+            "RFC-0001",
+            "--section",
+            "Specification",
+            "--order",
+            "C-THREE,RFC-0001:C-ONE",
+        ]],
+    )?;
+    assert!(output.contains("exit: 0"), "output: {output}");
+    assert!(
+        output.contains("Section 'Specification': C-THREE, C-ONE, C-TWO"),
+        "output: {output}"
+    );
+
+    let rfc: toml::Value = toml::from_str(&fs::read_to_string(
+        temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"),
+    )?)?;
+    let clauses: Vec<&str> = rfc
+        .get("sections")
+        .and_then(toml::Value::as_array)
+        .and_then(|sections| {
+            sections.iter().find(|section| {
+                section.get("title").and_then(toml::Value::as_str) == Some("Specification")
+            })
+        })
+        .and_then(|section| section.get("clauses"))
+        .and_then(toml::Value::as_array)
+        .ok_or("Specification clauses missing")?
+        .iter()
+        .filter_map(toml::Value::as_str)
+        .collect();
+    assert_eq!(
+        clauses,
+        [
+            "clauses/C-THREE.toml",
+            "clauses/C-ONE.toml",
+            "clauses/C-TWO.toml"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_clause_reorder_rejects_unknown_section_and_clause() -> common::TestResult {
+    let temp_dir = init_project()?;
+    setup_rfc_with_three_clauses(temp_dir.path())?;
+    let rfc_path = temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml");
+    let before = fs::read_to_string(&rfc_path)?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &[
+                "clause", "reorder", "RFC-0001", "-s", "Appendix", "--order", "C-ONE",
+            ],
+            &[
+                "clause",
+                "reorder",
+                "RFC-0001",
+                "--order",
+                "C-TWO,C-MISSING",
+            ],
+            &["clause", "reorder", "RFC-0001", "--order", "RFC-0002:C-ONE"],
+        ],
+    )?;
+    assert!(
+        output.contains("error[E0116]: Section 'Appendix' not found in RFC-0001"),
+        "output: {output}"
+    );
+    assert!(
+        output
+            .contains("error[E0202]: Clause RFC-0001:C-MISSING is not in section 'Specification'"),
+        "output: {output}"
+    );
+    assert!(output.contains("error[E0802]"), "output: {output}");
+    assert_eq!(fs::read_to_string(&rfc_path)?, before);
+    Ok(())
+}
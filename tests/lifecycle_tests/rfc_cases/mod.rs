@@ -4,3 +4,5 @@ mod advance;
 mod bump;
 mod deprecation;
 mod finalize;
+mod restructure;
+mod sections;
@@ -0,0 +1,310 @@
+use super::*;
+
+// ============================================================================
+// RFC Split/Merge Tests
+// ============================================================================
+
+fn read_toml(path: &std::path::Path) -> Result<toml::Value, Box<dyn std::error::Error>> {
+    Ok(toml::from_str(&fs::read_to_string(path)?)?)
+}
+
+#[test]
+fn test_split_draft_rfc_moves_clauses() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Source RFC"],
+            &["clause", "new", "RFC-0001:C-A", "A"],
+            &["clause", "new", "RFC-0001:C-B", "B", "-s", "Summary"],
+            &["clause", "new", "RFC-0001:C-KEEP", "Keep"],
+            &[
+                "rfc",
+                "split",
+                "RFC-0001",
+                "--clauses",
+                "C-A,RFC-0001:C-B",
+                "--into",
+                "RFC-0010",
+                "Split RFC",
+                "--force",
+            ],
+            &["check"],
+        ],
+    )?;
+    assert!(
+        output.contains("Moved RFC-0001:C-A -> RFC-0010:C-A"),
+        "output: {output}"
+    );
+    assert!(output.ends_with("exit: 0\n\n"), "output: {output}");
+
+    let rfc_dir = temp_dir.path().join("gov/rfc");
+    assert!(!rfc_dir.join("RFC-0001/clauses/C-A.toml").exists());
+    assert!(!rfc_dir.join("RFC-0001/clauses/C-B.toml").exists());
+    assert!(rfc_dir.join("RFC-0010/clauses/C-A.toml").exists());
+    assert!(rfc_dir.join("RFC-0010/clauses/C-B.toml").exists());
+
+    let target = read_toml(&rfc_dir.join("RFC-0010/rfc.toml"))?;
+    assert_eq!(target["govctl"]["status"].as_str(), Some("draft"));
+    assert_eq!(target["govctl"]["refs"][0].as_str(), Some("RFC-0001"));
+    assert_eq!(target["sections"][0]["title"].as_str(), Some("Summary"));
+    assert_eq!(
+        target["sections"][1]["clauses"][0].as_str(),
+        Some("clauses/C-A.toml")
+    );
+
+    let source = read_toml(&rfc_dir.join("RFC-0001/rfc.toml"))?;
+    assert_eq!(
+        source["changelog"][0]["removed"][0].as_str(),
+        Some("Move C-A, C-B to [[RFC-0010]]")
+    );
+    assert_eq!(
+        source["sections"][1]["clauses"][0].as_str(),
+        Some("clauses/C-KEEP.toml")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_split_normative_rfc_supersedes_clauses() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Source RFC"],
+            &["clause", "new", "RFC-0001:C-A", "A"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &[
+                "rfc",
+                "split",
+                "RFC-0001",
+                "--clauses",
+                "C-A",
+                "--into",
+                "RFC-0002",
+                "Split RFC",
+                "-f",
+            ],
+        ],
+    )?;
+    assert!(
+        output.contains("Superseded RFC-0001:C-A by RFC-0002:C-A"),
+        "output: {output}"
+    );
+
+    let rfc_dir = temp_dir.path().join("gov/rfc");
+    let original = read_toml(&rfc_dir.join("RFC-0001/clauses/C-A.toml"))?;
+    assert_eq!(original["govctl"]["status"].as_str(), Some("superseded"));
+    assert_eq!(
+        original["govctl"]["superseded_by"].as_str(),
+        Some("RFC-0002:C-A")
+    );
+    let copy = read_toml(&rfc_dir.join("RFC-0002/clauses/C-A.toml"))?;
+    assert_eq!(copy["govctl"]["status"].as_str(), Some("active"));
+    assert!(copy["govctl"].get("since").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_split_rejects_existing_target_and_unknown_clause() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Source RFC"],
+            &["rfc", "new", "Other RFC"],
+            &["clause", "new", "RFC-0001:C-A", "A"],
+            &[
+                "rfc",
+                "split",
+                "RFC-0001",
+                "--clauses",
+                "C-A",
+                "--into",
+                "RFC-0002",
+                "X",
+                "-f",
+            ],
+            &[
+                "rfc",
+                "split",
+                "RFC-0001",
+                "--clauses",
+                "C-NOPE",
+                "--into",
+                "RFC-0003",
+                "X",
+                "-f",
+            ],
+        ],
+    )?;
+    assert!(output.contains("error[E0109]"), "output: {output}");
+    assert!(output.contains("error[E0202]"), "output: {output}");
+    assert!(!temp_dir.path().join("gov/rfc/RFC-0003").exists());
+    assert!(
+        temp_dir
+            .path()
+            .join("gov/rfc/RFC-0001/clauses/C-A.toml")
+            .exists()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_split_rejects_inactive_clause() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Source RFC"],
+            &["clause", "new", "RFC-0001:C-OLD", "Old"],
+            &["clause", "deprecate", "RFC-0001:C-OLD", "--force"],
+            &[
+                "rfc",
+                "split",
+                "RFC-0001",
+                "--clauses",
+                "C-OLD",
+                "--into",
+                "RFC-0002",
+                "X",
+                "-f",
+            ],
+        ],
+    )?;
+    assert!(
+        output.contains("error[E0218]: Cannot relocate deprecated clause: RFC-0001:C-OLD"),
+        "output: {output}"
+    );
+    assert!(!temp_dir.path().join("gov/rfc/RFC-0002").exists());
+    Ok(())
+}
+
+#[test]
+fn test_merge_rfc_supersedes_source() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Target RFC"],
+            &["rfc", "new", "Source RFC"],
+            &[
+                "clause",
+                "new",
+                "RFC-0002:C-MOVED",
+                "Moved",
+                "-s",
+                "Details",
+            ],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "finalize", "RFC-0002", "normative"],
+            &["rfc", "merge", "RFC-0002", "--into", "RFC-0001", "--force"],
+            &["check"],
+        ],
+    )?;
+    assert!(
+        output.contains("Superseded RFC: RFC-0002"),
+        "output: {output}"
+    );
+    assert!(output.ends_with("exit: 0\n\n"), "output: {output}");
+
+    let rfc_dir = temp_dir.path().join("gov/rfc");
+    let target = read_toml(&rfc_dir.join("RFC-0001/rfc.toml"))?;
+    assert_eq!(target["govctl"]["supersedes"].as_str(), Some("RFC-0002"));
+    assert_eq!(
+        target["changelog"][0]["added"][0].as_str(),
+        Some("Merge C-MOVED from [[RFC-0002]]")
+    );
+    let details = target["sections"]
+        .as_array()
+        .and_then(|sections| {
+            sections
+                .iter()
+                .find(|s| s["title"].as_str() == Some("Details"))
+        })
+        .ok_or("Details section missing")?;
+    assert_eq!(details["clauses"][0].as_str(), Some("clauses/C-MOVED.toml"));
+
+    let copy = read_toml(&rfc_dir.join("RFC-0001/clauses/C-MOVED.toml"))?;
+    assert_eq!(copy["govctl"]["since"].as_str(), Some("0.1.0"));
+    let source = read_toml(&rfc_dir.join("RFC-0002/rfc.toml"))?;
+    assert_eq!(source["govctl"]["status"].as_str(), Some("deprecated"));
+    let original = read_toml(&rfc_dir.join("RFC-0002/clauses/C-MOVED.toml"))?;
+    assert_eq!(
+        original["govctl"]["superseded_by"].as_str(),
+        Some("RFC-0001:C-MOVED")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_merge_draft_rfc_moves_clauses_and_removes_source() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Target RFC"],
+            &["rfc", "new", "Source RFC"],
+            &["clause", "new", "RFC-0002:C-A", "A", "-s", "Details"],
+            &["clause", "new", "RFC-0002:C-B", "B"],
+            &["rfc", "merge", "RFC-0002", "--into", "RFC-0001", "--force"],
+            &["check"],
+        ],
+    )?;
+    assert!(
+        output.contains("Merged draft RFC-0002 into RFC-0001"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Moved RFC-0002:C-A -> RFC-0001:C-A"),
+        "output: {output}"
+    );
+    assert!(output.ends_with("exit: 0\n\n"), "output: {output}");
+
+    let rfc_dir = temp_dir.path().join("gov/rfc");
+    assert!(!rfc_dir.join("RFC-0002").exists());
+    assert!(rfc_dir.join("RFC-0001/clauses/C-A.toml").exists());
+    assert!(rfc_dir.join("RFC-0001/clauses/C-B.toml").exists());
+    let target = read_toml(&rfc_dir.join("RFC-0001/rfc.toml"))?;
+    assert_eq!(target["govctl"]["status"].as_str(), Some("draft"));
+    assert!(target["govctl"].get("supersedes").is_none());
+    assert_eq!(
+        target["changelog"][0]["added"][0].as_str(),
+        Some("Merge C-B, C-A from draft RFC-0002")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_merge_rejects_referenced_draft_source_without_mutation() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Target RFC"],
+            &["rfc", "new", "Source RFC"],
+            &["clause", "new", "RFC-0002:C-A", "A"],
+            &["rfc", "new", "Consumer RFC"],
+            &["rfc", "add", "RFC-0003", "refs", "RFC-0002"],
+        ],
+    )?;
+    let target_path = temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml");
+    let before = fs::read(&target_path)?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["rfc", "merge", "RFC-0002", "--into", "RFC-0001", "-f"]],
+    )?;
+    assert!(
+        output
+            .contains("error[E0104]: Cannot remove draft RFC: RFC-0002 is referenced by: RFC-0003"),
+        "output: {output}"
+    );
+    assert_eq!(fs::read(&target_path)?, before);
+    assert!(
+        temp_dir
+            .path()
+            .join("gov/rfc/RFC-0002/clauses/C-A.toml")
+            .exists()
+    );
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+// ============================================================================
+// RFC Section Management Tests
+// ============================================================================
+
+fn section_titles(dir: &std::path::Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let rfc: toml::Value =
+        toml::from_str(&fs::read_to_string(dir.join("gov/rfc/RFC-0001/rfc.toml"))?)?;
+    Ok(rfc
+        .get("sections")
+        .and_then(toml::Value::as_array)
+        .ok_or("sections missing")?
+        .iter()
+        .filter_map(|section| section.get("title").and_then(toml::Value::as_str))
+        .map(str::to_string)
+        .collect())
+}
+
+#[test]
+fn test_section_add_rename_reorder() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Sections RFC"],
+            &["rfc", "section", "add", "RFC-0001", "Appendix"],
+            &[
+                "rfc",
+                "section",
+                "add",
+                "RFC-0001",
+                "Rationale",
+                "--after",
+                "Summary",
+            ],
+            &[
+                "rfc",
+                "section",
+                "rename",
+                "RFC-0001",
+                "Specification",
+                "Requirements",
+            ],
+            &[
+                "rfc",
+                "section",
+                "reorder",
+                "RFC-0001",
+                "--order",
+                "Requirements,Summary",
+            ],
+            &["check"],
+        ],
+    )?;
+    assert!(
+        output.contains("Sections: Requirements, Summary, Rationale, Appendix"),
+        "output: {output}"
+    );
+    assert!(output.ends_with("exit: 0\n\n"), "output: {output}");
+    assert_eq!(
+        section_titles(temp_dir.path())?,
+        ["Requirements", "Summary", "Rationale", "Appendix"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_section_add_and_rename_reject_duplicate_titles() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Sections RFC"],
+            &["rfc", "section", "add", "RFC-0001", "Summary"],
+            &[
+                "rfc",
+                "section",
+                "rename",
+                "RFC-0001",
+                "Summary",
+                "Specification",
+            ],
+            &["rfc", "section", "rename", "RFC-0001", "Missing", "Other"],
+        ],
+    )?;
+    assert_eq!(output.matches("E0117").count(), 2, "output: {output}");
+    assert!(
+        output.contains("Section 'Missing' not found in RFC-0001"),
+        "output: {output}"
+    );
+    assert_eq!(
+        section_titles(temp_dir.path())?,
+        ["Summary", "Specification"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_section_remove_requires_force_for_clauses() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Sections RFC"],
+            &["clause", "new", "RFC-0001:C-A", "A"],
+            &["rfc", "section", "remove", "RFC-0001", "Specification"],
+        ],
+    )?;
+    assert!(output.contains("E0118"), "output: {output}");
+    assert!(output.contains("C-A"), "output: {output}");
+    let clause_path = temp_dir.path().join("gov/rfc/RFC-0001/clauses/C-A.toml");
+    assert!(clause_path.exists());
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &[
+                "rfc",
+                "section",
+                "remove",
+                "RFC-0001",
+                "Specification",
+                "--force",
+            ],
+            &["rfc", "section", "remove", "RFC-0001", "Summary"],
+            &["check"],
+        ],
+    )?;
+    assert!(output.contains("Deleted clauses: C-A"), "output: {output}");
+    assert!(
+        output.contains("RFC-0001 must keep at least one section"),
+        "output: {output}"
+    );
+    assert!(output.ends_with("exit: 0\n\n"), "output: {output}");
+    assert!(!clause_path.exists());
+    assert!(
+        temp_dir
+            .path()
+            .join("gov/.trash/RFC-0001_C-A/tombstone.toml")
+            .exists()
+    );
+    assert_eq!(section_titles(temp_dir.path())?, ["Summary"]);
+    Ok(())
+}
+
+#[test]
+fn test_rfc_add_remove_sections_field() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Sections RFC"],
+            &["rfc", "add", "RFC-0001", "sections", "Rationale"],
+            &["rfc", "add", "RFC-0001", "sections", "Summary"],
+            &["rfc", "edit", "RFC-0001", "sections", "--add", "Appendix"],
+            &["clause", "new", "RFC-0001:C-A", "A", "-s", "Rationale"],
+            &["rfc", "get", "RFC-0001", "sections[2].clauses"],
+            &["rfc", "remove", "RFC-0001", "sections", "Rationale"],
+            &["rfc", "remove", "RFC-0001", "sections", "--at", "0"],
+            &["rfc", "remove", "RFC-0001", "sections", "Appendix"],
+            &["check"],
+        ],
+    )?;
+    assert!(output.contains("E0117"), "output: {output}");
+    assert!(output.contains("clauses/C-A.toml"), "output: {output}");
+    assert!(output.contains("E0118"), "output: {output}");
+    assert!(output.contains("E0802"), "output: {output}");
+    assert!(output.ends_with("exit: 0\n\n"), "output: {output}");
+    assert_eq!(
+        section_titles(temp_dir.path())?,
+        ["Summary", "Specification", "Rationale"]
+    );
+    Ok(())
+}
@@ -152,7 +152,7 @@ fn test_write_command_without_init_reports_missing_gov_root() -> common::TestRes
     let temp_dir = tempfile::TempDir::new()?;
 
     let output = run_commands(temp_dir.path(), &[&["work", "new", "Needs init"]])?;
-    assert!(output.contains("exit: 1"), "output: {}", output);
+    assert!(output.contains("exit: 4"), "output: {}", output);
     assert!(output.contains("error[E0502]"), "output: {}", output);
     assert!(
         output.contains("Run 'govctl init' first"),
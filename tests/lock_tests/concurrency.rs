@@ -2,8 +2,8 @@ use super::*;
 
 /// Test: Concurrent write is blocked by lock (cross-process)
 ///
-/// Uses govctl itself as the lock holder - spawns a write command that
-/// blocks waiting for user input (which never comes), holding the lock.
+/// Uses govctl itself as the lock holder - spawns a write command whose
+/// hook sleeps, holding the lock.
 #[test]
 fn test_concurrent_write_blocked_by_lock() -> common::TestResult {
     let temp_dir = init_project()?;
@@ -11,16 +11,18 @@ fn test_concurrent_write_blocked_by_lock() -> common::TestResult {
     // Short timeout for the second writer
     create_config_with_timeout(temp_dir.path(), 1)?;
 
-    // Start a work item deletion in another process.
-    // This will prompt for confirmation, holding the lock while waiting.
+    // Start a work item move in another process. Its pre_move hook sleeps,
+    // holding the lock while it runs.
     write_queue_work_item_for_lock_delete(temp_dir.path())?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[hooks]\npre_move = [{ command = \"sleep 5\" }]\n");
+    fs::write(&config_path, config)?;
 
-    // Spawn a delete command (will hold lock while waiting for confirmation)
     let holder = Command::new(env!("CARGO_BIN_EXE_govctl"))
-        .args(["work", "delete", "WI-2026-01-01-001"])
+        .args(["work", "move", "WI-2026-01-01-001", "active"])
         .current_dir(temp_dir.path())
         .env("NO_COLOR", "1")
-        .stdin(std::process::Stdio::piped())
         .spawn()?;
 
     // Wait a bit for the holder to acquire the lock
@@ -50,6 +52,13 @@ fn test_concurrent_write_blocked_by_lock() -> common::TestResult {
         "Expected timeout error, got: {}",
         stderr
     );
+    assert!(
+        stderr.contains(&format!("pid {}", holder.id()))
+            && stderr.contains("`govctl work move WI-2026-01-01-001 active`"),
+        "Expected the holder to be named, got: {}",
+        stderr
+    );
+    assert_eq!(result.status.code(), Some(6), "lock contention exit code");
 
     // Clean up
     kill_and_wait(holder, Duration::from_secs(2));
@@ -158,6 +167,7 @@ fn test_concurrent_tick_commands_persist_all_acceptance_criteria_updates() -> co
                 .current_dir(dir)
                 .env("NO_COLOR", "1")
                 .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+                .env("GOVCTL_ACTOR", "human:test-user")
                 .output()
         }));
     }
@@ -180,6 +190,7 @@ fn test_concurrent_tick_commands_persist_all_acceptance_criteria_updates() -> co
         .current_dir(temp_dir.path())
         .env("NO_COLOR", "1")
         .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
         .output()?;
     assert!(
         get_output.status.success(),
@@ -221,6 +232,7 @@ refs = ["WI-2026-01-01-001"]
 
     let lock = OpenOptions::new()
         .create(true)
+        .truncate(false)
         .read(true)
         .write(true)
         .open(temp_dir.path().join("gov/.govctl.lock"))?;
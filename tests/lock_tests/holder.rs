@@ -0,0 +1,113 @@
+use super::*;
+
+/// Hold the gov-root lock from the test process, recording `record` as the holder.
+fn hold_lock(temp_dir: &std::path::Path, record: &str) -> std::io::Result<fs::File> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .read(true)
+        .write(true)
+        .open(temp_dir.join("gov/.govctl.lock"))?;
+    file.lock_exclusive()?;
+    std::io::Write::write_all(&mut file, record.as_bytes())?;
+    Ok(file)
+}
+
+/// Test: --lock-wait overrides the configured timeout
+#[test]
+fn test_lock_wait_zero_fails_without_waiting() -> common::TestResult {
+    let temp_dir = init_project()?;
+    create_config_with_timeout(temp_dir.path(), 30)?;
+    let _lock = hold_lock(temp_dir.path(), "")?;
+
+    let start = Instant::now();
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["--lock-wait", "0", "work", "new", "Blocked"]],
+    )?;
+
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "took {:?}",
+        start.elapsed()
+    );
+    assert!(output.contains("error[E0503]"), "output: {output}");
+    assert!(output.contains("exit: 6"), "output: {output}");
+    Ok(())
+}
+
+/// Test: a waiting command proceeds once the holder releases the lock
+#[test]
+fn test_lock_wait_blocks_until_lock_is_released() -> common::TestResult {
+    let temp_dir = init_project()?;
+    create_config_with_timeout(temp_dir.path(), 0)?;
+    let lock = hold_lock(temp_dir.path(), "")?;
+    let releaser = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(500));
+        drop(lock);
+    });
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["work", "new", "After wait", "--lock-wait", "10"]],
+    )?;
+    let _ = releaser.join();
+
+    assert!(
+        output.contains("Waiting up to 10s for the gov-root lock"),
+        "output: {output}"
+    );
+    assert!(output.contains("Created work item"), "output: {output}");
+    Ok(())
+}
+
+/// Test: a held lock whose record names an exited process is not taken over
+#[cfg(unix)]
+#[test]
+fn test_held_lock_with_stale_record_is_reported_not_replaced() -> common::TestResult {
+    let temp_dir = init_project()?;
+    create_config_with_timeout(temp_dir.path(), 0)?;
+
+    let mut exited = Command::new("true").spawn()?;
+    let pid = exited.id();
+    exited.wait()?;
+    let host = Command::new("uname").arg("-n").output()?;
+    let host = String::from_utf8_lossy(&host.stdout).trim().to_string();
+    let _lock = hold_lock(
+        temp_dir.path(),
+        &format!(
+            "pid = {pid}\nhost = \"{host}\"\ncommand = \"govctl work new Crashed\"\nsince = \"2026-01-01 00:00:00\"\n"
+        ),
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["work", "new", "After crash"]])?;
+
+    assert!(output.contains("error[E0503]"), "output: {output}");
+    assert!(
+        output.contains(&format!("recorded holder: pid {pid} on {host}")),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("the record may be stale"),
+        "output: {output}"
+    );
+    assert!(!output.contains("Created work item"), "output: {output}");
+    assert!(temp_dir.path().join("gov/.govctl.lock").exists());
+    Ok(())
+}
+
+/// Test: a record left by a crashed holder does not block the next command
+#[test]
+fn test_unheld_lock_with_leftover_record_is_taken() -> common::TestResult {
+    let temp_dir = init_project()?;
+    create_config_with_timeout(temp_dir.path(), 0)?;
+    drop(hold_lock(
+        temp_dir.path(),
+        "pid = 1\nhost = \"elsewhere\"\ncommand = \"govctl work new Crashed\"\nsince = \"2026-01-01 00:00:00\"\n",
+    )?);
+
+    let output = run_commands(temp_dir.path(), &[&["work", "new", "After crash"]])?;
+
+    assert!(output.contains("Created work item"), "output: {output}");
+    Ok(())
+}
@@ -2,3 +2,4 @@ use super::*;
 
 mod basic;
 mod concurrency;
+mod holder;
@@ -173,3 +173,55 @@ fn test_init_no_skills() -> common::TestResult {
     );
     Ok(())
 }
+
+#[test]
+fn test_init_skills_only_installs_selected_groups() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    run_commands(temp_dir.path(), &[&["init-skills", "--only", "agents"]])?;
+
+    assert!(
+        temp_dir
+            .path()
+            .join(".claude/agents/rfc-reviewer.md")
+            .exists()
+    );
+    assert!(!temp_dir.path().join(".claude/skills").exists());
+    Ok(())
+}
+
+#[test]
+fn test_init_skills_check_and_diff_report_drift() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(temp_dir.path(), &[&["init-skills"]])?;
+    let agent = temp_dir.path().join(".claude/agents/wi-reviewer.md");
+    let original = fs::read_to_string(&agent)?;
+    fs::write(&agent, format!("{original}Local edit\n"))?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["init-skills", "--check", "--only", "skills"],
+            &["init-skills", "--diff"],
+            &["init-skills", "--check"],
+        ],
+    )?;
+    assert!(output.contains("Agent assets are up to date"), "{output}");
+    assert!(
+        output.contains("--- .claude/agents/wi-reviewer.md"),
+        "{output}"
+    );
+    assert!(output.contains("- Local edit"), "{output}");
+    assert!(
+        output.contains(
+            "error[E0826]: 1 agent asset(s) missing or out of date: .claude/agents/wi-reviewer.md"
+        ),
+        "{output}"
+    );
+    assert_eq!(
+        fs::read_to_string(&agent)?,
+        format!("{original}Local edit\n"),
+        "--diff and --check write nothing"
+    );
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Clause anchors: `govctl anchor list`, `[[ANCHOR-NAME]]` resolution in
+//! source scanning, and anchor validation.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+fn init_anchored_project() -> Result<tempfile::TempDir, Box<dyn std::error::Error>> {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let mut doc: toml::Table = toml::from_str(&fs::read_to_string(&config_path)?)?;
+    let mut scan = toml::Table::new();
+    scan.insert("enabled".into(), toml::Value::Boolean(true));
+    scan.insert("include".into(), toml::Value::Array(vec!["src/**".into()]));
+    doc.insert("source_scan".into(), toml::Value::Table(scan));
+    fs::write(&config_path, toml::to_string_pretty(&doc)?)?;
+
+    let clause = |id: &'static str| -> [&'static str; 8] {
+        [
+            "clause",
+            "new",
+            id,
+            "Cache Expiry",
+            "-s",
+            "Specification",
+            "-k",
+            "normative",
+        ]
+    };
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching"],
+            &clause("RFC-0001:C-EXPIRY"),
+            &clause("RFC-0001:C-EVICTION"),
+            &[
+                "clause",
+                "edit",
+                "RFC-0001:C-EXPIRY",
+                "anchors",
+                "--add",
+                "CACHE-TTL",
+            ],
+        ],
+    )?;
+    Ok(temp_dir)
+}
+
+#[test]
+fn test_anchor_list_shows_owning_clause() -> common::TestResult {
+    let temp_dir = init_anchored_project()?;
+
+    let output = run_commands(temp_dir.path(), &[&["anchor", "list", "-o", "plain"]])?;
+
+    assert!(
+        output.contains("CACHE-TTL\tRFC-0001:C-EXPIRY"),
+        "output: {}",
+        output
+    );
+    Ok(())
+}
+
+#[test]
+fn test_source_anchor_refs_resolve_to_clause() -> common::TestResult {
+    let temp_dir = init_anchored_project()?;
+    fs::create_dir_all(temp_dir.path().join("src"))?;
+    fs::write(
+        temp_dir.path().join("src/cache.rs"),
+        "// Implements [[CACHE-TTL]]\n// See [[CACHE-SIZE]]\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["check"], &["scan", "coverage", "-o", "plain"]],
+    )?;
+
+    assert!(
+        output.contains("  2 references found"),
+        "output: {}",
+        output
+    );
+    assert!(
+        output.contains("error[E0107]: Unknown anchor reference: CACHE-SIZE (src/cache.rs:2:8)"),
+        "output: {}",
+        output
+    );
+    assert!(!output.contains("CACHE-TTL"), "output: {}", output);
+    assert!(
+        output.contains("RFC-0001:C-EXPIRY\t1\tsrc/cache.rs:1"),
+        "output: {}",
+        output
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_rejects_invalid_and_duplicate_anchors() -> common::TestResult {
+    let temp_dir = init_anchored_project()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &[
+                "clause",
+                "edit",
+                "RFC-0001:C-EVICTION",
+                "anchors",
+                "--add",
+                "CACHE-TTL",
+            ],
+            &[
+                "clause",
+                "edit",
+                "RFC-0001:C-EVICTION",
+                "anchors",
+                "--add",
+                "lru",
+            ],
+            &["check"],
+        ],
+    )?;
+
+    assert!(
+        output.contains(
+            "error[E0215]: Anchor 'CACHE-TTL' on RFC-0001:C-EVICTION is already declared by RFC-0001:C-EXPIRY"
+        ),
+        "output: {}",
+        output
+    );
+    assert!(
+        output.contains("error[E0214]: Anchor 'lru' on RFC-0001:C-EVICTION must be uppercase"),
+        "output: {}",
+        output
+    );
+    Ok(())
+}
@@ -0,0 +1,171 @@
+//! Tests for `govctl apply` batch transactions.
+
+mod common;
+
+use common::{format_command_output, init_project, run_commands};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Every file under `gov/` with its bytes, excluding the lock file.
+fn gov_tree(dir: &Path) -> Result<BTreeMap<PathBuf, Vec<u8>>, std::io::Error> {
+    fn walk(
+        root: &Path,
+        dir: &Path,
+        files: &mut BTreeMap<PathBuf, Vec<u8>>,
+    ) -> Result<(), std::io::Error> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk(root, &path, files)?;
+            } else if path.file_name().is_some_and(|name| name != ".govctl.lock") {
+                let relative = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+                files.insert(relative, fs::read(&path)?);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = BTreeMap::new();
+    walk(dir, &dir.join("gov"), &mut files)?;
+    Ok(files)
+}
+
+#[test]
+fn test_apply_runs_steps_in_order() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("ops.yaml"),
+        r#"- [rfc, new, "Caching layer"]
+- [clause, new, "RFC-0001:C-TTL", "Cache TTL", -s, Specification, -k, normative]
+- [clause, edit, "RFC-0001:C-TTL", text, --set, "Entries MUST expire."]
+- [tag, new, caching]
+- [rfc, add, RFC-0001, tags, caching]
+"#,
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["apply", "ops.yaml"],
+            &["clause", "get", "RFC-0001:C-TTL", "text"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("Applied 5 step(s) from ops.yaml"),
+        "output: {output}"
+    );
+    assert!(output.contains("Entries MUST expire."), "output: {output}");
+    assert!(!output.contains("exit: 1"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_apply_failed_step_rolls_back_earlier_steps() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let before = gov_tree(temp_dir.path())?;
+    fs::write(
+        temp_dir.path().join("ops.yaml"),
+        r#"- [rfc, new, "Caching layer"]
+- [clause, new, "RFC-0001:C-TTL", "Cache TTL", -s, Specification, -k, normative]
+- [tag, new, caching]
+- [work, new, "Implement caching", --active]
+- [rfc, finalize, RFC-0099, normative]
+"#,
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["apply", "ops.yaml"]])?;
+
+    assert!(
+        output.contains("Step 5 (`govctl rfc finalize RFC-0099 normative`) failed"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("no changes were applied"),
+        "output: {output}"
+    );
+    assert!(output.contains("exit: 4"), "output: {output}");
+    assert_eq!(gov_tree(temp_dir.path())?, before);
+    assert!(!temp_dir.path().join("gov/rfc/RFC-0001").exists());
+    Ok(())
+}
+
+#[test]
+fn test_apply_rejects_invalid_steps_before_writing() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let before = gov_tree(temp_dir.path())?;
+    fs::write(
+        temp_dir.path().join("bad-command.yaml"),
+        "- [rfc, new, \"Caching layer\"]\n- [rfc, frobnicate]\n",
+    )?;
+    fs::write(
+        temp_dir.path().join("interactive.yaml"),
+        "- [work, new, --interactive]\n",
+    )?;
+    fs::write(
+        temp_dir.path().join("stdin.yaml"),
+        "- [work, set, WI-2026-01-01-001, description, --stdin]\n",
+    )?;
+    fs::write(temp_dir.path().join("not-a-list.yaml"), "rfc: new\n")?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["apply", "bad-command.yaml"],
+            &["apply", "interactive.yaml"],
+            &["apply", "stdin.yaml"],
+            &["apply", "not-a-list.yaml"],
+        ],
+    )?;
+
+    assert_eq!(
+        output.matches("error[E0825]").count(),
+        4,
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Step 2 (`govctl rfc frobnicate`)"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("interactive creation cannot run inside apply"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("--stdin is not available in scripts"),
+        "output: {output}"
+    );
+    assert!(output.contains("Invalid apply script"), "output: {output}");
+    assert_eq!(gov_tree(temp_dir.path())?, before);
+    Ok(())
+}
+
+#[test]
+fn test_apply_reads_script_from_stdin() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let mut child = Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(["apply", "--stdin"])
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(b"- [adr, new, \"Use Redis for caching\"]\n")?;
+    }
+    let result = child.wait_with_output()?;
+    let output = format_command_output(&["apply", "--stdin"], &result);
+
+    assert!(
+        output.contains("Applied 1 step(s) from stdin"),
+        "output: {output}"
+    );
+    assert!(output.contains("exit: 0"), "output: {output}");
+    Ok(())
+}
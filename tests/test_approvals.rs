@@ -0,0 +1,101 @@
+//! Tests for `rfc approve` / `adr approve` and approval quorums.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+use std::path::Path;
+
+const QUORUM_CONFIG: &str = r#"
+[owners]
+members = ["@alice", "@bob", "@carol", "@test-user"]
+rfc_quorum = 2
+adr_quorum = 1
+"#;
+
+fn add_quorum_config(root: &Path) -> std::io::Result<()> {
+    let config_path = root.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(QUORUM_CONFIG);
+    fs::write(config_path, config)
+}
+
+#[test]
+fn test_rfc_finalize_enforces_quorum() -> common::TestResult {
+    let temp_dir = init_project()?;
+    add_quorum_config(temp_dir.path())?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "approve", "RFC-0001", "--as", "@alice"],
+            &["rfc", "approve", "RFC-0001", "--as", "@alice"],
+            &["rfc", "approve", "RFC-0001", "--as", "@mallory"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "approve", "RFC-0001", "--as", "@bob"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["render"],
+            &["check"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("Approved RFC-0001 as @alice (1 of 2 required approvals)"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("RFC-0001 is already approved by @alice"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("error[E0120]: RFC 'RFC-0001' approval by '@mallory'"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("error[E0121]: RFC 'RFC-0001' has 1 of 2 required approvals"),
+        "output: {output}"
+    );
+    let last_check = output.rsplit("$ govctl check").next().unwrap_or_default();
+    assert!(last_check.contains("exit: 0"), "output: {output}");
+
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("status = \"normative\""), "{rfc}");
+    let rendered = fs::read_to_string(temp_dir.path().join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        rendered.contains("> **Approved by:** @alice, @bob"),
+        "{rendered}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_adr_accept_enforces_quorum() -> common::TestResult {
+    let temp_dir = init_project()?;
+    add_quorum_config(temp_dir.path())?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "new", "Use Postgres"],
+            &["adr", "accept", "ADR-0001", "--force"],
+            &["adr", "approve", "ADR-0001", "--as", "@carol"],
+            &["adr", "accept", "ADR-0001", "--force"],
+            &["adr", "approve", "ADR-0001", "--as", "@bob"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("error[E0308]: ADR 'ADR-0001' has 0 of 1 required approvals"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Cannot approve ADR-0001: only proposed ADRs take approvals"),
+        "output: {output}"
+    );
+    let adr = fs::read_to_string(temp_dir.path().join("gov/adr/ADR-0001-use-postgres.toml"))?;
+    assert!(adr.contains("status = \"accepted\""), "{adr}");
+    assert!(adr.contains("@carol"), "{adr}");
+    assert!(!adr.contains("@bob"), "{adr}");
+    Ok(())
+}
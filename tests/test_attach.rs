@@ -0,0 +1,65 @@
+//! Attachments: stored under gov/assets/, copied beside rendered docs.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_attachment_is_stored_rendered_and_checked() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(temp_dir.path().join("diagram.svg"), "<svg/>")?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Architecture"],
+            &["attach", "add", "RFC-0001", "diagram.svg"],
+            &["attach", "list", "RFC-0001", "-o", "plain"],
+            &["rfc", "render", "RFC-0001"],
+            &["check"],
+        ],
+    )?;
+    assert!(
+        output.contains("RFC-0001\tRFC-0001/diagram.svg"),
+        "{output}"
+    );
+    assert!(!output.contains("error["), "{output}");
+
+    let stored = temp_dir.path().join("gov/assets/RFC-0001/diagram.svg");
+    assert_eq!(fs::read_to_string(&stored)?, "<svg/>");
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("RFC-0001/diagram.svg"), "{rfc}");
+
+    let rendered = fs::read_to_string(temp_dir.path().join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        rendered.contains("![diagram.svg](../assets/RFC-0001/diagram.svg)"),
+        "{rendered}"
+    );
+    assert!(
+        temp_dir
+            .path()
+            .join("docs/assets/RFC-0001/diagram.svg")
+            .is_file()
+    );
+
+    // A listed file that goes missing fails validation.
+    fs::remove_file(&stored)?;
+    let output = run_commands(temp_dir.path(), &[&["check"]])?;
+    assert!(output.contains("error[E1701]"), "{output}");
+
+    fs::write(&stored, "<svg/>")?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["attach", "remove", "RFC-0001", "diagram.svg"],
+            &["rfc", "render", "RFC-0001"],
+            &["check"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "{output}");
+    assert!(!stored.exists());
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(!rfc.contains("diagram.svg"), "{rfc}");
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Command audit log: `[audit]` logging of write commands and `govctl audit`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_audit_log_records_write_commands() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let log = temp_dir.path().join("gov/audit.log");
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["config", "set", "audit.enabled", "true"],
+            &["rfc", "new", "Audited"],
+            &["rfc", "new", "Audited", "--id", "RFC-0001"],
+            &["--dry-run", "rfc", "new", "Preview"],
+            &["rfc", "list"],
+        ],
+    )?;
+
+    let body = fs::read_to_string(&log)?;
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(lines.len(), 2, "{body}");
+    assert!(lines[0].contains(r#""seq":1"#), "{body}");
+    assert!(
+        lines[0].contains(r#""command":"govctl rfc new Audited""#),
+        "{body}"
+    );
+    assert!(
+        lines[0].contains(r#""files":["gov/rfc/RFC-0001/rfc.toml"]"#),
+        "{body}"
+    );
+    assert!(lines[0].contains(r#""result":"ok""#), "{body}");
+    assert!(lines[1].contains(r#""result":"error""#), "{body}");
+    assert!(lines[1].contains("E0109"), "{body}");
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["audit", "list", "-o", "plain"],
+            &["audit", "show", "1"],
+            &["audit", "show", "9"],
+        ],
+    )?;
+    assert!(
+        output.contains("\terror\tgovctl rfc new Audited --id RFC-0001"),
+        "{output}"
+    );
+    assert!(output.contains("#1 govctl rfc new Audited"), "{output}");
+    assert!(output.contains("  - gov/rfc/RFC-0001/rfc.toml"), "{output}");
+    assert!(output.contains("error[E1502]"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_audit_log_drops_entries_past_retention() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let log = temp_dir.path().join("gov/audit.log");
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["config", "set", "audit.enabled", "true"],
+            &["config", "set", "audit.retention_days", "30"],
+        ],
+    )?;
+    let old = r#"{"seq":1,"timestamp":"2020-01-01T00:00:00+00:00","user":"ci","command":"govctl rfc new Old","files":[],"result":"ok"}"#;
+    fs::write(&log, format!("{old}\n"))?;
+
+    run_commands(temp_dir.path(), &[&["rfc", "new", "Fresh"]])?;
+
+    let body = fs::read_to_string(&log)?;
+    assert!(!body.contains("Old"), "{body}");
+    assert_eq!(body.lines().count(), 1, "{body}");
+    assert!(body.contains(r#""seq":2"#), "{body}");
+    Ok(())
+}
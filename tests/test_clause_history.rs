@@ -0,0 +1,134 @@
+//! Tests for `clause history`, which reads clause text changes from git.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::path::Path;
+
+fn git(dir: &Path, args: &[&str]) -> common::TestResult {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()?;
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_clause_history_prints_a_diff_per_text_change() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    git(dir, &["init", "-q"])?;
+    git(dir, &["config", "user.email", "author@example.com"])?;
+    git(dir, &["config", "user.name", "Author"])?;
+
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-SCOPE",
+                "text",
+                "Cards are accepted.\nRefunds are manual.",
+            ],
+        ],
+    )?;
+    git(dir, &["add", "-A"])?;
+    git(dir, &["commit", "-qm", "Add scope"])?;
+    run_commands(dir, &[&["rfc", "set", "RFC-0001", "title", "Billing"]])?;
+    git(dir, &["commit", "-qam", "Rename RFC"])?;
+    run_commands(
+        dir,
+        &[&[
+            "clause",
+            "set",
+            "RFC-0001:C-SCOPE",
+            "text",
+            "Cards are accepted.\nRefunds are automatic.",
+        ]],
+    )?;
+    git(dir, &["commit", "-qam", "Automate refunds"])?;
+    run_commands(
+        dir,
+        &[&[
+            "clause",
+            "set",
+            "RFC-0001:C-SCOPE",
+            "text",
+            "Cards and wallets are accepted.\nRefunds are automatic.",
+        ]],
+    )?;
+
+    let output = run_commands(
+        dir,
+        &[
+            &["clause", "history", "RFC-0001:C-SCOPE"],
+            &["clause", "history", "RFC-0001:C-SCOPE", "-n", "1"],
+            &["clause", "history", "RFC-0001:C-MISSING"],
+        ],
+    )?;
+
+    assert!(output.contains("Author: Add scope\n"), "output: {output}");
+    assert!(
+        !output.contains("Author: Rename RFC"),
+        "unchanged text is skipped: {output}"
+    );
+    assert!(
+        output.contains(
+            "Author: Automate refunds\n  Cards are accepted.\n- Refunds are manual.\n+ Refunds are automatic.\n"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "working tree (uncommitted)\n- Cards are accepted.\n+ Cards and wallets are accepted.\n  Refunds are automatic.\n"
+        ),
+        "output: {output}"
+    );
+    assert_eq!(
+        output.matches("Author: Add scope").count(),
+        1,
+        "-n 1 keeps only the latest change: {output}"
+    );
+    assert!(
+        output.contains("error[E0202]: Clause not found: RFC-0001:C-MISSING"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_clause_history_requires_a_git_repository() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &["clause", "history", "RFC-0001:C-SCOPE"],
+        ],
+    )?;
+    assert!(output.contains("error[E1304]"), "output: {output}");
+    Ok(())
+}
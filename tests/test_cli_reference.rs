@@ -0,0 +1,45 @@
+//! Tests for `govctl docs man|markdown` - the generated command reference.
+
+mod common;
+
+use common::{run_commands, temp_dir_with_date};
+
+#[test]
+fn test_docs_markdown_covers_nested_commands() -> common::TestResult {
+    let (temp_dir, _date) = temp_dir_with_date()?;
+    let output = run_commands(temp_dir.path(), &[&["docs", "markdown"]])?;
+
+    assert!(output.contains("# govctl command reference"), "{output}");
+    assert!(output.contains("### `govctl rfc new`"), "{output}");
+    assert!(output.contains("### `govctl work move`"), "{output}");
+    assert!(output.contains("- `--dry-run`: "), "{output}");
+    assert!(!output.contains("`govctl help`"), "{output}");
+    assert!(output.contains("exit: 0"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_docs_man_emits_roff() -> common::TestResult {
+    let (temp_dir, _date) = temp_dir_with_date()?;
+    let output = run_commands(temp_dir.path(), &[&["docs", "man"]])?;
+
+    assert!(output.contains(".TH GOVCTL 1"), "{output}");
+    assert!(output.contains(".SS \"govctl rfc new\""), "{output}");
+    assert!(output.contains("\\fB\\-\\-dry\\-run\\fR"), "{output}");
+    assert!(output.contains("exit: 0"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_docs_out_writes_file() -> common::TestResult {
+    let (temp_dir, _date) = temp_dir_with_date()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["docs", "man", "--out", "target/man/govctl.1"]],
+    )?;
+
+    assert!(output.contains("exit: 0"), "{output}");
+    let page = std::fs::read_to_string(temp_dir.path().join("target/man/govctl.1"))?;
+    assert!(page.starts_with(".TH GOVCTL 1"), "{page}");
+    Ok(())
+}
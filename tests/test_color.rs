@@ -0,0 +1,78 @@
+//! Tests for `--color` and terminal-width aware tables.
+
+mod common;
+
+use common::init_project;
+use std::path::Path;
+use std::process::{Command, Output};
+
+const LONG_TITLE: &str = "A rather long title that cannot fit in a narrow terminal";
+
+fn govctl(dir: &Path, args: &[&str], columns: Option<&str>) -> std::io::Result<Output> {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_govctl"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env_remove("NO_COLOR")
+        .env_remove("COLUMNS")
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user");
+    if let Some(columns) = columns {
+        command.env("COLUMNS", columns);
+    }
+    command.output()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_color_always_styles_piped_tables() -> common::TestResult {
+    let temp_dir = init_project()?;
+    govctl(temp_dir.path(), &["rfc", "new", "Caching layer"], None)?;
+
+    let always = govctl(temp_dir.path(), &["--color", "always", "rfc", "list"], None)?;
+    assert!(always.status.success());
+    assert!(stdout(&always).contains('\u{1b}'), "{}", stdout(&always));
+
+    let auto = govctl(temp_dir.path(), &["rfc", "list"], None)?;
+    assert!(!stdout(&auto).contains('\u{1b}'), "{}", stdout(&auto));
+    Ok(())
+}
+
+#[test]
+fn test_json_and_plain_output_are_never_colored() -> common::TestResult {
+    let temp_dir = init_project()?;
+    govctl(temp_dir.path(), &["rfc", "new", "Caching layer"], None)?;
+
+    for args in [
+        &["--color=always", "rfc", "list", "-o", "json"][..],
+        &["--color=always", "rfc", "list", "-o", "plain"][..],
+        &["--color=always", "rfc", "show", "RFC-0001", "-o", "plain"][..],
+    ] {
+        let output = govctl(temp_dir.path(), args, None)?;
+        assert!(output.status.success(), "{args:?}");
+        assert!(!stdout(&output).contains('\u{1b}'), "{args:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_narrow_tables_truncate_cells_with_ellipsis() -> common::TestResult {
+    let temp_dir = init_project()?;
+    govctl(temp_dir.path(), &["rfc", "new", LONG_TITLE], None)?;
+
+    let narrow = stdout(&govctl(temp_dir.path(), &["rfc", "list"], Some("60"))?);
+    assert!(narrow.contains("RFC-0001"), "{narrow}");
+    assert!(narrow.contains('…'), "{narrow}");
+    assert!(!narrow.contains(LONG_TITLE), "{narrow}");
+    assert!(
+        narrow.lines().all(|line| line.chars().count() <= 60),
+        "{narrow}"
+    );
+
+    let unbounded = stdout(&govctl(temp_dir.path(), &["rfc", "list"], None)?);
+    assert!(unbounded.contains(LONG_TITLE), "{unbounded}");
+    Ok(())
+}
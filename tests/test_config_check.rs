@@ -0,0 +1,190 @@
+//! Tests for `govctl config check`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_config_check_accepts_default_config() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let output = run_commands(temp_dir.path(), &[&["config", "check"]])?;
+
+    assert!(
+        output.contains("gov/config.toml is valid"),
+        "output: {output}"
+    );
+    assert!(output.contains("exit: 0"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_config_check_reports_unknown_deprecated_and_type_errors_with_locations()
+-> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.toml"),
+        r#"[project]
+name = "demo"
+
+[paths]
+commands_dir = ".claude/commands"
+
+[concurrency]
+lock_timeout_secs = "soon"
+
+[verificaton]
+enabled = true
+"#,
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["config", "check"]])?;
+
+    assert!(
+        output.contains(
+            "Config key 'paths.commands_dir' is deprecated: Renamed to `paths.agent_dir`"
+        ),
+        "output: {output}"
+    );
+    assert!(output.contains("(gov/config.toml:5:1)"), "output: {output}");
+    assert!(
+        output.contains("Unknown config key 'verificaton' is ignored"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("(gov/config.toml:10:2)"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "error[E0507]: concurrency.lock_timeout_secs: \"soon\" is not of type \"integer\" (gov/config.toml:8:21)"
+        ),
+        "output: {output}"
+    );
+    assert!(output.contains("exit: 3"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_config_check_reports_conflicting_settings() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.toml"),
+        r#"[paths]
+docs_output = "gov/rendered"
+
+[verification]
+enabled = false
+default_guards = ["GUARD-CARGO-TEST"]
+"#,
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["config", "check"]])?;
+
+    assert!(
+        output.contains("error[E0504]: paths.docs_output overlaps the governance root gov/"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("(gov/config.toml:2:15)"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "warning[W0116]: verification.default_guards has no effect while verification.enabled is false"
+        ),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_config_check_validates_artifact_and_work_item_prefixes() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let content = fs::read_to_string(&config_path)?;
+
+    fs::write(
+        &config_path,
+        format!("{content}\n[prefixes]\nrfc = \"SPEC\"\nadr = \"SPEC\"\n"),
+    )?;
+    let output = run_commands(temp_dir.path(), &[&["config", "check"]])?;
+    assert!(
+        output.contains("error[E0501]: RFCs and ADRs cannot share the prefix 'SPEC'"),
+        "output: {output}"
+    );
+
+    fs::write(
+        &config_path,
+        format!("{content}\n[work_item]\nid_prefix = \"pay-\"\n"),
+    )?;
+    let output = run_commands(temp_dir.path(), &[&["config", "check"]])?;
+    assert!(
+        output.contains("error[E0507]: work_item.id_prefix: \"pay-\" does not match"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_config_check_validates_tui_keys_and_theme() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.toml"),
+        r#"[tui.keys]
+down = "nn"
+
+[tui.theme]
+ascii = true
+sucess = "green"
+"#,
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["config", "check"]])?;
+
+    assert!(
+        output.contains("error[E0507]: tui.keys.down: \"nn\" is longer than 1 character"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Unknown config key 'tui.theme.sucess' is ignored"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_config_check_reports_parse_errors_that_block_other_commands() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.toml"),
+        "[project]\nname = \"demo\"\n[paths\n",
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["config", "check"]])?;
+
+    assert!(
+        output.contains("error[E0501]: Failed to parse config"),
+        "output: {output}"
+    );
+    assert!(output.contains("(gov/config.toml:3:7)"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_config_check_deny_warnings_fails_on_warnings() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(&config_path, format!("{config}\n[extra]\nkey = 1\n"))?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["config", "check"], &["config", "check", "-W"]],
+    )?;
+
+    assert!(output.contains("exit: 0"), "output: {output}");
+    assert!(output.contains("exit: 3"), "output: {output}");
+    Ok(())
+}
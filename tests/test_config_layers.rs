@@ -0,0 +1,129 @@
+//! Tests for layered config: `gov/config.local.toml`, `GOVCTL_*` overrides,
+//! and `govctl config show`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn govctl_with_env(dir: &Path, args: &[&str], vars: &[(&str, &str)]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(args)
+        .current_dir(dir)
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
+        .env("NO_COLOR", "1")
+        .env_remove("COLUMNS")
+        .envs(vars.iter().copied())
+        .output()
+}
+
+#[test]
+fn test_local_config_overrides_project_config() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.local.toml"),
+        "[project]\ndefault_owner = \"@local-me\"\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching layer"],
+            &["rfc", "get", "RFC-0001", "owners"],
+            &["config", "show", "-o", "plain"],
+        ],
+    )?;
+
+    assert!(output.contains("@local-me"), "output: {output}");
+    assert!(
+        output.contains("project.default_owner = \"@local-me\"\t# gov/config.local.toml"),
+        "output: {output}"
+    );
+    assert!(
+        !output.contains("concurrency.lock_timeout_secs"),
+        "defaults are only listed with --resolved: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_env_overrides_win_over_config_files() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.local.toml"),
+        "[concurrency]\nlock_timeout_secs = 5\n",
+    )?;
+
+    let output = govctl_with_env(
+        temp_dir.path(),
+        &["config", "show", "--resolved", "-o", "plain"],
+        &[("GOVCTL_CONCURRENCY_LOCK_TIMEOUT_SECS", "7")],
+    )?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "stdout: {stdout}");
+    assert!(
+        stdout.contains(
+            "concurrency.lock_timeout_secs = 7\t# env GOVCTL_CONCURRENCY_LOCK_TIMEOUT_SECS"
+        ),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains("source_scan.enabled = false\t# default"),
+        "stdout: {stdout}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_env_override_is_reported() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let output = govctl_with_env(
+        temp_dir.path(),
+        &["status"],
+        &[("GOVCTL_SOURCE_SCAN_ENABLED", "maybe")],
+    )?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(
+        stderr.contains("error[E0501]: Invalid value for GOVCTL_SOURCE_SCAN_ENABLED"),
+        "stderr: {stderr}"
+    );
+    assert!(!output.status.success());
+    Ok(())
+}
+
+#[test]
+fn test_local_config_cannot_change_schema_version() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.local.toml"),
+        "[schema]\nversion = 1\n",
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["status"]])?;
+
+    assert!(
+        output.contains("[schema] describes the governed tree"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_init_gitignores_local_config() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let gitignore = fs::read_to_string(temp_dir.path().join(".gitignore"))?;
+    assert!(
+        gitignore
+            .lines()
+            .any(|line| line == "gov/config.local.toml"),
+        "{gitignore}"
+    );
+    Ok(())
+}
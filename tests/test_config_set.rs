@@ -0,0 +1,116 @@
+//! Tests for `govctl config get`, `config set`, and `config list`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_config_set_preserves_comments_and_is_undoable() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let before = fs::read_to_string(&config_path)?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["config", "set", "concurrency.lock_timeout_secs", "60"],
+            &["config", "set", "project.name", "demo"],
+            &["config", "get", "concurrency.lock_timeout_secs"],
+            &["config", "get", "project.name"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("Set concurrency.lock_timeout_secs = 60 in gov/config.toml"),
+        "output: {output}"
+    );
+    assert!(output.contains("\n60\n"), "output: {output}");
+    assert!(output.contains("\ndemo\n"), "output: {output}");
+
+    let after = fs::read_to_string(&config_path)?;
+    for line in before.lines().filter(|line| line.starts_with('#')) {
+        assert!(after.contains(line), "lost comment {line:?} in:\n{after}");
+    }
+    assert!(
+        after.contains("[concurrency]\nlock_timeout_secs = 60\n"),
+        "{after}"
+    );
+
+    run_commands(
+        temp_dir.path(),
+        &[&["undo", "--force"], &["undo", "--force"]],
+    )?;
+    assert_eq!(fs::read_to_string(&config_path)?, before);
+    Ok(())
+}
+
+#[test]
+fn test_config_set_rejects_invalid_values_without_writing() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let before = fs::read_to_string(&config_path)?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["config", "set", "concurrency.lock_timeout_secs", "soon"],
+            &["config", "set", "source_scan.pattern", "(("],
+            &["config", "set", "paths.docs_output", "missing/parent/docs"],
+            &["config", "set", "paths.unknown", "x"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("Invalid value for concurrency.lock_timeout_secs: expected integer"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Invalid source_scan.pattern regex"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("paths.docs_output: parent directory missing/parent does not exist"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("error[E0508]: Unknown config key 'paths.unknown'"),
+        "output: {output}"
+    );
+    assert_eq!(fs::read_to_string(&config_path)?, before);
+    Ok(())
+}
+
+#[test]
+fn test_config_set_local_writes_override_file() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &[
+                "config",
+                "set",
+                "project.default_owner",
+                "@alice",
+                "--local",
+            ],
+            &["config", "set", "schema.version", "1", "--local"],
+            &["config", "list", "-o", "plain"],
+        ],
+    )?;
+
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("gov/config.local.toml"))?,
+        "[project]\ndefault_owner = \"@alice\"\n"
+    );
+    assert!(
+        output.contains("schema.version describes the governed tree"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("project.default_owner = \"@alice\""),
+        "output: {output}"
+    );
+    Ok(())
+}
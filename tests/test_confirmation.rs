@@ -0,0 +1,81 @@
+//! Tests for confirmation prompts without a terminal, `--yes`, and
+//! `GOVCTL_ASSUME_YES`.
+
+mod common;
+
+use common::{first_work_id, init_project_with_date, run_commands, run_dynamic_commands, work_new};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn work_item_count(dir: &Path) -> std::io::Result<usize> {
+    Ok(fs::read_dir(dir.join("gov/work"))?.count())
+}
+
+#[test]
+fn test_prompt_without_terminal_fails_instead_of_waiting() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching")])?;
+
+    let output = run_commands(temp_dir.path(), &[&["work", "delete", &id]])?;
+
+    assert!(output.contains("error[E0824]"), "{output}");
+    assert!(
+        output.contains("pass --force or --yes (or set GOVCTL_ASSUME_YES=1)"),
+        "{output}"
+    );
+    assert_eq!(work_item_count(temp_dir.path())?, 1);
+    Ok(())
+}
+
+#[test]
+fn test_yes_flag_and_env_answer_prompts() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching"), work_new("Search")])?;
+
+    let output = run_commands(temp_dir.path(), &[&["--yes", "work", "delete", &id]])?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert_eq!(work_item_count(temp_dir.path())?, 1);
+
+    let remaining = fs::read_dir(temp_dir.path().join("gov/work"))?
+        .next()
+        .ok_or("missing work item")??
+        .path();
+    let item: toml::Value = toml::from_str(&fs::read_to_string(&remaining)?)?;
+    let remaining_id = item["govctl"]["id"].as_str().unwrap_or_default();
+    let result = Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(["work", "delete", remaining_id])
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .env("GOVCTL_ASSUME_YES", "1")
+        .output()?;
+    assert!(
+        result.status.success(),
+        "{}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+    assert_eq!(work_item_count(temp_dir.path())?, 0);
+    Ok(())
+}
+
+#[test]
+fn test_yes_flag_answers_prompts_in_apply_steps() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching")])?;
+    fs::write(
+        temp_dir.path().join("ops.yaml"),
+        format!("- [work, delete, {id}]\n"),
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["apply", "ops.yaml"]])?;
+    assert!(output.contains("error[E0824]"), "{output}");
+    assert_eq!(work_item_count(temp_dir.path())?, 1);
+
+    let output = run_commands(temp_dir.path(), &[&["--yes", "apply", "ops.yaml"]])?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert_eq!(work_item_count(temp_dir.path())?, 0);
+    Ok(())
+}
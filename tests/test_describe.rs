@@ -2,7 +2,7 @@
 
 mod common;
 
-use common::{init_project_with_date, temp_dir_with_date};
+use common::{init_project_with_date, run_commands, temp_dir_with_date};
 
 #[test]
 fn test_describe_basic() -> common::TestResult {
@@ -155,3 +155,89 @@ fn test_describe_with_context_queued_work_items() -> common::TestResult {
     );
     Ok(())
 }
+
+#[test]
+fn test_describe_with_context_blocked_work_and_unmet_gates() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let first = format!("WI-{date}-001");
+    let second = format!("WI-{date}-002");
+
+    crate::assert_normalized_command_snapshot!(
+        "test_describe",
+        temp_dir.path(),
+        &date,
+        &[
+            &["rfc", "new", "Test RFC"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "advance", "RFC-0001", "impl"],
+            &["work", "new", "First task"],
+            &["work", "new", "Second task"],
+            &["work", "add", &first, "refs", "RFC-0001:C-SCOPE"],
+            &["work", "add", &second, "depends_on", &first],
+            &["work", "move", &second, "active"],
+            &["describe", "--context"],
+        ],
+    );
+    Ok(())
+}
+
+#[test]
+fn test_describe_with_context_reports_checks_on_clause_text() -> common::TestResult {
+    let (temp_dir, _) = temp_dir_with_date()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["init"],
+            &["rfc", "new", "Payments"],
+            &["clause", "new", "RFC-0001:C-MISSING", "Missing"],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-MISSING",
+                "text",
+                "{{include:FRAG-MISSING}}",
+            ],
+            &["describe", "--context"],
+        ],
+    )?;
+    assert!(output.contains("\"code\": \"E0216\""), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_describe_schemas_emits_each_file_format() -> common::TestResult {
+    let (temp_dir, _) = temp_dir_with_date()?;
+    let output = run_commands(temp_dir.path(), &[&["describe", "--schemas"]])?;
+    let payload = output
+        .split_once('\n')
+        .and_then(|(_, body)| body.strip_suffix("exit: 0\n\n"))
+        .ok_or("single successful command output")?;
+    let value: serde_json::Value = serde_json::from_str(payload)?;
+
+    for format in ["rfc", "clause", "adr", "work", "release", "guard", "config"] {
+        let schema = &value["schemas"][format];
+        assert_eq!(
+            schema["$schema"], "http://json-schema.org/draft-07/schema#",
+            "{format} schema: {schema}"
+        );
+    }
+    assert!(
+        value["schemas"]["rfc"]["properties"]["govctl"].is_object(),
+        "{payload}"
+    );
+
+    let conflict = run_commands(temp_dir.path(), &[&["describe", "--schemas", "--context"]])?;
+    assert!(
+        conflict.contains("cannot be used with '--context'"),
+        "{conflict}"
+    );
+    Ok(())
+}
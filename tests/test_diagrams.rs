@@ -0,0 +1,42 @@
+//! Diagram blocks in clause and ADR prose are checked parse-only.
+
+mod common;
+
+use common::{init_project, run_commands};
+
+#[test]
+fn test_check_reports_invalid_diagram_blocks() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Architecture"],
+            &["clause", "new", "RFC-0001:C-FLOW", "Request flow"],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-FLOW",
+                "text",
+                "Requests MUST pass the gateway.\n\n```mermaid\nflowchart LR\n  Client --> Gateway[API gateway]\n```\n",
+            ],
+            &["clause", "new", "RFC-0001:C-SEQ", "Sequence"],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-SEQ",
+                "text",
+                "See below.\n\n```plantuml\n@startuml\nif (ok) then\n:accept;\n@enduml\n```\n",
+            ],
+            &["check"],
+        ],
+    )?;
+    assert!(output.contains("warning[W0127]"), "{output}");
+    assert!(
+        output.contains(
+            "Invalid plantuml diagram in text (block at line 3): line 2: 'if' is never closed"
+        ),
+        "{output}"
+    );
+    assert_eq!(output.matches("warning[W0127]").count(), 1, "{output}");
+    Ok(())
+}
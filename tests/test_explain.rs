@@ -0,0 +1,41 @@
+//! Tests for `govctl explain`.
+
+mod common;
+
+use common::{init_project, run_commands};
+
+#[test]
+fn test_explain_prints_causes_fixes_and_clauses() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["explain", "e0207"], &["explain", "E9999"]],
+    )?;
+    assert!(output.contains("E0207 (error, exit code 5)"), "{output}");
+    assert!(output.contains("Typical causes:"), "{output}");
+    assert!(output.contains("How to fix:"), "{output}");
+    assert!(output.contains("  - RFC-0001:C-CLAUSE-STATUS"), "{output}");
+    assert!(
+        output.contains("error[E0828]: Unknown diagnostic code: E9999"),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_explain_json_lists_every_code() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let result = std::process::Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(["explain", "-o", "json"])
+        .current_dir(temp_dir.path())
+        .output()?;
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&result.stdout)?;
+    let warning = entries
+        .iter()
+        .find(|entry| entry["code"] == "W0122")
+        .ok_or("missing W0122")?;
+    assert_eq!(warning["level"], "warning");
+    assert!(warning.get("exit_code").is_none());
+    assert!(entries.iter().any(|entry| entry["code"] == "E0828"));
+    Ok(())
+}
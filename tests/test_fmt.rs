@@ -0,0 +1,88 @@
+//! Tests for `govctl fmt` and `fmt --check`.
+
+mod common;
+
+use common::{init_project_with_date, run_commands};
+use std::fs;
+
+#[test]
+fn test_fmt_check_passes_on_govctl_written_files() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Cache API"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-TTL",
+                "TTL",
+                "-s",
+                "Specification",
+            ],
+            &["adr", "new", "Use Redis"],
+            &["work", "new", "Implement caching"],
+            &["fmt", "--check"],
+        ],
+    )?;
+    let check = output
+        .split("$ govctl fmt --check")
+        .nth(1)
+        .unwrap_or_default();
+    assert!(
+        check.contains("All artifact files are formatted"),
+        "{output}"
+    );
+    assert!(check.contains("exit: 0"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_fmt_rewrites_hand_edited_files_and_wraps_prose() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let dir = temp_dir.path();
+    run_commands(dir, &[&["adr", "new", "Use Redis"]])?;
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[format]\nwrap_width = 40\n");
+    fs::write(&config_path, config)?;
+
+    let adr_path = fs::read_dir(dir.join("gov/adr"))?
+        .next()
+        .ok_or("missing ADR")??
+        .path();
+    fs::write(
+        &adr_path,
+        format!(
+            "#:schema ../schema/adr.schema.json\n\n\
+             [govctl]\n\
+             # Decided at the cache review\n\
+             title = \"Use Redis\"\n\
+             id = \"ADR-0001\"\n\
+             status = \"proposed\"\n\
+             date = \"{date}\"\n\n\
+             [content]\n\
+             decision = \"We will use Redis for the response cache because it is already deployed.\"\n\
+             context = \"Responses are slow.\"\n\
+             consequences = \"Redis must stay up.\"\n"
+        ),
+    )?;
+
+    let output = run_commands(dir, &[&["fmt", "--check"], &["fmt"], &["fmt", "--check"]])?;
+    assert!(
+        output.contains("error[E0827]: 1 artifact file(s) not formatted"),
+        "{output}"
+    );
+    assert!(output.contains("Formatted: gov/adr/"), "{output}");
+    assert!(output.ends_with("exit: 0\n\n"), "{output}");
+
+    let formatted = fs::read_to_string(&adr_path)?;
+    assert!(!formatted.contains("# Decided"), "{formatted}");
+    assert!(
+        formatted.contains(
+            "decision = \"\"\"\nWe will use Redis for the response cache\nbecause it is already deployed.\"\"\""
+        ),
+        "{formatted}"
+    );
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! Clause text includes shared fragments from `gov/fragments/`.
+
+mod common;
+
+use common::{init_project, run_commands};
+
+#[test]
+fn test_include_expands_fragment_when_rendered() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let fragments = temp_dir.path().join("gov/fragments");
+    std::fs::create_dir_all(&fragments)?;
+    std::fs::write(
+        fragments.join("FRAG-SEC-001.md"),
+        "{{system}} MUST encrypt data at rest.\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["clause", "new", "RFC-0001:C-SECURITY", "Security"],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-SECURITY",
+                "text",
+                "{{include:FRAG-SEC-001 system=\"Payments API\"}}",
+            ],
+            &["rfc", "render", "RFC-0001"],
+            &["check"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "{output}");
+    let rendered = std::fs::read_to_string(temp_dir.path().join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        rendered.contains("Payments API MUST encrypt data at rest."),
+        "{rendered}"
+    );
+    assert!(!rendered.contains("{{include:"), "{rendered}");
+
+    // Editing the fragment leaves the rendered RFC stale.
+    std::fs::write(
+        fragments.join("FRAG-SEC-001.md"),
+        "{{system}} MUST encrypt data at rest and in transit.\n",
+    )?;
+    let output = run_commands(temp_dir.path(), &[&["check"]])?;
+    assert!(output.contains("error[E0601]"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_check_reports_missing_fragment_and_parameter() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let fragments = temp_dir.path().join("gov/fragments");
+    std::fs::create_dir_all(&fragments)?;
+    std::fs::write(
+        fragments.join("FRAG-SEC-001.md"),
+        "{{system}} MUST encrypt data at rest.\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["clause", "new", "RFC-0001:C-MISSING", "Missing"],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-MISSING",
+                "text",
+                "{{include:FRAG-NONE-001}}",
+            ],
+            &["clause", "new", "RFC-0001:C-UNFILLED", "Unfilled"],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-UNFILLED",
+                "text",
+                "{{include:FRAG-SEC-001}}",
+            ],
+            &["check"],
+        ],
+    )?;
+    assert!(output.contains("error[E0216]"), "{output}");
+    assert!(
+        output.contains("Included fragment FRAG-NONE-001 not found"),
+        "{output}"
+    );
+    assert!(output.contains("error[E0217]"), "{output}");
+    assert!(
+        output.contains("Fragment FRAG-SEC-001 needs parameter 'system'"),
+        "{output}"
+    );
+    Ok(())
+}
@@ -0,0 +1,110 @@
+//! Tests for `rfc freeze` / `rfc unfreeze` review windows.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_frozen_rfc_refuses_writes_until_unfrozen() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &["rfc", "freeze", "RFC-0001"],
+            &["rfc", "freeze", "RFC-0001"],
+            &["rfc", "set", "RFC-0001", "title", "Billing"],
+            &["clause", "set", "RFC-0001:C-SCOPE", "text", "Changed"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-LIMITS",
+                "Limits",
+                "-s",
+                "Specification",
+            ],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "approve", "RFC-0001", "--as", "@alice"],
+            &["render"],
+            &["check"],
+            &["rfc", "unfreeze", "RFC-0001"],
+            &["rfc", "set", "RFC-0001", "title", "Billing"],
+        ],
+    )?;
+
+    assert!(output.contains("Froze RFC-0001"), "output: {output}");
+    assert!(
+        output.contains("RFC-0001 is already frozen"),
+        "output: {output}"
+    );
+    assert_eq!(
+        output
+            .matches("error[E0122]: RFC-0001 is frozen for review")
+            .count(),
+        4,
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Approved RFC-0001 as @alice"),
+        "output: {output}"
+    );
+    assert!(output.contains("Unfroze RFC-0001"), "output: {output}");
+
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("title = \"Billing\""), "{rfc}");
+    assert!(rfc.contains("status = \"draft\""), "{rfc}");
+    assert!(!rfc.contains("frozen"), "{rfc}");
+    assert!(
+        !temp_dir
+            .path()
+            .join("gov/rfc/RFC-0001/clauses/C-LIMITS.toml")
+            .exists()
+    );
+    let rendered = fs::read_to_string(temp_dir.path().join("docs/rfc/RFC-0001.md"))?;
+    assert!(rendered.contains("> **Frozen for review**"), "{rendered}");
+    Ok(())
+}
+
+#[test]
+fn test_frozen_rfc_refuses_attachments() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(temp_dir.path().join("flow.svg"), "<svg/>")?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["attach", "add", "RFC-0001", "flow.svg"],
+            &["rfc", "freeze", "RFC-0001"],
+            &["attach", "add", "RFC-0001", "flow.svg"],
+            &["attach", "remove", "RFC-0001", "flow.svg"],
+        ],
+    )?;
+
+    assert_eq!(
+        output
+            .matches("error[E0122]: RFC-0001 is frozen for review")
+            .count(),
+        2,
+        "output: {output}"
+    );
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("RFC-0001/flow.svg"), "{rfc}");
+    assert!(
+        temp_dir
+            .path()
+            .join("gov/assets/RFC-0001/flow.svg")
+            .is_file()
+    );
+    Ok(())
+}
@@ -0,0 +1,174 @@
+//! Tests for GitHub links on work items and `release --github` against a
+//! local stand-in for the GitHub REST API.
+
+mod common;
+
+use common::{
+    RecordedRequests, first_work_id, format_command_output, init_project_with_date, mock_server,
+    run_commands, run_dynamic_commands, work_add_acceptance, work_move_done, work_new_active,
+    work_tick_acceptance_done,
+};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Serve canned GitHub responses on a local port.
+fn mock_github() -> std::io::Result<(u16, RecordedRequests)> {
+    mock_server(|method, path| match (method, path) {
+        ("GET", "/repos/acme/api/issues/1") => ("200 OK", r#"{"state":"open"}"#),
+        ("GET", "/repos/acme/api/issues/2") => (
+            "200 OK",
+            r#"{"state":"closed","pull_request":{"merged_at":"2026-01-02T00:00:00Z"}}"#,
+        ),
+        ("POST", "/repos/acme/api/releases") => (
+            "201 Created",
+            r#"{"html_url":"https://github.com/acme/api/releases/tag/v0.1.0"}"#,
+        ),
+        _ => ("404 Not Found", r#"{"message":"Not Found"}"#),
+    })
+}
+
+fn add_github(dir: &Path, port: u16, repo: Option<&str>) -> std::io::Result<()> {
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(&format!(
+        "\n[github]\napi_url = \"http://127.0.0.1:{port}\"\n"
+    ));
+    if let Some(repo) = repo {
+        config.push_str(&format!("repo = \"{repo}\"\n"));
+    }
+    fs::write(config_path, config)
+}
+
+fn govctl(dir: &Path, args: &[&str], token: Option<&str>) -> std::io::Result<String> {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_govctl"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GH_TOKEN");
+    if let Some(token) = token {
+        command.env("GITHUB_TOKEN", token);
+    }
+    Ok(format_command_output(args, &command.output()?))
+}
+
+fn done_work_item(dir: &Path, date: &str) -> common::TestResult {
+    let work_id = first_work_id(date);
+    run_dynamic_commands(
+        dir,
+        &[
+            work_new_active("Ship caching"),
+            work_add_acceptance(&work_id, "add: Cache works"),
+            work_tick_acceptance_done(&work_id, "Cache works"),
+            work_move_done(&work_id),
+        ],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_work_link_and_list_github_state() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let work_id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new_active("Ship caching")])?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "link", &work_id, "--github", "acme/api#1"],
+            &["work", "link", &work_id, "--github", "acme/api#2"],
+            &["work", "link", &work_id, "--github", "acme/api#404"],
+            &["work", "link", &work_id, "--github", "acme#1"],
+        ],
+    )?;
+    assert!(output.contains("error[E0820]"), "{output}");
+
+    let work_path = fs::read_dir(temp_dir.path().join("gov/work"))?
+        .next()
+        .ok_or("missing work item")??
+        .path();
+    let work: toml::Value = toml::from_str(&fs::read_to_string(work_path)?)?;
+    assert_eq!(
+        work["govctl"]["github"],
+        toml::Value::try_from(["acme/api#1", "acme/api#2", "acme/api#404"])?
+    );
+
+    let (port, requests) = mock_github()?;
+    add_github(temp_dir.path(), port, None)?;
+    let output = govctl(
+        temp_dir.path(),
+        &["work", "list", "active", "--github"],
+        Some("secret"),
+    )?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert!(
+        output.contains("acme/api#1 (open), acme/api#2 (merged), acme/api#404 (not found)"),
+        "{output}"
+    );
+    let requests = requests.lock().map_err(|err| err.to_string())?.clone();
+    assert_eq!(requests.len(), 3, "{requests:?}");
+    assert_eq!(requests[0].authorization, "authorization: Bearer secret");
+    Ok(())
+}
+
+#[test]
+fn test_release_github_drafts_release_from_notes() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    done_work_item(temp_dir.path(), &date)?;
+    let (port, requests) = mock_github()?;
+    add_github(temp_dir.path(), port, Some("acme/api"))?;
+
+    let output = govctl(
+        temp_dir.path(),
+        &["--dry-run", "release", "0.1.0", "--github"],
+        None,
+    )?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert!(output.contains("v0.1.0"), "{output}");
+    assert!(requests.lock().map_err(|err| err.to_string())?.is_empty());
+
+    let output = govctl(
+        temp_dir.path(),
+        &["release", "0.1.0", "--github"],
+        Some("secret"),
+    )?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert!(
+        output.contains("https://github.com/acme/api/releases/tag/v0.1.0"),
+        "{output}"
+    );
+    let requests = requests.lock().map_err(|err| err.to_string())?.clone();
+    let draft = requests
+        .iter()
+        .find(|request| request.method == "POST" && request.path == "/repos/acme/api/releases")
+        .map(|request| &request.body)
+        .ok_or("no release drafted")?;
+    assert!(draft.contains(r#""draft":true"#), "{draft}");
+    assert!(draft.contains(r#""tag_name":"v0.1.0""#), "{draft}");
+    assert!(draft.contains("- Cache works"), "{draft}");
+    assert!(fs::read_to_string(temp_dir.path().join("gov/releases.toml"))?.contains("0.1.0"));
+    Ok(())
+}
+
+#[test]
+fn test_release_github_requires_repo_and_token() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    done_work_item(temp_dir.path(), &date)?;
+
+    let output = govctl(
+        temp_dir.path(),
+        &["release", "0.1.0", "--github"],
+        Some("secret"),
+    )?;
+    assert!(output.contains("error[E0513]"), "{output}");
+
+    add_github(temp_dir.path(), 9, Some("acme/api"))?;
+    let output = govctl(temp_dir.path(), &["release", "0.1.0", "--github"], None)?;
+    assert!(
+        output.contains("error[E0513]: GITHUB_TOKEN is not set"),
+        "{output}"
+    );
+    assert!(!temp_dir.path().join("gov/releases.toml").exists());
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! Tests for `govctl undo` and `govctl history`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_undo_reverts_last_write_command() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching layer"],
+            &["rfc", "set", "RFC-0001", "title", "Cache layer"],
+            &["undo"],
+            &["rfc", "get", "RFC-0001", "title"],
+            &["undo"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("Undid #2: govctl rfc set RFC-0001 title \"Cache layer\""),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Undid #1: govctl rfc new \"Caching layer\""),
+        "output: {output}"
+    );
+    assert!(!output.contains("exit: 1"), "output: {output}");
+    assert!(!temp_dir.path().join("gov/rfc/RFC-0001").exists());
+    Ok(())
+}
+
+#[test]
+fn test_history_lists_recent_operations() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["history"],
+            &["tag", "new", "caching"],
+            &["adr", "new", "Use Redis"],
+            &["rfc", "list"],
+        ],
+    )?;
+    assert!(
+        output.contains("No recorded operations"),
+        "output: {output}"
+    );
+
+    let listed = run_commands(temp_dir.path(), &[&["history", "-o", "plain"]])?;
+    assert!(
+        listed.contains("2\t") && listed.contains("govctl adr new \"Use Redis\""),
+        "output: {listed}"
+    );
+    assert!(listed.contains("1\t"), "output: {listed}");
+    assert!(
+        listed.contains("govctl tag new caching"),
+        "output: {listed}"
+    );
+    assert!(!listed.contains("govctl rfc list"), "output: {listed}");
+
+    let newest = run_commands(temp_dir.path(), &[&["history", "-n", "1", "-o", "plain"]])?;
+    assert!(newest.contains("Use Redis"), "output: {newest}");
+    assert!(!newest.contains("govctl tag new"), "output: {newest}");
+    Ok(())
+}
+
+#[test]
+fn test_undo_refuses_when_files_changed_since() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(temp_dir.path(), &[&["rfc", "new", "Caching layer"]])?;
+    let rfc_path = temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml");
+    let edited = format!("{}\n# local note\n", fs::read_to_string(&rfc_path)?);
+    fs::write(&rfc_path, &edited)?;
+
+    let output = run_commands(temp_dir.path(), &[&["undo"]])?;
+    assert!(output.contains("error[E1302]"), "output: {output}");
+    assert!(output.contains("--force"), "output: {output}");
+    assert_eq!(fs::read_to_string(&rfc_path)?, edited);
+
+    let output = run_commands(temp_dir.path(), &[&["undo", "--force"]])?;
+    assert!(output.contains("exit: 0"), "output: {output}");
+    assert!(!rfc_path.exists());
+    Ok(())
+}
+
+#[test]
+fn test_undo_without_history_fails() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(temp_dir.path(), &[&["undo"]])?;
+    assert!(output.contains("error[E1301]"), "output: {output}");
+    assert!(output.contains("exit: 1"), "output: {output}");
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! Tests for user commands configured under `[hooks]`.
+
+mod common;
+
+use common::{first_work_id, init_project_with_date, run_commands, run_dynamic_commands, work_new};
+use std::fs;
+use std::path::Path;
+
+fn add_hooks(dir: &Path, hooks: &str) -> std::io::Result<()> {
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(hooks);
+    fs::write(config_path, config)
+}
+
+fn work_status(dir: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    let entry = fs::read_dir(dir.join("gov/work"))?
+        .next()
+        .ok_or("missing work item")??;
+    let item: toml::Value = toml::from_str(&fs::read_to_string(entry.path())?)?;
+    Ok(item["govctl"]["status"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string())
+}
+
+#[test]
+fn test_pre_move_hook_receives_event_and_can_block() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching")])?;
+    add_hooks(
+        temp_dir.path(),
+        r#"
+[hooks]
+pre_move = [
+  { command = 'echo "$GOVCTL_HOOK $GOVCTL_ID $GOVCTL_STATUS" >> hooks.log; cat >> hooks.log; echo >> hooks.log' },
+  { command = 'test "$GOVCTL_STATUS" != active' },
+]
+"#,
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["--dry-run", "work", "move", &id, "active"],
+            &["work", "move", &id, "active"],
+        ],
+    )?;
+    assert!(
+        output.contains("Would run hook: test \"$GOVCTL_STATUS\" != active (pre_move)"),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "error[E1008]: pre_move hook `test \"$GOVCTL_STATUS\" != active` failed: exit code 1"
+        ),
+        "{output}"
+    );
+    assert_eq!(work_status(temp_dir.path())?, "queue");
+
+    let log = fs::read_to_string(temp_dir.path().join("hooks.log"))?;
+    let mut lines = log.lines();
+    assert_eq!(lines.next(), Some(format!("pre_move {id} active").as_str()));
+    let payload: serde_json::Value = serde_json::from_str(lines.next().unwrap_or_default())?;
+    assert_eq!(payload["hook"], "pre_move");
+    assert_eq!(payload["id"], id.as_str());
+    assert_eq!(payload["status"], "active");
+    assert!(lines.next().is_none(), "{log}");
+    Ok(())
+}
+
+#[test]
+fn test_post_move_hook_failure_policy() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching")])?;
+    add_hooks(
+        temp_dir.path(),
+        r#"
+[hooks]
+post_move = [{ command = 'grep -q "status = \"active\"" gov/work/*.toml && exit 3' }]
+"#,
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["work", "move", &id, "active"]])?;
+    assert!(output.contains("error[E1008]"), "{output}");
+    assert!(output.contains("failed: exit code 3"), "{output}");
+    assert_eq!(work_status(temp_dir.path())?, "queue");
+
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?
+        .replace("&& exit 3' }", "&& exit 3', on_failure = \"warn\" }");
+    fs::write(&config_path, config)?;
+    let output = run_commands(temp_dir.path(), &[&["work", "move", &id, "active"]])?;
+    assert!(output.contains("warning[W0126]"), "{output}");
+    assert!(output.contains("exit: 0"), "{output}");
+    assert_eq!(work_status(temp_dir.path())?, "active");
+    Ok(())
+}
+
+#[test]
+fn test_hook_past_its_timeout_is_killed() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching")])?;
+    add_hooks(
+        temp_dir.path(),
+        r#"
+[hooks]
+pre_move = [{ command = "sleep 30 & wait", timeout_secs = 1 }]
+"#,
+    )?;
+
+    let started = std::time::Instant::now();
+    let output = run_commands(temp_dir.path(), &[&["work", "move", &id, "active"]])?;
+    assert!(started.elapsed() < std::time::Duration::from_secs(20));
+    assert!(
+        output.contains("error[E1008]: pre_move hook `sleep 30 & wait` failed: timed out after 1s"),
+        "{output}"
+    );
+    assert_eq!(work_status(temp_dir.path())?, "queue");
+    Ok(())
+}
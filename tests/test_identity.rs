@@ -0,0 +1,55 @@
+//! Actor identity: `--as`, `GOVCTL_ACTOR`, and `[identity] actor`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_created_artifacts_record_the_actor() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "By a person"],
+            &["--as", "agent:claude", "adr", "new", "By an agent"],
+            &["--as", "bot:ci", "rfc", "new", "Rejected"],
+            &["history", "-o", "json"],
+        ],
+    )?;
+
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("created_by = \"human:test-user\""), "{rfc}");
+    let adr = fs::read_to_string(temp_dir.path().join("gov/adr/ADR-0001-by-an-agent.toml"))?;
+    assert!(adr.contains("created_by = \"agent:claude\""), "{adr}");
+
+    assert!(output.contains("error[E0831]"), "{output}");
+    assert!(!temp_dir.path().join("gov/rfc/RFC-0002").exists());
+    assert!(output.contains(r#""actor": "agent:claude""#), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_identity_config_names_the_actor() -> common::TestResult {
+    let temp_dir = init_project()?;
+    fs::write(
+        temp_dir.path().join("gov/config.local.toml"),
+        "[identity]\nactor = \"agent:planner\"\n",
+    )?;
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(["work", "new", "Planned"])
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .env_remove("GOVCTL_ACTOR")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let work_dir = temp_dir.path().join("gov/work");
+    let mut found = false;
+    for entry in fs::read_dir(&work_dir)? {
+        let body = fs::read_to_string(entry?.path())?;
+        found |= body.contains("created_by = \"agent:planner\"");
+    }
+    assert!(found, "no work item records the configured actor");
+    Ok(())
+}
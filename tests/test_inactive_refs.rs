@@ -0,0 +1,102 @@
+//! Tests for refs that point at deprecated or superseded artifacts.
+
+mod common;
+
+use common::{init_project, run_commands};
+
+#[test]
+fn test_check_warns_on_inactive_refs_and_strict_refs_fails() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments v1"],
+            &["rfc", "new", "Payments v2"],
+            &["adr", "new", "Use a ledger"],
+            &["adr", "new", "Use an event log"],
+            &["adr", "add", "ADR-0002", "refs", "RFC-0001"],
+            &["adr", "add", "ADR-0002", "refs", "ADR-0001"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "supersede", "RFC-0001", "--by", "RFC-0002", "-f"],
+            &["adr", "accept", "ADR-0001", "--force"],
+            &["adr", "accept", "ADR-0002", "--force"],
+        ],
+    )?;
+
+    let output = run_commands(dir, &[&["check"], &["check", "--strict-refs"]])?;
+    let (check, strict) = output
+        .split_once("$ govctl check --strict-refs")
+        .ok_or("missing strict run")?;
+
+    assert!(
+        check.contains(
+            "warning[W0123]: ADR-0002 references RFC-0001, which is deprecated (hint: point the ref at its replacement or remove it)"
+        ),
+        "output: {output}"
+    );
+    assert!(check.contains("exit: 0"), "output: {output}");
+    assert!(
+        strict.contains("error[W0123]: ADR-0002 references RFC-0001, which is deprecated"),
+        "output: {output}"
+    );
+    assert!(!strict.contains("exit: 0"), "output: {output}");
+
+    let output = run_commands(
+        dir,
+        &[
+            &["adr", "supersede", "ADR-0001", "--by", "ADR-0002", "-f"],
+            &["check"],
+        ],
+    )?;
+    assert!(
+        !output.contains("references ADR-0001"),
+        "the replacement may cite what it replaces: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_refs_to_amended_or_earlier_chain_decisions_are_not_inactive() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    run_commands(
+        dir,
+        &[
+            &["adr", "new", "Store as JSON"],
+            &["adr", "new", "Amend storage"],
+            &["adr", "new", "Store as TOML"],
+            &["adr", "new", "Store as YAML"],
+            &["adr", "new", "Late citation"],
+            &["adr", "edit", "ADR-0001", "date", "--set", "2019-01-01"],
+            &["adr", "edit", "ADR-0002", "date", "--set", "2019-06-01"],
+            &["adr", "edit", "ADR-0003", "date", "--set", "2020-01-01"],
+            &["adr", "edit", "ADR-0004", "date", "--set", "2020-06-01"],
+            &["adr", "add", "ADR-0002", "refs", "ADR-0001"],
+            &["adr", "add", "ADR-0004", "refs", "ADR-0001"],
+            &["adr", "add", "ADR-0005", "refs", "ADR-0001"],
+            &["adr", "accept", "ADR-0001", "--force"],
+            &["adr", "accept", "ADR-0002", "--force"],
+            &["adr", "accept", "ADR-0003", "--force"],
+            &["adr", "accept", "ADR-0004", "--force"],
+            &["adr", "accept", "ADR-0005", "--force"],
+            &["adr", "supersede", "ADR-0001", "--by", "ADR-0003", "-f"],
+            &["adr", "supersede", "ADR-0003", "--by", "ADR-0004", "-f"],
+        ],
+    )?;
+
+    let output = run_commands(dir, &[&["check"]])?;
+    assert!(
+        !output.contains("ADR-0002 references ADR-0001"),
+        "an ADR may cite the decision it amended before it was replaced: {output}"
+    );
+    assert!(
+        !output.contains("ADR-0004 references ADR-0001"),
+        "a replacement may cite anything earlier in its chain: {output}"
+    );
+    assert!(
+        output.contains("warning[W0123]: ADR-0005 references ADR-0001, which is superseded"),
+        "output: {output}"
+    );
+    Ok(())
+}
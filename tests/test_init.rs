@@ -44,6 +44,7 @@ fn test_init_creates_artifact_schema_files() -> common::TestResult {
         "guard.schema.json",
         "loop-state.schema.json",
         "loop-round.schema.json",
+        "config.schema.json",
     ] {
         let schema_path = temp_dir.path().join("gov/schema").join(filename);
         assert!(
@@ -69,6 +70,60 @@ fn test_init_creates_artifact_schema_files() -> common::TestResult {
     Ok(())
 }
 
+#[test]
+fn test_init_vscode_maps_governed_files_to_schemas() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+    let settings_path = temp_dir.path().join(".vscode/settings.json");
+    fs::create_dir_all(temp_dir.path().join(".vscode"))?;
+    fs::write(&settings_path, "{\"editor.tabSize\": 2}\n")?;
+
+    let output = run_commands(temp_dir.path(), &[&["init", "--vscode"]])?;
+    assert!(output.contains("Project initialized"), "{output}");
+
+    let settings: serde_json::Value = serde_json::from_str(&fs::read_to_string(&settings_path)?)?;
+    assert_eq!(settings["editor.tabSize"], 2, "existing settings are kept");
+    let associations = &settings["evenBetterToml.schema.associations"];
+    assert_eq!(
+        associations[r".*/gov/rfc/[^/]+/rfc\.toml$"],
+        "./gov/schema/rfc.schema.json"
+    );
+    assert_eq!(
+        associations[r".*/gov/work/[^/]+\.toml$"],
+        "./gov/schema/work.schema.json"
+    );
+    assert!(
+        fs::read_to_string(temp_dir.path().join("gov/config.toml"))?
+            .starts_with("#:schema schema/config.schema.json\n"),
+        "config.toml carries a schema header"
+    );
+
+    // migrate restores a mapping that went missing.
+    let mut trimmed = settings.clone();
+    if let Some(map) = trimmed["evenBetterToml.schema.associations"].as_object_mut() {
+        map.remove(r".*/gov/adr/[^/]+\.toml$");
+    }
+    fs::write(&settings_path, serde_json::to_string(&trimmed)?)?;
+    let output = run_commands(temp_dir.path(), &[&["migrate"]])?;
+    assert!(
+        output.contains("Synced 1 editor schema mapping(s)"),
+        "{output}"
+    );
+    let settings: serde_json::Value = serde_json::from_str(&fs::read_to_string(&settings_path)?)?;
+    assert_eq!(
+        settings["evenBetterToml.schema.associations"][r".*/gov/adr/[^/]+\.toml$"],
+        "./gov/schema/adr.schema.json"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_init_without_vscode_leaves_editor_settings_alone() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+    run_commands(temp_dir.path(), &[&["init"], &["migrate"]])?;
+    assert!(!temp_dir.path().join(".vscode").exists());
+    Ok(())
+}
+
 #[test]
 fn test_init_appends_to_existing_gitignore() -> common::TestResult {
     let temp_dir = TempDir::new()?;
@@ -106,7 +161,10 @@ fn test_init_appends_to_existing_gitignore_without_trailing_newline() -> common:
     assert!(output.contains("Project initialized"));
 
     let content = fs::read_to_string(&gitignore_path)?;
-    assert_eq!(content, "target/\n.govctl.lock\n.govctl/\n");
+    assert_eq!(
+        content,
+        "target/\n.govctl.lock\n.govctl/\ngov/config.local.toml\n"
+    );
     Ok(())
 }
 
@@ -202,3 +260,184 @@ docs_output = "output/docs"
     assert!(docs_dir.exists(), "docs should be under output/docs/adr/");
     Ok(())
 }
+
+#[test]
+fn test_init_from_packed_template_copies_baseline() -> common::TestResult {
+    let source = common::init_project()?;
+    let guard_dir = source.path().join("gov/guard");
+    fs::create_dir_all(&guard_dir)?;
+    fs::write(
+        guard_dir.join("GUARD-UNIT.toml"),
+        "[govctl]\nid = \"GUARD-UNIT\"\ntitle = \"Unit tests\"\n\n[check]\ncommand = \"true\"\n",
+    )?;
+    let config_path = source.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(&config_path, format!("{config}\n# shared baseline\n"))?;
+    let archive = source.path().join("baseline.tar.zst");
+
+    let output = run_commands(
+        source.path(),
+        &[&["template", "pack", "--out", "baseline.tar.zst"]],
+    )?;
+    assert!(output.contains("exit: 0"), "output: {output}");
+    assert!(
+        fs::read(&archive)?.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]),
+        "a .tar.zst template is zstd-compressed"
+    );
+
+    let target = TempDir::new()?;
+    let archive_arg = archive.display().to_string();
+    let output = run_commands(target.path(), &[&["init", "--from", &archive_arg]])?;
+    assert!(output.contains("Project initialized"), "output: {output}");
+
+    assert_eq!(
+        fs::read_to_string(target.path().join("gov/config.toml"))?,
+        fs::read_to_string(&config_path)?
+    );
+    assert!(target.path().join("gov/guard/GUARD-UNIT.toml").exists());
+    assert!(target.path().join("gov/schema/rfc.schema.json").exists());
+    Ok(())
+}
+
+#[test]
+fn test_init_from_template_keeps_seed_rfc_under_custom_prefix() -> common::TestResult {
+    let source = common::init_project()?;
+    let config_path = source.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{config}\n[prefixes]\nrfc = \"SPEC\"\n"),
+    )?;
+
+    let output = run_commands(
+        source.path(),
+        &[
+            &["rfc", "new", "Core", "--id", "SPEC-0000"],
+            &["template", "pack", "--out", "baseline.tar.zst"],
+        ],
+    )?;
+    assert!(output.contains("exit: 0"), "output: {output}");
+
+    let target = TempDir::new()?;
+    let archive_arg = source.path().join("baseline.tar.zst").display().to_string();
+    let output = run_commands(target.path(), &[&["init", "--from", &archive_arg]])?;
+    assert!(output.contains("Project initialized"), "output: {output}");
+    assert_eq!(
+        fs::read_to_string(target.path().join("gov/rfc/SPEC-0000/rfc.toml"))?,
+        fs::read_to_string(source.path().join("gov/rfc/SPEC-0000/rfc.toml"))?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_template_codec_follows_the_archive_name() -> common::TestResult {
+    let source = common::init_project()?;
+    let output = run_commands(
+        source.path(),
+        &[
+            &["template", "pack", "--out", "baseline.tgz"],
+            &["template", "pack", "--out", "baseline.zip"],
+        ],
+    )?;
+    assert!(
+        output.contains("Unsupported template archive name"),
+        "output: {output}"
+    );
+    assert!(!source.path().join("baseline.zip").exists());
+    let archive = source.path().join("baseline.tgz");
+    assert!(fs::read(&archive)?.starts_with(&[0x1f, 0x8b]));
+
+    let target = TempDir::new()?;
+    let archive_arg = archive.display().to_string();
+    let output = run_commands(target.path(), &[&["init", "--from", &archive_arg]])?;
+    assert!(output.contains("Project initialized"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_init_from_rejects_invalid_template() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+    fs::write(temp_dir.path().join("bogus.tar.gz"), "not an archive")?;
+
+    let output = run_commands(temp_dir.path(), &[&["init", "--from", "bogus.tar.gz"]])?;
+
+    assert!(output.contains("E0506"), "output: {output}");
+    assert!(
+        !temp_dir.path().join("gov/config.toml").exists(),
+        "invalid template must not write config"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_template_pack_requires_init() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["template", "pack", "--out", "baseline.tar.zst"]],
+    )?;
+
+    assert!(output.contains("E0501"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_init_minimal_preset_writes_bare_config() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["init", "--preset", "minimal"], &["check"]],
+    )?;
+    assert!(output.contains("All checks passed"), "output: {output}");
+
+    let config = fs::read_to_string(temp_dir.path().join("gov/config.toml"))?;
+    assert!(config.contains("[schema]"), "{config}");
+    assert!(
+        !config.contains("\n# "),
+        "minimal config has no guidance: {config}"
+    );
+    assert!(!temp_dir.path().join(".vscode").exists());
+    Ok(())
+}
+
+#[test]
+fn test_init_agents_preset_adds_guard_ulid_and_skills() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["init", "--preset", "agents"], &["check"]],
+    )?;
+    assert!(output.contains("All checks passed"), "output: {output}");
+
+    let config: toml::Value = toml::from_str(&fs::read_to_string(
+        temp_dir.path().join("gov/config.toml"),
+    )?)?;
+    assert_eq!(config["work_item"]["id_strategy"].as_str(), Some("ulid"));
+    assert_eq!(config["verification"]["enabled"].as_bool(), Some(true));
+    assert_eq!(
+        config["verification"]["default_guards"][0].as_str(),
+        Some("GUARD-GOVCTL-CHECK")
+    );
+    let guard = fs::read_to_string(temp_dir.path().join("gov/guard/govctl-check.toml"))?;
+    assert!(guard.contains("command = \"govctl check\""), "{guard}");
+    assert!(temp_dir.path().join(".claude/skills/gov/SKILL.md").exists());
+    assert!(temp_dir.path().join(".vscode/settings.json").exists());
+    Ok(())
+}
+
+#[test]
+fn test_init_interactive_requires_terminal() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+
+    let output = run_commands(temp_dir.path(), &[&["init", "--interactive"]])?;
+
+    assert!(output.contains("E0824"), "output: {output}");
+    assert!(
+        !temp_dir.path().join("gov/config.toml").exists(),
+        "nothing is written before the prompts"
+    );
+    Ok(())
+}
@@ -0,0 +1,188 @@
+//! Tests for Jira links on work items and `govctl sync jira` against a local
+//! stand-in for the Jira REST API.
+
+mod common;
+
+use common::{
+    RecordedRequests, first_work_id, format_command_output, init_project_with_date, mock_server,
+    run_commands, run_dynamic_commands, work_id, work_new, work_new_active,
+};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Serve canned Jira issues on a local port.
+fn mock_jira() -> std::io::Result<(u16, RecordedRequests)> {
+    mock_server(|_, path| match path.split('?').next() {
+        Some("/rest/api/2/issue/PROJ-1") => (
+            "200 OK",
+            r#"{"key":"PROJ-1","fields":{"status":{"name":"In Review","statusCategory":{"key":"indeterminate"}}}}"#,
+        ),
+        Some("/rest/api/2/issue/PROJ-2") => (
+            "200 OK",
+            r#"{"key":"PROJ-2","fields":{"status":{"name":"Closed","statusCategory":{"key":"done"}}}}"#,
+        ),
+        _ => (
+            "404 Not Found",
+            r#"{"errorMessages":["Issue does not exist"]}"#,
+        ),
+    })
+}
+
+fn add_jira(dir: &Path, port: u16) -> std::io::Result<()> {
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(&format!(
+        "\n[jira]\nbase_url = \"http://127.0.0.1:{port}\"\n"
+    ));
+    fs::write(config_path, config)
+}
+
+fn govctl(dir: &Path, args: &[&str], token: Option<&str>) -> std::io::Result<String> {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_govctl"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env_remove("JIRA_USER")
+        .env_remove("JIRA_TOKEN");
+    if let Some(token) = token {
+        command.env("JIRA_TOKEN", token);
+    }
+    Ok(format_command_output(args, &command.output()?))
+}
+
+fn work_item(dir: &Path, id: &str) -> Result<toml::Value, Box<dyn std::error::Error>> {
+    for entry in fs::read_dir(dir.join("gov/work"))? {
+        let path = entry?.path();
+        let item: toml::Value = toml::from_str(&fs::read_to_string(&path)?)?;
+        if item["govctl"]["id"].as_str() == Some(id) {
+            return Ok(item);
+        }
+    }
+    Err(format!("missing work item {id}").into())
+}
+
+#[test]
+fn test_work_link_jira_sets_key() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching")])?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "link", &id, "--jira", "proj-1"],
+            &["work", "link", &id],
+            &["work", "link", &id, "--jira", "PROJ-1"],
+            &["work", "show", &id],
+        ],
+    )?;
+    assert!(output.contains("error[E0820]"), "{output}");
+    assert!(
+        output.contains("the following required arguments were not provided"),
+        "{output}"
+    );
+    assert!(output.contains("**Jira:** PROJ-1"), "{output}");
+    assert_eq!(
+        work_item(temp_dir.path(), &id)?["govctl"]["jira_key"].as_str(),
+        Some("PROJ-1")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sync_jira_records_status_and_flags_divergence() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let active = work_id(&date, 1);
+    let queued = work_id(&date, 2);
+    run_dynamic_commands(
+        temp_dir.path(),
+        &[work_new_active("Caching"), work_new("Eviction")],
+    )?;
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "link", &active, "--jira", "PROJ-1"],
+            &["work", "link", &queued, "--jira", "PROJ-2"],
+        ],
+    )?;
+    let (port, requests) = mock_jira()?;
+    add_jira(temp_dir.path(), port)?;
+
+    let output = govctl(temp_dir.path(), &["sync", "jira"], Some("secret"))?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert!(
+        output.contains(&format!(
+            "warning[W0124]: {queued} is queue but Jira PROJ-2 is 'Closed'"
+        )),
+        "{output}"
+    );
+    assert!(!output.contains(&format!("{active} is")), "{output}");
+    assert!(
+        output.contains("Recorded Jira status changes on 2 work item(s)"),
+        "{output}"
+    );
+    let notes = &work_item(temp_dir.path(), &active)?["content"]["notes"];
+    assert_eq!(
+        notes.as_array().map(Vec::len),
+        Some(1),
+        "unexpected notes: {notes}"
+    );
+    assert!(
+        notes[0]
+            .as_str()
+            .is_some_and(|note| note.starts_with("Jira PROJ-1 status: In Review (as of ")),
+        "{notes}"
+    );
+
+    let output = govctl(temp_dir.path(), &["sync", "jira", &active], Some("secret"))?;
+    assert!(
+        output.contains("Recorded Jira status changes on 0 work item(s)"),
+        "{output}"
+    );
+    let requests = requests.lock().map_err(|err| err.to_string())?.clone();
+    assert_eq!(requests.len(), 3, "{requests:?}");
+    assert_eq!(requests[0].method, "GET", "{requests:?}");
+    assert_eq!(
+        requests[0].path, "/rest/api/2/issue/PROJ-1?fields=status",
+        "{requests:?}"
+    );
+    assert_eq!(requests[0].authorization, "authorization: Bearer secret");
+
+    let output = run_commands(temp_dir.path(), &[&["work", "show", &active]])?;
+    assert!(
+        output.contains(&format!(
+            "**Jira:** [PROJ-1](http://127.0.0.1:{port}/browse/PROJ-1)"
+        )),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sync_jira_requires_config_token_and_key() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let id = first_work_id(&date);
+    run_dynamic_commands(temp_dir.path(), &[work_new("Caching")])?;
+
+    let output = govctl(temp_dir.path(), &["sync", "jira"], Some("secret"))?;
+    assert!(output.contains("error[E0513]"), "{output}");
+
+    add_jira(temp_dir.path(), 9)?;
+    let output = govctl(temp_dir.path(), &["sync", "jira", &id], Some("secret"))?;
+    assert!(
+        output.contains(&format!("error[E0812]: {id} has no jira_key")),
+        "{output}"
+    );
+
+    run_commands(
+        temp_dir.path(),
+        &[&["work", "link", &id, "--jira", "PROJ-1"]],
+    )?;
+    let output = govctl(temp_dir.path(), &["sync", "jira"], None)?;
+    assert!(
+        output.contains("error[E0513]: JIRA_TOKEN is not set"),
+        "{output}"
+    );
+    Ok(())
+}
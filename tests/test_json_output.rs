@@ -0,0 +1,142 @@
+//! Tests for the global `--output json` result output.
+
+mod common;
+
+use common::{first_work_id, init_project_with_date, run_commands};
+use serde_json::Value;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn govctl_json(dir: &Path, args: &[&str]) -> Result<(Output, Value), Box<dyn std::error::Error>> {
+    let output = Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(["--output", "json"])
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
+        .output()?;
+    let value = serde_json::from_slice(&output.stdout)?;
+    Ok((output, value))
+}
+
+#[test]
+fn test_json_envelope_reports_changed_artifacts() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let (output, value) = govctl_json(temp_dir.path(), &["work", "new", "Implement caching"])?;
+
+    assert!(output.status.success());
+    assert_eq!(value["status"], "ok");
+    assert_eq!(value["dry_run"], false);
+    assert_eq!(value["changes"][0]["change"], "created");
+    assert_eq!(value["changes"][0]["artifact"], first_work_id(&date));
+    assert_eq!(value["changes"].as_array().map(Vec::len), Some(1));
+    assert_eq!(value["diagnostics"], Value::Array(vec![]));
+    Ok(())
+}
+
+#[test]
+fn test_dry_run_reports_unified_diffs() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    govctl_json(temp_dir.path(), &["rfc", "new", "Cache"])?;
+    let rfc_path = temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml");
+    let before = std::fs::read_to_string(&rfc_path)?;
+
+    let (output, value) = govctl_json(
+        temp_dir.path(),
+        &["--dry-run", "rfc", "set", "RFC-0001", "title", "Cache API"],
+    )?;
+
+    assert!(output.status.success());
+    assert_eq!(value["dry_run"], true);
+    assert_eq!(value["changes"][0]["change"], "modified");
+    assert_eq!(value["changes"][0]["artifact"], "RFC-0001");
+    let diff = value["changes"][0]["diff"].as_str().unwrap_or_default();
+    assert!(
+        diff.starts_with("--- a/gov/rfc/RFC-0001/rfc.toml\n+++ b/gov/rfc/RFC-0001/rfc.toml\n@@ "),
+        "{diff}"
+    );
+    assert!(
+        diff.contains("\n-title = \"Cache\"\n+title = \"Cache API\"\n"),
+        "{diff}"
+    );
+    assert_eq!(std::fs::read_to_string(&rfc_path)?, before);
+    Ok(())
+}
+
+#[test]
+fn test_json_envelope_reports_errors_instead_of_stderr() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    let (output, value) = govctl_json(
+        temp_dir.path(),
+        &["rfc", "finalize", "RFC-0099", "normative"],
+    )?;
+
+    assert_eq!(output.status.code(), Some(4));
+    assert_eq!(value["status"], "error");
+    assert_eq!(value["changes"], Value::Array(vec![]));
+    assert_eq!(value["diagnostics"][0]["code"], "E0102");
+    assert_eq!(value["diagnostics"][0]["level"], "error");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("error[E0102]"), "stderr: {stderr}");
+    Ok(())
+}
+
+#[test]
+fn test_json_query_commands_print_their_data() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    govctl_json(temp_dir.path(), &["rfc", "new", "Caching layer"])?;
+    let (output, value) = govctl_json(temp_dir.path(), &["rfc", "list"])?;
+
+    assert!(output.status.success());
+    let ids: Vec<&Value> = value
+        .as_array()
+        .map(|rfcs| rfcs.iter().map(|rfc| &rfc["id"]).collect())
+        .unwrap_or_default();
+    assert!(ids.contains(&&Value::from("RFC-0001")), "stdout: {value}");
+    Ok(())
+}
+
+#[test]
+fn test_diagnostics_report_line_and_column() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    govctl_json(temp_dir.path(), &["adr", "new", "Cache"])?;
+    let adr_path = temp_dir.path().join("gov/adr/ADR-0001-cache.toml");
+    let content = std::fs::read_to_string(&adr_path)?;
+    std::fs::write(
+        &adr_path,
+        content.replace("status = \"proposed\"", "status = 5"),
+    )?;
+
+    let (output, value) = govctl_json(temp_dir.path(), &["check"])?;
+
+    assert!(!output.status.success());
+    let diagnostic = &value["diagnostics"][0];
+    assert_eq!(diagnostic["code"], "E0301");
+    assert_eq!(diagnostic["file"], "gov/adr/ADR-0001-cache.toml");
+    assert_eq!(diagnostic["line"], 6);
+    assert_eq!(diagnostic["column"], 10);
+    Ok(())
+}
+
+#[test]
+fn test_output_formats_a_command_cannot_produce_are_rejected() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "new", "Implement caching", "-o", "yaml"],
+            &["work", "list", "-o", "toml"],
+            &["work", "list", "-o", "plain"],
+        ],
+    )?;
+
+    assert_eq!(output.matches("error[E0822]").count(), 2, "{output}");
+    assert!(
+        output.contains("This command does not support --output yaml"),
+        "{output}"
+    );
+    // The rejected `work new` created nothing for the plain list to show.
+    assert!(!output.contains("WI-"), "{output}");
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Compliance mappings: `govctl mapping`, their validation in `check`, and
+//! `render compliance`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+fn init_mapped_project() -> Result<tempfile::TempDir, Box<dyn std::error::Error>> {
+    let temp_dir = init_project()?;
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Access"],
+            &["clause", "new", "RFC-0001:C-ACCESS", "Access control"],
+            &["clause", "new", "RFC-0001:C-AUDIT", "Audit log"],
+            &[
+                "mapping",
+                "add",
+                "ISO-27001",
+                "A.5.15",
+                "RFC-0001:C-ACCESS",
+                "--title",
+                "Access control",
+                "--standard-title",
+                "ISO/IEC 27001:2022",
+            ],
+            &["mapping", "add", "SOC2", "CC7.2", "RFC-0001:C-AUDIT"],
+        ],
+    )?;
+    Ok(temp_dir)
+}
+
+#[test]
+fn test_mapping_add_writes_one_file_per_standard() -> common::TestResult {
+    let temp_dir = init_mapped_project()?;
+
+    let iso = fs::read_to_string(temp_dir.path().join("gov/mapping/iso-27001.toml"))?;
+    assert!(iso.contains("title = \"ISO/IEC 27001:2022\""), "{iso}");
+    assert!(iso.contains("id = \"A.5.15\""), "{iso}");
+    assert!(iso.contains("clauses = [\"RFC-0001:C-ACCESS\"]"), "{iso}");
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["mapping", "list", "-o", "plain"],
+            &["mapping", "add", "SOC2", "CC7.2", "RFC-0001:C-MISSING"],
+        ],
+    )?;
+    assert!(
+        output.contains("ISO-27001\tA.5.15\tRFC-0001:C-ACCESS"),
+        "{output}"
+    );
+    assert!(output.contains("SOC2\tCC7.2\tRFC-0001:C-AUDIT"), "{output}");
+    assert!(output.contains("error[E1402]"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_check_rejects_controls_mapped_to_inactive_clauses() -> common::TestResult {
+    let temp_dir = init_mapped_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["clause", "deprecate", "RFC-0001:C-AUDIT", "--force"],
+            &["check"],
+            &["mapping", "remove", "SOC2", "CC7.2"],
+            &["check"],
+        ],
+    )?;
+
+    let (before, after) = output
+        .split_once("mapping remove")
+        .ok_or("missing remove output")?;
+    assert!(
+        before.contains(
+            "error[E1403]: Control 'SOC2 CC7.2' is mapped to RFC-0001:C-AUDIT, which is deprecated"
+        ),
+        "{before}"
+    );
+    assert!(!after.contains("E1403"), "{after}");
+    Ok(())
+}
+
+#[test]
+fn test_render_compliance_writes_matrix() -> common::TestResult {
+    let temp_dir = init_mapped_project()?;
+    run_commands(temp_dir.path(), &[&["render", "compliance"]])?;
+
+    let matrix = fs::read_to_string(temp_dir.path().join("docs/compliance.md"))?;
+    assert!(
+        matrix.contains("## ISO/IEC 27001:2022 (ISO-27001)"),
+        "{matrix}"
+    );
+    assert!(
+        matrix.contains(
+            "| A.5.15 | Access control | [RFC-0001:C-ACCESS](./rfc/RFC-0001.md#rfc-0001c-access) | covered |"
+        ),
+        "{matrix}"
+    );
+    assert!(matrix.contains("## SOC2 (SOC2)"), "{matrix}");
+    Ok(())
+}
@@ -245,7 +245,7 @@ fn test_migrate_syncs_missing_local_state_gitignore_entry_at_current_version() -
     let temp_dir = init_project()?;
     let expected_version = current_schema_version(temp_dir.path())?;
     let gitignore_path = temp_dir.path().join(".gitignore");
-    fs::write(&gitignore_path, ".govctl.lock\n")?;
+    fs::write(&gitignore_path, ".govctl.lock\ngov/config.local.toml\n")?;
 
     let output = run_commands(temp_dir.path(), &[&["migrate"]])?;
     assert!(
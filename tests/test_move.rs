@@ -109,6 +109,164 @@ fn test_move_to_done_with_pending_criteria_fails() -> common::TestResult {
     assert_move_snapshot!(temp_dir, &date, &output)
 }
 
+#[test]
+fn test_move_to_done_checks_configured_requirements() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let work_id = first_work_id(&date);
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!(
+            "{config}\n[work_item.done]\nacceptance_criteria = false\nnotes = true\nrefs = true\n"
+        ),
+    )?;
+    run_commands(
+        temp_dir.path(),
+        &[&["work", "new", "Test task", "--active"]],
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["work", "move", &work_id, "done"]])?;
+    assert!(!output.contains("error[E0407]"), "{output}");
+    assert!(
+        output.contains("error[E0412]: Cannot mark as done: no notes recorded"),
+        "{output}"
+    );
+    assert!(
+        output.contains("error[E0412]: Cannot mark as done: no refs"),
+        "{output}"
+    );
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching"],
+            &["work", "add", &work_id, "notes", "Cache keyed by path"],
+            &["work", "add", &work_id, "refs", "RFC-0001"],
+            &["work", "move", &work_id, "done"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "{output}");
+    assert!(output.contains("done"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_work_start_and_finish_move_through_the_lifecycle() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let work_id = first_work_id(&date);
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "new", "Test task"],
+            &[
+                "work",
+                "add",
+                &work_id,
+                "acceptance_criteria",
+                "add: Feature",
+            ],
+            &[
+                "work",
+                "add",
+                &work_id,
+                "acceptance_criteria",
+                "chore: Docs",
+            ],
+        ],
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "start", &work_id],
+            &["work", "get", &work_id, "started"],
+            &["work", "finish", &work_id, "--tick-chores"],
+        ],
+    )?;
+    assert!(output.contains(&format!("{date}\nexit: 0")), "{output}");
+    assert!(
+        output.contains("1 pending acceptance criteria:\n  - Feature"),
+        "{output}"
+    );
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &[
+                "work",
+                "tick",
+                &work_id,
+                "acceptance_criteria",
+                "Feature",
+                "-s",
+                "done",
+            ],
+            &["work", "finish", &work_id, "--tick-chores"],
+            &["work", "get", &work_id, "status"],
+            &["work", "get", &work_id, "completed"],
+        ],
+    )?;
+    assert!(output.contains("done\nexit: 0"), "{output}");
+    assert!(output.contains(&format!("{date}\nexit: 0")), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_move_requeues_cancelled_item_with_reason() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let work_id = first_work_id(&date);
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "new", "Test task"],
+            &["work", "move", &work_id, "cancelled"],
+            &[
+                "work", "move", &work_id, "active", "--reopen", "--reason", "x",
+            ],
+            &[
+                "work", "move", &work_id, "queue", "--reopen", "--reason", " ",
+            ],
+            &[
+                "work",
+                "move",
+                &work_id,
+                "queue",
+                "--reopen",
+                "--reason",
+                "Customer asked again",
+            ],
+            &[
+                "work", "move", &work_id, "active", "--reopen", "--reason", "x",
+            ],
+            &["work", "get", &work_id, "status"],
+            &["work", "get", &work_id, "notes"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("Invalid transition: cancelled -> active"),
+        "output: {output}"
+    );
+    assert!(output.contains("error[E0812]"), "output: {output}");
+    assert!(
+        output.contains("--reopen only applies to done -> active and cancelled -> queue"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(&format!(
+            "Reopened from cancelled on {date}: Customer asked again"
+        )),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(&format!("$ govctl work get {work_id} status\nqueue")),
+        "output: {output}"
+    );
+    Ok(())
+}
+
 #[test]
 fn test_move_active_to_cancelled() -> common::TestResult {
     let (temp_dir, date) = init_project_with_date()?;
@@ -279,11 +437,25 @@ fn test_move_reopens_unreleased_done_item_and_updates_timestamps() -> common::Te
             ],
             &["work", "move", &work_id, "done"],
             &["work", "move", &work_id, "active"],
+            &[
+                "work",
+                "move",
+                &work_id,
+                "active",
+                "--reopen",
+                "--reason",
+                "Fails on Windows paths",
+            ],
             &["work", "get", &work_id, "status"],
             &["work", "get", &work_id, "started"],
         ],
     )?;
 
+    assert!(
+        output.contains("requires --reopen --reason"),
+        "output: {output}"
+    );
+
     assert!(
         output.contains(&format!("$ govctl work get {work_id} status\nactive")),
         "output: {output}"
@@ -299,6 +471,10 @@ fn test_move_reopens_unreleased_done_item_and_updates_timestamps() -> common::Te
         .path();
     let work: toml::Value = toml::from_str(&fs::read_to_string(work_path)?)?;
     assert!(work["govctl"].get("completed").is_none());
+    assert_eq!(
+        work["content"]["notes"][0].as_str(),
+        Some(format!("Reopened from done on {date}: Fails on Windows paths").as_str())
+    );
     Ok(())
 }
 
@@ -322,7 +498,15 @@ fn test_move_rejects_reopening_released_done_item() -> common::TestResult {
             ],
             &["work", "move", &work_id, "done"],
             &["release", "0.1.0"],
-            &["work", "move", &work_id, "active"],
+            &[
+                "work",
+                "move",
+                &work_id,
+                "active",
+                "--reopen",
+                "--reason",
+                "Fails on Windows paths",
+            ],
             &["work", "get", &work_id, "status"],
         ],
     )?;
@@ -371,7 +555,18 @@ fn test_move_reopen_release_load_failure_preserves_work_item() -> common::TestRe
         "not valid TOML [",
     )?;
 
-    let output = run_commands(temp_dir.path(), &[&["work", "move", &work_id, "active"]])?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[&[
+            "work",
+            "move",
+            &work_id,
+            "active",
+            "--reopen",
+            "--reason",
+            "Fails on Windows paths",
+        ]],
+    )?;
 
     assert!(output.contains("error[E0704]"), "output: {output}");
     assert_eq!(fs::read_to_string(work_path)?, original);
@@ -409,7 +604,18 @@ fn test_move_reopen_does_not_mutate_existing_loop_files() -> common::TestResult
     fs::write(&state_path, &state)?;
     fs::write(&round_path, &round)?;
 
-    run_commands(temp_dir.path(), &[&["work", "move", &work_id, "active"]])?;
+    run_commands(
+        temp_dir.path(),
+        &[&[
+            "work",
+            "move",
+            &work_id,
+            "active",
+            "--reopen",
+            "--reason",
+            "Fails on Windows paths",
+        ]],
+    )?;
 
     assert_eq!(fs::read_to_string(state_path)?, state);
     assert_eq!(fs::read_to_string(round_path)?, round);
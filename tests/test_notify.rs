@@ -0,0 +1,140 @@
+//! Tests for lifecycle webhooks configured under `[[notify.webhooks]]`.
+
+mod common;
+
+use common::{
+    RecordedRequests, first_work_id, format_command_output, init_project_with_date, mock_server,
+    run_dynamic_commands, work_add_acceptance, work_new_active, work_tick_acceptance_done,
+};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Accept webhook POSTs on a local port; `/broken` answers with a server error.
+fn mock_webhook() -> std::io::Result<(u16, RecordedRequests)> {
+    mock_server(|_, path| {
+        if path == "/broken" {
+            ("500 Internal Server Error", "")
+        } else {
+            ("200 OK", "")
+        }
+    })
+}
+
+fn add_webhooks(dir: &Path, webhooks: &str) -> std::io::Result<()> {
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(webhooks);
+    fs::write(config_path, config)
+}
+
+fn govctl(dir: &Path, args: &[&str]) -> std::io::Result<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env("TEST_WEBHOOK_URL", "http://127.0.0.1:9/from-env")
+        .output()?;
+    Ok(format_command_output(args, &output))
+}
+
+fn ready_work_item(dir: &Path, date: &str) -> common::TestResult {
+    let work_id = first_work_id(date);
+    run_dynamic_commands(
+        dir,
+        &[
+            work_new_active("Ship caching"),
+            work_add_acceptance(&work_id, "add: Cache works"),
+            work_tick_acceptance_done(&work_id, "Cache works"),
+        ],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_webhooks_receive_filtered_events() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let work_id = first_work_id(&date);
+    ready_work_item(temp_dir.path(), &date)?;
+    let (port, requests) = mock_webhook()?;
+    add_webhooks(
+        temp_dir.path(),
+        &format!(
+            "\n[[notify.webhooks]]\nurl = \"http://127.0.0.1:{port}/all\"\n\n[[notify.webhooks]]\nurl = \"http://127.0.0.1:{port}/slack\"\nevents = [\"release\"]\nformat = \"slack\"\n"
+        ),
+    )?;
+
+    let output = govctl(temp_dir.path(), &["work", "move", &work_id, "done"])?;
+    assert!(output.contains("exit: 0"), "{output}");
+    let output = govctl(temp_dir.path(), &["release", "0.1.0"])?;
+    assert!(output.contains("exit: 0"), "{output}");
+    let output = govctl(temp_dir.path(), &["work", "list"])?;
+    assert!(output.contains("exit: 0"), "{output}");
+
+    let requests = requests.lock().map_err(|err| err.to_string())?.clone();
+    assert_eq!(requests.len(), 3, "{requests:?}");
+    assert_eq!(requests[0].path, "/all", "{requests:?}");
+    let body = &requests[0].body;
+    assert!(body.contains(r#""event":"work.done""#), "{body}");
+    assert!(body.contains(&format!(r#""id":"{work_id}""#)), "{body}");
+    assert!(body.contains(r#""status":"done""#), "{body}");
+    let release = requests
+        .iter()
+        .find(|request| request.path == "/all" && request.body.contains(r#""event":"release""#))
+        .map(|request| &request.body)
+        .ok_or("release not sent as JSON")?;
+    assert!(
+        release.contains(&format!(r#""work_items":["{work_id}"]"#)),
+        "{release}"
+    );
+    let slack = requests
+        .iter()
+        .find(|request| request.path == "/slack")
+        .map(|request| &request.body)
+        .ok_or("release not sent to Slack")?;
+    assert!(
+        slack.contains(r#"{"text":"*"#) && slack.contains("*: Released 0.1.0 with 1 work item(s)"),
+        "{slack}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_webhook_failures_warn_without_failing() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let work_id = first_work_id(&date);
+    ready_work_item(temp_dir.path(), &date)?;
+    let (port, requests) = mock_webhook()?;
+    add_webhooks(
+        temp_dir.path(),
+        &format!(
+            "\n[[notify.webhooks]]\nurl = \"http://127.0.0.1:{port}/broken\"\n\n[[notify.webhooks]]\nurl = \"$TEST_WEBHOOK_URL\"\n\n[[notify.webhooks]]\nurl = \"$MISSING_WEBHOOK_URL\"\n"
+        ),
+    )?;
+
+    let output = govctl(
+        temp_dir.path(),
+        &["--dry-run", "work", "move", &work_id, "done"],
+    )?;
+    assert!(
+        output.contains(&format!("Would notify: 127.0.0.1 (work.done {work_id})")),
+        "{output}"
+    );
+    assert!(requests.lock().map_err(|err| err.to_string())?.is_empty());
+
+    let output = govctl(temp_dir.path(), &["work", "move", &work_id, "done"])?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert!(
+        output.contains(&format!(
+            "warning[W0125]: Webhook for work.done {work_id} failed: 500 Internal Server Error"
+        )),
+        "{output}"
+    );
+    assert_eq!(output.matches("warning[W0125]").count(), 3, "{output}");
+    assert!(
+        output.contains("MISSING_WEBHOOK_URL is not set"),
+        "{output}"
+    );
+    assert!(!output.contains("from-env"), "{output}");
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//! Tests for the `[owners]` registry and RFC approvals.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+use std::path::Path;
+
+const OWNERS_CONFIG: &str = r#"
+[owners]
+members = ["@alice", "@bob", "carol@example.com"]
+require_approval = true
+
+[owners.teams]
+"@acme/platform" = ["@bob"]
+"#;
+
+fn add_owners_registry(root: &Path) -> std::io::Result<()> {
+    let config_path = root.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(OWNERS_CONFIG);
+    fs::write(config_path, config)
+}
+
+#[test]
+fn test_finalize_requires_owner_approval() -> common::TestResult {
+    let temp_dir = init_project()?;
+    add_owners_registry(temp_dir.path())?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["check"],
+            &["rfc", "add", "RFC-0001", "owners", "@mallory"],
+            &["rfc", "add", "RFC-0001", "owners", "@acme/platform"],
+            &["rfc", "remove", "RFC-0001", "owners", "--at", "0"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "add", "RFC-0001", "approvals", "@alice"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "add", "RFC-0001", "approvals", "@bob"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["check"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("is not a member or team listed in [owners]"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "error[E0120]: RFC 'RFC-0001' owner '@mallory' is not a member or team listed in [owners]"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "error[E0121]: RFC 'RFC-0001' needs an approval from one of its owners (@acme/platform)"
+        ),
+        "output: {output}"
+    );
+    assert_eq!(
+        output.matches("error[E0121]").count(),
+        2,
+        "output: {output}"
+    );
+    let last_check = output.rsplit("$ govctl check").next().unwrap_or_default();
+    assert!(last_check.contains("exit: 0"), "output: {output}");
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("status = \"normative\""), "{rfc}");
+    assert!(rfc.contains("\"@bob\","), "{rfc}");
+    Ok(())
+}
+
+#[test]
+fn test_approvals_must_come_from_registered_members() -> common::TestResult {
+    let temp_dir = init_project()?;
+    add_owners_registry(temp_dir.path())?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "add", "RFC-0001", "approvals", "@acme/platform"],
+            &["rfc", "add", "RFC-0001", "approvals", "carol@example.com"],
+        ],
+    )?;
+
+    assert!(
+        output.contains(
+            "error[E0120]: RFC 'RFC-0001' approval by '@acme/platform' is not from a member listed in [owners]"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Added 'carol@example.com' to RFC-0001.approvals"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_empty_registry_leaves_owners_unchecked() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "add", "RFC-0001", "owners", "@anyone"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+        ],
+    )?;
+
+    assert!(!output.contains("error["), "output: {output}");
+    Ok(())
+}
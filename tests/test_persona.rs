@@ -0,0 +1,50 @@
+//! `--for agent` diagnostics: stable layout with exact fix commands.
+
+mod common;
+
+use common::{init_project, run_commands};
+
+#[test]
+fn test_agent_persona_lists_fix_commands() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["--for", "agent", "check", "--has-active"],
+            &["check", "--has-active"],
+        ],
+    )?;
+
+    let (agent, human) = output
+        .split_once("$ govctl check")
+        .ok_or("missing human output")?;
+    assert!(
+        agent.contains(
+            "warning[W0109]: No active work item ()\n  fix: govctl work new --active \"<title>\"\n  explain: govctl explain W0109\n"
+        ),
+        "{agent}"
+    );
+    assert!(
+        human.contains("warning[W0109]: No active work item (hint: `govctl work new"),
+        "{human}"
+    );
+    assert!(!human.contains("fix:"), "{human}");
+    Ok(())
+}
+
+#[test]
+fn test_agent_persona_adds_fixes_to_json_diagnostics() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[&[
+            "--for", "agent", "-o", "json", "mapping", "remove", "SOC2", "CC1.1",
+        ]],
+    )?;
+
+    assert!(
+        output.contains("\"fixes\": [\n        \"govctl mapping list\"\n      ]"),
+        "{output}"
+    );
+    Ok(())
+}
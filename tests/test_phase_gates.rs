@@ -0,0 +1,118 @@
+//! Tests for `[gates]` phase checklists on `rfc advance`.
+
+mod common;
+
+use common::{first_work_id, init_project_with_date, run_commands};
+use std::fs;
+use std::path::Path;
+
+const GATES_CONFIG: &str = r#"
+[gates]
+impl = ["adr-references-rfc"]
+stable = ["work-items-done"]
+"#;
+
+fn add_gates(root: &Path) -> std::io::Result<()> {
+    let config_path = root.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(GATES_CONFIG);
+    fs::write(config_path, config)
+}
+
+#[test]
+fn test_advance_evaluates_gates_and_force_overrides() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    add_gates(temp_dir.path())?;
+    let work_id = first_work_id(&date);
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "advance", "RFC-0001", "impl"],
+            &["adr", "new", "Use Postgres"],
+            &["adr", "add", "ADR-0001", "refs", "RFC-0001"],
+            &["rfc", "advance", "RFC-0001", "impl"],
+            &["rfc", "advance", "RFC-0001", "test"],
+            &["work", "new", "Wire up payments"],
+            &["work", "add", &work_id, "refs", "RFC-0001"],
+            &["rfc", "advance", "RFC-0001", "stable"],
+            &["rfc", "advance", "RFC-0001", "stable", "--force"],
+        ],
+    )?;
+
+    assert!(
+        output.contains(
+            "error[E0123]: Cannot advance RFC-0001 to impl: unmet gates: adr-references-rfc (no ADR references RFC-0001)"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Advanced RFC-0001 to phase: test"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(&format!(
+            "error[E0123]: Cannot advance RFC-0001 to stable: unmet gates: work-items-done (open work items: {work_id})"
+        )),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("warning[W0118]: Advanced RFC-0001 to stable with unmet gates"),
+        "output: {output}"
+    );
+    let rfc = fs::read_to_string(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("phase = \"stable\""), "{rfc}");
+    Ok(())
+}
+
+#[test]
+fn test_open_clause_work_blocks_test_phase() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let work_id = first_work_id(&date);
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "advance", "RFC-0001", "impl"],
+            &["work", "new", "Implement scope", "--active"],
+            &["work", "add", &work_id, "refs", "RFC-0001:C-SCOPE"],
+            &["rfc", "advance", "RFC-0001", "test", "--report"],
+            &["rfc", "advance", "RFC-0001", "test"],
+            &["work", "move", &work_id, "cancelled"],
+            &["rfc", "advance", "RFC-0001", "test", "--report"],
+            &["rfc", "advance", "RFC-0001", "test"],
+        ],
+    )?;
+
+    assert!(
+        output.contains(&format!(
+            "Blockers for RFC-0001 to enter test:\n  - clause-work-done: open work items on its clauses: {work_id} (C-SCOPE)"
+        )),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("error[E0123]: Cannot advance RFC-0001 to test"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("No blockers for RFC-0001 to enter test"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Advanced RFC-0001 to phase: test"),
+        "output: {output}"
+    );
+    Ok(())
+}
@@ -0,0 +1,73 @@
+//! Tests for renamed artifact prefixes under `[prefixes]`.
+
+mod common;
+
+use common::{first_work_id, init_project_with_date, run_commands, run_dynamic_commands, work_new};
+use std::fs;
+use std::path::Path;
+
+fn set_prefixes(dir: &Path, prefixes: &str) -> std::io::Result<()> {
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(prefixes);
+    fs::write(config_path, config)
+}
+
+#[test]
+fn test_configured_prefixes_drive_ids_refs_and_links() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let dir = temp_dir.path();
+    set_prefixes(dir, "\n[prefixes]\nrfc = \"SPEC\"\nadr = \"DEC\"\n")?;
+    let work_id = first_work_id(&date);
+    run_dynamic_commands(dir, &[work_new("Caching")])?;
+
+    let output = run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Cache API"],
+            &[
+                "clause",
+                "new",
+                "SPEC-0001:C-TTL",
+                "TTL",
+                "-s",
+                "Specification",
+            ],
+            &["adr", "new", "Use Redis"],
+            &["adr", "add", "DEC-0001", "refs", "SPEC-0001:C-TTL"],
+            &["work", "add", &work_id, "refs", "DEC-0001"],
+            &["rfc", "add", "SPEC-0001", "refs", "DEC-0001"],
+            &["rfc", "new", "Legacy", "--id", "RFC-0002"],
+            &["check"],
+            &["adr", "render", "DEC-0001"],
+        ],
+    )?;
+    assert!(dir.join("gov/rfc/SPEC-0001/rfc.toml").exists(), "{output}");
+    assert!(
+        output.contains("error[E0112]: RFC 'SPEC-0001' references DEC-0001"),
+        "{output}"
+    );
+    assert!(
+        output.contains("RFC ID must start with 'SPEC-' (got: RFC-0002)"),
+        "{output}"
+    );
+    let check = output.split("$ govctl check").nth(1).unwrap_or_default();
+    assert!(!check.contains("error["), "{output}");
+
+    let adr = fs::read_to_string(dir.join("docs/adr/DEC-0001.md"))?;
+    assert!(
+        adr.contains("[SPEC-0001:C-TTL](../rfc/SPEC-0001.md#spec-0001c-ttl)"),
+        "{adr}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_prefixes_are_rejected() -> common::TestResult {
+    let (temp_dir, _) = init_project_with_date()?;
+    set_prefixes(temp_dir.path(), "\n[prefixes]\nrfc = \"WI\"\n")?;
+    let output = run_commands(temp_dir.path(), &[&["rfc", "list"]])?;
+    assert!(output.contains("error[E0501]"), "{output}");
+    assert!(output.contains("Invalid artifact prefix 'WI'"), "{output}");
+    Ok(())
+}
@@ -0,0 +1,200 @@
+//! Tests for `work prioritize --suggest`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::{fs, path::Path};
+
+fn write_work_item(
+    dir: &Path,
+    id: &str,
+    status: &str,
+    refs: &[&str],
+    depends_on: &[&str],
+) -> common::TestResult {
+    let quote = |values: &[&str]| {
+        values
+            .iter()
+            .map(|value| format!("\"{value}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let content = format!(
+        r#"[govctl]
+schema = 1
+id = "{id}"
+title = "Item {id}"
+status = "{status}"
+created = "2025-01-01"
+refs = [{refs}]
+depends_on = [{depends_on}]
+
+[content]
+description = "Test"
+"#,
+        refs = quote(refs),
+        depends_on = quote(depends_on),
+    );
+    fs::write(
+        dir.join(format!("gov/work/{}.toml", id.to_lowercase())),
+        content,
+    )?;
+    Ok(())
+}
+
+fn write_impl_rfc(dir: &Path, rfc_id: &str) -> common::TestResult {
+    let rfc_dir = dir.join("gov/rfc").join(rfc_id);
+    fs::create_dir_all(rfc_dir.join("clauses"))?;
+    fs::write(
+        rfc_dir.join("rfc.toml"),
+        format!(
+            r#"[govctl]
+schema = 1
+id = "{rfc_id}"
+title = "Impl RFC"
+version = "0.1.0"
+status = "normative"
+phase = "impl"
+owners = ["@test-user"]
+created = "2025-01-01"
+
+[[sections]]
+title = "Specification"
+
+[[changelog]]
+version = "0.1.0"
+date = "2025-01-01"
+notes = "Initial version"
+"#
+        ),
+    )?;
+    Ok(())
+}
+
+fn ranked_ids(output: &str) -> Vec<&str> {
+    output
+        .lines()
+        .filter_map(|line| line.split('\t').nth(1))
+        .collect()
+}
+
+#[test]
+fn test_prioritize_ranks_by_signals_with_reasons() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    write_impl_rfc(dir, "RFC-0001")?;
+    write_work_item(dir, "WI-2025-01-01-001", "queue", &[], &[])?;
+    write_work_item(dir, "WI-2025-01-01-002", "queue", &["RFC-0001:C-X"], &[])?;
+    write_work_item(
+        dir,
+        "WI-2025-01-01-003",
+        "queue",
+        &[],
+        &["WI-2025-01-01-004"],
+    )?;
+    write_work_item(dir, "WI-2025-01-01-004", "queue", &[], &[])?;
+    write_work_item(
+        dir,
+        "WI-2025-01-01-005",
+        "active",
+        &[],
+        &["WI-2025-01-01-004"],
+    )?;
+
+    let output = run_commands(dir, &[&["work", "prioritize", "--suggest", "-o", "plain"]])?;
+
+    assert_eq!(
+        ranked_ids(&output),
+        [
+            "WI-2025-01-01-004",
+            "WI-2025-01-01-002",
+            "WI-2025-01-01-001",
+            "WI-2025-01-01-003",
+        ],
+        "output: {output}"
+    );
+    assert!(
+        output.contains("unblocks active WI-2025-01-01-005; blocks queued WI-2025-01-01-003"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("implements RFC-0001 (normative, impl)"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("waiting on WI-2025-01-01-004"),
+        "output: {output}"
+    );
+    assert!(!output.contains("WI-2025-01-01-005\t"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_prioritize_rewards_last_open_item_for_rfc() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    write_work_item(dir, "WI-2025-01-01-001", "queue", &[], &[])?;
+    write_work_item(dir, "WI-2025-01-01-002", "queue", &["RFC-0002"], &[])?;
+    write_work_item(dir, "WI-2025-01-01-003", "done", &["RFC-0002"], &[])?;
+
+    let output = run_commands(dir, &[&["work", "prioritize", "--suggest", "-o", "plain"]])?;
+
+    assert_eq!(
+        ranked_ids(&output),
+        ["WI-2025-01-01-002", "WI-2025-01-01-001"],
+        "output: {output}"
+    );
+    assert!(
+        output.contains("last open work item for RFC-0002 (1 done)"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_prioritize_apply_writes_priority_field() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    write_work_item(
+        dir,
+        "WI-2025-01-01-001",
+        "queue",
+        &[],
+        &["WI-2025-01-01-002"],
+    )?;
+    write_work_item(dir, "WI-2025-01-01-002", "queue", &[], &[])?;
+
+    let output = run_commands(
+        dir,
+        &[
+            &["work", "prioritize", "--suggest", "--apply", "-o", "plain"],
+            &["work", "get", "WI-2025-01-01-001", "priority"],
+            &["work", "get", "WI-2025-01-01-002", "priority"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("Applied priority to 2 queued work item(s)"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("$ govctl work get WI-2025-01-01-001 priority\n2\n"),
+        "output: {output}"
+    );
+    let first = fs::read_to_string(dir.join("gov/work/wi-2025-01-01-001.toml"))?;
+    let second = fs::read_to_string(dir.join("gov/work/wi-2025-01-01-002.toml"))?;
+    assert!(first.contains("priority = 2"), "{first}");
+    assert!(second.contains("priority = 1"), "{second}");
+    Ok(())
+}
+
+#[test]
+fn test_prioritize_requires_suggest() -> common::TestResult {
+    let temp_dir = init_project()?;
+
+    let output = run_commands(temp_dir.path(), &[&["work", "prioritize"]])?;
+
+    assert!(output.contains("--suggest"), "output: {output}");
+    assert!(!output.contains("exit: 0"), "output: {output}");
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! Tests for `govctl publish confluence` against a local stand-in for the
+//! Confluence REST API.
+
+mod common;
+
+use common::{RecordedRequests, format_command_output, init_project, mock_server, run_commands};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Serve canned Confluence responses on a local port.
+fn mock_confluence() -> std::io::Result<(u16, RecordedRequests)> {
+    mock_server(|method, path| {
+        let response = match (method, path) {
+            ("GET", path) if path.starts_with("/wiki/rest/api/content?") => r#"{"results":[]}"#,
+            ("GET", path) if path.starts_with("/wiki/rest/api/content/555") => {
+                r#"{"id":"555","version":{"number":4}}"#
+            }
+            ("PUT", "/wiki/rest/api/content/555") => r#"{"id":"555"}"#,
+            ("POST", "/wiki/rest/api/content") => r#"{"id":"101"}"#,
+            ("POST", path) if path.ends_with("/label") => r#"{"results":[]}"#,
+            _ => "{}",
+        };
+        ("200 OK", response)
+    })
+}
+
+fn add_confluence(dir: &Path, port: u16) -> std::io::Result<()> {
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(&format!(
+        "\n[confluence]\nbase_url = \"http://127.0.0.1:{port}/wiki\"\nspace = \"ENG\"\nrfc_parent = \"42\"\n\n[confluence.pages]\n\"ADR-0001\" = \"555\"\n"
+    ));
+    fs::write(config_path, config)
+}
+
+fn publish(dir: &Path, args: &[&str], token: Option<&str>) -> std::io::Result<String> {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_govctl"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env_remove("CONFLUENCE_USER")
+        .env_remove("CONFLUENCE_TOKEN");
+    if let Some(token) = token {
+        command.env("CONFLUENCE_TOKEN", token);
+    }
+    Ok(format_command_output(args, &command.output()?))
+}
+
+#[test]
+fn test_publish_creates_updates_and_labels_pages() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(
+        temp_dir.path(),
+        &[&["rfc", "new", "Caching"], &["adr", "new", "Use Redis"]],
+    )?;
+    let (port, requests) = mock_confluence()?;
+    add_confluence(temp_dir.path(), port)?;
+
+    let output = publish(temp_dir.path(), &["publish", "confluence"], Some("secret"))?;
+    assert!(output.contains("exit: 0"), "{output}");
+    assert!(
+        output.contains(&format!(
+            "Published RFC-0001: http://127.0.0.1:{port}/wiki/pages/viewpage.action?pageId=101"
+        )),
+        "{output}"
+    );
+    assert!(output.contains("Published ADR-0001:"), "{output}");
+
+    let requests = requests.lock().map_err(|err| err.to_string())?.clone();
+    let created = requests
+        .iter()
+        .find(|request| request.method == "POST" && request.path == "/wiki/rest/api/content")
+        .ok_or("no page created")?;
+    assert_eq!(created.authorization, "authorization: Bearer secret");
+    let created = &created.body;
+    assert!(
+        created.contains(r#""title":"RFC-0001: Caching""#),
+        "{created}"
+    );
+    assert!(
+        created.contains(r#""ancestors":[{"id":"42"}]"#),
+        "{created}"
+    );
+    assert!(created.contains(r#""key":"ENG""#), "{created}");
+    assert!(
+        created.contains("Generated by govctl from RFC-0001"),
+        "{created}"
+    );
+    assert!(!created.contains("SIGNATURE"), "{created}");
+
+    let updated = requests
+        .iter()
+        .find(|request| request.method == "PUT" && request.path == "/wiki/rest/api/content/555")
+        .map(|request| &request.body)
+        .ok_or("mapped ADR page not updated")?;
+    assert!(updated.contains(r#""version":{"number":5}"#), "{updated}");
+    assert!(!updated.contains("ancestors"), "{updated}");
+
+    let labels: Vec<_> = requests
+        .iter()
+        .filter(|request| request.path.ends_with("/label"))
+        .collect();
+    assert_eq!(labels.len(), 2, "{requests:?}");
+    assert!(
+        labels[0]
+            .body
+            .contains(r#"[{"name":"govctl-generated","prefix":"global"}]"#)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_publish_dry_run_makes_no_requests() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(temp_dir.path(), &[&["rfc", "new", "Caching"]])?;
+    let (port, requests) = mock_confluence()?;
+    add_confluence(temp_dir.path(), port)?;
+
+    let output = publish(
+        temp_dir.path(),
+        &["--dry-run", "publish", "confluence", "RFC-0001"],
+        None,
+    )?;
+    assert!(
+        output.contains("Would publish: RFC-0001 (RFC-0001: Caching)"),
+        "{output}"
+    );
+    assert!(output.contains("exit: 0"), "{output}");
+    assert!(requests.lock().map_err(|err| err.to_string())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_publish_requires_config_and_token() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(temp_dir.path(), &[&["rfc", "new", "Caching"]])?;
+
+    let output = publish(temp_dir.path(), &["publish", "confluence"], Some("secret"))?;
+    assert!(output.contains("error[E0513]"), "{output}");
+
+    add_confluence(temp_dir.path(), 9)?;
+    let output = publish(temp_dir.path(), &["publish", "confluence"], None)?;
+    assert!(
+        output.contains("error[E0513]: CONFLUENCE_TOKEN is not set"),
+        "{output}"
+    );
+
+    let output = publish(
+        temp_dir.path(),
+        &["--dry-run", "publish", "confluence", "WI-2026-01-01-001"],
+        None,
+    )?;
+    assert!(output.contains("error[E0822]"), "{output}");
+    Ok(())
+}
@@ -0,0 +1,109 @@
+//! Tests for cross-repository refs (`name:ID`) and `govctl export`.
+
+mod common;
+
+use common::run_commands;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// A `platform` project with RFC-0001 next to an empty `service` project.
+fn init_repos() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    for name in ["platform", "service"] {
+        fs::create_dir_all(temp_dir.path().join(name))?;
+        run_commands(&temp_dir.path().join(name), &[&["init"]])?;
+    }
+    run_commands(
+        &temp_dir.path().join("platform"),
+        &[&["rfc", "new", "Platform API"]],
+    )?;
+    run_commands(
+        &temp_dir.path().join("service"),
+        &[&["rfc", "new", "Service API"]],
+    )?;
+    Ok(temp_dir)
+}
+
+fn add_remote(service: &Path, name: &str, key: &str, value: &str) -> std::io::Result<()> {
+    let config_path = service.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(&format!("\n[remotes.{name}]\n{key} = \"{value}\"\n"));
+    fs::write(config_path, config)
+}
+
+#[test]
+fn test_remote_refs_resolve_against_export_bundle() -> common::TestResult {
+    let temp_dir = init_repos()?;
+    let service = temp_dir.path().join("service");
+
+    let output = run_commands(
+        &temp_dir.path().join("platform"),
+        &[
+            &["adr", "new", "Use gRPC"],
+            &["export", "--out", "../platform-refs.json"],
+        ],
+    )?;
+    assert!(output.contains("exit: 0"), "output: {output}");
+    let bundle = fs::read_to_string(temp_dir.path().join("platform-refs.json"))?;
+    assert!(bundle.contains("\"id\": \"RFC-0001\""), "{bundle}");
+    assert!(bundle.contains("\"title\": \"Platform API\""), "{bundle}");
+
+    add_remote(&service, "platform", "bundle", "../platform-refs.json")?;
+    let output = run_commands(
+        &service,
+        &[
+            &["rfc", "add", "RFC-0001", "refs", "platform:RFC-0001"],
+            &["rfc", "add", "RFC-0001", "refs", "platform:RFC-0009"],
+            &["rfc", "add", "RFC-0001", "refs", "billing:RFC-0001"],
+            &["rfc", "add", "RFC-0001", "refs", "platform:ADR-0001"],
+            &["check"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("RFC 'RFC-0001' references unknown artifact: platform:RFC-0009"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "error[E0512]: 'RFC-0001' references billing:RFC-0001, but no [remotes.billing] is configured (remotes: platform)"
+        ),
+        "output: {output}"
+    );
+    assert!(output.contains("error[E0112]"), "output: {output}");
+    let rfc = fs::read_to_string(service.join("gov/rfc/RFC-0001/rfc.toml"))?;
+    assert!(rfc.contains("\"platform:RFC-0001\""), "{rfc}");
+    let check = output.split("$ govctl check").nth(1).unwrap_or_default();
+    assert!(check.contains("exit: 0"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_remote_refs_resolve_against_sibling_checkout() -> common::TestResult {
+    let temp_dir = init_repos()?;
+    let service = temp_dir.path().join("service");
+    add_remote(&service, "platform", "path", "../platform")?;
+
+    let output = run_commands(
+        &service,
+        &[
+            &["adr", "new", "Call the platform API"],
+            &["adr", "add", "ADR-0001", "refs", "platform:RFC-0001"],
+            &["check"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "output: {output}");
+
+    fs::rename(
+        temp_dir.path().join("platform"),
+        temp_dir.path().join("moved"),
+    )?;
+    let output = run_commands(&service, &[&["check"]])?;
+    assert!(
+        output.contains("warning[W0117]: References to remote 'platform' were not verified"),
+        "output: {output}"
+    );
+    assert!(!output.contains("error["), "output: {output}");
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! Tests for skipping unchanged artifacts in `render`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+use std::path::Path;
+
+fn project_with_docs(dir: &Path) -> Result<String, std::io::Error> {
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "new", "Refunds"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-CAPTURE",
+                "Capture",
+                "-s",
+                "Specification",
+            ],
+            &["adr", "new", "Use a ledger"],
+            &["render", "all"],
+        ],
+    )
+}
+
+#[test]
+fn test_render_skips_artifacts_whose_sources_are_unchanged() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    let output = project_with_docs(dir)?;
+    assert!(output.contains("✓ Rendered 2 RFC(s)\n"), "{output}");
+    assert!(dir.join(".govctl/render-cache.json").exists());
+
+    let output = run_commands(dir, &[&["render"]])?;
+    assert!(
+        output.contains("✓ Rendered 0 RFC(s), 2 unchanged"),
+        "{output}"
+    );
+
+    let output = run_commands(
+        dir,
+        &[
+            &["rfc", "set", "RFC-0002", "title", "Chargebacks"],
+            &["render"],
+        ],
+    )?;
+    assert!(
+        output.contains("Rendered: docs/rfc/RFC-0002.md"),
+        "{output}"
+    );
+    assert!(
+        !output.contains("Rendered: docs/rfc/RFC-0001.md"),
+        "{output}"
+    );
+    assert!(
+        output.contains("✓ Rendered 1 RFC(s), 1 unchanged"),
+        "{output}"
+    );
+    assert!(fs::read_to_string(dir.join("docs/rfc/RFC-0002.md"))?.contains("Chargebacks"));
+
+    let output = run_commands(dir, &[&["render", "--force"]])?;
+    assert!(output.contains("✓ Rendered 2 RFC(s)\n"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_render_redoes_edited_or_deleted_output() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    project_with_docs(dir)?;
+    let rendered = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+
+    fs::write(dir.join("docs/rfc/RFC-0001.md"), "hand edited\n")?;
+    fs::remove_file(dir.join("docs/adr/ADR-0001.md"))?;
+    let output = run_commands(dir, &[&["render", "all"]])?;
+    assert!(
+        output.contains("✓ Rendered 1 RFC(s), 1 unchanged"),
+        "{output}"
+    );
+    assert!(output.contains("✓ Rendered 1 ADR(s)\n"), "{output}");
+    assert_eq!(
+        fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?,
+        rendered
+    );
+    assert!(dir.join("docs/adr/ADR-0001.md").exists());
+    Ok(())
+}
+
+#[test]
+fn test_render_follows_inputs_outside_the_artifact() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    project_with_docs(dir)?;
+
+    // An ADR citing a clause appears in that RFC's rendered clause.
+    let output = run_commands(
+        dir,
+        &[
+            &["adr", "add", "ADR-0001", "refs", "RFC-0001:C-CAPTURE"],
+            &["render"],
+        ],
+    )?;
+    assert!(
+        output.contains("✓ Rendered 1 RFC(s), 1 unchanged"),
+        "{output}"
+    );
+    assert!(fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?.contains("ADR-0001"));
+
+    // A template applies to every artifact of its kind.
+    let templates = dir.join("gov/templates/render");
+    fs::create_dir_all(&templates)?;
+    fs::write(templates.join("rfc.md.tera"), "# {{ rfc.govctl.title }}\n")?;
+    let output = run_commands(dir, &[&["render"]])?;
+    assert!(output.contains("✓ Rendered 2 RFC(s)\n"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_single_artifact_render_ignores_the_cache() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    project_with_docs(dir)?;
+
+    let output = run_commands(dir, &[&["rfc", "render", "RFC-0001"]])?;
+    assert!(
+        output.contains("Rendered: docs/rfc/RFC-0001.md"),
+        "{output}"
+    );
+    assert!(output.contains("✓ Rendered 1 RFC(s)\n"), "{output}");
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//! Tests for `render --check` and byte-identical re-rendering.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn project_with_docs(dir: &Path) -> Result<String, std::io::Error> {
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-CAPTURE",
+                "Capture",
+                "-s",
+                "Specification",
+            ],
+            &["adr", "new", "Use a ledger"],
+            &["work", "new", "Build the ledger"],
+            &["render", "all"],
+        ],
+    )
+}
+
+#[test]
+fn test_render_check_passes_until_a_source_changes() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    project_with_docs(dir)?;
+
+    let output = run_commands(dir, &[&["render", "all", "--check"]])?;
+    assert!(output.contains("Rendered files are up to date"), "{output}");
+    assert!(output.contains("exit: 0"), "{output}");
+
+    let rendered = dir.join("docs/rfc/RFC-0001.md");
+    let before = fs::read(&rendered)?;
+    let output = run_commands(
+        dir,
+        &[
+            &["rfc", "set", "RFC-0001", "title", "Card payments"],
+            &["render", "all", "--check"],
+        ],
+    )?;
+    assert!(output.contains("error[E0833]"), "{output}");
+    assert!(output.contains("docs/rfc/RFC-0001.md"), "{output}");
+    assert!(output.contains("docs/rfc/index.md"), "{output}");
+    assert!(!output.contains("docs/adr/ADR-0001.md"), "{output}");
+    assert!(output.ends_with("exit: 1\n\n"), "{output}");
+    assert_eq!(fs::read(&rendered)?, before, "--check must not write");
+
+    let output = run_commands(dir, &[&["render", "all"], &["render", "all", "--check"]])?;
+    assert!(output.ends_with("exit: 0\n\n"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_render_check_flags_edited_and_missing_files() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    project_with_docs(dir)?;
+
+    fs::write(dir.join("docs/adr/ADR-0001.md"), "hand edited\n")?;
+    fs::remove_file(dir.join("docs/rfc/RFC-0001.md"))?;
+    let output = run_commands(dir, &[&["render", "all", "--check"]])?;
+    assert!(
+        output.contains("2 rendered file(s) out of date"),
+        "{output}"
+    );
+    assert!(output.contains("docs/adr/ADR-0001.md"), "{output}");
+    assert!(!dir.join("docs/rfc/RFC-0001.md").exists());
+
+    let output = run_commands(dir, &[&["render", "rfc", "--check"]])?;
+    assert!(
+        output.contains("1 rendered file(s) out of date"),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_render_check_rejects_dry_run() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(temp_dir.path(), &[&["render", "--check", "--dry-run"]])?;
+    assert!(output.contains("cannot be used with"), "{output}");
+    assert!(output.ends_with("exit: 2\n\n"), "{output}");
+    Ok(())
+}
+
+#[test]
+fn test_render_is_byte_identical_across_locale_and_time_zone() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    project_with_docs(dir)?;
+    let files = [
+        "docs/rfc/RFC-0001.md",
+        "docs/rfc/index.md",
+        "docs/adr/ADR-0001.md",
+        "docs/adr/index.md",
+        "docs/work/index.md",
+    ];
+    let before: Vec<Vec<u8>> = files
+        .iter()
+        .map(|file| fs::read(dir.join(file)))
+        .collect::<Result<_, _>>()?;
+
+    let render = Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(["render", "all"])
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env("LC_ALL", "tr_TR.UTF-8")
+        .env("LANG", "tr_TR.UTF-8")
+        .env("TZ", "Pacific/Kiritimati")
+        .output()?;
+    assert!(
+        render.status.success(),
+        "{}",
+        String::from_utf8_lossy(&render.stderr)
+    );
+    for (file, before) in files.iter().zip(&before) {
+        assert_eq!(&fs::read(dir.join(file))?, before, "{file} changed");
+    }
+    Ok(())
+}
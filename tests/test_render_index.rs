@@ -0,0 +1,54 @@
+//! Tests for the `index.md` pages written by `render all`.
+
+mod common;
+
+use common::{init_project_with_date, run_commands};
+use std::fs;
+
+#[test]
+fn test_render_all_writes_index_per_output_dir() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let dir = temp_dir.path();
+    let output = run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments | Billing"],
+            &["adr", "new", "Use a ledger"],
+            &["render", "all", "--dry-run"],
+        ],
+    )?;
+    assert!(output.contains("docs/rfc/index.md"), "output: {output}");
+    assert!(!dir.join("docs/rfc/index.md").exists());
+
+    run_commands(dir, &[&["render", "all"]])?;
+    let rfcs = fs::read_to_string(dir.join("docs/rfc/index.md"))?;
+    assert!(
+        rfcs.contains(&format!(
+            "# RFCs\n\n| ID | Title | Status | Phase | Version | Updated |\n| --- | --- | --- | --- | --- | --- |\n| [RFC-0001](RFC-0001.md) | Payments \\| Billing | draft | spec | 0.1.0 | {date} |\n"
+        )),
+        "rfcs: {rfcs}"
+    );
+    let adrs = fs::read_to_string(dir.join("docs/adr/index.md"))?;
+    assert!(
+        adrs.contains(&format!(
+            "| [ADR-0001](ADR-0001.md) | Use a ledger | proposed | {date} |"
+        )),
+        "adrs: {adrs}"
+    );
+    assert!(!dir.join("docs/work/index.md").exists());
+    Ok(())
+}
+
+#[test]
+fn test_render_all_leaves_unchanged_index_alone() -> common::TestResult {
+    let (temp_dir, _date) = init_project_with_date()?;
+    let dir = temp_dir.path();
+    run_commands(dir, &[&["rfc", "new", "Payments"], &["render", "all"]])?;
+    let index = dir.join("docs/rfc/index.md");
+    let before = fs::metadata(&index)?.modified()?;
+
+    let output = run_commands(dir, &[&["render", "all"]])?;
+    assert!(!output.contains("docs/rfc/index.md"), "output: {output}");
+    assert_eq!(fs::metadata(&index)?.modified()?, before);
+    Ok(())
+}
@@ -0,0 +1,246 @@
+//! Tests for render options: `[render]` config and `render` flags.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_render_layout_flags_add_toc_numbers_and_badges() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Summary",
+            ],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-CAPTURE",
+                "Capture",
+                "-s",
+                "Specification",
+            ],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-REFUND",
+                "Refund",
+                "-s",
+                "Specification",
+            ],
+            &["render"],
+        ],
+    )?;
+    let plain = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+    assert!(!plain.contains("## Contents"), "plain: {plain}");
+    assert!(
+        plain.contains("### [RFC-0001:C-REFUND] Refund (Normative) <a id="),
+        "plain: {plain}"
+    );
+
+    run_commands(
+        dir,
+        &[&[
+            "rfc",
+            "render",
+            "RFC-0001",
+            "--toc",
+            "--clause-numbers",
+            "--status-badges",
+        ]],
+    )?;
+    let rendered = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        rendered.contains(
+            "## Contents\n\n- [1. Summary](#section-1)\n  - [1.1 C-SCOPE: Scope](#rfc-0001c-scope)\n- [2. Specification](#section-2)\n  - [2.1 C-CAPTURE: Capture](#rfc-0001c-capture)\n  - [2.2 C-REFUND: Refund](#rfc-0001c-refund)\n"
+        ),
+        "rendered: {rendered}"
+    );
+    assert!(
+        rendered.contains("## 2. Specification <a id=\"section-2\"></a>"),
+        "rendered: {rendered}"
+    );
+    assert!(
+        rendered.contains("### 2.2 [RFC-0001:C-REFUND] Refund (Normative) `active` <a id="),
+        "rendered: {rendered}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_render_config_sets_layout_for_every_render() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[render]\nclause_numbers = true\n");
+    fs::write(&config_path, config)?;
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Summary",
+            ],
+            &["render", "all"],
+        ],
+    )?;
+
+    let rendered = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        rendered.contains("### 1.1 [RFC-0001:C-SCOPE] Scope (Normative) <a id="),
+        "rendered: {rendered}"
+    );
+    assert!(!rendered.contains("## Contents"), "rendered: {rendered}");
+    Ok(())
+}
+
+#[test]
+fn test_render_front_matter_leads_each_file_and_keeps_signatures_valid() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments \"v2\""],
+            &["adr", "new", "Use a ledger"],
+            &["render", "all", "--front-matter"],
+        ],
+    )?;
+    let rfc = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        rfc.starts_with(
+            "---\ntitle: \"RFC-0001: Payments \\\"v2\\\"\"\nslug: rfc-0001\nsidebar_position: 1\n---\n<!-- GENERATED: do not edit. Source: RFC-0001 -->\n"
+        ),
+        "rfc: {rfc}"
+    );
+    let adr = fs::read_to_string(dir.join("docs/adr/ADR-0001.md"))?;
+    assert!(
+        adr.starts_with("---\ntitle: \"ADR-0001: Use a ledger\"\n"),
+        "adr: {adr}"
+    );
+
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[render.front_matter]\nenabled = true\nfields = [\"sidebar_position\"]\n");
+    fs::write(&config_path, config)?;
+    let output = run_commands(dir, &[&["adr", "render", "ADR-0001"], &["check"]])?;
+    let adr = fs::read_to_string(dir.join("docs/adr/ADR-0001.md"))?;
+    assert!(
+        adr.starts_with("---\nsidebar_position: 1\n---\n"),
+        "adr: {adr}"
+    );
+    assert!(!output.contains("E060"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_render_lists_decisions_affecting_each_clause() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-CAPTURE",
+                "Capture",
+                "-s",
+                "Specification",
+            ],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-REFUND",
+                "Refund",
+                "-s",
+                "Specification",
+            ],
+            &["adr", "new", "Capture on shipment"],
+            &["adr", "add", "ADR-0001", "refs", "RFC-0001:C-CAPTURE"],
+            &["adr", "new", "Refund to card"],
+            &["adr", "add", "ADR-0002", "refs", "RFC-0001:C-CAPTURE"],
+            &["adr", "reject", "ADR-0002"],
+            &["render"],
+        ],
+    )?;
+    let rendered = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+    let (capture, refund) = rendered
+        .split_once("### [RFC-0001:C-REFUND]")
+        .ok_or("refund clause")?;
+    assert!(
+        capture.contains(
+            "<details>\n<summary>Decisions affecting this clause</summary>\n\n\
+             - [ADR-0001](../adr/ADR-0001.md): Capture on shipment (proposed)\n\n</details>"
+        ),
+        "rendered: {rendered}"
+    );
+    assert!(!rendered.contains("Refund to card"), "rendered: {rendered}");
+    assert!(!refund.contains("<details>"), "rendered: {rendered}");
+
+    fs::remove_file(dir.join("gov/rfc/RFC-0001/clauses/C-CAPTURE.toml"))?;
+    let rfc_toml = dir.join("gov/rfc/RFC-0001/rfc.toml");
+    let rfc = fs::read_to_string(&rfc_toml)?;
+    fs::write(&rfc_toml, rfc.replace("\"clauses/C-CAPTURE.toml\",", ""))?;
+    let output = run_commands(dir, &[&["check"]])?;
+    assert!(
+        output.contains(
+            "ADR 'ADR-0001' references clause RFC-0001:C-CAPTURE, which RFC-0001 no longer has"
+        ),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_render_profile_omits_sections_into_its_own_docs_set() -> common::TestResult {
+    let (temp_dir, date) = common::init_project_with_date()?;
+    let dir = temp_dir.path();
+    let config_path = dir.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(
+        "\n[render.profiles.public]\ndocs_output = \"public\"\nomit = [\"owners\", \"notes\"]\n",
+    );
+    fs::write(&config_path, config)?;
+    let work_id = common::first_work_id(&date);
+    let output = run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &["work", "new", "Wire payments"],
+            &["work", "add", &work_id, "notes", "Ask finance about fees"],
+            &["render", "all"],
+            &["render", "all", "--profile", "public"],
+            &["render", "--profile", "internal"],
+        ],
+    )?;
+    assert!(output.contains("error[E0832]"), "output: {output}");
+
+    let internal = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+    assert!(internal.contains("**Owners:**"), "internal: {internal}");
+    let public = fs::read_to_string(dir.join("public/rfc/RFC-0001.md"))?;
+    assert!(!public.contains("**Owners:**"), "public: {public}");
+
+    let work_file = format!("work/{work_id}.md");
+    let internal = fs::read_to_string(dir.join("docs").join(&work_file))?;
+    assert!(internal.contains("Ask finance"), "internal: {internal}");
+    let public = fs::read_to_string(dir.join("public").join(&work_file))?;
+    assert!(!public.contains("Ask finance"), "public: {public}");
+    Ok(())
+}
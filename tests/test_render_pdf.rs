@@ -0,0 +1,71 @@
+//! Tests for `render rfc --format pdf`.
+
+mod common;
+
+use common::{init_project, run_commands};
+
+#[cfg(feature = "pdf")]
+#[test]
+fn test_render_rfc_pdf_writes_print_ready_file() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    let output = run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments [draft] #1 \"quoted\""],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &[
+                "clause",
+                "edit",
+                "RFC-0001:C-SCOPE",
+                "text",
+                "--set",
+                "Covers *card* and `ledger` flows per [[RFC-0001]]:\n\n1. Capture\n2. Refund\n\n```toml\nkey = \"value\"\n```",
+            ],
+            &["render", "rfc", "--format", "pdf", "--dry-run"],
+        ],
+    )?;
+    assert!(output.contains("RFC-0001.pdf"), "output: {output}");
+    assert!(!dir.join("docs/rfc/RFC-0001.pdf").exists());
+
+    let output = run_commands(dir, &[&["render", "rfc", "--format", "pdf"]])?;
+    assert!(output.contains("exit: 0"), "output: {output}");
+    let pdf = std::fs::read(dir.join("docs/rfc/RFC-0001.pdf"))?;
+    assert!(pdf.starts_with(b"%PDF"));
+    assert!(!dir.join("docs/rfc/RFC-0001.md").exists());
+    Ok(())
+}
+
+#[test]
+fn test_render_pdf_rejects_other_targets() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(temp_dir.path(), &[&["render", "adr", "--format", "pdf"]])?;
+    assert!(output.contains("E0822"), "output: {output}");
+    assert!(output.contains("exit: 1"), "output: {output}");
+    Ok(())
+}
+
+#[cfg(not(feature = "pdf"))]
+#[test]
+fn test_render_rfc_pdf_needs_pdf_feature() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    let output = run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &["render", "rfc", "--format", "pdf"],
+        ],
+    )?;
+    assert!(output.contains("E0822"), "output: {output}");
+    assert!(output.contains("`pdf` feature"), "output: {output}");
+    assert!(!dir.join("docs/rfc/RFC-0001.pdf").exists());
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Tests for user render templates in `gov/templates/render/`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_render_templates_replace_builtin_layout_per_kind_and_rfc() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    let templates = dir.join("gov/templates/render");
+    fs::create_dir_all(templates.join("rfc"))?;
+    fs::write(
+        templates.join("rfc.md.tera"),
+        "# {{ rfc.govctl.title }} ({{ id }})\n\n{% for clause in clauses %}- {{ clause.govctl.title }}\n{% endfor %}",
+    )?;
+    fs::write(
+        templates.join("rfc/RFC-0002.md.tera"),
+        "Company header\n\n{{ builtin }}",
+    )?;
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "new", "Billing"],
+            &[
+                "clause",
+                "new",
+                "RFC-0001:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &["adr", "new", "Use a ledger"],
+            &["render", "all"],
+        ],
+    )?;
+
+    let payments = fs::read_to_string(dir.join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        payments.starts_with(
+            "<!-- GENERATED: do not edit. Source: RFC-0001 -->\n<!-- SIGNATURE: sha256:"
+        ),
+        "payments: {payments}"
+    );
+    assert!(
+        payments.contains("\n\n# Payments (RFC-0001)\n\n- Scope\n"),
+        "payments: {payments}"
+    );
+    let billing = fs::read_to_string(dir.join("docs/rfc/RFC-0002.md"))?;
+    assert!(
+        billing.contains("-->\n\nCompany header\n\n# RFC-0002: Billing\n"),
+        "billing: {billing}"
+    );
+    let adr = fs::read_to_string(dir.join("docs/adr/ADR-0001.md"))?;
+    assert!(adr.contains("# ADR-0001: Use a ledger"), "adr: {adr}");
+
+    fs::write(templates.join("adr.md.tera"), "{{ adr.missing.field }}")?;
+    let output = run_commands(dir, &[&["check"], &["adr", "render", "ADR-0001"]])?;
+    assert!(
+        !output.contains("[E060"),
+        "templated files keep a valid signature: {output}"
+    );
+    assert!(
+        output.contains("error[E0506]: Render template failed:"),
+        "output: {output}"
+    );
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Tests for RFC version snapshots (`[snapshots] rfc_versions`).
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+const RELEASE_TWO_VERSIONS: &[&[&str]] = &[
+    &["rfc", "new", "Payments"],
+    &[
+        "clause",
+        "new",
+        "RFC-0001:C-SCOPE",
+        "Scope",
+        "-s",
+        "Specification",
+    ],
+    &[
+        "clause",
+        "set",
+        "RFC-0001:C-SCOPE",
+        "text",
+        "Cards are accepted.",
+    ],
+    &["rfc", "finalize", "RFC-0001", "normative"],
+    &["rfc", "advance", "RFC-0001", "impl"],
+    &[
+        "clause",
+        "set",
+        "RFC-0001:C-SCOPE",
+        "text",
+        "Cards and wallets are accepted.",
+    ],
+    &[
+        "rfc",
+        "bump",
+        "RFC-0001",
+        "--minor",
+        "--summary",
+        "Accept wallets",
+    ],
+    &["rfc", "advance", "RFC-0001", "impl"],
+];
+
+#[test]
+fn test_sealed_versions_are_snapshotted_for_pinned_show() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[snapshots]\nrfc_versions = true\n");
+    fs::write(&config_path, config)?;
+
+    let output = run_commands(temp_dir.path(), RELEASE_TWO_VERSIONS)?;
+    assert!(
+        output.contains("Saved v0.1.0 snapshot to docs/rfc/RFC-0001/v0.1.0.md"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Saved v0.2.0 snapshot to docs/rfc/RFC-0001/v0.2.0.md"),
+        "output: {output}"
+    );
+    let snapshot_dir = temp_dir.path().join("docs/rfc/RFC-0001");
+    assert!(snapshot_dir.join("v0.1.0.json").exists());
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "show", "RFC-0001@0.1.0", "-o", "plain"],
+            &["rfc", "show", "RFC-0001@v0.1.0", "-o", "json"],
+            &["rfc", "show", "RFC-0001@0.2.0", "-o", "plain"],
+            &["rfc", "show", "RFC-0001@0.9.0"],
+        ],
+    )?;
+    let pinned = output
+        .split("$ govctl rfc show RFC-0001@v0.1.0")
+        .next()
+        .unwrap_or_default();
+    assert!(pinned.contains("Cards are accepted."), "output: {output}");
+    assert!(!pinned.contains("wallets"), "output: {output}");
+    assert!(
+        output.contains("\"text\": \"Cards are accepted.\""),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Cards and wallets are accepted."),
+        "the later version keeps its own text: {output}"
+    );
+    assert!(
+        output.contains("error[E0124]: No snapshot of RFC-0001 at version 0.9.0"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sealing_without_snapshots_config_writes_none() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(temp_dir.path(), RELEASE_TWO_VERSIONS)?;
+    assert!(!output.contains("snapshot"), "output: {output}");
+    assert!(!temp_dir.path().join("docs/rfc/RFC-0001").exists());
+    Ok(())
+}
@@ -247,3 +247,129 @@ fn test_scan_mixed_valid_invalid_references() -> common::TestResult {
 
     assert_scan_check_snapshot!(temp_dir, &date)
 }
+
+#[test]
+fn test_scan_comments_only_skips_string_literals() -> common::TestResult {
+    let (temp_dir, _) = init_source_scan_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let mut doc: toml::Table = toml::from_str(&fs::read_to_string(&config_path)?)?;
+    let scan = doc
+        .get_mut("source_scan")
+        .and_then(toml::Value::as_table_mut)
+        .ok_or("missing [source_scan]")?;
+    scan.insert("comments_only".into(), toml::Value::Boolean(true));
+    scan.insert(
+        "include".into(),
+        toml::Value::Array(vec!["src/**".into(), "docs/**".into()]),
+    );
+    fs::write(&config_path, toml::to_string_pretty(&doc)?)?;
+
+    create_normative_rfc(temp_dir.path(), "RFC-0001", "Test RFC")?;
+    write_main_rs(
+        temp_dir.path(),
+        "// Implements [[RFC-0001]]\nconst FIXTURE: &str = \"// [[RFC-9001]]\";\n/* [[RFC-9002]] */\n",
+    )?;
+    fs::write(
+        temp_dir.path().join("src/tool.py"),
+        "\"\"\"Fixture: [[RFC-9003]]\"\"\"\n# Per [[RFC-9004]]\n",
+    )?;
+    let docs_dir = temp_dir.path().join("docs");
+    fs::create_dir_all(&docs_dir)?;
+    fs::write(docs_dir.join("guide.md"), "See [[RFC-9005]].\n")?;
+
+    let output = run_commands(temp_dir.path(), &[&["check"]])?;
+
+    assert!(
+        output.contains("  3 source files scanned"),
+        "output: {}",
+        output
+    );
+    for id in ["RFC-9002", "RFC-9004", "RFC-9005"] {
+        assert!(output.contains(id), "missing {id} in output: {output}");
+    }
+    for id in ["RFC-9001", "RFC-9003"] {
+        assert!(!output.contains(id), "unexpected {id} in output: {output}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_scan_coverage_counts_implements_annotations() -> common::TestResult {
+    let (temp_dir, _) = init_source_scan_project()?;
+
+    let clause = |id: &'static str| -> [&'static str; 8] {
+        [
+            "clause",
+            "new",
+            id,
+            "Test Clause",
+            "-s",
+            "Specification",
+            "-k",
+            "normative",
+        ]
+    };
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Test RFC"],
+            &clause("RFC-0001:C-DONE"),
+            &clause("RFC-0001:C-TODO"),
+        ],
+    )?;
+    write_main_rs(
+        temp_dir.path(),
+        "// Implements [[RFC-0001:C-DONE]]\nfn main() {}\n\n// See [[RFC-0001:C-TODO]]\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["scan", "coverage", "-o", "json", "-W"]],
+    )?;
+
+    assert!(
+        output.contains("\"clause\": \"RFC-0001:C-DONE\",\n    \"title\": \"Test Clause\",\n    \"count\": 1,\n    \"locations\": [\n      \"src/main.rs:1\"\n    ]"),
+        "output: {}",
+        output
+    );
+    assert!(output.contains("\"count\": 0"), "output: {}", output);
+    assert!(
+        output.contains(
+            "warning[W0122]: Normative clause RFC-0001:C-TODO has no `Implements` annotation"
+        ),
+        "output: {}",
+        output
+    );
+    assert!(output.contains("exit: 3"), "output: {}", output);
+    Ok(())
+}
+
+#[test]
+fn test_scan_skips_gitignored_and_govctlignored_paths() -> common::TestResult {
+    let (temp_dir, _) = init_source_scan_project()?;
+
+    create_normative_rfc(temp_dir.path(), "RFC-0001", "Test RFC")?;
+    write_main_rs(
+        temp_dir.path(),
+        "// Implements [[RFC-0001]]\nfn main() {}\n",
+    )?;
+    for dir in ["src/vendor", "src/generated"] {
+        fs::create_dir_all(temp_dir.path().join(dir))?;
+        fs::write(
+            temp_dir.path().join(dir).join("lib.rs"),
+            "// Implements [[RFC-9999]]\n",
+        )?;
+    }
+    fs::write(temp_dir.path().join(".gitignore"), "/src/vendor/\n")?;
+    fs::write(temp_dir.path().join(".govctlignore"), "generated/\n")?;
+
+    let output = run_commands(temp_dir.path(), &[&["check"]])?;
+
+    assert!(
+        output.contains("  1 source files scanned"),
+        "output: {}",
+        output
+    );
+    assert!(!output.contains("RFC-9999"), "output: {}", output);
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! Sensitive ADR fields: encrypted at rest, redacted when rendered.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+const KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+#[test]
+fn test_sensitive_field_is_encrypted_and_redacted() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{config}\n[secrets]\nkey_file = \"secret.key\"\n"),
+    )?;
+    fs::write(temp_dir.path().join("secret.key"), format!("{KEY}\n"))?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "new", "Vendor choice"],
+            &["adr", "set", "ADR-0001", "context", "Vendor X quoted 40k"],
+            &["adr", "add", "ADR-0001", "sensitive", "context"],
+            &["adr", "get", "ADR-0001", "context"],
+            &["adr", "set", "ADR-0001", "consequences", "Renew yearly"],
+            &["adr", "render", "ADR-0001"],
+            &["check"],
+        ],
+    )?;
+    assert!(output.contains("Vendor X quoted 40k"), "{output}");
+    assert!(!output.contains("error["), "{output}");
+
+    let adr_path = temp_dir.path().join("gov/adr/ADR-0001-vendor-choice.toml");
+    let adr = fs::read_to_string(&adr_path)?;
+    assert!(!adr.contains("Vendor X quoted"), "{adr}");
+    assert!(adr.contains("context = \"ENC[chacha20-poly1305,"), "{adr}");
+
+    let rendered = fs::read_to_string(temp_dir.path().join("docs/adr/ADR-0001.md"))?;
+    assert!(rendered.contains("*Redacted.*"), "{rendered}");
+    assert!(!rendered.contains("Vendor X"), "{rendered}");
+    assert!(rendered.contains("Renew yearly"), "{rendered}");
+
+    // Without the key, the ciphertext is kept and new plaintext is refused.
+    fs::write(&config_path, config)?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "add", "ADR-0001", "tags", "vendor"],
+            &["adr", "set", "ADR-0001", "context", "Leaked"],
+        ],
+    )?;
+    assert!(output.contains("error[E1601]"), "{output}");
+    let after = fs::read_to_string(&adr_path)?;
+    assert!(!after.contains("Leaked"), "{after}");
+    assert!(
+        after.contains("context = \"ENC[chacha20-poly1305,"),
+        "{after}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sensitive_value_is_redacted_from_audit_and_history() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{config}\n[secrets]\nkey_file = \"secret.key\"\n"),
+    )?;
+    fs::write(temp_dir.path().join("secret.key"), format!("{KEY}\n"))?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["config", "set", "audit.enabled", "true"],
+            &["adr", "new", "Vendor choice"],
+            &["adr", "add", "ADR-0001", "sensitive", "context"],
+            &["adr", "set", "ADR-0001", "context", "Vendor X quoted 40k"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "{output}");
+
+    let audit = fs::read_to_string(temp_dir.path().join("gov/audit.log"))?;
+    assert!(!audit.contains("Vendor X"), "{audit}");
+    assert!(
+        audit.contains("govctl adr set ADR-0001 context <redacted>"),
+        "{audit}"
+    );
+
+    let history = temp_dir.path().join(".govctl/history");
+    let mut records = 0;
+    for entry in walkdir::WalkDir::new(&history) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let content = fs::read(entry.path())?;
+        let text = String::from_utf8_lossy(&content);
+        assert!(
+            !text.contains("Vendor X"),
+            "{}: {text}",
+            entry.path().display()
+        );
+        records += usize::from(text.contains("context <redacted>"));
+    }
+    assert_eq!(records, 1, "the adr set entry is recorded, redacted");
+    Ok(())
+}
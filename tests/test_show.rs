@@ -8,21 +8,25 @@ fn assert_success_with(output: &str, marker: &str) {
     assert!(output.contains("exit: 0"), "output: {output}");
 }
 
-fn successful_payload(output: &str) -> &str {
-    output
+fn successful_payload(output: &str) -> Result<&str, Box<dyn std::error::Error>> {
+    Ok(output
         .split_once('\n')
         .and_then(|(_, body)| body.strip_suffix("exit: 0\n\n"))
-        .expect("single successful command output")
-        .trim_end()
+        .ok_or("single successful command output")?
+        .trim_end())
 }
 
-fn get_value(resource: &str, output: &str) -> serde_json::Value {
-    let payload = successful_payload(output);
+fn get_value(
+    resource: &str,
+    output: &str,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let payload = successful_payload(output)?;
     if matches!(resource, "rfc" | "clause") {
-        serde_json::from_str(payload).expect("complete get JSON")
+        Ok(serde_json::from_str(payload)?)
     } else {
-        serde_json::to_value(toml::from_str::<toml::Value>(payload).expect("complete get TOML"))
-            .expect("TOML converts to JSON value")
+        Ok(serde_json::to_value(toml::from_str::<toml::Value>(
+            payload,
+        )?)?)
     }
 }
 
@@ -50,7 +54,7 @@ fn test_show_structured_formats_are_complete_for_every_resource() -> common::Tes
 
     for (resource, id, identity_key, content_key, toml_content_marker) in resources {
         let get = run_commands(temp_dir.path(), &[&[resource, "get", id]])?;
-        let expected = get_value(resource, &get);
+        let expected = get_value(resource, &get)?;
 
         let json = run_commands(
             temp_dir.path(),
@@ -58,8 +62,7 @@ fn test_show_structured_formats_are_complete_for_every_resource() -> common::Tes
         )?;
         assert_success_with(&json, &format!("\"{identity_key}\""));
         assert!(json.contains(&format!("\"{content_key}\"")), "{json}");
-        let json_value: serde_json::Value =
-            serde_json::from_str(successful_payload(&json)).expect("show JSON");
+        let json_value: serde_json::Value = serde_json::from_str(successful_payload(&json)?)?;
         assert_eq!(json_value, expected, "resource: {resource}");
 
         let yaml = run_commands(
@@ -68,8 +71,7 @@ fn test_show_structured_formats_are_complete_for_every_resource() -> common::Tes
         )?;
         assert_success_with(&yaml, &format!("{identity_key}:"));
         assert!(yaml.contains(&format!("{content_key}:")), "{yaml}");
-        let yaml_value: serde_json::Value =
-            serde_yaml::from_str(successful_payload(&yaml)).expect("show YAML");
+        let yaml_value: serde_json::Value = serde_yaml::from_str(successful_payload(&yaml)?)?;
         assert_eq!(yaml_value, expected, "resource: {resource}");
 
         let toml = run_commands(
@@ -83,10 +85,8 @@ fn test_show_structured_formats_are_complete_for_every_resource() -> common::Tes
         };
         assert_success_with(&toml, &toml_identity_marker);
         assert!(toml.contains(toml_content_marker), "{toml}");
-        let toml_value = serde_json::to_value(
-            toml::from_str::<toml::Value>(successful_payload(&toml)).expect("show TOML"),
-        )
-        .expect("TOML converts to JSON value");
+        let toml_value =
+            serde_json::to_value(toml::from_str::<toml::Value>(successful_payload(&toml)?)?)?;
         assert_eq!(toml_value, expected, "resource: {resource}");
 
         for format in ["json", "yaml", "toml"] {
@@ -94,7 +94,13 @@ fn test_show_structured_formats_are_complete_for_every_resource() -> common::Tes
                 temp_dir.path(),
                 &[&[resource, "show", id, "--history", "--output", format]],
             )?;
-            assert!(conflict.contains("error[E0802]"), "output: {conflict}");
+            // Under json, errors are reported in the result envelope.
+            let error = if format == "json" {
+                "\"code\": \"E0802\""
+            } else {
+                "error[E0802]"
+            };
+            assert!(conflict.contains(error), "output: {conflict}");
             assert!(conflict.contains("exit: 1"), "output: {conflict}");
         }
     }
@@ -227,8 +233,8 @@ fn test_content_equivalent_resources_accept_explicit_human_projection_matrix() -
         )?;
         assert_success_with(&work_current, "Projection Work");
         assert_eq!(
-            successful_payload(&work_current),
-            successful_payload(&work_history),
+            successful_payload(&work_current)?,
+            successful_payload(&work_history)?,
             "work format: {format}"
         );
 
@@ -255,8 +261,8 @@ fn test_content_equivalent_resources_accept_explicit_human_projection_matrix() -
         )?;
         assert_success_with(&guard_current, "Projection Guard");
         assert_eq!(
-            successful_payload(&guard_current),
-            successful_payload(&guard_history),
+            successful_payload(&guard_current)?,
+            successful_payload(&guard_history)?,
             "guard format: {format}"
         );
     }
@@ -308,3 +314,123 @@ fn test_superseded_adr_show_requires_history_for_body_content() -> common::TestR
     );
     Ok(())
 }
+
+#[test]
+fn test_shorthand_ids_resolve_and_typos_get_suggestions() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching"],
+            &["clause", "new", "RFC-0001:C-EXPIRY", "Expiry"],
+            &["adr", "new", "Use LRU"],
+        ],
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "get", "1", "title"],
+            &["rfc", "get", "rfc-01", "title"],
+            &["clause", "get", "RFC-1:c-expiry", "title"],
+            &["clause", "show", "RFC-0001:C-EXPIRE"],
+            &["rfc", "get", "RFC-0010"],
+        ],
+    )?;
+    assert!(output.contains("Use LRU\nexit: 0"), "output: {output}");
+    assert!(output.contains("Caching\nexit: 0"), "output: {output}");
+    assert!(output.contains("Expiry\nexit: 0"), "output: {output}");
+    assert!(
+        output.contains(
+            "Clause not found: RFC-0001:C-EXPIRE (hint: did you mean RFC-0001:C-EXPIRY?)"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("RFC not found: RFC-0010 (hint: did you mean RFC-0001?)"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_title_fragments_and_id_endings_resolve_to_one_artifact() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching"],
+            &["clause", "new", "RFC-0001:C-EXPIRY", "Expiry"],
+            &["adr", "new", "Use LRU cache"],
+            &["work", "new", "Guard timeout"],
+            &["work", "new", "Guard retries"],
+        ],
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "get", "lru-cache", "title"],
+            &["clause", "get", "expiry", "title"],
+            &["work", "move", "guard-timeout", "active"],
+            &["work", "get", "timeout", "status"],
+            &["work", "get", "guard", "status"],
+            &["adr", "show", "postgres"],
+        ],
+    )?;
+    assert!(
+        output.contains("Use LRU cache\nexit: 0"),
+        "output: {output}"
+    );
+    assert!(output.contains("Expiry\nexit: 0"), "output: {output}");
+    assert!(output.contains("active\nexit: 0"), "output: {output}");
+    assert!(
+        output.contains("error[E0406]: 'guard' matches 2 Work items"),
+        "output: {output}"
+    );
+    assert!(output.contains("(Guard timeout)"), "output: {output}");
+    assert!(output.contains("(Guard retries)"), "output: {output}");
+    assert!(
+        output.contains("ADR not found: postgres"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aliases_and_last_stand_in_for_ids() -> common::TestResult {
+    let temp_dir = init_project()?;
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Caching"],
+            &["clause", "new", "RFC-0001:C-EXPIRY", "Expiry"],
+        ],
+    )?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{config}\n[aliases]\ncore = \"RFC-0001\"\n"),
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "get", "@core", "title"],
+            &["clause", "get", "@core:c-expiry", "title"],
+            &["adr", "new", "Use LRU"],
+            &["adr", "add", "@last", "refs", "@core"],
+            &["adr", "get", "ADR-0001", "refs"],
+            &["rfc", "show", "@cor"],
+        ],
+    )?;
+    assert!(output.contains("Caching\nexit: 0"), "output: {output}");
+    assert!(output.contains("Expiry\nexit: 0"), "output: {output}");
+    assert!(output.contains("RFC-0001\nexit: 0"), "output: {output}");
+    assert!(
+        output.contains("error[E0830]: Alias not found: @cor (hint: did you mean @core?)"),
+        "output: {output}"
+    );
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Tests for `[staleness]` warnings and `list --stale`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+use std::path::Path;
+
+const STALENESS_CONFIG: &str = r#"
+[staleness]
+draft_rfc_days = 90
+proposed_adr_days = 30
+"#;
+
+fn backdate(path: &Path, key: &str) -> std::io::Result<()> {
+    let content = fs::read_to_string(path)?;
+    let backdated: Vec<String> = content
+        .lines()
+        .map(|line| {
+            if line.starts_with(&format!("{key} = \"")) {
+                format!("{key} = \"2020-01-01\"")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    fs::write(path, backdated.join("\n") + "\n")
+}
+
+#[test]
+fn test_stale_drafts_and_proposals_are_reported() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let root = temp_dir.path();
+    let config_path = root.join("gov/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(STALENESS_CONFIG);
+    fs::write(&config_path, config)?;
+
+    run_commands(
+        root,
+        &[
+            &["rfc", "new", "Old idea"],
+            &["rfc", "new", "Fresh idea"],
+            &["adr", "new", "Old proposal"],
+        ],
+    )?;
+    let rfc_path = root.join("gov/rfc/RFC-0001/rfc.toml");
+    backdate(&rfc_path, "created")?;
+    backdate(&rfc_path, "date")?;
+    backdate(&root.join("gov/adr/ADR-0001-old-proposal.toml"), "date")?;
+
+    let output = run_commands(
+        root,
+        &[
+            &["check"],
+            &["rfc", "list", "--stale", "-o", "plain"],
+            &["adr", "list", "--stale", "-o", "plain"],
+            &["work", "list", "--stale"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("warning[W0119]: Draft RFC-0001 has not been updated in"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("warning[W0119]: ADR-0001 has been proposed for"),
+        "output: {output}"
+    );
+    assert!(!output.contains("RFC-0002 has not"), "output: {output}");
+    let rfc_list = output
+        .split("$ govctl rfc list")
+        .nth(1)
+        .unwrap_or_default()
+        .split("$ govctl")
+        .next()
+        .unwrap_or_default();
+    assert!(rfc_list.contains("RFC-0001"), "output: {output}");
+    assert!(!rfc_list.contains("RFC-0002"), "output: {output}");
+    assert!(
+        output.contains("--stale applies to `rfc list` and `adr list` only"),
+        "output: {output}"
+    );
+    Ok(())
+}
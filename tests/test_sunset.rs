@@ -0,0 +1,137 @@
+//! Tests for deprecation sunsets (`deprecate --sunset/--reason`).
+
+mod common;
+
+use common::{init_project, run_commands};
+
+#[test]
+fn test_passed_sunset_warns_while_still_referenced() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "new", "Billing"],
+            &[
+                "clause",
+                "new",
+                "RFC-0002:C-SCOPE",
+                "Scope",
+                "-s",
+                "Specification",
+            ],
+            &["adr", "new", "Use a ledger"],
+            &["adr", "add", "ADR-0001", "refs", "RFC-0001"],
+            &["adr", "add", "ADR-0001", "refs", "RFC-0002:C-SCOPE"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &[
+                "rfc",
+                "deprecate",
+                "RFC-0001",
+                "-f",
+                "--sunset",
+                "2020-01-31",
+                "--reason",
+                "Replaced by RFC-0002",
+            ],
+            &[
+                "clause",
+                "deprecate",
+                "RFC-0002:C-SCOPE",
+                "-f",
+                "--sunset",
+                "2999-01-01",
+            ],
+            &["rfc", "show", "RFC-0001", "-o", "plain"],
+            &["check"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("> **Sunset:** 2020-01-31"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("> **Deprecation reason:** Replaced by RFC-0002"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "warning[W0120]: RFC-0001 passed its sunset date 2020-01-31 but is still referenced by ADR-0001"
+        ),
+        "output: {output}"
+    );
+    assert_eq!(
+        output.matches("warning[W0120]").count(),
+        1,
+        "a future sunset does not warn: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_adr_sunset_keeps_status_and_rejects_bad_dates() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "new", "Use a ledger"],
+            &[
+                "adr",
+                "deprecate",
+                "ADR-0001",
+                "-f",
+                "--sunset",
+                "2999-01-01",
+            ],
+            &["adr", "accept", "ADR-0001", "--force"],
+            &[
+                "adr",
+                "deprecate",
+                "ADR-0001",
+                "-f",
+                "--sunset",
+                "31/12/2999",
+            ],
+            &["adr", "deprecate", "ADR-0001", "-f"],
+            &[
+                "adr",
+                "deprecate",
+                "ADR-0001",
+                "-f",
+                "--sunset",
+                "2999-01-01",
+                "--reason",
+                "Moving to event sourcing",
+            ],
+            &["adr", "get", "ADR-0001", "status"],
+            &["adr", "show", "ADR-0001", "-o", "plain"],
+        ],
+    )?;
+
+    assert!(
+        output.contains("error[E0305]: Cannot schedule a sunset for ADR-0001"),
+        "proposed ADRs take no sunset: {output}"
+    );
+    assert!(
+        output.contains("error[E0820]: Invalid --sunset date '31/12/2999'"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("error[E0305]: ADRs cannot be deprecated"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Scheduled ADR-0001 sunset for 2999-01-01"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("$ govctl adr get ADR-0001 status\naccepted"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("> **Deprecation reason:** Moving to event sourcing"),
+        "output: {output}"
+    );
+    Ok(())
+}
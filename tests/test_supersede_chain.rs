@@ -0,0 +1,184 @@
+//! Tests for `supersede chain` and the supersession integrity checks.
+
+mod common;
+
+use common::{init_project, init_project_with_date, run_commands};
+
+/// Insert `line` after the first line equal to `after` in `path`.
+fn insert_after(path: &std::path::Path, after: &str, line: &str) -> common::TestResult {
+    let content = std::fs::read_to_string(path)?;
+    let updated = content.replacen(&format!("{after}\n"), &format!("{after}\n{line}\n"), 1);
+    assert_ne!(content, updated, "{after} not found in {}", path.display());
+    std::fs::write(path, updated)?;
+    Ok(())
+}
+
+#[test]
+fn test_supersede_chain_prints_the_chain_and_warns_on_inactive_head() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Payments v1"],
+            &["rfc", "new", "Payments v2"],
+            &["rfc", "new", "Payments v3"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "finalize", "RFC-0002", "normative"],
+            &["rfc", "supersede", "RFC-0001", "--by", "RFC-0002", "-f"],
+            &["rfc", "supersede", "RFC-0002", "--by", "RFC-0003", "-f"],
+            &["supersede", "chain", "RFC-0002"],
+            &["supersede", "chain", "RFC-0009"],
+            &["check"],
+        ],
+    )?;
+
+    assert!(
+        output.contains(
+            "RFC-0001 → RFC-0002 → RFC-0003\n  RFC-0001  deprecated (oldest)\n  RFC-0002  deprecated\n  RFC-0003  draft (head)\n"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("error[E0102]: RFC not found: RFC-0009"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "warning[W0121]: Supersession chain RFC-0001 -> RFC-0002 -> RFC-0003 ends at RFC-0003, which is draft"
+        ),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_rejects_supersession_forks_and_cycles() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments"],
+            &["rfc", "new", "Billing"],
+            &["rfc", "new", "Invoicing"],
+            &["adr", "new", "Use a ledger"],
+            &["adr", "new", "Use an event log"],
+        ],
+    )?;
+    for rfc in ["RFC-0002", "RFC-0003"] {
+        insert_after(
+            &dir.join(format!("gov/rfc/{rfc}/rfc.toml")),
+            "phase = \"spec\"",
+            "supersedes = \"RFC-0001\"",
+        )?;
+    }
+    for (adr, by) in [
+        ("ADR-0001-use-a-ledger", "ADR-0002"),
+        ("ADR-0002-use-an-event-log", "ADR-0001"),
+    ] {
+        let path = dir.join(format!("gov/adr/{adr}.toml"));
+        let content = std::fs::read_to_string(&path)?.replace(
+            "status = \"proposed\"",
+            &format!("status = \"superseded\"\nsuperseded_by = \"{by}\""),
+        );
+        std::fs::write(&path, content)?;
+    }
+
+    let output = run_commands(
+        dir,
+        &[
+            &["check"],
+            &["supersede", "chain", "ADR-0001"],
+            &["supersede", "chain", "RFC-0001"],
+        ],
+    )?;
+
+    assert!(
+        output.contains(
+            "error[E0126]: RFC-0001 is superseded by more than one artifact: RFC-0002, RFC-0003"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "error[E0309]: Supersession cycle detected: ADR-0001 -> ADR-0002 -> ADR-0001"
+        ),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("ADR-0001 → ADR-0002 → … (cycle)"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("    also superseded by RFC-0003"),
+        "output: {output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_supersede_update_refs_rewrites_refs_and_reports_source() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let dir = temp_dir.path();
+    let work_id = format!("WI-{date}-001");
+    let config_path = dir.join("gov/config.toml");
+    let config = std::fs::read_to_string(&config_path)?;
+    std::fs::write(
+        &config_path,
+        format!("{config}\n[source_scan]\nenabled = true\ninclude = [\"src/**/*.rs\"]\n"),
+    )?;
+    std::fs::create_dir_all(dir.join("src"))?;
+    std::fs::write(
+        dir.join("src/lib.rs"),
+        "// Implements [[RFC-0001]]\npub fn pay() {}\n",
+    )?;
+    run_commands(
+        dir,
+        &[
+            &["rfc", "new", "Payments v1"],
+            &["rfc", "new", "Payments v2"],
+            &["rfc", "finalize", "RFC-0001", "normative"],
+            &["rfc", "edit", "RFC-0002", "refs", "--add", "RFC-0001"],
+            &["adr", "new", "Use a ledger"],
+            &["adr", "edit", "ADR-0001", "refs", "--add", "RFC-0001"],
+            &["work", "new", "Build payments"],
+            &["work", "edit", &work_id, "refs", "--add", "RFC-0001"],
+            &["work", "edit", &work_id, "refs", "--add", "RFC-0002"],
+        ],
+    )?;
+
+    let output = run_commands(
+        dir,
+        &[&[
+            "rfc",
+            "supersede",
+            "RFC-0001",
+            "--by",
+            "RFC-0002",
+            "--update-refs",
+            "-f",
+        ]],
+    )?;
+
+    assert!(
+        output.contains("Updated refs in ADR-0001: RFC-0001 -> RFC-0002"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(&format!("Updated refs in {work_id}: RFC-0001 -> RFC-0002")),
+        "output: {output}"
+    );
+    assert!(
+        output.contains(
+            "warning[W0107]: Reference to superseded RFC-0001: update it to RFC-0002 (src/lib.rs:1)"
+        ),
+        "output: {output}"
+    );
+    let adr = std::fs::read_to_string(dir.join("gov/adr/ADR-0001-use-a-ledger.toml"))?;
+    assert!(adr.contains("refs = [\"RFC-0002\"]"), "adr: {adr}");
+    let work = std::fs::read_to_string(dir.join(format!("gov/work/{date}-build-payments.toml")))?;
+    assert!(work.contains("refs = [\"RFC-0002\"]"), "work: {work}");
+    let rfc = std::fs::read_to_string(dir.join("gov/rfc/RFC-0002/rfc.toml"))?;
+    assert!(rfc.contains("refs = [\"RFC-0001\"]"), "rfc: {rfc}");
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Tests for `--timings`.
+
+mod common;
+
+use common::init_project;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn govctl(dir: &Path, args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
+        .output()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn test_timings_lists_nested_phases_after_the_command() -> common::TestResult {
+    let temp_dir = init_project()?;
+    govctl(temp_dir.path(), &["rfc", "new", "Caching layer"])?;
+
+    let check = govctl(temp_dir.path(), &["check", "--timings"])?;
+    assert!(check.status.success(), "{}", stderr(&check));
+    let err = stderr(&check);
+    let timings = err
+        .split_once("Timings:\n")
+        .map(|(_, rest)| rest)
+        .ok_or(format!("no timings in {err}"))?;
+    let rows: Vec<&str> = timings.lines().collect();
+    assert!(
+        rows.iter().any(|row| row.starts_with("  check ")),
+        "{timings}"
+    );
+    assert!(
+        rows.iter().any(|row| row.starts_with("    load project ")),
+        "{timings}"
+    );
+    assert!(
+        rows.iter().any(|row| row.starts_with("      load RFCs ")),
+        "{timings}"
+    );
+    assert!(
+        rows.last().is_some_and(|row| row.starts_with("total ")),
+        "{timings}"
+    );
+
+    let plain = govctl(temp_dir.path(), &["check"])?;
+    assert!(!stderr(&plain).contains("Timings:"), "{}", stderr(&plain));
+    Ok(())
+}
+
+#[test]
+fn test_timings_sums_repeated_phases() -> common::TestResult {
+    let temp_dir = init_project()?;
+    govctl(temp_dir.path(), &["rfc", "new", "First"])?;
+    govctl(temp_dir.path(), &["rfc", "new", "Second"])?;
+
+    let render = govctl(temp_dir.path(), &["render", "rfc", "--timings"])?;
+    assert!(render.status.success(), "{}", stderr(&render));
+    let err = stderr(&render);
+    assert!(err.contains("  render RFCs "), "{err}");
+    assert!(err.contains("    load ADRs (x2) "), "{err}");
+    Ok(())
+}
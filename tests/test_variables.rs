@@ -0,0 +1,79 @@
+//! `{{var.<name>}}` in clause and ADR text resolves from `[variables]`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::fs;
+
+#[test]
+fn test_variables_expand_in_rendered_rfc_and_adr() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!(
+            "{config}\n[variables]\nproduct = \"Acme Ledger\"\nsupport_url = \"https://support.example.com\"\n"
+        ),
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Support"],
+            &["clause", "new", "RFC-0001:C-SUPPORT", "Support"],
+            &[
+                "clause",
+                "set",
+                "RFC-0001:C-SUPPORT",
+                "text",
+                "{{var.product}} MUST link to {{var.support_url}}.",
+            ],
+            &["rfc", "render", "RFC-0001"],
+            &["adr", "new", "Branding"],
+            &[
+                "adr",
+                "set",
+                "ADR-0001",
+                "consequences",
+                "Ship as {{var.product}}.",
+            ],
+            &["adr", "render", "ADR-0001"],
+            &["check"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "{output}");
+    let rfc = fs::read_to_string(temp_dir.path().join("docs/rfc/RFC-0001.md"))?;
+    assert!(
+        rfc.contains("Acme Ledger MUST link to https://support.example.com."),
+        "{rfc}"
+    );
+    let adr = fs::read_to_string(temp_dir.path().join("docs/adr/ADR-0001.md"))?;
+    assert!(adr.contains("Ship as Acme Ledger."), "{adr}");
+    Ok(())
+}
+
+#[test]
+fn test_check_reports_undefined_variable() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["adr", "new", "Branding"],
+            &[
+                "adr",
+                "set",
+                "ADR-0001",
+                "context",
+                "Customers know us as {{var.product}}.",
+            ],
+            &["check"],
+        ],
+    )?;
+    assert!(output.contains("error[E0514]"), "{output}");
+    assert!(
+        output.contains("Variable 'product' is not defined"),
+        "{output}"
+    );
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Tests for the global `--quiet` and `--verbose` flags.
+
+mod common;
+
+use common::init_project;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn govctl(dir: &Path, args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_govctl"))
+        .args(args)
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
+        .output()
+}
+
+#[test]
+fn test_quiet_suppresses_success_messages() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = govctl(temp_dir.path(), &["-q", "rfc", "new", "Caching layer"])?;
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty(), "stderr: {output:?}");
+    assert!(temp_dir.path().join("gov/rfc/RFC-0001/rfc.toml").is_file());
+
+    let output = govctl(temp_dir.path(), &["check", "--quiet"])?;
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty(), "stderr: {output:?}");
+    Ok(())
+}
+
+#[test]
+fn test_quiet_keeps_diagnostics() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = govctl(
+        temp_dir.path(),
+        &["-q", "rfc", "finalize", "RFC-0099", "normative"],
+    )?;
+
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error[E0102]"), "stderr: {stderr}");
+    Ok(())
+}
+
+#[test]
+fn test_verbose_logs_writes_lock_and_timing() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = govctl(temp_dir.path(), &["-v", "rfc", "new", "Caching layer"])?;
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("acquired lock"), "stderr: {stderr}");
+    assert!(
+        stderr.contains("wrote gov/rfc/RFC-0001/rfc.toml"),
+        "stderr: {stderr}"
+    );
+    assert!(!stderr.contains("read "), "stderr: {stderr}");
+
+    let output = govctl(temp_dir.path(), &["check", "-vv"])?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("validate artifacts took"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("read "), "stderr: {stderr}");
+    assert!(stderr.contains("All checks passed"), "stderr: {stderr}");
+    Ok(())
+}
+
+#[test]
+fn test_quiet_conflicts_with_verbose() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let output = govctl(temp_dir.path(), &["-q", "-v", "check"])?;
+
+    assert_eq!(output.status.code(), Some(2));
+    Ok(())
+}
@@ -63,7 +63,7 @@ fn test_verify_timeout_diagnostic_mentions_primary_shell_state() -> TestResult {
         "output: {}",
         output
     );
-    assert!(output.contains("exit: 1"), "output: {}", output);
+    assert!(output.contains("exit: 3"), "output: {}", output);
 
     Ok(())
 }
@@ -112,7 +112,34 @@ fn test_work_move_done_rejects_failed_required_guard() -> TestResult {
         output
     );
     assert!(output.contains("error[E1004]"), "output: {}", output);
-    assert!(output.contains("exit: 1"), "output: {}", output);
+    assert!(output.contains("exit: 3"), "output: {}", output);
+
+    Ok(())
+}
+
+#[test]
+fn test_work_finish_runs_guards_even_when_done_skips_them() -> TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{config}\n[work_item.done]\nguards = false\n"),
+    )?;
+
+    write_guard_with_timeout(temp_dir.path(), "GUARD-FAIL", "exit 1", None, 300)?;
+    write_canonical_guarded_work_item(temp_dir.path(), "WI-2026-01-01-001", "GUARD-FAIL", None)?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "finish", "WI-2026-01-01-001"],
+            &["work", "move", "WI-2026-01-01-001", "done"],
+        ],
+    )?;
+    assert!(output.contains("error[E1004]"), "output: {}", output);
+    assert!(output.contains("exit: 3"), "output: {}", output);
+    assert!(output.contains("exit: 0"), "output: {}", output);
 
     Ok(())
 }
@@ -148,6 +175,224 @@ fn test_work_move_done_allows_waived_guard() -> TestResult {
     Ok(())
 }
 
+/// An active work item ready for done whose `[verification]` is `verification`.
+fn write_work_item_with_verification(dir: &Path, verification: &str) -> TestResult {
+    fs::write(
+        dir.join("gov/work/2026-01-01-guarded-item.toml"),
+        format!(
+            "[govctl]\nschema = 1\nid = \"WI-2026-01-01-001\"\ntitle = \"Guarded Item\"\nstatus = \"active\"\ncreated = \"2026-01-01\"\nstarted = \"2026-01-01\"\n\n[content]\ndescription = \"Guarded work item\"\n\n[[content.acceptance_criteria]]\ntext = \"done criteria\"\nstatus = \"done\"\ncategory = \"chore\"\n\n{verification}"
+        ),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_work_move_done_runs_guard_declared_on_item() -> TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{config}\n[work_item.done]\nguards = false\n"),
+    )?;
+    write_work_item_with_verification(
+        temp_dir.path(),
+        "[[verification.guards]]\nid = \"GUARD-BENCH\"\ncommand = \"exit 1\"\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["work", "move", "WI-2026-01-01-001", "done"]],
+    )?;
+    assert!(
+        output.contains("Verification guard 'GUARD-BENCH' failed"),
+        "output: {}",
+        output
+    );
+    assert!(output.contains("error[E1004]"), "output: {}", output);
+
+    Ok(())
+}
+
+#[test]
+fn test_guard_declared_on_item_overrides_project_guard() -> TestResult {
+    let temp_dir = init_project()?;
+
+    write_guard_with_timeout(temp_dir.path(), "GUARD-FAIL", "exit 1", None, 300)?;
+    write_work_item_with_verification(
+        temp_dir.path(),
+        "[verification]\nrequired_guards = [\"GUARD-FAIL\"]\n\n[[verification.guards]]\nid = \"GUARD-FAIL\"\ncommand = \"true\"\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["verify", "--work", "WI-2026-01-01-001"],
+            &["work", "move", "WI-2026-01-01-001", "done"],
+        ],
+    )?;
+    assert!(output.contains("PASS GUARD-FAIL"), "output: {}", output);
+    assert!(!output.contains("error["), "output: {}", output);
+
+    Ok(())
+}
+
+#[test]
+fn test_guard_runs_are_recorded_on_work_item() -> TestResult {
+    let temp_dir = init_project()?;
+    let config_path = temp_dir.path().join("gov/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{config}\n[verification]\noutput_lines = 2\n"),
+    )?;
+    write_work_item_with_verification(
+        temp_dir.path(),
+        "[[verification.guards]]\nid = \"GUARD-FLAKY\"\ncommand = \"printf 'one\\\\ntwo\\\\nthree\\\\n'; exit 3\"\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "move", "WI-2026-01-01-001", "done"],
+            &["verify", "--work", "WI-2026-01-01-001"],
+            &["work", "get", "WI-2026-01-01-001", "status"],
+        ],
+    )?;
+    assert!(output.contains("error[E1004]"), "output: {}", output);
+    assert!(output.contains("\nactive\n"), "output: {}", output);
+
+    let item = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("gov/work/2026-01-01-guarded-item.toml"),
+    )?;
+    assert_eq!(
+        item.matches("[[verification.results]]").count(),
+        2,
+        "item: {}",
+        item
+    );
+    assert!(item.contains("exit_code = 3"), "item: {}", item);
+    assert!(item.contains("passed = false"), "item: {}", item);
+    assert!(item.contains("three"), "item: {}", item);
+    assert!(!item.contains("\none"), "item: {}", item);
+
+    Ok(())
+}
+
+fn append_to_guard(dir: &Path, guard_id: &str, extra: &str) -> TestResult {
+    let path = dir
+        .join("gov/guard")
+        .join(format!("{}.toml", guard_id.to_lowercase()));
+    let content = fs::read_to_string(&path)?;
+    fs::write(path, format!("{content}{extra}"))?;
+    Ok(())
+}
+
+#[test]
+fn test_verify_applies_guard_env_working_dir_and_shell() -> TestResult {
+    let temp_dir = init_project()?;
+    write_guard_with_timeout(
+        temp_dir.path(),
+        "GUARD-ENV",
+        "test \\\"$STAGE\\\" = ci && test -d guard",
+        None,
+        NON_TIMEOUT_GUARD_TIMEOUT_SECS,
+    )?;
+    append_to_guard(
+        temp_dir.path(),
+        "GUARD-ENV",
+        "working_dir = \"gov\"\nshell = \"sh\"\n\n[check.env]\nSTAGE = \"ci\"\n",
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["verify", "GUARD-ENV"]])?;
+    assert!(output.contains("PASS GUARD-ENV"), "output: {}", output);
+    assert!(output.contains("exit: 0"), "output: {}", output);
+
+    Ok(())
+}
+
+#[test]
+fn test_allowed_guard_failure_does_not_block_done() -> TestResult {
+    let temp_dir = init_project()?;
+    write_guard_with_timeout(temp_dir.path(), "GUARD-BENCH", "exit 1", None, 300)?;
+    append_to_guard(temp_dir.path(), "GUARD-BENCH", "allow_failure = true\n")?;
+    write_work_item_with_verification(
+        temp_dir.path(),
+        "[verification]\nrequired_guards = [\"GUARD-BENCH\"]\n",
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["verify", "GUARD-BENCH"],
+            &["work", "move", "WI-2026-01-01-001", "done"],
+            &["work", "get", "WI-2026-01-01-001", "status"],
+        ],
+    )?;
+    assert!(output.contains("warning[W0128]"), "output: {}", output);
+    assert!(
+        output.contains("FAIL GUARD-BENCH (failure allowed)"),
+        "output: {}",
+        output
+    );
+    assert!(!output.contains("error["), "output: {}", output);
+    assert!(output.contains("\ndone\n"), "output: {}", output);
+
+    Ok(())
+}
+
+#[test]
+fn test_misconfigured_guard_fails_before_running() -> TestResult {
+    let temp_dir = init_project()?;
+    write_guard_with_timeout(temp_dir.path(), "GUARD-BAD", "touch ran", None, 300)?;
+    append_to_guard(
+        temp_dir.path(),
+        "GUARD-BAD",
+        "working_dir = \"../elsewhere\"\nshell = \"no-such-shell\"\n",
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["verify", "GUARD-BAD"], &["check"]])?;
+    assert!(
+        output.contains("Guard working_dir must be a relative path inside the project: GUARD-BAD"),
+        "output: {}",
+        output
+    );
+    assert!(
+        output.contains("Guard shell 'no-such-shell' not found: GUARD-BAD"),
+        "output: {}",
+        output
+    );
+    assert!(!temp_dir.path().join("ran").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_check_rejects_invalid_guard_declared_on_item() -> TestResult {
+    let temp_dir = init_project()?;
+    write_work_item_with_verification(
+        temp_dir.path(),
+        "[[verification.guards]]\nid = \"GUARD-EMPTY\"\ncommand = \" \"\n\n[[verification.guards]]\nid = \"GUARD-REGEX\"\ncommand = \"true\"\npattern = \"(unclosed\"\n",
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["check"]])?;
+    assert!(
+        output.contains("Verification guard command must not be empty: GUARD-EMPTY"),
+        "output: {}",
+        output
+    );
+    assert!(
+        output.contains("Invalid guard regex pattern: GUARD-REGEX"),
+        "output: {}",
+        output
+    );
+    assert!(output.contains("error[E1001]"), "output: {}", output);
+
+    Ok(())
+}
+
 #[cfg(unix)]
 fn perl_supports_setsid() -> bool {
     Command::new("perl")
@@ -168,6 +413,7 @@ fn run_command_with_timeout(
         .current_dir(dir)
         .env("NO_COLOR", "1")
         .env("GOVCTL_DEFAULT_OWNER", "@test-user")
+        .env("GOVCTL_ACTOR", "human:test-user")
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .spawn()?;
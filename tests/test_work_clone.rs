@@ -0,0 +1,84 @@
+//! Tests for `work clone`.
+
+mod common;
+
+use common::{first_work_id, init_project_with_date, run_commands};
+use std::fs;
+
+#[test]
+fn test_work_clone_copies_description_refs_and_pending_criteria() -> common::TestResult {
+    let (temp_dir, date) = init_project_with_date()?;
+    let source = first_work_id(&date);
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["rfc", "new", "Key rotation"],
+            &["work", "new", "Rotate keys", "--active"],
+            &[
+                "work",
+                "set",
+                &source,
+                "description",
+                "Rotate signing keys.",
+            ],
+            &["work", "add", &source, "refs", "RFC-0001"],
+            &[
+                "work",
+                "add",
+                &source,
+                "acceptance_criteria",
+                "add: Old key",
+            ],
+            &[
+                "work",
+                "add",
+                &source,
+                "acceptance_criteria",
+                "add: New key",
+            ],
+            &[
+                "work",
+                "tick",
+                &source,
+                "acceptance_criteria",
+                "Old key",
+                "-s",
+                "done",
+            ],
+            &["work", "add", &source, "notes", "Started on production"],
+        ],
+    )?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["work", "clone", &source, "--title", "Rotate staging keys"],
+            &["work", "clone", "rotate-keys"],
+        ],
+    )?;
+    assert!(!output.contains("error["), "{output}");
+
+    let copy = fs::read_to_string(
+        temp_dir
+            .path()
+            .join(format!("gov/work/{date}-rotate-staging-keys.toml")),
+    )?;
+    assert!(copy.contains("title = \"Rotate staging keys\""), "{copy}");
+    assert!(copy.contains("status = \"queue\""), "{copy}");
+    assert!(copy.contains("refs = [\"RFC-0001\"]"), "{copy}");
+    assert!(copy.contains("Rotate signing keys."), "{copy}");
+    assert!(copy.contains("New key"), "{copy}");
+    assert!(!copy.contains("Old key"), "{copy}");
+    assert!(!copy.contains("Started on production"), "{copy}");
+
+    let same_title = fs::read_to_string(
+        temp_dir
+            .path()
+            .join(format!("gov/work/{date}-rotate-keys-001.toml")),
+    )?;
+    assert!(
+        same_title.contains("title = \"Rotate keys\""),
+        "{same_title}"
+    );
+    Ok(())
+}
@@ -0,0 +1,180 @@
+//! Tests for `work rank` and `work next`.
+
+mod common;
+
+use common::{init_project, run_commands};
+use std::{fs, path::Path};
+
+fn write_work_item(
+    dir: &Path,
+    id: &str,
+    status: &str,
+    tags: &[&str],
+    depends_on: &[&str],
+) -> common::TestResult {
+    let quote = |values: &[&str]| {
+        values
+            .iter()
+            .map(|value| format!("\"{value}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let content = format!(
+        r#"[govctl]
+schema = 1
+id = "{id}"
+title = "Item {id}"
+status = "{status}"
+created = "2025-01-01"
+tags = [{tags}]
+depends_on = [{depends_on}]
+
+[content]
+description = "Test"
+"#,
+        tags = quote(tags),
+        depends_on = quote(depends_on),
+    );
+    fs::write(
+        dir.join(format!("gov/work/{}.toml", id.to_lowercase())),
+        content,
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_rank_moves_item_and_renumbers_queue() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    for id in [
+        "WI-2025-01-01-001",
+        "WI-2025-01-01-002",
+        "WI-2025-01-01-003",
+    ] {
+        write_work_item(dir, id, "queue", &[], &[])?;
+    }
+
+    let output = run_commands(
+        dir,
+        &[
+            &[
+                "work",
+                "rank",
+                "WI-2025-01-01-003",
+                "--before",
+                "WI-2025-01-01-001",
+            ],
+            &["work", "next", "-o", "plain"],
+            &["work", "get", "WI-2025-01-01-001", "priority"],
+        ],
+    )?;
+    assert!(
+        output.contains("Ranked WI-2025-01-01-003 1 of 3 in the queue"),
+        "{output}"
+    );
+    assert!(
+        output.contains("$ govctl work next -o plain\nWI-2025-01-01-003\n"),
+        "{output}"
+    );
+    assert!(
+        output.contains("$ govctl work get WI-2025-01-01-001 priority\n2\n"),
+        "{output}"
+    );
+
+    let output = run_commands(
+        dir,
+        &[
+            &[
+                "work",
+                "rank",
+                "WI-2025-01-01-003",
+                "--after",
+                "WI-2025-01-01-002",
+            ],
+            &["work", "list", "queue", "-o", "plain"],
+        ],
+    )?;
+    assert!(
+        output.contains("Ranked WI-2025-01-01-003 3 of 3 in the queue"),
+        "{output}"
+    );
+    let listed: Vec<&str> = output
+        .lines()
+        .filter(|line| line.starts_with("WI-"))
+        .map(|line| line.split_whitespace().next().unwrap_or_default())
+        .collect();
+    assert_eq!(
+        listed,
+        [
+            "WI-2025-01-01-001",
+            "WI-2025-01-01-002",
+            "WI-2025-01-01-003"
+        ],
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rank_rejects_items_not_in_queue() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    write_work_item(dir, "WI-2025-01-01-001", "queue", &[], &[])?;
+    write_work_item(dir, "WI-2025-01-01-002", "active", &[], &[])?;
+
+    let output = run_commands(
+        dir,
+        &[&[
+            "work",
+            "rank",
+            "WI-2025-01-01-002",
+            "--before",
+            "WI-2025-01-01-001",
+        ]],
+    )?;
+    assert!(output.contains("error[E0413]"), "{output}");
+    assert!(
+        output.contains("WI-2025-01-01-002 is active; only queued work items have a queue rank"),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_next_skips_blocked_and_filtered_items_and_activates() -> common::TestResult {
+    let temp_dir = init_project()?;
+    let dir = temp_dir.path();
+    write_work_item(dir, "WI-2025-01-01-001", "active", &[], &[])?;
+    write_work_item(
+        dir,
+        "WI-2025-01-01-002",
+        "queue",
+        &[],
+        &["WI-2025-01-01-001"],
+    )?;
+    write_work_item(dir, "WI-2025-01-01-003", "queue", &[], &[])?;
+    write_work_item(dir, "WI-2025-01-01-004", "queue", &["backend"], &[])?;
+    let output = run_commands(dir, &[&["tag", "new", "backend"]])?;
+    assert!(!output.contains("error["), "{output}");
+
+    let output = run_commands(
+        dir,
+        &[
+            &["work", "next", "-o", "plain"],
+            &["work", "next", "--tag", "backend", "--activate"],
+            &["work", "get", "WI-2025-01-01-004", "status"],
+        ],
+    )?;
+    assert!(
+        output.contains("$ govctl work next -o plain\nWI-2025-01-01-003\n"),
+        "{output}"
+    );
+    assert!(
+        output.contains("$ govctl work get WI-2025-01-01-004 status\nactive\n"),
+        "{output}"
+    );
+
+    let output = run_commands(dir, &[&["work", "next", "--tag", "backend"]])?;
+    assert!(output.contains("No queued work item is ready"), "{output}");
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! Tests for workspaces: `-p/--project` and `check --workspace`.
+
+mod common;
+
+use common::run_commands;
+use std::fs;
+use tempfile::TempDir;
+
+fn init_workspace() -> Result<TempDir, Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    fs::write(
+        temp_dir.path().join("govctl-workspace.toml"),
+        "[members]\napi = \"services/api\"\nbilling = \"services/billing\"\n",
+    )?;
+    fs::create_dir_all(temp_dir.path().join("services/api"))?;
+    fs::create_dir_all(temp_dir.path().join("services/billing"))?;
+    let output = run_commands(
+        temp_dir.path(),
+        &[&["-p", "api", "init"], &["-p", "billing", "init"]],
+    )?;
+    assert!(!output.contains("exit: 1"), "output: {output}");
+    Ok(temp_dir)
+}
+
+#[test]
+fn test_project_flag_selects_workspace_member() -> common::TestResult {
+    let temp_dir = init_workspace()?;
+
+    let output = run_commands(
+        temp_dir.path(),
+        &[
+            &["-p", "billing", "rfc", "new", "Invoices"],
+            &["--project", "api", "rfc", "list", "-o", "plain"],
+            &["-p", "shop", "status"],
+        ],
+    )?;
+
+    assert!(
+        temp_dir
+            .path()
+            .join("services/billing/gov/rfc/RFC-0001/rfc.toml")
+            .is_file()
+    );
+    assert!(
+        !temp_dir
+            .path()
+            .join("services/api/gov/rfc/RFC-0001")
+            .exists()
+    );
+    assert!(
+        output.contains("error[E0510]: Unknown workspace member 'shop' (members: api, billing)"),
+        "output: {output}"
+    );
+    assert!(output.contains("exit: 4"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_member_commands_work_from_inside_member_directory() -> common::TestResult {
+    let temp_dir = init_workspace()?;
+
+    let output = run_commands(
+        &temp_dir.path().join("services/api"),
+        &[&["rfc", "new", "Gateway"]],
+    )?;
+
+    assert!(output.contains("exit: 0"), "output: {output}");
+    assert!(
+        temp_dir
+            .path()
+            .join("services/api/gov/rfc/RFC-0001/rfc.toml")
+            .is_file()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_workspace_resolves_cross_project_refs() -> common::TestResult {
+    let temp_dir = init_workspace()?;
+    run_commands(
+        temp_dir.path(),
+        &[
+            &["-p", "billing", "rfc", "new", "Invoices"],
+            &[
+                "-p",
+                "api",
+                "adr",
+                "new",
+                "Bill through the billing service",
+            ],
+            &[
+                "-p",
+                "api",
+                "adr",
+                "set",
+                "ADR-0001",
+                "context",
+                "Invoices follow [[billing/RFC-0001]]; refunds follow [[billing/RFC-0002]] and [[shop/ADR-0001]].",
+            ],
+        ],
+    )?;
+
+    let output = run_commands(temp_dir.path(), &[&["check", "--workspace"]])?;
+
+    assert!(
+        output.contains("error[E0511]: Reference [[billing/RFC-0002]] not found: billing has no RFC-0002 (services/api/gov/adr/ADR-0001-"),
+        "output: {output}"
+    );
+    assert!(
+        output.contains("Reference [[shop/ADR-0001]] names unknown workspace member 'shop'"),
+        "output: {output}"
+    );
+    assert!(
+        !output.contains("[[billing/RFC-0001]] not found"),
+        "output: {output}"
+    );
+    assert!(output.contains("exit: 3"), "output: {output}");
+    Ok(())
+}
+
+#[test]
+fn test_check_workspace_requires_manifest() -> common::TestResult {
+    let temp_dir = TempDir::new()?;
+
+    let output = run_commands(temp_dir.path(), &[&["check", "--workspace"]])?;
+
+    assert!(
+        output.contains("No govctl-workspace.toml found"),
+        "output: {output}"
+    );
+    Ok(())
+}